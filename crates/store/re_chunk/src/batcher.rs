@@ -11,7 +11,9 @@ use nohash_hasher::IntMap;
 
 use re_arrow_util::arrays_to_list_array_opt;
 use re_byte_size::SizeBytes as _;
-use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimePoint, Timeline, TimelineName};
+use re_log_types::{
+    AbsoluteTimeRange, EntityPath, StoreId, TimeInt, TimePoint, Timeline, TimelineName,
+};
 use re_types_core::ComponentDescriptor;
 
 use crate::{Chunk, ChunkId, ChunkResult, RowId, TimeColumn, chunk::ChunkComponents};
@@ -349,7 +351,7 @@ fn chunk_batcher_config() {
 /// The splitting process will then run on top of that big dataframe, and split it further down
 /// into smaller [`Chunk`]s.
 /// Specifically, the dataframe will be splits into enough [`Chunk`]s so as to guarantee that:
-/// * no chunk contains data for more than one entity path
+/// * no chunk contains data for more than one store or entity path
 /// * no chunk contains rows with different sets of timelines
 /// * no chunk uses more than one datatype for a given component
 /// * no chunk contains more rows than a pre-configured threshold if one or more timelines are unsorted
@@ -367,6 +369,12 @@ fn chunk_batcher_config() {
 /// previous data sent by the calling thread has been batched and sent down the channel returned
 /// by [`ChunkBatcher::chunks`]; no more, no less.
 ///
+/// ## Stores
+///
+/// A single batcher can batch the data of several stores, see [`Self::push_row_for_store`].
+/// Every chunk comes out tagged with the store it belongs to, where `None` stands for whichever
+/// store the owner of the batcher is logging to.
+///
 /// ## Shutdown
 ///
 /// The batcher can only be shutdown by dropping all instances of it, at which point it will
@@ -386,7 +394,7 @@ struct ChunkBatcherInner {
     /// running.
     tx_cmds: Sender<Command>,
     // NOTE: Option so we can make shutdown non-blocking even with bounded channels.
-    rx_chunks: Option<Receiver<Chunk>>,
+    rx_chunks: Option<Receiver<(Option<StoreId>, Chunk)>>,
     cmds_to_chunks_handle: Option<std::thread::JoinHandle<()>>,
 }
 
@@ -410,8 +418,8 @@ impl Drop for ChunkBatcherInner {
 }
 
 enum Command {
-    AppendChunk(Chunk),
-    AppendRow(EntityPath, PendingRow),
+    AppendChunk(Option<StoreId>, Chunk),
+    AppendRow(Option<StoreId>, EntityPath, PendingRow),
     Flush { on_done: Sender<()> },
     UpdateConfig(ChunkBatcherConfig),
     Shutdown,
@@ -472,7 +480,15 @@ impl ChunkBatcher {
     // --- Send commands ---
 
     pub fn push_chunk(&self, chunk: Chunk) {
-        self.inner.push_chunk(chunk);
+        self.inner.push_chunk(None, chunk);
+    }
+
+    /// Like [`Self::push_chunk`], for a chunk of the given store.
+    ///
+    /// See [`ChunkBatcher`] docs for how stores are handled.
+    #[inline]
+    pub fn push_chunk_for_store(&self, store_id: Option<StoreId>, chunk: Chunk) {
+        self.inner.push_chunk(store_id, chunk);
     }
 
     /// Pushes a [`PendingRow`] down the batching pipeline.
@@ -482,7 +498,21 @@ impl ChunkBatcher {
     /// See [`ChunkBatcher`] docs for ordering semantics and multithreading guarantees.
     #[inline]
     pub fn push_row(&self, entity_path: EntityPath, row: PendingRow) {
-        self.inner.push_row(entity_path, row);
+        self.inner.push_row(None, entity_path, row);
+    }
+
+    /// Like [`Self::push_row`], for a row of the given store.
+    ///
+    /// Rows of different stores are never batched into the same chunk.
+    /// See [`ChunkBatcher`] docs for how stores are handled.
+    #[inline]
+    pub fn push_row_for_store(
+        &self,
+        store_id: Option<StoreId>,
+        entity_path: EntityPath,
+        row: PendingRow,
+    ) {
+        self.inner.push_row(store_id, entity_path, row);
     }
 
     /// Initiates a flush of the pipeline and returns immediately.
@@ -509,12 +539,13 @@ impl ChunkBatcher {
 
     // --- Subscribe to chunks ---
 
-    /// Returns a _shared_ channel in which are sent the batched [`Chunk`]s.
+    /// Returns a _shared_ channel in which are sent the batched [`Chunk`]s, along with the store
+    /// they belong to.
     ///
     /// Shutting down the batcher will close this channel.
     ///
     /// See [`ChunkBatcher`] docs for ordering semantics and multithreading guarantees.
-    pub fn chunks(&self) -> Receiver<Chunk> {
+    pub fn chunks(&self) -> Receiver<(Option<StoreId>, Chunk)> {
         // NOTE: `rx_chunks` is only ever taken when the batcher as a whole is dropped, at which
         // point it is impossible to call this method.
         #[allow(clippy::unwrap_used)]
@@ -523,12 +554,12 @@ impl ChunkBatcher {
 }
 
 impl ChunkBatcherInner {
-    fn push_chunk(&self, chunk: Chunk) {
-        self.send_cmd(Command::AppendChunk(chunk));
+    fn push_chunk(&self, store_id: Option<StoreId>, chunk: Chunk) {
+        self.send_cmd(Command::AppendChunk(store_id, chunk));
     }
 
-    fn push_row(&self, entity_path: EntityPath, row: PendingRow) {
-        self.send_cmd(Command::AppendRow(entity_path, row));
+    fn push_row(&self, store_id: Option<StoreId>, entity_path: EntityPath, row: PendingRow) {
+        self.send_cmd(Command::AppendRow(store_id, entity_path, row));
     }
 
     fn flush_async(&self) {
@@ -564,20 +595,22 @@ fn batching_thread(
     mut config: ChunkBatcherConfig,
     hooks: BatcherHooks,
     rx_cmd: Receiver<Command>,
-    tx_chunk: Sender<Chunk>,
+    tx_chunk: Sender<(Option<StoreId>, Chunk)>,
 ) {
     let mut rx_tick = crossbeam::channel::tick(config.flush_tick);
 
     struct Accumulator {
         latest: Instant,
+        store_id: Option<StoreId>,
         entity_path: EntityPath,
         pending_rows: Vec<PendingRow>,
         pending_num_bytes: u64,
     }
 
     impl Accumulator {
-        fn new(entity_path: EntityPath) -> Self {
+        fn new(store_id: Option<StoreId>, entity_path: EntityPath) -> Self {
             Self {
+                store_id,
                 entity_path,
                 latest: Instant::now(),
                 pending_rows: Default::default(),
@@ -592,7 +625,8 @@ fn batching_thread(
         }
     }
 
-    let mut accs: IntMap<EntityPath, Accumulator> = IntMap::default();
+    let mut accs: ahash::HashMap<(Option<StoreId>, EntityPath), Accumulator> =
+        ahash::HashMap::default();
 
     fn do_push_row(acc: &mut Accumulator, row: PendingRow) {
         acc.pending_num_bytes += row.total_size_bytes();
@@ -601,7 +635,7 @@ fn batching_thread(
 
     fn do_flush_all(
         acc: &mut Accumulator,
-        tx_chunk: &Sender<Chunk>,
+        tx_chunk: &Sender<(Option<StoreId>, Chunk)>,
         reason: &str,
         chunk_max_rows_if_unsorted: u64,
     ) {
@@ -632,7 +666,7 @@ fn batching_thread(
 
             if !chunk.components.is_empty() {
                 // make sure the chunk didn't contain *only* indicators!
-                tx_chunk.send(chunk).ok();
+                tx_chunk.send((acc.store_id.clone(), chunk)).ok();
             } else {
                 re_log::warn_once!(
                     "Dropping chunk without components. Entity path: {}",
@@ -671,13 +705,13 @@ fn batching_thread(
 
 
                 match cmd {
-                    Command::AppendChunk(chunk) => {
+                    Command::AppendChunk(store_id, chunk) => {
                         // NOTE: This can only fail if all receivers have been dropped, which simply cannot happen
                         // as long the batching thread is alive… which is where we currently are.
 
                         if !chunk.components.is_empty() {
                             // make sure the chunk didn't contain *only* indicators!
-                            tx_chunk.send(chunk).ok();
+                            tx_chunk.send((store_id, chunk)).ok();
                         } else {
                             re_log::warn_once!(
                                 "Dropping chunk without components. Entity path: {}",
@@ -685,9 +719,9 @@ fn batching_thread(
                             );
                         }
                     },
-                    Command::AppendRow(entity_path, row) => {
-                        let acc = accs.entry(entity_path.clone())
-                            .or_insert_with(|| Accumulator::new(entity_path));
+                    Command::AppendRow(store_id, entity_path, row) => {
+                        let acc = accs.entry((store_id.clone(), entity_path.clone()))
+                            .or_insert_with(|| Accumulator::new(store_id, entity_path));
                         do_push_row(acc, row);
 
                         if let Some(config) = hooks.on_insert.as_ref() {
@@ -1161,7 +1195,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1270,7 +1304,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1358,7 +1392,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1434,7 +1468,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1502,6 +1536,59 @@ mod tests {
         Ok(())
     }
 
+    /// Rows of different stores will end up in different batches, tagged with their store.
+    #[test]
+    fn different_stores() -> anyhow::Result<()> {
+        let batcher = ChunkBatcher::new(ChunkBatcherConfig::NEVER, BatcherHooks::NONE)?;
+
+        let timeline1 = Timeline::new_duration("log_time");
+
+        let points1 = MyPoint::to_arrow([MyPoint::new(1.0, 2.0), MyPoint::new(3.0, 4.0)])?;
+        let points2 = MyPoint::to_arrow([MyPoint::new(10.0, 20.0), MyPoint::new(30.0, 40.0)])?;
+        let points3 = MyPoint::to_arrow([MyPoint::new(100.0, 200.0), MyPoint::new(300.0, 400.0)])?;
+
+        let row = |time: i64, points: &ArrayRef| {
+            PendingRow::new(
+                TimePoint::default().with(timeline1, time),
+                std::iter::once((MyPoints::descriptor_points(), points.clone())).collect(),
+            )
+        };
+
+        let store_id = StoreId::random(re_log_types::StoreKind::Recording, "test_app");
+        let entity_path1: EntityPath = "ent1".into();
+        batcher.push_row(entity_path1.clone(), row(42, &points1));
+        batcher.push_row_for_store(
+            Some(store_id.clone()),
+            entity_path1.clone(),
+            row(43, &points2),
+        );
+        batcher.push_row(entity_path1.clone(), row(44, &points3));
+
+        let chunks_rx = batcher.chunks();
+        drop(batcher); // flush and close
+
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = match chunks_rx.try_recv() {
+                Ok(chunk) => chunk,
+                Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
+                Err(TryRecvError::Disconnected) => break,
+            };
+            chunks.push(chunk);
+        }
+
+        chunks.sort_by_key(|(_, chunk)| chunk.row_id_range().unwrap().0);
+
+        assert_eq!(2, chunks.len());
+        assert_eq!(None, chunks[0].0);
+        assert_eq!(2, chunks[0].1.num_rows());
+        assert_eq!(Some(store_id), chunks[1].0);
+        assert_eq!(1, chunks[1].1.num_rows());
+        assert_eq!(&entity_path1, chunks[1].1.entity_path());
+
+        Ok(())
+    }
+
     /// A bunch of rows with different sets of timelines will end up in different batches.
     #[test]
     fn different_timelines() -> anyhow::Result<()> {
@@ -1541,7 +1628,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1650,7 +1737,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1774,7 +1861,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -1881,7 +1968,7 @@ mod tests {
         let mut chunks = Vec::new();
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
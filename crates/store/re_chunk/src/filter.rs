@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+use arrow::array::BooleanArray as ArrowBooleanArray;
+use nohash_hasher::IntSet;
+
+use re_log_types::{
+    AbsoluteTimeRange, EntityPath, LogMsg, ResolvedEntityPathFilter, StoreKind, TimelineName,
+};
+
+use crate::{Chunk, ChunkResult};
+
+// ---
+
+/// Selects a subset of the data of a recording, e.g. to share a small repro out of a large recording.
+///
+/// All of the rules are combined: a row is only kept if it passes all of them.
+///
+/// Blueprints and the recording properties are always kept as-is, and static data is never affected by
+/// [`Self::with_time_range`].
+#[derive(Debug, Clone, Default)]
+pub struct ChunkFilter {
+    entity_filter: Option<ResolvedEntityPathFilter>,
+    dropped_entity_paths: HashSet<EntityPath>,
+    dropped_timelines: IntSet<TimelineName>,
+    time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+}
+
+impl ChunkFilter {
+    /// Only keeps the entities that match the filter, e.g. `/camera/**`.
+    #[inline]
+    pub fn with_entity_filter(mut self, entity_filter: ResolvedEntityPathFilter) -> Self {
+        self.entity_filter = Some(entity_filter);
+        self
+    }
+
+    /// Drops this exact entity, but not its children.
+    #[inline]
+    pub fn with_dropped_entity(mut self, entity_path: EntityPath) -> Self {
+        self.dropped_entity_paths.insert(entity_path);
+        self
+    }
+
+    /// Drops the time column of this timeline.
+    ///
+    /// Temporal data that has no timeline left afterwards is dropped as a whole,
+    /// rather than becoming static.
+    #[inline]
+    pub fn with_dropped_timeline(mut self, timeline: TimelineName) -> Self {
+        self.dropped_timelines.insert(timeline);
+        self
+    }
+
+    /// Only keeps the temporal rows whose time on `timeline` is within `time_range`, inclusive.
+    ///
+    /// Temporal data that isn't on `timeline` at all is dropped.
+    #[inline]
+    pub fn with_time_range(
+        mut self,
+        timeline: TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) -> Self {
+        self.time_range = Some((timeline, time_range));
+        self
+    }
+
+    /// Whether any of the data of this entity is kept.
+    pub fn keeps_entity(&self, entity_path: &EntityPath) -> bool {
+        if entity_path.starts_with(&EntityPath::properties()) {
+            return true;
+        }
+
+        !self.dropped_entity_paths.contains(entity_path)
+            && self
+                .entity_filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(entity_path))
+    }
+
+    /// Returns what is left of the chunk, if anything.
+    ///
+    /// WARNING: the returned chunk has the same old [`crate::ChunkId`]! Change it with [`Chunk::with_id`].
+    pub fn apply(&self, chunk: &Chunk) -> Option<Chunk> {
+        re_tracing::profile_function!();
+
+        let entity_path = chunk.entity_path();
+        if !self.keeps_entity(entity_path) {
+            return None;
+        }
+        if chunk.is_static() || entity_path.starts_with(&EntityPath::properties()) {
+            return Some(chunk.clone());
+        }
+
+        let mut chunk = chunk.clone();
+
+        if let Some((timeline, time_range)) = &self.time_range {
+            let time_column = chunk.timelines().get(timeline)?;
+            if !time_range.contains_range(time_column.time_range()) {
+                let filter: ArrowBooleanArray = time_column
+                    .times()
+                    .map(|time| Some(time_range.contains(time)))
+                    .collect();
+                chunk = chunk.filtered(&filter)?;
+            }
+        }
+
+        if !self.dropped_timelines.is_empty() {
+            let timelines_to_keep: IntSet<TimelineName> = chunk
+                .timelines()
+                .keys()
+                .filter(|timeline| !self.dropped_timelines.contains(*timeline))
+                .copied()
+                .collect();
+            if timelines_to_keep.is_empty() {
+                return None;
+            }
+            if timelines_to_keep.len() < chunk.num_timelines() {
+                chunk = chunk.timelines_sliced(&timelines_to_keep);
+            }
+        }
+
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    /// Applies the filter to the chunk in a message of a log stream, see [`Self::apply`].
+    ///
+    /// All other messages, and the data of blueprints, are returned as-is.
+    pub fn apply_to_log_msg(&self, msg: LogMsg) -> ChunkResult<Option<LogMsg>> {
+        match msg {
+            LogMsg::ArrowMsg(store_id, arrow_msg) if store_id.kind() == StoreKind::Recording => {
+                let chunk = Chunk::from_arrow_msg(&arrow_msg)?;
+                self.apply(&chunk)
+                    .map(|chunk| Ok(LogMsg::ArrowMsg(store_id, chunk.to_arrow_msg()?)))
+                    .transpose()
+            }
+
+            msg => Ok(Some(msg)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{
+        EntityPathFilter, EntityPathSubs, TimeInt, Timeline,
+        example_components::{MyPoint, MyPoints},
+    };
+    use re_types_core::RowId;
+
+    use super::*;
+
+    fn points_chunk(entity_path: &str, frames: impl IntoIterator<Item = i64>) -> Chunk {
+        let mut builder = Chunk::builder(entity_path);
+        for frame in frames {
+            builder = builder.with_component_batches(
+                RowId::new(),
+                [
+                    (Timeline::new_sequence("frame"), frame),
+                    (Timeline::new_sequence("tick"), frame * 10),
+                ],
+                [(
+                    MyPoints::descriptor_points(),
+                    &[MyPoint::new(frame as f32, frame as f32)] as _,
+                )],
+            );
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn filter_entities_and_time_range() {
+        let filter = ChunkFilter::default()
+            .with_entity_filter(
+                EntityPathFilter::parse_forgiving("/camera/**")
+                    .resolve_forgiving(&EntityPathSubs::empty()),
+            )
+            .with_time_range(
+                "frame".into(),
+                AbsoluteTimeRange::new(TimeInt::new_temporal(2), TimeInt::new_temporal(4)),
+            )
+            .with_dropped_timeline("tick".into());
+
+        assert!(filter.apply(&points_chunk("/lidar", 0..10)).is_none());
+        assert!(filter.apply(&points_chunk("/camera", 5..10)).is_none());
+
+        let chunk = filter.apply(&points_chunk("/camera/rgb", 0..10)).unwrap();
+        assert_eq!(chunk.num_rows(), 3);
+        assert_eq!(chunk.num_timelines(), 1);
+        assert_eq!(
+            chunk.timelines()[&TimelineName::from("frame")].time_range(),
+            AbsoluteTimeRange::new(TimeInt::new_temporal(2), TimeInt::new_temporal(4))
+        );
+
+        // Dropping the only timeline of temporal data doesn't make it static.
+        let filter = ChunkFilter::default()
+            .with_dropped_timeline("frame".into())
+            .with_dropped_timeline("tick".into());
+        assert!(filter.apply(&points_chunk("/camera", 0..10)).is_none());
+    }
+}
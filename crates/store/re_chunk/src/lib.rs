@@ -6,6 +6,7 @@
 
 mod builder;
 mod chunk;
+mod filter;
 mod helpers;
 mod iter;
 mod latest_at;
@@ -13,6 +14,7 @@ mod merge;
 mod range;
 mod shuffle;
 mod slice;
+mod timeline_rewrite;
 mod transport;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -22,12 +24,14 @@ pub use self::builder::{ChunkBuilder, TimeColumnBuilder};
 pub use self::chunk::{
     Chunk, ChunkComponents, ChunkError, ChunkResult, TimeColumn, TimeColumnError,
 };
+pub use self::filter::ChunkFilter;
 pub use self::helpers::{ChunkShared, UnitChunkShared};
 pub use self::iter::{
     ChunkComponentIter, ChunkComponentIterItem, ChunkComponentSlicer, ChunkIndicesIter,
 };
 pub use self::latest_at::LatestAtQuery;
 pub use self::range::{RangeQuery, RangeQueryOptions};
+pub use self::timeline_rewrite::TimelineRewrite;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use self::batcher::{
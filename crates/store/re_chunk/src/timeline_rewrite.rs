@@ -0,0 +1,238 @@
+use re_log_types::{
+    AbsoluteTimeRange, LogMsg, StoreKind, TimeInt, TimeType, Timeline, TimelineName,
+};
+
+use crate::{Chunk, ChunkError, ChunkResult, TimeColumn};
+
+// ---
+
+/// Rewrites the times of a timeline, e.g. to correct a clock that was off, or to convert frame numbers to
+/// a duration at a known rate.
+///
+/// Every time `t` becomes `round(t * scale) + offset`, in the unit of the timeline: sequence numbers, or
+/// nanoseconds for the others. Times that don't fit are clamped.
+///
+/// Other timelines, static data, and blueprints are left untouched.
+#[derive(Debug, Clone)]
+pub struct TimelineRewrite {
+    timeline: TimelineName,
+    offset: i64,
+    scale: f64,
+    new_name: Option<TimelineName>,
+    new_type: Option<TimeType>,
+}
+
+impl TimelineRewrite {
+    /// A rewrite of `timeline` that doesn't change anything yet.
+    #[inline]
+    pub fn new(timeline: TimelineName) -> Self {
+        Self {
+            timeline,
+            offset: 0,
+            scale: 1.0,
+            new_name: None,
+            new_type: None,
+        }
+    }
+
+    /// Added to every time, after scaling.
+    #[inline]
+    pub fn with_offset(mut self, offset: i64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Every time is multiplied by this, before the offset is added.
+    ///
+    /// Timestamps beyond 2^53 nanoseconds (i.e. any recent date) lose some precision when scaled.
+    #[inline]
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Renames the timeline.
+    #[inline]
+    pub fn with_name(mut self, name: TimelineName) -> Self {
+        self.new_name = Some(name);
+        self
+    }
+
+    /// Changes the type of the timeline, e.g. from [`TimeType::Sequence`] to [`TimeType::DurationNs`].
+    #[inline]
+    pub fn with_type(mut self, typ: TimeType) -> Self {
+        self.new_type = Some(typ);
+        self
+    }
+
+    /// The name of the timeline once rewritten.
+    #[inline]
+    pub fn new_name(&self) -> TimelineName {
+        self.new_name.unwrap_or(self.timeline)
+    }
+
+    /// The rewritten value of a single time.
+    pub fn rewrite_time(&self, time: i64) -> i64 {
+        let scaled = if self.scale == 1.0 {
+            time // No need to go through a float, and lose precision.
+        } else {
+            (time as f64 * self.scale).round() as i64 // saturating
+        };
+
+        scaled
+            .saturating_add(self.offset)
+            .clamp(TimeInt::MIN.as_i64(), TimeInt::MAX.as_i64())
+    }
+
+    /// Returns the chunk with the rewritten timeline, or as-is if it isn't on that timeline.
+    ///
+    /// Fails if the chunk already has a timeline with the new name.
+    ///
+    /// WARNING: the returned chunk has the same old [`crate::ChunkId`]! Change it with [`Chunk::with_id`].
+    pub fn apply(&self, chunk: &Chunk) -> ChunkResult<Chunk> {
+        re_tracing::profile_function!();
+
+        let Some(time_column) = chunk.timelines().get(&self.timeline) else {
+            return Ok(chunk.clone());
+        };
+
+        let new_name = self.new_name();
+        if new_name != self.timeline && chunk.timelines().contains_key(&new_name) {
+            return Err(ChunkError::Malformed {
+                reason: format!(
+                    "can't rename timeline {:?} to {new_name:?}: chunk {} already has one",
+                    self.timeline,
+                    chunk.id()
+                ),
+            });
+        }
+
+        let timeline = Timeline::new(
+            new_name,
+            self.new_type
+                .unwrap_or_else(|| time_column.timeline().typ()),
+        );
+        let times = time_column
+            .times_raw()
+            .iter()
+            .map(|&time| self.rewrite_time(time))
+            .collect();
+        // A negative scale reverses the order.
+        let is_sorted = (self.scale >= 0.0 && time_column.is_sorted()).then_some(true);
+
+        let mut chunk = chunk.clone();
+        chunk.timelines.remove(&self.timeline);
+        chunk.add_timeline(TimeColumn::new(is_sorted, timeline, times))?;
+
+        Ok(chunk)
+    }
+
+    /// Applies the rewrite to the chunk in a message of a log stream, see [`Self::apply`].
+    ///
+    /// Time range deletions on the timeline are rewritten the same way.
+    /// All other messages, and the data of blueprints, are returned as-is.
+    pub fn apply_to_log_msg(&self, msg: LogMsg) -> ChunkResult<LogMsg> {
+        match msg {
+            LogMsg::ArrowMsg(store_id, arrow_msg) if store_id.kind() == StoreKind::Recording => {
+                let chunk = Chunk::from_arrow_msg(&arrow_msg)?;
+                if !chunk.timelines().contains_key(&self.timeline) {
+                    return Ok(LogMsg::ArrowMsg(store_id, arrow_msg));
+                }
+
+                let chunk = self.apply(&chunk)?;
+                Ok(LogMsg::ArrowMsg(store_id, chunk.to_arrow_msg()?))
+            }
+
+            LogMsg::DropTimeRange(mut cmd)
+                if cmd.store_id.kind() == StoreKind::Recording && cmd.timeline == self.timeline =>
+            {
+                let (min, max) = (
+                    self.rewrite_time(cmd.range.min().as_i64()),
+                    self.rewrite_time(cmd.range.max().as_i64()),
+                );
+                cmd.timeline = self.new_name();
+                cmd.range = AbsoluteTimeRange::new(min.min(max), min.max(max));
+                Ok(LogMsg::DropTimeRange(cmd))
+            }
+
+            msg => Ok(msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::example_components::{MyPoint, MyPoints};
+    use re_types_core::RowId;
+
+    use super::*;
+
+    fn points_chunk(frames: impl IntoIterator<Item = i64>) -> Chunk {
+        let mut builder = Chunk::builder("points");
+        for frame in frames {
+            builder = builder.with_component_batches(
+                RowId::new(),
+                [
+                    (Timeline::new_sequence("frame"), frame),
+                    (Timeline::new_sequence("tick"), frame * 10),
+                ],
+                [(
+                    MyPoints::descriptor_points(),
+                    &[MyPoint::new(frame as f32, frame as f32)] as _,
+                )],
+            );
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn rewrite_frames_to_duration() {
+        // 30 frames per second, starting 2 seconds in.
+        let rewrite = TimelineRewrite::new("frame".into())
+            .with_scale(1e9 / 30.0)
+            .with_offset(2_000_000_000)
+            .with_name("time".into())
+            .with_type(TimeType::DurationNs);
+
+        let chunk = rewrite.apply(&points_chunk(0..=30)).unwrap();
+        assert_eq!(chunk.num_rows(), 31);
+        assert!(!chunk.timelines().contains_key(&TimelineName::from("frame")));
+
+        let time_column = &chunk.timelines()[&TimelineName::from("time")];
+        assert_eq!(time_column.timeline().typ(), TimeType::DurationNs);
+        assert_eq!(time_column.times_raw()[1], 2_033_333_333);
+        assert_eq!(
+            time_column.time_range(),
+            AbsoluteTimeRange::new(
+                TimeInt::new_temporal(2_000_000_000),
+                TimeInt::new_temporal(3_000_000_000)
+            )
+        );
+
+        // The other timelines are left alone.
+        assert_eq!(
+            chunk.timelines()[&TimelineName::from("tick")].times_raw()[1],
+            10
+        );
+
+        // Renaming onto an existing timeline is refused.
+        let rewrite = TimelineRewrite::new("frame".into()).with_name("tick".into());
+        assert!(rewrite.apply(&points_chunk(0..10)).is_err());
+    }
+
+    #[test]
+    fn rewrite_time_clamps() {
+        let rewrite = TimelineRewrite::new("frame".into()).with_offset(i64::MAX);
+        assert_eq!(rewrite.rewrite_time(1), TimeInt::MAX.as_i64());
+
+        let rewrite = TimelineRewrite::new("frame".into()).with_scale(-1e30);
+        assert_eq!(rewrite.rewrite_time(1), TimeInt::MIN.as_i64());
+
+        // Large timestamps are exact as long as they aren't scaled.
+        let rewrite = TimelineRewrite::new("log_time".into()).with_offset(-37_000_000_000);
+        assert_eq!(
+            rewrite.rewrite_time(1_700_000_000_123_456_789),
+            1_699_999_963_123_456_789
+        );
+    }
+}
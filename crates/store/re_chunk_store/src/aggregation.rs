@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU64;
+
+use arrow::array::{Array as _, AsArray as _};
+use arrow::datatypes::{DataType as ArrowDataType, Float64Type};
+
+use re_chunk::{Chunk, EntityPath, RangeQuery, TimeInt};
+use re_types_core::ComponentDescriptor;
+
+use crate::ChunkStore;
+
+// ---
+
+/// The min, max, sum and count of a set of scalars.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScalarStats {
+    pub count: u64,
+    pub min: f64,
+    pub max: f64,
+    pub sum: f64,
+}
+
+impl Default for ScalarStats {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+        }
+    }
+}
+
+impl ScalarStats {
+    /// `None` if there were no values.
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+
+    #[inline]
+    pub fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+    }
+
+    #[inline]
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.sum += other.sum;
+    }
+}
+
+/// The statistics of all the values of a component within one time bucket, see [`ChunkStore::range_aggregate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregationBucket {
+    /// The bucket covers `[start, start + bucket_size)`.
+    pub start: TimeInt,
+
+    /// One per instance of the component, e.g. one per series of a `Scalars` archetype.
+    pub stats: Vec<ScalarStats>,
+}
+
+impl ChunkStore {
+    /// Aggregates the values of a numeric component over time, without materializing every sample.
+    ///
+    /// The range of the `query` is cut into buckets of `bucket_size` (in the unit of the timeline), aligned on
+    /// multiples of it, and the min, max, mean and count of the values in each bucket are returned, in order.
+    /// Buckets without any values are left out.
+    ///
+    /// The component can be of any numeric Arrow type. Nulls and `NaN`s are ignored.
+    /// Returns nothing for static data, or components that aren't numeric.
+    pub fn range_aggregate(
+        &self,
+        query: &RangeQuery,
+        entity_path: &EntityPath,
+        component_descr: &ComponentDescriptor,
+        bucket_size: NonZeroU64,
+    ) -> Vec<AggregationBucket> {
+        re_tracing::profile_function!(format!("{query:?}"));
+
+        let mut buckets: BTreeMap<i64, Vec<ScalarStats>> = BTreeMap::new();
+
+        for chunk in self.range_relevant_chunks(query, entity_path, component_descr) {
+            if chunk.is_static() {
+                continue;
+            }
+
+            // Chunks are aggregated on their own, and only then merged together.
+            for (start, stats) in aggregate_chunk(&chunk, query, component_descr, bucket_size) {
+                let merged = buckets.entry(start).or_default();
+                if merged.len() < stats.len() {
+                    merged.resize(stats.len(), ScalarStats::default());
+                }
+                for (merged, stats) in merged.iter_mut().zip(&stats) {
+                    merged.merge(stats);
+                }
+            }
+        }
+
+        buckets
+            .into_iter()
+            .map(|(start, stats)| AggregationBucket {
+                start: TimeInt::new_temporal(start),
+                stats,
+            })
+            .collect()
+    }
+}
+
+fn aggregate_chunk(
+    chunk: &Chunk,
+    query: &RangeQuery,
+    component_descr: &ComponentDescriptor,
+    bucket_size: NonZeroU64,
+) -> BTreeMap<i64, Vec<ScalarStats>> {
+    re_tracing::profile_function!();
+
+    let mut buckets: BTreeMap<i64, Vec<ScalarStats>> = BTreeMap::new();
+
+    let chunk = chunk.range(query, component_descr);
+    let (Some(time_column), Some(list_array)) = (
+        chunk.timelines().get(query.timeline()),
+        chunk.components().get(component_descr),
+    ) else {
+        return buckets;
+    };
+
+    if !list_array.values().data_type().is_numeric() {
+        re_log::debug_once!("Can't aggregate non-numeric component {component_descr}");
+        return buckets;
+    }
+    let Ok(values) = arrow::compute::cast(list_array.values(), &ArrowDataType::Float64) else {
+        return buckets;
+    };
+    let values = values.as_primitive::<Float64Type>();
+
+    let bucket_size = bucket_size.get().min(i64::MAX as u64) as i64;
+    let range = query.range();
+    let offsets = list_array.value_offsets();
+
+    for (row_index, time) in time_column.times().enumerate() {
+        if !range.contains(time) || list_array.is_null(row_index) {
+            continue;
+        }
+
+        let start = time.as_i64().div_euclid(bucket_size) * bucket_size;
+        let stats = buckets.entry(start).or_default();
+
+        let (from, to) = (offsets[row_index] as usize, offsets[row_index + 1] as usize);
+        if stats.len() < to - from {
+            stats.resize(to - from, ScalarStats::default());
+        }
+        for (instance, value_index) in (from..to).enumerate() {
+            let value = values.value(value_index);
+            if values.is_valid(value_index) && !value.is_nan() {
+                stats[instance].add(value);
+            }
+        }
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{RowId, Timeline};
+    use re_log_types::AbsoluteTimeRange;
+    use re_types::{archetypes::Scalars, components::Scalar};
+
+    use super::*;
+
+    #[test]
+    fn aggregate_scalars() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        let timeline = Timeline::new_sequence("frame");
+        let entity_path = EntityPath::from("plot");
+
+        // Two series, spread over two chunks.
+        for frames in [0..15_i64, 15..30] {
+            let mut builder = Chunk::builder(entity_path.clone());
+            for frame in frames {
+                let scalars = [Scalar::from(frame as f64), Scalar::from(-(frame as f64))];
+                builder = builder.with_component_batches(
+                    RowId::new(),
+                    [(timeline, frame)],
+                    [(Scalars::descriptor_scalars(), &scalars as _)],
+                );
+            }
+            store.insert_chunk(&Arc::new(builder.build()?))?;
+        }
+
+        let query = RangeQuery::new(
+            *timeline.name(),
+            AbsoluteTimeRange::new(TimeInt::new_temporal(5), TimeInt::new_temporal(24)),
+        );
+        let buckets = store.range_aggregate(
+            &query,
+            &entity_path,
+            &Scalars::descriptor_scalars(),
+            NonZeroU64::new(10).unwrap(),
+        );
+
+        assert_eq!(
+            buckets
+                .iter()
+                .map(|bucket| bucket.start.as_i64())
+                .collect::<Vec<_>>(),
+            vec![0, 10, 20]
+        );
+
+        let middle = &buckets[1].stats;
+        assert_eq!(middle.len(), 2);
+        assert_eq!(middle[0].count, 10);
+        assert_eq!((middle[0].min, middle[0].max), (10.0, 19.0));
+        assert_eq!(middle[0].mean(), Some(14.5));
+        assert_eq!((middle[1].min, middle[1].max), (-19.0, -10.0));
+
+        // Only what's within the range of the query.
+        assert_eq!(buckets[0].stats[0].count, 5);
+        assert_eq!(buckets[2].stats[0].count, 5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use web_time::Instant;
+
+use re_chunk::RowId;
+
+use crate::{ChunkStore, ChunkStoreEvent, ChunkStoreResult};
+
+// ---
+
+/// Where an incremental [`ChunkStore::compact`] left off.
+///
+/// Compaction runs in passes over all temporal chunks, oldest first, until a pass no longer merges anything.
+#[derive(Debug, Clone, Default)]
+pub struct CompactionCursor {
+    /// The smallest [`RowId`] of the next chunk to look at in the current pass.
+    next_min_row_id: Option<RowId>,
+
+    /// How many chunks were merged into a neighbor in the current pass.
+    num_merges_in_pass: u64,
+
+    /// How many chunks were merged into a neighbor so far.
+    num_merges: u64,
+
+    /// How many passes were completed so far.
+    num_passes: u32,
+
+    is_done: bool,
+}
+
+impl CompactionCursor {
+    /// Whether the compaction converged, i.e. its last pass didn't merge any chunks.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.is_done
+    }
+
+    /// How many chunks were merged into a neighbor so far.
+    #[inline]
+    pub fn num_merges(&self) -> u64 {
+        self.num_merges
+    }
+
+    /// How many passes over the store were completed so far.
+    #[inline]
+    pub fn num_passes(&self) -> u32 {
+        self.num_passes
+    }
+}
+
+impl ChunkStore {
+    /// Merges the temporal chunks of the store that are still below the compaction thresholds
+    /// of its [`crate::ChunkStoreConfig`] with their neighbors.
+    ///
+    /// The store already compacts chunks as they are inserted, but only with the chunks that were there before them.
+    /// This helps recordings that were logged with many tiny log calls, or whose thresholds were raised
+    /// with [`Self::set_config`] after the fact.
+    ///
+    /// Runs for at most `time_budget`, and picks up where the `cursor` left off.
+    /// Call it until [`CompactionCursor::is_done`] to compact the whole store.
+    ///
+    /// Each merged chunk is reported as removed and then added back,
+    /// with the [`crate::ChunkCompactionReport`] of the neighbor it was merged with.
+    pub fn compact(
+        &mut self,
+        cursor: &mut CompactionCursor,
+        time_budget: Duration,
+    ) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
+        re_tracing::profile_function!();
+
+        let start_time = Instant::now();
+        let mut events = Vec::new();
+
+        while !cursor.is_done && start_time.elapsed() < time_budget {
+            let next = self
+                .chunk_ids_per_min_row_id
+                .range(cursor.next_min_row_id.unwrap_or(RowId::ZERO)..)
+                .next()
+                .map(|(min_row_id, chunk_id)| (*min_row_id, *chunk_id));
+
+            let Some((min_row_id, chunk_id)) = next else {
+                // End of a pass: every pass improves on the previous one, until nothing can be merged anymore.
+                cursor.is_done = cursor.num_merges_in_pass == 0;
+                cursor.num_passes += 1;
+                cursor.num_merges_in_pass = 0;
+                cursor.next_min_row_id = None;
+                continue;
+            };
+            cursor.next_min_row_id = Some(min_row_id.next());
+
+            let Some(chunk) = self.chunks_per_chunk_id.get(&chunk_id).cloned() else {
+                continue;
+            };
+            if chunk.is_static() || self.find_and_elect_compaction_candidate(&chunk).is_none() {
+                continue;
+            }
+
+            // Re-inserting the chunk merges it with the elected neighbor, just like a new chunk would be.
+            let diffs = self.remove_chunk(chunk_id);
+            if self.config.enable_changelog {
+                let removals: Vec<_> = diffs
+                    .into_iter()
+                    .map(|diff| ChunkStoreEvent {
+                        store_id: self.id.clone(),
+                        store_generation: self.generation(),
+                        event_id: self
+                            .event_id
+                            .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                        diff,
+                    })
+                    .collect();
+                Self::on_events(&removals);
+                events.extend(removals);
+            }
+            events.extend(self.insert_chunk(&chunk)?);
+
+            cursor.num_merges_in_pass += 1;
+            cursor.num_merges += 1;
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, Timeline};
+    use re_log_types::{
+        EntityPath,
+        example_components::{MyPoint, MyPoints},
+    };
+
+    use crate::{ChunkStoreConfig, ChunkStoreDiffKind};
+
+    use super::*;
+
+    #[test]
+    fn compact_tiny_chunks() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let entity_path = EntityPath::from("this/that");
+        for frame in 0..10_i64 {
+            let chunk = Chunk::builder(entity_path.clone())
+                .with_component_batches(
+                    RowId::new(),
+                    [(Timeline::new_sequence("frame"), frame)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(frame as f32, frame as f32)] as _,
+                    )],
+                )
+                .build()?;
+            store.insert_chunk(&Arc::new(chunk))?;
+        }
+        assert_eq!(10, store.num_chunks());
+
+        // Nothing to do with compaction disabled.
+        let mut cursor = CompactionCursor::default();
+        assert!(store.compact(&mut cursor, Duration::MAX)?.is_empty());
+        assert!(cursor.is_done());
+
+        store.set_config(ChunkStoreConfig {
+            chunk_max_rows: 4,
+            ..ChunkStoreConfig::DEFAULT
+        });
+
+        // No time budget: no progress.
+        let mut cursor = CompactionCursor::default();
+        assert!(store.compact(&mut cursor, Duration::ZERO)?.is_empty());
+        assert!(!cursor.is_done());
+
+        let events = store.compact(&mut cursor, Duration::MAX)?;
+        assert!(cursor.is_done());
+        assert!(cursor.num_merges() > 0);
+
+        let num_rows = store
+            .iter_chunks()
+            .map(|chunk| chunk.num_rows())
+            .sum::<usize>();
+        assert_eq!(10, num_rows);
+        assert!(store.num_chunks() < 10);
+        assert!(store.iter_chunks().all(|chunk| chunk.num_rows() <= 4));
+
+        let num_additions = events
+            .iter()
+            .filter(|event| event.kind == ChunkStoreDiffKind::Addition)
+            .count();
+        let num_deletions = events
+            .iter()
+            .filter(|event| event.kind == ChunkStoreDiffKind::Deletion)
+            .count();
+        assert_eq!(cursor.num_merges() as usize, num_additions);
+        assert_eq!(num_additions, num_deletions);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use std::sync::{
+    Arc, LazyLock,
+    atomic::{AtomicU64, Ordering},
+    mpsc,
+};
+
+use arrow::array::{Array as _, ArrayRef as ArrowArrayRef};
+use nohash_hasher::{IntMap, IntSet};
+
+use re_chunk::{EntityPath, RowId, TimePoint};
+use re_log_types::StoreId;
+use re_types_core::ComponentDescriptor;
+
+use crate::{
+    ChunkStore, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreSubscriber,
+    ChunkStoreSubscriberHandle,
+};
+
+// ---
+
+/// A new value of a component that a [`ComponentSubscription`] is interested in.
+#[derive(Debug, Clone)]
+pub struct ComponentChange {
+    /// Which [`ChunkStore`] the value was added to.
+    pub store_id: StoreId,
+
+    pub entity_path: EntityPath,
+
+    pub component: ComponentDescriptor,
+
+    pub row_id: RowId,
+
+    /// Empty for static data.
+    pub timepoint: TimePoint,
+
+    /// The component batch of the row, i.e. all of its instances.
+    ///
+    /// Empty if the component was cleared.
+    pub value: ArrowArrayRef,
+}
+
+/// Receives the new values of a set of components, as they are added to any [`ChunkStore`].
+///
+/// Created with [`ChunkStore::subscribe_to_components`].
+/// Values are queued until they are received, and are no longer sent once this is dropped.
+/// If the queue is full, new values are dropped, see [`Self::num_dropped`].
+pub struct ComponentSubscription {
+    id: u64,
+    rx: mpsc::Receiver<ComponentChange>,
+    num_dropped: Arc<AtomicU64>,
+}
+
+impl Drop for ComponentSubscription {
+    fn drop(&mut self) {
+        ChunkStore::with_subscriber_mut(
+            *COMPONENT_CHANGE_SUBSCRIBER,
+            |subscriber: &mut ComponentChangeSubscriber| {
+                subscriber.subscriptions.remove(&self.id);
+            },
+        );
+    }
+}
+
+impl ComponentSubscription {
+    /// Returns the next new value, if there is one already.
+    #[inline]
+    pub fn try_recv(&self) -> Option<ComponentChange> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Blocks until the next new value, or until `timeout` has passed.
+    #[inline]
+    pub fn recv_timeout(&self, timeout: std::time::Duration) -> Option<ComponentChange> {
+        self.rx.recv_timeout(timeout).ok()
+    }
+
+    /// All the new values received so far, without blocking.
+    #[inline]
+    pub fn try_iter(&self) -> impl Iterator<Item = ComponentChange> + '_ {
+        self.rx.try_iter()
+    }
+
+    /// Number of new values that were dropped so far, because they weren't received fast enough.
+    #[inline]
+    pub fn num_dropped(&self) -> u64 {
+        self.num_dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// The single [`ChunkStoreSubscriber`] behind all [`ComponentSubscription`]s.
+///
+/// Subscribers can't be unregistered, so we register this one once, and add and remove
+/// subscriptions to and from it instead.
+static COMPONENT_CHANGE_SUBSCRIBER: LazyLock<ChunkStoreSubscriberHandle> =
+    LazyLock::new(|| ChunkStore::register_subscriber(Box::<ComponentChangeSubscriber>::default()));
+
+#[derive(Default)]
+struct ComponentChangeSubscriber {
+    next_id: u64,
+
+    /// The subscriptions that are still alive, keyed by [`ComponentSubscription::id`].
+    subscriptions: IntMap<u64, SubscriptionSender>,
+}
+
+/// The sending end of a [`ComponentSubscription`].
+struct SubscriptionSender {
+    components_per_entity: IntMap<EntityPath, IntSet<ComponentDescriptor>>,
+    tx: mpsc::SyncSender<ComponentChange>,
+    num_dropped: Arc<AtomicU64>,
+}
+
+impl SubscriptionSender {
+    /// Sends the values of all subscribed components in the added chunk.
+    ///
+    /// Returns `false` if the [`ComponentSubscription`] is gone.
+    fn on_addition(&self, event: &ChunkStoreEvent) -> bool {
+        let chunk = &event.chunk;
+        let Some(components) = self.components_per_entity.get(chunk.entity_path()) else {
+            return true;
+        };
+
+        for component in components {
+            let Some(list_array) = chunk.components().get(component) else {
+                continue;
+            };
+
+            let rows = chunk.row_ids().zip(chunk.iter_timepoints()).enumerate();
+            for (row_index, (row_id, timepoint)) in rows {
+                if !list_array.is_valid(row_index) {
+                    continue;
+                }
+
+                let change = ComponentChange {
+                    store_id: event.store_id.clone(),
+                    entity_path: chunk.entity_path().clone(),
+                    component: component.clone(),
+                    row_id,
+                    timepoint,
+                    value: list_array.value(row_index),
+                };
+
+                // Never block here: we're holding up whoever is writing to the store.
+                match self.tx.try_send(change) {
+                    Ok(()) => {}
+                    Err(mpsc::TrySendError::Full(_)) => {
+                        self.num_dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(mpsc::TrySendError::Disconnected(_)) => return false,
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl ChunkStoreSubscriber for ComponentChangeSubscriber {
+    fn name(&self) -> String {
+        "rerun.store_subscribers.ComponentChangeSubscriber".into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn on_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        if self.subscriptions.is_empty() {
+            return;
+        }
+
+        for event in events {
+            if event.kind != ChunkStoreDiffKind::Addition {
+                continue;
+            }
+
+            self.subscriptions
+                .retain(|_, subscription| subscription.on_addition(event));
+        }
+    }
+}
+
+impl ChunkStore {
+    /// Subscribes to the new values of the given components of the given entities, across all [`ChunkStore`]s.
+    ///
+    /// Every row that is added to a store with a value for one of these components results in a
+    /// [`ComponentChange`], e.g. to trigger alerts on a live stream without having to poll the store.
+    /// Removals (i.e. garbage collection) are not reported.
+    ///
+    /// At most `capacity` values are queued, see [`ComponentSubscription::num_dropped`].
+    ///
+    /// Panics if `capacity` is zero, as no value could ever be received.
+    ///
+    /// The same caveats as for [`ChunkStore::register_subscriber`] apply: register before the data
+    /// you're interested in arrives.
+    pub fn subscribe_to_components(
+        components: impl IntoIterator<Item = (EntityPath, ComponentDescriptor)>,
+        capacity: usize,
+    ) -> ComponentSubscription {
+        assert!(
+            capacity > 0,
+            "a component subscription needs a capacity of at least 1"
+        );
+
+        let mut components_per_entity: IntMap<EntityPath, IntSet<ComponentDescriptor>> =
+            IntMap::default();
+        for (entity_path, component) in components {
+            components_per_entity
+                .entry(entity_path)
+                .or_default()
+                .insert(component);
+        }
+
+        let (tx, rx) = mpsc::sync_channel(capacity);
+        let num_dropped = Arc::new(AtomicU64::new(0));
+        let sender = SubscriptionSender {
+            components_per_entity,
+            tx,
+            num_dropped: Arc::clone(&num_dropped),
+        };
+
+        let id = Self::with_subscriber_mut(
+            *COMPONENT_CHANGE_SUBSCRIBER,
+            |subscriber: &mut ComponentChangeSubscriber| {
+                let id = subscriber.next_id;
+                subscriber.next_id += 1;
+                subscriber.subscriptions.insert(id, sender);
+                id
+            },
+        )
+        .expect("the component change subscriber is always registered");
+
+        ComponentSubscription {
+            id,
+            rx,
+            num_dropped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, Timeline};
+    use re_log_types::example_components::{MyColor, MyPoint, MyPoints};
+
+    use super::*;
+
+    #[test]
+    fn component_subscription() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        // NOTE: `cargo` implicitly runs tests in parallel, so use an entity that no other test logs to.
+        let entity_path = EntityPath::from("component_subscription/points");
+        let subscription = ChunkStore::subscribe_to_components(
+            [(entity_path.clone(), MyPoints::descriptor_colors())],
+            16,
+        );
+
+        let timeline = Timeline::new_sequence("frame");
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                RowId::new(),
+                [(timeline, 1_i64)],
+                [(
+                    MyPoints::descriptor_points(),
+                    &[MyPoint::new(1.0, 1.0)] as _,
+                )],
+            )
+            .with_component_batches(
+                RowId::new(),
+                [(timeline, 2_i64)],
+                [(
+                    MyPoints::descriptor_colors(),
+                    &[MyColor::from_rgb(255, 0, 0)] as _,
+                )],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+
+        let chunk = Chunk::builder("component_subscription/other")
+            .with_component_batches(
+                RowId::new(),
+                [(timeline, 3_i64)],
+                [(
+                    MyPoints::descriptor_colors(),
+                    &[MyColor::from_rgb(0, 255, 0)] as _,
+                )],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+
+        let changes: Vec<_> = subscription
+            .try_iter()
+            .filter(|change| change.store_id == store.id())
+            .collect();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].entity_path, entity_path);
+        assert_eq!(changes[0].component, MyPoints::descriptor_colors());
+        assert_eq!(changes[0].timepoint, TimePoint::from([(timeline, 2_i64)]));
+        assert_eq!(changes[0].value.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn component_subscription_is_bounded() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        // NOTE: `cargo` implicitly runs tests in parallel, so use an entity that no other test logs to.
+        let entity_path = EntityPath::from("component_subscription_is_bounded/points");
+        let subscription = ChunkStore::subscribe_to_components(
+            [(entity_path.clone(), MyPoints::descriptor_colors())],
+            1,
+        );
+
+        let timeline = Timeline::new_sequence("frame");
+        let mut builder = Chunk::builder(entity_path);
+        for frame in 0..3_i64 {
+            builder = builder.with_component_batches(
+                RowId::new(),
+                [(timeline, frame)],
+                [(
+                    MyPoints::descriptor_colors(),
+                    &[MyColor::from_rgb(255, 0, 0)] as _,
+                )],
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+
+        // Only the first value fits, the others are dropped instead of blocking the store.
+        assert_eq!(subscription.try_iter().count(), 1);
+        assert_eq!(subscription.num_dropped(), 2);
+
+        // Dropping the subscription removes it from the shared subscriber.
+        let id = subscription.id;
+        let is_subscribed = || {
+            ChunkStore::with_subscriber(
+                *COMPONENT_CHANGE_SUBSCRIBER,
+                |subscriber: &ComponentChangeSubscriber| subscriber.subscriptions.contains_key(&id),
+            )
+        };
+        assert_eq!(is_subscribed(), Some(true));
+        drop(subscription);
+        assert_eq!(is_subscribed(), Some(false));
+
+        Ok(())
+    }
+}
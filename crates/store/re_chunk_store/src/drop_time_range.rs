@@ -1,4 +1,4 @@
-use re_chunk::{ChunkId, TimelineName};
+use re_chunk::{ChunkId, EntityPath, TimelineName};
 use re_log_types::AbsoluteTimeRange;
 
 use crate::{ChunkStore, ChunkStoreEvent};
@@ -18,6 +18,43 @@ impl ChunkStore {
     ) -> Vec<ChunkStoreEvent> {
         re_tracing::profile_function!();
 
+        self.drop_time_range_where(timeline, drop_range, |_| true)
+    }
+
+    /// Drop all events of `entity_path` that are in the given range on the given timeline.
+    ///
+    /// If `recursive` is true, the events of all the descendants of `entity_path` are dropped too.
+    ///
+    /// Like [`Self::drop_time_range`], matching events are dropped from all timelines they appear on,
+    /// and static chunks are unaffected.
+    ///
+    /// Used to excise a segment of a recording, e.g. sensitive or corrupted data.
+    pub fn drop_entity_time_range(
+        &mut self,
+        entity_path: &EntityPath,
+        recursive: bool,
+        timeline: &TimelineName,
+        drop_range: AbsoluteTimeRange,
+    ) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!();
+
+        self.drop_time_range_where(timeline, drop_range, |chunk_entity_path| {
+            if recursive {
+                chunk_entity_path.starts_with(entity_path)
+            } else {
+                chunk_entity_path == entity_path
+            }
+        })
+    }
+
+    /// Drop all events that are in the given range on the given timeline, for the entities that
+    /// `drop_entity` accepts.
+    fn drop_time_range_where(
+        &mut self,
+        timeline: &TimelineName,
+        drop_range: AbsoluteTimeRange,
+        drop_entity: impl Fn(&EntityPath) -> bool,
+    ) -> Vec<ChunkStoreEvent> {
         if drop_range.max() < drop_range.min() {
             return Default::default();
         }
@@ -28,6 +65,10 @@ impl ChunkStore {
         let mut new_chunks = vec![];
 
         for (chunk_id, chunk) in &self.chunks_per_chunk_id {
+            if !drop_entity(chunk.entity_path()) {
+                continue; // keep it
+            }
+
             let Some(time_column) = chunk.timelines().get(timeline) else {
                 // static chunk, or chunk that doesn't overlap this timeline
                 continue; // keep it
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+
+use re_chunk::EntityPath;
+use re_log_types::{EntityPathPart, ResolvedEntityPathFilter};
+
+use crate::ChunkStore;
+
+// ---
+
+/// All the [`EntityPath`]s of a [`ChunkStore`], as a tree.
+///
+/// This makes resolving a subtree (e.g. the contents of a view with a narrow origin) proportional to
+/// the size of that subtree, rather than to the total number of entities in the store.
+#[derive(Debug, Clone)]
+pub(crate) struct EntityPathIndex {
+    root: EntityPathIndexNode,
+}
+
+impl Default for EntityPathIndex {
+    fn default() -> Self {
+        Self {
+            root: EntityPathIndexNode::new(EntityPath::root()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EntityPathIndexNode {
+    path: EntityPath,
+
+    /// Whether there is any data logged at this exact path, as opposed to only below it.
+    is_entity: bool,
+
+    children: BTreeMap<EntityPathPart, EntityPathIndexNode>,
+}
+
+impl EntityPathIndexNode {
+    fn new(path: EntityPath) -> Self {
+        Self {
+            path,
+            is_entity: false,
+            children: BTreeMap::default(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.is_entity && self.children.is_empty()
+    }
+}
+
+impl EntityPathIndex {
+    pub(crate) fn insert(&mut self, entity_path: &EntityPath) {
+        let mut node = &mut self.root;
+        for (depth, part) in entity_path.iter().enumerate() {
+            node = node.children.entry(part.clone()).or_insert_with(|| {
+                EntityPathIndexNode::new(EntityPath::new(entity_path.as_slice()[..=depth].to_vec()))
+            });
+        }
+        node.is_entity = true;
+    }
+
+    /// Removes the entity, and all the nodes that are left without any entity below them.
+    pub(crate) fn remove(&mut self, entity_path: &EntityPath) {
+        fn remove_in(node: &mut EntityPathIndexNode, parts: &[EntityPathPart]) {
+            let Some((part, rest)) = parts.split_first() else {
+                node.is_entity = false;
+                return;
+            };
+
+            if let Some(child) = node.children.get_mut(part) {
+                remove_in(child, rest);
+                if child.is_empty() {
+                    node.children.remove(part);
+                }
+            }
+        }
+
+        remove_in(&mut self.root, entity_path.as_slice());
+    }
+
+    /// All the entities at or below `root`, in [`EntityPath`] order.
+    pub(crate) fn subtree<'a>(
+        &'a self,
+        root: &EntityPath,
+    ) -> impl Iterator<Item = &'a EntityPath> + use<'a> {
+        let mut node = Some(&self.root);
+        for part in root.iter() {
+            node = node.and_then(|node| node.children.get(part));
+        }
+
+        self.walk(node, |_| true)
+            .filter(|node| node.is_entity)
+            .map(|node| &node.path)
+    }
+
+    /// All the entities matched by the `filter`, in [`EntityPath`] order.
+    ///
+    /// Subtrees that the filter cannot match anything in are skipped altogether.
+    pub(crate) fn matching<'a>(
+        &'a self,
+        filter: &'a ResolvedEntityPathFilter,
+    ) -> impl Iterator<Item = &'a EntityPath> + 'a {
+        self.walk(Some(&self.root), |node| {
+            filter.is_anything_in_subtree_included(&node.path)
+        })
+        .filter(|node| node.is_entity && filter.matches(&node.path))
+        .map(|node| &node.path)
+    }
+
+    /// Depth-first walk, starting at `start`, which only descends into the nodes that `descend` accepts.
+    ///
+    /// Parents come before their children, and siblings are sorted, i.e. nodes come in [`EntityPath`] order.
+    fn walk<'a>(
+        &'a self,
+        start: Option<&'a EntityPathIndexNode>,
+        mut descend: impl FnMut(&EntityPathIndexNode) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a EntityPathIndexNode> + 'a {
+        let mut stack: Vec<&EntityPathIndexNode> = start.into_iter().collect();
+        std::iter::from_fn(move || {
+            loop {
+                let node = stack.pop()?;
+                if descend(node) {
+                    stack.extend(node.children.values().rev());
+                    return Some(node);
+                }
+            }
+        })
+    }
+}
+
+impl ChunkStore {
+    /// All the [`EntityPath`]s at or below `root`, in order.
+    ///
+    /// `root` itself doesn't need to have any data.
+    /// Only the given subtree is visited, which is much cheaper than filtering [`Self::all_entities_sorted`]
+    /// when the store has many entities.
+    #[inline]
+    pub fn entities_in_subtree<'a>(
+        &'a self,
+        root: &EntityPath,
+    ) -> impl Iterator<Item = &'a EntityPath> + use<'a> {
+        self.entity_path_index.subtree(root)
+    }
+
+    /// All the [`EntityPath`]s that the `filter` matches, in order.
+    ///
+    /// Subtrees that the filter cannot match anything in are never visited, see
+    /// [`ResolvedEntityPathFilter::is_anything_in_subtree_included`].
+    #[inline]
+    pub fn entities_matching<'a>(
+        &'a self,
+        filter: &'a ResolvedEntityPathFilter,
+    ) -> impl Iterator<Item = &'a EntityPath> + 'a {
+        self.entity_path_index.matching(filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId, Timeline};
+    use re_log_types::{
+        EntityPathFilter,
+        example_components::{MyPoint, MyPoints},
+    };
+
+    use super::*;
+
+    #[test]
+    fn entities_in_subtree() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        let entity_paths = [
+            "world/robot",
+            "world/robot/arm/joint2",
+            "world/robot/arm/joint10",
+            "world/camera",
+            "plots/speed",
+        ];
+        for entity_path in entity_paths {
+            let chunk = Chunk::builder(entity_path)
+                .with_component_batches(
+                    RowId::new(),
+                    [(Timeline::new_sequence("frame"), 1_i64)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(1.0, 1.0)] as _,
+                    )],
+                )
+                .build()?;
+            store.insert_chunk(&Arc::new(chunk))?;
+        }
+
+        let subtree = |store: &ChunkStore, root: &str| {
+            store
+                .entities_in_subtree(&EntityPath::from(root))
+                .map(|entity_path| entity_path.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            subtree(&store, "world/robot"),
+            vec![
+                "/world/robot",
+                "/world/robot/arm/joint2",
+                "/world/robot/arm/joint10"
+            ]
+        );
+        assert_eq!(subtree(&store, "world/robot/arm").len(), 2);
+        assert_eq!(subtree(&store, "world/nothing"), Vec::<String>::new());
+
+        // Same order as a linear scan.
+        assert!(
+            store
+                .entities_in_subtree(&EntityPath::root())
+                .eq(store.all_entities_sorted().iter())
+        );
+
+        let filter = EntityPathFilter::parse_forgiving("+ /world/**\n- /world/robot/arm/**")
+            .resolve_without_substitutions();
+        assert!(
+            store.entities_matching(&filter).eq(store
+                .all_entities_sorted()
+                .iter()
+                .filter(|entity_path| filter.matches(entity_path)))
+        );
+        assert_eq!(store.entities_matching(&filter).count(), 2);
+
+        store.drop_entity_path(&EntityPath::from("world/robot/arm/joint2"));
+        store.drop_entity_path(&EntityPath::from("world/robot/arm/joint10"));
+        assert_eq!(subtree(&store, "world/robot"), vec!["/world/robot"]);
+
+        // `/world/robot/arm` is gone too: /, world, robot, camera, plots & speed are left.
+        let index = &store.entity_path_index;
+        assert_eq!(index.walk(Some(&index.root), |_| true).count(), 6);
+
+        Ok(())
+    }
+}
@@ -5,7 +5,6 @@ use std::{
 
 use ahash::{HashMap, HashSet};
 use nohash_hasher::IntMap;
-use re_byte_size::SizeBytes;
 use web_time::Instant;
 
 use re_chunk::{Chunk, ChunkId, TimelineName};
@@ -13,8 +12,8 @@ use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt};
 use re_types_core::ComponentDescriptor;
 
 use crate::{
-    ChunkStore, ChunkStoreChunkStats, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent,
-    ChunkStoreStats, store::ChunkIdSetPerTime,
+    ChunkStore, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreStats, Retention,
+    RetentionPolicy, store::ChunkIdSetPerTime,
 };
 
 // Used all over in docstrings.
@@ -32,6 +31,9 @@ pub enum GarbageCollectionTarget {
 
     /// GC Everything that isn't protected.
     Everything,
+
+    /// GC the data that was kept longer than its retention, see [`GarbageCollectionOptions::retention`].
+    Expired,
 }
 
 #[derive(Debug, Clone)]
@@ -55,6 +57,12 @@ pub struct GarbageCollectionOptions {
 
     /// Do not remove any data within these time ranges.
     pub protected_time_ranges: IntMap<TimelineName, AbsoluteTimeRange>,
+
+    /// How long the data of each entity is kept.
+    ///
+    /// Data kept [`Retention::Forever`] is never removed,
+    /// and [`GarbageCollectionTarget::Expired`] removes the data that is past its retention.
+    pub retention: RetentionPolicy,
 }
 
 impl GarbageCollectionOptions {
@@ -64,11 +72,15 @@ impl GarbageCollectionOptions {
             time_budget: std::time::Duration::MAX,
             protect_latest: 0,
             protected_time_ranges: Default::default(),
+            retention: Default::default(),
         }
     }
 
     /// If true, we cannot remove this chunk.
     pub fn is_chunk_protected(&self, chunk: &Chunk) -> bool {
+        if self.retention.retention_for(chunk.entity_path()) == Some(Retention::Forever) {
+            return true;
+        }
         for (timeline, protected_time_range) in &self.protected_time_ranges {
             if let Some(time_column) = chunk.timelines().get(timeline)
                 && time_column.time_range().intersects(*protected_time_range)
@@ -87,6 +99,7 @@ impl std::fmt::Display for GarbageCollectionTarget {
                 write!(f, "DropAtLeast({:.3}%)", *p * 100.0)
             }
             Self::Everything => write!(f, "Everything"),
+            Self::Expired => write!(f, "Expired"),
         }
     }
 }
@@ -167,10 +180,28 @@ impl ChunkStore {
                     "starting GC"
                 );
 
+                self.gc_drop_at_least_num_bytes(options, f64::INFINITY, &protected_chunk_ids)
+            }
+            GarbageCollectionTarget::Expired => {
+                if options.retention.is_empty() {
+                    return (Vec::new(), ChunkStoreStats::default());
+                }
+
+                re_log::trace!(
+                    kind = "gc",
+                    id = self.gc_id,
+                    %options.target,
+                    total_num_rows_before = re_format::format_uint(total_num_rows_before),
+                    total_size_bytes_before = re_format::format_bytes(total_size_bytes_before),
+                    "starting GC"
+                );
+
                 self.gc_drop_at_least_num_bytes(options, f64::INFINITY, &protected_chunk_ids)
             }
         };
 
+        self.reclaim_disk_space();
+
         let stats_after = self.stats();
         let total_size_bytes_after = stats_after.total().total_size_bytes as f64;
         let total_num_chunks_after = stats_after.total().num_chunks;
@@ -278,6 +309,13 @@ impl ChunkStore {
 
         let start_time = Instant::now();
 
+        // Retention is relative to the newest data, see `Retention::For`.
+        let newest_nanos = self
+            .chunk_ids_per_min_row_id
+            .last_key_value()
+            .map(|(row_id, _)| row_id.nanos_since_epoch());
+        let only_expired = matches!(options.target, GarbageCollectionTarget::Expired);
+
         {
             re_tracing::profile_scope!("mark");
 
@@ -290,10 +328,14 @@ impl ChunkStore {
                     if options.is_chunk_protected(chunk) {
                         continue;
                     }
+                    if only_expired
+                        && !newest_nanos
+                            .is_some_and(|newest| options.retention.is_expired(chunk, newest))
+                    {
+                        continue;
+                    }
 
-                    // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
-                    // and would count as amortized (i.e. 0 bytes).
-                    num_bytes_to_drop -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
+                    num_bytes_to_drop -= self.mmap_storage.chunk_size_bytes(chunk) as f64;
 
                     // NOTE: We cannot blindly `retain` across all temporal tables, it's way too costly
                     // and slow. Rather we need to surgically remove the superfluous chunks.
@@ -351,9 +393,12 @@ impl ChunkStore {
                 temporal_chunks_stats: _,
                 static_chunk_ids_per_entity: _, // we don't GC static data
                 static_chunks_stats: _,         // we don't GC static data
+                entity_path_index: _,           // entities are only removed by `drop_entity_path`
+                static_column_dedup: _,         // we don't GC static data
                 insert_id: _,
                 gc_id: _,
                 event_id: _,
+                mmap_storage: _,
             } = self;
 
             let mut diffs = Vec::new();
@@ -699,7 +744,7 @@ impl ChunkStore {
                 .into_iter()
                 .filter_map(|chunk_id| self.chunks_per_chunk_id.remove(&chunk_id))
                 .inspect(|chunk| {
-                    self.temporal_chunks_stats -= ChunkStoreChunkStats::from_chunk(chunk);
+                    self.temporal_chunks_stats -= self.mmap_storage.release(chunk);
                 })
                 .map(ChunkStoreDiff::deletion)
                 .collect()
@@ -14,25 +14,43 @@
 #![doc = document_features::document_features!()]
 //!
 
+mod aggregation;
+mod compaction;
+mod component_subscriptions;
 mod dataframe;
 mod drop_time_range;
+mod entity_path_index;
 mod events;
 mod gc;
+mod mmap_storage;
+#[cfg(all(feature = "parquet", not(target_arch = "wasm32")))]
+mod parquet_export;
 mod query;
+mod retention;
+mod static_dedup;
 mod stats;
 mod store;
 mod subscribers;
 mod writes;
 
+#[cfg(all(feature = "parquet", not(target_arch = "wasm32")))]
+pub use self::parquet_export::{PARQUET_EXPORT_FILE_NAME, ParquetExportError, ParquetExportReport};
 pub use self::{
+    aggregation::{AggregationBucket, ScalarStats},
+    compaction::CompactionCursor,
+    component_subscriptions::{ComponentChange, ComponentSubscription},
     dataframe::{
         Index, IndexRange, IndexValue, QueryExpression, SparseFillStrategy, StaticColumnSelection,
         ViewContentsSelector,
     },
     events::{ChunkCompactionReport, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent},
     gc::{GarbageCollectionOptions, GarbageCollectionTarget},
+    retention::{Retention, RetentionParseError, RetentionPolicy},
     stats::{ChunkStoreChunkStats, ChunkStoreStats},
-    store::{ChunkStore, ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreHandle, ColumnMetadata},
+    store::{
+        ChunkStore, ChunkStoreBackend, ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreHandle,
+        ColumnMetadata,
+    },
     subscribers::{ChunkStoreSubscriber, ChunkStoreSubscriberHandle, PerStoreChunkSubscriber},
 };
 pub use re_sorbet::{ColumnDescriptor, ComponentColumnDescriptor, IndexColumnDescriptor};
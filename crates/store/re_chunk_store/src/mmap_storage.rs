@@ -0,0 +1,652 @@
+use std::collections::BTreeMap;
+use std::io::{Seek as _, SeekFrom, Write as _};
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+use arrow::buffer::Buffer;
+
+use re_byte_size::SizeBytes;
+use re_chunk::{Chunk, ChunkId};
+
+use crate::{ChunkStore, ChunkStoreBackend, ChunkStoreChunkStats, ChunkStoreConfig};
+
+// ---
+
+/// The size of the files the chunks are written to. Bigger chunks get a file of their own.
+const SEGMENT_SIZE: usize = 256 * 1024 * 1024;
+
+/// Every chunk starts at a multiple of this in its file, so that its arrow buffers can be used in place.
+const ALIGNMENT: usize = 64;
+
+/// How many bytes of chunks are kept on the heap at most while they may still be compacted,
+/// see [`MmapStorage::pending`].
+const MAX_PENDING_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(thiserror::Error, Debug)]
+pub enum MmapStorageError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Arrow(#[from] arrow::error::ArrowError),
+
+    #[error(transparent)]
+    Chunk(#[from] re_chunk::ChunkError),
+
+    #[error("Chunk could not be read back from its file")]
+    Corrupt,
+}
+
+/// Identifies a [`Segment`], in the order they were created.
+type SegmentId = u64;
+
+/// A temporary file, mapped into memory as a whole.
+struct Segment {
+    id: SegmentId,
+
+    file: std::fs::File,
+
+    /// The whole file.
+    ///
+    /// Keeps the mapping alive, as do all the arrow arrays that were sliced from it.
+    buffer: Buffer,
+
+    /// Where the next chunk goes.
+    cursor: usize,
+}
+
+impl Segment {
+    fn create(id: SegmentId, len: usize) -> Result<Self, MmapStorageError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let file = tempfile::tempfile()?;
+            file.set_len(len as u64)?;
+
+            // SAFETY: file-backed memory maps are marked unsafe because of potential UB when the underlying file is modified.
+            // Nobody else knows about this file, and we only ever write to the parts of it that were never read.
+            #[expect(unsafe_code)]
+            let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+            let ptr = std::ptr::NonNull::new(mmap.as_ptr().cast_mut())
+                .ok_or(MmapStorageError::Corrupt)?;
+            let len = mmap.len();
+
+            // SAFETY: the buffer covers exactly the mapping, which it keeps alive.
+            #[expect(unsafe_code)]
+            let buffer = unsafe { Buffer::from_custom_allocation(ptr, len, Arc::new(mmap)) };
+
+            Ok(Self {
+                id,
+                file,
+                buffer,
+                cursor: 0,
+            })
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            _ = (id, len);
+            Err(std::io::Error::from(std::io::ErrorKind::Unsupported).into())
+        }
+    }
+
+    fn has_room_for(&self, num_bytes: usize) -> bool {
+        self.cursor + num_bytes <= self.buffer.len()
+    }
+}
+
+/// A chunk of the store that lives in one of the files.
+#[derive(Clone, Copy)]
+struct MappedChunk {
+    segment_id: SegmentId,
+
+    /// The size of the chunk before it was written to disk.
+    ///
+    /// This is what the chunk counts for in the stats of the store:
+    /// the arrow buffers of a memory-mapped chunk report the size of the whole file instead.
+    num_bytes_on_heap: u64,
+
+    /// The size of the chunk in its file.
+    num_bytes_on_disk: u64,
+}
+
+/// Writes chunks to memory-mapped temporary files, see [`ChunkStoreBackend::MemoryMapped`].
+///
+/// The files are append-only: a file is removed once all the chunks that live in it are dropped.
+/// Files that are mostly taken up by dropped chunks are compacted during garbage collection,
+/// see [`ChunkStore::reclaim_disk_space`].
+pub(crate) struct MmapStorage {
+    /// The size of new files, [`SEGMENT_SIZE`] unless changed by tests.
+    segment_size: usize,
+
+    /// The file new chunks are written to.
+    current: Option<Segment>,
+
+    /// The id of the next file.
+    next_segment_id: SegmentId,
+
+    /// The number of bytes written to each file that may still hold chunks of the store.
+    num_bytes_per_segment: BTreeMap<SegmentId, u64>,
+
+    /// The chunks of the store that live in the files.
+    mapped_chunks: HashMap<ChunkId, MappedChunk>,
+
+    /// The total [`MappedChunk::num_bytes_on_heap`] of the [`Self::mapped_chunks`].
+    num_bytes_mapped: u64,
+
+    /// Chunks that are kept on the heap for now, because they may still be compacted with newer ones.
+    ///
+    /// Writing them right away would also write every intermediate result of the compaction,
+    /// and that space can't be reclaimed as long as other chunks live in the same file.
+    ///
+    /// The values are the sizes of the chunks. Ordered by [`ChunkId`], i.e. oldest first.
+    pending: BTreeMap<ChunkId, u64>,
+
+    /// The total size of the [`Self::pending`] chunks.
+    ///
+    /// Includes the chunks that were since compacted or dropped, until the next cleanup.
+    num_pending_bytes: u64,
+
+    /// The number of bytes written to all files so far.
+    num_bytes_written: u64,
+}
+
+impl Default for MmapStorage {
+    fn default() -> Self {
+        Self {
+            segment_size: SEGMENT_SIZE,
+            current: None,
+            next_segment_id: 0,
+            num_bytes_per_segment: Default::default(),
+            mapped_chunks: Default::default(),
+            num_bytes_mapped: 0,
+            pending: Default::default(),
+            num_pending_bytes: 0,
+            num_bytes_written: 0,
+        }
+    }
+}
+
+impl Clone for MmapStorage {
+    /// The clone shares the files of the chunks written so far, but writes new chunks to files of its own.
+    fn clone(&self) -> Self {
+        Self {
+            segment_size: self.segment_size,
+            current: None,
+            next_segment_id: self.next_segment_id,
+            num_bytes_per_segment: self.num_bytes_per_segment.clone(),
+            mapped_chunks: self.mapped_chunks.clone(),
+            num_bytes_mapped: self.num_bytes_mapped,
+            pending: self.pending.clone(),
+            num_pending_bytes: self.num_pending_bytes,
+            num_bytes_written: self.num_bytes_written,
+        }
+    }
+}
+
+impl std::fmt::Debug for MmapStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MmapStorage")
+            .field("num_segments", &self.num_bytes_per_segment.len())
+            .field("num_bytes_mapped", &self.num_bytes_mapped)
+            .field("num_pending_bytes", &self.num_pending_bytes)
+            .field("num_bytes_written", &self.num_bytes_written)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MmapStorage {
+    /// Writes the chunk to disk, and returns the same chunk, with its data in the memory-mapped file.
+    ///
+    /// The chunk may already live in another file, see [`ChunkStore::reclaim_disk_space`].
+    fn store(&mut self, chunk: &Chunk) -> Result<Chunk, MmapStorageError> {
+        re_tracing::profile_function!();
+
+        let ipc = encode_ipc(chunk)?;
+        let num_bytes_on_heap = self.chunk_size_bytes(chunk);
+
+        let has_room = self
+            .current
+            .as_ref()
+            .is_some_and(|segment| segment.has_room_for(ipc.len()));
+        let segment = match &mut self.current {
+            Some(segment) if has_room => segment,
+            current => {
+                let id = self.next_segment_id;
+                self.next_segment_id += 1;
+                current.insert(Segment::create(id, ipc.len().max(self.segment_size))?)
+            }
+        };
+
+        let offset = segment.cursor;
+        segment.file.seek(SeekFrom::Start(offset as u64))?;
+        segment.file.write_all(&ipc)?;
+        segment.cursor = (offset + ipc.len()).next_multiple_of(ALIGNMENT);
+        self.num_bytes_written += ipc.len() as u64;
+        *self.num_bytes_per_segment.entry(segment.id).or_default() += ipc.len() as u64;
+
+        // Decoding from the mapped buffer slices the arrays out of it, instead of copying them to the heap.
+        let mut buffer = segment.buffer.slice_with_length(offset, ipc.len());
+        let mut decoder = arrow::ipc::reader::StreamDecoder::new();
+        while !buffer.is_empty() {
+            if let Some(batch) = decoder.decode(&mut buffer)? {
+                let stored = Chunk::from_record_batch(&batch)?;
+
+                let previous = self.mapped_chunks.insert(
+                    stored.id(),
+                    MappedChunk {
+                        segment_id: segment.id,
+                        num_bytes_on_heap,
+                        num_bytes_on_disk: ipc.len() as u64,
+                    },
+                );
+                self.num_bytes_mapped += num_bytes_on_heap;
+                if let Some(previous) = previous {
+                    self.num_bytes_mapped -= previous.num_bytes_on_heap;
+                }
+
+                return Ok(stored);
+            }
+        }
+        Err(MmapStorageError::Corrupt)
+    }
+
+    /// Like [`Self::store`], but keeps the chunk on the heap if that fails.
+    fn store_or_keep(&mut self, chunk: Arc<Chunk>) -> Arc<Chunk> {
+        match self.store(&chunk) {
+            Ok(stored) => Arc::new(stored),
+            Err(err) => {
+                re_log::warn_once!("Failed to write chunk to disk, keeping it in memory: {err}");
+                chunk
+            }
+        }
+    }
+
+    /// The size of the chunk, as accounted for in the stats of the store.
+    ///
+    /// Use this instead of [`SizeBytes::total_size_bytes`] for any chunk of the store,
+    /// since that overestimates the size of memory-mapped chunks by a lot.
+    pub(crate) fn chunk_size_bytes(&self, chunk: &Chunk) -> u64 {
+        if let Some(mapped) = self.mapped_chunks.get(&chunk.id()) {
+            mapped.num_bytes_on_heap
+        } else {
+            <Chunk as SizeBytes>::total_size_bytes(chunk)
+        }
+    }
+
+    /// Like [`ChunkStoreChunkStats::from_chunk`], but with the size of [`Self::chunk_size_bytes`].
+    pub(crate) fn chunk_stats(&self, chunk: &Arc<Chunk>) -> ChunkStoreChunkStats {
+        ChunkStoreChunkStats {
+            num_chunks: 1,
+            total_size_bytes: self.chunk_size_bytes(chunk),
+            num_rows: chunk.num_rows() as u64,
+            num_events: chunk.num_events_cumulative(),
+        }
+    }
+
+    /// The [`Self::chunk_stats`] of a chunk that is removed from the store, which is then forgotten about.
+    pub(crate) fn release(&mut self, chunk: &Arc<Chunk>) -> ChunkStoreChunkStats {
+        let stats = self.chunk_stats(chunk);
+        if let Some(mapped) = self.mapped_chunks.remove(&chunk.id()) {
+            self.num_bytes_mapped -= mapped.num_bytes_on_heap;
+        }
+        stats
+    }
+
+    /// How many bytes of the chunks of the store are in memory-mapped files rather than on the heap,
+    /// see [`crate::ChunkStoreStats::bytes_memory_mapped`].
+    #[inline]
+    pub(crate) fn num_bytes_mapped(&self) -> u64 {
+        self.num_bytes_mapped
+    }
+}
+
+fn encode_ipc(chunk: &Chunk) -> Result<Vec<u8>, MmapStorageError> {
+    let batch = chunk.to_record_batch()?;
+    let mut ipc = Vec::new();
+    {
+        let mut writer = arrow::ipc::writer::StreamWriter::try_new(&mut ipc, batch.schema_ref())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+    }
+    Ok(ipc)
+}
+
+impl ChunkStore {
+    /// Moves the data of the chunk to disk, if this store uses [`ChunkStoreBackend::MemoryMapped`].
+    ///
+    /// Chunks that may still be compacted with newer ones are kept on the heap until they
+    /// can't grow anymore, or until they take up too much memory, see [`Self::offload_pending_chunks`].
+    /// The chunk is also kept on the heap if writing it fails.
+    pub(crate) fn offload_chunk(&mut self, chunk: Arc<Chunk>) -> Arc<Chunk> {
+        if self.config.backend != ChunkStoreBackend::MemoryMapped {
+            return chunk;
+        }
+
+        if self.may_be_compacted(&chunk) {
+            let num_bytes = <Chunk as SizeBytes>::total_size_bytes(&chunk);
+            self.mmap_storage.pending.insert(chunk.id(), num_bytes);
+            self.mmap_storage.num_pending_bytes += num_bytes;
+            return chunk;
+        }
+
+        self.mmap_storage.store_or_keep(chunk)
+    }
+
+    /// Moves the oldest chunks that were kept on the heap by [`Self::offload_chunk`] to disk,
+    /// once there are too many of them.
+    ///
+    /// Must be called after the chunk returned by [`Self::offload_chunk`] was inserted.
+    pub(crate) fn offload_pending_chunks(&mut self) {
+        let Self {
+            chunks_per_chunk_id,
+            mmap_storage,
+            ..
+        } = self;
+
+        if mmap_storage.num_pending_bytes <= MAX_PENDING_BYTES {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        // Forget about the chunks that were compacted or dropped in the meantime.
+        mmap_storage
+            .pending
+            .retain(|chunk_id, _| chunks_per_chunk_id.contains_key(chunk_id));
+        mmap_storage.num_pending_bytes = mmap_storage.pending.values().sum();
+
+        // Go well below the limit, so that we don't clean up on every insertion.
+        while MAX_PENDING_BYTES / 2 < mmap_storage.num_pending_bytes {
+            let Some((chunk_id, num_bytes)) = mmap_storage.pending.pop_first() else {
+                break;
+            };
+            mmap_storage.num_pending_bytes -= num_bytes;
+
+            if let Some(chunk) = chunks_per_chunk_id.get_mut(&chunk_id) {
+                *chunk = mmap_storage.store_or_keep(Arc::clone(chunk));
+            }
+        }
+    }
+
+    /// Rewrites the chunks that are left in files that are mostly taken up by dropped chunks,
+    /// so that these files can be removed.
+    ///
+    /// Called after garbage collection. A file is only removed once nothing refers to the chunks
+    /// that used to live in it anymore, e.g. the query caches.
+    pub(crate) fn reclaim_disk_space(&mut self) {
+        let Self {
+            chunks_per_chunk_id,
+            mmap_storage,
+            ..
+        } = self;
+
+        if mmap_storage.num_bytes_per_segment.is_empty() {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        let mut num_live_bytes_per_segment: HashMap<SegmentId, u64> = HashMap::default();
+        for mapped in mmap_storage.mapped_chunks.values() {
+            *num_live_bytes_per_segment
+                .entry(mapped.segment_id)
+                .or_default() += mapped.num_bytes_on_disk;
+        }
+
+        // New chunks are still written to the current file, so it isn't worth rewriting.
+        let current_segment_id = mmap_storage.current.as_ref().map(|segment| segment.id);
+        let wasteful_segment_ids: HashSet<SegmentId> = mmap_storage
+            .num_bytes_per_segment
+            .iter()
+            .filter(|(segment_id, num_bytes_written)| {
+                let num_live_bytes = num_live_bytes_per_segment
+                    .get(segment_id)
+                    .copied()
+                    .unwrap_or_default();
+                Some(**segment_id) != current_segment_id && num_live_bytes < **num_bytes_written / 2
+            })
+            .map(|(segment_id, _)| *segment_id)
+            .collect();
+
+        let chunk_ids_to_rewrite: Vec<ChunkId> = mmap_storage
+            .mapped_chunks
+            .iter()
+            .filter(|(_, mapped)| wasteful_segment_ids.contains(&mapped.segment_id))
+            .map(|(chunk_id, _)| *chunk_id)
+            .collect();
+        for chunk_id in chunk_ids_to_rewrite {
+            if let Some(chunk) = chunks_per_chunk_id.get_mut(&chunk_id) {
+                *chunk = mmap_storage.store_or_keep(Arc::clone(chunk));
+            }
+        }
+
+        // Forget about the files without any chunks of the store left.
+        let live_segment_ids: HashSet<SegmentId> = mmap_storage
+            .mapped_chunks
+            .values()
+            .map(|mapped| mapped.segment_id)
+            .collect();
+        let current_segment_id = mmap_storage.current.as_ref().map(|segment| segment.id);
+        mmap_storage.num_bytes_per_segment.retain(|segment_id, _| {
+            live_segment_ids.contains(segment_id) || Some(*segment_id) == current_segment_id
+        });
+    }
+
+    /// Whether the chunk is small enough to be compacted with another one,
+    /// see [`Self::find_and_elect_compaction_candidate`].
+    fn may_be_compacted(&self, chunk: &Chunk) -> bool {
+        let ChunkStoreConfig {
+            enable_changelog: _,
+            chunk_max_bytes,
+            chunk_max_rows,
+            chunk_max_rows_if_unsorted,
+            backend: _,
+        } = self.config;
+
+        let max_rows = if chunk.is_time_sorted() {
+            chunk_max_rows
+        } else {
+            chunk_max_rows_if_unsorted
+        };
+
+        // Static chunks are never compacted.
+        !chunk.is_static()
+            && <Chunk as SizeBytes>::total_size_bytes(chunk) < chunk_max_bytes
+            && (chunk.num_rows() as u64) < max_rows
+    }
+
+    /// The number of bytes in the files of [`ChunkStoreBackend::MemoryMapped`] that may still hold chunks of the store.
+    ///
+    /// Includes the data of chunks that were dropped since,
+    /// until the files are compacted after garbage collection.
+    #[inline]
+    pub fn num_bytes_on_disk(&self) -> u64 {
+        self.mmap_storage.num_bytes_per_segment.values().sum()
+    }
+
+    /// The number of bytes written to disk with [`ChunkStoreBackend::MemoryMapped`] so far.
+    ///
+    /// This includes the data of chunks that were since dropped, and of chunks that were rewritten
+    /// to compact the files, see [`Self::num_bytes_on_disk`] for what is still in use.
+    /// Chunks that may still be compacted are only written once they can't grow anymore.
+    #[inline]
+    pub fn num_bytes_written_to_disk(&self) -> u64 {
+        self.mmap_storage.num_bytes_written
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, Timeline};
+    use re_log_types::example_components::{MyPoint, MyPoints};
+
+    use crate::{ChunkStoreConfig, GarbageCollectionOptions, LatestAtQuery};
+
+    use super::*;
+
+    #[test]
+    fn mmap_backend_roundtrip() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        // Without compaction, every chunk is written to disk right away.
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig {
+                backend: ChunkStoreBackend::MemoryMapped,
+                ..ChunkStoreConfig::COMPACTION_DISABLED
+            },
+        );
+
+        let timeline = Timeline::new_sequence("frame");
+        for frame in 0..3_i64 {
+            let chunk = Chunk::builder("points")
+                .with_component_batches(
+                    RowId::new(),
+                    [(timeline, frame)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(frame as f32, 1.0)] as _,
+                    )],
+                )
+                .build()?;
+            let events = store.insert_chunk(&Arc::new(chunk))?;
+
+            // Subscribers get the chunk that is on disk, not the one on the heap.
+            let stored = store
+                .chunk(&events[0].chunk.id())
+                .expect("chunk was just inserted");
+            assert!(Arc::ptr_eq(&events[0].chunk, stored));
+        }
+        assert!(store.num_bytes_written_to_disk() > 0);
+
+        let descr = MyPoints::descriptor_points();
+        let query = LatestAtQuery::new(*timeline.name(), 1);
+        let points: Vec<MyPoint> = store
+            .latest_at_relevant_chunks(&query, &"points".into(), &descr)
+            .into_iter()
+            .filter_map(|chunk| chunk.latest_at(&query, &descr).into_unit())
+            .filter_map(|unit| unit.component_mono::<MyPoint>(&descr)?.ok())
+            .collect();
+        assert_eq!(points, vec![MyPoint::new(1.0, 1.0)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mmap_backend_with_compaction() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig {
+                backend: ChunkStoreBackend::MemoryMapped,
+                chunk_max_bytes: u64::MAX,
+                chunk_max_rows: 100,
+                chunk_max_rows_if_unsorted: 100,
+                ..ChunkStoreConfig::DEFAULT
+            },
+        );
+
+        let timeline = Timeline::new_sequence("frame");
+        for frame in 0..1000_i64 {
+            let chunk = Chunk::builder("points")
+                .with_component_batches(
+                    RowId::new(),
+                    [(timeline, frame)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(frame as f32, 1.0)] as _,
+                    )],
+                )
+                .build()?;
+            store.insert_chunk(&Arc::new(chunk))?;
+        }
+        assert!(store.num_chunks() < 1000);
+        assert!(store.num_bytes_written_to_disk() > 0);
+
+        // Every chunk is written once. Writing every intermediate result of the compaction
+        // would take about fifty times that.
+        let mut num_bytes_in_store = 0;
+        for chunk in store.iter_chunks() {
+            num_bytes_in_store += encode_ipc(chunk)?.len() as u64;
+        }
+        assert!(store.num_bytes_written_to_disk() <= 2 * num_bytes_in_store);
+
+        let descr = MyPoints::descriptor_points();
+        let query = LatestAtQuery::new(*timeline.name(), 500);
+        let points: Vec<MyPoint> = store
+            .latest_at_relevant_chunks(&query, &"points".into(), &descr)
+            .into_iter()
+            .filter_map(|chunk| chunk.latest_at(&query, &descr).into_unit())
+            .filter_map(|unit| unit.component_mono::<MyPoint>(&descr)?.ok())
+            .collect();
+        assert_eq!(points, vec![MyPoint::new(500.0, 1.0)]);
+
+        Ok(())
+    }
+    #[test]
+    fn mmap_backend_reclaims_disk_space_on_gc() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig {
+                backend: ChunkStoreBackend::MemoryMapped,
+                ..ChunkStoreConfig::COMPACTION_DISABLED
+            },
+        );
+
+        // Small files, so that the chunks are spread over many of them.
+        store.mmap_storage.segment_size = 64 * 1024;
+
+        // Small chunks that are kept, in between big ones that are dropped.
+        let timeline = Timeline::new_sequence("frame");
+        for frame in 0..100_i64 {
+            for (entity_path, num_points) in [("big", 2000), ("small", 1)] {
+                let points = vec![MyPoint::new(frame as f32, 1.0); num_points];
+                let chunk = Chunk::builder(entity_path)
+                    .with_component_batches(
+                        RowId::new(),
+                        [(timeline, frame)],
+                        [(MyPoints::descriptor_points(), &points as _)],
+                    )
+                    .build()?;
+                store.insert_chunk(&Arc::new(chunk))?;
+            }
+        }
+
+        // Everything is accounted for with its size on the heap, rather than the size of the files.
+        let stats = store.stats();
+        assert_eq!(stats.bytes_memory_mapped, stats.total().total_size_bytes);
+
+        let num_bytes_on_disk_before = store.num_bytes_on_disk();
+        let num_bytes_written_before = store.num_bytes_written_to_disk();
+        assert_eq!(num_bytes_on_disk_before, num_bytes_written_before);
+
+        store.gc(&GarbageCollectionOptions {
+            retention: "small=forever".parse()?,
+            ..GarbageCollectionOptions::gc_everything()
+        });
+        assert_eq!(store.num_chunks(), 100);
+
+        // The small chunks were moved to new files, and the old ones forgotten about.
+        assert!(store.num_bytes_written_to_disk() > num_bytes_written_before);
+        assert!(store.num_bytes_on_disk() < num_bytes_on_disk_before / 4);
+
+        let stats = store.stats();
+        assert_eq!(stats.bytes_memory_mapped, stats.total().total_size_bytes);
+
+        let descr = MyPoints::descriptor_points();
+        let query = LatestAtQuery::new(*timeline.name(), 50);
+        let points: Vec<MyPoint> = store
+            .latest_at_relevant_chunks(&query, &"small".into(), &descr)
+            .into_iter()
+            .filter_map(|chunk| chunk.latest_at(&query, &descr).into_unit())
+            .filter_map(|unit| unit.component_mono::<MyPoint>(&descr)?.ok())
+            .collect();
+        assert_eq!(points, vec![MyPoint::new(50.0, 1.0)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+//! Exporting the contents of a [`ChunkStore`] as Parquet files, for offline analytics with other tools.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef as ArrowArrayRef, RecordBatch as ArrowRecordBatch, new_null_array};
+use arrow::datatypes::{FieldRef as ArrowFieldRef, Schema as ArrowSchema};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use re_chunk::{Chunk, ChunkError, EntityPath};
+use re_sorbet::ColumnKind;
+
+use crate::ChunkStore;
+
+// ---
+
+/// The name of the file each entity is exported to, see [`ChunkStore::export_parquet`].
+pub const PARQUET_EXPORT_FILE_NAME: &str = "chunks.parquet";
+
+#[derive(thiserror::Error, Debug)]
+pub enum ParquetExportError {
+    #[error("Failed to write {0:?}: {1}")]
+    Io(PathBuf, std::io::Error),
+
+    #[error(transparent)]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    #[error(transparent)]
+    Chunk(#[from] ChunkError),
+}
+
+/// What [`ChunkStore::export_parquet`] wrote.
+#[derive(Debug, Clone, Default)]
+pub struct ParquetExportReport {
+    /// One per entity.
+    pub files: Vec<PathBuf>,
+
+    /// The number of rows across all files.
+    pub num_rows: u64,
+
+    /// Chunks that couldn't be exported because their columns don't match the other chunks of their entity.
+    pub num_skipped_chunks: u64,
+}
+
+impl ChunkStore {
+    /// Writes the chunks of each entity to `<dir>/<entity path>/chunks.parquet`, e.g. `<dir>/camera/rgb/chunks.parquet`.
+    ///
+    /// Each file has a row id column, one column per timeline and one per component,
+    /// with the same Arrow types as in the store, and one row group per chunk.
+    /// Columns that a chunk doesn't have are null, e.g. the timelines of static data.
+    ///
+    /// Characters in entity paths that can't be used in file names are replaced with `_`.
+    pub fn export_parquet(&self, dir: &Path) -> Result<ParquetExportReport, ParquetExportError> {
+        re_tracing::profile_function!();
+
+        let mut chunks_per_entity: BTreeMap<&EntityPath, Vec<&Arc<Chunk>>> = BTreeMap::new();
+        for chunk in self.iter_chunks() {
+            chunks_per_entity
+                .entry(chunk.entity_path())
+                .or_default()
+                .push(chunk);
+        }
+
+        let mut report = ParquetExportReport::default();
+        for (entity_path, mut chunks) in chunks_per_entity {
+            // Static data first, then in the order it was logged.
+            chunks.sort_by_key(|chunk| {
+                (!chunk.is_static(), chunk.row_id_range().map(|(min, _)| min))
+            });
+
+            let path = entity_dir(dir, entity_path).join(PARQUET_EXPORT_FILE_NAME);
+            export_entity(&path, entity_path, &chunks, &mut report)?;
+            report.files.push(path);
+        }
+
+        Ok(report)
+    }
+}
+
+fn export_entity(
+    path: &Path,
+    entity_path: &EntityPath,
+    chunks: &[&Arc<Chunk>],
+    report: &mut ParquetExportReport,
+) -> Result<(), ParquetExportError> {
+    re_tracing::profile_function!(entity_path.to_string());
+
+    let batches = chunks
+        .iter()
+        .map(|chunk| chunk.to_record_batch())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // All the columns of all the chunks, with the row id first, then the timelines, then the components.
+    let mut fields: Vec<ArrowFieldRef> = Vec::new();
+    let mut field_names: HashSet<&str> = HashSet::new();
+    for batch in &batches {
+        for field in batch.schema_ref().fields() {
+            if field_names.insert(field.name()) {
+                fields.push(Arc::new(field.as_ref().clone().with_nullable(true)));
+            }
+        }
+    }
+    fields.sort_by_key(|field| match ColumnKind::try_from(field.as_ref()) {
+        Ok(ColumnKind::RowId) => 0,
+        Ok(ColumnKind::Index) => 1,
+        Ok(ColumnKind::Component) | Err(_) => 2,
+    });
+
+    let schema = Arc::new(ArrowSchema::new_with_metadata(
+        fields.clone(),
+        [("rerun:entity_path".to_owned(), entity_path.to_string())].into(),
+    ));
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| ParquetExportError::Io(parent.to_owned(), err))?;
+    }
+    let file =
+        std::fs::File::create(path).map_err(|err| ParquetExportError::Io(path.to_owned(), err))?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(file, schema.clone(), Some(properties))?;
+
+    for batch in batches {
+        let Some(batch) = with_fields(&batch, &schema, &fields) else {
+            re_log::warn_once!(
+                "Skipped chunks of {entity_path} with columns that don't match the other chunks of the entity"
+            );
+            report.num_skipped_chunks += 1;
+            continue;
+        };
+
+        report.num_rows += batch.num_rows() as u64;
+        writer.write(&batch)?;
+        // One row group per chunk.
+        writer.flush()?;
+    }
+
+    writer.close()?;
+
+    Ok(())
+}
+
+/// The batch with exactly the given fields, filling the ones it doesn't have with nulls.
+///
+/// Returns `None` if the batch has a field of the same name, but with another type.
+fn with_fields(
+    batch: &ArrowRecordBatch,
+    schema: &Arc<ArrowSchema>,
+    fields: &[ArrowFieldRef],
+) -> Option<ArrowRecordBatch> {
+    let columns = fields
+        .iter()
+        .map(|field| match batch.column_by_name(field.name()) {
+            Some(column) if column.data_type() == field.data_type() => Some(column.clone()),
+            Some(_) => None,
+            None => Some(new_null_array(field.data_type(), batch.num_rows())),
+        })
+        .collect::<Option<Vec<ArrowArrayRef>>>()?;
+
+    ArrowRecordBatch::try_new(schema.clone(), columns).ok()
+}
+
+fn entity_dir(dir: &Path, entity_path: &EntityPath) -> PathBuf {
+    let mut path = dir.to_owned();
+    for part in entity_path.iter() {
+        let part: String = part
+            .unescaped_str()
+            .chars()
+            .map(|c| {
+                if c.is_control()
+                    || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
+                {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .collect();
+        path.push(if part == "." || part == ".." {
+            part.replace('.', "_")
+        } else {
+            part
+        });
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_log_types::example_components::{MyPoint, MyPoints};
+
+    use crate::ChunkStoreConfig;
+
+    use super::*;
+
+    #[test]
+    fn export_entities() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        for (entity_path, timepoint) in [
+            ("/points", vec![(Timeline::new_sequence("frame"), 1_i64)]),
+            ("/points", vec![]),
+            (
+                "/points/child",
+                vec![(Timeline::new_sequence("frame"), 2_i64)],
+            ),
+        ] {
+            let chunk = Chunk::builder(entity_path)
+                .with_component_batches(
+                    RowId::new(),
+                    timepoint.into_iter().collect::<TimePoint>(),
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(1.0, 2.0)] as _,
+                    )],
+                )
+                .build()?;
+            store.insert_chunk(&Arc::new(chunk))?;
+        }
+
+        let dir = tempfile::tempdir()?;
+        let report = store.export_parquet(dir.path())?;
+        assert_eq!(report.files.len(), 2);
+        assert_eq!(report.num_rows, 3);
+        assert_eq!(report.num_skipped_chunks, 0);
+
+        let file = std::fs::File::open(dir.path().join("points").join(PARQUET_EXPORT_FILE_NAME))?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            2
+        );
+
+        // The static row comes first, without a time.
+        let frame = batches[0]
+            .column_by_name("frame")
+            .expect("the timeline is a column");
+        assert!(frame.is_null(0));
+        assert!(
+            dir.path()
+                .join("points/child")
+                .join(PARQUET_EXPORT_FILE_NAME)
+                .exists()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+//! Per-entity retention of data, on top of the garbage collection driven by memory limits.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use re_chunk::Chunk;
+use re_log_types::EntityPath;
+
+/// How long the data of an entity subtree is kept, see [`RetentionPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Retention {
+    /// Never garbage collected, not even to stay within memory limits.
+    Forever,
+
+    /// Dropped once it was logged this long before the newest data in the store.
+    ///
+    /// The age is measured with the [`re_chunk::RowId`]s, i.e. the clock of the logging process,
+    /// so it is independent of the timelines, and the same whether the data is streamed or loaded from a file.
+    For(Duration),
+}
+
+impl std::fmt::Display for Retention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Forever => f.write_str("forever"),
+            Self::For(duration) => {
+                let millis = duration.as_millis();
+                if millis % 3_600_000 == 0 && millis > 0 {
+                    write!(f, "{}h", millis / 3_600_000)
+                } else if millis % 60_000 == 0 && millis > 0 {
+                    write!(f, "{}min", millis / 60_000)
+                } else if millis % 1_000 == 0 {
+                    write!(f, "{}s", millis / 1_000)
+                } else {
+                    write!(f, "{millis}ms")
+                }
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Retention {
+    type Err = RetentionParseError;
+
+    /// `forever`, or a duration like `500ms`, `30s`, `2min`, `1h` or `1d`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("forever") {
+            return Ok(Self::Forever);
+        }
+
+        let invalid = || RetentionParseError::InvalidRetention(s.to_owned());
+        let unit_start = s
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or_else(invalid)?;
+        let (value, unit) = s.split_at(unit_start);
+        let seconds_per_unit = match unit {
+            "ms" => 1e-3,
+            "s" => 1.0,
+            "min" => 60.0,
+            "h" => 3600.0,
+            "d" => 86400.0,
+            _ => return Err(invalid()),
+        };
+        let value = value.trim().parse::<f64>().map_err(|_err| invalid())?;
+        Duration::try_from_secs_f64(value * seconds_per_unit)
+            .map(Self::For)
+            .map_err(|_err| invalid())
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RetentionParseError {
+    #[error("Expected a rule like `/camera/**=2min`, got {0:?}")]
+    InvalidRule(String),
+
+    #[error("Expected `forever` or a duration like `30s`, `2min` or `1h`, got {0:?}")]
+    InvalidRetention(String),
+}
+
+/// How long the data of each entity subtree is kept, e.g. `/camera/**=2min, /state/**=forever`.
+///
+/// The rule of the deepest subtree an entity is in applies to it.
+/// The data of entities without a rule is only dropped to stay within memory limits.
+/// Static data is never dropped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    rules: BTreeMap<EntityPath, Retention>,
+}
+
+impl RetentionPolicy {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Sets the retention of everything in the subtree at `subtree`.
+    pub fn insert(&mut self, subtree: EntityPath, retention: Retention) {
+        self.rules.insert(subtree, retention);
+    }
+
+    /// Adds the rules of `other`, which take precedence for subtrees that both have a rule for.
+    pub fn extend(&mut self, other: &Self) {
+        self.rules.extend(
+            other
+                .rules
+                .iter()
+                .map(|(path, retention)| (path.clone(), *retention)),
+        );
+    }
+
+    /// The retention of an entity, if any rule applies to it.
+    pub fn retention_for(&self, entity_path: &EntityPath) -> Option<Retention> {
+        if self.rules.is_empty() {
+            return None;
+        }
+
+        let mut path = Some(entity_path.clone());
+        while let Some(subtree) = path {
+            if let Some(retention) = self.rules.get(&subtree) {
+                return Some(*retention);
+            }
+            path = subtree.parent();
+        }
+        None
+    }
+
+    /// Whether the whole chunk was logged longer ago than its retention allows.
+    ///
+    /// `newest_nanos` is the time of the newest data in the store, in nanoseconds since the epoch.
+    pub fn is_expired(&self, chunk: &Chunk, newest_nanos: u64) -> bool {
+        let Some(Retention::For(duration)) = self.retention_for(chunk.entity_path()) else {
+            return false;
+        };
+        let Some((_, max_row_id)) = chunk.row_id_range() else {
+            return false;
+        };
+        let expires_at = u128::from(max_row_id.nanos_since_epoch()) + duration.as_nanos();
+        expires_at < u128::from(newest_nanos)
+    }
+}
+
+impl std::str::FromStr for RetentionPolicy {
+    type Err = RetentionParseError;
+
+    /// Comma or newline separated `<subtree>=<retention>` rules, see [`Retention::from_str`].
+    ///
+    /// A trailing `/**` on the subtree is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut policy = Self::default();
+        for rule in s
+            .split([',', '\n'])
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+        {
+            let (subtree, retention) = rule
+                .split_once('=')
+                .ok_or_else(|| RetentionParseError::InvalidRule(rule.to_owned()))?;
+            let subtree = subtree.trim();
+            let subtree = subtree
+                .strip_suffix("/**")
+                .or_else(|| subtree.strip_suffix("**"))
+                .unwrap_or(subtree);
+            policy.insert(EntityPath::from(subtree), retention.parse()?);
+        }
+        Ok(policy)
+    }
+}
+
+impl std::fmt::Display for RetentionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (subtree, retention)) in self.rules.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if subtree.is_root() {
+                write!(f, "/**={retention}")?;
+            } else {
+                write!(f, "{subtree}/**={retention}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_lookup() {
+        let policy: RetentionPolicy = "/camera/**=2min, /camera/depth=forever\n/**=1.5s"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            policy.retention_for(&"/camera/rgb".into()),
+            Some(Retention::For(Duration::from_secs(120)))
+        );
+        assert_eq!(
+            policy.retention_for(&"/camera/depth/points".into()),
+            Some(Retention::Forever)
+        );
+        assert_eq!(
+            policy.retention_for(&"/state".into()),
+            Some(Retention::For(Duration::from_millis(1500)))
+        );
+        assert_eq!(
+            policy.to_string(),
+            "/**=1500ms, /camera/**=2min, /camera/depth/**=forever"
+        );
+        assert_eq!(policy.to_string().parse::<RetentionPolicy>(), Ok(policy));
+
+        assert_eq!(
+            "/camera".parse::<RetentionPolicy>(),
+            Err(RetentionParseError::InvalidRule("/camera".to_owned()))
+        );
+        assert_eq!(
+            "/camera=soon".parse::<RetentionPolicy>(),
+            Err(RetentionParseError::InvalidRetention("soon".to_owned()))
+        );
+    }
+
+    #[test]
+    fn later_rules_take_precedence() {
+        let mut policy: RetentionPolicy = "/a=1s, /b=forever".parse().unwrap();
+        policy.extend(&"/a=forever".parse().unwrap());
+        assert_eq!(policy.retention_for(&"/a".into()), Some(Retention::Forever));
+        assert_eq!(policy.retention_for(&"/b".into()), Some(Retention::Forever));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::hash_map::Entry;
+use std::hash::{Hash as _, Hasher as _};
+use std::sync::Arc;
+
+use ahash::HashMap;
+use arrow::array::{Array as _, ArrayData, ListArray as ArrowListArray};
+
+use re_byte_size::SizeBytes as _;
+use re_chunk::{Chunk, ChunkId};
+
+// ---
+
+/// Shares the data of identical static component columns between chunks.
+///
+/// Re-logging the same static data (e.g. the same mesh asset for every episode, or repeated `log_static`
+/// calls on different entities) would otherwise keep one copy of it per chunk.
+///
+/// Columns are identified by a hash of their content, and reference-counted so that the shared data lives
+/// exactly as long as the chunks using it.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StaticColumnDedup {
+    columns_per_hash: HashMap<u64, SharedColumn>,
+
+    /// The hashes of the columns of every chunk that went through [`Self::deduplicate`].
+    hashes_per_chunk_id: HashMap<ChunkId, Vec<u64>>,
+
+    /// How many bytes would be used by the duplicates, if they weren't shared.
+    pub(crate) num_bytes_saved: u64,
+}
+
+#[derive(Debug, Clone)]
+struct SharedColumn {
+    list_array: ArrowListArray,
+    num_bytes: u64,
+    num_refs: u64,
+}
+
+impl StaticColumnDedup {
+    /// Returns `chunk`, with every component column that's identical to one of another static chunk
+    /// replaced by a reference to the existing data.
+    pub(crate) fn deduplicate(&mut self, chunk: &Arc<Chunk>) -> Arc<Chunk> {
+        re_tracing::profile_function!();
+
+        let mut components = chunk.components().clone();
+        let mut hashes = Vec::with_capacity(components.len());
+        let mut num_deduplicated = 0;
+
+        for list_array in components.values_mut() {
+            let hash = hash_list_array(list_array);
+
+            match self.columns_per_hash.entry(hash) {
+                Entry::Occupied(mut entry) => {
+                    let shared = entry.get_mut();
+                    if shared.list_array != *list_array {
+                        // A hash collision: just keep this one to itself.
+                        continue;
+                    }
+
+                    shared.num_refs += 1;
+                    self.num_bytes_saved += shared.num_bytes;
+                    *list_array = shared.list_array.clone();
+                    num_deduplicated += 1;
+                }
+
+                Entry::Vacant(entry) => {
+                    entry.insert(SharedColumn {
+                        list_array: list_array.clone(),
+                        num_bytes: list_array.total_size_bytes(),
+                        num_refs: 1,
+                    });
+                }
+            }
+
+            hashes.push(hash);
+        }
+
+        self.hashes_per_chunk_id.insert(chunk.id(), hashes);
+
+        if num_deduplicated == 0 {
+            return Arc::clone(chunk);
+        }
+
+        let deduplicated = Chunk::new(
+            chunk.id(),
+            chunk.entity_path().clone(),
+            Some(chunk.is_sorted()),
+            chunk.row_ids_array().clone(),
+            chunk.timelines().clone(),
+            components,
+        );
+        match deduplicated {
+            Ok(deduplicated) => Arc::new(deduplicated),
+            Err(err) => {
+                re_log::error_once!("Failed to deduplicate static chunk: {err}");
+                Arc::clone(chunk)
+            }
+        }
+    }
+
+    /// Releases the columns of a chunk that was removed from the store.
+    pub(crate) fn release(&mut self, chunk_id: &ChunkId) {
+        let Some(hashes) = self.hashes_per_chunk_id.remove(chunk_id) else {
+            return;
+        };
+
+        for hash in hashes {
+            let Entry::Occupied(mut entry) = self.columns_per_hash.entry(hash) else {
+                continue;
+            };
+
+            let shared = entry.get_mut();
+            if shared.num_refs > 1 {
+                shared.num_refs -= 1;
+                self.num_bytes_saved = self.num_bytes_saved.saturating_sub(shared.num_bytes);
+            } else {
+                entry.remove();
+            }
+        }
+    }
+}
+
+/// A hash of the content of `list_array`.
+///
+/// Identical columns always hash the same if they are laid out the same in memory, which is the case
+/// for data that was logged the same way. Anything else is only a missed opportunity for sharing.
+fn hash_list_array(list_array: &ArrowListArray) -> u64 {
+    fn hash_array_data(data: &ArrayData, hasher: &mut ahash::AHasher) {
+        data.data_type().hash(hasher);
+        data.len().hash(hasher);
+        data.offset().hash(hasher);
+
+        if let Some(nulls) = data.nulls() {
+            nulls.offset().hash(hasher);
+            nulls.inner().values().hash(hasher);
+        }
+        for buffer in data.buffers() {
+            buffer.as_slice().hash(hasher);
+        }
+        for child_data in data.child_data() {
+            hash_array_data(child_data, hasher);
+        }
+    }
+
+    re_tracing::profile_function!();
+
+    let mut hasher = ahash::AHasher::default();
+    hash_array_data(&list_array.to_data(), &mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{EntityPath, RowId};
+    use re_log_types::example_components::{MyPoint, MyPoints};
+
+    use crate::ChunkStore;
+
+    use super::*;
+
+    #[test]
+    fn deduplicate_static_chunks() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+
+        let points: Vec<_> = (0..1000).map(|i| MyPoint::new(i as f32, 0.0)).collect();
+        let static_chunk = |entity_path: &str, points: Vec<MyPoint>| {
+            Chunk::builder(entity_path)
+                .with_component_batches(
+                    RowId::new(),
+                    re_log_types::TimePoint::STATIC,
+                    [(MyPoints::descriptor_points(), &points as _)],
+                )
+                .build()
+        };
+
+        store.insert_chunk(&Arc::new(static_chunk("episode_1/mesh", points.clone())?))?;
+        assert_eq!(store.stats().static_bytes_deduplicated, 0);
+
+        store.insert_chunk(&Arc::new(static_chunk("episode_2/mesh", points.clone())?))?;
+        store.insert_chunk(&Arc::new(static_chunk(
+            "episode_3/mesh",
+            points[1..].to_vec(),
+        )?))?;
+        let num_bytes_saved = store.stats().static_bytes_deduplicated;
+        assert!(num_bytes_saved > (points.len() * size_of::<MyPoint>()) as u64);
+
+        // The data is shared, and still reads back the same.
+        let column = |entity_path: &str| {
+            store
+                .iter_chunks()
+                .find(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+                .and_then(|chunk| {
+                    chunk
+                        .components()
+                        .get(&MyPoints::descriptor_points())
+                        .cloned()
+                })
+                .unwrap()
+        };
+        assert_eq!(
+            column("episode_1/mesh").offsets().as_ptr(),
+            column("episode_2/mesh").offsets().as_ptr(),
+        );
+        assert_eq!(column("episode_1/mesh"), column("episode_2/mesh"));
+
+        // Nothing is saved anymore once the duplicate is gone.
+        store.drop_entity_path(&EntityPath::from("episode_2/mesh"));
+        assert_eq!(store.stats().static_bytes_deduplicated, 0);
+
+        // Overwriting static data releases the data of the previous chunk too.
+        store.insert_chunk(&Arc::new(static_chunk("episode_3/mesh", points.clone())?))?;
+        assert_eq!(store.stats().static_bytes_deduplicated, num_bytes_saved);
+
+        Ok(())
+    }
+}
@@ -12,6 +12,19 @@ use crate::ChunkStore;
 pub struct ChunkStoreStats {
     pub static_chunks: ChunkStoreChunkStats,
     pub temporal_chunks: ChunkStoreChunkStats,
+
+    /// How many bytes of static data are shared between chunks rather than duplicated.
+    ///
+    /// These bytes are counted in [`Self::static_chunks`] for every chunk that uses them, but only take up
+    /// memory once.
+    pub static_bytes_deduplicated: u64,
+
+    /// How many bytes of data are in memory-mapped files rather than on the heap,
+    /// see [`crate::ChunkStoreBackend::MemoryMapped`].
+    ///
+    /// These bytes are counted in [`Self::static_chunks`] and [`Self::temporal_chunks`] with the size the
+    /// chunks had on the heap, but the operating system pages them in and out of RAM as needed.
+    pub bytes_memory_mapped: u64,
 }
 
 impl ChunkStoreStats {
@@ -20,6 +33,8 @@ impl ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            static_bytes_deduplicated: _,
+            bytes_memory_mapped: _,
         } = *self;
         static_chunks + temporal_chunks
     }
@@ -33,14 +48,20 @@ impl std::ops::Add for ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            static_bytes_deduplicated,
+            bytes_memory_mapped,
         } = self;
 
         let static_chunks = static_chunks + rhs.static_chunks;
         let temporal_chunks = temporal_chunks + rhs.temporal_chunks;
+        let static_bytes_deduplicated = static_bytes_deduplicated + rhs.static_bytes_deduplicated;
+        let bytes_memory_mapped = bytes_memory_mapped + rhs.bytes_memory_mapped;
 
         Self {
             static_chunks,
             temporal_chunks,
+            static_bytes_deduplicated,
+            bytes_memory_mapped,
         }
     }
 }
@@ -53,14 +74,20 @@ impl std::ops::Sub for ChunkStoreStats {
         let Self {
             static_chunks,
             temporal_chunks,
+            static_bytes_deduplicated,
+            bytes_memory_mapped,
         } = self;
 
         let static_chunks = static_chunks - rhs.static_chunks;
         let temporal_chunks = temporal_chunks - rhs.temporal_chunks;
+        let static_bytes_deduplicated = static_bytes_deduplicated - rhs.static_bytes_deduplicated;
+        let bytes_memory_mapped = bytes_memory_mapped - rhs.bytes_memory_mapped;
 
         Self {
             static_chunks,
             temporal_chunks,
+            static_bytes_deduplicated,
+            bytes_memory_mapped,
         }
     }
 }
@@ -71,6 +98,8 @@ impl ChunkStore {
         ChunkStoreStats {
             static_chunks: self.static_chunks_stats,
             temporal_chunks: self.temporal_chunks_stats,
+            static_bytes_deduplicated: self.static_column_dedup.num_bytes_saved,
+            bytes_memory_mapped: self.mmap_storage.num_bytes_mapped(),
         }
     }
 }
@@ -226,7 +255,7 @@ impl ChunkStore {
                 chunk_ids
                     .into_iter()
                     .filter_map(|chunk_id| self.chunks_per_chunk_id.get(&chunk_id))
-                    .map(ChunkStoreChunkStats::from_chunk)
+                    .map(|chunk| self.mmap_storage.chunk_stats(chunk))
                     .sum()
             },
         )
@@ -255,7 +284,7 @@ impl ChunkStore {
                         .values()
                         .flat_map(|chunk_ids| chunk_ids.iter())
                         .filter_map(|id| self.chunks_per_chunk_id.get(id))
-                        .map(ChunkStoreChunkStats::from_chunk)
+                        .map(|chunk| self.mmap_storage.chunk_stats(chunk))
                         .sum()
                 },
             )
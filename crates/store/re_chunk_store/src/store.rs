@@ -9,7 +9,10 @@ use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId, TimelineName};
 use re_log_types::{EntityPath, StoreId, StoreInfo, TimeInt, TimeType};
 use re_types_core::{ComponentDescriptor, ComponentType};
 
-use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult};
+use crate::{
+    ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult, entity_path_index::EntityPathIndex,
+    mmap_storage::MmapStorage, static_dedup::StaticColumnDedup,
+};
 
 // ---
 
@@ -72,6 +75,9 @@ pub struct ChunkStoreConfig {
     /// The default byte threshold is set to 8MiB, which is a reasonable unit of work when e.g.
     /// sending chunks over the network.
     pub chunk_max_rows_if_unsorted: u64,
+
+    /// Where the data of the chunks is kept, see [`ChunkStoreBackend`].
+    pub backend: ChunkStoreBackend,
     //
     // TODO(cmc): It could make sense to have time-range-based thresholds in here, since the time
     // range covered by a chunk has direct effects on A) the complexity of backward walks and
@@ -109,6 +115,8 @@ impl ChunkStoreConfig {
         chunk_max_rows: 4096,
 
         chunk_max_rows_if_unsorted: 1024,
+
+        backend: ChunkStoreBackend::Memory,
     };
 
     /// [`Self::DEFAULT`], but with compaction entirely disabled.
@@ -131,6 +139,7 @@ impl ChunkStoreConfig {
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
+        backend: ChunkStoreBackend::Memory,
     };
 
     /// Environment variable to configure [`Self::enable_changelog`].
@@ -147,6 +156,9 @@ impl ChunkStoreConfig {
     // NOTE: Shared with the same env-var on the batcher side, for consistency.
     pub const ENV_CHUNK_MAX_ROWS_IF_UNSORTED: &'static str = "RERUN_CHUNK_MAX_ROWS_IF_UNSORTED";
 
+    /// Environment variable to configure [`Self::backend`], e.g. `RERUN_CHUNK_STORE_BACKEND=mmap`.
+    pub const ENV_CHUNK_STORE_BACKEND: &'static str = "RERUN_CHUNK_STORE_BACKEND";
+
     /// Creates a new `ChunkStoreConfig` using the default values, optionally overridden
     /// through the environment.
     ///
@@ -159,8 +171,8 @@ impl ChunkStoreConfig {
     /// Returns a copy of `self`, overriding existing fields with values from the environment if
     /// they are present.
     ///
-    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`],
+    /// [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`] and [`Self::ENV_CHUNK_STORE_BACKEND`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -197,10 +209,48 @@ impl ChunkStoreConfig {
                 })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_CHUNK_STORE_BACKEND) {
+            new.backend = s
+                .parse()
+                .map_err(|err: String| ChunkStoreError::ParseConfig {
+                    name: Self::ENV_CHUNK_STORE_BACKEND,
+                    value: s.clone(),
+                    err: err.into(),
+                })?;
+        }
+
         Ok(new)
     }
 }
 
+/// Where a [`ChunkStore`] keeps the data of its chunks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkStoreBackend {
+    /// On the heap: fastest, but limited by the available RAM.
+    #[default]
+    Memory,
+
+    /// In memory-mapped temporary files, written as the chunks are inserted.
+    ///
+    /// Only the indices are kept in RAM, and the operating system pages the data in and out as it
+    /// is queried. This allows opening recordings far larger than RAM, at the cost of slower queries.
+    ///
+    /// Not supported on the web.
+    MemoryMapped,
+}
+
+impl std::str::FromStr for ChunkStoreBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "memory" => Ok(Self::Memory),
+            "mmap" => Ok(Self::MemoryMapped),
+            _ => Err(format!("expected `memory` or `mmap`, got {s:?}")),
+        }
+    }
+}
+
 #[test]
 fn chunk_store_config() {
     // Detect breaking changes in our environment variables.
@@ -212,6 +262,7 @@ fn chunk_store_config() {
         std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
+        std::env::set_var("RERUN_CHUNK_STORE_BACKEND", "mmap");
     };
 
     let config = ChunkStoreConfig::from_env().unwrap();
@@ -221,6 +272,7 @@ fn chunk_store_config() {
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
+        backend: ChunkStoreBackend::MemoryMapped,
     };
 
     assert_eq!(expected, config);
@@ -461,6 +513,14 @@ pub struct ChunkStore {
     /// This is too costly to be computed from scratch every frame, and is required by e.g. the GC.
     pub(crate) static_chunks_stats: ChunkStoreChunkStats,
 
+    /// All the entities with either static or temporal data, as a tree.
+    ///
+    /// Kept in sync with [`Self::static_chunk_ids_per_entity`] and [`Self::temporal_chunk_ids_per_entity`].
+    pub(crate) entity_path_index: EntityPathIndex,
+
+    /// Shares the data of identical static component columns, see [`StaticColumnDedup`].
+    pub(crate) static_column_dedup: StaticColumnDedup,
+
     /// Monotonically increasing ID for insertions.
     pub(crate) insert_id: u64,
 
@@ -469,6 +529,9 @@ pub struct ChunkStore {
 
     /// Monotonically increasing ID for store events.
     pub(crate) event_id: AtomicU64,
+
+    /// Where the chunks are written to with [`ChunkStoreBackend::MemoryMapped`].
+    pub(crate) mmap_storage: MmapStorage,
 }
 
 impl Drop for ChunkStore {
@@ -505,9 +568,12 @@ impl Clone for ChunkStore {
             temporal_chunks_stats: self.temporal_chunks_stats,
             static_chunk_ids_per_entity: self.static_chunk_ids_per_entity.clone(),
             static_chunks_stats: self.static_chunks_stats,
+            entity_path_index: self.entity_path_index.clone(),
+            static_column_dedup: self.static_column_dedup.clone(),
             insert_id: Default::default(),
             gc_id: Default::default(),
             event_id: Default::default(),
+            mmap_storage: self.mmap_storage.clone(),
         }
     }
 }
@@ -528,9 +594,12 @@ impl std::fmt::Display for ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity: _,
             static_chunks_stats,
+            entity_path_index: _,
+            static_column_dedup: _,
             insert_id: _,
             gc_id: _,
             event_id: _,
+            mmap_storage: _,
         } = self;
 
         f.write_str("ChunkStore {\n")?;
@@ -590,9 +659,12 @@ impl ChunkStore {
             temporal_chunks_stats: Default::default(),
             static_chunk_ids_per_entity: Default::default(),
             static_chunks_stats: Default::default(),
+            entity_path_index: Default::default(),
+            static_column_dedup: Default::default(),
             insert_id: 0,
             gc_id: 0,
             event_id: AtomicU64::new(0),
+            mmap_storage: Default::default(),
         }
     }
 
@@ -638,6 +710,15 @@ impl ChunkStore {
         &self.config
     }
 
+    /// Changes the compaction thresholds and other settings of the store.
+    ///
+    /// This only affects the chunks inserted from now on.
+    /// See [`Self::compact`] to apply new thresholds to the chunks already in the store.
+    #[inline]
+    pub fn set_config(&mut self, config: ChunkStoreConfig) {
+        self.config = config;
+    }
+
     /// Iterate over all chunks in the store, in ascending [`ChunkId`] order.
     #[inline]
     pub fn iter_chunks(&self) -> impl Iterator<Item = &Arc<Chunk>> + '_ {
@@ -754,6 +835,17 @@ impl ChunkStore {
                 }
 
                 re_log_types::LogMsg::BlueprintActivationCommand(_) => {}
+
+                re_log_types::LogMsg::DropTimeRange(cmd) => {
+                    if let Some(store) = stores.get_mut(&cmd.store_id) {
+                        store.drop_entity_time_range(
+                            &cmd.entity_path,
+                            cmd.recursive,
+                            &cmd.timeline,
+                            cmd.range,
+                        );
+                    }
+                }
             }
         }
 
@@ -802,6 +894,17 @@ impl ChunkStore {
                 }
 
                 re_log_types::LogMsg::BlueprintActivationCommand(_) => {}
+
+                re_log_types::LogMsg::DropTimeRange(cmd) => {
+                    if let Some(store) = stores.get_mut(&cmd.store_id) {
+                        store.drop_entity_time_range(
+                            &cmd.entity_path,
+                            cmd.recursive,
+                            &cmd.timeline,
+                            cmd.range,
+                        );
+                    }
+                }
             }
         }
 
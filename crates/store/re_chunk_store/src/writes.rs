@@ -8,8 +8,9 @@ use re_byte_size::SizeBytes;
 use re_chunk::{Chunk, EntityPath, RowId};
 
 use crate::{
-    ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiff, ChunkStoreError,
-    ChunkStoreEvent, ChunkStoreResult, ColumnMetadataState, store::ChunkIdSetPerTime,
+    ChunkStore, ChunkStoreBackend, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiff,
+    ChunkStoreError, ChunkStoreEvent, ChunkStoreResult, ColumnMetadataState,
+    store::ChunkIdSetPerTime,
 };
 
 // Used all over in docstrings.
@@ -65,7 +66,7 @@ impl ChunkStore {
 
         let non_compacted_chunk = Arc::clone(chunk); // we'll need it to create the store event
 
-        let (chunk, diffs) = if chunk.is_static() {
+        let (chunk, mut diffs) = if chunk.is_static() {
             // Static data: make sure to keep the most recent chunk available for each component column.
             re_tracing::profile_scope!("static");
 
@@ -182,15 +183,22 @@ impl ChunkStore {
                         debug_assert!(chunk_removed.is_some());
 
                         if let Some(chunk_removed) = chunk_removed {
-                            self.static_chunks_stats -=
-                                ChunkStoreChunkStats::from_chunk(&chunk_removed);
+                            self.static_chunks_stats -= self.mmap_storage.release(&chunk_removed);
+                            self.static_column_dedup.release(&chunk_id);
                             diffs.push(ChunkStoreDiff::deletion(chunk_removed));
                         }
                     }
                 }
             }
 
-            (Arc::clone(chunk), diffs)
+            // Memory-mapped chunks are off the heap already: there's nothing to share.
+            let chunk = if self.config.backend == ChunkStoreBackend::Memory {
+                self.static_column_dedup.deduplicate(chunk)
+            } else {
+                Arc::clone(chunk)
+            };
+
+            (chunk, diffs)
         } else {
             // Temporal data: just index the chunk on every dimension of interest.
             re_tracing::profile_scope!("temporal");
@@ -346,7 +354,22 @@ impl ChunkStore {
             (chunk_or_compacted, vec![diff])
         };
 
+        // Subscribers get the offloaded chunk too, or whatever they keep of it would stay on the heap.
+        let chunk = self.offload_chunk(chunk);
+        for diff in &mut diffs {
+            if diff.chunk.id() == chunk.id() {
+                diff.chunk = Arc::clone(&chunk);
+            }
+            if let Some(compacted) = &mut diff.compacted
+                && compacted.new_chunk.id() == chunk.id()
+            {
+                compacted.new_chunk = Arc::clone(&chunk);
+            }
+        }
+
         self.chunks_per_chunk_id.insert(chunk.id(), chunk.clone());
+        self.offload_pending_chunks();
+        self.entity_path_index.insert(chunk.entity_path());
         // NOTE: ⚠️Make sure to recompute the Row ID range! The chunk might have been compacted
         // with another one, which might or might not have modified the range.
         if let Some(min_row_id) = chunk.row_id_range().map(|(min, _)| min)
@@ -452,7 +475,12 @@ impl ChunkStore {
     /// Everytime we encounter a neighbor, it earns points.
     ///
     /// The neighbor with the most points at the end of the process is elected.
-    fn find_and_elect_compaction_candidate(&self, chunk: &Arc<Chunk>) -> Option<Arc<Chunk>> {
+    ///
+    /// The chunk itself is never elected, in case it is already in the store, see [`Self::compact`].
+    pub(crate) fn find_and_elect_compaction_candidate(
+        &self,
+        chunk: &Arc<Chunk>,
+    ) -> Option<Arc<Chunk>> {
         re_tracing::profile_function!();
 
         {
@@ -464,6 +492,7 @@ impl ChunkStore {
                 chunk_max_bytes,
                 chunk_max_rows,
                 chunk_max_rows_if_unsorted,
+                backend: _,
             } = self.config;
 
             let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk);
@@ -489,8 +518,13 @@ impl ChunkStore {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    backend: _,
                 } = store.config;
 
+                if candidate_chunk_id == chunk.id() {
+                    return false;
+                }
+
                 *candidates_below_threshold
                     .entry(candidate_chunk_id)
                     .or_insert_with(|| {
@@ -503,7 +537,7 @@ impl ChunkStore {
                                 }
 
                                 let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk)
-                                    + <Chunk as SizeBytes>::total_size_bytes(candidate);
+                                    + store.mmap_storage.chunk_size_bytes(candidate);
                                 let is_below_bytes_threshold = total_bytes <= chunk_max_bytes;
 
                                 let total_rows = (chunk.num_rows() + candidate.num_rows()) as u64;
@@ -598,7 +632,7 @@ impl ChunkStore {
     ///
     /// Returns the list of `Chunk`s that were dropped from the store in the form of [`ChunkStoreEvent`]s.
     ///
-    /// This is _not_ recursive. The store is unaware of the entity hierarchy.
+    /// This is _not_ recursive: the descendants of `entity_path` are left untouched.
     pub fn drop_entity_path(&mut self, entity_path: &EntityPath) -> Vec<ChunkStoreEvent> {
         re_tracing::profile_function!(entity_path.to_string());
 
@@ -620,12 +654,16 @@ impl ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity,
             static_chunks_stats,
+            entity_path_index,
+            static_column_dedup,
             insert_id: _,
             gc_id: _,
             event_id,
+            mmap_storage,
         } = self;
 
         per_column_metadata.remove(entity_path);
+        entity_path_index.remove(entity_path);
 
         let dropped_static_chunks = {
             let dropped_static_chunk_ids: BTreeSet<_> = static_chunk_ids_per_entity
@@ -681,7 +719,8 @@ impl ChunkStore {
         let dropped_static_chunks = dropped_static_chunks
             .filter_map(|chunk_id| chunks_per_chunk_id.remove(&chunk_id))
             .inspect(|chunk| {
-                *static_chunks_stats -= ChunkStoreChunkStats::from_chunk(chunk);
+                *static_chunks_stats -= mmap_storage.release(chunk);
+                static_column_dedup.release(&chunk.id());
             })
             // NOTE: gotta collect to release the mut ref on `chunks_per_chunk_id`.
             .collect_vec();
@@ -689,7 +728,7 @@ impl ChunkStore {
         let dropped_temporal_chunks = dropped_temporal_chunks
             .filter_map(|chunk_id| chunks_per_chunk_id.remove(&chunk_id))
             .inspect(|chunk| {
-                *temporal_chunks_stats -= ChunkStoreChunkStats::from_chunk(chunk);
+                *temporal_chunks_stats -= mmap_storage.release(chunk);
             });
 
         if self.config.enable_changelog {
@@ -725,7 +764,7 @@ mod tests {
     };
     use similar_asserts::assert_eq;
 
-    use crate::ChunkStoreDiffKind;
+    use crate::{ChunkStoreBackend, ChunkStoreDiffKind};
 
     use super::*;
 
@@ -1177,6 +1216,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    backend: ChunkStoreBackend::Memory,
                 },
             );
 
@@ -1206,6 +1246,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    backend: ChunkStoreBackend::Memory,
                 },
             );
 
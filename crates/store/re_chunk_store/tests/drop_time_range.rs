@@ -128,3 +128,52 @@ fn drop_time_range() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn drop_entity_time_range() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let timeline = Timeline::new_sequence("timeline");
+    let data = MyColor::from_rgb(255, 0, 0);
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let entity_paths = ["camera", "camera/image", "lidar"].map(EntityPath::from);
+    for entity_path in &entity_paths {
+        let mut builder = Chunk::builder(entity_path.clone());
+        for time in 0..10_i64 {
+            builder = builder.with_component_batch(
+                RowId::new(),
+                TimePoint::from([(timeline, time)]),
+                (MyPoints::descriptor_colors(), &data),
+            );
+        }
+        store.insert_chunk(&Arc::new(builder.build()?))?;
+    }
+
+    let num_events = |store: &ChunkStore, entity_path: &EntityPath| {
+        store.num_temporal_events_for_component_on_timeline(
+            timeline.name(),
+            entity_path,
+            &MyPoints::descriptor_colors(),
+        )
+    };
+    let [camera, image, lidar] = &entity_paths;
+
+    // Only the entity itself:
+    store.drop_entity_time_range(camera, false, timeline.name(), AbsoluteTimeRange::new(2, 4));
+    assert_eq!(num_events(&store, camera), 7);
+    assert_eq!(num_events(&store, image), 10);
+    assert_eq!(num_events(&store, lidar), 10);
+
+    // The whole subtree:
+    store.drop_entity_time_range(camera, true, timeline.name(), AbsoluteTimeRange::new(3, 5));
+    assert_eq!(num_events(&store, camera), 6);
+    assert_eq!(num_events(&store, image), 7);
+    assert_eq!(num_events(&store, lidar), 10);
+
+    Ok(())
+}
@@ -69,10 +69,10 @@ fn memory_use<R>(run: impl Fn() -> R) -> (usize, usize) {
 // ----------------------------------------------------------------------------
 
 use re_chunk::{
-    BatcherHooks, ChunkBatcher, ChunkBatcherConfig, PendingRow,
+    BatcherHooks, Chunk, ChunkBatcher, ChunkBatcherConfig, PendingRow, RowId,
     external::crossbeam::channel::TryRecvError,
 };
-use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_chunk_store::{ChunkStore, ChunkStoreBackend, ChunkStoreConfig};
 use re_log_types::{TimePoint, Timeline};
 use re_types::{Loggable as _, archetypes, components::Scalar};
 
@@ -120,7 +120,7 @@ fn scalar_memory_overhead() {
 
         loop {
             let chunk = match chunks_rx.try_recv() {
-                Ok(chunk) => chunk,
+                Ok((_, chunk)) => chunk,
                 Err(TryRecvError::Empty) => panic!("expected chunk, got none"),
                 Err(TryRecvError::Disconnected) => break,
             };
@@ -150,3 +150,58 @@ fn scalar_memory_overhead() {
         ]
     );
 }
+
+/// The data of a memory-mapped store is kept off the heap.
+#[test]
+fn mmap_backend_heap_usage() {
+    re_log::setup_logging();
+
+    const NUM_CHUNKS: usize = 64;
+    const NUM_SCALARS_PER_CHUNK: usize = 32 * 1024;
+
+    let heap_usage = |backend: ChunkStoreBackend| {
+        // Everything happens on this thread, so other tests running in parallel don't matter.
+        let (_total_mem_use_global, total_mem_use_local) = memory_use(|| {
+            let mut store = ChunkStore::new(
+                re_log_types::StoreId::random(re_log_types::StoreKind::Recording, "test_app"),
+                ChunkStoreConfig {
+                    backend,
+                    ..ChunkStoreConfig::COMPACTION_DISABLED
+                },
+            );
+
+            for i in 0..NUM_CHUNKS {
+                let scalars = vec![Scalar::from(i as f64); NUM_SCALARS_PER_CHUNK];
+                let chunk = Chunk::builder("scalars")
+                    .with_component_batches(
+                        RowId::new(),
+                        [(Timeline::log_time(), i as i64)],
+                        [(archetypes::Scalars::descriptor_scalars(), &scalars as _)],
+                    )
+                    .build()
+                    .unwrap();
+                _ = store.insert_chunk(&Arc::new(chunk)).unwrap();
+            }
+
+            store
+        });
+        total_mem_use_local
+    };
+
+    let heap_usage_in_memory = heap_usage(ChunkStoreBackend::Memory);
+    let heap_usage_mmap = heap_usage(ChunkStoreBackend::MemoryMapped);
+
+    let num_bytes_of_data = NUM_CHUNKS * NUM_SCALARS_PER_CHUNK * std::mem::size_of::<f64>();
+    assert!(
+        heap_usage_in_memory >= num_bytes_of_data,
+        "{} on the heap for {} of data",
+        re_format::format_bytes(heap_usage_in_memory as _),
+        re_format::format_bytes(num_bytes_of_data as _),
+    );
+    assert!(
+        heap_usage_mmap * 10 < heap_usage_in_memory,
+        "{} on the heap with memory-mapping, {} without",
+        re_format::format_bytes(heap_usage_mmap as _),
+        re_format::format_bytes(heap_usage_in_memory as _),
+    );
+}
@@ -20,7 +20,7 @@ pub fn load_from_path(
     settings: &crate::DataLoaderSettings,
     file_source: FileSource,
     path: &std::path::Path,
-    // NOTE: This channel must be unbounded since we serialize all operations when running on wasm.
+    // NOTE: This channel may be bounded, since the data is forwarded from another thread.
     tx: &Sender<LogMsg>,
 ) -> Result<(), DataLoaderError> {
     use re_log_types::ApplicationId;
@@ -305,7 +305,9 @@ pub(crate) fn send(
                             LogMsg::ArrowMsg(store_id, _arrow_msg) => {
                                 Some((store_id.clone(), false))
                             }
-                            LogMsg::BlueprintActivationCommand(_) => None,
+                            LogMsg::BlueprintActivationCommand(_) | LogMsg::DropTimeRange(_) => {
+                                None
+                            }
                         };
 
                         if let Some((store_id, store_info_created)) = store_info {
@@ -1,4 +1,4 @@
-use re_log_encoding::decoder::Decoder;
+use re_log_encoding::decoder::{DecodeError, Decoder, RrdFileReader};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crossbeam::channel::Receiver;
@@ -76,6 +76,30 @@ impl crate::DataLoader for RrdLoader {
             }
 
             "rrd" => {
+                let file = std::fs::File::open(&filepath)
+                    .with_context(|| format!("Failed to open file {filepath:?}"))?;
+
+                // Files that end with an index are complete: read them in order of importance,
+                // so that the first data shows up as early as possible.
+                if let Some(reader) = RrdFileReader::new_indexed(std::io::BufReader::new(file))? {
+                    // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
+                    std::thread::Builder::new()
+                        .name(format!("decode_and_stream({filepath:?})"))
+                        .spawn({
+                            let filepath = filepath.clone();
+                            move || {
+                                decode_and_stream(
+                                    &filepath, &tx, reader,
+                                    // Never use import semantics for .rrd files
+                                    None, None,
+                                );
+                            }
+                        })
+                        .with_context(|| format!("Failed to spawn IO thread for {filepath:?}"))?;
+
+                    return Ok(());
+                }
+
                 // For .rrd files we retry reading despite reaching EOF to support live (writer) streaming.
                 // Decoder will give up when it sees end of file marker (i.e. end-of-stream message header)
                 let retryable_reader = RetryableFileReader::new(&filepath).with_context(|| {
@@ -120,12 +144,11 @@ impl crate::DataLoader for RrdLoader {
         }
 
         let contents = std::io::Cursor::new(contents);
-        let decoder = match re_log_encoding::decoder::Decoder::new(contents) {
+        let decoder = match RrdFileReader::new(contents) {
             Ok(decoder) => decoder,
             Err(err) => match err {
                 // simply not interested
-                re_log_encoding::decoder::DecodeError::NotAnRrd
-                | re_log_encoding::decoder::DecodeError::Options(_) => return Ok(()),
+                DecodeError::NotAnRrd | DecodeError::Options(_) => return Ok(()),
                 _ => return Err(err.into()),
             },
         };
@@ -154,10 +177,10 @@ impl crate::DataLoader for RrdLoader {
     }
 }
 
-fn decode_and_stream<R: std::io::Read>(
+fn decode_and_stream(
     filepath: &std::path::Path,
     tx: &std::sync::mpsc::Sender<crate::LoadedData>,
-    decoder: Decoder<R>,
+    decoder: impl Iterator<Item = Result<re_log_types::LogMsg, DecodeError>>,
     forced_application_id: Option<&ApplicationId>,
     forced_recording_id: Option<&String>,
 ) {
@@ -206,6 +229,21 @@ fn decode_and_stream<R: std::io::Read>(
                 re_log_types::LogMsg::BlueprintActivationCommand(blueprint_activation_command) => {
                     re_log_types::LogMsg::BlueprintActivationCommand(blueprint_activation_command)
                 }
+
+                re_log_types::LogMsg::DropTimeRange(mut drop_time_range_command) => {
+                    if let Some(forced_application_id) = forced_application_id {
+                        drop_time_range_command.store_id = drop_time_range_command
+                            .store_id
+                            .with_application_id(forced_application_id.clone());
+                    }
+                    if let Some(forced_recording_id) = forced_recording_id {
+                        drop_time_range_command.store_id = drop_time_range_command
+                            .store_id
+                            .with_recording_id(forced_recording_id.clone());
+                    }
+
+                    re_log_types::LogMsg::DropTimeRange(drop_time_range_command)
+                }
             }
         } else {
             msg
@@ -148,9 +148,36 @@ impl LogDataSource {
         connection_registry: &ConnectionRegistryHandle,
         on_ui_cmd: Option<Box<dyn Fn(re_redap_client::UiCommand) + Send + Sync>>,
         on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+    ) -> anyhow::Result<Receiver<LogMsg>> {
+        self.stream_with_capacity(connection_registry, on_ui_cmd, on_msg, None)
+    }
+
+    /// Like [`Self::stream`], but local files and standard input are streamed through a channel
+    /// holding at most `channel_capacity` messages, see [`re_smart_channel::smart_channel_bounded`].
+    ///
+    /// Loading then waits for the receiver to catch up, instead of reading everything into memory
+    /// up front. Other sources ignore the capacity.
+    pub fn stream_with_capacity(
+        self,
+        connection_registry: &ConnectionRegistryHandle,
+        on_ui_cmd: Option<Box<dyn Fn(re_redap_client::UiCommand) + Send + Sync>>,
+        on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+        channel_capacity: Option<usize>,
     ) -> anyhow::Result<Receiver<LogMsg>> {
         re_tracing::profile_function!();
 
+        // Only local sources are loaded on background threads, which can wait for room.
+        #[cfg(not(target_arch = "wasm32"))]
+        let local_channel = |sender_source, source| {
+            if let Some(capacity) = channel_capacity {
+                re_smart_channel::smart_channel_bounded(sender_source, source, capacity)
+            } else {
+                re_smart_channel::smart_channel(sender_source, source)
+            }
+        };
+        #[cfg(target_arch = "wasm32")]
+        let _ = channel_capacity;
+
         match self {
             Self::RrdHttpUrl { url, follow } => Ok(
                 re_log_encoding::stream_rrd_from_http::stream_rrd_from_http_to_channel(
@@ -162,7 +189,7 @@ impl LogDataSource {
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(file_source, path) => {
-                let (tx, rx) = re_smart_channel::smart_channel(
+                let (tx, rx) = local_channel(
                     SmartMessageSource::File(path.clone()),
                     SmartChannelSource::File(path.clone()),
                 );
@@ -220,10 +247,7 @@ impl LogDataSource {
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::Stdin => {
-                let (tx, rx) = re_smart_channel::smart_channel(
-                    SmartMessageSource::Stdin,
-                    SmartChannelSource::Stdin,
-                );
+                let (tx, rx) = local_channel(SmartMessageSource::Stdin, SmartChannelSource::Stdin);
 
                 crate::load_stdin::load_stdin(tx).with_context(|| "stdin".to_owned())?;
 
@@ -270,7 +294,16 @@ impl LogDataSource {
                 Ok(rx)
             }
 
-            Self::RedapProxy(uri) => Ok(re_grpc_client::stream(uri, on_msg)),
+            Self::RedapProxy(uri) => {
+                // A token saved in the viewer for this server takes precedence over the environment.
+                let auth_token = connection_registry
+                    .token(&uri.origin)
+                    .map(|jwt| jwt.as_str().to_owned())
+                    .or_else(re_grpc_client::auth_token_from_env);
+                Ok(re_grpc_client::read::stream_with_auth_token(
+                    uri, auth_token, on_msg,
+                ))
+            }
         }
     }
 }
@@ -98,9 +98,10 @@ impl<E: StorageEngineLike + Clone> QueryEngine<E> {
         let filter = filter.clone().resolve_without_substitutions();
         self.engine.with(|store, _cache| {
             store
-                .all_entities_sorted()
+                .entities_matching(&filter)
+                .cloned()
+                .collect::<Vec<_>>()
                 .into_iter()
-                .filter(move |entity_path| filter.matches(entity_path))
         })
     }
 }
@@ -0,0 +1,281 @@
+//! Aligning the rows of two dataframe queries on a shared index, e.g. a ground truth and an estimate that were
+//! logged to different recordings, so that they can be compared row by row.
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{
+        ArrayRef as ArrowArrayRef, AsArray as _, RecordBatch as ArrowRecordBatch,
+        UInt64Array as ArrowUInt64Array,
+    },
+    buffer::ScalarBuffer as ArrowScalarBuffer,
+    compute::{cast, concat_batches, take},
+    datatypes::{DataType as ArrowDataType, Int64Type, Schema as ArrowSchema},
+    error::ArrowError,
+};
+
+use re_chunk::TimelineName;
+use re_chunk_store::ColumnDescriptor;
+use re_query::StorageEngineLike;
+
+use crate::QueryHandle;
+
+// ---
+
+#[derive(thiserror::Error, Debug)]
+pub enum JoinError {
+    #[error("Both queries must have a filtered index, which is the timeline they are joined on")]
+    MissingIndex,
+
+    #[error("Can't join queries on different indices: {0:?} and {1:?}")]
+    IndexMismatch(TimelineName, TimelineName),
+
+    #[error("The index {0:?} must be part of the selection of both queries")]
+    IndexNotSelected(TimelineName),
+
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+}
+
+/// Which row of the right query is matched with each row of the left query, see [`join`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JoinStrategy {
+    /// The last row at or before the time of the left row.
+    #[default]
+    AsOf,
+
+    /// The row closest in time to the left row, before or after it.
+    ///
+    /// Ties go to the earlier row.
+    Nearest,
+}
+
+/// How to [`join`] two queries.
+#[derive(Debug, Clone)]
+pub struct JoinOptions {
+    pub strategy: JoinStrategy,
+
+    /// Rows further apart in time than this are never matched.
+    ///
+    /// In the unit of the index: sequence numbers, or nanoseconds.
+    pub tolerance: Option<u64>,
+
+    /// Prepended to the names of the columns of the right query, so that they don't clash with the left ones.
+    pub right_prefix: String,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        Self {
+            strategy: JoinStrategy::default(),
+            tolerance: None,
+            right_prefix: "right:".to_owned(),
+        }
+    }
+}
+
+/// Joins the rows of `right` onto the rows of `left`, on the index both queries are filtered on.
+///
+/// The result has one row per row of `left`, with all the columns of `left` followed by all the columns of
+/// `right`, renamed with [`JoinOptions::right_prefix`].
+/// The columns of `right` are null where no row matched, including its index column, which otherwise
+/// holds the time of the matched row.
+///
+/// Both queries are run to completion.
+pub fn join<L: StorageEngineLike, R: StorageEngineLike>(
+    left: &QueryHandle<L>,
+    right: &QueryHandle<R>,
+    options: &JoinOptions,
+) -> Result<ArrowRecordBatch, JoinError> {
+    re_tracing::profile_function!();
+
+    let (Some(left_index), Some(right_index)) =
+        (left.query().filtered_index, right.query().filtered_index)
+    else {
+        return Err(JoinError::MissingIndex);
+    };
+    if left_index != right_index {
+        return Err(JoinError::IndexMismatch(left_index, right_index));
+    }
+
+    let left_batch = run_to_completion(left)?;
+    let left_times = index_values(left, &left_batch, left_index)?;
+    let right_batch = run_to_completion(right)?;
+    let right_times = index_values(right, &right_batch, right_index)?;
+
+    let indices: ArrowUInt64Array = left_times
+        .iter()
+        .map(|&time| matching_row(&right_times, time, options).map(|row_index| row_index as u64))
+        .collect();
+
+    let mut fields = left_batch.schema().fields().to_vec();
+    fields.extend(right_batch.schema().fields().iter().map(|field| {
+        Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_name(format!("{}{}", options.right_prefix, field.name()))
+                .with_nullable(true),
+        )
+    }));
+
+    let mut columns = left_batch.columns().to_vec();
+    for column in right_batch.columns() {
+        columns.push(take(column.as_ref(), &indices, None)?);
+    }
+
+    Ok(ArrowRecordBatch::try_new(
+        Arc::new(ArrowSchema::new_with_metadata(
+            fields,
+            left_batch.schema().metadata().clone(),
+        )),
+        columns,
+    )?)
+}
+
+/// The index of the row of `times` (which must be sorted) that matches `time`.
+fn matching_row(times: &[i64], time: i64, options: &JoinOptions) -> Option<usize> {
+    let num_at_or_before = times.partition_point(|&t| t <= time);
+    let before = num_at_or_before.checked_sub(1);
+
+    let row_index = match options.strategy {
+        JoinStrategy::AsOf => before?,
+
+        JoinStrategy::Nearest => {
+            let after = (num_at_or_before < times.len()).then_some(num_at_or_before);
+            match (before, after) {
+                (Some(before), Some(after)) => {
+                    if time.abs_diff(times[before]) <= times[after].abs_diff(time) {
+                        before
+                    } else {
+                        after
+                    }
+                }
+                (Some(row_index), None) | (None, Some(row_index)) => row_index,
+                (None, None) => return None,
+            }
+        }
+    };
+
+    let is_within_tolerance = options
+        .tolerance
+        .is_none_or(|tolerance| times[row_index].abs_diff(time) <= tolerance);
+    is_within_tolerance.then_some(row_index)
+}
+
+fn run_to_completion<E: StorageEngineLike>(
+    query_handle: &QueryHandle<E>,
+) -> Result<ArrowRecordBatch, ArrowError> {
+    query_handle.seek_to_row(0);
+    let rows: Vec<_> = query_handle.batch_iter().collect();
+    concat_batches(query_handle.schema(), &rows)
+}
+
+/// The values of the index column of the results of the query, in order.
+fn index_values<E: StorageEngineLike>(
+    query_handle: &QueryHandle<E>,
+    batch: &ArrowRecordBatch,
+    index: TimelineName,
+) -> Result<ArrowScalarBuffer<i64>, JoinError> {
+    let column_index = query_handle
+        .selected_contents()
+        .iter()
+        .position(|(_, column)| {
+            matches!(column, ColumnDescriptor::Time(descr) if descr.timeline_name() == index)
+        })
+        .ok_or(JoinError::IndexNotSelected(index))?;
+
+    let column: &ArrowArrayRef = batch.column(column_index);
+    let times = cast(column, &ArrowDataType::Int64)?;
+
+    Ok(times.as_primitive::<Int64Type>().values().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::Array as _;
+
+    use re_chunk::{Chunk, RowId};
+    use re_chunk_store::{ChunkStore, ChunkStoreHandle, QueryExpression};
+    use re_log_types::{
+        StoreId, StoreKind, Timeline,
+        example_components::{MyPoint, MyPoints},
+    };
+
+    use crate::QueryEngine;
+
+    use super::*;
+
+    fn points_engine(frames: &[i64]) -> anyhow::Result<QueryEngine<re_query::StorageEngine>> {
+        let mut store = ChunkStore::new(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            Default::default(),
+        );
+        for &frame in frames {
+            let chunk = Chunk::builder("points")
+                .with_component_batches(
+                    RowId::new(),
+                    [(Timeline::new_sequence("frame"), frame)],
+                    [(
+                        MyPoints::descriptor_points(),
+                        &[MyPoint::new(frame as f32, 0.0)] as _,
+                    )],
+                )
+                .build()?;
+            store.insert_chunk(&Arc::new(chunk))?;
+        }
+        Ok(QueryEngine::from_store(ChunkStoreHandle::new(store)))
+    }
+
+    /// The frame of the matched right row, for each left row.
+    fn matched_frames(batch: &ArrowRecordBatch) -> Vec<Option<i64>> {
+        let column = batch.column_by_name("right:frame").unwrap();
+        let frames = cast(column, &ArrowDataType::Int64).unwrap();
+        let frames = frames.as_primitive::<Int64Type>();
+        (0..frames.len())
+            .map(|i| frames.is_valid(i).then(|| frames.value(i)))
+            .collect()
+    }
+
+    #[test]
+    fn join_asof_and_nearest() -> anyhow::Result<()> {
+        let ground_truth = points_engine(&[0, 10, 20])?;
+        let estimate = points_engine(&[1, 9, 22])?;
+
+        let query = QueryExpression {
+            filtered_index: Some("frame".into()),
+            ..Default::default()
+        };
+        let left = ground_truth.query(query.clone());
+        let right = estimate.query(query);
+
+        let joined = join(&left, &right, &JoinOptions::default())?;
+        assert_eq!(joined.num_rows(), 3);
+        assert_eq!(matched_frames(&joined), vec![None, Some(9), Some(9)]);
+
+        let options = JoinOptions {
+            strategy: JoinStrategy::Nearest,
+            ..Default::default()
+        };
+        let joined = join(&left, &right, &options)?;
+        assert_eq!(matched_frames(&joined), vec![Some(1), Some(9), Some(22)]);
+
+        let options = JoinOptions {
+            strategy: JoinStrategy::Nearest,
+            tolerance: Some(1),
+            ..Default::default()
+        };
+        let joined = join(&left, &right, &options)?;
+        assert_eq!(matched_frames(&joined), vec![Some(1), Some(9), None]);
+
+        let static_only = estimate.query(QueryExpression::default());
+        assert!(matches!(
+            join(&left, &static_only, &JoinOptions::default()),
+            Err(JoinError::MissingIndex)
+        ));
+
+        Ok(())
+    }
+}
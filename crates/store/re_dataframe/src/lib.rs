@@ -1,10 +1,14 @@
 //! The Rerun public data APIs. Get dataframes back from your Rerun datastore.
 
 mod engine;
+mod join;
 mod query;
+mod text_query;
 
 pub use self::engine::QueryEngine;
+pub use self::join::{JoinError, JoinOptions, JoinStrategy, join};
 pub use self::query::QueryHandle;
+pub use self::text_query::{TextQuery, TextQueryError};
 
 #[doc(no_inline)]
 pub use self::external::re_chunk_store::{
@@ -0,0 +1,566 @@
+//! A textual, SQL-like way of writing a [`QueryExpression`].
+//!
+//! ```text
+//! SELECT frame_nr, /world/points:Points3D:positions
+//! FROM /world/**
+//! EXCEPT /world/debug/**
+//! WHERE frame_nr BETWEEN 10 AND 20 AND /world/points:Points3D:positions IS NOT NULL
+//! FILL LATEST
+//! ```
+//!
+//! Supported clauses, in this order:
+//! * `SELECT *` or `SELECT <column>, …`: a column is either `rowid`, a timeline name,
+//!   or `<entity_path>:<component>`.
+//! * `FROM <entity path expression>, …`: the entities to query, e.g. `/world/**`.
+//! * `EXCEPT <entity path expression>, …` (optional): entities to leave out.
+//! * `WHERE <condition> AND …` (optional), with these conditions:
+//!   * `<timeline> BETWEEN <time> AND <time>`,
+//!   * `<timeline> <op> <time>`, where `<op>` is one of `=`, `<`, `<=`, `>`, `>=`,
+//!   * `<timeline> IN (<time>, …)`,
+//!   * `<entity_path>:<component> IS NOT NULL`.
+//! * `ORDER BY <timeline>` (optional): the index of the rows, if no condition on a timeline is given.
+//! * `FILL LATEST` (optional): fills empty cells with their latest-at value.
+//!
+//! Times are integers on sequence timelines, and nanoseconds on temporal timelines,
+//! unless a unit is given, e.g. `1.5s` or `200ms`.
+//! Without a condition on a timeline nor `ORDER BY`, only static data is returned.
+//!
+//! Keywords are case-insensitive. Names containing spaces or commas can be quoted with `'` or `"`.
+
+use std::collections::BTreeSet;
+
+use re_chunk_store::{QueryExpression, SparseFillStrategy};
+use re_log_types::{AbsoluteTimeRange, EntityPathFilter, TimeInt, TimelineName};
+use re_query::StorageEngineLike;
+use re_sorbet::{ColumnSelector, ComponentColumnSelector, TimeColumnSelector};
+
+use crate::{QueryEngine, QueryHandle};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum TextQueryError {
+    #[error("Expected {expected}, found the end of the query")]
+    UnexpectedEnd { expected: &'static str },
+
+    #[error("Expected {expected}, found {found:?}")]
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error("Unterminated quote")]
+    UnterminatedQuote,
+
+    #[error("Invalid time {0:?}, expected e.g. `42`, `1.5s` or `200ms`")]
+    InvalidTime(String),
+
+    #[error("Only one timeline can be filtered on, found both {0:?} and {1:?}")]
+    MultipleTimelines(TimelineName, TimelineName),
+
+    #[error("Only one column can be filtered on with `IS NOT NULL`")]
+    MultipleNotNullColumns,
+}
+
+/// A parsed textual query, see the [module docs](self).
+///
+/// Turned into a [`QueryExpression`] with [`Self::to_query_expression`],
+/// or run right away with [`QueryEngine::query_text`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextQuery {
+    /// The entities in the `FROM` and `EXCEPT` clauses.
+    pub entity_path_filter: EntityPathFilter,
+
+    /// The columns of the `SELECT` clause, `None` for `*`.
+    pub selection: Option<Vec<ColumnSelector>>,
+
+    pub filtered_index: Option<TimelineName>,
+
+    /// `None` if no range condition was given.
+    pub filtered_index_range: Option<AbsoluteTimeRange>,
+
+    pub filtered_index_values: Option<BTreeSet<TimeInt>>,
+
+    pub filtered_is_not_null: Option<ComponentColumnSelector>,
+
+    pub sparse_fill_strategy: SparseFillStrategy,
+}
+
+impl std::str::FromStr for TextQuery {
+    type Err = TextQueryError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+        }
+        .query()
+    }
+}
+
+impl TextQuery {
+    /// The query expression over all entities of the engine that match [`Self::entity_path_filter`].
+    pub fn to_query_expression<E: StorageEngineLike + Clone>(
+        &self,
+        engine: &QueryEngine<E>,
+    ) -> QueryExpression {
+        QueryExpression {
+            view_contents: Some(
+                engine
+                    .iter_entity_paths_sorted(&self.entity_path_filter)
+                    .map(|entity_path| (entity_path, None))
+                    .collect(),
+            ),
+            filtered_index: self.filtered_index,
+            filtered_index_range: self.filtered_index_range,
+            filtered_index_values: self.filtered_index_values.clone(),
+            filtered_is_not_null: self.filtered_is_not_null.clone(),
+            sparse_fill_strategy: self.sparse_fill_strategy.clone(),
+            selection: self.selection.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<E: StorageEngineLike + Clone> QueryEngine<E> {
+    /// Parses a textual query and starts it, see [`TextQuery`].
+    pub fn query_text(&self, text: &str) -> Result<QueryHandle<E>, TextQueryError> {
+        let query: TextQuery = text.parse()?;
+        Ok(self.query(query.to_query_expression(self)))
+    }
+}
+
+// ---
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// A keyword, name, path or number.
+    Word { text: String, quoted: bool },
+
+    /// `,`, `(`, `)`, or a comparison operator.
+    Symbol(&'static str),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word { text, .. } => text.clone(),
+            Self::Symbol(symbol) => (*symbol).to_owned(),
+        }
+    }
+}
+
+const SYMBOLS: [&str; 8] = [",", "(", ")", "<=", ">=", "<", ">", "="];
+
+fn tokenize(text: &str) -> Result<Vec<Token>, TextQueryError> {
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else if c == '\'' || c == '"' {
+            let end = rest[1..].find(c).ok_or(TextQueryError::UnterminatedQuote)?;
+            tokens.push(Token::Word {
+                text: rest[1..=end].to_owned(),
+                quoted: true,
+            });
+            rest = &rest[end + 2..];
+        } else {
+            let end = rest
+                .find(|c: char| {
+                    c.is_whitespace() || SYMBOLS.iter().any(|symbol| symbol.starts_with(c))
+                })
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word {
+                text: rest[..end].to_owned(),
+                quoted: false,
+            });
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self, expected: &'static str) -> Result<Token, TextQueryError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or(TextQueryError::UnexpectedEnd { expected })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::Word { text, quoted: false }) if text.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    /// Consumes the keyword if it's next.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.is_keyword(keyword);
+        if is_keyword {
+            self.position += 1;
+        }
+        is_keyword
+    }
+
+    fn keyword(&mut self, keyword: &'static str) -> Result<(), TextQueryError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            let token = self.next(keyword)?;
+            Err(TextQueryError::UnexpectedToken {
+                expected: keyword,
+                found: token.describe(),
+            })
+        }
+    }
+
+    /// Consumes the symbol if it's next.
+    fn eat_symbol(&mut self, symbol: &'static str) -> bool {
+        let is_symbol = self.peek() == Some(&Token::Symbol(symbol));
+        if is_symbol {
+            self.position += 1;
+        }
+        is_symbol
+    }
+
+    /// `*` is tokenized as a word, since it can also be part of entity paths.
+    fn eat_symbol_word(&mut self, text: &str) -> bool {
+        let is_word = matches!(
+            self.peek(),
+            Some(Token::Word { text: word, quoted: false }) if word == text
+        );
+        if is_word {
+            self.position += 1;
+        }
+        is_word
+    }
+
+    fn word(&mut self, expected: &'static str) -> Result<String, TextQueryError> {
+        match self.next(expected)? {
+            Token::Word { text, .. } => Ok(text),
+            token @ Token::Symbol(_) => Err(TextQueryError::UnexpectedToken {
+                expected,
+                found: token.describe(),
+            }),
+        }
+    }
+
+    /// Comma-separated words.
+    fn word_list(&mut self, expected: &'static str) -> Result<Vec<String>, TextQueryError> {
+        let mut words = vec![self.word(expected)?];
+        while self.eat_symbol(",") {
+            words.push(self.word(expected)?);
+        }
+        Ok(words)
+    }
+
+    fn time(&mut self) -> Result<TimeInt, TextQueryError> {
+        let text = self.word("a time")?;
+        parse_time(&text).ok_or_else(|| TextQueryError::InvalidTime(text))
+    }
+
+    fn query(mut self) -> Result<TextQuery, TextQueryError> {
+        self.keyword("SELECT")?;
+        let selection = if self.eat_symbol_word("*") {
+            None
+        } else {
+            Some(
+                self.word_list("a column")?
+                    .into_iter()
+                    .map(|column| parse_column(&column))
+                    .collect(),
+            )
+        };
+
+        self.keyword("FROM")?;
+        let mut rules = self
+            .word_list("an entity path")?
+            .into_iter()
+            .map(|path| format!("+ {path}"))
+            .collect::<Vec<_>>();
+        if self.eat_keyword("EXCEPT") {
+            rules.extend(
+                self.word_list("an entity path")?
+                    .into_iter()
+                    .map(|path| format!("- {path}")),
+            );
+        }
+        let entity_path_filter =
+            EntityPathFilter::from_query_expressions(rules.iter().map(String::as_str));
+
+        let mut query = TextQuery {
+            entity_path_filter,
+            selection,
+            filtered_index: None,
+            filtered_index_range: None,
+            filtered_index_values: None,
+            filtered_is_not_null: None,
+            sparse_fill_strategy: SparseFillStrategy::None,
+        };
+
+        if self.eat_keyword("WHERE") {
+            loop {
+                self.condition(&mut query)?;
+                if !self.eat_keyword("AND") {
+                    break;
+                }
+            }
+        }
+
+        if self.eat_keyword("ORDER") {
+            self.keyword("BY")?;
+            let timeline = TimelineName::new(&self.word("a timeline")?);
+            set_index(&mut query, timeline)?;
+        }
+
+        if self.eat_keyword("FILL") {
+            self.keyword("LATEST")?;
+            query.sparse_fill_strategy = SparseFillStrategy::LatestAtGlobal;
+        }
+
+        if let Some(token) = self.peek() {
+            return Err(TextQueryError::UnexpectedToken {
+                expected: "the end of the query",
+                found: token.describe(),
+            });
+        }
+
+        Ok(query)
+    }
+
+    fn condition(&mut self, query: &mut TextQuery) -> Result<(), TextQueryError> {
+        let column = self.word("a timeline or column")?;
+
+        if self.eat_keyword("IS") {
+            self.keyword("NOT")?;
+            self.keyword("NULL")?;
+            if query.filtered_is_not_null.is_some() {
+                return Err(TextQueryError::MultipleNotNullColumns);
+            }
+            let ColumnSelector::Component(selector) = parse_column(&column) else {
+                return Err(TextQueryError::UnexpectedToken {
+                    expected: "a component column, e.g. `/points:Points3D:positions`",
+                    found: column,
+                });
+            };
+            query.filtered_is_not_null = Some(selector);
+            return Ok(());
+        }
+
+        set_index(query, TimelineName::new(&column))?;
+
+        if self.eat_keyword("IN") {
+            if !self.eat_symbol("(") {
+                let token = self.next("(")?;
+                return Err(TextQueryError::UnexpectedToken {
+                    expected: "(",
+                    found: token.describe(),
+                });
+            }
+            let mut values = BTreeSet::new();
+            loop {
+                values.insert(self.time()?);
+                if !self.eat_symbol(",") {
+                    break;
+                }
+            }
+            if !self.eat_symbol(")") {
+                let token = self.next(")")?;
+                return Err(TextQueryError::UnexpectedToken {
+                    expected: ")",
+                    found: token.describe(),
+                });
+            }
+            query.filtered_index_values = Some(values);
+            return Ok(());
+        }
+
+        let (min, max) = if self.eat_keyword("BETWEEN") {
+            let min = self.time()?;
+            self.keyword("AND")?;
+            (min, self.time()?)
+        } else {
+            let operator = match self.next("a comparison")? {
+                Token::Symbol(operator) if !matches!(operator, "," | "(" | ")") => operator,
+                token => {
+                    return Err(TextQueryError::UnexpectedToken {
+                        expected: "BETWEEN, IN, IS NOT NULL or a comparison",
+                        found: token.describe(),
+                    });
+                }
+            };
+            let time = self.time()?;
+            match operator {
+                "=" => (time, time),
+                "<" => (TimeInt::MIN, time.dec()),
+                "<=" => (TimeInt::MIN, time),
+                ">" => (time.inc(), TimeInt::MAX),
+                _ => (time, TimeInt::MAX),
+            }
+        };
+
+        let range = query
+            .filtered_index_range
+            .unwrap_or(AbsoluteTimeRange::EVERYTHING);
+        query.filtered_index_range = Some(AbsoluteTimeRange::new(
+            range.min().max(min),
+            range.max().min(max),
+        ));
+
+        Ok(())
+    }
+}
+
+fn set_index(query: &mut TextQuery, timeline: TimelineName) -> Result<(), TextQueryError> {
+    match query.filtered_index {
+        Some(index) if index != timeline => Err(TextQueryError::MultipleTimelines(index, timeline)),
+        _ => {
+            query.filtered_index = Some(timeline);
+            Ok(())
+        }
+    }
+}
+
+fn parse_column(column: &str) -> ColumnSelector {
+    if column.eq_ignore_ascii_case("rowid") {
+        ColumnSelector::RowId
+    } else if let Ok(selector) = column.parse::<ComponentColumnSelector>() {
+        ColumnSelector::Component(selector)
+    } else {
+        ColumnSelector::Time(TimeColumnSelector::from(column))
+    }
+}
+
+/// An integer, or a number with a duration unit which is converted to nanoseconds.
+fn parse_time(text: &str) -> Option<TimeInt> {
+    if let Ok(value) = text.parse::<i64>() {
+        return Some(TimeInt::saturated_temporal_i64(value));
+    }
+
+    let unit_start = text.find(|c: char| c.is_ascii_alphabetic())?;
+    let (value, unit) = text.split_at(unit_start);
+    let nanos_per_unit = match unit {
+        "ns" => 1.0,
+        "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        "min" => 60e9,
+        "h" => 3600e9,
+        _ => return None,
+    };
+    let value = value.parse::<f64>().ok()?;
+    Some(TimeInt::saturated_temporal_i64(
+        (value * nanos_per_unit).round() as i64,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full_query() {
+        let query: TextQuery = "select frame_nr, '/world/my points:Points3D:positions' \
+            FROM /world/** EXCEPT /world/debug/** \
+            WHERE frame_nr BETWEEN 10 AND 20 AND frame_nr < 15 \
+            AND /world/points:Points3D:colors IS NOT NULL \
+            FILL LATEST"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            query.entity_path_filter,
+            EntityPathFilter::parse_forgiving("+ /world/**\n- /world/debug/**")
+        );
+        assert_eq!(
+            query.selection,
+            Some(vec![
+                ColumnSelector::Time("frame_nr".into()),
+                ColumnSelector::Component(ComponentColumnSelector {
+                    entity_path: "/world/my points".into(),
+                    component: "Points3D:positions".into(),
+                }),
+            ])
+        );
+        assert_eq!(query.filtered_index, Some(TimelineName::new("frame_nr")));
+        assert_eq!(
+            query.filtered_index_range,
+            Some(AbsoluteTimeRange::new(
+                TimeInt::new_temporal(10),
+                TimeInt::new_temporal(14)
+            ))
+        );
+        assert_eq!(
+            query.filtered_is_not_null,
+            Some(ComponentColumnSelector {
+                entity_path: "/world/points".into(),
+                component: "Points3D:colors".into(),
+            })
+        );
+        assert_eq!(
+            query.sparse_fill_strategy,
+            SparseFillStrategy::LatestAtGlobal
+        );
+    }
+
+    #[test]
+    fn parse_index_values_and_units() {
+        let query: TextQuery = "SELECT * FROM /** WHERE log_time IN (1s, 1500ms, -2)"
+            .parse()
+            .unwrap();
+        assert_eq!(query.selection, None);
+        assert_eq!(
+            query.filtered_index_values,
+            Some(
+                [-2, 1_000_000_000, 1_500_000_000]
+                    .into_iter()
+                    .map(TimeInt::new_temporal)
+                    .collect()
+            )
+        );
+
+        let query: TextQuery = "SELECT * FROM /** ORDER BY frame".parse().unwrap();
+        assert_eq!(query.filtered_index, Some(TimelineName::new("frame")));
+        assert_eq!(query.filtered_index_range, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "SELECT *".parse::<TextQuery>(),
+            Err(TextQueryError::UnexpectedEnd { expected: "FROM" })
+        );
+        assert_eq!(
+            "SELECT * FROM /** WHERE frame > soon".parse::<TextQuery>(),
+            Err(TextQueryError::InvalidTime("soon".to_owned()))
+        );
+        assert_eq!(
+            "SELECT * FROM /** WHERE frame > 1 ORDER BY log_time".parse::<TextQuery>(),
+            Err(TextQueryError::MultipleTimelines(
+                TimelineName::new("frame"),
+                TimelineName::new("log_time")
+            ))
+        );
+        assert_eq!(
+            "SELECT * FROM '/points".parse::<TextQuery>(),
+            Err(TextQueryError::UnterminatedQuote)
+        );
+    }
+}
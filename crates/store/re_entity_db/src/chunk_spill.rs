@@ -0,0 +1,217 @@
+use std::io::{Read as _, Seek as _, SeekFrom, Write as _};
+
+use nohash_hasher::IntMap;
+
+use re_chunk::{Chunk, TimelineName};
+use re_log_types::{AbsoluteTimeRange, LogMsg, StoreId};
+
+/// The errors that can occur when spilling chunks to disk, or reloading them.
+#[derive(thiserror::Error, Debug)]
+pub enum SpillError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Chunk(#[from] re_chunk::ChunkError),
+
+    #[error(transparent)]
+    Encode(#[from] re_log_encoding::encoder::EncodeError),
+
+    #[error(transparent)]
+    Decode(#[from] re_log_encoding::decoder::DecodeError),
+
+    #[error("Spilled data is missing or corrupt")]
+    Corrupt,
+}
+
+/// A chunk that was written to a [`ChunkSpill`].
+struct SpilledChunk {
+    /// Where in the file the chunk starts.
+    offset: u64,
+
+    /// The size of the encoded chunk in the file.
+    num_bytes: u64,
+
+    /// The time range covered by the chunk on each of its timelines.
+    time_ranges: IntMap<TimelineName, AbsoluteTimeRange>,
+}
+
+/// Chunks that were dropped from an [`crate::EntityDb`] to stay within the memory limit,
+/// kept in a temporary file so that they can be reloaded when they are needed again.
+///
+/// The file is created on the first [`Self::spill`], and removed once all chunks are reloaded,
+/// or this is dropped.
+///
+/// Spilling is not supported on the web.
+#[derive(Default)]
+pub struct ChunkSpill {
+    file: Option<std::fs::File>,
+
+    /// The size of the file.
+    file_len: u64,
+
+    /// The chunks that are in the file, and haven't been reloaded yet.
+    chunks: Vec<SpilledChunk>,
+}
+
+impl ChunkSpill {
+    /// Number of chunks waiting to be reloaded.
+    #[inline]
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Total size of the chunks waiting to be reloaded, as stored on disk.
+    pub fn num_bytes(&self) -> u64 {
+        self.chunks.iter().map(|chunk| chunk.num_bytes).sum()
+    }
+
+    /// Writes a chunk to disk.
+    ///
+    /// Static chunks are ignored, since they are never dropped to begin with.
+    pub fn spill(&mut self, store_id: &StoreId, chunk: &Chunk) -> Result<(), SpillError> {
+        re_tracing::profile_function!();
+
+        if chunk.is_static() {
+            return Ok(());
+        }
+
+        let msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+        let bytes = re_log_encoding::encoder::encode_to_bytes(
+            re_build_info::CrateVersion::LOCAL,
+            re_log_encoding::EncodingOptions::PROTOBUF_UNCOMPRESSED,
+            [&msg],
+        )?;
+
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(create_temp_file()?),
+        };
+        file.seek(SeekFrom::Start(self.file_len))?;
+        file.write_all(&bytes)?;
+
+        self.chunks.push(SpilledChunk {
+            offset: self.file_len,
+            num_bytes: bytes.len() as u64,
+            time_ranges: chunk
+                .timelines()
+                .iter()
+                .map(|(timeline, time_column)| (*timeline, time_column.time_range()))
+                .collect(),
+        });
+        self.file_len += bytes.len() as u64;
+
+        Ok(())
+    }
+
+    /// Reads back all spilled chunks with data in the given time range, and forgets about them.
+    pub fn reload(
+        &mut self,
+        timeline: &TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) -> Result<Vec<Chunk>, SpillError> {
+        let (to_reload, to_keep) = std::mem::take(&mut self.chunks)
+            .into_iter()
+            .partition::<Vec<_>, _>(|chunk| {
+                chunk
+                    .time_ranges
+                    .get(timeline)
+                    .is_some_and(|chunk_range| chunk_range.intersects(time_range))
+            });
+        self.chunks = to_keep;
+
+        if to_reload.is_empty() {
+            return Ok(Vec::new());
+        }
+        let Some(file) = &mut self.file else {
+            return Err(SpillError::Corrupt);
+        };
+
+        re_tracing::profile_function!();
+
+        let mut chunks = Vec::with_capacity(to_reload.len());
+        for spilled in &to_reload {
+            let mut bytes = vec![0; spilled.num_bytes as usize];
+            file.seek(SeekFrom::Start(spilled.offset))?;
+            file.read_exact(&mut bytes)?;
+
+            let msgs = re_log_encoding::decoder::decode_bytes(&bytes)?;
+            let [LogMsg::ArrowMsg(_, arrow_msg)] = msgs.as_slice() else {
+                return Err(SpillError::Corrupt);
+            };
+            chunks.push(Chunk::from_arrow_msg(arrow_msg)?);
+        }
+
+        if self.chunks.is_empty() {
+            // Everything was reloaded, so we can reclaim the disk space.
+            self.file = None;
+            self.file_len = 0;
+        }
+
+        Ok(chunks)
+    }
+}
+
+/// A file that is removed as soon as it is closed.
+fn create_temp_file() -> std::io::Result<std::fs::File> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tempfile::tempfile()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_log_types::example_components::{MyPoint, MyPoints};
+
+    use super::*;
+
+    #[test]
+    fn spill_and_reload() -> anyhow::Result<()> {
+        let store_id = StoreId::random(re_log_types::StoreKind::Recording, "test_app");
+        let timeline = Timeline::new_sequence("frame");
+        let point = MyPoint::new(1.0, 2.0);
+
+        let chunk_at = |frame: i64| {
+            Chunk::builder("points")
+                .with_component_batches(
+                    RowId::new(),
+                    TimePoint::from_iter([(timeline, frame)]),
+                    [(MyPoints::descriptor_points(), &[point] as _)],
+                )
+                .build()
+        };
+
+        let mut spill = ChunkSpill::default();
+        spill.spill(&store_id, &chunk_at(10)?)?;
+        spill.spill(&store_id, &chunk_at(20)?)?;
+        assert_eq!(spill.num_chunks(), 2);
+
+        let reloaded = spill.reload(timeline.name(), AbsoluteTimeRange::new(15, 25))?;
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(
+            reloaded[0].timelines()[timeline.name()].time_range(),
+            AbsoluteTimeRange::point(20)
+        );
+        assert_eq!(spill.num_chunks(), 1);
+
+        assert!(
+            spill
+                .reload(timeline.name(), AbsoluteTimeRange::new(15, 25))?
+                .is_empty()
+        );
+
+        let reloaded = spill.reload(timeline.name(), AbsoluteTimeRange::EVERYTHING)?;
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(spill.num_chunks(), 0);
+        assert_eq!(spill.num_bytes(), 0);
+
+        Ok(())
+    }
+}
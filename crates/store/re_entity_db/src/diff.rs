@@ -0,0 +1,260 @@
+//! Entity-by-entity and component-by-component differences between two recordings,
+//! or between two points in time of the same recording.
+
+use std::collections::BTreeMap;
+
+use re_chunk::{ArrowArray as _, ArrowArrayRef, LatestAtQuery, RowId};
+use re_log_types::EntityPath;
+use re_types_core::ComponentDescriptor;
+
+use crate::EntityDb;
+
+/// How an entity or component differs from the baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiffStatus {
+    /// Only has data in the compared recording.
+    Added,
+
+    /// Only has data in the baseline.
+    Removed,
+
+    /// Has data in both, but it differs.
+    Changed,
+}
+
+/// A value of a component, as of the query of its recording.
+#[derive(Clone, Debug)]
+pub struct DiffValue {
+    pub row_id: Option<RowId>,
+    pub array: ArrowArrayRef,
+}
+
+#[derive(Clone, Debug)]
+pub struct ComponentDiff {
+    pub component: ComponentDescriptor,
+    pub status: DiffStatus,
+
+    /// The value in the baseline, `None` if it was [`DiffStatus::Added`].
+    pub before: Option<DiffValue>,
+
+    /// The value in the compared recording, `None` if it was [`DiffStatus::Removed`].
+    pub after: Option<DiffValue>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EntityDiff {
+    pub entity_path: EntityPath,
+    pub status: DiffStatus,
+
+    /// Only the components that differ, sorted by descriptor.
+    pub components: Vec<ComponentDiff>,
+}
+
+/// The differences between the latest-at state of two recordings.
+///
+/// Entities and components that are identical on both sides are left out.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingDiff {
+    /// Sorted by entity path.
+    pub entities: Vec<EntityDiff>,
+}
+
+impl RecordingDiff {
+    /// Compares `after` as of `after_query` with the baseline `before` as of `before_query`.
+    ///
+    /// Both sides can be the same recording, to compare two points in time.
+    pub fn latest_at(
+        before: &EntityDb,
+        before_query: &LatestAtQuery,
+        after: &EntityDb,
+        after_query: &LatestAtQuery,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        // Both sides may be the same recording: never hold both storage engines at once.
+        let mut components_per_entity = all_components_per_entity(before);
+        for (entity_path, components) in all_components_per_entity(after) {
+            components_per_entity
+                .entry(entity_path)
+                .or_default()
+                .extend(components);
+        }
+
+        let entities = components_per_entity
+            .into_iter()
+            .filter_map(|(entity_path, components)| {
+                let before_results = before.latest_at(before_query, &entity_path, &components);
+                let after_results = after.latest_at(after_query, &entity_path, &components);
+
+                let value = |results: &re_query::LatestAtResults,
+                             component: &ComponentDescriptor| {
+                    Some(DiffValue {
+                        row_id: results.component_row_id(component),
+                        array: results.component_batch_raw(component)?,
+                    })
+                };
+
+                let mut any_before = false;
+                let mut any_after = false;
+                let components: Vec<ComponentDiff> = components
+                    .into_iter()
+                    .filter_map(|component| {
+                        let before = value(&before_results, &component);
+                        let after = value(&after_results, &component);
+                        any_before |= before.is_some();
+                        any_after |= after.is_some();
+
+                        let status = match (&before, &after) {
+                            (None, None) => return None,
+                            (None, Some(_)) => DiffStatus::Added,
+                            (Some(_), None) => DiffStatus::Removed,
+                            (Some(before), Some(after)) => {
+                                if before.array.to_data() == after.array.to_data() {
+                                    return None;
+                                }
+                                DiffStatus::Changed
+                            }
+                        };
+
+                        Some(ComponentDiff {
+                            component,
+                            status,
+                            before,
+                            after,
+                        })
+                    })
+                    .collect();
+
+                if components.is_empty() {
+                    return None;
+                }
+
+                let status = match (any_before, any_after) {
+                    (false, _) => DiffStatus::Added,
+                    (true, false) => DiffStatus::Removed,
+                    (true, true) => DiffStatus::Changed,
+                };
+
+                Some(EntityDiff {
+                    entity_path,
+                    status,
+                    components,
+                })
+            })
+            .collect();
+
+        Self { entities }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// The number of entities with the given status.
+    pub fn num_entities(&self, status: DiffStatus) -> usize {
+        self.entities
+            .iter()
+            .filter(|entity| entity.status == status)
+            .count()
+    }
+
+    pub fn entity(&self, entity_path: &EntityPath) -> Option<&EntityDiff> {
+        self.entities
+            .binary_search_by(|entity| entity.entity_path.cmp(entity_path))
+            .ok()
+            .map(|index| &self.entities[index])
+    }
+}
+
+fn all_components_per_entity(
+    db: &EntityDb,
+) -> BTreeMap<EntityPath, re_types_core::ComponentDescriptorSet> {
+    let engine = db.storage_engine();
+    let store = engine.store();
+    store
+        .all_entities_sorted()
+        .into_iter()
+        .filter_map(|entity_path| {
+            let components = store.all_components_for_entity_sorted(&entity_path)?;
+            Some((entity_path, components))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{
+        StoreId, StoreKind, TimePoint, Timeline,
+        example_components::{MyPoint, MyPoints},
+    };
+
+    use super::*;
+
+    fn add_point(db: &mut EntityDb, entity_path: &str, frame: i64, point: MyPoint) {
+        let timepoint = TimePoint::from_iter([(Timeline::new_sequence("frame"), frame)]);
+        let chunk = Chunk::builder(entity_path)
+            .with_component_batches(
+                RowId::new(),
+                timepoint,
+                [(MyPoints::descriptor_points(), &[point] as _)],
+            )
+            .build()
+            .unwrap();
+        db.add_chunk(&Arc::new(chunk)).unwrap();
+    }
+
+    #[test]
+    fn diff_between_recordings() {
+        let mut before = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+        let mut after = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+
+        let point = MyPoint::new(1.0, 2.0);
+        add_point(&mut before, "same", 1, point);
+        add_point(&mut after, "same", 1, point);
+        add_point(&mut before, "changed", 1, point);
+        add_point(&mut after, "changed", 1, MyPoint::new(3.0, 4.0));
+        add_point(&mut before, "removed", 1, point);
+        add_point(&mut after, "added", 1, point);
+
+        let query = LatestAtQuery::new("frame".into(), 1);
+        let diff = RecordingDiff::latest_at(&before, &query, &after, &query);
+
+        let statuses: Vec<_> = diff
+            .entities
+            .iter()
+            .map(|entity| (entity.entity_path.to_string(), entity.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                ("/added".to_owned(), DiffStatus::Added),
+                ("/changed".to_owned(), DiffStatus::Changed),
+                ("/removed".to_owned(), DiffStatus::Removed),
+            ]
+        );
+
+        let changed = diff.entity(&"changed".into()).unwrap();
+        assert_eq!(changed.components.len(), 1);
+        assert!(changed.components[0].before.is_some());
+        assert!(changed.components[0].after.is_some());
+    }
+
+    #[test]
+    fn diff_between_times() {
+        let mut db = EntityDb::new(StoreId::random(StoreKind::Recording, "test_app"));
+        add_point(&mut db, "points", 1, MyPoint::new(1.0, 2.0));
+        add_point(&mut db, "points", 2, MyPoint::new(3.0, 4.0));
+
+        let frame_1 = LatestAtQuery::new("frame".into(), 1);
+        let frame_2 = LatestAtQuery::new("frame".into(), 2);
+
+        assert!(RecordingDiff::latest_at(&db, &frame_1, &db, &frame_1).is_empty());
+
+        let diff = RecordingDiff::latest_at(&db, &frame_1, &db, &frame_2);
+        assert_eq!(diff.num_entities(DiffStatus::Changed), 1);
+    }
+}
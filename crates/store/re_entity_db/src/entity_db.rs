@@ -9,7 +9,8 @@ use re_chunk::{
 };
 use re_chunk_store::{
     ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent,
-    ChunkStoreHandle, ChunkStoreSubscriber as _, GarbageCollectionOptions, GarbageCollectionTarget,
+    ChunkStoreHandle, ChunkStoreSubscriber as _, CompactionCursor, GarbageCollectionOptions,
+    GarbageCollectionTarget, RetentionPolicy,
 };
 use re_log_types::{
     AbsoluteTimeRange, AbsoluteTimeRangeF, ApplicationId, EntityPath, EntityPathHash, LogMsg,
@@ -571,6 +572,17 @@ impl EntityDb {
                 // Not for us to handle
                 vec![]
             }
+
+            LogMsg::DropTimeRange(cmd) => {
+                self.last_modified_at = web_time::Instant::now();
+
+                self.drop_entity_time_range(
+                    &cmd.entity_path,
+                    cmd.recursive,
+                    &cmd.timeline,
+                    cmd.range,
+                )
+            }
         };
 
         Ok(store_events)
@@ -631,7 +643,15 @@ impl EntityDb {
     }
 
     /// Free up some RAM by forgetting the older parts of all timelines.
-    pub fn purge_fraction_of_ram(&mut self, fraction_to_purge: f32) -> Vec<ChunkStoreEvent> {
+    ///
+    /// Data within the `protected_time_ranges` is kept, e.g. because it is currently being viewed,
+    /// and so is the data that `retention` keeps forever.
+    pub fn purge_fraction_of_ram(
+        &mut self,
+        fraction_to_purge: f32,
+        protected_time_ranges: IntMap<TimelineName, AbsoluteTimeRange>,
+        retention: RetentionPolicy,
+    ) -> Vec<ChunkStoreEvent> {
         re_tracing::profile_function!();
 
         assert!((0.0..=1.0).contains(&fraction_to_purge));
@@ -641,12 +661,12 @@ impl EntityDb {
             protect_latest: 1,
             time_budget: DEFAULT_GC_TIME_BUDGET,
 
-            // TODO(emilk): we could protect the data that is currently being viewed
-            // (e.g. when paused in the live camera example).
-            // To be perfect it would need margins (because of latest-at), i.e. we would need to know
-            // exactly how far back the latest-at is of each component at the current time…
+            // NOTE: To be perfect, protecting the data that is currently being viewed would need
+            // margins (because of latest-at), i.e. we would need to know exactly how far back the
+            // latest-at is of each component at the current time…
             // …but maybe it doesn't have to be perfect.
-            protected_time_ranges: Default::default(),
+            protected_time_ranges,
+            retention,
         });
 
         if store_events.is_empty() {
@@ -663,6 +683,21 @@ impl EntityDb {
         store_events
     }
 
+    /// Forget the data that was kept longer than its retention, regardless of memory use.
+    pub fn drop_expired(&mut self, retention: RetentionPolicy) -> Vec<ChunkStoreEvent> {
+        if retention.is_empty() {
+            return Vec::new();
+        }
+
+        self.gc(&GarbageCollectionOptions {
+            target: GarbageCollectionTarget::Expired,
+            protect_latest: 1,
+            time_budget: DEFAULT_GC_TIME_BUDGET,
+            protected_time_ranges: Default::default(),
+            retention,
+        })
+    }
+
     pub fn gc(&mut self, gc_options: &GarbageCollectionOptions) -> Vec<ChunkStoreEvent> {
         re_tracing::profile_function!();
 
@@ -686,6 +721,36 @@ impl EntityDb {
         store_events
     }
 
+    /// Changes the compaction thresholds of this recording, see [`ChunkStore::set_config`].
+    pub fn set_store_config(&mut self, store_config: ChunkStoreConfig) {
+        self.storage_engine.write().store().set_config(store_config);
+    }
+
+    /// Merge the small chunks of this recording with their neighbors,
+    /// for at most `time_budget`, see [`ChunkStore::compact`].
+    pub fn compact(
+        &mut self,
+        cursor: &mut CompactionCursor,
+        time_budget: std::time::Duration,
+    ) -> Result<Vec<ChunkStoreEvent>, Error> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+        let store_events = engine.store().compact(cursor, time_budget)?;
+
+        // Every merged chunk is removed and then added back.
+        self.tree.on_store_additions(&store_events);
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        Ok(store_events)
+    }
+
     /// Drop all events in the given time range from the given timeline.
     ///
     /// Used to implement undo (erase the last event from the blueprint db).
@@ -710,6 +775,35 @@ impl EntityDb {
         store_events
     }
 
+    /// Drop all events of the given [`EntityPath`] in the given time range from the given timeline.
+    ///
+    /// If `recursive` is true, this applies to all the children of the entity too.
+    pub fn drop_entity_time_range(
+        &mut self,
+        entity_path: &EntityPath,
+        recursive: bool,
+        timeline: &TimelineName,
+        drop_range: AbsoluteTimeRange,
+    ) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events =
+            engine
+                .store()
+                .drop_entity_time_range(entity_path, recursive, timeline, drop_range);
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        store_events
+    }
+
     /// Unconditionally drops all the data for a given [`EntityPath`] .
     ///
     /// This is _not_ recursive. Children of this entity will not be affected.
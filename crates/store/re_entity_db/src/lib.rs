@@ -4,6 +4,8 @@
 #![doc = document_features::document_features!()]
 //!
 
+mod chunk_spill;
+pub mod diff;
 pub mod entity_db;
 pub mod entity_tree;
 mod ingestion_statistics;
@@ -14,6 +16,8 @@ mod times_per_timeline;
 mod versioned_instance_path;
 
 pub use self::{
+    chunk_spill::{ChunkSpill, SpillError},
+    diff::{ComponentDiff, DiffStatus, DiffValue, EntityDiff, RecordingDiff},
     entity_db::{DEFAULT_GC_TIME_BUDGET, EntityDb},
     entity_tree::EntityTree,
     ingestion_statistics::{IngestionStatistics, LatencySnapshot, LatencyStats},
@@ -14,6 +14,44 @@ pub mod write_table;
 
 const MAX_DECODING_MESSAGE_SIZE: usize = u32::MAX as usize;
 
+/// Environment variable holding the bearer token that clients send to servers that require one,
+/// see `re_grpc_server::ServerSecurity::auth_tokens`.
+pub const AUTH_TOKEN_ENV_VAR: &str = "RERUN_PROXY_TOKEN";
+
+/// The bearer token in [`AUTH_TOKEN_ENV_VAR`], if set.
+pub fn auth_token_from_env() -> Option<String> {
+    std::env::var(AUTH_TOKEN_ENV_VAR)
+        .ok()
+        .filter(|token| !token.trim().is_empty())
+}
+
+/// Environment variable holding the name that clients give themselves when connecting to a server,
+/// see `re_grpc_server::CLIENT_NAME_HEADER`.
+///
+/// Useful to tell apart e.g. several robots running the same code.
+pub const CLIENT_NAME_ENV_VAR: &str = "RERUN_CLIENT_NAME";
+
+/// The client name in [`CLIENT_NAME_ENV_VAR`] if set, or else the name of the running executable.
+pub fn client_name_from_env() -> Option<String> {
+    std::env::var(CLIENT_NAME_ENV_VAR)
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .or_else(|| {
+            let exe = std::env::current_exe().ok()?;
+            Some(exe.file_stem()?.to_string_lossy().into_owned())
+        })
+}
+
+/// Names the client sending `request`, see [`CLIENT_NAME_ENV_VAR`].
+fn with_client_name<T>(request: tonic::Request<T>, client_name: Option<&str>) -> tonic::Request<T> {
+    use re_protos::headers::RerunHeadersInjectorExt as _;
+
+    match client_name {
+        Some(client_name) => request.with_client_name(client_name),
+        None => request,
+    }
+}
+
 /// Wrapper with a nicer error message
 #[derive(Debug)]
 pub struct TonicStatusError(Box<tonic::Status>);
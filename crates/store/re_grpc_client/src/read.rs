@@ -1,5 +1,6 @@
 use tokio_stream::StreamExt as _;
 
+use re_auth::client::AuthDecorator;
 use re_log_encoding::protobuf_conversions::log_msg_from_proto;
 use re_log_types::LogMsg;
 use re_protos::sdk_comms::v1alpha1::ReadMessagesRequest;
@@ -13,9 +14,21 @@ use crate::TonicStatusError;
 /// Read log messages from a proxy server.
 ///
 /// This is used by the viewer to _receive_ log messages.
+///
+/// Sends the token in [`crate::AUTH_TOKEN_ENV_VAR`] along, if set,
+/// and names itself after [`crate::client_name_from_env`].
 pub fn stream(
     uri: re_uri::ProxyUri,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+) -> re_smart_channel::Receiver<LogMsg> {
+    stream_with_auth_token(uri, crate::auth_token_from_env(), on_msg)
+}
+
+/// Like [`stream`], but with an explicit bearer token for servers that require one.
+pub fn stream_with_auth_token(
+    uri: re_uri::ProxyUri,
+    auth_token: Option<String>,
+    on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> re_smart_channel::Receiver<LogMsg> {
     re_log::debug!("Loading {uri} via gRPC…");
 
@@ -25,7 +38,7 @@ pub fn stream(
     );
 
     crate::spawn_future(async move {
-        if let Err(err) = stream_async(uri, &tx, on_msg).await {
+        if let Err(err) = stream_async(uri, auth_token, &tx, on_msg).await {
             tx.quit(Some(Box::new(err))).ok();
         }
     });
@@ -35,6 +48,7 @@ pub fn stream(
 
 async fn stream_async(
     uri: re_uri::ProxyUri,
+    auth_token: Option<String>,
     tx: &re_smart_channel::Sender<LogMsg>,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> Result<(), StreamError> {
@@ -52,14 +66,21 @@ async fn stream_async(
         #[cfg(not(target_arch = "wasm32"))]
         let tonic_client = { tonic::transport::Endpoint::new(url)?.connect().await? };
 
-        MessageProxyServiceClient::new(tonic_client)
-            .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+        MessageProxyServiceClient::with_interceptor(
+            tonic_client,
+            AuthDecorator::with_bearer_token(auth_token),
+        )
+        .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
     };
 
     re_log::debug!("Streaming messages from gRPC endpoint {uri}");
 
+    let request = crate::with_client_name(
+        tonic::Request::new(ReadMessagesRequest {}),
+        crate::client_name_from_env().as_deref(),
+    );
     let mut stream = client
-        .read_messages(ReadMessagesRequest {})
+        .read_messages(request)
         .await
         .map_err(TonicStatusError::from)?
         .into_inner();
@@ -8,19 +8,26 @@ use tokio::{
     runtime,
     sync::mpsc::{self, Receiver, Sender, UnboundedReceiver, UnboundedSender},
 };
-use tonic::transport::Endpoint;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Channel, Endpoint};
 use web_time::Instant;
 
+use re_auth::client::AuthDecorator;
 use re_chunk::external::crossbeam::atomic::AtomicCell;
 use re_log_encoding::Compression;
+use re_log_encoding::shared_memory::{DEFAULT_SEGMENT_CAPACITY, SharedMemoryWriter};
 use re_log_types::LogMsg;
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
 use re_protos::sdk_comms::v1alpha1::{
+    AttachSharedMemoryRequest, AwaitFlushBarrierRequest, FlushBarrier, SharedMemoryRef,
     WriteMessagesRequest, message_proxy_service_client::MessageProxyServiceClient,
 };
 use re_uri::ProxyUri;
 
 use crate::TonicStatusError;
 
+type ProxyClient = MessageProxyServiceClient<InterceptedService<Channel, AuthDecorator>>;
+
 /// An error that can occur when flushing.
 #[derive(Debug, thiserror::Error)]
 pub enum GrpcFlushError {
@@ -33,6 +40,9 @@ pub enum GrpcFlushError {
     #[error("gRPC connection to {uri} gracefully disconnected")]
     GracefulDisconnect { uri: ProxyUri },
 
+    #[error("gRPC server at {uri} did not acknowledge the flush: {message}")]
+    NotAcknowledged { uri: ProxyUri, message: String },
+
     #[error("{0}")]
     InternalError(String),
 
@@ -63,7 +73,7 @@ impl GrpcFlushError {
 enum Cmd {
     LogMsg(LogMsg),
     Flush {
-        on_done: crossbeam::channel::Sender<()>,
+        on_done: crossbeam::channel::Sender<Result<(), GrpcFlushError>>,
     },
 }
 
@@ -78,6 +88,22 @@ pub struct Options {
     /// But blocking [`Client::flush_blocking`] forever when the
     /// server just isn't there is not a good idea.
     pub connect_timeout_on_flush: Duration,
+
+    /// If set, try to send large payloads through shared memory instead of the socket.
+    ///
+    /// This only works if the server runs on the same machine. If the server refuses
+    /// (or doesn't know about shared memory), everything is sent over gRPC as usual.
+    pub shared_memory: Option<SharedMemoryOptions>,
+
+    /// Sent as a bearer token with every request, for servers that require one.
+    ///
+    /// Defaults to the token in [`crate::AUTH_TOKEN_ENV_VAR`], if any.
+    pub auth_token: Option<String>,
+
+    /// Name to identify this client with on the server, and in the viewer.
+    ///
+    /// Defaults to [`crate::client_name_from_env`].
+    pub client_name: Option<String>,
 }
 
 impl Default for Options {
@@ -85,6 +111,32 @@ impl Default for Options {
         Self {
             compression: Compression::LZ4,
             connect_timeout_on_flush: Duration::from_secs(5),
+            shared_memory: None,
+            auth_token: crate::auth_token_from_env(),
+            client_name: crate::client_name_from_env(),
+        }
+    }
+}
+
+/// Configuration of the shared memory side-channel, see [`Options::shared_memory`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedMemoryOptions {
+    /// Size of the shared memory segment, in bytes.
+    ///
+    /// If a payload doesn't fit in the free part of the segment, it is sent over gRPC instead.
+    pub segment_capacity: u64,
+
+    /// Payloads smaller than this are always sent over gRPC.
+    ///
+    /// For small messages the overhead of the socket is negligible.
+    pub min_payload_size: u64,
+}
+
+impl Default for SharedMemoryOptions {
+    fn default() -> Self {
+        Self {
+            segment_capacity: DEFAULT_SEGMENT_CAPACITY,
+            min_payload_size: 1024 * 1024,
         }
     }
 }
@@ -138,6 +190,7 @@ impl Client {
         let thread = {
             let uri = uri.clone();
             let status = status.clone();
+            let auth_token = options.auth_token.clone();
             thread::Builder::new()
                 .name("message_proxy_client".to_owned())
                 .spawn(move || {
@@ -151,6 +204,9 @@ impl Client {
                             cmd_rx,
                             shutdown_rx,
                             options.compression,
+                            options.shared_memory,
+                            auth_token,
+                            options.client_name,
                             status,
                         ));
                 })
@@ -178,6 +234,9 @@ impl Client {
 
     /// Block until all messages are sent, or there is a failure.
     ///
+    /// If the server supports it, this waits for the server to acknowledge that it has
+    /// received all messages, rather than just for them to be handed over to the socket.
+    ///
     /// If the gRPC connection has not yet been established,
     /// this call will block for _at most_ [`Options::connect_timeout_on_flush`].
     /// This means this function will only block all the way to the given `timeout` argument
@@ -211,7 +270,8 @@ impl Client {
             // Check in if the connection status has changed every now and then.
             let interval = Duration::from_secs(1).min(timeout); // This could be better, but is good enough.
             match flush_done_rx.recv_timeout(interval) {
-                Ok(()) => {
+                Ok(Err(err)) => return Err(err),
+                Ok(Ok(())) => {
                     let elapsed = start.elapsed();
                     if has_emitted_slow_warning {
                         re_log::info!(
@@ -312,6 +372,9 @@ async fn message_proxy_client(
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
     compression: Compression,
+    shared_memory_options: Option<SharedMemoryOptions>,
+    auth_token: Option<String>,
+    client_name: Option<String>,
     status: Arc<AtomicCell<ClientConnectionState>>,
 ) {
     let endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
@@ -354,11 +417,49 @@ async fn message_proxy_client(
     re_log::debug!("Connected to {uri}");
     status.store(ClientConnectionState::Connected);
 
-    let mut client = MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
+    let mut client = MessageProxyServiceClient::with_interceptor(
+        channel,
+        AuthDecorator::with_bearer_token(auth_token),
+    )
+    .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
+
+    let mut shared_memory = match shared_memory_options {
+        Some(options) => attach_shared_memory(&mut client, options.segment_capacity)
+            .await
+            .map(|writer| (writer, options.min_payload_size)),
+        None => None,
+    };
+
+    // Older servers don't know about flush barriers, in which case a flush only
+    // waits for the messages to be handed over to the socket.
+    let stream_id = re_tuid::Tuid::new().to_string();
+    let flush_barrier_client = supports_flush_barriers(&mut client, &stream_id)
+        .await
+        .then(|| client.clone());
+    let mut last_flush_barrier = 0;
+
+    // There is no point in compressing what we hand over through shared memory,
+    // and the socket to a local server is cheap.
+    let compression = if shared_memory.is_some() {
+        Compression::Off
+    } else {
+        compression
+    };
 
     let stream_status = status.clone();
     let stream = async_stream::stream! {
+        // Announce our stream id, so that the server knows which barriers to wait for.
+        if flush_barrier_client.is_some() {
+            yield WriteMessagesRequest {
+                log_msg: None,
+                shared_memory_payload: None,
+                flush_barrier: Some(FlushBarrier {
+                    stream_id: stream_id.clone(),
+                    barrier: 0,
+                }),
+            };
+        }
+
         loop {
             tokio::select! {
                 cmd = cmd_rx.recv() => {
@@ -370,7 +471,7 @@ async fn message_proxy_client(
                                 re_sorbet::timestamp_metadata::now_timestamp(),
                             );
 
-                            let msg = match re_log_encoding::protobuf_conversions::log_msg_to_proto(log_msg, compression) {
+                            let mut msg = match re_log_encoding::protobuf_conversions::log_msg_to_proto(log_msg, compression) {
                                 Ok(msg) => msg,
                                 Err(err) => {
                                     stream_status.store(ClientConnectionState::Disconnected(
@@ -381,18 +482,52 @@ async fn message_proxy_client(
                                 }
                             };
 
+                            let shared_memory_payload = shared_memory
+                                .as_mut()
+                                .and_then(|(writer, min_payload_size)| {
+                                    move_payload_to_shared_memory(writer, *min_payload_size, &mut msg)
+                                });
+
                             let msg = WriteMessagesRequest {
                                 log_msg: Some(msg),
+                                shared_memory_payload,
+                                flush_barrier: None,
                             };
 
                             yield msg;
                         }
 
+                        Some(Cmd::Flush { on_done }) if flush_barrier_client.is_some() => {
+                            re_log::debug!("Flush requested");
+
+                            last_flush_barrier += 1;
+                            let flush_barrier = FlushBarrier {
+                                stream_id: stream_id.clone(),
+                                barrier: last_flush_barrier,
+                            };
+
+                            // Wait for the acknowledgment in the background, so that we can keep sending messages.
+                            if let Some(client) = flush_barrier_client.clone() {
+                                tokio::spawn(await_flush_barrier(
+                                    client,
+                                    uri.clone(),
+                                    flush_barrier.clone(),
+                                    on_done,
+                                ));
+                            }
+
+                            yield WriteMessagesRequest {
+                                log_msg: None,
+                                shared_memory_payload: None,
+                                flush_barrier: Some(flush_barrier),
+                            };
+                        }
+
                         Some(Cmd::Flush { on_done }) => {
                             // Messages are received in order, so once we receive a `flush`
                             // we know we've sent all messages before that flush through already.
                             re_log::debug!("Flush requested");
-                            if on_done.send(()).is_err() {
+                            if on_done.send(Ok(())).is_err() {
                                 // Flush channel may already be closed for non-blocking flush, so this isn't an error.
                                 re_log::debug!("Failed to respond to flush: flush report channel was closed");
                                 break;
@@ -415,7 +550,8 @@ async fn message_proxy_client(
         }
     };
 
-    let disconnect_result = if let Err(status) = client.write_messages(stream).await {
+    let request = crate::with_client_name(tonic::Request::new(stream), client_name.as_deref());
+    let disconnect_result = if let Err(status) = client.write_messages(request).await {
         re_log::error!(
             "Write messages call failed: {}",
             TonicStatusError::from(status.clone())
@@ -436,3 +572,130 @@ async fn message_proxy_client(
         status.store(ClientConnectionState::Disconnected(disconnect_result));
     }
 }
+
+/// Whether the server can acknowledge flush barriers, see [`await_flush_barrier`].
+async fn supports_flush_barriers(client: &mut ProxyClient, stream_id: &str) -> bool {
+    // Barrier 0 is always reached immediately.
+    let request = AwaitFlushBarrierRequest {
+        flush_barrier: Some(FlushBarrier {
+            stream_id: stream_id.to_owned(),
+            barrier: 0,
+        }),
+    };
+
+    match client.await_flush_barrier(request).await {
+        Ok(_) => true,
+        Err(status) => {
+            // Most likely an older server.
+            re_log::debug!(
+                "Server does not support flush barriers: {}",
+                TonicStatusError::from(status)
+            );
+            false
+        }
+    }
+}
+
+/// Wait for the server to have received everything sent before `flush_barrier`,
+/// then report back to [`Client::flush_blocking`].
+async fn await_flush_barrier(
+    mut client: ProxyClient,
+    uri: ProxyUri,
+    flush_barrier: FlushBarrier,
+    on_done: crossbeam::channel::Sender<Result<(), GrpcFlushError>>,
+) {
+    let request = AwaitFlushBarrierRequest {
+        flush_barrier: Some(flush_barrier),
+    };
+
+    let result = match client.await_flush_barrier(request).await {
+        Ok(_) => Ok(()),
+        Err(status) => Err(GrpcFlushError::NotAcknowledged {
+            uri,
+            message: TonicStatusError::from(status).to_string(),
+        }),
+    };
+
+    // Nobody is waiting for a non-blocking flush.
+    on_done.send(result).ok();
+}
+
+/// Create a shared memory segment and ask the server to attach to it.
+///
+/// Returns `None` if that fails for any reason, in which case we just use plain gRPC.
+async fn attach_shared_memory(
+    client: &mut ProxyClient,
+    segment_capacity: u64,
+) -> Option<SharedMemoryWriter> {
+    let mut writer = match SharedMemoryWriter::create(segment_capacity) {
+        Ok(writer) => writer,
+        Err(err) => {
+            re_log::warn!("Failed to create shared memory segment, falling back to gRPC: {err}");
+            return None;
+        }
+    };
+
+    let request = AttachSharedMemoryRequest {
+        path: writer.path().to_string_lossy().into_owned(),
+        token: writer.token().to_vec().into(),
+    };
+
+    match client.attach_shared_memory(request).await {
+        Ok(response) => {
+            let response = response.into_inner();
+            if !response.accepted {
+                // Most likely the server is on another machine.
+                re_log::debug!("Server refused shared memory segment: {}", response.reason);
+                None
+            } else if let Err(err) =
+                writer.open_release_file(std::path::Path::new(&response.release_path))
+            {
+                re_log::warn!(
+                    "Failed to open shared memory release file, falling back to gRPC: {err}"
+                );
+                None
+            } else {
+                re_log::debug!("Sending large payloads through shared memory");
+                Some(writer)
+            }
+        }
+        Err(status) => {
+            // Most likely an older server.
+            re_log::debug!(
+                "Server does not support shared memory: {}",
+                TonicStatusError::from(status)
+            );
+            None
+        }
+    }
+}
+
+/// Move the payload of `msg` into the shared memory segment, if it is large enough and fits.
+fn move_payload_to_shared_memory(
+    writer: &mut SharedMemoryWriter,
+    min_payload_size: u64,
+    msg: &mut LogMsgProto,
+) -> Option<SharedMemoryRef> {
+    use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+    let Some(Msg::ArrowMsg(arrow_msg)) = &mut msg.msg else {
+        return None;
+    };
+
+    if (arrow_msg.payload.len() as u64) < min_payload_size {
+        return None;
+    }
+
+    let Some(slot) = writer.try_write(&arrow_msg.payload) else {
+        re_log::debug_once!("Shared memory segment is full, sending payloads over gRPC");
+        return None;
+    };
+
+    arrow_msg.payload = Default::default();
+
+    Some(SharedMemoryRef {
+        path: writer.path().to_string_lossy().into_owned(),
+        offset: slot.offset,
+        length: slot.length,
+    })
+}
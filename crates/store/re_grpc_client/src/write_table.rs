@@ -1,13 +1,20 @@
+use re_auth::client::AuthDecorator;
 use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 
 use re_uri::Origin;
 
-pub type ViewerClient = MessageProxyServiceClient<tonic::transport::Channel>;
+pub type ViewerClient = MessageProxyServiceClient<
+    tonic::service::interceptor::InterceptedService<tonic::transport::Channel, AuthDecorator>,
+>;
 
+/// Sends the token in [`crate::AUTH_TOKEN_ENV_VAR`] along with every request, if set.
 pub async fn viewer_client(origin: Origin) -> Result<ViewerClient, tonic::transport::Error> {
     let channel = channel(origin).await?;
-    Ok(MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE))
+    Ok(MessageProxyServiceClient::with_interceptor(
+        channel,
+        AuthDecorator::with_bearer_token(crate::auth_token_from_env()),
+    )
+    .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE))
 }
 
 pub async fn channel(origin: Origin) -> Result<tonic::transport::Channel, tonic::transport::Error> {
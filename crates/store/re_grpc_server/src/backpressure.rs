@@ -0,0 +1,231 @@
+//! Slows down the producers of log messages until the slowest subscriber catches up,
+//! see [`crate::OverflowPolicy::Backpressure`].
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use tokio::sync::{Notify, broadcast};
+
+use crate::ClientLogMsgProto;
+
+/// Decides when a new log message may be sent to the event loop.
+///
+/// Producers wait in [`Self::admit`] before sending, so that the event loop itself never
+/// waits, and keeps handling new clients, flush barriers and tables in the meantime.
+pub(crate) struct Backpressure {
+    max_bytes: Option<u64>,
+
+    /// Capacity of the broadcast channel.
+    capacity: usize,
+
+    /// Only used to look at how far behind the slowest subscriber is.
+    broadcast_log_tx: broadcast::Sender<ClientLogMsgProto>,
+
+    state: parking_lot::Mutex<BackpressureState>,
+
+    /// Notified whenever a subscriber received a message or went away.
+    caught_up: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct BackpressureState {
+    /// Running total of bytes admitted, after each of the most recent messages.
+    totals: VecDeque<u64>,
+
+    /// Messages that were admitted, but not yet broadcast by the event loop.
+    in_flight: usize,
+}
+
+impl Backpressure {
+    pub fn new(
+        max_bytes: Option<u64>,
+        capacity: usize,
+        broadcast_log_tx: broadcast::Sender<ClientLogMsgProto>,
+    ) -> Self {
+        Self {
+            max_bytes,
+            capacity,
+            broadcast_log_tx,
+            state: Default::default(),
+            caught_up: Default::default(),
+        }
+    }
+
+    /// Subscribers report their progress through this, see [`CaughtUpSignal`].
+    pub fn caught_up_signal(&self) -> CaughtUpSignal {
+        CaughtUpSignal(Arc::clone(&self.caught_up))
+    }
+
+    /// Waits until a new message of the given size can be broadcast without any subscriber
+    /// falling too far behind.
+    ///
+    /// Every admitted message must be reported back with [`Self::broadcast`].
+    pub async fn admit(&self, msg_size_bytes: u64) {
+        loop {
+            // Created before checking, so that we don't miss a notification in between.
+            let caught_up = self.caught_up.notified();
+            if self.try_admit(msg_size_bytes) {
+                return;
+            }
+            caught_up.await;
+        }
+    }
+
+    /// Like [`Self::admit`], for producers outside of the async runtime.
+    pub fn admit_blocking(&self, msg_size_bytes: u64) {
+        if !self.try_admit(msg_size_bytes) {
+            tokio::runtime::Handle::current().block_on(self.admit(msg_size_bytes));
+        }
+    }
+
+    fn try_admit(&self, msg_size_bytes: u64) -> bool {
+        let mut state = self.state.lock();
+
+        let unreceived = state.in_flight + self.broadcast_log_tx.len();
+
+        // The slowest subscriber would miss messages if it fell behind by the channel capacity.
+        let is_queue_full = self.capacity <= unreceived + 1;
+        let is_over_memory_limit = self.max_bytes.is_some_and(|max_bytes| {
+            max_bytes < state.unreceived_bytes(unreceived) + msg_size_bytes
+        });
+
+        // We always let through at least one message, or a message bigger than the limit
+        // would block forever.
+        if unreceived != 0 && (is_queue_full || is_over_memory_limit) {
+            return false;
+        }
+
+        state.in_flight += 1;
+        let total = state.totals.back().copied().unwrap_or_default();
+        state.totals.push_back(total + msg_size_bytes);
+        if self.capacity + 1 < state.totals.len() {
+            state.totals.pop_front();
+        }
+
+        true
+    }
+
+    /// Called by the event loop once it broadcast an admitted message.
+    pub fn broadcast(&self) {
+        let mut state = self.state.lock();
+        state.in_flight = state.in_flight.saturating_sub(1);
+    }
+}
+
+impl BackpressureState {
+    /// Number of bytes in the `unreceived` most recently admitted messages.
+    fn unreceived_bytes(&self, unreceived: usize) -> u64 {
+        if unreceived == 0 {
+            return 0;
+        }
+
+        let total = self.totals.back().copied().unwrap_or_default();
+        let received = self
+            .totals
+            .len()
+            .checked_sub(unreceived + 1)
+            .and_then(|index| self.totals.get(index))
+            .copied()
+            .unwrap_or_default();
+        total.saturating_sub(received)
+    }
+}
+
+/// Held by every subscriber of the broadcast channel, to wake up waiting producers.
+///
+/// Also wakes them up on drop, since a subscriber that goes away no longer holds anyone back.
+pub(crate) struct CaughtUpSignal(Arc<Notify>);
+
+impl CaughtUpSignal {
+    pub fn received(&self) {
+        self.0.notify_waiters();
+    }
+}
+
+impl Drop for CaughtUpSignal {
+    fn drop(&mut self) {
+        self.received();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    fn msg() -> ClientLogMsgProto {
+        ClientLogMsgProto {
+            msg: Default::default(),
+            client: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn waits_for_slowest_subscriber_over_memory_limit() {
+        let (broadcast_log_tx, mut broadcast_log_rx) = broadcast::channel(16);
+        let backpressure = Arc::new(Backpressure::new(Some(100), 16, broadcast_log_tx.clone()));
+        let signal = backpressure.caught_up_signal();
+
+        // Nobody is behind yet, so even a message over the limit gets through.
+        backpressure.admit(150).await;
+        broadcast_log_tx.send(msg()).ok();
+        backpressure.broadcast();
+
+        let admitted = tokio::spawn({
+            let backpressure = Arc::clone(&backpressure);
+            async move { backpressure.admit(10).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!admitted.is_finished());
+
+        broadcast_log_rx.recv().await.unwrap();
+        signal.received();
+
+        tokio::time::timeout(Duration::from_secs(5), admitted)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn counts_messages_that_are_not_broadcast_yet() {
+        let (broadcast_log_tx, _broadcast_log_rx) = broadcast::channel(4);
+        let backpressure = Backpressure::new(None, 4, broadcast_log_tx);
+
+        // The event loop hasn't broadcast any of these yet, but they still take up room.
+        assert!(backpressure.try_admit(1));
+        assert!(backpressure.try_admit(1));
+        assert!(backpressure.try_admit(1));
+        assert!(!backpressure.try_admit(1));
+
+        backpressure.broadcast();
+        assert!(backpressure.try_admit(1));
+    }
+
+    #[tokio::test]
+    async fn dropped_subscriber_releases_producers() {
+        let (broadcast_log_tx, broadcast_log_rx) = broadcast::channel(16);
+        let backpressure = Arc::new(Backpressure::new(Some(1), 16, broadcast_log_tx.clone()));
+        let signal = backpressure.caught_up_signal();
+
+        backpressure.admit(10).await;
+        broadcast_log_tx.send(msg()).ok();
+        backpressure.broadcast();
+
+        let admitted = tokio::spawn({
+            let backpressure = Arc::clone(&backpressure);
+            async move { backpressure.admit(10).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!admitted.is_finished());
+
+        drop(broadcast_log_rx);
+        drop(signal);
+
+        tokio::time::timeout(Duration::from_secs(5), admitted)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -1,22 +1,62 @@
 //! Server for the legacy `StoreHub` API.
 
+mod backpressure;
+mod memory_limit;
+mod metrics;
+mod options;
+mod persist;
+mod query;
+mod relay;
+mod security;
 pub mod shutdown;
+mod spill;
+mod store_events;
+
+pub use self::memory_limit::{OverflowPolicy, ServerMemoryLimit};
+pub use self::metrics::serve_metrics;
+pub use self::options::ServerOptions;
+pub use self::persist::PersistOptions;
+pub use self::relay::RelayOptions;
+pub use self::security::{ServerSecurity, TlsConfig};
+
+use self::backpressure::{Backpressure, CaughtUpSignal};
+use self::metrics::{DropReason, metrics};
+use self::persist::Persister;
+use self::query::ChunkQuery;
+use self::relay::{SkipAheadStream, StoreControlMessages};
+use self::spill::{SpillFile, SpillReader};
+use self::store_events::StoreEventQuery;
 
 use re_byte_size::SizeBytes;
 use re_log_encoding::codec::wire::decoder::Decode as _;
+use re_log_encoding::shared_memory::{SharedMemoryReader, SharedMemorySlot};
 use re_log_types::TableMsg;
+use re_protos::headers::RerunHeadersExtractorExt as _;
+use re_protos::sdk_comms::v1alpha1::AttachSharedMemoryRequest;
+use re_protos::sdk_comms::v1alpha1::AttachSharedMemoryResponse;
+use re_protos::sdk_comms::v1alpha1::AwaitFlushBarrierRequest;
+use re_protos::sdk_comms::v1alpha1::AwaitFlushBarrierResponse;
+use re_protos::sdk_comms::v1alpha1::FlushBarrier;
+use re_protos::sdk_comms::v1alpha1::QueryBufferedChunksRequest;
+use re_protos::sdk_comms::v1alpha1::QueryBufferedChunksResponse;
+use re_protos::sdk_comms::v1alpha1::ReadStoreEventsRequest;
+use re_protos::sdk_comms::v1alpha1::ReadStoreEventsResponse;
 use re_protos::sdk_comms::v1alpha1::ReadTablesRequest;
 use re_protos::sdk_comms::v1alpha1::ReadTablesResponse;
+use re_protos::sdk_comms::v1alpha1::SharedMemoryRef;
 use re_protos::sdk_comms::v1alpha1::WriteMessagesRequest;
 use re_protos::sdk_comms::v1alpha1::WriteTableRequest;
 use re_protos::sdk_comms::v1alpha1::WriteTableResponse;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::net::SocketAddr;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::net::TcpListener;
 use tokio::sync::broadcast;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
+use tokio::sync::watch;
 use tokio_stream::Stream;
 use tokio_stream::StreamExt as _;
 use tokio_stream::wrappers::BroadcastStream;
@@ -43,6 +83,16 @@ pub const DEFAULT_MEMORY_LIMIT: MemoryLimit = MemoryLimit::UNLIMITED;
 pub const MAX_DECODING_MESSAGE_SIZE: usize = u32::MAX as usize;
 pub const MAX_ENCODING_MESSAGE_SIZE: usize = MAX_DECODING_MESSAGE_SIZE;
 
+/// gRPC metadata header through which clients of `WriteMessages` can name themselves,
+/// e.g. to tell apart several robots streaming to the same viewer.
+///
+/// The SDK and the viewer send this based on `RERUN_CLIENT_NAME`, or the name of their executable.
+pub const CLIENT_NAME_HEADER: &str = re_protos::headers::RERUN_HTTP_HEADER_CLIENT_NAME;
+
+/// How long `AwaitFlushBarrier` waits for a `WriteMessages` stream to announce itself
+/// before concluding that the stream is unknown or already closed.
+const FLUSH_BARRIER_STREAM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 // Channel capacity is completely arbitrary, e just want something large enough
 // to handle bursts of messages. This is roughly 16 MiB of `Msg` (excluding their contents).
 const MESSAGE_QUEUE_CAPACITY: usize =
@@ -94,32 +144,39 @@ impl From<tonic::Status> for TonicStatusError {
 ///
 /// Clients send data to the server via `WriteMessages`. Any sent messages will be stored
 /// in the server's message queue. Messages are only removed if the server hits its configured
-/// memory limit.
+/// memory limit, see [`OverflowPolicy`] for what happens then.
 ///
 /// Clients receive data from the server via `ReadMessages`. Upon establishing the stream,
 /// the server sends all messages stored in its message queue, and subscribes the client
 /// to the queue. Any messages sent to the server through `WriteMessages` will be proxied
 /// to the open `ReadMessages` stream.
+///
+/// Clients running on the same machine may call `AttachSharedMemory` first, and then pass
+/// large payloads through a shared memory segment instead of the socket.
 pub async fn serve(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: impl Into<ServerOptions>,
     shutdown: shutdown::Shutdown,
 ) -> anyhow::Result<()> {
-    serve_impl(addr, MessageProxy::new(memory_limit), shutdown).await
+    let options = options.into();
+    let security = options.security.clone();
+    serve_impl(addr, MessageProxy::new(options), shutdown, security).await
 }
 
 async fn serve_impl(
     addr: SocketAddr,
     message_proxy: MessageProxy,
     shutdown: shutdown::Shutdown,
+    security: ServerSecurity,
 ) -> anyhow::Result<()> {
     let tcp_listener = TcpListener::bind(addr).await?;
     let incoming = TcpIncoming::from(tcp_listener).with_nodelay(Some(true));
 
+    let scheme = security.scheme();
     let connect_addr = if addr.ip().is_loopback() || addr.ip().is_unspecified() {
-        format!("rerun+http://127.0.0.1:{}/proxy", addr.port())
+        format!("{scheme}://127.0.0.1:{}/proxy", addr.port())
     } else {
-        format!("rerun+http://{addr}/proxy")
+        format!("{scheme}://{addr}/proxy")
     };
     re_log::info!(
         "Listening for gRPC connections on {addr}. Connect by running `rerun --connect {connect_addr}`"
@@ -130,17 +187,23 @@ async fn serve_impl(
 
     let routes = {
         let mut routes_builder = tonic::service::Routes::builder();
-        routes_builder.add_service(
+        routes_builder.add_service(tonic::service::interceptor::InterceptedService::new(
             re_protos::sdk_comms::v1alpha1::message_proxy_service_server::MessageProxyServiceServer::new(
                 message_proxy,
             )
             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
             .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
-        );
+            security.token_authenticator(),
+        ));
         routes_builder.routes()
     };
 
-    Server::builder()
+    let mut server = Server::builder();
+    if let Some(tls) = security.tls {
+        server = server.tls_config(tls.into_server_tls_config())?;
+    }
+
+    server
         .accept_http1(true) // Support `grpc-web` clients
         .layer(cors) // Allow CORS requests from web clients
         .layer(grpc_web) // Support `grpc-web` clients
@@ -151,6 +214,29 @@ async fn serve_impl(
     Ok(())
 }
 
+/// Start a Rerun server that relays messages from one producer to many subscribers,
+/// listening on `addr`.
+///
+/// The returned future must be polled for the server to make progress.
+///
+/// This is for e.g. a robot on a constrained link: it streams its data to the relay once,
+/// and any number of viewers connect to the relay to watch live.
+/// Unlike with [`serve`], a viewer on a slow connection doesn't get disconnected when it
+/// falls behind, but skips ahead instead, see [`RelayOptions`].
+///
+/// See [`serve`] for more information about what a Rerun server is.
+pub async fn serve_relay(
+    addr: SocketAddr,
+    options: impl Into<ServerOptions>,
+    shutdown: shutdown::Shutdown,
+    relay: RelayOptions,
+) -> anyhow::Result<()> {
+    let options = options.into();
+    let security = options.security.clone();
+    let (message_proxy, _, _) = MessageProxy::new_with_recv(options, Some(relay));
+    serve_impl(addr, message_proxy, shutdown, security).await
+}
+
 /// Start a Rerun server, listening on `addr`.
 ///
 /// The returned future must be polled for the server to make progress.
@@ -163,12 +249,15 @@ async fn serve_impl(
 /// See [`serve`] for more information about what a Rerun server is.
 pub async fn serve_from_channel(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: impl Into<ServerOptions>,
     shutdown: shutdown::Shutdown,
     channel_rx: re_smart_channel::Receiver<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    let options = options.into();
+    let security = options.security.clone();
+    let message_proxy = MessageProxy::new(options);
     let event_tx = message_proxy.event_tx.clone();
+    let backpressure = message_proxy.backpressure.clone();
 
     tokio::task::spawn_blocking(move || {
         use re_smart_channel::SmartMessagePayload;
@@ -206,14 +295,20 @@ pub async fn serve_from_channel(
                 }
             };
 
-            if event_tx.blocking_send(Event::Message(msg)).is_err() {
+            if let Some(backpressure) = &backpressure {
+                backpressure.admit_blocking(msg.total_size_bytes());
+            }
+            if event_tx
+                .blocking_send(Event::Message(ClientLogMsgProto { msg, client: None }))
+                .is_err()
+            {
                 re_log::debug!("shut down, closing sender");
                 break;
             }
         }
     });
 
-    if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+    if let Err(err) = serve_impl(addr, message_proxy, shutdown, security).await {
         re_log::error!("message proxy server crashed: {err}");
     }
 }
@@ -228,15 +323,18 @@ pub async fn serve_from_channel(
 /// See [`serve`] for more information about what a Rerun server is.
 pub fn spawn_from_rx_set(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: impl Into<ServerOptions>,
     shutdown: shutdown::Shutdown,
     rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    let options = options.into();
+    let security = options.security.clone();
+    let message_proxy = MessageProxy::new(options);
     let event_tx = message_proxy.event_tx.clone();
+    let backpressure = message_proxy.backpressure.clone();
 
     tokio::spawn(async move {
-        if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+        if let Err(err) = serve_impl(addr, message_proxy, shutdown, security).await {
             re_log::error!("message proxy server crashed: {err}");
         }
     });
@@ -284,7 +382,13 @@ pub fn spawn_from_rx_set(
                 }
             };
 
-            if event_tx.blocking_send(Event::Message(msg)).is_err() {
+            if let Some(backpressure) = &backpressure {
+                backpressure.admit_blocking(msg.total_size_bytes());
+            }
+            if event_tx
+                .blocking_send(Event::Message(ClientLogMsgProto { msg, client: None }))
+                .is_err()
+            {
                 re_log::debug!("shut down, closing sender");
                 break;
             }
@@ -302,74 +406,95 @@ pub fn spawn_from_rx_set(
 /// The server is spawned as a task on a `tokio` runtime. This function panics if the
 /// runtime is not available.
 ///
+/// To shut down without losing messages the server has already received,
+/// use [`shutdown::shutdown_with_drain`].
+///
+/// See [`ServerOptions`] for how to secure the server, write the received messages to disk,
+/// or bound the returned smart channel.
+///
 /// See [`serve`] for more information about what a Rerun server is.
 pub fn spawn_with_recv(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
-    shutdown: shutdown::Shutdown,
+    options: impl Into<ServerOptions>,
+    mut shutdown: shutdown::Shutdown,
 ) -> (
     re_smart_channel::Receiver<re_log_types::LogMsg>,
     crossbeam::channel::Receiver<re_log_types::TableMsg>,
 ) {
-    let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
-        re_uri::Scheme::RerunHttp,
-        addr,
-    ));
-    let (channel_log_tx, channel_log_rx) = re_smart_channel::smart_channel(
-        re_smart_channel::SmartMessageSource::MessageProxy(uri.clone()),
-        re_smart_channel::SmartChannelSource::MessageProxy(uri),
-    );
+    let options = options.into();
+    let security = options.security.clone();
+    let scheme = security.scheme();
+    let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(scheme, addr));
+    let sender_source = re_smart_channel::SmartMessageSource::MessageProxy(uri.clone());
+    let channel_source = re_smart_channel::SmartChannelSource::MessageProxy(uri.clone());
+    let (channel_log_tx, channel_log_rx) = if let Some(capacity) = options.channel_capacity {
+        re_smart_channel::smart_channel_bounded(sender_source, channel_source, capacity)
+    } else {
+        re_smart_channel::smart_channel(sender_source, channel_source)
+    };
     let (channel_table_tx, channel_table_rx) = crossbeam::channel::unbounded();
     let (message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
-        MessageProxy::new_with_recv(memory_limit);
+        MessageProxy::new_with_recv(options, None);
+    let caught_up = message_proxy.caught_up_signal();
+
+    // Only needed for draining, and would keep the server alive otherwise.
+    let drain = shutdown.take_drain();
+    let mut drain_event_tx = drain.is_some().then(|| message_proxy.event_tx.clone());
+
     tokio::spawn(async move {
-        if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
+        if let Err(err) = serve_impl(addr, message_proxy, shutdown, security).await {
             re_log::error!("message proxy server crashed: {err}");
         }
     });
     tokio::spawn(async move {
-        let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+        let mut forwarder = LogForwarder {
+            uri,
+            app_id_cache: Default::default(),
+            channel_log_tx,
+            client_log_txs: HashMap::new(),
+            drain,
+        };
 
         loop {
-            let msg = match broadcast_log_rx.recv().await {
-                Ok(msg) => re_log_encoding::protobuf_conversions::log_msg_from_proto(
-                    &mut app_id_cache,
-                    msg,
-                ),
-                Err(broadcast::error::RecvError::Closed) => {
-                    re_log::debug!("message proxy server shut down, closing receiver");
-                    channel_log_tx.quit(None).ok();
-                    break;
+            let result = tokio::select! {
+                result = broadcast_log_rx.recv() => {
+                    if let Some(caught_up) = &caught_up {
+                        caught_up.received();
+                    }
+                    result
                 }
-                Err(broadcast::error::RecvError::Lagged(n)) => {
-                    re_log::warn!(
-                        "message proxy receiver dropped {n} messages due to backpressure"
-                    );
-                    continue;
+                () = forwarder.stopping() => {
+                    forwarder.drain(&mut broadcast_log_rx, drain_event_tx.take()).await;
+                    break;
                 }
             };
-            match msg {
-                Ok(mut log_msg) => {
-                    // Insert the timestamp metadata into the Arrow message for accurate e2e latency measurements.
-                    // Note that this function is only called by the viewer
-                    // (that's what the message-receiver is connected to).
-                    log_msg.insert_arrow_record_batch_metadata(
-                        re_sorbet::timestamp_metadata::KEY_TIMESTAMP_VIEWER_IPC_DECODED.to_owned(),
-                        re_sorbet::timestamp_metadata::now_timestamp(),
-                    );
 
-                    if channel_log_tx.send(log_msg).is_err() {
+            match result {
+                Ok(msg) => {
+                    if !forwarder.forward(msg).await {
                         re_log::debug!(
                             "message proxy smart channel receiver closed, closing sender"
                         );
                         break;
                     }
                 }
-                Err(err) => {
-                    re_log::error!("dropping LogMsg due to failed decode: {err}");
+                Err(broadcast::error::RecvError::Closed) => {
+                    re_log::debug!("message proxy server shut down, closing receiver");
+                    forwarder.channel_log_tx.quit(None).ok();
+                    break;
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    re_log::warn!(
+                        "message proxy receiver dropped {n} messages due to backpressure"
+                    );
+                    forwarder.dropped(DropReason::SlowClient, n);
                 }
             }
         }
+
+        if let Some(drain) = &forwarder.drain {
+            drain.done.cancel();
+        }
     });
     tokio::spawn(async move {
         loop {
@@ -408,21 +533,228 @@ pub fn spawn_with_recv(
     (channel_log_rx, channel_table_rx)
 }
 
+/// Forwards log messages from the broadcast channel into the smart channel of
+/// [`spawn_with_recv`].
+struct LogForwarder {
+    uri: re_uri::ProxyUri,
+    app_id_cache: re_log_encoding::CachingApplicationIdInjector,
+    channel_log_tx: re_smart_channel::Sender<re_log_types::LogMsg>,
+
+    /// One sender per client, so that the viewer can tell where each message came from.
+    ///
+    /// Entries are removed once their client disconnected and all of its messages were forwarded.
+    client_log_txs: HashMap<u64, ClientLogTx>,
+
+    /// Set if the server was started with [`shutdown::shutdown_with_drain`].
+    drain: Option<Arc<shutdown::Drain>>,
+}
+
+/// See [`LogForwarder::client_log_txs`].
+struct ClientLogTx {
+    /// Only the `WriteMessages` call of the client and its messages keep the client alive.
+    client: std::sync::Weak<ClientInfo>,
+    tx: re_smart_channel::Sender<re_log_types::LogMsg>,
+}
+
+impl LogForwarder {
+    /// Returns `false` if the receiving end of the smart channel was closed.
+    ///
+    /// Waits for room if the smart channel is bounded and full.
+    async fn forward(&mut self, ClientLogMsgProto { msg, client }: ClientLogMsgProto) -> bool {
+        let mut log_msg = match re_log_encoding::protobuf_conversions::log_msg_from_proto(
+            &mut self.app_id_cache,
+            msg,
+        ) {
+            Ok(log_msg) => log_msg,
+            Err(err) => {
+                re_log::error!("dropping LogMsg due to failed decode: {err}");
+                self.dropped(DropReason::Undecodable, 1);
+                return true;
+            }
+        };
+
+        // Insert the timestamp metadata into the Arrow message for accurate e2e latency measurements.
+        // Note that this function is only called by the viewer
+        // (that's what the message-receiver is connected to).
+        log_msg.insert_arrow_record_batch_metadata(
+            re_sorbet::timestamp_metadata::KEY_TIMESTAMP_VIEWER_IPC_DECODED.to_owned(),
+            re_sorbet::timestamp_metadata::now_timestamp(),
+        );
+
+        let Some(client) = client else {
+            return self.channel_log_tx.send_async(log_msg).await.is_ok();
+        };
+
+        let log_tx = &self
+            .client_log_txs
+            .entry(client.id)
+            .or_insert_with(|| ClientLogTx {
+                client: Arc::downgrade(&client),
+                tx: self.channel_log_tx.clone_as(
+                    re_smart_channel::SmartMessageSource::MessageProxyClient {
+                        uri: self.uri.clone(),
+                        peer_addr: client.addr,
+                        client_name: client.name.clone(),
+                    },
+                ),
+            })
+            .tx;
+        let is_connected = log_tx.send_async(log_msg).await.is_ok();
+
+        drop(client);
+        self.forget_disconnected_clients();
+        is_connected
+    }
+
+    /// Drops the senders of clients that disconnected and have no messages left in flight.
+    fn forget_disconnected_clients(&mut self) {
+        self.client_log_txs.retain(|id, client_log_tx| {
+            let is_alive = client_log_tx.client.strong_count() > 0;
+            if !is_alive {
+                re_log::debug!("Forgetting disconnected client #{id}");
+            }
+            is_alive
+        });
+    }
+
+    fn dropped(&self, reason: DropReason, num_messages: u64) {
+        metrics().dropped(reason, num_messages);
+        if let Some(drain) = &self.drain {
+            drain.dropped(num_messages);
+        }
+    }
+
+    /// Resolves once we're asked to drain, or never if draining isn't enabled.
+    async fn stopping(&self) {
+        if let Some(drain) = &self.drain {
+            drain.stopping.cancelled().await;
+        } else {
+            std::future::pending::<()>().await;
+        }
+    }
+
+    /// Forwards everything the server received so far, unless the drain times out first,
+    /// then closes the smart channel.
+    async fn drain(
+        &mut self,
+        broadcast_log_rx: &mut broadcast::Receiver<ClientLogMsgProto>,
+        event_tx: Option<mpsc::Sender<Event>>,
+    ) {
+        let Some(drain) = self.drain.clone() else {
+            return;
+        };
+
+        re_log::debug!("Draining message proxy server…");
+
+        // Wait for the event loop to broadcast all messages queued so far.
+        if let Some(event_tx) = event_tx {
+            let (on_done, done) = oneshot::channel();
+            let barrier_reached = async {
+                if event_tx.send(Event::FlushBarrier(on_done)).await.is_ok() {
+                    done.await.ok();
+                }
+            };
+
+            tokio::select! {
+                () = barrier_reached => {}
+                () = drain.timed_out.cancelled() => {
+                    let num_queued = event_tx.max_capacity() - event_tx.capacity();
+                    re_log::warn!(
+                        "Timed out draining the message proxy server, dropping {num_queued} queued events"
+                    );
+                    drain.dropped(num_queued as u64);
+                }
+            }
+        }
+
+        loop {
+            match broadcast_log_rx.try_recv() {
+                Ok(msg) => {
+                    // A bounded smart channel may keep us waiting.
+                    let forwarded = tokio::select! {
+                        forwarded = self.forward(msg) => forwarded,
+                        () = drain.timed_out.cancelled() => {
+                            drain.dropped(1 + broadcast_log_rx.len() as u64);
+                            break;
+                        }
+                    };
+                    if !forwarded {
+                        break;
+                    }
+                }
+                Err(broadcast::error::TryRecvError::Lagged(n)) => {
+                    self.dropped(DropReason::SlowClient, n);
+                }
+                Err(
+                    broadcast::error::TryRecvError::Empty | broadcast::error::TryRecvError::Closed,
+                ) => {
+                    break;
+                }
+            }
+        }
+
+        self.channel_log_tx.quit(None).ok();
+    }
+}
+
 enum Event {
     /// New client connected, requesting full history and subscribing to new messages.
     NewClient(
         oneshot::Sender<(
-            Vec<LogOrTableMsgProto>,
-            broadcast::Receiver<LogMsgProto>,
+            History,
+            broadcast::Receiver<ClientLogMsgProto>,
             broadcast::Receiver<TableMsgProto>,
         )>,
     ),
 
+    /// A client queried the history, without subscribing to new messages.
+    History(oneshot::Sender<History>),
+
     /// A client sent a message.
-    Message(LogMsgProto),
+    Message(ClientLogMsgProto),
 
     /// A client sent a table.
     Table(TableMsgProto),
+
+    /// A client sent a flush barrier.
+    ///
+    /// Answered once all previous events have been handled.
+    FlushBarrier(oneshot::Sender<()>),
+}
+
+/// A client connected to `WriteMessages`.
+#[derive(Debug)]
+struct ClientInfo {
+    /// Unique among all clients of a server.
+    id: u64,
+
+    /// Address of the client, if known.
+    addr: Option<SocketAddr>,
+
+    /// Name the client sent in the [`CLIENT_NAME_HEADER`], if any.
+    name: Option<String>,
+}
+
+impl std::fmt::Display for ClientInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.id)?;
+        if let Some(name) = &self.name {
+            write!(f, " {name:?}")?;
+        }
+        if let Some(addr) = &self.addr {
+            write!(f, " at {addr}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A log message, and the client that sent it.
+#[derive(Clone)]
+struct ClientLogMsgProto {
+    msg: LogMsgProto,
+
+    /// `None` if the message didn't come in over the network, e.g. in [`serve_from_channel`].
+    client: Option<Arc<ClientInfo>>,
 }
 
 #[derive(Clone)]
@@ -463,23 +795,25 @@ impl From<TableMsgProto> for LogOrTableMsgProto {
 #[derive(Default)]
 struct MsgQueue {
     /// Messages stored in order of arrival, and garbage collected if the server hits the memory limit.
-    queue: VecDeque<LogOrTableMsgProto>,
+    ///
+    /// Shared with the [`History`] handed out to clients, so that taking one is cheap.
+    queue: VecDeque<Arc<LogOrTableMsgProto>>,
 
     /// Total size of [`Self::queue`] in bytes.
     size_bytes: u64,
 }
 
 impl MsgQueue {
-    pub fn iter(&self) -> impl Iterator<Item = &LogOrTableMsgProto> {
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<LogOrTableMsgProto>> {
         self.queue.iter()
     }
 
     pub fn push_back(&mut self, msg: LogOrTableMsgProto) {
         self.size_bytes += msg.total_size_bytes();
-        self.queue.push_back(msg);
+        self.queue.push_back(Arc::new(msg));
     }
 
-    pub fn pop_front(&mut self) -> Option<LogOrTableMsgProto> {
+    pub fn pop_front(&mut self) -> Option<Arc<LogOrTableMsgProto>> {
         if let Some(msg) = self.queue.pop_front() {
             self.size_bytes -= msg.total_size_bytes();
             Some(msg)
@@ -519,45 +853,67 @@ struct MessageBuffer {
 
     /// These are never garbage collected.
     persistent: MsgQueue,
+
+    /// Messages moved out of [`Self::disposable`] and [`Self::static_`] to make room,
+    /// if the server uses [`OverflowPolicy::SpillToDisk`].
+    spilled: Option<SpillFile>,
 }
 
-impl MessageBuffer {
-    fn size_bytes(&self) -> u64 {
-        let Self {
-            disposable,
-            static_,
-            persistent,
-        } = self;
-        disposable.size_bytes + static_.size_bytes + persistent.size_bytes
-    }
+/// The messages of a [`MessageBuffer`] at some point in time, as sent to new clients.
+///
+/// Taken on the event loop, so it only shares the messages in memory: they are cloned
+/// one at a time, as the client's stream is polled.
+struct History {
+    persistent: Vec<Arc<LogOrTableMsgProto>>,
 
-    fn all(&self) -> Vec<LogOrTableMsgProto> {
-        re_tracing::profile_function!();
+    /// Read lazily, so that a large spill file doesn't end up in memory all at once.
+    spilled: Option<SpillReader>,
 
+    /// Static data, then all other messages.
+    in_memory: Vec<Arc<LogOrTableMsgProto>>,
+}
+
+impl History {
+    /// All messages, in the order they should be replayed.
+    fn into_messages(self) -> impl Iterator<Item = LogOrTableMsgProto> + Send {
         let Self {
-            disposable,
-            static_,
             persistent,
+            spilled,
+            in_memory,
         } = self;
 
-        // NOTE: the order here is important!
-        // TODO(#6523): make this behavior configurable
-        itertools::chain!(persistent.iter(), static_.iter(), disposable.iter(),)
-            .cloned()
-            .collect()
-    }
+        let spilled = spilled.into_iter().flatten().map_while(|msg| match msg {
+            Ok(msg) => Some(LogOrTableMsgProto::from(msg)),
+            Err(err) => {
+                re_log::error!("Failed to read back messages spilled to disk: {err}");
+                None
+            }
+        });
 
-    fn add_table(&mut self, table: TableMsgProto) {
-        self.disposable.push_back(table.into());
+        itertools::chain!(
+            persistent.into_iter().map(Arc::unwrap_or_clone),
+            spilled,
+            in_memory.into_iter().map(Arc::unwrap_or_clone),
+        )
     }
+}
+
+/// Which queue of the [`MessageBuffer`] a message goes into.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MsgQueueKind {
+    Disposable,
+    Static,
+    Persistent,
+}
 
-    fn add_log_msg(&mut self, msg: LogMsgProto) {
+impl MsgQueueKind {
+    fn of_log_msg(msg: &LogMsgProto) -> Option<Self> {
         let Some(inner) = &msg.msg else {
             re_log::error!(
                 "{}",
                 re_protos::missing_field!(re_protos::log_msg::v1alpha1::LogMsg, "msg")
             );
-            return;
+            return None;
         };
 
         // We put store info, blueprint data, and blueprint activation commands
@@ -565,9 +921,10 @@ impl MessageBuffer {
         use re_protos::log_msg::v1alpha1::log_msg::Msg;
         match inner {
             // Store info, blueprint activation commands
-            Msg::SetStoreInfo(..) | Msg::BlueprintActivationCommand(..) => {
-                self.persistent.push_back(msg.into());
-            }
+            Msg::SetStoreInfo(..) | Msg::BlueprintActivationCommand(..) => Some(Self::Persistent),
+
+            // Must be replayed after the data it drops, which is temporal data.
+            Msg::DropTimeRangeCommand(..) => Some(Self::Disposable),
 
             Msg::ArrowMsg(inner) => {
                 let is_blueprint = inner
@@ -577,16 +934,128 @@ impl MessageBuffer {
 
                 if is_blueprint {
                     // Persist blueprint messages forever.
-                    self.persistent.push_back(msg.into());
+                    Some(Self::Persistent)
                 } else if inner.is_static == Some(true) {
-                    self.static_.push_back(msg.into());
+                    Some(Self::Static)
                 } else {
                     // Recording data
-                    self.disposable.push_back(msg.into());
+                    Some(Self::Disposable)
                 }
             }
         }
     }
+}
+
+impl MessageBuffer {
+    fn size_bytes(&self) -> u64 {
+        let Self {
+            disposable,
+            static_,
+            persistent,
+            spilled: _, // on disk
+        } = self;
+        disposable.size_bytes + static_.size_bytes + persistent.size_bytes
+    }
+
+    fn history(&self) -> History {
+        re_tracing::profile_function!();
+
+        let Self {
+            disposable,
+            static_,
+            persistent,
+            spilled,
+        } = self;
+
+        let spilled = spilled.as_ref().and_then(|spilled| match spilled.read() {
+            Ok(reader) => Some(reader),
+            Err(err) => {
+                re_log::error!("Failed to read back messages spilled to disk: {err}");
+                None
+            }
+        });
+
+        // NOTE: the order here is important!
+        // TODO(#6523): make this behavior configurable
+        History {
+            persistent: persistent.iter().cloned().collect(),
+            spilled,
+            in_memory: static_.iter().chain(disposable.iter()).cloned().collect(),
+        }
+    }
+
+    fn add_table(&mut self, table: TableMsgProto) {
+        self.disposable.push_back(table.into());
+    }
+
+    fn add_log_msg(&mut self, msg: LogMsgProto, kind: MsgQueueKind) {
+        match kind {
+            MsgQueueKind::Disposable => self.disposable.push_back(msg.into()),
+            MsgQueueKind::Static => self.static_.push_back(msg.into()),
+            MsgQueueKind::Persistent => self.persistent.push_back(msg.into()),
+        }
+    }
+
+    /// Like [`Self::gc`], but moves log messages to disk instead of dropping them.
+    ///
+    /// Falls back to dropping messages if the spill file cannot be written.
+    pub fn spill(&mut self, max_bytes: u64) {
+        if self.size_bytes() <= max_bytes {
+            // We're not using too much memory.
+            return;
+        }
+
+        re_tracing::profile_scope!("Spill messages");
+
+        let spilled = match self.spilled.take().map_or_else(SpillFile::new, Ok) {
+            Ok(spilled) => self.spilled.insert(spilled),
+            Err(err) => {
+                re_log::error_once!(
+                    "Failed to create a file to spill messages to, dropping them instead: {err}"
+                );
+                self.gc(max_bytes);
+                return;
+            }
+        };
+
+        re_log::info_once!(
+            "Memory limit ({}) exceeded. Moving old log messages of the gRPC proxy server to disk.",
+            re_format::format_bytes(max_bytes as _)
+        );
+
+        let mut messages_spilled = 0;
+        let mut bytes_spilled = 0;
+        let mut size_bytes =
+            self.disposable.size_bytes + self.static_.size_bytes + self.persistent.size_bytes;
+
+        // Oldest recording data first, static data last, just like `gc`.
+        while max_bytes < size_bytes {
+            let Some(msg) = self
+                .disposable
+                .pop_front()
+                .or_else(|| self.static_.pop_front())
+            else {
+                break;
+            };
+            size_bytes -= msg.total_size_bytes();
+            bytes_spilled += msg.total_size_bytes();
+            messages_spilled += 1;
+
+            // Tables can't be spilled, so they are simply dropped.
+            if let LogOrTableMsgProto::LogMsg(msg) = msg.as_ref()
+                && let Err(err) = spilled.push(msg)
+            {
+                re_log::error_once!("Failed to spill messages to disk: {err}");
+            }
+        }
+
+        metrics().evicted(messages_spilled, bytes_spilled);
+
+        re_log::trace!(
+            "Spilled {messages_spilled} message(s), {} on disk in total",
+            re_format::format_bytes(spilled.size_bytes() as _)
+        );
+    }
 
     pub fn gc(&mut self, max_bytes: u64) {
         if self.size_bytes() <= max_bytes {
@@ -624,6 +1093,7 @@ impl MessageBuffer {
         }
 
         let bytes_dropped = start_size - self.size_bytes();
+        metrics().evicted(messages_dropped, bytes_dropped);
 
         re_log::trace!(
             "Dropped {} bytes in {messages_dropped} message(s)",
@@ -645,10 +1115,10 @@ impl MessageBuffer {
 ///
 /// Handles message history, and broadcasts messages to clients.
 struct EventLoop {
-    server_memory_limit: MemoryLimit,
+    server_memory_limit: ServerMemoryLimit,
 
     /// New log messages are broadcast to all clients.
-    broadcast_log_tx: broadcast::Sender<LogMsgProto>,
+    broadcast_log_tx: broadcast::Sender<ClientLogMsgProto>,
 
     /// New table messages are broadcast to all clients.
     broadcast_table_tx: broadcast::Sender<TableMsgProto>,
@@ -657,14 +1127,30 @@ struct EventLoop {
     event_rx: mpsc::Receiver<Event>,
 
     messages: MessageBuffer,
+
+    /// Writes every log message to disk, if enabled.
+    persister: Option<Persister>,
+
+    /// Only set for [`OverflowPolicy::Backpressure`].
+    backpressure: Option<Arc<Backpressure>>,
+
+    /// Only set for relays, see [`RelayOptions`].
+    store_control: Option<Arc<parking_lot::Mutex<StoreControlMessages>>>,
+
+    /// Identifies this server in the metrics.
+    metrics_labels: metrics::ServerLabels,
 }
 
 impl EventLoop {
     fn new(
-        server_memory_limit: MemoryLimit,
+        server_memory_limit: ServerMemoryLimit,
         event_rx: mpsc::Receiver<Event>,
-        broadcast_log_tx: broadcast::Sender<LogMsgProto>,
+        broadcast_log_tx: broadcast::Sender<ClientLogMsgProto>,
         broadcast_table_tx: broadcast::Sender<TableMsgProto>,
+        backpressure: Option<Arc<Backpressure>>,
+        persister: Option<Persister>,
+        store_control: Option<Arc<parking_lot::Mutex<StoreControlMessages>>>,
+        server_id: u64,
     ) -> Self {
         Self {
             server_memory_limit,
@@ -672,6 +1158,12 @@ impl EventLoop {
             broadcast_table_tx,
             event_rx,
             messages: Default::default(),
+            persister,
+            backpressure,
+            store_control,
+            metrics_labels: metrics::ServerLabels {
+                server: server_id.to_string(),
+            },
         }
     }
 
@@ -683,40 +1175,88 @@ impl EventLoop {
 
             match event {
                 Event::NewClient(channel) => self.handle_new_client(channel),
+                Event::History(channel) => {
+                    channel.send(self.messages.history()).ok();
+                }
                 Event::Message(msg) => self.handle_msg(msg),
                 Event::Table(table) => self.handle_table(table),
+                Event::FlushBarrier(on_done) => {
+                    on_done.send(()).ok();
+                }
             }
+
+            self.update_metrics();
         }
+
+        for gauges in [
+            &metrics().event_queue_depth,
+            &metrics().broadcast_queue_depth,
+            &metrics().history_bytes,
+        ] {
+            gauges.remove(&self.metrics_labels);
+        }
+    }
+
+    fn update_metrics(&self) {
+        let metrics = metrics();
+        metrics
+            .event_queue_depth
+            .get_or_create(&self.metrics_labels)
+            .set(self.event_rx.len() as _);
+        metrics
+            .broadcast_queue_depth
+            .get_or_create(&self.metrics_labels)
+            .set(self.broadcast_log_tx.len() as _);
+        metrics
+            .history_bytes
+            .get_or_create(&self.metrics_labels)
+            .set(self.messages.size_bytes() as _);
     }
 
     fn handle_new_client(
         &self,
         channel: oneshot::Sender<(
-            Vec<LogOrTableMsgProto>,
-            broadcast::Receiver<LogMsgProto>,
+            History,
+            broadcast::Receiver<ClientLogMsgProto>,
             broadcast::Receiver<TableMsgProto>,
         )>,
     ) {
         channel
             .send((
-                self.messages.all(),
+                self.messages.history(),
                 self.broadcast_log_tx.subscribe(),
                 self.broadcast_table_tx.subscribe(),
             ))
             .ok();
     }
 
-    fn handle_msg(&mut self, msg: LogMsgProto) {
+    fn handle_msg(&mut self, msg: ClientLogMsgProto) {
+        if let Some(persister) = &self.persister {
+            persister.send(msg.msg.clone());
+        }
+
+        // Before broadcasting, so that subscribers that skip the message get it anyway.
+        if let Some(store_control) = &self.store_control {
+            store_control.lock().update(&msg.msg);
+        }
+
         self.broadcast_log_tx.send(msg.clone()).ok();
+        if let Some(backpressure) = &self.backpressure {
+            backpressure.broadcast();
+        }
 
         if self.is_history_disabled() {
             // no need to gc or maintain history
             return;
         }
 
-        self.gc_if_using_too_much_ram();
+        let Some(kind) = MsgQueueKind::of_log_msg(&msg.msg) else {
+            return;
+        };
 
-        self.messages.add_log_msg(msg);
+        if self.make_room(msg.msg.total_size_bytes(), kind) {
+            self.messages.add_log_msg(msg.msg, kind);
+        }
     }
 
     fn handle_table(&mut self, table: TableMsgProto) {
@@ -727,76 +1267,269 @@ impl EventLoop {
             return;
         }
 
-        self.gc_if_using_too_much_ram();
-
-        self.messages.add_table(table);
+        if self.make_room(table.total_size_bytes(), MsgQueueKind::Disposable) {
+            self.messages.add_table(table);
+        }
     }
 
     fn is_history_disabled(&self) -> bool {
-        self.server_memory_limit.max_bytes.is_some_and(|b| b == 0)
+        self.server_memory_limit.max_bytes() == Some(0)
     }
 
-    fn gc_if_using_too_much_ram(&mut self) {
-        let Some(max_bytes) = self.server_memory_limit.max_bytes else {
+    /// Makes room in the history for a new message, according to the [`OverflowPolicy`].
+    ///
+    /// Returns `false` if the message should not be added to the history.
+    fn make_room(&mut self, msg_size_bytes: u64, kind: MsgQueueKind) -> bool {
+        let Some(max_bytes) = self.server_memory_limit.max_bytes() else {
             // Unlimited memory!
-            return;
+            return true;
         };
 
-        self.messages.gc(max_bytes as _);
-    }
-}
+        match self.server_memory_limit.overflow_policy {
+            OverflowPolicy::DropOldest | OverflowPolicy::Backpressure => {
+                self.messages.gc(max_bytes);
+                true
+            }
 
-impl SizeBytes for TableMsgProto {
+            OverflowPolicy::DropNewest => {
+                if kind == MsgQueueKind::Persistent
+                    || self.messages.size_bytes() + msg_size_bytes <= max_bytes
+                {
+                    true
+                } else {
+                    re_log::info_once!(
+                        "Memory limit ({}) exceeded. No longer adding new log messages to the history of the gRPC proxy server. Clients connecting after this will not see the full history.",
+                        re_format::format_bytes(max_bytes as _)
+                    );
+                    metrics().evicted(1, msg_size_bytes);
+                    false
+                }
+            }
+
+            OverflowPolicy::SpillToDisk => {
+                self.messages.spill(max_bytes);
+                true
+            }
+        }
+    }
+}
+
+impl SizeBytes for TableMsgProto {
     fn heap_size_bytes(&self) -> u64 {
         let Self { id, data } = self;
         id.heap_size_bytes() + data.heap_size_bytes()
     }
 }
 
+type FlushBarriers = parking_lot::Mutex<HashMap<String, watch::Sender<Option<u64>>>>;
+
+/// Removes the flush barrier entry created by an `AwaitFlushBarrier` request
+/// if no `WriteMessages` stream ever claimed it and nobody else is waiting on it.
+struct ForgetUnknownFlushStream<'a> {
+    flush_barriers: &'a FlushBarriers,
+    stream_id: &'a str,
+}
+
+impl Drop for ForgetUnknownFlushStream<'_> {
+    fn drop(&mut self) {
+        let mut flush_barriers = self.flush_barriers.lock();
+        if let Some(reached) = flush_barriers.get(self.stream_id)
+            && reached.borrow().is_none()
+            && reached.receiver_count() == 0
+        {
+            flush_barriers.remove(self.stream_id);
+        }
+    }
+}
+
 pub struct MessageProxy {
     _queue_task_handle: tokio::task::JoinHandle<()>,
     event_tx: mpsc::Sender<Event>,
+
+    /// Shared memory segments that local clients asked us to attach to, keyed by path.
+    shared_memory: parking_lot::Mutex<HashMap<String, Arc<SharedMemoryReader>>>,
+
+    /// The last flush barrier reached by each `WriteMessages` stream, keyed by stream id.
+    ///
+    /// `None` for streams that have only been waited on so far, see [`ForgetUnknownFlushStream`].
+    /// Entries are removed once their stream closes.
+    flush_barriers: FlushBarriers,
+
+    /// Identifies this server in the metrics.
+    server_id: u64,
+
+    /// Id of the next client to connect to `WriteMessages`.
+    next_client_id: AtomicU64,
+
+    /// See [`ServerOptions::isolate_clients`].
+    isolate_clients: bool,
+
+    /// Only set for relays, whose subscribers skip ahead instead of being disconnected
+    /// when they fall behind, see [`RelayOptions`].
+    store_control: Option<Arc<parking_lot::Mutex<StoreControlMessages>>>,
+
+    /// Only set for [`OverflowPolicy::Backpressure`].
+    backpressure: Option<Arc<Backpressure>>,
 }
 
 impl MessageProxy {
-    pub fn new(server_memory_limit: MemoryLimit) -> Self {
-        Self::new_with_recv(server_memory_limit).0
+    pub fn new(options: impl Into<ServerOptions>) -> Self {
+        Self::new_with_recv(options.into(), None).0
     }
 
     fn new_with_recv(
-        server_memory_limit: MemoryLimit,
+        options: ServerOptions,
+        relay: Option<RelayOptions>,
     ) -> (
         Self,
-        broadcast::Receiver<LogMsgProto>,
+        broadcast::Receiver<ClientLogMsgProto>,
         broadcast::Receiver<TableMsgProto>,
     ) {
+        let ServerOptions {
+            memory_limit: server_memory_limit,
+            isolate_clients,
+            security: _,
+            persist,
+            channel_capacity: _,
+        } = options;
+
+        let persister = persist.and_then(|persist| {
+            Persister::new(persist)
+                .map_err(|err| re_log::error!("failed to persist received messages: {err:#}"))
+                .ok()
+        });
+
+        // `broadcast::channel` panics on zero capacity.
+        let broadcast_log_capacity = relay
+            .map_or(MESSAGE_QUEUE_CAPACITY, |relay| relay.ring_buffer_capacity)
+            .max(1);
+
         let (event_tx, event_rx) = mpsc::channel(MESSAGE_QUEUE_CAPACITY);
-        let (broadcast_log_tx, broadcast_log_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
+        let (broadcast_log_tx, broadcast_log_rx) = broadcast::channel(broadcast_log_capacity);
         let (broadcast_table_tx, broadcast_table_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
-
-        let task_handle = tokio::spawn(async move {
-            EventLoop::new(
-                server_memory_limit,
-                event_rx,
-                broadcast_log_tx,
-                broadcast_table_tx,
-            )
-            .run_in_place()
-            .await;
+        let server_id = metrics::next_server_id();
+
+        let backpressure = (server_memory_limit.overflow_policy == OverflowPolicy::Backpressure)
+            .then(|| {
+                Arc::new(Backpressure::new(
+                    server_memory_limit.max_bytes(),
+                    broadcast_log_capacity,
+                    broadcast_log_tx.clone(),
+                ))
+            });
+
+        let store_control = relay.map(|_| Arc::new(parking_lot::Mutex::new(Default::default())));
+
+        let task_handle = tokio::spawn({
+            let backpressure = backpressure.clone();
+            let store_control = store_control.clone();
+            async move {
+                EventLoop::new(
+                    server_memory_limit,
+                    event_rx,
+                    broadcast_log_tx,
+                    broadcast_table_tx,
+                    backpressure,
+                    persister,
+                    store_control,
+                    server_id,
+                )
+                .run_in_place()
+                .await;
+            }
         });
 
         (
             Self {
                 _queue_task_handle: task_handle,
                 event_tx,
+                shared_memory: Default::default(),
+                flush_barriers: Default::default(),
+                server_id,
+                next_client_id: AtomicU64::new(0),
+                isolate_clients,
+                store_control,
+                backpressure,
             },
             broadcast_log_rx,
             broadcast_table_rx,
         )
     }
 
-    async fn push_msg(&self, msg: LogMsgProto) {
-        self.event_tx.send(Event::Message(msg)).await.ok();
+    /// Fill in the payload of `log_msg` from the shared memory segment referenced by `shm_ref`.
+    fn resolve_shared_memory_payload(
+        &self,
+        log_msg: &mut LogMsgProto,
+        shm_ref: &SharedMemoryRef,
+    ) -> anyhow::Result<()> {
+        use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+        let Some(Msg::ArrowMsg(arrow_msg)) = &mut log_msg.msg else {
+            anyhow::bail!("shared memory payload attached to a non-Arrow message");
+        };
+
+        // Payloads can be big, so we don't hold the lock while copying them.
+        let Some(reader) = self.shared_memory.lock().get(&shm_ref.path).cloned() else {
+            anyhow::bail!("unknown shared memory segment {:?}", shm_ref.path);
+        };
+
+        let payload = reader.read_and_release(SharedMemorySlot {
+            offset: shm_ref.offset,
+            length: shm_ref.length,
+        })?;
+        arrow_msg.payload = payload.into();
+
+        Ok(())
+    }
+
+    /// Marks `barrier` as reached once all messages pushed before it have been handled.
+    ///
+    /// Barrier 0 is sent first, to announce the stream to `AwaitFlushBarrier` requests.
+    async fn reach_flush_barrier(&self, FlushBarrier { stream_id, barrier }: FlushBarrier) {
+        let (on_done, done) = oneshot::channel();
+        if self
+            .event_tx
+            .send(Event::FlushBarrier(on_done))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        done.await.ok();
+
+        self.flush_barriers
+            .lock()
+            .entry(stream_id)
+            .or_insert_with(|| watch::channel(None).0)
+            .send_replace(Some(barrier));
+    }
+
+    /// Subscribers of the broadcast channel report their progress through this,
+    /// if the server uses [`OverflowPolicy::Backpressure`].
+    fn caught_up_signal(&self) -> Option<CaughtUpSignal> {
+        self.backpressure
+            .as_ref()
+            .map(|backpressure| backpressure.caught_up_signal())
+    }
+
+    async fn push_msg(&self, mut msg: LogMsgProto, client: &Arc<ClientInfo>) {
+        if self.isolate_clients {
+            isolate_recording_id(&mut msg, client.id);
+        }
+
+        // Waiting here stops us from reading the `WriteMessages` stream,
+        // which makes the client wait in turn, through gRPC flow control.
+        if let Some(backpressure) = &self.backpressure {
+            backpressure.admit(msg.total_size_bytes()).await;
+        }
+
+        self.event_tx
+            .send(Event::Message(ClientLogMsgProto {
+                msg,
+                client: Some(Arc::clone(client)),
+            }))
+            .await
+            .ok();
     }
 
     async fn push_table(&self, table: TableMsgProto) {
@@ -819,7 +1552,7 @@ impl MessageProxy {
 
         let history = tokio_stream::iter(
             history
-                .into_iter()
+                .into_messages()
                 .filter_map(|log_msg| {
                     if let LogOrTableMsgProto::LogMsg(log_msg) = log_msg {
                         Some(ReadMessagesResponse {
@@ -831,20 +1564,69 @@ impl MessageProxy {
                 })
                 .map(Ok),
         );
-        let channel = BroadcastStream::new(log_channel).map(|result| {
-            result
-                .map(|log_msg| ReadMessagesResponse {
-                    log_msg: Some(log_msg),
-                })
-                .map_err(|err| {
+        let caught_up = self.caught_up_signal();
+
+        if let Some(store_control) = &self.store_control {
+            let channel = SkipAheadStream::new(log_channel, Arc::clone(store_control), caught_up)
+                .map(|log_msg| {
+                    Ok(ReadMessagesResponse {
+                        log_msg: Some(log_msg),
+                    })
+                });
+            return Box::pin(history.chain(channel));
+        }
+
+        let channel = BroadcastStream::new(log_channel).map(move |result| {
+            if let Some(caught_up) = &caught_up {
+                caught_up.received();
+            }
+            match result {
+                Ok(log_msg) => Ok(ReadMessagesResponse {
+                    log_msg: Some(log_msg.msg),
+                }),
+                Err(err) => {
+                    let tokio_stream::wrappers::errors::BroadcastStreamRecvError::Lagged(n) = err;
+                    metrics().dropped(DropReason::SlowClient, n);
                     re_log::error!("Error reading message from broadcast channel: {err}");
-                    tonic::Status::internal("internal channel error")
-                })
+                    Err(tonic::Status::internal("internal channel error"))
+                }
+            }
         });
 
         Box::pin(history.chain(channel))
     }
 
+    /// Answers from the buffered history, not from the chunk store of the hosting process.
+    async fn query_buffered_chunks_stream(&self, query: ChunkQuery) -> QueryBufferedChunksStream {
+        let (sender, receiver) = oneshot::channel();
+        if let Err(err) = self.event_tx.send(Event::History(sender)).await {
+            re_log::error!("Error querying chunks: {err}");
+            return Box::pin(tokio_stream::empty());
+        }
+        let history = match receiver.await {
+            Ok(history) => history,
+            Err(err) => {
+                re_log::error!("Error querying chunks: {err}");
+                return Box::pin(tokio_stream::empty());
+            }
+        };
+
+        // Chunks are only decoded as the stream is polled, so slow readers don't hold up the server.
+        Box::pin(tokio_stream::iter(
+            history
+                .into_messages()
+                .filter_map(move |msg| match msg {
+                    LogOrTableMsgProto::LogMsg(log_msg) if query.matches(&log_msg) => {
+                        Some(QueryBufferedChunksResponse {
+                            log_msg: Some(log_msg),
+                        })
+                    }
+                    _ => None,
+                })
+                .map(Ok),
+        ))
+    }
+
     async fn new_client_table_stream(&self) -> ReadTablesStream {
         let (sender, receiver) = oneshot::channel();
         if let Err(err) = self.event_tx.send(Event::NewClient(sender)).await {
@@ -859,9 +1641,15 @@ impl MessageProxy {
             }
         };
 
+        // Tables are never spilled to disk, so there is no need to read it back.
+        let history = History {
+            spilled: None,
+            ..history
+        };
+
         let history = tokio_stream::iter(
             history
-                .into_iter()
+                .into_messages()
                 .filter_map(|table| {
                     if let LogOrTableMsgProto::Table(table) = table {
                         Some(ReadTablesResponse {
@@ -890,8 +1678,34 @@ impl MessageProxy {
     }
 }
 
+/// Makes the recording id of `msg` unique to the given client,
+/// so that clients logging to the same recording id end up in separate recordings.
+///
+/// Blueprints are left alone, so that clients of the same application keep sharing them.
+fn isolate_recording_id(msg: &mut LogMsgProto, client_id: u64) {
+    use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+    let store_id = match &mut msg.msg {
+        Some(Msg::SetStoreInfo(set_store_info)) => set_store_info
+            .info
+            .as_mut()
+            .and_then(|info| info.store_id.as_mut()),
+        Some(Msg::ArrowMsg(arrow_msg)) => arrow_msg.store_id.as_mut(),
+        Some(Msg::DropTimeRangeCommand(cmd)) => cmd.store_id.as_mut(),
+        Some(Msg::BlueprintActivationCommand(_)) | None => None,
+    };
+
+    if let Some(store_id) = store_id.filter(|id| id.kind() == StoreKindProto::Recording) {
+        store_id.recording_id = format!("{}-client{client_id}", store_id.recording_id);
+    }
+}
+
 type ReadMessagesStream = Pin<Box<dyn Stream<Item = tonic::Result<ReadMessagesResponse>> + Send>>;
 type ReadTablesStream = Pin<Box<dyn Stream<Item = tonic::Result<ReadTablesResponse>> + Send>>;
+type QueryBufferedChunksStream =
+    Pin<Box<dyn Stream<Item = tonic::Result<QueryBufferedChunksResponse>> + Send>>;
+type ReadStoreEventsStream =
+    Pin<Box<dyn Stream<Item = tonic::Result<ReadStoreEventsResponse>> + Send>>;
 
 #[tonic::async_trait]
 impl message_proxy_service_server::MessageProxyService for MessageProxy {
@@ -899,16 +1713,69 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
         &self,
         request: tonic::Request<tonic::Streaming<WriteMessagesRequest>>,
     ) -> tonic::Result<tonic::Response<WriteMessagesResponse>> {
+        let client = Arc::new(ClientInfo {
+            id: self.next_client_id.fetch_add(1, Ordering::Relaxed),
+            addr: request.remote_addr(),
+            name: request.client_name().ok().flatten(),
+        });
+        re_log::debug!("Client {client} started writing messages");
+
+        let metrics_labels = metrics::ClientLabels {
+            server: self.server_id.to_string(),
+            client: client.id.to_string(),
+            addr: client.addr.map(|addr| addr.to_string()).unwrap_or_default(),
+            name: client.name.clone().unwrap_or_default(),
+        };
+        let messages_received = metrics()
+            .client_messages
+            .get_or_create(&metrics_labels)
+            .clone();
+        let bytes_received = metrics()
+            .client_bytes
+            .get_or_create(&metrics_labels)
+            .clone();
+
         let mut stream = request.into_inner();
+
+        // Segments used by this client, which we detach from once it disconnects.
+        let mut used_segments = BTreeSet::new();
+
+        // Flush barrier streams used by this client, which we forget once it disconnects.
+        let mut used_flush_barriers = BTreeSet::new();
+
         loop {
             match stream.message().await {
                 Ok(Some(WriteMessagesRequest {
-                    log_msg: Some(log_msg),
+                    log_msg: Some(mut log_msg),
+                    shared_memory_payload,
+                    flush_barrier: _,
+                })) => {
+                    if let Some(shm_ref) = shared_memory_payload {
+                        if let Err(err) = self.resolve_shared_memory_payload(&mut log_msg, &shm_ref)
+                        {
+                            re_log::error!("Dropping message with unreadable payload: {err}");
+                            metrics().dropped(DropReason::Undecodable, 1);
+                            continue;
+                        }
+                        used_segments.insert(shm_ref.path);
+                    }
+
+                    messages_received.inc();
+                    bytes_received.inc_by(log_msg.total_size_bytes());
+
+                    self.push_msg(log_msg, &client).await;
+                }
+
+                Ok(Some(WriteMessagesRequest {
+                    log_msg: None,
+                    flush_barrier: Some(flush_barrier),
+                    ..
                 })) => {
-                    self.push_msg(log_msg).await;
+                    used_flush_barriers.insert(flush_barrier.stream_id.clone());
+                    self.reach_flush_barrier(flush_barrier).await;
                 }
 
-                Ok(Some(WriteMessagesRequest { log_msg: None })) => {
+                Ok(Some(WriteMessagesRequest { log_msg: None, .. })) => {
                     re_log::warn!("missing log_msg in WriteMessagesRequest");
                 }
 
@@ -924,6 +1791,28 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
             }
         }
 
+        if !used_segments.is_empty() {
+            let mut segments = self.shared_memory.lock();
+            for path in used_segments {
+                re_log::debug!("Detaching from shared memory segment {path:?}");
+                segments.remove(&path);
+            }
+        }
+
+        if !used_flush_barriers.is_empty() {
+            // Anyone still waiting on one of these barriers will get an error.
+            let mut flush_barriers = self.flush_barriers.lock();
+            for stream_id in used_flush_barriers {
+                flush_barriers.remove(&stream_id);
+            }
+        }
+
+        re_log::debug!("Client {client} stopped writing messages");
+
+        // Don't keep the metrics of every client that ever connected around.
+        metrics().client_messages.remove(&metrics_labels);
+        metrics().client_bytes.remove(&metrics_labels);
+
         Ok(tonic::Response::new(WriteMessagesResponse {}))
     }
 
@@ -931,8 +1820,11 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
 
     async fn read_messages(
         &self,
-        _: tonic::Request<ReadMessagesRequest>,
+        request: tonic::Request<ReadMessagesRequest>,
     ) -> tonic::Result<tonic::Response<Self::ReadMessagesStream>> {
+        if let Some(name) = request.client_name().ok().flatten() {
+            re_log::debug!("Client {name:?} started reading messages");
+        }
         Ok(tonic::Response::new(self.new_client_message_stream().await))
     }
 
@@ -961,6 +1853,132 @@ impl message_proxy_service_server::MessageProxyService for MessageProxy {
     ) -> tonic::Result<tonic::Response<Self::ReadTablesStream>> {
         Ok(tonic::Response::new(self.new_client_table_stream().await))
     }
+
+    async fn attach_shared_memory(
+        &self,
+        request: tonic::Request<AttachSharedMemoryRequest>,
+    ) -> tonic::Result<tonic::Response<AttachSharedMemoryResponse>> {
+        let AttachSharedMemoryRequest { path, token } = request.into_inner();
+
+        let response = match SharedMemoryReader::open(std::path::Path::new(&path), &token) {
+            Ok(reader) => {
+                re_log::debug!("Attached to shared memory segment {path:?}");
+                let release_path = reader.release_path().to_string_lossy().into_owned();
+                self.shared_memory.lock().insert(path, Arc::new(reader));
+                AttachSharedMemoryResponse {
+                    accepted: true,
+                    reason: String::new(),
+                    release_path,
+                }
+            }
+            Err(err) => {
+                // Expected whenever the client is on another machine.
+                re_log::debug!("Not attaching to shared memory segment: {err}");
+                AttachSharedMemoryResponse {
+                    accepted: false,
+                    reason: err.to_string(),
+                    release_path: String::new(),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(response))
+    }
+
+    async fn await_flush_barrier(
+        &self,
+        request: tonic::Request<AwaitFlushBarrierRequest>,
+    ) -> tonic::Result<tonic::Response<AwaitFlushBarrierResponse>> {
+        let Some(FlushBarrier { stream_id, barrier }) = request.into_inner().flush_barrier else {
+            return Err(tonic::Status::invalid_argument("missing flush_barrier"));
+        };
+
+        // Barrier 0 is used by clients to check whether we support flush barriers at all.
+        if barrier == 0 {
+            return Ok(tonic::Response::new(AwaitFlushBarrierResponse {}));
+        }
+
+        // Declared before `reached` so that it runs after our receiver is gone.
+        let _forget_if_unknown = ForgetUnknownFlushStream {
+            flush_barriers: &self.flush_barriers,
+            stream_id: &stream_id,
+        };
+
+        // The `WriteMessages` stream may not have announced itself yet.
+        let mut reached = self
+            .flush_barriers
+            .lock()
+            .entry(stream_id.clone())
+            .or_insert_with(|| watch::channel(None).0)
+            .subscribe();
+
+        let closed = || {
+            tonic::Status::aborted(
+                "the WriteMessages stream was closed before reaching the flush barrier",
+            )
+        };
+
+        match tokio::time::timeout(
+            FLUSH_BARRIER_STREAM_TIMEOUT,
+            reached.wait_for(Option::is_some),
+        )
+        .await
+        {
+            Ok(Ok(_)) => {}
+            Ok(Err(_)) => return Err(closed()),
+            Err(_) => {
+                return Err(tonic::Status::not_found(format!(
+                    "no WriteMessages stream {stream_id:?}: it is either unknown or already closed"
+                )));
+            }
+        }
+
+        match reached
+            .wait_for(|reached| reached.is_some_and(|reached| barrier <= reached))
+            .await
+        {
+            Ok(_) => Ok(tonic::Response::new(AwaitFlushBarrierResponse {})),
+            Err(_) => Err(closed()),
+        }
+    }
+
+    type QueryBufferedChunksStream = QueryBufferedChunksStream;
+
+    async fn query_buffered_chunks(
+        &self,
+        request: tonic::Request<QueryBufferedChunksRequest>,
+    ) -> tonic::Result<tonic::Response<Self::QueryBufferedChunksStream>> {
+        let query = ChunkQuery::from_request(request.into_inner())?;
+        Ok(tonic::Response::new(
+            self.query_buffered_chunks_stream(query).await,
+        ))
+    }
+
+    type ReadStoreEventsStream = ReadStoreEventsStream;
+
+    async fn read_store_events(
+        &self,
+        request: tonic::Request<ReadStoreEventsRequest>,
+    ) -> tonic::Result<tonic::Response<Self::ReadStoreEventsStream>> {
+        let query = StoreEventQuery::from_request(request.into_inner());
+
+        let stream = BroadcastStream::new(store_events::subscribe()).filter_map(move |result| {
+            match result {
+                Ok(event) => query.to_response(&event),
+
+                Err(err) => {
+                    // Mirrors would silently go out of sync if we skipped ahead: end the stream instead.
+                    let tokio_stream::wrappers::errors::BroadcastStreamRecvError::Lagged(n) = err;
+                    re_log::warn!("A store event subscriber fell behind and missed {n} events");
+                    Some(Err(tonic::Status::data_loss(format!(
+                        "fell behind and missed {n} store events"
+                    ))))
+                }
+            }
+        });
+
+        Ok(tonic::Response::new(Box::pin(stream)))
+    }
 }
 
 #[cfg(test)]
@@ -1116,7 +2134,13 @@ mod tests {
         setup_with_memory_limit(MemoryLimit::UNLIMITED).await
     }
 
-    async fn setup_with_memory_limit(memory_limit: MemoryLimit) -> (Completion, SocketAddr) {
+    async fn setup_with_memory_limit(
+        options: impl Into<ServerOptions>,
+    ) -> (Completion, SocketAddr) {
+        setup_with_proxy(super::MessageProxy::new(options)).await
+    }
+
+    async fn setup_with_proxy(message_proxy: super::MessageProxy) -> (Completion, SocketAddr) {
         let completion = Completion::new();
 
         let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -1127,7 +2151,7 @@ mod tests {
             async move {
                 tonic::transport::Server::builder()
                     .add_service(
-                        MessageProxyServiceServer::new(super::MessageProxy::new(memory_limit))
+                        MessageProxyServiceServer::new(message_proxy)
                             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
                             .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
                     )
@@ -1164,7 +2188,11 @@ mod tests {
                     .clone()
                     .into_iter()
                     .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
-                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+                    .map(|msg| WriteMessagesRequest {
+                        log_msg: Some(msg),
+                        shared_memory_payload: None,
+                        flush_barrier: None,
+                    }),
             ))
             .await
             .unwrap();
@@ -1297,6 +2325,56 @@ mod tests {
         completion.finish();
     }
 
+    #[tokio::test]
+    async fn isolated_clients_get_distinct_recording_ids() {
+        let message_proxy = super::MessageProxy::new(
+            ServerOptions::from(MemoryLimit::UNLIMITED).with_isolated_clients(true),
+        );
+        let (completion, addr) = setup_with_proxy(message_proxy).await;
+
+        let mut producers = vec![make_client(addr).await, make_client(addr).await];
+        let mut consumer = make_client(addr).await;
+        let recording = fake_log_stream_recording(3);
+        let blueprint = fake_log_stream_blueprint(3);
+        let messages = [recording.clone(), blueprint.clone()].concat();
+
+        // Both producers log to the same recording and blueprint:
+        for producer in &mut producers {
+            write_messages(producer, messages.clone()).await;
+        }
+
+        let mut log_stream = consumer
+            .read_messages(ReadMessagesRequest {})
+            .await
+            .unwrap();
+        let actual = read_log_stream(&mut log_stream, 2 * messages.len()).await;
+
+        let recording_ids = actual
+            .iter()
+            .map(|msg| msg.store_id().clone())
+            .filter(|id| id.is_recording())
+            .unique()
+            .collect_vec();
+        assert_eq!(recording_ids.len(), 2);
+        for recording_id in &recording_ids {
+            assert_ne!(recording_id, recording[0].store_id());
+            assert_eq!(
+                recording_id.application_id(),
+                recording[0].store_id().application_id()
+            );
+        }
+
+        // Blueprints are still shared:
+        assert!(
+            actual
+                .iter()
+                .filter(|msg| !msg.store_id().is_recording())
+                .all(|msg| msg.store_id() == blueprint[0].store_id())
+        );
+
+        completion.finish();
+    }
+
     #[tokio::test]
     async fn memory_limit_drops_messages() {
         // Use an absurdly low memory limit to force all messages to be dropped immediately from history
@@ -1373,6 +2451,210 @@ mod tests {
         completion.finish();
     }
 
+    /// Reads messages until none arrive for a while.
+    async fn read_log_stream_until_idle(
+        log_stream: &mut tonic::Response<tonic::Streaming<ReadMessagesResponse>>,
+    ) -> Vec<LogMsg> {
+        let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+        let mut actual = vec![];
+        loop {
+            let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
+            tokio::pin!(timeout_stream);
+            match timeout_stream.try_next().await {
+                Ok(Some(value)) => {
+                    actual.push(
+                        log_msg_from_proto(&mut app_id_cache, value.unwrap().log_msg.unwrap())
+                            .unwrap(),
+                    );
+                }
+
+                // Stream closed | Timed out
+                Ok(None) | Err(_) => break,
+            }
+        }
+        actual
+    }
+
+    #[tokio::test]
+    async fn memory_limit_drop_newest_keeps_oldest_messages() {
+        let (completion, addr) = setup_with_memory_limit(ServerMemoryLimit::new(
+            MemoryLimit::from_bytes(1),
+            OverflowPolicy::DropNewest,
+        ))
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        write_messages(&mut client, messages.clone()).await;
+
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream_until_idle(&mut log_stream).await;
+
+        // Nothing fits, except for the persistent store info:
+        assert_eq!(actual, vec![messages[0].clone()]);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn memory_limit_spill_to_disk_keeps_all_messages() {
+        let (completion, addr) = setup_with_memory_limit(ServerMemoryLimit::new(
+            MemoryLimit::from_bytes(1),
+            OverflowPolicy::SpillToDisk,
+        ))
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        write_messages(&mut client, messages.clone()).await;
+
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream_until_idle(&mut log_stream).await;
+
+        assert_eq!(actual, messages);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn memory_limit_backpressure_does_not_drop_live_messages() {
+        let (completion, addr) = setup_with_memory_limit(ServerMemoryLimit::new(
+            MemoryLimit::from_bytes(1),
+            OverflowPolicy::Backpressure,
+        ))
+        .await;
+        let mut producer = make_client(addr).await;
+        let mut consumer = make_client(addr).await;
+        let messages = fake_log_stream_recording(100);
+
+        let mut log_stream = consumer
+            .read_messages(ReadMessagesRequest {})
+            .await
+            .unwrap();
+
+        // The producer has to wait for the consumer, so write from another task:
+        let writer = tokio::spawn({
+            let messages = messages.clone();
+            async move { write_messages(&mut producer, messages).await }
+        });
+
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(actual, messages);
+        writer.await.unwrap();
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn memory_limit_backpressure_does_not_block_other_events() {
+        let (proxy, mut broadcast_log_rx, _) = super::MessageProxy::new_with_recv(
+            ServerMemoryLimit::new(MemoryLimit::from_bytes(1), OverflowPolicy::Backpressure).into(),
+            None,
+            None,
+        );
+        let proxy = Arc::new(proxy);
+        let client = Arc::new(ClientInfo {
+            id: 0,
+            addr: None,
+            name: None,
+        });
+        let messages = fake_log_stream_recording(2)
+            .into_iter()
+            .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+            .collect_vec();
+
+        // Nobody is behind yet, so the first message goes through…
+        proxy.push_msg(messages[0].clone(), &client).await;
+
+        // …but the second has to wait for `broadcast_log_rx`, which doesn't read anything yet.
+        let writer = tokio::spawn({
+            let proxy = Arc::clone(&proxy);
+            let msg = messages[1].clone();
+            async move { proxy.push_msg(msg, &client).await }
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!writer.is_finished());
+
+        // Meanwhile, the event loop keeps serving new clients…
+        let mut history =
+            tokio::time::timeout(Duration::from_secs(5), proxy.new_client_message_stream())
+                .await
+                .unwrap();
+        let first = history.next().await.unwrap().unwrap();
+        assert_eq!(first.log_msg, Some(messages[0].clone()));
+
+        // …and the writer continues once the slowest subscriber catches up.
+        drop(history);
+        broadcast_log_rx.recv().await.unwrap();
+        proxy.caught_up_signal().unwrap().received();
+        tokio::time::timeout(Duration::from_secs(5), writer)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn forwarder_forgets_disconnected_clients() {
+        let uri = re_uri::ProxyUri::new(re_uri::Origin::from_scheme_and_socket_addr(
+            re_uri::Scheme::RerunHttp,
+            "127.0.0.1:0".parse().unwrap(),
+        ));
+        let (channel_log_tx, channel_log_rx) = re_smart_channel::smart_channel(
+            re_smart_channel::SmartMessageSource::MessageProxy(uri.clone()),
+            re_smart_channel::SmartChannelSource::MessageProxy(uri.clone()),
+        );
+        let mut forwarder = LogForwarder {
+            uri,
+            app_id_cache: Default::default(),
+            channel_log_tx,
+            client_log_txs: HashMap::new(),
+            drain: None,
+        };
+
+        let new_client = |id| {
+            Arc::new(ClientInfo {
+                id,
+                addr: None,
+                name: None,
+            })
+        };
+        let messages = fake_log_stream_recording(2)
+            .into_iter()
+            .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap())
+            .collect_vec();
+
+        let (first, second) = (new_client(0), new_client(1));
+        for client in [&first, &second] {
+            assert!(
+                forwarder
+                    .forward(ClientLogMsgProto {
+                        msg: messages[0].clone(),
+                        client: Some(Arc::clone(client)),
+                    })
+                    .await
+            );
+        }
+        assert_eq!(forwarder.client_log_txs.len(), 2);
+
+        // The first client disconnects, which is noticed with the next message.
+        drop(first);
+        assert!(
+            forwarder
+                .forward(ClientLogMsgProto {
+                    msg: messages[1].clone(),
+                    client: Some(Arc::clone(&second)),
+                })
+                .await
+        );
+        assert_eq!(
+            forwarder.client_log_txs.keys().copied().collect_vec(),
+            vec![1]
+        );
+
+        // The messages of both clients still made it through.
+        assert_eq!(channel_log_rx.len(), 3);
+    }
+
     #[tokio::test]
     async fn memory_limit_does_not_interrupt_stream() {
         let memory_limits = [
@@ -1428,4 +2710,493 @@ mod tests {
 
         completion.finish();
     }
+
+    #[tokio::test]
+    async fn flush_barrier_is_reached_after_previous_messages() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_blueprint(3);
+
+        let barrier = FlushBarrier {
+            stream_id: "test_stream".to_owned(),
+            barrier: 1,
+        };
+
+        // Barrier 0 is always reached.
+        client
+            .await_flush_barrier(AwaitFlushBarrierRequest {
+                flush_barrier: Some(FlushBarrier {
+                    barrier: 0,
+                    ..barrier.clone()
+                }),
+            })
+            .await
+            .unwrap();
+
+        // Keep the stream open, otherwise the server forgets about its barriers.
+        let (write_tx, write_rx) = tokio::sync::mpsc::channel(16);
+        let writer = tokio::spawn({
+            let mut client = client.clone();
+            async move {
+                client
+                    .write_messages(tokio_stream::wrappers::ReceiverStream::new(write_rx))
+                    .await
+            }
+        });
+
+        // Waiting can start before the barrier is sent.
+        let waiter = tokio::spawn({
+            let mut client = client.clone();
+            let barrier = barrier.clone();
+            async move {
+                client
+                    .await_flush_barrier(AwaitFlushBarrierRequest {
+                        flush_barrier: Some(barrier),
+                    })
+                    .await
+            }
+        });
+
+        for msg in messages.clone() {
+            write_tx
+                .send(WriteMessagesRequest {
+                    log_msg: Some(log_msg_to_proto(msg, Compression::Off).unwrap()),
+                    shared_memory_payload: None,
+                    flush_barrier: None,
+                })
+                .await
+                .unwrap();
+        }
+        write_tx
+            .send(WriteMessagesRequest {
+                log_msg: None,
+                shared_memory_payload: None,
+                flush_barrier: Some(barrier),
+            })
+            .await
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        // Everything sent before the barrier has been accepted.
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(actual, messages);
+
+        drop(write_tx);
+        writer.await.unwrap().unwrap();
+
+        completion.finish();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flush_barrier_of_unknown_stream_times_out() {
+        use message_proxy_service_server::MessageProxyService as _;
+
+        let proxy = super::MessageProxy::new(MemoryLimit::UNLIMITED);
+
+        let status = proxy
+            .await_flush_barrier(tonic::Request::new(AwaitFlushBarrierRequest {
+                flush_barrier: Some(FlushBarrier {
+                    stream_id: "unknown_stream".to_owned(),
+                    barrier: 1,
+                }),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        // Nothing is kept around for streams that never showed up.
+        assert!(proxy.flush_barriers.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn flush_barrier_fails_when_stream_closes() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+
+        let (write_tx, write_rx) = tokio::sync::mpsc::channel(16);
+        let writer = tokio::spawn({
+            let mut client = client.clone();
+            async move {
+                client
+                    .write_messages(tokio_stream::wrappers::ReceiverStream::new(write_rx))
+                    .await
+            }
+        });
+
+        // Announce the stream, but never reach barrier 1.
+        write_tx
+            .send(WriteMessagesRequest {
+                log_msg: None,
+                shared_memory_payload: None,
+                flush_barrier: Some(FlushBarrier {
+                    stream_id: "closing_stream".to_owned(),
+                    barrier: 0,
+                }),
+            })
+            .await
+            .unwrap();
+
+        let waiter = tokio::spawn(async move {
+            client
+                .await_flush_barrier(AwaitFlushBarrierRequest {
+                    flush_barrier: Some(FlushBarrier {
+                        stream_id: "closing_stream".to_owned(),
+                        barrier: 1,
+                    }),
+                })
+                .await
+        });
+
+        // Give the waiter time to start waiting before the stream goes away.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        drop(write_tx);
+        writer.await.unwrap().unwrap();
+
+        let status = tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Aborted);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn query_buffered_chunks_filters_by_entity_and_time() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+
+        let store_id = StoreId::random(StoreKind::Recording, "test_app");
+        let chunk_msg = |entity_path: &str, frame: Option<i64>| {
+            let timepoint = frame.map_or(re_log_types::TimePoint::STATIC, |frame| {
+                re_log_types::TimePoint::default()
+                    .with(re_log_types::Timeline::new_sequence("frame"), frame)
+            });
+            LogMsg::ArrowMsg(
+                store_id.clone(),
+                re_chunk::Chunk::builder(entity_path)
+                    .with_archetype(
+                        RowId::new(),
+                        timepoint,
+                        &re_types::archetypes::Points2D::new([(0.0, 0.0)]),
+                    )
+                    .build()
+                    .unwrap()
+                    .to_arrow_msg()
+                    .unwrap(),
+            )
+        };
+
+        let set_store_info = set_store_info_msg(&store_id);
+        let early = chunk_msg("/world/points", Some(1));
+        let late = chunk_msg("/world/points", Some(10));
+        let static_ = chunk_msg("/world/points", None);
+        let filtered_out = chunk_msg("/debug/points", Some(1));
+
+        // Blueprints are only returned when asked for by store id.
+        write_messages(
+            &mut client,
+            chain!(
+                fake_log_stream_blueprint(1),
+                [
+                    set_store_info.clone(),
+                    early.clone(),
+                    late,
+                    static_.clone(),
+                    filtered_out,
+                ]
+            )
+            .collect(),
+        )
+        .await;
+
+        let response = client
+            .query_buffered_chunks(QueryBufferedChunksRequest {
+                store_id: None,
+                entity_path_filter: "+ /world/**".to_owned(),
+                timeline: Some(re_protos::common::v1alpha1::Timeline {
+                    name: "frame".to_owned(),
+                }),
+                time_range: Some(re_protos::common::v1alpha1::TimeRange { start: 0, end: 5 }),
+            })
+            .await
+            .unwrap();
+
+        let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+        let actual: Vec<LogMsg> = response
+            .into_inner()
+            .map(|result| {
+                log_msg_from_proto(&mut app_id_cache, result.unwrap().log_msg.unwrap()).unwrap()
+            })
+            .collect()
+            .await;
+
+        // Store infos come first, then static data, then temporal data.
+        assert_eq!(actual, vec![set_store_info, static_, early]);
+
+        // A timeline without a time range is rejected.
+        let status = client
+            .query_buffered_chunks(QueryBufferedChunksRequest {
+                timeline: Some(re_protos::common::v1alpha1::Timeline {
+                    name: "frame".to_owned(),
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn read_store_events_of_local_stores() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+
+        let store_id = StoreId::random(StoreKind::Recording, "test_app");
+        let mut store = re_chunk_store::ChunkStore::new(store_id.clone(), Default::default());
+
+        let response = client
+            .read_store_events(ReadStoreEventsRequest {
+                // Other tests might be running stores of their own at the same time.
+                store_id: Some(store_id.clone().into()),
+                include_payloads: true,
+            })
+            .await
+            .unwrap();
+        let mut events = response.into_inner();
+
+        let chunk = re_chunk::Chunk::builder("/world/points")
+            .with_archetype(
+                RowId::new(),
+                re_log_types::TimePoint::default()
+                    .with(re_log_types::Timeline::new_sequence("frame"), 1_i64),
+                &re_types::archetypes::Points2D::new([(0.0, 0.0), (1.0, 1.0)]),
+            )
+            .build()
+            .unwrap();
+        store.insert_chunk(&Arc::new(chunk.clone())).unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            event.kind(),
+            re_protos::sdk_comms::v1alpha1::StoreEventKind::Addition
+        );
+        assert_eq!(event.entity_path.unwrap().path, "/world/points");
+        assert_eq!(event.num_rows, 1);
+
+        let arrow_msg =
+            re_log_encoding::protobuf_conversions::arrow_msg_from_proto(&event.chunk.unwrap())
+                .unwrap();
+        let received = re_chunk::Chunk::from_arrow_msg(&arrow_msg).unwrap();
+        assert_eq!(received.id(), chunk.id());
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn relay_subscribers_skip_ahead_instead_of_disconnecting() {
+        // A tiny ring buffer, so that subscribers are likely to fall behind.
+        let (message_proxy, _, _) = super::MessageProxy::new_with_recv(
+            MemoryLimit::UNLIMITED.into(),
+            Some(RelayOptions::default().with_ring_buffer_capacity(1)),
+        );
+        let (completion, addr) = setup_with_proxy(message_proxy).await;
+        let mut producer = make_client(addr).await;
+        let mut subscribers = vec![make_client(addr).await, make_client(addr).await];
+
+        let mut log_streams = vec![];
+        for subscriber in &mut subscribers {
+            log_streams.push(
+                subscriber
+                    .read_messages(ReadMessagesRequest {})
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let messages = fake_log_stream_recording(64);
+        write_messages(&mut producer, messages.clone()).await;
+
+        // Every subscriber gets to the last message, having received the others in order,
+        // minus whatever it skipped.
+        for log_stream in &mut log_streams {
+            let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+            let mut remaining = messages.iter();
+            loop {
+                let msg = log_stream.get_mut().next().await.unwrap().unwrap();
+                let msg = log_msg_from_proto(&mut app_id_cache, msg.log_msg.unwrap()).unwrap();
+                assert!(remaining.any(|expected| expected == &msg));
+                if Some(&msg) == messages.last() {
+                    break;
+                }
+            }
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn relay_subscribers_get_skipped_store_infos_again() {
+        let (message_proxy, _, _) = super::MessageProxy::new_with_recv(
+            MemoryLimit::UNLIMITED.into(),
+            Some(RelayOptions::default().with_ring_buffer_capacity(2)),
+        );
+
+        // Subscribes to the live messages, but doesn't read any of them yet.
+        let mut subscriber = message_proxy.new_client_message_stream().await;
+
+        let first = fake_log_stream_recording(1);
+        let second = fake_log_stream_recording(4);
+        for msg in chain!(&first, &second) {
+            let msg = log_msg_to_proto(msg.clone(), Compression::Off).unwrap();
+            message_proxy
+                .event_tx
+                .send(Event::Message(ClientLogMsgProto { msg, client: None }))
+                .await
+                .unwrap();
+        }
+        let (on_done, done) = oneshot::channel();
+        message_proxy
+            .event_tx
+            .send(Event::FlushBarrier(on_done))
+            .await
+            .unwrap();
+        done.await.unwrap();
+
+        // The subscriber skipped the store info of the second recording along with some of its data,
+        // and gets the store infos of both recordings before resuming.
+        let mut app_id_cache = re_log_encoding::CachingApplicationIdInjector::default();
+        let mut received = vec![];
+        for _ in 0..4 {
+            let msg = subscriber.next().await.unwrap().unwrap();
+            received.push(log_msg_from_proto(&mut app_id_cache, msg.log_msg.unwrap()).unwrap());
+        }
+        assert_eq!(
+            received,
+            vec![
+                first[0].clone(),
+                second[0].clone(),
+                second[3].clone(),
+                second[4].clone(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn drain_forwards_received_messages_before_closing() {
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let (signal, shutdown) = shutdown::shutdown_with_drain();
+        let (log_rx, _table_rx) = super::spawn_with_recv(addr, MemoryLimit::UNLIMITED, shutdown);
+
+        // The server is spawned in the background, so it may not be listening yet.
+        let endpoint = Endpoint::from_shared(format!("http://{addr}")).unwrap();
+        let channel = loop {
+            if let Ok(channel) = endpoint.connect().await {
+                break channel;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        let mut client = MessageProxyServiceClient::new(channel);
+
+        let messages = fake_log_stream_recording(16);
+        write_messages(&mut client, messages.clone()).await;
+
+        let report = signal.stop_and_drain(Duration::from_secs(5)).await;
+        assert_eq!(
+            report,
+            shutdown::DrainReport {
+                messages_dropped: 0,
+                timed_out: false,
+            }
+        );
+
+        // Everything has been forwarded by the time the drain is done, followed by a quit.
+        let mut num_received = 0;
+        loop {
+            match log_rx.try_recv().unwrap().payload {
+                re_smart_channel::SmartMessagePayload::Msg(_) => num_received += 1,
+                re_smart_channel::SmartMessagePayload::Flush { .. } => {}
+                re_smart_channel::SmartMessagePayload::Quit(err) => {
+                    assert!(err.is_none());
+                    break;
+                }
+            }
+        }
+        assert_eq!(num_received, messages.len());
+    }
+
+    #[tokio::test]
+    async fn tls_server_accepts_tls_clients_and_rejects_plaintext_ones() {
+        let certified_key =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+        let cert_pem = certified_key.cert.pem();
+        let key_pem = certified_key.key_pair.serialize_pem();
+
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let (signal, shutdown) = shutdown::shutdown();
+        let options = ServerOptions::from(MemoryLimit::UNLIMITED).with_security(
+            ServerSecurity::default().with_tls(TlsConfig::from_pem(cert_pem.clone(), key_pem)),
+        );
+        tokio::spawn(super::serve(addr, options, shutdown));
+
+        // A client that trusts the self-signed certificate completes the handshake.
+        let tls = tonic::transport::ClientTlsConfig::new()
+            .ca_certificate(tonic::transport::Certificate::from_pem(&cert_pem))
+            .domain_name("localhost");
+        let endpoint = Endpoint::from_shared(format!("https://{addr}"))
+            .unwrap()
+            .tls_config(tls)
+            .unwrap();
+        // The server is spawned in the background, so it may not be listening yet.
+        let channel = loop {
+            if let Ok(channel) = endpoint.connect().await {
+                break channel;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        let mut client = MessageProxyServiceClient::new(channel);
+
+        let messages = fake_log_stream_recording(3);
+        write_messages(&mut client, messages.clone()).await;
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(actual, messages);
+
+        // A plaintext client doesn't get through, neither when connecting nor on its first request.
+        let rejected = match Endpoint::from_shared(format!("http://{addr}"))
+            .unwrap()
+            .connect()
+            .await
+        {
+            Err(_) => true,
+            Ok(channel) => MessageProxyServiceClient::new(channel)
+                .read_messages(ReadMessagesRequest {})
+                .await
+                .is_err(),
+        };
+        assert!(rejected);
+
+        signal.stop();
+    }
 }
@@ -0,0 +1,100 @@
+use re_memory::MemoryLimit;
+
+/// How much memory a server may use for buffering log messages,
+/// and what it does once it reaches that limit.
+///
+/// Can be created from a plain [`MemoryLimit`], in which case the server drops
+/// the oldest messages when it runs out of memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ServerMemoryLimit {
+    pub limit: MemoryLimit,
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl ServerMemoryLimit {
+    #[inline]
+    pub fn new(limit: MemoryLimit, overflow_policy: OverflowPolicy) -> Self {
+        Self {
+            limit,
+            overflow_policy,
+        }
+    }
+
+    /// Maximum number of bytes to use, if any.
+    ///
+    /// Zero means that the server doesn't keep any history.
+    pub(crate) fn max_bytes(&self) -> Option<u64> {
+        self.limit.max_bytes.map(|max_bytes| max_bytes as u64)
+    }
+}
+
+impl From<MemoryLimit> for ServerMemoryLimit {
+    #[inline]
+    fn from(limit: MemoryLimit) -> Self {
+        Self {
+            limit,
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// What a server does once it uses more memory than its [`ServerMemoryLimit`].
+///
+/// Store infos, blueprints and blueprint activation commands are always kept,
+/// regardless of the policy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Drop the oldest messages from the history, static data last.
+    ///
+    /// Clients connecting after this will not see the full history.
+    #[default]
+    DropOldest,
+
+    /// Stop adding new messages to the history.
+    ///
+    /// New messages are still forwarded to connected clients, but clients connecting
+    /// later will only see the data that fit into memory.
+    DropNewest,
+
+    /// Never let connected clients miss messages: once the messages that the slowest client
+    /// hasn't received yet add up to the memory limit, stop accepting new messages until it
+    /// catches up.
+    ///
+    /// The wait propagates all the way back to the logging SDKs, through gRPC flow control.
+    /// The history is capped at the memory limit as with [`Self::DropOldest`].
+    Backpressure,
+
+    /// Move the oldest messages from memory to a temporary file, and read them back
+    /// whenever a new client connects.
+    ///
+    /// Bounds the memory used between clients connecting, at the cost of disk space.
+    /// Tables are dropped instead, as with [`Self::DropOldest`].
+    SpillToDisk,
+}
+
+impl std::fmt::Display for OverflowPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::DropOldest => "drop-oldest",
+            Self::DropNewest => "drop-newest",
+            Self::Backpressure => "backpressure",
+            Self::SpillToDisk => "spill-to-disk",
+        })
+    }
+}
+
+impl std::str::FromStr for OverflowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "drop-oldest" => Ok(Self::DropOldest),
+            "drop-newest" => Ok(Self::DropNewest),
+            "backpressure" => Ok(Self::Backpressure),
+            "spill-to-disk" => Ok(Self::SpillToDisk),
+            _ => Err(format!(
+                "unknown overflow policy {s:?}, expected one of: drop-oldest, drop-newest, backpressure, spill-to-disk"
+            )),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Prometheus metrics of all message proxy servers running in this process,
+//! see [`serve_metrics`].
+
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::{
+    Router,
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::registry::Registry;
+use tokio::net::TcpListener;
+
+use crate::shutdown;
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// The metrics of all servers in this process.
+pub(crate) fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Identifies a server in this process, since there may be more than one.
+pub(crate) fn next_server_id() -> u64 {
+    static NEXT_SERVER_ID: AtomicU64 = AtomicU64::new(0);
+    NEXT_SERVER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ServerLabels {
+    pub server: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ClientLabels {
+    pub server: String,
+    pub client: String,
+    pub addr: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct DropLabels {
+    pub reason: DropReason,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash, PartialEq, Eq, prometheus_client::encoding::EncodeLabelValue,
+)]
+pub(crate) enum DropReason {
+    /// A client fell too far behind and skipped messages.
+    SlowClient,
+
+    /// A message couldn't be decoded, or its payload couldn't be read.
+    Undecodable,
+}
+
+pub(crate) struct Metrics {
+    registry: Registry,
+
+    /// Log messages received from each connected client.
+    pub client_messages: Family<ClientLabels, Counter>,
+
+    /// Bytes of log messages received from each connected client.
+    pub client_bytes: Family<ClientLabels, Counter>,
+
+    /// Log messages that never reached some client.
+    pub dropped_messages: Family<DropLabels, Counter>,
+
+    /// Messages removed from the history because of the memory limit.
+    pub evicted_messages: Counter,
+
+    /// Bytes removed from the history because of the memory limit.
+    pub evicted_bytes: Counter,
+
+    /// Events waiting to be handled by each server.
+    pub event_queue_depth: Family<ServerLabels, Gauge>,
+
+    /// Log messages that the slowest client of each server has yet to receive.
+    pub broadcast_queue_depth: Family<ServerLabels, Gauge>,
+
+    /// Bytes of history kept in memory by each server.
+    pub history_bytes: Family<ServerLabels, Gauge>,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let mut registry = Registry::with_prefix("rerun_grpc_server");
+
+        let client_messages = Family::<ClientLabels, Counter>::default();
+        registry.register(
+            "client_messages",
+            "Log messages received from each connected client",
+            client_messages.clone(),
+        );
+
+        let client_bytes = Family::<ClientLabels, Counter>::default();
+        registry.register(
+            "client_bytes",
+            "Bytes of log messages received from each connected client",
+            client_bytes.clone(),
+        );
+
+        let dropped_messages = Family::<DropLabels, Counter>::default();
+        registry.register(
+            "dropped_messages",
+            "Log messages that never reached some client",
+            dropped_messages.clone(),
+        );
+
+        let evicted_messages = Counter::default();
+        registry.register(
+            "evicted_messages",
+            "Messages dropped from or spilled out of the in-memory history because of the memory limit",
+            evicted_messages.clone(),
+        );
+
+        let evicted_bytes = Counter::default();
+        registry.register(
+            "evicted_bytes",
+            "Bytes dropped from or spilled out of the in-memory history because of the memory limit",
+            evicted_bytes.clone(),
+        );
+
+        let event_queue_depth = Family::<ServerLabels, Gauge>::default();
+        registry.register(
+            "event_queue_depth",
+            "Events waiting to be handled by the server",
+            event_queue_depth.clone(),
+        );
+
+        let broadcast_queue_depth = Family::<ServerLabels, Gauge>::default();
+        registry.register(
+            "broadcast_queue_depth",
+            "Log messages that the slowest client has yet to receive",
+            broadcast_queue_depth.clone(),
+        );
+
+        let history_bytes = Family::<ServerLabels, Gauge>::default();
+        registry.register(
+            "history_bytes",
+            "Bytes of history kept in memory",
+            history_bytes.clone(),
+        );
+
+        Self {
+            registry,
+            client_messages,
+            client_bytes,
+            dropped_messages,
+            evicted_messages,
+            evicted_bytes,
+            event_queue_depth,
+            broadcast_queue_depth,
+            history_bytes,
+        }
+    }
+
+    pub fn dropped(&self, reason: DropReason, num_messages: u64) {
+        self.dropped_messages
+            .get_or_create(&DropLabels { reason })
+            .inc_by(num_messages);
+    }
+
+    pub fn evicted(&self, num_messages: u64, num_bytes: u64) {
+        self.evicted_messages.inc_by(num_messages);
+        self.evicted_bytes.inc_by(num_bytes);
+    }
+
+    fn encode(&self) -> Result<String, std::fmt::Error> {
+        let mut buffer = String::new();
+        prometheus_client::encoding::text::encode(&mut buffer, &self.registry)?;
+        Ok(buffer)
+    }
+}
+
+/// Serve the metrics of all Rerun servers in this process at `http://{addr}/metrics`,
+/// in the Prometheus text format.
+///
+/// The returned future must be polled for the metrics server to make progress.
+///
+/// Metrics include per-client message and byte counts, dropped messages,
+/// queue depths and memory limit evictions.
+/// They are collected regardless of whether this endpoint is served.
+pub async fn serve_metrics(addr: SocketAddr, shutdown: shutdown::Shutdown) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+
+    re_log::info!("Serving Prometheus metrics at http://{addr}/metrics");
+
+    let app = Router::new().route("/metrics", get(metrics_handler));
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown.wait())
+        .await?;
+
+    Ok(())
+}
+
+async fn metrics_handler() -> impl IntoResponse {
+    match metrics().encode() {
+        Ok(buffer) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+            buffer,
+        ),
+        Err(err) => {
+            re_log::error!("Failed to encode metrics: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(header::CONTENT_TYPE, "text/plain")],
+                format!("Failed to encode metrics: {err}"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_metrics() {
+        let metrics = Metrics::new();
+        metrics
+            .client_messages
+            .get_or_create(&ClientLabels {
+                server: "0".to_owned(),
+                client: "1".to_owned(),
+                addr: "127.0.0.1:1234".to_owned(),
+                name: "robot".to_owned(),
+            })
+            .inc_by(3);
+        metrics.dropped(DropReason::SlowClient, 2);
+        metrics.evicted(1, 100);
+
+        let text = metrics.encode().unwrap();
+        assert!(text.contains(
+            r#"rerun_grpc_server_client_messages_total{server="0",client="1",addr="127.0.0.1:1234",name="robot"} 3"#
+        ));
+        assert!(
+            text.contains(r#"rerun_grpc_server_dropped_messages_total{reason="SlowClient"} 2"#)
+        );
+        assert!(text.contains("rerun_grpc_server_evicted_bytes_total 100"));
+    }
+}
@@ -0,0 +1,94 @@
+use re_memory::MemoryLimit;
+
+use crate::{PersistOptions, ServerMemoryLimit, ServerSecurity};
+
+/// How a Rerun server buffers and organizes the data it receives, see [`crate::serve`].
+///
+/// Can be created from a plain [`MemoryLimit`] or [`ServerMemoryLimit`],
+/// in which case everything else is left at its default.
+#[derive(Clone, Debug)]
+pub struct ServerOptions {
+    pub memory_limit: ServerMemoryLimit,
+
+    /// If set, every client connection gets its own recordings, even if several clients
+    /// log to the same recording id.
+    ///
+    /// By default, data logged to the same recording id is merged, no matter who sent it.
+    /// This is what you want when several processes cooperate on one recording, but lets
+    /// clients interfere with each other otherwise, e.g. several robots running the same code
+    /// with a fixed recording id.
+    pub isolate_clients: bool,
+
+    /// Whether to terminate TLS and authenticate clients.
+    ///
+    /// This is how a server, e.g. one embedded in a viewer, should accept connections from
+    /// remote machines, see [`ServerSecurity`] for details.
+    pub security: ServerSecurity,
+
+    /// If set, every received log message is additionally written to `.rrd` files on disk.
+    ///
+    /// This way an embedded viewer doubles as a durable recording endpoint:
+    /// the data survives even if the viewer later crashes.
+    /// If the first file can't be created, an error is logged and the server runs without
+    /// writing to disk.
+    pub persist: Option<PersistOptions>,
+
+    /// If set, the smart channel returned by [`crate::spawn_with_recv`] holds at most this many
+    /// log messages, see [`re_smart_channel::smart_channel_bounded`].
+    ///
+    /// Without this, a viewer that can't keep up lets the channel grow without limit.
+    /// Once the channel is full, the server stops forwarding messages to it until the viewer catches up.
+    /// Messages then pile up in the server, where [`Self::memory_limit`] applies:
+    /// use [`crate::OverflowPolicy::Backpressure`] to slow down the clients instead of dropping messages.
+    pub channel_capacity: Option<usize>,
+}
+
+impl ServerOptions {
+    /// Give every client connection its own recordings, see [`Self::isolate_clients`].
+    #[inline]
+    pub fn with_isolated_clients(mut self, isolate_clients: bool) -> Self {
+        self.isolate_clients = isolate_clients;
+        self
+    }
+
+    /// Terminate TLS and/or authenticate clients, see [`Self::security`].
+    #[inline]
+    pub fn with_security(mut self, security: ServerSecurity) -> Self {
+        self.security = security;
+        self
+    }
+
+    /// Write every received log message to disk, see [`Self::persist`].
+    #[inline]
+    pub fn with_persist(mut self, persist: PersistOptions) -> Self {
+        self.persist = Some(persist);
+        self
+    }
+
+    /// Bound the smart channel returned by [`crate::spawn_with_recv`], see [`Self::channel_capacity`].
+    #[inline]
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = Some(channel_capacity);
+        self
+    }
+}
+
+impl From<ServerMemoryLimit> for ServerOptions {
+    #[inline]
+    fn from(memory_limit: ServerMemoryLimit) -> Self {
+        Self {
+            memory_limit,
+            isolate_clients: false,
+            security: ServerSecurity::default(),
+            persist: None,
+            channel_capacity: None,
+        }
+    }
+}
+
+impl From<MemoryLimit> for ServerOptions {
+    #[inline]
+    fn from(memory_limit: MemoryLimit) -> Self {
+        ServerMemoryLimit::from(memory_limit).into()
+    }
+}
@@ -0,0 +1,222 @@
+//! Writing the messages received by the message proxy server to disk.
+
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+
+use re_log_encoding::encoder::DroppableEncoder;
+use re_protos::log_msg::v1alpha1::{LogMsg as LogMsgProto, log_msg::Msg};
+
+/// Where a server writes the log messages it receives, see [`crate::ServerOptions::persist`].
+///
+/// Messages are written to the file as soon as they arrive, so everything received so far
+/// survives a crash of the process.
+#[derive(Clone, Debug)]
+pub struct PersistOptions {
+    /// The `.rrd` file to write to.
+    ///
+    /// If [`Self::max_file_size`] is set, this is instead used as a template for the names of
+    /// the rotated files, e.g. `recording.rrd` becomes `recording-0000.rrd`, `recording-0001.rrd`, …
+    pub path: PathBuf,
+
+    /// Start a new file once the current one has grown to this many bytes.
+    ///
+    /// Every new file starts with the store info of every recording seen so far,
+    /// so that each file can be opened on its own.
+    pub max_file_size: Option<u64>,
+}
+
+impl PersistOptions {
+    /// Write all received log messages to the given `.rrd` file.
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_file_size: None,
+        }
+    }
+
+    /// Rotate files once they have grown to `max_file_size` bytes, see [`Self::max_file_size`].
+    #[inline]
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    fn file_path(&self, index: u32) -> PathBuf {
+        if self.max_file_size.is_none() {
+            return self.path.clone();
+        }
+
+        let stem = self
+            .path
+            .file_stem()
+            .map_or_else(|| "recording".into(), |stem| stem.to_string_lossy());
+        let extension = self
+            .path
+            .extension()
+            .map_or_else(|| "rrd".into(), |extension| extension.to_string_lossy());
+        self.path
+            .with_file_name(format!("{stem}-{index:04}.{extension}"))
+    }
+}
+
+/// Writes log messages to disk on a background thread.
+pub(crate) struct Persister {
+    // `None` once we're shutting down.
+    tx: Option<std::sync::mpsc::Sender<LogMsgProto>>,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for Persister {
+    fn drop(&mut self) {
+        self.tx.take();
+        if let Some(join_handle) = self.join_handle.take() {
+            join_handle.join().ok();
+        }
+    }
+}
+
+impl Persister {
+    /// Creates the first file right away, so that a bad path is reported to the caller.
+    pub fn new(options: PersistOptions) -> anyhow::Result<Self> {
+        let writer = RotatingWriter::new(options)?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let join_handle = std::thread::Builder::new()
+            .name("grpc_server_persister".into())
+            .spawn(move || writer.run(&rx))
+            .context("failed to spawn thread")?;
+
+        Ok(Self {
+            tx: Some(tx),
+            join_handle: Some(join_handle),
+        })
+    }
+
+    pub fn send(&self, msg: LogMsgProto) {
+        if let Some(tx) = &self.tx {
+            tx.send(msg).ok();
+        }
+    }
+}
+
+struct RotatingWriter {
+    options: PersistOptions,
+
+    /// Index of the current file, see [`PersistOptions::file_path`].
+    file_index: u32,
+
+    /// Bytes written to the current file so far.
+    file_size: u64,
+
+    encoder: DroppableEncoder<std::fs::File>,
+
+    /// The latest `SetStoreInfo` message of every store, repeated at the start of every new file.
+    store_infos: Vec<LogMsgProto>,
+}
+
+impl RotatingWriter {
+    fn new(options: PersistOptions) -> anyhow::Result<Self> {
+        let encoder = open_file(&options.file_path(0))?;
+        Ok(Self {
+            options,
+            file_index: 0,
+            file_size: 0,
+            encoder,
+            store_infos: Vec::new(),
+        })
+    }
+
+    fn run(mut self, rx: &std::sync::mpsc::Receiver<LogMsgProto>) {
+        while let Ok(msg) = rx.recv() {
+            if let Err(err) = self.append(msg) {
+                re_log::error!(
+                    "Failed to persist log messages to {:?}: {err}",
+                    self.options.file_path(self.file_index)
+                );
+                return;
+            }
+        }
+
+        if let Err(err) = self.encoder.finish() {
+            re_log::error!(
+                "Failed to end log stream for {:?}: {err}",
+                self.options.file_path(self.file_index)
+            );
+        }
+    }
+
+    fn append(&mut self, msg: LogMsgProto) -> anyhow::Result<()> {
+        if let Some(Msg::SetStoreInfo(set_store_info)) = &msg.msg {
+            let store_id = store_id_of(set_store_info);
+            self.store_infos.retain(|info| {
+                !matches!(&info.msg, Some(Msg::SetStoreInfo(info)) if store_id_of(info) == store_id)
+            });
+            self.store_infos.push(msg.clone());
+        }
+
+        self.file_size += self.encoder.append_proto(msg)?;
+
+        if self
+            .options
+            .max_file_size
+            .is_some_and(|max_file_size| max_file_size <= self.file_size)
+        {
+            self.rotate()?;
+        }
+
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> anyhow::Result<()> {
+        self.encoder.finish()?;
+
+        self.file_index += 1;
+        self.file_size = 0;
+        self.encoder = open_file(&self.options.file_path(self.file_index))?;
+
+        for store_info in &self.store_infos {
+            self.file_size += self.encoder.append_proto(store_info.clone())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn store_id_of(
+    set_store_info: &re_protos::log_msg::v1alpha1::SetStoreInfo,
+) -> Option<&re_protos::common::v1alpha1::StoreId> {
+    set_store_info
+        .info
+        .as_ref()
+        .and_then(|info| info.store_id.as_ref())
+}
+
+fn open_file(path: &std::path::Path) -> anyhow::Result<DroppableEncoder<std::fs::File>> {
+    re_log::debug!("Persisting received log messages to {path:?}…");
+
+    let file = std::fs::File::create(path).with_context(|| format!("failed to create {path:?}"))?;
+
+    // We always compress on disk
+    Ok(DroppableEncoder::new(
+        re_build_info::CrateVersion::LOCAL,
+        re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED,
+        file,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotated_file_paths() {
+        let options = PersistOptions::new("/data/robot.rrd");
+        assert_eq!(options.file_path(3), PathBuf::from("/data/robot.rrd"));
+
+        let options = options.with_max_file_size(1024);
+        assert_eq!(options.file_path(0), PathBuf::from("/data/robot-0000.rrd"));
+        assert_eq!(options.file_path(12), PathBuf::from("/data/robot-0012.rrd"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Filtering the message history for the `QueryBufferedChunks` RPC.
+
+use re_log_types::{AbsoluteTimeRange, ResolvedEntityPathFilter, TimelineName};
+use re_protos::common::v1alpha1::{StoreId as StoreIdProto, StoreKind as StoreKindProto};
+use re_protos::log_msg::v1alpha1::{LogMsg as LogMsgProto, log_msg::Msg};
+use re_protos::sdk_comms::v1alpha1::QueryBufferedChunksRequest;
+
+/// Which log messages a `QueryBufferedChunks` request asks for.
+pub(crate) struct ChunkQuery {
+    /// Only this store, or all recordings if `None`.
+    store_id: Option<StoreIdProto>,
+
+    entity_path_filter: Option<ResolvedEntityPathFilter>,
+
+    time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+}
+
+impl ChunkQuery {
+    pub fn from_request(request: QueryBufferedChunksRequest) -> tonic::Result<Self> {
+        let QueryBufferedChunksRequest {
+            store_id,
+            entity_path_filter,
+            timeline,
+            time_range,
+        } = request;
+
+        let entity_path_filter = (!entity_path_filter.trim().is_empty()).then(|| {
+            re_log_types::EntityPathFilter::parse_forgiving(&entity_path_filter)
+                .resolve_without_substitutions()
+        });
+
+        let time_range = match (timeline, time_range) {
+            (Some(timeline), Some(time_range)) => {
+                Some((TimelineName::new(&timeline.name), time_range.into()))
+            }
+            (None, None) => None,
+            (Some(_), None) => {
+                return Err(tonic::Status::invalid_argument(
+                    "time_range must be set along with timeline",
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(tonic::Status::invalid_argument(
+                    "timeline must be set along with time_range",
+                ));
+            }
+        };
+
+        Ok(Self {
+            store_id,
+            entity_path_filter,
+            time_range,
+        })
+    }
+
+    /// Whether `msg` should be part of the response.
+    ///
+    /// Store infos and time range deletions match if their store does, chunks only if they match the
+    /// entity path filter and time range as well. Blueprint activation commands never match.
+    pub fn matches(&self, msg: &LogMsgProto) -> bool {
+        match &msg.msg {
+            Some(Msg::SetStoreInfo(set_store_info)) => self.matches_store(
+                set_store_info
+                    .info
+                    .as_ref()
+                    .and_then(|info| info.store_id.as_ref()),
+            ),
+
+            Some(Msg::ArrowMsg(arrow_msg)) => {
+                if !self.matches_store(arrow_msg.store_id.as_ref()) {
+                    return false;
+                }
+
+                if self.entity_path_filter.is_none() && self.time_range.is_none() {
+                    // No need to decode the chunk.
+                    return true;
+                }
+
+                let chunk = match decode_chunk(arrow_msg) {
+                    Ok(chunk) => chunk,
+                    Err(err) => {
+                        re_log::warn_once!("Skipping undecodable chunk in query: {err}");
+                        return false;
+                    }
+                };
+
+                self.matches_chunk(&chunk)
+            }
+
+            Some(Msg::DropTimeRangeCommand(cmd)) => self.matches_store(cmd.store_id.as_ref()),
+
+            Some(Msg::BlueprintActivationCommand(_)) | None => false,
+        }
+    }
+
+    fn matches_store(&self, store_id: Option<&StoreIdProto>) -> bool {
+        let Some(store_id) = store_id else {
+            return false;
+        };
+
+        match &self.store_id {
+            Some(expected) => {
+                store_id.kind == expected.kind && store_id.recording_id == expected.recording_id
+            }
+            None => store_id.kind() == StoreKindProto::Recording,
+        }
+    }
+
+    fn matches_chunk(&self, chunk: &re_chunk::Chunk) -> bool {
+        if let Some(entity_path_filter) = &self.entity_path_filter
+            && !entity_path_filter.matches(chunk.entity_path())
+        {
+            return false;
+        }
+
+        if let Some((timeline, time_range)) = &self.time_range {
+            // Static data is valid at all times.
+            return chunk.is_static()
+                || chunk
+                    .timelines()
+                    .get(timeline)
+                    .is_some_and(|time_column| time_column.time_range().intersects(*time_range));
+        }
+
+        true
+    }
+}
+
+fn decode_chunk(
+    arrow_msg: &re_protos::log_msg::v1alpha1::ArrowMsg,
+) -> anyhow::Result<re_chunk::Chunk> {
+    let arrow_msg = re_log_encoding::protobuf_conversions::arrow_msg_from_proto(arrow_msg)?;
+    Ok(re_chunk::Chunk::from_arrow_msg(&arrow_msg)?)
+}
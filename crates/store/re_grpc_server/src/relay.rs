@@ -0,0 +1,161 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use parking_lot::Mutex;
+use re_protos::log_msg::v1alpha1::{LogMsg as LogMsgProto, log_msg::Msg};
+use tokio_stream::Stream;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+use crate::ClientLogMsgProto;
+use crate::backpressure::CaughtUpSignal;
+use crate::metrics::{DropReason, metrics};
+
+/// How a server fans out messages when running as a relay, see [`crate::serve_relay`].
+///
+/// Every subscriber of `ReadMessages` reads live messages from a shared ring buffer,
+/// at its own pace. A subscriber that falls behind by more than the capacity of the ring buffer
+/// skips ahead to the oldest message still in it, instead of being disconnected.
+/// It then gets the latest store infos and blueprint activations again, in case it skipped any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelayOptions {
+    /// Number of live log messages kept around for slow subscribers to catch up from.
+    ///
+    /// Larger values let subscribers on slower connections fall further behind without missing
+    /// anything, at the cost of memory. This is independent of the memory limit, which
+    /// only applies to the history sent to newly connected subscribers.
+    pub ring_buffer_capacity: usize,
+}
+
+impl Default for RelayOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            ring_buffer_capacity: crate::MESSAGE_QUEUE_CAPACITY,
+        }
+    }
+}
+
+impl RelayOptions {
+    /// Keep the given number of live log messages around, see [`Self::ring_buffer_capacity`].
+    #[inline]
+    pub fn with_ring_buffer_capacity(mut self, ring_buffer_capacity: usize) -> Self {
+        self.ring_buffer_capacity = ring_buffer_capacity;
+        self
+    }
+}
+
+/// Kind and recording id of a store.
+type StoreKey = (i32, String);
+
+/// The latest store info and blueprint activation of every store.
+///
+/// A relay subscriber that skips ahead may miss these, and would then not know what to do with
+/// the data that follows, see [`SkipAheadStream`].
+#[derive(Default)]
+pub(crate) struct StoreControlMessages {
+    /// In order of arrival.
+    store_infos: Vec<(StoreKey, LogMsgProto)>,
+
+    /// In order of arrival.
+    blueprint_activations: Vec<(StoreKey, LogMsgProto)>,
+}
+
+impl StoreControlMessages {
+    /// Remembers `msg` if it is a store info or blueprint activation.
+    pub(crate) fn update(&mut self, msg: &LogMsgProto) {
+        let (entries, store_id) = match &msg.msg {
+            Some(Msg::SetStoreInfo(set_store_info)) => (
+                &mut self.store_infos,
+                set_store_info
+                    .info
+                    .as_ref()
+                    .and_then(|info| info.store_id.as_ref()),
+            ),
+            Some(Msg::BlueprintActivationCommand(command)) => (
+                &mut self.blueprint_activations,
+                command.blueprint_id.as_ref(),
+            ),
+            _ => return,
+        };
+        let Some(store_id) = store_id else {
+            return;
+        };
+
+        let key = (store_id.kind, store_id.recording_id.clone());
+        if let Some((_, latest)) = entries.iter_mut().find(|(k, _)| *k == key) {
+            *latest = msg.clone();
+        } else {
+            entries.push((key, msg.clone()));
+        }
+    }
+
+    /// Store infos come first, so that blueprint activations refer to known stores.
+    fn snapshot(&self) -> Vec<LogMsgProto> {
+        self.store_infos
+            .iter()
+            .chain(&self.blueprint_activations)
+            .map(|(_, msg)| msg.clone())
+            .collect()
+    }
+}
+
+/// Live log messages for a relay subscriber, which skips ahead if it falls behind.
+///
+/// After skipping ahead, the latest store infos and blueprint activations are delivered again
+/// before resuming with the live messages.
+pub(crate) struct SkipAheadStream {
+    live: BroadcastStream<ClientLogMsgProto>,
+    store_control: Arc<Mutex<StoreControlMessages>>,
+
+    /// Delivered before the next live message.
+    redeliver: std::vec::IntoIter<LogMsgProto>,
+
+    caught_up: Option<CaughtUpSignal>,
+}
+
+impl SkipAheadStream {
+    pub(crate) fn new(
+        live: tokio::sync::broadcast::Receiver<ClientLogMsgProto>,
+        store_control: Arc<Mutex<StoreControlMessages>>,
+        caught_up: Option<CaughtUpSignal>,
+    ) -> Self {
+        Self {
+            live: BroadcastStream::new(live),
+            store_control,
+            redeliver: Vec::new().into_iter(),
+            caught_up,
+        }
+    }
+}
+
+impl Stream for SkipAheadStream {
+    type Item = LogMsgProto;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(msg) = this.redeliver.next() {
+                return Poll::Ready(Some(msg));
+            }
+
+            let result = std::task::ready!(Pin::new(&mut this.live).poll_next(cx));
+            if let Some(caught_up) = &this.caught_up {
+                caught_up.received();
+            }
+
+            match result {
+                None => return Poll::Ready(None),
+                Some(Ok(msg)) => return Poll::Ready(Some(msg.msg)),
+                Some(Err(BroadcastStreamRecvError::Lagged(n))) => {
+                    metrics().dropped(DropReason::SlowClient, n);
+                    re_log::warn!("A subscriber fell behind and skipped {n} messages");
+
+                    // Carry on from the oldest message still in the ring buffer.
+                    this.redeliver = this.store_control.lock().snapshot().into_iter();
+                }
+            }
+        }
+    }
+}
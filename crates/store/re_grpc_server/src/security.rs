@@ -0,0 +1,152 @@
+//! Transport security and client authentication of the message proxy server.
+
+use re_auth::server::BearerTokenAuthenticator;
+
+/// How a Rerun server protects itself when exposed to the network,
+/// see [`crate::ServerOptions::security`].
+///
+/// The default is to listen in plaintext and accept any client, which is fine on `localhost`
+/// but means that anyone on the network can write to a server listening on e.g. `0.0.0.0`.
+#[derive(Clone, Default)]
+pub struct ServerSecurity {
+    /// If set, the server only accepts TLS connections, which SDKs and viewers make when given
+    /// a `rerun+https://` URL.
+    ///
+    /// Note that clients verify the server certificate against the native root certificates of
+    /// their system, so self-signed certificates must be trusted there first.
+    pub tls: Option<TlsConfig>,
+
+    /// If not empty, every request must carry one of these tokens in an
+    /// `authorization: Bearer <token>` header, and is rejected as unauthenticated otherwise.
+    ///
+    /// Tokens are sent in the clear unless [`Self::tls`] is set as well.
+    pub auth_tokens: Vec<String>,
+}
+
+impl std::fmt::Debug for ServerSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak the tokens into logs.
+        f.debug_struct("ServerSecurity")
+            .field("tls", &self.tls)
+            .field("num_auth_tokens", &self.auth_tokens.len())
+            .finish()
+    }
+}
+
+impl ServerSecurity {
+    /// Only accept TLS connections, see [`Self::tls`].
+    #[inline]
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Accept requests carrying the given bearer token, see [`Self::auth_tokens`].
+    #[inline]
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_tokens.push(token.into());
+        self
+    }
+
+    pub(crate) fn scheme(&self) -> re_uri::Scheme {
+        if self.tls.is_some() {
+            re_uri::Scheme::RerunHttps
+        } else {
+            re_uri::Scheme::RerunHttp
+        }
+    }
+
+    pub(crate) fn token_authenticator(&self) -> BearerTokenAuthenticator {
+        BearerTokenAuthenticator::new(self.auth_tokens.iter().cloned())
+    }
+}
+
+/// TLS configuration of a Rerun server, see [`ServerSecurity::tls`].
+#[derive(Clone)]
+pub struct TlsConfig {
+    /// PEM-encoded certificate chain.
+    pub cert_pem: Vec<u8>,
+
+    /// PEM-encoded private key.
+    pub key_pem: Vec<u8>,
+}
+
+impl std::fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak the private key into logs.
+        f.debug_struct("TlsConfig").finish_non_exhaustive()
+    }
+}
+
+impl TlsConfig {
+    /// Create a TLS configuration from a PEM-encoded certificate chain and private key.
+    pub fn from_pem(cert_pem: impl Into<Vec<u8>>, key_pem: impl Into<Vec<u8>>) -> Self {
+        Self {
+            cert_pem: cert_pem.into(),
+            key_pem: key_pem.into(),
+        }
+    }
+
+    /// Read the PEM-encoded certificate chain and private key from the given files.
+    pub fn from_pem_files(
+        cert_path: impl AsRef<std::path::Path>,
+        key_path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<Self> {
+        let read = |path: &std::path::Path| {
+            std::fs::read(path).map_err(|err| {
+                std::io::Error::new(err.kind(), format!("failed to read {path:?}: {err}"))
+            })
+        };
+        Ok(Self::from_pem(
+            read(cert_path.as_ref())?,
+            read(key_path.as_ref())?,
+        ))
+    }
+
+    pub(crate) fn into_server_tls_config(self) -> tonic::transport::ServerTlsConfig {
+        tonic::transport::ServerTlsConfig::new().identity(tonic::transport::Identity::from_pem(
+            self.cert_pem,
+            self.key_pem,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tonic::{Request, service::Interceptor as _};
+
+    fn request(authorization: Option<&str>) -> Request<()> {
+        let mut req = Request::new(());
+        if let Some(authorization) = authorization {
+            req.metadata_mut()
+                .insert("authorization", authorization.parse().unwrap());
+        }
+        req
+    }
+
+    #[test]
+    fn token_authentication() {
+        let mut open = ServerSecurity::default().token_authenticator();
+        assert!(open.call(request(None)).is_ok());
+        assert!(open.call(request(Some("Bearer whatever"))).is_ok());
+
+        let mut secured = ServerSecurity::default()
+            .with_auth_token("secret")
+            .with_auth_token("other-secret")
+            .token_authenticator();
+        assert!(secured.call(request(Some("Bearer secret"))).is_ok());
+        assert!(secured.call(request(Some("Bearer other-secret"))).is_ok());
+
+        for authorization in [
+            None,
+            Some("secret"),
+            Some("Bearer secre"),
+            Some("Bearer wrong"),
+        ] {
+            let status = secured.call(request(authorization)).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        }
+    }
+}
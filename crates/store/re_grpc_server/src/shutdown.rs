@@ -1,13 +1,39 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
 use parking_lot::Mutex;
 use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
 
 pub fn shutdown() -> (Signal, Shutdown) {
     let (tx, rx) = oneshot::channel();
-    (Signal(Mutex::new(Some(tx))), Shutdown(Some(rx)))
+    (
+        Signal(Mutex::new(Some(tx))),
+        Shutdown {
+            rx: Some(rx),
+            drain: None,
+        },
+    )
+}
+
+/// Like [`shutdown`], but the returned [`DrainSignal`] can also wait for the server to hand over
+/// the messages it already received, see [`DrainSignal::stop_and_drain`].
+///
+/// Draining only applies to servers that forward messages into a smart channel,
+/// i.e. those started with [`crate::spawn_with_recv`].
+pub fn shutdown_with_drain() -> (DrainSignal, Shutdown) {
+    let (signal, mut shutdown) = shutdown();
+    let drain = Arc::new(Drain::default());
+    shutdown.drain = Some(Arc::clone(&drain));
+    (DrainSignal { signal, drain }, shutdown)
 }
 
 pub fn never() -> Shutdown {
-    Shutdown(None)
+    Shutdown {
+        rx: None,
+        drain: None,
+    }
 }
 
 pub struct Signal(Mutex<Option<oneshot::Sender<()>>>);
@@ -23,18 +49,118 @@ impl Signal {
     }
 }
 
-pub struct Shutdown(Option<oneshot::Receiver<()>>);
+/// Asks a server to shut down, and waits for it to drain, see [`shutdown_with_drain`].
+pub struct DrainSignal {
+    signal: Signal,
+    drain: Arc<Drain>,
+}
+
+impl DrainSignal {
+    /// Ask the server to shut down, without waiting for it to drain.
+    ///
+    /// Subsequent calls to this function have no effect.
+    pub fn stop(&self) {
+        self.signal.stop();
+    }
+
+    /// Ask the server to shut down, and wait for all messages received so far to be
+    /// forwarded into the smart channel, for at most `timeout`.
+    ///
+    /// The server stops accepting new connections right away.
+    /// Whatever hasn't been forwarded once the timeout expires is dropped,
+    /// and the smart channel is closed either way.
+    pub async fn stop_and_drain(&self, timeout: Duration) -> DrainReport {
+        self.signal.stop();
+
+        if !self.drain.is_attached.load(Ordering::Relaxed) {
+            // No server is forwarding messages, so there's nothing to drain.
+            return self.drain.report(false);
+        }
+
+        self.drain.stopping.cancel();
+
+        let timed_out = tokio::time::timeout(timeout, self.drain.done.cancelled())
+            .await
+            .is_err();
+        if timed_out {
+            self.drain.timed_out.cancel();
+            // Doesn't take long, now that we stopped waiting for messages.
+            self.drain.done.cancelled().await;
+        }
+
+        self.drain.report(timed_out)
+    }
+}
+
+/// The outcome of [`DrainSignal::stop_and_drain`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Log messages that never made it into the smart channel, over the lifetime of the server.
+    ///
+    /// This includes messages that were still queued when the drain timed out,
+    /// as well as those dropped earlier, e.g. because they couldn't be decoded.
+    pub messages_dropped: u64,
+
+    /// Whether the server had to give up on draining because of the timeout.
+    pub timed_out: bool,
+}
+
+/// Shared between a [`DrainSignal`] and the server draining its messages.
+#[derive(Default)]
+pub(crate) struct Drain {
+    /// Whether a server took on this drain, see [`Shutdown::take_drain`].
+    is_attached: AtomicBool,
+
+    /// Cancelled when the server should start draining.
+    pub stopping: CancellationToken,
+
+    /// Cancelled when the server should stop draining, and drop whatever is left.
+    pub timed_out: CancellationToken,
+
+    /// Cancelled by the server once it is done draining.
+    pub done: CancellationToken,
+
+    messages_dropped: AtomicU64,
+}
+
+impl Drain {
+    pub fn dropped(&self, num_messages: u64) {
+        self.messages_dropped
+            .fetch_add(num_messages, Ordering::Relaxed);
+    }
+
+    fn report(&self, timed_out: bool) -> DrainReport {
+        DrainReport {
+            messages_dropped: self.messages_dropped.load(Ordering::Relaxed),
+            timed_out,
+        }
+    }
+}
+
+pub struct Shutdown {
+    rx: Option<oneshot::Receiver<()>>,
+
+    /// Only set by [`shutdown_with_drain`].
+    drain: Option<Arc<Drain>>,
+}
 
 impl Shutdown {
     /// Returns a future that resolves when the signal is sent.
     ///
     /// If this was constructed with [`never()`], then it never resolves.
     pub async fn wait(self) {
-        if let Some(rx) = self.0 {
+        if let Some(rx) = self.rx {
             rx.await.ok();
         } else {
             // Never resolve
             std::future::pending::<()>().await;
         }
     }
+
+    /// For servers that are able to drain, see [`shutdown_with_drain`].
+    pub(crate) fn take_drain(&mut self) -> Option<Arc<Drain>> {
+        let drain = self.drain.take()?;
+        drain.is_attached.store(true, Ordering::Relaxed);
+        Some(drain)
+    }
 }
@@ -0,0 +1,144 @@
+//! Temporary storage for messages that don't fit into the memory limit,
+//! see [`crate::OverflowPolicy::SpillToDisk`].
+
+use std::io::{BufReader, Read as _, Write as _};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use re_protos::external::prost::Message as _;
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
+
+/// An append-only file of length-delimited log messages, deleted on drop.
+pub(crate) struct SpillFile {
+    path: PathBuf,
+    file: std::fs::File,
+
+    /// Total size of the file in bytes.
+    size_bytes: u64,
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_file(&self.path) {
+            re_log::warn!("Failed to remove spill file {:?}: {err}", self.path);
+        }
+    }
+}
+
+impl SpillFile {
+    pub fn new() -> std::io::Result<Self> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "rerun-grpc-server-{}-{}.spill",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = std::fs::File::options()
+            .append(true)
+            .create_new(true)
+            .open(&path)?;
+
+        re_log::debug!("Spilling messages that don't fit into memory to {path:?}");
+
+        Ok(Self {
+            path,
+            file,
+            size_bytes: 0,
+        })
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.size_bytes
+    }
+
+    pub fn push(&mut self, msg: &LogMsgProto) -> std::io::Result<()> {
+        let bytes = msg.encode_length_delimited_to_vec();
+        self.file.write_all(&bytes)?;
+        self.size_bytes += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Reads back the messages pushed so far, in the order they were pushed.
+    ///
+    /// Messages are only read from disk as the returned iterator advances,
+    /// and messages pushed after this call are not included.
+    pub fn read(&self) -> std::io::Result<SpillReader> {
+        let file = std::fs::File::open(&self.path)?;
+        Ok(SpillReader {
+            reader: BufReader::new(file).take(self.size_bytes),
+        })
+    }
+}
+
+/// Reads back the messages of a [`SpillFile`], one at a time.
+pub(crate) struct SpillReader {
+    reader: std::io::Take<BufReader<std::fs::File>>,
+}
+
+impl SpillReader {
+    fn read_next(&mut self) -> std::io::Result<Option<LogMsgProto>> {
+        // Each message is preceded by its length, as a varint.
+        let mut len = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let mut byte = [0_u8];
+            if self.reader.read(&mut byte)? == 0 {
+                if shift == 0 {
+                    return Ok(None);
+                }
+                return Err(std::io::ErrorKind::UnexpectedEof.into());
+            }
+            len |= u64::from(byte[0] & 0x7f) << shift;
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+        }
+
+        let mut bytes = vec![0; len as usize];
+        self.reader.read_exact(&mut bytes)?;
+        LogMsgProto::decode(bytes.as_slice())
+            .map(Some)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl Iterator for SpillReader {
+    type Item = std::io::Result<LogMsgProto>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use re_protos::log_msg::v1alpha1::{ArrowMsg, log_msg::Msg};
+
+    fn msg(payload: &[u8]) -> LogMsgProto {
+        LogMsgProto {
+            msg: Some(Msg::ArrowMsg(ArrowMsg {
+                payload: payload.to_vec().into(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    #[test]
+    fn reads_back_messages_pushed_before_reading() {
+        let mut spill_file = SpillFile::new().unwrap();
+        let first = msg(b"first");
+        let second = msg(&[42; 300]); // Long enough for a multi-byte length prefix.
+
+        spill_file.push(&first).unwrap();
+        spill_file.push(&second).unwrap();
+        let reader = spill_file.read().unwrap();
+
+        // Not part of what `reader` sees.
+        spill_file.push(&msg(b"third")).unwrap();
+
+        let messages = reader.collect::<std::io::Result<Vec<_>>>().unwrap();
+        assert_eq!(messages, vec![first, second]);
+    }
+}
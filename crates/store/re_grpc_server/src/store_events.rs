@@ -0,0 +1,129 @@
+//! Forwarding the events of the chunk stores of the process for the `ReadStoreEvents` RPC.
+
+use std::sync::OnceLock;
+
+use tokio::sync::broadcast;
+
+use re_chunk_store::{ChunkStore, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreSubscriber};
+use re_protos::common::v1alpha1::StoreId as StoreIdProto;
+use re_protos::sdk_comms::v1alpha1::{
+    ReadStoreEventsRequest, ReadStoreEventsResponse, StoreEventKind,
+};
+
+/// How many events are buffered for clients that are slow to read them.
+const STORE_EVENT_CHANNEL_CAPACITY: usize = 4096;
+
+/// Forwards the events of every [`ChunkStore`] of the process to the clients of `ReadStoreEvents`.
+struct StoreEventForwarder {
+    tx: broadcast::Sender<ChunkStoreEvent>,
+}
+
+impl ChunkStoreSubscriber for StoreEventForwarder {
+    fn name(&self) -> String {
+        "rerun.store_subscribers.StoreEventForwarder".into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn on_events(&mut self, events: &[ChunkStoreEvent]) {
+        for event in events {
+            // This only fails if nobody is listening right now.
+            self.tx.send(event.clone()).ok();
+        }
+    }
+}
+
+/// Receives the events of every [`ChunkStore`] of the process, from now on.
+///
+/// The subscriber forwarding them is only registered on first use, so that processes which never
+/// serve `ReadStoreEvents` don't pay for it.
+pub(crate) fn subscribe() -> broadcast::Receiver<ChunkStoreEvent> {
+    static TX: OnceLock<broadcast::Sender<ChunkStoreEvent>> = OnceLock::new();
+
+    TX.get_or_init(|| {
+        let (tx, _) = broadcast::channel(STORE_EVENT_CHANNEL_CAPACITY);
+        ChunkStore::register_subscriber(Box::new(StoreEventForwarder { tx: tx.clone() }));
+        tx
+    })
+    .subscribe()
+}
+
+/// Which store events a `ReadStoreEvents` request asks for, and how.
+pub(crate) struct StoreEventQuery {
+    /// Only this store, or all of them if `None`.
+    store_id: Option<StoreIdProto>,
+
+    include_payloads: bool,
+}
+
+impl StoreEventQuery {
+    pub fn from_request(request: ReadStoreEventsRequest) -> Self {
+        let ReadStoreEventsRequest {
+            store_id,
+            include_payloads,
+        } = request;
+
+        Self {
+            store_id,
+            include_payloads,
+        }
+    }
+
+    /// The response for `event`, or `None` if it isn't part of the query.
+    pub fn to_response(
+        &self,
+        event: &ChunkStoreEvent,
+    ) -> Option<tonic::Result<ReadStoreEventsResponse>> {
+        let store_id = StoreIdProto::from(event.store_id.clone());
+        if let Some(expected) = &self.store_id
+            && (store_id.kind != expected.kind || store_id.recording_id != expected.recording_id)
+        {
+            return None;
+        }
+
+        let chunk = &event.diff.chunk;
+        let kind = match event.diff.kind {
+            ChunkStoreDiffKind::Addition => StoreEventKind::Addition,
+            ChunkStoreDiffKind::Deletion => StoreEventKind::Deletion,
+        };
+
+        let payload = if self.include_payloads {
+            match encode_chunk(event) {
+                Ok(payload) => Some(payload),
+                Err(err) => {
+                    re_log::error!("Failed to encode chunk for store event: {err}");
+                    return Some(Err(tonic::Status::internal(format!(
+                        "failed to encode chunk: {err}"
+                    ))));
+                }
+            }
+        } else {
+            None
+        };
+
+        Some(Ok(ReadStoreEventsResponse {
+            store_id: Some(store_id),
+            event_id: event.event_id,
+            kind: kind as i32,
+            chunk_id: Some(chunk.id().as_tuid().into()),
+            entity_path: Some(chunk.entity_path().clone().into()),
+            num_rows: chunk.num_rows() as u64,
+            chunk: payload,
+        }))
+    }
+}
+
+fn encode_chunk(event: &ChunkStoreEvent) -> anyhow::Result<re_protos::log_msg::v1alpha1::ArrowMsg> {
+    let arrow_msg = event.diff.chunk.to_arrow_msg()?;
+    Ok(re_log_encoding::protobuf_conversions::arrow_msg_to_proto(
+        &arrow_msg,
+        event.store_id.clone(),
+        re_log_encoding::Compression::LZ4,
+    )?)
+}
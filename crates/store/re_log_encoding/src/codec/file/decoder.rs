@@ -82,7 +82,9 @@ pub fn decode_bytes_to_transport(
     buf: &[u8],
 ) -> Result<Option<re_protos::log_msg::v1alpha1::log_msg::Msg>, DecodeError> {
     use re_protos::external::prost::Message as _;
-    use re_protos::log_msg::v1alpha1::{ArrowMsg, BlueprintActivationCommand, SetStoreInfo};
+    use re_protos::log_msg::v1alpha1::{
+        ArrowMsg, BlueprintActivationCommand, DropTimeRangeCommand, SetStoreInfo,
+    };
 
     let msg = match message_kind {
         MessageKind::SetStoreInfo => {
@@ -102,6 +104,11 @@ pub fn decode_bytes_to_transport(
             Some(re_protos::log_msg::v1alpha1::log_msg::Msg::BlueprintActivationCommand(msg))
         }
 
+        MessageKind::DropTimeRangeCommand => {
+            let msg = DropTimeRangeCommand::decode(buf)?;
+            Some(re_protos::log_msg::v1alpha1::log_msg::Msg::DropTimeRangeCommand(msg))
+        }
+
         MessageKind::End => None,
     };
 
@@ -210,6 +217,14 @@ pub fn decode_transport_to_app(
                 make_default: blueprint_activation_command.make_default,
             })
         }
+
+        re_protos::log_msg::v1alpha1::log_msg::Msg::DropTimeRangeCommand(
+            drop_time_range_command,
+        ) => LogMsg::DropTimeRange(
+            crate::protobuf_conversions::drop_time_range_command_from_proto(
+                drop_time_range_command,
+            )?,
+        ),
     };
 
     Ok(msg)
@@ -12,7 +12,7 @@ pub(crate) fn encode(
 ) -> Result<(), EncodeError> {
     use re_protos::external::prost::Message as _;
     use re_protos::log_msg::v1alpha1::{
-        ArrowMsg, BlueprintActivationCommand, Encoding, SetStoreInfo,
+        ArrowMsg, BlueprintActivationCommand, DropTimeRangeCommand, Encoding, SetStoreInfo,
     };
 
     match message {
@@ -63,6 +63,16 @@ pub(crate) fn encode(
             header.encode(buf)?;
             blueprint_activation_command.encode(buf)?;
         }
+        LogMsg::DropTimeRange(drop_time_range_command) => {
+            let drop_time_range_command: DropTimeRangeCommand =
+                drop_time_range_command.clone().into();
+            let header = MessageHeader {
+                kind: MessageKind::DropTimeRangeCommand,
+                len: drop_time_range_command.encoded_len() as u64,
+            };
+            header.encode(buf)?;
+            drop_time_range_command.encode(buf)?;
+        }
     }
 
     Ok(())
@@ -97,6 +107,14 @@ pub(crate) fn encode_proto(buf: &mut Vec<u8>, message: proto::LogMsg) -> Result<
             header.encode(buf)?;
             blueprint_activation_command.encode(buf)?;
         }
+        proto::log_msg::Msg::DropTimeRangeCommand(drop_time_range_command) => {
+            let header = MessageHeader {
+                kind: MessageKind::DropTimeRangeCommand,
+                len: drop_time_range_command.encoded_len() as u64,
+            };
+            header.encode(buf)?;
+            drop_time_range_command.encode(buf)?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,262 @@
+//! The optional index footer of `.rrd` files.
+//!
+//! The footer follows the end-of-stream marker, and lists where each message of the file is:
+//!
+//! ```text,ignore
+//! "RRFI" | num_entries: u64                            <- same size as a `FileHeader`, so streams can skip it
+//! (byte_offset: u64 | byte_len: u64 | flags: u64) * num_entries
+//! num_entries: u64 | file_len: u64 | "RRFI" 0 0 0 0     <- read first, from the end of the file
+//! ```
+//!
+//! All integers are little-endian. Decoders that don't know about the footer stop at the end-of-stream marker,
+//! i.e. they never see it.
+
+use re_chunk::Span;
+
+use super::MessageKind;
+
+// ---
+
+pub(crate) const INDEX_MAGIC: &[u8; 4] = b"RRFI";
+
+/// The size of the start of the footer, see [`index_footer_len`].
+pub(crate) const HEAD_SIZE: usize = 12;
+
+const _: () = assert!(
+    HEAD_SIZE == crate::FileHeader::SIZE,
+    "Streaming decoders tell footers and file headers apart by their first bytes"
+);
+
+const ENTRY_SIZE: usize = 24;
+
+const TAIL_SIZE: usize = 24;
+
+const FLAG_STATIC: u64 = 1 << 8;
+const FLAG_BLUEPRINT: u64 = 1 << 9;
+
+/// Where a message is in an `.rrd` file, and what it is, without decoding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RrdIndexEntry {
+    /// Covers both the message header and the message, from the start of the file.
+    pub byte_span: Span<u64>,
+
+    pub kind: MessageKind,
+
+    /// Whether the message holds a static chunk.
+    pub is_static: bool,
+
+    /// Whether the message belongs to a blueprint rather than a recording.
+    pub is_blueprint: bool,
+}
+
+impl RrdIndexEntry {
+    #[cfg(feature = "encoder")]
+    fn flags(&self) -> u64 {
+        let mut flags = self.kind as u64;
+        if self.is_static {
+            flags |= FLAG_STATIC;
+        }
+        if self.is_blueprint {
+            flags |= FLAG_BLUEPRINT;
+        }
+        flags
+    }
+
+    #[cfg(feature = "decoder")]
+    fn from_flags(byte_span: Span<u64>, flags: u64) -> Option<Self> {
+        let kind = match flags & 0xFF {
+            MessageKind::SET_STORE_INFO => MessageKind::SetStoreInfo,
+            MessageKind::ARROW_MSG => MessageKind::ArrowMsg,
+            MessageKind::BLUEPRINT_ACTIVATION_COMMAND => MessageKind::BlueprintActivationCommand,
+            MessageKind::DROP_TIME_RANGE_COMMAND => MessageKind::DropTimeRangeCommand,
+            _ => return None,
+        };
+
+        Some(Self {
+            byte_span,
+            kind,
+            is_static: flags & FLAG_STATIC != 0,
+            is_blueprint: flags & FLAG_BLUEPRINT != 0,
+        })
+    }
+
+    #[cfg(feature = "encoder")]
+    pub(crate) fn from_log_msg(byte_span: Span<u64>, msg: &re_log_types::LogMsg) -> Self {
+        use re_log_types::LogMsg;
+
+        let (kind, is_static) = match msg {
+            LogMsg::SetStoreInfo(_) => (MessageKind::SetStoreInfo, false),
+            LogMsg::ArrowMsg(_, arrow_msg) => (
+                MessageKind::ArrowMsg,
+                re_sorbet::is_static_chunk(&arrow_msg.batch).unwrap_or(false),
+            ),
+            LogMsg::BlueprintActivationCommand(_) => {
+                (MessageKind::BlueprintActivationCommand, false)
+            }
+            LogMsg::DropTimeRange(_) => (MessageKind::DropTimeRangeCommand, false),
+        };
+
+        Self {
+            byte_span,
+            kind,
+            is_static,
+            is_blueprint: msg.store_id().is_blueprint(),
+        }
+    }
+
+    #[cfg(feature = "encoder")]
+    pub(crate) fn from_proto(
+        byte_span: Span<u64>,
+        msg: &re_protos::log_msg::v1alpha1::log_msg::Msg,
+    ) -> Self {
+        use re_protos::common::v1alpha1::StoreKind;
+        use re_protos::log_msg::v1alpha1::log_msg::Msg;
+
+        let is_blueprint = |store_id: Option<&re_protos::common::v1alpha1::StoreId>| {
+            store_id.is_some_and(|store_id| store_id.kind == StoreKind::Blueprint as i32)
+        };
+
+        let (kind, is_static, is_blueprint) = match msg {
+            Msg::SetStoreInfo(set_store_info) => (
+                MessageKind::SetStoreInfo,
+                false,
+                is_blueprint(
+                    set_store_info
+                        .info
+                        .as_ref()
+                        .and_then(|info| info.store_id.as_ref()),
+                ),
+            ),
+            Msg::ArrowMsg(arrow_msg) => (
+                MessageKind::ArrowMsg,
+                arrow_msg.is_static.unwrap_or(false),
+                is_blueprint(arrow_msg.store_id.as_ref()),
+            ),
+            Msg::BlueprintActivationCommand(_) => {
+                (MessageKind::BlueprintActivationCommand, false, true)
+            }
+            Msg::DropTimeRangeCommand(drop_time_range_command) => (
+                MessageKind::DropTimeRangeCommand,
+                false,
+                is_blueprint(drop_time_range_command.store_id.as_ref()),
+            ),
+        };
+
+        Self {
+            byte_span,
+            kind,
+            is_static,
+            is_blueprint,
+        }
+    }
+}
+
+/// The index footer of an `.rrd` file: where each of its messages is, in the order they were written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RrdIndex {
+    pub entries: Vec<RrdIndexEntry>,
+}
+
+impl RrdIndex {
+    /// Writes the footer, right after the end-of-stream marker that ends at `offset`.
+    ///
+    /// Returns the size in bytes of the footer.
+    #[cfg(feature = "encoder")]
+    pub(crate) fn encode(
+        &self,
+        offset: u64,
+        write: &mut impl std::io::Write,
+    ) -> Result<u64, crate::encoder::EncodeError> {
+        let num_entries = self.entries.len() as u64;
+        let footer_len = (HEAD_SIZE + self.entries.len() * ENTRY_SIZE + TAIL_SIZE) as u64;
+
+        let mut buf = Vec::with_capacity(footer_len as usize);
+        buf.extend_from_slice(INDEX_MAGIC);
+        buf.extend_from_slice(&num_entries.to_le_bytes());
+        for entry in &self.entries {
+            buf.extend_from_slice(&entry.byte_span.start.to_le_bytes());
+            buf.extend_from_slice(&entry.byte_span.len.to_le_bytes());
+            buf.extend_from_slice(&entry.flags().to_le_bytes());
+        }
+        buf.extend_from_slice(&num_entries.to_le_bytes());
+        buf.extend_from_slice(&(offset + footer_len).to_le_bytes());
+        buf.extend_from_slice(INDEX_MAGIC);
+        buf.extend_from_slice(&[0; 4]);
+
+        write.write_all(&buf)?;
+
+        Ok(footer_len)
+    }
+
+    /// Reads the footer at the end of `read`, if it has one.
+    ///
+    /// Returns `None` if the footer doesn't describe the whole of `read`, e.g. for concatenated files.
+    #[cfg(feature = "decoder")]
+    pub fn decode_from_end(
+        read: &mut (impl std::io::Read + std::io::Seek),
+    ) -> Result<Option<Self>, crate::decoder::DecodeError> {
+        use std::io::SeekFrom;
+
+        let stream_len = read.seek(SeekFrom::End(0))?;
+        if stream_len < (crate::FileHeader::SIZE + HEAD_SIZE + TAIL_SIZE) as u64 {
+            return Ok(None);
+        }
+
+        let mut tail = [0_u8; TAIL_SIZE];
+        read.seek(SeekFrom::End(-(TAIL_SIZE as i64)))?;
+        read.read_exact(&mut tail)?;
+        if &tail[16..20] != INDEX_MAGIC {
+            return Ok(None);
+        }
+
+        let num_entries = read_u64(&tail[0..8]);
+        let file_len = read_u64(&tail[8..16]);
+        let footer_len = (HEAD_SIZE as u64)
+            .saturating_add(num_entries.saturating_mul(ENTRY_SIZE as u64))
+            .saturating_add(TAIL_SIZE as u64);
+        if file_len != stream_len || footer_len > stream_len {
+            return Ok(None);
+        }
+
+        let mut footer = vec![0_u8; (footer_len - TAIL_SIZE as u64) as usize];
+        read.seek(SeekFrom::Start(stream_len - footer_len))?;
+        read.read_exact(&mut footer)?;
+        if index_footer_len(&footer[..HEAD_SIZE]) != Some(footer_len - HEAD_SIZE as u64) {
+            return Ok(None);
+        }
+
+        let entries = footer[HEAD_SIZE..]
+            .chunks_exact(ENTRY_SIZE)
+            .map(|entry| {
+                let byte_span = Span {
+                    start: read_u64(&entry[0..8]),
+                    len: read_u64(&entry[8..16]),
+                };
+                RrdIndexEntry::from_flags(byte_span, read_u64(&entry[16..24]))
+                    .filter(|_| byte_span.end() <= stream_len - footer_len)
+            })
+            .collect::<Option<Vec<_>>>();
+
+        Ok(entries.map(|entries| Self { entries }))
+    }
+}
+
+/// If `head` is the start of an index footer, returns how many more bytes the footer has.
+///
+/// `head` must be at least [`HEAD_SIZE`] bytes long.
+#[cfg(feature = "decoder")]
+pub(crate) fn index_footer_len(head: &[u8]) -> Option<u64> {
+    if head.get(..4)? != INDEX_MAGIC {
+        return None;
+    }
+    let num_entries = read_u64(head.get(4..HEAD_SIZE)?);
+    num_entries
+        .checked_mul(ENTRY_SIZE as u64)?
+        .checked_add(TAIL_SIZE as u64)
+}
+
+#[cfg(feature = "decoder")]
+#[expect(clippy::unwrap_used)] // Always 8 bytes
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().unwrap())
+}
@@ -2,6 +2,8 @@
 pub mod decoder;
 #[cfg(feature = "encoder")]
 pub mod encoder;
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+pub mod index;
 
 #[allow(dead_code)] // used behind feature flag
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +14,7 @@ pub enum MessageKind {
     SetStoreInfo = Self::SET_STORE_INFO,
     ArrowMsg = Self::ARROW_MSG,
     BlueprintActivationCommand = Self::BLUEPRINT_ACTIVATION_COMMAND,
+    DropTimeRangeCommand = Self::DROP_TIME_RANGE_COMMAND,
 }
 
 #[allow(dead_code)] // used behind feature flag
@@ -20,6 +23,7 @@ impl MessageKind {
     const SET_STORE_INFO: u64 = 1;
     const ARROW_MSG: u64 = 2;
     const BLUEPRINT_ACTIVATION_COMMAND: u64 = 3;
+    const DROP_TIME_RANGE_COMMAND: u64 = 4;
 }
 
 #[allow(dead_code)] // used behind feature flag
@@ -76,6 +80,7 @@ impl MessageHeader {
             MessageKind::SET_STORE_INFO => MessageKind::SetStoreInfo,
             MessageKind::ARROW_MSG => MessageKind::ArrowMsg,
             MessageKind::BLUEPRINT_ACTIVATION_COMMAND => MessageKind::BlueprintActivationCommand,
+            MessageKind::DROP_TIME_RANGE_COMMAND => MessageKind::DropTimeRangeCommand,
             _ => {
                 return Err(crate::decoder::DecodeError::Codec(
                     crate::codec::CodecError::UnknownMessageHeader,
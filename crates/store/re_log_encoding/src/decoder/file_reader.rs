@@ -0,0 +1,272 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use re_build_info::CrateVersion;
+use re_log_types::LogMsg;
+
+use crate::{
+    EncodingOptions, FileHeader, Serializer,
+    app_id_injector::CachingApplicationIdInjector,
+    codec::file::{
+        MessageKind, decoder,
+        index::{RrdIndex, RrdIndexEntry},
+    },
+};
+
+use super::{DecodeError, Decoder, options_from_bytes};
+
+// ---
+
+/// Reads the messages of an `.rrd` file one at a time, so that they can be shown while the rest of the file loads.
+///
+/// If the file ends with an index (see [`crate::encoder::Encoder::with_index_footer`]), the messages are read
+/// straight from where the index says they are, store infos first, then static data, then everything else in the
+/// order it was logged. That way everything that the first temporal data depends on is already there when it
+/// shows up.
+///
+/// Other files are decoded from front to back, just like with [`Decoder`].
+pub struct RrdFileReader<R: Read + Seek> {
+    inner: Inner<R>,
+}
+
+enum Inner<R: Read + Seek> {
+    Indexed {
+        version: CrateVersion,
+        options: EncodingOptions,
+        read: R,
+
+        /// The messages that are left to read, in reverse order.
+        entries: Vec<RrdIndexEntry>,
+
+        /// The total number of messages in the file.
+        num_entries: usize,
+
+        buffer: Vec<u8>,
+
+        /// The application id cache used for migrating old data.
+        app_id_cache: CachingApplicationIdInjector,
+    },
+
+    Sequential(Decoder<R>),
+}
+
+impl<R: Read + Seek> RrdFileReader<R> {
+    pub fn new(mut read: R) -> Result<Self, DecodeError> {
+        re_tracing::profile_function!();
+
+        let index = RrdIndex::decode_from_end(&mut read)?;
+        read.seek(SeekFrom::Start(0))?;
+
+        if let Some(index) = index {
+            Self::with_index(read, index)
+        } else {
+            re_log::trace!("No index found, decoding the whole stream");
+            Ok(Self {
+                inner: Inner::Sequential(Decoder::new(read)?),
+            })
+        }
+    }
+
+    /// Like [`Self::new`], but returns `None` for files without an index.
+    ///
+    /// Files that are still being written to never have one.
+    pub fn new_indexed(mut read: R) -> Result<Option<Self>, DecodeError> {
+        re_tracing::profile_function!();
+
+        let index = RrdIndex::decode_from_end(&mut read)?;
+        read.seek(SeekFrom::Start(0))?;
+
+        index.map(|index| Self::with_index(read, index)).transpose()
+    }
+
+    fn with_index(mut read: R, index: RrdIndex) -> Result<Self, DecodeError> {
+        let mut data = [0_u8; FileHeader::SIZE];
+        read.read_exact(&mut data)?;
+        let (version, options) = options_from_bytes(&data)?;
+
+        let mut entries = index.entries;
+        entries.sort_by_key(|entry| match entry.kind {
+            MessageKind::SetStoreInfo => 0,
+            _ if entry.is_static => 1,
+            _ => 2,
+        });
+        entries.reverse();
+
+        Ok(Self {
+            inner: Inner::Indexed {
+                version,
+                options,
+                read,
+                num_entries: entries.len(),
+                entries,
+                buffer: Vec::new(),
+                app_id_cache: CachingApplicationIdInjector::default(),
+            },
+        })
+    }
+
+    /// Returns the Rerun version that was used to encode the data in the first place.
+    #[inline]
+    pub fn version(&self) -> CrateVersion {
+        match &self.inner {
+            Inner::Indexed { version, .. } => *version,
+            Inner::Sequential(decoder) => decoder.version(),
+        }
+    }
+
+    /// Whether the file had an index, i.e. its messages are read in order of importance.
+    #[inline]
+    pub fn is_indexed(&self) -> bool {
+        matches!(self.inner, Inner::Indexed { .. })
+    }
+
+    /// How many of the messages of the file were read so far, and how many there are in total.
+    ///
+    /// Only known for indexed files, see [`Self::is_indexed`].
+    #[inline]
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        match &self.inner {
+            Inner::Indexed {
+                entries,
+                num_entries,
+                ..
+            } => Some((num_entries - entries.len(), *num_entries)),
+            Inner::Sequential(_) => None,
+        }
+    }
+}
+
+impl<R: Read + Seek> Iterator for RrdFileReader<R> {
+    type Item = Result<LogMsg, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Sequential(decoder) => Iterator::next(decoder),
+
+            Inner::Indexed {
+                version: _,
+                options,
+                read,
+                entries,
+                num_entries: _,
+                buffer,
+                app_id_cache,
+            } => loop {
+                let entry = entries.pop()?;
+
+                let result = match options.serializer {
+                    Serializer::Protobuf => read_entry(read, &entry, buffer).and_then(|()| {
+                        decoder::decode_to_app(app_id_cache, &mut buffer.as_slice())
+                    }),
+                };
+
+                match result {
+                    Ok((_, Some(msg))) => return Some(Ok(msg)),
+
+                    Ok((_, None)) => {} // An end-of-stream marker, which has nothing to yield.
+
+                    //TODO(#10730): remove this if/when we remove the legacy `StoreId` migration.
+                    Err(DecodeError::StoreIdMissingApplicationId {
+                        store_kind,
+                        recording_id,
+                    }) => {
+                        re_log::warn_once!(
+                            "Dropping message without application id which arrived before `SetStoreInfo` \
+                            (kind: {store_kind}, recording id: {recording_id}."
+                        );
+                    }
+
+                    Err(err) => return Some(Err(err)),
+                }
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Inner::Indexed { entries, .. } => (0, Some(entries.len())),
+            Inner::Sequential(decoder) => Iterator::size_hint(decoder),
+        }
+    }
+}
+
+fn read_entry(
+    read: &mut (impl Read + Seek),
+    entry: &RrdIndexEntry,
+    buffer: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    re_tracing::profile_function!();
+
+    buffer.resize(entry.byte_span.len as usize, 0);
+    read.seek(SeekFrom::Start(entry.byte_span.start))?;
+    read.read_exact(buffer)?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "encoder"))]
+mod tests {
+    #![allow(clippy::unwrap_used)] // acceptable for tests
+
+    use re_build_info::CrateVersion;
+
+    use crate::EncodingOptions;
+    use crate::decoder::tests::fake_log_messages;
+    use crate::encoder::DroppableEncoder;
+
+    use super::*;
+
+    fn encode(with_index: bool, messages: &[LogMsg]) -> Vec<u8> {
+        let mut data = vec![];
+        {
+            let mut encoder = DroppableEncoder::new(
+                CrateVersion::LOCAL,
+                EncodingOptions::PROTOBUF_COMPRESSED,
+                &mut data,
+            )
+            .unwrap();
+            if with_index {
+                encoder = encoder.with_index_footer();
+            }
+            for msg in messages {
+                encoder.append(msg).unwrap();
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn indexed_and_sequential_read_the_same_messages() {
+        let messages = fake_log_messages();
+
+        let sequential =
+            RrdFileReader::new(std::io::Cursor::new(encode(false, &messages))).unwrap();
+        assert!(!sequential.is_indexed());
+        let sequential: Vec<_> = sequential.map(Result::unwrap).collect();
+        assert_eq!(sequential, messages);
+
+        let data = encode(true, &messages);
+        let indexed = RrdFileReader::new(std::io::Cursor::new(data.clone())).unwrap();
+        assert!(indexed.is_indexed());
+        assert_eq!(indexed.progress(), Some((0, messages.len())));
+        let indexed: Vec<_> = indexed.map(Result::unwrap).collect();
+        assert_eq!(indexed.len(), messages.len());
+        assert!(matches!(indexed[0], LogMsg::SetStoreInfo(_)));
+        for msg in &messages {
+            assert!(indexed.contains(msg));
+        }
+
+        // Decoders that don't use the index are not bothered by it.
+        let decoded: Vec<_> = Decoder::new(data.as_slice())
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(decoded, messages);
+
+        let concatenated = [data.clone(), data].concat();
+        let decoded: Vec<_> =
+            Decoder::new_concatenated(std::io::BufReader::new(concatenated.as_slice()))
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+        assert_eq!(decoded, [messages.clone(), messages].concat());
+    }
+}
@@ -1,10 +1,13 @@
 //! Decoding [`LogMsg`]:es from `.rrd` files/streams.
 
+mod file_reader;
 pub mod stream;
 
 #[cfg(feature = "decoder")]
 pub mod streaming;
 
+pub use self::file_reader::RrdFileReader;
+
 use std::io::{BufRead as _, Read as _};
 
 use re_build_info::CrateVersion;
@@ -13,7 +16,10 @@ use re_log_types::LogMsg;
 use crate::{
     EncodingOptions, FileHeader, OLD_RRD_HEADERS, Serializer,
     app_id_injector::CachingApplicationIdInjector,
-    codec::{self, file::decoder},
+    codec::{
+        self,
+        file::{decoder, index},
+    },
 };
 
 // ----------------------------------------------------------------------------
@@ -373,7 +379,8 @@ impl<R: std::io::Read> Decoder<R> {
         };
 
         let Some(msg) = msg else {
-            // we might have a concatenated stream, so we peek beyond end of file marker to see
+            // we might have a concatenated stream, so we peek beyond end of file marker (and index) to see
+            self.skip_index_footer();
             if self.peek_file_header() {
                 re_log::debug!(
                     "Reached end of stream, but it seems we have a concatenated file, continuing"
@@ -411,6 +418,31 @@ impl<R: std::io::Read> Decoder<R> {
         }
     }
 
+    /// Skips the index footer that may follow the end-of-stream marker, see [`codec::file::index`].
+    ///
+    /// No-op if the decoder wasn't initialized with [`Decoder::new_concatenated`].
+    fn skip_index_footer(&mut self) {
+        let Reader::Buffered(read) = &mut self.read else {
+            return;
+        };
+
+        let footer_len = match read.fill_buf() {
+            Ok(buf) if buf.len() >= index::HEAD_SIZE => {
+                index::index_footer_len(&buf[..index::HEAD_SIZE])
+            }
+            _ => None,
+        };
+        let Some(footer_len) = footer_len else {
+            return;
+        };
+
+        read.consume(index::HEAD_SIZE);
+        match std::io::copy(&mut read.by_ref().take(footer_len), &mut std::io::sink()) {
+            Ok(num_bytes) => self.size_bytes += index::HEAD_SIZE as u64 + num_bytes,
+            Err(err) => re_log::warn_once!("Failed to skip the index of an .rrd stream: {err}"),
+        }
+    }
+
     /// Returns a [`RawIterator`] over the transport-level data (Protobuf).
     pub fn into_raw_iter(self) -> RawIterator<R> {
         RawIterator { decoder: self }
@@ -512,7 +544,7 @@ mod tests {
 
     fn log_msg_to_proto(message: LogMsg) -> LogMsgProto {
         use re_protos::log_msg::v1alpha1::{
-            ArrowMsg, BlueprintActivationCommand, Encoding, SetStoreInfo,
+            ArrowMsg, BlueprintActivationCommand, DropTimeRangeCommand, Encoding, SetStoreInfo,
         };
 
         let msg: proto::log_msg::Msg = match message {
@@ -548,6 +580,12 @@ mod tests {
 
                 proto::log_msg::Msg::BlueprintActivationCommand(blueprint_activation_command)
             }
+            LogMsg::DropTimeRange(drop_time_range_command) => {
+                let drop_time_range_command: DropTimeRangeCommand =
+                    drop_time_range_command.clone().into();
+
+                proto::log_msg::Msg::DropTimeRangeCommand(drop_time_range_command)
+            }
         };
 
         LogMsgProto { msg: Some(msg) }
@@ -600,6 +638,10 @@ mod tests {
                         legacy_store_id.application_id = None;
                         *store_id = Some(legacy_store_id);
                     }
+
+                    Some(proto::log_msg::Msg::DropTimeRangeCommand(..)) => {
+                        panic!("`DropTimeRangeCommand` never had legacy store ids")
+                    }
                 }
 
                 log_msg
@@ -638,6 +680,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_decode_drop_time_range() {
+        let messages = vec![LogMsg::DropTimeRange(re_log_types::DropTimeRangeCommand {
+            store_id: StoreId::random(StoreKind::Recording, "test_app"),
+            entity_path: "camera/image".into(),
+            recursive: true,
+            timeline: "frame".into(),
+            range: re_log_types::AbsoluteTimeRange::new(10, 20),
+        })];
+
+        let mut file = vec![];
+        crate::encoder::encode_ref(
+            CrateVersion::LOCAL,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            messages.iter().map(Ok),
+            &mut file,
+        )
+        .unwrap();
+
+        let decoded_messages = Decoder::new(&mut file.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<LogMsg>, DecodeError>>()
+            .unwrap();
+
+        similar_asserts::assert_eq!(decoded_messages, messages);
+    }
+
     /// Test that legacy messages (aka `StoreId` without an application id) are properly decoded.
     #[test]
     fn test_decode_legacy() {
@@ -64,6 +64,11 @@ enum State {
     /// Compression is only applied to individual `ArrowMsg`s, instead of
     /// the entire stream.
     Message(crate::codec::file::MessageHeader),
+
+    /// The rest of the index footer that may follow the end of the stream, which we skip.
+    ///
+    /// See [`crate::codec::file::index`].
+    IndexFooter(u64),
 }
 
 impl StreamDecoder {
@@ -110,6 +115,11 @@ impl StreamDecoder {
         match self.state {
             State::StreamHeader => {
                 if let Some(header) = self.chunks.try_read(FileHeader::SIZE) {
+                    if let Some(footer_len) = crate::codec::file::index::index_footer_len(header) {
+                        self.state = State::IndexFooter(footer_len);
+                        return self.try_read();
+                    }
+
                     // header contains version and compression options
                     let (version, options) = options_from_bytes(header)?;
                     self.version = Some(version);
@@ -156,6 +166,13 @@ impl StreamDecoder {
                     }
                 }
             }
+
+            State::IndexFooter(footer_len) => {
+                if self.chunks.try_read(footer_len as usize).is_some() {
+                    self.state = State::StreamHeader;
+                    return self.try_read();
+                }
+            }
         }
 
         Ok(None)
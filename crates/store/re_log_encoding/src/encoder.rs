@@ -3,10 +3,13 @@
 use crate::FileHeader;
 use crate::Serializer;
 use crate::codec;
-use crate::codec::file::{self, encoder};
+use crate::codec::file::{
+    self, encoder,
+    index::{RrdIndex, RrdIndexEntry},
+};
 use crate::{Compression, EncodingOptions};
 use re_build_info::CrateVersion;
-use re_chunk::{ChunkError, ChunkResult};
+use re_chunk::{ChunkError, ChunkResult, Span};
 use re_log_types::LogMsg;
 use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
 
@@ -93,6 +96,13 @@ impl<W: std::io::Write> DroppableEncoder<W> {
         })
     }
 
+    /// Ends the stream with an index of all its messages, see [`Encoder::with_index_footer`].
+    #[inline]
+    pub fn with_index_footer(mut self) -> Self {
+        self.encoder.index = Some(RrdIndex::default());
+        self
+    }
+
     /// Returns the size in bytes of the encoded data.
     #[inline]
     pub fn append(&mut self, message: &LogMsg) -> Result<u64, EncodeError> {
@@ -141,6 +151,12 @@ pub struct Encoder<W: std::io::Write> {
     compression: Compression,
     write: W,
     scratch: Vec<u8>,
+
+    /// The number of bytes written so far, including the file header.
+    num_bytes_written: u64,
+
+    /// Where each message was written to, if the stream ends with an index footer.
+    index: Option<RrdIndex>,
 }
 
 impl<W: std::io::Write> Encoder<W> {
@@ -161,9 +177,40 @@ impl<W: std::io::Write> Encoder<W> {
             compression: options.compression,
             write,
             scratch: Vec::new(),
+            num_bytes_written: FileHeader::SIZE as u64,
+            index: None,
         })
     }
 
+    /// Ends the stream with an index of all its messages, which lets readers find them without decoding the whole
+    /// stream first, see [`crate::decoder::RrdFileReader`].
+    ///
+    /// Only makes sense for streams that end up in a file of their own, as the index is relative to the start of
+    /// the stream.
+    #[inline]
+    pub fn with_index_footer(mut self) -> Self {
+        self.index = Some(RrdIndex::default());
+        self
+    }
+
+    fn write_scratch(
+        &mut self,
+        index_entry: impl FnOnce(Span<u64>) -> RrdIndexEntry,
+    ) -> Result<u64, EncodeError> {
+        let byte_span = Span {
+            start: self.num_bytes_written,
+            len: self.scratch.len() as u64,
+        };
+        self.write.write_all(&self.scratch)?;
+        self.num_bytes_written += byte_span.len;
+
+        if let Some(index) = &mut self.index {
+            index.entries.push(index_entry(byte_span));
+        }
+
+        Ok(byte_span.len)
+    }
+
     /// Returns the size in bytes of the encoded data.
     pub fn append(&mut self, message: &LogMsg) -> Result<u64, EncodeError> {
         re_tracing::profile_function!();
@@ -172,11 +219,7 @@ impl<W: std::io::Write> Encoder<W> {
         match self.serializer {
             Serializer::Protobuf => {
                 encoder::encode(&mut self.scratch, message, self.compression)?;
-
-                self.write
-                    .write_all(&self.scratch)
-                    .map(|_| self.scratch.len() as _)
-                    .map_err(EncodeError::Write)
+                self.write_scratch(|byte_span| RrdIndexEntry::from_log_msg(byte_span, message))
             }
         }
     }
@@ -188,12 +231,17 @@ impl<W: std::io::Write> Encoder<W> {
         self.scratch.clear();
         match self.serializer {
             Serializer::Protobuf => {
-                encoder::encode_proto(&mut self.scratch, message)?;
+                let msg = message
+                    .msg
+                    .as_ref()
+                    .ok_or(EncodeError::MissingField("msg"))?;
+                let index_entry = RrdIndexEntry::from_proto(Span::default(), msg);
 
-                self.write
-                    .write_all(&self.scratch)
-                    .map(|_| self.scratch.len() as _)
-                    .map_err(EncodeError::Write)
+                encoder::encode_proto(&mut self.scratch, message)?;
+                self.write_scratch(|byte_span| RrdIndexEntry {
+                    byte_span,
+                    ..index_entry
+                })
             }
         }
     }
@@ -209,6 +257,12 @@ impl<W: std::io::Write> Encoder<W> {
                     len: 0,
                 }
                 .encode(&mut self.write)?;
+                self.num_bytes_written += file::MessageHeader::SIZE_BYTES as u64;
+
+                if let Some(index) = self.index.take() {
+                    self.num_bytes_written +=
+                        index.encode(self.num_bytes_written, &mut self.write)?;
+                }
             }
         }
         Ok(())
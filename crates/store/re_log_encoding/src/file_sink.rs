@@ -99,7 +99,8 @@ impl FileSink {
             re_build_info::CrateVersion::LOCAL,
             encoding_options,
             file,
-        )?;
+        )?
+        .with_index_footer();
         let join_handle = spawn_and_stream(Some(&path), encoder, rx)?;
 
         Ok(Self {
@@ -161,6 +162,7 @@ fn spawn_and_stream<W: std::io::Write + Send + 'static>(
     mut encoder: crate::encoder::DroppableEncoder<W>,
     rx: Receiver<Option<Command>>,
 ) -> Result<std::thread::JoinHandle<()>, FileSinkError> {
+    let is_stdout = filepath.is_none();
     let (name, target) = if let Some(filepath) = filepath {
         ("file_writer", filepath.display().to_string())
     } else {
@@ -177,6 +179,15 @@ fn spawn_and_stream<W: std::io::Write + Send + 'static>(
                                 re_log::error!("Failed to write log stream to {target}: {err}");
                                 return;
                             }
+
+                            // Stdout is line-buffered, which makes no sense for a binary stream:
+                            // whatever comes after the last newline byte would sit in the buffer
+                            // until the next message arrives, stalling whoever is reading the other
+                            // end of the pipe (e.g. `my_app | ssh host rerun -`).
+                            if is_stdout && let Err(err) = encoder.flush_blocking() {
+                                re_log::error!("Failed to flush log stream to {target}: {err}");
+                                return;
+                            }
                         }
                         Command::Flush { on_done } => {
                             re_log::trace!("Flushing…");
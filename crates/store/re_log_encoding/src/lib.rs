@@ -14,6 +14,10 @@ pub mod protobuf_conversions;
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
 
+#[cfg(feature = "shared_memory")]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod shared_memory;
+
 #[cfg(feature = "stream_from_http")]
 pub mod stream_rrd_from_http;
 
@@ -137,7 +141,6 @@ pub(crate) struct FileHeader {
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
 impl FileHeader {
-    #[cfg(feature = "decoder")]
     pub const SIZE: usize = 12;
 
     #[cfg(feature = "encoder")]
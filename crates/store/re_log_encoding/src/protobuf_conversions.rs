@@ -1,7 +1,7 @@
 // TODO(#9430): this belongs in re_protos::ext
 
 #[cfg(feature = "decoder")]
-use re_log_types::{BlueprintActivationCommand, SetStoreInfo};
+use re_log_types::{BlueprintActivationCommand, DropTimeRangeCommand, SetStoreInfo};
 
 #[cfg(feature = "decoder")]
 use crate::ApplicationIdInjector;
@@ -102,10 +102,50 @@ pub fn log_msg_from_proto(
             ))
         }
 
+        Some(Msg::DropTimeRangeCommand(drop_time_range_command)) => {
+            Ok(re_log_types::LogMsg::DropTimeRange(
+                drop_time_range_command_from_proto(drop_time_range_command)?,
+            ))
+        }
+
         None => Err(missing_field!(re_protos::log_msg::v1alpha1::LogMsg, "msg").into()),
     }
 }
 
+#[cfg(feature = "decoder")]
+pub fn drop_time_range_command_from_proto(
+    drop_time_range_command: re_protos::log_msg::v1alpha1::DropTimeRangeCommand,
+) -> Result<DropTimeRangeCommand, crate::decoder::DecodeError> {
+    use re_protos::{
+        log_msg::v1alpha1::DropTimeRangeCommand as ProtoDropTimeRangeCommand, missing_field,
+    };
+
+    let ProtoDropTimeRangeCommand {
+        store_id,
+        entity_path,
+        recursive,
+        timeline,
+        time_range,
+    } = drop_time_range_command;
+
+    Ok(DropTimeRangeCommand {
+        // This message is more recent than store ids with an application id, so there is nothing to migrate.
+        store_id: store_id
+            .ok_or_else(|| missing_field!(ProtoDropTimeRangeCommand, "store_id"))?
+            .try_into()?,
+        entity_path: entity_path
+            .ok_or_else(|| missing_field!(ProtoDropTimeRangeCommand, "entity_path"))?
+            .try_into()?,
+        recursive,
+        timeline: timeline
+            .ok_or_else(|| missing_field!(ProtoDropTimeRangeCommand, "timeline"))?
+            .into(),
+        range: time_range
+            .ok_or_else(|| missing_field!(ProtoDropTimeRangeCommand, "time_range"))?
+            .into(),
+    })
+}
+
 #[cfg(feature = "decoder")]
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn arrow_msg_from_proto(
@@ -143,7 +183,7 @@ pub fn log_msg_to_proto(
     re_tracing::profile_function!();
 
     use re_protos::log_msg::v1alpha1::{
-        BlueprintActivationCommand, LogMsg as ProtoLogMsg, SetStoreInfo,
+        BlueprintActivationCommand, DropTimeRangeCommand, LogMsg as ProtoLogMsg, SetStoreInfo,
     };
 
     let proto_msg = match message {
@@ -176,6 +216,17 @@ pub fn log_msg_to_proto(
                 ),
             }
         }
+
+        re_log_types::LogMsg::DropTimeRange(drop_time_range_command) => {
+            let drop_time_range_command: DropTimeRangeCommand = drop_time_range_command.into();
+            ProtoLogMsg {
+                msg: Some(
+                    re_protos::log_msg::v1alpha1::log_msg::Msg::DropTimeRangeCommand(
+                        drop_time_range_command,
+                    ),
+                ),
+            }
+        }
     };
 
     Ok(proto_msg)
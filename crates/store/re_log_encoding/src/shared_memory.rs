@@ -0,0 +1,625 @@
+//! A single-producer/single-consumer ring buffer living in a memory-mapped file.
+//!
+//! This is used as a side-channel next to the gRPC message proxy: when an SDK and
+//! the server live on the same machine, the (potentially huge) payloads of large
+//! Arrow messages are written into shared memory, and only a small reference to them
+//! is sent over the socket.
+//!
+//! The producer ([`SharedMemoryWriter`]) creates the segment and owns the backing file.
+//! The consumer ([`SharedMemoryReader`]) opens it read-only by path, after proving it can see
+//! the same file by echoing back the random token stored in its header.
+//! The consumer in turn creates and owns a small release file, in which it publishes how far
+//! it has read, and which the producer opens read-only.
+//!
+//! Both ends refuse to open anything but files starting with [`SEGMENT_FILE_PREFIX`],
+//! directly inside the shared memory directory of the machine.
+//!
+//! ## Layout
+//!
+//! ```text
+//! segment: [ magic: 8 ][ capacity: u64 ][ head: u64 ][ reserved: 8 ][ token: 16 ][ reserved … ][ data: capacity ]
+//! release: [ magic: 8 ][ tail: u64 ][ token: 16 ][ reserved … ]
+//! ```
+//!
+//! `head` and `tail` are monotonically increasing byte counters. `head` is only ever written
+//! by the producer, `tail` only ever by the consumer. A payload is always stored contiguously;
+//! if it doesn't fit before the end of the data area, the producer skips ahead to the start.
+
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// The file name of every segment and release file starts with this.
+pub const SEGMENT_FILE_PREFIX: &str = "rerun-shm-";
+
+const MAGIC: &[u8; 8] = b"RRSHM001";
+
+const OFFSET_CAPACITY: usize = 8;
+const OFFSET_HEAD: usize = 16;
+const OFFSET_TOKEN: usize = 32;
+
+/// Size of the header, in bytes. Rounded up so that the data area is nicely aligned.
+const HEADER_SIZE: usize = 64;
+
+const RELEASE_MAGIC: &[u8; 8] = b"RRSHMREL";
+
+const RELEASE_OFFSET_TAIL: usize = 8;
+const RELEASE_OFFSET_TOKEN: usize = 16;
+
+/// Size of a release file, in bytes.
+const RELEASE_FILE_SIZE: usize = 64;
+
+/// The default size of the data area of a new segment.
+pub const DEFAULT_SEGMENT_CAPACITY: u64 = 256 * 1024 * 1024;
+
+/// A random token used to make sure both ends are looking at the same segment.
+pub type SharedMemoryToken = [u8; 16];
+
+/// Errors that can occur when creating or opening a shared memory segment.
+#[derive(thiserror::Error, Debug)]
+pub enum SharedMemoryError {
+    #[error("Failed to access shared memory segment {path:?}: {err}")]
+    Io { path: PathBuf, err: std::io::Error },
+
+    #[error("{path:?} is not a Rerun shared memory file in {:?}", segment_dir())]
+    ForeignFile { path: PathBuf },
+
+    #[error("{path:?} is not a Rerun shared memory segment")]
+    BadMagic { path: PathBuf },
+
+    #[error("Token mismatch for shared memory segment {path:?}")]
+    TokenMismatch { path: PathBuf },
+
+    #[error("Invalid capacity for shared memory segment: {capacity}")]
+    InvalidCapacity { capacity: u64 },
+
+    #[error(
+        "Payload reference [{offset}, +{length}) is out of bounds (head: {head}, tail: {tail})"
+    )]
+    OutOfBounds {
+        offset: u64,
+        length: u64,
+        head: u64,
+        tail: u64,
+    },
+}
+
+/// Where a payload lives inside a segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedMemorySlot {
+    /// Monotonic offset of the first byte of the payload.
+    pub offset: u64,
+
+    /// Length of the payload, in bytes.
+    pub length: u64,
+}
+
+/// The directory all segment and release files live in.
+///
+/// On Linux we prefer `/dev/shm` since it is guaranteed to be RAM-backed.
+fn segment_dir() -> PathBuf {
+    let dev_shm = Path::new("/dev/shm");
+    if cfg!(target_os = "linux") && dev_shm.is_dir() {
+        dev_shm.to_owned()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+/// Create a new, empty file of `len` bytes in the [`segment_dir`], and map it.
+fn create_segment_file(
+    kind: &str,
+    len: u64,
+) -> Result<(tempfile::NamedTempFile, memmap2::MmapRaw), SharedMemoryError> {
+    let file = tempfile::Builder::new()
+        .prefix(&format!(
+            "{SEGMENT_FILE_PREFIX}{kind}{}-",
+            std::process::id()
+        ))
+        .tempfile_in(segment_dir())
+        .map_err(|err| SharedMemoryError::Io {
+            path: segment_dir(),
+            err,
+        })?;
+
+    let io_err = |err| SharedMemoryError::Io {
+        path: file.path().to_owned(),
+        err,
+    };
+
+    file.as_file().set_len(len).map_err(io_err)?;
+    let mmap = memmap2::MmapRaw::map_raw(file.as_file()).map_err(io_err)?;
+
+    Ok((file, mmap))
+}
+
+/// Open and map a file created by the other end, read-only.
+///
+/// The other end could be anyone, so we refuse to open anything but a regular file
+/// named like our own ones, directly in the [`segment_dir`].
+/// Returns the mapping and the length of the file.
+fn open_segment_file(path: &Path) -> Result<(memmap2::MmapRaw, u64), SharedMemoryError> {
+    let io_err = |err| SharedMemoryError::Io {
+        path: path.to_owned(),
+        err,
+    };
+
+    // Resolves symlinks and `..`, so that we check where the path actually leads.
+    let path_in_dir = path.canonicalize().map_err(io_err)?;
+    let dir = segment_dir().canonicalize().map_err(io_err)?;
+
+    let is_segment_file = path_in_dir.parent() == Some(dir.as_path())
+        && path_in_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(SEGMENT_FILE_PREFIX));
+
+    // Checked before opening, since merely opening e.g. a FIFO can block.
+    if !is_segment_file || !std::fs::metadata(&path_in_dir).map_err(io_err)?.is_file() {
+        return Err(SharedMemoryError::ForeignFile {
+            path: path.to_owned(),
+        });
+    }
+
+    let file = File::open(&path_in_dir).map_err(io_err)?;
+    let file_len = file.metadata().map_err(io_err)?.len();
+    let mmap = memmap2::MmapOptions::new()
+        .map_raw_read_only(&file)
+        .map_err(io_err)?;
+
+    Ok((mmap, file_len))
+}
+
+/// Atomically access one of the counters of a writable mapping.
+fn header_atomic(mmap: &memmap2::MmapRaw, offset: usize) -> &AtomicU64 {
+    debug_assert!(offset + 8 <= HEADER_SIZE);
+    debug_assert!(offset % 8 == 0);
+
+    // SAFETY:
+    // * The mapping is page-aligned and `offset` is a multiple of 8, so the pointer is aligned.
+    // * The mapping is at least `HEADER_SIZE` bytes long, so the pointer is in bounds.
+    // * The mapping is writable.
+    // * The counters are only ever accessed atomically, from both processes.
+    // * The returned reference cannot outlive the mapping.
+    #[allow(unsafe_code)]
+    unsafe {
+        AtomicU64::from_ptr(mmap.as_mut_ptr().add(offset).cast::<u64>())
+    }
+}
+
+/// Load one of the counters of a read-only mapping, which the other process stores to.
+fn load_read_only_counter(mmap: &memmap2::MmapRaw, offset: usize) -> u64 {
+    debug_assert!(offset + 8 <= HEADER_SIZE);
+    debug_assert!(offset % 8 == 0);
+
+    // SAFETY:
+    // * The mapping is page-aligned and `offset` is a multiple of 8, so the pointer is aligned.
+    // * The mapping is at least `HEADER_SIZE` bytes long, so the pointer is in bounds.
+    // * `AtomicU64` requires writable memory, so we use a volatile load instead,
+    //   which is a single untorn load for an aligned `u64` on all platforms we support.
+    #[allow(unsafe_code)]
+    let value = unsafe { mmap.as_ptr().add(offset).cast::<u64>().read_volatile() };
+
+    // Pairs with the `Release` store of the other process.
+    std::sync::atomic::fence(Ordering::Acquire);
+
+    value
+}
+
+// ----------------------------------------------------------------------------
+
+/// The producing end of a shared memory segment.
+///
+/// The backing file is removed when this is dropped.
+pub struct SharedMemoryWriter {
+    file: tempfile::NamedTempFile,
+    token: SharedMemoryToken,
+    capacity: u64,
+    mmap: memmap2::MmapRaw,
+
+    /// The release file of the consumer, see [`Self::open_release_file`].
+    release_mmap: Option<memmap2::MmapRaw>,
+}
+
+impl SharedMemoryWriter {
+    /// Create a new segment with a data area of `capacity` bytes.
+    pub fn create(capacity: u64) -> Result<Self, SharedMemoryError> {
+        re_tracing::profile_function!();
+
+        if capacity == 0 {
+            return Err(SharedMemoryError::InvalidCapacity { capacity });
+        }
+
+        let (file, mmap) = create_segment_file("", HEADER_SIZE as u64 + capacity)?;
+
+        let token: SharedMemoryToken = re_log_types::external::re_tuid::Tuid::new()
+            .as_u128()
+            .to_le_bytes();
+
+        // SAFETY: nobody else knows about this file yet, so we have exclusive access to the header.
+        #[allow(unsafe_code)]
+        unsafe {
+            let header = std::slice::from_raw_parts_mut(mmap.as_mut_ptr(), HEADER_SIZE);
+            header[..OFFSET_CAPACITY].copy_from_slice(MAGIC);
+            header[OFFSET_CAPACITY..OFFSET_HEAD].copy_from_slice(&capacity.to_le_bytes());
+            header[OFFSET_TOKEN..OFFSET_TOKEN + token.len()].copy_from_slice(&token);
+        }
+        header_atomic(&mmap, OFFSET_HEAD).store(0, Ordering::Release);
+
+        re_log::debug!(
+            "Created shared memory segment {:?} ({capacity} bytes)",
+            file.path()
+        );
+
+        Ok(Self {
+            file,
+            token,
+            capacity,
+            mmap,
+            release_mmap: None,
+        })
+    }
+
+    /// Open the release file the consumer created when it attached to this segment.
+    ///
+    /// Until then, nothing that was written is ever released.
+    pub fn open_release_file(&mut self, path: &Path) -> Result<(), SharedMemoryError> {
+        re_tracing::profile_function!();
+
+        let (mmap, file_len) = open_segment_file(path)?;
+
+        if file_len < RELEASE_FILE_SIZE as u64 {
+            return Err(SharedMemoryError::BadMagic {
+                path: path.to_owned(),
+            });
+        }
+
+        // SAFETY: the magic and token are immutable after creation, and the file is long enough.
+        #[allow(unsafe_code)]
+        let header = unsafe { std::slice::from_raw_parts(mmap.as_ptr(), RELEASE_FILE_SIZE) };
+
+        if &header[..RELEASE_OFFSET_TAIL] != RELEASE_MAGIC {
+            return Err(SharedMemoryError::BadMagic {
+                path: path.to_owned(),
+            });
+        }
+
+        if header[RELEASE_OFFSET_TOKEN..RELEASE_OFFSET_TOKEN + self.token.len()] != self.token {
+            return Err(SharedMemoryError::TokenMismatch {
+                path: path.to_owned(),
+            });
+        }
+
+        self.release_mmap = Some(mmap);
+
+        Ok(())
+    }
+
+    /// The path of the backing file, which the consumer needs to open the segment.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+
+    /// The token the consumer has to present to prove it is looking at this segment.
+    #[inline]
+    pub fn token(&self) -> SharedMemoryToken {
+        self.token
+    }
+
+    /// The size of the data area, in bytes.
+    #[inline]
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// How far the consumer has read.
+    fn tail(&self) -> u64 {
+        self.release_mmap
+            .as_ref()
+            .map_or(0, |mmap| load_read_only_counter(mmap, RELEASE_OFFSET_TAIL))
+    }
+
+    /// Number of bytes that have been written but not yet released by the consumer.
+    pub fn num_bytes_in_flight(&self) -> u64 {
+        let head = header_atomic(&self.mmap, OFFSET_HEAD).load(Ordering::Relaxed);
+        head.saturating_sub(self.tail())
+    }
+
+    /// Copy `payload` into the segment.
+    ///
+    /// Returns `None` if there currently isn't enough free space, in which case
+    /// the caller should fall back to sending the payload inline.
+    pub fn try_write(&mut self, payload: &[u8]) -> Option<SharedMemorySlot> {
+        re_tracing::profile_function!();
+
+        let length = payload.len() as u64;
+        if length == 0 || self.capacity < length {
+            return None;
+        }
+
+        let head = header_atomic(&self.mmap, OFFSET_HEAD).load(Ordering::Relaxed);
+        let tail = self.tail();
+        let free = self.capacity - head.saturating_sub(tail);
+
+        // Payloads are stored contiguously, so we may have to skip the end of the data area.
+        let position = head % self.capacity;
+        let skip = if self.capacity < position + length {
+            self.capacity - position
+        } else {
+            0
+        };
+
+        if free < skip + length {
+            return None;
+        }
+
+        let offset = head + skip;
+        let start = HEADER_SIZE + (offset % self.capacity) as usize;
+
+        // SAFETY:
+        // * `start + payload.len()` is within the mapping, as checked above.
+        // * The consumer has released this region (it is behind `tail`), and won't touch it
+        //   again until we publish it by bumping `head`.
+        #[allow(unsafe_code)]
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                payload.as_ptr(),
+                self.mmap.as_mut_ptr().add(start),
+                payload.len(),
+            );
+        }
+
+        header_atomic(&self.mmap, OFFSET_HEAD).store(offset + length, Ordering::Release);
+
+        Some(SharedMemorySlot { offset, length })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The consuming end of a shared memory segment.
+///
+/// The release file is removed when this is dropped.
+pub struct SharedMemoryReader {
+    path: PathBuf,
+    capacity: u64,
+
+    /// The segment, mapped read-only.
+    mmap: memmap2::MmapRaw,
+
+    release_file: tempfile::NamedTempFile,
+    release_mmap: memmap2::MmapRaw,
+}
+
+impl SharedMemoryReader {
+    /// Open the segment at `path`, checking that it was created with the given `token`.
+    ///
+    /// Also creates the release file the producer has to open, see [`Self::release_path`].
+    pub fn open(path: &Path, token: &[u8]) -> Result<Self, SharedMemoryError> {
+        re_tracing::profile_function!();
+
+        let (mmap, file_len) = open_segment_file(path)?;
+        if file_len < HEADER_SIZE as u64 {
+            return Err(SharedMemoryError::BadMagic {
+                path: path.to_owned(),
+            });
+        }
+
+        // SAFETY: the header is immutable after creation, except for the atomic counters which we don't read here.
+        #[allow(unsafe_code)]
+        let header = unsafe { std::slice::from_raw_parts(mmap.as_ptr(), HEADER_SIZE) };
+
+        if &header[..OFFSET_CAPACITY] != MAGIC {
+            return Err(SharedMemoryError::BadMagic {
+                path: path.to_owned(),
+            });
+        }
+
+        if header.get(OFFSET_TOKEN..OFFSET_TOKEN + std::mem::size_of::<SharedMemoryToken>())
+            != Some(token)
+        {
+            return Err(SharedMemoryError::TokenMismatch {
+                path: path.to_owned(),
+            });
+        }
+
+        let mut capacity_bytes = [0_u8; 8];
+        capacity_bytes.copy_from_slice(&header[OFFSET_CAPACITY..OFFSET_HEAD]);
+        let capacity = u64::from_le_bytes(capacity_bytes);
+        if capacity == 0 || file_len < HEADER_SIZE as u64 + capacity {
+            return Err(SharedMemoryError::InvalidCapacity { capacity });
+        }
+
+        let (release_file, release_mmap) =
+            create_segment_file("release-", RELEASE_FILE_SIZE as u64)?;
+
+        // SAFETY: the producer doesn't know about this file yet, so we have exclusive access to it.
+        #[allow(unsafe_code)]
+        unsafe {
+            let release =
+                std::slice::from_raw_parts_mut(release_mmap.as_mut_ptr(), RELEASE_FILE_SIZE);
+            release[..RELEASE_OFFSET_TAIL].copy_from_slice(RELEASE_MAGIC);
+            release[RELEASE_OFFSET_TOKEN..RELEASE_OFFSET_TOKEN + token.len()]
+                .copy_from_slice(token);
+        }
+        header_atomic(&release_mmap, RELEASE_OFFSET_TAIL).store(0, Ordering::Release);
+
+        Ok(Self {
+            path: path.to_owned(),
+            capacity,
+            mmap,
+            release_file,
+            release_mmap,
+        })
+    }
+
+    /// The path of the backing file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The path of the release file, which the producer needs to know when its payloads were read.
+    #[inline]
+    pub fn release_path(&self) -> &Path {
+        self.release_file.path()
+    }
+
+    /// Copy the payload in `slot` out of the segment, and release it (and everything before it)
+    /// back to the producer.
+    ///
+    /// Slots must be read in the order they were written.
+    pub fn read_and_release(&self, slot: SharedMemorySlot) -> Result<Vec<u8>, SharedMemoryError> {
+        re_tracing::profile_function!();
+
+        let SharedMemorySlot { offset, length } = slot;
+
+        let head = load_read_only_counter(&self.mmap, OFFSET_HEAD);
+        let tail = header_atomic(&self.release_mmap, RELEASE_OFFSET_TAIL).load(Ordering::Relaxed);
+
+        let position = offset % self.capacity;
+        let is_valid = tail <= offset
+            && offset.checked_add(length).is_some_and(|end| end <= head)
+            && position + length <= self.capacity;
+        if !is_valid {
+            return Err(SharedMemoryError::OutOfBounds {
+                offset,
+                length,
+                head,
+                tail,
+            });
+        }
+
+        let start = HEADER_SIZE + position as usize;
+        let mut payload = vec![0_u8; length as usize];
+
+        // SAFETY:
+        // * `start + length` is within the mapping, as checked above.
+        // * The producer published this region by bumping `head` (acquired above),
+        //   and won't touch it again until we release it by bumping `tail`.
+        #[allow(unsafe_code)]
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self.mmap.as_ptr().add(start),
+                payload.as_mut_ptr(),
+                payload.len(),
+            );
+        }
+
+        // Never move `tail` backwards, even if a misbehaving client has us read slots concurrently.
+        header_atomic(&self.release_mmap, RELEASE_OFFSET_TAIL)
+            .fetch_max(offset + length, Ordering::Release);
+
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_and_wraparound() {
+        let mut writer = SharedMemoryWriter::create(100).unwrap();
+        let reader = SharedMemoryReader::open(writer.path(), &writer.token()).unwrap();
+        writer.open_release_file(reader.release_path()).unwrap();
+
+        let a = writer.try_write(&[1; 60]).unwrap();
+        assert_eq!(
+            a,
+            SharedMemorySlot {
+                offset: 0,
+                length: 60
+            }
+        );
+
+        // Doesn't fit until `a` has been released.
+        assert!(writer.try_write(&[2; 50]).is_none());
+        assert_eq!(reader.read_and_release(a).unwrap(), vec![1; 60]);
+        assert_eq!(writer.num_bytes_in_flight(), 0);
+
+        // Skips the last 40 bytes and wraps around to the start.
+        let b = writer.try_write(&[2; 50]).unwrap();
+        assert_eq!(
+            b,
+            SharedMemorySlot {
+                offset: 100,
+                length: 50
+            }
+        );
+        assert_eq!(reader.read_and_release(b).unwrap(), vec![2; 50]);
+
+        // Too big to ever fit.
+        assert!(writer.try_write(&[3; 101]).is_none());
+    }
+
+    #[test]
+    fn rejects_wrong_token() {
+        let writer = SharedMemoryWriter::create(16).unwrap();
+        assert!(matches!(
+            SharedMemoryReader::open(writer.path(), &[0; 16]),
+            Err(SharedMemoryError::TokenMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_foreign_files() {
+        let writer = SharedMemoryWriter::create(16).unwrap();
+
+        // A valid segment, but outside of the shared memory directory.
+        let dir = tempfile::tempdir().unwrap();
+        let outside = dir.path().join(writer.path().file_name().unwrap());
+        std::fs::copy(writer.path(), &outside).unwrap();
+        assert!(matches!(
+            SharedMemoryReader::open(&outside, &writer.token()),
+            Err(SharedMemoryError::ForeignFile { .. })
+        ));
+
+        // In the shared memory directory, but not named like a segment.
+        let unprefixed = tempfile::Builder::new()
+            .prefix("not-rerun-")
+            .tempfile_in(segment_dir())
+            .unwrap();
+        std::fs::copy(writer.path(), unprefixed.path()).unwrap();
+        assert!(matches!(
+            SharedMemoryReader::open(unprefixed.path(), &writer.token()),
+            Err(SharedMemoryError::ForeignFile { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_release_file_of_other_segment() {
+        let mut writer = SharedMemoryWriter::create(16).unwrap();
+        let other_writer = SharedMemoryWriter::create(16).unwrap();
+        let other_reader =
+            SharedMemoryReader::open(other_writer.path(), &other_writer.token()).unwrap();
+
+        assert!(matches!(
+            writer.open_release_file(other_reader.release_path()),
+            Err(SharedMemoryError::TokenMismatch { .. })
+        ));
+
+        // Not a release file at all.
+        assert!(matches!(
+            writer.open_release_file(other_writer.path()),
+            Err(SharedMemoryError::BadMagic { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_unpublished_slot() {
+        let writer = SharedMemoryWriter::create(16).unwrap();
+        let reader = SharedMemoryReader::open(writer.path(), &writer.token()).unwrap();
+        assert!(
+            reader
+                .read_and_release(SharedMemorySlot {
+                    offset: 0,
+                    length: 8
+                })
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::{Duration, TimeCell, Timestamp};
+
+/// The clock a raw timestamp was read from, e.g. by a sensor driver.
+///
+/// Sensors often stamp their data with hardware clocks that don't agree with the wall clock:
+/// GNSS receivers report GPS time, PTP-synchronized devices report TAI,
+/// and many drivers simply count nanoseconds since the device booted.
+///
+/// A [`Clock`] knows how to map raw readings of such a clock onto a Rerun timeline,
+/// so that data stamped by different clocks lines up in the viewer.
+/// Readings from [`Self::Utc`], [`Self::Tai`], [`Self::Gps`] and [`Self::Monotonic`] clocks
+/// with an epoch all end up as [`super::TimeType::TimestampNs`], i.e. UTC nanoseconds since the unix epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Clock {
+    /// Nanoseconds since the unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// This is what [`Timestamp`] uses.
+    #[default]
+    Utc,
+
+    /// International Atomic Time: nanoseconds since 1970-01-01 00:00:00 TAI.
+    ///
+    /// Unlike UTC, TAI does not have leap seconds.
+    /// This is the time scale used by PTP (IEEE 1588).
+    Tai,
+
+    /// GPS time: nanoseconds since the GPS epoch (1980-01-06 00:00:00 UTC).
+    ///
+    /// Like TAI, GPS time does not have leap seconds, and is always 19 seconds behind TAI.
+    Gps,
+
+    /// An arbitrary monotonic clock, e.g. nanoseconds since a device booted.
+    ///
+    /// If the `epoch`, i.e. the point in time at which the clock read zero, is known,
+    /// readings are mapped to timestamps by offsetting them by it.
+    /// Otherwise, readings are logged as durations.
+    Monotonic { epoch: Option<Timestamp> },
+}
+
+impl Clock {
+    /// A monotonic clock that started counting at the given point in time.
+    #[inline]
+    pub fn monotonic_since(epoch: Timestamp) -> Self {
+        Self::Monotonic { epoch: Some(epoch) }
+    }
+
+    /// Maps a raw reading of this clock, in nanoseconds, onto a timeline value.
+    pub fn time_cell(self, nanos: i64) -> TimeCell {
+        match self {
+            Self::Monotonic { epoch: None } => TimeCell::from_duration_nanos(nanos),
+            Self::Utc | Self::Tai | Self::Gps | Self::Monotonic { epoch: Some(_) } => {
+                self.timestamp(nanos).into()
+            }
+        }
+    }
+
+    /// Converts a raw reading of this clock, in nanoseconds, to a UTC [`Timestamp`].
+    ///
+    /// Readings of a [`Self::Monotonic`] clock without epoch are interpreted as nanoseconds since the unix epoch.
+    pub fn timestamp(self, nanos: i64) -> Timestamp {
+        match self {
+            Self::Utc | Self::Monotonic { epoch: None } => Timestamp::from_nanos_since_epoch(nanos),
+            Self::Tai => Timestamp::from_tai_nanos_since_epoch(nanos),
+            Self::Gps => Timestamp::from_gps_nanos_since_gps_epoch(nanos),
+            Self::Monotonic { epoch: Some(epoch) } => epoch + Duration::from_nanos(nanos),
+        }
+    }
+}
+
+impl std::fmt::Display for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Utc => f.write_str("UTC"),
+            Self::Tai => f.write_str("TAI"),
+            Self::Gps => f.write_str("GPS"),
+            Self::Monotonic { epoch: None } => f.write_str("monotonic"),
+            Self::Monotonic { epoch: Some(epoch) } => {
+                write!(f, "monotonic since {}", epoch.format_iso())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clocks_agree() {
+        const SEC: i64 = 1_000_000_000;
+
+        let utc: Timestamp = "2024-03-01T12:00:00Z".parse().unwrap();
+        let utc_nanos = utc.nanos_since_epoch();
+
+        assert_eq!(Clock::Utc.timestamp(utc_nanos), utc);
+        assert_eq!(Clock::Tai.timestamp(utc_nanos + 37 * SEC), utc);
+        assert_eq!(
+            Clock::Gps.timestamp(utc_nanos - 315_964_800 * SEC + 18 * SEC),
+            utc
+        );
+
+        let boot: Timestamp = "2024-03-01T11:00:00Z".parse().unwrap();
+        assert_eq!(Clock::monotonic_since(boot).timestamp(3600 * SEC), utc);
+
+        assert_eq!(
+            Clock::monotonic_since(boot).time_cell(3600 * SEC),
+            TimeCell::from(utc)
+        );
+        assert_eq!(
+            Clock::Monotonic { epoch: None }.time_cell(3600 * SEC),
+            TimeCell::from_duration_nanos(3600 * SEC)
+        );
+    }
+}
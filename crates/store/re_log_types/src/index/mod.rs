@@ -1,6 +1,7 @@
 //! Related to indices, i.e. timelines.
 
 mod absolute_time_range;
+mod clock;
 mod duration;
 mod non_min_i64;
 mod time_cell;
@@ -14,6 +15,7 @@ mod timestamp_format;
 
 pub use self::{
     absolute_time_range::{AbsoluteTimeRange, AbsoluteTimeRangeF},
+    clock::Clock,
     duration::Duration,
     non_min_i64::{NonMinI64, TryFromIntError},
     time_cell::TimeCell,
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, btree_map};
 
-use super::{NonMinI64, TimeCell, TimeInt, Timeline, TimelineName};
+use super::{Clock, NonMinI64, TimeCell, TimeInt, Timeline, TimelineName};
 
 /// A point in time on any number of [`Timeline`]s.
 ///
@@ -72,6 +72,20 @@ impl TimePoint {
         self
     }
 
+    /// Adds a raw reading of the given [`Clock`], converted with [`Clock::time_cell`].
+    ///
+    /// Chain several of these to index the same row on multiple clocks.
+    #[must_use]
+    #[inline]
+    pub fn with_clock_time(
+        self,
+        timeline_name: impl Into<TimelineName>,
+        clock: Clock,
+        nanos: i64,
+    ) -> Self {
+        self.with_index(timeline_name, clock.time_cell(nanos))
+    }
+
     #[must_use]
     #[inline]
     pub fn with(mut self, timeline: Timeline, time: impl TryInto<TimeInt>) -> Self {
@@ -85,6 +85,95 @@ impl TryFrom<web_time::SystemTime> for Timestamp {
     }
 }
 
+// ------------------------------------------
+// Atomic time scales
+
+/// Unix time (in UTC seconds) at which each leap second took effect,
+/// together with the resulting offset `TAI - UTC`, in seconds.
+///
+/// Taken from the IERS `leap-seconds.list`. Needs updating whenever a new leap second is announced.
+const LEAP_SECONDS: [(i64, i64); 28] = [
+    (63_072_000, 10),    // 1972-01-01
+    (78_796_800, 11),    // 1972-07-01
+    (94_694_400, 12),    // 1973-01-01
+    (126_230_400, 13),   // 1974-01-01
+    (157_766_400, 14),   // 1975-01-01
+    (189_302_400, 15),   // 1976-01-01
+    (220_924_800, 16),   // 1977-01-01
+    (252_460_800, 17),   // 1978-01-01
+    (283_996_800, 18),   // 1979-01-01
+    (315_532_800, 19),   // 1980-01-01
+    (362_793_600, 20),   // 1981-07-01
+    (394_329_600, 21),   // 1982-07-01
+    (425_865_600, 22),   // 1983-07-01
+    (489_024_000, 23),   // 1985-07-01
+    (567_993_600, 24),   // 1988-01-01
+    (631_152_000, 25),   // 1990-01-01
+    (662_688_000, 26),   // 1991-01-01
+    (709_948_800, 27),   // 1992-07-01
+    (741_484_800, 28),   // 1993-07-01
+    (773_020_800, 29),   // 1994-07-01
+    (820_454_400, 30),   // 1996-01-01
+    (867_715_200, 31),   // 1997-07-01
+    (915_148_800, 32),   // 1999-01-01
+    (1_136_073_600, 33), // 2006-01-01
+    (1_230_768_000, 34), // 2009-01-01
+    (1_341_100_800, 35), // 2012-07-01
+    (1_435_708_800, 36), // 2015-07-01
+    (1_483_228_800, 37), // 2017-01-01
+];
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// The GPS epoch, 1980-01-06 00:00:00 UTC, in seconds since the unix epoch.
+const GPS_EPOCH_SECS: i64 = 315_964_800;
+
+/// GPS time is always this many seconds behind TAI.
+const TAI_MINUS_GPS_SECS: i64 = 19;
+
+impl Timestamp {
+    /// Converts nanoseconds since 1970-01-01 00:00:00 TAI (as used by e.g. PTP) to a UTC timestamp.
+    ///
+    /// Before 1972, `TAI - UTC` is taken to be 10 seconds.
+    pub fn from_tai_nanos_since_epoch(tai_nanos: i64) -> Self {
+        let tai_secs = tai_nanos.div_euclid(NANOS_PER_SEC);
+        let offset_secs = LEAP_SECONDS
+            .iter()
+            .rev()
+            .find(|(utc_secs, offset_secs)| tai_secs - offset_secs >= *utc_secs)
+            .map_or(LEAP_SECONDS[0].1, |(_, offset_secs)| *offset_secs);
+        Self(tai_nanos.saturating_sub(offset_secs * NANOS_PER_SEC))
+    }
+
+    /// Converts nanoseconds since the GPS epoch (1980-01-06 00:00:00 UTC) to a UTC timestamp.
+    pub fn from_gps_nanos_since_gps_epoch(gps_nanos: i64) -> Self {
+        Self::from_tai_nanos_since_epoch(
+            gps_nanos.saturating_add((GPS_EPOCH_SECS + TAI_MINUS_GPS_SECS) * NANOS_PER_SEC),
+        )
+    }
+
+    /// Nanoseconds since 1970-01-01 00:00:00 TAI.
+    ///
+    /// The inverse of [`Self::from_tai_nanos_since_epoch`].
+    pub fn tai_nanos_since_epoch(self) -> i64 {
+        let utc_secs = self.0.div_euclid(NANOS_PER_SEC);
+        let offset_secs = LEAP_SECONDS
+            .iter()
+            .rev()
+            .find(|(leap_utc_secs, _)| utc_secs >= *leap_utc_secs)
+            .map_or(LEAP_SECONDS[0].1, |(_, offset_secs)| *offset_secs);
+        self.0.saturating_add(offset_secs * NANOS_PER_SEC)
+    }
+
+    /// Nanoseconds since the GPS epoch (1980-01-06 00:00:00 UTC).
+    ///
+    /// The inverse of [`Self::from_gps_nanos_since_gps_epoch`].
+    pub fn gps_nanos_since_gps_epoch(self) -> i64 {
+        self.tai_nanos_since_epoch()
+            .saturating_sub((GPS_EPOCH_SECS + TAI_MINUS_GPS_SECS) * NANOS_PER_SEC)
+    }
+}
+
 // ------------------------------------------
 // `jiff` converters
 
@@ -379,6 +468,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tai_and_gps() {
+        let utc = Timestamp::from_str("2024-03-01T12:00:00.5Z").unwrap();
+        assert_eq!(
+            utc.tai_nanos_since_epoch(),
+            utc.nanos_since_epoch() + 37_000_000_000
+        );
+        assert_eq!(
+            Timestamp::from_tai_nanos_since_epoch(utc.tai_nanos_since_epoch()),
+            utc
+        );
+        assert_eq!(
+            Timestamp::from_gps_nanos_since_gps_epoch(utc.gps_nanos_since_gps_epoch()),
+            utc
+        );
+
+        // The GPS epoch itself.
+        let gps_epoch = Timestamp::from_str("1980-01-06T00:00:00Z").unwrap();
+        assert_eq!(gps_epoch.gps_nanos_since_gps_epoch(), 0);
+        assert_eq!(Timestamp::from_gps_nanos_since_gps_epoch(0), gps_epoch);
+
+        // Right before and after the leap second at the end of 2016.
+        let before = Timestamp::from_str("2016-12-31T23:59:59Z").unwrap();
+        let after = Timestamp::from_str("2017-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            after.tai_nanos_since_epoch() - before.tai_nanos_since_epoch(),
+            2_000_000_000
+        );
+        assert_eq!(
+            Timestamp::from_tai_nanos_since_epoch(before.tai_nanos_since_epoch()),
+            before
+        );
+        assert_eq!(
+            Timestamp::from_tai_nanos_since_epoch(after.tai_nanos_since_epoch()),
+            after
+        );
+    }
+
     #[test]
     fn test_parsing_timestamp() {
         fn parse(s: &str, format: TimestampFormat) -> Option<Timestamp> {
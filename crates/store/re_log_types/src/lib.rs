@@ -41,8 +41,9 @@ pub use self::{
     arrow_msg::{ArrowMsg, ArrowRecordBatchReleaseCallback},
     entry_id::{EntryId, EntryIdOrName},
     index::{
-        AbsoluteTimeRange, AbsoluteTimeRangeF, Duration, NonMinI64, TimeCell, TimeInt, TimePoint,
-        TimeReal, TimeType, Timeline, TimelineName, Timestamp, TimestampFormat, TryFromIntError,
+        AbsoluteTimeRange, AbsoluteTimeRangeF, Clock, Duration, NonMinI64, TimeCell, TimeInt,
+        TimePoint, TimeReal, TimeType, Timeline, TimelineName, Timestamp, TimestampFormat,
+        TryFromIntError,
     },
     instance::Instance,
     path::*,
@@ -466,6 +467,34 @@ impl BlueprintActivationCommand {
     }
 }
 
+/// Drops the data of an entity within a time range, e.g. to excise sensitive or corrupted data from a recording.
+///
+/// Only the data that has already been received is affected: anything logged to that range afterwards is kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTimeRangeCommand {
+    /// The recording this command applies to.
+    pub store_id: StoreId,
+
+    pub entity_path: EntityPath,
+
+    /// Also drop the data of all the descendants of [`Self::entity_path`].
+    pub recursive: bool,
+
+    /// The timeline [`Self::range`] is on.
+    ///
+    /// Matching rows are dropped from all the timelines they appear on.
+    pub timeline: TimelineName,
+
+    /// The (inclusive) range of time to drop.
+    pub range: AbsoluteTimeRange,
+}
+
+/// Arrow schema metadata key set on every [`ArrowMsg`] of a transaction, except for the last one.
+///
+/// Viewers should hold back such messages until the rest of the transaction has arrived,
+/// so that a transaction never shows up partially.
+pub const ARROW_METADATA_KEY_TRANSACTION_CONTINUES: &str = "rerun:transaction_continues";
+
 /// The most general log message sent from the SDK to the server.
 ///
 /// Note: this does not contain tables sent via [`TableMsg`], as these concepts are fundamentally
@@ -492,6 +521,9 @@ pub enum LogMsg {
     /// fully transmitted. Showing a half-transmitted blueprint can cause confusion,
     /// and also lead to problems with view heuristics.
     BlueprintActivationCommand(BlueprintActivationCommand),
+
+    /// Drop previously logged data of an entity within a time range.
+    DropTimeRange(DropTimeRangeCommand),
 }
 
 impl LogMsg {
@@ -500,6 +532,7 @@ impl LogMsg {
             Self::SetStoreInfo(msg) => &msg.info.store_id,
             Self::ArrowMsg(store_id, _) => store_id,
             Self::BlueprintActivationCommand(cmd) => &cmd.blueprint_id,
+            Self::DropTimeRange(cmd) => &cmd.store_id,
         }
     }
 
@@ -514,6 +547,9 @@ impl LogMsg {
             Self::BlueprintActivationCommand(cmd) => {
                 cmd.blueprint_id = new_store_id;
             }
+            Self::DropTimeRange(cmd) => {
+                cmd.store_id = new_store_id;
+            }
         }
     }
 
@@ -526,6 +562,20 @@ impl LogMsg {
         }
     }
 
+    /// Is this message part of a transaction that continues with the next message of this store?
+    ///
+    /// See [`ARROW_METADATA_KEY_TRANSACTION_CONTINUES`].
+    pub fn continues_transaction(&self) -> bool {
+        match self {
+            Self::ArrowMsg(_, arrow_msg) => arrow_msg
+                .batch
+                .schema_ref()
+                .metadata()
+                .contains_key(ARROW_METADATA_KEY_TRANSACTION_CONTINUES),
+            _ => false,
+        }
+    }
+
     pub fn insert_arrow_record_batch_metadata(&mut self, key: String, value: String) {
         if let Some(record_batch) = self.arrow_record_batch_mut() {
             record_batch.schema_metadata_mut().insert(key, value);
@@ -539,6 +589,7 @@ impl_into_enum!(
     LogMsg,
     BlueprintActivationCommand
 );
+impl_into_enum!(DropTimeRangeCommand, LogMsg, DropTimeRange);
 
 // ----------------------------------------------------------------------------
 
@@ -941,6 +992,21 @@ impl SizeBytes for BlueprintActivationCommand {
     }
 }
 
+impl SizeBytes for DropTimeRangeCommand {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        let Self {
+            store_id,
+            entity_path,
+            recursive: _,
+            timeline: _,
+            range: _,
+        } = self;
+
+        store_id.heap_size_bytes() + entity_path.heap_size_bytes()
+    }
+}
+
 impl SizeBytes for ArrowMsg {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
@@ -965,6 +1031,9 @@ impl SizeBytes for LogMsg {
             Self::BlueprintActivationCommand(blueprint_activation_command) => {
                 blueprint_activation_command.heap_size_bytes()
             }
+            Self::DropTimeRange(drop_time_range_command) => {
+                drop_time_range_command.heap_size_bytes()
+            }
         }
     }
 }
@@ -0,0 +1,338 @@
+use std::cmp::Ordering;
+
+use arrow::{
+    array::{Array, AsArray as _},
+    datatypes::{DataType, Float64Type},
+};
+
+use re_types_core::ComponentDescriptor;
+
+/// Error returned by [`ComponentPredicate::parse`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ComponentPredicateError {
+    #[error("Expected comparisons like `confidence > 0.8 and label == \"car\"`, got {0:?}")]
+    InvalidSyntax(String),
+
+    #[error("Unknown comparison operator {0:?}, expected one of ==, !=, <, <=, > or >=")]
+    UnknownOperator(String),
+
+    #[error("Unterminated string in {0:?}")]
+    UnterminatedString(String),
+}
+
+/// How a [`ComponentComparison`] compares component values to its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl ComparisonOperator {
+    fn parse(operator: &str) -> Result<Self, ComponentPredicateError> {
+        match operator {
+            "==" | "=" => Ok(Self::Equal),
+            "!=" => Ok(Self::NotEqual),
+            "<" => Ok(Self::Less),
+            "<=" => Ok(Self::LessOrEqual),
+            ">" => Ok(Self::Greater),
+            ">=" => Ok(Self::GreaterOrEqual),
+            _ => Err(ComponentPredicateError::UnknownOperator(
+                operator.to_owned(),
+            )),
+        }
+    }
+
+    /// Whether a component value ordered like this relative to the compared value passes.
+    ///
+    /// Values that can't be ordered, like NaN, never pass.
+    fn accepts(self, ordering: Option<Ordering>) -> bool {
+        let Some(ordering) = ordering else {
+            return false;
+        };
+        match self {
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+            Self::Less => ordering.is_lt(),
+            Self::LessOrEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+}
+
+/// The value component values are compared to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PredicateValue {
+    Number(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl PredicateValue {
+    fn from_word(word: &str) -> Self {
+        if let Ok(value) = word.parse::<bool>() {
+            Self::Bool(value)
+        } else if let Ok(value) = word.parse::<f64>() {
+            Self::Number(value)
+        } else {
+            Self::String(word.to_owned())
+        }
+    }
+}
+
+/// Compares the values of a component to a value, e.g. `confidence > 0.8`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentComparison {
+    /// The compared component, see [`Self::refers_to`].
+    pub component: String,
+    pub operator: ComparisonOperator,
+    pub value: PredicateValue,
+}
+
+impl ComponentComparison {
+    /// Whether this compares the given component.
+    ///
+    /// Components are referred to by their identifier (`Points3D:radii`), their field name (`radii`),
+    /// or their type (`Radius` or `rerun.components.Radius`).
+    pub fn refers_to(&self, descriptor: &ComponentDescriptor) -> bool {
+        let component = descriptor.component.as_str();
+        component == self.component
+            || component
+                .rsplit_once(':')
+                .is_some_and(|(_, field)| field == self.component)
+            || descriptor.component_type.is_some_and(|component_type| {
+                component_type.as_str() == self.component
+                    || component_type.short_name() == self.component
+            })
+    }
+
+    /// Whether any of the values of a component passes the comparison.
+    ///
+    /// Numbers are compared to numeric values, booleans to boolean values and strings to text.
+    /// Values of any other type never pass.
+    pub fn matches_any(&self, values: &dyn Array) -> bool {
+        match &self.value {
+            PredicateValue::Number(number) => {
+                let Ok(values) = arrow::compute::cast(values, &DataType::Float64) else {
+                    return false;
+                };
+                values
+                    .as_primitive::<Float64Type>()
+                    .iter()
+                    .flatten()
+                    .any(|value| self.operator.accepts(value.partial_cmp(number)))
+            }
+            PredicateValue::Bool(expected) => {
+                let Ok(values) = arrow::compute::cast(values, &DataType::Boolean) else {
+                    return false;
+                };
+                values
+                    .as_boolean()
+                    .iter()
+                    .flatten()
+                    .any(|value| self.operator.accepts(Some(value.cmp(expected))))
+            }
+            PredicateValue::String(expected) => {
+                if !matches!(values.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+                    return false;
+                }
+                let Ok(values) = arrow::compute::cast(values, &DataType::Utf8) else {
+                    return false;
+                };
+                values
+                    .as_string::<i32>()
+                    .iter()
+                    .flatten()
+                    .any(|value| self.operator.accepts(Some(value.cmp(expected.as_str()))))
+            }
+        }
+    }
+}
+
+/// Restricts an [`crate::EntityPathRule`] to entities whose component values pass all comparisons.
+///
+/// Follows the path of a rule after `where`, with comparisons joined by `and`:
+///
+/// ```diff
+/// + /detections/** where confidence > 0.8 and label != "background"
+/// ```
+///
+/// A comparison passes if any value of the component passes, and fails if the entity has no such component.
+/// Predicates are evaluated at the time the view is showing.
+///
+/// The default predicate has no comparisons and never passes.
+/// This is what invalid predicates resolve to when parsing forgivingly, so that they don't affect the view.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComponentPredicate {
+    pub comparisons: Vec<ComponentComparison>,
+}
+
+enum Token<'a> {
+    Word(&'a str),
+    Quoted(&'a str),
+    Operator(&'a str),
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, ComponentPredicateError> {
+    const OPERATOR_CHARS: [char; 4] = ['<', '>', '=', '!'];
+
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '"' || c == '\'' {
+            let Some(end) = rest[1..].find(c) else {
+                return Err(ComponentPredicateError::UnterminatedString(
+                    expression.to_owned(),
+                ));
+            };
+            tokens.push(Token::Quoted(&rest[1..1 + end]));
+            end + 2
+        } else if OPERATOR_CHARS.contains(&c) {
+            let len = rest
+                .find(|c: char| !OPERATOR_CHARS.contains(&c))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Operator(&rest[..len]));
+            len
+        } else {
+            let len = rest
+                .find(|c: char| {
+                    c.is_whitespace() || OPERATOR_CHARS.contains(&c) || c == '"' || c == '\''
+                })
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(&rest[..len]));
+            len
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+impl ComponentPredicate {
+    /// Parses comparisons joined by `and`, like `confidence > 0.8 and label == "car"`.
+    ///
+    /// Strings are quoted with `"` or `'`. Unquoted values are booleans or numbers if they parse as such,
+    /// and strings otherwise.
+    pub fn parse(expression: &str) -> Result<Self, ComponentPredicateError> {
+        let invalid = || ComponentPredicateError::InvalidSyntax(expression.to_owned());
+
+        let mut tokens = tokenize(expression)?.into_iter();
+        let mut comparisons = Vec::new();
+        loop {
+            let (Some(Token::Word(component)), Some(Token::Operator(operator)), Some(value)) =
+                (tokens.next(), tokens.next(), tokens.next())
+            else {
+                return Err(invalid());
+            };
+            let value = match value {
+                Token::Word(word) => PredicateValue::from_word(word),
+                Token::Quoted(string) => PredicateValue::String(string.to_owned()),
+                Token::Operator(_) => return Err(invalid()),
+            };
+            comparisons.push(ComponentComparison {
+                component: component.to_owned(),
+                operator: ComparisonOperator::parse(operator)?,
+                value,
+            });
+
+            match tokens.next() {
+                None => return Ok(Self { comparisons }),
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("and") => {}
+                Some(_) => return Err(invalid()),
+            }
+        }
+    }
+
+    /// Whether the components of an entity pass all comparisons.
+    ///
+    /// `values_of` returns the values of the first component of the entity the comparison [refers to](ComponentComparison::refers_to),
+    /// or `None` if there is none.
+    pub fn matches(
+        &self,
+        mut values_of: impl FnMut(&ComponentComparison) -> Option<arrow::array::ArrayRef>,
+    ) -> bool {
+        !self.comparisons.is_empty()
+            && self.comparisons.iter().all(|comparison| {
+                values_of(comparison).is_some_and(|values| comparison.matches_any(&values))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, BooleanArray, Float32Array, StringArray};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_component_predicate() {
+        assert_eq!(
+            ComponentPredicate::parse("confidence>0.8 and label != 'a b' AND visible == true"),
+            Ok(ComponentPredicate {
+                comparisons: vec![
+                    ComponentComparison {
+                        component: "confidence".to_owned(),
+                        operator: ComparisonOperator::Greater,
+                        value: PredicateValue::Number(0.8),
+                    },
+                    ComponentComparison {
+                        component: "label".to_owned(),
+                        operator: ComparisonOperator::NotEqual,
+                        value: PredicateValue::String("a b".to_owned()),
+                    },
+                    ComponentComparison {
+                        component: "visible".to_owned(),
+                        operator: ComparisonOperator::Equal,
+                        value: PredicateValue::Bool(true),
+                    },
+                ]
+            })
+        );
+
+        assert!(ComponentPredicate::parse("Points3D:radii <= -1").is_ok());
+        assert!(ComponentPredicate::parse("").is_err());
+        assert!(ComponentPredicate::parse("confidence >").is_err());
+        assert!(ComponentPredicate::parse("confidence > 0.8 or x < 1").is_err());
+        assert_eq!(
+            ComponentPredicate::parse("confidence <> 0.8"),
+            Err(ComponentPredicateError::UnknownOperator("<>".to_owned()))
+        );
+        assert!(matches!(
+            ComponentPredicate::parse("label == \"car"),
+            Err(ComponentPredicateError::UnterminatedString(_))
+        ));
+    }
+
+    #[test]
+    fn test_component_predicate_matches() {
+        let confidences: ArrayRef = Arc::new(Float32Array::from(vec![0.2, 0.9]));
+        let labels: ArrayRef = Arc::new(StringArray::from(vec!["car"]));
+        let flags: ArrayRef = Arc::new(BooleanArray::from(vec![false]));
+
+        let values_of = |comparison: &ComponentComparison| match comparison.component.as_str() {
+            "confidence" => Some(confidences.clone()),
+            "label" => Some(labels.clone()),
+            "flag" => Some(flags.clone()),
+            _ => None,
+        };
+        let matches = |predicate: &str| {
+            ComponentPredicate::parse(predicate)
+                .unwrap()
+                .matches(values_of)
+        };
+
+        assert!(matches("confidence > 0.8"));
+        assert!(!matches("confidence > 0.95"));
+        assert!(matches("confidence > 0.8 and label == car"));
+        assert!(!matches("confidence > 0.8 and label != car"));
+        assert!(matches("flag == false"));
+        assert!(!matches("label > 0"));
+        assert!(!matches("missing == 1"));
+        assert!(!ComponentPredicate::default().matches(values_of));
+    }
+}
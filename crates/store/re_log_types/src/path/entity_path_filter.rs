@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use ahash::HashMap;
 use itertools::Itertools as _;
 
-use crate::EntityPath;
+use crate::{ComponentPredicate, ComponentPredicateError, EntityPath};
 
 /// Error returned by [`EntityPathFilter::resolve_strict`] and [`EntityPathFilter::parse_strict`].
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
@@ -13,6 +13,9 @@ pub enum EntityPathFilterError {
 
     #[error("Unresolved substitution: {0}")]
     UnresolvedSubstitution(String),
+
+    #[error("Invalid component predicate: {0}")]
+    InvalidPredicate(#[from] ComponentPredicateError),
 }
 
 /// A set of substitutions for entity paths.
@@ -52,6 +55,14 @@ impl EntityPathSubs {
 /// (`/world/**` matches both `/world` and `/world/car/driver`).
 /// Other uses of `*` are not (yet) supported.
 ///
+/// A rule can be restricted to entities with certain component values, see [`ComponentPredicate`]:
+///
+/// ```diff
+/// + /detections/** where confidence > 0.8
+/// ```
+///
+/// Where a rule with a predicate doesn't pass, the next most specific rule applies.
+///
 /// Since variable substitution (and thus path parsing) hasn't been performed yet,
 /// the rules can not be sorted yet from general to specific, instead they are stored
 /// in alphabetical order.
@@ -171,6 +182,9 @@ pub struct ResolvedEntityPathRule {
 
     /// The resolved path, with all variables Resolved.
     pub resolved_path: EntityPath,
+
+    /// Restricts the rule to entities with certain component values, if set.
+    pub predicate: Option<ComponentPredicate>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -207,17 +221,21 @@ impl std::iter::Sum for EntityPathFilter {
 ///  - Otherwise always split on `\n` (even if it follows a `+` or `-` character).
 ///  - Only consider `+` and `-` characters as special if they are the first character of a token.
 ///  - Split on whitespace does not following a relevant `+` or `-` character.
+///  - A `where` token and the rest of its line are joined to the token before it, as its component predicate.
 fn split_whitespace_smart(path: &'_ str) -> Vec<&'_ str> {
     #![allow(clippy::unwrap_used)]
 
     // We parse on bytes, and take care to only split on either side of a one-byte ASCII,
     // making the `from_utf8(…)`s below safe to unwrap.
-    let mut bytes = path.as_bytes();
+    let all_bytes = path.as_bytes();
+    let mut bytes = all_bytes;
 
-    let mut tokens = vec![];
+    // Byte ranges of the tokens in `path`.
+    let mut tokens: Vec<std::ops::Range<usize>> = vec![];
 
     // Start by ignoring any leading whitespace
     while !bytes.is_empty() {
+        let offset = all_bytes.len() - bytes.len();
         let mut i = 0;
         let mut is_in_escape = false;
         let mut is_include_exclude = false;
@@ -245,7 +263,18 @@ fn split_whitespace_smart(path: &'_ str) -> Vec<&'_ str> {
             i += 1;
         }
         if i > 0 {
-            tokens.push(&bytes[..i]);
+            if &bytes[..i] == b"where"
+                && let Some(previous) = tokens.last_mut()
+            {
+                // A predicate runs until the end of the line, and belongs to the rule before it.
+                i = bytes
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .unwrap_or(bytes.len());
+                previous.end = offset + i;
+            } else {
+                tokens.push(offset..offset + i);
+            }
         }
 
         // Continue skipping whitespace characters
@@ -262,8 +291,8 @@ fn split_whitespace_smart(path: &'_ str) -> Vec<&'_ str> {
 
     // Safety: we split at proper character boundaries
     tokens
-        .iter()
-        .map(|token| std::str::from_utf8(token).unwrap())
+        .into_iter()
+        .map(|range| std::str::from_utf8(&all_bytes[range]).unwrap())
         .collect()
 }
 
@@ -583,13 +612,31 @@ impl ResolvedEntityPathFilter {
 
     /// Find the most specific matching rule and return its effect.
     /// If no rule matches, return `None`.
+    ///
+    /// Rules with a [`ComponentPredicate`] are assumed to pass, see [`Self::most_specific_match_with`].
     pub fn most_specific_match(&self, path: &EntityPath) -> Option<RuleEffect> {
+        self.most_specific_match_with(path, |_| true)
+    }
+
+    /// Find the most specific matching rule whose [`ComponentPredicate`] passes for the entity,
+    /// and return its effect.
+    /// If no rule matches, return `None`.
+    pub fn most_specific_match_with(
+        &self,
+        path: &EntityPath,
+        mut predicate_passes: impl FnMut(&ComponentPredicate) -> bool,
+    ) -> Option<RuleEffect> {
         // We sort the rule by entity path, with recursive coming before non-recursive.
         // This means the last matching rule is also the most specific one.
         // We can definitely optimize this at some point, especially when matching
         // again an `EntityTree` where we could potentially cut out whole subtrees.
         for (rule, effect) in self.rules.iter().rev() {
-            if rule.matches(path) {
+            if rule.matches(path)
+                && rule
+                    .predicate
+                    .as_ref()
+                    .is_none_or(|predicate| predicate_passes(predicate))
+            {
                 return Some(*effect);
             }
         }
@@ -597,9 +644,20 @@ impl ResolvedEntityPathFilter {
     }
 
     /// Does this filter include the given entity path?
+    ///
+    /// Rules with a [`ComponentPredicate`] are assumed to pass, see [`Self::matches_with`].
     pub fn matches(&self, path: &EntityPath) -> bool {
+        self.matches_with(path, |_| true)
+    }
+
+    /// Does this filter include the given entity path, given which [`ComponentPredicate`]s pass for it?
+    pub fn matches_with(
+        &self,
+        path: &EntityPath,
+        predicate_passes: impl FnMut(&ComponentPredicate) -> bool,
+    ) -> bool {
         let effect = self
-            .most_specific_match(path)
+            .most_specific_match_with(path, predicate_passes)
             .unwrap_or(RuleEffect::Exclude);
         match effect {
             RuleEffect::Include => true,
@@ -630,10 +688,7 @@ impl ResolvedEntityPathFilter {
     /// that not only do we add a subtree exclusion, but clear out any existing
     /// inclusions or (now redundant) exclusions that would match the subtree.
     pub fn remove_subtree_and_matching_rules(&mut self, entity_path: EntityPath) {
-        let new_exclusion = ResolvedEntityPathRule {
-            rule: EntityPathRule::including_entity_subtree(&entity_path),
-            resolved_path: entity_path,
-        };
+        let new_exclusion = ResolvedEntityPathRule::including_subtree(&entity_path);
 
         // Remove any rule that is a subtree of the new exclusion.
         self.rules
@@ -692,7 +747,7 @@ impl ResolvedEntityPathFilter {
                         return true; // the entity (with or without subtree) is explicitly included
                     }
                     RuleEffect::Exclude => {
-                        if rule.rule.include_subtree() {
+                        if rule.rule.include_subtree() && rule.predicate.is_none() {
                             // the subtree is explicitly excluded,
                             // and we've already checked that nothing in the subtree was included.
                             return false;
@@ -740,6 +795,14 @@ impl ResolvedEntityPathFilter {
                                 {
                                     return false;
                                 }
+
+                                // A predicate may leave out entities the other rule includes.
+                                if self_rule.predicate.is_some()
+                                    && self_rule.rule.predicate_expression()
+                                        != other_rule.rule.predicate_expression()
+                                {
+                                    return false;
+                                }
                             }
                             RuleEffect::Exclude => return false,
                         }
@@ -793,7 +856,17 @@ impl EntityPathRule {
     /// Whether this rule includes a subtree.
     #[inline]
     pub fn include_subtree(&self) -> bool {
-        self.0.ends_with("/**")
+        self.path_expression().ends_with("/**")
+    }
+
+    /// The path or variable expression of the rule, without its component predicate.
+    pub fn path_expression(&self) -> &str {
+        split_predicate(&self.0).0
+    }
+
+    /// The [`ComponentPredicate`] following `where`, if any.
+    pub fn predicate_expression(&self) -> Option<&str> {
+        split_predicate(&self.0).1
     }
 
     /// Match this path or variable expression, but not children.
@@ -821,8 +894,34 @@ impl EntityPathRule {
     }
 }
 
+/// Splits a rule at a whitespace-delimited `where`, into its path and component predicate.
+fn split_predicate(expression: &str) -> (&str, Option<&str>) {
+    const WHERE: &str = "where";
+
+    let mut search_start = 0;
+    while let Some(position) = expression[search_start..].find(WHERE) {
+        let start = search_start + position;
+        let end = start + WHERE.len();
+        if expression[..start].ends_with(|c: char| c.is_ascii_whitespace())
+            && expression[end..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_ascii_whitespace())
+        {
+            return (
+                expression[..start].trim_end(),
+                Some(expression[end..].trim()),
+            );
+        }
+        search_start = end;
+    }
+    (expression, None)
+}
+
 impl ResolvedEntityPathRule {
     /// Whether this rule matches the given path.
+    ///
+    /// The [`ComponentPredicate`] of the rule, if any, is not evaluated.
     #[inline]
     pub fn matches(&self, path: &EntityPath) -> bool {
         if self.rule.include_subtree() {
@@ -838,6 +937,7 @@ impl ResolvedEntityPathRule {
         Self {
             rule: EntityPathRule::exact_entity(path),
             resolved_path: path.clone(),
+            predicate: None,
         }
     }
 
@@ -847,6 +947,7 @@ impl ResolvedEntityPathRule {
         Self {
             rule: EntityPathRule::including_entity_subtree(entity_path),
             resolved_path: entity_path.clone(),
+            predicate: None,
         }
     }
 
@@ -854,7 +955,7 @@ impl ResolvedEntityPathRule {
         // TODO(#5528): This is a very naive implementation of variable substitution.
         // unclear if we want to do this here, push this down into `EntityPath::parse`,
         // or even supported deferred evaluation on the `EntityPath` itself.
-        let mut expression_sub = rule.0.clone();
+        let mut expression_sub = rule.path_expression().to_owned();
         for (key, value) in &subst_env.0 {
             expression_sub = expression_sub.replace(format!("${key}").as_str(), value);
             expression_sub = expression_sub.replace(format!("${{{key}}}").as_str(), value);
@@ -878,20 +979,28 @@ impl ResolvedEntityPathRule {
             ));
         }
 
+        let predicate = rule
+            .predicate_expression()
+            .map(ComponentPredicate::parse)
+            .transpose()?;
+
         if expression_sub == "/**" {
             Ok(Self {
                 rule,
                 resolved_path: EntityPath::root(),
+                predicate,
             })
         } else if let Some(path) = expression_sub.strip_suffix("/**") {
             Ok(Self {
                 rule,
                 resolved_path: EntityPath::parse_strict(path)?,
+                predicate,
             })
         } else {
             Ok(Self {
                 rule,
                 resolved_path: EntityPath::parse_strict(&expression_sub)?,
+                predicate,
             })
         }
     }
@@ -900,20 +1009,28 @@ impl ResolvedEntityPathRule {
         let rule = EntityPathRule::new(expression);
         let expression_sub = Self::substitute_variables(&rule, subst_env);
 
+        // Invalid predicates never pass, so that the rule doesn't apply while it is being typed.
+        let predicate = rule
+            .predicate_expression()
+            .map(|predicate| ComponentPredicate::parse(predicate).unwrap_or_default());
+
         if expression_sub == "/**" {
             Self {
                 rule,
                 resolved_path: EntityPath::root(),
+                predicate,
             }
         } else if let Some(path) = expression_sub.strip_suffix("/**") {
             Self {
                 rule,
                 resolved_path: EntityPath::parse_forgiving(path),
+                predicate,
             }
         } else {
             Self {
                 rule,
                 resolved_path: EntityPath::parse_forgiving(&expression_sub),
+                predicate,
             }
         }
     }
@@ -925,13 +1042,18 @@ impl std::fmt::Display for ResolvedEntityPathRule {
         let Self {
             rule,
             resolved_path: path,
+            predicate: _,
         } = self;
 
         f.write_fmt(format_args!(
             "{path}{}{}",
             if path.is_root() { "" } else { "/" },
             if rule.include_subtree() { "**" } else { "" }
-        ))
+        ))?;
+        if let Some(predicate) = rule.predicate_expression() {
+            f.write_fmt(format_args!(" where {predicate}"))?;
+        }
+        Ok(())
     }
 }
 
@@ -940,18 +1062,27 @@ impl PartialEq for ResolvedEntityPathRule {
         // Careful! This has to check the same fields as `Ord`/`Hash`!
         self.rule.include_subtree() == other.rule.include_subtree()
             && self.resolved_path == other.resolved_path
+            && self.rule.predicate_expression() == other.rule.predicate_expression()
     }
 }
 
 impl Eq for ResolvedEntityPathRule {}
 
 impl std::cmp::Ord for ResolvedEntityPathRule {
-    /// Most specific last, which means recursive first.
+    /// Most specific last, which means recursive first, and rules with predicates after those without.
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         // Careful! This has to check the same fields as `PartialEq`/`Hash`!
-        (&self.resolved_path, !self.rule.include_subtree())
-            .cmp(&(&other.resolved_path, !other.rule.include_subtree()))
+        (
+            &self.resolved_path,
+            !self.rule.include_subtree(),
+            self.rule.predicate_expression(),
+        )
+            .cmp(&(
+                &other.resolved_path,
+                !other.rule.include_subtree(),
+                other.rule.predicate_expression(),
+            ))
     }
 }
 
@@ -964,14 +1095,19 @@ impl std::cmp::PartialOrd for ResolvedEntityPathRule {
 
 impl std::hash::Hash for ResolvedEntityPathRule {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        (self.rule.include_subtree(), self.resolved_path.hash()).hash(state);
+        (
+            self.rule.include_subtree(),
+            self.resolved_path.hash(),
+            self.rule.predicate_expression(),
+        )
+            .hash(state);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        EntityPath, EntityPathFilter, EntityPathSubs, RuleEffect,
+        EntityPath, EntityPathFilter, EntityPathFilterError, EntityPathSubs, RuleEffect,
         path::entity_path_filter::{ResolvedEntityPathRule, split_whitespace_smart},
     };
 
@@ -1261,6 +1397,72 @@ mod tests {
             split_whitespace_smart(r"+ world/** - /world/points"),
             vec!["+ world/**", "- /world/points"]
         );
+        assert_eq!(
+            split_whitespace_smart("+ /a/** where x > -1 and y == 'b c'\n- /a/b"),
+            vec!["+ /a/** where x > -1 and y == 'b c'", "- /a/b"]
+        );
+    }
+
+    #[test]
+    fn test_entity_path_filter_predicates() {
+        let filter = EntityPathFilter::parse_forgiving(
+            r#"
+        + /detections/** where confidence > 0.8
+        - /detections/ignored
+        - /world/** where hidden == true
+        + /world/**
+        "#,
+        )
+        .resolve_forgiving(&EntityPathSubs::empty());
+
+        assert_eq!(
+            filter.formatted_without_properties(),
+            "+ /detections/** where confidence > 0.8\n- /detections/ignored\n+ /world/**\n- /world/** where hidden == true"
+        );
+
+        for (path, passes, expected) in [
+            ("/detections/car", true, true),
+            ("/detections/car", false, false),
+            ("/detections/ignored", true, false),
+            ("/world/house", true, false),
+            ("/world/house", false, true),
+        ] {
+            assert_eq!(
+                filter.matches_with(&EntityPath::from(path), |_| passes),
+                expected,
+                "path: {path:?}, predicates pass: {passes}",
+            );
+        }
+
+        // The subtree may still contain entities the predicate doesn't exclude.
+        assert!(filter.is_anything_in_subtree_included(&EntityPath::from("/world/house")));
+
+        // A predicate may leave out entities that the same rule without it includes.
+        let without_predicate = EntityPathFilter::parse_forgiving("+ /detections/**")
+            .resolve_forgiving(&EntityPathSubs::empty());
+        assert!(!filter.is_superset_of(&without_predicate));
+        assert!(
+            without_predicate.is_superset_of(
+                &EntityPathFilter::parse_forgiving("+ /detections/** where confidence > 0.8")
+                    .resolve_forgiving(&EntityPathSubs::empty())
+            )
+        );
+
+        // Invalid predicates are errors when parsing strictly, and never pass otherwise.
+        assert!(matches!(
+            EntityPathFilter::parse_forgiving("+ /a/** where confidence >")
+                .resolve_strict(&EntityPathSubs::empty()),
+            Err(EntityPathFilterError::InvalidPredicate(_))
+        ));
+        let invalid = EntityPathFilter::parse_forgiving("+ /a/** where confidence >")
+            .resolve_forgiving(&EntityPathSubs::empty());
+        let (rule, _) = invalid.rules().last().unwrap();
+        assert_eq!(rule.resolved_path, EntityPath::from("/a"));
+        assert!(
+            rule.predicate
+                .as_ref()
+                .is_some_and(|p| p.comparisons.is_empty())
+        );
     }
 
     #[test]
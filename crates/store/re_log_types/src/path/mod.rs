@@ -4,6 +4,7 @@
 //! which are just non-empty strings.
 
 mod component_path;
+mod component_predicate;
 mod data_path;
 mod entity_path;
 mod entity_path_filter;
@@ -12,6 +13,10 @@ pub mod natural_ordering;
 mod parse_path;
 
 pub use component_path::ComponentPath;
+pub use component_predicate::{
+    ComparisonOperator, ComponentComparison, ComponentPredicate, ComponentPredicateError,
+    PredicateValue,
+};
 pub use data_path::DataPath;
 pub use entity_path::{EntityPath, EntityPathHash};
 pub use entity_path_filter::{
@@ -12,6 +12,13 @@ pub const RERUN_HTTP_HEADER_ENTRY_ID: &str = "x-rerun-entry-id";
 /// while HTTP2 headers only support ASCII.
 pub const RERUN_HTTP_HEADER_ENTRY_NAME: &str = "x-rerun-entry-name-bin";
 
+/// The HTTP header key through which clients of the message proxy name themselves,
+/// e.g. to tell apart several robots streaming to the same viewer.
+///
+/// This is serialized as base64-encoded data (hence `-bin`), since client names can be any UTF8 strings,
+/// while HTTP2 headers only support ASCII.
+pub const RERUN_HTTP_HEADER_CLIENT_NAME: &str = "x-rerun-client-name-bin";
+
 /// Extension trait for [`tonic::Request`] to inject Rerun Data Protocol headers into gRPC requests.
 ///
 /// Example:
@@ -24,6 +31,8 @@ pub trait RerunHeadersInjectorExt: Sized {
 
     fn with_entry_name(self, entry_name: impl AsRef<str>) -> Result<Self, tonic::Status>;
 
+    fn with_client_name(self, client_name: impl AsRef<str>) -> Self;
+
     fn with_metadata(self, md: &tonic::metadata::MetadataMap) -> Self;
 }
 
@@ -54,6 +63,17 @@ impl<T> RerunHeadersInjectorExt for tonic::Request<T> {
         Ok(self)
     }
 
+    fn with_client_name(mut self, client_name: impl AsRef<str>) -> Self {
+        const HEADER: &str = RERUN_HTTP_HEADER_CLIENT_NAME;
+
+        let client_name = client_name.as_ref();
+        let client_name = tonic::metadata::BinaryMetadataValue::from_bytes(client_name.as_bytes());
+
+        self.metadata_mut().insert_bin(HEADER, client_name);
+
+        self
+    }
+
     fn with_metadata(mut self, md: &tonic::metadata::MetadataMap) -> Self {
         if let Some(entry_id) = md.get(RERUN_HTTP_HEADER_ENTRY_ID).cloned() {
             self.metadata_mut()
@@ -81,6 +101,8 @@ pub trait RerunHeadersExtractorExt {
     fn entry_id(&self) -> Result<Option<re_log_types::EntryId>, tonic::Status>;
 
     fn entry_name(&self) -> Result<Option<String>, tonic::Status>;
+
+    fn client_name(&self) -> Result<Option<String>, tonic::Status>;
 }
 
 impl<T> RerunHeadersExtractorExt for tonic::Request<T> {
@@ -125,4 +147,25 @@ impl<T> RerunHeadersExtractorExt for tonic::Request<T> {
 
         Ok(Some(entry_name))
     }
+
+    fn client_name(&self) -> Result<Option<String>, tonic::Status> {
+        const HEADER: &str = RERUN_HTTP_HEADER_CLIENT_NAME;
+
+        let Some(client_name) = self.metadata().get_bin(HEADER) else {
+            return Ok(None);
+        };
+
+        let client_name = client_name.to_bytes().map_err(|err| {
+            tonic::Status::invalid_argument(format!(
+                "'{client_name:?}' is not a valid value for '{HEADER}': {err:#}"
+            ))
+        })?;
+        let client_name = String::from_utf8(client_name.to_vec()).map_err(|err| {
+            tonic::Status::invalid_argument(format!(
+                "'{client_name:?}' is not a valid value for '{HEADER}': {err:#}"
+            ))
+        })?;
+
+        Ok(Some(client_name))
+    }
 }
@@ -212,6 +212,9 @@ mod sizes {
                 Self::BlueprintActivationCommand(blueprint_activation_command) => {
                     blueprint_activation_command.heap_size_bytes()
                 }
+                Self::DropTimeRangeCommand(drop_time_range_command) => {
+                    drop_time_range_command.heap_size_bytes()
+                }
             }
         }
     }
@@ -348,6 +351,52 @@ mod sizes {
         }
     }
 
+    impl SizeBytes for crate::log_msg::v1alpha1::DropTimeRangeCommand {
+        #[inline]
+        fn heap_size_bytes(&self) -> u64 {
+            let Self {
+                store_id,
+                entity_path,
+                recursive,
+                timeline,
+                time_range,
+            } = self;
+
+            store_id.heap_size_bytes()
+                + entity_path.heap_size_bytes()
+                + recursive.heap_size_bytes()
+                + timeline.heap_size_bytes()
+                + time_range.heap_size_bytes()
+        }
+    }
+
+    impl SizeBytes for crate::common::v1alpha1::EntityPath {
+        #[inline]
+        fn heap_size_bytes(&self) -> u64 {
+            let Self { path } = self;
+
+            path.heap_size_bytes()
+        }
+    }
+
+    impl SizeBytes for crate::common::v1alpha1::Timeline {
+        #[inline]
+        fn heap_size_bytes(&self) -> u64 {
+            let Self { name } = self;
+
+            name.heap_size_bytes()
+        }
+    }
+
+    impl SizeBytes for crate::common::v1alpha1::TimeRange {
+        #[inline]
+        fn heap_size_bytes(&self) -> u64 {
+            let Self { start, end } = self;
+
+            start.heap_size_bytes() + end.heap_size_bytes()
+        }
+    }
+
     impl SizeBytes for crate::common::v1alpha1::DataframePart {
         #[inline]
         fn heap_size_bytes(&self) -> u64 {
@@ -309,6 +309,27 @@ impl From<re_log_types::BlueprintActivationCommand>
 // `StoreId`, so we don't implement it here.
 //TODO(#10730): we could reimplement it if/when we remove 0.24 back compat.
 
+impl From<re_log_types::DropTimeRangeCommand> for crate::log_msg::v1alpha1::DropTimeRangeCommand {
+    #[inline]
+    fn from(value: re_log_types::DropTimeRangeCommand) -> Self {
+        let re_log_types::DropTimeRangeCommand {
+            store_id,
+            entity_path,
+            recursive,
+            timeline,
+            range,
+        } = value;
+
+        Self {
+            store_id: Some(store_id.into()),
+            entity_path: Some(entity_path.into()),
+            recursive,
+            timeline: Some(timeline.into()),
+            time_range: Some(range.into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -2,7 +2,7 @@
 /// TODO(#8631): Remove `LogMsg`
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LogMsg {
-    #[prost(oneof = "log_msg::Msg", tags = "1, 2, 3")]
+    #[prost(oneof = "log_msg::Msg", tags = "1, 2, 3, 4")]
     pub msg: ::core::option::Option<log_msg::Msg>,
 }
 /// Nested message and enum types in `LogMsg`.
@@ -18,6 +18,9 @@ pub mod log_msg {
         /// A message that contains a blueprint activation command.
         #[prost(message, tag = "3")]
         BlueprintActivationCommand(super::BlueprintActivationCommand),
+        /// A message that contains a command to drop data within a time range.
+        #[prost(message, tag = "4")]
+        DropTimeRangeCommand(super::DropTimeRangeCommand),
     }
 }
 impl ::prost::Name for LogMsg {
@@ -111,6 +114,37 @@ impl ::prost::Name for BlueprintActivationCommand {
         "/rerun.log_msg.v1alpha1.BlueprintActivationCommand".into()
     }
 }
+/// Corresponds to `LogMsg::DropTimeRange`.
+///
+/// Used for excising the data of an entity within a time range, e.g. sensitive or corrupted data.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DropTimeRangeCommand {
+    /// The ID of the store to drop data from.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// The entity to drop data from.
+    #[prost(message, optional, tag = "2")]
+    pub entity_path: ::core::option::Option<super::super::common::v1alpha1::EntityPath>,
+    /// Whether to also drop the data of all the descendants of the entity.
+    #[prost(bool, tag = "3")]
+    pub recursive: bool,
+    /// The timeline the time range is on.
+    #[prost(message, optional, tag = "4")]
+    pub timeline: ::core::option::Option<super::super::common::v1alpha1::Timeline>,
+    /// The time range to drop, inclusive.
+    #[prost(message, optional, tag = "5")]
+    pub time_range: ::core::option::Option<super::super::common::v1alpha1::TimeRange>,
+}
+impl ::prost::Name for DropTimeRangeCommand {
+    const NAME: &'static str = "DropTimeRangeCommand";
+    const PACKAGE: &'static str = "rerun.log_msg.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.log_msg.v1alpha1.DropTimeRangeCommand".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.log_msg.v1alpha1.DropTimeRangeCommand".into()
+    }
+}
 /// Information about a recording or blueprint.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreInfo {
@@ -3,6 +3,15 @@
 pub struct WriteMessagesRequest {
     #[prost(message, optional, tag = "1")]
     pub log_msg: ::core::option::Option<super::super::log_msg::v1alpha1::LogMsg>,
+    /// If set, the payload of `log_msg.arrow_msg` is empty, and must instead be read
+    /// from this location in a previously attached shared memory segment.
+    #[prost(message, optional, tag = "2")]
+    pub shared_memory_payload: ::core::option::Option<SharedMemoryRef>,
+    /// If set, this is a flush barrier rather than a message, and `log_msg` is not set.
+    ///
+    /// See `AwaitFlushBarrier`.
+    #[prost(message, optional, tag = "3")]
+    pub flush_barrier: ::core::option::Option<FlushBarrier>,
 }
 impl ::prost::Name for WriteMessagesRequest {
     const NAME: &'static str = "WriteMessagesRequest";
@@ -111,6 +120,244 @@ impl ::prost::Name for ReadTablesResponse {
         "/rerun.sdk_comms.v1alpha1.ReadTablesResponse".into()
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachSharedMemoryRequest {
+    /// Path of the file backing the shared memory segment.
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    /// Random token stored in the segment header.
+    #[prost(bytes = "bytes", tag = "2")]
+    pub token: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for AttachSharedMemoryRequest {
+    const NAME: &'static str = "AttachSharedMemoryRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.AttachSharedMemoryRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.AttachSharedMemoryRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachSharedMemoryResponse {
+    /// Whether the server attached to the segment.
+    #[prost(bool, tag = "1")]
+    pub accepted: bool,
+    /// Why the server refused, if it did.
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+    /// Path of a file created by the server, in which it publishes how much of the segment it has read.
+    #[prost(string, tag = "3")]
+    pub release_path: ::prost::alloc::string::String,
+}
+impl ::prost::Name for AttachSharedMemoryResponse {
+    const NAME: &'static str = "AttachSharedMemoryResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.AttachSharedMemoryResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.AttachSharedMemoryResponse".into()
+    }
+}
+/// A payload stored in a shared memory segment.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SharedMemoryRef {
+    /// Path of the file backing the segment, as sent in `AttachSharedMemoryRequest`.
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    /// Monotonic offset of the payload in the segment.
+    #[prost(uint64, tag = "2")]
+    pub offset: u64,
+    /// Length of the payload, in bytes.
+    #[prost(uint64, tag = "3")]
+    pub length: u64,
+}
+impl ::prost::Name for SharedMemoryRef {
+    const NAME: &'static str = "SharedMemoryRef";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.SharedMemoryRef".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.SharedMemoryRef".into()
+    }
+}
+/// Marks a point in a `WriteMessages` stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlushBarrier {
+    /// Random id of the `WriteMessages` stream, chosen by the client.
+    #[prost(string, tag = "1")]
+    pub stream_id: ::prost::alloc::string::String,
+    /// Increases with every barrier sent on the same stream.
+    #[prost(uint64, tag = "2")]
+    pub barrier: u64,
+}
+impl ::prost::Name for FlushBarrier {
+    const NAME: &'static str = "FlushBarrier";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.FlushBarrier".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.FlushBarrier".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AwaitFlushBarrierRequest {
+    #[prost(message, optional, tag = "1")]
+    pub flush_barrier: ::core::option::Option<FlushBarrier>,
+}
+impl ::prost::Name for AwaitFlushBarrierRequest {
+    const NAME: &'static str = "AwaitFlushBarrierRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.AwaitFlushBarrierRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.AwaitFlushBarrierRequest".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AwaitFlushBarrierResponse {}
+impl ::prost::Name for AwaitFlushBarrierResponse {
+    const NAME: &'static str = "AwaitFlushBarrierResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.AwaitFlushBarrierResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.AwaitFlushBarrierResponse".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBufferedChunksRequest {
+    /// Only return chunks of this store.
+    ///
+    /// If not set, chunks of all recordings are returned.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// Only return chunks of entities matching this filter, e.g. `+ /world/** - /world/debug/**`.
+    ///
+    /// If empty, chunks of all entities are returned.
+    #[prost(string, tag = "2")]
+    pub entity_path_filter: ::prost::alloc::string::String,
+    /// Only return chunks with data on this timeline within `time_range`, as well as static chunks.
+    ///
+    /// If not set, chunks are returned regardless of their time.
+    #[prost(message, optional, tag = "3")]
+    pub timeline: ::core::option::Option<super::super::common::v1alpha1::Timeline>,
+    /// Inclusive range of times on `timeline`. Must be set if `timeline` is.
+    #[prost(message, optional, tag = "4")]
+    pub time_range: ::core::option::Option<super::super::common::v1alpha1::TimeRange>,
+}
+impl ::prost::Name for QueryBufferedChunksRequest {
+    const NAME: &'static str = "QueryBufferedChunksRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.QueryBufferedChunksRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.QueryBufferedChunksRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBufferedChunksResponse {
+    #[prost(message, optional, tag = "1")]
+    pub log_msg: ::core::option::Option<super::super::log_msg::v1alpha1::LogMsg>,
+}
+impl ::prost::Name for QueryBufferedChunksResponse {
+    const NAME: &'static str = "QueryBufferedChunksResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.QueryBufferedChunksResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.QueryBufferedChunksResponse".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadStoreEventsRequest {
+    /// Only stream the events of this store.
+    ///
+    /// If not set, the events of all stores are streamed, blueprints included.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// Whether to send the chunks themselves, rather than only describing them.
+    #[prost(bool, tag = "2")]
+    pub include_payloads: bool,
+}
+impl ::prost::Name for ReadStoreEventsRequest {
+    const NAME: &'static str = "ReadStoreEventsRequest";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ReadStoreEventsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ReadStoreEventsRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadStoreEventsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v1alpha1::StoreId>,
+    /// Increases with every event of the same store.
+    #[prost(uint64, tag = "2")]
+    pub event_id: u64,
+    #[prost(enumeration = "StoreEventKind", tag = "3")]
+    pub kind: i32,
+    #[prost(message, optional, tag = "4")]
+    pub chunk_id: ::core::option::Option<super::super::common::v1alpha1::Tuid>,
+    #[prost(message, optional, tag = "5")]
+    pub entity_path: ::core::option::Option<super::super::common::v1alpha1::EntityPath>,
+    #[prost(uint64, tag = "6")]
+    pub num_rows: u64,
+    /// The chunk itself, if `include_payloads` was set.
+    #[prost(message, optional, tag = "7")]
+    pub chunk: ::core::option::Option<super::super::log_msg::v1alpha1::ArrowMsg>,
+}
+impl ::prost::Name for ReadStoreEventsResponse {
+    const NAME: &'static str = "ReadStoreEventsResponse";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v1alpha1.ReadStoreEventsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v1alpha1.ReadStoreEventsResponse".into()
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StoreEventKind {
+    Unspecified = 0,
+    /// A chunk was added to the store.
+    Addition = 1,
+    /// A chunk was removed from the store.
+    Deletion = 2,
+}
+impl StoreEventKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "STORE_EVENT_KIND_UNSPECIFIED",
+            Self::Addition => "STORE_EVENT_KIND_ADDITION",
+            Self::Deletion => "STORE_EVENT_KIND_DELETION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STORE_EVENT_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "STORE_EVENT_KIND_ADDITION" => Some(Self::Addition),
+            "STORE_EVENT_KIND_DELETION" => Some(Self::Deletion),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod message_proxy_service_client {
     #![allow(
@@ -281,6 +528,116 @@ pub mod message_proxy_service_client {
             ));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Ask the server to attach to a shared memory segment created by the client.
+        ///
+        /// If the server accepts, the client may then send large payloads through that segment,
+        /// referencing them with `WriteMessagesRequest.shared_memory_payload`.
+        /// The server only accepts if it can open the segment and read back the expected token,
+        /// which in practice means it is running on the same machine.
+        /// The segment must live in the shared memory directory of the machine, and is only ever read by the server.
+        pub async fn attach_shared_memory(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachSharedMemoryRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachSharedMemoryResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/AttachSharedMemory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "AttachSharedMemory",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Wait until the server has accepted all messages sent before a flush barrier.
+        ///
+        /// The client sends `WriteMessagesRequest.flush_barrier` on its `WriteMessages` stream,
+        /// and then calls this to know when everything before it has been handed over to the
+        /// server's message buffer.
+        /// Barrier `0` is always reached, which clients use to check whether the server supports barriers.
+        pub async fn await_flush_barrier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AwaitFlushBarrierRequest>,
+        ) -> std::result::Result<tonic::Response<super::AwaitFlushBarrierResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/AwaitFlushBarrier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "AwaitFlushBarrier",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query the chunks still buffered by the server, e.g. to read back data from a running viewer.
+        ///
+        /// This reads the server's own message buffer, not the chunk store of the viewer hosting it:
+        /// only what is still buffered within the server's memory limit is returned,
+        /// as it was received, without compaction.
+        ///
+        /// Yields the store info of every matching recording, followed by its matching chunks,
+        /// in the order they were received.
+        /// Unlike `ReadMessages`, the stream ends once the buffered messages have been sent.
+        pub async fn query_buffered_chunks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryBufferedChunksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::QueryBufferedChunksResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/QueryBufferedChunks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "QueryBufferedChunks",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Stream the changes to the chunk stores of the process hosting the server, e.g. a running viewer.
+        ///
+        /// Yields an event for every chunk added to, or removed from (e.g. by garbage collection), a store,
+        /// from the moment of the call onwards. This lets external processes mirror or react to the stores of
+        /// a viewer without linking the viewer itself.
+        /// A standalone server has no stores, and never yields anything.
+        ///
+        /// If the client falls too far behind, the stream ends with a `DATA_LOSS` error.
+        pub async fn read_store_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReadStoreEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ReadStoreEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/ReadStoreEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v1alpha1.MessageProxyService",
+                "ReadStoreEvents",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -324,6 +681,62 @@ pub mod message_proxy_service_server {
             &self,
             request: tonic::Request<super::ReadTablesRequest>,
         ) -> std::result::Result<tonic::Response<Self::ReadTablesStream>, tonic::Status>;
+        /// Ask the server to attach to a shared memory segment created by the client.
+        ///
+        /// If the server accepts, the client may then send large payloads through that segment,
+        /// referencing them with `WriteMessagesRequest.shared_memory_payload`.
+        /// The server only accepts if it can open the segment and read back the expected token,
+        /// which in practice means it is running on the same machine.
+        /// The segment must live in the shared memory directory of the machine, and is only ever read by the server.
+        async fn attach_shared_memory(
+            &self,
+            request: tonic::Request<super::AttachSharedMemoryRequest>,
+        ) -> std::result::Result<tonic::Response<super::AttachSharedMemoryResponse>, tonic::Status>;
+        /// Wait until the server has accepted all messages sent before a flush barrier.
+        ///
+        /// The client sends `WriteMessagesRequest.flush_barrier` on its `WriteMessages` stream,
+        /// and then calls this to know when everything before it has been handed over to the
+        /// server's message buffer.
+        /// Barrier `0` is always reached, which clients use to check whether the server supports barriers.
+        async fn await_flush_barrier(
+            &self,
+            request: tonic::Request<super::AwaitFlushBarrierRequest>,
+        ) -> std::result::Result<tonic::Response<super::AwaitFlushBarrierResponse>, tonic::Status>;
+        /// Server streaming response type for the QueryBufferedChunks method.
+        type QueryBufferedChunksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::QueryBufferedChunksResponse, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Query the chunks still buffered by the server, e.g. to read back data from a running viewer.
+        ///
+        /// This reads the server's own message buffer, not the chunk store of the viewer hosting it:
+        /// only what is still buffered within the server's memory limit is returned,
+        /// as it was received, without compaction.
+        ///
+        /// Yields the store info of every matching recording, followed by its matching chunks,
+        /// in the order they were received.
+        /// Unlike `ReadMessages`, the stream ends once the buffered messages have been sent.
+        async fn query_buffered_chunks(
+            &self,
+            request: tonic::Request<super::QueryBufferedChunksRequest>,
+        ) -> std::result::Result<tonic::Response<Self::QueryBufferedChunksStream>, tonic::Status>;
+        /// Server streaming response type for the ReadStoreEvents method.
+        type ReadStoreEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ReadStoreEventsResponse, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Stream the changes to the chunk stores of the process hosting the server, e.g. a running viewer.
+        ///
+        /// Yields an event for every chunk added to, or removed from (e.g. by garbage collection), a store,
+        /// from the moment of the call onwards. This lets external processes mirror or react to the stores of
+        /// a viewer without linking the viewer itself.
+        /// A standalone server has no stores, and never yields anything.
+        ///
+        /// If the client falls too far behind, the stream ends with a `DATA_LOSS` error.
+        async fn read_store_events(
+            &self,
+            request: tonic::Request<super::ReadStoreEventsRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ReadStoreEventsStream>, tonic::Status>;
     }
     /// Simple buffer for messages between SDKs and viewers.
     ///
@@ -578,6 +991,181 @@ pub mod message_proxy_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/AttachSharedMemory" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachSharedMemorySvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::UnaryService<super::AttachSharedMemoryRequest>
+                        for AttachSharedMemorySvc<T>
+                    {
+                        type Response = super::AttachSharedMemoryResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachSharedMemoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::attach_shared_memory(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AttachSharedMemorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/AwaitFlushBarrier" => {
+                    #[allow(non_camel_case_types)]
+                    struct AwaitFlushBarrierSvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::UnaryService<super::AwaitFlushBarrierRequest>
+                        for AwaitFlushBarrierSvc<T>
+                    {
+                        type Response = super::AwaitFlushBarrierResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AwaitFlushBarrierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::await_flush_barrier(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AwaitFlushBarrierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/QueryBufferedChunks" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryBufferedChunksSvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::ServerStreamingService<super::QueryBufferedChunksRequest>
+                        for QueryBufferedChunksSvc<T>
+                    {
+                        type Response = super::QueryBufferedChunksResponse;
+                        type ResponseStream = T::QueryBufferedChunksStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBufferedChunksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::query_buffered_chunks(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryBufferedChunksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v1alpha1.MessageProxyService/ReadStoreEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadStoreEventsSvc<T: MessageProxyService>(pub Arc<T>);
+                    impl<T: MessageProxyService>
+                        tonic::server::ServerStreamingService<super::ReadStoreEventsRequest>
+                        for ReadStoreEventsSvc<T>
+                    {
+                        type Response = super::ReadStoreEventsResponse;
+                        type ResponseStream = T::ReadStoreEventsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadStoreEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxyService>::read_store_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadStoreEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     let mut response = http::Response::new(tonic::body::Body::default());
                     let headers = response.headers_mut();
@@ -34,7 +34,8 @@ impl Args {
     }
 
     pub async fn create_server_handle(self) -> anyhow::Result<ServerHandle> {
-        let rerun_cloud_server = {
+        let (rerun_cloud_server, flight_server) = {
+            use crate::flight_service::flight_service_server::FlightServiceServer;
             use re_protos::cloud::v1alpha1::rerun_cloud_service_server::RerunCloudServiceServer;
 
             let mut builder = crate::RerunCloudHandlerBuilder::new();
@@ -46,16 +47,25 @@ impl Args {
                 )?;
             }
 
-            RerunCloudServiceServer::new(builder.build())
+            let handler = builder.build();
+
+            // Served on the same port: gRPC routes requests by service name.
+            let flight_server = FlightServiceServer::new(handler.flight_handler())
+                .max_encoding_message_size(re_grpc_server::MAX_ENCODING_MESSAGE_SIZE);
+
+            let rerun_cloud_server = RerunCloudServiceServer::new(handler)
                 .max_decoding_message_size(re_grpc_server::MAX_DECODING_MESSAGE_SIZE)
-                .max_encoding_message_size(re_grpc_server::MAX_ENCODING_MESSAGE_SIZE)
+                .max_encoding_message_size(re_grpc_server::MAX_ENCODING_MESSAGE_SIZE);
+
+            (rerun_cloud_server, flight_server)
         };
 
         let addr = SocketAddr::new(self.addr.parse()?, self.port);
 
         let server_builder = ServerBuilder::default()
             .with_address(addr)
-            .with_service(rerun_cloud_server);
+            .with_service(rerun_cloud_server)
+            .with_service(flight_server);
 
         let server = server_builder.build();
 
@@ -0,0 +1,321 @@
+//! An [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) endpoint over the loaded datasets.
+//!
+//! This lets analytics tools (`pyarrow.flight`, the Java Flight client, …) pull the results of dataframe queries
+//! straight from the server, instead of going through file exports.
+//!
+//! Flights are addressed either with a path descriptor, `[dataset, partition_id]` for the static data of a
+//! partition, or `[dataset, partition_id, timeline]` for all of its data indexed by that timeline,
+//! or with a command descriptor holding a JSON-encoded [`FlightQuery`]. Tickets are always a [`FlightQuery`].
+
+use std::sync::Arc;
+
+use arrow::compute::concat_batches;
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+    encode::FlightDataEncoderBuilder, error::FlightError, flight_descriptor::DescriptorType,
+};
+use futures::{StreamExt as _, TryStreamExt as _};
+use itertools::Itertools as _;
+
+use re_dataframe::{QueryEngine, QueryHandle, StorageEngine, TextQuery};
+use re_protos::common::v1alpha1::ext::PartitionId;
+
+use crate::flight_service::flight_service_server::FlightService;
+use crate::store::InMemoryStore;
+
+// ---
+
+/// How many rows are sent at once by `DoGet`.
+const ROWS_PER_BATCH: usize = 4096;
+
+/// How many batches are computed ahead of what the client has received.
+const MAX_PENDING_BATCHES: usize = 4;
+
+/// What a Flight ticket (or command descriptor) asks for: a [`TextQuery`] against a single partition.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FlightQuery {
+    /// The name of the dataset.
+    pub dataset: String,
+
+    /// The partition of the dataset, i.e. the recording id.
+    pub partition_id: String,
+
+    /// E.g. `SELECT * FROM /world/** WHERE frame_nr BETWEEN 10 AND 20`.
+    pub query: String,
+}
+
+impl FlightQuery {
+    fn from_json(json: &[u8]) -> tonic::Result<Self> {
+        serde_json::from_slice(json)
+            .map_err(|err| tonic::Status::invalid_argument(format!("Invalid query: {err}")))
+    }
+
+    fn from_descriptor(descriptor: &FlightDescriptor) -> tonic::Result<Self> {
+        match descriptor.r#type() {
+            DescriptorType::Cmd => Self::from_json(&descriptor.cmd),
+
+            DescriptorType::Path => match descriptor.path.as_slice() {
+                [dataset, partition_id] => Ok(Self {
+                    dataset: dataset.clone(),
+                    partition_id: partition_id.clone(),
+                    query: "SELECT * FROM /**".to_owned(),
+                }),
+
+                [dataset, partition_id, timeline] => Ok(Self {
+                    dataset: dataset.clone(),
+                    partition_id: partition_id.clone(),
+                    query: format!("SELECT * FROM /** ORDER BY {timeline:?}"),
+                }),
+
+                _ => Err(tonic::Status::invalid_argument(
+                    "Expected a path of either [dataset, partition_id] or [dataset, partition_id, timeline]",
+                )),
+            },
+
+            DescriptorType::Unknown => Err(tonic::Status::invalid_argument(
+                "Unknown flight descriptor type",
+            )),
+        }
+    }
+
+    fn to_ticket(&self) -> tonic::Result<Ticket> {
+        serde_json::to_vec(self)
+            .map(Ticket::new)
+            .map_err(|err| tonic::Status::internal(format!("Failed to encode ticket: {err}")))
+    }
+
+    /// Starts the query, without running it yet.
+    fn start(&self, store: &InMemoryStore) -> tonic::Result<QueryHandle<StorageEngine>> {
+        let dataset = store.dataset_by_name(&self.dataset).ok_or_else(|| {
+            tonic::Status::not_found(format!("Dataset {:?} not found", self.dataset))
+        })?;
+        let partition = dataset
+            .partition(&PartitionId::new(self.partition_id.clone()))
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("Partition {:?} not found", self.partition_id))
+            })?;
+
+        #[expect(unsafe_code)]
+        // Safety: no viewer is running, and queries only ever take the locks of the storage engine one at a time.
+        let engine = unsafe { partition.storage_engine_raw() }.clone();
+
+        let query: TextQuery = self
+            .query
+            .parse()
+            .map_err(|err| tonic::Status::invalid_argument(format!("Invalid query: {err}")))?;
+        let engine = QueryEngine { engine };
+        Ok(engine.query(query.to_query_expression(&engine)))
+    }
+
+    fn flight_info(
+        &self,
+        store: &InMemoryStore,
+        descriptor: FlightDescriptor,
+    ) -> tonic::Result<FlightInfo> {
+        let query_handle = self.start(store)?;
+
+        Ok(FlightInfo::new()
+            .try_with_schema(query_handle.schema())
+            .map_err(|err| tonic::Status::internal(format!("Failed to encode schema: {err}")))?
+            .with_descriptor(descriptor)
+            .with_endpoint(FlightEndpoint::new().with_ticket(self.to_ticket()?))
+            .with_total_records(query_handle.num_rows() as i64)
+            .with_ordered(true))
+    }
+}
+
+// ---
+
+/// Serves the datasets of a [`crate::RerunCloudHandler`] over Arrow Flight, see the [module docs](self).
+///
+/// Created with [`crate::RerunCloudHandler::flight_handler`].
+pub struct FlightHandler {
+    store: Arc<tokio::sync::RwLock<InMemoryStore>>,
+}
+
+impl FlightHandler {
+    pub(crate) fn new(store: Arc<tokio::sync::RwLock<InMemoryStore>>) -> Self {
+        Self { store }
+    }
+}
+
+impl std::fmt::Debug for FlightHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlightHandler").finish()
+    }
+}
+
+type BoxStream<T> = std::pin::Pin<Box<dyn futures::Stream<Item = Result<T, tonic::Status>> + Send>>;
+
+#[tonic::async_trait]
+impl FlightService for FlightHandler {
+    type HandshakeStream = BoxStream<HandshakeResponse>;
+    type ListFlightsStream = BoxStream<FlightInfo>;
+    type DoGetStream = BoxStream<FlightData>;
+    type DoPutStream = BoxStream<PutResult>;
+    type DoActionStream = BoxStream<arrow_flight::Result>;
+    type ListActionsStream = BoxStream<ActionType>;
+    type DoExchangeStream = BoxStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        _request: tonic::Request<tonic::Streaming<HandshakeRequest>>,
+    ) -> tonic::Result<tonic::Response<Self::HandshakeStream>> {
+        // There is no authentication: anyone who can reach the server can read its datasets.
+        let response = HandshakeResponse::default();
+        Ok(tonic::Response::new(Box::pin(futures::stream::once(
+            async { Ok(response) },
+        ))))
+    }
+
+    /// Lists the static data and every timeline of every partition.
+    ///
+    /// A non-empty criteria expression only lists the dataset with that name.
+    async fn list_flights(
+        &self,
+        request: tonic::Request<Criteria>,
+    ) -> tonic::Result<tonic::Response<Self::ListFlightsStream>> {
+        let dataset_name = String::from_utf8_lossy(&request.get_ref().expression).into_owned();
+
+        let store = self.store.read().await;
+
+        let mut flights = Vec::new();
+        for dataset in store.iter_datasets() {
+            if !dataset_name.is_empty() && dataset.name() != dataset_name {
+                continue;
+            }
+
+            for partition_id in dataset.partition_ids().sorted() {
+                let Some(partition) = dataset.partition(&partition_id) else {
+                    continue;
+                };
+                let timelines = partition.storage_engine().store().timelines().into_keys();
+
+                let paths =
+                    std::iter::once(vec![dataset.name().to_owned(), partition_id.id.clone()])
+                        .chain(timelines.map(|timeline| {
+                            vec![
+                                dataset.name().to_owned(),
+                                partition_id.id.clone(),
+                                timeline.to_string(),
+                            ]
+                        }));
+                for path in paths {
+                    let descriptor = FlightDescriptor::new_path(path);
+                    let query = FlightQuery::from_descriptor(&descriptor)?;
+                    flights.push(Ok(query.flight_info(&store, descriptor)?));
+                }
+            }
+        }
+
+        Ok(tonic::Response::new(Box::pin(futures::stream::iter(
+            flights,
+        ))))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: tonic::Request<FlightDescriptor>,
+    ) -> tonic::Result<tonic::Response<FlightInfo>> {
+        let descriptor = request.into_inner();
+        let query = FlightQuery::from_descriptor(&descriptor)?;
+
+        let store = self.store.read().await;
+        Ok(tonic::Response::new(query.flight_info(&store, descriptor)?))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: tonic::Request<FlightDescriptor>,
+    ) -> tonic::Result<tonic::Response<PollInfo>> {
+        Err(tonic::Status::unimplemented(
+            "poll_flight_info: queries are not long-running, use get_flight_info",
+        ))
+    }
+
+    async fn get_schema(
+        &self,
+        request: tonic::Request<FlightDescriptor>,
+    ) -> tonic::Result<tonic::Response<SchemaResult>> {
+        let query = FlightQuery::from_descriptor(request.get_ref())?;
+
+        let store = self.store.read().await;
+        let query_handle = query.start(&store)?;
+        let schema = SchemaResult::try_from(arrow_flight::SchemaAsIpc::new(
+            query_handle.schema(),
+            &arrow::ipc::writer::IpcWriteOptions::default(),
+        ))
+        .map_err(|err| tonic::Status::internal(format!("Failed to encode schema: {err}")))?;
+
+        Ok(tonic::Response::new(schema))
+    }
+
+    /// Runs the query of the ticket, and streams its results as they are computed.
+    async fn do_get(
+        &self,
+        request: tonic::Request<Ticket>,
+    ) -> tonic::Result<tonic::Response<Self::DoGetStream>> {
+        let query = FlightQuery::from_json(&request.get_ref().ticket)?;
+        re_log::debug!(?query, "do_get");
+
+        let query_handle = query.start(&*self.store.read().await)?;
+        let schema = query_handle.schema().clone();
+
+        let (tx, rx) = tokio::sync::mpsc::channel(MAX_PENDING_BATCHES);
+        tokio::task::spawn_blocking({
+            let schema = schema.clone();
+            move || {
+                for rows in &query_handle.into_batch_iter().chunks(ROWS_PER_BATCH) {
+                    let batch =
+                        concat_batches(&schema, &rows.collect_vec()).map_err(FlightError::from);
+                    if tx.blocking_send(batch).is_err() {
+                        break; // The client went away.
+                    }
+                }
+            }
+        });
+
+        let stream = FlightDataEncoderBuilder::new()
+            .with_schema(schema)
+            .build(tokio_stream::wrappers::ReceiverStream::new(rx))
+            .map_err(|err| tonic::Status::internal(err.to_string()))
+            .boxed();
+
+        Ok(tonic::Response::new(stream))
+    }
+
+    async fn do_put(
+        &self,
+        _request: tonic::Request<tonic::Streaming<FlightData>>,
+    ) -> tonic::Result<tonic::Response<Self::DoPutStream>> {
+        Err(tonic::Status::unimplemented(
+            "do_put: the Flight endpoint is read-only, use write_chunks instead",
+        ))
+    }
+
+    async fn do_action(
+        &self,
+        _request: tonic::Request<Action>,
+    ) -> tonic::Result<tonic::Response<Self::DoActionStream>> {
+        Err(tonic::Status::unimplemented(
+            "do_action: no actions are supported",
+        ))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: tonic::Request<Empty>,
+    ) -> tonic::Result<tonic::Response<Self::ListActionsStream>> {
+        Ok(tonic::Response::new(Box::pin(futures::stream::empty())))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: tonic::Request<tonic::Streaming<FlightData>>,
+    ) -> tonic::Result<tonic::Response<Self::DoExchangeStream>> {
+        Err(tonic::Status::unimplemented(
+            "do_exchange: the Flight endpoint is read-only",
+        ))
+    }
+}
@@ -0,0 +1,695 @@
+//! Arrow Flight's `FlightService`, generated by `tonic-build` from Arrow's `format/Flight.proto`, using the
+//! message types of `arrow-flight`.
+//!
+//! `arrow-flight` comes with its own generated service, but that one is built against an older `tonic`
+//! than ours, and so can't be served next to our other services.
+
+// Note: `allow(clippy::all)` does NOT allow all lints
+#![allow(clippy::all, clippy::pedantic, clippy::nursery)]
+
+// This file is @generated by prost-build.
+/// Generated server implementations.
+pub mod flight_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with FlightServiceServer.
+    #[async_trait]
+    pub trait FlightService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the Handshake method.
+        type HandshakeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::HandshakeResponse, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Handshake between client and server. Depending on the server, the
+        /// handshake may be required to determine the token that should be used for
+        /// future operations. Both request and response are streams to allow multiple
+        /// round-trips depending on auth mechanism.
+        async fn handshake(
+            &self,
+            request: tonic::Request<tonic::Streaming<::arrow_flight::HandshakeRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::HandshakeStream>, tonic::Status>;
+        /// Server streaming response type for the ListFlights method.
+        type ListFlightsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::FlightInfo, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Get a list of available streams given a particular criteria. Most flight
+        /// services will expose one or more streams that are readily available for
+        /// retrieval. This api allows listing the streams available for
+        /// consumption. A user can also provide a criteria. The criteria can limit
+        /// the subset of streams that can be listed via this interface. Each flight
+        /// service allows its own definition of how to consume criteria.
+        async fn list_flights(
+            &self,
+            request: tonic::Request<::arrow_flight::Criteria>,
+        ) -> std::result::Result<tonic::Response<Self::ListFlightsStream>, tonic::Status>;
+        /// For a given FlightDescriptor, get information about how the flight can be
+        /// consumed. This is a useful interface if the consumer of the interface
+        /// already can identify the specific flight to consume. This interface can
+        /// also allow a consumer to generate a flight stream through a specified
+        /// descriptor. For example, a flight descriptor might be something that
+        /// includes a SQL statement or a Pickled Python operation that will be
+        /// executed. In those cases, the descriptor will not be previously available
+        /// within the list of available streams provided by ListFlights but will be
+        /// available for consumption for the duration defined by the specific flight
+        /// service.
+        async fn get_flight_info(
+            &self,
+            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+        ) -> std::result::Result<tonic::Response<::arrow_flight::FlightInfo>, tonic::Status>;
+        /// For a given FlightDescriptor, start a query and get information
+        /// to poll its execution status. This is a useful interface if the
+        /// query may be a long-running query.
+        async fn poll_flight_info(
+            &self,
+            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+        ) -> std::result::Result<tonic::Response<::arrow_flight::PollInfo>, tonic::Status>;
+        /// For a given FlightDescriptor, get the Schema as described in Schema.fbs::Schema
+        /// This is used when a consumer needs the Schema of flight stream. Similar to
+        /// GetFlightInfo this interface may generate a new flight that was not previously
+        /// available in ListFlights.
+        async fn get_schema(
+            &self,
+            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+        ) -> std::result::Result<tonic::Response<::arrow_flight::SchemaResult>, tonic::Status>;
+        /// Server streaming response type for the DoGet method.
+        type DoGetStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::FlightData, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Retrieve a single stream associated with a particular descriptor
+        /// associated with the referenced ticket. A Flight can be composed of one or
+        /// more streams where each stream can be retrieved using a separate opaque
+        /// ticket that the flight service uses for managing a collection of streams.
+        async fn do_get(
+            &self,
+            request: tonic::Request<::arrow_flight::Ticket>,
+        ) -> std::result::Result<tonic::Response<Self::DoGetStream>, tonic::Status>;
+        /// Server streaming response type for the DoPut method.
+        type DoPutStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::PutResult, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Push a stream to the flight service associated with a particular
+        /// flight stream. This allows a client of a flight service to upload a stream
+        /// of data. Depending on the particular flight service, a client consumer
+        /// could be allowed to upload a single stream per descriptor or an unlimited
+        /// number. In the latter, the service might implement a 'seal' action that
+        /// can be applied to a descriptor once all streams are uploaded.
+        async fn do_put(
+            &self,
+            request: tonic::Request<tonic::Streaming<::arrow_flight::FlightData>>,
+        ) -> std::result::Result<tonic::Response<Self::DoPutStream>, tonic::Status>;
+        /// Server streaming response type for the DoExchange method.
+        type DoExchangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::FlightData, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Open a bidirectional data channel for a given descriptor. This
+        /// allows clients to send and receive arbitrary Arrow data and
+        /// application-specific metadata in a single logical stream. In
+        /// contrast to DoGet/DoPut, this is more suited for clients
+        /// offloading computation (rather than storage) to a Flight service.
+        async fn do_exchange(
+            &self,
+            request: tonic::Request<tonic::Streaming<::arrow_flight::FlightData>>,
+        ) -> std::result::Result<tonic::Response<Self::DoExchangeStream>, tonic::Status>;
+        /// Server streaming response type for the DoAction method.
+        type DoActionStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::Result, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// Flight services can support an arbitrary number of simple actions in
+        /// addition to the possible ListFlights, GetFlightInfo, DoGet, DoPut
+        /// operations that are potentially available. DoAction allows a flight client
+        /// to do a specific action against a flight service. An action includes
+        /// opaque request and response objects that are specific to the type action
+        /// being undertaken.
+        async fn do_action(
+            &self,
+            request: tonic::Request<::arrow_flight::Action>,
+        ) -> std::result::Result<tonic::Response<Self::DoActionStream>, tonic::Status>;
+        /// Server streaming response type for the ListActions method.
+        type ListActionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<::arrow_flight::ActionType, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        /// A flight service exposes all of the available action types that it has
+        /// along with descriptions. This allows different flight consumers to
+        /// understand the capabilities of the flight service.
+        async fn list_actions(
+            &self,
+            request: tonic::Request<::arrow_flight::Empty>,
+        ) -> std::result::Result<tonic::Response<Self::ListActionsStream>, tonic::Status>;
+    }
+    /// A flight service is an endpoint for retrieving or storing Arrow data. A
+    /// flight service can expose one or more predefined endpoints that can be
+    /// accessed using the Arrow Flight Protocol. Additionally, a flight service
+    /// can expose a set of actions that are available.
+    #[derive(Debug)]
+    pub struct FlightServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> FlightServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for FlightServiceServer<T>
+    where
+        T: FlightService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/arrow.flight.protocol.FlightService/Handshake" => {
+                    #[allow(non_camel_case_types)]
+                    struct HandshakeSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::StreamingService<::arrow_flight::HandshakeRequest>
+                        for HandshakeSvc<T>
+                    {
+                        type Response = ::arrow_flight::HandshakeResponse;
+                        type ResponseStream = T::HandshakeStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<::arrow_flight::HandshakeRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::handshake(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HandshakeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/ListFlights" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFlightsSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::ServerStreamingService<::arrow_flight::Criteria>
+                        for ListFlightsSvc<T>
+                    {
+                        type Response = ::arrow_flight::FlightInfo;
+                        type ResponseStream = T::ListFlightsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::Criteria>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::list_flights(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListFlightsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/GetFlightInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFlightInfoSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::UnaryService<::arrow_flight::FlightDescriptor>
+                        for GetFlightInfoSvc<T>
+                    {
+                        type Response = ::arrow_flight::FlightInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::get_flight_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetFlightInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/PollFlightInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct PollFlightInfoSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::UnaryService<::arrow_flight::FlightDescriptor>
+                        for PollFlightInfoSvc<T>
+                    {
+                        type Response = ::arrow_flight::PollInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::poll_flight_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PollFlightInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/GetSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSchemaSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::UnaryService<::arrow_flight::FlightDescriptor>
+                        for GetSchemaSvc<T>
+                    {
+                        type Response = ::arrow_flight::SchemaResult;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::FlightDescriptor>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::get_schema(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/DoGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct DoGetSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::ServerStreamingService<::arrow_flight::Ticket>
+                        for DoGetSvc<T>
+                    {
+                        type Response = ::arrow_flight::FlightData;
+                        type ResponseStream = T::DoGetStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::Ticket>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as FlightService>::do_get(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DoGetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/DoPut" => {
+                    #[allow(non_camel_case_types)]
+                    struct DoPutSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::StreamingService<::arrow_flight::FlightData>
+                        for DoPutSvc<T>
+                    {
+                        type Response = ::arrow_flight::PutResult;
+                        type ResponseStream = T::DoPutStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<::arrow_flight::FlightData>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as FlightService>::do_put(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DoPutSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/DoExchange" => {
+                    #[allow(non_camel_case_types)]
+                    struct DoExchangeSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::StreamingService<::arrow_flight::FlightData>
+                        for DoExchangeSvc<T>
+                    {
+                        type Response = ::arrow_flight::FlightData;
+                        type ResponseStream = T::DoExchangeStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<::arrow_flight::FlightData>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::do_exchange(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DoExchangeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/DoAction" => {
+                    #[allow(non_camel_case_types)]
+                    struct DoActionSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::ServerStreamingService<::arrow_flight::Action>
+                        for DoActionSvc<T>
+                    {
+                        type Response = ::arrow_flight::Result;
+                        type ResponseStream = T::DoActionStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::Action>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::do_action(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DoActionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/arrow.flight.protocol.FlightService/ListActions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListActionsSvc<T: FlightService>(pub Arc<T>);
+                    impl<T: FlightService>
+                        tonic::server::ServerStreamingService<::arrow_flight::Empty>
+                        for ListActionsSvc<T>
+                    {
+                        type Response = ::arrow_flight::ActionType;
+                        type ResponseStream = T::ListActionsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<::arrow_flight::Empty>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as FlightService>::list_actions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListActionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    let mut response = http::Response::new(tonic::body::Body::default());
+                    let headers = response.headers_mut();
+                    headers.insert(
+                        tonic::Status::GRPC_STATUS,
+                        (tonic::Code::Unimplemented as i32).into(),
+                    );
+                    headers.insert(
+                        http::header::CONTENT_TYPE,
+                        tonic::metadata::GRPC_CONTENT_TYPE,
+                    );
+                    Ok(response)
+                }),
+            }
+        }
+    }
+    impl<T> Clone for FlightServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "arrow.flight.protocol.FlightService";
+    impl<T> tonic::server::NamedService for FlightServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
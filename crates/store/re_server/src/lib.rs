@@ -1,12 +1,15 @@
 //! A Rerun server implementation backed by an in-memory store.
 
 mod entrypoint;
+mod flight;
+mod flight_service;
 mod rerun_cloud;
 mod server;
 mod store;
 
 pub use self::{
     entrypoint::Args,
+    flight::{FlightHandler, FlightQuery},
     rerun_cloud::{RerunCloudHandler, RerunCloudHandlerBuilder, RerunCloudHandlerSettings},
     server::{Server, ServerBuilder, ServerError, ServerHandle},
 };
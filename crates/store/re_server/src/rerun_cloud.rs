@@ -81,17 +81,22 @@ pub struct RerunCloudHandler {
     #[expect(dead_code)]
     settings: RerunCloudHandlerSettings,
 
-    store: tokio::sync::RwLock<InMemoryStore>,
+    store: Arc<tokio::sync::RwLock<InMemoryStore>>,
 }
 
 impl RerunCloudHandler {
     pub fn new(settings: RerunCloudHandlerSettings, store: InMemoryStore) -> Self {
         Self {
             settings,
-            store: tokio::sync::RwLock::new(store),
+            store: Arc::new(tokio::sync::RwLock::new(store)),
         }
     }
 
+    /// Serves the same datasets over Arrow Flight, see [`crate::FlightHandler`].
+    pub fn flight_handler(&self) -> crate::FlightHandler {
+        crate::FlightHandler::new(self.store.clone())
+    }
+
     async fn get_storage_engines(
         &self,
         dataset_id: EntryId,
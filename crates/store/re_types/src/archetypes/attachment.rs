@@ -0,0 +1,341 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/attachment.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: An arbitrary file attached to a recording, e.g. a configuration file or a report.
+///
+/// The Rerun Viewer shows a preview of the attachment in the selection panel:
+/// images and videos are shown as such, JSON documents as a collapsible tree,
+/// other text as plain text, and anything else as a hexdump.
+/// There is no dedicated preview for PDF documents yet: save them to disk to view them.
+///
+/// See also [`archetypes::Asset3D`][crate::archetypes::Asset3D] and [`archetypes::AssetVideo`][crate::archetypes::AssetVideo] for assets that are shown in the spatial views.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Attachment {
+    /// The contents of the attachment.
+    pub blob: Option<SerializedComponentBatch>,
+
+    /// The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+    ///
+    /// If omitted, the viewer will try to guess from the data blob.
+    pub media_type: Option<SerializedComponentBatch>,
+
+    /// The name of the file the attachment was read from, e.g. `config.yaml`.
+    ///
+    /// Used as the default file name when saving the attachment from the viewer.
+    pub filename: Option<SerializedComponentBatch>,
+}
+
+impl Attachment {
+    /// Returns the [`ComponentDescriptor`] for [`Self::blob`].
+    ///
+    /// The corresponding component is [`crate::components::Blob`].
+    #[inline]
+    pub fn descriptor_blob() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Attachment".into()),
+            component: "Attachment:blob".into(),
+            component_type: Some("rerun.components.Blob".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::media_type`].
+    ///
+    /// The corresponding component is [`crate::components::MediaType`].
+    #[inline]
+    pub fn descriptor_media_type() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Attachment".into()),
+            component: "Attachment:media_type".into(),
+            component_type: Some("rerun.components.MediaType".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::filename`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_filename() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Attachment".into()),
+            component: "Attachment:filename".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Attachment::descriptor_blob()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Attachment::descriptor_media_type()]);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Attachment::descriptor_filename()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Attachment::descriptor_blob(),
+            Attachment::descriptor_media_type(),
+            Attachment::descriptor_filename(),
+        ]
+    });
+
+impl Attachment {
+    /// The total number of components in the archetype: 1 required, 1 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for Attachment {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Attachment".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Attachment"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let blob = arrays_by_descr
+            .get(&Self::descriptor_blob())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_blob()));
+        let media_type = arrays_by_descr
+            .get(&Self::descriptor_media_type())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_media_type())
+            });
+        let filename = arrays_by_descr
+            .get(&Self::descriptor_filename())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_filename()));
+        Ok(Self {
+            blob,
+            media_type,
+            filename,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Attachment {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.blob.clone(),
+            self.media_type.clone(),
+            self.filename.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Attachment {}
+
+impl Attachment {
+    /// Create a new `Attachment`.
+    #[inline]
+    pub fn new(blob: impl Into<crate::components::Blob>) -> Self {
+        Self {
+            blob: try_serialize_field(Self::descriptor_blob(), [blob]),
+            media_type: None,
+            filename: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Attachment`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Attachment`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            blob: Some(SerializedComponentBatch::new(
+                crate::components::Blob::arrow_empty(),
+                Self::descriptor_blob(),
+            )),
+            media_type: Some(SerializedComponentBatch::new(
+                crate::components::MediaType::arrow_empty(),
+                Self::descriptor_media_type(),
+            )),
+            filename: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_filename(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.blob
+                .map(|blob| blob.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.media_type
+                .map(|media_type| media_type.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.filename
+                .map(|filename| filename.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_blob = self.blob.as_ref().map(|b| b.array.len());
+        let len_media_type = self.media_type.as_ref().map(|b| b.array.len());
+        let len_filename = self.filename.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_blob)
+            .or(len_media_type)
+            .or(len_filename)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The contents of the attachment.
+    #[inline]
+    pub fn with_blob(mut self, blob: impl Into<crate::components::Blob>) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), [blob]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Blob`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_blob`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_blob(
+        mut self,
+        blob: impl IntoIterator<Item = impl Into<crate::components::Blob>>,
+    ) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), blob);
+        self
+    }
+
+    /// The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+    ///
+    /// If omitted, the viewer will try to guess from the data blob.
+    #[inline]
+    pub fn with_media_type(mut self, media_type: impl Into<crate::components::MediaType>) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), [media_type]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::MediaType`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_media_type`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_media_type(
+        mut self,
+        media_type: impl IntoIterator<Item = impl Into<crate::components::MediaType>>,
+    ) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), media_type);
+        self
+    }
+
+    /// The name of the file the attachment was read from, e.g. `config.yaml`.
+    ///
+    /// Used as the default file name when saving the attachment from the viewer.
+    #[inline]
+    pub fn with_filename(mut self, filename: impl Into<crate::components::Name>) -> Self {
+        self.filename = try_serialize_field(Self::descriptor_filename(), [filename]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Name`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_filename`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_filename(
+        mut self,
+        filename: impl IntoIterator<Item = impl Into<crate::components::Name>>,
+    ) -> Self {
+        self.filename = try_serialize_field(Self::descriptor_filename(), filename);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Attachment {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.blob.heap_size_bytes()
+            + self.media_type.heap_size_bytes()
+            + self.filename.heap_size_bytes()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::components::MediaType;
+
+use super::Attachment;
+
+impl Attachment {
+    /// Creates a new [`Attachment`] from the file contents at `path`.
+    ///
+    /// The [`MediaType`] will first be guessed from the file extension, then from the file
+    /// contents if needed. The file name is stored alongside the contents.
+    ///
+    /// Returns an error if the file cannot be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn from_file_path(filepath: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let filepath = filepath.as_ref();
+        let contents = std::fs::read(filepath)?;
+        let mut attachment =
+            Self::from_file_contents(contents, MediaType::guess_from_path(filepath));
+        if let Some(filename) = filepath.file_name().and_then(|name| name.to_str()) {
+            attachment = attachment.with_filename(filename);
+        }
+        Ok(attachment)
+    }
+
+    /// Creates a new [`Attachment`] from the given `contents`.
+    ///
+    /// If no [`MediaType`] is given, it will be guessed from magic bytes in the data.
+    #[inline]
+    pub fn from_file_contents(contents: Vec<u8>, media_type: Option<impl Into<MediaType>>) -> Self {
+        let media_type = media_type.map(Into::into);
+        if let Some(media_type) = MediaType::or_guess_from_data(media_type, &contents) {
+            Self::new(contents).with_media_type(media_type)
+        } else {
+            Self::new(contents)
+        }
+    }
+}
@@ -0,0 +1,528 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/heatmap.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A 2D grid of scalar values, shown as a colormapped image.
+///
+/// Useful for cost maps, attention maps, correlation matrices and other data that
+/// would otherwise have to be colormapped manually before logging it as an [`archetypes::Image`][crate::archetypes::Image].
+///
+/// In 2D views the heatmap is drawn like an image, with one pixel per value.
+/// In 3D views it is drawn as a textured quad on the XY plane of its entity.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Heatmap {
+    /// The values of the heatmap, in row-major order.
+    pub buffer: Option<SerializedComponentBatch>,
+
+    /// The format of the heatmap, i.e. its width, height and datatype.
+    pub format: Option<SerializedComponentBatch>,
+
+    /// Colormap used to map the values to colors.
+    ///
+    /// If not set, the heatmap will be rendered using the Viridis colormap.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The range of values that is mapped onto the colormap.
+    ///
+    /// Values outside of the range are clamped to the range for the purpose of colormapping.
+    ///
+    /// If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+    pub value_range: Option<SerializedComponentBatch>,
+
+    /// Opacity of the heatmap, useful for layering it on top of an image.
+    ///
+    /// Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+    pub opacity: Option<SerializedComponentBatch>,
+
+    /// An optional floating point value that specifies the 2D drawing order.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    /// Defaults to `-5.0`.
+    pub draw_order: Option<SerializedComponentBatch>,
+}
+
+impl Heatmap {
+    /// Returns the [`ComponentDescriptor`] for [`Self::buffer`].
+    ///
+    /// The corresponding component is [`crate::components::ImageBuffer`].
+    #[inline]
+    pub fn descriptor_buffer() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:buffer".into(),
+            component_type: Some("rerun.components.ImageBuffer".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::format`].
+    ///
+    /// The corresponding component is [`crate::components::ImageFormat`].
+    #[inline]
+    pub fn descriptor_format() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:format".into(),
+            component_type: Some("rerun.components.ImageFormat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    ///
+    /// The corresponding component is [`crate::components::Colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:colormap".into(),
+            component_type: Some("rerun.components.Colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::value_range`].
+    ///
+    /// The corresponding component is [`crate::components::ValueRange`].
+    #[inline]
+    pub fn descriptor_value_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:value_range".into(),
+            component_type: Some("rerun.components.ValueRange".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:opacity".into(),
+            component_type: Some("rerun.components.Opacity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::draw_order`].
+    ///
+    /// The corresponding component is [`crate::components::DrawOrder`].
+    #[inline]
+    pub fn descriptor_draw_order() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Heatmap".into()),
+            component: "Heatmap:draw_order".into(),
+            component_type: Some("rerun.components.DrawOrder".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| [Heatmap::descriptor_buffer(), Heatmap::descriptor_format()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Heatmap::descriptor_colormap(),
+            Heatmap::descriptor_value_range(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Heatmap::descriptor_opacity(),
+            Heatmap::descriptor_draw_order(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Heatmap::descriptor_buffer(),
+            Heatmap::descriptor_format(),
+            Heatmap::descriptor_colormap(),
+            Heatmap::descriptor_value_range(),
+            Heatmap::descriptor_opacity(),
+            Heatmap::descriptor_draw_order(),
+        ]
+    });
+
+impl Heatmap {
+    /// The total number of components in the archetype: 2 required, 2 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
+}
+
+impl ::re_types_core::Archetype for Heatmap {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Heatmap".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Heatmap"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let buffer = arrays_by_descr
+            .get(&Self::descriptor_buffer())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_buffer()));
+        let format = arrays_by_descr
+            .get(&Self::descriptor_format())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_format()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let value_range = arrays_by_descr
+            .get(&Self::descriptor_value_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_value_range())
+            });
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        let draw_order = arrays_by_descr
+            .get(&Self::descriptor_draw_order())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_draw_order())
+            });
+        Ok(Self {
+            buffer,
+            format,
+            colormap,
+            value_range,
+            opacity,
+            draw_order,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Heatmap {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.buffer.clone(),
+            self.format.clone(),
+            self.colormap.clone(),
+            self.value_range.clone(),
+            self.opacity.clone(),
+            self.draw_order.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Heatmap {}
+
+impl Heatmap {
+    /// Create a new `Heatmap`.
+    #[inline]
+    pub fn new(
+        buffer: impl Into<crate::components::ImageBuffer>,
+        format: impl Into<crate::components::ImageFormat>,
+    ) -> Self {
+        Self {
+            buffer: try_serialize_field(Self::descriptor_buffer(), [buffer]),
+            format: try_serialize_field(Self::descriptor_format(), [format]),
+            colormap: None,
+            value_range: None,
+            opacity: None,
+            draw_order: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Heatmap`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Heatmap`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            buffer: Some(SerializedComponentBatch::new(
+                crate::components::ImageBuffer::arrow_empty(),
+                Self::descriptor_buffer(),
+            )),
+            format: Some(SerializedComponentBatch::new(
+                crate::components::ImageFormat::arrow_empty(),
+                Self::descriptor_format(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            value_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_value_range(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+            draw_order: Some(SerializedComponentBatch::new(
+                crate::components::DrawOrder::arrow_empty(),
+                Self::descriptor_draw_order(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.buffer
+                .map(|buffer| buffer.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.format
+                .map(|format| format.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colormap
+                .map(|colormap| colormap.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.value_range
+                .map(|value_range| value_range.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.opacity
+                .map(|opacity| opacity.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.draw_order
+                .map(|draw_order| draw_order.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_buffer = self.buffer.as_ref().map(|b| b.array.len());
+        let len_format = self.format.as_ref().map(|b| b.array.len());
+        let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
+        let len_value_range = self.value_range.as_ref().map(|b| b.array.len());
+        let len_opacity = self.opacity.as_ref().map(|b| b.array.len());
+        let len_draw_order = self.draw_order.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_buffer)
+            .or(len_format)
+            .or(len_colormap)
+            .or(len_value_range)
+            .or(len_opacity)
+            .or(len_draw_order)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The values of the heatmap, in row-major order.
+    #[inline]
+    pub fn with_buffer(mut self, buffer: impl Into<crate::components::ImageBuffer>) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), [buffer]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageBuffer`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_buffer`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_buffer(
+        mut self,
+        buffer: impl IntoIterator<Item = impl Into<crate::components::ImageBuffer>>,
+    ) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), buffer);
+        self
+    }
+
+    /// The format of the heatmap, i.e. its width, height and datatype.
+    #[inline]
+    pub fn with_format(mut self, format: impl Into<crate::components::ImageFormat>) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), [format]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageFormat`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_format`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_format(
+        mut self,
+        format: impl IntoIterator<Item = impl Into<crate::components::ImageFormat>>,
+    ) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), format);
+        self
+    }
+
+    /// Colormap used to map the values to colors.
+    ///
+    /// If not set, the heatmap will be rendered using the Viridis colormap.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Colormap`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_colormap`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_colormap(
+        mut self,
+        colormap: impl IntoIterator<Item = impl Into<crate::components::Colormap>>,
+    ) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), colormap);
+        self
+    }
+
+    /// The range of values that is mapped onto the colormap.
+    ///
+    /// Values outside of the range are clamped to the range for the purpose of colormapping.
+    ///
+    /// If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+    #[inline]
+    pub fn with_value_range(
+        mut self,
+        value_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), [value_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ValueRange`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_value_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_value_range(
+        mut self,
+        value_range: impl IntoIterator<Item = impl Into<crate::components::ValueRange>>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), value_range);
+        self
+    }
+
+    /// Opacity of the heatmap, useful for layering it on top of an image.
+    ///
+    /// Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Opacity`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_opacity`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_opacity(
+        mut self,
+        opacity: impl IntoIterator<Item = impl Into<crate::components::Opacity>>,
+    ) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), opacity);
+        self
+    }
+
+    /// An optional floating point value that specifies the 2D drawing order.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    /// Defaults to `-5.0`.
+    #[inline]
+    pub fn with_draw_order(mut self, draw_order: impl Into<crate::components::DrawOrder>) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), [draw_order]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::DrawOrder`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_draw_order`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_draw_order(
+        mut self,
+        draw_order: impl IntoIterator<Item = impl Into<crate::components::DrawOrder>>,
+    ) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), draw_order);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Heatmap {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.buffer.heap_size_bytes()
+            + self.format.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.value_range.heap_size_bytes()
+            + self.opacity.heap_size_bytes()
+            + self.draw_order.heap_size_bytes()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    components::{ImageBuffer, ImageFormat},
+    datatypes::{ChannelDatatype, TensorData},
+    image::{blob_and_datatype_from_tensor, find_non_empty_dim_indices, ImageConstructionError},
+};
+
+use super::Heatmap;
+
+impl Heatmap {
+    /// Try to construct a [`Heatmap`] from anything that can be converted into [`TensorData`]
+    ///
+    /// Will return an [`ImageConstructionError`] if the shape of the tensor data is not two-dimensional.
+    ///
+    /// This is useful for constructing a [`Heatmap`] from an ndarray.
+    pub fn try_from<T: TryInto<TensorData>>(data: T) -> Result<Self, ImageConstructionError<T>>
+    where
+        <T as TryInto<TensorData>>::Error: std::error::Error,
+    {
+        let tensor_data: TensorData = data
+            .try_into()
+            .map_err(ImageConstructionError::TensorDataConversion)?;
+        let TensorData { shape, buffer, .. } = tensor_data;
+
+        let non_empty_dim_inds = find_non_empty_dim_indices(&shape);
+
+        if non_empty_dim_inds.len() != 2 {
+            return Err(ImageConstructionError::BadImageShape(shape));
+        }
+
+        let (blob, datatype) = blob_and_datatype_from_tensor(buffer);
+
+        let (height, width) = (shape[non_empty_dim_inds[0]], shape[non_empty_dim_inds[1]]);
+
+        let image_format = ImageFormat::heatmap([width as u32, height as u32], datatype);
+
+        Ok(Self::new(blob, image_format))
+    }
+
+    /// Construct a heatmap from `width * height` values in row-major order.
+    pub fn from_values([width, height]: [u32; 2], values: &[f32]) -> Self {
+        let image_format = ImageFormat::heatmap([width, height], ChannelDatatype::F32);
+
+        let num_expected_values = width as usize * height as usize;
+        if values.len() != num_expected_values {
+            re_log::warn_once!(
+                "Expected {width}x{height} heatmap to have {num_expected_values} values, but got {}",
+                values.len()
+            );
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(values);
+        Self::new(ImageBuffer(bytes.into()), image_format)
+    }
+}
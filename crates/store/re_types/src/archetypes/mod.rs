@@ -9,6 +9,8 @@ mod asset3d;
 mod asset3d_ext;
 mod asset_video;
 mod asset_video_ext;
+mod attachment;
+mod attachment_ext;
 mod bar_chart;
 mod boxes2d;
 mod boxes2d_ext;
@@ -31,6 +33,8 @@ mod geo_points_ext;
 mod graph_edges;
 mod graph_edges_ext;
 mod graph_nodes;
+mod heatmap;
+mod heatmap_ext;
 mod image;
 mod image_ext;
 mod instance_poses3d;
@@ -71,6 +75,7 @@ pub use self::arrows2d::Arrows2D;
 pub use self::arrows3d::Arrows3D;
 pub use self::asset3d::Asset3D;
 pub use self::asset_video::AssetVideo;
+pub use self::attachment::Attachment;
 pub use self::bar_chart::BarChart;
 pub use self::boxes2d::Boxes2D;
 pub use self::boxes3d::Boxes3D;
@@ -83,6 +88,7 @@ pub use self::geo_line_strings::GeoLineStrings;
 pub use self::geo_points::GeoPoints;
 pub use self::graph_edges::GraphEdges;
 pub use self::graph_nodes::GraphNodes;
+pub use self::heatmap::Heatmap;
 pub use self::image::Image;
 pub use self::instance_poses3d::InstancePoses3D;
 pub use self::line_strips2d::LineStrips2D;
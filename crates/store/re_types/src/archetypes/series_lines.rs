@@ -105,6 +105,14 @@ pub struct SeriesLines {
     /// May change over time, but can cause discontinuities in the line.
     pub visible_series: Option<SerializedComponentBatch>,
 
+    /// Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+    ///
+    /// If not set, all lines on this entity use the primary Y axis.
+    /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    ///
+    /// Expected to be unchanging over time.
+    pub secondary_axis: Option<SerializedComponentBatch>,
+
     /// Configures the zoom-dependent scalar aggregation.
     ///
     /// This is done only if steps on the X axis go below a single pixel,
@@ -164,6 +172,18 @@ impl SeriesLines {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::secondary_axis`].
+    ///
+    /// The corresponding component is [`crate::components::SeriesSecondaryAxis`].
+    #[inline]
+    pub fn descriptor_secondary_axis() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.SeriesLines".into()),
+            component: "SeriesLines:secondary_axis".into(),
+            component_type: Some("rerun.components.SeriesSecondaryAxis".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::aggregation_policy`].
     ///
     /// The corresponding component is [`crate::components::AggregationPolicy`].
@@ -183,31 +203,33 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesLines::descriptor_colors(),
             SeriesLines::descriptor_widths(),
             SeriesLines::descriptor_names(),
             SeriesLines::descriptor_visible_series(),
+            SeriesLines::descriptor_secondary_axis(),
             SeriesLines::descriptor_aggregation_policy(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesLines::descriptor_colors(),
             SeriesLines::descriptor_widths(),
             SeriesLines::descriptor_names(),
             SeriesLines::descriptor_visible_series(),
+            SeriesLines::descriptor_secondary_axis(),
             SeriesLines::descriptor_aggregation_policy(),
         ]
     });
 
 impl SeriesLines {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for SeriesLines {
@@ -262,6 +284,11 @@ impl ::re_types_core::Archetype for SeriesLines {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_visible_series())
             });
+        let secondary_axis = arrays_by_descr
+            .get(&Self::descriptor_secondary_axis())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_secondary_axis())
+            });
         let aggregation_policy = arrays_by_descr
             .get(&Self::descriptor_aggregation_policy())
             .map(|array| {
@@ -272,6 +299,7 @@ impl ::re_types_core::Archetype for SeriesLines {
             widths,
             names,
             visible_series,
+            secondary_axis,
             aggregation_policy,
         })
     }
@@ -286,6 +314,7 @@ impl ::re_types_core::AsComponents for SeriesLines {
             self.widths.clone(),
             self.names.clone(),
             self.visible_series.clone(),
+            self.secondary_axis.clone(),
             self.aggregation_policy.clone(),
         ]
         .into_iter()
@@ -305,6 +334,7 @@ impl SeriesLines {
             widths: None,
             names: None,
             visible_series: None,
+            secondary_axis: None,
             aggregation_policy: None,
         }
     }
@@ -336,6 +366,10 @@ impl SeriesLines {
                 crate::components::SeriesVisible::arrow_empty(),
                 Self::descriptor_visible_series(),
             )),
+            secondary_axis: Some(SerializedComponentBatch::new(
+                crate::components::SeriesSecondaryAxis::arrow_empty(),
+                Self::descriptor_secondary_axis(),
+            )),
             aggregation_policy: Some(SerializedComponentBatch::new(
                 crate::components::AggregationPolicy::arrow_empty(),
                 Self::descriptor_aggregation_policy(),
@@ -374,6 +408,9 @@ impl SeriesLines {
             self.visible_series
                 .map(|visible_series| visible_series.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.secondary_axis
+                .map(|secondary_axis| secondary_axis.partitioned(_lengths.clone()))
+                .transpose()?,
             self.aggregation_policy
                 .map(|aggregation_policy| aggregation_policy.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -393,12 +430,14 @@ impl SeriesLines {
         let len_widths = self.widths.as_ref().map(|b| b.array.len());
         let len_names = self.names.as_ref().map(|b| b.array.len());
         let len_visible_series = self.visible_series.as_ref().map(|b| b.array.len());
+        let len_secondary_axis = self.secondary_axis.as_ref().map(|b| b.array.len());
         let len_aggregation_policy = self.aggregation_policy.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_colors)
             .or(len_widths)
             .or(len_names)
             .or(len_visible_series)
+            .or(len_secondary_axis)
             .or(len_aggregation_policy)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
@@ -457,6 +496,22 @@ impl SeriesLines {
         self
     }
 
+    /// Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+    ///
+    /// If not set, all lines on this entity use the primary Y axis.
+    /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    ///
+    /// Expected to be unchanging over time.
+    #[inline]
+    pub fn with_secondary_axis(
+        mut self,
+        secondary_axis: impl IntoIterator<Item = impl Into<crate::components::SeriesSecondaryAxis>>,
+    ) -> Self {
+        self.secondary_axis =
+            try_serialize_field(Self::descriptor_secondary_axis(), secondary_axis);
+        self
+    }
+
     /// Configures the zoom-dependent scalar aggregation.
     ///
     /// This is done only if steps on the X axis go below a single pixel,
@@ -496,6 +551,7 @@ impl ::re_byte_size::SizeBytes for SeriesLines {
             + self.widths.heap_size_bytes()
             + self.names.heap_size_bytes()
             + self.visible_series.heap_size_bytes()
+            + self.secondary_axis.heap_size_bytes()
             + self.aggregation_policy.heap_size_bytes()
     }
 }
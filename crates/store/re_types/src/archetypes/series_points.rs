@@ -101,6 +101,14 @@ pub struct SeriesPoints {
     /// May change over time.
     pub visible_series: Option<SerializedComponentBatch>,
 
+    /// Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+    ///
+    /// If not set, all points on this entity use the primary Y axis.
+    /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    ///
+    /// Expected to be unchanging over time.
+    pub secondary_axis: Option<SerializedComponentBatch>,
+
     /// Sizes of the markers.
     ///
     /// May change over time.
@@ -156,6 +164,18 @@ impl SeriesPoints {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::secondary_axis`].
+    ///
+    /// The corresponding component is [`crate::components::SeriesSecondaryAxis`].
+    #[inline]
+    pub fn descriptor_secondary_axis() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.SeriesPoints".into()),
+            component: "SeriesPoints:secondary_axis".into(),
+            component_type: Some("rerun.components.SeriesSecondaryAxis".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::marker_sizes`].
     ///
     /// The corresponding component is [`crate::components::MarkerSize`].
@@ -175,30 +195,32 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesPoints::descriptor_colors(),
             SeriesPoints::descriptor_names(),
             SeriesPoints::descriptor_visible_series(),
+            SeriesPoints::descriptor_secondary_axis(),
             SeriesPoints::descriptor_marker_sizes(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 6usize]> =
     std::sync::LazyLock::new(|| {
         [
             SeriesPoints::descriptor_markers(),
             SeriesPoints::descriptor_colors(),
             SeriesPoints::descriptor_names(),
             SeriesPoints::descriptor_visible_series(),
+            SeriesPoints::descriptor_secondary_axis(),
             SeriesPoints::descriptor_marker_sizes(),
         ]
     });
 
 impl SeriesPoints {
-    /// The total number of components in the archetype: 1 required, 0 recommended, 4 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 1 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 impl ::re_types_core::Archetype for SeriesPoints {
@@ -253,6 +275,11 @@ impl ::re_types_core::Archetype for SeriesPoints {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_visible_series())
             });
+        let secondary_axis = arrays_by_descr
+            .get(&Self::descriptor_secondary_axis())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_secondary_axis())
+            });
         let marker_sizes = arrays_by_descr
             .get(&Self::descriptor_marker_sizes())
             .map(|array| {
@@ -263,6 +290,7 @@ impl ::re_types_core::Archetype for SeriesPoints {
             markers,
             names,
             visible_series,
+            secondary_axis,
             marker_sizes,
         })
     }
@@ -277,6 +305,7 @@ impl ::re_types_core::AsComponents for SeriesPoints {
             self.markers.clone(),
             self.names.clone(),
             self.visible_series.clone(),
+            self.secondary_axis.clone(),
             self.marker_sizes.clone(),
         ]
         .into_iter()
@@ -296,6 +325,7 @@ impl SeriesPoints {
             markers: None,
             names: None,
             visible_series: None,
+            secondary_axis: None,
             marker_sizes: None,
         }
     }
@@ -327,6 +357,10 @@ impl SeriesPoints {
                 crate::components::SeriesVisible::arrow_empty(),
                 Self::descriptor_visible_series(),
             )),
+            secondary_axis: Some(SerializedComponentBatch::new(
+                crate::components::SeriesSecondaryAxis::arrow_empty(),
+                Self::descriptor_secondary_axis(),
+            )),
             marker_sizes: Some(SerializedComponentBatch::new(
                 crate::components::MarkerSize::arrow_empty(),
                 Self::descriptor_marker_sizes(),
@@ -365,6 +399,9 @@ impl SeriesPoints {
             self.visible_series
                 .map(|visible_series| visible_series.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.secondary_axis
+                .map(|secondary_axis| secondary_axis.partitioned(_lengths.clone()))
+                .transpose()?,
             self.marker_sizes
                 .map(|marker_sizes| marker_sizes.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -384,12 +421,14 @@ impl SeriesPoints {
         let len_markers = self.markers.as_ref().map(|b| b.array.len());
         let len_names = self.names.as_ref().map(|b| b.array.len());
         let len_visible_series = self.visible_series.as_ref().map(|b| b.array.len());
+        let len_secondary_axis = self.secondary_axis.as_ref().map(|b| b.array.len());
         let len_marker_sizes = self.marker_sizes.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_colors)
             .or(len_markers)
             .or(len_names)
             .or(len_visible_series)
+            .or(len_secondary_axis)
             .or(len_marker_sizes)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
@@ -448,6 +487,22 @@ impl SeriesPoints {
         self
     }
 
+    /// Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+    ///
+    /// If not set, all points on this entity use the primary Y axis.
+    /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    ///
+    /// Expected to be unchanging over time.
+    #[inline]
+    pub fn with_secondary_axis(
+        mut self,
+        secondary_axis: impl IntoIterator<Item = impl Into<crate::components::SeriesSecondaryAxis>>,
+    ) -> Self {
+        self.secondary_axis =
+            try_serialize_field(Self::descriptor_secondary_axis(), secondary_axis);
+        self
+    }
+
     /// Sizes of the markers.
     ///
     /// May change over time.
@@ -468,6 +523,7 @@ impl ::re_byte_size::SizeBytes for SeriesPoints {
             + self.markers.heap_size_bytes()
             + self.names.heap_size_bytes()
             + self.visible_series.heap_size_bytes()
+            + self.secondary_axis.heap_size_bytes()
             + self.marker_sizes.heap_size_bytes()
     }
 }
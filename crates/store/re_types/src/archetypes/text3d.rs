@@ -0,0 +1,399 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/text3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Text labels placed at positions in 3D space.
+///
+/// The labels always face the camera. Their size is either given in UI points, in which case
+/// they keep the same size on screen, or in scene units, in which case they get smaller as
+/// the camera moves away from them.
+///
+/// Unlike the `labels` of other archetypes, these labels are not attached to any other geometry.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Text3D {
+    /// The 3D positions of the labels.
+    pub positions: Option<SerializedComponentBatch>,
+
+    /// The text of each label.
+    pub texts: Option<SerializedComponentBatch>,
+
+    /// Optional font sizes for the labels.
+    ///
+    /// Positive values are in scene units, negative values in UI points.
+    pub font_sizes: Option<SerializedComponentBatch>,
+
+    /// Optional colors for the labels.
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// Which point of each label is placed at its position.
+    ///
+    /// Defaults to the center of the label.
+    pub anchors: Option<SerializedComponentBatch>,
+}
+
+impl Text3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::positions`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_positions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Text3D".into()),
+            component: "Text3D:positions".into(),
+            component_type: Some("rerun.components.Position3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::texts`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_texts() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Text3D".into()),
+            component: "Text3D:texts".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::font_sizes`].
+    ///
+    /// The corresponding component is [`crate::components::FontSize`].
+    #[inline]
+    pub fn descriptor_font_sizes() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Text3D".into()),
+            component: "Text3D:font_sizes".into(),
+            component_type: Some("rerun.components.FontSize".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Text3D".into()),
+            component: "Text3D:colors".into(),
+            component_type: Some("rerun.components.Color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::anchors`].
+    ///
+    /// The corresponding component is [`crate::components::TextAnchor`].
+    #[inline]
+    pub fn descriptor_anchors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Text3D".into()),
+            component: "Text3D:anchors".into(),
+            component_type: Some("rerun.components.TextAnchor".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| [Text3D::descriptor_positions(), Text3D::descriptor_texts()]);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| [Text3D::descriptor_font_sizes(), Text3D::descriptor_colors()]);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [Text3D::descriptor_anchors()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Text3D::descriptor_positions(),
+            Text3D::descriptor_texts(),
+            Text3D::descriptor_font_sizes(),
+            Text3D::descriptor_colors(),
+            Text3D::descriptor_anchors(),
+        ]
+    });
+
+impl Text3D {
+    /// The total number of components in the archetype: 2 required, 2 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for Text3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.Text3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let positions = arrays_by_descr
+            .get(&Self::descriptor_positions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_positions())
+            });
+        let texts = arrays_by_descr
+            .get(&Self::descriptor_texts())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_texts()));
+        let font_sizes = arrays_by_descr
+            .get(&Self::descriptor_font_sizes())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_font_sizes())
+            });
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let anchors = arrays_by_descr
+            .get(&Self::descriptor_anchors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_anchors()));
+        Ok(Self {
+            positions,
+            texts,
+            font_sizes,
+            colors,
+            anchors,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Text3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.positions.clone(),
+            self.texts.clone(),
+            self.font_sizes.clone(),
+            self.colors.clone(),
+            self.anchors.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Text3D {}
+
+impl Text3D {
+    /// Create a new `Text3D`.
+    #[inline]
+    pub fn new(
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+        texts: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        Self {
+            positions: try_serialize_field(Self::descriptor_positions(), positions),
+            texts: try_serialize_field(Self::descriptor_texts(), texts),
+            font_sizes: None,
+            colors: None,
+            anchors: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Text3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Text3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            positions: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_positions(),
+            )),
+            texts: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_texts(),
+            )),
+            font_sizes: Some(SerializedComponentBatch::new(
+                crate::components::FontSize::arrow_empty(),
+                Self::descriptor_font_sizes(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            anchors: Some(SerializedComponentBatch::new(
+                crate::components::TextAnchor::arrow_empty(),
+                Self::descriptor_anchors(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.positions
+                .map(|positions| positions.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.texts
+                .map(|texts| texts.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.font_sizes
+                .map(|font_sizes| font_sizes.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.anchors
+                .map(|anchors| anchors.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns.into_iter().flatten())
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_positions = self.positions.as_ref().map(|b| b.array.len());
+        let len_texts = self.texts.as_ref().map(|b| b.array.len());
+        let len_font_sizes = self.font_sizes.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_anchors = self.anchors.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_positions)
+            .or(len_texts)
+            .or(len_font_sizes)
+            .or(len_colors)
+            .or(len_anchors)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat_n(1, len))
+    }
+
+    /// The 3D positions of the labels.
+    #[inline]
+    pub fn with_positions(
+        mut self,
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.positions = try_serialize_field(Self::descriptor_positions(), positions);
+        self
+    }
+
+    /// The text of each label.
+    #[inline]
+    pub fn with_texts(
+        mut self,
+        texts: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.texts = try_serialize_field(Self::descriptor_texts(), texts);
+        self
+    }
+
+    /// Optional font sizes for the labels.
+    ///
+    /// Positive values are in scene units, negative values in UI points.
+    #[inline]
+    pub fn with_font_sizes(
+        mut self,
+        font_sizes: impl IntoIterator<Item = impl Into<crate::components::FontSize>>,
+    ) -> Self {
+        self.font_sizes = try_serialize_field(Self::descriptor_font_sizes(), font_sizes);
+        self
+    }
+
+    /// Optional colors for the labels.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// Which point of each label is placed at its position.
+    ///
+    /// Defaults to the center of the label.
+    #[inline]
+    pub fn with_anchors(
+        mut self,
+        anchors: impl IntoIterator<Item = impl Into<crate::components::TextAnchor>>,
+    ) -> Self {
+        self.anchors = try_serialize_field(Self::descriptor_anchors(), anchors);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Text3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.positions.heap_size_bytes()
+            + self.texts.heap_size_bytes()
+            + self.font_sizes.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.anchors.heap_size_bytes()
+    }
+}
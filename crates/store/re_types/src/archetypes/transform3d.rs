@@ -352,6 +352,12 @@ pub struct Transform3D {
     /// Specifies the relation this transform establishes between this entity and its parent.
     pub relation: Option<SerializedComponentBatch>,
 
+    /// How the transform is interpolated between logged samples.
+    ///
+    /// By default, the latest transform logged before the queried time is used.
+    /// With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
+    pub interpolation: Option<SerializedComponentBatch>,
+
     /// Visual length of the 3 axes.
     ///
     /// The length is interpreted in the local coordinate system of the transform.
@@ -432,6 +438,18 @@ impl Transform3D {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::interpolation`].
+    ///
+    /// The corresponding component is [`crate::components::TransformInterpolation`].
+    #[inline]
+    pub fn descriptor_interpolation() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.Transform3D".into()),
+            component: "Transform3D:interpolation".into(),
+            component_type: Some("rerun.components.TransformInterpolation".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::axis_length`].
     ///
     /// The corresponding component is [`crate::components::AxisLength`].
@@ -451,7 +469,7 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             Transform3D::descriptor_translation(),
@@ -460,11 +478,12 @@ static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
+            Transform3D::descriptor_interpolation(),
             Transform3D::descriptor_axis_length(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 8usize]> =
     std::sync::LazyLock::new(|| {
         [
             Transform3D::descriptor_translation(),
@@ -473,13 +492,14 @@ static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 7usize]> =
             Transform3D::descriptor_scale(),
             Transform3D::descriptor_mat3x3(),
             Transform3D::descriptor_relation(),
+            Transform3D::descriptor_interpolation(),
             Transform3D::descriptor_axis_length(),
         ]
     });
 
 impl Transform3D {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 7 optional
-    pub const NUM_COMPONENTS: usize = 7usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 8 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 impl ::re_types_core::Archetype for Transform3D {
@@ -544,6 +564,11 @@ impl ::re_types_core::Archetype for Transform3D {
         let relation = arrays_by_descr
             .get(&Self::descriptor_relation())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_relation()));
+        let interpolation = arrays_by_descr
+            .get(&Self::descriptor_interpolation())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_interpolation())
+            });
         let axis_length = arrays_by_descr
             .get(&Self::descriptor_axis_length())
             .map(|array| {
@@ -556,6 +581,7 @@ impl ::re_types_core::Archetype for Transform3D {
             scale,
             mat3x3,
             relation,
+            interpolation,
             axis_length,
         })
     }
@@ -572,6 +598,7 @@ impl ::re_types_core::AsComponents for Transform3D {
             self.scale.clone(),
             self.mat3x3.clone(),
             self.relation.clone(),
+            self.interpolation.clone(),
             self.axis_length.clone(),
         ]
         .into_iter()
@@ -618,6 +645,10 @@ impl Transform3D {
                 crate::components::TransformRelation::arrow_empty(),
                 Self::descriptor_relation(),
             )),
+            interpolation: Some(SerializedComponentBatch::new(
+                crate::components::TransformInterpolation::arrow_empty(),
+                Self::descriptor_interpolation(),
+            )),
             axis_length: Some(SerializedComponentBatch::new(
                 crate::components::AxisLength::arrow_empty(),
                 Self::descriptor_axis_length(),
@@ -662,6 +693,9 @@ impl Transform3D {
             self.relation
                 .map(|relation| relation.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.interpolation
+                .map(|interpolation| interpolation.partitioned(_lengths.clone()))
+                .transpose()?,
             self.axis_length
                 .map(|axis_length| axis_length.partitioned(_lengths.clone()))
                 .transpose()?,
@@ -683,6 +717,7 @@ impl Transform3D {
         let len_scale = self.scale.as_ref().map(|b| b.array.len());
         let len_mat3x3 = self.mat3x3.as_ref().map(|b| b.array.len());
         let len_relation = self.relation.as_ref().map(|b| b.array.len());
+        let len_interpolation = self.interpolation.as_ref().map(|b| b.array.len());
         let len_axis_length = self.axis_length.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_translation)
@@ -691,6 +726,7 @@ impl Transform3D {
             .or(len_scale)
             .or(len_mat3x3)
             .or(len_relation)
+            .or(len_interpolation)
             .or(len_axis_length)
             .unwrap_or(0);
         self.columns(std::iter::repeat_n(1, len))
@@ -832,6 +868,32 @@ impl Transform3D {
         self
     }
 
+    /// How the transform is interpolated between logged samples.
+    ///
+    /// By default, the latest transform logged before the queried time is used.
+    /// With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
+    #[inline]
+    pub fn with_interpolation(
+        mut self,
+        interpolation: impl Into<crate::components::TransformInterpolation>,
+    ) -> Self {
+        self.interpolation = try_serialize_field(Self::descriptor_interpolation(), [interpolation]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::TransformInterpolation`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_interpolation`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_interpolation(
+        mut self,
+        interpolation: impl IntoIterator<Item = impl Into<crate::components::TransformInterpolation>>,
+    ) -> Self {
+        self.interpolation = try_serialize_field(Self::descriptor_interpolation(), interpolation);
+        self
+    }
+
     /// Visual length of the 3 axes.
     ///
     /// The length is interpreted in the local coordinate system of the transform.
@@ -868,6 +930,7 @@ impl ::re_byte_size::SizeBytes for Transform3D {
             + self.scale.heap_size_bytes()
             + self.mat3x3.heap_size_bytes()
             + self.relation.heap_size_bytes()
+            + self.interpolation.heap_size_bytes()
             + self.axis_length.heap_size_bytes()
     }
 }
@@ -17,6 +17,7 @@ impl Transform3D {
         scale: None,
         mat3x3: None,
         relation: None,
+        interpolation: None,
         axis_length: None,
     };
 
@@ -0,0 +1,161 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_controller3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: The camera controller that moves the eye of a 3D view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct CameraController3D {
+    /// Identifier of the controller, e.g. `orbit` or `fly`.
+    ///
+    /// If not set, the eye controls of the view are used.
+    pub controller: Option<SerializedComponentBatch>,
+}
+
+impl CameraController3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::controller`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_controller() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraController3D".into()),
+            component: "CameraController3D:controller".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [CameraController3D::descriptor_controller()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [CameraController3D::descriptor_controller()]);
+
+impl CameraController3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for CameraController3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.CameraController3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Camera controller 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let controller = arrays_by_descr
+            .get(&Self::descriptor_controller())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_controller())
+            });
+        Ok(Self { controller })
+    }
+}
+
+impl ::re_types_core::AsComponents for CameraController3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.controller.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CameraController3D {}
+
+impl CameraController3D {
+    /// Create a new `CameraController3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { controller: None }
+    }
+
+    /// Update only some specific fields of a `CameraController3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CameraController3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            controller: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_controller(),
+            )),
+        }
+    }
+
+    /// Identifier of the controller, e.g. `orbit` or `fly`.
+    ///
+    /// If not set, the eye controls of the view are used.
+    #[inline]
+    pub fn with_controller(mut self, controller: impl Into<crate::components::Name>) -> Self {
+        self.controller = try_serialize_field(Self::descriptor_controller(), [controller]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CameraController3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.controller.heap_size_bytes()
+    }
+}
@@ -0,0 +1,348 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Keyframes of the camera of a 3D view, to fly through the scene along them.
+///
+/// All fields have one instance per keyframe, sorted by time.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct CameraPath3D {
+    /// Time of each keyframe.
+    ///
+    /// On the timeline if one is set, otherwise in seconds since the start of the playback.
+    pub times: Option<SerializedComponentBatch>,
+
+    /// Position of the camera at each keyframe, in the space of the view.
+    pub positions: Option<SerializedComponentBatch>,
+
+    /// Rotation of the camera at each keyframe, in the space of the view.
+    pub rotations: Option<SerializedComponentBatch>,
+
+    /// Vertical field of view of the camera at each keyframe, in radians.
+    ///
+    /// NaN for an orthographic camera.
+    pub fields_of_view: Option<SerializedComponentBatch>,
+
+    /// The timeline the keyframe times are on.
+    ///
+    /// If not set, the keyframe times are wall-clock seconds since the start of the playback.
+    pub timeline: Option<SerializedComponentBatch>,
+}
+
+impl CameraPath3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::times`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_times() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraPath3D".into()),
+            component: "CameraPath3D:times".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::positions`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_positions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraPath3D".into()),
+            component: "CameraPath3D:positions".into(),
+            component_type: Some("rerun.components.Position3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::rotations`].
+    ///
+    /// The corresponding component is [`crate::components::RotationQuat`].
+    #[inline]
+    pub fn descriptor_rotations() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraPath3D".into()),
+            component: "CameraPath3D:rotations".into(),
+            component_type: Some("rerun.components.RotationQuat".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::fields_of_view`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_fields_of_view() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraPath3D".into()),
+            component: "CameraPath3D:fields_of_view".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::timeline`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimelineName`].
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.CameraPath3D".into()),
+            component: "CameraPath3D:timeline".into(),
+            component_type: Some("rerun.blueprint.components.TimelineName".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CameraPath3D::descriptor_times(),
+            CameraPath3D::descriptor_positions(),
+            CameraPath3D::descriptor_rotations(),
+            CameraPath3D::descriptor_fields_of_view(),
+            CameraPath3D::descriptor_timeline(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            CameraPath3D::descriptor_times(),
+            CameraPath3D::descriptor_positions(),
+            CameraPath3D::descriptor_rotations(),
+            CameraPath3D::descriptor_fields_of_view(),
+            CameraPath3D::descriptor_timeline(),
+        ]
+    });
+
+impl CameraPath3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for CameraPath3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.CameraPath3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Camera path 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let times = arrays_by_descr
+            .get(&Self::descriptor_times())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_times()));
+        let positions = arrays_by_descr
+            .get(&Self::descriptor_positions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_positions())
+            });
+        let rotations = arrays_by_descr
+            .get(&Self::descriptor_rotations())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_rotations())
+            });
+        let fields_of_view = arrays_by_descr
+            .get(&Self::descriptor_fields_of_view())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_fields_of_view())
+            });
+        let timeline = arrays_by_descr
+            .get(&Self::descriptor_timeline())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_timeline()));
+        Ok(Self {
+            times,
+            positions,
+            rotations,
+            fields_of_view,
+            timeline,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for CameraPath3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.times.clone(),
+            self.positions.clone(),
+            self.rotations.clone(),
+            self.fields_of_view.clone(),
+            self.timeline.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CameraPath3D {}
+
+impl CameraPath3D {
+    /// Create a new `CameraPath3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            times: None,
+            positions: None,
+            rotations: None,
+            fields_of_view: None,
+            timeline: None,
+        }
+    }
+
+    /// Update only some specific fields of a `CameraPath3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CameraPath3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            times: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_times(),
+            )),
+            positions: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_positions(),
+            )),
+            rotations: Some(SerializedComponentBatch::new(
+                crate::components::RotationQuat::arrow_empty(),
+                Self::descriptor_rotations(),
+            )),
+            fields_of_view: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_fields_of_view(),
+            )),
+            timeline: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimelineName::arrow_empty(),
+                Self::descriptor_timeline(),
+            )),
+        }
+    }
+
+    /// Time of each keyframe.
+    ///
+    /// On the timeline if one is set, otherwise in seconds since the start of the playback.
+    #[inline]
+    pub fn with_times(
+        mut self,
+        times: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.times = try_serialize_field(Self::descriptor_times(), times);
+        self
+    }
+
+    /// Position of the camera at each keyframe, in the space of the view.
+    #[inline]
+    pub fn with_positions(
+        mut self,
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.positions = try_serialize_field(Self::descriptor_positions(), positions);
+        self
+    }
+
+    /// Rotation of the camera at each keyframe, in the space of the view.
+    #[inline]
+    pub fn with_rotations(
+        mut self,
+        rotations: impl IntoIterator<Item = impl Into<crate::components::RotationQuat>>,
+    ) -> Self {
+        self.rotations = try_serialize_field(Self::descriptor_rotations(), rotations);
+        self
+    }
+
+    /// Vertical field of view of the camera at each keyframe, in radians.
+    ///
+    /// NaN for an orthographic camera.
+    #[inline]
+    pub fn with_fields_of_view(
+        mut self,
+        fields_of_view: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.fields_of_view =
+            try_serialize_field(Self::descriptor_fields_of_view(), fields_of_view);
+        self
+    }
+
+    /// The timeline the keyframe times are on.
+    ///
+    /// If not set, the keyframe times are wall-clock seconds since the start of the playback.
+    #[inline]
+    pub fn with_timeline(
+        mut self,
+        timeline: impl Into<crate::blueprint::components::TimelineName>,
+    ) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), [timeline]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CameraPath3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.times.heap_size_bytes()
+            + self.positions.heap_size_bytes()
+            + self.rotations.heap_size_bytes()
+            + self.fields_of_view.heap_size_bytes()
+            + self.timeline.heap_size_bytes()
+    }
+}
@@ -0,0 +1,215 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/follow_latest.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Whether a view always shows the newest data on the selected timeline, wherever the time cursor is.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct FollowLatest {
+    /// Whether the view follows the latest data.
+    ///
+    /// Defaults to false.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// The entity the eye of a 3D view tracks while following the latest data.
+    pub camera_entity: Option<SerializedComponentBatch>,
+}
+
+impl FollowLatest {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.FollowLatest".into()),
+            component: "FollowLatest:enabled".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::camera_entity`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_camera_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.FollowLatest".into()),
+            component: "FollowLatest:camera_entity".into(),
+            component_type: Some("rerun.components.EntityPath".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            FollowLatest::descriptor_enabled(),
+            FollowLatest::descriptor_camera_entity(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            FollowLatest::descriptor_enabled(),
+            FollowLatest::descriptor_camera_entity(),
+        ]
+    });
+
+impl FollowLatest {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for FollowLatest {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.FollowLatest".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Follow latest"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let camera_entity = arrays_by_descr
+            .get(&Self::descriptor_camera_entity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_camera_entity())
+            });
+        Ok(Self {
+            enabled,
+            camera_entity,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for FollowLatest {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.enabled.clone(), self.camera_entity.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for FollowLatest {}
+
+impl FollowLatest {
+    /// Create a new `FollowLatest`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            camera_entity: None,
+        }
+    }
+
+    /// Update only some specific fields of a `FollowLatest`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `FollowLatest`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            camera_entity: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_camera_entity(),
+            )),
+        }
+    }
+
+    /// Whether the view follows the latest data.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// The entity the eye of a 3D view tracks while following the latest data.
+    #[inline]
+    pub fn with_camera_entity(
+        mut self,
+        camera_entity: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.camera_entity = try_serialize_field(Self::descriptor_camera_entity(), [camera_entity]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for FollowLatest {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes() + self.camera_entity.heap_size_bytes()
+    }
+}
@@ -0,0 +1,161 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout_algorithm.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: The algorithm that lays out the nodes of a graph view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct GraphLayoutAlgorithm {
+    /// Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+    ///
+    /// If not set, the force-based layout is used.
+    pub algorithm: Option<SerializedComponentBatch>,
+}
+
+impl GraphLayoutAlgorithm {
+    /// Returns the [`ComponentDescriptor`] for [`Self::algorithm`].
+    ///
+    /// The corresponding component is [`crate::components::Name`].
+    #[inline]
+    pub fn descriptor_algorithm() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GraphLayoutAlgorithm".into()),
+            component: "GraphLayoutAlgorithm:algorithm".into(),
+            component_type: Some("rerun.components.Name".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [GraphLayoutAlgorithm::descriptor_algorithm()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [GraphLayoutAlgorithm::descriptor_algorithm()]);
+
+impl GraphLayoutAlgorithm {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for GraphLayoutAlgorithm {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.GraphLayoutAlgorithm".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Graph layout algorithm"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let algorithm = arrays_by_descr
+            .get(&Self::descriptor_algorithm())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_algorithm())
+            });
+        Ok(Self { algorithm })
+    }
+}
+
+impl ::re_types_core::AsComponents for GraphLayoutAlgorithm {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.algorithm.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GraphLayoutAlgorithm {}
+
+impl GraphLayoutAlgorithm {
+    /// Create a new `GraphLayoutAlgorithm`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { algorithm: None }
+    }
+
+    /// Update only some specific fields of a `GraphLayoutAlgorithm`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GraphLayoutAlgorithm`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            algorithm: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_algorithm(),
+            )),
+        }
+    }
+
+    /// Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+    ///
+    /// If not set, the force-based layout is used.
+    #[inline]
+    pub fn with_algorithm(mut self, algorithm: impl Into<crate::components::Name>) -> Self {
+        self.algorithm = try_serialize_field(Self::descriptor_algorithm(), [algorithm]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GraphLayoutAlgorithm {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.algorithm.heap_size_bytes()
+    }
+}
@@ -0,0 +1,352 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/grid_overlay2d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Rulers along the edges and a grid over the contents of a 2D view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct GridOverlay2D {
+    /// Whether the grid is drawn.
+    ///
+    /// Defaults to false.
+    pub show_grid: Option<SerializedComponentBatch>,
+
+    /// Whether the rulers are drawn.
+    ///
+    /// Defaults to false.
+    pub show_rulers: Option<SerializedComponentBatch>,
+
+    /// Distance between major lines in scene units.
+    ///
+    /// If not set, it is picked for the zoom level.
+    pub spacing: Option<SerializedComponentBatch>,
+
+    /// Number of minor cells each major cell is divided into.
+    ///
+    /// Defaults to 5.
+    pub subdivisions: Option<SerializedComponentBatch>,
+
+    /// Whether the cursor snaps to the nearest intersection of minor lines.
+    ///
+    /// Defaults to false.
+    pub snap_to_grid: Option<SerializedComponentBatch>,
+}
+
+impl GridOverlay2D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::show_grid`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_show_grid() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GridOverlay2D".into()),
+            component: "GridOverlay2D:show_grid".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::show_rulers`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_show_rulers() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GridOverlay2D".into()),
+            component: "GridOverlay2D:show_rulers".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::spacing`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::GridSpacing`].
+    #[inline]
+    pub fn descriptor_spacing() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GridOverlay2D".into()),
+            component: "GridOverlay2D:spacing".into(),
+            component_type: Some("rerun.blueprint.components.GridSpacing".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::subdivisions`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_subdivisions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GridOverlay2D".into()),
+            component: "GridOverlay2D:subdivisions".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::snap_to_grid`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_snap_to_grid() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.GridOverlay2D".into()),
+            component: "GridOverlay2D:snap_to_grid".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            GridOverlay2D::descriptor_show_grid(),
+            GridOverlay2D::descriptor_show_rulers(),
+            GridOverlay2D::descriptor_spacing(),
+            GridOverlay2D::descriptor_subdivisions(),
+            GridOverlay2D::descriptor_snap_to_grid(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            GridOverlay2D::descriptor_show_grid(),
+            GridOverlay2D::descriptor_show_rulers(),
+            GridOverlay2D::descriptor_spacing(),
+            GridOverlay2D::descriptor_subdivisions(),
+            GridOverlay2D::descriptor_snap_to_grid(),
+        ]
+    });
+
+impl GridOverlay2D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for GridOverlay2D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.GridOverlay2D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Grid overlay 2D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let show_grid = arrays_by_descr
+            .get(&Self::descriptor_show_grid())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_show_grid())
+            });
+        let show_rulers = arrays_by_descr
+            .get(&Self::descriptor_show_rulers())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_show_rulers())
+            });
+        let spacing = arrays_by_descr
+            .get(&Self::descriptor_spacing())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_spacing()));
+        let subdivisions = arrays_by_descr
+            .get(&Self::descriptor_subdivisions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_subdivisions())
+            });
+        let snap_to_grid = arrays_by_descr
+            .get(&Self::descriptor_snap_to_grid())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_snap_to_grid())
+            });
+        Ok(Self {
+            show_grid,
+            show_rulers,
+            spacing,
+            subdivisions,
+            snap_to_grid,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GridOverlay2D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.show_grid.clone(),
+            self.show_rulers.clone(),
+            self.spacing.clone(),
+            self.subdivisions.clone(),
+            self.snap_to_grid.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GridOverlay2D {}
+
+impl GridOverlay2D {
+    /// Create a new `GridOverlay2D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            show_grid: None,
+            show_rulers: None,
+            spacing: None,
+            subdivisions: None,
+            snap_to_grid: None,
+        }
+    }
+
+    /// Update only some specific fields of a `GridOverlay2D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GridOverlay2D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            show_grid: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_show_grid(),
+            )),
+            show_rulers: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_show_rulers(),
+            )),
+            spacing: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::GridSpacing::arrow_empty(),
+                Self::descriptor_spacing(),
+            )),
+            subdivisions: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_subdivisions(),
+            )),
+            snap_to_grid: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_snap_to_grid(),
+            )),
+        }
+    }
+
+    /// Whether the grid is drawn.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_show_grid(
+        mut self,
+        show_grid: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.show_grid = try_serialize_field(Self::descriptor_show_grid(), [show_grid]);
+        self
+    }
+
+    /// Whether the rulers are drawn.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_show_rulers(
+        mut self,
+        show_rulers: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.show_rulers = try_serialize_field(Self::descriptor_show_rulers(), [show_rulers]);
+        self
+    }
+
+    /// Distance between major lines in scene units.
+    ///
+    /// If not set, it is picked for the zoom level.
+    #[inline]
+    pub fn with_spacing(
+        mut self,
+        spacing: impl Into<crate::blueprint::components::GridSpacing>,
+    ) -> Self {
+        self.spacing = try_serialize_field(Self::descriptor_spacing(), [spacing]);
+        self
+    }
+
+    /// Number of minor cells each major cell is divided into.
+    ///
+    /// Defaults to 5.
+    #[inline]
+    pub fn with_subdivisions(mut self, subdivisions: impl Into<crate::components::Scalar>) -> Self {
+        self.subdivisions = try_serialize_field(Self::descriptor_subdivisions(), [subdivisions]);
+        self
+    }
+
+    /// Whether the cursor snaps to the nearest intersection of minor lines.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_snap_to_grid(
+        mut self,
+        snap_to_grid: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.snap_to_grid = try_serialize_field(Self::descriptor_snap_to_grid(), [snap_to_grid]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GridOverlay2D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.show_grid.heap_size_bytes()
+            + self.show_rulers.heap_size_bytes()
+            + self.spacing.heap_size_bytes()
+            + self.subdivisions.heap_size_bytes()
+            + self.snap_to_grid.heap_size_bytes()
+    }
+}
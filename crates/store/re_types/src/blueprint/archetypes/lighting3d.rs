@@ -0,0 +1,308 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/lighting3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: The lights meshes are shaded with in a 3D view.
+///
+/// Anything not set uses the default lighting of the viewer.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct Lighting3D {
+    /// Direction towards the key light, in the space of the view.
+    ///
+    /// Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+    pub key_light_direction: Option<SerializedComponentBatch>,
+
+    /// Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+    pub key_light_intensity: Option<SerializedComponentBatch>,
+
+    /// Brightness of the light reaching meshes from all sides.
+    pub ambient_light: Option<SerializedComponentBatch>,
+
+    /// Draws meshes in their albedo color, ignoring all lights.
+    ///
+    /// Defaults to false.
+    pub unlit: Option<SerializedComponentBatch>,
+}
+
+impl Lighting3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::key_light_direction`].
+    ///
+    /// The corresponding component is [`crate::components::Vector3D`].
+    #[inline]
+    pub fn descriptor_key_light_direction() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Lighting3D".into()),
+            component: "Lighting3D:key_light_direction".into(),
+            component_type: Some("rerun.components.Vector3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::key_light_intensity`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_key_light_intensity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Lighting3D".into()),
+            component: "Lighting3D:key_light_intensity".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::ambient_light`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_ambient_light() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Lighting3D".into()),
+            component: "Lighting3D:ambient_light".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::unlit`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_unlit() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.Lighting3D".into()),
+            component: "Lighting3D:unlit".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Lighting3D::descriptor_key_light_direction(),
+            Lighting3D::descriptor_key_light_intensity(),
+            Lighting3D::descriptor_ambient_light(),
+            Lighting3D::descriptor_unlit(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            Lighting3D::descriptor_key_light_direction(),
+            Lighting3D::descriptor_key_light_intensity(),
+            Lighting3D::descriptor_ambient_light(),
+            Lighting3D::descriptor_unlit(),
+        ]
+    });
+
+impl Lighting3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+impl ::re_types_core::Archetype for Lighting3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.Lighting3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Lighting 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let key_light_direction = arrays_by_descr
+            .get(&Self::descriptor_key_light_direction())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_key_light_direction())
+            });
+        let key_light_intensity = arrays_by_descr
+            .get(&Self::descriptor_key_light_intensity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_key_light_intensity())
+            });
+        let ambient_light = arrays_by_descr
+            .get(&Self::descriptor_ambient_light())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_ambient_light())
+            });
+        let unlit = arrays_by_descr
+            .get(&Self::descriptor_unlit())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_unlit()));
+        Ok(Self {
+            key_light_direction,
+            key_light_intensity,
+            ambient_light,
+            unlit,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for Lighting3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.key_light_direction.clone(),
+            self.key_light_intensity.clone(),
+            self.ambient_light.clone(),
+            self.unlit.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Lighting3D {}
+
+impl Lighting3D {
+    /// Create a new `Lighting3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            key_light_direction: None,
+            key_light_intensity: None,
+            ambient_light: None,
+            unlit: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Lighting3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Lighting3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            key_light_direction: Some(SerializedComponentBatch::new(
+                crate::components::Vector3D::arrow_empty(),
+                Self::descriptor_key_light_direction(),
+            )),
+            key_light_intensity: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_key_light_intensity(),
+            )),
+            ambient_light: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_ambient_light(),
+            )),
+            unlit: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_unlit(),
+            )),
+        }
+    }
+
+    /// Direction towards the key light, in the space of the view.
+    ///
+    /// Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+    #[inline]
+    pub fn with_key_light_direction(
+        mut self,
+        key_light_direction: impl Into<crate::components::Vector3D>,
+    ) -> Self {
+        self.key_light_direction = try_serialize_field(
+            Self::descriptor_key_light_direction(),
+            [key_light_direction],
+        );
+        self
+    }
+
+    /// Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+    #[inline]
+    pub fn with_key_light_intensity(
+        mut self,
+        key_light_intensity: impl Into<crate::components::Scalar>,
+    ) -> Self {
+        self.key_light_intensity = try_serialize_field(
+            Self::descriptor_key_light_intensity(),
+            [key_light_intensity],
+        );
+        self
+    }
+
+    /// Brightness of the light reaching meshes from all sides.
+    #[inline]
+    pub fn with_ambient_light(
+        mut self,
+        ambient_light: impl Into<crate::components::Scalar>,
+    ) -> Self {
+        self.ambient_light = try_serialize_field(Self::descriptor_ambient_light(), [ambient_light]);
+        self
+    }
+
+    /// Draws meshes in their albedo color, ignoring all lights.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_unlit(mut self, unlit: impl Into<crate::blueprint::components::Enabled>) -> Self {
+        self.unlit = try_serialize_field(Self::descriptor_unlit(), [unlit]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Lighting3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.key_light_direction.heap_size_bytes()
+            + self.key_light_intensity.heap_size_bytes()
+            + self.ambient_light.heap_size_bytes()
+            + self.unlit.heap_size_bytes()
+    }
+}
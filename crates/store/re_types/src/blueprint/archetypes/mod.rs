@@ -1,26 +1,38 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod background;
+mod camera_controller3d;
+mod camera_path3d;
 mod container_blueprint;
 mod dataframe_query;
 mod entity_behavior;
 mod eye_controls3d;
+mod follow_latest;
 mod force_center;
 mod force_collision_radius;
 mod force_link;
 mod force_many_body;
 mod force_position;
+mod graph_layout_algorithm;
+mod grid_overlay2d;
+mod lighting3d;
 mod line_grid3d;
 mod map_background;
 mod map_zoom;
 mod near_clip_plane;
 mod panel_blueprint;
 mod plot_legend;
+mod plot_style;
+mod point_sizing;
 mod scalar_axis;
+mod section_plane3d;
 mod tensor_scalar_mapping;
 mod tensor_slice_selection;
+mod tensor_slice_value_range;
 mod tensor_view_fit;
+mod text_log_saved_filters;
 mod time_axis;
+mod time_offset;
 mod view_blueprint;
 mod view_contents;
 mod viewport_blueprint;
@@ -29,26 +41,38 @@ mod visual_bounds2d;
 mod visualizer_overrides;
 
 pub use self::background::Background;
+pub use self::camera_controller3d::CameraController3D;
+pub use self::camera_path3d::CameraPath3D;
 pub use self::container_blueprint::ContainerBlueprint;
 pub use self::dataframe_query::DataframeQuery;
 pub use self::entity_behavior::EntityBehavior;
 pub use self::eye_controls3d::EyeControls3D;
+pub use self::follow_latest::FollowLatest;
 pub use self::force_center::ForceCenter;
 pub use self::force_collision_radius::ForceCollisionRadius;
 pub use self::force_link::ForceLink;
 pub use self::force_many_body::ForceManyBody;
 pub use self::force_position::ForcePosition;
+pub use self::graph_layout_algorithm::GraphLayoutAlgorithm;
+pub use self::grid_overlay2d::GridOverlay2D;
+pub use self::lighting3d::Lighting3D;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
 pub use self::map_zoom::MapZoom;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_blueprint::PanelBlueprint;
 pub use self::plot_legend::PlotLegend;
+pub use self::plot_style::PlotStyle;
+pub use self::point_sizing::PointSizing;
 pub use self::scalar_axis::ScalarAxis;
+pub use self::section_plane3d::SectionPlane3D;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
 pub use self::tensor_slice_selection::TensorSliceSelection;
+pub use self::tensor_slice_value_range::TensorSliceValueRange;
 pub use self::tensor_view_fit::TensorViewFit;
+pub use self::text_log_saved_filters::TextLogSavedFilters;
 pub use self::time_axis::TimeAxis;
+pub use self::time_offset::TimeOffset;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
 pub use self::viewport_blueprint::ViewportBlueprint;
@@ -0,0 +1,257 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_sizing.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: How the radii of points are interpreted and limited.
+///
+/// Set per view, and overridden per entity.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct PointSizing {
+    /// Whether radii are in ui points rather than in scene units, like meters.
+    ///
+    /// Defaults to false.
+    pub screen_space: Option<SerializedComponentBatch>,
+
+    /// Points are drawn with at least this radius, in ui points.
+    pub min_radius: Option<SerializedComponentBatch>,
+
+    /// Points are drawn with at most this radius, in ui points.
+    pub max_radius: Option<SerializedComponentBatch>,
+}
+
+impl PointSizing {
+    /// Returns the [`ComponentDescriptor`] for [`Self::screen_space`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_screen_space() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.PointSizing".into()),
+            component: "PointSizing:screen_space".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::min_radius`].
+    ///
+    /// The corresponding component is [`crate::components::Radius`].
+    #[inline]
+    pub fn descriptor_min_radius() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.PointSizing".into()),
+            component: "PointSizing:min_radius".into(),
+            component_type: Some("rerun.components.Radius".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::max_radius`].
+    ///
+    /// The corresponding component is [`crate::components::Radius`].
+    #[inline]
+    pub fn descriptor_max_radius() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.PointSizing".into()),
+            component: "PointSizing:max_radius".into(),
+            component_type: Some("rerun.components.Radius".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            PointSizing::descriptor_screen_space(),
+            PointSizing::descriptor_min_radius(),
+            PointSizing::descriptor_max_radius(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            PointSizing::descriptor_screen_space(),
+            PointSizing::descriptor_min_radius(),
+            PointSizing::descriptor_max_radius(),
+        ]
+    });
+
+impl PointSizing {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for PointSizing {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.PointSizing".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Point sizing"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let screen_space = arrays_by_descr
+            .get(&Self::descriptor_screen_space())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_screen_space())
+            });
+        let min_radius = arrays_by_descr
+            .get(&Self::descriptor_min_radius())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_min_radius())
+            });
+        let max_radius = arrays_by_descr
+            .get(&Self::descriptor_max_radius())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_max_radius())
+            });
+        Ok(Self {
+            screen_space,
+            min_radius,
+            max_radius,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for PointSizing {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.screen_space.clone(),
+            self.min_radius.clone(),
+            self.max_radius.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for PointSizing {}
+
+impl PointSizing {
+    /// Create a new `PointSizing`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            screen_space: None,
+            min_radius: None,
+            max_radius: None,
+        }
+    }
+
+    /// Update only some specific fields of a `PointSizing`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `PointSizing`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            screen_space: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_screen_space(),
+            )),
+            min_radius: Some(SerializedComponentBatch::new(
+                crate::components::Radius::arrow_empty(),
+                Self::descriptor_min_radius(),
+            )),
+            max_radius: Some(SerializedComponentBatch::new(
+                crate::components::Radius::arrow_empty(),
+                Self::descriptor_max_radius(),
+            )),
+        }
+    }
+
+    /// Whether radii are in ui points rather than in scene units, like meters.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_screen_space(
+        mut self,
+        screen_space: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.screen_space = try_serialize_field(Self::descriptor_screen_space(), [screen_space]);
+        self
+    }
+
+    /// Points are drawn with at least this radius, in ui points.
+    #[inline]
+    pub fn with_min_radius(mut self, min_radius: impl Into<crate::components::Radius>) -> Self {
+        self.min_radius = try_serialize_field(Self::descriptor_min_radius(), [min_radius]);
+        self
+    }
+
+    /// Points are drawn with at most this radius, in ui points.
+    #[inline]
+    pub fn with_max_radius(mut self, max_radius: impl Into<crate::components::Radius>) -> Self {
+        self.max_radius = try_serialize_field(Self::descriptor_max_radius(), [max_radius]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for PointSizing {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.screen_space.heap_size_bytes()
+            + self.min_radius.heap_size_bytes()
+            + self.max_radius.heap_size_bytes()
+    }
+}
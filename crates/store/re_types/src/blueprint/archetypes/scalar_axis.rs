@@ -31,6 +31,16 @@ pub struct ScalarAxis {
 
     /// If enabled, the Y axis range will remain locked to the specified range when zooming.
     pub zoom_lock: Option<SerializedComponentBatch>,
+
+    /// If enabled, the Y axis uses a logarithmic scale.
+    ///
+    /// Values that aren't positive can't be shown on a logarithmic scale and are left out.
+    pub log_scale: Option<SerializedComponentBatch>,
+
+    /// If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+    ///
+    /// Only has an effect if some series are plotted against the secondary axis.
+    pub secondary_log_scale: Option<SerializedComponentBatch>,
 }
 
 impl ScalarAxis {
@@ -57,6 +67,30 @@ impl ScalarAxis {
             component_type: Some("rerun.blueprint.components.LockRangeDuringZoom".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::log_scale`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_log_scale() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ScalarAxis".into()),
+            component: "ScalarAxis:log_scale".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::secondary_log_scale`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_secondary_log_scale() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.ScalarAxis".into()),
+            component: "ScalarAxis:secondary_log_scale".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
@@ -65,25 +99,29 @@ static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
 static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
     std::sync::LazyLock::new(|| []);
 
-static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
     std::sync::LazyLock::new(|| {
         [
             ScalarAxis::descriptor_range(),
             ScalarAxis::descriptor_zoom_lock(),
+            ScalarAxis::descriptor_log_scale(),
+            ScalarAxis::descriptor_secondary_log_scale(),
         ]
     });
 
-static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 2usize]> =
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 4usize]> =
     std::sync::LazyLock::new(|| {
         [
             ScalarAxis::descriptor_range(),
             ScalarAxis::descriptor_zoom_lock(),
+            ScalarAxis::descriptor_log_scale(),
+            ScalarAxis::descriptor_secondary_log_scale(),
         ]
     });
 
 impl ScalarAxis {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
-    pub const NUM_COMPONENTS: usize = 2usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
 }
 
 impl ::re_types_core::Archetype for ScalarAxis {
@@ -132,7 +170,22 @@ impl ::re_types_core::Archetype for ScalarAxis {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_zoom_lock())
             });
-        Ok(Self { range, zoom_lock })
+        let log_scale = arrays_by_descr
+            .get(&Self::descriptor_log_scale())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_log_scale())
+            });
+        let secondary_log_scale = arrays_by_descr
+            .get(&Self::descriptor_secondary_log_scale())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_secondary_log_scale())
+            });
+        Ok(Self {
+            range,
+            zoom_lock,
+            log_scale,
+            secondary_log_scale,
+        })
     }
 }
 
@@ -140,10 +193,15 @@ impl ::re_types_core::AsComponents for ScalarAxis {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        [self.range.clone(), self.zoom_lock.clone()]
-            .into_iter()
-            .flatten()
-            .collect()
+        [
+            self.range.clone(),
+            self.zoom_lock.clone(),
+            self.log_scale.clone(),
+            self.secondary_log_scale.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -156,6 +214,8 @@ impl ScalarAxis {
         Self {
             range: None,
             zoom_lock: None,
+            log_scale: None,
+            secondary_log_scale: None,
         }
     }
 
@@ -178,6 +238,14 @@ impl ScalarAxis {
                 crate::blueprint::components::LockRangeDuringZoom::arrow_empty(),
                 Self::descriptor_zoom_lock(),
             )),
+            log_scale: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_log_scale(),
+            )),
+            secondary_log_scale: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_secondary_log_scale(),
+            )),
         }
     }
 
@@ -199,11 +267,41 @@ impl ScalarAxis {
         self.zoom_lock = try_serialize_field(Self::descriptor_zoom_lock(), [zoom_lock]);
         self
     }
+
+    /// If enabled, the Y axis uses a logarithmic scale.
+    ///
+    /// Values that aren't positive can't be shown on a logarithmic scale and are left out.
+    #[inline]
+    pub fn with_log_scale(
+        mut self,
+        log_scale: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.log_scale = try_serialize_field(Self::descriptor_log_scale(), [log_scale]);
+        self
+    }
+
+    /// If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+    ///
+    /// Only has an effect if some series are plotted against the secondary axis.
+    #[inline]
+    pub fn with_secondary_log_scale(
+        mut self,
+        secondary_log_scale: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.secondary_log_scale = try_serialize_field(
+            Self::descriptor_secondary_log_scale(),
+            [secondary_log_scale],
+        );
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for ScalarAxis {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.range.heap_size_bytes() + self.zoom_lock.heap_size_bytes()
+        self.range.heap_size_bytes()
+            + self.zoom_lock.heap_size_bytes()
+            + self.log_scale.heap_size_bytes()
+            + self.secondary_log_scale.heap_size_bytes()
     }
 }
@@ -0,0 +1,255 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_plane3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A plane cutting away part of a 3D view, to look inside of meshes and dense point clouds.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct SectionPlane3D {
+    /// A point on the plane, in the space of the view.
+    pub position: Option<SerializedComponentBatch>,
+
+    /// Normal of the plane.
+    ///
+    /// Everything on the side the normal points to is kept.
+    pub normal: Option<SerializedComponentBatch>,
+
+    /// Whether meshes are colored where they are cut.
+    ///
+    /// Defaults to false.
+    pub show_contour: Option<SerializedComponentBatch>,
+}
+
+impl SectionPlane3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::position`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_position() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.SectionPlane3D".into()),
+            component: "SectionPlane3D:position".into(),
+            component_type: Some("rerun.components.Position3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::normal`].
+    ///
+    /// The corresponding component is [`crate::components::Vector3D`].
+    #[inline]
+    pub fn descriptor_normal() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.SectionPlane3D".into()),
+            component: "SectionPlane3D:normal".into(),
+            component_type: Some("rerun.components.Vector3D".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::show_contour`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_show_contour() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.SectionPlane3D".into()),
+            component: "SectionPlane3D:show_contour".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            SectionPlane3D::descriptor_position(),
+            SectionPlane3D::descriptor_normal(),
+            SectionPlane3D::descriptor_show_contour(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 3usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            SectionPlane3D::descriptor_position(),
+            SectionPlane3D::descriptor_normal(),
+            SectionPlane3D::descriptor_show_contour(),
+        ]
+    });
+
+impl SectionPlane3D {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for SectionPlane3D {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.SectionPlane3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Section plane 3D"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let position = arrays_by_descr
+            .get(&Self::descriptor_position())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_position()));
+        let normal = arrays_by_descr
+            .get(&Self::descriptor_normal())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_normal()));
+        let show_contour = arrays_by_descr
+            .get(&Self::descriptor_show_contour())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_show_contour())
+            });
+        Ok(Self {
+            position,
+            normal,
+            show_contour,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for SectionPlane3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.position.clone(),
+            self.normal.clone(),
+            self.show_contour.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for SectionPlane3D {}
+
+impl SectionPlane3D {
+    /// Create a new `SectionPlane3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            position: None,
+            normal: None,
+            show_contour: None,
+        }
+    }
+
+    /// Update only some specific fields of a `SectionPlane3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `SectionPlane3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            position: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_position(),
+            )),
+            normal: Some(SerializedComponentBatch::new(
+                crate::components::Vector3D::arrow_empty(),
+                Self::descriptor_normal(),
+            )),
+            show_contour: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_show_contour(),
+            )),
+        }
+    }
+
+    /// A point on the plane, in the space of the view.
+    #[inline]
+    pub fn with_position(mut self, position: impl Into<crate::components::Position3D>) -> Self {
+        self.position = try_serialize_field(Self::descriptor_position(), [position]);
+        self
+    }
+
+    /// Normal of the plane.
+    ///
+    /// Everything on the side the normal points to is kept.
+    #[inline]
+    pub fn with_normal(mut self, normal: impl Into<crate::components::Vector3D>) -> Self {
+        self.normal = try_serialize_field(Self::descriptor_normal(), [normal]);
+        self
+    }
+
+    /// Whether meshes are colored where they are cut.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_show_contour(
+        mut self,
+        show_contour: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.show_contour = try_serialize_field(Self::descriptor_show_contour(), [show_contour]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SectionPlane3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.position.heap_size_bytes()
+            + self.normal.heap_size_bytes()
+            + self.show_contour.heap_size_bytes()
+    }
+}
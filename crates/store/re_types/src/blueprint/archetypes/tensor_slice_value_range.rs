@@ -0,0 +1,166 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_slice_value_range.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Which values of a tensor the colormap of a tensor view spans.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TensorSliceValueRange {
+    /// Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+    ///
+    /// Defaults to false.
+    pub fit_to_slice: Option<SerializedComponentBatch>,
+}
+
+impl TensorSliceValueRange {
+    /// Returns the [`ComponentDescriptor`] for [`Self::fit_to_slice`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_fit_to_slice() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TensorSliceValueRange".into()),
+            component: "TensorSliceValueRange:fit_to_slice".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [TensorSliceValueRange::descriptor_fit_to_slice()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [TensorSliceValueRange::descriptor_fit_to_slice()]);
+
+impl TensorSliceValueRange {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for TensorSliceValueRange {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TensorSliceValueRange".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Tensor slice value range"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let fit_to_slice = arrays_by_descr
+            .get(&Self::descriptor_fit_to_slice())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_fit_to_slice())
+            });
+        Ok(Self { fit_to_slice })
+    }
+}
+
+impl ::re_types_core::AsComponents for TensorSliceValueRange {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.fit_to_slice.clone())
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TensorSliceValueRange {}
+
+impl TensorSliceValueRange {
+    /// Create a new `TensorSliceValueRange`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { fit_to_slice: None }
+    }
+
+    /// Update only some specific fields of a `TensorSliceValueRange`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TensorSliceValueRange`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            fit_to_slice: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_fit_to_slice(),
+            )),
+        }
+    }
+
+    /// Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_fit_to_slice(
+        mut self,
+        fit_to_slice: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.fit_to_slice = try_serialize_field(Self::descriptor_fit_to_slice(), [fit_to_slice]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TensorSliceValueRange {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.fit_to_slice.heap_size_bytes()
+    }
+}
@@ -0,0 +1,333 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_saved_filters.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Named filters of a text log view, to apply them again later.
+///
+/// All fields have one instance per saved filter, in the order they were saved.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TextLogSavedFilters {
+    /// The name the user gave each filter.
+    pub names: Option<SerializedComponentBatch>,
+
+    /// The text each filter matches log messages against.
+    pub patterns: Option<SerializedComponentBatch>,
+
+    /// Whether each pattern is a regular expression rather than plain text.
+    pub is_regex: Option<SerializedComponentBatch>,
+
+    /// The least severe level each filter shows.
+    pub min_levels: Option<SerializedComponentBatch>,
+
+    /// The most severe level each filter shows.
+    pub max_levels: Option<SerializedComponentBatch>,
+}
+
+impl TextLogSavedFilters {
+    /// Returns the [`ComponentDescriptor`] for [`Self::names`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_names() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogSavedFilters".into()),
+            component: "TextLogSavedFilters:names".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::patterns`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_patterns() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogSavedFilters".into()),
+            component: "TextLogSavedFilters:patterns".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::is_regex`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_is_regex() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogSavedFilters".into()),
+            component: "TextLogSavedFilters:is_regex".into(),
+            component_type: Some("rerun.blueprint.components.Enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::min_levels`].
+    ///
+    /// The corresponding component is [`crate::components::TextLogLevel`].
+    #[inline]
+    pub fn descriptor_min_levels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogSavedFilters".into()),
+            component: "TextLogSavedFilters:min_levels".into(),
+            component_type: Some("rerun.components.TextLogLevel".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::max_levels`].
+    ///
+    /// The corresponding component is [`crate::components::TextLogLevel`].
+    #[inline]
+    pub fn descriptor_max_levels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogSavedFilters".into()),
+            component: "TextLogSavedFilters:max_levels".into(),
+            component_type: Some("rerun.components.TextLogLevel".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            TextLogSavedFilters::descriptor_names(),
+            TextLogSavedFilters::descriptor_patterns(),
+            TextLogSavedFilters::descriptor_is_regex(),
+            TextLogSavedFilters::descriptor_min_levels(),
+            TextLogSavedFilters::descriptor_max_levels(),
+        ]
+    });
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 5usize]> =
+    std::sync::LazyLock::new(|| {
+        [
+            TextLogSavedFilters::descriptor_names(),
+            TextLogSavedFilters::descriptor_patterns(),
+            TextLogSavedFilters::descriptor_is_regex(),
+            TextLogSavedFilters::descriptor_min_levels(),
+            TextLogSavedFilters::descriptor_max_levels(),
+        ]
+    });
+
+impl TextLogSavedFilters {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+impl ::re_types_core::Archetype for TextLogSavedFilters {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogSavedFilters".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log saved filters"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let names = arrays_by_descr
+            .get(&Self::descriptor_names())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_names()));
+        let patterns = arrays_by_descr
+            .get(&Self::descriptor_patterns())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_patterns()));
+        let is_regex = arrays_by_descr
+            .get(&Self::descriptor_is_regex())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_is_regex()));
+        let min_levels = arrays_by_descr
+            .get(&Self::descriptor_min_levels())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_min_levels())
+            });
+        let max_levels = arrays_by_descr
+            .get(&Self::descriptor_max_levels())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_max_levels())
+            });
+        Ok(Self {
+            names,
+            patterns,
+            is_regex,
+            min_levels,
+            max_levels,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogSavedFilters {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.names.clone(),
+            self.patterns.clone(),
+            self.is_regex.clone(),
+            self.min_levels.clone(),
+            self.max_levels.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogSavedFilters {}
+
+impl TextLogSavedFilters {
+    /// Create a new `TextLogSavedFilters`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            names: None,
+            patterns: None,
+            is_regex: None,
+            min_levels: None,
+            max_levels: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogSavedFilters`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogSavedFilters`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            names: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_names(),
+            )),
+            patterns: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_patterns(),
+            )),
+            is_regex: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_is_regex(),
+            )),
+            min_levels: Some(SerializedComponentBatch::new(
+                crate::components::TextLogLevel::arrow_empty(),
+                Self::descriptor_min_levels(),
+            )),
+            max_levels: Some(SerializedComponentBatch::new(
+                crate::components::TextLogLevel::arrow_empty(),
+                Self::descriptor_max_levels(),
+            )),
+        }
+    }
+
+    /// The name the user gave each filter.
+    #[inline]
+    pub fn with_names(
+        mut self,
+        names: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.names = try_serialize_field(Self::descriptor_names(), names);
+        self
+    }
+
+    /// The text each filter matches log messages against.
+    #[inline]
+    pub fn with_patterns(
+        mut self,
+        patterns: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.patterns = try_serialize_field(Self::descriptor_patterns(), patterns);
+        self
+    }
+
+    /// Whether each pattern is a regular expression rather than plain text.
+    #[inline]
+    pub fn with_is_regex(
+        mut self,
+        is_regex: impl IntoIterator<Item = impl Into<crate::blueprint::components::Enabled>>,
+    ) -> Self {
+        self.is_regex = try_serialize_field(Self::descriptor_is_regex(), is_regex);
+        self
+    }
+
+    /// The least severe level each filter shows.
+    #[inline]
+    pub fn with_min_levels(
+        mut self,
+        min_levels: impl IntoIterator<Item = impl Into<crate::components::TextLogLevel>>,
+    ) -> Self {
+        self.min_levels = try_serialize_field(Self::descriptor_min_levels(), min_levels);
+        self
+    }
+
+    /// The most severe level each filter shows.
+    #[inline]
+    pub fn with_max_levels(
+        mut self,
+        max_levels: impl IntoIterator<Item = impl Into<crate::components::TextLogLevel>>,
+    ) -> Self {
+        self.max_levels = try_serialize_field(Self::descriptor_max_levels(), max_levels);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogSavedFilters {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.names.heap_size_bytes()
+            + self.patterns.heap_size_bytes()
+            + self.is_regex.heap_size_bytes()
+            + self.min_levels.heap_size_bytes()
+            + self.max_levels.heap_size_bytes()
+    }
+}
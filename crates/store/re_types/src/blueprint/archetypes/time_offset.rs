@@ -0,0 +1,159 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/time_offset.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Offset of the time a view is shown at, relative to the time cursor.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TimeOffset {
+    /// In seconds on temporal timelines, and in steps on sequence timelines.
+    ///
+    /// Negative to show the past. Defaults to zero.
+    pub offset: Option<SerializedComponentBatch>,
+}
+
+impl TimeOffset {
+    /// Returns the [`ComponentDescriptor`] for [`Self::offset`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_offset() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TimeOffset".into()),
+            component: "TimeOffset:offset".into(),
+            component_type: Some("rerun.components.Scalar".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static RECOMMENDED_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 0usize]> =
+    std::sync::LazyLock::new(|| []);
+
+static OPTIONAL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [TimeOffset::descriptor_offset()]);
+
+static ALL_COMPONENTS: std::sync::LazyLock<[ComponentDescriptor; 1usize]> =
+    std::sync::LazyLock::new(|| [TimeOffset::descriptor_offset()]);
+
+impl TimeOffset {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for TimeOffset {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TimeOffset".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Time offset"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let offset = arrays_by_descr
+            .get(&Self::descriptor_offset())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_offset()));
+        Ok(Self { offset })
+    }
+}
+
+impl ::re_types_core::AsComponents for TimeOffset {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.offset.clone()).flatten().collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TimeOffset {}
+
+impl TimeOffset {
+    /// Create a new `TimeOffset`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { offset: None }
+    }
+
+    /// Update only some specific fields of a `TimeOffset`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TimeOffset`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            offset: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_offset(),
+            )),
+        }
+    }
+
+    /// In seconds on temporal timelines, and in steps on sequence timelines.
+    ///
+    /// Negative to show the past. Defaults to zero.
+    #[inline]
+    pub fn with_offset(mut self, offset: impl Into<crate::components::Scalar>) -> Self {
+        self.offset = try_serialize_field(Self::descriptor_offset(), [offset]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TimeOffset {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.offset.heap_size_bytes()
+    }
+}
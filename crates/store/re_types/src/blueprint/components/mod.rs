@@ -33,6 +33,7 @@ mod near_clip_plane;
 mod near_clip_plane_ext;
 mod panel_state;
 mod panel_state_ext;
+mod plot_mode;
 mod query_expression;
 mod root_container;
 mod row_share;
@@ -79,6 +80,7 @@ pub use self::lock_range_during_zoom::LockRangeDuringZoom;
 pub use self::map_provider::MapProvider;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_state::PanelState;
+pub use self::plot_mode::PlotMode;
 pub use self::query_expression::QueryExpression;
 pub use self::root_container::RootContainer;
 pub use self::row_share::RowShare;
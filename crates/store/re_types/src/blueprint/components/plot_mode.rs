@@ -0,0 +1,184 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/plot_mode.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: How the series of a time series view are drawn.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum PlotMode {
+    /// Every series is drawn on its own, as lines or points.
+    #[default]
+    Lines = 1,
+
+    /// The series are stacked on top of each other as filled areas.
+    ///
+    /// Values are averaged per time bucket.
+    StackedArea = 2,
+
+    /// The series are drawn as bars next to each other, one group per time bucket.
+    ///
+    /// Values are averaged per time bucket.
+    GroupedBars = 3,
+
+    /// The series are drawn as bars stacked on top of each other, one stack per time bucket.
+    ///
+    /// Values are averaged per time bucket.
+    StackedBars = 4,
+}
+
+impl ::re_types_core::Component for PlotMode {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.blueprint.components.PlotMode".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(PlotMode);
+
+impl ::re_types_core::Loggable for PlotMode {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.blueprint.components.PlotMode#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::Lines)),
+                Some(2) => Ok(Some(Self::StackedArea)),
+                Some(3) => Ok(Some(Self::GroupedBars)),
+                Some(4) => Ok(Some(Self::StackedBars)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.blueprint.components.PlotMode")?)
+    }
+}
+
+impl std::fmt::Display for PlotMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lines => write!(f, "Lines"),
+            Self::StackedArea => write!(f, "StackedArea"),
+            Self::GroupedBars => write!(f, "GroupedBars"),
+            Self::StackedBars => write!(f, "StackedBars"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for PlotMode {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[
+            Self::Lines,
+            Self::StackedArea,
+            Self::GroupedBars,
+            Self::StackedBars,
+        ]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::Lines => "Every series is drawn on its own, as lines or points.",
+            Self::StackedArea => {
+                "The series are stacked on top of each other as filled areas.\n\nValues are averaged per time bucket."
+            }
+            Self::GroupedBars => {
+                "The series are drawn as bars next to each other, one group per time bucket.\n\nValues are averaged per time bucket."
+            }
+            Self::StackedBars => {
+                "The series are drawn as bars stacked on top of each other, one stack per time bucket.\n\nValues are averaged per time bucket."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for PlotMode {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
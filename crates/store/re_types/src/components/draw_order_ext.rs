@@ -14,6 +14,9 @@ impl DrawOrder {
     /// Draw order used for images if no draw order was specified.
     pub const DEFAULT_IMAGE: Self = Self(Float32(-10.0));
 
+    /// Draw order used for heatmaps if no draw order was specified.
+    pub const DEFAULT_HEATMAP: Self = Self(Float32(-5.0));
+
     /// Draw order used for segmentation images if no draw order was specified.
     pub const DEFAULT_SEGMENTATION_IMAGE: Self = Self(Float32(0.0));
 
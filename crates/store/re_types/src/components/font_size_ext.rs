@@ -0,0 +1,50 @@
+use re_types_core::datatypes::Float32;
+
+use super::FontSize;
+
+impl Default for FontSize {
+    #[inline]
+    fn default() -> Self {
+        Self::new_ui_points(12.0)
+    }
+}
+
+impl FontSize {
+    /// Creates a new font size in scene units.
+    ///
+    /// Values passed must be finite positive.
+    #[inline]
+    pub fn new_scene_units(size_in_scene_units: f32) -> Self {
+        debug_assert!(
+            0.0 <= size_in_scene_units,
+            "Bad font size: {size_in_scene_units}"
+        );
+        Self(Float32(size_in_scene_units))
+    }
+
+    /// Creates a new font size in ui points.
+    ///
+    /// Values passed must be finite positive.
+    #[inline]
+    pub fn new_ui_points(size_in_ui_points: f32) -> Self {
+        debug_assert!(
+            0.0 <= size_in_ui_points,
+            "Bad font size: {size_in_ui_points}"
+        );
+        Self(Float32(-size_in_ui_points))
+    }
+
+    /// If this font size is in scene units, returns the size in scene units.
+    #[inline]
+    pub fn scene_units(&self) -> Option<f32> {
+        // Ensure negative zero is treated as a point size.
+        self.0.is_sign_positive().then_some(*self.0)
+    }
+
+    /// If this font size is in ui points, returns the size in ui points.
+    #[inline]
+    pub fn ui_points(&self) -> Option<f32> {
+        // Ensure negative zero is treated as a point size.
+        self.0.is_sign_negative().then_some(-*self.0)
+    }
+}
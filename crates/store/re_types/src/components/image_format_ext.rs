@@ -14,6 +14,12 @@ impl ImageFormat {
         datatypes::ImageFormat::segmentation([width, height], datatype).into()
     }
 
+    /// Create a new heatmap format with the given resolution and datatype.
+    #[inline]
+    pub fn heatmap([width, height]: [u32; 2], datatype: ChannelDatatype) -> Self {
+        datatypes::ImageFormat::heatmap([width, height], datatype).into()
+    }
+
     /// Create a new grayscale image format with 8 bit for the single channel with the given
     /// resolution.
     #[inline]
@@ -11,6 +11,19 @@ impl MediaType {
     /// <https://www.iana.org/assignments/media-types/text/markdown>
     pub const MARKDOWN: &'static str = "text/markdown";
 
+    /// [JSON](https://en.wikipedia.org/wiki/JSON): `application/json`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/application/json>
+    pub const JSON: &'static str = "application/json";
+
+    // -------------------------------------------------------
+    // Documents:
+
+    /// [PDF document](https://en.wikipedia.org/wiki/PDF): `application/pdf`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/application/pdf>
+    pub const PDF: &'static str = "application/pdf";
+
     // -------------------------------------------------------
     // Images:
 
@@ -68,6 +81,21 @@ impl MediaType {
         Self(Self::MARKDOWN.into())
     }
 
+    /// `application/json`
+    #[inline]
+    pub fn json() -> Self {
+        Self(Self::JSON.into())
+    }
+
+    // -------------------------------------------------------
+    // Documents:
+
+    /// `application/pdf`
+    #[inline]
+    pub fn pdf() -> Self {
+        Self(Self::PDF.into())
+    }
+
     // -------------------------------------------------------
     // Images:
 
@@ -25,6 +25,8 @@ mod fill_mode;
 mod fill_mode_ext;
 mod fill_ratio;
 mod fill_ratio_ext;
+mod font_size;
+mod font_size_ext;
 mod gamma_correction;
 mod gamma_correction_ext;
 mod geo_line_string;
@@ -101,6 +103,7 @@ mod scalar_ext;
 mod scale3d;
 mod scale3d_ext;
 mod schema_id;
+mod series_secondary_axis;
 mod series_visible;
 mod show_labels;
 mod show_labels_ext;
@@ -114,11 +117,14 @@ mod tensor_width_dimension;
 mod texcoord2d;
 mod texcoord2d_ext;
 mod text;
+mod text_anchor;
+mod text_anchor_ext;
 mod text_ext;
 mod text_log_level;
 mod text_log_level_ext;
 mod timestamp;
 mod timestamp_ext;
+mod transform_interpolation;
 mod transform_mat3x3;
 mod transform_mat3x3_ext;
 mod transform_relation;
@@ -157,6 +163,7 @@ pub use self::draw_order::DrawOrder;
 pub use self::entity_path::EntityPath;
 pub use self::fill_mode::FillMode;
 pub use self::fill_ratio::FillRatio;
+pub use self::font_size::FontSize;
 pub use self::gamma_correction::GammaCorrection;
 pub use self::geo_line_string::GeoLineString;
 pub use self::graph_edge::GraphEdge;
@@ -198,6 +205,7 @@ pub use self::rotation_quat::RotationQuat;
 pub use self::scalar::Scalar;
 pub use self::scale3d::Scale3D;
 pub use self::schema_id::SchemaId;
+pub use self::series_secondary_axis::SeriesSecondaryAxis;
 pub use self::series_visible::SeriesVisible;
 pub use self::show_labels::ShowLabels;
 pub use self::stroke_width::StrokeWidth;
@@ -207,8 +215,10 @@ pub use self::tensor_height_dimension::TensorHeightDimension;
 pub use self::tensor_width_dimension::TensorWidthDimension;
 pub use self::texcoord2d::Texcoord2D;
 pub use self::text::Text;
+pub use self::text_anchor::TextAnchor;
 pub use self::text_log_level::TextLogLevel;
 pub use self::timestamp::Timestamp;
+pub use self::transform_interpolation::TransformInterpolation;
 pub use self::transform_mat3x3::TransformMat3x3;
 pub use self::transform_relation::TransformRelation;
 pub use self::translation3d::Translation3D;
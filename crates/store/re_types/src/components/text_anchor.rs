@@ -0,0 +1,209 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/text_anchor.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: Which point of a text label is placed at its position.
+///
+/// E.g. with [`components::TextAnchor::Bottom`][crate::components::TextAnchor::Bottom], the text is centered horizontally above its position.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum TextAnchor {
+    /// The center of the text is placed at the position.
+    #[default]
+    Center = 1,
+
+    /// The middle of the top edge of the text is placed at the position.
+    Top = 2,
+
+    /// The middle of the bottom edge of the text is placed at the position.
+    Bottom = 3,
+
+    /// The middle of the left edge of the text is placed at the position.
+    Left = 4,
+
+    /// The middle of the right edge of the text is placed at the position.
+    Right = 5,
+
+    /// The top left corner of the text is placed at the position.
+    TopLeft = 6,
+
+    /// The top right corner of the text is placed at the position.
+    TopRight = 7,
+
+    /// The bottom left corner of the text is placed at the position.
+    BottomLeft = 8,
+
+    /// The bottom right corner of the text is placed at the position.
+    BottomRight = 9,
+}
+
+impl ::re_types_core::Component for TextAnchor {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.TextAnchor".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(TextAnchor);
+
+impl ::re_types_core::Loggable for TextAnchor {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.components.TextAnchor#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::Center)),
+                Some(2) => Ok(Some(Self::Top)),
+                Some(3) => Ok(Some(Self::Bottom)),
+                Some(4) => Ok(Some(Self::Left)),
+                Some(5) => Ok(Some(Self::Right)),
+                Some(6) => Ok(Some(Self::TopLeft)),
+                Some(7) => Ok(Some(Self::TopRight)),
+                Some(8) => Ok(Some(Self::BottomLeft)),
+                Some(9) => Ok(Some(Self::BottomRight)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.components.TextAnchor")?)
+    }
+}
+
+impl std::fmt::Display for TextAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Center => write!(f, "Center"),
+            Self::Top => write!(f, "Top"),
+            Self::Bottom => write!(f, "Bottom"),
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+            Self::TopLeft => write!(f, "TopLeft"),
+            Self::TopRight => write!(f, "TopRight"),
+            Self::BottomLeft => write!(f, "BottomLeft"),
+            Self::BottomRight => write!(f, "BottomRight"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for TextAnchor {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[
+            Self::Center,
+            Self::Top,
+            Self::Bottom,
+            Self::Left,
+            Self::Right,
+            Self::TopLeft,
+            Self::TopRight,
+            Self::BottomLeft,
+            Self::BottomRight,
+        ]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::Center => "The center of the text is placed at the position.",
+            Self::Top => "The middle of the top edge of the text is placed at the position.",
+            Self::Bottom => "The middle of the bottom edge of the text is placed at the position.",
+            Self::Left => "The middle of the left edge of the text is placed at the position.",
+            Self::Right => "The middle of the right edge of the text is placed at the position.",
+            Self::TopLeft => "The top left corner of the text is placed at the position.",
+            Self::TopRight => "The top right corner of the text is placed at the position.",
+            Self::BottomLeft => "The bottom left corner of the text is placed at the position.",
+            Self::BottomRight => "The bottom right corner of the text is placed at the position.",
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextAnchor {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
@@ -0,0 +1,35 @@
+use super::TextAnchor;
+
+impl TextAnchor {
+    /// Instantiate a new [`TextAnchor`] from a u8 value.
+    ///
+    /// Returns `None` if the value doesn't match any of the enum's arms.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        // NOTE: This code will be optimized out, it's only here to make sure this method fails to
+        // compile if the enum is modified.
+        match Self::default() {
+            Self::Center
+            | Self::Top
+            | Self::Bottom
+            | Self::Left
+            | Self::Right
+            | Self::TopLeft
+            | Self::TopRight
+            | Self::BottomLeft
+            | Self::BottomRight => {}
+        }
+
+        match value {
+            v if v == Self::Center as u8 => Some(Self::Center),
+            v if v == Self::Top as u8 => Some(Self::Top),
+            v if v == Self::Bottom as u8 => Some(Self::Bottom),
+            v if v == Self::Left as u8 => Some(Self::Left),
+            v if v == Self::Right as u8 => Some(Self::Right),
+            v if v == Self::TopLeft as u8 => Some(Self::TopLeft),
+            v if v == Self::TopRight as u8 => Some(Self::TopRight),
+            v if v == Self::BottomLeft as u8 => Some(Self::BottomLeft),
+            v if v == Self::BottomRight as u8 => Some(Self::BottomRight),
+            _ => None,
+        }
+    }
+}
@@ -25,6 +25,18 @@ impl ImageFormat {
         }
     }
 
+    /// Create a new heatmap format with the given resolution and datatype.
+    #[inline]
+    pub fn heatmap([width, height]: [u32; 2], datatype: ChannelDatatype) -> Self {
+        Self {
+            width,
+            height,
+            pixel_format: None,
+            channel_datatype: Some(datatype),
+            color_model: None,
+        }
+    }
+
     /// Create a new grayscale image format with 8 bit for the single channel with the given
     /// resolution.
     #[inline]
@@ -14,7 +14,7 @@ use crate::datatypes::ImageFormat;
 
 // ----------------------------------------------------------------------------
 
-/// The kind of image data, either color, segmentation, or depth image.
+/// The kind of image data, either color, segmentation, depth image, or heatmap.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ImageKind {
     /// A normal grayscale or color image ([`archetypes::Image`]).
@@ -28,6 +28,9 @@ pub enum ImageKind {
     /// The data is a [`crate::components::ClassId`] which should be
     /// looked up using the appropriate [`crate::components::AnnotationContext`]
     Segmentation,
+
+    /// A grid of scalar values that is colormapped for display ([`archetypes::Heatmap`]).
+    Heatmap,
 }
 
 impl ImageKind {
@@ -37,6 +40,8 @@ impl ImageKind {
             Self::Segmentation
         } else if archetype_name == Some(archetypes::DepthImage::name()) {
             Self::Depth
+        } else if archetype_name == Some(archetypes::Heatmap::name()) {
+            Self::Heatmap
         } else {
             // TODO(#9046): Note that currently all encoded images are treated as color images.
             Self::Color
@@ -276,6 +276,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: PanelState::verify_arrow_array,
             },
         ),
+        (
+            <PlotMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How the series of a time series view are drawn.",
+                deprecation_summary: None,
+                custom_placeholder: Some(PlotMode::default().to_arrow()?),
+                datatype: PlotMode::arrow_datatype(),
+                verify_arrow_array: PlotMode::verify_arrow_array,
+            },
+        ),
         (
             <QueryExpression as Component>::name(),
             ComponentReflection {
@@ -600,6 +610,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: FillRatio::verify_arrow_array,
             },
         ),
+        (
+            <FontSize as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The size of text, measured as the height of a line of text.\n\nInternally, positive values indicate scene units, whereas negative values\nare interpreted as UI points.\n\nUI points are independent of zooming in Views, but are sensitive to the application UI scaling.\nat 100% UI scaling, UI points are equal to pixels\nThe Viewer's UI scaling defaults to the OS scaling which typically is 100% for full HD screens and 200% for 4k screens.",
+                deprecation_summary: None,
+                custom_placeholder: Some(FontSize::default().to_arrow()?),
+                datatype: FontSize::arrow_datatype(),
+                verify_arrow_array: FontSize::verify_arrow_array,
+            },
+        ),
         (
             <GammaCorrection as Component>::name(),
             ComponentReflection {
@@ -1010,6 +1030,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: SchemaId::verify_arrow_array,
             },
         ),
+        (
+            <SeriesSecondaryAxis as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Whether a time series is plotted against the secondary Y axis, on the right-hand side of the plot.\n\nUseful for series whose values differ by orders of magnitude from the others in the same plot.",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: SeriesSecondaryAxis::arrow_datatype(),
+                verify_arrow_array: SeriesSecondaryAxis::verify_arrow_array,
+            },
+        ),
         (
             <SeriesVisible as Component>::name(),
             ComponentReflection {
@@ -1102,6 +1132,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Text::verify_arrow_array,
             },
         ),
+        (
+            <TextAnchor as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Which point of a text label is placed at its position.\n\nE.g. with [`components.TextAnchor#Bottom`](https://rerun.io/docs/reference/types/components/text_anchor), the text is centered horizontally above its position.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextAnchor::default().to_arrow()?),
+                datatype: TextAnchor::arrow_datatype(),
+                verify_arrow_array: TextAnchor::verify_arrow_array,
+            },
+        ),
         (
             <TextLogLevel as Component>::name(),
             ComponentReflection {
@@ -1122,6 +1162,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Timestamp::verify_arrow_array,
             },
         ),
+        (
+            <TransformInterpolation as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Specifies how the viewer resolves a transform at times that fall between two logged samples.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TransformInterpolation::default().to_arrow()?),
+                datatype: TransformInterpolation::arrow_datatype(),
+                verify_arrow_array: TransformInterpolation::verify_arrow_array,
+            },
+        ),
         (
             <TransformMat3x3 as Component>::name(),
             ComponentReflection {
@@ -1389,6 +1439,29 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Attachment"),
+            ArchetypeReflection {
+                display_name: "Attachment",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "blob", display_name : "Blob",
+                    component_type : "rerun.components.Blob".into(), docstring_md :
+                    "The contents of the attachment.", is_required : true, },
+                    ArchetypeFieldReflection { name : "media_type", display_name :
+                    "Media type", component_type : "rerun.components.MediaType".into(),
+                    docstring_md :
+                    "The Media Type of the attachment, e.g. `application/json` or `application/pdf`.\n\nIf omitted, the viewer will try to guess from the data blob.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "filename", display_name : "Filename", component_type :
+                    "rerun.components.Name".into(), docstring_md :
+                    "The name of the file the attachment was read from, e.g. `config.yaml`.\n\nUsed as the default file name when saving the attachment from the viewer.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.BarChart"),
             ArchetypeReflection {
@@ -1842,6 +1915,41 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Heatmap"),
+            ArchetypeReflection {
+                display_name: "Heatmap",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial2DView", "Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "buffer", display_name : "Buffer",
+                    component_type : "rerun.components.ImageBuffer".into(), docstring_md
+                    : "The values of the heatmap, in row-major order.", is_required :
+                    true, }, ArchetypeFieldReflection { name : "format", display_name :
+                    "Format", component_type : "rerun.components.ImageFormat".into(),
+                    docstring_md :
+                    "The format of the heatmap, i.e. its width, height and datatype.",
+                    is_required : true, }, ArchetypeFieldReflection { name : "colormap",
+                    display_name : "Colormap", component_type :
+                    "rerun.components.Colormap".into(), docstring_md :
+                    "Colormap used to map the values to colors.\n\nIf not set, the heatmap will be rendered using the Viridis colormap.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "value_range", display_name : "Value range", component_type :
+                    "rerun.components.ValueRange".into(), docstring_md :
+                    "The range of values that is mapped onto the colormap.\n\nValues outside of the range are clamped to the range for the purpose of colormapping.\n\nIf not specified, the range is the minimum and maximum of the finite values in the heatmap.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "opacity",
+                    display_name : "Opacity", component_type :
+                    "rerun.components.Opacity".into(), docstring_md :
+                    "Opacity of the heatmap, useful for layering it on top of an image.\n\nDefaults to 0.5 if there are any images in the view, otherwise 1.0.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "draw_order", display_name : "Draw order", component_type :
+                    "rerun.components.DrawOrder".into(), docstring_md :
+                    "An optional floating point value that specifies the 2D drawing order.\n\nObjects with higher values are drawn on top of those with lower values.\nDefaults to `-5.0`.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.Image"),
             ArchetypeReflection {
@@ -2330,6 +2438,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "rerun.components.SeriesVisible".into(), docstring_md :
                     "Which lines are visible.\n\nIf not set, all line series on this entity are visible.\nUnlike with the regular visibility property of the entire entity, any series that is hidden\nvia this property will still be visible in the legend.\n\nMay change over time, but can cause discontinuities in the line.",
                     is_required : false, }, ArchetypeFieldReflection { name :
+                    "secondary_axis", display_name : "Secondary axis", component_type :
+                    "rerun.components.SeriesSecondaryAxis".into(), docstring_md :
+                    "Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.\n\nIf not set, all lines on this entity use the primary Y axis.\nThe secondary axis has its own scale, so that series of very different magnitudes can share a plot.\n\nExpected to be unchanging over time.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
                     "aggregation_policy", display_name : "Aggregation policy",
                     component_type : "rerun.components.AggregationPolicy".into(),
                     docstring_md :
@@ -2362,6 +2474,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "rerun.components.SeriesVisible".into(), docstring_md :
                     "Which lines are visible.\n\nIf not set, all line series on this entity are visible.\nUnlike with the regular visibility property of the entire entity, any series that is hidden\nvia this property will still be visible in the legend.\n\nMay change over time.",
                     is_required : false, }, ArchetypeFieldReflection { name :
+                    "secondary_axis", display_name : "Secondary axis", component_type :
+                    "rerun.components.SeriesSecondaryAxis".into(), docstring_md :
+                    "Which points are plotted against the secondary Y axis, on the right-hand side of the plot.\n\nIf not set, all points on this entity use the primary Y axis.\nThe secondary axis has its own scale, so that series of very different magnitudes can share a plot.\n\nExpected to be unchanging over time.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
                     "marker_sizes", display_name : "Marker sizes", component_type :
                     "rerun.components.MarkerSize".into(), docstring_md :
                     "Sizes of the markers.\n\nMay change over time.", is_required :
@@ -2387,6 +2503,36 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.Text3D"),
+            ArchetypeReflection {
+                display_name: "Text 3D",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "positions", display_name :
+                    "Positions", component_type : "rerun.components.Position3D".into(),
+                    docstring_md : "The 3D positions of the labels.", is_required :
+                    true, }, ArchetypeFieldReflection { name : "texts", display_name :
+                    "Texts", component_type : "rerun.components.Text".into(),
+                    docstring_md : "The text of each label.", is_required : true, },
+                    ArchetypeFieldReflection { name : "font_sizes", display_name :
+                    "Font sizes", component_type : "rerun.components.FontSize".into(),
+                    docstring_md :
+                    "Optional font sizes for the labels.\n\nPositive values are in scene units, negative values in UI points.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colors",
+                    display_name : "Colors", component_type :
+                    "rerun.components.Color".into(), docstring_md :
+                    "Optional colors for the labels.", is_required : false, },
+                    ArchetypeFieldReflection { name : "anchors", display_name :
+                    "Anchors", component_type : "rerun.components.TextAnchor".into(),
+                    docstring_md :
+                    "Which point of each label is placed at its position.\n\nDefaults to the center of the label.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.TextDocument"),
             ArchetypeReflection {
@@ -2458,6 +2604,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     .into(), docstring_md :
                     "Specifies the relation this transform establishes between this entity and its parent.",
                     is_required : false, }, ArchetypeFieldReflection { name :
+                    "interpolation", display_name : "Interpolation", component_type :
+                    "rerun.components.TransformInterpolation".into(), docstring_md :
+                    "How the transform is interpolated between logged samples.\n\nBy default, the latest transform logged before the queried time is used.\nWith `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
                     "axis_length", display_name : "Axis length", component_type :
                     "rerun.components.AxisLength".into(), docstring_md :
                     "Visual length of the 3 axes.\n\nThe length is interpreted in the local coordinate system of the transform.\nIf the transform is scaled, the axes will be scaled accordingly.",
@@ -2552,6 +2702,53 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.CameraController3D"),
+            ArchetypeReflection {
+                display_name: "Camera controller 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "controller", display_name :
+                    "Controller", component_type : "rerun.components.Name".into(),
+                    docstring_md :
+                    "Identifier of the controller, e.g. `orbit` or `fly`.\n\nIf not set, the eye controls of the view are used.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.CameraPath3D"),
+            ArchetypeReflection {
+                display_name: "Camera path 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "times", display_name : "Times",
+                    component_type : "rerun.components.Scalar".into(), docstring_md :
+                    "Time of each keyframe.\n\nOn the timeline if one is set, otherwise in seconds since the start of the playback.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "positions", display_name : "Positions", component_type :
+                    "rerun.components.Position3D".into(), docstring_md :
+                    "Position of the camera at each keyframe, in the space of the view.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "rotations", display_name : "Rotations", component_type :
+                    "rerun.components.RotationQuat".into(), docstring_md :
+                    "Rotation of the camera at each keyframe, in the space of the view.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "fields_of_view", display_name : "Fields of view", component_type :
+                    "rerun.components.Scalar".into(), docstring_md :
+                    "Vertical field of view of the camera at each keyframe, in radians.\n\nNaN for an orthographic camera.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "timeline",
+                    display_name : "Timeline", component_type :
+                    "rerun.blueprint.components.TimelineName".into(), docstring_md :
+                    "The timeline the keyframe times are on.\n\nIf not set, the keyframe times are wall-clock seconds since the start of the playback.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ContainerBlueprint"),
             ArchetypeReflection {
@@ -2668,6 +2865,26 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.FollowLatest"),
+            ArchetypeReflection {
+                display_name: "Follow latest",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "enabled", display_name :
+                    "Enabled", component_type : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the view follows the latest data.\n\nDefaults to false.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "camera_entity", display_name : "Camera entity", component_type :
+                    "rerun.components.EntityPath".into(), docstring_md :
+                    "The entity the eye of a 3D view tracks while following the latest data.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ForceCenter"),
             ArchetypeReflection {
@@ -2779,6 +2996,82 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.GraphLayoutAlgorithm"),
+            ArchetypeReflection {
+                display_name: "Graph layout algorithm",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "algorithm", display_name :
+                    "Algorithm", component_type : "rerun.components.Name".into(),
+                    docstring_md :
+                    "Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.\n\nIf not set, the force-based layout is used.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.GridOverlay2D"),
+            ArchetypeReflection {
+                display_name: "Grid overlay 2D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "show_grid", display_name :
+                    "Show grid", component_type : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the grid is drawn.\n\nDefaults to false.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "show_rulers",
+                    display_name : "Show rulers", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Whether the rulers are drawn.\n\nDefaults to false.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "spacing", display_name :
+                    "Spacing", component_type : "rerun.blueprint.components.GridSpacing"
+                    .into(), docstring_md :
+                    "Distance between major lines in scene units.\n\nIf not set, it is picked for the zoom level.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "subdivisions", display_name : "Subdivisions", component_type :
+                    "rerun.components.Scalar".into(), docstring_md :
+                    "Number of minor cells each major cell is divided into.\n\nDefaults to 5.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "snap_to_grid", display_name : "Snap to grid", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Whether the cursor snaps to the nearest intersection of minor lines.\n\nDefaults to false.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.Lighting3D"),
+            ArchetypeReflection {
+                display_name: "Lighting 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "key_light_direction", display_name
+                    : "Key light direction", component_type : "rerun.components.Vector3D"
+                    .into(), docstring_md :
+                    "Direction towards the key light, in the space of the view.\n\nMeshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "key_light_intensity", display_name : "Key light intensity",
+                    component_type : "rerun.components.Scalar".into(), docstring_md :
+                    "Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "ambient_light", display_name : "Ambient light", component_type :
+                    "rerun.components.Scalar".into(), docstring_md :
+                    "Brightness of the light reaching meshes from all sides.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "unlit",
+                    display_name : "Unlit", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Draws meshes in their albedo color, ignoring all lights.\n\nDefaults to false.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.LineGrid3D"),
             ArchetypeReflection {
@@ -2893,6 +3186,46 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.PlotStyle"),
+            ArchetypeReflection {
+                display_name: "Plot style",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "mode", display_name : "Mode",
+                    component_type : "rerun.blueprint.components.PlotMode".into(),
+                    docstring_md :
+                    "Whether the series are drawn on their own, or stacked to show their composition over time.\n\nDefaults to lines.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.PointSizing"),
+            ArchetypeReflection {
+                display_name: "Point sizing",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "screen_space", display_name :
+                    "Screen space", component_type : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether radii are in ui points rather than in scene units, like meters.\n\nDefaults to false.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "min_radius", display_name : "Min radius", component_type :
+                    "rerun.components.Radius".into(), docstring_md :
+                    "Points are drawn with at least this radius, in ui points.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "max_radius", display_name : "Max radius", component_type :
+                    "rerun.components.Radius".into(), docstring_md :
+                    "Points are drawn with at most this radius, in ui points.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ScalarAxis"),
             ArchetypeReflection {
@@ -2909,6 +3242,38 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "rerun.blueprint.components.LockRangeDuringZoom".into(), docstring_md
                     :
                     "If enabled, the Y axis range will remain locked to the specified range when zooming.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "log_scale", display_name : "Log scale", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "If enabled, the Y axis uses a logarithmic scale.\n\nValues that aren't positive can't be shown on a logarithmic scale and are left out.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "secondary_log_scale", display_name : "Secondary log scale",
+                    component_type : "rerun.blueprint.components.Enabled".into(),
+                    docstring_md :
+                    "If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.\n\nOnly has an effect if some series are plotted against the secondary axis.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.SectionPlane3D"),
+            ArchetypeReflection {
+                display_name: "Section plane 3D",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "position", display_name :
+                    "Position", component_type : "rerun.components.Position3D".into(),
+                    docstring_md : "A point on the plane, in the space of the view.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "normal",
+                    display_name : "Normal", component_type : "rerun.components.Vector3D"
+                    .into(), docstring_md :
+                    "Normal of the plane.\n\nEverything on the side the normal points to is kept.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "show_contour", display_name : "Show contour", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Whether meshes are colored where they are cut.\n\nDefaults to false.",
                     is_required : false, },
                 ],
             },
@@ -2967,6 +3332,22 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TensorSliceValueRange"),
+            ArchetypeReflection {
+                display_name: "Tensor slice value range",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "fit_to_slice", display_name :
+                    "Fit to slice", component_type : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the colormap spans the values of the shown slice rather than of the whole tensor.\n\nDefaults to false.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TensorViewFit"),
             ArchetypeReflection {
@@ -2982,6 +3363,35 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogSavedFilters"),
+            ArchetypeReflection {
+                display_name: "Text log saved filters",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "names", display_name : "Names",
+                    component_type : "rerun.components.Text".into(), docstring_md :
+                    "The name the user gave each filter.", is_required : false, },
+                    ArchetypeFieldReflection { name : "patterns", display_name :
+                    "Patterns", component_type : "rerun.components.Text".into(),
+                    docstring_md : "The text each filter matches log messages against.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "is_regex",
+                    display_name : "Is regex", component_type :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Whether each pattern is a regular expression rather than plain text.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "min_levels", display_name : "Min levels", component_type :
+                    "rerun.components.TextLogLevel".into(), docstring_md :
+                    "The least severe level each filter shows.", is_required : false, },
+                    ArchetypeFieldReflection { name : "max_levels", display_name :
+                    "Max levels", component_type : "rerun.components.TextLogLevel"
+                    .into(), docstring_md : "The most severe level each filter shows.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TimeAxis"),
             ArchetypeReflection {
@@ -2998,6 +3408,21 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TimeOffset"),
+            ArchetypeReflection {
+                display_name: "Time offset",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "offset", display_name : "Offset",
+                    component_type : "rerun.components.Scalar".into(), docstring_md :
+                    "In seconds on temporal timelines, and in steps on sequence timelines.\n\nNegative to show the past. Defaults to zero.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ViewBlueprint"),
             ArchetypeReflection {
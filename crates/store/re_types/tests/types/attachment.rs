@@ -0,0 +1,31 @@
+use re_types::{
+    archetypes::Attachment,
+    components::{Blob, MediaType, Name},
+    datatypes::Utf8,
+    Archetype as _, AsComponents as _, ComponentBatch as _,
+};
+
+#[test]
+fn roundtrip() {
+    const BYTES: &[u8] = br#"{"answer": 42}"#;
+
+    let expected = Attachment {
+        blob: Blob(BYTES.to_vec().into()).serialized(Attachment::descriptor_blob()),
+        media_type: MediaType(Utf8(MediaType::JSON.into()))
+            .serialized(Attachment::descriptor_media_type()),
+        filename: Name::from("answer.json").serialized(Attachment::descriptor_filename()),
+    };
+
+    let arch = Attachment::from_file_contents(BYTES.to_vec(), Some(MediaType::json()))
+        .with_filename("answer.json");
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name());
+    }
+
+    let deserialized = Attachment::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
@@ -0,0 +1,56 @@
+use re_types::{
+    archetypes::Heatmap,
+    components::{Colormap, ImageBuffer, ImageFormat, ValueRange},
+    datatypes::{self, ChannelDatatype},
+    Archetype as _, AsComponents as _, ComponentBatch as _,
+};
+
+#[test]
+fn heatmap_roundtrip() {
+    let format_expected = ImageFormat(datatypes::ImageFormat {
+        width: 3,
+        height: 2,
+        pixel_format: None,
+        channel_datatype: Some(ChannelDatatype::F32),
+        color_model: None,
+    });
+
+    let values = [0.0f32, 0.5, 1.0, 1.5, 2.0, 2.5];
+
+    let expected = Heatmap {
+        buffer: ImageBuffer::from(bytemuck::cast_slice::<f32, u8>(&values).to_vec())
+            .serialized(Heatmap::descriptor_buffer()),
+        format: format_expected.serialized(Heatmap::descriptor_format()),
+        colormap: Colormap::Inferno.serialized(Heatmap::descriptor_colormap()),
+        value_range: ValueRange::new(0.0, 2.0).serialized(Heatmap::descriptor_value_range()),
+        opacity: None,
+        draw_order: None,
+    };
+    let all_expected = [expected.clone(), expected];
+
+    let all_arch_serialized = [
+        Heatmap::try_from(ndarray::array![[0.0f32, 0.5, 1.0], [1.5, 2.0, 2.5]])
+            .unwrap()
+            .with_colormap(Colormap::Inferno)
+            .with_value_range(ValueRange::new(0.0, 2.0))
+            .to_arrow()
+            .unwrap(),
+        Heatmap::from_values([3, 2], &values)
+            .with_colormap(Colormap::Inferno)
+            .with_value_range(ValueRange::new(0.0, 2.0))
+            .to_arrow()
+            .unwrap(),
+    ];
+
+    for (expected, serialized) in all_expected.into_iter().zip(all_arch_serialized) {
+        for (field, array) in &serialized {
+            // NOTE: Keep those around please, very useful when debugging.
+            // eprintln!("field = {field:#?}");
+            // eprintln!("array = {array:#?}");
+            eprintln!("{} = {array:#?}", field.name());
+        }
+
+        let deserialized = Heatmap::from_arrow(serialized).unwrap();
+        similar_asserts::assert_eq!(expected, deserialized);
+    }
+}
@@ -5,10 +5,12 @@
 mod annotation_context;
 mod arrows3d;
 mod asset3d;
+mod attachment;
 mod box2d;
 mod box3d;
 mod clear;
 mod depth_image;
+mod heatmap;
 mod line_strips2d;
 mod line_strips3d;
 mod mesh3d;
@@ -17,6 +19,7 @@ mod points2d;
 mod points3d;
 mod segmentation_image;
 mod tensor;
+mod text3d;
 mod text_document;
 mod transform3d;
 mod view_coordinates;
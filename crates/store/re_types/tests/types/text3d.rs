@@ -0,0 +1,52 @@
+use re_types::{
+    archetypes::Text3D, components, Archetype as _, AsComponents as _, ComponentBatch as _,
+};
+
+#[test]
+fn roundtrip() {
+    let expected = Text3D {
+        positions: vec![
+            components::Position3D::new(1.0, 2.0, 3.0), //
+            components::Position3D::new(4.0, 5.0, 6.0),
+        ]
+        .serialized(Text3D::descriptor_positions()),
+        texts: (vec!["hello".into(), "friend".into()] as Vec<components::Text>)
+            .serialized(Text3D::descriptor_texts()),
+        font_sizes: vec![
+            components::FontSize::from(0.5), //
+            components::FontSize::from(-14.0),
+        ]
+        .serialized(Text3D::descriptor_font_sizes()),
+        colors: vec![
+            components::Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            components::Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]
+        .serialized(Text3D::descriptor_colors()),
+        anchors: vec![
+            components::TextAnchor::Bottom, //
+            components::TextAnchor::TopLeft,
+        ]
+        .serialized(Text3D::descriptor_anchors()),
+    };
+
+    let arch = Text3D::new([(1.0, 2.0, 3.0), (4.0, 5.0, 6.0)], ["hello", "friend"])
+        .with_font_sizes([
+            components::FontSize::new_scene_units(0.5),
+            components::FontSize::new_ui_points(14.0),
+        ])
+        .with_colors([0xAA0000CC, 0x00BB00DD])
+        .with_anchors([
+            components::TextAnchor::Bottom,
+            components::TextAnchor::TopLeft,
+        ]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name());
+    }
+
+    let deserialized = Text3D::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
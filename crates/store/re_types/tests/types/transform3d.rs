@@ -2,7 +2,10 @@ use std::f32::consts::TAU;
 
 use re_types::{
     archetypes::Transform3D,
-    components::{RotationAxisAngle, Scale3D, TransformMat3x3, TransformRelation, Translation3D},
+    components::{
+        RotationAxisAngle, Scale3D, TransformInterpolation, TransformMat3x3, TransformRelation,
+        Translation3D,
+    },
     datatypes::Angle,
     Archetype as _, AsComponents as _, ComponentBatch as _,
 };
@@ -22,6 +25,8 @@ fn roundtrip() {
         TransformRelation::ChildFromParent.serialized(Transform3D::descriptor_relation());
     let relation_parent_from_child_serialized =
         TransformRelation::ParentFromChild.serialized(Transform3D::descriptor_relation());
+    let interpolation_linear_serialized =
+        TransformInterpolation::Linear.serialized(Transform3D::descriptor_interpolation());
 
     let all_expected = [
         Transform3D::clear_fields(),
@@ -53,6 +58,11 @@ fn roundtrip() {
             relation: relation_parent_from_child_serialized.clone(),
             ..Transform3D::clear_fields()
         }, //
+        Transform3D {
+            translation: translation_serialized.clone(),
+            interpolation: interpolation_linear_serialized.clone(),
+            ..Transform3D::clear_fields()
+        }, //
     ];
 
     let all_arch = [
@@ -73,6 +83,8 @@ fn roundtrip() {
             .with_relation(TransformRelation::ChildFromParent),
         Transform3D::from_mat3x3([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
             .with_relation(TransformRelation::ParentFromChild),
+        Transform3D::from_translation([1.0, 2.0, 3.0])
+            .with_interpolation(TransformInterpolation::Linear),
     ];
 
     for (expected, arch) in all_expected.into_iter().zip(all_arch) {
@@ -0,0 +1,304 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, TimeCell, TimePoint};
+use re_mcap::{
+    MessageLayer as _, ParserContext, cdr,
+    layers::McapRos2Layer,
+    ros2msg::{geometry_msgs, nav_msgs, std_msgs, tf2_msgs},
+};
+use re_sdk::RecordingStream;
+use re_types::{
+    AsComponents,
+    archetypes::{LineStrips3D, Scalars, SeriesLines, Transform3D},
+    components::LineStrip3D,
+    datatypes::Quaternion,
+};
+
+use crate::{FrameTree, Ros2BridgeError, Ros2Sample};
+
+/// Topic on which static transforms are published.
+const TF_STATIC_TOPIC: &str = "/tf_static";
+
+/// Converts ROS 2 messages into Rerun archetypes and logs them to a [`RecordingStream`].
+///
+/// * `tf2_msgs/msg/TFMessage` and `nav_msgs/msg/Odometry` build up the transform hierarchy below `/tf`,
+///   see [`FrameTree`]. Transforms on `/tf_static` are logged as static data.
+/// * `nav_msgs/msg/Path` is logged as a [`LineStrips3D`].
+/// * All `sensor_msgs` that are supported by the MCAP importer are converted the same way.
+///
+/// Messages with a `header.frame_id` are logged below the entity of their frame, so that they
+/// are placed correctly in the transform hierarchy.
+/// All data is logged on the `log_time` timeline (when the bridge received the message) and,
+/// if the message has a header, on the `timestamp` timeline (`header.stamp`).
+/// This matches the timelines created when importing an MCAP file.
+pub struct Ros2Bridge {
+    rec: RecordingStream,
+    frames: FrameTree,
+    ros2_layer: McapRos2Layer,
+
+    /// The MCAP channels we pretend to have received `sensor_msgs` on, keyed by topic and type.
+    channels: HashMap<(String, String), Arc<mcap::Channel<'static>>>,
+
+    /// Entities for which we already logged the static series metadata.
+    described_series: HashSet<EntityPath>,
+}
+
+impl Ros2Bridge {
+    pub fn new(rec: RecordingStream) -> Self {
+        Self {
+            rec,
+            frames: FrameTree::default(),
+            ros2_layer: McapRos2Layer,
+            channels: HashMap::default(),
+            described_series: HashSet::default(),
+        }
+    }
+
+    /// The frame hierarchy we have seen so far.
+    pub fn frames(&self) -> &FrameTree {
+        &self.frames
+    }
+
+    /// Converts a single message and logs the result.
+    pub fn handle_sample(&mut self, sample: &Ros2Sample) -> Result<(), Ros2BridgeError> {
+        re_tracing::profile_function!();
+
+        match sample.type_name.as_str() {
+            "tf2_msgs/msg/TFMessage" => self.handle_tf(sample),
+            "nav_msgs/msg/Odometry" => self.handle_odometry(sample),
+            "nav_msgs/msg/Path" => self.handle_path(sample),
+            _ => self.handle_with_mcap_parser(sample),
+        }
+    }
+
+    fn handle_tf(&mut self, sample: &Ros2Sample) -> Result<(), Ros2BridgeError> {
+        let tf_message = decode::<tf2_msgs::TFMessage>(sample)?;
+        let is_static = sample.topic == TF_STATIC_TOPIC;
+
+        for geometry_msgs::TransformStamped {
+            header,
+            child_frame_id,
+            transform,
+        } in &tf_message.transforms
+        {
+            self.frames.set_parent(&header.frame_id, child_frame_id);
+
+            let transform = Transform3D::from_translation_rotation(
+                vec3(&transform.translation),
+                quaternion(&transform.rotation),
+            );
+
+            let timepoint = if is_static {
+                TimePoint::default()
+            } else {
+                timepoint(sample, Some(header))
+            };
+
+            self.log(
+                self.frames.entity_path(child_frame_id),
+                timepoint,
+                &transform,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_odometry(&mut self, sample: &Ros2Sample) -> Result<(), Ros2BridgeError> {
+        let nav_msgs::Odometry {
+            header,
+            child_frame_id,
+            pose,
+            twist,
+        } = decode::<nav_msgs::Odometry>(sample)?;
+
+        let timepoint = timepoint(sample, Some(&header));
+
+        // The pose describes `child_frame_id` relative to `header.frame_id`, just like a transform.
+        self.frames.set_parent(&header.frame_id, &child_frame_id);
+        let transform = Transform3D::from_translation_rotation(
+            [
+                pose.pose.position.x as f32,
+                pose.pose.position.y as f32,
+                pose.pose.position.z as f32,
+            ],
+            quaternion(&pose.pose.orientation),
+        );
+        self.log(
+            self.frames.entity_path(&child_frame_id),
+            timepoint.clone(),
+            &transform,
+        )?;
+
+        // The twist is expressed in `child_frame_id`.
+        let entity_path = self.entity_path(&sample.topic, &child_frame_id);
+        if self.described_series.insert(entity_path.clone()) {
+            let series = SeriesLines::new().with_names([
+                "linear/x",
+                "linear/y",
+                "linear/z",
+                "angular/x",
+                "angular/y",
+                "angular/z",
+            ]);
+            self.log(entity_path.clone(), TimePoint::default(), &series)?;
+        }
+
+        let geometry_msgs::Twist { linear, angular } = &twist.twist;
+        let scalars = Scalars::new([
+            linear.x, linear.y, linear.z, angular.x, angular.y, angular.z,
+        ]);
+        self.log(entity_path, timepoint, &scalars)
+    }
+
+    fn handle_path(&mut self, sample: &Ros2Sample) -> Result<(), Ros2BridgeError> {
+        let nav_msgs::Path { header, poses } = decode::<nav_msgs::Path>(sample)?;
+
+        let strip = LineStrip3D::from_iter(poses.iter().map(|pose| {
+            let position = &pose.pose.position;
+            [position.x as f32, position.y as f32, position.z as f32]
+        }));
+
+        self.log(
+            self.entity_path(&sample.topic, &header.frame_id),
+            timepoint(sample, Some(&header)),
+            &LineStrips3D::new([strip]),
+        )
+    }
+
+    /// Reuses the parsers of the MCAP importer, treating each message as a single-row MCAP channel.
+    fn handle_with_mcap_parser(&mut self, sample: &Ros2Sample) -> Result<(), Ros2BridgeError> {
+        let channel = self.channel(sample);
+
+        let Some(mut parser) = self.ros2_layer.message_parser(&channel, 1) else {
+            // The layer already warned about the unsupported message type.
+            return Ok(());
+        };
+
+        // All supported `sensor_msgs` start with a header, other messages are logged at their topic.
+        let frame_id = if sample.type_name.starts_with("sensor_msgs/") {
+            cdr::try_decode_message::<Stamped>(&sample.payload)
+                .map(|stamped| stamped.header.frame_id)
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        let mut ctx = ParserContext::new(self.entity_path(&sample.topic, &frame_id));
+        ctx.add_timepoint(timepoint(sample, None));
+
+        let log_time = TimeCell::try_from(sample.receive_time)
+            .map(|cell| u64::try_from(cell.as_i64()).unwrap_or_default())
+            .unwrap_or_default();
+
+        let msg = mcap::Message {
+            channel,
+            sequence: 0,
+            log_time,
+            publish_time: log_time,
+            data: Cow::Borrowed(&sample.payload),
+        };
+
+        let convert_err = |err| Ros2BridgeError::Convert {
+            topic: sample.topic.clone(),
+            type_name: sample.type_name.clone(),
+            err,
+        };
+
+        parser.append(&mut ctx, &msg).map_err(convert_err)?;
+        let chunks = parser.finalize(ctx).map_err(convert_err)?;
+        self.rec.send_chunks(chunks);
+
+        Ok(())
+    }
+
+    fn channel(&mut self, sample: &Ros2Sample) -> Arc<mcap::Channel<'static>> {
+        let next_id = u16::try_from(self.channels.len()).unwrap_or(u16::MAX);
+
+        self.channels
+            .entry((sample.topic.clone(), sample.type_name.clone()))
+            .or_insert_with(|| {
+                Arc::new(mcap::Channel {
+                    id: next_id,
+                    topic: sample.topic.clone(),
+                    schema: Some(Arc::new(mcap::Schema {
+                        id: next_id,
+                        name: sample.type_name.clone(),
+                        encoding: "ros2msg".to_owned(),
+                        data: Cow::Borrowed(&[]),
+                    })),
+                    message_encoding: "cdr".to_owned(),
+                    metadata: BTreeMap::default(),
+                })
+            })
+            .clone()
+    }
+
+    /// Data is logged at its topic, below the entity of its frame (if any).
+    fn entity_path(&self, topic: &str, frame_id: &str) -> EntityPath {
+        let topic = EntityPath::from(topic);
+
+        if frame_id.is_empty() {
+            topic
+        } else {
+            self.frames.entity_path(frame_id).join(&topic)
+        }
+    }
+
+    fn log(
+        &self,
+        entity_path: EntityPath,
+        timepoint: TimePoint,
+        archetype: &dyn AsComponents,
+    ) -> Result<(), Ros2BridgeError> {
+        let chunk = Chunk::builder(entity_path)
+            .with_archetype(RowId::new(), timepoint, archetype)
+            .build()?;
+        self.rec.send_chunk(chunk);
+        Ok(())
+    }
+}
+
+/// Only the header of a message, used to find the frame of arbitrary stamped messages.
+#[derive(serde::Deserialize)]
+struct Stamped {
+    header: std_msgs::Header,
+}
+
+fn decode<T: serde::de::DeserializeOwned>(sample: &Ros2Sample) -> Result<T, Ros2BridgeError> {
+    cdr::try_decode_message::<T>(&sample.payload).map_err(|err| Ros2BridgeError::Decode {
+        topic: sample.topic.clone(),
+        type_name: sample.type_name.clone(),
+        err,
+    })
+}
+
+fn timepoint(sample: &Ros2Sample, header: Option<&std_msgs::Header>) -> TimePoint {
+    let mut timepoint = TimePoint::default();
+
+    if let Ok(log_time) = TimeCell::try_from(sample.receive_time) {
+        timepoint.insert_cell("log_time", log_time);
+    }
+
+    if let Some(header) = header {
+        timepoint.insert_cell(
+            "timestamp",
+            TimeCell::from_timestamp_nanos_since_epoch(header.stamp.as_nanos()),
+        );
+    }
+
+    timepoint
+}
+
+fn vec3(v: &geometry_msgs::Vector3) -> [f32; 3] {
+    [v.x as f32, v.y as f32, v.z as f32]
+}
+
+fn quaternion(q: &geometry_msgs::Quaternion) -> Quaternion {
+    Quaternion::from_xyzw([q.x as f32, q.y as f32, q.z as f32, q.w as f32])
+}
@@ -0,0 +1,58 @@
+use crate::{DEFAULT_ZENOH_ENDPOINT, Ros2Bridge, Ros2Subscriber};
+
+/// Bridge live ROS 2 topics into a Rerun Viewer or server.
+#[derive(Clone, Debug, clap::Parser)]
+#[clap(author, version, about)]
+pub struct Args {
+    /// The Zenoh router the ROS 2 nodes are connected to (see `rmw_zenoh`).
+    #[clap(long, default_value = DEFAULT_ZENOH_ENDPOINT)]
+    pub zenoh_endpoint: String,
+
+    /// The ROS 2 domain to bridge.
+    #[clap(long, env = "ROS_DOMAIN_ID", default_value_t = 0)]
+    pub domain_id: u32,
+
+    /// Only bridge this topic (can be specified multiple times).
+    ///
+    /// All topics are bridged if none are specified.
+    #[clap(long = "topic", short = 't')]
+    pub topics: Vec<String>,
+
+    /// The Rerun Viewer or server to send the converted data to.
+    #[clap(long, default_value = re_sdk::DEFAULT_CONNECT_URL)]
+    pub connect: String,
+
+    /// The application id of the resulting recording.
+    #[clap(long, default_value = "rerun_ros2_bridge")]
+    pub application_id: String,
+}
+
+impl Args {
+    pub fn run(self) -> anyhow::Result<()> {
+        let Self {
+            zenoh_endpoint,
+            domain_id,
+            topics,
+            connect,
+            application_id,
+        } = self;
+
+        let rec = re_sdk::RecordingStreamBuilder::new(application_id).connect_grpc_opts(connect)?;
+        let subscriber = Ros2Subscriber::connect(&zenoh_endpoint, domain_id)?;
+        let mut bridge = Ros2Bridge::new(rec.clone());
+
+        while let Some(sample) = subscriber.recv() {
+            if !topics.is_empty() && !topics.contains(&sample.topic) {
+                continue;
+            }
+
+            if let Err(err) = bridge.handle_sample(&sample) {
+                re_log::warn_once!("{err}");
+            }
+        }
+
+        rec.flush_blocking()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::{BTreeSet, HashMap};
+
+use re_log_types::{EntityPath, EntityPathPart};
+
+/// All `tf2` frames are logged below this entity.
+pub const TF_ENTITY_PATH_PREFIX: &str = "tf";
+
+/// Keeps track of the `tf2` frame hierarchy and maps frames onto entity paths.
+///
+/// Rerun derives the transform hierarchy from the entity hierarchy, so every frame
+/// is logged as a child of its parent frame, e.g. `/tf/map/odom/base_link`.
+#[derive(Default, Debug)]
+pub struct FrameTree {
+    /// Maps a child frame to its parent frame.
+    parents: HashMap<String, String>,
+}
+
+impl FrameTree {
+    /// Records that `child` is attached to `parent`.
+    ///
+    /// Returns `true` if this changed the hierarchy.
+    /// Edges that would introduce a cycle are ignored.
+    pub fn set_parent(&mut self, parent: &str, child: &str) -> bool {
+        let parent = normalize_frame_id(parent);
+        let child = normalize_frame_id(child);
+
+        if parent.is_empty() || child.is_empty() {
+            return false;
+        }
+
+        if self.parents.get(child).is_some_and(|p| p == parent) {
+            return false;
+        }
+
+        if parent == child || self.ancestors(parent).contains(&child) {
+            re_log::warn_once!(
+                "Ignoring transform from {parent:?} to {child:?}: it would introduce a cycle in the frame hierarchy"
+            );
+            return false;
+        }
+
+        if let Some(previous) = self.parents.insert(child.to_owned(), parent.to_owned()) {
+            re_log::debug!("Frame {child:?} was re-parented from {previous:?} to {parent:?}");
+        }
+
+        true
+    }
+
+    /// The parent of the given frame, if known.
+    pub fn parent(&self, frame_id: &str) -> Option<&str> {
+        self.parents
+            .get(normalize_frame_id(frame_id))
+            .map(String::as_str)
+    }
+
+    /// The entity path of the given frame, e.g. `/tf/map/odom/base_link`.
+    pub fn entity_path(&self, frame_id: &str) -> EntityPath {
+        let frame_id = normalize_frame_id(frame_id);
+
+        let mut parts = vec![EntityPathPart::new(frame_id)];
+        parts.extend(
+            self.ancestors(frame_id)
+                .into_iter()
+                .map(EntityPathPart::new),
+        );
+        parts.push(EntityPathPart::new(TF_ENTITY_PATH_PREFIX));
+        parts.reverse();
+
+        EntityPath::new(parts)
+    }
+
+    /// All ancestors of `frame_id`, starting with its parent.
+    fn ancestors<'a>(&'a self, frame_id: &str) -> Vec<&'a str> {
+        let mut ancestors = Vec::new();
+        let mut visited = BTreeSet::new();
+
+        let mut current = frame_id;
+        while let Some(parent) = self.parents.get(current) {
+            if !visited.insert(parent.as_str()) {
+                break;
+            }
+            ancestors.push(parent.as_str());
+            current = parent.as_str();
+        }
+
+        ancestors
+    }
+}
+
+/// ROS 1 style frame ids could have a leading slash, which `tf2` ignores.
+fn normalize_frame_id(frame_id: &str) -> &str {
+    frame_id.trim_start_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_hierarchy_to_entity_paths() {
+        let mut frames = FrameTree::default();
+
+        assert_eq!(
+            frames.entity_path("base_link"),
+            EntityPath::from("/tf/base_link")
+        );
+
+        assert!(frames.set_parent("odom", "base_link"));
+        assert!(frames.set_parent("map", "odom"));
+        assert!(frames.set_parent("/base_link", "camera_link"));
+        assert!(!frames.set_parent("map", "odom"));
+
+        assert_eq!(frames.parent("base_link"), Some("odom"));
+        assert_eq!(
+            frames.entity_path("camera_link"),
+            EntityPath::from("/tf/map/odom/base_link/camera_link")
+        );
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let mut frames = FrameTree::default();
+
+        assert!(frames.set_parent("map", "odom"));
+        assert!(frames.set_parent("odom", "base_link"));
+        assert!(!frames.set_parent("base_link", "map"));
+        assert!(!frames.set_parent("map", "map"));
+
+        assert_eq!(frames.parent("map"), None);
+        assert_eq!(
+            frames.entity_path("base_link"),
+            EntityPath::from("/tf/map/odom/base_link")
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Bridge live ROS 2 topics into Rerun.
+//!
+//! Subscribes to the topics of a ROS 2 domain and converts `sensor_msgs`, `nav_msgs` and
+//! `tf2_msgs` messages into Rerun archetypes, see [`Ros2Bridge`] for the details of the conversion.
+//!
+//! Messages are received via [`rmw_zenoh`](https://github.com/ros2/rmw_zenoh),
+//! so the ROS 2 nodes need to run with `RMW_IMPLEMENTATION=rmw_zenoh_cpp`.
+//!
+//! This is usually run via the CLI: `rerun ros2-bridge --help`.
+
+mod bridge;
+mod entrypoint;
+mod frames;
+mod transport;
+
+pub use self::{
+    bridge::Ros2Bridge,
+    entrypoint::Args,
+    frames::{FrameTree, TF_ENTITY_PATH_PREFIX},
+    transport::{DEFAULT_ZENOH_ENDPOINT, Ros2Sample, Ros2Subscriber},
+};
+
+/// Errors that can occur while bridging ROS 2 messages.
+#[derive(thiserror::Error, Debug)]
+pub enum Ros2BridgeError {
+    #[error("Zenoh error: {0}")]
+    Zenoh(zenoh::Error),
+
+    #[error("Failed to decode {type_name:?} message on topic {topic:?}: {err}")]
+    Decode {
+        topic: String,
+        type_name: String,
+        err: re_mcap::cdr::CdrError,
+    },
+
+    #[error("Failed to convert {type_name:?} message on topic {topic:?}: {err}")]
+    Convert {
+        topic: String,
+        type_name: String,
+        err: anyhow::Error,
+    },
+
+    #[error(transparent)]
+    Chunk(#[from] re_chunk::ChunkError),
+}
@@ -0,0 +1,159 @@
+//! Receives ROS 2 messages published through [`rmw_zenoh`](https://github.com/ros2/rmw_zenoh).
+//!
+//! `rmw_zenoh` publishes every topic on a key expression of the form
+//! `<domain_id>/<topic>/<type_name>/<type_hash>`, with a CDR encoded payload.
+
+use std::time::SystemTime;
+
+use zenoh::Wait as _;
+
+use crate::Ros2BridgeError;
+
+/// The default endpoint of the Zenoh router started by `ros2 run rmw_zenoh_cpp rmw_zenohd`.
+pub const DEFAULT_ZENOH_ENDPOINT: &str = "tcp/localhost:7447";
+
+/// A single, still encoded, message received on a ROS 2 topic.
+#[derive(Clone, Debug)]
+pub struct Ros2Sample {
+    /// The fully qualified topic name, e.g. `/camera/image_raw`.
+    pub topic: String,
+
+    /// The ROS 2 message type, e.g. `sensor_msgs/msg/Image`.
+    pub type_name: String,
+
+    /// The CDR encoded message, including the encapsulation header.
+    pub payload: Vec<u8>,
+
+    /// When the bridge received this message.
+    pub receive_time: SystemTime,
+}
+
+/// Subscribes to all topics of a ROS 2 domain.
+pub struct Ros2Subscriber {
+    domain_id: u32,
+    subscriber:
+        zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>,
+
+    // Keep the session alive for as long as we are subscribed.
+    _session: zenoh::Session,
+}
+
+impl Ros2Subscriber {
+    /// Connects to the Zenoh router at `endpoint` and subscribes to all topics of `domain_id`.
+    pub fn connect(endpoint: &str, domain_id: u32) -> Result<Self, Ros2BridgeError> {
+        let mut config = zenoh::Config::default();
+        config
+            .insert_json5("mode", r#""client""#)
+            .map_err(Ros2BridgeError::Zenoh)?;
+        config
+            .insert_json5("connect/endpoints", &format!("[{endpoint:?}]"))
+            .map_err(Ros2BridgeError::Zenoh)?;
+
+        let session = zenoh::open(config).wait().map_err(Ros2BridgeError::Zenoh)?;
+        let subscriber = session
+            .declare_subscriber(format!("{domain_id}/**"))
+            .wait()
+            .map_err(Ros2BridgeError::Zenoh)?;
+
+        re_log::info!("Subscribed to ROS 2 domain {domain_id} via Zenoh router at {endpoint:?}");
+
+        Ok(Self {
+            domain_id,
+            subscriber,
+            _session: session,
+        })
+    }
+
+    /// Blocks until the next ROS 2 message arrives.
+    ///
+    /// Returns `None` once the Zenoh session has been closed.
+    pub fn recv(&self) -> Option<Ros2Sample> {
+        loop {
+            let sample = self.subscriber.recv().ok()?;
+
+            let key_expr = sample.key_expr().as_str();
+            let Some((topic, type_name)) = parse_key_expr(key_expr, self.domain_id) else {
+                re_log::debug_once!(
+                    "Ignoring Zenoh sample with unexpected key expression {key_expr:?}"
+                );
+                continue;
+            };
+
+            return Some(Ros2Sample {
+                topic,
+                type_name,
+                payload: sample.payload().to_bytes().into_owned(),
+                receive_time: SystemTime::now(),
+            });
+        }
+    }
+}
+
+/// Splits an `rmw_zenoh` key expression into the topic name and the ROS 2 message type.
+///
+/// `0/camera/image_raw/sensor_msgs::msg::dds_::Image_/RIHS01_…` becomes
+/// `("/camera/image_raw", "sensor_msgs/msg/Image")`.
+fn parse_key_expr(key_expr: &str, domain_id: u32) -> Option<(String, String)> {
+    let mut parts = key_expr.split('/').collect::<Vec<_>>();
+
+    // <domain_id>, at least one topic segment, <type_name>, <type_hash>
+    if parts.len() < 4 || parts[0] != domain_id.to_string() {
+        return None;
+    }
+
+    let _type_hash = parts.pop()?;
+    let dds_type_name = parts.pop()?;
+    let topic = format!("/{}", parts[1..].join("/"));
+
+    Some((topic, ros2_type_name(dds_type_name)?))
+}
+
+/// Converts the mangled DDS type name `sensor_msgs::msg::dds_::Image_` to `sensor_msgs/msg/Image`.
+fn ros2_type_name(dds_type_name: &str) -> Option<String> {
+    let parts = dds_type_name
+        .split("::")
+        .filter(|part| *part != "dds_")
+        .collect::<Vec<_>>();
+
+    let (name, namespace) = parts.split_last()?;
+    let name = name.strip_suffix('_').unwrap_or(name);
+
+    if namespace.is_empty() || name.is_empty() {
+        return None;
+    }
+
+    Some(format!("{}/{name}", namespace.join("/")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rmw_zenoh_key_expr() {
+        assert_eq!(
+            parse_key_expr(
+                "0/camera/image_raw/sensor_msgs::msg::dds_::Image_/RIHS01_d31d41a9a4c4bc8eae9be757b0beed306564f7526c88ea6a4588fb9582527d47",
+                0
+            ),
+            Some((
+                "/camera/image_raw".to_owned(),
+                "sensor_msgs/msg/Image".to_owned()
+            ))
+        );
+
+        assert_eq!(
+            parse_key_expr("42/tf/tf2_msgs::msg::dds_::TFMessage_/RIHS01_abc", 42),
+            Some(("/tf".to_owned(), "tf2_msgs/msg/TFMessage".to_owned()))
+        );
+
+        // Wrong domain.
+        assert_eq!(
+            parse_key_expr("1/tf/tf2_msgs::msg::dds_::TFMessage_/RIHS01_abc", 0),
+            None
+        );
+
+        // Not a topic.
+        assert_eq!(parse_key_expr("0/tf", 0), None);
+    }
+}
@@ -0,0 +1,793 @@
+//! Build blueprints from Rust: the layout and configuration of the viewer.
+//!
+//! A [`Blueprint`] is a tree of [`Container`]s and [`View`]s, plus the state of the viewer's panels.
+//! Send it to the viewer using [`RecordingStream::send_blueprint_with_options`].
+//!
+//! ```no_run
+//! use re_sdk::blueprint::{Blueprint, BlueprintSendOptions, Container, View};
+//! use re_sdk::blueprint::archetypes::VisualBounds2D;
+//! use re_types::datatypes::Range2D;
+//! use re_sdk::blueprint::views::{Spatial2DView, Spatial3DView, TimeSeriesView};
+//!
+//! let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_blueprint").connect_grpc()?;
+//!
+//! let blueprint = Blueprint::new(Container::horizontal([
+//!     View::new::<Spatial3DView>("/world").with_name("3D").into(),
+//!     Container::vertical([
+//!         View::new::<Spatial2DView>("/world/camera")
+//!             .with_visual_bounds(VisualBounds2D::new(Range2D {
+//!                 x_range: [0.0, 640.0].into(),
+//!                 y_range: [0.0, 480.0].into(),
+//!             }))
+//!             .into(),
+//!         View::new::<TimeSeriesView>("/")
+//!             .with_contents(["+ /metrics/**", "- /metrics/debug/**"])
+//!             .into(),
+//!     ])
+//!     .with_row_shares([2.0, 1.0])
+//!     .into(),
+//! ]))
+//! .with_collapse_panels(true);
+//!
+//! rec.send_blueprint_with_options(blueprint, BlueprintSendOptions::default());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! This mirrors the blueprint API of the Python SDK, and logs the exact same data.
+
+use re_log_types::{
+    ApplicationId, BlueprintActivationCommand, EntityPath, LogMsg, RecordingId, StoreId,
+};
+use re_types::{
+    Archetype, AsComponents, SerializedComponentBatch, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{
+            ContainerBlueprint, PanelBlueprint, ViewBlueprint, ViewContents, ViewportBlueprint,
+            VisualizerOverrides,
+        },
+        components::{ContainerKind, PanelState},
+    },
+};
+
+use crate::{RecordingStream, RecordingStreamBuilder, RecordingStreamResult};
+
+pub use re_types::blueprint::{archetypes, components, datatypes, views};
+
+/// The timeline all blueprint data is logged on.
+const BLUEPRINT_TIMELINE: &str = "blueprint";
+
+// ---
+
+/// A view of some data, e.g. a 3D scene or a time series plot.
+///
+/// This is an ergonomic helper on top of [`ViewBlueprint`].
+#[derive(Clone, Debug)]
+pub struct View {
+    id: uuid::Uuid,
+    class_identifier: ViewClassIdentifier,
+    name: Option<String>,
+    origin: EntityPath,
+    contents: Vec<String>,
+    visible: Option<bool>,
+    properties: Vec<(&'static str, Vec<SerializedComponentBatch>)>,
+    defaults: Vec<SerializedComponentBatch>,
+    overrides: Vec<(EntityPath, Vec<SerializedComponentBatch>)>,
+}
+
+impl View {
+    /// A new view of the given type, e.g. [`views::Spatial3DView`].
+    ///
+    /// All entities below `origin` are shown, and transformed to be displayed relative to it.
+    pub fn new<V: re_types::View>(origin: impl Into<EntityPath>) -> Self {
+        Self::from_class_identifier(V::identifier(), origin)
+    }
+
+    /// A new view of the given class, for views that are not part of [`views`].
+    ///
+    /// The class identifier must correspond to a view class known to the viewer.
+    pub fn from_class_identifier(
+        class_identifier: impl Into<ViewClassIdentifier>,
+        origin: impl Into<EntityPath>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            class_identifier: class_identifier.into(),
+            name: None,
+            origin: origin.into(),
+            contents: vec!["$origin/**".to_owned()],
+            visible: None,
+            properties: Vec::new(),
+            defaults: Vec::new(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// The name of the view, as shown in the viewer.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Which entities are part of this view.
+    ///
+    /// Each entry is an entity filter expression, e.g. `+ $origin/**` or `- /world/debug/**`.
+    /// See [`ViewContents`] for the full syntax.
+    /// Defaults to `$origin/**`.
+    #[inline]
+    pub fn with_contents(mut self, contents: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.contents = contents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether this view is visible.
+    ///
+    /// Defaults to true if not specified.
+    #[inline]
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets a view property, e.g. [`archetypes::Background`] or [`archetypes::PlotLegend`].
+    ///
+    /// Which properties are supported depends on the view class,
+    /// see the fields of the corresponding type in [`views`].
+    #[inline]
+    pub fn with_property<A: Archetype + AsComponents>(mut self, property: &A) -> Self {
+        self.properties
+            .push((A::name().short_name(), property.as_serialized_batches()));
+        self
+    }
+
+    /// The visible region of a 2D view.
+    ///
+    /// Shorthand for [`Self::with_property`] with a [`archetypes::VisualBounds2D`].
+    #[inline]
+    pub fn with_visual_bounds(self, visual_bounds: archetypes::VisualBounds2D) -> Self {
+        self.with_property(&visual_bounds)
+    }
+
+    /// Default values for components of all entities in this view.
+    ///
+    /// When an archetype in the view is missing a component included here,
+    /// this value is used instead of the normal fallback of the visualizer.
+    ///
+    /// Prefer partial archetypes (e.g. `Points3D::update_fields().with_radii([0.1])`),
+    /// since the required components of an archetype typically have no effect here.
+    #[inline]
+    pub fn with_defaults(mut self, defaults: &dyn AsComponents) -> Self {
+        self.defaults.extend(defaults.as_serialized_batches());
+        self
+    }
+
+    /// Overrides components of a single entity in this view.
+    ///
+    /// The path must be the fully qualified entity path, `$origin` and wildcards are not supported.
+    #[inline]
+    pub fn with_overrides(
+        mut self,
+        entity_path: impl Into<EntityPath>,
+        overrides: &dyn AsComponents,
+    ) -> Self {
+        self.overrides
+            .push((entity_path.into(), overrides.as_serialized_batches()));
+        self
+    }
+
+    /// Chooses which visualizers are used for a single entity in this view.
+    ///
+    /// By default the viewer picks the visualizers based on the archetypes logged to the entity.
+    /// Use this to e.g. show a scalar series as points instead of lines: `["SeriesPoints"]`.
+    /// The names are the identifiers of the visualizers, as shown in the selection panel.
+    ///
+    /// The path must be the fully qualified entity path, `$origin` and wildcards are not supported.
+    #[inline]
+    pub fn with_visualizers(
+        mut self,
+        entity_path: impl Into<EntityPath>,
+        visualizers: impl IntoIterator<Item = impl Into<components::VisualizerOverride>>,
+    ) -> Self {
+        self.overrides.push((
+            entity_path.into(),
+            VisualizerOverrides::new(visualizers).as_serialized_batches(),
+        ));
+        self
+    }
+
+    /// The path of this view in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from(format!("view/{}", self.id))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let path = self.blueprint_path();
+
+        stream.log(
+            path.join(&EntityPath::from("ViewContents")),
+            &ViewContents::new(self.contents.iter().map(String::as_str)),
+        )?;
+
+        let mut view =
+            ViewBlueprint::new(self.class_identifier.as_str()).with_space_origin(&self.origin);
+        if let Some(name) = &self.name {
+            view = view.with_display_name(name.as_str());
+        }
+        if let Some(visible) = self.visible {
+            view = view.with_visible(visible);
+        }
+        stream.log(path.clone(), &view)?;
+
+        for (property_name, property) in &self.properties {
+            stream.log(
+                path.join(&EntityPath::from_single_string(*property_name)),
+                property,
+            )?;
+        }
+
+        if !self.defaults.is_empty() {
+            stream.log(path.join(&EntityPath::from("defaults")), &self.defaults)?;
+        }
+
+        let overrides_path = path.join(&EntityPath::from("ViewContents/overrides"));
+        for (entity_path, overrides) in &self.overrides {
+            stream.log(overrides_path.join(entity_path), overrides)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Either a [`Container`] or a [`View`].
+#[derive(Clone, Debug)]
+pub enum ContainerLike {
+    /// A container of other containers and views.
+    Container(Container),
+
+    /// A single view.
+    View(View),
+}
+
+impl ContainerLike {
+    /// The path of this container or view in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        match self {
+            Self::Container(container) => container.blueprint_path(),
+            Self::View(view) => view.blueprint_path(),
+        }
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        match self {
+            Self::Container(container) => container.log_to_stream(stream),
+            Self::View(view) => view.log_to_stream(stream),
+        }
+    }
+}
+
+impl From<Container> for ContainerLike {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::Container(container)
+    }
+}
+
+impl From<View> for ContainerLike {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::View(view)
+    }
+}
+
+/// Arranges [`View`]s and other containers, e.g. side by side or as tabs.
+///
+/// This is an ergonomic helper on top of [`ContainerBlueprint`].
+#[derive(Clone, Debug)]
+pub struct Container {
+    id: uuid::Uuid,
+    kind: ContainerKind,
+    contents: Vec<ContainerLike>,
+    column_shares: Option<Vec<f32>>,
+    row_shares: Option<Vec<f32>>,
+    grid_columns: Option<u32>,
+    active_tab: Option<usize>,
+    name: Option<String>,
+}
+
+impl Container {
+    /// A new container of the given kind.
+    ///
+    /// Prefer [`Self::horizontal`], [`Self::vertical`], [`Self::grid`] or [`Self::tabs`].
+    pub fn new(kind: ContainerKind, contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4(),
+            kind,
+            contents: contents.into_iter().collect(),
+            column_shares: None,
+            row_shares: None,
+            grid_columns: None,
+            active_tab: None,
+            name: None,
+        }
+    }
+
+    /// Lays out its contents side by side.
+    #[inline]
+    pub fn horizontal(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Horizontal, contents)
+    }
+
+    /// Lays out its contents on top of each other.
+    #[inline]
+    pub fn vertical(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Vertical, contents)
+    }
+
+    /// Lays out its contents in a grid.
+    #[inline]
+    pub fn grid(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Grid, contents)
+    }
+
+    /// Shows one of its contents at a time, as tabs.
+    #[inline]
+    pub fn tabs(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(ContainerKind::Tabs, contents)
+    }
+
+    /// The name of the container, as shown in the viewer.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The relative width of each column.
+    ///
+    /// Column `i` takes up `shares[i] / sum(shares)` of the total width.
+    /// Only applies to horizontal and grid containers.
+    #[inline]
+    pub fn with_column_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.column_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The relative height of each row.
+    ///
+    /// Row `i` takes up `shares[i] / sum(shares)` of the total height.
+    /// Only applies to vertical and grid containers.
+    #[inline]
+    pub fn with_row_shares(mut self, shares: impl IntoIterator<Item = f32>) -> Self {
+        self.row_shares = Some(shares.into_iter().collect());
+        self
+    }
+
+    /// The number of columns of a grid container.
+    ///
+    /// If unset, the grid layout is determined automatically.
+    #[inline]
+    pub fn with_grid_columns(mut self, grid_columns: u32) -> Self {
+        self.grid_columns = Some(grid_columns);
+        self
+    }
+
+    /// The index of the active tab of a tabs container.
+    #[inline]
+    pub fn with_active_tab(mut self, active_tab: usize) -> Self {
+        self.active_tab = Some(active_tab);
+        self
+    }
+
+    /// The path of this container in the blueprint store.
+    pub fn blueprint_path(&self) -> EntityPath {
+        EntityPath::from(format!("container/{}", self.id))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        for content in &self.contents {
+            content.log_to_stream(stream)?;
+        }
+
+        let contents = self
+            .contents
+            .iter()
+            .map(ContainerLike::blueprint_path)
+            .collect::<Vec<_>>();
+
+        let mut container = ContainerBlueprint::new(self.kind)
+            .with_contents(&contents)
+            .with_visible(true);
+
+        if let Some(name) = &self.name {
+            container = container.with_display_name(name.as_str());
+        }
+        if let Some(column_shares) = &self.column_shares {
+            container = container.with_col_shares(column_shares.iter().copied());
+        }
+        if let Some(row_shares) = &self.row_shares {
+            container = container.with_row_shares(row_shares.iter().copied());
+        }
+        if let Some(grid_columns) = self.grid_columns {
+            container = container.with_grid_columns(grid_columns);
+        }
+        if let Some(active_tab) = self.active_tab {
+            if let Some(path) = contents.get(active_tab) {
+                container = container.with_active_tab(path);
+            } else {
+                re_log::warn!(
+                    "Active tab {active_tab} is out of range for a container with {} tabs",
+                    contents.len()
+                );
+            }
+        }
+
+        stream.log(self.blueprint_path(), &container)
+    }
+}
+
+impl From<View> for Container {
+    /// A single view is wrapped in a tabs container.
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::tabs([view.into()])
+    }
+}
+
+// ---
+
+/// The top-level description of the viewer blueprint.
+///
+/// See the [module level documentation](self) for an example.
+#[derive(Clone, Debug, Default)]
+pub struct Blueprint {
+    root_container: Option<Container>,
+    auto_layout: Option<bool>,
+    auto_views: Option<bool>,
+    collapse_panels: bool,
+    top_panel: Option<PanelState>,
+    blueprint_panel: Option<PanelState>,
+    selection_panel: Option<PanelState>,
+    time_panel: Option<PanelState>,
+}
+
+impl Blueprint {
+    /// A blueprint with the given root container.
+    ///
+    /// Views and containers will not be added automatically,
+    /// unless enabled with [`Self::with_auto_layout`] and [`Self::with_auto_views`].
+    pub fn new(root_container: impl Into<Container>) -> Self {
+        Self {
+            root_container: Some(root_container.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A blueprint that only configures the panels, and lets the viewer create the views.
+    pub fn auto() -> Self {
+        Self {
+            auto_layout: Some(true),
+            auto_views: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// A blueprint with several top-level views or containers, each shown as a tab.
+    pub fn from_contents(contents: impl IntoIterator<Item = ContainerLike>) -> Self {
+        Self::new(Container::tabs(contents))
+    }
+
+    /// Whether the container layout is reset whenever a new view is added.
+    #[inline]
+    pub fn with_auto_layout(mut self, auto_layout: bool) -> Self {
+        self.auto_layout = Some(auto_layout);
+        self
+    }
+
+    /// Whether the viewer automatically adds views for the data it receives.
+    #[inline]
+    pub fn with_auto_views(mut self, auto_views: bool) -> Self {
+        self.auto_views = Some(auto_views);
+        self
+    }
+
+    /// Collapses all panels that have not been explicitly configured.
+    ///
+    /// This fully hides the blueprint and selection panels, and shows the simplified time panel.
+    #[inline]
+    pub fn with_collapse_panels(mut self, collapse_panels: bool) -> Self {
+        self.collapse_panels = collapse_panels;
+        self
+    }
+
+    /// The state of the top panel.
+    #[inline]
+    pub fn with_top_panel(mut self, state: PanelState) -> Self {
+        self.top_panel = Some(state);
+        self
+    }
+
+    /// The state of the blueprint panel on the left.
+    #[inline]
+    pub fn with_blueprint_panel(mut self, state: PanelState) -> Self {
+        self.blueprint_panel = Some(state);
+        self
+    }
+
+    /// The state of the selection panel on the right.
+    #[inline]
+    pub fn with_selection_panel(mut self, state: PanelState) -> Self {
+        self.selection_panel = Some(state);
+        self
+    }
+
+    /// The state of the time panel at the bottom.
+    #[inline]
+    pub fn with_time_panel(mut self, state: PanelState) -> Self {
+        self.time_panel = Some(state);
+        self
+    }
+
+    /// Logs this blueprint to a new, in-memory blueprint store of the given application.
+    ///
+    /// Returns the id of the blueprint store and all of its messages,
+    /// ready to be passed to [`RecordingStream::send_blueprint`].
+    pub fn to_log_msgs(
+        &self,
+        application_id: impl Into<ApplicationId>,
+    ) -> RecordingStreamResult<(StoreId, Vec<LogMsg>)> {
+        re_tracing::profile_function!();
+
+        let (stream, storage) = RecordingStreamBuilder::new(application_id)
+            // We don't support additive blueprints, so every blueprint gets a new, unique id.
+            .recording_id(RecordingId::random())
+            .blueprint()
+            // Whether anything gets sent is up to the recording the blueprint is sent to.
+            .enabled(true)
+            .memory()?;
+
+        stream.set_time_sequence(BLUEPRINT_TIMELINE, 0);
+        self.log_to_stream(&stream)?;
+
+        let store_id = storage
+            .store_id()
+            .expect("the blueprint store is always enabled");
+
+        Ok((store_id, storage.take()))
+    }
+
+    fn log_to_stream(&self, stream: &RecordingStream) -> RecordingStreamResult<()> {
+        let mut viewport = ViewportBlueprint::new();
+
+        if let Some(root_container) = &self.root_container {
+            root_container.log_to_stream(stream)?;
+            viewport = viewport.with_root_container(root_container.id);
+        }
+        if let Some(auto_layout) = self.auto_layout {
+            viewport = viewport.with_auto_layout(auto_layout);
+        }
+        if let Some(auto_views) = self.auto_views {
+            viewport = viewport.with_auto_views(auto_views);
+        }
+
+        stream.log("viewport", &viewport)?;
+
+        let collapsed = self.collapse_panels.then_some(PanelState::Collapsed);
+        for (path, state) in [
+            ("top_panel", self.top_panel),
+            ("blueprint_panel", self.blueprint_panel.or(collapsed)),
+            ("selection_panel", self.selection_panel.or(collapsed)),
+            ("time_panel", self.time_panel.or(collapsed)),
+        ] {
+            if let Some(state) = state {
+                stream.log(path, &PanelBlueprint::new().with_state(state))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<View> for Blueprint {
+    #[inline]
+    fn from(view: View) -> Self {
+        Self::new(view)
+    }
+}
+
+impl From<Container> for Blueprint {
+    #[inline]
+    fn from(container: Container) -> Self {
+        Self::new(container)
+    }
+}
+
+// ---
+
+/// How a blueprint sent with [`RecordingStream::send_blueprint_with_options`] is used by the viewer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlueprintSendOptions {
+    /// Immediately make this the active blueprint for the application.
+    ///
+    /// Note that setting this to `false` does not mean the blueprint may not still end
+    /// up becoming active. In particular, if `make_default` is true and there is no other
+    /// currently active blueprint.
+    pub make_active: bool,
+
+    /// Make this the default blueprint for the application.
+    ///
+    /// The default blueprint will be used as the template when the user resets the
+    /// blueprint for the app. It will also become the active blueprint if no other
+    /// blueprint is currently active.
+    pub make_default: bool,
+}
+
+impl Default for BlueprintSendOptions {
+    fn default() -> Self {
+        Self {
+            make_active: false,
+            make_default: true,
+        }
+    }
+}
+
+impl RecordingStream {
+    /// Send a blueprint to the viewer, for the application of this recording.
+    ///
+    /// See [`crate::blueprint`] for how to build one.
+    pub fn send_blueprint_with_options(
+        &self,
+        blueprint: impl Into<Blueprint>,
+        options: BlueprintSendOptions,
+    ) {
+        let Some(store_info) = self.store_info() else {
+            re_log::warn_once!(
+                "Recording disabled - call to send_blueprint_with_options() ignored"
+            );
+            return;
+        };
+
+        let (blueprint_id, msgs) = match blueprint
+            .into()
+            .to_log_msgs(store_info.application_id().clone())
+        {
+            Ok(blueprint) => blueprint,
+            Err(err) => {
+                re_log::error!("Failed to build blueprint: {err}");
+                return;
+            }
+        };
+
+        let BlueprintSendOptions {
+            make_active,
+            make_default,
+        } = options;
+
+        self.send_blueprint(
+            msgs,
+            BlueprintActivationCommand {
+                blueprint_id,
+                make_active,
+                make_default,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_types::blueprint::views::{Spatial2DView, Spatial3DView};
+
+    use super::*;
+
+    fn logged_entity_paths(msgs: &[LogMsg]) -> Vec<String> {
+        let mut paths = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(
+                    Chunk::from_arrow_msg(arrow_msg)
+                        .unwrap()
+                        .entity_path()
+                        .to_string(),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    #[test]
+    fn blueprint_layout() {
+        let view_3d = View::new::<Spatial3DView>("/world").with_name("3D");
+        let view_2d = View::new::<Spatial2DView>("/world/camera")
+            .with_contents(["+ $origin/**", "- $origin/debug/**"])
+            .with_visual_bounds(archetypes::VisualBounds2D::new(
+                re_types::datatypes::Range2D {
+                    x_range: [0.0, 640.0].into(),
+                    y_range: [0.0, 480.0].into(),
+                },
+            ))
+            .with_overrides(
+                "/world/camera/points",
+                &re_types::archetypes::Points2D::update_fields().with_radii([2.0]),
+            );
+
+        let view_3d_path = view_3d.blueprint_path().to_string();
+        let view_2d_path = view_2d.blueprint_path().to_string();
+
+        let root =
+            Container::horizontal([view_3d.into(), view_2d.into()]).with_column_shares([2.0, 1.0]);
+        let root_path = root.blueprint_path().to_string();
+
+        let blueprint = Blueprint::new(root).with_collapse_panels(true);
+        let (store_id, msgs) = blueprint.to_log_msgs("rerun_example_blueprint").unwrap();
+
+        assert!(!store_id.is_recording());
+        assert!(
+            msgs.iter().all(|msg| msg.store_id() == &store_id),
+            "all messages belong to the blueprint store"
+        );
+
+        let mut expected = vec![
+            root_path,
+            view_3d_path.clone(),
+            format!("{view_3d_path}/ViewContents"),
+            view_2d_path.clone(),
+            format!("{view_2d_path}/ViewContents"),
+            format!("{view_2d_path}/VisualBounds2D"),
+            format!("{view_2d_path}/ViewContents/overrides/world/camera/points"),
+            "/viewport".to_owned(),
+            "/blueprint_panel".to_owned(),
+            "/selection_panel".to_owned(),
+            "/time_panel".to_owned(),
+        ];
+        expected.sort();
+
+        assert_eq!(logged_entity_paths(&msgs), expected);
+    }
+
+    #[test]
+    fn visualizer_overrides() {
+        let view = View::new::<re_types::blueprint::views::TimeSeriesView>("/")
+            .with_visualizers("/metrics/loss", ["SeriesPoints"])
+            .with_overrides(
+                "/metrics/loss",
+                &re_types::archetypes::SeriesPoints::update_fields().with_marker_sizes([4.0]),
+            );
+        let view_path = view.blueprint_path().to_string();
+
+        let (_, msgs) = Blueprint::new(view)
+            .to_log_msgs("rerun_example_blueprint")
+            .unwrap();
+
+        let override_path =
+            EntityPath::from(format!("{view_path}/ViewContents/overrides/metrics/loss"));
+        let components = msgs
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(Chunk::from_arrow_msg(arrow_msg).unwrap()),
+                _ => None,
+            })
+            .filter(|chunk| chunk.entity_path() == &override_path)
+            .flat_map(|chunk| chunk.component_descriptors().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert!(components.contains(&VisualizerOverrides::descriptor_ranges()));
+        assert!(
+            components.contains(&re_types::archetypes::SeriesPoints::descriptor_marker_sizes())
+        );
+    }
+
+    #[test]
+    fn auto_blueprint() {
+        let (_, msgs) = Blueprint::auto()
+            .to_log_msgs("rerun_example_blueprint")
+            .unwrap();
+
+        assert_eq!(logged_entity_paths(&msgs), ["/viewport"]);
+    }
+}
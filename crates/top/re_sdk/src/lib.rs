@@ -18,8 +18,12 @@
 mod binary_stream_sink;
 mod global;
 mod log_sink;
+#[cfg(all(feature = "mcap", not(target_arch = "wasm32")))]
+mod mcap_sink;
 mod recording_stream;
 mod spawn;
+mod strict_schema;
+mod transaction;
 
 // -------------
 // Public items:
@@ -30,6 +34,11 @@ pub use self::recording_stream::{
     RecordingStream, RecordingStreamBuilder, RecordingStreamError, RecordingStreamResult,
     forced_sink_path,
 };
+pub use self::strict_schema::SchemaMismatchError;
+pub use self::transaction::Transaction;
+
+#[cfg(feature = "async_flush")]
+pub use self::recording_stream::FlushFuture;
 
 /// The default port of a Rerun gRPC /proxy server.
 pub const DEFAULT_SERVER_PORT: u16 = re_uri::DEFAULT_PROXY_PORT;
@@ -78,6 +87,8 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
 // ---------------
 // Public modules:
 
+pub mod blueprint;
+
 /// Different destinations for log messages.
 ///
 /// This is how you select whether the log stream ends up
@@ -89,10 +100,18 @@ pub mod sink {
         MultiSink, SinkFlushError,
     };
 
-    pub use crate::log_sink::{GrpcSink, GrpcSinkConnectionFailure, GrpcSinkConnectionState};
+    pub use crate::log_sink::{
+        GrpcSink, GrpcSinkConnectionFailure, GrpcSinkConnectionState, GrpcSinkSharedMemoryOptions,
+    };
 
     #[cfg(not(target_arch = "wasm32"))]
     pub use re_log_encoding::{FileSink, FileSinkError};
+
+    #[cfg(all(feature = "mcap", not(target_arch = "wasm32")))]
+    pub use crate::mcap_sink::{McapSink, McapSinkError};
+
+    #[cfg(all(feature = "mcap", not(target_arch = "wasm32")))]
+    pub use re_mcap::{McapExportOptions, McapMessageEncoding};
 }
 
 /// Things directly related to logging.
@@ -106,9 +125,11 @@ pub mod log {
 
 /// Time-related types.
 pub mod time {
-    pub use re_log_types::{Duration, TimeCell, TimeInt, TimePoint, TimeType, Timeline, Timestamp};
+    pub use re_log_types::{
+        Clock, Duration, TimeCell, TimeInt, TimePoint, TimeType, Timeline, Timestamp,
+    };
 }
-pub use time::{TimeCell, TimePoint, Timeline};
+pub use time::{Clock, TimeCell, TimePoint, Timeline};
 
 pub use re_types::{
     Archetype, ArchetypeName, AsComponents, Component, ComponentBatch, ComponentDescriptor,
@@ -242,6 +242,12 @@ impl private::Sealed for crate::sink::GrpcSink {}
 
 impl MultiSinkCompatible for crate::sink::GrpcSink {}
 
+#[cfg(all(feature = "mcap", not(target_arch = "wasm32")))]
+impl private::Sealed for crate::sink::McapSink {}
+
+#[cfg(all(feature = "mcap", not(target_arch = "wasm32")))]
+impl MultiSinkCompatible for crate::sink::McapSink {}
+
 // ----------------------------------------------------------------------------
 
 /// Store log messages in memory until you call [`LogSink::drain_backlog`].
@@ -522,6 +528,9 @@ impl LogSink for CallbackSink {
 // ----------------------------------------------------------------------------
 
 /// Stream log messages to an a remote Rerun server.
+///
+/// Flushing waits for the server to acknowledge that it has received everything sent so far.
+/// Older servers cannot do that, in which case a flush only waits for the data to be sent.
 pub struct GrpcSink {
     client: MessageProxyClient,
 }
@@ -532,6 +541,9 @@ pub type GrpcSinkConnectionState = re_grpc_client::write::ClientConnectionState;
 /// The reason why a [`GrpcSink`] was disconnected.
 pub type GrpcSinkConnectionFailure = re_grpc_client::write::ClientConnectionFailure;
 
+/// Configuration of the shared memory transport of a [`GrpcSink`].
+pub type GrpcSinkSharedMemoryOptions = re_grpc_client::write::SharedMemoryOptions;
+
 impl GrpcSink {
     /// Connect to the in-memory storage node over HTTP.
     ///
@@ -551,6 +563,66 @@ impl GrpcSink {
         }
     }
 
+    /// Like [`Self::new`], but sends large payloads (e.g. images) through shared memory
+    /// if the server runs on the same machine.
+    ///
+    /// This avoids pushing multi-megabyte blobs through the socket. If the server is remote,
+    /// or doesn't support shared memory, this behaves exactly like [`Self::new`].
+    ///
+    /// # Experimental
+    ///
+    /// This API is experimental and may change in future releases.
+    #[inline]
+    pub fn with_shared_memory(
+        uri: re_uri::ProxyUri,
+        shared_memory: GrpcSinkSharedMemoryOptions,
+    ) -> Self {
+        Self {
+            client: MessageProxyClient::new(
+                uri,
+                Options {
+                    shared_memory: Some(shared_memory),
+                    ..Default::default()
+                },
+            ),
+        }
+    }
+
+    /// Like [`Self::new`], but sends the given bearer token with every request,
+    /// for servers that require one.
+    ///
+    /// [`Self::new`] sends the token in the `RERUN_PROXY_TOKEN` environment variable, if set.
+    #[inline]
+    pub fn with_auth_token(uri: re_uri::ProxyUri, auth_token: impl Into<String>) -> Self {
+        Self {
+            client: MessageProxyClient::new(
+                uri,
+                Options {
+                    auth_token: Some(auth_token.into()),
+                    ..Default::default()
+                },
+            ),
+        }
+    }
+
+    /// Like [`Self::new`], but identifies this client with the given name on the server,
+    /// which the viewer then shows as the source of the data.
+    ///
+    /// [`Self::new`] uses the name in the `RERUN_CLIENT_NAME` environment variable if set,
+    /// or else the name of the executable.
+    #[inline]
+    pub fn with_client_name(uri: re_uri::ProxyUri, client_name: impl Into<String>) -> Self {
+        Self {
+            client: MessageProxyClient::new(
+                uri,
+                Options {
+                    client_name: Some(client_name.into()),
+                    ..Default::default()
+                },
+            ),
+        }
+    }
+
     /// The connection state of underlying Grpc connection of this sink.
     ///
     /// # Experimental
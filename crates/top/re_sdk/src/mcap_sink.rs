@@ -0,0 +1,98 @@
+use std::{fs::File, io::BufWriter, path::PathBuf, time::Duration};
+
+use re_chunk::Chunk;
+use re_log_types::LogMsg;
+use re_mcap::{McapExportOptions, McapExporter};
+
+use crate::sink::{LogSink, SinkFlushError};
+
+/// Errors that can occur when creating an [`McapSink`].
+#[derive(thiserror::Error, Debug)]
+pub enum McapSinkError {
+    /// Error creating the file.
+    #[error("Failed to create file {0:?}: {1}")]
+    CreateFile(PathBuf, std::io::Error),
+
+    /// Error writing the MCAP header.
+    #[error(transparent)]
+    Mcap(#[from] re_mcap::Error),
+}
+
+/// Writes all logged recording data to an MCAP file.
+///
+/// Every entity gets its own channel, see [`re_mcap::export`] for the details.
+/// Blueprints are not exported.
+///
+/// The MCAP summary is written when the sink is dropped.
+pub struct McapSink {
+    path: PathBuf,
+
+    /// `None` once the file has been finished.
+    exporter: parking_lot::Mutex<Option<McapExporter<BufWriter<File>>>>,
+}
+
+impl McapSink {
+    /// Create a new MCAP file at the given path, overwriting any existing file.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        options: McapExportOptions,
+    ) -> Result<Self, McapSinkError> {
+        let path = path.into();
+
+        re_log::debug!("Saving MCAP file to {path:?}…");
+
+        let file =
+            File::create(&path).map_err(|err| McapSinkError::CreateFile(path.clone(), err))?;
+        let exporter = McapExporter::new(BufWriter::new(file), options)?;
+
+        Ok(Self {
+            path,
+            exporter: parking_lot::Mutex::new(Some(exporter)),
+        })
+    }
+}
+
+impl Drop for McapSink {
+    fn drop(&mut self) {
+        if let Some(exporter) = self.exporter.lock().take()
+            && let Err(err) = exporter.finish()
+        {
+            re_log::error!("Failed to finish MCAP file {:?}: {err}", self.path);
+        }
+    }
+}
+
+impl LogSink for McapSink {
+    fn send(&self, msg: LogMsg) {
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = msg else {
+            return;
+        };
+
+        if !store_id.is_recording() {
+            return;
+        }
+
+        let mut exporter = self.exporter.lock();
+        let Some(exporter) = exporter.as_mut() else {
+            return;
+        };
+
+        let result = Chunk::from_arrow_msg(&arrow_msg)
+            .map_err(re_mcap::Error::from)
+            .and_then(|chunk| exporter.add_chunk(&chunk));
+
+        if let Err(err) = result {
+            re_log::error_once!("Failed to write chunk to MCAP file {:?}: {err}", self.path);
+        }
+    }
+
+    #[inline]
+    fn flush_blocking(&self, _timeout: Duration) -> Result<(), SinkFlushError> {
+        // Messages are written as they come in, the summary is only written once we're done.
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
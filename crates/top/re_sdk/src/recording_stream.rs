@@ -10,23 +10,27 @@ use itertools::Either;
 use nohash_hasher::IntMap;
 use parking_lot::Mutex;
 
+use re_chunk::external::arrow::datatypes::DataType as ArrowDataType;
 use re_chunk::{
     BatcherFlushError, BatcherHooks, Chunk, ChunkBatcher, ChunkBatcherConfig, ChunkBatcherError,
     ChunkComponents, ChunkError, ChunkId, PendingRow, RowId, TimeColumn,
 };
 use re_log_types::{
-    ApplicationId, ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, EntityPath, LogMsg,
-    RecordingId, StoreId, StoreInfo, StoreKind, StoreSource, TimeCell, TimeInt, TimePoint,
-    Timeline, TimelineName,
+    ARROW_METADATA_KEY_TRANSACTION_CONTINUES, AbsoluteTimeRange, ApplicationId,
+    ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, Clock, DropTimeRangeCommand,
+    EntityPath, LogMsg, RecordingId, StoreId, StoreInfo, StoreKind, StoreSource, TimeCell, TimeInt,
+    TimePoint, Timeline, TimelineName,
 };
 use re_types::archetypes::RecordingInfo;
-use re_types::components::Timestamp;
+use re_types::components::{Text, Timestamp};
 use re_types::{AsComponents, SerializationError, SerializedComponentColumn};
 
 #[cfg(feature = "web_viewer")]
 use re_web_viewer_server::WebViewerServerPort;
 
+use crate::Transaction;
 use crate::sink::{LogSink, MemorySinkStorage};
+use crate::strict_schema::{SchemaMismatchError, SchemaValidator};
 use crate::{binary_stream_sink::BinaryStreamStorage, sink::SinkFlushError};
 
 // ---
@@ -101,6 +105,10 @@ pub enum RecordingStreamError {
     /// Invalid bind IP.
     #[error(transparent)]
     InvalidAddress(#[from] std::net::AddrParseError),
+
+    /// A component was logged with a different datatype than before, see [`RecordingStream::set_strict_schema`].
+    #[error(transparent)]
+    SchemaMismatch(#[from] Box<SchemaMismatchError>),
 }
 
 /// Results that can occur when creating/manipulating a [`RecordingStream`].
@@ -873,14 +881,21 @@ struct RecordingStreamInner {
     recording_info: Option<RecordingInfo>,
     tick: AtomicI64,
 
-    /// The one and only entrypoint into the pipeline: this is _never_ cloned nor publicly exposed,
-    /// therefore the `Drop` implementation is guaranteed that no more data can come in while it's
+    /// The one and only entrypoint into the pipeline: this is _never_ publicly exposed, and only
+    /// ever cloned by forks, which keep this recording alive.
+    /// Therefore the `Drop` implementation is guaranteed that no more data can come in while it's
     /// running.
     cmds_tx: Sender<Command>,
 
     batcher: ChunkBatcher,
     batcher_to_sink_handle: Option<std::thread::JoinHandle<()>>,
 
+    /// The recording this one was forked from, if any, see [`RecordingStream::fork`].
+    ///
+    /// A fork shares the pipeline (batcher, forwarding thread and sink) of that recording,
+    /// so it keeps it alive.
+    fork_of: Option<RecordingStream>,
+
     /// It true, any new sink will update the batcher's configuration (as far as possible).
     sink_dependent_batcher_config: bool,
 
@@ -890,6 +905,9 @@ struct RecordingStreamInner {
     /// See [`RecordingStream::log_file_from_path`] and [`RecordingStream::log_file_from_contents`].
     dataloader_handles: Mutex<Vec<std::thread::JoinHandle<()>>>,
 
+    /// Set in strict schema mode, see [`RecordingStream::set_strict_schema`].
+    schema_validator: Mutex<Option<SchemaValidator>>,
+
     pid_at_creation: u32,
 }
 
@@ -920,6 +938,15 @@ impl Drop for RecordingStreamInner {
             re_log::error!("Failed to flush batcher: {err}");
         }
         self.cmds_tx.send(Command::PopPendingChunks).ok();
+
+        if self.fork_of.is_some() {
+            // The pipeline belongs to the recording this was forked from, which lives on.
+            self.cmds_tx
+                .send(Command::RemoveFork(self.store_info.store_id.clone()))
+                .ok();
+            return;
+        }
+
         self.cmds_tx.send(Command::Shutdown).ok();
         if let Some(handle) = self.batcher_to_sink_handle.take() {
             handle.join().ok();
@@ -1007,12 +1034,23 @@ impl RecordingStreamInner {
             cmds_tx,
             batcher,
             batcher_to_sink_handle: Some(batcher_to_sink_handle),
+            fork_of: None,
             sink_dependent_batcher_config,
             dataloader_handles: Mutex::new(Vec::new()),
+            schema_validator: Mutex::new(None),
             pid_at_creation: std::process::id(),
         })
     }
 
+    /// The store to tag batched data with, see [`ChunkBatcher::push_row_for_store`].
+    ///
+    /// Only forks need to, since the pipeline assumes everything else belongs to its own store.
+    fn batcher_store_id(&self) -> Option<StoreId> {
+        self.fork_of
+            .as_ref()
+            .map(|_| self.store_info.store_id.clone())
+    }
+
     #[inline]
     pub fn is_forked_child(&self) -> bool {
         self.pid_at_creation != std::process::id()
@@ -1035,8 +1073,83 @@ type InspectSinkFn = Box<dyn FnOnce(&dyn LogSink) + Send + 'static>;
 
 type FlushResult = Result<(), SinkFlushError>;
 
+/// Where to report the result of a [`Command::Flush`] to.
+enum FlushReporter {
+    Blocking(Sender<FlushResult>),
+
+    #[cfg(feature = "async_flush")]
+    Async(tokio::sync::oneshot::Sender<FlushResult>),
+
+    /// Nobody waits for the result.
+    Detached,
+}
+
+impl FlushReporter {
+    /// Gives back the result if nobody is listening anymore.
+    fn send(self, result: FlushResult) -> Result<(), FlushResult> {
+        match self {
+            Self::Blocking(tx) => tx.send(result).map_err(|err| err.0),
+
+            #[cfg(feature = "async_flush")]
+            Self::Async(tx) => tx.send(result),
+
+            Self::Detached => Err(result),
+        }
+    }
+}
+
+/// A flush started by [`RecordingStream::flush_future`].
+///
+/// Resolves once all data logged before the flush has been accepted by the sink.
+/// For a [`GrpcSink`][`crate::log_sink::GrpcSink`] that means the server has acknowledged it.
+///
+/// The flush happens whether or not this future is awaited: dropping it is fine
+/// if you don't care about the outcome.
+#[cfg(feature = "async_flush")]
+pub struct FlushFuture(FlushFutureState);
+
+#[cfg(feature = "async_flush")]
+enum FlushFutureState {
+    /// The flush never made it to the sink, or this future has already resolved.
+    Ready(Option<FlushResult>),
+    Pending(tokio::sync::oneshot::Receiver<FlushResult>),
+}
+
+#[cfg(feature = "async_flush")]
+impl std::future::Future for FlushFuture {
+    type Output = FlushResult;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        match &mut self.get_mut().0 {
+            FlushFutureState::Ready(result) => {
+                std::task::Poll::Ready(result.take().unwrap_or(Ok(())))
+            }
+            FlushFutureState::Pending(rx) => std::pin::Pin::new(rx).poll(cx).map(|result| {
+                result.unwrap_or_else(|_closed| {
+                    Err(SinkFlushError::failed(
+                        "Flush never finished. This is likely a bug in the Rerun SDK.",
+                    ))
+                })
+            }),
+        }
+    }
+}
+
 enum Command {
     RecordMsg(LogMsg),
+    RecordTransaction(Vec<LogMsg>),
+
+    /// Starts sending the data of a fork, see [`RecordingStream::fork`].
+    ///
+    /// Reports back once the fork's [`StoreInfo`] has been sent.
+    AddFork {
+        store_info: StoreInfo,
+        on_done: Sender<()>,
+    },
+    RemoveFork(StoreId),
     SwapSink {
         new_sink: Box<dyn LogSink>,
         timeout: Duration,
@@ -1044,7 +1157,7 @@ enum Command {
     // TODO(#10444): This should go away with more explicit sinks.
     InspectSink(InspectSinkFn),
     Flush {
-        on_done: Sender<FlushResult>,
+        on_done: FlushReporter,
         timeout: Duration,
     },
     PopPendingChunks,
@@ -1054,7 +1167,13 @@ enum Command {
 impl Command {
     fn flush(timeout: Duration) -> (Self, Receiver<FlushResult>) {
         let (on_done, rx) = crossbeam::channel::bounded(1); // oneshot
-        (Self::Flush { on_done, timeout }, rx)
+        (
+            Self::Flush {
+                on_done: FlushReporter::Blocking(on_done),
+                timeout,
+            },
+            rx,
+        )
     }
 }
 
@@ -1116,6 +1235,87 @@ impl RecordingStream {
             inner: Either::Left(Arc::new(None)),
         }
     }
+
+    /// Creates a new recording that sends its data through the sink of this one.
+    ///
+    /// Unlike creating another [`RecordingStream`] with a [`RecordingStreamBuilder`], this doesn't
+    /// open a new connection (or file, …), so a single process can e.g. maintain both a "debug" and a
+    /// "metrics" recording over the same gRPC connection.
+    ///
+    /// The fork gets the given `recording_id`, and the given `application_id` if any.
+    /// It keeps the store kind, store source and recording properties of this recording,
+    /// but has its own clock.
+    ///
+    /// The fork shares the batcher and sink of this recording: its data always ends up in the
+    /// _current_ sink of this recording, even after a call to [`Self::set_sink`], and the sink of
+    /// the fork itself cannot be changed. Flushing the fork also flushes this recording.
+    /// The fork keeps this recording alive for as long as it exists.
+    ///
+    /// Forking a disabled recording returns a disabled recording.
+    pub fn fork(
+        &self,
+        application_id: Option<ApplicationId>,
+        recording_id: impl Into<RecordingId>,
+    ) -> RecordingStreamResult<Self> {
+        let Some((store_info, recording_info, cmds_tx, batcher)) = self.with(|inner| {
+            (
+                inner.store_info.clone(),
+                inner.recording_info.clone(),
+                inner.cmds_tx.clone(),
+                inner.batcher.clone(),
+            )
+        }) else {
+            return Ok(Self::disabled());
+        };
+
+        let store_id = StoreId::new(
+            store_info.store_id.kind(),
+            application_id.unwrap_or_else(|| store_info.application_id().clone()),
+            recording_id,
+        );
+        let store_info = StoreInfo {
+            store_id,
+            cloned_from: None,
+            ..store_info
+        };
+
+        // Wait for the forwarding thread to send the `StoreInfo`, so that it reaches the sink
+        // before any of the fork's chunks.
+        let (on_done, done) = crossbeam::channel::bounded(1); // oneshot
+        cmds_tx
+            .send(Command::AddFork {
+                store_info: store_info.clone(),
+                on_done,
+            })
+            .ok();
+        done.recv().ok();
+
+        if let Some(recording_info) = recording_info.as_ref() {
+            let chunk = Chunk::builder(EntityPath::properties())
+                .with_archetype(RowId::new(), TimePoint::default(), recording_info)
+                .build()?;
+
+            batcher.push_chunk_for_store(Some(store_info.store_id.clone()), chunk);
+        }
+
+        let inner = RecordingStreamInner {
+            store_info,
+            recording_info,
+            tick: AtomicI64::new(0),
+            cmds_tx,
+            batcher,
+            batcher_to_sink_handle: None,
+            fork_of: Some(self.clone()),
+            sink_dependent_batcher_config: false,
+            dataloader_handles: Mutex::new(Vec::new()),
+            schema_validator: Mutex::new(None),
+            pid_at_creation: std::process::id(),
+        };
+
+        Ok(Self {
+            inner: Either::Left(Arc::new(Some(inner))),
+        })
+    }
 }
 
 impl RecordingStream {
@@ -1191,6 +1391,14 @@ impl RecordingStream {
 
         let chunk = Chunk::from_auto_row_ids(id, ent_path.into(), indexes, components)?;
 
+        self.validate_schema(
+            chunk.entity_path(),
+            chunk
+                .components()
+                .iter()
+                .map(|(descr, list_array)| (descr, list_array.value_type())),
+        )?;
+
         self.send_chunk(chunk);
 
         Ok(())
@@ -1310,6 +1518,27 @@ impl RecordingStream {
         self.log_static(EntityPath::properties().join(&sub_path), values)
     }
 
+    /// Sends how long the viewer should keep the data of each entity subtree of this recording,
+    /// e.g. `/camera/**=2min, /state/**=forever`.
+    ///
+    /// Data older than its retention is dropped, and data kept `forever` is never dropped to stay within memory limits.
+    /// These rules take precedence over the ones the viewer was started with, for this recording only.
+    pub fn send_retention_hints(&self, rules: impl Into<String>) -> RecordingStreamResult<()> {
+        use re_types::ComponentBatch as _;
+
+        let descriptor = re_types::ComponentDescriptor {
+            archetype: None,
+            component: "retention:rules".into(),
+            component_type: Some(<Text as re_types::Component>::name()),
+        };
+        let rules = Text::from(rules.into()).try_serialized(descriptor)?;
+        self.log_serialized_batches(
+            EntityPath::properties().join(&EntityPath::from("retention")),
+            true,
+            [rules],
+        )
+    }
+
     /// Sends the name of the recording.
     #[inline]
     pub fn send_recording_name(&self, name: impl Into<String>) -> RecordingStreamResult<()> {
@@ -1327,9 +1556,6 @@ impl RecordingStream {
         self.log_static(EntityPath::properties(), &update)
     }
 
-    // NOTE: For bw and fw compatibility reasons, we need our logging APIs to be fallible, even
-    // though they really aren't at the moment.
-    #[allow(clippy::unnecessary_wraps)]
     fn log_serialized_batches_impl(
         &self,
         row_id: RowId,
@@ -1349,6 +1575,13 @@ impl RecordingStream {
             .collect();
         let components: IntMap<_, _> = comp_batches.into_iter().collect();
 
+        self.validate_schema(
+            &entity_path,
+            components
+                .iter()
+                .map(|(descr, array)| (descr, array.data_type().clone())),
+        )?;
+
         // NOTE: The timepoint is irrelevant, the `RecordingStream` will overwrite it using its
         // internal clock.
         let timepoint = TimePoint::default();
@@ -1365,6 +1598,48 @@ impl RecordingStream {
         Ok(())
     }
 
+    /// Enables or disables strict schema mode for this recording.
+    ///
+    /// In strict schema mode, every log call checks that each component has the same Arrow datatype
+    /// as previous uses of the same [`re_types::ComponentDescriptor`] on that entity,
+    /// and fails with [`RecordingStreamError::SchemaMismatch`] otherwise, without logging anything.
+    /// This catches mistakes early that would otherwise result in recordings that fail to visualize.
+    ///
+    /// Only data logged via [`Self::log`], [`Self::send_columns`] and their variants is checked,
+    /// and only against data that was logged while strict schema mode was enabled.
+    /// Disabling strict schema mode forgets all datatypes seen so far.
+    ///
+    /// Strict schema mode is disabled by default, since checking comes at a small cost per log call.
+    pub fn set_strict_schema(&self, strict: bool) {
+        let f = move |inner: &RecordingStreamInner| {
+            let mut schema_validator = inner.schema_validator.lock();
+            if strict {
+                schema_validator.get_or_insert_with(SchemaValidator::default);
+            } else {
+                *schema_validator = None;
+            }
+        };
+
+        if self.with(f).is_none() {
+            re_log::warn_once!("Recording disabled - call to set_strict_schema() ignored");
+        }
+    }
+
+    /// Checks the given components against previous log calls if in strict schema mode.
+    fn validate_schema<'a>(
+        &self,
+        entity_path: &EntityPath,
+        components: impl IntoIterator<Item = (&'a re_types::ComponentDescriptor, ArrowDataType)>,
+    ) -> RecordingStreamResult<()> {
+        self.with(|inner| -> RecordingStreamResult<()> {
+            if let Some(schema_validator) = inner.schema_validator.lock().as_mut() {
+                schema_validator.validate(entity_path, components)?;
+            }
+            Ok(())
+        })
+        .unwrap_or(Ok(()))
+    }
+
     /// Logs the file at the given `path` using all [`re_data_loader::DataLoader`]s available.
     ///
     /// A single `path` might be handled by more than one loader.
@@ -1508,16 +1783,47 @@ fn forwarding_thread(
     store_info: StoreInfo,
     mut sink: Box<dyn LogSink>,
     cmds_rx: Receiver<Command>,
-    chunks: Receiver<Chunk>,
+    chunks: Receiver<(Option<StoreId>, Chunk)>,
     on_release: Option<ArrowRecordBatchReleaseCallback>,
 ) {
     /// Returns `true` to indicate that processing can continue; i.e. `false` means immediate
     /// shutdown.
-    fn handle_cmd(store_info: &StoreInfo, cmd: Command, sink: &mut Box<dyn LogSink>) -> bool {
+    fn handle_cmd(
+        store_info: &StoreInfo,
+        forks: &mut Vec<StoreInfo>,
+        cmd: Command,
+        sink: &mut Box<dyn LogSink>,
+    ) -> bool {
         match cmd {
             Command::RecordMsg(msg) => {
                 sink.send(msg);
             }
+            Command::RecordTransaction(msgs) => {
+                for msg in msgs {
+                    sink.send(msg);
+                }
+            }
+            Command::AddFork {
+                store_info,
+                on_done,
+            } => {
+                re_log::debug!(
+                    store_id = ?store_info.store_id,
+                    "Setting StoreInfo of fork",
+                );
+                sink.send(
+                    re_log_types::SetStoreInfo {
+                        row_id: *RowId::new(),
+                        info: store_info.clone(),
+                    }
+                    .into(),
+                );
+                forks.push(store_info);
+                on_done.send(()).ok();
+            }
+            Command::RemoveFork(store_id) => {
+                forks.retain(|fork| fork.store_id != store_id);
+            }
             Command::SwapSink { new_sink, timeout } => {
                 re_log::trace!("Swapping sink…");
 
@@ -1534,7 +1840,7 @@ fn forwarding_thread(
                 };
 
                 // Send the recording info to the new sink. This is idempotent.
-                {
+                for store_info in std::iter::once(store_info).chain(forks.iter()) {
                     re_log::debug!(
                         store_id = ?store_info.store_id,
                         "Setting StoreInfo",
@@ -1546,8 +1852,8 @@ fn forwarding_thread(
                         }
                         .into(),
                     );
-                    new_sink.send_all(backlog);
                 }
+                new_sink.send_all(backlog);
 
                 *sink = new_sink;
             }
@@ -1560,7 +1866,7 @@ fn forwarding_thread(
                 let result = sink.flush_blocking(timeout);
 
                 // Send back the result:
-                if let Err(crossbeam::channel::SendError(result)) = on_done.send(result)
+                if let Err(result) = on_done.send(result)
                     && let Err(err) = result
                 {
                     // There was an error, and nobody received it:
@@ -1577,11 +1883,14 @@ fn forwarding_thread(
         true
     }
 
+    // The forks currently sending their data through this pipeline.
+    let mut forks = Vec::new();
+
     use crossbeam::select;
     loop {
         // NOTE: Always pop chunks first, this is what makes `Command::PopPendingChunks` possible,
         // which in turns makes `RecordingStream::flush_blocking` well defined.
-        while let Ok(chunk) = chunks.try_recv() {
+        while let Ok((store_id, chunk)) = chunks.try_recv() {
             let mut msg = match chunk.to_arrow_msg() {
                 Ok(chunk) => chunk,
                 Err(err) => {
@@ -1590,12 +1899,13 @@ fn forwarding_thread(
                 }
             };
             msg.on_release = on_release.clone();
-            sink.send(LogMsg::ArrowMsg(store_info.store_id.clone(), msg));
+            let store_id = store_id.unwrap_or_else(|| store_info.store_id.clone());
+            sink.send(LogMsg::ArrowMsg(store_id, msg));
         }
 
         select! {
             recv(chunks) -> res => {
-                let Ok(chunk) = res else {
+                let Ok((store_id, chunk)) = res else {
                     // The batcher is gone, which can only happen if the `RecordingStream` itself
                     // has been dropped.
                     re_log::trace!("Shutting down forwarding_thread: batcher is gone");
@@ -1610,7 +1920,8 @@ fn forwarding_thread(
                     }
                 };
 
-                sink.send(LogMsg::ArrowMsg(store_info.store_id.clone(), msg));
+                let store_id = store_id.unwrap_or_else(|| store_info.store_id.clone());
+                sink.send(LogMsg::ArrowMsg(store_id, msg));
             }
 
             recv(cmds_rx) -> res => {
@@ -1620,7 +1931,7 @@ fn forwarding_thread(
                     re_log::trace!("Shutting down forwarding_thread: all command senders are gone");
                     break;
                 };
-                if !handle_cmd(&store_info, cmd, &mut sink) {
+                if !handle_cmd(&store_info, &mut forks, cmd, &mut sink) {
                     break; // shutdown
                 }
             }
@@ -1702,7 +2013,9 @@ impl RecordingStream {
                 }
             }
 
-            inner.batcher.push_row(entity_path, row);
+            inner
+                .batcher
+                .push_row_for_store(inner.batcher_store_id(), entity_path, row);
         };
 
         if self.with(f).is_none() {
@@ -1760,7 +2073,9 @@ impl RecordingStream {
                 }
             }
 
-            inner.batcher.push_chunk(chunk);
+            inner
+                .batcher
+                .push_chunk_for_store(inner.batcher_store_id(), chunk);
         };
 
         if self.with(f).is_none() {
@@ -1785,7 +2100,9 @@ impl RecordingStream {
     #[inline]
     pub fn send_chunk(&self, chunk: Chunk) {
         let f = move |inner: &RecordingStreamInner| {
-            inner.batcher.push_chunk(chunk);
+            inner
+                .batcher
+                .push_chunk_for_store(inner.batcher_store_id(), chunk);
         };
 
         if self.with(f).is_none() {
@@ -1803,6 +2120,107 @@ impl RecordingStream {
         }
     }
 
+    /// Starts a [`Transaction`], which groups several log calls into a single update.
+    ///
+    /// The data of a transaction shares the current time of this recording on the calling thread,
+    /// and is never shown partially by the viewer. See [`Transaction`] for more.
+    pub fn transaction(&self) -> Transaction {
+        let f = move |inner: &RecordingStreamInner| {
+            let tick = inner
+                .tick
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+            let mut now = ThreadInfo::thread_now(&inner.store_info.store_id);
+            now.insert_cell(TimelineName::log_tick(), TimeCell::from_sequence(tick));
+            now
+        };
+
+        Transaction::new(self.clone(), self.with(f).unwrap_or_default())
+    }
+
+    /// Sends the chunks of a committed [`Transaction`] back-to-back,
+    /// after everything that was logged before.
+    pub(crate) fn send_transaction(&self, chunks: Vec<Chunk>) -> RecordingStreamResult<()> {
+        let f = move |inner: &RecordingStreamInner| -> RecordingStreamResult<()> {
+            let store_id = &inner.store_info.store_id;
+
+            let mut msgs = chunks
+                .iter()
+                .map(|chunk| Ok(LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?)))
+                .collect::<RecordingStreamResult<Vec<_>>>()?;
+
+            // Tell the viewer to wait for the rest of the transaction.
+            if let Some((_last, continued)) = msgs.split_last_mut() {
+                for msg in continued {
+                    msg.insert_arrow_record_batch_metadata(
+                        ARROW_METADATA_KEY_TRANSACTION_CONTINUES.to_owned(),
+                        "true".to_owned(),
+                    );
+                }
+            }
+
+            // Make sure everything that was logged before the transaction is sent first.
+            if let Err(err) = inner.batcher.flush_blocking(Duration::MAX) {
+                re_log::warn!("Failed to flush batcher before transaction: {err}");
+            }
+            inner.cmds_tx.send(Command::PopPendingChunks).ok();
+
+            // NOTE: Internal channels can never be closed outside of the `Drop` impl, this send cannot
+            // fail.
+            inner.cmds_tx.send(Command::RecordTransaction(msgs)).ok();
+
+            Ok(())
+        };
+
+        self.with(f).unwrap_or_else(|| {
+            re_log::warn_once!("Recording disabled - call to send_transaction() ignored");
+            Ok(())
+        })
+    }
+
+    /// Drops the data that was logged to `entity_path` within `range` on the given timeline.
+    ///
+    /// If `recursive` is true, the data of all the descendants of `entity_path` is dropped too.
+    /// Rows are dropped from all the timelines they appear on, and static data is never affected.
+    ///
+    /// This is meant for excising segments of a recording, e.g. sensitive or corrupted data.
+    /// Only the data logged before this call is dropped: anything logged to that range
+    /// afterwards is kept.
+    pub fn drop_time_range(
+        &self,
+        entity_path: impl Into<EntityPath>,
+        recursive: bool,
+        timeline: impl Into<TimelineName>,
+        range: AbsoluteTimeRange,
+    ) {
+        let entity_path = entity_path.into();
+        let timeline = timeline.into();
+
+        let f = move |inner: &RecordingStreamInner| {
+            let msg = LogMsg::DropTimeRange(DropTimeRangeCommand {
+                store_id: inner.store_info.store_id.clone(),
+                entity_path,
+                recursive,
+                timeline,
+                range,
+            });
+
+            // Make sure everything that was logged before is sent first, so that it gets dropped too.
+            if let Err(err) = inner.batcher.flush_blocking(Duration::MAX) {
+                re_log::warn!("Failed to flush batcher before dropping time range: {err}");
+            }
+            inner.cmds_tx.send(Command::PopPendingChunks).ok();
+
+            // NOTE: Internal channels can never be closed outside of the `Drop` impl, this send cannot
+            // fail.
+            inner.cmds_tx.send(Command::RecordMsg(msg)).ok();
+        };
+
+        if self.with(f).is_none() {
+            re_log::warn_once!("Recording disabled - call to drop_time_range() ignored");
+        }
+    }
+
     /// Swaps the underlying sink for a new one.
     ///
     /// This guarantees that:
@@ -1831,6 +2249,13 @@ impl RecordingStream {
         let timeout = Duration::MAX; // The background thread should block forever if necessary
 
         let f = move |inner: &RecordingStreamInner| {
+            if inner.fork_of.is_some() {
+                re_log::warn_once!(
+                    "Cannot set the sink of a forked recording, set it on the recording it was forked from instead - call to set_sink() ignored"
+                );
+                return;
+            }
+
             // NOTE: Internal channels can never be closed outside of the `Drop` impl, all these sends
             // are safe.
 
@@ -1876,12 +2301,32 @@ impl RecordingStream {
     /// This will never return [`SinkFlushError::Timeout`].
     pub fn flush_async(&self) -> Result<(), SinkFlushError> {
         re_tracing::profile_function!();
-        match self.flush(None) {
+        match self.flush(FlushReporter::Detached) {
             Err(SinkFlushError::Timeout) => Ok(()),
             result => result,
         }
     }
 
+    /// Initiates a flush of the pipeline and returns a future that resolves once it has propagated.
+    ///
+    /// The returned [`FlushFuture`] resolves once the sink has accepted all data that was
+    /// logged before this call, e.g. once the server has acknowledged it when using a
+    /// [`GrpcSink`][`crate::log_sink::GrpcSink`].
+    /// The flush happens regardless of whether the future is awaited.
+    ///
+    /// See [`Self::flush_with_timeout`] for a blocking version.
+    /// See [`RecordingStream`] docs for ordering semantics and multithreading guarantees.
+    #[cfg(feature = "async_flush")]
+    pub fn flush_future(&self) -> FlushFuture {
+        re_tracing::profile_function!();
+
+        let (on_done, rx) = tokio::sync::oneshot::channel();
+        match self.flush(FlushReporter::Async(on_done)) {
+            Ok(()) => FlushFuture(FlushFutureState::Pending(rx)),
+            Err(err) => FlushFuture(FlushFutureState::Ready(Some(Err(err)))),
+        }
+    }
+
     /// Flush the batching pipeline and waits for it to propagate.
     ///
     /// The function will block until either the flush has completed successfully (`Ok`),
@@ -1893,11 +2338,14 @@ impl RecordingStream {
         self.flush_with_timeout(Duration::MAX)
     }
 
-    /// Flush the batching pipeline and optionally waits for it to propagate.
+    /// Flush the batching pipeline and waits for it to propagate.
     /// If you don't want a timeout you can pass in [`Duration::MAX`].
     ///
     /// The function will block until that timeout is reached,
     /// an error occurs, or the flush is complete.
+    /// The flush is complete once the sink has accepted all data that was logged before this call,
+    /// e.g. once the server has acknowledged it when using a [`GrpcSink`][`crate::log_sink::GrpcSink`].
+    ///
     /// The function will only block while there is some hope of progress.
     /// For instance: if the underlying gRPC connection is disconnected (or never connected at all),
     /// then [`SinkFlushError::Failed`] is returned.
@@ -1905,25 +2353,34 @@ impl RecordingStream {
     /// See [`RecordingStream`] docs for ordering semantics and multithreading guarantees.
     pub fn flush_with_timeout(&self, timeout: Duration) -> Result<(), SinkFlushError> {
         re_tracing::profile_function!();
-        self.flush(Some(timeout))
+
+        let (on_done, rx) = crossbeam::channel::bounded(1); // oneshot
+        self.flush(FlushReporter::Blocking(on_done))?;
+
+        rx.recv_timeout(timeout).map_err(|err| match err {
+            RecvTimeoutError::Timeout => SinkFlushError::Timeout,
+            RecvTimeoutError::Disconnected => SinkFlushError::failed(
+                "Flush never finished. This is likely a bug in the Rerun SDK.",
+            ),
+        })?
     }
 
-    /// Flush the batching pipeline and optionally waits for it to propagate.
+    /// Flush the batching pipeline and starts flushing the sink, without waiting for it.
     ///
-    /// If `timeout` is `None`, then this function will start the flush, but NOT wait for it to finish.
-    ///
-    /// If a `timeout` is given, then the function will block until that timeout is reached,
-    /// an error occurs, or the flush is complete.
+    /// The result of the sink flush is reported to `on_done`.
+    /// Returns an error if the flush could not be started at all.
     ///
     /// See [`RecordingStream`] docs for ordering semantics and multithreading guarantees.
-    fn flush(&self, timeout: Option<Duration>) -> Result<(), SinkFlushError> {
+    fn flush(&self, on_done: FlushReporter) -> Result<(), SinkFlushError> {
         if self.is_forked_child() {
             return Err(SinkFlushError::failed(
                 "Fork detected during flush. cleanup_if_forked() should always be called after forking. This is likely a bug in the Rerun SDK.",
             ));
         }
 
-        let f = move |inner: &RecordingStreamInner| -> Result<(), SinkFlushError> {
+        let mut on_done = Some(on_done);
+
+        let f = |inner: &RecordingStreamInner| -> Result<(), SinkFlushError> {
             // 1. Synchronously flush the batcher down the chunk channel
             //
             // NOTE: This _has_ to be done synchronously as we need to be guaranteed that all chunks
@@ -1948,30 +2405,28 @@ impl RecordingStream {
                 })?;
 
             // 3. Asynchronously flush everything down the sink
-            let (cmd, on_done) = Command::flush(Duration::MAX); // The background thread should block forever if necessary
-            inner.cmds_tx.send(cmd).map_err(|_ignored| {
-                SinkFlushError::failed(
-                    "Sink shut down prematurely. This is likely a bug in the Rerun SDK.",
-                )
-            })?;
-
-            if let Some(timeout) = timeout {
-                on_done.recv_timeout(timeout).map_err(|err| match err {
-                    RecvTimeoutError::Timeout => SinkFlushError::Timeout,
-                    RecvTimeoutError::Disconnected => SinkFlushError::failed(
-                        "Flush never finished. This is likely a bug in the Rerun SDK.",
-                    ),
-                })??;
+            if let Some(on_done) = on_done.take() {
+                let cmd = Command::Flush {
+                    on_done,
+                    timeout: Duration::MAX, // The background thread should block forever if necessary
+                };
+                inner.cmds_tx.send(cmd).map_err(|_ignored| {
+                    SinkFlushError::failed(
+                        "Sink shut down prematurely. This is likely a bug in the Rerun SDK.",
+                    )
+                })?;
             }
 
             Ok(())
         };
 
         match self.with(f) {
-            Some(Ok(())) => Ok(()),
-            Some(Err(err)) => Err(err),
+            Some(result) => result,
             None => {
                 re_log::warn_once!("Recording disabled - call to flush ignored");
+                if let Some(on_done) = on_done {
+                    on_done.send(Ok(())).ok();
+                }
                 Ok(())
             }
         }
@@ -2278,6 +2733,8 @@ impl RecordingStream {
     }
 
     /// Send a blueprint through this recording stream
+    ///
+    /// Use [`Self::send_blueprint_with_options`] to send a blueprint built with [`crate::blueprint`].
     pub fn send_blueprint(
         &self,
         blueprint: Vec<LogMsg>,
@@ -2321,8 +2778,10 @@ impl fmt::Debug for RecordingStream {
                 cmds_tx: _,
                 batcher: _,
                 batcher_to_sink_handle: _,
+                fork_of,
                 sink_dependent_batcher_config,
                 dataloader_handles,
+                schema_validator,
                 pid_at_creation,
             } = inner;
 
@@ -2330,11 +2789,13 @@ impl fmt::Debug for RecordingStream {
                 .field("store_info", &store_info)
                 .field("recording_info", &recording_info)
                 .field("tick", &tick)
+                .field("is_fork", &fork_of.is_some())
                 .field(
                     "sink_dependent_batcher_config",
                     &sink_dependent_batcher_config,
                 )
                 .field("pending_dataloaders", &dataloader_handles.lock().len())
+                .field("strict_schema", &schema_validator.lock().is_some())
                 .field("pid_at_creation", &pid_at_creation)
                 .finish_non_exhaustive()
         };
@@ -2599,6 +3060,41 @@ impl RecordingStream {
         );
     }
 
+    /// Set the time of a timeline from a raw reading of some [`Clock`], e.g. a sensor's hardware clock.
+    ///
+    /// Short for `self.set_time(timeline, clock.time_cell(nanos))`.
+    ///
+    /// The reading is converted such that data stamped by different clocks lines up in the viewer:
+    /// TAI and GPS readings are converted to UTC timestamps, taking leap seconds into account,
+    /// and monotonic readings are offset by the clock's epoch, if known.
+    ///
+    /// Call this once per clock to log the same data on several clocks:
+    /// ```no_run
+    /// # mod rerun { pub use re_sdk::*; }
+    /// # let rec: rerun::RecordingStream = unimplemented!();
+    /// # let (gps_nanos, driver_nanos, boot_time) = (0, 0, rerun::time::Timestamp::now());
+    /// rec.set_clock_time("gps_time", rerun::Clock::Gps, gps_nanos);
+    /// rec.set_clock_time("driver_time", rerun::Clock::monotonic_since(boot_time), driver_nanos);
+    /// ```
+    ///
+    /// Used for all subsequent logging performed from this same thread, until the next call
+    /// to one of the index/time setting methods.
+    ///
+    /// See also:
+    /// - [`Self::set_time`]
+    /// - [`Self::set_timepoint`]
+    /// - [`Self::disable_timeline`]
+    /// - [`Self::reset_time`]
+    #[inline]
+    pub fn set_clock_time(
+        &self,
+        timeline: impl Into<TimelineName>,
+        clock: Clock,
+        nanos: impl Into<i64>,
+    ) {
+        self.set_time(timeline, clock.time_cell(nanos.into()));
+    }
+
     /// Set the current time of the recording, for the current calling thread.
     ///
     /// Used for all subsequent logging performed from this same thread, until the next call
@@ -2979,6 +3475,205 @@ mod tests {
         assert!(msgs.pop().is_none());
     }
 
+    struct FailingFlushSink;
+
+    impl LogSink for FailingFlushSink {
+        fn send(&self, _msg: LogMsg) {}
+
+        fn flush_blocking(&self, _timeout: Duration) -> Result<(), SinkFlushError> {
+            Err(SinkFlushError::failed("not acknowledged"))
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn flush_reports_sink_result() {
+        let (rec, _storage) = RecordingStreamBuilder::new("rerun_example_flush")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+        for row in example_rows(false) {
+            rec.record_row("a".into(), row, false);
+        }
+        rec.flush_blocking().unwrap();
+
+        rec.set_sink(Box::new(FailingFlushSink));
+        let err = rec.flush_blocking().unwrap_err();
+        assert!(err.to_string().contains("not acknowledged"), "{err}");
+
+        // Nobody waits for the result of an async flush.
+        rec.flush_async().unwrap();
+    }
+
+    #[cfg(feature = "async_flush")]
+    #[test]
+    fn flush_future_reports_sink_result() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let (rec, _storage) = RecordingStreamBuilder::new("rerun_example_flush_future")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+        for row in example_rows(false) {
+            rec.record_row("a".into(), row, false);
+        }
+        runtime.block_on(rec.flush_future()).unwrap();
+
+        rec.set_sink(Box::new(FailingFlushSink));
+        let err = runtime.block_on(rec.flush_future()).unwrap_err();
+        assert!(err.to_string().contains("not acknowledged"), "{err}");
+
+        // Disabled recordings have nothing to flush.
+        let rec = RecordingStreamBuilder::new("rerun_example_flush_future")
+            .enabled(false)
+            .buffered()
+            .unwrap();
+        runtime.block_on(rec.flush_future()).unwrap();
+    }
+
+    #[test]
+    fn fork_shares_sink() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_fork")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let fork = rec
+            .fork(Some("rerun_example_fork_metrics".into()), "metrics")
+            .unwrap();
+        let fork_store_id = fork.store_info().unwrap().store_id;
+        assert_eq!(fork_store_id.recording_id().as_str(), "metrics");
+        assert_eq!(
+            fork_store_id.application_id().as_str(),
+            "rerun_example_fork_metrics"
+        );
+        assert_eq!(fork_store_id.kind(), StoreKind::Recording);
+
+        for row in example_rows(false) {
+            fork.record_row("a".into(), row, false);
+        }
+        fork.flush_blocking().unwrap();
+
+        let fork_msgs = storage
+            .take()
+            .into_iter()
+            .filter(|msg| msg.store_id() == &fork_store_id)
+            .collect::<Vec<_>>();
+        assert!(matches!(fork_msgs[0], LogMsg::SetStoreInfo(_)));
+        assert!(
+            fork_msgs
+                .iter()
+                .any(|msg| matches!(msg, LogMsg::ArrowMsg(..)))
+        );
+
+        // The fork follows this recording to its new sink.
+        let storage = rec.memory();
+        for row in example_rows(false) {
+            fork.record_row("a".into(), row, false);
+        }
+        fork.flush_blocking().unwrap();
+
+        let fork_msgs = storage
+            .take()
+            .into_iter()
+            .filter(|msg| msg.store_id() == &fork_store_id)
+            .collect::<Vec<_>>();
+        assert!(matches!(fork_msgs[0], LogMsg::SetStoreInfo(_)));
+        assert!(
+            fork_msgs
+                .iter()
+                .any(|msg| matches!(msg, LogMsg::ArrowMsg(..)))
+        );
+
+        // Disabled recordings fork into disabled recordings.
+        assert!(
+            !RecordingStream::disabled()
+                .fork(None, "metrics")
+                .unwrap()
+                .is_enabled()
+        );
+    }
+
+    #[test]
+    fn drop_time_range_comes_after_logged_data() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_drop_time_range")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        for row in example_rows(false) {
+            rec.record_row("a".into(), row, false);
+        }
+        rec.drop_time_range("a", true, "frame_nr", AbsoluteTimeRange::new(0, 42));
+        rec.flush_blocking().unwrap();
+
+        let msgs = storage.take();
+        let Some(LogMsg::DropTimeRange(cmd)) = msgs.last() else {
+            panic!("expected DropTimeRange, got {:?}", msgs.last());
+        };
+        assert_eq!(cmd.store_id, rec.store_info().unwrap().store_id);
+        assert_eq!(cmd.entity_path, EntityPath::from("a"));
+        assert!(cmd.recursive);
+
+        // Everything that was logged before is sent before the command, even though the batcher never flushes.
+        assert!(msgs.iter().any(|msg| matches!(msg, LogMsg::ArrowMsg(..))));
+    }
+
+    #[test]
+    fn strict_schema_rejects_datatype_changes() {
+        use re_chunk::external::arrow::array::{ArrayRef, Int32Array};
+        use re_types::{ComponentBatch as _, SerializedComponentBatch};
+
+        let (rec, _mem) = RecordingStreamBuilder::new("rerun_example_strict_schema")
+            .enabled(true)
+            .batcher_config(ChunkBatcherConfig::NEVER)
+            .memory()
+            .unwrap();
+
+        let labels = [MyLabel("a".into())]
+            .try_serialized(MyPoints::descriptor_labels())
+            .unwrap();
+        let ints = SerializedComponentBatch::new(
+            Arc::new(Int32Array::from(vec![42])) as ArrayRef,
+            MyPoints::descriptor_labels(),
+        );
+
+        // Not checked by default.
+        rec.log("a", &labels).unwrap();
+        rec.log("a", &ints).unwrap();
+
+        rec.set_strict_schema(true);
+        rec.log("a", &labels).unwrap();
+        rec.log("a", &labels).unwrap();
+        let err = rec.log("a", &ints).unwrap_err();
+        assert!(matches!(err, RecordingStreamError::SchemaMismatch(_)));
+
+        // Other entities are independent.
+        rec.log("b", &ints).unwrap();
+
+        // Columns are checked too.
+        let err = rec
+            .send_columns(
+                "b",
+                [TimeColumn::new_sequence("frame", [1])],
+                [labels.clone().partitioned([1]).unwrap()],
+            )
+            .unwrap_err();
+        assert!(matches!(err, RecordingStreamError::SchemaMismatch(_)));
+
+        rec.set_strict_schema(false);
+        rec.log("a", &ints).unwrap();
+    }
+
     #[test]
     fn test_set_thread_local() {
         // Regression-test for https://github.com/rerun-io/rerun/issues/2889
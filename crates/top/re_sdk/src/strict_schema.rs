@@ -0,0 +1,64 @@
+use ahash::HashMap;
+
+use re_chunk::external::arrow::datatypes::DataType;
+use re_log_types::EntityPath;
+use re_types::ComponentDescriptor;
+
+/// A component was logged with a different Arrow datatype than it was previously logged with.
+///
+/// Only returned by [`crate::RecordingStream`]s in strict schema mode,
+/// see [`crate::RecordingStream::set_strict_schema`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "Component {descriptor} of entity {entity_path} was previously logged as {expected}, but is now logged as {actual}"
+)]
+pub struct SchemaMismatchError {
+    pub entity_path: EntityPath,
+    pub descriptor: ComponentDescriptor,
+
+    /// The datatype the component was first logged with.
+    pub expected: DataType,
+
+    /// The datatype the component is now being logged with.
+    pub actual: DataType,
+}
+
+/// Remembers the datatype of every component logged to a recording in strict schema mode.
+#[derive(Default)]
+pub(crate) struct SchemaValidator {
+    datatypes: HashMap<(EntityPath, ComponentDescriptor), DataType>,
+}
+
+impl SchemaValidator {
+    /// Checks that all given components have the same datatype as previous uses of their descriptor
+    /// on that entity, and remembers the datatypes of those that weren't logged before.
+    ///
+    /// If any of them doesn't match, nothing is remembered.
+    pub fn validate<'a>(
+        &mut self,
+        entity_path: &EntityPath,
+        components: impl IntoIterator<Item = (&'a ComponentDescriptor, DataType)>,
+    ) -> Result<(), Box<SchemaMismatchError>> {
+        let mut new_datatypes = Vec::new();
+
+        for (descriptor, actual) in components {
+            let key = (entity_path.clone(), descriptor.clone());
+            match self.datatypes.get(&key) {
+                Some(expected) if *expected != actual => {
+                    return Err(Box::new(SchemaMismatchError {
+                        entity_path: entity_path.clone(),
+                        descriptor: descriptor.clone(),
+                        expected: expected.clone(),
+                        actual,
+                    }));
+                }
+                Some(_) => {}
+                None => new_datatypes.push((key, actual)),
+            }
+        }
+
+        self.datatypes.extend(new_datatypes);
+
+        Ok(())
+    }
+}
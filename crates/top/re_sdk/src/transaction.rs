@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+
+use re_chunk::{Chunk, ChunkBuilder, RowId};
+use re_log_types::{EntityPath, TimePoint};
+use re_types::AsComponents;
+
+use crate::{RecordingStream, RecordingStreamResult};
+
+/// Groups several log calls into a single update of the recording, see [`RecordingStream::transaction`].
+///
+/// All temporal data logged to a transaction shares the same [`TimePoint`]: the time of the
+/// recording on the calling thread when the transaction was started.
+/// Nothing is sent until [`Self::commit`] is called, at which point all the data of
+/// an entity is sent as a single chunk, and all chunks are sent back-to-back.
+/// The viewer holds back the chunks of a transaction until all of them have arrived,
+/// so that e.g. a camera pose and its image never show up out of sync.
+///
+/// Dropping a transaction without committing it discards its data.
+///
+/// ```no_run
+/// # use re_types::archetypes::{Boxes2D, Image, Transform3D};
+/// # fn example(
+/// #     rec: &re_sdk::RecordingStream,
+/// #     pose: &Transform3D,
+/// #     image: &Image,
+/// #     detections: &Boxes2D,
+/// # ) -> re_sdk::RecordingStreamResult<()> {
+/// rec.set_time_sequence("frame", 42);
+///
+/// let mut frame = rec.transaction();
+/// frame
+///     .log("world/camera", pose)
+///     .log("world/camera/image", image)
+///     .log("world/camera/image/detections", detections);
+/// frame.commit()?;
+/// # Ok(()) }
+/// ```
+pub struct Transaction {
+    rec: RecordingStream,
+    timepoint: TimePoint,
+
+    /// One chunk per entity, with static data kept apart.
+    chunks: BTreeMap<(EntityPath, bool), ChunkBuilder>,
+}
+
+impl Transaction {
+    pub(crate) fn new(rec: RecordingStream, timepoint: TimePoint) -> Self {
+        Self {
+            rec,
+            timepoint,
+            chunks: BTreeMap::default(),
+        }
+    }
+
+    /// The time shared by all temporal data of this transaction.
+    #[inline]
+    pub fn timepoint(&self) -> &TimePoint {
+        &self.timepoint
+    }
+
+    /// Adds an archetype or collection of component batches to the transaction.
+    ///
+    /// This is the transactional counterpart of [`RecordingStream::log`].
+    #[inline]
+    pub fn log<AS: ?Sized + AsComponents>(
+        &mut self,
+        ent_path: impl Into<EntityPath>,
+        as_components: &AS,
+    ) -> &mut Self {
+        self.log_with_static(ent_path, false, as_components)
+    }
+
+    /// Adds static data to the transaction.
+    ///
+    /// This is the transactional counterpart of [`RecordingStream::log_static`].
+    #[inline]
+    pub fn log_static<AS: ?Sized + AsComponents>(
+        &mut self,
+        ent_path: impl Into<EntityPath>,
+        as_components: &AS,
+    ) -> &mut Self {
+        self.log_with_static(ent_path, true, as_components)
+    }
+
+    /// Adds data to the transaction, as static data if `static_` is `true`.
+    ///
+    /// This is the transactional counterpart of [`RecordingStream::log_with_static`].
+    pub fn log_with_static<AS: ?Sized + AsComponents>(
+        &mut self,
+        ent_path: impl Into<EntityPath>,
+        static_: bool,
+        as_components: &AS,
+    ) -> &mut Self {
+        if !self.rec.is_enabled() {
+            return self; // silently drop the data
+        }
+
+        let row_id = RowId::new();
+        let comp_batches = as_components.as_serialized_batches();
+        if comp_batches.is_empty() {
+            return self;
+        }
+
+        let entity_path = ent_path.into();
+        let timepoint = if static_ {
+            TimePoint::default()
+        } else {
+            self.timepoint.clone()
+        };
+
+        let key = (entity_path, static_);
+        let builder = self
+            .chunks
+            .remove(&key)
+            .unwrap_or_else(|| Chunk::builder(key.0.clone()));
+        self.chunks.insert(
+            key,
+            builder.with_serialized_batches(row_id, timepoint, comp_batches),
+        );
+
+        self
+    }
+
+    /// Sends all data of the transaction.
+    ///
+    /// Everything logged to the [`RecordingStream`] before this call is sent before the transaction.
+    pub fn commit(self) -> RecordingStreamResult<()> {
+        re_tracing::profile_function!();
+
+        let chunks = self
+            .chunks
+            .into_values()
+            .map(|builder| builder.build())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chunks.is_empty() {
+            return Ok(());
+        }
+
+        self.rec.send_transaction(chunks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{ARROW_METADATA_KEY_TRANSACTION_CONTINUES, LogMsg, TimelineName};
+    use re_types::archetypes::{Points3D, TextDocument};
+
+    use crate::RecordingStreamBuilder;
+
+    use super::*;
+
+    #[test]
+    fn transaction_is_sent_as_one_chunk_per_entity() {
+        let (rec, storage) = RecordingStreamBuilder::new("rerun_example_transaction")
+            .enabled(true)
+            .memory()
+            .unwrap();
+        rec.set_time_sequence("frame", 42);
+
+        let mut transaction = rec.transaction();
+        transaction
+            .log("points", &Points3D::new([[1.0, 2.0, 3.0]]))
+            .log("points", &Points3D::new([[4.0, 5.0, 6.0]]))
+            .log("text", &TextDocument::new("hello"))
+            .log_static("description", &TextDocument::new("static"));
+        transaction.commit().unwrap();
+
+        let msgs = storage
+            .take()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Some(arrow_msg),
+                _ => None,
+            })
+            .filter(|arrow_msg| {
+                let chunk = Chunk::from_arrow_msg(arrow_msg).unwrap();
+                !chunk.entity_path().is_reserved()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(msgs.len(), 3);
+
+        let chunks = msgs
+            .iter()
+            .map(|arrow_msg| Chunk::from_arrow_msg(arrow_msg).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| (chunk.entity_path().to_string(), chunk.num_rows()))
+                .collect::<Vec<_>>(),
+            [
+                ("/description".to_owned(), 1),
+                ("/points".to_owned(), 2),
+                ("/text".to_owned(), 1),
+            ]
+        );
+        assert!(chunks[0].is_static());
+
+        let frames = chunks[1..]
+            .iter()
+            .flat_map(|chunk| {
+                chunk.timelines()[&TimelineName::new("frame")]
+                    .times_raw()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(frames, [42, 42, 42]);
+
+        // Only the last message closes the transaction.
+        let continues = msgs
+            .iter()
+            .map(|arrow_msg| {
+                arrow_msg
+                    .batch
+                    .schema_ref()
+                    .metadata()
+                    .contains_key(ARROW_METADATA_KEY_TRANSACTION_CONTINUES)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(continues, [true, true, false]);
+    }
+}
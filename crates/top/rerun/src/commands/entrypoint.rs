@@ -22,6 +22,9 @@ use crate::commands::AnalyticsCommands;
 #[cfg(feature = "auth")]
 use super::auth::AuthCommands;
 
+#[cfg(feature = "headless")]
+use super::screenshot::ScreenshotCommand;
+
 // ---
 
 const LONG_ABOUT: &str = r#"
@@ -38,6 +41,8 @@ Environment variables:
     RERUN_CHUNK_MAX_ROWS      Maximum chunk row count threshold for the compactor (sorted chunks).
     RERUN_CHUNK_MAX_ROWS_IF_UNSORTED
                               Maximum chunk row count threshold for the compactor (unsorted chunks).
+    RERUN_CHUNK_STORE_BACKEND Where the viewer keeps the data of recordings: `memory` (default), or `mmap` to keep
+                              it in memory-mapped temporary files, for recordings larger than RAM.
     RERUN_SHADER_PATH         The search path for shader/shader-imports. Only available in developer builds.
     RERUN_TRACK_ALLOCATIONS   Track memory allocations to diagnose memory leaks in the viewer.
                               WARNING: slows down the viewer by a lot!
@@ -106,6 +111,33 @@ Example: `16GB` or `50%` (of system total)."
     )]
     memory_limit: String,
 
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"An upper limit on how much memory each recording in the Rerun Viewer may use.
+When a recording exceeds it, Rerun will drop the oldest data of that recording only.
+Example: `2GB` or `25%` (of `--memory-limit`)."
+    )]
+    recording_memory_budget: Option<String>,
+
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"How long the Rerun Viewer keeps the data of each entity subtree, regardless of `--memory-limit`.
+Data older than its retention is dropped, and data kept `forever` is never dropped to stay within memory limits.
+Example: `/camera/**=2min,/state/**=forever`."
+    )]
+    retention: Option<String>,
+
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"How many milliseconds per frame the Rerun Viewer may spend compacting a recording, when asked to.
+The compaction thresholds are set with `RERUN_CHUNK_MAX_ROWS`, `RERUN_CHUNK_MAX_ROWS_IF_UNSORTED` and `RERUN_CHUNK_MAX_BYTES`.
+Example: `10`."
+    )]
+    compaction_time_budget: Option<u64>,
+
     #[clap(
         long,
         default_value = None,
@@ -147,6 +179,24 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     screenshot_to: Option<std::path::PathBuf>,
 
+    /// Run a Rhai script once the first recording is loaded, e.g. to select or jump to something.
+    ///
+    /// Can be specified more than once. Type `help()` in the script console
+    /// (in the Rerun menu) to see the available functions.
+    #[clap(long = "script", value_name = "PATH")]
+    scripts: Vec<std::path::PathBuf>,
+
+    /// Load a blueprint (`.rbl`) file at startup.
+    ///
+    /// It becomes the active blueprint of the application it was saved for,
+    /// so a layout checked into version control can be shared across a team.
+    #[clap(long, value_name = "PATH")]
+    blueprint: Option<std::path::PathBuf>,
+
+    /// Save the active blueprint to a `.rbl` file when the Viewer is closed.
+    #[clap(long, value_name = "PATH")]
+    save_blueprint: Option<std::path::PathBuf>,
+
     /// This will host a web-viewer over HTTP, and a gRPC server,
     /// unless one or more URIs are provided that can be viewed directly in the web viewer.
     ///
@@ -540,9 +590,22 @@ enum Command {
     #[cfg(feature = "native_viewer")]
     Reset,
 
+    /// Bridge live ROS 2 topics into a Rerun Viewer or server.
+    ///
+    /// Example: `rerun ros2-bridge --topic /tf --topic /camera/image_raw`
+    #[cfg(feature = "ros2_bridge")]
+    #[command(name = "ros2-bridge")]
+    Ros2Bridge(re_ros2_bridge::Args),
+
     #[command(subcommand)]
     Rrd(RrdCommands),
 
+    /// Render views of a recording to a PNG, without opening a window.
+    ///
+    /// Example: `rerun screenshot recording.rrd --view "3D view" --time 42 -o screenshot.png`
+    #[cfg(feature = "headless")]
+    Screenshot(ScreenshotCommand),
+
     /// In-memory Rerun data server
     #[cfg(feature = "oss_server")]
     #[command(name = "server")]
@@ -640,8 +703,16 @@ where
             #[cfg(feature = "native_viewer")]
             Command::Reset => re_viewer::reset_viewer_persistence(),
 
+            #[cfg(feature = "ros2_bridge")]
+            Command::Ros2Bridge(bridge) => bridge.run(),
+
             Command::Rrd(rrd) => rrd.run(),
 
+            #[cfg(feature = "headless")]
+            Command::Screenshot(screenshot) => {
+                screenshot.run(main_thread_token, call_source.app_env())
+            }
+
             #[cfg(feature = "oss_server")]
             Command::Server(server) => server.run(),
         }
@@ -921,6 +992,10 @@ fn native_startup_options_from_args(args: &Args) -> anyhow::Result<re_viewer::St
         Ok(hw_accell) => Some(hw_accell),
     });
 
+    if !cfg!(feature = "scripting") && !args.scripts.is_empty() {
+        anyhow::bail!("Can't run --script - rerun was not compiled with the 'scripting' feature");
+    }
+
     Ok(re_viewer::StartupOptions {
         hide_welcome_screen: args.hide_welcome_screen,
         detach_process: args.detach_process,
@@ -929,6 +1004,24 @@ fn native_startup_options_from_args(args: &Args) -> anyhow::Result<re_viewer::St
             re_memory::MemoryLimit::parse(&args.memory_limit)
                 .map_err(|err| anyhow::format_err!("Bad --memory-limit: {err}"))?
         },
+        recording_memory_budget: if let Some(budget) = &args.recording_memory_budget {
+            re_viewer::RecordingMemoryBudget::parse(budget)
+                .map_err(|err| anyhow::format_err!("Bad --recording-memory-budget: {err}"))?
+        } else {
+            Default::default()
+        },
+        spill_evicted_chunks: false,
+        retention_policy: if let Some(retention) = &args.retention {
+            retention
+                .parse()
+                .map_err(|err| anyhow::format_err!("Bad --retention: {err}"))?
+        } else {
+            Default::default()
+        },
+        compaction_time_budget: args.compaction_time_budget.map_or(
+            re_viewer::StartupOptions::DEFAULT_COMPACTION_TIME_BUDGET,
+            std::time::Duration::from_millis,
+        ),
         persist_state: args.persist_state,
         is_in_notebook: false,
         screenshot_to_path_then_quit: args.screenshot_to.clone(),
@@ -945,12 +1038,18 @@ fn native_startup_options_from_args(args: &Args) -> anyhow::Result<re_viewer::St
         } else {
             None
         },
+        #[cfg(feature = "scripting")]
+        startup_scripts: args.scripts.clone(),
+        blueprint_path: args.blueprint.clone(),
+        save_blueprint_on_exit: args.save_blueprint.clone(),
         force_wgpu_backend: args.renderer.clone(),
         video_decoder_hw_acceleration,
 
         on_event: None,
 
         panel_state_overrides: Default::default(),
+
+        custom_theme: None,
     })
 }
 
@@ -1278,7 +1377,7 @@ fn run_profiler(args: &Args) -> re_tracing::Profiler {
 }
 
 #[cfg(feature = "native_viewer")]
-fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
+pub(crate) fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
     fn parse_size_inner(size: &str) -> Option<[f32; 2]> {
         let (w, h) = size.split_once('x')?;
         let w = w.parse().ok()?;
@@ -1,13 +1,19 @@
 use std::{collections::BTreeSet, fs::File, io::BufWriter, sync::mpsc::Receiver};
 
+use anyhow::Context as _;
 use clap::Subcommand;
+use re_chunk::Chunk;
 use re_log_encoding::encoder::DroppableEncoder;
-use re_log_types::{LogMsg, RecordingId};
-use re_mcap::{LayerIdentifier, SelectedLayers};
+use re_log_types::{LogMsg, RecordingId, TimelineName};
+use re_mcap::{
+    LayerIdentifier, McapExportOptions, McapExporter, McapMessageEncoding, SelectedLayers,
+};
 use re_sdk::{
     ApplicationId, DataLoader, DataLoaderSettings, LoadedData, external::re_data_loader::McapLoader,
 };
 
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
 #[derive(Debug, Clone, clap::Parser)]
 pub struct ConvertCommand {
     /// Paths to read from. Reads from standard input if none are specified.
@@ -101,17 +107,129 @@ impl ConvertCommand {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+enum ExportEncoding {
+    /// Lossless: every message is a single row, encoded as an Arrow IPC stream.
+    #[default]
+    Arrow,
+
+    /// Every message is a single row, encoded as a JSON object.
+    Json,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Path to write to.
+    #[arg(short = 'o', long = "output", value_name = "dst.mcap")]
+    path_to_output_mcap: String,
+
+    /// How to encode the MCAP messages.
+    #[clap(long, value_enum, default_value_t)]
+    encoding: ExportEncoding,
+
+    /// The timestamp timeline used for the log time of the MCAP messages.
+    ///
+    /// Defaults to `log_time`, falling back to the first timestamp timeline of each chunk.
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl ExportCommand {
+    fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_mcap,
+            encoding,
+            timeline,
+            continue_on_error,
+        } = self;
+
+        let start_time = std::time::Instant::now();
+
+        let options = McapExportOptions {
+            encoding: match encoding {
+                ExportEncoding::Arrow => McapMessageEncoding::Arrow,
+                ExportEncoding::Json => McapMessageEncoding::Json,
+            },
+            timeline: timeline.as_deref().map(TimelineName::new),
+        };
+
+        let file = File::create(path_to_output_mcap)
+            .with_context(|| format!("{path_to_output_mcap:?}"))?;
+        let mut exporter = McapExporter::new(BufWriter::new(file), options)?;
+
+        let (rx, _) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        let mut num_chunks = 0;
+        for (_source, res) in rx {
+            let result = res.and_then(|msg| {
+                match msg {
+                    // Blueprints are not part of the exported data.
+                    LogMsg::ArrowMsg(store_id, arrow_msg) if store_id.is_recording() => {
+                        let chunk = Chunk::from_arrow_msg(&arrow_msg)?;
+                        exporter.add_chunk(&chunk)?;
+                        num_chunks += 1;
+                    }
+
+                    // Chunks are exported as they come, so there is nothing left to drop them from.
+                    LogMsg::DropTimeRange(_) => {
+                        re_log::warn_once!(
+                            "Time range deletions are not supported when exporting to MCAP and will be ignored"
+                        );
+                    }
+
+                    LogMsg::ArrowMsg(..)
+                    | LogMsg::SetStoreInfo(_)
+                    | LogMsg::BlueprintActivationCommand(_) => {}
+                }
+
+                Ok(())
+            });
+
+            if let Err(err) = result {
+                re_log::error_once!("{}", re_error::format(err));
+
+                if !continue_on_error {
+                    anyhow::bail!(
+                        "one or more IO and/or decoding failures in the input stream (check logs)"
+                    );
+                }
+            }
+        }
+
+        exporter.finish()?;
+
+        re_log::info!(
+            "Exported {num_chunks} chunks to {path_to_output_mcap:?} in {:.1}s",
+            start_time.elapsed().as_secs_f64()
+        );
+
+        Ok(())
+    }
+}
+
 /// Manipulate the contents of .mcap files.
 #[derive(Debug, Clone, Subcommand)]
 pub enum McapCommands {
     /// Convert an .mcap file to an .rrd
     Convert(ConvertCommand),
+
+    /// Export .rrd files to a single .mcap file, with one channel per entity.
+    Export(ExportCommand),
 }
 
 impl McapCommands {
     pub fn run(&self) -> anyhow::Result<()> {
         match self {
             Self::Convert(cmd) => cmd.run(),
+            Self::Export(cmd) => cmd.run(),
         }
     }
 }
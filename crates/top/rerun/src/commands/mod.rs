@@ -28,6 +28,8 @@ mod entrypoint;
 #[cfg(feature = "data_loaders")]
 mod mcap;
 mod rrd;
+#[cfg(feature = "headless")]
+mod screenshot;
 mod stdio;
 
 #[cfg(feature = "analytics")]
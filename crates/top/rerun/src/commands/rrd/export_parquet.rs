@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportParquetCommand {
+    /// Path to the .rrd file to export.
+    path_to_input_rrd: String,
+
+    /// The directory to write the Parquet files to. It is created if needed.
+    #[arg(short = 'o', long = "output", value_name = "dst")]
+    path_to_output_dir: String,
+}
+
+impl ExportParquetCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrd,
+            path_to_output_dir,
+        } = self;
+
+        let stores =
+            ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, path_to_input_rrd)
+                .with_context(|| format!("path: {path_to_input_rrd:?}"))?;
+
+        let mut num_files = 0;
+        let mut num_rows = 0;
+        let mut num_skipped_chunks = 0;
+
+        for (store_id, store) in &stores {
+            if !store_id.is_recording() {
+                continue;
+            }
+
+            let dir = PathBuf::from(path_to_output_dir).join(store_id.recording_id().as_str());
+            let report = store
+                .export_parquet(&dir)
+                .with_context(|| format!("exporting recording {:?}", store_id.recording_id()))?;
+
+            num_files += report.files.len();
+            num_rows += report.num_rows;
+            num_skipped_chunks += report.num_skipped_chunks;
+        }
+
+        eprintln!(
+            "Exported {} rows to {} Parquet files in {path_to_output_dir:?}",
+            re_format::format_uint(num_rows),
+            re_format::format_uint(num_files),
+        );
+        if num_skipped_chunks > 0 {
+            re_log::warn!(
+                "Skipped {} chunks whose columns don't match the other chunks of their entity",
+                re_format::format_uint(num_skipped_chunks),
+            );
+        }
+
+        Ok(())
+    }
+}
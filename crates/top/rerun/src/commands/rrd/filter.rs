@@ -1,16 +1,12 @@
-use std::{collections::HashSet, io::IsTerminal as _};
+use std::io::IsTerminal as _;
 
 use anyhow::Context as _;
-use arrow::array::RecordBatchOptions;
-use arrow::{
-    array::RecordBatch as ArrowRecordBatch,
-    datatypes::{Field as ArrowField, Schema as ArrowSchema},
-};
 use itertools::Either;
 
 use re_build_info::CrateVersion;
-use re_chunk::external::crossbeam;
-use re_sdk::{EntityPath, external::arrow};
+use re_chunk::{ChunkFilter, external::crossbeam};
+use re_log_types::{AbsoluteTimeRange, EntityPathFilter, EntityPathSubs, TimeInt, TimelineName};
+use re_sdk::EntityPath;
 
 use crate::commands::read_rrd_streams_from_file_or_stdin;
 
@@ -25,6 +21,21 @@ pub struct FilterCommand {
     #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
     path_to_output_rrd: Option<String>,
 
+    /// Entity path filter expressions of the entities to keep, e.g. `/camera/**` or `-/camera/depth/**`.
+    ///
+    /// Everything is kept if unspecified. The recording properties are always kept.
+    #[clap(long = "entity", value_name = "EXPR")]
+    entity_filters: Vec<String>,
+
+    /// Only keep the temporal data within this time range of a timeline, inclusive, e.g. `frame=100..200`.
+    ///
+    /// Either bound can be omitted, e.g. `log_time=..1700000000000000000`.
+    /// Times are sequence numbers for sequence timelines, and nanoseconds for the others.
+    ///
+    /// Temporal data that isn't on that timeline is dropped, static data is always kept.
+    #[clap(long = "time-range", value_name = "TIMELINE=MIN..MAX", value_parser = parse_time_range)]
+    time_range: Option<(TimelineName, AbsoluteTimeRange)>,
+
     /// Names of the timelines to be filtered out.
     #[clap(long = "drop-timeline")]
     dropped_timelines: Vec<String>,
@@ -43,6 +54,8 @@ impl FilterCommand {
         let Self {
             path_to_input_rrds,
             path_to_output_rrd,
+            entity_filters,
+            time_range,
             dropped_timelines,
             dropped_entity_paths,
             continue_on_error,
@@ -57,13 +70,30 @@ impl FilterCommand {
         }
 
         let now = std::time::Instant::now();
-        re_log::info!(srcs = ?path_to_input_rrds, ?dropped_timelines, "filter started");
+        re_log::info!(
+            srcs = ?path_to_input_rrds,
+            ?entity_filters,
+            ?time_range,
+            ?dropped_timelines,
+            "filter started"
+        );
 
-        let dropped_timelines: HashSet<_> = dropped_timelines.iter().cloned().collect();
-        let dropped_entity_paths: HashSet<EntityPath> = dropped_entity_paths
-            .iter()
-            .map(|s| EntityPath::parse_forgiving(s))
-            .collect();
+        let mut filter = ChunkFilter::default();
+        if !entity_filters.is_empty() {
+            filter = filter.with_entity_filter(
+                EntityPathFilter::from_query_expressions(entity_filters.iter().map(String::as_str))
+                    .resolve_forgiving(&EntityPathSubs::empty()),
+            );
+        }
+        if let Some((timeline, time_range)) = time_range {
+            filter = filter.with_time_range(*timeline, *time_range);
+        }
+        for timeline in dropped_timelines {
+            filter = filter.with_dropped_timeline(timeline.as_str().into());
+        }
+        for entity_path in dropped_entity_paths {
+            filter = filter.with_dropped_entity(EntityPath::parse_forgiving(entity_path));
+        }
 
         let (rx_decoder, rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
 
@@ -106,56 +136,17 @@ impl FilterCommand {
             let mut is_success = true;
 
             match res {
-                Ok(msg) => {
-                    let msg = match msg {
-                        re_log_types::LogMsg::ArrowMsg(store_id, mut msg) => {
-                            match re_sorbet::ChunkBatch::try_from(&msg.batch) {
-                                Ok(batch) => {
-                                    if !dropped_entity_paths.contains(batch.entity_path()) {
-                                        None
-                                    } else {
-                                        let (fields, columns): (Vec<_>, Vec<_>) = itertools::izip!(
-                                            &batch.schema().fields,
-                                            batch.columns()
-                                        )
-                                        .filter(|(field, _col)| {
-                                            !is_field_timeline_of(field, &dropped_timelines)
-                                        })
-                                        .map(|(field, col)| (field.clone(), col.clone()))
-                                        .unzip();
-
-                                        if let Ok(new_batch) =
-                                            ArrowRecordBatch::try_new_with_options(
-                                                ArrowSchema::new_with_metadata(
-                                                    fields,
-                                                    batch.schema().metadata().clone(),
-                                                )
-                                                .into(),
-                                                columns,
-                                                &RecordBatchOptions::default(),
-                                            )
-                                        {
-                                            msg.batch = new_batch;
-                                            Some(re_log_types::LogMsg::ArrowMsg(store_id, msg))
-                                        } else {
-                                            None // Probably failed because we filtered out everything
-                                        }
-                                    }
-                                }
-                                Err(err) => {
-                                    re_log::warn_once!("Failed to parse chunk schema: {err}");
-                                    None
-                                }
-                            }
-                        }
-
-                        msg => Some(msg),
-                    };
-
-                    if let Some(msg) = msg {
+                Ok(msg) => match filter.apply_to_log_msg(msg) {
+                    Ok(Some(msg)) => {
                         tx_encoder.send(msg).ok();
                     }
-                }
+
+                    Ok(None) => {} // Filtered out entirely.
+
+                    Err(err) => {
+                        re_log::warn_once!("Failed to parse chunk: {err}");
+                    }
+                },
 
                 Err(err) => {
                     re_log::error!(err = re_error::format(err));
@@ -209,9 +200,30 @@ impl FilterCommand {
 
 // ---
 
-// Does the given field represent a timeline that is in the given set?
-fn is_field_timeline_of(field: &ArrowField, dropped_timelines: &HashSet<String>) -> bool {
-    re_sorbet::IndexColumnDescriptor::try_from(field)
-        .ok()
-        .is_some_and(|schema| dropped_timelines.contains(schema.column_name()))
+/// Parses `<timeline>=<min>..<max>`, where either bound can be omitted.
+fn parse_time_range(s: &str) -> Result<(TimelineName, AbsoluteTimeRange), String> {
+    let invalid =
+        || format!("expected `<timeline>=<min>..<max>`, e.g. `frame=100..200`, got {s:?}");
+
+    let (timeline, range) = s.split_once('=').ok_or_else(invalid)?;
+    let (min, max) = range.split_once("..").ok_or_else(invalid)?;
+    let parse_bound = |bound: &str, unbounded: TimeInt| {
+        let bound = bound.trim();
+        if bound.is_empty() {
+            Ok(unbounded)
+        } else {
+            bound
+                .parse::<i64>()
+                .map(TimeInt::new_temporal)
+                .map_err(|_err| invalid())
+        }
+    };
+
+    Ok((
+        TimelineName::new(timeline.trim()),
+        AbsoluteTimeRange::new(
+            parse_bound(min, TimeInt::MIN)?,
+            parse_bound(max, TimeInt::MAX)?,
+        ),
+    ))
 }
@@ -138,6 +138,7 @@ fn migrate_from_to(from_path: &Utf8PathBuf, to_path: &Utf8PathBuf) -> anyhow::Re
                 }
             }
             re_log_types::LogMsg::BlueprintActivationCommand(..)
+            | re_log_types::LogMsg::DropTimeRange(..)
             | re_log_types::LogMsg::SetStoreInfo(..) => Some(Ok(msg)),
         },
         Err(err) => {
@@ -1,18 +1,22 @@
 mod compare;
+mod export_parquet;
 mod filter;
 mod merge_compact;
 mod migrate;
 mod print;
+mod rewrite_timeline;
 mod route;
 mod stats;
 mod verify;
 
 use self::{
     compare::CompareCommand,
+    export_parquet::ExportParquetCommand,
     filter::FilterCommand,
     merge_compact::{CompactCommand, MergeCommand},
     migrate::MigrateCommand,
     print::PrintCommand,
+    rewrite_timeline::RewriteTimelineCommand,
     route::RouteCommand,
     stats::StatsCommand,
     verify::VerifyCommand,
@@ -52,13 +56,24 @@ pub enum RrdCommands {
     /// This ignores the `log_time` timeline.
     Compare(CompareCommand),
 
+    /// Exports the recordings of an .rrd file as Parquet files, one per entity, for use with other analytics tools.
+    ///
+    /// Each recording goes to `<dst>/<recording id>/<entity path>/chunks.parquet`, with one column per timeline
+    /// and per component.
+    ///
+    /// Example: `rerun rrd export-parquet recording.rrd -o /tmp/parquet`
+    ExportParquet(ExportParquetCommand),
+
     /// Filters out data from .rrd/.rbl files/streams, and writes the result to standard output.
     ///
     /// Reads from standard input if no paths are specified.
     ///
     /// This will not affect the chunking of the data in any way.
+    /// Blueprints and static data are kept as-is.
     ///
     /// Example: `rerun rrd filter --drop-timeline log_tick /my/recordings/*.rrd > output.rrd`
+    ///
+    /// Example: `rerun rrd filter --entity '/camera/**' --time-range frame=100..200 big.rrd -o repro.rrd`
     Filter(FilterCommand),
 
     /// Merges the contents of multiple .rrd/.rbl files/streams, and writes the result to standard output.
@@ -83,6 +98,18 @@ pub enum RrdCommands {
     /// Example: `rerun rrd print /my/recordings/*.rrd`
     Print(PrintCommand),
 
+    /// Rewrites the times of a timeline of .rrd/.rbl files/streams, and writes the result to standard output.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Every time `t` becomes `round(t * scale) + offset`. The timeline can also be renamed and change type.
+    /// Other timelines, blueprints and static data are kept as-is.
+    ///
+    /// Example: `rerun rrd rewrite-timeline --timeline log_time --offset=-37000000000 in.rrd -o fixed.rrd`
+    ///
+    /// Example: `rerun rrd rewrite-timeline --timeline frame --scale 33333333.333 --type duration --rename time in.rrd > out.rrd`
+    RewriteTimeline(RewriteTimelineCommand),
+
     /// Manipulates the metadata of log message streams without decoding the payloads.
     ///
     /// This can be used to combine multiple .rrd files into a single recording.
@@ -113,10 +140,12 @@ impl RrdCommands {
                     .with_context(|| format!("current directory {:?}", std::env::current_dir()))
             }
             Self::Compact(cmd) => cmd.run(),
+            Self::ExportParquet(cmd) => cmd.run(),
             Self::Filter(cmd) => cmd.run(),
             Self::Merge(cmd) => cmd.run(),
             Self::Migrate(cmd) => cmd.run(),
             Self::Print(cmd) => cmd.run(),
+            Self::RewriteTimeline(cmd) => cmd.run(),
             Self::Route(cmd) => cmd.run(),
             Self::Stats(cmd) => cmd.run(),
             Self::Verify(cmd) => cmd.run(),
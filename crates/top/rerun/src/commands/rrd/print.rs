@@ -237,6 +237,18 @@ fn print_msg(options: &Options, msg: LogMsg) -> anyhow::Result<()> {
                 "BlueprintActivationCommand({blueprint_id:?}, make_active: {make_active}, make_default: {make_default})"
             );
         }
+
+        LogMsg::DropTimeRange(re_log_types::DropTimeRangeCommand {
+            store_id,
+            entity_path,
+            recursive,
+            timeline,
+            range,
+        }) => {
+            println!(
+                "DropTimeRange({store_id:?}, entity_path: {entity_path}, recursive: {recursive}, timeline: {timeline}, range: {range:?})"
+            );
+        }
     }
 
     Ok(())
@@ -0,0 +1,195 @@
+use std::io::IsTerminal as _;
+
+use anyhow::Context as _;
+use itertools::Either;
+
+use re_build_info::CrateVersion;
+use re_chunk::{TimelineRewrite, external::crossbeam};
+use re_log_types::{TimeType, TimelineName};
+
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct RewriteTimelineCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Path to write to. Writes to standard output if unspecified.
+    #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
+    path_to_output_rrd: Option<String>,
+
+    /// Name of the timeline to rewrite.
+    #[clap(long = "timeline")]
+    timeline: String,
+
+    /// Added to every time, after scaling.
+    ///
+    /// In sequence numbers for sequence timelines, and nanoseconds for the others, e.g. `-37000000000` to
+    /// correct a clock that was 37 s ahead.
+    #[clap(long = "offset", default_value_t = 0, allow_hyphen_values = true)]
+    offset: i64,
+
+    /// Every time is multiplied by this, before the offset is added.
+    ///
+    /// E.g. `33333333.333` turns frame numbers at 30 fps into nanoseconds, to be used with `--type duration`.
+    #[clap(long = "scale", default_value_t = 1.0, allow_hyphen_values = true)]
+    scale: f64,
+
+    /// New name for the timeline.
+    #[clap(long = "rename")]
+    new_name: Option<String>,
+
+    /// New type for the timeline: `sequence`, `duration` or `timestamp`.
+    #[clap(long = "type", value_parser = parse_time_type)]
+    new_type: Option<TimeType>,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl RewriteTimelineCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_rrd,
+            timeline,
+            offset,
+            scale,
+            new_name,
+            new_type,
+            continue_on_error,
+        } = self;
+
+        anyhow::ensure!(scale.is_finite(), "the scale must be a finite number");
+
+        let path_to_output_rrd = path_to_output_rrd.clone();
+        if path_to_output_rrd.is_none() {
+            anyhow::ensure!(
+                !std::io::stdout().is_terminal(),
+                "you must redirect the output to a file and/or stream"
+            );
+        }
+
+        let now = std::time::Instant::now();
+        re_log::info!(
+            srcs = ?path_to_input_rrds,
+            %timeline,
+            offset,
+            scale,
+            ?new_name,
+            ?new_type,
+            "rewrite-timeline started"
+        );
+
+        let mut rewrite = TimelineRewrite::new(TimelineName::new(timeline))
+            .with_offset(*offset)
+            .with_scale(*scale);
+        if let Some(new_name) = new_name {
+            rewrite = rewrite.with_name(TimelineName::new(new_name));
+        }
+        if let Some(new_type) = new_type {
+            rewrite = rewrite.with_type(*new_type);
+        }
+
+        let (rx_decoder, _rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+        // TODO(cmc): might want to make this configurable at some point.
+        let (tx_encoder, rx_encoder) = crossbeam::channel::bounded(100);
+
+        let encoding_handle = std::thread::Builder::new()
+            .name("rerun-rrd-rewrite-timeline-out".to_owned())
+            .spawn(move || -> anyhow::Result<u64> {
+                use std::io::Write as _;
+
+                let mut rrd_out = if let Some(path) = path_to_output_rrd.as_ref() {
+                    Either::Left(std::io::BufWriter::new(
+                        std::fs::File::create(path).with_context(|| format!("{path:?}"))?,
+                    ))
+                } else {
+                    Either::Right(std::io::BufWriter::new(std::io::stdout().lock()))
+                };
+
+                let mut encoder = {
+                    // TODO(cmc): encoding options & version should match the original.
+                    let version = CrateVersion::LOCAL;
+                    let options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+                    re_log_encoding::encoder::DroppableEncoder::new(version, options, &mut rrd_out)
+                        .context("couldn't init encoder")?
+                };
+
+                let mut size_bytes = 0;
+                for msg in rx_encoder {
+                    size_bytes += encoder.append(&msg).context("encoding failure")?;
+                }
+
+                drop(encoder);
+                rrd_out.flush().context("couldn't flush output")?;
+
+                Ok(size_bytes)
+            });
+
+        let mut num_failures = 0;
+        for (_source, res) in rx_decoder {
+            let mut is_success = true;
+
+            match res {
+                Ok(msg) => match rewrite.apply_to_log_msg(msg) {
+                    Ok(msg) => {
+                        tx_encoder.send(msg).ok();
+                    }
+
+                    Err(err) => {
+                        re_log::error!("Failed to rewrite chunk: {err}");
+                        is_success = false;
+                    }
+                },
+
+                Err(err) => {
+                    re_log::error!(err = re_error::format(err));
+                    is_success = false;
+                }
+            }
+
+            if !is_success {
+                num_failures += 1;
+                if !*continue_on_error {
+                    anyhow::bail!(
+                        "one or more IO, decoding and/or rewriting failures in the input stream (check logs)"
+                    )
+                }
+            }
+        }
+
+        std::mem::drop(tx_encoder);
+        let rrd_out_size = encoding_handle
+            .context("couldn't spawn IO thread")?
+            .join()
+            .map_err(|err| anyhow::anyhow!("Unknown error: {err:?}"))??; // NOLINT: there is no `Display` for this `err`
+
+        re_log::info!(
+            dst_size_bytes = %re_format::format_bytes(rrd_out_size as _),
+            time = ?now.elapsed(),
+            num_failures,
+            srcs = ?path_to_input_rrds,
+            "rewrite-timeline finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+fn parse_time_type(s: &str) -> Result<TimeType, String> {
+    match s {
+        "sequence" => Ok(TimeType::Sequence),
+        "duration" => Ok(TimeType::DurationNs),
+        "timestamp" => Ok(TimeType::TimestampNs),
+        _ => Err(format!(
+            "expected `sequence`, `duration` or `timestamp`, got {s:?}"
+        )),
+    }
+}
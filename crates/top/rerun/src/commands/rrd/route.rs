@@ -5,7 +5,8 @@ use re_log_encoding::encoder::DroppableEncoder;
 use re_protos::{
     common::v1alpha1::ApplicationId,
     log_msg::v1alpha1::{
-        ArrowMsg, BlueprintActivationCommand, LogMsg, SetStoreInfo, StoreInfo, log_msg::Msg,
+        ArrowMsg, BlueprintActivationCommand, DropTimeRangeCommand, LogMsg, SetStoreInfo,
+        StoreInfo, log_msg::Msg,
     },
 };
 
@@ -148,6 +149,13 @@ fn process_messages<W: std::io::Write>(
                         encoding: _,
                         payload: _,
                         is_static: _,
+                    })
+                    | Msg::DropTimeRangeCommand(DropTimeRangeCommand {
+                        store_id,
+                        entity_path: _,
+                        recursive: _,
+                        timeline: _,
+                        time_range: _,
                     }) => {
                         if let Some(target_store_id) = store_id {
                             if let Some(recording_id) = &rewrites.recording_id {
@@ -65,7 +65,9 @@ impl Verifier {
 
     fn verify_log_msg(&mut self, source: &str, msg: LogMsg) {
         match msg {
-            LogMsg::SetStoreInfo { .. } | LogMsg::BlueprintActivationCommand { .. } => {}
+            LogMsg::SetStoreInfo { .. }
+            | LogMsg::BlueprintActivationCommand { .. }
+            | LogMsg::DropTimeRange { .. } => {}
 
             LogMsg::ArrowMsg(_store_id, arrow_msg) => {
                 self.verify_record_batch(source, &arrow_msg.batch);
@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+
+use re_viewer::headless::{HeadlessOptions, HeadlessRender};
+
+use super::entrypoint::parse_size;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ScreenshotCommand {
+    /// The recording to render, e.g. an .rrd file.
+    path_to_input: PathBuf,
+
+    /// Use this blueprint (.rbl) instead of the one in the recording, or the default one.
+    #[clap(long)]
+    blueprint: Option<PathBuf>,
+
+    /// The timeline to use, if not the default one.
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// The time to render at, e.g. `42`, `1.5s` or `2025-01-01T12:00:00Z`.
+    ///
+    /// Defaults to the end of the timeline.
+    #[clap(long)]
+    time: Option<String>,
+
+    /// The name or id of a view to render.
+    ///
+    /// Can be specified more than once, in which case each view is written to its own file,
+    /// with the name of the view appended to the output path.
+    /// If not specified, the whole viewport is rendered.
+    #[clap(long = "view")]
+    views: Vec<String>,
+
+    /// Size of the image in logical points, e.g. `1920x1080`.
+    #[clap(long, default_value = "1024x768")]
+    size: String,
+
+    /// Number of pixels per logical point.
+    #[clap(long, default_value_t = 1.0)]
+    pixels_per_point: f32,
+
+    /// Path of the PNG to write.
+    #[arg(short = 'o', long = "output", value_name = "dst.png")]
+    path_to_output: PathBuf,
+}
+
+impl ScreenshotCommand {
+    pub fn run(
+        self,
+        main_thread_token: crate::MainThreadToken,
+        app_env: re_viewer::AppEnvironment,
+    ) -> anyhow::Result<()> {
+        let Self {
+            path_to_input,
+            blueprint,
+            timeline,
+            time,
+            views,
+            size,
+            pixels_per_point,
+            path_to_output,
+        } = self;
+
+        let options = HeadlessOptions {
+            recording: path_to_input,
+            blueprint,
+            size: parse_size(&size)?.into(),
+            pixels_per_point,
+            timeline,
+            time,
+            views,
+        };
+
+        let renders = re_viewer::headless::render_views(main_thread_token, app_env, &options)?;
+        let is_single_image = renders.len() == 1;
+
+        for HeadlessRender { view_name, image } in renders {
+            let path = match view_name {
+                Some(view_name) if !is_single_image => {
+                    path_with_suffix(&path_to_output, &view_name)
+                }
+                _ => path_to_output.clone(),
+            };
+
+            image
+                .save(&path)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            re_log::info!("Wrote {}", path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// `out/screenshot.png` + `3D view` = `out/screenshot_3D_view.png`
+fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let suffix = suffix
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{stem}_{suffix}.{}", extension.to_string_lossy()),
+        None => format!("{stem}_{suffix}"),
+    };
+    path.with_file_name(file_name)
+}
@@ -92,6 +92,25 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! #### Blueprints
+//! You can control the layout of the viewer by sending a [`blueprint::Blueprint`]:
+//!
+//! ```no_run
+//! use rerun::blueprint::{Blueprint, BlueprintSendOptions, Container, View, views};
+//!
+//! let rec = rerun::RecordingStreamBuilder::new("rerun_example_app").connect_grpc()?;
+//!
+//! rec.send_blueprint_with_options(
+//!     Blueprint::new(Container::horizontal([
+//!         View::new::<views::Spatial3DView>("/world").into(),
+//!         View::new::<views::TimeSeriesView>("/metrics").into(),
+//!     ])),
+//!     BlueprintSendOptions::default(),
+//! );
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! ```ignore
 //! cargo install rerun --locked
 //! rerun --help
@@ -123,6 +142,9 @@ pub mod demo_util;
 #[cfg(feature = "log")]
 pub mod log_integration;
 
+#[cfg(all(feature = "mqtt", not(target_arch = "wasm32")))]
+pub mod mqtt_bridge;
+
 #[cfg(feature = "log")]
 pub use re_log::default_log_filter;
 
@@ -0,0 +1,444 @@
+//! Subscribe to MQTT topics and log the received messages to a [`RecordingStream`].
+//!
+//! ```no_run
+//! let rec = rerun::RecordingStreamBuilder::new("rerun_example_mqtt").connect_grpc()?;
+//!
+//! rerun::mqtt_bridge::MqttBridge::new(rec, "localhost", 1883)
+//!     .subscribe_json("sensors/#")
+//!     .run_blocking()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Each message is logged on the `mqtt_time` timeline, using the time it was received.
+//! By default the entity path is derived from the topic, so `sensors/room1` ends up at `/sensors/room1`.
+
+use std::{sync::Arc, time::Duration};
+
+use re_types::{SerializedComponentBatch, archetypes::Scalars, archetypes::TextLog};
+
+use crate::{AsComponents as _, EntityPath, RecordingStream};
+
+/// The timeline on which all bridged messages are logged.
+pub const MQTT_TIMELINE: &str = "mqtt_time";
+
+/// A message received from the MQTT broker.
+#[derive(Clone, Copy, Debug)]
+pub struct MqttMessage<'a> {
+    /// The topic the message was published on.
+    pub topic: &'a str,
+
+    /// The raw payload.
+    pub payload: &'a [u8],
+}
+
+/// Data to log as the result of mapping a single [`MqttMessage`].
+#[derive(Clone, Debug)]
+pub struct MqttRecord {
+    /// Where to log the data.
+    pub entity_path: EntityPath,
+
+    /// What to log, e.g. from [`crate::AsComponents::as_serialized_batches`].
+    pub components: Vec<SerializedComponentBatch>,
+}
+
+/// A user-provided function mapping an [`MqttMessage`] to data to log.
+///
+/// This is how you bridge binary payloads, e.g. protobuf messages decoded with your own types.
+pub type MqttMapper =
+    dyn Fn(&MqttMessage<'_>) -> anyhow::Result<Vec<MqttRecord>> + Send + Sync + 'static;
+
+/// How the payloads on a topic are mapped.
+#[derive(Clone)]
+enum PayloadMapping {
+    /// See [`MqttBridge::subscribe_json`].
+    Json,
+
+    /// See [`MqttBridge::subscribe_with`].
+    Custom(Arc<MqttMapper>),
+}
+
+#[derive(Clone)]
+struct Subscription {
+    filter: String,
+    mapping: PayloadMapping,
+}
+
+/// Errors that can occur when running an [`MqttBridge`].
+#[derive(thiserror::Error, Debug)]
+pub enum MqttBridgeError {
+    /// Failed to subscribe to a topic.
+    #[error("Failed to subscribe to {filter:?}: {err}")]
+    Subscribe {
+        /// The topic filter.
+        filter: String,
+
+        /// The underlying error.
+        err: Box<rumqttc::ClientError>,
+    },
+
+    /// The connection to the broker failed.
+    #[error("MQTT connection failed: {0}")]
+    Connection(Box<rumqttc::ConnectionError>),
+
+    /// Failed to spawn the bridge thread.
+    #[error("Failed to spawn MQTT bridge thread: {0}")]
+    SpawnThread(std::io::Error),
+}
+
+/// Subscribes to MQTT topics and logs everything it receives to a [`RecordingStream`].
+///
+/// Payloads are either interpreted as JSON (see [`Self::subscribe_json`]),
+/// or handed to a user-provided mapping (see [`Self::subscribe_with`]).
+pub struct MqttBridge {
+    rec: RecordingStream,
+    host: String,
+    port: u16,
+    client_id: String,
+    credentials: Option<(String, String)>,
+    subscriptions: Vec<Subscription>,
+    path_prefix: Option<EntityPath>,
+    max_reconnect_attempts: Option<usize>,
+}
+
+impl MqttBridge {
+    /// Prepare a bridge to the broker at `host:port`.
+    ///
+    /// Nothing happens until you call [`Self::run_blocking`] or [`Self::spawn`].
+    pub fn new(rec: RecordingStream, host: impl Into<String>, port: u16) -> Self {
+        Self {
+            rec,
+            host: host.into(),
+            port,
+            client_id: format!("rerun-mqtt-bridge-{}", std::process::id()),
+            credentials: None,
+            subscriptions: Vec::new(),
+            path_prefix: None,
+            max_reconnect_attempts: Some(10),
+        }
+    }
+
+    /// Use a specific client id instead of the default one.
+    #[inline]
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = client_id.into();
+        self
+    }
+
+    /// Authenticate with the broker.
+    #[inline]
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Log everything under this entity path prefix, e.g. `mqtt`.
+    #[inline]
+    pub fn with_path_prefix(mut self, path_prefix: impl Into<EntityPath>) -> Self {
+        self.path_prefix = Some(path_prefix.into());
+        self
+    }
+
+    /// How many times in a row to try reconnecting to the broker before giving up.
+    ///
+    /// `None` means retrying forever. Defaults to 10.
+    #[inline]
+    pub fn with_max_reconnect_attempts(mut self, max_reconnect_attempts: Option<usize>) -> Self {
+        self.max_reconnect_attempts = max_reconnect_attempts;
+        self
+    }
+
+    /// Subscribe to a topic filter (which may contain `+` and `#` wildcards) with JSON payloads.
+    ///
+    /// Numbers and booleans are logged as [`Scalars`], strings as [`TextLog`]s.
+    /// Nested objects become child entities, so `{"temp": 21.5}` on topic `room1`
+    /// logs a scalar to `/room1/temp`. Arrays of numbers are logged as multiple scalars.
+    #[inline]
+    pub fn subscribe_json(mut self, filter: impl Into<String>) -> Self {
+        self.subscriptions.push(Subscription {
+            filter: filter.into(),
+            mapping: PayloadMapping::Json,
+        });
+        self
+    }
+
+    /// Subscribe to a topic filter, mapping payloads with your own function.
+    ///
+    /// Entity paths returned by `mapper` are still prefixed with [`Self::with_path_prefix`].
+    #[inline]
+    pub fn subscribe_with(
+        mut self,
+        filter: impl Into<String>,
+        mapper: impl Fn(&MqttMessage<'_>) -> anyhow::Result<Vec<MqttRecord>> + Send + Sync + 'static,
+    ) -> Self {
+        self.subscriptions.push(Subscription {
+            filter: filter.into(),
+            mapping: PayloadMapping::Custom(Arc::new(mapper)),
+        });
+        self
+    }
+
+    /// Run the bridge on a background thread.
+    pub fn spawn(self) -> Result<std::thread::JoinHandle<()>, MqttBridgeError> {
+        std::thread::Builder::new()
+            .name("mqtt_bridge".to_owned())
+            .spawn(move || {
+                if let Err(err) = self.run_blocking() {
+                    re_log::error!("MQTT bridge stopped: {err}");
+                }
+            })
+            .map_err(MqttBridgeError::SpawnThread)
+    }
+
+    /// Run the bridge on the current thread, until the connection fails for good.
+    pub fn run_blocking(self) -> Result<(), MqttBridgeError> {
+        let Self {
+            rec,
+            host,
+            port,
+            client_id,
+            credentials,
+            subscriptions,
+            path_prefix,
+            max_reconnect_attempts,
+        } = self;
+
+        re_log::info!("Bridging MQTT broker at {host}:{port} to Rerun");
+
+        let mut options = rumqttc::MqttOptions::new(client_id, host, port);
+        options.set_keep_alive(Duration::from_secs(5));
+        if let Some((username, password)) = credentials {
+            options.set_credentials(username, password);
+        }
+
+        let (client, mut connection) = rumqttc::Client::new(options, 64);
+
+        // Subscriptions are queued until the connection is up.
+        for subscription in &subscriptions {
+            client
+                .subscribe(&subscription.filter, rumqttc::QoS::AtMostOnce)
+                .map_err(|err| MqttBridgeError::Subscribe {
+                    filter: subscription.filter.clone(),
+                    err: Box::new(err),
+                })?;
+        }
+
+        let mut num_failed_attempts = 0;
+
+        for notification in connection.iter() {
+            match notification {
+                Ok(rumqttc::Event::Incoming(rumqttc::Packet::Publish(publish))) => {
+                    num_failed_attempts = 0;
+
+                    let msg = MqttMessage {
+                        topic: &publish.topic,
+                        payload: &publish.payload[..],
+                    };
+
+                    let Some(subscription) = subscriptions
+                        .iter()
+                        .find(|subscription| topic_matches_filter(msg.topic, &subscription.filter))
+                    else {
+                        re_log::debug!("Ignoring message on unexpected topic {:?}", msg.topic);
+                        continue;
+                    };
+
+                    let records = match &subscription.mapping {
+                        PayloadMapping::Json => json_to_records(&msg),
+                        PayloadMapping::Custom(mapper) => mapper(&msg),
+                    };
+
+                    match records {
+                        Ok(records) => log_records(&rec, path_prefix.as_ref(), records),
+                        Err(err) => {
+                            re_log::warn_once!(
+                                "Failed to map MQTT message on topic {:?}: {err}",
+                                msg.topic
+                            );
+                        }
+                    }
+                }
+
+                Ok(rumqttc::Event::Incoming(rumqttc::Packet::ConnAck(_))) => {
+                    num_failed_attempts = 0;
+                    re_log::debug!("Connected to MQTT broker");
+                }
+
+                Ok(_) => {}
+
+                Err(err) => {
+                    num_failed_attempts += 1;
+                    if max_reconnect_attempts.is_some_and(|max| max <= num_failed_attempts) {
+                        return Err(MqttBridgeError::Connection(Box::new(err)));
+                    }
+
+                    re_log::warn!("MQTT connection error, reconnecting: {err}");
+                    std::thread::sleep(Duration::from_secs(1));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn log_records(rec: &RecordingStream, path_prefix: Option<&EntityPath>, records: Vec<MqttRecord>) {
+    if records.is_empty() {
+        return;
+    }
+
+    rec.set_time(MQTT_TIMELINE, std::time::SystemTime::now());
+
+    for MqttRecord {
+        entity_path,
+        components,
+    } in records
+    {
+        let entity_path = match path_prefix {
+            Some(prefix) => prefix.join(&entity_path),
+            None => entity_path,
+        };
+
+        if let Err(err) = rec.log(entity_path, &components) {
+            re_log::warn_once!("Failed to log MQTT message: {err}");
+        }
+    }
+}
+
+/// Does `topic` match the MQTT topic `filter`, which may contain `+` and `#` wildcards?
+fn topic_matches_filter(topic: &str, filter: &str) -> bool {
+    let mut topic_levels = topic.split('/');
+
+    for filter_level in filter.split('/') {
+        match filter_level {
+            // Matches the parent level and any number of child levels.
+            "#" => return true,
+
+            // Matches exactly one level, whatever it is.
+            "+" => {
+                if topic_levels.next().is_none() {
+                    return false;
+                }
+            }
+
+            filter_level => {
+                if topic_levels.next() != Some(filter_level) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    topic_levels.next().is_none()
+}
+
+/// The default mapping for JSON payloads, see [`MqttBridge::subscribe_json`].
+fn json_to_records(msg: &MqttMessage<'_>) -> anyhow::Result<Vec<MqttRecord>> {
+    let value: serde_json::Value = serde_json::from_slice(msg.payload)?;
+
+    let mut records = Vec::new();
+    json_value_to_records(EntityPath::parse_forgiving(msg.topic), &value, &mut records);
+    Ok(records)
+}
+
+fn json_value_to_records(
+    entity_path: EntityPath,
+    value: &serde_json::Value,
+    records: &mut Vec<MqttRecord>,
+) {
+    use serde_json::Value;
+
+    let components = match value {
+        Value::Null => return,
+
+        Value::Bool(b) => Scalars::single(if *b { 1.0 } else { 0.0 }).as_serialized_batches(),
+
+        Value::Number(n) => {
+            let Some(n) = n.as_f64() else {
+                return;
+            };
+            Scalars::single(n).as_serialized_batches()
+        }
+
+        Value::String(s) => TextLog::new(s.clone()).as_serialized_batches(),
+
+        Value::Array(values) => {
+            let scalars: Option<Vec<f64>> = values.iter().map(Value::as_f64).collect();
+            if let Some(scalars) = scalars {
+                Scalars::new(scalars).as_serialized_batches()
+            } else {
+                for (i, value) in values.iter().enumerate() {
+                    json_value_to_records(
+                        entity_path.join(&EntityPath::from_single_string(i.to_string())),
+                        value,
+                        records,
+                    );
+                }
+                return;
+            }
+        }
+
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                json_value_to_records(
+                    entity_path.join(&EntityPath::from_single_string(name.clone())),
+                    value,
+                    records,
+                );
+            }
+            return;
+        }
+    };
+
+    records.push(MqttRecord {
+        entity_path,
+        components,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_matches_filter() {
+        assert!(topic_matches_filter("a/b/c", "a/b/c"));
+        assert!(topic_matches_filter("a/b/c", "a/+/c"));
+        assert!(topic_matches_filter("a/b/c", "a/#"));
+        assert!(topic_matches_filter("a", "a/#"));
+        assert!(topic_matches_filter("a/b/c", "#"));
+
+        assert!(!topic_matches_filter("a/b/c", "a/b"));
+        assert!(!topic_matches_filter("a/b", "a/b/c"));
+        assert!(!topic_matches_filter("a/b/c", "a/+"));
+        assert!(!topic_matches_filter("x/b/c", "a/#"));
+    }
+
+    #[test]
+    fn test_json_to_records() {
+        let payload = br#"{"temp": 21.5, "door": {"open": true, "label": "front"}, "xyz": [1, 2, 3], "none": null}"#;
+        let records = json_to_records(&MqttMessage {
+            topic: "home/room1",
+            payload,
+        })
+        .unwrap();
+
+        let mut paths: Vec<String> = records
+            .iter()
+            .map(|record| record.entity_path.to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/home/room1/door/label",
+                "/home/room1/door/open",
+                "/home/room1/temp",
+                "/home/room1/xyz",
+            ]
+        );
+    }
+}
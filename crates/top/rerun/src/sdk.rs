@@ -25,8 +25,9 @@ mod prelude {
     pub use re_chunk::TimeColumn;
     pub use re_types::components::{
         AlbedoFactor, Color, FillMode, HalfSize2D, HalfSize3D, ImageFormat, LineStrip2D,
-        LineStrip3D, MediaType, Position2D, Position3D, Radius, Scale3D, Text, TextLogLevel,
-        TransformRelation, TriangleIndices, Vector2D, Vector3D,
+        LineStrip3D, MediaType, Position2D, Position3D, Radius, Scale3D, Text, TextAnchor,
+        TextLogLevel, TransformInterpolation, TransformRelation, TriangleIndices, Vector2D,
+        Vector3D,
     };
     pub use re_types::datatypes::{
         Angle, AnnotationInfo, ChannelDatatype, ClassDescription, ColorModel, Float32,
@@ -8,19 +8,27 @@ use super::{AUTHORIZATION_KEY, TOKEN_PREFIX};
 
 #[derive(Default, Clone)]
 pub struct AuthDecorator {
-    jwt: Option<Jwt>,
+    token: Option<String>,
 }
 
 impl AuthDecorator {
     pub fn new(jwt: Option<Jwt>) -> Self {
-        Self { jwt }
+        Self {
+            token: jwt.map(|jwt| jwt.0),
+        }
+    }
+
+    /// Sends an opaque bearer token instead of a [`Jwt`],
+    /// see [`crate::server::BearerTokenAuthenticator`].
+    pub fn with_bearer_token(token: Option<String>) -> Self {
+        Self { token }
     }
 }
 
 impl Interceptor for AuthDecorator {
     fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
-        if let Some(jwt) = self.jwt.as_ref() {
-            let token = jwt.0.trim();
+        if let Some(token) = self.token.as_ref() {
+            let token = token.trim();
             let token =
                 format!("{TOKEN_PREFIX}{token}")
                     .parse()
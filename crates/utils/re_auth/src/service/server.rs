@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use tonic::{
     Request, Status,
     metadata::{Ascii, MetadataValue},
@@ -86,3 +88,102 @@ impl Interceptor for Authenticator {
         Ok(req)
     }
 }
+
+/// Accepts requests that carry one of a fixed set of opaque bearer tokens,
+/// e.g. tokens shared with the clients out of band.
+///
+/// Unlike [`Authenticator`], requests without a token are rejected.
+/// Lets everything through if there are no expected tokens.
+#[derive(Clone)]
+pub struct BearerTokenAuthenticator {
+    tokens: Arc<[String]>,
+}
+
+impl BearerTokenAuthenticator {
+    pub fn new(tokens: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            tokens: tokens.into_iter().collect(),
+        }
+    }
+}
+
+impl Interceptor for BearerTokenAuthenticator {
+    fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
+        if self.tokens.is_empty() {
+            return Ok(req);
+        }
+
+        let Some(token_metadata) = req.metadata().get(AUTHORIZATION_KEY) else {
+            re_log::warn_once!(
+                "Rejected a client from {:?} that didn't provide an auth token",
+                req.remote_addr()
+            );
+            return Err(Status::unauthenticated(
+                crate::ERROR_MESSAGE_MISSING_CREDENTIALS,
+            ));
+        };
+
+        let token = Jwt::try_from(token_metadata)
+            .map_err(|_err| Status::unauthenticated(crate::ERROR_MESSAGE_MALFORMED_CREDENTIALS))?;
+
+        if self
+            .tokens
+            .iter()
+            .any(|expected| constant_time_eq(expected.as_bytes(), token.0.trim().as_bytes()))
+        {
+            Ok(req)
+        } else {
+            re_log::warn_once!(
+                "Rejected a client from {:?} that provided an invalid auth token",
+                req.remote_addr()
+            );
+            Err(Status::unauthenticated(
+                crate::ERROR_MESSAGE_INVALID_CREDENTIALS,
+            ))
+        }
+    }
+}
+
+/// Compares two byte strings in time that only depends on their lengths,
+/// so that a token can't be guessed byte by byte from response times.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::client::AuthDecorator;
+
+    fn request(authorization: Option<&str>) -> Request<()> {
+        let mut req = Request::new(());
+        if let Some(authorization) = authorization {
+            req.metadata_mut()
+                .insert(AUTHORIZATION_KEY, authorization.parse().unwrap());
+        }
+        req
+    }
+
+    #[test]
+    fn bearer_token_authentication() {
+        let mut open = BearerTokenAuthenticator::new([]);
+        assert!(open.call(request(None)).is_ok());
+
+        let mut secured = BearerTokenAuthenticator::new(["secret".to_owned()]);
+        assert!(secured.call(request(Some("Bearer secret"))).is_ok());
+        for authorization in [None, Some("secret"), Some("Bearer secre")] {
+            let status = secured.call(request(authorization)).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        }
+    }
+
+    #[test]
+    fn decorated_requests_are_accepted() {
+        let mut decorator = AuthDecorator::with_bearer_token(Some("secret".to_owned()));
+        let mut authenticator = BearerTokenAuthenticator::new(["secret".to_owned()]);
+
+        let req = decorator.call(Request::new(())).unwrap();
+        assert!(authenticator.call(req).is_ok());
+    }
+}
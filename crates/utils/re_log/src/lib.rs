@@ -47,6 +47,16 @@ pub use setup::{setup_logging, setup_logging_with_filter};
 #[cfg(all(feature = "setup", not(target_arch = "wasm32")))]
 pub use setup::PanicOnWarnScope;
 
+/// Warnings and errors logged with this target are shown as notifications in the viewer,
+/// also when logged from outside of Rerun's crates, e.g. by a custom data loader:
+///
+/// ```
+/// re_log::warn!(target: re_log::NOTIFICATION_TARGET, "Dropped {} frames", 1200);
+/// ```
+///
+/// Code with access to the viewer's command sender can send a notification directly instead.
+pub const NOTIFICATION_TARGET: &str = "rerun::notification";
+
 /// Re-exports of other crates.
 pub mod external {
     pub use log;
@@ -0,0 +1,345 @@
+//! Export Rerun chunks to MCAP, so that recordings can be consumed by existing MCAP tooling.
+//!
+//! Every entity gets its own channel, with the entity path as its topic.
+//! Each row of a chunk becomes a single MCAP message, see [`McapMessageEncoding`] for how it is encoded.
+//!
+//! Since the columns of an entity can change over time, an entity can end up with
+//! several channels that share the same topic but have different schemas.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{Seek, Write},
+};
+
+use arrow::{
+    array::RecordBatch,
+    datatypes::{Fields, Schema},
+};
+use re_chunk::{Chunk, EntityPath, TimelineName};
+use re_log_types::TimeType;
+
+use crate::Error;
+
+/// How the rows of a chunk are encoded in MCAP messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum McapMessageEncoding {
+    /// Each message is a self-contained Arrow IPC stream holding a single row of the chunk.
+    ///
+    /// The schema of the channel is the Arrow IPC encoded schema of the chunk.
+    /// This is lossless: the original chunk can be reconstructed from the messages.
+    #[default]
+    Arrow,
+
+    /// Each message is a JSON object with one entry per column.
+    ///
+    /// The schema of the channel is a JSON schema listing all columns.
+    /// This is what most MCAP tooling (e.g. Foxglove or `PlotJuggler`) can display out of the box.
+    Json,
+}
+
+impl McapMessageEncoding {
+    /// The MCAP message encoding.
+    pub fn message_encoding(&self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::Json => "json",
+        }
+    }
+
+    /// The MCAP schema encoding.
+    pub fn schema_encoding(&self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::Json => "jsonschema",
+        }
+    }
+}
+
+/// Options for [`McapExporter`].
+#[derive(Clone, Debug, Default)]
+pub struct McapExportOptions {
+    /// How to encode the rows of each chunk.
+    pub encoding: McapMessageEncoding,
+
+    /// The timeline used for the `log_time` and `publish_time` of the MCAP messages.
+    ///
+    /// Must be a timestamp timeline. Defaults to `log_time`, falling back to the first
+    /// timestamp timeline of each chunk. Rows without a timestamp are written at time `0`.
+    pub timeline: Option<TimelineName>,
+}
+
+/// Writes Rerun chunks to an MCAP file.
+///
+/// Call [`Self::finish`] once done, otherwise the MCAP summary section is missing.
+pub struct McapExporter<W: Write + Seek> {
+    writer: mcap::Writer<W>,
+    options: McapExportOptions,
+
+    /// The channels we already created, keyed by entity and the columns of their schema.
+    channels: HashMap<(EntityPath, Fields), u16>,
+
+    /// The sequence number of the next message, per channel.
+    sequences: HashMap<u16, u32>,
+}
+
+impl<W: Write + Seek> McapExporter<W> {
+    pub fn new(writer: W, options: McapExportOptions) -> Result<Self, Error> {
+        Ok(Self {
+            writer: mcap::Writer::new(writer)?,
+            options,
+            channels: HashMap::default(),
+            sequences: HashMap::default(),
+        })
+    }
+
+    /// Writes every row of the chunk as a message on the channel of its entity.
+    pub fn add_chunk(&mut self, chunk: &Chunk) -> Result<(), Error> {
+        re_tracing::profile_function!();
+
+        if chunk.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let batch = chunk.to_record_batch()?;
+        let channel_id = self.channel_for(chunk.entity_path(), batch.schema_ref())?;
+        let times = self.message_times(chunk);
+
+        for row in 0..batch.num_rows() {
+            let data = self.encode_row(&batch.slice(row, 1))?;
+            let log_time = times
+                .and_then(|times| times.get(row))
+                .and_then(|&time| u64::try_from(time).ok())
+                .unwrap_or_default();
+
+            let sequence = self.sequences.entry(channel_id).or_default();
+            self.writer.write_to_known_channel(
+                &mcap::records::MessageHeader {
+                    channel_id,
+                    sequence: *sequence,
+                    log_time,
+                    publish_time: log_time,
+                },
+                &data,
+            )?;
+            *sequence = sequence.wrapping_add(1);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the summary section and flushes the underlying writer.
+    pub fn finish(mut self) -> Result<(), Error> {
+        self.writer.finish()?;
+        Ok(())
+    }
+
+    fn channel_for(&mut self, entity_path: &EntityPath, schema: &Schema) -> Result<u16, Error> {
+        let key = (entity_path.clone(), schema.fields().clone());
+        if let Some(&id) = self.channels.get(&key) {
+            return Ok(id);
+        }
+
+        // The schema metadata contains chunk-specific information (e.g. the chunk id),
+        // which must not end up in the channel schema.
+        let schema = schema.clone().with_metadata(Default::default());
+        let schema_data = match self.options.encoding {
+            McapMessageEncoding::Arrow => encode_arrow_schema(&schema)?,
+            McapMessageEncoding::Json => encode_json_schema(&schema)?,
+        };
+
+        let topic = entity_path.to_string();
+        let schema_id = self.writer.add_schema(
+            &topic,
+            self.options.encoding.schema_encoding(),
+            &schema_data,
+        )?;
+        let id = self.writer.add_channel(
+            schema_id,
+            &topic,
+            self.options.encoding.message_encoding(),
+            &BTreeMap::from([("rerun:entity_path".to_owned(), topic.clone())]),
+        )?;
+
+        self.channels.insert(key, id);
+
+        Ok(id)
+    }
+
+    /// The raw timestamps (nanoseconds since epoch) used for the messages of this chunk.
+    fn message_times<'a>(&self, chunk: &'a Chunk) -> Option<&'a [i64]> {
+        let timelines = chunk.timelines();
+
+        let timeline = self.options.timeline.unwrap_or_else(TimelineName::log_time);
+
+        let column = timelines.get(&timeline).or_else(|| {
+            let mut timestamp_timelines = timelines
+                .iter()
+                .filter(|(_, column)| column.timeline().typ() == TimeType::TimestampNs)
+                .collect::<Vec<_>>();
+            timestamp_timelines.sort_by_key(|(name, _)| **name);
+            timestamp_timelines.first().map(|(_, column)| *column)
+        })?;
+
+        if column.timeline().typ() != TimeType::TimestampNs {
+            re_log::warn_once!(
+                "Timeline {timeline:?} is not a timestamp timeline, MCAP messages will be written at time 0"
+            );
+            return None;
+        }
+
+        Some(column.times_raw())
+    }
+
+    fn encode_row(&self, row: &RecordBatch) -> Result<Vec<u8>, Error> {
+        match self.options.encoding {
+            McapMessageEncoding::Arrow => {
+                let mut writer =
+                    arrow::ipc::writer::StreamWriter::try_new(Vec::new(), row.schema_ref())?;
+                writer.write(row)?;
+                Ok(writer.into_inner()?)
+            }
+
+            McapMessageEncoding::Json => {
+                let mut writer = arrow::json::LineDelimitedWriter::new(Vec::new());
+                writer.write(row)?;
+                writer.finish()?;
+                let mut data = writer.into_inner();
+
+                // Each message is a single JSON object, without the trailing newline.
+                if data.last() == Some(&b'\n') {
+                    data.pop();
+                }
+
+                Ok(data)
+            }
+        }
+    }
+}
+
+fn encode_arrow_schema(schema: &Schema) -> Result<Vec<u8>, Error> {
+    let writer = arrow::ipc::writer::StreamWriter::try_new(Vec::new(), schema)?;
+    Ok(writer.into_inner()?)
+}
+
+fn encode_json_schema(schema: &Schema) -> Result<Vec<u8>, Error> {
+    let properties = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            (
+                field.name().clone(),
+                serde_json::json!({ "description": field.data_type().to_string() }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let json_schema = serde_json::json!({
+        "type": "object",
+        "properties": properties,
+    });
+
+    serde_json::to_vec(&json_schema).map_err(|err| Error::Other(err.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_log_types::TimeCell;
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    fn test_chunk(entity_path: &str) -> Chunk {
+        let timeline = Timeline::log_time();
+        Chunk::builder(entity_path)
+            .with_archetype(
+                RowId::new(),
+                TimePoint::from([(
+                    *timeline.name(),
+                    TimeCell::from_timestamp_nanos_since_epoch(1_000),
+                )]),
+                &Points3D::new([[1.0, 2.0, 3.0]]),
+            )
+            .with_archetype(
+                RowId::new(),
+                TimePoint::from([(
+                    *timeline.name(),
+                    TimeCell::from_timestamp_nanos_since_epoch(2_000),
+                )]),
+                &Points3D::new([[4.0, 5.0, 6.0]]),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn export(encoding: McapMessageEncoding) -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut exporter = McapExporter::new(
+            &mut buffer,
+            McapExportOptions {
+                encoding,
+                timeline: None,
+            },
+        )
+        .unwrap();
+
+        exporter.add_chunk(&test_chunk("points/a")).unwrap();
+        exporter.add_chunk(&test_chunk("points/b")).unwrap();
+        exporter.add_chunk(&test_chunk("points/a")).unwrap();
+        exporter.finish().unwrap();
+
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn export_arrow() {
+        let mcap = export(McapMessageEncoding::Arrow);
+
+        let messages = mcap::MessageStream::new(&mcap)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(messages.len(), 6);
+
+        let mut topics = messages
+            .iter()
+            .map(|msg| msg.channel.topic.clone())
+            .collect::<Vec<_>>();
+        topics.dedup();
+        assert_eq!(topics, ["/points/a", "/points/b", "/points/a"]);
+
+        assert_eq!(
+            messages.iter().map(|msg| msg.log_time).collect::<Vec<_>>(),
+            [1_000, 2_000, 1_000, 2_000, 1_000, 2_000]
+        );
+
+        // Chunks of the same entity with the same columns share a channel.
+        assert_eq!(messages[0].channel.id, messages[4].channel.id);
+        assert_ne!(messages[0].channel.id, messages[2].channel.id);
+
+        // Every message can be decoded back into a single row.
+        let reader =
+            arrow::ipc::reader::StreamReader::try_new(messages[0].data.as_ref(), None).unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 1);
+    }
+
+    #[test]
+    fn export_json() {
+        let mcap = export(McapMessageEncoding::Json);
+
+        let messages = mcap::MessageStream::new(&mcap)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(messages.len(), 6);
+
+        let schema = messages[0].channel.schema.as_ref().unwrap();
+        assert_eq!(schema.encoding, "jsonschema");
+        assert_eq!(messages[0].channel.message_encoding, "json");
+
+        let row: serde_json::Value = serde_json::from_slice(&messages[0].data).unwrap();
+        assert!(row.is_object());
+    }
+}
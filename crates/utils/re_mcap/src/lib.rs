@@ -1,14 +1,16 @@
 //! Library providing utilities to load MCAP files with Rerun.
 
 mod error;
+pub mod export;
 pub mod layers;
 
 pub(crate) mod parsers;
 pub(crate) mod util;
 
 pub use error::Error;
+pub use export::{McapExportOptions, McapExporter, McapMessageEncoding};
 pub use layers::{Layer, LayerIdentifier, LayerRegistry, MessageLayer, SelectedLayers};
-pub use parsers::{MessageParser, ParserContext, cdr};
+pub use parsers::{MessageParser, ParserContext, cdr, ros2msg::definitions as ros2msg};
 
 // TODO(grtlr): We should expose an `Mcap` object that internally holds the summary + a reference to the bytes.
 pub use util::read_summary;
@@ -4,6 +4,8 @@
 //!
 use serde::{Deserialize, Serialize};
 
+use super::std_msgs::Header;
+
 /// This represents a vector in free space.
 ///
 /// This is semantically different than a point.
@@ -39,3 +41,62 @@ pub struct Pose {
     pub position: Point,
     pub orientation: Quaternion,
 }
+
+/// A pose with reference coordinate frame and timestamp.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PoseStamped {
+    pub header: Header,
+    pub pose: Pose,
+}
+
+/// This represents a pose in free space with uncertainty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PoseWithCovariance {
+    pub pose: Pose,
+
+    /// Row-major representation of the 6x6 covariance matrix.
+    ///
+    /// The orientation parameters use a fixed-axis representation.
+    /// In order, the parameters are: `(x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)`
+    pub covariance: [f64; 36],
+}
+
+/// This expresses velocity in free space broken into its linear and angular parts.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Twist {
+    pub linear: Vector3,
+    pub angular: Vector3,
+}
+
+/// This expresses velocity in free space with uncertainty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwistWithCovariance {
+    pub twist: Twist,
+
+    /// Row-major representation of the 6x6 covariance matrix.
+    ///
+    /// The orientation parameters use a fixed-axis representation.
+    /// In order, the parameters are: `(x, y, z, rotation about X axis, rotation about Y axis, rotation about Z axis)`
+    pub covariance: [f64; 36],
+}
+
+/// This represents the transform between two coordinate frames in free space.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+}
+
+/// This expresses a transform from coordinate frame `header.frame_id`
+/// to the coordinate frame `child_frame_id` at the time of `header.stamp`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransformStamped {
+    /// The frame id in the header is used as the reference frame of this transform.
+    pub header: Header,
+
+    /// The frame id of the child frame to which this transform points.
+    pub child_frame_id: String,
+
+    /// Translation and rotation in 3-dimensions of `child_frame_id` from `header.frame_id`.
+    pub transform: Transform,
+}
@@ -6,6 +6,8 @@
 
 pub mod builtin_interfaces;
 pub mod geometry_msgs;
+pub mod nav_msgs;
 pub mod rcl_interfaces;
 pub mod sensor_msgs;
 pub mod std_msgs;
+pub mod tf2_msgs;
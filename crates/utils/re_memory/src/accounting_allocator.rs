@@ -8,8 +8,9 @@ use std::sync::{
 use parking_lot::Mutex;
 
 use crate::{
-    CountAndSize,
+    CountAndSize, HeapProfile, MemoryRegion,
     allocation_tracker::{AllocationTracker, CallstackStatistics, PtrHash},
+    memory_region::current_region,
 };
 
 /// Only track allocations of at least this size.
@@ -28,6 +29,11 @@ static BIG_ALLOCATION_TRACKER: LazyLock<Mutex<AllocationTracker>> =
 static MEDIUM_ALLOCATION_TRACKER: LazyLock<Mutex<AllocationTracker>> =
     LazyLock::new(|| Mutex::new(AllocationTracker::with_stochastic_rate(64)));
 
+/// Which [`MemoryRegion`] each live allocation was made in, so we know what to attribute it to
+/// once it is freed.
+static REGION_ALLOCATIONS: LazyLock<Mutex<nohash_hasher::IntMap<PtrHash, MemoryRegion>>> =
+    LazyLock::new(Default::default);
+
 thread_local! {
     /// Used to prevent re-entrancy when tracking allocations.
     ///
@@ -93,6 +99,12 @@ struct GlobalStats {
 
     /// The live allocations done by [`AllocationTracker`] used for internal book-keeping.
     pub overhead: AtomicCountAndSize,
+
+    /// Attribute allocations to [`MemoryRegion`]s?
+    pub track_regions: AtomicBool,
+
+    /// The live allocations of each [`MemoryRegion`].
+    pub regions: [AtomicCountAndSize; MemoryRegion::ALL.len()],
 }
 
 // ----------------------------------------------------------------------------
@@ -104,6 +116,8 @@ static GLOBAL_STATS: GlobalStats = GlobalStats {
     stochastically_tracked: AtomicCountAndSize::zero(),
     fully_tracked: AtomicCountAndSize::zero(),
     overhead: AtomicCountAndSize::zero(),
+    track_regions: AtomicBool::new(false),
+    regions: [const { AtomicCountAndSize::zero() }; MemoryRegion::ALL.len()],
 };
 
 /// Total number of live allocations,
@@ -142,6 +156,45 @@ pub fn turn_on_tracking_if_env_var(env_var: &str) {
     }
 }
 
+/// Are we attributing allocations to [`MemoryRegion`]s?
+pub fn is_tracking_regions() -> bool {
+    GLOBAL_STATS.track_regions.load(Relaxed)
+}
+
+/// Should we attribute allocations to [`MemoryRegion`]s?
+///
+/// This is cheaper than [`set_tracking_callstacks`], but still takes a lock for every allocation
+/// made inside a region, and for every deallocation.
+/// Only allocations made while this is turned on are attributed to a region.
+///
+/// Collect the stats with [`region_stats`].
+///
+/// Requires that you have installed the [`AccountingAllocator`].
+pub fn set_tracking_regions(track: bool) {
+    GLOBAL_STATS.track_regions.store(track, Relaxed);
+
+    if !track {
+        // Start from scratch next time, since we'll miss the deallocations in the meantime.
+        REGION_ALLOCATIONS.lock().clear();
+        for region in &GLOBAL_STATS.regions {
+            region.count.store(0, Relaxed);
+            region.size.store(0, Relaxed);
+        }
+    }
+}
+
+/// The live allocations of each [`MemoryRegion`].
+///
+/// Returns `None` unless turned on with [`set_tracking_regions`].
+pub fn region_stats() -> Option<Vec<(MemoryRegion, CountAndSize)>> {
+    is_tracking_regions().then(|| {
+        MemoryRegion::ALL
+            .iter()
+            .map(|&region| (region, GLOBAL_STATS.regions[region.index()].load()))
+            .collect()
+    })
+}
+
 // ----------------------------------------------------------------------------
 
 const MAX_CALLSTACKS: usize = 128;
@@ -215,6 +268,28 @@ pub fn tracking_stats() -> Option<TrackingStatistics> {
     })
 }
 
+/// All tracked callstacks, for exporting with external profiling tools, if enabled.
+///
+/// Unlike [`tracking_stats`], this is not limited to the top callstacks.
+///
+/// Enable this with [`set_tracking_callstacks`], preferably the first thing you do in `main`.
+///
+/// Requires that you have installed the [`AccountingAllocator`].
+pub fn heap_profile() -> Option<HeapProfile> {
+    GLOBAL_STATS.track_callstacks.load(Relaxed).then(|| {
+        let callstacks = IS_THREAD_IN_ALLOCATION_TRACKER.with(|is_thread_in_allocation_tracker| {
+            // prevent double-lock of ALLOCATION_TRACKER:
+            is_thread_in_allocation_tracker.set(true);
+            let mut callstacks = BIG_ALLOCATION_TRACKER.lock().top_callstacks(usize::MAX);
+            callstacks.extend(MEDIUM_ALLOCATION_TRACKER.lock().top_callstacks(usize::MAX));
+            is_thread_in_allocation_tracker.set(false);
+            callstacks
+        });
+
+        HeapProfile::from_callstacks(&callstacks)
+    })
+}
+
 // ----------------------------------------------------------------------------
 
 /// Install this as the global allocator to get memory usage tracking.
@@ -297,6 +372,22 @@ unsafe impl<InnerAllocator: std::alloc::GlobalAlloc> std::alloc::GlobalAlloc
 fn note_alloc(ptr: *mut u8, size: usize) {
     GLOBAL_STATS.live.add(size);
 
+    if GLOBAL_STATS.track_regions.load(Relaxed)
+        && let Some(region) = current_region()
+    {
+        IS_THREAD_IN_ALLOCATION_TRACKER.with(|is_thread_in_allocation_tracker| {
+            // Don't attribute the book-keeping of the trackers to any region.
+            if !is_thread_in_allocation_tracker.get() {
+                is_thread_in_allocation_tracker.set(true);
+
+                REGION_ALLOCATIONS.lock().insert(PtrHash::new(ptr), region);
+                GLOBAL_STATS.regions[region.index()].add(size);
+
+                is_thread_in_allocation_tracker.set(false);
+            }
+        });
+    }
+
     if GLOBAL_STATS.track_callstacks.load(Relaxed) {
         if size < SMALL_SIZE {
             // Too small to track.
@@ -332,6 +423,22 @@ fn note_alloc(ptr: *mut u8, size: usize) {
 fn note_dealloc(ptr: *mut u8, size: usize) {
     GLOBAL_STATS.live.sub(size);
 
+    if GLOBAL_STATS.track_regions.load(Relaxed) {
+        IS_THREAD_IN_ALLOCATION_TRACKER.with(|is_thread_in_allocation_tracker| {
+            if !is_thread_in_allocation_tracker.get() {
+                is_thread_in_allocation_tracker.set(true);
+
+                // Freed no matter where, but attributed to where it was allocated.
+                let region = REGION_ALLOCATIONS.lock().remove(&PtrHash::new(ptr));
+                if let Some(region) = region {
+                    GLOBAL_STATS.regions[region.index()].sub(size);
+                }
+
+                is_thread_in_allocation_tracker.set(false);
+            }
+        });
+    }
+
     if GLOBAL_STATS.track_callstacks.load(Relaxed) {
         if size < SMALL_SIZE {
             // Too small to track.
@@ -0,0 +1,330 @@
+//! Export the tracked allocations for external tools, see [`HeapProfile`].
+
+use ahash::HashMap;
+
+use crate::{CallstackStatistics, CountAndSize};
+
+/// The live allocations, grouped by the call stack that made them.
+///
+/// Get one with [`crate::accounting_allocator::heap_profile`], then export it with
+/// [`Self::to_pprof`] or [`Self::to_folded`].
+///
+/// The numbers are estimates: medium-sized allocations are only sampled,
+/// and small allocations aren't tracked at all.
+pub struct HeapProfile {
+    pub stacks: Vec<StackSample>,
+}
+
+/// The live allocations made from one call stack.
+pub struct StackSample {
+    /// Innermost frame first.
+    pub frames: Vec<StackFrame>,
+
+    /// Estimated live allocations, already scaled by the sampling rate.
+    pub extant: CountAndSize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StackFrame {
+    pub function: String,
+    pub file: Option<String>,
+    pub line: Option<i64>,
+}
+
+impl HeapProfile {
+    pub fn from_callstacks(callstacks: &[CallstackStatistics]) -> Self {
+        let stacks = callstacks
+            .iter()
+            .map(|callstack| {
+                let rate = callstack.stochastic_rate;
+                StackSample {
+                    frames: parse_backtrace(&callstack.readable_backtrace.to_string()),
+                    extant: CountAndSize {
+                        count: callstack.extant.count * rate,
+                        size: callstack.extant.size * rate,
+                    },
+                }
+            })
+            .filter(|stack| !stack.frames.is_empty())
+            .collect();
+        Self { stacks }
+    }
+
+    /// The "folded stacks" format understood by most flamegraph tools
+    /// (e.g. `inferno-flamegraph`, `flamegraph.pl` and speedscope).
+    ///
+    /// One line per call stack, outermost frame first, weighted by live bytes.
+    pub fn to_folded(&self) -> String {
+        let mut folded = String::new();
+        for stack in &self.stacks {
+            let frames = stack
+                .frames
+                .iter()
+                .rev()
+                .map(|frame| frame.function.replace(';', ":"))
+                .collect::<Vec<_>>();
+            folded.push_str(&frames.join(";"));
+            folded.push_str(&format!(" {}\n", stack.extant.size));
+        }
+        folded
+    }
+
+    /// An uncompressed [pprof](https://github.com/google/pprof) profile,
+    /// with the live allocation count and bytes as sample values.
+    ///
+    /// Open it with e.g. `go tool pprof -http=: heap.pb`.
+    pub fn to_pprof(&self) -> Vec<u8> {
+        let mut builder = PprofBuilder::default();
+
+        // `string_table[0]` must always be the empty string.
+        builder.string_id("");
+
+        let mut profile = ProtoWriter::default();
+
+        for (type_, unit) in [("inuse_objects", "count"), ("inuse_space", "bytes")] {
+            let type_ = builder.string_id(type_);
+            let unit = builder.string_id(unit);
+            profile.message(1, |value_type| {
+                value_type.int64(1, type_);
+                value_type.int64(2, unit);
+            });
+        }
+
+        for stack in &self.stacks {
+            let location_ids = stack
+                .frames
+                .iter()
+                .map(|frame| builder.location_id(frame))
+                .collect::<Vec<_>>();
+            profile.message(2, |sample| {
+                sample.packed(1, location_ids.iter().copied());
+                sample.packed(
+                    2,
+                    [stack.extant.count as u64, stack.extant.size as u64].into_iter(),
+                );
+            });
+        }
+
+        for (frame, (location_id, function_id)) in sorted_by_id(&builder.locations) {
+            profile.message(4, |location| {
+                location.uint64(1, location_id);
+                location.message(4, |line| {
+                    line.uint64(1, function_id);
+                    line.int64(2, frame.line.unwrap_or_default());
+                });
+            });
+        }
+
+        let functions = sorted_by_id(&builder.functions)
+            .map(|((name, file), id)| (id, *name, *file))
+            .collect::<Vec<_>>();
+        for (id, name, file) in functions {
+            profile.message(5, |function| {
+                function.uint64(1, id);
+                function.int64(2, name);
+                function.int64(3, name);
+                function.int64(4, file);
+            });
+        }
+
+        for string in &builder.strings {
+            profile.bytes(6, string.as_bytes());
+        }
+
+        profile.0
+    }
+}
+
+/// Parses a [`crate::ReadableBacktrace`], innermost frame first.
+///
+/// Understands both the native format, where each numbered frame is optionally followed by its
+/// source location:
+/// ```text
+///   12: rerun::run
+///              at rerun/src/run.rs:10:5
+/// ```
+/// and the web format, which has one function per line.
+fn parse_backtrace(backtrace: &str) -> Vec<StackFrame> {
+    let mut frames: Vec<StackFrame> = Vec::new();
+
+    for line in backtrace.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(location) = line.strip_prefix("at ") {
+            // The source location of the previous frame.
+            if let Some(frame) = frames.last_mut() {
+                let mut parts = location.rsplitn(3, ':');
+                let (col, line_nr, file) = (parts.next(), parts.next(), parts.next());
+                if let (Some(_col), Some(line_nr), Some(file)) = (col, line_nr, file) {
+                    frame.file = Some(file.to_owned());
+                    frame.line = line_nr.parse().ok();
+                } else {
+                    frame.file = Some(location.to_owned());
+                }
+            }
+            continue;
+        }
+
+        let function = match line.split_once(": ") {
+            Some((index, function)) if index.chars().all(|c| c.is_ascii_digit()) => function,
+            _ => line,
+        };
+        frames.push(StackFrame {
+            function: function.to_owned(),
+            file: None,
+            line: None,
+        });
+    }
+
+    frames
+}
+
+/// Assigns ids to the strings, functions and locations of a pprof profile.
+#[derive(Default)]
+struct PprofBuilder {
+    strings: Vec<String>,
+    string_ids: HashMap<String, i64>,
+
+    /// `(name, file)` string ids to function id.
+    functions: HashMap<(i64, i64), u64>,
+
+    /// Frame to `(location id, function id)`.
+    locations: HashMap<StackFrame, (u64, u64)>,
+}
+
+impl PprofBuilder {
+    fn string_id(&mut self, string: &str) -> i64 {
+        if let Some(id) = self.string_ids.get(string) {
+            return *id;
+        }
+        let id = self.strings.len() as i64;
+        self.strings.push(string.to_owned());
+        self.string_ids.insert(string.to_owned(), id);
+        id
+    }
+
+    fn location_id(&mut self, frame: &StackFrame) -> u64 {
+        if let Some((location_id, _)) = self.locations.get(frame) {
+            return *location_id;
+        }
+
+        let name = self.string_id(&frame.function);
+        let file = self.string_id(frame.file.as_deref().unwrap_or_default());
+        let num_functions = self.functions.len() as u64;
+        let function_id = *self
+            .functions
+            .entry((name, file))
+            .or_insert(num_functions + 1); // Ids must be non-zero.
+
+        let location_id = self.locations.len() as u64 + 1; // Ids must be non-zero.
+        self.locations
+            .insert(frame.clone(), (location_id, function_id));
+        location_id
+    }
+}
+
+/// Sorted, so that the output is deterministic.
+fn sorted_by_id<K, V: Ord + Copy>(map: &HashMap<K, V>) -> impl Iterator<Item = (&K, V)> {
+    let mut entries = map.iter().map(|(k, v)| (k, *v)).collect::<Vec<_>>();
+    entries.sort_by_key(|(_, v)| *v);
+    entries.into_iter()
+}
+
+/// Just enough of the protobuf wire format to write a pprof profile.
+#[derive(Default)]
+struct ProtoWriter(Vec<u8>);
+
+impl ProtoWriter {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn uint64(&mut self, field: u32, value: u64) {
+        self.key(field, 0);
+        self.varint(value);
+    }
+
+    fn int64(&mut self, field: u32, value: i64) {
+        self.uint64(field, value as u64);
+    }
+
+    fn bytes(&mut self, field: u32, bytes: &[u8]) {
+        self.key(field, 2);
+        self.varint(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn packed(&mut self, field: u32, values: impl Iterator<Item = u64>) {
+        let mut packed = Self::default();
+        for value in values {
+            packed.varint(value);
+        }
+        self.bytes(field, &packed.0);
+    }
+
+    fn message(&mut self, field: u32, write: impl FnOnce(&mut Self)) {
+        let mut message = Self::default();
+        write(&mut message);
+        self.bytes(field, &message.0);
+    }
+}
+
+#[test]
+fn test_parse_backtrace() {
+    let native = "             at re_memory/src/accounting_allocator.rs:300:5
+  13: alloc::vec::Vec<T>::push
+             at alloc/src/vec/mod.rs:1800:13
+  14: rerun::main
+             at rerun/src/main.rs:10:5
+";
+    assert_eq!(
+        parse_backtrace(native),
+        vec![
+            StackFrame {
+                function: "alloc::vec::Vec<T>::push".to_owned(),
+                file: Some("alloc/src/vec/mod.rs".to_owned()),
+                line: Some(1800),
+            },
+            StackFrame {
+                function: "rerun::main".to_owned(),
+                file: Some("rerun/src/main.rs".to_owned()),
+                line: Some(10),
+            },
+        ]
+    );
+
+    let web = "re_viewer::App::update\neframe::web::AppRunner::logic\n";
+    assert_eq!(
+        parse_backtrace(web)
+            .into_iter()
+            .map(|frame| frame.function)
+            .collect::<Vec<_>>(),
+        vec!["re_viewer::App::update", "eframe::web::AppRunner::logic"]
+    );
+}
+
+#[test]
+fn test_folded() {
+    let profile = HeapProfile {
+        stacks: vec![StackSample {
+            frames: parse_backtrace("  0: inner\n  1: outer\n"),
+            extant: CountAndSize {
+                count: 2,
+                size: 1024,
+            },
+        }],
+    };
+    assert_eq!(profile.to_folded(), "outer;inner 1024\n");
+    assert!(!profile.to_pprof().is_empty());
+}
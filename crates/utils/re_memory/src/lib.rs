@@ -31,12 +31,29 @@
 //! to get the statistics. Any memory leak should show up in
 //! [`TrackingStatistics::top_callstacks`].
 //!
+//! ### Heap profiles
+//! With callstack tracking turned on, [`accounting_allocator::heap_profile`] gives you all
+//! tracked callstacks at once. Export them with [`HeapProfile::to_pprof`] for `pprof`,
+//! or [`HeapProfile::to_folded`] for flamegraph tools.
+//!
+//! ### Finding out who is using memory
+//! Wrap the work of each subsystem in a [`MemoryRegion`]:
+//!
+//! ```rs
+//! let _region = re_memory::MemoryRegion::Store.enter();
+//! ```
+//!
+//! Then turn on [`accounting_allocator::set_tracking_regions`], and query
+//! [`accounting_allocator::region_stats`] for the live allocations of each region.
+//!
 //! ### More
 //! See also [`accounting_allocator`].
 
 pub mod accounting_allocator;
 mod allocation_tracker;
+mod heap_profile;
 mod memory_limit;
+mod memory_region;
 mod memory_use;
 mod ram_warner;
 pub mod util;
@@ -56,7 +73,9 @@ use backtrace_web::Backtrace;
 pub use {
     accounting_allocator::{AccountingAllocator, TrackingStatistics},
     allocation_tracker::{CallstackStatistics, ReadableBacktrace},
+    heap_profile::{HeapProfile, StackFrame, StackSample},
     memory_limit::MemoryLimit,
+    memory_region::{MemoryRegion, MemoryRegionScope, current_region},
     memory_use::MemoryUse,
     ram_warner::*,
 };
@@ -0,0 +1,95 @@
+//! Attribute memory use to the different subsystems of an application.
+
+use std::cell::Cell;
+
+/// A subsystem whose memory use is accounted for separately.
+///
+/// Allocations made while a region is [entered](Self::enter) are attributed to it,
+/// until they are freed again, no matter where that happens.
+///
+/// Regions are per thread: work handed over to other threads (e.g. a thread pool)
+/// is only attributed to a region if that thread enters it as well.
+///
+/// Requires that you have installed the [`crate::AccountingAllocator`], and turned on
+/// [`crate::accounting_allocator::set_tracking_regions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryRegion {
+    /// The data stores and everything that goes into ingesting data into them.
+    Store,
+
+    /// CPU-side copies of data on its way to, or mirrored from, the GPU.
+    Renderer,
+
+    /// Video and image decoders.
+    Decoders,
+
+    /// Building and laying out the user interface.
+    Ui,
+}
+
+impl MemoryRegion {
+    pub const ALL: [Self; 4] = [Self::Store, Self::Renderer, Self::Decoders, Self::Ui];
+
+    /// Human readable name of the region.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Store => "Store",
+            Self::Renderer => "Renderer CPU mirrors",
+            Self::Decoders => "Decoders",
+            Self::Ui => "UI",
+        }
+    }
+
+    #[inline]
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Attribute all allocations on this thread to this region, until the returned scope is dropped.
+    ///
+    /// Scopes can be nested, in which case the innermost one wins.
+    ///
+    /// ```
+    /// let _region = re_memory::MemoryRegion::Store.enter();
+    /// ```
+    #[inline]
+    pub fn enter(self) -> MemoryRegionScope {
+        MemoryRegionScope {
+            previous: CURRENT_REGION.replace(Some(self)),
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+impl std::fmt::Display for MemoryRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+thread_local! {
+    /// The region allocations on this thread are attributed to, if any.
+    static CURRENT_REGION: Cell<Option<MemoryRegion>> = const { Cell::new(None) };
+}
+
+/// The region allocations on this thread are currently attributed to, see [`MemoryRegion::enter`].
+#[inline]
+pub fn current_region() -> Option<MemoryRegion> {
+    CURRENT_REGION.get()
+}
+
+/// Leaves a [`MemoryRegion`] when dropped, see [`MemoryRegion::enter`].
+#[must_use = "The region is left as soon as the scope is dropped"]
+pub struct MemoryRegionScope {
+    previous: Option<MemoryRegion>,
+
+    /// Regions are per thread, so the scope must be dropped on the thread that created it.
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Drop for MemoryRegionScope {
+    #[inline]
+    fn drop(&mut self) {
+        CURRENT_REGION.set(self.previous);
+    }
+}
@@ -1,12 +1,17 @@
 //! A channel that keeps track of latency and queue length.
 
-use std::sync::{Arc, atomic::AtomicU64};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+};
 
 use re_uri::RedapUri;
 use web_time::Instant;
 
-pub use crossbeam::channel::{RecvError, RecvTimeoutError, SendError, TryRecvError};
+pub use crossbeam::channel::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
 
+#[cfg(not(target_arch = "wasm32"))] // Cannot spawn threads on web
+mod merge;
 mod receive_set;
 mod receiver;
 mod sender;
@@ -242,6 +247,18 @@ pub enum SmartMessageSource {
 
     /// A stream of messages over message proxy gRPC interface.
     MessageProxy(re_uri::ProxyUri),
+
+    /// A client that connected to our own message proxy server and wrote messages to it.
+    MessageProxyClient {
+        /// The server the client connected to.
+        uri: re_uri::ProxyUri,
+
+        /// Address of the client, if known.
+        peer_addr: Option<std::net::SocketAddr>,
+
+        /// Name the client gave itself when connecting, if any.
+        client_name: Option<String>,
+    },
 }
 
 impl std::fmt::Display for SmartMessageSource {
@@ -251,6 +268,16 @@ impl std::fmt::Display for SmartMessageSource {
             Self::File(path) => format!("file://{}", path.to_string_lossy()),
             Self::RrdHttpStream { url } => url.clone(),
             Self::MessageProxy(uri) => uri.to_string(),
+            Self::MessageProxyClient {
+                uri,
+                peer_addr,
+                client_name,
+            } => match (client_name, peer_addr) {
+                (Some(name), Some(addr)) => format!("{name} ({addr}) via {uri}"),
+                (Some(name), None) => format!("{name} via {uri}"),
+                (None, Some(addr)) => format!("{addr} via {uri}"),
+                (None, None) => uri.to_string(),
+            },
             Self::RedapGrpcStream { uri, .. } => uri.to_string(),
             Self::RrdWebEventCallback => "web_callback".into(),
             Self::JsChannelPush => "javascript".into(),
@@ -269,6 +296,127 @@ pub(crate) struct SharedStats {
     latency_nanos: AtomicU64,
 }
 
+/// Counts the messages going through a single channel.
+///
+/// Unlike [`SharedStats`], these are never shared between chained channels.
+#[derive(Default)]
+pub(crate) struct ChannelCounters {
+    /// Messages successfully sent, including flushes and quits.
+    enqueued: AtomicU64,
+
+    /// Messages received, including flushes and quits.
+    dequeued: AtomicU64,
+}
+
+impl ChannelCounters {
+    #[inline]
+    pub(crate) fn on_sent(&self) {
+        self.enqueued.fetch_add(1, Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn on_received(&self) {
+        self.dequeued.fetch_add(1, Relaxed);
+    }
+}
+
+/// A snapshot of the statistics of a channel, see [`Receiver::stats`].
+///
+/// Poll this regularly, and compare consecutive snapshots with [`Self::rates_since`]
+/// to find out how fast messages are flowing through the channel.
+#[derive(Clone, Copy, Debug)]
+pub struct ChannelStats {
+    /// When this snapshot was taken.
+    pub time: Instant,
+
+    /// Number of messages waiting in the channel.
+    pub queue_len: usize,
+
+    /// Maximum number of messages in the channel, if it is bounded.
+    pub capacity: Option<usize>,
+
+    /// Number of messages sent into the channel so far.
+    pub total_enqueued: u64,
+
+    /// Number of messages received from the channel so far.
+    pub total_dequeued: u64,
+
+    /// Latest known latency from sending a message to receiving it, in seconds.
+    ///
+    /// For chained channels, this is the latency from the first sender to the last receiver.
+    pub latency_sec: f32,
+}
+
+impl ChannelStats {
+    /// How fast messages went in and out of the channel between `earlier` and this snapshot.
+    ///
+    /// Returns `None` if `earlier` isn't actually earlier.
+    pub fn rates_since(&self, earlier: &Self) -> Option<ChannelRates> {
+        let dt = self
+            .time
+            .checked_duration_since(earlier.time)?
+            .as_secs_f64();
+        if dt <= 0.0 {
+            return None;
+        }
+
+        let rate = |now: u64, then: u64| now.saturating_sub(then) as f64 / dt;
+        Some(ChannelRates {
+            enqueued_per_sec: rate(self.total_enqueued, earlier.total_enqueued),
+            dequeued_per_sec: rate(self.total_dequeued, earlier.total_dequeued),
+        })
+    }
+}
+
+/// Message rates of a channel, see [`ChannelStats::rates_since`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ChannelRates {
+    /// Messages sent into the channel per second.
+    pub enqueued_per_sec: f64,
+
+    /// Messages received from the channel per second.
+    ///
+    /// If this stays below [`Self::enqueued_per_sec`], the receiver can't keep up.
+    pub dequeued_per_sec: f64,
+}
+
+/// Senders of a bounded channel waiting for room, see [`Sender::send_async`].
+#[derive(Default)]
+pub(crate) struct SendWakers {
+    wakers: parking_lot::Mutex<Vec<std::task::Waker>>,
+
+    /// Set when the receiver is dropped, which happens before the channel itself is closed.
+    closed: AtomicBool,
+}
+
+impl SendWakers {
+    pub(crate) fn register(&self, waker: &std::task::Waker) {
+        let mut wakers = self.wakers.lock();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    /// Called whenever there is room in the channel, or the channel was closed.
+    pub(crate) fn wake_all(&self) {
+        let wakers = std::mem::take(&mut *self.wakers.lock());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Called by the receiver when it goes away.
+    pub(crate) fn close(&self) {
+        self.closed
+            .store(true, std::sync::atomic::Ordering::Release);
+        self.wake_all();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(std::sync::atomic::Ordering::Acquire)
+    }
+}
+
 pub fn smart_channel<T: Send>(
     sender_source: SmartMessageSource,
     source: SmartChannelSource,
@@ -277,6 +425,33 @@ pub fn smart_channel<T: Send>(
     smart_channel_with_stats(sender_source, Arc::new(source), stats)
 }
 
+/// Like [`smart_channel`], but holds at most `capacity` messages at a time.
+///
+/// Once the channel is full, [`Sender::send`] blocks until the receiver catches up,
+/// [`Sender::send_async`] waits without blocking, and [`Sender::try_send`] fails.
+/// This way a slow receiver slows down the producer, instead of letting memory grow unbounded.
+///
+/// Never block on a bounded channel on the same thread as its receiver, e.g. on web.
+pub fn smart_channel_bounded<T: Send>(
+    sender_source: SmartMessageSource,
+    source: SmartChannelSource,
+    capacity: usize,
+) -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = crossbeam::channel::bounded(capacity);
+    let stats = Arc::new(SharedStats::default());
+    let counters = Arc::new(ChannelCounters::default());
+    let send_wakers = Arc::new(SendWakers::default());
+    let sender = Sender::new(
+        tx,
+        Arc::new(sender_source),
+        stats.clone(),
+        counters.clone(),
+        Some(send_wakers.clone()),
+    );
+    let receiver = Receiver::new(rx, stats, counters, Arc::new(source), Some(send_wakers));
+    (sender, receiver)
+}
+
 /// Create a new channel using the same stats as some other.
 ///
 /// This is a very leaky abstraction, and it would be nice to refactor some day
@@ -287,8 +462,9 @@ pub(crate) fn smart_channel_with_stats<T: Send>(
 ) -> (Sender<T>, Receiver<T>) {
     let (tx, rx) = crossbeam::channel::unbounded();
     let sender_source = Arc::new(sender_source);
-    let sender = Sender::new(tx, sender_source, stats.clone());
-    let receiver = Receiver::new(rx, stats, source);
+    let counters = Arc::new(ChannelCounters::default());
+    let sender = Sender::new(tx, sender_source, stats.clone(), counters.clone(), None);
+    let receiver = Receiver::new(rx, stats, counters, source, None);
     (sender, receiver)
 }
 
@@ -401,3 +577,116 @@ fn test_smart_channel_connected() {
     assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
     assert!(!rx.is_connected());
 }
+
+#[test]
+fn test_smart_channel_pause() {
+    let (tx, rx) = smart_channel(SmartMessageSource::Sdk, SmartChannelSource::Sdk); // whatever source
+
+    rx.pause();
+    assert!(rx.is_paused());
+
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    assert_eq!(
+        rx.recv_timeout(std::time::Duration::from_millis(10)),
+        Err(RecvTimeoutError::Timeout)
+    );
+    assert_eq!(rx.len(), 2);
+
+    // Paused receivers don't notice the senders leaving.
+    drop(tx);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    assert!(rx.is_connected());
+
+    rx.resume();
+    assert!(!rx.is_paused());
+    assert_eq!(rx.try_recv().map(|msg| msg.into_data()), Ok(Some(1)));
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(2)));
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+    assert!(!rx.is_connected());
+}
+
+#[test]
+fn test_smart_channel_bounded() {
+    let (tx, rx) = smart_channel_bounded(SmartMessageSource::Sdk, SmartChannelSource::Sdk, 1); // whatever source
+    assert_eq!(tx.capacity(), Some(1));
+
+    tx.send(1).unwrap();
+    assert!(matches!(tx.try_send(2), Err(TrySendError::Full(2))));
+
+    let sent = Arc::new(AtomicBool::new(false));
+    let sender = std::thread::spawn({
+        let sent = Arc::clone(&sent);
+        move || {
+            tx.send(2).unwrap();
+            sent.store(true, Relaxed);
+        }
+    });
+
+    // Blocks until the receiver makes room.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    assert!(!sent.load(Relaxed));
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(1)));
+
+    sender.join().unwrap();
+    assert!(sent.load(Relaxed));
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(2)));
+}
+
+/// Counts how often a future was woken up, so we can poll it by hand.
+#[cfg(test)]
+#[derive(Default)]
+struct WakeCounter(AtomicU64);
+
+#[cfg(test)]
+impl std::task::Wake for WakeCounter {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Relaxed);
+    }
+}
+
+#[test]
+fn test_smart_channel_send_async() {
+    let (tx, rx) = smart_channel_bounded(SmartMessageSource::Sdk, SmartChannelSource::Sdk, 1); // whatever source
+    tx.send(1).unwrap();
+
+    let wake_counter = Arc::new(WakeCounter::default());
+    let waker = std::task::Waker::from(Arc::clone(&wake_counter));
+    let mut cx = std::task::Context::from_waker(&waker);
+
+    let mut send = std::pin::pin!(tx.send_async(2));
+    assert!(send.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(wake_counter.0.load(Relaxed), 0);
+
+    // Receiving makes room, and wakes up the sender.
+    assert_eq!(rx.try_recv().map(|msg| msg.into_data()), Ok(Some(1)));
+    assert_eq!(wake_counter.0.load(Relaxed), 1);
+    assert!(matches!(
+        send.as_mut().poll(&mut cx),
+        std::task::Poll::Ready(Ok(()))
+    ));
+
+    assert_eq!(rx.try_recv().map(|msg| msg.into_data()), Ok(Some(2)));
+}
+
+#[test]
+fn test_smart_channel_send_async_disconnected() {
+    let (tx, rx) = smart_channel_bounded(SmartMessageSource::Sdk, SmartChannelSource::Sdk, 1); // whatever source
+    tx.send(1).unwrap();
+
+    let wake_counter = Arc::new(WakeCounter::default());
+    let waker = std::task::Waker::from(Arc::clone(&wake_counter));
+    let mut cx = std::task::Context::from_waker(&waker);
+
+    let mut send = std::pin::pin!(tx.send_async(2));
+    assert!(send.as_mut().poll(&mut cx).is_pending());
+
+    // Nobody is ever going to make room, so the sender is woken up and fails.
+    drop(rx);
+    assert_eq!(wake_counter.0.load(Relaxed), 1);
+    assert!(matches!(
+        send.as_mut().poll(&mut cx),
+        std::task::Poll::Ready(Err(SendError(2)))
+    ));
+}
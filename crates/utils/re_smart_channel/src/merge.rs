@@ -0,0 +1,127 @@
+use crossbeam::channel::Select;
+
+use crate::{Receiver, SmartChannelSource, SmartMessage, SmartMessageSource};
+
+/// How often we check whether paused inputs were resumed, see [`Receiver::pause`].
+const PAUSED_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// One of the receivers being merged, along with the oldest message we took from it.
+struct Input<T: Send> {
+    rx: Receiver<T>,
+    head: Option<SmartMessage<T>>,
+}
+
+impl<T: Send + 'static> Receiver<T> {
+    /// Merges several receivers into one, interleaving their messages by send time.
+    ///
+    /// Messages from the same receiver always come out in the order they were sent.
+    /// Across receivers, the oldest message that is available is handed out first.
+    /// Note that a message can only be ordered against messages that are already waiting in the
+    /// other receivers: one that shows up later with an older send time can't be moved ahead of
+    /// the messages that went out in the meantime.
+    ///
+    /// The send times and sources of the messages are kept as-is, so the latency of the merged
+    /// receiver is measured from the original senders.
+    ///
+    /// Forwarding happens on a background thread, which exits once all `receivers` are
+    /// disconnected and drained.
+    /// Dropping the returned receiver does not stop the thread right away: it only notices when it
+    /// tries to forward the next message, so it keeps waiting for as long as all `receivers` are idle.
+    pub fn merge_ordered(receivers: Vec<Self>, source: SmartChannelSource) -> Self {
+        // NOTE: It doesn't matter what the sender source is, as it will only be used to forward
+        // existing messages.
+        let (tx, rx) = crate::smart_channel(SmartMessageSource::Unknown, source);
+
+        let mut inputs = receivers
+            .into_iter()
+            .map(|rx| Input { rx, head: None })
+            .collect::<Vec<_>>();
+
+        std::thread::Builder::new()
+            .name("merge_ordered".to_owned())
+            .spawn(move || {
+                while let Some(msg) = next_in_order(&mut inputs) {
+                    if tx.send_at(msg.time, msg.source, msg.payload).is_err() {
+                        break; // The merged receiver was dropped.
+                    }
+                }
+            })
+            .expect("Failed to spawn merge_ordered thread");
+
+        rx
+    }
+}
+
+/// Blocks until any of the inputs has a message, and returns the oldest one.
+///
+/// Returns `None` once all inputs are disconnected and drained.
+fn next_in_order<T: Send>(inputs: &mut Vec<Input<T>>) -> Option<SmartMessage<T>> {
+    loop {
+        for input in inputs.iter_mut() {
+            if input.head.is_none() {
+                // This also notices when an input got disconnected.
+                input.head = input.rx.try_recv().ok();
+            }
+        }
+
+        inputs.retain(|input| input.head.is_some() || input.rx.is_connected());
+        if inputs.is_empty() {
+            return None;
+        }
+
+        let oldest = inputs
+            .iter_mut()
+            .filter(|input| input.head.is_some())
+            .min_by_key(|input| input.head.as_ref().map(|msg| msg.time));
+        if let Some(oldest) = oldest {
+            return oldest.head.take();
+        }
+
+        // Nothing to hand out: wait for any of the inputs to be ready, then try again.
+        let mut sel = Select::new();
+        let mut any_paused = false;
+        for input in inputs.iter() {
+            if input.rx.is_paused() {
+                any_paused = true;
+            } else {
+                sel.recv(&input.rx.rx);
+            }
+        }
+
+        if any_paused {
+            sel.ready_timeout(PAUSED_POLL_INTERVAL).ok();
+        } else {
+            sel.ready();
+        }
+    }
+}
+
+#[test]
+fn test_merge_ordered() {
+    use crate::{SmartMessagePayload, smart_channel};
+
+    let (tx_a, rx_a) = smart_channel(SmartMessageSource::Sdk, SmartChannelSource::Sdk);
+    let (tx_b, rx_b) = smart_channel(SmartMessageSource::Sdk, SmartChannelSource::Stdin);
+
+    let source = std::sync::Arc::new(SmartMessageSource::Sdk);
+    let start = web_time::Instant::now();
+    let at = |millis| start + std::time::Duration::from_millis(millis);
+
+    tx_a.send_at(at(0), source.clone(), SmartMessagePayload::Msg("a1"))
+        .unwrap();
+    tx_b.send_at(at(1), source.clone(), SmartMessagePayload::Msg("b1"))
+        .unwrap();
+    tx_a.send_at(at(2), source.clone(), SmartMessagePayload::Msg("a2"))
+        .unwrap();
+    tx_b.send_at(at(3), source, SmartMessagePayload::Msg("b2"))
+        .unwrap();
+    drop((tx_a, tx_b));
+
+    let rx = Receiver::merge_ordered(vec![rx_a, rx_b], SmartChannelSource::File("merged".into()));
+
+    let received = std::iter::from_fn(|| rx.recv().ok())
+        .filter_map(|msg| msg.into_data())
+        .collect::<Vec<_>>();
+    assert_eq!(received, vec!["a1", "b1", "a2", "b2"]);
+    assert!(!rx.is_connected());
+}
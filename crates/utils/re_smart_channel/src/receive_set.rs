@@ -3,7 +3,10 @@ use std::sync::Arc;
 use crossbeam::channel::Select;
 use parking_lot::Mutex;
 
-use crate::{Receiver, RecvError, SmartChannelSource, SmartMessage};
+use crate::{ChannelStats, Receiver, RecvError, SmartChannelSource, SmartMessage};
+
+/// How often blocking receives check whether paused receivers were resumed.
+const PAUSED_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
 
 /// A set of connected [`Receiver`]s.
 ///
@@ -101,6 +104,39 @@ impl<T: Send> ReceiveSet<T> {
         latency_nanos
     }
 
+    /// Statistics of each receiver, see [`Receiver::stats`].
+    pub fn stats(&self) -> Vec<(Arc<SmartChannelSource>, ChannelStats)> {
+        re_tracing::profile_function!();
+        let rx = self.receivers.lock();
+        rx.iter().map(|r| (r.source.clone(), r.stats())).collect()
+    }
+
+    /// Pause all receivers with the given source, see [`Receiver::pause`].
+    pub fn pause(&self, source: &SmartChannelSource) {
+        for r in self.receivers.lock().iter() {
+            if r.source() == source {
+                r.pause();
+            }
+        }
+    }
+
+    /// Resume all receivers with the given source, see [`Receiver::resume`].
+    pub fn resume(&self, source: &SmartChannelSource) {
+        for r in self.receivers.lock().iter() {
+            if r.source() == source {
+                r.resume();
+            }
+        }
+    }
+
+    /// Is any receiver with the given source paused?
+    pub fn is_paused(&self, source: &SmartChannelSource) -> bool {
+        self.receivers
+            .lock()
+            .iter()
+            .any(|r| r.source() == source && r.is_paused())
+    }
+
     /// Sum queue length of all receivers.
     pub fn queue_len(&self) -> usize {
         re_tracing::profile_function!();
@@ -110,60 +146,92 @@ impl<T: Send> ReceiveSet<T> {
 
     /// Blocks until a message is ready to be received,
     /// or we are empty.
+    ///
+    /// Paused receivers are skipped until they are resumed.
     pub fn recv(&self) -> Result<SmartMessage<T>, RecvError> {
         re_tracing::profile_function!();
 
-        let mut rx = self.receivers.lock();
+        loop {
+            let mut rx = self.receivers.lock();
 
-        rx.retain(|r| r.is_connected());
-        if rx.is_empty() {
-            // Have to early out here, because `Select::select` will panic if there are no channels to select from.
-            return Err(RecvError);
-        }
+            rx.retain(|r| r.is_connected());
+            if rx.is_empty() {
+                // Have to early out here, because `Select::select` will panic if there are no channels to select from.
+                return Err(RecvError);
+            }
 
-        let mut sel = Select::new();
-        for r in rx.iter() {
-            sel.recv(&r.rx);
-        }
+            if rx.iter().all(|r| r.is_paused()) {
+                drop(rx);
+                std::thread::sleep(PAUSED_POLL_INTERVAL);
+                continue;
+            }
+
+            let active = rx.iter().filter(|r| !r.is_paused()).collect::<Vec<_>>();
 
-        let oper = sel.select();
-        let index = oper.index();
-        oper.recv(&rx[index].rx).map_err(|_err| RecvError)
+            let mut sel = Select::new();
+            for r in &active {
+                sel.recv(&r.rx);
+            }
+
+            let oper = if active.len() == rx.len() {
+                sel.select()
+            } else {
+                // Wake up regularly, so we notice when the paused receivers are resumed.
+                let Ok(oper) = sel.select_timeout(PAUSED_POLL_INTERVAL) else {
+                    continue;
+                };
+                oper
+            };
+            let r = active[oper.index()];
+            let msg = oper.recv(&r.rx).map_err(|_err| RecvError)?;
+            r.on_received();
+            r.register_latency(&msg);
+            return Ok(msg);
+        }
     }
 
     /// Returns immediately if there is nothing to receive.
+    ///
+    /// Paused receivers are skipped.
     pub fn try_recv(&self) -> Option<(Arc<SmartChannelSource>, SmartMessage<T>)> {
         re_tracing::profile_function!();
 
         let mut rx = self.receivers.lock();
 
         rx.retain(|r| r.is_connected());
-        if rx.is_empty() {
+
+        let active = rx.iter().filter(|r| !r.is_paused()).collect::<Vec<_>>();
+        if active.is_empty() {
             return None;
         }
 
         let mut sel = Select::new();
-        for r in rx.iter() {
+        for r in &active {
             sel.recv(&r.rx);
         }
 
         let oper = sel.try_select().ok()?;
-        let index = oper.index();
-        if let Ok(msg) = oper.recv(&rx[index].rx) {
-            return Some((rx[index].source.clone(), msg));
+        let r = active[oper.index()];
+        if let Ok(msg) = oper.recv(&r.rx) {
+            r.on_received();
+            r.register_latency(&msg);
+            return Some((r.source.clone(), msg));
         }
 
         // Nothing ready to receive, but we must poll all receivers to update their `connected` status.
         // Why use `select` first? Because `select` is fair (random) when there is contention.
-        for rx in rx.iter() {
-            if let Ok(msg) = rx.try_recv() {
-                return Some((rx.source.clone(), msg));
+        for r in active {
+            if let Ok(msg) = r.try_recv() {
+                return Some((r.source.clone(), msg));
             }
         }
 
         None
     }
 
+    /// Paused receivers are skipped.
+    ///
+    /// While any receiver is paused, this may return `None` before the timeout has passed.
     pub fn recv_timeout(
         &self,
         timeout: std::time::Duration,
@@ -177,22 +245,38 @@ impl<T: Send> ReceiveSet<T> {
             return None;
         }
 
+        if rx.iter().all(|r| r.is_paused()) {
+            drop(rx);
+            std::thread::sleep(timeout.min(PAUSED_POLL_INTERVAL));
+            return None;
+        }
+
+        let active = rx.iter().filter(|r| !r.is_paused()).collect::<Vec<_>>();
+        let timeout = if active.len() == rx.len() {
+            timeout
+        } else {
+            // Return early, so the caller notices when the paused receivers are resumed.
+            timeout.min(PAUSED_POLL_INTERVAL)
+        };
+
         let mut sel = Select::new();
-        for r in rx.iter() {
+        for r in &active {
             sel.recv(&r.rx);
         }
 
         let oper = sel.select_timeout(timeout).ok()?;
-        let index = oper.index();
-        if let Ok(msg) = oper.recv(&rx[index].rx) {
-            return Some((rx[index].source.clone(), msg));
+        let r = active[oper.index()];
+        if let Ok(msg) = oper.recv(&r.rx) {
+            r.on_received();
+            r.register_latency(&msg);
+            return Some((r.source.clone(), msg));
         }
 
         // Nothing ready to receive, but we must poll all receivers to update their `connected` status.
         // Why use `select` first? Because `select` is fair (random) when there is contention.
-        for rx in rx.iter() {
-            if let Ok(msg) = rx.try_recv() {
-                return Some((rx.source.clone(), msg));
+        for r in active {
+            if let Ok(msg) = r.try_recv() {
+                return Some((r.source.clone(), msg));
             }
         }
 
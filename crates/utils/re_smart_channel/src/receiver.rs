@@ -3,26 +3,79 @@ use std::sync::{
     atomic::{AtomicBool, Ordering::Relaxed},
 };
 
-use crate::{SharedStats, SmartChannelSource, SmartMessage, TryRecvError};
+use web_time::Instant;
+
+use crate::{
+    ChannelCounters, ChannelStats, SendWakers, SharedStats, SmartChannelSource, SmartMessage,
+    TryRecvError,
+};
 
 pub struct Receiver<T: Send> {
     pub(crate) rx: crossbeam::channel::Receiver<SmartMessage<T>>,
     stats: Arc<SharedStats>,
+    counters: Arc<ChannelCounters>,
     pub(crate) source: Arc<SmartChannelSource>,
     connected: AtomicBool,
+    pause: PauseState,
+
+    /// Only set for bounded channels.
+    send_wakers: Option<Arc<SendWakers>>,
+}
+
+/// See [`Receiver::pause`].
+#[derive(Default)]
+struct PauseState {
+    paused: parking_lot::Mutex<bool>,
+    resumed: parking_lot::Condvar,
+}
+
+impl<T: Send> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        // Let waiting senders know that the channel is closed.
+        if let Some(send_wakers) = &self.send_wakers {
+            send_wakers.close();
+        }
+    }
 }
 
 impl<T: Send> Receiver<T> {
     pub(crate) fn new(
         rx: crossbeam::channel::Receiver<SmartMessage<T>>,
         stats: Arc<SharedStats>,
+        counters: Arc<ChannelCounters>,
         source: Arc<SmartChannelSource>,
+        send_wakers: Option<Arc<SendWakers>>,
     ) -> Self {
         Self {
             rx,
             stats,
+            counters,
             source,
             connected: AtomicBool::new(true),
+            pause: PauseState::default(),
+            send_wakers,
+        }
+    }
+
+    /// Must be called after receiving a message.
+    #[inline]
+    pub(crate) fn on_received(&self) {
+        self.counters.on_received();
+        self.wake_senders();
+    }
+
+    /// Records the latency of a message that was just received.
+    #[inline]
+    pub(crate) fn register_latency(&self, msg: &SmartMessage<T>) {
+        let latency_nanos = msg.time.elapsed().as_nanos() as u64;
+        self.stats.latency_nanos.store(latency_nanos, Relaxed);
+    }
+
+    /// Lets senders waiting for room in a bounded channel continue.
+    #[inline]
+    fn wake_senders(&self) {
+        if let Some(send_wakers) = &self.send_wakers {
+            send_wakers.wake_all();
         }
     }
 
@@ -35,22 +88,79 @@ impl<T: Send> Receiver<T> {
         self.connected.load(Relaxed)
     }
 
+    /// Stop handing out messages until [`Self::resume`] is called.
+    ///
+    /// Messages keep queuing up in the channel in the meantime. For a bounded channel,
+    /// this means the senders eventually have to wait, see [`crate::smart_channel_bounded`].
+    ///
+    /// While paused, the receive methods act as if the channel was empty,
+    /// so they also won't notice that the senders disconnected.
+    /// A receive that is already blocked waiting for a message may still return it.
+    pub fn pause(&self) {
+        *self.pause.paused.lock() = true;
+    }
+
+    /// Continue handing out messages after [`Self::pause`], starting with those that queued up.
+    pub fn resume(&self) {
+        *self.pause.paused.lock() = false;
+        self.pause.resumed.notify_all();
+    }
+
+    /// See [`Self::pause`].
+    pub fn is_paused(&self) -> bool {
+        *self.pause.paused.lock()
+    }
+
+    /// Blocks while paused, until `deadline` if any.
+    ///
+    /// Returns `false` if we are still paused once the deadline passed.
+    #[cfg(not(target_arch = "wasm32"))] // Cannot block on web
+    fn wait_while_paused(&self, deadline: Option<std::time::Instant>) -> bool {
+        let mut paused = self.pause.paused.lock();
+        while *paused {
+            if let Some(deadline) = deadline {
+                if self
+                    .pause
+                    .resumed
+                    .wait_until(&mut paused, deadline)
+                    .timed_out()
+                {
+                    return !*paused;
+                }
+            } else {
+                self.pause.resumed.wait(&mut paused);
+            }
+        }
+        true
+    }
+
+    /// Blocks while paused, see [`Self::pause`].
     #[cfg(not(target_arch = "wasm32"))] // Cannot block on web
     pub fn recv(&self) -> Result<SmartMessage<T>, crate::RecvError> {
+        self.wait_while_paused(None);
+
         let Ok(msg) = self.rx.recv() else {
             self.connected.store(false, Relaxed);
             return Err(crate::RecvError);
         };
+        self.on_received();
 
-        let latency_nanos = msg.time.elapsed().as_nanos() as u64;
-        self.stats.latency_nanos.store(latency_nanos, Relaxed);
+        self.register_latency(&msg);
 
         Ok(msg)
     }
 
+    /// Returns [`TryRecvError::Empty`] while paused, see [`Self::pause`].
     pub fn try_recv(&self) -> Result<SmartMessage<T>, TryRecvError> {
+        if self.is_paused() {
+            return Err(TryRecvError::Empty);
+        }
+
         let msg = match self.rx.try_recv() {
-            Ok(x) => x,
+            Ok(x) => {
+                self.on_received();
+                x
+            }
             Err(err) => {
                 if err == TryRecvError::Disconnected {
                     self.connected.store(false, Relaxed);
@@ -59,19 +169,27 @@ impl<T: Send> Receiver<T> {
             }
         };
 
-        let latency_nanos = msg.time.elapsed().as_nanos() as u64;
-        self.stats.latency_nanos.store(latency_nanos, Relaxed);
+        self.register_latency(&msg);
 
         Ok(msg)
     }
 
+    /// Blocks while paused, see [`Self::pause`].
     #[cfg(not(target_arch = "wasm32"))] // Cannot block on web
     pub fn recv_timeout(
         &self,
         timeout: std::time::Duration,
     ) -> Result<SmartMessage<T>, crate::RecvTimeoutError> {
-        let msg = match self.rx.recv_timeout(timeout) {
-            Ok(x) => x,
+        let deadline = std::time::Instant::now() + timeout;
+        if !self.wait_while_paused(Some(deadline)) {
+            return Err(crate::RecvTimeoutError::Timeout);
+        }
+
+        let msg = match self.rx.recv_deadline(deadline) {
+            Ok(x) => {
+                self.on_received();
+                x
+            }
             Err(err) => {
                 if err == crate::RecvTimeoutError::Disconnected {
                     self.connected.store(false, Relaxed);
@@ -80,8 +198,7 @@ impl<T: Send> Receiver<T> {
             }
         };
 
-        let latency_nanos = msg.time.elapsed().as_nanos() as u64;
-        self.stats.latency_nanos.store(latency_nanos, Relaxed);
+        self.register_latency(&msg);
 
         Ok(msg)
     }
@@ -92,7 +209,11 @@ impl<T: Send> Receiver<T> {
     /// created with [`Self::chained_channel`].
     #[cfg(not(target_arch = "wasm32"))] // Cannot block on web
     pub fn recv_with_send_time(&self) -> Result<SmartMessage<T>, crate::RecvError> {
-        self.rx.recv()
+        self.wait_while_paused(None);
+
+        let msg = self.rx.recv()?;
+        self.on_received();
+        Ok(msg)
     }
 
     /// Where is the data coming from?
@@ -113,6 +234,25 @@ impl<T: Send> Receiver<T> {
         self.rx.len()
     }
 
+    /// Maximum number of messages in the channel, if it is bounded,
+    /// see [`crate::smart_channel_bounded`].
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.rx.capacity()
+    }
+
+    /// A snapshot of the statistics of this channel, see [`ChannelStats`].
+    pub fn stats(&self) -> ChannelStats {
+        ChannelStats {
+            time: Instant::now(),
+            queue_len: self.len(),
+            capacity: self.capacity(),
+            total_enqueued: self.counters.enqueued.load(Relaxed),
+            total_dequeued: self.counters.dequeued.load(Relaxed),
+            latency_sec: self.latency_sec(),
+        }
+    }
+
     /// Latest known latency from sending a message to receiving it, it nanoseconds.
     pub fn latency_nanos(&self) -> u64 {
         self.stats.latency_nanos.load(Relaxed)
@@ -127,6 +267,7 @@ impl<T: Send> Receiver<T> {
     /// Create a new channel that use the same stats as this one.
     ///
     /// This means both channels will see the same latency numbers.
+    /// All other statistics are tracked separately for each channel.
     ///
     /// Care must be taken to use [`Self::recv_with_send_time`] and [`crate::Sender::send_at`].
     /// This is a very leaky abstraction, and it would be nice with a refactor.
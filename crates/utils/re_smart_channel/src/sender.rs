@@ -2,13 +2,20 @@ use std::sync::{Arc, atomic::Ordering::Relaxed};
 
 use web_time::Instant;
 
-use crate::{SendError, SharedStats, SmartMessage, SmartMessagePayload, SmartMessageSource};
+use crate::{
+    ChannelCounters, SendError, SendWakers, SharedStats, SmartMessage, SmartMessagePayload,
+    SmartMessageSource, TrySendError,
+};
 
 #[derive(Clone)]
 pub struct Sender<T: Send> {
     tx: crossbeam::channel::Sender<SmartMessage<T>>,
     source: Arc<SmartMessageSource>,
     stats: Arc<SharedStats>,
+    counters: Arc<ChannelCounters>,
+
+    /// Only set for bounded channels.
+    send_wakers: Option<Arc<SendWakers>>,
 }
 
 impl<T: Send> Sender<T> {
@@ -16,8 +23,16 @@ impl<T: Send> Sender<T> {
         tx: crossbeam::channel::Sender<SmartMessage<T>>,
         source: Arc<SmartMessageSource>,
         stats: Arc<SharedStats>,
+        counters: Arc<ChannelCounters>,
+        send_wakers: Option<Arc<SendWakers>>,
     ) -> Self {
-        Self { tx, source, stats }
+        Self {
+            tx,
+            source,
+            stats,
+            counters,
+            send_wakers,
+        }
     }
 
     /// Clones the sender with an updated source.
@@ -26,9 +41,15 @@ impl<T: Send> Sender<T> {
             tx: self.tx.clone(),
             source: Arc::new(source),
             stats: Arc::clone(&self.stats),
+            counters: Arc::clone(&self.counters),
+            send_wakers: self.send_wakers.clone(),
         }
     }
 
+    /// Sends a message down the channel.
+    ///
+    /// If the channel is bounded and full, this blocks until there is room,
+    /// see [`crate::smart_channel_bounded`].
     pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
         self.send_at(
             Instant::now(),
@@ -41,6 +62,69 @@ impl<T: Send> Sender<T> {
         })
     }
 
+    /// Sends a message down the channel, failing if it is bounded and full.
+    pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
+        // NOTE: We should never be sending a message with an unknown source.
+        debug_assert!(!matches!(*self.source, SmartMessageSource::Unknown));
+
+        let into_msg = |payload| match payload {
+            SmartMessagePayload::Msg(msg) => msg,
+            SmartMessagePayload::Flush { .. } | SmartMessagePayload::Quit(_) => unreachable!(),
+        };
+
+        self.tx
+            .try_send(SmartMessage {
+                time: Instant::now(),
+                source: Arc::clone(&self.source),
+                payload: SmartMessagePayload::Msg(msg),
+            })
+            .inspect(|()| self.counters.on_sent())
+            .map_err(|err| match err {
+                TrySendError::Full(msg) => TrySendError::Full(into_msg(msg.payload)),
+                TrySendError::Disconnected(msg) => {
+                    TrySendError::Disconnected(into_msg(msg.payload))
+                }
+            })
+    }
+
+    /// Sends a message down the channel, waiting without blocking for room if it is bounded
+    /// and full, see [`crate::smart_channel_bounded`].
+    ///
+    /// Doesn't depend on any particular async runtime.
+    pub async fn send_async(&self, msg: T) -> Result<(), SendError<T>> {
+        let Some(send_wakers) = &self.send_wakers else {
+            // Unbounded, so never full.
+            return self.send(msg);
+        };
+
+        let mut msg = Some(msg);
+        std::future::poll_fn(|cx| {
+            let Some(pending) = msg.take() else {
+                unreachable!("polled after completion");
+            };
+
+            // Register before trying, so we can't miss the receiver making room in between.
+            send_wakers.register(cx.waker());
+
+            match self.try_send(pending) {
+                Ok(()) => std::task::Poll::Ready(Ok(())),
+                Err(TrySendError::Disconnected(pending)) => {
+                    std::task::Poll::Ready(Err(SendError(pending)))
+                }
+                Err(TrySendError::Full(pending)) => {
+                    if send_wakers.is_closed() {
+                        // The receiver is being dropped, but hasn't closed the channel yet,
+                        // so nobody would ever wake us up again.
+                        return std::task::Poll::Ready(Err(SendError(pending)));
+                    }
+                    msg = Some(pending);
+                    std::task::Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
     /// Forwards a message as-is.
     pub fn send_at(
         &self,
@@ -57,6 +141,7 @@ impl<T: Send> Sender<T> {
                 source,
                 payload,
             })
+            .inspect(|()| self.counters.on_sent())
             .map_err(|SendError(msg)| SendError(msg.payload))
     }
 
@@ -80,6 +165,7 @@ impl<T: Send> Sender<T> {
                 },
             })
             .map_err(|_ignored| FlushError::Closed)?;
+        self.counters.on_sent();
 
         rx.recv_timeout(timeout).map_err(|err| match err {
             std::sync::mpsc::RecvTimeoutError::Timeout => FlushError::Timeout,
@@ -101,11 +187,13 @@ impl<T: Send> Sender<T> {
         // NOTE: We should never be sending a message with an unknown source.
         debug_assert!(!matches!(*self.source, SmartMessageSource::Unknown));
 
-        self.tx.send(SmartMessage {
-            time: Instant::now(),
-            source: Arc::clone(&self.source),
-            payload: SmartMessagePayload::Quit(err),
-        })
+        self.tx
+            .send(SmartMessage {
+                time: Instant::now(),
+                source: Arc::clone(&self.source),
+                payload: SmartMessagePayload::Quit(err),
+            })
+            .inspect(|()| self.counters.on_sent())
     }
 
     /// Is the channel currently empty of messages?
@@ -120,6 +208,13 @@ impl<T: Send> Sender<T> {
         self.tx.len()
     }
 
+    /// Maximum number of messages in the channel, if it is bounded,
+    /// see [`crate::smart_channel_bounded`].
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.tx.capacity()
+    }
+
     /// Latest known latency from sending a message to receiving it, it nanoseconds.
     pub fn latency_nanos(&self) -> u64 {
         self.stats.latency_nanos.load(Relaxed)
@@ -84,6 +84,7 @@ impl AsyncDecoderWrapper {
                 let comms = comms.clone();
                 move || {
                     econtext::econtext_data!("Video", debug_name.clone());
+                    let _region = re_memory::MemoryRegion::Decoders.enter();
 
                     decoder_thread(sync_decoder.as_mut(), &comms, &command_rx, &output_sender);
                     re_log::debug!("Closing decoder thread for {debug_name}");
@@ -340,6 +340,7 @@ impl FFmpegProcessAndListener {
                 let ffmpeg_path = ffmpeg_path.map(|p| p.to_owned());
                 let outstanding_frames = num_outstanding_frames.clone();
                 move || {
+                    let _region = re_memory::MemoryRegion::Decoders.enter();
                     read_ffmpeg_output(
                         &debug_name,
                         ffmpeg_path.as_deref(),
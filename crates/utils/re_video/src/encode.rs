@@ -0,0 +1,188 @@
+//! Encode RGBA frames to an MP4 by sending them to `ffmpeg` over CLI.
+
+use std::{
+    io::{Read as _, Write as _},
+    process::{Child, ChildStdin, Command, Stdio},
+    thread::JoinHandle,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum EncodeError {
+    #[error("Couldn't find an installation of the FFmpeg executable.")]
+    FFmpegNotInstalled,
+
+    #[error("Failed to start FFmpeg: {0}")]
+    FailedToStartFfmpeg(std::io::Error),
+
+    #[error("Failed to communicate with FFmpeg: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Expected a frame of {expected} bytes, got {actual}")]
+    BadFrameSize { expected: usize, actual: usize },
+
+    #[error("FFmpeg failed: {0}")]
+    Ffmpeg(String),
+}
+
+/// Encodes RGBA frames into an H.264 MP4, using the `ffmpeg` executable.
+///
+/// Frames are streamed to `ffmpeg` as they are pushed,
+/// and the encoded file is returned by [`Self::finish`].
+pub struct Mp4Encoder {
+    width: u32,
+    height: u32,
+
+    child: Child,
+
+    /// `None` once we're done sending frames.
+    stdin: Option<ChildStdin>,
+
+    /// Collects the encoded MP4.
+    ///
+    /// We need to read it while writing frames, or `ffmpeg` may block on a full pipe.
+    stdout_thread: Option<JoinHandle<std::io::Result<Vec<u8>>>>,
+
+    /// Collects error messages.
+    stderr_thread: Option<JoinHandle<String>>,
+}
+
+impl Mp4Encoder {
+    /// Starts `ffmpeg` for encoding frames of the given size, in pixels.
+    ///
+    /// Width and height must be even.
+    /// If no path is given, `ffmpeg` is looked up in the `PATH`.
+    pub fn new(
+        ffmpeg_path: Option<&std::path::Path>,
+        width: u32,
+        height: u32,
+        fps: f32,
+    ) -> Result<Self, EncodeError> {
+        re_tracing::profile_function!();
+
+        let ffmpeg_path = ffmpeg_path.map_or_else(ffmpeg_sidecar::paths::ffmpeg_path, |path| {
+            path.to_path_buf()
+        });
+
+        let mut child = Command::new(ffmpeg_path)
+            .args(["-hide_banner", "-loglevel", "error", "-nostats"])
+            // Input: raw RGBA frames on stdin.
+            .args(["-f", "rawvideo", "-pix_fmt", "rgba"])
+            .args(["-s", &format!("{width}x{height}")])
+            .args(["-r", &fps.to_string()])
+            .args(["-i", "-"])
+            // Output: H.264 in a fragmented MP4 on stdout, since a regular MP4 requires seeking.
+            .args(["-c:v", "libx264", "-pix_fmt", "yuv420p"])
+            .args(["-movflags", "frag_keyframe+empty_moov+default_base_moof"])
+            .args(["-f", "mp4", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| {
+                if err.kind() == std::io::ErrorKind::NotFound {
+                    EncodeError::FFmpegNotInstalled
+                } else {
+                    EncodeError::FailedToStartFfmpeg(err)
+                }
+            })?;
+
+        let stdin = child.stdin.take();
+
+        let stdout_thread = child.stdout.take().map(|mut stdout| {
+            std::thread::Builder::new()
+                .name("ffmpeg_encoder_stdout".to_owned())
+                .spawn(move || {
+                    let mut mp4 = Vec::new();
+                    stdout.read_to_end(&mut mp4)?;
+                    Ok(mp4)
+                })
+        });
+        let stderr_thread = child.stderr.take().map(|mut stderr| {
+            std::thread::Builder::new()
+                .name("ffmpeg_encoder_stderr".to_owned())
+                .spawn(move || {
+                    let mut errors = String::new();
+                    stderr.read_to_string(&mut errors).ok();
+                    errors
+                })
+        });
+
+        Ok(Self {
+            width,
+            height,
+            child,
+            stdin,
+            stdout_thread: stdout_thread.transpose()?,
+            stderr_thread: stderr_thread.transpose()?,
+        })
+    }
+
+    /// Size of the frames, in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        [self.width, self.height]
+    }
+
+    /// Encodes the next frame, which must be tightly packed RGBA of [`Self::size`].
+    pub fn push_frame(&mut self, rgba: &[u8]) -> Result<(), EncodeError> {
+        re_tracing::profile_function!();
+
+        let expected = self.width as usize * self.height as usize * 4;
+        if rgba.len() != expected {
+            return Err(EncodeError::BadFrameSize {
+                expected,
+                actual: rgba.len(),
+            });
+        }
+
+        let Some(stdin) = &mut self.stdin else {
+            return Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe).into());
+        };
+        if let Err(err) = stdin.write_all(rgba) {
+            // Most likely `ffmpeg` exited, and its error message is more useful than ours.
+            self.stdin = None;
+            return Err(self.ffmpeg_error().unwrap_or_else(|| err.into()));
+        }
+
+        Ok(())
+    }
+
+    /// Waits for `ffmpeg` to encode the remaining frames, and returns the MP4 file.
+    pub fn finish(mut self) -> Result<Vec<u8>, EncodeError> {
+        re_tracing::profile_function!();
+
+        // Closing stdin tells `ffmpeg` that there are no more frames.
+        self.stdin = None;
+
+        let mp4 = match self.stdout_thread.take().map(|thread| thread.join()) {
+            Some(Ok(result)) => result?,
+            Some(Err(_)) | None => Vec::new(),
+        };
+
+        let status = self.child.wait()?;
+        if !status.success() {
+            return Err(self
+                .ffmpeg_error()
+                .unwrap_or_else(|| EncodeError::Ffmpeg(status.to_string())));
+        }
+
+        Ok(mp4)
+    }
+
+    /// Waits for `ffmpeg` to exit, and returns what it had to say about it.
+    fn ffmpeg_error(&mut self) -> Option<EncodeError> {
+        self.child.wait().ok()?;
+        let errors = self.stderr_thread.take()?.join().ok()?;
+        let errors = errors.trim();
+        (!errors.is_empty()).then(|| EncodeError::Ffmpeg(errors.to_owned()))
+    }
+}
+
+impl Drop for Mp4Encoder {
+    fn drop(&mut self) {
+        if self.stdin.is_some() {
+            // We never finished, so don't wait for the rest of the video to be encoded.
+            self.child.kill().ok();
+        }
+        self.child.wait().ok();
+    }
+}
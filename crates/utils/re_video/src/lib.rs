@@ -1,7 +1,9 @@
-//! Video decoding library.
+//! Video decoding and encoding library.
 
 mod decode;
 mod demux;
+#[cfg(with_ffmpeg)]
+mod encode;
 mod gop_detection;
 mod h264;
 mod h265;
@@ -18,6 +20,8 @@ pub use gop_detection::{DetectGopStartError, GopStartDetection, detect_gop_start
 
 #[cfg(with_ffmpeg)]
 pub use self::decode::{FFmpegError, FFmpegVersion, FFmpegVersionParseError, ffmpeg_download_url};
+#[cfg(with_ffmpeg)]
+pub use self::encode::{EncodeError, Mp4Encoder};
 
 pub use demux::{
     ChromaSubsamplingModes, GopIndex, GroupOfPictures, SampleIndex, SampleMetadata,
@@ -38,14 +38,14 @@ use re_types::{
     blueprint::components::{
         BackgroundKind, Corner2D, Enabled, Eye3DKind, ForceDistance, ForceIterations,
         ForceStrength, GridSpacing, LinkAxis, LockRangeDuringZoom, MapProvider, NearClipPlane,
-        RootContainer, ViewFit, ViewMaximized,
+        PlotMode, RootContainer, ViewFit, ViewMaximized,
     },
     components::{
         AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
         FillRatio, GammaCorrection, GraphType, ImagePlaneDistance, LinearSpeed,
         MagnificationFilter, MarkerSize, Name, Opacity, Position2D, Range1D, Scale3D,
-        SeriesVisible, ShowLabels, StrokeWidth, Text, Timestamp, TransformRelation, Translation3D,
-        ValueRange, VideoCodec, Visible,
+        SeriesSecondaryAxis, SeriesVisible, ShowLabels, StrokeWidth, Text, TextAnchor, Timestamp,
+        TransformInterpolation, TransformRelation, Translation3D, ValueRange, VideoCodec, Visible,
     },
 };
 use re_viewer_context::gpu_bridge::colormap_edit_or_view_ui;
@@ -106,6 +106,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<LockRangeDuringZoom>(edit_bool);
     registry.add_singleline_edit_or_view::<ShowLabels>(edit_bool);
     registry.add_singleline_edit_or_view::<Visible>(edit_bool);
+    registry.add_singleline_edit_or_view::<SeriesSecondaryAxis>(edit_bool);
     registry.add_singleline_edit_or_view::<SeriesVisible>(edit_bool);
 
     // Date components:
@@ -133,6 +134,9 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
         >,
     );
     registry.add_singleline_edit_or_view::<MagnificationFilter>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<PlotMode>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<TextAnchor>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<TransformInterpolation>(edit_view_enum);
     registry.add_singleline_edit_or_view::<TransformRelation>(edit_view_enum);
     registry.add_singleline_edit_or_view::<VideoCodec>(|ctx, ui, value| {
         // Hack to make this field never editable.
@@ -168,7 +172,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     // --------------------------------------------------------------------------------
 
     // `Colormap` _is_ an enum, but its custom editor is far better.
-    registry.add_singleline_edit_or_view(colormap_edit_or_view_ui);
+    registry.add_singleline_edit_or_view_with_target(colormap_edit_or_view_ui);
 
     registry.add_multiline_edit_or_view(visual_bounds2d::multiline_edit_visual_bounds2d);
     registry.add_singleline_edit_or_view(visual_bounds2d::singleline_edit_visual_bounds2d);
@@ -0,0 +1,31 @@
+use re_viewer_context::{Item, SystemCommand, SystemCommandSender as _, ViewId, ViewRectPublisher};
+
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Render the view over a time range, and save it as a video.
+pub struct ExportVideoAction;
+
+impl ContextMenuAction for ExportVideoAction {
+    fn supports_item(&self, ctx: &ContextMenuContext<'_>, item: &Item) -> bool {
+        let Item::View(view_id) = item else {
+            return false;
+        };
+
+        ctx.egui_context().memory_mut(|mem| {
+            mem.caches
+                .cache::<ViewRectPublisher>()
+                .get(view_id)
+                .is_some()
+        })
+    }
+
+    fn label(&self, _ctx: &ContextMenuContext<'_>) -> String {
+        "Export video…".to_owned()
+    }
+
+    fn process_view(&self, ctx: &ContextMenuContext<'_>, view_id: &ViewId) {
+        ctx.viewer_context
+            .command_sender()
+            .send_system(SystemCommand::ShowVideoExportDialog(*view_id));
+    }
+}
@@ -0,0 +1,37 @@
+use re_viewer_context::{Item, SystemCommand, SystemCommandSender as _, ViewId, ViewRectPublisher};
+
+use crate::{ContextMenuAction, ContextMenuContext};
+
+/// Render the view again at a chosen resolution, and save it to disk.
+pub struct HighResScreenshotAction;
+
+impl ContextMenuAction for HighResScreenshotAction {
+    fn supports_item(&self, ctx: &ContextMenuContext<'_>, item: &Item) -> bool {
+        let Item::View(view_id) = item else {
+            return false;
+        };
+
+        let supported = ctx.viewport_blueprint.view(view_id).is_some_and(|view| {
+            view.class(ctx.viewer_context.view_class_registry())
+                .supports_high_res_screenshot()
+        });
+
+        supported
+            && ctx.egui_context().memory_mut(|mem| {
+                mem.caches
+                    .cache::<ViewRectPublisher>()
+                    .get(view_id)
+                    .is_some()
+            })
+    }
+
+    fn label(&self, _ctx: &ContextMenuContext<'_>) -> String {
+        "Save high-resolution screenshot…".to_owned()
+    }
+
+    fn process_view(&self, ctx: &ContextMenuContext<'_>, view_id: &ViewId) {
+        ctx.viewer_context
+            .command_sender()
+            .send_system(SystemCommand::ShowHighResScreenshotDialog(*view_id));
+    }
+}
@@ -8,7 +8,13 @@ pub mod remove;
 pub mod show_hide;
 
 mod copy_entity_path;
+#[cfg(not(target_arch = "wasm32"))]
+mod export_video_action;
+mod high_res_screenshot_action;
 mod screenshot_action;
 
 pub use copy_entity_path::CopyEntityPathToClipboard;
+#[cfg(not(target_arch = "wasm32"))]
+pub use export_video_action::ExportVideoAction;
+pub use high_res_screenshot_action::HighResScreenshotAction;
 pub use screenshot_action::ScreenshotAction;
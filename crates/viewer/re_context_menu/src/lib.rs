@@ -113,13 +113,16 @@ fn context_menu_ui_for_item_with_context_impl(
                 item_context,
             )));
 
+            // Touch screens open the context menu with a long press instead of a secondary click.
+            let opened_on_item = item_response.secondary_clicked() || item_response.long_touched();
+
             // handle selection
             match selection_update_behavior {
                 SelectionUpdateBehavior::UseSelection => {
                     if !ctx.selection().contains_item(item) {
                         // When the context menu is triggered open, we check if we're part of the selection,
                         // and, if not, we update the selection to include only the item that was clicked.
-                        if item_response.hovered() && item_response.secondary_clicked() {
+                        if item_response.hovered() && opened_on_item {
                             show_context_menu(&item_collection);
                             ctx.selection_state().set_selection(item_collection);
                         } else {
@@ -133,7 +136,7 @@ fn context_menu_ui_for_item_with_context_impl(
                 SelectionUpdateBehavior::OverrideSelection => {
                     show_context_menu(&item_collection);
 
-                    if item_response.secondary_clicked() {
+                    if opened_on_item {
                         ctx.selection_state().set_selection(item_collection);
                     }
                 }
@@ -170,6 +173,9 @@ fn action_list(
             vec![
                 Box::new(actions::ScreenshotAction::CopyScreenshot),
                 Box::new(actions::ScreenshotAction::SaveScreenshot),
+                Box::new(actions::HighResScreenshotAction),
+                #[cfg(not(target_arch = "wasm32"))]
+                Box::new(actions::ExportVideoAction),
             ],
             vec![
                 Box::new(CollapseExpandAllAction::ExpandAll),
@@ -13,6 +13,7 @@ use re_viewer_context::{StoredBlobCacheKey, UiLayout, ViewerContext};
 
 use crate::{
     EntityDataUi,
+    document::document_preview_ui,
     image::image_preview_ui,
     video::{show_decoded_frame_info, video_asset_result_ui},
 };
@@ -52,6 +53,7 @@ impl EntityDataUi for Blob {
                     self,
                     media_type.as_ref(),
                     None,
+                    None,
                 );
 
                 ui.label(compact_size_string);
@@ -97,6 +99,7 @@ impl EntityDataUi for Blob {
                     self,
                     media_type.as_ref(),
                     None,
+                    None,
                 );
             });
         }
@@ -114,6 +117,7 @@ pub fn blob_preview_and_save_ui(
     blob_row_id: Option<RowId>,
     blob: &re_types::datatypes::Blob,
     media_type: Option<&MediaType>,
+    file_name: Option<&str>,
     video_timestamp: Option<VideoTimestamp>,
 ) {
     #[allow(unused_assignments)] // Not used when targeting web.
@@ -168,6 +172,18 @@ pub fn blob_preview_and_save_ui(
         }
     }
 
+    let is_image_or_video =
+        image.is_some() || matches!(video_result_for_frame_preview, Some(Ok(_)));
+    if !is_image_or_video {
+        document_preview_ui(
+            ui,
+            ui_layout,
+            blob_row_id.map(|row_id| StoredBlobCacheKey::new(row_id, blob_component_descriptor)),
+            blob,
+            media_type,
+        );
+    }
+
     if !ui_layout.is_single_line() && ui_layout != UiLayout::Tooltip {
         ui.horizontal(|ui| {
             let text = if cfg!(target_arch = "wasm32") {
@@ -182,16 +198,23 @@ pub fn blob_preview_and_save_ui(
                 ))
                 .clicked()
             {
-                let mut file_name = entity_path
-                    .last()
-                    .map_or("blob", |name| name.unescaped_str())
-                    .to_owned();
+                let file_name = if let Some(file_name) = file_name {
+                    file_name.to_owned()
+                } else {
+                    let mut file_name = entity_path
+                        .last()
+                        .map_or("blob", |name| name.unescaped_str())
+                        .to_owned();
 
-                if let Some(file_extension) = media_type.as_ref().and_then(|mt| mt.file_extension())
-                {
-                    file_name.push('.');
-                    file_name.push_str(file_extension);
-                }
+                    if let Some(file_extension) =
+                        media_type.as_ref().and_then(|mt| mt.file_extension())
+                    {
+                        file_name.push('.');
+                        file_name.push_str(file_extension);
+                    }
+
+                    file_name
+                };
 
                 ctx.command_sender().save_file_dialog(
                     re_capabilities::MainThreadToken::from_egui_ui(ui),
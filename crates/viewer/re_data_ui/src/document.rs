@@ -0,0 +1,263 @@
+//! Previews of blobs that are neither images nor videos, e.g. [`re_types::archetypes::Attachment`]s.
+
+use std::sync::Arc;
+
+use re_types::components::MediaType;
+use re_ui::{UiExt as _, list_item::PropertyContent};
+use re_viewer_context::{StoredBlobCacheKey, UiLayout};
+
+/// Text previews are cut off after this many bytes.
+const MAX_TEXT_PREVIEW_BYTES: usize = 64 * 1024;
+
+/// Hexdumps are cut off after this many bytes.
+const MAX_HEXDUMP_BYTES: usize = 4 * 1024;
+
+/// JSON trees are not expanded past this depth.
+const MAX_JSON_DEPTH: usize = 32;
+
+/// What we know how to preview about a blob.
+///
+/// There is no preview for rich documents such as PDFs: they are shown as a hexdump,
+/// even if they happen to be valid UTF-8.
+enum DocumentPreview {
+    Json(serde_json::Value),
+
+    Text(String),
+
+    /// Anything else is shown as a hexdump.
+    Binary,
+}
+
+impl DocumentPreview {
+    fn new(blob: &[u8], media_type: Option<&MediaType>) -> Self {
+        re_tracing::profile_function!();
+
+        let media_type = media_type.map(|media_type| media_type.as_str());
+
+        if media_type == Some(MediaType::PDF) || blob.starts_with(b"%PDF-") {
+            return Self::Binary;
+        }
+
+        let Ok(text) = std::str::from_utf8(blob) else {
+            return Self::Binary;
+        };
+
+        if !looks_like_text(text) {
+            return Self::Binary;
+        }
+
+        let might_be_json =
+            media_type == Some(MediaType::JSON) || text.trim_start().starts_with(['{', '[']);
+        if might_be_json && let Ok(value) = serde_json::from_str(text) {
+            return Self::Json(value);
+        }
+
+        Self::Text(truncated_text(text).into_owned())
+    }
+}
+
+/// Show a preview of a blob that isn't an image or a video: plain text, a JSON tree, or a hexdump.
+pub fn document_preview_ui(
+    ui: &mut egui::Ui,
+    ui_layout: UiLayout,
+    key: Option<StoredBlobCacheKey>,
+    blob: &re_types::datatypes::Blob,
+    media_type: Option<&MediaType>,
+) {
+    if ui_layout.is_single_line() || ui_layout == UiLayout::Tooltip {
+        return;
+    }
+
+    let preview = if let Some(key) = key {
+        ui.ctx().memory_mut(|mem| {
+            // Parsing a large JSON document every frame would be way too slow.
+            let cache = mem
+                .caches
+                .cache::<egui::cache::FramePublisher<StoredBlobCacheKey, Arc<DocumentPreview>>>();
+            cache.get(&key).cloned().unwrap_or_else(|| {
+                let preview = Arc::new(DocumentPreview::new(blob, media_type));
+                cache.set(key, preview.clone());
+                preview
+            })
+        })
+    } else {
+        Arc::new(DocumentPreview::new(blob, media_type))
+    };
+
+    match &*preview {
+        DocumentPreview::Json(value) => {
+            ui.list_item_collapsible_noninteractive_label("JSON", true, |ui| {
+                json_ui(ui, value, 0);
+            });
+        }
+
+        DocumentPreview::Text(text) => {
+            ui.list_item_collapsible_noninteractive_label("Text", true, |ui| {
+                scrollable_monospace_ui(ui, "text_preview", text);
+            });
+        }
+
+        DocumentPreview::Binary => {
+            ui.list_item_collapsible_noninteractive_label("Hexdump", false, |ui| {
+                let hexdump = hexdump(&blob[..blob.len().min(MAX_HEXDUMP_BYTES)]);
+                scrollable_monospace_ui(ui, "hexdump_preview", &hexdump);
+                if MAX_HEXDUMP_BYTES < blob.len() {
+                    ui.weak(format!(
+                        "Showing the first {} of {}",
+                        re_format::format_bytes(MAX_HEXDUMP_BYTES as _),
+                        re_format::format_bytes(blob.len() as _)
+                    ));
+                }
+            });
+        }
+    }
+}
+
+fn scrollable_monospace_ui(ui: &mut egui::Ui, id_salt: &str, text: &str) {
+    egui::ScrollArea::both()
+        .id_salt(id_salt)
+        .max_height(300.0)
+        .show(ui, |ui| {
+            ui.add(egui::Label::new(egui::RichText::new(text).monospace()).extend());
+        });
+}
+
+fn json_ui(ui: &mut egui::Ui, value: &serde_json::Value, depth: usize) {
+    let children: Vec<(String, &serde_json::Value)> = match value {
+        serde_json::Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        serde_json::Value::Array(array) => array
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("[{i}]"), v))
+            .collect(),
+        _ => {
+            ui.list_item_flat_noninteractive(
+                PropertyContent::new("").value_text(value.to_string()),
+            );
+            return;
+        }
+    };
+
+    for (key, child) in children {
+        match child {
+            serde_json::Value::Object(_) | serde_json::Value::Array(_)
+                if depth < MAX_JSON_DEPTH =>
+            {
+                let label = format!("{key} {}", json_summary(child));
+                ui.list_item_collapsible_noninteractive_label(label, depth == 0, |ui| {
+                    json_ui(ui, child, depth + 1);
+                });
+            }
+            _ => {
+                ui.list_item_flat_noninteractive(
+                    PropertyContent::new(key).value_text(child.to_string()),
+                );
+            }
+        }
+    }
+}
+
+/// E.g. `{3}` for an object with three entries.
+fn json_summary(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => format!("{{{}}}", map.len()),
+        serde_json::Value::Array(array) => format!("[{}]", array.len()),
+        _ => String::new(),
+    }
+}
+
+fn looks_like_text(text: &str) -> bool {
+    !text
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+}
+
+fn truncated_text(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.len() <= MAX_TEXT_PREVIEW_BYTES {
+        return text.into();
+    }
+
+    let mut end = MAX_TEXT_PREVIEW_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}\n…", &text[..end]).into()
+}
+
+/// The classic `offset  hex bytes  |ascii|` format, 16 bytes per line.
+fn hexdump(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    for (line_idx, line) in bytes.chunks(16).enumerate() {
+        write!(out, "{:08x}  ", line_idx * 16).ok();
+        for i in 0..16 {
+            if let Some(byte) = line.get(i) {
+                write!(out, "{byte:02x} ").ok();
+            } else {
+                out.push_str("   ");
+            }
+            if i == 7 {
+                out.push(' ');
+            }
+        }
+        out.push_str(" |");
+        out.extend(line.iter().map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        }));
+        out.push_str("|\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexdump() {
+        assert_eq!(
+            hexdump(b"Hello, world!\n\x00\x01\xff"),
+            "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|\n\
+             00000010  ff                                                |.|\n"
+        );
+    }
+
+    #[test]
+    fn test_classify() {
+        assert!(matches!(
+            DocumentPreview::new(br#"{"a": [1, 2]}"#, None),
+            DocumentPreview::Json(_)
+        ));
+        assert!(matches!(
+            DocumentPreview::new(b"{ not json", None),
+            DocumentPreview::Text(_)
+        ));
+        assert!(matches!(
+            DocumentPreview::new(b"\x00\x01\x02", None),
+            DocumentPreview::Binary
+        ));
+        assert!(matches!(
+            DocumentPreview::new(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n", None),
+            DocumentPreview::Binary
+        ));
+    }
+
+    #[test]
+    fn test_classify_ascii_pdf() {
+        // PDFs don't have to contain any binary data.
+        let pdf = b"%PDF-1.1\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\ntrailer << /Root 1 0 R >>\n%%EOF\n";
+        assert!(matches!(
+            DocumentPreview::new(pdf, None),
+            DocumentPreview::Binary
+        ));
+        assert!(matches!(
+            DocumentPreview::new(b"1 0 obj", Some(&MediaType::pdf())),
+            DocumentPreview::Binary
+        ));
+    }
+}
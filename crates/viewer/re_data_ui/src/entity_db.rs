@@ -2,12 +2,12 @@ use jiff::SignedDuration;
 use jiff::fmt::friendly::{FractionalUnit, SpanPrinter};
 
 use re_byte_size::SizeBytes as _;
-use re_chunk_store::ChunkStoreConfig;
+use re_chunk_store::{ChunkStoreBackend, ChunkStoreConfig};
 use re_entity_db::EntityDb;
 use re_log_types::StoreKind;
 use re_smart_channel::SmartChannelSource;
 use re_ui::UiExt as _;
-use re_viewer_context::{UiLayout, ViewerContext};
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, UiLayout, ViewerContext};
 
 use crate::item_ui::{app_id_button_ui, data_source_button_ui};
 
@@ -135,8 +135,26 @@ impl crate::DataUi for EntityDb {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    backend,
                 } = self.storage_engine().store().config();
 
+                if backend == ChunkStoreBackend::MemoryMapped {
+                    ui.grid_left_hand_label("Storage");
+                    ui.label(format!(
+                        "Memory-mapped files ({} on disk)",
+                        re_format::format_bytes(
+                            self.storage_engine().store().num_bytes_on_disk() as _
+                        ),
+                    ))
+                    .on_hover_text(format!(
+                        "The data of this recording is kept in temporary files, \
+                        and only read into RAM when it is needed.\n\
+                        Set {}=memory before starting the viewer to keep it all in RAM instead.",
+                        ChunkStoreConfig::ENV_CHUNK_STORE_BACKEND,
+                    ));
+                    ui.end_row();
+                }
+
                 ui.grid_left_hand_label("Compaction");
                 ui.label(format!(
                     "{} rows ({} if unsorted) or {}",
@@ -180,6 +198,29 @@ impl crate::DataUi for EntityDb {
                         )),
                     );
                 ui.end_row();
+
+                if self.store_kind() == StoreKind::Recording {
+                    ui.label("");
+                    if ctx.storage_context.hub.is_compacting(self.store_id()) {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Compacting…");
+                        });
+                    } else if ui
+                        .button("Compact now")
+                        .on_hover_text(
+                            "Merge the chunks of this recording that are below the thresholds above \
+                            with their neighbors.\n\
+                            This helps recordings that were logged with many tiny log calls, \
+                            and are slow to query as a result.",
+                        )
+                        .clicked()
+                    {
+                        ctx.command_sender()
+                            .send_system(SystemCommand::CompactRecording(self.store_id().clone()));
+                    }
+                    ui.end_row();
+                }
             }
 
             if let Some(data_source) = &self.data_source {
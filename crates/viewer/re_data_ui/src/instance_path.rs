@@ -13,7 +13,8 @@ use re_types::{
 use re_ui::{UiExt as _, design_tokens_of_visuals, list_item};
 use re_viewer_context::{
     ColormapWithRange, HoverHighlight, ImageInfo, ImageStatsCache, Item, UiLayout,
-    VideoStreamCache, ViewerContext, gpu_bridge::image_data_range_heuristic,
+    VideoStreamCache, ViewerContext,
+    gpu_bridge::{self, image_data_range_heuristic},
     video_stream_time_from_query,
 };
 
@@ -77,6 +78,17 @@ impl DataUi for InstancePath {
                 .cache()
                 .latest_at(query, entity_path, &unordered_components);
 
+        if ui_layout == UiLayout::Tooltip {
+            let components = unordered_components.iter().cloned().collect::<Vec<_>>();
+            if let Some(hover_card) = ctx
+                .component_ui_registry()
+                .hover_card(entity_path, &components)
+            {
+                hover_card(ctx, ui, self, &query_results);
+                return;
+            }
+        }
+
         // Keep previously established order.
         let mut components_by_archetype: BTreeMap<
             Option<ArchetypeName>,
@@ -370,6 +382,12 @@ fn preview_single_image(
         image_buffer_descr.archetype,
     );
 
+    let custom_colormap = find_and_deserialize_archetype_mono_component::<components::Name>(
+        components,
+        image_buffer_descr.archetype,
+    )
+    .and_then(|name| gpu_bridge::custom_colormap(name.as_str()));
+
     let colormap_with_range = colormap.map(|colormap| ColormapWithRange {
         colormap,
         value_range: value_range
@@ -382,6 +400,7 @@ fn preview_single_image(
                     [min as _, max as _]
                 }
             }),
+        custom_colormap,
     });
 
     image_preview_ui(
@@ -569,6 +588,12 @@ fn preview_single_blob(
     )
     .or_else(|| components::MediaType::guess_from_data(&blob));
 
+    // Attachments may come with the name of the file they were read from.
+    let file_name = find_and_deserialize_archetype_mono_component::<components::Name>(
+        components,
+        blob_descr.archetype,
+    );
+
     // Video timestamp is only relevant here if it comes from a VideoFrameReference archetype.
     // It doesn't show up in the blob's archetype.
     let video_timestamp_descr = archetypes::VideoFrameReference::descriptor_timestamp();
@@ -593,6 +618,7 @@ fn preview_single_blob(
         blob_chunk.row_id(),
         &blob,
         media_type.as_ref(),
+        file_name.as_ref().map(|name| name.as_str()),
         video_timestamp,
     );
 
@@ -17,6 +17,7 @@ mod component_path;
 mod component_type;
 mod component_ui_registry;
 mod data_source;
+mod document;
 mod entity_db;
 mod entity_path;
 mod image;
@@ -16,4 +16,6 @@ pub use datafusion_table_widget::DataFusionTableWidget;
 pub use display_record_batch::{DisplayRecordBatch, DisplayRecordBatchError};
 pub use header_tooltip::column_header_tooltip_ui;
 pub use requested_object::RequestedObject;
-pub use table_blueprint::{ColumnBlueprint, TableBlueprint, default_display_name_for_column};
+pub use table_blueprint::{
+    ColumnBlueprint, SortDirection, TableBlueprint, default_display_name_for_column,
+};
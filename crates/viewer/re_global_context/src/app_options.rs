@@ -30,6 +30,9 @@ pub struct AppOptions {
     #[serde(rename = "timestamp_format")]
     pub timestamp_format: TimestampFormat,
 
+    /// The language of the UI as a BCP 47 tag like `de`, or `None` for English.
+    pub locale: Option<String>,
+
     /// Preferred method for video decoding on web.
     pub video_decoder_hw_acceleration: DecodeHardwareAcceleration,
 
@@ -82,6 +85,8 @@ impl Default for AppOptions {
 
             timestamp_format: TimestampFormat::Utc,
 
+            locale: None,
+
             video_decoder_hw_acceleration: DecodeHardwareAcceleration::default(),
             video_decoder_override_ffmpeg_path: false,
             video_decoder_ffmpeg_path: String::new(),
@@ -86,6 +86,9 @@ pub enum SystemCommand {
     /// is both modified and changed in the same frame.
     DropEntity(StoreId, EntityPath),
 
+    /// Merge the small chunks of a recording with their neighbors, over the next frames.
+    CompactRecording(StoreId),
+
     /// Show a timeline of the blueprint data.
     #[cfg(debug_assertions)]
     EnableInspectBlueprintTimeline(bool),
@@ -123,6 +126,21 @@ pub enum SystemCommand {
     /// Just like selection highlighting, the exact behavior of focusing is up to the receiving views.
     SetFocus(crate::Item),
 
+    /// Open the dialog for saving a high-resolution screenshot of a view.
+    ShowHighResScreenshotDialog(crate::ViewId),
+
+    /// Open the dialog for exporting a view as a video.
+    #[cfg(not(target_arch = "wasm32"))]
+    ShowVideoExportDialog(crate::ViewId),
+
+    /// Show a notification to the user, e.g. about a non-fatal problem.
+    ///
+    /// See also [`CommandSender::notify`].
+    ShowNotification {
+        level: re_ui::notifications::NotificationLevel,
+        text: String,
+    },
+
     /// Add a task, run on a background thread, that saves something to disk.
     #[cfg(not(target_arch = "wasm32"))]
     FileSaver(Box<dyn FnOnce() -> anyhow::Result<std::path::PathBuf> + Send + 'static>),
@@ -187,6 +205,19 @@ pub fn command_channel() -> (CommandSender, CommandReceiver) {
     )
 }
 
+impl CommandSender {
+    /// Show a notification in the notification panel and as a toast.
+    ///
+    /// For warnings and errors that users should see, e.g. from custom visualizers,
+    /// instead of only logging them.
+    pub fn notify(&self, level: re_ui::notifications::NotificationLevel, text: impl Into<String>) {
+        self.send_system(SystemCommand::ShowNotification {
+            level,
+            text: text.into(),
+        });
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl SystemCommandSender for CommandSender {
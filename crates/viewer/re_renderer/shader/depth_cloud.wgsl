@@ -53,6 +53,9 @@ struct DepthCloudInfo {
 
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
+
+    /// If not zero, look up the color in `colormap_texture` instead of using `colormap`.
+    use_colormap_texture: u32,
 };
 
 @group(1) @binding(0)
@@ -67,6 +70,9 @@ var texture_sint: texture_2d<i32>;
 @group(1) @binding(3)
 var texture_uint: texture_2d<u32>;
 
+@group(1) @binding(4)
+var colormap_texture: texture_2d<f32>;
+
 struct VertexOut {
     @builtin(position)
     pos_in_clip: vec4f,
@@ -123,7 +129,17 @@ fn compute_point_data(quad_idx: u32) -> PointData {
         let normalized_depth =
             (world_space_depth - depth_cloud_info.min_max_depth_in_world.x) /
             (depth_cloud_info.min_max_depth_in_world.y - depth_cloud_info.min_max_depth_in_world.x);
-        let color = vec4f(colormap_linear(depth_cloud_info.colormap, normalized_depth), 1.0);
+        var color: vec4f;
+        if depth_cloud_info.use_colormap_texture != 0u {
+            // Indexed like the colormap texture of rectangles, see `rectangle_fs.wgsl`.
+            let colormap_size = textureDimensions(colormap_texture).xy;
+            let num_colors = colormap_size.x * colormap_size.y;
+            let color_index = min(u32(round(saturate(normalized_depth) * f32(num_colors))), num_colors - 1u);
+            let colormap_coords = vec2u(color_index % colormap_size.x, color_index / colormap_size.x);
+            color = vec4f(textureLoad(colormap_texture, colormap_coords, 0).rgb, 1.0);
+        } else {
+            color = vec4f(colormap_linear(depth_cloud_info.colormap, normalized_depth), 1.0);
+        }
 
         // TODO(cmc): This assumes a pinhole camera; need to support other kinds at some point.
         let intrinsics = depth_cloud_info.depth_camera_intrinsics;
@@ -185,7 +201,7 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) vec4f {
     let coverage = sphere_quad_coverage(in.pos_in_world, in.point_radius, in.point_pos_in_world);
-    if coverage < 0.001 {
+    if coverage < 0.001 || clip_plane_distance(in.point_pos_in_world) < 0.0 {
         discard;
     }
     return vec4f(in.point_color.rgb, coverage);
@@ -194,7 +210,7 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
 @fragment
 fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
     let coverage = sphere_quad_coverage(in.pos_in_world, in.point_radius, in.point_pos_in_world);
-    if coverage <= 0.5 {
+    if coverage <= 0.5 || clip_plane_distance(in.point_pos_in_world) < 0.0 {
         discard;
     }
     return vec4u(depth_cloud_info.picking_layer_object_id, in.quad_idx, 0u);
@@ -205,7 +221,7 @@ fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
     // Output is an integer target so we can't use coverage even though
     // the target is anti-aliased.
     let coverage = sphere_quad_coverage(in.pos_in_world, in.point_radius, in.point_pos_in_world);
-    if coverage <= 0.5 {
+    if coverage <= 0.5 || clip_plane_distance(in.point_pos_in_world) < 0.0 {
         discard;
     }
     return depth_cloud_info.outline_mask_id;
@@ -23,6 +23,26 @@ struct FrameUniformBuffer {
     tan_half_fov: vec2f,
     _padding: vec2f,
 
+    /// Plane in world space, as normal and distance from the origin.
+    /// Everything behind it is cut away. All zero if there is no clip plane.
+    clip_plane: vec4f,
+
+    /// Color of the contour where meshes are cut by the clip plane. Zero alpha if there is none.
+    clip_contour_color: vec4f,
+
+    /// Direction towards the key light in world space, normalized.
+    key_light_direction: vec3f,
+
+    /// Brightness of the key light. Meshes are lit from the opposite side at half of it.
+    key_light_intensity: f32,
+
+    /// Brightness of the light reaching meshes from all sides.
+    ambient_light: f32,
+
+    /// Non-zero if meshes are drawn in their albedo color, without any shading.
+    unlit: u32,
+    _row_padding: vec2u,
+
     /// re_renderer defined device tier.
     device_tier: u32,
 };
@@ -37,6 +57,13 @@ var nearest_sampler_clamped: sampler;
 @group(0) @binding(3)
 var trilinear_sampler_repeat: sampler;
 
+/// Signed distance of a world space position from the clip plane, negative if it is cut away.
+///
+/// Always zero if there is no clip plane.
+fn clip_plane_distance(position_world: vec3f) -> f32 {
+    return dot(frame.clip_plane.xyz, position_world) + frame.clip_plane.w;
+}
+
 // See config.rs#DeviceTier
 const DEVICE_TIER_GLES = 0u;
 const DEVICE_TIER_WEBGPU = 1u;
@@ -1,6 +1,7 @@
 #import <./types.wgsl>
 #import <./global_bindings.wgsl>
 #import <./mesh_vertex.wgsl>
+#import <./utils/camera.wgsl>
 #import <./utils/srgb.wgsl>
 
 @group(1) @binding(0)
@@ -40,6 +41,9 @@ struct VertexOut {
 
     @location(5) @interpolate(flat)
     picking_layer_id: vec4u,
+
+    @location(6)
+    position_world: vec3f,
 };
 
 @vertex
@@ -63,12 +67,25 @@ fn vs_main(in_vertex: VertexIn, in_instance: InstanceIn) -> VertexOut {
     out.additive_tint_rgb = linear_from_srgb(in_instance.additive_tint_srgb.rgb);
     out.outline_mask_ids = in_instance.outline_mask_ids;
     out.picking_layer_id = in_instance.picking_layer_id;
+    out.position_world = world_position;
 
     return out;
 }
 
 @fragment
 fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
+    let clip_distance = clip_plane_distance(in.position_world);
+    if clip_distance < 0.0 {
+        discard;
+    }
+    if frame.clip_contour_color.a > 0.0 {
+        // Draw a band of a few pixels where the surface meets the clip plane.
+        let pixel_world_size = approx_pixel_world_size_at(distance(in.position_world, frame.camera_position));
+        if clip_distance < 2.0 * pixel_world_size {
+            return frame.clip_contour_color;
+        }
+    }
+
     let sample = textureSample(albedo_texture, trilinear_sampler_repeat, in.texcoord);
     var texture: vec3f;
     switch material.texture_format {
@@ -82,19 +99,20 @@ fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
                  * material.albedo_factor.rgb
                  + in.additive_tint_rgb;
 
-    if all(in.normal_world_space == vec3f(0.0, 0.0, 0.0)) {
-        // no normal, no shading
+    if frame.unlit != 0u || all(in.normal_world_space == vec3f(0.0, 0.0, 0.0)) {
+        // unlit or no normal, no shading
         return vec4f(albedo, 1.0);
     } else {
         let normal = normalize(in.normal_world_space);
 
-        var shading = 0.2;
+        var shading = frame.ambient_light;
 
         // We use two lights so we get shading on all sides
-        shading += 1.0 * clamp(dot(normalize(vec3f(1.0, 2.0, 3.0)), normal), 0.0, 1.0);
-        shading += 0.5 * clamp(dot(normalize(vec3f(-1.0, -3.0, -5.0)), normal), 0.0, 1.0);
+        shading += frame.key_light_intensity * clamp(dot(frame.key_light_direction, normal), 0.0, 1.0);
+        shading += 0.5 * frame.key_light_intensity * clamp(dot(-frame.key_light_direction, normal), 0.0, 1.0);
 
-        shading = clamp(shading, 0.0, 1.0);
+        // Only a key light brighter than usual may brighten meshes beyond their albedo.
+        shading = clamp(shading, 0.0, max(1.0, frame.key_light_intensity));
 
         let radiance = albedo * shading;
 
@@ -104,10 +122,16 @@ fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
 
 @fragment
 fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
+    if clip_plane_distance(in.position_world) < 0.0 {
+        discard;
+    }
     return in.picking_layer_id;
 }
 
 @fragment
 fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
+    if clip_plane_distance(in.position_world) < 0.0 {
+        discard;
+    }
     return in.outline_mask_ids;
 }
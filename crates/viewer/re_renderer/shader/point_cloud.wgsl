@@ -28,7 +28,8 @@ struct BatchUniformBuffer {
     world_from_obj: mat4x4f,
     flags: u32,
     depth_offset: f32,
-    _padding: vec2u,
+    min_radius_in_ui_points: f32,
+    max_radius_in_ui_points: f32, // Zero if there's no maximum.
     outline_mask: vec2u,
     picking_layer_object_id: vec2u,
 };
@@ -106,8 +107,12 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     // Span quad
     let camera_distance = distance(frame.camera_position, point_data.pos);
     let world_scale_factor = average_scale_from_transform(batch.world_from_obj); // TODO(andreas): somewhat costly, should precompute this
-    let world_radius = unresolved_size_to_world(point_data.unresolved_radius, camera_distance, world_scale_factor) +
-                       world_size_from_point_size(draw_data.radius_boost_in_ui_points, camera_distance);
+    var world_radius = unresolved_size_to_world(point_data.unresolved_radius, camera_distance, world_scale_factor);
+    world_radius = max(world_radius, world_size_from_point_size(batch.min_radius_in_ui_points, camera_distance));
+    if batch.max_radius_in_ui_points > 0.0 {
+        world_radius = min(world_radius, world_size_from_point_size(batch.max_radius_in_ui_points, camera_distance));
+    }
+    world_radius += world_size_from_point_size(draw_data.radius_boost_in_ui_points, camera_distance);
     let quad = sphere_or_circle_quad_span(vertex_idx, point_data.pos, world_radius,
                                              has_any_flag(batch.flags, FLAG_DRAW_AS_CIRCLES));
 
@@ -148,7 +153,7 @@ fn coverage(world_position: vec3f, radius: f32, point_center: vec3f) -> f32 {
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) vec4f {
     let cov = coverage(in.world_position, in.radius, in.point_center);
-    if cov < 0.001 {
+    if cov < 0.001 || clip_plane_distance(in.point_center) < 0.0 {
         discard;
     }
 
@@ -165,7 +170,7 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
 @fragment
 fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
     let cov = coverage(in.world_position, in.radius, in.point_center);
-    if cov <= 0.5 {
+    if cov <= 0.5 || clip_plane_distance(in.point_center) < 0.0 {
         discard;
     }
     return vec4u(batch.picking_layer_object_id, in.picking_instance_id);
@@ -176,7 +181,7 @@ fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
     // Output is an integer target so we can't use coverage even though
     // the target is anti-aliased.
     let cov = coverage(in.world_position, in.radius, in.point_center);
-    if cov <= 0.5 {
+    if cov <= 0.5 || clip_plane_distance(in.point_center) < 0.0 {
         discard;
     }
     return batch.outline_mask;
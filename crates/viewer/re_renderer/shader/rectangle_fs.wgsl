@@ -155,7 +155,8 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
         // TODO(emilk): interpolate between neighboring colors for non-integral color indices
         // It's important to round here since otherwise numerical instability can push us to the adjacent class-id
         // See: https://github.com/rerun-io/rerun/issues/1968
-        let color_index_u32 = u32(round(color_index));
+        // Values at the top of the range would index one past the end.
+        let color_index_u32 = min(u32(round(color_index)), colormap_size.x * colormap_size.y - 1u);
         let x = color_index_u32 % colormap_size.x;
         let y = color_index_u32 / colormap_size.x;
         texture_color = textureLoad(colormap_texture, vec2u(x, y), 0);
@@ -328,6 +328,7 @@ impl RenderContext {
     /// Updates internal book-keeping, frame allocators and executes delayed events like shader reloading.
     pub fn begin_frame(&mut self) {
         re_tracing::profile_function!();
+        let _region = re_memory::MemoryRegion::Renderer.enter();
 
         // If the currently active frame still has an encoder, we need to finish it and queue it.
         // This should only ever happen for the first frame where we created an encoder for preparatory work. Every other frame we take the encoder at submit!
@@ -433,6 +434,7 @@ This means, either a call to RenderContext::before_submit was omitted, or the pr
     /// Call this at the end of a frame but before submitting command buffers (e.g. from [`crate::view_builder::ViewBuilder`])
     pub fn before_submit(&mut self) {
         re_tracing::profile_function!();
+        let _region = re_memory::MemoryRegion::Renderer.enter();
 
         // Unmap all write staging buffers.
         self.cpu_write_gpu_read_belt.lock().before_queue_submit();
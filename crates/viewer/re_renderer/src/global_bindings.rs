@@ -39,6 +39,27 @@ pub struct FrameUniformBuffer {
     /// Both values are set to f32max for orthographic projection
     pub tan_half_fov: wgpu_buffer_types::Vec2RowPadded,
 
+    /// Plane in world space, as normal and distance from the origin.
+    /// Everything behind it is cut away. All zero if there is no clip plane.
+    pub clip_plane: wgpu_buffer_types::Vec4,
+
+    /// Color of the contour where meshes are cut by the clip plane. Zero alpha if there is none.
+    pub clip_contour_color: wgpu_buffer_types::Vec4,
+
+    /// Direction towards the key light in world space, normalized.
+    pub key_light_direction: glam::Vec3,
+
+    /// Brightness of the key light. Meshes are lit from the opposite side at half of it.
+    pub key_light_intensity: f32,
+
+    /// Brightness of the light reaching meshes from all sides.
+    pub ambient_light: f32,
+
+    /// Non-zero if meshes are drawn in their albedo color, without any shading.
+    pub unlit: u32,
+
+    pub _row_padding: [u32; 2],
+
     /// `re_renderer` defined device tier.
     pub device_tier: wgpu_buffer_types::U32RowPadded,
 }
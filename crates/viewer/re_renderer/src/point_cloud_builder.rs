@@ -131,6 +131,13 @@ impl PointCloudBatchBuilder<'_, '_> {
         self
     }
 
+    /// Clamps the radii of all points in the batch to a range in ui points.
+    #[inline]
+    pub fn radius_range_in_ui_points(mut self, range: std::ops::RangeInclusive<f32>) -> Self {
+        self.batch_mut().radius_range_in_ui_points = range;
+        self
+    }
+
     /// Add several 3D points
     ///
     /// Returns a `PointBuilder` which can be used to set the colors, radii, and user-data for the points.
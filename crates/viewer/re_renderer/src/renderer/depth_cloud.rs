@@ -75,7 +75,8 @@ mod gpu_data {
         /// Changes over different draw-phases.
         pub radius_boost_in_ui_points: f32,
 
-        pub _row_padding: [f32; 1],
+        /// Whether to use the colormap texture instead of [`Self::colormap`].
+        pub use_colormap_texture: u32,
 
         // ---
         pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1],
@@ -95,6 +96,7 @@ mod gpu_data {
                 depth_dimensions: _,
                 depth_texture,
                 colormap,
+                colormap_texture,
                 outline_mask_id,
                 picking_object_id,
             } = depth_cloud;
@@ -121,8 +123,8 @@ mod gpu_data {
                 colormap: *colormap as u32,
                 sample_type,
                 radius_boost_in_ui_points,
+                use_colormap_texture: colormap_texture.is_some() as u32,
                 picking_layer_object_id: *picking_object_id,
-                _row_padding: Default::default(),
                 _end_padding: Default::default(),
             })
         }
@@ -159,6 +161,11 @@ pub struct DepthCloud {
     /// Configures color mapping mode.
     pub colormap: Colormap,
 
+    /// Look up colors in this texture instead of using [`Self::colormap`].
+    ///
+    /// Indexed like [`super::ColorMapper::Texture`], and must have the format [`wgpu::TextureFormat::Rgba8UnormSrgb`].
+    pub colormap_texture: Option<GpuTexture2D>,
+
     /// Option outline mask id preference.
     pub outline_mask_id: OutlineMaskPreference,
 
@@ -226,6 +233,9 @@ impl DrawData for DepthCloudDrawData {
 pub enum DepthCloudDrawDataError {
     #[error("Texture format not supported: {0:?} - use float or integer textures instead.")]
     TextureFormatNotSupported(wgpu::TextureFormat),
+
+    #[error("Invalid color map texture format: {0:?}")]
+    UnsupportedColormapTextureFormat(wgpu::TextureFormat),
 }
 
 impl DepthCloudDrawData {
@@ -295,6 +305,18 @@ impl DepthCloudDrawData {
                 }
             }
 
+            let colormap_texture = if let Some(colormap_texture) = &depth_cloud.colormap_texture {
+                let format = colormap_texture.format();
+                if format != wgpu::TextureFormat::Rgba8UnormSrgb {
+                    return Err(DepthCloudDrawDataError::UnsupportedColormapTextureFormat(
+                        format,
+                    ));
+                }
+                colormap_texture.handle()
+            } else {
+                ctx.texture_manager_2d.zeroed_texture_float().handle
+            };
+
             let mk_bind_group = |label, ubo: BindGroupEntry| {
                 ctx.gpu_resources.bind_groups.alloc(
                     &ctx.device,
@@ -306,6 +328,7 @@ impl DepthCloudDrawData {
                             BindGroupEntry::DefaultTextureView(texture_float),
                             BindGroupEntry::DefaultTextureView(texture_sint),
                             BindGroupEntry::DefaultTextureView(texture_uint),
+                            BindGroupEntry::DefaultTextureView(colormap_texture),
                         ],
                         layout: bg_layout,
                     },
@@ -401,6 +424,17 @@ impl Renderer for DepthCloudRenderer {
                         },
                         count: None,
                     },
+                    // colormap texture:
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
                 ],
             },
         );
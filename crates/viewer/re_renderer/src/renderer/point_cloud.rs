@@ -13,7 +13,10 @@
 //! that srgb->linear conversion happens on texture load.
 //!
 
-use std::{num::NonZeroU64, ops::Range};
+use std::{
+    num::NonZeroU64,
+    ops::{Range, RangeInclusive},
+};
 
 use crate::{
     DebugLabel, DepthOffset, OutlineMaskPreference, PointCloudBuilder,
@@ -84,7 +87,8 @@ pub mod gpu_data {
 
         pub flags: u32, // PointCloudBatchFlags
         pub depth_offset: f32,
-        pub _row_padding: [f32; 2],
+        pub min_radius_in_ui_points: f32,
+        pub max_radius_in_ui_points: f32, // Zero if there's no maximum.
 
         pub outline_mask_ids: wgpu_buffer_types::UVec2,
         pub picking_object_id: PickingLayerObjectId,
@@ -149,6 +153,23 @@ pub struct PointCloudBatchInfo {
 
     /// Depth offset applied after projection.
     pub depth_offset: DepthOffset,
+
+    /// The radii of the points are clamped to this range in ui points, after resolving them.
+    ///
+    /// Keeps points given in scene units visible when far away, and small when close by.
+    pub radius_range_in_ui_points: RangeInclusive<f32>,
+}
+
+impl PointCloudBatchInfo {
+    fn min_radius_in_ui_points(&self) -> f32 {
+        self.radius_range_in_ui_points.start().max(0.0)
+    }
+
+    /// The shader treats zero as no maximum.
+    fn max_radius_in_ui_points(&self) -> f32 {
+        let max = *self.radius_range_in_ui_points.end();
+        if max.is_finite() { max.max(0.0) } else { 0.0 }
+    }
 }
 
 impl Default for PointCloudBatchInfo {
@@ -163,6 +184,7 @@ impl Default for PointCloudBatchInfo {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            radius_range_in_ui_points: 0.0..=f32::INFINITY,
         }
     }
 }
@@ -214,6 +236,7 @@ impl PointCloudDrawData {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            radius_range_in_ui_points: 0.0..=f32::INFINITY,
         }];
         let batches = if batches.is_empty() {
             &fallback_batches
@@ -299,8 +322,8 @@ impl PointCloudDrawData {
                             .into(),
                         picking_object_id: batch_info.picking_object_id,
                         depth_offset: batch_info.depth_offset as f32,
-
-                        _row_padding: [0.0, 0.0],
+                        min_radius_in_ui_points: batch_info.min_radius_in_ui_points(),
+                        max_radius_in_ui_points: batch_info.max_radius_in_ui_points(),
                         end_padding: Default::default(),
                     }),
             );
@@ -323,8 +346,8 @@ impl PointCloudDrawData {
                                     outline_mask_ids: mask.0.unwrap_or_default().into(),
                                     picking_object_id: batch_info.picking_object_id,
                                     depth_offset: batch_info.depth_offset as f32,
-
-                                    _row_padding: [0.0, 0.0],
+                                    min_radius_in_ui_points: batch_info.min_radius_in_ui_points(),
+                                    max_radius_in_ui_points: batch_info.max_radius_in_ui_points(),
                                     end_padding: Default::default(),
                                 })
                         })
@@ -259,6 +259,7 @@ pub fn transfer_image_data_to_texture(
     target_texture: &GpuTexture,
 ) -> Result<(), ImageDataToTextureError> {
     re_tracing::profile_function!();
+    let _region = re_memory::MemoryRegion::Renderer.enter();
 
     image_data.validate(&ctx.device.limits(), &target_texture.creation_desc)?;
 
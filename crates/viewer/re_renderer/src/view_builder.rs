@@ -188,6 +188,58 @@ impl Projection {
     }
 }
 
+/// Cuts away everything behind a plane, e.g. to look inside of meshes and dense point clouds.
+///
+/// Applies to meshes, point clouds and depth clouds. Lines are never cut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClipPlane {
+    /// A point on the plane, in world space.
+    pub point: glam::Vec3,
+
+    /// Everything on the side the normal points to is kept.
+    pub normal: glam::Vec3,
+
+    /// If set, meshes are drawn in this color where they are cut.
+    pub contour_color: Option<Rgba>,
+}
+
+impl ClipPlane {
+    /// Normal and distance from the origin, as used by the shaders.
+    fn to_gpu(self) -> glam::Vec4 {
+        let normal = self.normal.normalize_or_zero();
+        normal.extend(-normal.dot(self.point))
+    }
+}
+
+/// How meshes are shaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lighting {
+    /// Direction towards the key light, in world space.
+    ///
+    /// Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+    pub key_light_direction: glam::Vec3,
+
+    /// Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+    pub key_light_intensity: f32,
+
+    /// Brightness of the light reaching meshes from all sides.
+    pub ambient_light: f32,
+
+    /// Draws meshes in their albedo color, ignoring all lights.
+    pub unlit: bool,
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        Self {
+            key_light_direction: glam::vec3(1.0, 2.0, 3.0).normalize(),
+            key_light_intensity: 1.0,
+            ambient_light: 0.2,
+            unlit: false,
+        }
+    }
+}
+
 /// Basic configuration for a target view.
 #[derive(Debug, Clone)]
 pub struct TargetConfiguration {
@@ -225,6 +277,12 @@ pub struct TargetConfiguration {
     /// Otherwise, this step will overwrite whatever was there before, drawing the view builder's result
     /// as an opaque rectangle.
     pub blend_with_background: bool,
+
+    /// Cuts away part of the scene, see [`ClipPlane`].
+    pub clip_plane: Option<ClipPlane>,
+
+    /// How meshes are shaded.
+    pub lighting: Lighting,
 }
 
 impl Default for TargetConfiguration {
@@ -242,6 +300,8 @@ impl Default for TargetConfiguration {
             pixels_per_point: 1.0,
             outline_config: None,
             blend_with_background: false,
+            clip_plane: None,
+            lighting: Lighting::default(),
         }
     }
 }
@@ -500,6 +560,24 @@ impl ViewBuilder {
             tan_half_fov: tan_half_fov.into(),
             pixel_world_size_from_camera_distance,
             pixels_per_point: config.pixels_per_point,
+            clip_plane: config
+                .clip_plane
+                .map_or(glam::Vec4::ZERO, ClipPlane::to_gpu)
+                .into(),
+            clip_contour_color: config
+                .clip_plane
+                .and_then(|clip_plane| clip_plane.contour_color)
+                .unwrap_or(Rgba::TRANSPARENT)
+                .into(),
+            key_light_direction: config
+                .lighting
+                .key_light_direction
+                .try_normalize()
+                .unwrap_or(Lighting::default().key_light_direction),
+            key_light_intensity: config.lighting.key_light_intensity,
+            ambient_light: config.lighting.ambient_light,
+            unlit: config.lighting.unlit as u32,
+            _row_padding: Default::default(),
 
             device_tier: (ctx.device_caps().tier as u32).into(),
         };
@@ -179,6 +179,7 @@ impl RenderDepthClouds {
                     depth_dimensions: depth.dimensions,
                     depth_texture: depth.texture.clone(),
                     colormap: re_renderer::Colormap::Turbo,
+                    colormap_texture: None,
                     outline_mask_id: Default::default(),
                     picking_object_id: Default::default(),
                 }],
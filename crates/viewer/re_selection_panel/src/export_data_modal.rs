@@ -0,0 +1,349 @@
+//! Exporting the data of the selected entities and components as CSV or Parquet,
+//! for quick offline analysis without writing a dataframe query.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use arrow::array::{Array as _, ArrayRef};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use itertools::Itertools as _;
+
+use re_chunk::ComponentIdentifier;
+use re_chunk_store::{ColumnDescriptor, QueryExpression, StaticColumnSelection};
+use re_dataframe::external::re_query::StorageEngineArcReadGuard;
+use re_dataframe::{QueryEngine, QueryHandle};
+use re_entity_db::EntityPath;
+use re_log_types::{AbsoluteTimeRange, Timeline};
+use re_ui::modal::{ModalHandler, ModalWrapper};
+use re_ui::{TimeDragValue, UiExt as _};
+use re_viewer_context::{Item, ViewerContext};
+
+/// The entities to export, each with all its components (`None`) or some of them.
+type ExportContents = BTreeMap<EntityPath, Option<BTreeSet<ComponentIdentifier>>>;
+
+/// The entities and components of the selection, if it contains any.
+pub(crate) fn export_contents<'a>(items: impl Iterator<Item = &'a Item>) -> ExportContents {
+    let mut contents = ExportContents::new();
+
+    for item in items {
+        match item {
+            Item::ComponentPath(component_path) => {
+                let components = contents
+                    .entry(component_path.entity_path.clone())
+                    .or_insert_with(|| Some(BTreeSet::new()));
+                if let Some(components) = components {
+                    components.insert(component_path.component_descriptor.component);
+                }
+            }
+
+            Item::InstancePath(instance_path) | Item::DataResult(_, instance_path)
+                if instance_path.is_all() =>
+            {
+                contents.insert(instance_path.entity_path.clone(), None);
+            }
+
+            _ => {}
+        }
+    }
+
+    contents
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ExportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Lets the user pick the time range and file format before exporting the selected data.
+///
+/// The data is range-queried on the selected timeline, one row per time and entity.
+#[derive(Default)]
+pub(crate) struct ExportDataModal {
+    modal: ModalHandler,
+    contents: ExportContents,
+    timeline: Option<Timeline>,
+    time_range: AbsoluteTimeRange,
+    format: ExportFormat,
+}
+
+impl ExportDataModal {
+    pub fn open(&mut self, ctx: &ViewerContext<'_>, contents: ExportContents) {
+        let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+        let timeline = *time_ctrl.timeline();
+
+        self.time_range = time_ctrl
+            .loop_selection()
+            .map(|range| AbsoluteTimeRange::new(range.min.floor(), range.max.ceil()))
+            .or_else(|| ctx.recording().time_range_for(timeline.name()))
+            .unwrap_or(AbsoluteTimeRange::EVERYTHING);
+        self.timeline = Some(timeline);
+        self.contents = contents;
+        self.modal.open();
+    }
+
+    pub fn ui(&mut self, egui_ctx: &egui::Context, ctx: &ViewerContext<'_>) {
+        let Self {
+            modal,
+            contents,
+            timeline,
+            time_range,
+            format,
+        } = self;
+
+        let mut export = false;
+
+        modal.ui(
+            egui_ctx,
+            || ModalWrapper::new("Export data").max_width(400.0),
+            |ui| {
+                let Some(timeline) = timeline else {
+                    ui.close();
+                    return;
+                };
+                let typ = timeline.typ();
+                let timestamp_format = ctx.app_options().timestamp_format;
+
+                ui.label(format!(
+                    "Exports every change of {} on the {:?} timeline.",
+                    if contents.len() == 1 {
+                        "the selected data"
+                    } else {
+                        "the selected entities"
+                    },
+                    timeline.name().as_str()
+                ));
+
+                ui.add_space(8.0);
+
+                let time_drag_value = ctx.recording().time_histogram(timeline.name()).map_or_else(
+                    || TimeDragValue::from_time_range(0..=0),
+                    TimeDragValue::from_time_histogram,
+                );
+
+                egui::Grid::new("export_data_time_range")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("From:");
+                        time_drag_value.drag_value_ui(
+                            ui,
+                            typ,
+                            &mut time_range.min,
+                            true,
+                            None,
+                            timestamp_format,
+                        );
+                        ui.end_row();
+
+                        ui.label("To:");
+                        time_drag_value.drag_value_ui(
+                            ui,
+                            typ,
+                            &mut time_range.max,
+                            true,
+                            Some(time_range.min),
+                            timestamp_format,
+                        );
+                        ui.end_row();
+                    });
+
+                if let Some(full_range) = ctx.recording().time_range_for(timeline.name())
+                    && ui.button("Whole timeline").clicked()
+                {
+                    *time_range = full_range;
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Format:");
+                    ui.radio_value(format, ExportFormat::Csv, "CSV");
+                    ui.radio_value(format, ExportFormat::Parquet, "Parquet");
+                });
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let button_width = ui.tokens().modal_button_width;
+
+                    if ui
+                        .add(egui::Button::new("Export…").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        export = true;
+                        ui.close();
+                    }
+
+                    if ui
+                        .add(egui::Button::new("Cancel").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        ui.close();
+                    }
+                });
+
+                if export {
+                    save_export(ctx, ui, contents, timeline, *time_range, *format);
+                }
+            },
+        );
+    }
+}
+
+fn save_export(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    contents: &ExportContents,
+    timeline: &Timeline,
+    time_range: AbsoluteTimeRange,
+    format: ExportFormat,
+) {
+    re_tracing::profile_function!();
+
+    let query_engine = QueryEngine {
+        engine: ctx.recording().storage_engine_arc(),
+    };
+    let query_handle = query_engine.query(QueryExpression {
+        view_contents: Some(contents.clone().into_iter().collect()),
+        filtered_index: Some(*timeline.name()),
+        filtered_index_range: Some(time_range),
+        include_static_columns: StaticColumnSelection::Both,
+        ..Default::default()
+    });
+
+    let bytes = match format {
+        ExportFormat::Csv => Ok(to_csv(&query_handle).into_bytes()),
+        ExportFormat::Parquet => to_parquet(&query_handle),
+    };
+
+    let file_name = match contents.keys().exactly_one() {
+        Ok(entity_path) => entity_path.to_string(),
+        Err(_) => "data".to_owned(),
+    };
+
+    match bytes {
+        Ok(bytes) => ctx.command_sender().save_file_dialog(
+            re_capabilities::MainThreadToken::from_egui_ui(ui),
+            &format!("{file_name}.{}", format.file_extension()),
+            "Export data".to_owned(),
+            bytes,
+        ),
+        Err(err) => re_log::error!("Failed to export data: {err}"),
+    }
+}
+
+fn format_cell(cell: &ArrayRef) -> String {
+    if cell.is_empty() || cell.is_null(0) {
+        return String::new();
+    }
+    match ArrayFormatter::try_new(cell.as_ref(), &FormatOptions::default()) {
+        Ok(formatter) => formatter.value(0).to_string(),
+        Err(_) => format!("<{}>", cell.data_type()),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// All rows of the query as CSV.
+///
+/// Values are written as stored, e.g. timestamps are in nanoseconds since the epoch.
+fn to_csv(query_handle: &QueryHandle<StorageEngineArcReadGuard>) -> String {
+    let mut csv = String::new();
+
+    let header = query_handle
+        .selected_contents()
+        .iter()
+        .map(|(_, column)| match column {
+            ColumnDescriptor::Component(component) => csv_field(&format!(
+                "{}:{}",
+                component.entity_path,
+                column.display_name()
+            )),
+            ColumnDescriptor::RowId(_) | ColumnDescriptor::Time(_) => {
+                csv_field(&column.display_name())
+            }
+        })
+        .join(",");
+    csv.push_str(&header);
+    csv.push('\n');
+
+    query_handle.seek_to_row(0);
+    while let Some(row) = query_handle.next_row() {
+        writeln!(
+            csv,
+            "{}",
+            row.iter()
+                .map(|cell| csv_field(&format_cell(cell)))
+                .join(",")
+        )
+        .ok();
+    }
+
+    csv
+}
+
+/// All rows of the query as a Parquet file, keeping the Arrow types.
+fn to_parquet(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+) -> Result<Vec<u8>, parquet::errors::ParquetError> {
+    let mut bytes = Vec::new();
+    let mut writer =
+        parquet::arrow::ArrowWriter::try_new(&mut bytes, query_handle.schema().clone(), None)?;
+    query_handle.seek_to_row(0);
+    for batch in query_handle.batch_iter() {
+        writer.write(&batch)?;
+    }
+    writer.close()?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_entity_db::InstancePath;
+    use re_log_types::ComponentPath;
+    use re_types::ComponentDescriptor;
+
+    use super::*;
+
+    #[test]
+    fn selected_components_are_merged_per_entity() {
+        let points = EntityPath::from("points");
+        let component = |component: &str| {
+            Item::ComponentPath(ComponentPath::new(
+                points.clone(),
+                ComponentDescriptor::partial(component),
+            ))
+        };
+
+        let items = [
+            component("Points3D:positions"),
+            component("Points3D:colors"),
+            Item::InstancePath(InstancePath::entity_all(EntityPath::from("boxes"))),
+            Item::InstancePath(InstancePath::instance(EntityPath::from("arrows"), 0.into())),
+        ];
+        let contents = export_contents(items.iter());
+
+        assert_eq!(contents.len(), 2);
+        assert_eq!(contents[&EntityPath::from("boxes")], None);
+        assert_eq!(
+            contents[&points]
+                .as_ref()
+                .map(|components| components.len()),
+            Some(2)
+        );
+    }
+}
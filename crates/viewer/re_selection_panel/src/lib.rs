@@ -3,6 +3,7 @@
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod defaults_ui;
+mod export_data_modal;
 mod item_heading_no_breadcrumbs;
 mod item_heading_with_breadcrumbs;
 mod item_title;
@@ -7,7 +7,10 @@ use re_data_ui::{
     item_ui::{self, cursor_interact_with_selectable, guess_query_and_db_for_selected_entity},
 };
 use re_entity_db::{EntityPath, InstancePath};
-use re_log_types::{ComponentPath, EntityPathFilter, EntityPathSubs, ResolvedEntityPathFilter};
+use re_log_types::{
+    ComponentPath, ComponentPredicate, EntityPathFilter, EntityPathSubs, ResolvedEntityPathFilter,
+    TimeType,
+};
 use re_types::ComponentDescriptor;
 use re_ui::{
     SyntaxHighlighting as _, UiExt as _, icons,
@@ -17,10 +20,13 @@ use re_viewer_context::{
     ContainerId, Contents, DataQueryResult, DataResult, HoverHighlight, Item, UiLayout,
     ViewContext, ViewId, ViewStates, ViewerContext, contents_name_style, icon_for_container_kind,
 };
-use re_viewport_blueprint::{ViewportBlueprint, ui::show_add_view_or_container_modal};
+use re_viewport_blueprint::{
+    FollowLatest, TimeOffset, ViewportBlueprint, ui::show_add_view_or_container_modal,
+};
 
 use crate::{
     defaults_ui::view_components_defaults_section_ui,
+    export_data_modal::{ExportDataModal, export_contents},
     item_heading_no_breadcrumbs::item_title_list_item,
     item_heading_with_breadcrumbs::item_heading_with_breadcrumbs,
     view_entity_picker::ViewEntityPicker,
@@ -42,6 +48,10 @@ pub struct SelectionPanel {
     #[serde(skip)]
     /// State for the "Add entity" modal.
     view_entity_modal: ViewEntityPicker,
+
+    #[serde(skip)]
+    /// State for the "Export data" modal.
+    export_data_modal: ExportDataModal,
 }
 
 impl SelectionPanel {
@@ -91,6 +101,7 @@ impl SelectionPanel {
 
         // run modals (these are noop if the modals are not active)
         self.view_entity_modal.ui(ui.ctx(), ctx, viewport);
+        self.export_data_modal.ui(ui.ctx(), ctx);
     }
 
     #[allow(clippy::unused_self)]
@@ -127,6 +138,8 @@ impl SelectionPanel {
                         item,
                         UiLayout::SelectionPanel,
                     );
+
+                    self.export_data_button_ui(ctx, ui);
                 });
             }
         } else {
@@ -147,10 +160,27 @@ impl SelectionPanel {
                     ui.add_space(4.0);
                     item_title_list_item(ctx, viewport, ui, item);
                 }
+
+                ui.add_space(4.0);
+                self.export_data_button_ui(ctx, ui);
             });
         }
     }
 
+    /// Shown if the selection contains entities or components.
+    fn export_data_button_ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        let contents = export_contents(ctx.selection().iter_items());
+        if contents.is_empty() {
+            return;
+        }
+
+        ui.list_item_flat_noninteractive(
+            list_item::ButtonContent::new("Export data…")
+                .on_click(|| self.export_data_modal.open(ctx, contents))
+                .hover_text("Save the data of the selection over a time range as CSV or Parquet"),
+        );
+    }
+
     // TODO(emilk): this should probably be `impl DataUi for Item`
     fn item_ui(
         &mut self,
@@ -420,6 +450,19 @@ The last rule matching `/world/car/driver` is `+ /world/car/driver`, so it is in
 The last rule matching `/world/car/hood` is `- /world/car/**`, so it is excluded.
 The last rule matching `/world` is `- /world`, so it is excluded.
 The last rule matching `/world/house` is `+ /world/**`, so it is included.
+
+## Component predicates
+
+A rule can be restricted to entities with certain component values at the current time,
+with comparisons joined by `and` after `where`:
+
+```diff
++ /detections/** where confidence > 0.8 and label != "background"
+```
+
+Components are referred to by their name (`radii`), their identifier (`Points3D:radii`) or their type (`Radius`).
+A comparison passes if any value of the component passes.
+Where a rule with a predicate doesn't pass, the next most specific rule applies.
     "#
         .trim();
 
@@ -558,6 +601,20 @@ fn entity_selection_ui(
 
     if let Some(data_result) = &data_result {
         visible_time_range_ui_for_data_result(ctx, ui, data_result);
+
+        if let Some(view) = viewport.view(view_id) {
+            let view_class = view.class(ctx.view_class_registry());
+            let view_state = view_states.get_mut_or_create(view.id, view_class);
+            if let Err(err) =
+                view_class.data_result_selection_ui(ctx, ui, view_state, view.id, data_result)
+            {
+                re_log::error_once!(
+                    "Error in entity selection UI (class: {}, display name: {}): {err}",
+                    view.class_identifier(),
+                    view_class.display_name(),
+                );
+            }
+        }
     }
 }
 
@@ -673,8 +730,16 @@ fn entity_path_filter_ui(
         );
     }
 
-    // Apply the edit.
     let new_filter = EntityPathFilter::parse_forgiving(&filter_string);
+
+    // Rules with invalid predicates don't match anything, so point them out.
+    for (rule, _) in new_filter.rules() {
+        if let Some(Err(err)) = rule.predicate_expression().map(ComponentPredicate::parse) {
+            ui.warning_label(format!("{}: {err}", rule.path_expression()));
+        }
+    }
+
+    // Apply the edit.
     if new_filter == filter.unresolved() {
         None // no change
     } else {
@@ -840,6 +905,88 @@ fn view_top_level_properties(
             .value_text(view.class(ctx.view_class_registry()).display_name()),
     )
     .on_hover_text("The type of this view");
+
+    follow_latest_ui(ctx, ui, view);
+    time_offset_ui(ctx, ui, view);
+}
+
+fn time_offset_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view: &re_viewport_blueprint::ViewBlueprint,
+) {
+    let time_offset_before = TimeOffset::load(ctx, view.id);
+    let mut time_offset = time_offset_before;
+
+    let suffix = match ctx.rec_cfg.time_ctrl.read().time_type() {
+        TimeType::Sequence => " steps",
+        TimeType::DurationNs | TimeType::TimestampNs => " s",
+    };
+    ui.list_item_flat_noninteractive(PropertyContent::new("Time offset").value_fn(|ui, _| {
+        ui.add(
+            egui::DragValue::new(&mut time_offset.offset)
+                .speed(0.1)
+                .suffix(suffix),
+        );
+    }))
+    .on_hover_text(
+        "Shows the view at the time cursor plus this offset, \
+        e.g. −5 to show what happened five seconds before",
+    );
+
+    if time_offset != time_offset_before {
+        time_offset.save(ctx, view.id);
+    }
+}
+
+fn follow_latest_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view: &re_viewport_blueprint::ViewBlueprint,
+) {
+    let follow_latest_before = FollowLatest::load(ctx, view.id);
+    let mut follow_latest = follow_latest_before.clone();
+
+    ui.list_item_flat_noninteractive(
+        PropertyContent::new("Follow latest").value_bool_mut(&mut follow_latest.enabled),
+    )
+    .on_hover_text(
+        "Always show the newest data on the selected timeline, wherever the time cursor is",
+    );
+
+    if follow_latest.enabled {
+        ui.list_item_flat_noninteractive(PropertyContent::new("Camera follows").value_fn(
+            |ui, _| {
+                let mut entities = Vec::new();
+                ctx.lookup_query_result(view.id).tree.visit(&mut |node| {
+                    if !node.data_result.visualizers.is_empty() {
+                        entities.push(node.data_result.entity_path.clone());
+                    }
+                    true
+                });
+
+                let camera_entity = &mut follow_latest.camera_entity;
+                egui::ComboBox::from_id_salt("follow_latest_camera_entity")
+                    .selected_text(
+                        camera_entity
+                            .as_ref()
+                            .map_or_else(|| "(none)".to_owned(), ToString::to_string),
+                    )
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(camera_entity, None, "(none)");
+                        for entity in entities {
+                            let label = entity.to_string();
+                            ui.selectable_value(camera_entity, Some(entity), label);
+                        }
+                    });
+            },
+        ))
+        .on_hover_text("The entity the eye of a 3D view tracks while following the latest data");
+    }
+
+    if follow_latest != follow_latest_before {
+        follow_latest.save(ctx, view.id);
+    }
 }
 
 fn container_top_level_properties(
@@ -604,13 +604,18 @@ impl TestContext {
                 | SystemCommand::UndoBlueprint { .. }
                 | SystemCommand::RedoBlueprint { .. }
                 | SystemCommand::CloseAllEntries
-                | SystemCommand::SetLoopSelection { .. } => handled = false,
+                | SystemCommand::CompactRecording(_)
+                | SystemCommand::SetLoopSelection { .. }
+                | SystemCommand::ShowNotification { .. } => handled = false,
 
                 #[cfg(debug_assertions)]
-                SystemCommand::EnableInspectBlueprintTimeline(_) => handled = false,
+                SystemCommand::EnableInspectBlueprintTimeline(_)
+                | SystemCommand::ShowHighResScreenshotDialog(_) => handled = false,
 
                 #[cfg(not(target_arch = "wasm32"))]
-                SystemCommand::FileSaver(_) => handled = false,
+                SystemCommand::ShowVideoExportDialog(_) | SystemCommand::FileSaver(_) => {
+                    handled = false;
+                }
             }
 
             if !handled {
@@ -103,6 +103,7 @@ impl TestContextExt for TestContext {
                                 ctx.store_context,
                                 ctx.view_class_registry(),
                                 ctx.blueprint_query,
+                                &ctx.current_query(),
                                 &visualizable_entities,
                             );
 
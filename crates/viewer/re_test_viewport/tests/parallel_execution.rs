@@ -0,0 +1,172 @@
+//! Visualizers of a view are executed in parallel, see [`re_viewer_context::VisualizerSystem`].
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use re_log_types::{
+    TimePoint,
+    example_components::{MyPoint, MyPoints},
+};
+use re_test_context::TestContext;
+use re_test_viewport::TestContextExt as _;
+use re_ui::Help;
+use re_viewer_context::external::re_chunk_store::external::re_chunk::RowId;
+use re_viewer_context::{
+    ComponentFallbackProvider, ComponentFallbackProviderResult, IdentifiedViewSystem, QueryContext,
+    ViewClass, ViewContext, ViewContextCollection, ViewQuery, ViewSpawnHeuristics, ViewState,
+    ViewSystemExecutionError, ViewSystemIdentifier, ViewerContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+use re_viewport_blueprint::ViewBlueprint;
+
+/// Number of [`Rendezvous`] visualizers that started executing.
+static NUM_STARTED: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of [`Rendezvous`] visualizers that saw all others execute at the same time.
+static NUM_MET: AtomicUsize = AtomicUsize::new(0);
+
+const NUM_VISUALIZERS: usize = 2;
+
+/// Waits for the other visualizers of the view to start, which never happens if they run one after another.
+#[derive(Default)]
+struct Rendezvous<const INDEX: usize>;
+
+impl<const INDEX: usize> VisualizerSystem for Rendezvous<INDEX> {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<MyPoints>()
+    }
+
+    fn execute(
+        &mut self,
+        _ctx: &ViewContext<'_>,
+        _query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        NUM_STARTED.fetch_add(1, Ordering::SeqCst);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if NUM_STARTED.load(Ordering::SeqCst) == NUM_VISUALIZERS {
+                NUM_MET.fetch_add(1, Ordering::SeqCst);
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn ComponentFallbackProvider {
+        self
+    }
+}
+
+impl<const INDEX: usize> IdentifiedViewSystem for Rendezvous<INDEX> {
+    fn identifier() -> ViewSystemIdentifier {
+        if INDEX == 0 {
+            "FirstRendezvous".into()
+        } else {
+            "SecondRendezvous".into()
+        }
+    }
+}
+
+impl<const INDEX: usize> ComponentFallbackProvider for Rendezvous<INDEX> {
+    fn try_provide_fallback(
+        &self,
+        _ctx: &QueryContext<'_>,
+        _component_type: re_types::ComponentType,
+    ) -> ComponentFallbackProviderResult {
+        ComponentFallbackProviderResult::ComponentNotHandled
+    }
+}
+
+#[derive(Default)]
+struct RendezvousView;
+
+impl ViewClass for RendezvousView {
+    fn identifier() -> re_types::ViewClassIdentifier {
+        "RendezvousView".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Rendezvous view"
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Rendezvous view").markdown("Only used in tests.")
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), re_viewer_context::ViewClassRegistryError> {
+        system_registry.register_visualizer::<Rendezvous<0>>()?;
+        system_registry.register_visualizer::<Rendezvous<1>>()
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<()>::default()
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&re_log_types::EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _ui: &mut egui::Ui,
+        _state: &mut dyn ViewState,
+        _query: &ViewQuery<'_>,
+        _system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn visualizers_of_a_view_execute_in_parallel() {
+    // Parallel execution needs more than one thread, even on single-core machines or with `RAYON_NUM_THREADS=1`.
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(NUM_VISUALIZERS)
+        .build()
+        .expect("failed to build thread pool");
+
+    let mut test_context = TestContext::new_with_view_class::<RendezvousView>();
+    test_context.log_entity("points", |builder| {
+        builder.with_archetype(
+            RowId::new(),
+            TimePoint::STATIC,
+            &MyPoints::new([MyPoint::default()]),
+        )
+    });
+
+    let view_id = test_context.setup_viewport_blueprint(|_ctx, blueprint| {
+        blueprint.add_view_at_root(ViewBlueprint::new_with_root_wildcard(
+            RendezvousView::identifier(),
+        ))
+    });
+
+    // Everything run within `install` uses the thread pool, including the parallel execution of the visualizers.
+    thread_pool.install(|| {
+        test_context.run_once_in_egui_central_panel(|ctx, ui| {
+            test_context.ui_for_single_view(ui, ctx, view_id);
+        });
+    });
+
+    assert_eq!(NUM_STARTED.load(Ordering::SeqCst), NUM_VISUALIZERS);
+    assert_eq!(NUM_MET.load(Ordering::SeqCst), NUM_VISUALIZERS);
+}
@@ -0,0 +1,183 @@
+//! Views whose visualizers opted in reuse their output while nothing changed,
+//! see [`re_viewer_context::VisualizerSystem::is_output_reusable`].
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use re_log_types::{
+    Timeline,
+    example_components::{MyPoint, MyPoints},
+};
+use re_test_context::TestContext;
+use re_test_viewport::TestContextExt as _;
+use re_ui::Help;
+use re_viewer_context::external::re_chunk_store::external::re_chunk::RowId;
+use re_viewer_context::{
+    ComponentFallbackProvider, ComponentFallbackProviderResult, IdentifiedViewSystem, QueryContext,
+    ViewClass, ViewContext, ViewContextCollection, ViewId, ViewQuery, ViewSpawnHeuristics,
+    ViewState, ViewSystemExecutionError, ViewSystemIdentifier, ViewerContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+use re_viewport_blueprint::ViewBlueprint;
+
+/// Number of times a [`CountingVisualizer`] was executed.
+static NUM_EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Default)]
+struct CountingVisualizer;
+
+impl VisualizerSystem for CountingVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<MyPoints>()
+    }
+
+    fn execute(
+        &mut self,
+        _ctx: &ViewContext<'_>,
+        _query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        NUM_EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+        Ok(Vec::new())
+    }
+
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn ComponentFallbackProvider {
+        self
+    }
+}
+
+impl IdentifiedViewSystem for CountingVisualizer {
+    fn identifier() -> ViewSystemIdentifier {
+        "Counting".into()
+    }
+}
+
+impl ComponentFallbackProvider for CountingVisualizer {
+    fn try_provide_fallback(
+        &self,
+        _ctx: &QueryContext<'_>,
+        _component_type: re_types::ComponentType,
+    ) -> ComponentFallbackProviderResult {
+        ComponentFallbackProviderResult::ComponentNotHandled
+    }
+}
+
+#[derive(Default)]
+struct CountingView;
+
+impl ViewClass for CountingView {
+    fn identifier() -> re_types::ViewClassIdentifier {
+        "CountingView".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Counting view"
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Counting view").markdown("Only used in tests.")
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), re_viewer_context::ViewClassRegistryError> {
+        system_registry.register_visualizer::<CountingVisualizer>()
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<()>::default()
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&re_log_types::EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _ui: &mut egui::Ui,
+        _state: &mut dyn ViewState,
+        _query: &ViewQuery<'_>,
+        _system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        Ok(())
+    }
+}
+
+fn log_points(test_context: &mut TestContext, timeline: Timeline, frame: i64) {
+    test_context.log_entity("points", |builder| {
+        builder.with_archetype(
+            RowId::new(),
+            [(timeline, frame)],
+            &MyPoints::new([MyPoint::new(frame as f32, 0.0)]),
+        )
+    });
+}
+
+fn set_time(test_context: &TestContext, frame: i64) {
+    test_context
+        .recording_config
+        .time_ctrl
+        .write()
+        .set_time(frame);
+}
+
+fn show_view(test_context: &TestContext, view_id: ViewId) {
+    test_context.run_once_in_egui_central_panel(|ctx, ui| {
+        test_context.ui_for_single_view(ui, ctx, view_id);
+    });
+}
+
+#[test]
+fn view_output_is_reused_until_its_inputs_change() {
+    let timeline = Timeline::new_sequence("frame");
+
+    let mut test_context = TestContext::new_with_view_class::<CountingView>();
+    test_context.set_active_timeline(timeline);
+    log_points(&mut test_context, timeline, 1);
+
+    let view_id = test_context.setup_viewport_blueprint(|_ctx, blueprint| {
+        blueprint.add_view_at_root(ViewBlueprint::new_with_root_wildcard(
+            CountingView::identifier(),
+        ))
+    });
+
+    set_time(&test_context, 1);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 1);
+
+    // Nothing changed.
+    show_view(&test_context, view_id);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 1);
+
+    // The time cursor moved.
+    set_time(&test_context, 2);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 2);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 2);
+
+    // A new chunk arrived.
+    log_points(&mut test_context, timeline, 2);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 3);
+    show_view(&test_context, view_id);
+    assert_eq!(NUM_EXECUTIONS.load(Ordering::SeqCst), 3);
+}
@@ -1509,6 +1509,10 @@ fn help(os: egui::os::OperatingSystem) -> Help {
             "Zoom",
             IconText::from_modifiers_and(os, Modifiers::COMMAND, icons::SCROLL),
         )
+        .control(
+            "Pan and zoom with touch",
+            ("drag two fingers", "/", "pinch"),
+        )
         .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
 }
 
@@ -1720,7 +1724,14 @@ fn interact_with_streams_rect(
     full_rect: &Rect,
     streams_rect: &Rect,
 ) -> egui::Response {
-    let pointer_pos = ui.input(|i| i.pointer.hover_pos());
+    // Dragging two fingers pans, anywhere in the time area, and pinching zooms around their center.
+    let touch_gesture = ui
+        .input(|i| i.multi_touch())
+        .filter(|gesture| full_rect.contains(gesture.start_pos));
+    let pointer_pos = match &touch_gesture {
+        Some(gesture) => Some(gesture.center_pos),
+        None => ui.input(|i| i.pointer.hover_pos()),
+    };
 
     let mut delta_x = 0.0;
     let mut zoom_factor = 1.0;
@@ -1734,6 +1745,9 @@ fn interact_with_streams_rect(
             zoom_factor *= input.zoom_delta_2d().x;
         });
     }
+    if let Some(gesture) = &touch_gesture {
+        delta_x += gesture.translation_delta.x;
+    }
 
     // We only check for drags in the streams rect,
     // because drags in the timeline rect should move the time
@@ -1743,7 +1757,7 @@ fn interact_with_streams_rect(
         ui.id().with("time_area_interact"),
         egui::Sense::click_and_drag(),
     );
-    if response.dragged_by(PointerButton::Primary) {
+    if response.dragged_by(PointerButton::Primary) && touch_gesture.is_none() {
         delta_x += response.drag_delta().x;
         ui.ctx().set_cursor_icon(CursorIcon::AllScroll);
     }
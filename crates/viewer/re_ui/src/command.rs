@@ -1,7 +1,10 @@
 use egui::{Key, KeyboardShortcut, Modifiers, os::OperatingSystem};
 use smallvec::{SmallVec, smallvec};
 
-use crate::context_ext::ContextExt as _;
+use crate::{
+    context_ext::ContextExt as _,
+    keymap::{Keymap, KeymapCommand},
+};
 
 /// Interface for sending [`UICommand`] messages.
 pub trait UICommandSender {
@@ -13,7 +16,11 @@ pub trait UICommandSender {
 /// Most are available in the GUI,
 /// some have keyboard shortcuts,
 /// and all are visible in the [`crate::CommandPalette`].
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::EnumIter)]
+///
+/// The keyboard shortcuts can be changed by the user, see [`crate::Keymap`].
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, strum_macros::EnumIter, strum_macros::IntoStaticStr,
+)]
 pub enum UICommand {
     // Listed in the order they show up in the command palette by default!
     Open,
@@ -24,6 +31,7 @@ pub enum UICommand {
     SaveRecording,
     SaveRecordingSelection,
     SaveBlueprint,
+    SaveViewScreenshot,
     CloseCurrentRecording,
     CloseAllEntries,
 
@@ -45,12 +53,16 @@ pub enum UICommand {
 
     TogglePanelStateOverrides,
     ToggleMemoryPanel,
+    #[cfg(feature = "scripting")]
+    ToggleScriptConsole,
     ToggleTopPanel,
     ToggleBlueprintPanel,
     ExpandBlueprintPanel,
     ToggleSelectionPanel,
     ToggleTimePanel,
     ToggleChunkStoreBrowser,
+    ToggleComparison,
+    ToggleRecordingDiff,
     Settings,
 
     #[cfg(debug_assertions)]
@@ -106,6 +118,11 @@ pub enum UICommand {
 }
 
 impl UICommand {
+    /// Identifies the command in the keymap file.
+    pub fn id(self) -> &'static str {
+        self.into()
+    }
+
     pub fn text(self) -> &'static str {
         self.text_and_tooltip().0
     }
@@ -114,7 +131,13 @@ impl UICommand {
         self.text_and_tooltip().1
     }
 
+    /// The text and tooltip of the command, translated with [`crate::tr`].
     pub fn text_and_tooltip(self) -> (&'static str, &'static str) {
+        let (text, tooltip) = self.english_text_and_tooltip();
+        (crate::tr(text), crate::tr(tooltip))
+    }
+
+    fn english_text_and_tooltip(self) -> (&'static str, &'static str) {
         match self {
             Self::SaveRecording => (
                 "Save recording…",
@@ -131,6 +154,11 @@ impl UICommand {
                 "Save the current viewer setup as a Rerun blueprint file (.rbl)",
             ),
 
+            Self::SaveViewScreenshot => (
+                "Save view screenshot…",
+                "Render the selected view at a chosen resolution and save it as a PNG",
+            ),
+
             Self::Open => (
                 "Open…",
                 "Open any supported files (.rrd, images, meshes, …) in a new recording",
@@ -198,6 +226,12 @@ impl UICommand {
                 "View and track current RAM usage inside Rerun Viewer",
             ),
 
+            #[cfg(feature = "scripting")]
+            Self::ToggleScriptConsole => (
+                "Toggle script console",
+                "Run Rhai scripts that query the recording and control the viewer",
+            ),
+
             Self::TogglePanelStateOverrides => (
                 "Toggle panel state overrides",
                 "Toggle panel state between app blueprint and overrides",
@@ -211,6 +245,14 @@ impl UICommand {
                 "Toggle chunk store browser",
                 "Toggle the chunk store browser",
             ),
+            Self::ToggleRecordingDiff => (
+                "Toggle recording diff",
+                "List the entities and components that differ from another recording, or from another point in time",
+            ),
+            Self::ToggleComparison => (
+                "Compare recordings",
+                "Show the other recordings of the same application side by side with the active one, following its time cursor",
+            ),
             Self::Settings => ("Settings…", "Show the settings screen"),
 
             #[cfg(debug_assertions)]
@@ -321,8 +363,10 @@ impl UICommand {
         }
     }
 
-    /// All keyboard shortcuts, with the primary first.
-    pub fn kb_shortcuts(self, os: OperatingSystem) -> SmallVec<[KeyboardShortcut; 2]> {
+    /// All keyboard shortcuts, with the primary first, unless the user rebound them.
+    ///
+    /// Use [`crate::Keymap::kb_shortcuts`] to get the ones actually in use.
+    pub fn default_kb_shortcuts(self, os: OperatingSystem) -> SmallVec<[KeyboardShortcut; 2]> {
         fn key(key: Key) -> KeyboardShortcut {
             KeyboardShortcut::new(Modifiers::NONE, key)
         }
@@ -355,6 +399,7 @@ impl UICommand {
             Self::SaveRecording => smallvec![cmd(Key::S)],
             Self::SaveRecordingSelection => smallvec![cmd_alt(Key::S)],
             Self::SaveBlueprint => smallvec![],
+            Self::SaveViewScreenshot => smallvec![],
             Self::Open => smallvec![cmd(Key::O)],
             // Some browsers have a "paste and go" action.
             // But unfortunately there's no standard shortcut for this.
@@ -389,6 +434,8 @@ impl UICommand {
             #[cfg(not(target_arch = "wasm32"))]
             Self::OpenProfiler => smallvec![ctrl_shift(Key::P)],
             Self::ToggleMemoryPanel => smallvec![ctrl_shift(Key::M)],
+            #[cfg(feature = "scripting")]
+            Self::ToggleScriptConsole => smallvec![],
             Self::TogglePanelStateOverrides => smallvec![],
             Self::ToggleTopPanel => smallvec![],
             Self::ToggleBlueprintPanel => smallvec![ctrl_shift(Key::B)],
@@ -396,6 +443,8 @@ impl UICommand {
             Self::ToggleSelectionPanel => smallvec![ctrl_shift(Key::S)],
             Self::ToggleTimePanel => smallvec![ctrl_shift(Key::T)],
             Self::ToggleChunkStoreBrowser => smallvec![ctrl_shift(Key::D)],
+            Self::ToggleComparison => smallvec![],
+            Self::ToggleRecordingDiff => smallvec![],
             Self::Settings => smallvec![cmd(Key::Comma)],
 
             #[cfg(debug_assertions)]
@@ -451,9 +500,12 @@ impl UICommand {
         }
     }
 
-    /// Primary keyboard shortcut
-    pub fn primary_kb_shortcut(self, os: OperatingSystem) -> Option<KeyboardShortcut> {
-        self.kb_shortcuts(os).first().copied()
+    /// Primary keyboard shortcut, taking the user's keymap into account.
+    pub fn primary_kb_shortcut(self, egui_ctx: &egui::Context) -> Option<KeyboardShortcut> {
+        Keymap::from_ctx(egui_ctx)
+            .kb_shortcuts(self, egui_ctx.os())
+            .first()
+            .copied()
     }
 
     /// Return the keyboard shortcut for this command, nicely formatted
@@ -461,7 +513,7 @@ impl UICommand {
     pub fn formatted_kb_shortcut(self, egui_ctx: &egui::Context) -> Option<String> {
         // Note: we only show the primary shortcut to the user.
         // The fallbacks are there for people who have muscle memory for the other shortcuts.
-        self.primary_kb_shortcut(egui_ctx.os())
+        self.primary_kb_shortcut(egui_ctx)
             .map(|shortcut| egui_ctx.format_shortcut(&shortcut))
     }
 
@@ -477,46 +529,16 @@ impl UICommand {
         matches!(self, Self::OpenWebHelp | Self::OpenRerunDiscord)
     }
 
+    /// Returns the command whose keyboard shortcut was pressed this frame, if any.
+    ///
+    /// Shortcuts of custom commands are consumed but ignored,
+    /// use [`Keymap::listen_for_kb_shortcut`] to also handle those.
     #[must_use = "Returns the Command that was triggered by some keyboard shortcut"]
     pub fn listen_for_kb_shortcut(egui_ctx: &egui::Context) -> Option<Self> {
-        use strum::IntoEnumIterator as _;
-
-        let anything_has_focus = egui_ctx.memory(|mem| mem.focused().is_some());
-        if anything_has_focus {
-            return None; // e.g. we're typing in a TextField
+        match Keymap::listen_for_kb_shortcut(egui_ctx)? {
+            KeymapCommand::Ui(command) => Some(command),
+            KeymapCommand::Custom(_) => None,
         }
-
-        let mut commands: Vec<(KeyboardShortcut, Self)> = Self::iter()
-            .flat_map(|cmd| {
-                cmd.kb_shortcuts(egui_ctx.os())
-                    .into_iter()
-                    .map(move |kb_shortcut| (kb_shortcut, cmd))
-            })
-            .collect();
-
-        // If the user pressed `Cmd-Shift-S` then egui will match that
-        // with both `Cmd-Shift-S` and `Cmd-S`.
-        // The reason is that `Shift` (and `Alt`) are sometimes required to produce certain keys,
-        // such as `+` (`Shift =` on an american keyboard).
-        // The result of this is that we must check for `Cmd-Shift-S` before `Cmd-S`, etc.
-        // So we order the commands here so that the commands with `Shift` and `Alt` in them
-        // are checked first.
-        commands.sort_by_key(|(kb_shortcut, _cmd)| {
-            let num_shift_alts =
-                kb_shortcut.modifiers.shift as i32 + kb_shortcut.modifiers.alt as i32;
-            -num_shift_alts // most first
-        });
-
-        egui_ctx.input_mut(|input| {
-            for (kb_shortcut, command) in commands {
-                if input.consume_shortcut(&kb_shortcut) {
-                    // Clear the shortcut key from input to prevent it from propagating to other UI component.
-                    input.keys_down.remove(&kb_shortcut.logical_key);
-                    return Some(command);
-                }
-            }
-            None
-        })
     }
 
     /// Show this command as a menu-button.
@@ -569,7 +591,7 @@ impl UICommand {
 
         let (label, details) = self.text_and_tooltip();
 
-        if let Some(shortcut) = self.primary_kb_shortcut(os) {
+        if let Some(shortcut) = self.primary_kb_shortcut(ui.ctx()) {
             crate::Help::new_without_title()
                 .control(label, crate::IconText::from_keyboard_shortcut(os, shortcut))
                 .ui(ui);
@@ -584,23 +606,7 @@ impl UICommand {
 
 #[test]
 fn check_for_clashing_command_shortcuts() {
-    fn clashes(a: KeyboardShortcut, b: KeyboardShortcut) -> bool {
-        if a.logical_key != b.logical_key {
-            return false;
-        }
-
-        if a.modifiers.alt != b.modifiers.alt {
-            return false;
-        }
-
-        if a.modifiers.shift != b.modifiers.shift {
-            return false;
-        }
-
-        // On Non-Mac, command is interpreted as ctrl!
-        (a.modifiers.command || a.modifiers.ctrl) == (b.modifiers.command || b.modifiers.ctrl)
-    }
-
+    use crate::keymap::shortcuts_clash as clashes;
     use strum::IntoEnumIterator as _;
 
     for os in [
@@ -609,12 +615,12 @@ fn check_for_clashing_command_shortcuts() {
         OperatingSystem::Nix,
     ] {
         for a_cmd in UICommand::iter() {
-            for a_shortcut in a_cmd.kb_shortcuts(os) {
+            for a_shortcut in a_cmd.default_kb_shortcuts(os) {
                 for b_cmd in UICommand::iter() {
                     if a_cmd == b_cmd {
                         continue;
                     }
-                    for b_shortcut in b_cmd.kb_shortcuts(os) {
+                    for b_shortcut in b_cmd.default_kb_shortcuts(os) {
                         assert!(
                             !clashes(a_shortcut, b_shortcut),
                             "Command '{a_cmd:?}' and '{b_cmd:?}' have overlapping keyboard shortcuts: {:?} vs {:?}",
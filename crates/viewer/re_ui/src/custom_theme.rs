@@ -0,0 +1,122 @@
+//! Changing the look of the UI, for apps that embed the viewer.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::DesignTokens;
+use crate::hot_reload_design_tokens::DesignTokensPerTheme;
+
+/// Changes the design tokens of one theme, e.g. the accent or panel colors.
+pub type CustomizeDesignTokens = Arc<dyn Fn(&mut DesignTokens) + Send + Sync>;
+
+/// Changes to the look of the UI, so that an embedded viewer can match the branding of the app around it.
+///
+/// Install it with [`set_custom_theme`]. It applies to the whole process.
+#[derive(Clone, Default)]
+pub struct CustomTheme {
+    /// Called on the tokens of both the dark and the light theme.
+    ///
+    /// Check [`DesignTokens::theme`] to only change one of them.
+    pub customize_design_tokens: Option<CustomizeDesignTokens>,
+
+    /// Replaces the fonts of the UI.
+    pub fonts: Option<egui::FontDefinitions>,
+
+    /// Always use this theme, instead of following the user's preference.
+    pub theme: Option<egui::Theme>,
+}
+
+struct InstalledTheme {
+    theme: CustomTheme,
+
+    /// The default tokens with [`CustomTheme::customize_design_tokens`] applied.
+    design_tokens: Option<&'static DesignTokensPerTheme>,
+}
+
+static CUSTOM_THEME: RwLock<Option<InstalledTheme>> = RwLock::new(None);
+
+/// Replaces the current [`CustomTheme`], and applies it to the given context.
+pub fn set_custom_theme(egui_ctx: &egui::Context, theme: CustomTheme) {
+    re_tracing::profile_function!();
+
+    let design_tokens = theme
+        .customize_design_tokens
+        .as_ref()
+        .and_then(|customize| customize_default_tokens(customize.as_ref()));
+
+    *CUSTOM_THEME.write() = Some(InstalledTheme {
+        theme,
+        design_tokens,
+    });
+
+    crate::apply_style_and_install_loaders(egui_ctx);
+}
+
+/// The installed [`CustomTheme`], if any.
+pub fn custom_theme() -> Option<CustomTheme> {
+    CUSTOM_THEME
+        .read()
+        .as_ref()
+        .map(|installed| installed.theme.clone())
+}
+
+/// Whether the installed [`CustomTheme`] forces dark or light mode.
+pub fn is_theme_forced() -> bool {
+    CUSTOM_THEME
+        .read()
+        .as_ref()
+        .is_some_and(|installed| installed.theme.theme.is_some())
+}
+
+pub(crate) fn customized_design_tokens() -> Option<&'static DesignTokensPerTheme> {
+    CUSTOM_THEME
+        .read()
+        .as_ref()
+        .and_then(|installed| installed.design_tokens)
+}
+
+/// Loads the default tokens again, e.g. after hot-reloading them, and customizes them.
+#[cfg(hot_reload_design_tokens)]
+pub(crate) fn reapply_customization() {
+    if let Some(installed) = CUSTOM_THEME.write().as_mut()
+        && let Some(customize) = &installed.theme.customize_design_tokens
+    {
+        installed.design_tokens = customize_default_tokens(customize.as_ref());
+    }
+}
+
+fn customize_default_tokens(
+    customize: &(dyn Fn(&mut DesignTokens) + Send + Sync),
+) -> Option<&'static DesignTokensPerTheme> {
+    let mut design_tokens = match DesignTokensPerTheme::load() {
+        Ok(design_tokens) => design_tokens,
+        Err(err) => {
+            re_log::error!("Failed to load design tokens: {err}");
+            return None;
+        }
+    };
+    customize(&mut design_tokens.dark);
+    customize(&mut design_tokens.light);
+
+    // Like the default tokens, these live for the rest of the process.
+    Some(Box::leak(Box::new(design_tokens)))
+}
+
+/// Sets the custom fonts and theme, if any. Returns whether the fonts were set.
+pub(crate) fn apply_fonts_and_theme(egui_ctx: &egui::Context) -> bool {
+    let Some(theme) = custom_theme() else {
+        return false;
+    };
+
+    if let Some(forced_theme) = theme.theme {
+        egui_ctx.options_mut(|o| o.theme_preference = forced_theme.into());
+    }
+
+    if let Some(fonts) = theme.fonts {
+        egui_ctx.set_fonts(fonts);
+        true
+    } else {
+        false
+    }
+}
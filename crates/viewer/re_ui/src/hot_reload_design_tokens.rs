@@ -1,13 +1,13 @@
 use crate::DesignTokens;
 
-struct DesignTokensPerTheme {
-    dark: DesignTokens,
-    light: DesignTokens,
+pub(crate) struct DesignTokensPerTheme {
+    pub(crate) dark: DesignTokens,
+    pub(crate) light: DesignTokens,
 }
 
 impl DesignTokensPerTheme {
     #[cfg(not(hot_reload_design_tokens))]
-    fn load() -> anyhow::Result<Self> {
+    pub(crate) fn load() -> anyhow::Result<Self> {
         Ok(Self {
             dark: DesignTokens::load(egui::Theme::Dark, include_str!("../data/dark_theme.ron"))?,
             light: DesignTokens::load(egui::Theme::Light, include_str!("../data/light_theme.ron"))?,
@@ -15,7 +15,7 @@ impl DesignTokensPerTheme {
     }
 
     #[cfg(hot_reload_design_tokens)]
-    fn load() -> anyhow::Result<Self> {
+    pub(crate) fn load() -> anyhow::Result<Self> {
         let data_path = std::fs::canonicalize(
             std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data"),
         )
@@ -153,9 +153,11 @@ mod design_token_access {
 }
 
 pub fn design_tokens_of(theme: egui::Theme) -> &'static DesignTokens {
+    let design_tokens = crate::custom_theme::customized_design_tokens()
+        .unwrap_or_else(design_token_access::design_tokens_per_theme);
     match theme {
-        egui::Theme::Dark => &design_token_access::design_tokens_per_theme().dark,
-        egui::Theme::Light => &design_token_access::design_tokens_per_theme().light,
+        egui::Theme::Dark => &design_tokens.dark,
+        egui::Theme::Light => &design_tokens.light,
     }
 }
 
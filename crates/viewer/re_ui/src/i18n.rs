@@ -0,0 +1,210 @@
+//! Translations of the text of the UI, for teams that don't work in English.
+//!
+//! UI text is translated with [`tr`] into the language picked with [`set_locale`].
+//! The English text is the key of its translations, so that the UI stays in English wherever
+//! a catalog has no translation.
+//!
+//! Apps embedding the viewer supply catalogs for more languages with [`add_catalog`].
+
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use ahash::HashMap;
+use parking_lot::RwLock;
+
+/// Translations of English UI text into one language.
+struct Catalog {
+    /// The name of the language, in that language, e.g. `Deutsch`.
+    name: String,
+
+    /// Leaked once per translation, so that [`tr`] can hand out `'static` text.
+    translations: HashMap<&'static str, &'static str>,
+}
+
+struct Catalogs {
+    /// Lowercase BCP 47 tag of the picked language, `None` for English.
+    locale: Option<String>,
+
+    /// Keyed by lowercase BCP 47 tag.
+    catalogs: BTreeMap<String, Catalog>,
+}
+
+static CATALOGS: LazyLock<RwLock<Catalogs>> = LazyLock::new(|| {
+    let mut catalogs = Catalogs {
+        locale: None,
+        catalogs: Default::default(),
+    };
+    catalogs.add("de", "Deutsch", GERMAN.iter().copied());
+    RwLock::new(catalogs)
+});
+
+impl Catalogs {
+    fn add<'a>(
+        &mut self,
+        locale: &str,
+        name: &str,
+        translations: impl Iterator<Item = (&'a str, &'a str)>,
+    ) {
+        let catalog = self
+            .catalogs
+            .entry(locale.to_lowercase())
+            .or_insert_with(|| Catalog {
+                name: String::new(),
+                translations: Default::default(),
+            });
+        catalog.name = name.to_owned();
+        for (english, translation) in translations {
+            catalog
+                .translations
+                .insert(leak(english), leak(translation));
+        }
+    }
+
+    /// The translation in the catalog of the locale, or else of its language, e.g. `pt` for `pt-br`.
+    fn translate(&self, locale: &str, english: &str) -> Option<&'static str> {
+        let translate = |locale: &str| {
+            self.catalogs
+                .get(locale)
+                .and_then(|catalog| catalog.translations.get(english).copied())
+        };
+        translate(locale).or_else(|| {
+            let (language, _region) = locale.split_once('-')?;
+            translate(language)
+        })
+    }
+}
+
+fn leak(text: &str) -> &'static str {
+    Box::leak(text.to_owned().into_boxed_str())
+}
+
+/// Adds translations of English UI text into a language, given by its BCP 47 tag like `de` or `pt-BR`.
+///
+/// `name` is the name of the language in that language, e.g. `Deutsch`, shown when picking it.
+/// Translations are added to those already in the catalog of the language, replacing those of the same text.
+pub fn add_catalog<'a>(
+    locale: &str,
+    name: &str,
+    translations: impl IntoIterator<Item = (&'a str, &'a str)>,
+) {
+    CATALOGS.write().add(locale, name, translations.into_iter());
+}
+
+/// Shows the UI in the language of the given BCP 47 tag, or in English if `None`.
+///
+/// Text without a translation in the catalog of the language falls back to the catalog
+/// of the language without its region, e.g. `pt` for `pt-BR`, and to English after that.
+pub fn set_locale(locale: Option<&str>) {
+    CATALOGS.write().locale = locale.map(str::to_lowercase);
+}
+
+/// The language the UI is shown in, see [`set_locale`].
+pub fn locale() -> Option<String> {
+    CATALOGS.read().locale.clone()
+}
+
+/// The languages with a catalog, as their BCP 47 tags and names, sorted by tag.
+pub fn available_locales() -> Vec<(String, String)> {
+    CATALOGS
+        .read()
+        .catalogs
+        .iter()
+        .map(|(locale, catalog)| (locale.clone(), catalog.name.clone()))
+        .collect()
+}
+
+/// Translates English UI text into the language picked with [`set_locale`].
+///
+/// Returns the English text if there's no translation.
+pub fn tr(english: &'static str) -> &'static str {
+    let catalogs = CATALOGS.read();
+    let Some(locale) = &catalogs.locale else {
+        return english;
+    };
+    catalogs.translate(locale, english).unwrap_or(english)
+}
+
+/// The built-in German catalog.
+const GERMAN: &[(&str, &str)] = &[
+    // Commands
+    ("Save recording…", "Aufnahme speichern…"),
+    (
+        "Save all data to a Rerun data file (.rrd)",
+        "Alle Daten in einer Rerun-Datei (.rrd) speichern",
+    ),
+    (
+        "Save current time selection…",
+        "Aktuelle Zeitauswahl speichern…",
+    ),
+    ("Save blueprint…", "Blueprint speichern…"),
+    ("Save view screenshot…", "Screenshot der Ansicht speichern…"),
+    ("Open…", "Öffnen…"),
+    ("Open from URL…", "Von URL öffnen…"),
+    (
+        "Import into current recording…",
+        "In aktuelle Aufnahme importieren…",
+    ),
+    ("Close current recording", "Aktuelle Aufnahme schließen"),
+    ("Close all recordings", "Alle Aufnahmen schließen"),
+    ("Undo", "Rückgängig"),
+    ("Redo", "Wiederholen"),
+    ("Quit", "Beenden"),
+    ("Close the Rerun Viewer", "Den Rerun Viewer schließen"),
+    ("Help", "Hilfe"),
+    ("Reset Viewer", "Viewer zurücksetzen"),
+    ("Toggle memory panel", "Speicheranzeige ein/aus"),
+    ("Toggle top panel", "Obere Leiste ein/aus"),
+    ("Toggle blueprint panel", "Blueprint-Leiste ein/aus"),
+    ("Toggle selection panel", "Auswahl-Leiste ein/aus"),
+    ("Toggle time panel", "Zeitleiste ein/aus"),
+    ("Settings…", "Einstellungen…"),
+    ("Show the settings screen", "Die Einstellungen anzeigen"),
+    ("Toggle fullscreen", "Vollbild ein/aus"),
+    ("Zoom in", "Vergrößern"),
+    ("Zoom out", "Verkleinern"),
+    ("Reset zoom", "Zoom zurücksetzen"),
+    ("Command palette…", "Befehlspalette…"),
+    ("Toggle play/pause", "Wiedergabe/Pause"),
+    ("Follow", "Folgen"),
+    ("Step backwards", "Schritt zurück"),
+    ("Step forwards", "Schritt vor"),
+    ("Restart", "Neu starten"),
+    // Menus
+    ("About", "Über"),
+    // Settings
+    ("Settings", "Einstellungen"),
+    ("Close", "Schließen"),
+    ("General", "Allgemein"),
+    ("Language:", "Sprache:"),
+    ("Theme:", "Design:"),
+    ("Show performance metrics", "Leistungsmetriken anzeigen"),
+    ("Timezone", "Zeitzone"),
+    ("Local", "Lokal"),
+    ("Map view", "Kartenansicht"),
+    ("Keyboard shortcuts", "Tastenkürzel"),
+    ("Experimental features", "Experimentelle Funktionen"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_language_and_english() {
+        let mut catalogs = Catalogs {
+            locale: None,
+            catalogs: Default::default(),
+        };
+        catalogs.add("pt", "Português", [("Undo", "Desfazer")].into_iter());
+        catalogs.add(
+            "pt-BR",
+            "Português (Brasil)",
+            [("Redo", "Refazer")].into_iter(),
+        );
+
+        assert_eq!(catalogs.translate("pt-br", "Redo"), Some("Refazer"));
+        assert_eq!(catalogs.translate("pt-br", "Undo"), Some("Desfazer"));
+        assert_eq!(catalogs.translate("pt-pt", "Undo"), Some("Desfazer"));
+        assert_eq!(catalogs.translate("pt", "Redo"), None);
+        assert_eq!(catalogs.translate("fr", "Undo"), None);
+    }
+}
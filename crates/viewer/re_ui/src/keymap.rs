@@ -0,0 +1,393 @@
+//! User-remappable keyboard shortcuts.
+//!
+//! The [`Keymap`] holds the bindings the user changed from the defaults in [`UICommand::default_kb_shortcuts`],
+//! and the commands registered by applications embedding the viewer.
+//! It lives in the egui context, so all UI that shows or listens for shortcuts agrees on them.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use egui::{Key, KeyboardShortcut, Modifiers, os::OperatingSystem};
+use smallvec::SmallVec;
+
+use crate::UICommand;
+
+/// A command registered by an application embedding the viewer.
+#[derive(Clone, Debug)]
+pub struct CustomCommand {
+    /// Identifies the command in the keymap file, e.g. `my_robot.emergency_stop`.
+    ///
+    /// Must not collide with the name of a [`UICommand`] variant.
+    pub id: String,
+
+    /// Shown in the settings.
+    pub text: String,
+
+    /// Used unless the user rebinds the command.
+    pub default_kb_shortcuts: Vec<KeyboardShortcut>,
+}
+
+/// A command triggered by a keyboard shortcut.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeymapCommand {
+    Ui(UICommand),
+
+    /// The id of a [`CustomCommand`].
+    Custom(String),
+}
+
+/// The keyboard shortcuts of all commands.
+#[derive(Clone, Debug, Default)]
+pub struct Keymap {
+    /// Bindings changed by the user, by command id.
+    ///
+    /// An empty list means the user removed all shortcuts of the command.
+    user_bindings: BTreeMap<String, Vec<KeyboardShortcut>>,
+
+    custom_commands: Vec<CustomCommand>,
+}
+
+impl Keymap {
+    fn ctx_id() -> egui::Id {
+        egui::Id::new("re_ui::Keymap")
+    }
+
+    /// The keymap used by the given egui context.
+    pub fn from_ctx(egui_ctx: &egui::Context) -> Arc<Self> {
+        egui_ctx
+            .data(|data| data.get_temp::<Arc<Self>>(Self::ctx_id()))
+            .unwrap_or_default()
+    }
+
+    /// Changes the keymap used by the given egui context.
+    pub fn update_ctx(egui_ctx: &egui::Context, update: impl FnOnce(&mut Self)) {
+        let mut keymap = Arc::unwrap_or_clone(Self::from_ctx(egui_ctx));
+        update(&mut keymap);
+        egui_ctx.data_mut(|data| data.insert_temp(Self::ctx_id(), Arc::new(keymap)));
+    }
+
+    /// Registers a command of the embedding application, replacing any with the same id.
+    pub fn register_command(&mut self, command: CustomCommand) {
+        self.custom_commands
+            .retain(|existing| existing.id != command.id);
+        self.custom_commands.push(command);
+    }
+
+    pub fn custom_commands(&self) -> &[CustomCommand] {
+        &self.custom_commands
+    }
+
+    /// All keyboard shortcuts of a command, with the primary first.
+    pub fn kb_shortcuts(
+        &self,
+        command: UICommand,
+        os: OperatingSystem,
+    ) -> SmallVec<[KeyboardShortcut; 2]> {
+        match self.user_bindings.get(command.id()) {
+            Some(shortcuts) => shortcuts.iter().copied().collect(),
+            None => command.default_kb_shortcuts(os),
+        }
+    }
+
+    /// All keyboard shortcuts of a custom command, with the primary first.
+    pub fn custom_kb_shortcuts(&self, command: &CustomCommand) -> Vec<KeyboardShortcut> {
+        self.user_bindings
+            .get(&command.id)
+            .unwrap_or(&command.default_kb_shortcuts)
+            .clone()
+    }
+
+    /// Replaces the shortcuts of the command with the given id.
+    pub fn rebind(&mut self, id: impl Into<String>, shortcuts: Vec<KeyboardShortcut>) {
+        self.user_bindings.insert(id.into(), shortcuts);
+    }
+
+    /// Restores the default shortcuts of the command with the given id.
+    pub fn reset(&mut self, id: &str) {
+        self.user_bindings.remove(id);
+    }
+
+    pub fn reset_all(&mut self) {
+        self.user_bindings.clear();
+    }
+
+    /// Has the user changed the shortcuts of the command with the given id?
+    pub fn is_rebound(&self, id: &str) -> bool {
+        self.user_bindings.contains_key(id)
+    }
+
+    /// All bindings, in the order they should be checked.
+    fn bindings(&self, os: OperatingSystem) -> Vec<(KeyboardShortcut, KeymapCommand)> {
+        use strum::IntoEnumIterator as _;
+
+        let mut bindings: Vec<(KeyboardShortcut, KeymapCommand)> = UICommand::iter()
+            .flat_map(|command| {
+                self.kb_shortcuts(command, os)
+                    .into_iter()
+                    .map(move |shortcut| (shortcut, KeymapCommand::Ui(command)))
+            })
+            .chain(self.custom_commands.iter().flat_map(|command| {
+                self.custom_kb_shortcuts(command)
+                    .into_iter()
+                    .map(|shortcut| (shortcut, KeymapCommand::Custom(command.id.clone())))
+            }))
+            .collect();
+
+        // If the user pressed `Cmd-Shift-S` then egui will match that
+        // with both `Cmd-Shift-S` and `Cmd-S`.
+        // The reason is that `Shift` (and `Alt`) are sometimes required to produce certain keys,
+        // such as `+` (`Shift =` on an american keyboard).
+        // The result of this is that we must check for `Cmd-Shift-S` before `Cmd-S`, etc.
+        // So we order the commands here so that the commands with `Shift` and `Alt` in them
+        // are checked first.
+        bindings.sort_by_key(|(shortcut, _command)| {
+            let num_shift_alts = shortcut.modifiers.shift as i32 + shortcut.modifiers.alt as i32;
+            -num_shift_alts // most first
+        });
+
+        bindings
+    }
+
+    /// The commands other than `id` that are bound to the given shortcut.
+    pub fn conflicts(
+        &self,
+        id: &str,
+        shortcut: KeyboardShortcut,
+        os: OperatingSystem,
+    ) -> Vec<KeymapCommand> {
+        self.bindings(os)
+            .into_iter()
+            .filter(|(other, command)| {
+                let other_id = match command {
+                    KeymapCommand::Ui(command) => command.id(),
+                    KeymapCommand::Custom(other_id) => other_id.as_str(),
+                };
+                other_id != id && shortcuts_clash(shortcut, *other)
+            })
+            .map(|(_, command)| command)
+            .collect()
+    }
+
+    /// Returns the command whose keyboard shortcut was pressed this frame, if any.
+    #[must_use = "Returns the command that was triggered by some keyboard shortcut"]
+    pub fn listen_for_kb_shortcut(egui_ctx: &egui::Context) -> Option<KeymapCommand> {
+        let anything_has_focus = egui_ctx.memory(|mem| mem.focused().is_some());
+        if anything_has_focus {
+            return None; // e.g. we're typing in a TextField
+        }
+
+        let bindings = Self::from_ctx(egui_ctx).bindings(egui_ctx.os());
+
+        egui_ctx.input_mut(|input| {
+            for (shortcut, command) in bindings {
+                if input.consume_shortcut(&shortcut) {
+                    // Clear the shortcut key from input to prevent it from propagating to other UI component.
+                    input.keys_down.remove(&shortcut.logical_key);
+                    return Some(command);
+                }
+            }
+            None
+        })
+    }
+
+    /// The user bindings, in the format of the keymap file.
+    pub fn to_ron(&self) -> anyhow::Result<String> {
+        let bindings: BTreeMap<&str, Vec<String>> = self
+            .user_bindings
+            .iter()
+            .map(|(id, shortcuts)| {
+                (
+                    id.as_str(),
+                    shortcuts.iter().copied().map(format_shortcut).collect(),
+                )
+            })
+            .collect();
+        Ok(ron::ser::to_string_pretty(
+            &bindings,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    /// Replaces the user bindings with the ones in a keymap file.
+    ///
+    /// Shortcuts that can't be parsed are skipped with a warning.
+    pub fn load_ron(&mut self, ron: &str) -> anyhow::Result<()> {
+        let bindings: BTreeMap<String, Vec<String>> = ron::from_str(ron)?;
+        self.user_bindings = bindings
+            .into_iter()
+            .map(|(id, shortcuts)| {
+                let shortcuts = shortcuts
+                    .iter()
+                    .filter_map(|shortcut| {
+                        let parsed = parse_shortcut(shortcut);
+                        if parsed.is_none() {
+                            re_log::warn!(
+                                "Ignoring invalid keyboard shortcut {shortcut:?} for {id}"
+                            );
+                        }
+                        parsed
+                    })
+                    .collect();
+                (id, shortcuts)
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Where the user bindings are stored.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn default_path() -> Option<std::path::PathBuf> {
+        directories::ProjectDirs::from("io", "rerun", "Rerun")
+            .map(|dirs| dirs.config_dir().join("keymap.ron"))
+    }
+
+    /// Loads the user bindings from [`Self::default_path`], if the file exists.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_user_bindings(&mut self) -> anyhow::Result<()> {
+        let Some(path) = Self::default_path() else {
+            return Ok(());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(ron) => self.load_ron(&ron),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(anyhow::anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    /// Saves the user bindings to [`Self::default_path`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_user_bindings(&self) -> anyhow::Result<()> {
+        let Some(path) = Self::default_path() else {
+            anyhow::bail!("Could not determine the config directory");
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, self.to_ron()?)?;
+        Ok(())
+    }
+}
+
+/// Would both shortcuts be triggered by the same key press?
+pub fn shortcuts_clash(a: KeyboardShortcut, b: KeyboardShortcut) -> bool {
+    if a.logical_key != b.logical_key {
+        return false;
+    }
+
+    if a.modifiers.alt != b.modifiers.alt {
+        return false;
+    }
+
+    if a.modifiers.shift != b.modifiers.shift {
+        return false;
+    }
+
+    // On Non-Mac, command is interpreted as ctrl!
+    (a.modifiers.command || a.modifiers.ctrl) == (b.modifiers.command || b.modifiers.ctrl)
+}
+
+/// Formats a shortcut for the keymap file, e.g. `Cmd+Shift+S`.
+///
+/// Unlike [`egui::Context::format_shortcut`], this is the same on all platforms.
+pub fn format_shortcut(shortcut: KeyboardShortcut) -> String {
+    let Modifiers {
+        alt,
+        ctrl,
+        shift,
+        mac_cmd,
+        command,
+    } = shortcut.modifiers;
+
+    let mut text = String::new();
+    for (is_down, name) in [
+        (command, "Cmd"),
+        (mac_cmd, "MacCmd"),
+        (ctrl, "Ctrl"),
+        (alt, "Alt"),
+        (shift, "Shift"),
+    ] {
+        if is_down {
+            text.push_str(name);
+            text.push('+');
+        }
+    }
+    text.push_str(shortcut.logical_key.name());
+    text
+}
+
+/// Parses a shortcut written by [`format_shortcut`].
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let mut parts = text.split('+').map(str::trim);
+    let key = Key::from_name(parts.next_back()?)?;
+
+    let mut modifiers = Modifiers::NONE;
+    for part in parts {
+        match part {
+            "Cmd" => modifiers.command = true,
+            "MacCmd" => modifiers.mac_cmd = true,
+            "Ctrl" => modifiers.ctrl = true,
+            "Alt" => modifiers.alt = true,
+            "Shift" => modifiers.shift = true,
+            _ => return None,
+        }
+    }
+
+    Some(KeyboardShortcut::new(modifiers, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcut_roundtrip() {
+        for shortcut in [
+            KeyboardShortcut::new(Modifiers::NONE, Key::Space),
+            KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S),
+            KeyboardShortcut::new(Modifiers::CTRL | Modifiers::ALT, Key::ArrowLeft),
+            KeyboardShortcut::new(Modifiers::COMMAND, Key::Plus),
+        ] {
+            assert_eq!(parse_shortcut(&format_shortcut(shortcut)), Some(shortcut));
+        }
+
+        assert_eq!(parse_shortcut("Hyper+S"), None);
+        assert_eq!(parse_shortcut("Cmd+NotAKey"), None);
+    }
+
+    #[test]
+    fn test_rebind_and_persist() {
+        let os = OperatingSystem::Nix;
+        let play_pause = UICommand::PlaybackTogglePlayPause;
+        let shortcut = KeyboardShortcut::new(Modifiers::NONE, Key::K);
+
+        let mut keymap = Keymap::default();
+        keymap.register_command(CustomCommand {
+            id: "robot.stop".to_owned(),
+            text: "Stop robot".to_owned(),
+            default_kb_shortcuts: vec![KeyboardShortcut::new(Modifiers::NONE, Key::F9)],
+        });
+
+        keymap.rebind(play_pause.id(), vec![shortcut]);
+        keymap.rebind("robot.stop", vec![]);
+        assert_eq!(keymap.kb_shortcuts(play_pause, os).as_slice(), &[shortcut]);
+        assert!(
+            keymap
+                .custom_kb_shortcuts(&keymap.custom_commands()[0])
+                .is_empty()
+        );
+        assert_eq!(
+            keymap.conflicts("robot.stop", shortcut, os),
+            vec![KeymapCommand::Ui(play_pause)]
+        );
+
+        let mut loaded = Keymap::default();
+        loaded.load_ron(&keymap.to_ron().unwrap()).unwrap();
+        assert_eq!(loaded.kb_shortcuts(play_pause, os).as_slice(), &[shortcut]);
+        assert!(loaded.is_rebound("robot.stop"));
+
+        loaded.reset(play_pause.id());
+        assert_eq!(
+            loaded.kb_shortcuts(play_pause, os),
+            play_pause.default_kb_shortcuts(os)
+        );
+    }
+}
@@ -7,13 +7,16 @@ mod color_table;
 mod command;
 mod command_palette;
 mod context_ext;
+mod custom_theme;
 mod design_tokens;
 pub mod drag_and_drop;
 pub mod filter_widget;
 mod help;
 mod hot_reload_design_tokens;
+pub mod i18n;
 mod icon_text;
 pub mod icons;
+mod keymap;
 pub mod list_item;
 mod markdown_utils;
 pub mod modal;
@@ -21,6 +24,7 @@ pub mod notifications;
 mod section_collapsing_header;
 pub mod syntax_highlighting;
 mod time_drag_value;
+pub mod touch;
 mod ui_ext;
 mod ui_layout;
 
@@ -30,11 +34,16 @@ pub use self::{
     command::{UICommand, UICommandSender},
     command_palette::{CommandPalette, CommandPaletteAction, CommandPaletteUrl},
     context_ext::ContextExt,
+    custom_theme::{
+        CustomTheme, CustomizeDesignTokens, custom_theme, is_theme_forced, set_custom_theme,
+    },
     design_tokens::{DesignTokens, TableStyle},
     help::*,
     hot_reload_design_tokens::design_tokens_of,
+    i18n::tr,
     icon_text::*,
     icons::Icon,
+    keymap::{CustomCommand, Keymap, KeymapCommand, format_shortcut, parse_shortcut},
     markdown_utils::*,
     section_collapsing_header::SectionCollapsingHeader,
     syntax_highlighting::SyntaxHighlighting,
@@ -126,6 +135,7 @@ pub fn apply_style_and_install_loaders(egui_ctx: &egui::Context) {
         hot_reload_design_tokens::install_hot_reload(move || {
             re_log::debug!("Hot-reloading design tokens…");
             hot_reload_design_tokens::hot_reload_design_tokens();
+            custom_theme::reapply_customization();
             set_themes(&egui_ctx);
             egui_ctx.request_repaint();
         });
@@ -133,8 +143,10 @@ pub fn apply_style_and_install_loaders(egui_ctx: &egui::Context) {
 }
 
 fn set_themes(egui_ctx: &egui::Context) {
-    // It's the same fonts in dark/light mode:
-    design_tokens_of(egui::Theme::Dark).set_fonts(egui_ctx);
+    if !custom_theme::apply_fonts_and_theme(egui_ctx) {
+        // It's the same fonts in dark/light mode:
+        design_tokens_of(egui::Theme::Dark).set_fonts(egui_ctx);
+    }
 
     for theme in [egui::Theme::Dark, egui::Theme::Light] {
         let mut style = std::sync::Arc::unwrap_or_clone(egui_ctx.style_of(theme));
@@ -38,7 +38,7 @@ impl From<re_log::Level> for NotificationLevel {
 
 fn is_relevant(target: &str, level: re_log::Level) -> bool {
     let is_rerun_crate = target.starts_with("rerun") || target.starts_with("re_");
-    if !is_rerun_crate {
+    if !is_rerun_crate && target != re_log::NOTIFICATION_TARGET {
         return false;
     }
 
@@ -111,6 +111,20 @@ impl NotificationUi {
         self.push(NotificationLevel::Success, text.into());
     }
 
+    /// Shows a notification, and a toast for it.
+    ///
+    /// A notification identical to one that is still unread is ignored,
+    /// so code running every frame doesn't flood the panel.
+    pub fn add(&mut self, level: NotificationLevel, text: impl Into<String>) {
+        let text = text.into();
+        let is_duplicate = self.notifications.iter().any(|notification| {
+            notification.is_unread && notification.level == level && notification.text == text
+        });
+        if !is_duplicate {
+            self.push(level, text);
+        }
+    }
+
     fn push(&mut self, level: NotificationLevel, text: String) {
         self.notifications.push(Notification {
             level,
@@ -405,3 +419,25 @@ fn log_level_icon(ui: &mut egui::Ui, level: NotificationLevel) {
     ui.painter()
         .circle_filled(rect.center() + egui::vec2(0.0, 2.0), 5.0, color);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unread_duplicates_are_ignored() {
+        let mut notifications = NotificationUi::new();
+        notifications.add(NotificationLevel::Warning, "Dropped 1200 frames");
+        notifications.add(NotificationLevel::Warning, "Dropped 1200 frames");
+        notifications.add(NotificationLevel::Error, "Dropped 1200 frames");
+        assert_eq!(notifications.notifications.len(), 2);
+
+        notifications.notifications[0].is_unread = false;
+        notifications.add(NotificationLevel::Warning, "Dropped 1200 frames");
+        assert_eq!(notifications.notifications.len(), 3);
+        assert_eq!(
+            notifications.unread_notification_level(),
+            Some(NotificationLevel::Error)
+        );
+    }
+}
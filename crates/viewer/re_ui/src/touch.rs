@@ -0,0 +1,26 @@
+//! Helpers for touch screens.
+//!
+//! egui already turns the first finger into the pointer, pinching into zooming
+//! and long presses into secondary clicks. Gestures of several fingers, like dragging
+//! two fingers to pan, are up to each widget, using [`multi_finger_gesture`].
+//!
+//! Pens get no dedicated handling: the platform reports a hovering pen as a mouse pointer,
+//! and a pen touching the screen as a finger. In particular, pen pressure is ignored,
+//! and a palm resting on the screen while using a pen counts as a second finger.
+
+/// The gesture of two or more fingers that started on the widget, if any.
+pub fn multi_finger_gesture(response: &egui::Response) -> Option<egui::MultiTouchInfo> {
+    let gesture = response.ctx.input(|i| i.multi_touch())?;
+    response
+        .interact_rect
+        .contains(gesture.start_pos)
+        .then_some(gesture)
+}
+
+/// Whether fingers are on the screen.
+///
+/// Hover effects like tooltips are for the pointer of a mouse or a hovering pen,
+/// and only get in the way of fingers, or of a pen touching the screen.
+pub fn is_touching(ctx: &egui::Context) -> bool {
+    ctx.input(|i| i.any_touches())
+}
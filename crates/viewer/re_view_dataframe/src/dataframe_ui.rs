@@ -10,7 +10,9 @@ use re_chunk_store::{ColumnDescriptor, LatestAtQuery};
 use re_dataframe::QueryHandle;
 use re_dataframe::external::re_query::StorageEngineArcReadGuard;
 use re_dataframe_ui::table_utils::{apply_table_style_fixes, cell_ui, header_ui};
-use re_dataframe_ui::{ColumnBlueprint, DisplayRecordBatch, DisplayRecordBatchError};
+use re_dataframe_ui::{
+    ColumnBlueprint, DisplayRecordBatch, DisplayRecordBatchError, SortDirection,
+};
 use re_log_types::{EntityPath, TimeInt, TimelineName};
 use re_types::ComponentDescriptor;
 use re_types::reflection::ComponentDescriptorExt as _;
@@ -18,6 +20,7 @@ use re_ui::UiExt as _;
 use re_viewer_context::{SystemCommandSender as _, ViewId, ViewerContext};
 
 use crate::expanded_rows::{ExpandedRows, ExpandedRowsCache};
+use crate::sort_and_filter::{SortAndFilter, to_csv};
 
 /// Ui actions triggered by the dataframe UI to be handled by the calling code.
 pub(crate) enum HideColumnAction {
@@ -39,10 +42,14 @@ pub(crate) fn dataframe_ui(
     ui: &mut egui::Ui,
     query_handle: &re_dataframe::QueryHandle<StorageEngineArcReadGuard>,
     expanded_rows_cache: &mut ExpandedRowsCache,
+    sort_and_filter: &mut SortAndFilter,
+    row_order: Option<&[u64]>,
     view_id: &ViewId,
 ) -> Vec<HideColumnAction> {
     re_tracing::profile_function!();
 
+    toolbar_ui(ctx, ui, query_handle, sort_and_filter, row_order, view_id);
+
     let tokens = ui.tokens();
     let table_style = re_ui::TableStyle::Dense;
 
@@ -63,16 +70,19 @@ pub(crate) fn dataframe_ui(
     let row_expansion_id_salt = egui::Id::new("__dataframe_row_exp__")
         .with(view_id)
         .with(&selected_columns)
-        .with(query_handle.query());
+        .with(query_handle.query())
+        .with(&*sort_and_filter);
 
     let (header_groups, header_entity_paths) = column_groups_for_entity(&selected_columns);
 
-    let num_rows = query_handle.num_rows();
+    let num_rows = row_order.map_or_else(|| query_handle.num_rows(), |rows| rows.len() as u64);
 
     let mut table_delegate = DataframeTableDelegate {
         ctx,
         table_style,
         query_handle,
+        row_order,
+        sort_and_filter,
         selected_columns: &selected_columns,
         header_entity_paths,
         num_rows,
@@ -123,6 +133,63 @@ pub(crate) fn dataframe_ui(
     table_delegate.hide_column_actions
 }
 
+/// The row filter and the CSV export, above the table.
+fn toolbar_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    sort_and_filter: &mut SortAndFilter,
+    row_order: Option<&[u64]>,
+    view_id: &ViewId,
+) {
+    egui::Frame::new()
+        .inner_margin(egui::Margin::symmetric(4, 2))
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut sort_and_filter.filter)
+                        .id_salt(("dataframe_filter", view_id))
+                        .hint_text("Filter rows")
+                        .desired_width(200.0),
+                )
+                .on_hover_text("Only show rows with a value containing this text");
+
+                if let Some(row_order) = row_order {
+                    ui.weak(format!(
+                        "{} of {} rows",
+                        re_format::format_uint(row_order.len()),
+                        re_format::format_uint(query_handle.num_rows())
+                    ));
+                }
+
+                if sort_and_filter.sort_by.is_some()
+                    && ui
+                        .small_icon_button(&re_ui::icons::CLOSE, "Clear sorting")
+                        .on_hover_text("Show the rows in time order")
+                        .clicked()
+                {
+                    sort_and_filter.sort_by = None;
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .button("Export CSV…")
+                        .on_hover_text("Save the rows and columns shown in the table as CSV")
+                        .clicked()
+                    {
+                        let csv = to_csv(query_handle, row_order);
+                        ctx.command_sender().save_file_dialog(
+                            re_capabilities::MainThreadToken::from_egui_ui(ui),
+                            "dataframe.csv",
+                            "Export CSV".to_owned(),
+                            csv.into_bytes(),
+                        );
+                    }
+                });
+            });
+        });
+}
+
 #[derive(Debug, Clone, Copy)]
 struct BatchRef {
     /// Which batch?
@@ -204,6 +271,11 @@ struct DataframeTableDelegate<'a> {
     ctx: &'a ViewerContext<'a>,
     table_style: re_ui::TableStyle,
     query_handle: &'a QueryHandle<StorageEngineArcReadGuard>,
+
+    /// The query rows to show, in order, if sorted or filtered.
+    row_order: Option<&'a [u64]>,
+    sort_and_filter: &'a mut SortAndFilter,
+
     selected_columns: &'a [ColumnDescriptor],
     header_entity_paths: Vec<Option<EntityPath>>,
     display_data: anyhow::Result<RowsDisplayData>,
@@ -229,11 +301,22 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
             .filtered_index
             .unwrap_or_else(|| TimelineName::new(""));
 
-        self.query_handle
-            .seek_to_row(info.visible_rows.start as usize);
-        let data = std::iter::from_fn(|| self.query_handle.next_row())
-            .take((info.visible_rows.end - info.visible_rows.start) as usize)
-            .collect();
+        let data = if let Some(row_order) = self.row_order {
+            info.visible_rows
+                .clone()
+                .filter_map(|row_nr| {
+                    self.query_handle
+                        .seek_to_row(row_order[row_nr as usize] as usize);
+                    self.query_handle.next_row()
+                })
+                .collect()
+        } else {
+            self.query_handle
+                .seek_to_row(info.visible_rows.start as usize);
+            std::iter::from_fn(|| self.query_handle.next_row())
+                .take((info.visible_rows.end - info.visible_rows.start) as usize)
+                .collect()
+        };
 
         let data = RowsDisplayData::try_new(
             &info.visible_rows,
@@ -356,6 +439,8 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                         text = text.strong();
                     }
 
+                    let current_sort_direction = self.sort_and_filter.sort_direction(column);
+
                     let response = ui
                         .vertical(|ui| {
                             ui.spacing_mut().item_spacing.y = 2.0;
@@ -364,10 +449,42 @@ impl egui_table::TableDelegate for DataframeTableDelegate<'_> {
                                     .size(10.0)
                                     .color(ui.tokens().text_subdued),
                             );
-                            ui.selectable_label(is_selected, text)
+                            ui.horizontal(|ui| {
+                                let response = ui.selectable_label(is_selected, text);
+                                if let Some(sort_direction) = current_sort_direction {
+                                    ui.small_icon(
+                                        sort_direction.icon(),
+                                        Some(ui.tokens().table_sort_icon_color),
+                                    );
+                                }
+                                response
+                            })
+                            .inner
                         })
                         .inner;
 
+                    response.context_menu(|ui| {
+                        for sort_direction in SortDirection::iter() {
+                            let already_sorted = Some(sort_direction) == current_sort_direction;
+                            if ui
+                                .add_enabled_ui(!already_sorted, |ui| {
+                                    sort_direction.menu_item_ui(ui)
+                                })
+                                .inner
+                                .clicked()
+                            {
+                                self.sort_and_filter.sort_by =
+                                    Some((column.clone(), sort_direction));
+                                ui.close();
+                            }
+                        }
+                        if current_sort_direction.is_some() && ui.button("Clear sorting").clicked()
+                        {
+                            self.sort_and_filter.sort_by = None;
+                            ui.close();
+                        }
+                    });
+
                     match column {
                         ColumnDescriptor::RowId(_) => {}
                         ColumnDescriptor::Time(descr) => {
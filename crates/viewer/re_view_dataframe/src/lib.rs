@@ -5,6 +5,7 @@
 mod dataframe_ui;
 
 mod expanded_rows;
+mod sort_and_filter;
 mod view_class;
 mod view_query;
 mod visualizer_system;
@@ -0,0 +1,235 @@
+//! Sorting and filtering the rows of the dataframe view, and exporting them as CSV.
+
+use std::fmt::Write as _;
+
+use arrow::array::{Array as _, ArrayRef};
+use arrow::compute::SortOptions;
+use arrow::row::{RowConverter, SortField};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use itertools::Itertools as _;
+
+use re_chunk_store::{ChunkStoreGeneration, ColumnDescriptor, QueryExpression};
+use re_dataframe::QueryHandle;
+use re_dataframe::external::re_query::StorageEngineArcReadGuard;
+use re_dataframe_ui::SortDirection;
+
+/// How the user sorted and filtered the rows of a dataframe view.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct SortAndFilter {
+    /// The column to sort by. The rows are in query order if `None`.
+    pub sort_by: Option<(ColumnDescriptor, SortDirection)>,
+
+    /// Only rows with a cell containing this text are shown, ignoring case.
+    pub filter: String,
+}
+
+impl SortAndFilter {
+    fn is_active(&self) -> bool {
+        self.sort_by.is_some() || !self.filter.trim().is_empty()
+    }
+
+    pub fn sort_direction(&self, column: &ColumnDescriptor) -> Option<SortDirection> {
+        self.sort_by
+            .as_ref()
+            .and_then(|(sort_column, direction)| (sort_column == column).then_some(*direction))
+    }
+}
+
+/// What the row order was computed from, to only recompute it when something changed.
+#[derive(PartialEq)]
+struct RowOrderKey {
+    query: QueryExpression,
+    generation: ChunkStoreGeneration,
+    sort_and_filter: SortAndFilter,
+}
+
+/// The rows left after sorting and filtering.
+#[derive(Default)]
+pub(crate) struct RowOrderCache {
+    cached: Option<(RowOrderKey, Vec<u64>)>,
+}
+
+impl RowOrderCache {
+    /// The indices of the query rows to show, in order.
+    ///
+    /// `None` if all rows are shown in query order.
+    pub fn row_order(
+        &mut self,
+        query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+        generation: ChunkStoreGeneration,
+        sort_and_filter: &SortAndFilter,
+    ) -> Option<&[u64]> {
+        if !sort_and_filter.is_active() {
+            self.cached = None;
+            return None;
+        }
+
+        let key = RowOrderKey {
+            query: query_handle.query().clone(),
+            generation,
+            sort_and_filter: sort_and_filter.clone(),
+        };
+
+        if self
+            .cached
+            .as_ref()
+            .is_none_or(|(old_key, _)| old_key != &key)
+        {
+            let order = compute_row_order(query_handle, sort_and_filter).unwrap_or_else(|err| {
+                re_log::warn!("Failed to sort the rows of the dataframe: {err}");
+                (0..query_handle.num_rows()).collect()
+            });
+            self.cached = Some((key, order));
+        }
+
+        self.cached.as_ref().map(|(_, order)| order.as_slice())
+    }
+}
+
+fn compute_row_order(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    sort_and_filter: &SortAndFilter,
+) -> anyhow::Result<Vec<u64>> {
+    re_tracing::profile_function!();
+
+    let sort_by = sort_and_filter
+        .sort_by
+        .as_ref()
+        .and_then(|(column, direction)| {
+            let index = query_handle
+                .selected_contents()
+                .iter()
+                .position(|(_, selected)| selected == column)?;
+            Some((index, *direction))
+        });
+    let needle = sort_and_filter.filter.trim().to_lowercase();
+
+    let mut row_indices = Vec::new();
+    let mut sort_keys: Vec<ArrayRef> = Vec::new();
+
+    query_handle.seek_to_row(0);
+    let mut row_index = 0;
+    while let Some(row) = query_handle.next_row() {
+        if needle.is_empty()
+            || row
+                .iter()
+                .any(|cell| format_cell(cell).to_lowercase().contains(&needle))
+        {
+            row_indices.push(row_index);
+            if let Some((column_index, _)) = sort_by {
+                sort_keys.push(row[column_index].clone());
+            }
+        }
+        row_index += 1;
+    }
+
+    let Some((_, direction)) = sort_by else {
+        return Ok(row_indices);
+    };
+    if sort_keys.is_empty() {
+        return Ok(row_indices);
+    }
+
+    let sort_keys = arrow::compute::concat(&sort_keys.iter().map(|a| a.as_ref()).collect_vec())?;
+    let converter = RowConverter::new(vec![SortField::new_with_options(
+        sort_keys.data_type().clone(),
+        SortOptions {
+            descending: !direction.is_ascending(),
+            nulls_first: false,
+        },
+    )])?;
+    let sort_keys = converter.convert_columns(&[sort_keys])?;
+
+    // Stable, so that rows with equal keys stay in query order.
+    let mut order = (0..row_indices.len()).collect_vec();
+    order.sort_by(|&a, &b| sort_keys.row(a).cmp(&sort_keys.row(b)));
+
+    Ok(order.into_iter().map(|i| row_indices[i]).collect())
+}
+
+/// The value of a single-row array, as text.
+fn format_cell(cell: &ArrayRef) -> String {
+    if cell.is_empty() || cell.is_null(0) {
+        return String::new();
+    }
+    match ArrayFormatter::try_new(cell.as_ref(), &FormatOptions::default()) {
+        Ok(formatter) => formatter.value(0).to_string(),
+        Err(_) => format!("<{}>", cell.data_type()),
+    }
+}
+
+/// The selected columns of the query as CSV, for the given rows or all of them.
+///
+/// Values are written as stored, e.g. timestamps are in nanoseconds since the epoch.
+pub(crate) fn to_csv(
+    query_handle: &QueryHandle<StorageEngineArcReadGuard>,
+    row_order: Option<&[u64]>,
+) -> String {
+    re_tracing::profile_function!();
+
+    let mut csv = String::new();
+
+    let header = query_handle
+        .selected_contents()
+        .iter()
+        .map(|(_, column)| match column {
+            ColumnDescriptor::Component(component) => csv_field(&format!(
+                "{}:{}",
+                component.entity_path,
+                column.display_name()
+            )),
+            ColumnDescriptor::RowId(_) | ColumnDescriptor::Time(_) => {
+                csv_field(&column.display_name())
+            }
+        })
+        .join(",");
+    csv.push_str(&header);
+    csv.push('\n');
+
+    let mut write_row = |row: Vec<ArrayRef>| {
+        writeln!(
+            csv,
+            "{}",
+            row.iter()
+                .map(|cell| csv_field(&format_cell(cell)))
+                .join(",")
+        )
+        .ok();
+    };
+
+    if let Some(row_order) = row_order {
+        for &row_index in row_order {
+            query_handle.seek_to_row(row_index as usize);
+            if let Some(row) = query_handle.next_row() {
+                write_row(row);
+            }
+        }
+    } else {
+        query_handle.seek_to_row(0);
+        while let Some(row) = query_handle.next_row() {
+            write_row(row);
+        }
+    }
+
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!(csv_field("1.5"), "1.5");
+        assert_eq!(csv_field("[1, 2]"), "\"[1, 2]\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
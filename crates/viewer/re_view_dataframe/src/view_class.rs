@@ -11,7 +11,10 @@ use re_viewer_context::{
 };
 
 use crate::{
-    dataframe_ui::dataframe_ui, expanded_rows::ExpandedRowsCache, view_query,
+    dataframe_ui::dataframe_ui,
+    expanded_rows::ExpandedRowsCache,
+    sort_and_filter::{RowOrderCache, SortAndFilter},
+    view_query,
     visualizer_system::EmptySystem,
 };
 
@@ -22,6 +25,14 @@ struct DataframeViewState {
 
     /// List of view columns for the current query, cached here for the column visibility UI.
     view_columns: Option<Vec<ColumnDescriptor>>,
+
+    /// How the rows are sorted and filtered, set from the table itself.
+    sort_and_filter: SortAndFilter,
+
+    /// The rows left after sorting and filtering.
+    row_order_cache: RowOrderCache,
+
+    text_query_editor: view_query::TextQueryEditor,
 }
 
 impl ViewState for DataframeViewState {
@@ -96,12 +107,19 @@ Configure in the selection panel:
         ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
-        _space_origin: &EntityPath,
+        space_origin: &EntityPath,
         view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<DataframeViewState>()?;
         let view_query = view_query::Query::from_blueprint(ctx, view_id);
-        view_query.selection_panel_ui(ctx, ui, view_id, state.view_columns.as_deref())
+        view_query.selection_panel_ui(
+            ctx,
+            ui,
+            view_id,
+            space_origin,
+            state.view_columns.as_deref(),
+            &mut state.text_query_editor,
+        )
     }
 
     fn ui(
@@ -164,11 +182,19 @@ Configure in the selection panel:
 
         let query_handle = query_engine.query(dataframe_query);
 
+        let row_order = state.row_order_cache.row_order(
+            &query_handle,
+            ctx.recording().generation(),
+            &state.sort_and_filter,
+        );
+
         let hide_column_actions = dataframe_ui(
             ctx,
             ui,
             &query_handle,
             &mut state.expended_rows_cache,
+            &mut state.sort_and_filter,
+            row_order,
             &query.view_id,
         );
 
@@ -1,12 +1,15 @@
 use std::collections::HashSet;
 
-use re_chunk_store::ColumnDescriptor;
-use re_log_types::{AbsoluteTimeRange, Timeline, TimelineName};
+use re_chunk_store::{ColumnDescriptor, SparseFillStrategy};
+use re_dataframe::TextQuery;
+use re_log_types::{AbsoluteTimeRange, EntityPath, EntityPathSubs, Timeline, TimelineName};
 use re_sorbet::{ColumnSelector, ComponentColumnSelector};
 use re_types::blueprint::archetypes::DataframeQuery;
 use re_types::blueprint::{components, datatypes};
-use re_viewer_context::{ViewSystemExecutionError, ViewerContext};
+use re_viewer_context::{ViewClass as _, ViewId, ViewSystemExecutionError, ViewerContext};
+use re_viewport_blueprint::ViewContents;
 
+use crate::DataframeView;
 use crate::dataframe_ui::HideColumnAction;
 use crate::view_query::Query;
 
@@ -188,6 +191,68 @@ impl Query {
         );
     }
 
+    /// Replaces the query and the contents of the view with the ones of a textual query.
+    ///
+    /// The timeline is left as is if the query doesn't filter on one.
+    /// Filtering on specific times isn't supported by the view, and must be checked for beforehand.
+    pub fn save_text_query(
+        &self,
+        ctx: &ViewerContext<'_>,
+        view_id: ViewId,
+        space_origin: &EntityPath,
+        query: &TextQuery,
+    ) -> Result<(), ViewSystemExecutionError> {
+        debug_assert!(query.filtered_index_values.is_none());
+
+        let entity_path_filter = query
+            .entity_path_filter
+            .resolve_forgiving(&EntityPathSubs::new_with_origin(space_origin));
+        ViewContents::new(view_id, DataframeView::identifier(), entity_path_filter)
+            .save_to_blueprint_store(ctx);
+
+        if let Some(timeline_name) = &query.filtered_index {
+            self.save_timeline_name(ctx, timeline_name);
+        }
+        self.save_filter_by_range(
+            ctx,
+            query
+                .filtered_index_range
+                .unwrap_or(AbsoluteTimeRange::EVERYTHING),
+        );
+
+        if let Some(selector) = &query.filtered_is_not_null {
+            self.save_filter_is_not_null(
+                ctx,
+                &components::FilterIsNotNull::new(
+                    true,
+                    &selector.entity_path,
+                    selector.component.clone(),
+                ),
+            );
+        } else if let Some(filter) = self.filter_is_not_null_raw()?
+            && filter.active()
+        {
+            let selector = filter.column_selector();
+            self.save_filter_is_not_null(
+                ctx,
+                &components::FilterIsNotNull::new(false, &selector.entity_path, selector.component),
+            );
+        }
+
+        self.save_latest_at_enabled(
+            ctx,
+            query.sparse_fill_strategy == SparseFillStrategy::LatestAtGlobal,
+        );
+
+        if let Some(selection) = &query.selection {
+            self.save_selected_columns(ctx, selection.iter().cloned());
+        } else {
+            self.save_all_columns_selected(ctx);
+        }
+
+        Ok(())
+    }
+
     /// Given some view columns, list the columns that should be visible (aka "selected columns"),
     /// according to the blueprint.
     ///
@@ -2,10 +2,20 @@ mod blueprint;
 mod ui;
 
 use re_chunk_store::ColumnDescriptor;
+use re_log_types::EntityPath;
 use re_types::blueprint::archetypes;
 use re_viewer_context::{ViewId, ViewSystemExecutionError, ViewerContext};
 use re_viewport_blueprint::ViewProperty;
 
+/// Text typed into the query editor of the selection panel, see [`re_dataframe::TextQuery`].
+#[derive(Default)]
+pub(crate) struct TextQueryEditor {
+    text: String,
+
+    /// Why the last applied text couldn't be used.
+    error: Option<String>,
+}
+
 /// Wrapper over the `DataframeQuery` blueprint archetype that can also display some UI.
 pub struct Query {
     query_property: ViewProperty,
@@ -33,10 +43,15 @@ impl Query {
         ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         view_id: ViewId,
+        space_origin: &EntityPath,
         view_columns: Option<&[ColumnDescriptor]>,
+        text_query_editor: &mut TextQueryEditor,
     ) -> Result<(), ViewSystemExecutionError> {
         ui.add_space(4.0);
 
+        self.text_query_ui(ctx, ui, view_id, space_origin, text_query_editor)?;
+        ui.separator();
+
         let timeline_name = self.timeline_name(ctx)?;
         self.timeline_ui(ctx, ui, timeline_name)?;
 
@@ -1,4 +1,4 @@
-use crate::view_query::Query;
+use crate::view_query::{Query, TextQueryEditor};
 use egui::PopupCloseBehavior;
 use egui::containers::menu::{MenuButton, MenuConfig};
 use re_chunk_store::ColumnDescriptor;
@@ -13,6 +13,53 @@ use std::collections::{BTreeSet, HashSet};
 
 // UI implementation
 impl Query {
+    pub(super) fn text_query_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        view_id: ViewId,
+        space_origin: &EntityPath,
+        editor: &mut TextQueryEditor,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let apply_shortcut =
+            egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Enter);
+        ui.label("Query:").on_hover_text(format!(
+            "Sets the contents and query of the view from SQL-like text, e.g.\n\
+            SELECT * FROM /world/** WHERE frame_nr BETWEEN 0 AND 100 FILL LATEST\n\n\
+            Press {} or click Apply to run it.",
+            ui.ctx().format_shortcut(&apply_shortcut)
+        ));
+
+        let response = ui.add(
+            egui::TextEdit::multiline(&mut editor.text)
+                .code_editor()
+                .desired_rows(3)
+                .desired_width(f32::INFINITY)
+                .hint_text("SELECT * FROM /** WHERE frame_nr > 10"),
+        );
+        let submitted =
+            response.has_focus() && ui.input_mut(|i| i.consume_shortcut(&apply_shortcut));
+
+        if ui.button("Apply").clicked() || submitted {
+            editor.error = match editor.text.parse::<re_dataframe::TextQuery>() {
+                Ok(query) if query.filtered_index_values.is_some() => Some(
+                    "Filtering on specific times with `IN` isn't supported in this view".to_owned(),
+                ),
+                Ok(query) => {
+                    self.save_text_query(ctx, view_id, space_origin, &query)?;
+                    None
+                }
+                Err(err) => Some(err.to_string()),
+            };
+        }
+
+        if let Some(error) = &editor.error {
+            ui.error_label(error);
+        }
+
+        Ok(())
+    }
+
     pub(super) fn timeline_ui(
         &self,
         ctx: &ViewerContext<'_>,
@@ -0,0 +1,212 @@
+//! Pluggable layout algorithms for the graph view, see [`LayoutAlgorithm`].
+
+use std::sync::{Arc, LazyLock};
+
+use egui::{Pos2, Vec2};
+use parking_lot::RwLock;
+
+use re_log::error_once;
+use re_types::{blueprint::archetypes::GraphLayoutAlgorithm, components::Name};
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use super::{
+    Layout, LayoutRequest,
+    circular::CircularLayout,
+    fixed::FixedLayout,
+    layered::LayeredLayout,
+    provider::{considered_edges, layout_from_positions},
+};
+
+/// Space between nodes that are placed next to each other, in scene units.
+pub(super) const NODE_SPACING: f32 = 20.0;
+
+/// A node of the graph handed to a [`LayoutAlgorithm`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutNode {
+    /// The size of the node, in scene units.
+    pub size: Vec2,
+
+    /// The position the user logged for the node, if any.
+    pub fixed_position: Option<Pos2>,
+}
+
+/// The graph handed to a [`LayoutAlgorithm`], with the nodes of all entities in the view.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutInput {
+    pub nodes: Vec<LayoutNode>,
+
+    /// Directed edges, as indices into [`Self::nodes`].
+    ///
+    /// Self-edges are left out, and multiple edges between the same nodes appear once per edge.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl LayoutInput {
+    pub(super) fn from_request(request: &LayoutRequest) -> Self {
+        Self {
+            nodes: request
+                .all_nodes()
+                .map(|(_, template)| LayoutNode {
+                    size: template.size,
+                    fixed_position: template.fixed_position,
+                })
+                .collect(),
+            edges: considered_edges(request),
+        }
+    }
+}
+
+/// Computes the positions of the nodes of a graph in one go.
+///
+/// Algorithms are registered with [`register_layout_algorithm`], and picked per view in its blueprint.
+/// Views without a picked algorithm simulate a force-directed layout over several frames instead.
+pub trait LayoutAlgorithm: Send + Sync {
+    /// Identifies the algorithm in the blueprint. Must be unique.
+    fn identifier(&self) -> &'static str;
+
+    /// Shown in the selection panel.
+    fn display_name(&self) -> &'static str;
+
+    /// The center of every node, in the order of [`LayoutInput::nodes`].
+    ///
+    /// Nodes with a fixed position are always placed there, no matter what is returned for them.
+    fn positions(&self, input: &LayoutInput) -> Vec<Pos2>;
+}
+
+static LAYOUT_ALGORITHMS: LazyLock<RwLock<Vec<Arc<dyn LayoutAlgorithm>>>> = LazyLock::new(|| {
+    RwLock::new(vec![
+        Arc::new(LayeredLayout),
+        Arc::new(CircularLayout),
+        Arc::new(FixedLayout),
+    ])
+});
+
+/// Makes a layout algorithm available to all graph views.
+///
+/// Replaces any algorithm previously registered with the same identifier.
+pub fn register_layout_algorithm(algorithm: impl LayoutAlgorithm + 'static) {
+    let mut algorithms = LAYOUT_ALGORITHMS.write();
+    algorithms.retain(|a| a.identifier() != algorithm.identifier());
+    algorithms.push(Arc::new(algorithm));
+}
+
+/// The registered layout algorithms, the built-in ones first and the others in order of registration.
+pub fn layout_algorithms() -> Vec<Arc<dyn LayoutAlgorithm>> {
+    LAYOUT_ALGORITHMS.read().clone()
+}
+
+/// Lays out the request with an algorithm, keeping nodes with a fixed position in place.
+pub(crate) fn compute_layout(algorithm: &dyn LayoutAlgorithm, request: &LayoutRequest) -> Layout {
+    re_tracing::profile_function!(algorithm.identifier());
+
+    let input = LayoutInput::from_request(request);
+    let mut positions = algorithm.positions(&input);
+    if positions.len() != input.nodes.len() {
+        error_once!(
+            "Layout algorithm {:?} returned {} positions for {} nodes",
+            algorithm.identifier(),
+            positions.len(),
+            input.nodes.len()
+        );
+        positions.resize(input.nodes.len(), Pos2::ZERO);
+    }
+
+    for (position, node) in positions.iter_mut().zip(&input.nodes) {
+        if let Some(fixed_position) = node.fixed_position {
+            *position = fixed_position;
+        }
+    }
+
+    layout_from_positions(request, positions.into_iter())
+}
+
+// ---
+
+fn blueprint_property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<GraphLayoutAlgorithm>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// The identifier of the algorithm picked in the blueprint of the view, if any.
+pub fn picked_layout_algorithm_identifier(
+    ctx: &ViewerContext<'_>,
+    view_id: ViewId,
+) -> Option<String> {
+    blueprint_property(ctx, view_id)
+        .component_or_empty::<Name>(&GraphLayoutAlgorithm::descriptor_algorithm())
+        .ok()
+        .flatten()
+        .map(|name| name.to_string())
+}
+
+/// The algorithm picked in the blueprint of the view, if it's registered.
+pub fn picked_layout_algorithm(
+    ctx: &ViewerContext<'_>,
+    view_id: ViewId,
+) -> Option<Arc<dyn LayoutAlgorithm>> {
+    let identifier = picked_layout_algorithm_identifier(ctx, view_id)?;
+    let algorithm = layout_algorithms()
+        .into_iter()
+        .find(|a| a.identifier() == identifier);
+    if algorithm.is_none() {
+        re_log::warn_once!("Unknown graph layout algorithm {identifier:?}, using the force layout");
+    }
+    algorithm
+}
+
+/// Picks the algorithm of the view, or goes back to the force layout if `None`.
+pub fn pick_layout_algorithm(ctx: &ViewerContext<'_>, view_id: ViewId, identifier: Option<&str>) {
+    let property = blueprint_property(ctx, view_id);
+    if let Some(identifier) = identifier {
+        property.save_blueprint_component(
+            ctx,
+            &GraphLayoutAlgorithm::descriptor_algorithm(),
+            &Name::from(identifier),
+        );
+    } else {
+        property.clear_blueprint_component(ctx, GraphLayoutAlgorithm::descriptor_algorithm());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ReplacedLayout;
+
+    impl LayoutAlgorithm for ReplacedLayout {
+        fn identifier(&self) -> &'static str {
+            "layered"
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Replaced"
+        }
+
+        fn positions(&self, input: &LayoutInput) -> Vec<Pos2> {
+            vec![Pos2::ZERO; input.nodes.len()]
+        }
+    }
+
+    #[test]
+    fn registration_replaces_same_identifier() {
+        register_layout_algorithm(ReplacedLayout);
+
+        let algorithms: Vec<_> = layout_algorithms()
+            .iter()
+            .map(|a| (a.identifier(), a.display_name()))
+            .collect();
+        assert_eq!(
+            algorithms,
+            [
+                ("circular", "Circular"),
+                ("fixed", "Fixed positions"),
+                ("layered", "Replaced")
+            ]
+        );
+    }
+}
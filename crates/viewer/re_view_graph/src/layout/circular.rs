@@ -0,0 +1,41 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use egui::Pos2;
+
+use super::{LayoutAlgorithm, LayoutInput, algorithm::NODE_SPACING};
+
+/// Places the nodes evenly on a circle, in the order they were logged.
+///
+/// The circle is just large enough for the nodes not to overlap.
+pub struct CircularLayout;
+
+impl LayoutAlgorithm for CircularLayout {
+    fn identifier(&self) -> &'static str {
+        "circular"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Circular"
+    }
+
+    fn positions(&self, input: &LayoutInput) -> Vec<Pos2> {
+        // Nodes with a fixed position stay where they are, so they don't take up space on the circle.
+        let on_circle: Vec<usize> = (0..input.nodes.len())
+            .filter(|&node| input.nodes[node].fixed_position.is_none())
+            .collect();
+
+        let circumference: f32 = on_circle
+            .iter()
+            .map(|&node| input.nodes[node].size.length() + NODE_SPACING)
+            .sum();
+        let radius = circumference / TAU;
+
+        let mut positions = vec![Pos2::ZERO; input.nodes.len()];
+        for (i, &node) in on_circle.iter().enumerate() {
+            // Start at the top, going clockwise.
+            let angle = TAU * i as f32 / on_circle.len() as f32 - FRAC_PI_2;
+            positions[node] = Pos2::new(radius * angle.cos(), radius * angle.sin());
+        }
+        positions
+    }
+}
@@ -0,0 +1,43 @@
+use egui::{Pos2, Rect};
+
+use super::{LayoutAlgorithm, LayoutInput, algorithm::NODE_SPACING};
+
+/// Keeps nodes exactly where they were logged, without making room for overlapping nodes.
+///
+/// Nodes without a position are lined up below the others.
+pub struct FixedLayout;
+
+impl LayoutAlgorithm for FixedLayout {
+    fn identifier(&self) -> &'static str {
+        "fixed"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Fixed positions"
+    }
+
+    fn positions(&self, input: &LayoutInput) -> Vec<Pos2> {
+        let fixed_rect = input
+            .nodes
+            .iter()
+            .filter_map(|node| Some(Rect::from_center_size(node.fixed_position?, node.size)))
+            .fold(Rect::NOTHING, Rect::union);
+        let (mut left, top) = if fixed_rect.is_finite() {
+            (fixed_rect.left(), fixed_rect.bottom() + 2.0 * NODE_SPACING)
+        } else {
+            (0.0, 0.0)
+        };
+
+        input
+            .nodes
+            .iter()
+            .map(|node| {
+                node.fixed_position.unwrap_or_else(|| {
+                    let center = Pos2::new(left + 0.5 * node.size.x, top + 0.5 * node.size.y);
+                    left += node.size.x + NODE_SPACING;
+                    center
+                })
+            })
+            .collect()
+    }
+}
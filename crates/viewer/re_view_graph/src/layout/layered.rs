@@ -0,0 +1,219 @@
+//! A layered layout for directed graphs, in the style of `dot` from graphviz.
+//!
+//! Nodes are assigned to layers so that edges point downwards, and the nodes within each layer are
+//! ordered to reduce edge crossings. Edges that close a cycle are ignored for this.
+
+use std::collections::VecDeque;
+
+use ahash::HashSet;
+use egui::Pos2;
+
+use super::{LayoutAlgorithm, LayoutInput, algorithm::NODE_SPACING};
+
+/// Space between two layers.
+const LAYER_SPACING: f32 = 60.0;
+
+/// Number of sweeps up and down the layers to reduce edge crossings.
+const NUM_ORDERING_SWEEPS: usize = 4;
+
+/// Places the nodes of directed acyclic graphs, like hierarchies and pipelines, in layers from top to bottom.
+pub struct LayeredLayout;
+
+impl LayoutAlgorithm for LayeredLayout {
+    fn identifier(&self) -> &'static str {
+        "layered"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Layered"
+    }
+
+    fn positions(&self, input: &LayoutInput) -> Vec<Pos2> {
+        let num_nodes = input.nodes.len();
+        let edges = acyclic_edges(num_nodes, &input.edges);
+        let layers = order_layers(&assign_layers(num_nodes, &edges), &edges);
+
+        let mut positions = vec![Pos2::ZERO; num_nodes];
+        let mut top = 0.0;
+        for layer in &layers {
+            let sizes = || layer.iter().map(|&node| input.nodes[node].size);
+            let height = sizes().map(|size| size.y).fold(0.0, f32::max);
+            let width = sizes().map(|size| size.x).sum::<f32>()
+                + NODE_SPACING * layer.len().saturating_sub(1) as f32;
+
+            let mut left = -0.5 * width;
+            for &node in layer {
+                let size = input.nodes[node].size;
+                positions[node] = Pos2::new(left + 0.5 * size.x, top + 0.5 * height);
+                left += size.x + NODE_SPACING;
+            }
+            top += height + LAYER_SPACING;
+        }
+        positions
+    }
+}
+
+/// The edges without the ones that close a cycle, found with a depth-first search.
+fn acyclic_edges(num_nodes: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        New,
+        OnStack,
+        Done,
+    }
+
+    let mut successors = vec![Vec::new(); num_nodes];
+    for &(source, target) in edges {
+        successors[source].push(target);
+    }
+
+    let mut visits = vec![Visit::New; num_nodes];
+    let mut back_edges = HashSet::default();
+    for root in 0..num_nodes {
+        if visits[root] != Visit::New {
+            continue;
+        }
+        visits[root] = Visit::OnStack;
+
+        // Every entry is a node and the index of its next successor to visit.
+        let mut stack = vec![(root, 0)];
+        while let Some((node, next)) = stack.pop() {
+            let Some(&successor) = successors[node].get(next) else {
+                visits[node] = Visit::Done;
+                continue;
+            };
+            stack.push((node, next + 1));
+
+            match visits[successor] {
+                Visit::New => {
+                    visits[successor] = Visit::OnStack;
+                    stack.push((successor, 0));
+                }
+                Visit::OnStack => {
+                    back_edges.insert((node, successor));
+                }
+                Visit::Done => {}
+            }
+        }
+    }
+
+    edges
+        .iter()
+        .filter(|edge| !back_edges.contains(*edge))
+        .copied()
+        .collect()
+}
+
+/// The layer of every node, one below the lowest of its predecessors.
+///
+/// The edges must not form a cycle.
+fn assign_layers(num_nodes: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut successors = vec![Vec::new(); num_nodes];
+    let mut num_predecessors = vec![0; num_nodes];
+    for &(source, target) in edges {
+        successors[source].push(target);
+        num_predecessors[target] += 1;
+    }
+
+    let mut layers = vec![0; num_nodes];
+    let mut ready: VecDeque<usize> = (0..num_nodes)
+        .filter(|&node| num_predecessors[node] == 0)
+        .collect();
+    while let Some(node) = ready.pop_front() {
+        for &successor in &successors[node] {
+            layers[successor] = layers[successor].max(layers[node] + 1);
+            num_predecessors[successor] -= 1;
+            if num_predecessors[successor] == 0 {
+                ready.push_back(successor);
+            }
+        }
+    }
+    layers
+}
+
+/// The nodes in every layer, ordered by the average position of their neighbors to reduce edge crossings.
+fn order_layers(layer_of_node: &[usize], edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let num_nodes = layer_of_node.len();
+    let num_layers = layer_of_node.iter().max().map_or(0, |&layer| layer + 1);
+
+    let mut layers = vec![Vec::new(); num_layers];
+    for (node, &layer) in layer_of_node.iter().enumerate() {
+        layers[layer].push(node);
+    }
+
+    let mut predecessors = vec![Vec::new(); num_nodes];
+    let mut successors = vec![Vec::new(); num_nodes];
+    for &(source, target) in edges {
+        predecessors[target].push(source);
+        successors[source].push(target);
+    }
+
+    // The index of every node within its layer.
+    let mut ranks = vec![0.0; num_nodes];
+    for layer in &layers {
+        for (rank, &node) in layer.iter().enumerate() {
+            ranks[node] = rank as f32;
+        }
+    }
+
+    for sweep in 0..NUM_ORDERING_SWEEPS {
+        // Alternate between ordering by the layers above and below.
+        let (neighbors, layer_order): (_, Vec<usize>) = if sweep % 2 == 0 {
+            (&predecessors, (1..num_layers).collect())
+        } else {
+            (
+                &successors,
+                (0..num_layers.saturating_sub(1)).rev().collect(),
+            )
+        };
+
+        for layer in layer_order {
+            let mut barycenters: Vec<(f32, usize)> = layers[layer]
+                .iter()
+                .map(|&node| {
+                    let neighbors = &neighbors[node];
+                    let barycenter = if neighbors.is_empty() {
+                        ranks[node]
+                    } else {
+                        neighbors.iter().map(|&n| ranks[n]).sum::<f32>() / neighbors.len() as f32
+                    };
+                    (barycenter, node)
+                })
+                .collect();
+            barycenters.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            layers[layer] = barycenters.into_iter().map(|(_, node)| node).collect();
+            for (rank, &node) in layers[layer].iter().enumerate() {
+                ranks[node] = rank as f32;
+            }
+        }
+    }
+
+    layers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_are_broken() {
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3)];
+        assert_eq!(acyclic_edges(4, &edges), [(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn layers_follow_the_longest_path() {
+        // 0 -> 1 -> 3, and 0 -> 2 -> 3, and a shortcut 0 -> 3.
+        let edges = [(0, 1), (0, 2), (1, 3), (2, 3), (0, 3)];
+        assert_eq!(assign_layers(5, &edges), [0, 1, 1, 2, 0]);
+    }
+
+    #[test]
+    fn ordering_removes_crossings() {
+        // 0 -> 3 and 1 -> 2 cross when kept in their original order.
+        let edges = [(0, 3), (1, 2)];
+        let layers = order_layers(&[0, 0, 1, 1], &edges);
+        assert_eq!(layers, [vec![0, 1], vec![3, 2]]);
+    }
+}
@@ -1,10 +1,19 @@
+mod algorithm;
+mod circular;
+mod fixed;
 mod geometry;
+mod layered;
 mod params;
 mod provider;
 mod request;
 mod result;
 mod slots;
 
+pub(crate) use algorithm::compute_layout;
+pub use algorithm::{
+    LayoutAlgorithm, LayoutInput, LayoutNode, layout_algorithms, pick_layout_algorithm,
+    picked_layout_algorithm, picked_layout_algorithm_identifier, register_layout_algorithm,
+};
 pub use geometry::{EdgeGeometry, PathGeometry};
 pub use params::ForceLayoutParams;
 pub use provider::ForceLayoutProvider;
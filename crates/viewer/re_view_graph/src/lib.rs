@@ -9,5 +9,6 @@ mod ui;
 mod view;
 mod visualizers;
 
+pub use layout::{LayoutAlgorithm, LayoutInput, LayoutNode, register_layout_algorithm};
 pub use ui::GraphViewState;
 pub use view::GraphView;
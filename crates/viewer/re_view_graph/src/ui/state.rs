@@ -4,7 +4,9 @@ use re_types::blueprint::components::VisualBounds2D;
 use re_ui::UiExt as _;
 use re_viewer_context::ViewState;
 
-use crate::layout::{ForceLayoutParams, ForceLayoutProvider, Layout, LayoutRequest};
+use crate::layout::{
+    ForceLayoutParams, ForceLayoutProvider, Layout, LayoutAlgorithm, LayoutRequest, compute_layout,
+};
 
 /// View state for the custom view.
 ///
@@ -50,7 +52,7 @@ impl ViewState for GraphViewState {
 
 /// The following is a simple state machine that keeps track of the different
 /// layouts and if they need to be recomputed. It also holds the state of the
-/// force-based simulation.
+/// force-based simulation, or the layout computed in one go by a [`LayoutAlgorithm`].
 #[derive(Default)]
 pub enum LayoutState {
     #[default]
@@ -65,15 +67,20 @@ pub enum LayoutState {
         provider: ForceLayoutProvider,
         params: ForceLayoutParams,
     },
+    Computed {
+        layout: Layout,
+        request: LayoutRequest,
+        algorithm: &'static str,
+    },
 }
 
 impl LayoutState {
     pub fn bounding_rect(&self) -> Option<Rect> {
         match self {
             Self::None => None,
-            Self::Finished { layout, .. } | Self::InProgress { layout, .. } => {
-                Some(layout.bounding_rect())
-            }
+            Self::Finished { layout, .. }
+            | Self::InProgress { layout, .. }
+            | Self::Computed { layout, .. } => Some(layout.bounding_rect()),
         }
     }
 
@@ -86,7 +93,28 @@ impl LayoutState {
     }
 
     /// A simple state machine that keeps track of the different stages and if the layout needs to be recomputed.
-    fn update(self, new_request: LayoutRequest, new_params: ForceLayoutParams) -> Self {
+    fn update(
+        self,
+        new_request: LayoutRequest,
+        new_params: ForceLayoutParams,
+        algorithm: Option<&dyn LayoutAlgorithm>,
+    ) -> Self {
+        if let Some(algorithm) = algorithm {
+            return match self {
+                // Layout is up to date, nothing to do here.
+                Self::Computed {
+                    ref request,
+                    algorithm: identifier,
+                    ..
+                } if (request == &new_request) && (identifier == algorithm.identifier()) => self,
+                _ => Self::Computed {
+                    layout: compute_layout(algorithm, &new_request),
+                    request: new_request,
+                    algorithm: algorithm.identifier(),
+                },
+            };
+        }
+
         match self {
             // Layout is up to date, nothing to do here.
             Self::Finished {
@@ -106,7 +134,8 @@ impl LayoutState {
                     params: new_params,
                 }
             }
-            Self::Finished { layout, .. } => {
+            // Switching to the simulation starts off from the previous layout.
+            Self::Finished { layout, .. } | Self::Computed { layout, .. } => {
                 let mut provider =
                     ForceLayoutProvider::new_with_previous(new_request, &layout, &new_params);
                 let layout = provider.tick();
@@ -161,11 +190,18 @@ impl LayoutState {
     }
 
     /// This method is lazy. A new layout is only computed if the current timestamp requires it.
-    pub fn get(&mut self, request: LayoutRequest, params: ForceLayoutParams) -> &mut Layout {
-        *self = std::mem::take(self).update(request, params);
+    pub fn get(
+        &mut self,
+        request: LayoutRequest,
+        params: ForceLayoutParams,
+        algorithm: Option<&dyn LayoutAlgorithm>,
+    ) -> &mut Layout {
+        *self = std::mem::take(self).update(request, params, algorithm);
 
         match self {
-            Self::Finished { layout, .. } | Self::InProgress { layout, .. } => layout,
+            Self::Finished { layout, .. }
+            | Self::InProgress { layout, .. }
+            | Self::Computed { layout, .. } => layout,
             Self::None => unreachable!(), // We just set the state to `Self::Current` above.
         }
     }
@@ -21,7 +21,10 @@ use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     graph::Graph,
-    layout::{ForceLayoutParams, LayoutRequest},
+    layout::{
+        ForceLayoutParams, LayoutRequest, layout_algorithms, pick_layout_algorithm,
+        picked_layout_algorithm, picked_layout_algorithm_identifier,
+    },
     ui::{GraphViewState, LevelOfDetail, draw_graph, view_property_force_ui},
     visualizers::{EdgesVisualizer, NodeVisualizer, merge},
 };
@@ -126,19 +129,55 @@ impl ViewClass for GraphView {
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<GraphViewState>()?;
 
+        let picked = picked_layout_algorithm_identifier(ctx, view_id);
+
         ui.selection_grid("graph_view_settings_ui").show(ui, |ui| {
             state.layout_ui(ui);
-            state.simulation_ui(ui);
+
+            ui.grid_left_hand_label("Layout")
+                .on_hover_text("How the nodes are placed. Logged node positions are always kept");
+            let algorithms = layout_algorithms();
+            let selected_text = picked.as_deref().map_or("Force-directed", |identifier| {
+                algorithms
+                    .iter()
+                    .find(|a| a.identifier() == identifier)
+                    .map_or(identifier, |a| a.display_name())
+            });
+            let mut new_pick = picked.clone();
+            egui::ComboBox::from_id_salt("graph_layout_algorithm")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut new_pick, None, "Force-directed");
+                    for algorithm in &algorithms {
+                        ui.selectable_value(
+                            &mut new_pick,
+                            Some(algorithm.identifier().to_owned()),
+                            algorithm.display_name(),
+                        );
+                    }
+                });
+            if new_pick != picked {
+                pick_layout_algorithm(ctx, view_id, new_pick.as_deref());
+            }
+            ui.end_row();
+
+            if picked.is_none() {
+                state.simulation_ui(ui);
+            }
         });
 
         re_ui::list_item::list_item_scope(ui, "graph_selection_ui", |ui| {
             let ctx = self.view_context(ctx, view_id, state);
             view_property_ui::<VisualBounds2D>(&ctx, ui, self);
-            view_property_force_ui::<ForceLink>(&ctx, ui, self);
-            view_property_force_ui::<ForceManyBody>(&ctx, ui, self);
-            view_property_force_ui::<ForcePosition>(&ctx, ui, self);
-            view_property_force_ui::<ForceCenter>(&ctx, ui, self);
-            view_property_force_ui::<ForceCollisionRadius>(&ctx, ui, self);
+
+            // The forces only apply to the force-directed layout.
+            if picked.is_none() {
+                view_property_force_ui::<ForceLink>(&ctx, ui, self);
+                view_property_force_ui::<ForceManyBody>(&ctx, ui, self);
+                view_property_force_ui::<ForcePosition>(&ctx, ui, self);
+                view_property_force_ui::<ForceCenter>(&ctx, ui, self);
+                view_property_force_ui::<ForceCollisionRadius>(&ctx, ui, self);
+            }
         });
 
         Ok(())
@@ -179,7 +218,10 @@ impl ViewClass for GraphView {
 
         // Perform all layout-related tasks.
         let request = LayoutRequest::from_graphs(graphs.iter());
-        let layout = state.layout_state.get(request, params);
+        let algorithm = picked_layout_algorithm(ctx, query.view_id);
+        let layout = state
+            .layout_state
+            .get(request, params, algorithm.as_deref());
 
         let mut scene_rect = egui::Rect::from(rect_in_scene);
         let scene_rect_ref = scene_rect;
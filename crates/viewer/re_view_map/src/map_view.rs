@@ -393,6 +393,8 @@ fn create_view_builder(
 
             // Make sure the map in the background is not completely overwritten
             blend_with_background: true,
+            clip_plane: None,
+            lighting: Default::default(),
         },
     )
 }
@@ -0,0 +1,288 @@
+//! Selecting all instances within a rectangle dragged out over a spatial view.
+//!
+//! Clicking selects instances one by one, which is impractical for dozens of points.
+//! Instead, the picking layer is read back over the whole rectangle and everything visible in it is selected.
+
+use ahash::HashSet;
+use egui::{Modifiers, PointerButton, Pos2, Rect};
+
+use re_log::ResultExt as _;
+use re_log_types::Instance;
+use re_renderer::{PickingLayerProcessor, RectInt, view_builder::ViewBuilder};
+use re_ui::{ContextExt as _, UiExt as _};
+use re_viewer_context::{Item, ItemCollection, ViewId, ViewerContext, VisualizerCollection};
+
+use crate::picking_ui::iter_pickable_rects;
+
+/// Which mouse button drags out the rectangle.
+const BOX_SELECTION_BUTTON: PointerButton = PointerButton::Primary;
+
+/// Held while releasing the rectangle to add its instances to the selection.
+const ADD_MODIFIER: Modifiers = Modifiers::SHIFT;
+
+/// Held while releasing the rectangle to remove its instances from the selection.
+const REMOVE_MODIFIER: Modifiers = Modifiers::COMMAND;
+
+/// How the instances in the rectangle change the selection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BoxSelectionMode {
+    Replace,
+    Add,
+    Remove,
+}
+
+impl BoxSelectionMode {
+    fn from_modifiers(modifiers: Modifiers) -> Self {
+        if modifiers.contains(REMOVE_MODIFIER) {
+            Self::Remove
+        } else if modifiers.contains(ADD_MODIFIER) {
+            Self::Add
+        } else {
+            Self::Replace
+        }
+    }
+}
+
+/// User data of the picking readback of a box selection,
+/// which tells it apart from the readback of the hover picking.
+struct BoxSelectionReadback {
+    mode: BoxSelectionMode,
+}
+
+#[derive(Clone, Default)]
+pub struct BoxSelectionState {
+    enabled: bool,
+
+    /// Where the current drag started, in ui coordinates.
+    drag_start: Option<Pos2>,
+
+    /// The rectangle being dragged out, in ui coordinates.
+    dragged_rect: Option<Rect>,
+
+    /// A released rectangle whose picking layer still needs to be read back.
+    released: Option<(Rect, BoxSelectionMode)>,
+
+    awaiting_readback: bool,
+}
+
+impl BoxSelectionState {
+    /// Whether dragging with the primary button selects instead of moving the camera.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        if active != self.enabled {
+            *self = Self {
+                enabled: active,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Toggle for the selection panel.
+    pub fn selection_ui(&mut self, ui: &mut egui::Ui) {
+        let egui_ctx = ui.ctx().clone();
+        ui.grid_left_hand_label("Box select").on_hover_text(format!(
+            "Drag a rectangle to select all instances in it.\n\
+            Hold {} to add them to the selection, or {} to remove them from it",
+            egui_ctx.format_modifiers(ADD_MODIFIER),
+            egui_ctx.format_modifiers(REMOVE_MODIFIER),
+        ));
+        let mut active = self.is_active();
+        ui.re_checkbox(&mut active, "Drag to select");
+        self.set_active(active);
+        ui.end_row();
+    }
+
+    /// Drags out the rectangle.
+    ///
+    /// While active, this takes over all drags in the view, so the camera doesn't move meanwhile.
+    pub fn handle_drag(&mut self, ui: &egui::Ui, view_rect: Rect) {
+        if !self.enabled {
+            return;
+        }
+
+        let response = ui.interact(
+            view_rect,
+            ui.id().with("box_selection"),
+            egui::Sense::drag(),
+        );
+
+        if response.drag_started_by(BOX_SELECTION_BUTTON) {
+            self.drag_start = response.interact_pointer_pos();
+        }
+
+        if let (Some(drag_start), Some(pointer_pos)) =
+            (self.drag_start, response.interact_pointer_pos())
+        {
+            self.dragged_rect =
+                Some(Rect::from_two_pos(drag_start, pointer_pos).intersect(view_rect));
+        }
+
+        if response.drag_stopped() {
+            self.drag_start = None;
+            if let Some(rect) = self.dragged_rect.take() {
+                let mode = BoxSelectionMode::from_modifiers(ui.input(|i| i.modifiers));
+                self.released = Some((rect, mode));
+            }
+        }
+    }
+
+    /// Schedules the readback of the picking layer under a released rectangle.
+    ///
+    /// Returns `true` if it did, in which case no other picking rectangle can be scheduled on the view builder.
+    pub fn schedule_readback(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        view_builder: &mut ViewBuilder,
+        view_id: ViewId,
+        view_rect: Rect,
+    ) -> bool {
+        let Some((rect, mode)) = self.released.take() else {
+            return false;
+        };
+
+        let pixels_per_point = ctx.egui_ctx().pixels_per_point();
+        let min = ((rect.min - view_rect.min) * pixels_per_point).floor();
+        let max = ((rect.max - view_rect.min) * pixels_per_point).ceil();
+        let picking_rect = RectInt {
+            min: glam::ivec2(min.x as i32, min.y as i32),
+            extent: glam::uvec2((max.x - min.x) as u32, (max.y - min.y) as u32),
+        };
+        if picking_rect.width() == 0 || picking_rect.height() == 0 {
+            return false;
+        }
+
+        let scheduled = view_builder
+            .schedule_picking_rect(
+                ctx.render_ctx(),
+                picking_rect,
+                view_id.gpu_readback_id(),
+                BoxSelectionReadback { mode },
+                ctx.app_options().show_picking_debug_overlay,
+            )
+            .ok_or_log_error_once()
+            .is_some();
+        self.awaiting_readback |= scheduled;
+        scheduled
+    }
+
+    /// Selects the instances of a rectangle that was read back since the last frame.
+    pub fn apply_readback(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        view_id: ViewId,
+        visualizers: &VisualizerCollection,
+    ) {
+        if !self.awaiting_readback {
+            return;
+        }
+
+        let Some(result) = PickingLayerProcessor::readback_result::<BoxSelectionReadback>(
+            ctx.render_ctx(),
+            view_id.gpu_readback_id(),
+        ) else {
+            // The readback takes a few frames.
+            ctx.egui_ctx().request_repaint();
+            return;
+        };
+        self.awaiting_readback = false;
+
+        re_tracing::profile_function!();
+
+        // Images are picked as a whole, like when clicking them.
+        let image_entities: HashSet<_> = iter_pickable_rects(visualizers)
+            .map(|rect| rect.ent_path.hash())
+            .collect();
+
+        let mut seen = HashSet::default();
+        let mut instance_path_hashes = Vec::new();
+        for picking_id in &result.picking_id_data {
+            if picking_id.object.0 == 0 {
+                continue;
+            }
+            let mut instance_path_hash =
+                re_view::instance_path_hash_from_picking_layer_id(*picking_id);
+            if image_entities.contains(&instance_path_hash.entity_path_hash) {
+                instance_path_hash.instance = Instance::ALL;
+            }
+            if seen.insert(instance_path_hash) {
+                instance_path_hashes.push(instance_path_hash);
+            }
+        }
+
+        let query_result = ctx.lookup_query_result(view_id);
+        let items: Vec<Item> = instance_path_hashes
+            .into_iter()
+            .filter_map(|instance_path_hash| instance_path_hash.resolve(ctx.recording()))
+            .filter(|instance_path| {
+                query_result
+                    .tree
+                    .lookup_result_by_path(&instance_path.entity_path)
+                    .is_some_and(|data_result| data_result.is_interactive())
+            })
+            .map(|instance_path| Item::DataResult(view_id, instance_path))
+            .collect();
+
+        let selection_state = ctx.selection_state();
+        match result.user_data.mode {
+            BoxSelectionMode::Replace => {
+                selection_state.set_selection(ItemCollection::from_items_and_context(
+                    items.into_iter().map(|item| (item, None)),
+                ));
+            }
+            BoxSelectionMode::Add => {
+                selection_state.extend_selection(ItemCollection::from_items_and_context(
+                    items.into_iter().map(|item| (item, None)),
+                ));
+            }
+            BoxSelectionMode::Remove => {
+                let removed: HashSet<Item> = items.into_iter().collect();
+                let mut selection = selection_state.selected_items().clone();
+                selection.retain(|item, _| !removed.contains(item));
+                selection_state.set_selection(selection);
+            }
+        }
+    }
+
+    /// Paints the rectangle being dragged out.
+    pub fn paint(&self, painter: &egui::Painter) {
+        if let Some(rect) = self.dragged_rect {
+            let stroke = painter.ctx().selection_stroke();
+            painter.rect(
+                rect,
+                0.0,
+                stroke.color.gamma_multiply(0.1),
+                stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_pick_the_mode() {
+        assert_eq!(
+            BoxSelectionMode::from_modifiers(Modifiers::NONE),
+            BoxSelectionMode::Replace
+        );
+        assert_eq!(
+            BoxSelectionMode::from_modifiers(Modifiers::SHIFT),
+            BoxSelectionMode::Add
+        );
+        assert_eq!(
+            BoxSelectionMode::from_modifiers(Modifiers::COMMAND),
+            BoxSelectionMode::Remove
+        );
+        assert_eq!(
+            BoxSelectionMode::from_modifiers(Modifiers::SHIFT | Modifiers::COMMAND),
+            BoxSelectionMode::Remove
+        );
+    }
+}
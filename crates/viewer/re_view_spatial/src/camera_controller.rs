@@ -0,0 +1,299 @@
+//! Pluggable camera controllers for the 3D view, see [`CameraController`].
+
+use std::sync::{Arc, LazyLock};
+
+use ahash::HashMap;
+use glam::{Affine3A, Quat, Vec3};
+use parking_lot::RwLock;
+
+use re_types::{
+    ViewClassIdentifier,
+    blueprint::{archetypes::CameraController3D, components::Eye3DKind},
+    components::Name,
+};
+use re_view::controls::{DRAG_PAN3D_BUTTON, ROTATE3D_BUTTON};
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::eye::ViewEye;
+
+/// Input to a [`CameraController`] for one frame.
+pub struct CameraControllerInput<'a> {
+    /// The response of the view, for pointer and keyboard input.
+    pub response: &'a egui::Response,
+
+    /// Drags shorter than this many ui points should not move the eye, so clicks still select.
+    pub drag_threshold: f32,
+
+    /// Movement speed from the blueprint of the view, in scene units per second.
+    pub speed: f32,
+
+    /// The up axis of the scene, if it has view coordinates.
+    pub scene_up: Option<Vec3>,
+}
+
+/// Turns user input into movements of the eye of a 3D view.
+///
+/// Controllers are registered per view class with [`register_camera_controller`],
+/// and picked per view in its blueprint.
+/// Views without a picked controller use the [`Eye3DKind`] of their eye controls.
+///
+/// A controller is shared by all views using it, so any state beyond the [`ViewEye`] is up to the implementation.
+pub trait CameraController: Send + Sync {
+    /// Identifies the controller in the blueprint. Must be unique within a view class.
+    fn identifier(&self) -> &'static str;
+
+    /// Shown in the selection panel.
+    fn display_name(&self) -> &'static str;
+
+    /// Moves the eye according to the input.
+    ///
+    /// Returns `true` if the user interacted with the eye.
+    fn update(&self, eye: &mut ViewEye, input: &CameraControllerInput<'_>) -> bool;
+}
+
+type ControllersPerViewClass = HashMap<ViewClassIdentifier, Vec<Arc<dyn CameraController>>>;
+
+static CAMERA_CONTROLLERS: LazyLock<RwLock<ControllersPerViewClass>> =
+    LazyLock::new(Default::default);
+
+/// Makes a camera controller available to all views of the given class.
+///
+/// Replaces any controller previously registered with the same identifier.
+pub fn register_camera_controller(
+    view_class: ViewClassIdentifier,
+    controller: impl CameraController + 'static,
+) {
+    let mut controllers = CAMERA_CONTROLLERS.write();
+    let controllers = controllers.entry(view_class).or_default();
+    controllers.retain(|c| c.identifier() != controller.identifier());
+    controllers.push(Arc::new(controller));
+}
+
+/// The camera controllers of a view class, in order of registration.
+pub fn camera_controllers(view_class: ViewClassIdentifier) -> Vec<Arc<dyn CameraController>> {
+    CAMERA_CONTROLLERS
+        .read()
+        .get(&view_class)
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub(crate) fn register_builtin_camera_controllers(view_class: ViewClassIdentifier) {
+    register_camera_controller(view_class, OrbitController);
+    register_camera_controller(view_class, FlyController);
+    register_camera_controller(view_class, TurntableController);
+    register_camera_controller(view_class, TopDownController);
+}
+
+// ---
+
+fn blueprint_property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<CameraController3D>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// The identifier of the controller picked in the blueprint of the view, if any.
+pub(crate) fn picked_camera_controller_identifier(
+    ctx: &ViewerContext<'_>,
+    view_id: ViewId,
+) -> Option<String> {
+    blueprint_property(ctx, view_id)
+        .component_or_empty::<Name>(&CameraController3D::descriptor_controller())
+        .ok()
+        .flatten()
+        .map(|name| name.to_string())
+}
+
+/// The controller picked in the blueprint of the view, if it's registered for the view class.
+pub(crate) fn picked_camera_controller(
+    ctx: &ViewerContext<'_>,
+    view_class: ViewClassIdentifier,
+    view_id: ViewId,
+) -> Option<Arc<dyn CameraController>> {
+    let identifier = picked_camera_controller_identifier(ctx, view_id)?;
+    let controller = camera_controllers(view_class)
+        .into_iter()
+        .find(|c| c.identifier() == identifier);
+    if controller.is_none() {
+        re_log::warn_once!("Unknown camera controller {identifier:?}, using the eye controls");
+    }
+    controller
+}
+
+/// Picks the controller of the view, or goes back to the eye controls if `None`.
+pub(crate) fn pick_camera_controller(
+    ctx: &ViewerContext<'_>,
+    view_id: ViewId,
+    identifier: Option<&str>,
+) {
+    let property = blueprint_property(ctx, view_id);
+    if let Some(identifier) = identifier {
+        property.save_blueprint_component(
+            ctx,
+            &CameraController3D::descriptor_controller(),
+            &Name::from(identifier),
+        );
+    } else {
+        property.clear_blueprint_component(ctx, CameraController3D::descriptor_controller());
+    }
+}
+
+// ---
+
+/// Orbits around a center point. Left-drag rotates, right-drag pans, scroll zooms.
+pub struct OrbitController;
+
+impl CameraController for OrbitController {
+    fn identifier(&self) -> &'static str {
+        "orbit"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Orbit"
+    }
+
+    fn update(&self, eye: &mut ViewEye, input: &CameraControllerInput<'_>) -> bool {
+        eye.set_kind(Eye3DKind::Orbital);
+        eye.handle_input(input.response, input.drag_threshold, input.speed)
+    }
+}
+
+/// Flies like in a first-person game. Drag to look around, WASD and QE to move.
+pub struct FlyController;
+
+impl CameraController for FlyController {
+    fn identifier(&self) -> &'static str {
+        "fly"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Fly"
+    }
+
+    fn update(&self, eye: &mut ViewEye, input: &CameraControllerInput<'_>) -> bool {
+        eye.set_kind(Eye3DKind::FirstPerson);
+        eye.handle_input(input.response, input.drag_threshold, input.speed)
+    }
+}
+
+/// Spins the scene around its up axis like on a turntable, without rolling or panning.
+pub struct TurntableController;
+
+impl CameraController for TurntableController {
+    fn identifier(&self) -> &'static str {
+        "turntable"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Turntable"
+    }
+
+    fn update(&self, eye: &mut ViewEye, input: &CameraControllerInput<'_>) -> bool {
+        eye.set_kind(Eye3DKind::Orbital);
+
+        // Keep the eye upright, undoing any earlier roll.
+        let up = input.scene_up.unwrap_or(Vec3::Z);
+        eye.look_in_direction(eye.fwd(), up);
+
+        let response = input.response;
+        let mut did_interact = response.drag_delta().length() > 0.0;
+        if response.drag_delta().length() > input.drag_threshold
+            && response.dragged_by(ROTATE3D_BUTTON)
+        {
+            eye.rotate(response.drag_delta());
+        }
+        if response.hovered() {
+            did_interact |= eye.zoom(&response.ctx, input.speed);
+        }
+        did_interact
+    }
+}
+
+/// Looks straight down at the scene like at a map. Drag pans, scroll zooms, and rotation is locked.
+pub struct TopDownController;
+
+impl CameraController for TopDownController {
+    fn identifier(&self) -> &'static str {
+        "top_down"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Top-down (2D)"
+    }
+
+    fn update(&self, eye: &mut ViewEye, input: &CameraControllerInput<'_>) -> bool {
+        eye.set_kind(Eye3DKind::Orbital);
+
+        let up = input.scene_up.unwrap_or(Vec3::Z);
+        eye.look_in_direction(-up, any_orthogonal(up));
+
+        let response = input.response;
+        let mut did_interact = response.drag_delta().length() > 0.0;
+        if response.drag_delta().length() > input.drag_threshold
+            && (response.dragged_by(ROTATE3D_BUTTON) || response.dragged_by(DRAG_PAN3D_BUTTON))
+        {
+            // Move the map with the pointer, whatever the zoom level.
+            let pan_speed = 0.002 * eye.orbit_radius().unwrap_or(1.0);
+            eye.translate(pan_speed * response.drag_delta());
+        }
+        if response.hovered() {
+            did_interact |= eye.zoom(&response.ctx, input.speed);
+        }
+        did_interact
+    }
+}
+
+/// The screen-up direction of a top-down eye looking along `-up`.
+fn any_orthogonal(up: Vec3) -> Vec3 {
+    // Prefer +Y, like 2D views do, unless that is the up axis itself.
+    if up.abs_diff_eq(Vec3::Y, 1e-3) || up.abs_diff_eq(-Vec3::Y, 1e-3) {
+        Vec3::Z
+    } else {
+        Vec3::Y.reject_from_normalized(up).normalize_or(Vec3::X)
+    }
+}
+
+/// Rotation of an eye at the origin looking along `forward`, with `up` pointing up on screen.
+pub(crate) fn rotation_looking_in_direction(forward: Vec3, up: Vec3) -> Option<Quat> {
+    let forward = forward.try_normalize()?;
+    if forward.cross(up).length_squared() < 1e-6 {
+        return None;
+    }
+    Some(Quat::from_affine3(
+        &Affine3A::look_at_rh(Vec3::ZERO, forward, up).inverse(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registration_replaces_same_identifier() {
+        let view_class = ViewClassIdentifier::from("CameraControllerTestView");
+        register_builtin_camera_controllers(view_class);
+        register_camera_controller(view_class, OrbitController);
+
+        let identifiers: Vec<_> = camera_controllers(view_class)
+            .iter()
+            .map(|c| c.identifier())
+            .collect();
+        assert_eq!(identifiers, ["fly", "turntable", "top_down", "orbit"]);
+    }
+
+    #[test]
+    fn looking_straight_down() {
+        let rotation = rotation_looking_in_direction(-Vec3::Z, Vec3::Y).unwrap();
+        // View space is RUB, so the eye looks along its -Z.
+        assert!((rotation * -Vec3::Z).abs_diff_eq(-Vec3::Z, 1e-5));
+        assert!((rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 1e-5));
+
+        assert_eq!(rotation_looking_in_direction(Vec3::Z, Vec3::Z), None);
+        assert!(any_orthogonal(Vec3::Z).dot(Vec3::Z).abs() < 1e-5);
+        assert!(any_orthogonal(Vec3::Y).dot(Vec3::Y).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,382 @@
+//! Camera keyframes in a 3D view, and flying through them.
+//!
+//! The keyframes are stored in the blueprint, next to the other properties of the view.
+
+use glam::{Quat, Vec3};
+use macaw::IsoTransform;
+
+use re_log_types::{TimeInt, TimelineName};
+use re_types::{
+    blueprint::{archetypes::CameraPath3D, components::TimelineName as TimelineNameComponent},
+    components::{Position3D, RotationQuat, Scalar},
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{eye::Eye, ui_3d::View3DState};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    /// See [`CameraPath::timeline`].
+    pub time: f64,
+
+    pub eye: Eye,
+}
+
+/// Keyframes of the eye of a 3D view.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CameraPath {
+    /// The timeline the keyframe times are on.
+    ///
+    /// If `None`, keyframe times are wall-clock seconds since the start of the playback.
+    pub timeline: Option<TimelineName>,
+
+    /// Sorted by time.
+    pub keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraPath {
+    fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+        ViewProperty::from_archetype::<CameraPath3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        )
+    }
+
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Self {
+        let property = Self::property(ctx, view_id);
+
+        let times = property
+            .component_array_or_empty::<Scalar>(&CameraPath3D::descriptor_times())
+            .unwrap_or_default();
+        let positions = property
+            .component_array_or_empty::<Position3D>(&CameraPath3D::descriptor_positions())
+            .unwrap_or_default();
+        let rotations = property
+            .component_array_or_empty::<RotationQuat>(&CameraPath3D::descriptor_rotations())
+            .unwrap_or_default();
+        let fields_of_view = property
+            .component_array_or_empty::<Scalar>(&CameraPath3D::descriptor_fields_of_view())
+            .unwrap_or_default();
+
+        let keyframes = itertools::izip!(times, positions, rotations, fields_of_view)
+            .filter_map(|(time, position, rotation, fov_y)| {
+                let rotation = Quat::try_from(rotation.0).ok()?;
+                Some(CameraKeyframe {
+                    time: time.0.0,
+                    eye: Eye {
+                        world_from_rub_view: IsoTransform::from_rotation_translation(
+                            rotation,
+                            Vec3::from(position),
+                        ),
+                        // Orthographic eyes are stored as NaN.
+                        fov_y: Some(fov_y.0.0 as f32).filter(|fov_y| fov_y.is_finite()),
+                    },
+                })
+            })
+            .collect();
+
+        Self {
+            timeline: property
+                .component_or_empty::<TimelineNameComponent>(&CameraPath3D::descriptor_timeline())
+                .ok()
+                .flatten()
+                .map(Into::into),
+            keyframes,
+        }
+    }
+
+    pub fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = Self::property(ctx, view_id);
+
+        let times: Vec<Scalar> = self
+            .keyframes
+            .iter()
+            .map(|k| Scalar::from(k.time))
+            .collect();
+        let positions: Vec<Position3D> = self
+            .keyframes
+            .iter()
+            .map(|k| {
+                let position = k.eye.pos_in_world();
+                Position3D::new(position.x, position.y, position.z)
+            })
+            .collect();
+        let rotations: Vec<RotationQuat> = self
+            .keyframes
+            .iter()
+            .map(|k| RotationQuat::from(k.eye.world_from_rub_view.rotation()))
+            .collect();
+        let fields_of_view: Vec<Scalar> = self
+            .keyframes
+            .iter()
+            .map(|k| Scalar::from(k.eye.fov_y.map_or(f64::NAN, f64::from)))
+            .collect();
+
+        property.save_blueprint_component(ctx, &CameraPath3D::descriptor_times(), &times);
+        property.save_blueprint_component(ctx, &CameraPath3D::descriptor_positions(), &positions);
+        property.save_blueprint_component(ctx, &CameraPath3D::descriptor_rotations(), &rotations);
+        property.save_blueprint_component(
+            ctx,
+            &CameraPath3D::descriptor_fields_of_view(),
+            &fields_of_view,
+        );
+        if let Some(timeline) = &self.timeline {
+            property.save_blueprint_component(
+                ctx,
+                &CameraPath3D::descriptor_timeline(),
+                &TimelineNameComponent::from(timeline.as_str()),
+            );
+        } else {
+            property.clear_blueprint_component(ctx, CameraPath3D::descriptor_timeline());
+        }
+    }
+
+    /// Adds a keyframe, replacing any existing one at the same time.
+    pub fn add_keyframe(&mut self, time: f64, eye: Eye) {
+        let index = self.keyframes.partition_point(|k| k.time < time);
+        if self.keyframes.get(index).is_some_and(|k| k.time == time) {
+            self.keyframes[index].eye = eye;
+        } else {
+            self.keyframes.insert(index, CameraKeyframe { time, eye });
+        }
+    }
+
+    /// Sorts the keyframes by time, e.g. after editing them.
+    pub fn sort(&mut self) {
+        self.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+    }
+
+    pub fn end_time(&self) -> Option<f64> {
+        self.keyframes.last().map(|k| k.time)
+    }
+
+    /// The eye at the given time, clamped to the first and last keyframes.
+    ///
+    /// Positions follow a Catmull-Rom spline through the keyframes, so fly-throughs don't turn abruptly.
+    pub fn eye_at(&self, time: f64) -> Option<Eye> {
+        let keyframes = &self.keyframes;
+        let first = keyframes.first()?;
+        let last = keyframes.last()?;
+        if time <= first.time {
+            return Some(first.eye);
+        }
+        if time >= last.time {
+            return Some(last.eye);
+        }
+
+        let next = keyframes.partition_point(|k| k.time <= time);
+        let (k1, k2) = (&keyframes[next - 1], &keyframes[next]);
+        let k0 = &keyframes[next.saturating_sub(2)];
+        let k3 = keyframes.get(next + 1).unwrap_or(k2);
+
+        let t = ((time - k1.time) / (k2.time - k1.time)) as f32;
+        let mut eye = k1.eye.lerp(&k2.eye, t);
+        eye.world_from_rub_view = IsoTransform::from_rotation_translation(
+            eye.world_from_rub_view.rotation(),
+            catmull_rom([k0, k1, k2, k3].map(|k| k.eye.pos_in_world()), t),
+        );
+        Some(eye)
+    }
+}
+
+fn catmull_rom([p0, p1, p2, p3]: [Vec3; 4], t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * ((2.0 * p1)
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// How the eye follows a [`CameraPath`].
+#[derive(Clone, Copy, Debug)]
+pub enum CameraPathPlayback {
+    /// Plays the keyframes in real time, started at the given instant.
+    WallClock(web_time::Instant),
+
+    /// Follows the time cursor of the recording.
+    RecordingTime,
+}
+
+/// Wall-clock seconds between a new keyframe and the previous one.
+const DEFAULT_KEYFRAME_INTERVAL: f64 = 2.0;
+
+/// Editing, recording and playing the camera path of a view.
+pub fn camera_path_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    state_3d: &mut View3DState,
+) {
+    let mut camera_path = CameraPath::load(ctx, view_id);
+    let original_path = camera_path.clone();
+
+    let (current_timeline, current_time, time_type) = {
+        let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+        (
+            *time_ctrl.timeline().name(),
+            time_ctrl.time_int(),
+            time_ctrl.time_type(),
+        )
+    };
+
+    ui.horizontal(|ui| {
+        let mut follows_recording = camera_path.timeline.is_some();
+        ui.add_enabled_ui(camera_path.keyframes.is_empty(), |ui| {
+            ui.selectable_value(&mut follows_recording, false, "Wall clock")
+                .on_hover_text("Keyframes are seconds since the start of the playback");
+            ui.selectable_value(&mut follows_recording, true, "Recording time")
+                .on_hover_text(
+                    "Keyframes are times on the timeline, and the camera follows the time cursor",
+                );
+        })
+        .response
+        .on_disabled_hover_text("Clear the keyframes to change the clock");
+        camera_path.timeline =
+            follows_recording.then(|| camera_path.timeline.unwrap_or(current_timeline));
+    });
+
+    let on_other_timeline = camera_path
+        .timeline
+        .is_some_and(|timeline| timeline != current_timeline);
+    if let Some(timeline) = camera_path.timeline.filter(|_| on_other_timeline) {
+        ui.warning_label(format!("Keyframes are on the timeline {timeline:?}"));
+    }
+
+    let mut remove = None;
+    let mut sort = false;
+    for (index, keyframe) in camera_path.keyframes.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            if camera_path.timeline.is_some() {
+                ui.label(time_type.format(
+                    TimeInt::new_temporal(keyframe.time as i64),
+                    ctx.app_options().timestamp_format,
+                ));
+            } else {
+                sort |= ui
+                    .add(
+                        egui::DragValue::new(&mut keyframe.time)
+                            .range(0.0..=f64::INFINITY)
+                            .speed(0.1)
+                            .suffix(" s"),
+                    )
+                    .drag_stopped();
+            }
+            if ui
+                .small_icon_button(&re_ui::icons::VISIBLE, "Go to keyframe")
+                .on_hover_text("Move the camera to this keyframe")
+                .clicked()
+            {
+                state_3d.go_to_eye(keyframe.eye);
+            }
+            if ui
+                .small_icon_button(&re_ui::icons::REMOVE, "Remove keyframe")
+                .clicked()
+            {
+                remove = Some(index);
+            }
+        });
+    }
+    if let Some(index) = remove {
+        camera_path.keyframes.remove(index);
+    }
+    if sort {
+        camera_path.sort();
+    }
+
+    ui.horizontal(|ui| {
+        let time = if camera_path.timeline.is_some() {
+            current_time
+                .filter(|_| !on_other_timeline)
+                .map(|time| time.as_i64() as f64)
+        } else {
+            Some(
+                camera_path
+                    .end_time()
+                    .map_or(0.0, |end_time| end_time + DEFAULT_KEYFRAME_INTERVAL),
+            )
+        };
+        let eye = state_3d.view_eye.map(|view_eye| view_eye.to_eye());
+        if ui
+            .add_enabled(
+                time.is_some() && eye.is_some(),
+                egui::Button::new("Add keyframe"),
+            )
+            .on_hover_text("Add the current camera as a keyframe")
+            .clicked()
+            && let (Some(time), Some(eye)) = (time, eye)
+        {
+            camera_path.add_keyframe(time, eye);
+        }
+
+        let can_play = !camera_path.keyframes.is_empty() && !on_other_timeline;
+        if state_3d.camera_path_playback.is_some() {
+            if ui.button("Stop").clicked() {
+                state_3d.camera_path_playback = None;
+            }
+        } else if ui
+            .add_enabled(can_play, egui::Button::new("Play"))
+            .on_hover_text("Fly the camera along the keyframes")
+            .clicked()
+        {
+            state_3d.camera_path_playback = Some(if camera_path.timeline.is_some() {
+                CameraPathPlayback::RecordingTime
+            } else {
+                CameraPathPlayback::WallClock(web_time::Instant::now())
+            });
+        }
+
+        if ui
+            .add_enabled(
+                !camera_path.keyframes.is_empty(),
+                egui::Button::new("Clear"),
+            )
+            .clicked()
+        {
+            camera_path.keyframes.clear();
+            state_3d.camera_path_playback = None;
+        }
+    });
+
+    if camera_path != original_path {
+        camera_path.save(ctx, view_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eye_at(position: Vec3) -> Eye {
+        Eye {
+            world_from_rub_view: IsoTransform::from_translation(position),
+            fov_y: Some(Eye::DEFAULT_FOV_Y),
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let mut path = CameraPath::default();
+        assert_eq!(path.eye_at(0.0), None);
+
+        path.add_keyframe(2.0, eye_at(Vec3::X * 2.0));
+        path.add_keyframe(0.0, eye_at(Vec3::ZERO));
+        path.add_keyframe(3.0, eye_at(Vec3::X * 3.0));
+        path.add_keyframe(1.0, eye_at(Vec3::X));
+        assert_eq!(
+            path.keyframes.iter().map(|k| k.time).collect::<Vec<_>>(),
+            [0.0, 1.0, 2.0, 3.0]
+        );
+
+        let position = |time| path.eye_at(time).unwrap().pos_in_world();
+        assert_eq!(position(-1.0), Vec3::ZERO);
+        assert_eq!(position(1.0), Vec3::X);
+        assert_eq!(position(4.0), Vec3::X * 3.0);
+
+        // Evenly spaced keyframes on a line stay on it.
+        assert!(position(1.5).abs_diff_eq(Vec3::X * 1.5, 1e-5));
+    }
+}
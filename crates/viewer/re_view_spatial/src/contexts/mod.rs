@@ -11,6 +11,8 @@ pub use transform_tree_context::{TransformInfo, TransformTreeContext, TwoDInThre
 use re_renderer::DepthOffset;
 use re_viewer_context::{Annotations, ViewClassRegistryError};
 
+use crate::point_sizing::PointSizing;
+
 /// Context objects for a single entity in a spatial scene.
 pub struct SpatialSceneEntityContext<'a> {
     pub transform_info: &'a TransformInfo,
@@ -19,6 +21,9 @@ pub struct SpatialSceneEntityContext<'a> {
 
     pub highlight: &'a re_viewer_context::ViewOutlineMasks, // Not part of the context, but convenient to have here.
     pub view_class_identifier: ViewClassIdentifier,
+
+    /// How the radii of points of the entity are interpreted and limited.
+    pub point_sizing: PointSizing,
 }
 
 pub fn register_spatial_contexts(
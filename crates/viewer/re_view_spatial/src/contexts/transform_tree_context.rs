@@ -1,11 +1,17 @@
-use nohash_hasher::IntMap;
+use std::sync::Arc;
 
-use re_chunk_store::LatestAtQuery;
+use ahash::HashMap;
+use nohash_hasher::{IntMap, IntSet};
+
+use re_chunk_store::{ChunkStoreEvent, ChunkStoreGeneration, LatestAtQuery};
 use re_entity_db::{EntityPath, EntityTree};
-use re_log_types::EntityPathHash;
+use re_log_types::{EntityPathHash, TimeInt, TimelineName};
 use re_types::{ArchetypeName, archetypes, components::ImagePlaneDistance};
 use re_view::DataResultQuery as _;
-use re_viewer_context::{DataResultTree, IdentifiedViewSystem, ViewContext, ViewContextSystem};
+use re_viewer_context::{
+    Cache, CacheMemoryReport, DataResultTree, IdentifiedViewSystem, ViewContext, ViewContextSystem,
+    ViewId,
+};
 use vec1::smallvec_v1::SmallVec1;
 
 use crate::{
@@ -17,7 +23,7 @@ use crate::{
 };
 
 // TODO(andreas): this is struct is comically large for what we're doing here. Need to refactor this to make it smaller & more efficient.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TransformInfo {
     /// The transform from the entity to the reference space.
     ///
@@ -45,7 +51,7 @@ pub struct TransformInfo {
     pub twod_in_threed_info: Option<TwoDInThreeDTransformInfo>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TwoDInThreeDTransformInfo {
     /// Pinhole camera ancestor (may be this entity itself).
     ///
@@ -124,6 +130,9 @@ impl TransformInfo {
 /// The renderer then uses this reference space as its world space,
 /// making world and reference space equivalent for a given view.
 ///
+/// The resolved transforms are kept across frames in the [`TransformTreeCache`],
+/// such that only the subtrees of entities whose transforms changed, or that are new, are resolved again.
+///
 /// TODO(#7025): Right now we also do full tree traversal in here to resolve transforms to the root.
 /// However, for views that share the same query, we can easily make all entities relative to the respective origin in a linear pass over all matrices.
 /// (Note that right now the query IS always the same across all views for a given frame since it's just latest-at controlled by the timeline,
@@ -134,7 +143,9 @@ pub struct TransformTreeContext {
     space_origin: EntityPath,
 
     /// All reachable entities.
-    transform_per_entity: IntMap<EntityPathHash, TransformInfo>,
+    ///
+    /// Shared with the [`TransformTreeCache`].
+    transform_per_entity: Arc<IntMap<EntityPathHash, TransformInfo>>,
 }
 
 impl IdentifiedViewSystem for TransformTreeContext {
@@ -189,42 +200,205 @@ impl ViewContextSystem for TransformTreeContext {
         };
 
         let time_query = ctx.current_query();
+        let inputs = TransformTreeInputs {
+            recording_generation: ctx.recording().generation(),
+            blueprint_generation: ctx.blueprint_db().generation(),
+            time: time_query.at(),
+        };
 
-        TransformCacheStoreSubscriber::access(ctx.recording().store_id(), |cache| {
-            let transforms = cache.transforms_for_timeline(query.timeline);
-
-            // Child transforms of this space
-            {
-                re_tracing::profile_scope!("gather_descendants_transforms");
+        let caches = &ctx.viewer_ctx.store_context.caches;
+        let mut previous = caches
+            .entry(|c: &mut TransformTreeCache| c.take(query.view_id))
+            .filter(|previous| {
+                previous.space_origin == self.space_origin && previous.timeline == query.timeline
+            });
 
-                self.gather_descendants_transforms(
-                    ctx,
-                    data_result_tree,
-                    current_tree,
-                    &time_query,
-                    // Ignore potential pinhole camera at the root of the view, since it is regarded as being "above" this root.
-                    TransformInfo::default(),
-                    transforms,
+        let is_resolved =
+            TransformCacheStoreSubscriber::access(ctx.recording().store_id(), |cache| {
+                let transforms = cache.transforms_for_timeline(query.timeline);
+
+                match previous.take() {
+                    Some(previous)
+                        if previous.inputs == inputs && previous.touched_entities.is_empty() =>
+                    {
+                        self.transform_per_entity = previous.transform_per_entity;
+                    }
+
+                    Some(previous) => {
+                        let dirty_entities = previous.dirty_entities(&inputs, transforms);
+                        self.transform_per_entity = previous.transform_per_entity;
+
+                        if !self.update_subtrees(
+                            ctx,
+                            data_result_tree,
+                            dirty_entities,
+                            &time_query,
+                            transforms,
+                        ) {
+                            self.gather_all_transforms(
+                                ctx,
+                                data_result_tree,
+                                current_tree,
+                                &time_query,
+                                transforms,
+                            );
+                        }
+                    }
+
+                    None => {
+                        self.gather_all_transforms(
+                            ctx,
+                            data_result_tree,
+                            current_tree,
+                            &time_query,
+                            transforms,
+                        );
+                    }
+                }
+            })
+            .is_some(); // Note that this can return None if no event has happened for this timeline yet.
+
+        if is_resolved {
+            caches.entry(|c: &mut TransformTreeCache| {
+                c.insert(
+                    query.view_id,
+                    ResolvedTransformTree {
+                        space_origin: self.space_origin.clone(),
+                        timeline: query.timeline,
+                        inputs,
+                        transform_per_entity: self.transform_per_entity.clone(),
+                        touched_entities: Default::default(),
+                        last_used_frame: 0,
+                    },
                 );
-            }
+            });
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
 
-            // Walk up from the reference to the highest reachable parent.
-            self.gather_parent_transforms(
+impl TransformTreeContext {
+    /// Resolves the transforms of all entities from scratch.
+    fn gather_all_transforms(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        data_result_tree: &DataResultTree,
+        current_tree: &EntityTree,
+        time_query: &LatestAtQuery,
+        transforms: &CachedTransformsForTimeline,
+    ) {
+        Arc::make_mut(&mut self.transform_per_entity).clear();
+
+        // Child transforms of this space
+        {
+            re_tracing::profile_scope!("gather_descendants_transforms");
+
+            self.gather_descendants_transforms(
                 ctx,
                 data_result_tree,
                 current_tree,
-                &time_query,
+                time_query,
+                // Ignore potential pinhole camera at the root of the view, since it is regarded as being "above" this root.
+                TransformInfo::default(),
                 transforms,
             );
-        }); // Note that this can return None if no event has happened for this timeline yet.
+        }
+
+        // Walk up from the reference to the highest reachable parent.
+        self.gather_parent_transforms(ctx, data_result_tree, current_tree, time_query, transforms);
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
+    /// Resolves the transforms of the subtrees of the given entities again,
+    /// keeping the transforms of all other entities.
+    ///
+    /// Entities that aren't resolved yet are resolved along with their new parents.
+    /// Returns `false` if this isn't possible, because an entity at or above the reference changed,
+    /// which affects the transforms of all entities.
+    fn update_subtrees(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        data_result_tree: &DataResultTree,
+        dirty_entities: Vec<EntityPath>,
+        time_query: &LatestAtQuery,
+        transforms: &CachedTransformsForTimeline,
+    ) -> bool {
+        re_tracing::profile_function!();
+
+        let entity_tree = ctx.recording().tree();
+
+        let mut subtree_roots = Vec::with_capacity(dirty_entities.len());
+        for mut entity_path in dirty_entities {
+            while let Some(parent) = entity_path.parent()
+                && !self.transform_per_entity.contains_key(&parent.hash())
+            {
+                entity_path = parent;
+            }
+            if self.space_origin.starts_with(&entity_path) {
+                return false;
+            }
+            subtree_roots.push(entity_path);
+        }
+
+        // Updating a subtree updates all subtrees within it, so start with the topmost ones.
+        subtree_roots.sort_by_key(|entity_path| entity_path.len());
+
+        let mut updated = IntSet::<EntityPathHash>::default();
+        for entity_path in subtree_roots {
+            let mut ancestor = entity_path.parent();
+            let mut is_updated = false;
+            while let Some(ancestor_path) = ancestor {
+                if updated.contains(&ancestor_path.hash()) {
+                    is_updated = true;
+                    break;
+                }
+                ancestor = ancestor_path.parent();
+            }
+            if is_updated || !updated.insert(entity_path.hash()) {
+                continue;
+            }
+
+            let Some(subtree) = entity_tree.subtree(&entity_path) else {
+                // No longer part of the tree.
+                continue;
+            };
+            let Some(parent_transform) = entity_path
+                .parent()
+                .and_then(|parent| self.transform_per_entity.get(&parent.hash()))
+                .cloned()
+            else {
+                return false;
+            };
+
+            let transform_per_entity = Arc::make_mut(&mut self.transform_per_entity);
+            subtree.visit_children_recursively(|entity_path| {
+                transform_per_entity.remove(&entity_path.hash());
+            });
+
+            let transform = downward_transform_info(
+                ctx,
+                data_result_tree,
+                &entity_path,
+                time_query,
+                parent_transform.reference_from_entity,
+                parent_transform.twod_in_threed_info.as_ref(),
+                transforms,
+            );
+            self.gather_descendants_transforms(
+                ctx,
+                data_result_tree,
+                subtree,
+                time_query,
+                transform,
+                transforms,
+            );
+        }
+
+        true
     }
-}
 
-impl TransformTreeContext {
     /// Gather transforms for everything _above_ the root.
     fn gather_parent_transforms<'a>(
         &mut self,
@@ -292,7 +466,7 @@ impl TransformTreeContext {
     ) {
         let twod_in_threed_info = transform.twod_in_threed_info.clone();
         let reference_from_parent = transform.reference_from_entity;
-        match self.transform_per_entity.entry(subtree.path.hash()) {
+        match Arc::make_mut(&mut self.transform_per_entity).entry(subtree.path.hash()) {
             std::collections::hash_map::Entry::Occupied(_) => {
                 return;
             }
@@ -302,27 +476,14 @@ impl TransformTreeContext {
         }
 
         for child_tree in subtree.children.values() {
-            let child_path = &child_tree.path;
-
-            let lookup_image_plane =
-                |p: &_| lookup_image_plane_distance(ctx, data_result_tree, p, query);
-
-            let mut encountered_pinhole = twod_in_threed_info
-                .as_ref()
-                .map(|info| info.parent_pinhole.clone());
-
-            let transforms_at_entity = transforms_at(
-                child_path,
+            let new_transform = downward_transform_info(
+                ctx,
+                data_result_tree,
+                &child_tree.path,
                 query,
-                lookup_image_plane,
-                &mut encountered_pinhole,
-                transforms_for_timeline,
-            );
-            let new_transform = transform_info_for_downward_propagation(
-                child_path,
                 reference_from_parent,
-                twod_in_threed_info.clone(),
-                &transforms_at_entity,
+                twod_in_threed_info.as_ref(),
+                transforms_for_timeline,
             );
 
             self.gather_descendants_transforms(
@@ -348,6 +509,187 @@ impl TransformTreeContext {
     }
 }
 
+/// What the resolved transforms of a view depend on, besides the transforms in the store.
+#[derive(Clone, PartialEq, Eq)]
+struct TransformTreeInputs {
+    recording_generation: ChunkStoreGeneration,
+
+    /// Image plane distances may be overridden in the blueprint.
+    blueprint_generation: ChunkStoreGeneration,
+
+    time: TimeInt,
+}
+
+/// The transforms a [`TransformTreeContext`] resolved for a view in an earlier frame.
+struct ResolvedTransformTree {
+    space_origin: EntityPath,
+    timeline: TimelineName,
+    inputs: TransformTreeInputs,
+    transform_per_entity: Arc<IntMap<EntityPathHash, TransformInfo>>,
+
+    /// Entities that received data since, some of which may not be resolved yet.
+    touched_entities: IntMap<EntityPathHash, EntityPath>,
+
+    last_used_frame: u64,
+}
+
+impl ResolvedTransformTree {
+    /// Entities whose transforms may have changed since they were resolved, and new entities.
+    ///
+    /// Resolving the subtrees of these again gives the same result as resolving everything from scratch.
+    /// They are derived from the received data and the times at which the transform cache changes,
+    /// such that entities without any changes aren't even looked at.
+    fn dirty_entities(
+        &self,
+        inputs: &TransformTreeInputs,
+        transforms: &CachedTransformsForTimeline,
+    ) -> Vec<EntityPath> {
+        re_tracing::profile_function!();
+
+        let mut dirty_entities = IntMap::<EntityPathHash, EntityPath>::default();
+        let mut mark_dirty = |entity_path: &EntityPath| {
+            dirty_entities
+                .entry(entity_path.hash())
+                .or_insert_with(|| entity_path.clone());
+        };
+
+        // New transforms may apply at any time, including the current one.
+        #[expect(clippy::iter_over_hash_type)] // Order doesn't matter.
+        for (entity_path_hash, entity_path) in &self.touched_entities {
+            if !self.transform_per_entity.contains_key(entity_path_hash)
+                || transforms.entity_transforms(entity_path).is_some()
+            {
+                mark_dirty(entity_path);
+            }
+        }
+
+        if self.inputs.time != inputs.time {
+            for entity_path in transforms.entities_changed_between(self.inputs.time, inputs.time) {
+                mark_dirty(entity_path);
+            }
+        } else if self.inputs.blueprint_generation != inputs.blueprint_generation {
+            for entity_path in transforms.volatile_entities() {
+                mark_dirty(entity_path);
+            }
+        }
+
+        dirty_entities.into_values().collect()
+    }
+}
+
+/// Keeps the resolved transforms of each view across frames,
+/// such that [`TransformTreeContext`] only needs to resolve what changed.
+///
+/// This avoids walking the whole entity tree every frame for recordings with many entities.
+#[derive(Default)]
+pub struct TransformTreeCache {
+    frame_nr: u64,
+    per_view: HashMap<ViewId, ResolvedTransformTree>,
+}
+
+impl TransformTreeCache {
+    /// Views that weren't executed for this many frames, e.g. because they were hidden, are forgotten.
+    ///
+    /// Not executing a view for a few frames is common, since their output is reused while nothing changes.
+    const MAX_UNUSED_FRAMES: u64 = 600;
+
+    /// Takes the transforms resolved for a view, to update them without holding on to the cache.
+    fn take(&mut self, view_id: ViewId) -> Option<ResolvedTransformTree> {
+        self.per_view.remove(&view_id)
+    }
+
+    fn insert(&mut self, view_id: ViewId, mut resolved: ResolvedTransformTree) {
+        resolved.last_used_frame = self.frame_nr;
+        self.per_view.insert(view_id, resolved);
+    }
+}
+
+impl Cache for TransformTreeCache {
+    fn begin_frame(&mut self) {
+        self.frame_nr += 1;
+        let frame_nr = self.frame_nr;
+        self.per_view
+            .retain(|_, resolved| frame_nr - resolved.last_used_frame <= Self::MAX_UNUSED_FRAMES);
+    }
+
+    fn purge_memory(&mut self) {
+        self.per_view.clear();
+    }
+
+    fn name(&self) -> &'static str {
+        "Transform Trees"
+    }
+
+    fn memory_report(&self) -> CacheMemoryReport {
+        let bytes_cpu = self
+            .per_view
+            .values()
+            .map(|resolved| {
+                (resolved.transform_per_entity.len() * std::mem::size_of::<TransformInfo>()) as u64
+            })
+            .sum();
+
+        CacheMemoryReport {
+            bytes_cpu,
+            bytes_gpu: None,
+            per_cache_item_info: Vec::new(),
+        }
+    }
+
+    fn on_store_events(&mut self, events: &[&ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        for event in events {
+            if event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion {
+                // Removed entities would otherwise keep their transforms, so resolve everything again.
+                self.per_view.clear();
+                return;
+            }
+
+            let entity_path = event.chunk.entity_path();
+            #[expect(clippy::iter_over_hash_type)] // Order doesn't matter.
+            for resolved in self.per_view.values_mut() {
+                resolved
+                    .touched_entities
+                    .insert(entity_path.hash(), entity_path.clone());
+            }
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Transform info of a child, given the transform of its parent.
+fn downward_transform_info(
+    ctx: &ViewContext<'_>,
+    data_result_tree: &DataResultTree,
+    child_path: &EntityPath,
+    query: &LatestAtQuery,
+    reference_from_parent: glam::Affine3A,
+    twod_in_threed_info: Option<&TwoDInThreeDTransformInfo>,
+    transforms_for_timeline: &CachedTransformsForTimeline,
+) -> TransformInfo {
+    let lookup_image_plane = |p: &_| lookup_image_plane_distance(ctx, data_result_tree, p, query);
+
+    let mut encountered_pinhole = twod_in_threed_info.map(|info| info.parent_pinhole.clone());
+
+    let transforms_at_entity = transforms_at(
+        child_path,
+        query,
+        lookup_image_plane,
+        &mut encountered_pinhole,
+        transforms_for_timeline,
+    );
+    transform_info_for_downward_propagation(
+        child_path,
+        reference_from_parent,
+        twod_in_threed_info.cloned(),
+        &transforms_at_entity,
+    )
+}
+
 fn lookup_image_plane_distance(
     ctx: &ViewContext<'_>,
     data_result_tree: &DataResultTree,
@@ -649,3 +991,173 @@ fn transforms_at<'a>(
 
     transforms_at_entity
 }
+
+#[cfg(test)]
+mod tests {
+    use re_chunk_store::{Chunk, RowId};
+    use re_log_types::{TimePoint, build_frame_nr};
+    use re_test_context::TestContext;
+    use re_test_viewport::TestContextExt as _;
+    use re_types::{archetypes::Clear, components::TransformInterpolation};
+    use re_viewer_context::{ViewClass as _, ViewClassExt as _, ViewQuery};
+    use re_viewport_blueprint::ViewBlueprint;
+
+    use crate::{SpatialView3D, SpatialViewState};
+
+    use super::*;
+
+    /// Moves the time cursor, runs the context incrementally, and compares it to resolving everything from scratch.
+    fn execute_at_frame(test_context: &TestContext, view_id: ViewId, frame_nr: i64) {
+        let (timeline, time) = build_frame_nr(frame_nr);
+        {
+            let mut time_ctrl = test_context.recording_config.time_ctrl.write();
+            time_ctrl.set_timeline(timeline);
+            time_ctrl.set_time(time);
+        }
+
+        test_context.run_once_in_egui_central_panel(|ctx, _ui| {
+            let view_state = SpatialViewState::default();
+            let view_ctx = SpatialView3D::default().view_context(ctx, view_id, &view_state);
+            let space_origin = EntityPath::root();
+            let query = ViewQuery {
+                view_id,
+                space_origin: &space_origin,
+                per_visualizer_data_results: Default::default(),
+                timeline: *timeline.name(),
+                latest_at: time,
+                highlights: Default::default(),
+            };
+
+            let mut incremental = TransformTreeContext::default();
+            incremental.execute(&view_ctx, &query);
+
+            let mut full = TransformTreeContext::default();
+            let entity_tree = ctx.recording().tree();
+            let data_result_tree = &ctx.lookup_query_result(view_id).tree;
+            TransformCacheStoreSubscriber::access(ctx.recording().store_id(), |cache| {
+                full.gather_all_transforms(
+                    &view_ctx,
+                    data_result_tree,
+                    entity_tree,
+                    &ctx.current_query(),
+                    cache.transforms_for_timeline(*timeline.name()),
+                );
+            });
+
+            assert!(!full.transform_per_entity.is_empty());
+            assert!(
+                incremental.transform_per_entity == full.transform_per_entity,
+                "incremental update at frame {frame_nr} differs from resolving everything"
+            );
+        });
+    }
+
+    #[test]
+    fn incremental_update_matches_full_resolve() {
+        let mut test_context = TestContext::new_with_view_class::<SpatialView3D>();
+
+        for frame_nr in [0, 2, 4] {
+            test_context.log_entity("a", |builder| {
+                builder.with_archetype(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    &archetypes::Transform3D::from_translation([frame_nr as f32, 0.0, 0.0]),
+                )
+            });
+        }
+        for frame_nr in [1, 3] {
+            test_context.log_entity("a/b", |builder| {
+                builder.with_archetype(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    &archetypes::Transform3D::from_scale(frame_nr as f32),
+                )
+            });
+        }
+        test_context.log_entity("a/b/c", |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                TimePoint::STATIC,
+                &archetypes::Points3D::new([[0.0, 0.0, 0.0]]),
+            )
+        });
+        test_context.log_entity("a/b", |builder| {
+            builder.with_archetype(RowId::new(), [build_frame_nr(4)], &Clear::recursive())
+        });
+
+        test_context.log_entity("d", |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                TimePoint::STATIC,
+                &archetypes::Transform3D::from_translation([0.0, 1.0, 0.0]),
+            )
+        });
+        for frame_nr in [0, 4] {
+            test_context.log_entity("d/lerp", |builder| {
+                builder.with_archetype(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    &archetypes::Transform3D::from_translation([0.0, 0.0, frame_nr as f32])
+                        .with_interpolation(TransformInterpolation::Linear),
+                )
+            });
+        }
+
+        test_context.log_entity("camera", |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                [build_frame_nr(0)],
+                &archetypes::Pinhole::from_focal_length_and_resolution([1.0, 1.0], [2.0, 2.0]),
+            )
+        });
+        test_context.log_entity("camera", |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                [build_frame_nr(2)],
+                &archetypes::Pinhole::update_fields().with_image_plane_distance(5.0),
+            )
+        });
+        test_context.log_entity("camera/image", |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                TimePoint::STATIC,
+                &archetypes::Points2D::new([[0.0, 0.0]]),
+            )
+        });
+
+        test_context.set_active_timeline(build_frame_nr(0).0);
+        let view_id = test_context.setup_viewport_blueprint(|_ctx, blueprint| {
+            blueprint.add_view_at_root(ViewBlueprint::new_with_root_wildcard(
+                SpatialView3D::identifier(),
+            ))
+        });
+
+        for frame_nr in [0, 1, 3, 3, 2, 4, 0, 4, 1] {
+            execute_at_frame(&test_context, view_id, frame_nr);
+        }
+
+        // New data arrives for an entity that had no transforms, at a time before the time cursor.
+        let chunk = Chunk::builder("a/b/c")
+            .with_archetype(
+                RowId::new(),
+                [build_frame_nr(0)],
+                &archetypes::Transform3D::from_translation([0.0, 0.0, 1.0]),
+            )
+            .build()
+            .unwrap();
+        let events = test_context
+            .store_hub
+            .get_mut()
+            .active_recording_mut()
+            .unwrap()
+            .add_chunk(&Arc::new(chunk))
+            .unwrap();
+        test_context.run_once_in_egui_central_panel(|ctx, _ui| {
+            ctx.store_context.caches.on_store_events(&events);
+        });
+
+        for frame_nr in [1, 1, 3] {
+            execute_at_frame(&test_context, view_id, frame_nr);
+        }
+    }
+}
@@ -12,10 +12,16 @@ use re_view::controls::{
     DRAG_PAN3D_BUTTON, ROLL_MOUSE, ROLL_MOUSE_ALT, ROLL_MOUSE_MODIFIER, ROTATE3D_BUTTON,
     RuntimeModifiers, SPEED_UP_3D_MODIFIER,
 };
-use re_viewer_context::{TypedComponentFallbackProvider, ViewContext, ViewStateExt as _};
+use re_viewer_context::{
+    GamepadAxes, TypedComponentFallbackProvider, ViewContext, ViewStateExt as _,
+};
 use re_viewport_blueprint::ViewProperty;
 
-use crate::{SpatialViewState, space_camera_3d::SpaceCamera3D};
+use crate::{
+    SpatialViewState,
+    camera_controller::{CameraController, CameraControllerInput, rotation_looking_in_direction},
+    space_camera_3d::SpaceCamera3D,
+};
 
 /// An eye in a 3D view.
 ///
@@ -124,6 +130,10 @@ impl Eye {
         self.world_from_rub_view.rotation() * -Vec3::Z // because we use RUB
     }
 
+    pub fn up_in_world(&self) -> glam::Vec3 {
+        self.world_from_rub_view.rotation() * Vec3::Y // because we use RUB
+    }
+
     pub fn lerp(&self, other: &Self, t: f32) -> Self {
         let translation = self
             .world_from_rub_view
@@ -330,8 +340,22 @@ impl ViewEye {
         }
     }
 
+    /// Turns the eye to look along `forward`, with `up` pointing up on screen, without moving it.
+    pub fn look_in_direction(&mut self, forward: Vec3, up: Vec3) {
+        let Some(world_from_view_rot) = rotation_looking_in_direction(forward, up) else {
+            return;
+        };
+        let position = self.position();
+        self.world_from_view_rot = world_from_view_rot;
+        self.eye_up = up.normalize_or_zero();
+        self.center = match self.kind {
+            Eye3DKind::FirstPerson => position,
+            Eye3DKind::Orbital => position + self.orbit_radius * self.fwd(),
+        };
+    }
+
     /// World-direction we are looking at
-    fn fwd(&self) -> Vec3 {
+    pub fn fwd(&self) -> Vec3 {
         self.world_from_view_rot * -Vec3::Z // view-coordinates are RUB
     }
 
@@ -348,12 +372,16 @@ impl ViewEye {
 
     /// Returns `true` if interaction occurred.
     /// I.e. the camera changed via user input.
+    ///
+    /// Without a `controller`, the input is handled according to the [`Eye3DKind`] of the eye controls.
     pub fn update(
         &mut self,
         response: &egui::Response,
         drag_threshold: f32,
         view_ctx: &ViewContext<'_>,
         eye_property: &ViewProperty,
+        controller: Option<&dyn CameraController>,
+        scene_up: Option<Vec3>,
     ) -> bool {
         let mut speed = **eye_property
             .component_or_fallback::<LinearSpeed>(
@@ -375,6 +403,18 @@ impl ViewEye {
             }
         });
 
+        if let Some(controller) = controller {
+            return controller.update(
+                self,
+                &CameraControllerInput {
+                    response,
+                    drag_threshold,
+                    speed: speed as f32,
+                    scene_up,
+                },
+            );
+        }
+
         let kind = eye_property.component_or_fallback::<Eye3DKind>(
             view_ctx,
             self,
@@ -387,11 +427,30 @@ impl ViewEye {
             }
         }
 
+        self.handle_input(response, drag_threshold, speed as f32)
+    }
+
+    /// The built-in handling of input: rotating, panning, rolling, WSAD and zooming.
+    ///
+    /// Returns `true` if interaction occurred.
+    pub fn handle_input(
+        &mut self,
+        response: &egui::Response,
+        drag_threshold: f32,
+        speed: f32,
+    ) -> bool {
         // Dragging even below the [`drag_threshold`] should be considered interaction.
         // Otherwise we flicker in and out of "has interacted" too quickly.
         let mut did_interact = response.drag_delta().length() > 0.0;
 
-        if response.drag_delta().length() > drag_threshold {
+        if let Some(gesture) = re_ui::touch::multi_finger_gesture(response) {
+            // Dragging two fingers pans and twisting them rolls, while pinching zooms like scrolling does.
+            // The first finger also drags the pointer, which must not rotate the eye meanwhile.
+            let pan_speed = 0.001 * speed;
+            self.translate(pan_speed * gesture.translation_delta);
+            self.roll_by(gesture.rotation_delta);
+            did_interact = true;
+        } else if response.drag_delta().length() > drag_threshold {
             let roll = response.dragged_by(ROLL_MOUSE)
                 || (response.dragged_by(ROLL_MOUSE_ALT)
                     && response
@@ -407,24 +466,61 @@ impl ViewEye {
                 // The pan speed is selected to make the panning feel natural for orbit mode,
                 // but it should probably take FOV and screen size into account
                 let pan_speed = 0.001 * speed;
-                let delta_in_view = pan_speed as f32 * response.drag_delta();
+                let delta_in_view = pan_speed * response.drag_delta();
 
                 self.translate(delta_in_view);
             }
         }
 
         if response.hovered() {
-            did_interact |= self.keyboard_navigation(&response.ctx, speed as f32);
-            did_interact |= self.zoom(&response.ctx, speed as f32);
+            did_interact |= self.keyboard_navigation(&response.ctx, speed);
+            did_interact |= self.zoom(&response.ctx, speed);
+        }
+
+        // The gamepad flies the eye of the 3D view that was hovered last.
+        let gamepad_view_id = egui::Id::new("gamepad_view");
+        let gamepad_view = response.ctx.data_mut(|data| {
+            if response.hovered() {
+                data.insert_temp(gamepad_view_id, response.id);
+            }
+            *data.get_temp_mut_or_insert_with(gamepad_view_id, || response.id)
+        });
+        if gamepad_view == response.id {
+            did_interact |= self.gamepad_navigation(&response.ctx, speed);
         }
 
         did_interact
     }
 
+    /// Fly with the sticks and triggers of a gamepad, see [`GamepadAxes`].
+    ///
+    /// The left stick moves like WASD, the right stick looks around and the triggers move down and up.
+    ///
+    /// Returns `true` if we did anything.
+    pub fn gamepad_navigation(&mut self, egui_ctx: &egui::Context, speed: f32) -> bool {
+        /// How fast a fully tilted stick turns the eye, in the points per second of an equivalent drag.
+        const LOOK_SPEED: f32 = 400.0;
+
+        let axes = GamepadAxes::load(egui_ctx);
+        if axes.is_zero() {
+            return false;
+        }
+
+        let dt = egui_ctx.input(|input| input.stable_dt.at_most(0.1));
+
+        // X=right, Y=up, Z=back
+        let local_movement = vec3(axes.move_stick.x, axes.vertical, -axes.move_stick.y);
+        self.center += self.world_from_view_rot * (speed * dt * local_movement);
+        self.rotate(LOOK_SPEED * dt * egui::vec2(axes.look_stick.x, -axes.look_stick.y));
+
+        egui_ctx.request_repaint();
+        true
+    }
+
     /// Listen to WSAD and QE to move the eye.
     ///
     /// Returns `true` if we did anything.
-    fn keyboard_navigation(&mut self, egui_ctx: &egui::Context, speed: f32) -> bool {
+    pub fn keyboard_navigation(&mut self, egui_ctx: &egui::Context, speed: f32) -> bool {
         let anything_has_focus = egui_ctx.memory(|mem| mem.focused().is_some());
         if anything_has_focus {
             return false; // e.g. we're typing in a TextField
@@ -469,7 +565,7 @@ impl ViewEye {
     /// Handle zoom/scroll input.
     ///
     /// Returns `true` if we did anything.
-    fn zoom(&mut self, egui_ctx: &egui::Context, speed: f32) -> bool {
+    pub fn zoom(&mut self, egui_ctx: &egui::Context, speed: f32) -> bool {
         let zoom_factor = egui_ctx.input(|input| {
             let (zoom_delta, scroll_delta) = (input.zoom_delta(), input.smooth_scroll_delta.y);
             zoom_delta * (scroll_delta / 200.0).exp()
@@ -536,10 +632,15 @@ impl ViewEye {
     }
 
     /// Rotate around forward axis
-    fn roll(&mut self, rect: &egui::Rect, pointer_pos: egui::Pos2, delta: egui::Vec2) {
+    pub fn roll(&mut self, rect: &egui::Rect, pointer_pos: egui::Pos2, delta: egui::Vec2) {
         // steering-wheel model
         let rel = pointer_pos - rect.center();
         let delta_angle = delta.rot90().dot(rel) / rel.length_sq();
+        self.roll_by(delta_angle);
+    }
+
+    /// Rolls the eye around its view direction by the given angle in radians.
+    pub fn roll_by(&mut self, delta_angle: f32) {
         let rot_delta = Quat::from_rotation_z(delta_angle);
 
         let up_in_view = self.world_from_view_rot.inverse() * self.eye_up;
@@ -555,7 +656,7 @@ impl ViewEye {
     }
 
     /// Given a delta in view-space, translate the eye.
-    fn translate(&mut self, delta_in_view: egui::Vec2) {
+    pub fn translate(&mut self, delta_in_view: egui::Vec2) {
         let up = self.world_from_view_rot * Vec3::Y;
         let right = self.world_from_view_rot * -Vec3::X; // TODO(emilk): why do we need a negation here? O.o
 
@@ -0,0 +1,462 @@
+//! Rulers along the edges and a grid over the contents of a 2D view,
+//! for reviewing image annotations or floorplan-like data against scene coordinates.
+//!
+//! The grid is stored in the blueprint, next to the other properties of the view.
+
+use egui::{Align2, Pos2, Rect, Shape, Stroke, emath::RectTransform, pos2, vec2};
+
+use re_types::{
+    ComponentDescriptor,
+    blueprint::{
+        archetypes,
+        components::{Enabled, GridSpacing},
+    },
+    components::Scalar,
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+/// Thickness of the rulers, in ui points.
+const RULER_THICKNESS: f32 = 18.0;
+
+/// Major lines are at least this far apart with an automatic spacing, in ui points.
+const MIN_AUTOMATIC_SPACING_IN_UI: f32 = 80.0;
+
+/// Lines closer than this are not drawn, in ui points.
+const MIN_LINE_DISTANCE_IN_UI: f32 = 4.0;
+
+fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<archetypes::GridOverlay2D>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// The grid overlay of a 2D view, see the [module docs](self).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridOverlay2D {
+    pub show_grid: bool,
+    pub show_rulers: bool,
+
+    /// Distance between major lines in scene units.
+    ///
+    /// Picked for the zoom level if `None`.
+    pub spacing: Option<f32>,
+
+    /// Number of minor cells each major cell is divided into.
+    pub subdivisions: u32,
+
+    /// Whether the cursor snaps to the nearest intersection of minor lines.
+    pub snap_to_grid: bool,
+}
+
+impl Default for GridOverlay2D {
+    fn default() -> Self {
+        Self {
+            show_grid: false,
+            show_rulers: false,
+            spacing: None,
+            subdivisions: 5,
+            snap_to_grid: false,
+        }
+    }
+}
+
+impl GridOverlay2D {
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Self {
+        let property = property(ctx, view_id);
+
+        let default = Self::default();
+        let enabled = |descriptor: &ComponentDescriptor, default: bool| {
+            property
+                .component_or_empty::<Enabled>(descriptor)
+                .ok()
+                .flatten()
+                .map_or(default, bool::from)
+        };
+        Self {
+            show_grid: enabled(
+                &archetypes::GridOverlay2D::descriptor_show_grid(),
+                default.show_grid,
+            ),
+            show_rulers: enabled(
+                &archetypes::GridOverlay2D::descriptor_show_rulers(),
+                default.show_rulers,
+            ),
+            spacing: property
+                .component_or_empty::<GridSpacing>(&archetypes::GridOverlay2D::descriptor_spacing())
+                .ok()
+                .flatten()
+                .map(|spacing| spacing.0.0)
+                .filter(|spacing| spacing.is_finite() && *spacing > 0.0),
+            subdivisions: property
+                .component_or_empty::<Scalar>(&archetypes::GridOverlay2D::descriptor_subdivisions())
+                .ok()
+                .flatten()
+                .map_or(default.subdivisions, |subdivisions| {
+                    subdivisions.0.0.round().clamp(1.0, 100.0) as u32
+                }),
+            snap_to_grid: enabled(
+                &archetypes::GridOverlay2D::descriptor_snap_to_grid(),
+                default.snap_to_grid,
+            ),
+        }
+    }
+
+    fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = property(ctx, view_id);
+        property.save_blueprint_component(
+            ctx,
+            &archetypes::GridOverlay2D::descriptor_show_grid(),
+            &Enabled::from(self.show_grid),
+        );
+        property.save_blueprint_component(
+            ctx,
+            &archetypes::GridOverlay2D::descriptor_show_rulers(),
+            &Enabled::from(self.show_rulers),
+        );
+        if let Some(spacing) = self.spacing {
+            property.save_blueprint_component(
+                ctx,
+                &archetypes::GridOverlay2D::descriptor_spacing(),
+                &GridSpacing::from(spacing),
+            );
+        } else {
+            property
+                .clear_blueprint_component(ctx, archetypes::GridOverlay2D::descriptor_spacing());
+        }
+        property.save_blueprint_component(
+            ctx,
+            &archetypes::GridOverlay2D::descriptor_subdivisions(),
+            &Scalar::from(self.subdivisions as f64),
+        );
+        property.save_blueprint_component(
+            ctx,
+            &archetypes::GridOverlay2D::descriptor_snap_to_grid(),
+            &Enabled::from(self.snap_to_grid),
+        );
+    }
+
+    /// Distance between major lines in scene units, for a zoom level of `ui_per_scene` ui points per scene unit.
+    pub fn major_spacing(&self, ui_per_scene: f32) -> f32 {
+        self.spacing
+            .unwrap_or_else(|| nice_spacing(MIN_AUTOMATIC_SPACING_IN_UI / ui_per_scene))
+    }
+
+    /// Distance between minor lines in scene units, see [`Self::major_spacing`].
+    pub fn minor_spacing(&self, ui_per_scene: f32) -> f32 {
+        self.major_spacing(ui_per_scene) / self.subdivisions.max(1) as f32
+    }
+
+    /// The cursor position in the scene, snapped to the grid if enabled.
+    pub fn cursor_in_scene(&self, pointer_in_scene: Pos2, ui_per_scene: f32) -> Pos2 {
+        if !self.snap_to_grid {
+            return pointer_in_scene;
+        }
+        let spacing = self.minor_spacing(ui_per_scene);
+        pos2(
+            (pointer_in_scene.x / spacing).round() * spacing,
+            (pointer_in_scene.y / spacing).round() * spacing,
+        )
+    }
+
+    /// Paints the grid, the rulers and the cursor on top of the view.
+    ///
+    /// `pointer_in_ui` is where the view is hovered, if it is.
+    pub fn paint(
+        &self,
+        painter: &egui::Painter,
+        ui_from_scene: &RectTransform,
+        pointer_in_ui: Option<Pos2>,
+    ) {
+        if !self.show_grid && !self.show_rulers {
+            return;
+        }
+        re_tracing::profile_function!();
+
+        let view_rect = *ui_from_scene.to();
+        let scene_rect = ui_from_scene.inverse().transform_rect(view_rect);
+        let ui_per_scene = ui_from_scene.scale().x;
+        if !(ui_per_scene.is_finite() && ui_per_scene > 0.0) {
+            return;
+        }
+
+        let major_spacing = self.major_spacing(ui_per_scene);
+        let minor_spacing = self.minor_spacing(ui_per_scene);
+        let visuals = painter.ctx().style().visuals.clone();
+        let line_color = visuals.text_color();
+
+        if self.show_grid {
+            for (spacing, alpha) in [(minor_spacing, 0.08), (major_spacing, 0.25)] {
+                let stroke = Stroke::new(1.0, line_color.gamma_multiply(alpha));
+                for x in grid_lines(scene_rect.x_range(), spacing, ui_per_scene) {
+                    let x = ui_from_scene.transform_pos(pos2(x, 0.0)).x;
+                    painter.vline(x, view_rect.y_range(), stroke);
+                }
+                for y in grid_lines(scene_rect.y_range(), spacing, ui_per_scene) {
+                    let y = ui_from_scene.transform_pos(pos2(0.0, y)).y;
+                    painter.hline(view_rect.x_range(), y, stroke);
+                }
+            }
+        }
+
+        let cursor_in_scene = pointer_in_ui
+            .filter(|pointer| view_rect.contains(*pointer))
+            .map(|pointer| {
+                self.cursor_in_scene(ui_from_scene.inverse().transform_pos(pointer), ui_per_scene)
+            });
+        let cursor_in_ui = cursor_in_scene.map(|cursor| ui_from_scene.transform_pos(cursor));
+
+        if self.snap_to_grid
+            && let Some(cursor_in_ui) = cursor_in_ui
+        {
+            let stroke = Stroke::new(1.0, painter.ctx().style().visuals.selection.stroke.color);
+            painter.line_segment(
+                [cursor_in_ui - vec2(6.0, 0.0), cursor_in_ui + vec2(6.0, 0.0)],
+                stroke,
+            );
+            painter.line_segment(
+                [cursor_in_ui - vec2(0.0, 6.0), cursor_in_ui + vec2(0.0, 6.0)],
+                stroke,
+            );
+        }
+
+        if self.show_rulers {
+            paint_rulers(
+                painter,
+                ui_from_scene,
+                scene_rect,
+                major_spacing,
+                minor_spacing,
+                cursor_in_scene,
+                &visuals,
+            );
+        }
+    }
+}
+
+/// The smallest of 1, 2 and 5 times a power of ten that is at least `min_spacing`.
+fn nice_spacing(min_spacing: f32) -> f32 {
+    if !(min_spacing.is_finite() && min_spacing > 0.0) {
+        return 1.0;
+    }
+    let power_of_ten = 10.0_f32.powf(min_spacing.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * power_of_ten)
+        .find(|spacing| *spacing >= min_spacing)
+        .unwrap_or(10.0 * power_of_ten)
+}
+
+/// Positions of the lines `spacing` apart within `range`, none if they would be too dense to draw.
+fn grid_lines(range: egui::Rangef, spacing: f32, ui_per_scene: f32) -> impl Iterator<Item = f32> {
+    let (first, last) = if spacing * ui_per_scene >= MIN_LINE_DISTANCE_IN_UI {
+        (
+            (range.min / spacing).ceil() as i64,
+            (range.max / spacing).floor() as i64,
+        )
+    } else {
+        (1, 0)
+    };
+    (first..=last).map(move |index| index as f32 * spacing)
+}
+
+/// Formats a coordinate on a ruler with just enough decimals for the spacing of the ticks.
+fn format_coordinate(value: f32, spacing: f32) -> String {
+    let decimals = (-spacing.log10().floor()).clamp(0.0, 6.0) as usize;
+    // Avoid showing "-0".
+    let value = if value.abs() < spacing * 1e-3 {
+        0.0
+    } else {
+        value
+    };
+    format!("{value:.decimals$}")
+}
+
+fn paint_rulers(
+    painter: &egui::Painter,
+    ui_from_scene: &RectTransform,
+    scene_rect: Rect,
+    major_spacing: f32,
+    minor_spacing: f32,
+    cursor_in_scene: Option<Pos2>,
+    visuals: &egui::Visuals,
+) {
+    let view_rect = *ui_from_scene.to();
+    let ui_per_scene = ui_from_scene.scale().x;
+
+    let top = Rect::from_min_max(
+        view_rect.min,
+        pos2(view_rect.max.x, view_rect.min.y + RULER_THICKNESS),
+    );
+    let left = Rect::from_min_max(
+        view_rect.min,
+        pos2(view_rect.min.x + RULER_THICKNESS, view_rect.max.y),
+    );
+    let background = visuals.extreme_bg_color.gamma_multiply(0.85);
+    painter.rect_filled(top, 0.0, background);
+    painter.rect_filled(left, 0.0, background);
+
+    let tick_stroke = Stroke::new(1.0, visuals.weak_text_color());
+    let font_id = egui::TextStyle::Small.resolve(&painter.ctx().style());
+    let text_color = visuals.text_color();
+
+    for x in grid_lines(scene_rect.x_range(), minor_spacing, ui_per_scene) {
+        let x_in_ui = ui_from_scene.transform_pos(pos2(x, 0.0)).x;
+        if x_in_ui < left.max.x {
+            continue;
+        }
+        let is_major = (x / major_spacing - (x / major_spacing).round()).abs() < 1e-3;
+        let length = if is_major { RULER_THICKNESS } else { 4.0 };
+        painter.vline(
+            x_in_ui,
+            egui::Rangef::new(top.max.y - length, top.max.y),
+            tick_stroke,
+        );
+        if is_major {
+            painter.text(
+                pos2(x_in_ui + 2.0, top.min.y),
+                Align2::LEFT_TOP,
+                format_coordinate(x, major_spacing),
+                font_id.clone(),
+                text_color,
+            );
+        }
+    }
+
+    for y in grid_lines(scene_rect.y_range(), minor_spacing, ui_per_scene) {
+        let y_in_ui = ui_from_scene.transform_pos(pos2(0.0, y)).y;
+        if y_in_ui < top.max.y {
+            continue;
+        }
+        let is_major = (y / major_spacing - (y / major_spacing).round()).abs() < 1e-3;
+        let length = if is_major { RULER_THICKNESS } else { 4.0 };
+        painter.hline(
+            egui::Rangef::new(left.max.x - length, left.max.x),
+            y_in_ui,
+            tick_stroke,
+        );
+        if is_major {
+            painter.add(Shape::Text(
+                egui::epaint::TextShape::new(
+                    pos2(left.min.x, y_in_ui - 2.0),
+                    painter.layout_no_wrap(
+                        format_coordinate(y, major_spacing),
+                        font_id.clone(),
+                        text_color,
+                    ),
+                    text_color,
+                )
+                .with_angle(-std::f32::consts::FRAC_PI_2),
+            ));
+        }
+    }
+
+    // Mark the cursor on both rulers, with its coordinates in the corner.
+    if let Some(cursor) = cursor_in_scene {
+        let cursor_in_ui = ui_from_scene.transform_pos(cursor);
+        let marker_stroke = Stroke::new(1.5, visuals.selection.stroke.color);
+        if left.max.x <= cursor_in_ui.x {
+            painter.vline(cursor_in_ui.x, top.y_range(), marker_stroke);
+        }
+        if top.max.y <= cursor_in_ui.y {
+            painter.hline(left.x_range(), cursor_in_ui.y, marker_stroke);
+        }
+
+        painter.text(
+            pos2(left.max.x + 4.0, top.max.y + 2.0),
+            Align2::LEFT_TOP,
+            format!(
+                "{}, {}",
+                format_coordinate(cursor.x, minor_spacing),
+                format_coordinate(cursor.y, minor_spacing)
+            ),
+            font_id,
+            text_color,
+        );
+    }
+}
+
+/// Editing the grid overlay of a view, as rows of a selection grid.
+pub fn grid_overlay_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, view_id: ViewId) {
+    let grid = GridOverlay2D::load(ctx, view_id);
+    let mut edited = grid;
+
+    ui.grid_left_hand_label("Grid")
+        .on_hover_text("Lines over the view at regular distances in scene units");
+    ui.vertical(|ui| {
+        ui.re_checkbox(&mut edited.show_grid, "Show grid");
+        ui.re_checkbox(&mut edited.show_rulers, "Show rulers")
+            .on_hover_text("Coordinates along the top and left edges of the view");
+    });
+    ui.end_row();
+
+    ui.grid_left_hand_label("Grid spacing")
+        .on_hover_text("Distance between major lines in scene units, e.g. pixels for images");
+    ui.horizontal(|ui| {
+        let mut automatic = edited.spacing.is_none();
+        ui.re_checkbox(&mut automatic, "Auto")
+            .on_hover_text("Pick the spacing for the zoom level");
+        if automatic {
+            edited.spacing = None;
+        } else {
+            let spacing = edited.spacing.get_or_insert(grid.spacing.unwrap_or(10.0));
+            ui.add(
+                egui::DragValue::new(spacing)
+                    .speed(0.1)
+                    .range(1e-6..=f32::MAX),
+            );
+        }
+    });
+    ui.end_row();
+
+    ui.grid_left_hand_label("Subdivisions")
+        .on_hover_text("Number of minor cells each major cell is divided into");
+    ui.add(egui::DragValue::new(&mut edited.subdivisions).range(1..=100));
+    ui.end_row();
+
+    ui.grid_left_hand_label("Snap to grid")
+        .on_hover_text("Snap the cursor to the nearest intersection of minor lines");
+    ui.re_checkbox(&mut edited.snap_to_grid, "");
+    ui.end_row();
+
+    if edited != grid {
+        edited.save(ctx, view_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spacing_is_picked_from_one_two_five() {
+        assert_eq!(nice_spacing(0.8), 1.0);
+        assert_eq!(nice_spacing(1.0), 1.0);
+        assert_eq!(nice_spacing(1.5), 2.0);
+        assert_eq!(nice_spacing(30.0), 50.0);
+        assert_eq!(nice_spacing(600.0), 1000.0);
+    }
+
+    #[test]
+    fn cursor_snaps_to_minor_lines() {
+        let grid = GridOverlay2D {
+            spacing: Some(10.0),
+            subdivisions: 5,
+            snap_to_grid: true,
+            ..Default::default()
+        };
+        assert_eq!(grid.cursor_in_scene(pos2(3.1, -4.9), 1.0), pos2(4.0, -4.0));
+
+        let not_snapping = GridOverlay2D {
+            snap_to_grid: false,
+            ..grid
+        };
+        assert_eq!(
+            not_snapping.cursor_in_scene(pos2(3.1, -4.9), 1.0),
+            pos2(3.1, -4.9)
+        );
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! Views that show entities in a 2D or 3D spatial relationship.
 
+mod box_selection;
+mod camera_controller;
+mod camera_path;
 mod contexts;
 mod eye;
+mod grid_overlay_2d;
 mod heuristics;
+mod lighting;
 mod max_image_dimension_subscriber;
+mod measurement;
 mod mesh_cache;
 mod mesh_loader;
 mod pickable_textured_rect;
@@ -13,8 +19,11 @@ mod picking;
 mod picking_ui;
 mod picking_ui_pixel;
 mod pinhole;
+mod pixel_probe;
+mod point_sizing;
 mod proc_mesh;
 mod scene_bounding_boxes;
+mod section_plane;
 mod space_camera_3d;
 mod spatial_topology;
 mod ui;
@@ -28,6 +37,11 @@ mod visualizers;
 
 mod transform_cache;
 
+pub use camera_controller::{
+    CameraController, CameraControllerInput, FlyController, OrbitController, TopDownController,
+    TurntableController, camera_controllers, register_camera_controller,
+};
+pub use eye::{Eye, ViewEye};
 pub use ui::SpatialViewState;
 pub use view_2d::SpatialView2D;
 pub use view_3d::SpatialView3D;
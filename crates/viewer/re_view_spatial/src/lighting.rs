@@ -0,0 +1,189 @@
+//! The lights meshes are shaded with in a 3D view, e.g. to brighten dark scans.
+//!
+//! The lighting is stored in the blueprint, next to the other properties of the view.
+//! Views without it use the default lighting of the renderer.
+
+use glam::Vec3;
+
+use re_renderer::view_builder::Lighting;
+use re_types::{
+    ComponentDescriptor,
+    blueprint::{archetypes::Lighting3D, components::Enabled},
+    components::{Scalar, Vector3D},
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::eye::Eye;
+
+fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<Lighting3D>(ctx.blueprint_db(), ctx.blueprint_query, view_id)
+}
+
+/// The lighting of the view, where anything not in the blueprint is the default.
+pub fn load_lighting(ctx: &ViewerContext<'_>, view_id: ViewId) -> Lighting {
+    let property = property(ctx, view_id);
+
+    let default = Lighting::default();
+    let scalar = |descriptor: &ComponentDescriptor| {
+        property
+            .component_or_empty::<Scalar>(descriptor)
+            .ok()
+            .flatten()
+            .map(|scalar| scalar.0.0 as f32)
+    };
+    Lighting {
+        key_light_direction: property
+            .component_or_empty::<Vector3D>(&Lighting3D::descriptor_key_light_direction())
+            .ok()
+            .flatten()
+            .map(Vec3::from)
+            .and_then(Vec3::try_normalize)
+            .unwrap_or(default.key_light_direction),
+        key_light_intensity: scalar(&Lighting3D::descriptor_key_light_intensity())
+            .unwrap_or(default.key_light_intensity),
+        ambient_light: scalar(&Lighting3D::descriptor_ambient_light())
+            .unwrap_or(default.ambient_light),
+        unlit: property
+            .component_or_empty::<Enabled>(&Lighting3D::descriptor_unlit())
+            .ok()
+            .flatten()
+            .map_or(default.unlit, bool::from),
+    }
+}
+
+fn save_lighting(ctx: &ViewerContext<'_>, view_id: ViewId, lighting: &Lighting) {
+    let property = property(ctx, view_id);
+    property.save_blueprint_component(
+        ctx,
+        &Lighting3D::descriptor_key_light_direction(),
+        &Vector3D::from(lighting.key_light_direction),
+    );
+    property.save_blueprint_component(
+        ctx,
+        &Lighting3D::descriptor_key_light_intensity(),
+        &Scalar::from(lighting.key_light_intensity as f64),
+    );
+    property.save_blueprint_component(
+        ctx,
+        &Lighting3D::descriptor_ambient_light(),
+        &Scalar::from(lighting.ambient_light as f64),
+    );
+    property.save_blueprint_component(
+        ctx,
+        &Lighting3D::descriptor_unlit(),
+        &Enabled::from(lighting.unlit),
+    );
+}
+
+fn clear_lighting(ctx: &ViewerContext<'_>, view_id: ViewId) {
+    property(ctx, view_id).reset_all_components_to_empty(ctx);
+}
+
+/// Azimuth around the z axis and elevation above the xy plane, in degrees.
+fn azimuth_elevation(direction: Vec3) -> (f32, f32) {
+    let direction = direction.normalize_or_zero();
+    (
+        direction.y.atan2(direction.x).to_degrees(),
+        direction.z.clamp(-1.0, 1.0).asin().to_degrees(),
+    )
+}
+
+fn direction_from_azimuth_elevation(azimuth: f32, elevation: f32) -> Vec3 {
+    let (azimuth, elevation) = (azimuth.to_radians(), elevation.to_radians());
+    Vec3::new(
+        elevation.cos() * azimuth.cos(),
+        elevation.cos() * azimuth.sin(),
+        elevation.sin(),
+    )
+}
+
+/// Editing the lighting of a view.
+pub fn lighting_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, view_id: ViewId, eye: Option<Eye>) {
+    let lighting = load_lighting(ctx, view_id);
+    let mut edited = lighting;
+
+    ui.re_checkbox(&mut edited.unlit, "Unlit")
+        .on_hover_text("Draw meshes in their colors, without any shading");
+
+    ui.add_enabled_ui(!edited.unlit, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Key light");
+            ui.add(
+                egui::DragValue::new(&mut edited.key_light_intensity)
+                    .range(0.0..=10.0)
+                    .speed(0.01),
+            )
+            .on_hover_text(
+                "Brightness of the key light. Meshes are lit from the opposite side at half of it",
+            );
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Direction");
+            let (mut azimuth, mut elevation) = azimuth_elevation(edited.key_light_direction);
+            let azimuth_changed = ui
+                .add(
+                    egui::DragValue::new(&mut azimuth)
+                        .range(-180.0..=180.0)
+                        .suffix("°"),
+                )
+                .on_hover_text("Azimuth around the z axis")
+                .changed();
+            let elevation_changed = ui
+                .add(
+                    egui::DragValue::new(&mut elevation)
+                        .range(-90.0..=90.0)
+                        .suffix("°"),
+                )
+                .on_hover_text("Elevation above the xy plane")
+                .changed();
+            if azimuth_changed || elevation_changed {
+                edited.key_light_direction = direction_from_azimuth_elevation(azimuth, elevation);
+            }
+
+            if let Some(eye) = eye
+                && ui
+                    .button("From eye")
+                    .on_hover_text("Light the scene from where the eye is looking")
+                    .clicked()
+            {
+                edited.key_light_direction = -eye.forward_in_world();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Ambient");
+            ui.add(
+                egui::DragValue::new(&mut edited.ambient_light)
+                    .range(0.0..=1.0)
+                    .speed(0.01),
+            )
+            .on_hover_text("Light reaching meshes from all sides, brightening their dark sides");
+        });
+    });
+
+    if edited != lighting {
+        save_lighting(ctx, view_id, &edited);
+    }
+
+    if lighting != Lighting::default() && ui.button("Reset").clicked() {
+        clear_lighting(ctx, view_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn azimuth_elevation_roundtrip() {
+        let direction = Lighting::default().key_light_direction;
+        let (azimuth, elevation) = azimuth_elevation(direction);
+        assert!(direction_from_azimuth_elevation(azimuth, elevation).abs_diff_eq(direction, 1e-5));
+
+        assert_eq!(azimuth_elevation(Vec3::Z).1, 90.0);
+        assert!(direction_from_azimuth_elevation(90.0, 0.0).abs_diff_eq(Vec3::Y, 1e-6));
+    }
+}
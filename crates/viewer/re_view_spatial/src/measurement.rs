@@ -0,0 +1,298 @@
+//! Measuring distances and angles between picked points in the 3D view.
+
+use glam::Vec3;
+use re_renderer::{LineDrawableBuilder, Size, renderer::LineStripFlags};
+use re_ui::UiExt as _;
+
+/// What clicking in a 3D view measures.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeasurementMode {
+    /// Clicking selects, as usual.
+    #[default]
+    Off,
+
+    /// Distance between two points.
+    Distance,
+
+    /// Angle at the second of three points.
+    Angle,
+
+    /// Distance of the second point from the surface of the first one, along its normal.
+    NormalDistance,
+}
+
+impl MeasurementMode {
+    pub const ALL: [Self; 4] = [Self::Off, Self::Distance, Self::Angle, Self::NormalDistance];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Distance => "Distance",
+            Self::Angle => "Angle",
+            Self::NormalDistance => "Distance along normal",
+        }
+    }
+
+    fn num_points(self) -> usize {
+        match self {
+            Self::Off => 0,
+            Self::Distance | Self::NormalDistance => 2,
+            Self::Angle => 3,
+        }
+    }
+}
+
+/// A point picked for a measurement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeasuredPoint {
+    pub position: Vec3,
+
+    /// Normal of the picked surface, if known.
+    pub normal: Option<Vec3>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MeasurementState {
+    mode: MeasurementMode,
+    points: Vec<MeasuredPoint>,
+}
+
+impl MeasurementState {
+    #[inline]
+    pub fn mode(&self) -> MeasurementMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: MeasurementMode) {
+        if mode != self.mode {
+            self.mode = mode;
+            self.points.clear();
+        }
+    }
+
+    /// Whether clicks add measurement points instead of selecting.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.mode != MeasurementMode::Off
+    }
+
+    /// Adds a point, starting a new measurement if the previous one was complete.
+    pub fn add_point(&mut self, point: MeasuredPoint) {
+        if self.points.len() >= self.mode.num_points() {
+            self.points.clear();
+        }
+        self.points.push(point);
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    fn is_complete(&self) -> bool {
+        self.mode != MeasurementMode::Off && self.points.len() == self.mode.num_points()
+    }
+
+    /// The measured value and its unit, once all points are picked.
+    pub fn value(&self) -> Option<(f32, &'static str)> {
+        if !self.is_complete() {
+            return None;
+        }
+        match (self.mode, self.points.as_slice()) {
+            (MeasurementMode::Distance, [a, b]) => Some((distance(a.position, b.position), "")),
+            (MeasurementMode::Angle, [a, vertex, c]) => {
+                angle_degrees(a.position, vertex.position, c.position).map(|angle| (angle, "°"))
+            }
+            (MeasurementMode::NormalDistance, [a, b]) => {
+                normal_distance(a.position, a.normal?, b.position).map(|distance| (distance, ""))
+            }
+            _ => None,
+        }
+    }
+
+    /// Adds lines for the measurement so far, continuing to the hovered point if it's not complete yet.
+    pub fn add_lines(
+        &self,
+        line_builder: &mut LineDrawableBuilder<'_>,
+        hovered: Option<Vec3>,
+        color: egui::Color32,
+    ) {
+        if !self.is_active() || self.points.is_empty() {
+            return;
+        }
+
+        let mut positions: Vec<Vec3> = self.points.iter().map(|p| p.position).collect();
+        if !self.is_complete()
+            && let Some(hovered) = hovered
+        {
+            positions.push(hovered);
+        }
+
+        let mut batch = line_builder.batch("measurement");
+        match self.mode {
+            MeasurementMode::Off => {}
+            MeasurementMode::Distance | MeasurementMode::Angle => {
+                if positions.len() < 2 {
+                    return;
+                }
+                batch
+                    .add_strip(positions.into_iter())
+                    .radius(Size::new_ui_points(1.5))
+                    .color(color)
+                    .flags(LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS);
+            }
+            MeasurementMode::NormalDistance => {
+                let a = self.points[0];
+                if let (Some(normal), Some(&b)) = (a.normal, positions.get(1)) {
+                    // The normal from the first point up to the height of the second one.
+                    let foot = a.position + normal * (b - a.position).dot(normal);
+                    batch
+                        .add_segment(a.position, foot)
+                        .radius(Size::new_ui_points(1.5))
+                        .color(color)
+                        .flags(LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS);
+                    batch
+                        .add_segment(foot, b)
+                        .radius(Size::new_ui_points(0.75))
+                        .color(color.gamma_multiply(0.5));
+                }
+            }
+        }
+    }
+
+    /// Paints the picked points and the measured value in the scene.
+    pub fn paint(&self, painter: &egui::Painter, ui_from_world: &glam::Mat4, color: egui::Color32) {
+        let project = |pos: Vec3| {
+            let pos_in_ui = *ui_from_world * pos.extend(1.0);
+            (pos_in_ui.w > 0.0).then(|| {
+                let pos_in_ui = pos_in_ui / pos_in_ui.w;
+                egui::pos2(pos_in_ui.x, pos_in_ui.y)
+            })
+        };
+
+        for point in &self.points {
+            if let Some(pos) = project(point.position) {
+                painter.circle_filled(pos, 3.0, color);
+            }
+        }
+
+        let Some((value, unit)) = self.value() else {
+            return;
+        };
+        let label_anchor = match self.mode {
+            MeasurementMode::Angle => self.points[1].position,
+            _ => (self.points[0].position + self.points[1].position) * 0.5,
+        };
+        if let Some(pos) = project(label_anchor) {
+            let text = format!("{value:.3}{unit}");
+            let font_id = egui::TextStyle::Body.resolve(painter.ctx().style().as_ref());
+            let galley = painter.layout_no_wrap(
+                text,
+                font_id,
+                painter.ctx().style().visuals.strong_text_color(),
+            );
+            let rect = egui::Align2::CENTER_BOTTOM
+                .anchor_size(pos - egui::vec2(0.0, 6.0), galley.size())
+                .expand(3.0);
+            painter.rect_filled(rect, 3.0, painter.ctx().style().visuals.panel_fill);
+            painter.galley(rect.shrink(3.0).min, galley, color);
+        }
+    }
+
+    /// Instructions, the result and a button to copy it, shown on top of the view.
+    pub fn overlay_ui(&mut self, ui: &mut egui::Ui) {
+        if !self.is_active() {
+            return;
+        }
+
+        egui::Frame::popup(ui.style()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong(self.mode.label());
+
+                if let Some((value, unit)) = self.value() {
+                    ui.label(format!("{value:.3}{unit}"));
+                    if ui
+                        .small_icon_button(&re_ui::icons::COPY, "Copy value")
+                        .on_hover_text("Copy the value to the clipboard")
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(value.to_string());
+                    }
+                } else if self.mode == MeasurementMode::NormalDistance
+                    && self.points.first().is_some_and(|p| p.normal.is_none())
+                {
+                    ui.warning_label("No surface normal at the first point");
+                } else {
+                    ui.weak(format!(
+                        "Click point {} of {}",
+                        self.points.len() % self.mode.num_points() + 1,
+                        self.mode.num_points()
+                    ));
+                }
+
+                if !self.points.is_empty()
+                    && ui
+                        .small_icon_button(&re_ui::icons::CLOSE, "Clear")
+                        .clicked()
+                {
+                    self.clear();
+                }
+            });
+        });
+    }
+}
+
+pub fn distance(a: Vec3, b: Vec3) -> f32 {
+    a.distance(b)
+}
+
+/// Angle at `vertex` between the directions to `a` and `c`, in degrees.
+pub fn angle_degrees(a: Vec3, vertex: Vec3, c: Vec3) -> Option<f32> {
+    let to_a = (a - vertex).try_normalize()?;
+    let to_c = (c - vertex).try_normalize()?;
+    Some(to_a.dot(to_c).clamp(-1.0, 1.0).acos().to_degrees())
+}
+
+/// Signed distance of `b` from the plane through `a` with the given normal.
+pub fn normal_distance(a: Vec3, normal: Vec3, b: Vec3) -> Option<f32> {
+    Some((b - a).dot(normal.try_normalize()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometry() {
+        assert_eq!(distance(Vec3::ZERO, Vec3::new(3.0, 4.0, 0.0)), 5.0);
+
+        let angle = angle_degrees(Vec3::X, Vec3::ZERO, Vec3::Y).unwrap();
+        assert!((angle - 90.0).abs() < 1e-4);
+        assert_eq!(angle_degrees(Vec3::ZERO, Vec3::ZERO, Vec3::Y), None);
+
+        assert_eq!(
+            normal_distance(Vec3::ZERO, Vec3::Z * 2.0, Vec3::new(1.0, 1.0, -2.0)),
+            Some(-2.0)
+        );
+    }
+
+    #[test]
+    fn points_restart_when_complete() {
+        let point = |x| MeasuredPoint {
+            position: Vec3::new(x, 0.0, 0.0),
+            normal: None,
+        };
+
+        let mut state = MeasurementState::default();
+        state.set_mode(MeasurementMode::Distance);
+        state.add_point(point(1.0));
+        assert_eq!(state.value(), None);
+        state.add_point(point(3.0));
+        assert_eq!(state.value(), Some((2.0, "")));
+
+        state.add_point(point(0.0));
+        assert_eq!(state.value(), None);
+
+        state.set_mode(MeasurementMode::Angle);
+        assert!(state.points.is_empty());
+    }
+}
@@ -31,6 +31,9 @@ pub struct PickingRayHit {
     /// Where the ray hit the entity.
     pub space_position: glam::Vec3,
 
+    /// Normal of the hit surface facing the camera, if known.
+    pub space_normal: Option<glam::Vec3>,
+
     pub depth_offset: re_renderer::DepthOffset,
 
     /// Any additional information about the picking hit.
@@ -59,6 +62,15 @@ impl PickingResult {
             .or_else(|| self.hits.first())
             .map(|hit| hit.space_position)
     }
+
+    /// The surface normal at [`Self::space_position`], if known.
+    pub fn space_normal(&self) -> Option<glam::Vec3> {
+        self.hits
+            .iter()
+            .find(|h| h.hit_type == PickingHitType::GpuPickingResult)
+            .or_else(|| self.hits.first())
+            .and_then(|hit| hit.space_normal)
+    }
 }
 
 /// Picking context in which picking is performed.
@@ -234,12 +246,40 @@ fn picking_gpu(
             return None;
         }
 
+        let picked_on_picking_rect = picked_on_picking_rect.as_uvec2();
         let picked_world_position =
-            gpu_picking_result.picked_world_position(picked_on_picking_rect.as_uvec2());
+            gpu_picking_result.picked_world_position(picked_on_picking_rect);
+
+        // Estimate the surface normal from the depth of the neighboring pixels.
+        let neighbor_position = |offset: glam::IVec2| {
+            let pos = picked_on_picking_rect.as_ivec2() + offset;
+            let on_rect = pos.cmpge(glam::IVec2::ZERO).all()
+                && pos.cmplt(gpu_picking_result.rect.extent.as_ivec2()).all();
+            (on_rect && gpu_picking_result.picked_id(pos.as_uvec2()) == picked_id)
+                .then(|| {
+                    gpu_picking_result.picked_world_position(pos.as_uvec2()) - picked_world_position
+                })
+                .filter(|delta| delta.is_finite())
+        };
+        let along_x = neighbor_position(glam::IVec2::X)
+            .or_else(|| neighbor_position(glam::IVec2::NEG_X).map(|delta| -delta));
+        let along_y = neighbor_position(glam::IVec2::Y)
+            .or_else(|| neighbor_position(glam::IVec2::NEG_Y).map(|delta| -delta));
+        let space_normal = along_x
+            .zip(along_y)
+            .and_then(|(along_x, along_y)| along_x.cross(along_y).try_normalize())
+            .map(|normal| {
+                if normal.dot(context.ray_in_world.dir) > 0.0 {
+                    -normal
+                } else {
+                    normal
+                }
+            });
 
         Some(PickingRayHit {
             instance_path_hash: re_view::instance_path_hash_from_picking_layer_id(picked_id),
             space_position: picked_world_position,
+            space_normal,
             depth_offset: 1,
             hit_type: PickingHitType::GpuPickingResult,
         })
@@ -306,6 +346,11 @@ fn picking_textured_rects<'a>(
                         ),
                     },
                     space_position: intersection_world,
+                    space_normal: Some(if normal.dot(context.ray_in_world.dir) > 0.0 {
+                        -normal
+                    } else {
+                        normal
+                    }),
                     hit_type: PickingHitType::TexturedRect,
                     depth_offset: rect.options.depth_offset,
                 });
@@ -337,6 +382,7 @@ fn picking_ui_rects(
             return Some(PickingRayHit {
                 instance_path_hash: ui_rect.instance_hash,
                 space_position: context.ray_in_world.origin,
+                space_normal: None,
                 hit_type: PickingHitType::GuiOverlay,
                 depth_offset: 0,
             });
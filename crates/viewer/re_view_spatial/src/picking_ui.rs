@@ -83,6 +83,9 @@ pub fn picking(
     // TODO(#1818): Depth at pointer only works for depth images so far.
     let mut depth_at_pointer = None;
 
+    // Tooltips follow the pointer of a mouse or pen, but would sit under the finger on touch screens.
+    let show_hover_ui = !re_ui::touch::is_touching(&response.ctx);
+
     // We iterate front-to-back, putting foreground hits on top, like layers in Photoshop:
     for (hit_idx, hit) in picking_result.hits.iter().enumerate() {
         let Some(mut instance_path) = hit.instance_path_hash.resolve(ctx.recording()) else {
@@ -122,9 +125,9 @@ pub fn picking(
                 }
             }
 
-            response
-                .on_hover_cursor(egui::CursorIcon::Crosshair)
-                .on_hover_ui_at_pointer(|ui| {
+            let response = response.on_hover_cursor(egui::CursorIcon::Crosshair);
+            if show_hover_ui {
+                response.on_hover_ui_at_pointer(|ui| {
                     ui.set_max_width(320.0);
                     ui.vertical(|ui| {
                         textured_rect_hover_ui(
@@ -140,7 +143,10 @@ pub fn picking(
                         );
                     });
                 })
-        } else {
+            } else {
+                response
+            }
+        } else if show_hover_ui {
             // Hover ui for everything else
             response.on_hover_ui_at_pointer(|ui| {
                 list_item_scope(ui, "spatial_hover", |ui| {
@@ -156,6 +162,8 @@ pub fn picking(
                     instance_path.data_ui_recording(ctx, ui, UiLayout::Tooltip);
                 });
             })
+        } else {
+            response
         };
 
         let item = Item::DataResult(query.view_id, instance_path.clone());
@@ -225,12 +233,17 @@ pub fn picking(
         });
     }
 
-    ctx.handle_select_hover_drag_interactions(&response, hovered_items, false);
+    if state.state_3d.measurement.is_active() || state.pixel_probe.is_active() {
+        // Clicks pick measurement points or probe regions instead of selecting.
+        ctx.selection_state().set_hovered(hovered_items);
+    } else {
+        ctx.handle_select_hover_drag_interactions(&response, hovered_items, false);
+    }
 
     Ok(response)
 }
 
-fn iter_pickable_rects(
+pub(crate) fn iter_pickable_rects(
     visualizers: &VisualizerCollection,
 ) -> impl Iterator<Item = &PickableTexturedRect> {
     visualizers
@@ -388,7 +388,7 @@ fn format_pixel_value(
     elements: &[TensorElement],
 ) -> Option<(String, String)> {
     match image_kind {
-        ImageKind::Segmentation | ImageKind::Depth => elements
+        ImageKind::Segmentation | ImageKind::Depth | ImageKind::Heatmap => elements
             .first()
             .map(|v| ("Val:".to_owned(), v.format_padded())),
 
@@ -510,7 +510,7 @@ fn format_pixel_value(
 
 fn pixel_value_string_from_image(image: &ImageInfo, x: u32, y: u32) -> Option<(String, String)> {
     match image.kind {
-        ImageKind::Segmentation | ImageKind::Depth => format_pixel_value(
+        ImageKind::Segmentation | ImageKind::Depth | ImageKind::Heatmap => format_pixel_value(
             image.kind,
             image.color_model(),
             image.get_xyc(x, y, 0).as_slice(),
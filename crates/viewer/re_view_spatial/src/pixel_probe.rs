@@ -0,0 +1,456 @@
+//! Inspecting the pixels of images in the 2D view, within a region of interest.
+//!
+//! The raw values and the magnified neighborhood of the hovered pixel are shown by the hover ui,
+//! this adds statistics and histograms per channel for all pixels in a region the user drags out.
+
+use egui::{Pos2, Rect, Vec2, emath::RectTransform};
+use glam::Vec2 as GlamVec2;
+
+use re_log_types::{EntityPath, hash::Hash64};
+use re_types::{datatypes::ColorModel, image::ImageKind};
+use re_ui::UiExt as _;
+use re_view::controls::DRAG_PAN2D_BUTTON;
+use re_viewer_context::ImageInfo;
+
+use crate::{PickableRectSourceData, PickableTexturedRect};
+
+/// Number of histogram bins per channel.
+const NUM_BINS: usize = 32;
+
+/// Regions with more pixels than this are subsampled, to keep dragging the region smooth.
+const MAX_SAMPLED_PIXELS: u64 = 1 << 18;
+
+/// Statistics of the values of one channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelStatistics {
+    pub name: &'static str,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+
+    /// Number of values in each of [`NUM_BINS`] equally wide bins from `min` to `max`.
+    pub histogram: Vec<u64>,
+}
+
+impl ChannelStatistics {
+    /// Statistics of the finite values, `None` if there are none.
+    pub fn new(name: &'static str, values: &[f64]) -> Option<Self> {
+        let finite = || values.iter().copied().filter(|value| value.is_finite());
+
+        let count = finite().count();
+        if count == 0 {
+            return None;
+        }
+
+        let (min, max) = finite().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        let mean = finite().sum::<f64>() / count as f64;
+        let variance = finite().map(|value| (value - mean).powi(2)).sum::<f64>() / count as f64;
+
+        let mut histogram = vec![0; NUM_BINS];
+        for value in finite() {
+            let bin = if min < max {
+                ((value - min) / (max - min) * NUM_BINS as f64) as usize
+            } else {
+                0
+            };
+            histogram[bin.min(NUM_BINS - 1)] += 1;
+        }
+
+        Some(Self {
+            name,
+            min,
+            max,
+            mean,
+            std_dev: variance.sqrt(),
+            histogram,
+        })
+    }
+}
+
+fn channel_names(image: &ImageInfo) -> &'static [&'static str] {
+    match image.kind {
+        ImageKind::Depth => &["Depth"],
+        ImageKind::Segmentation => &["Class id"],
+        ImageKind::Heatmap => &["Value"],
+        ImageKind::Color => match image.color_model() {
+            ColorModel::L => &["L"],
+            ColorModel::RGB => &["R", "G", "B"],
+            ColorModel::RGBA => &["R", "G", "B", "A"],
+            ColorModel::BGR => &["B", "G", "R"],
+            ColorModel::BGRA => &["B", "G", "R", "A"],
+        },
+    }
+}
+
+/// A region of pixels, from `min` inclusive to `max` exclusive.
+pub type PixelRegion = [[u32; 2]; 2];
+
+/// The pixels of an image that are covered by a region of the scene, `None` if there are none.
+///
+/// The image spans `extent_u` and `extent_v` from its top left corner in the scene.
+pub fn pixel_region(
+    top_left: GlamVec2,
+    extent_u: GlamVec2,
+    extent_v: GlamVec2,
+    [width, height]: [u32; 2],
+    roi: Rect,
+) -> Option<PixelRegion> {
+    let scene_from_pixel = glam::Mat2::from_cols(extent_u / width as f32, extent_v / height as f32);
+    if scene_from_pixel.determinant() == 0.0 {
+        return None;
+    }
+    let pixel_from_scene = scene_from_pixel.inverse();
+
+    let corners = [
+        roi.left_top(),
+        roi.right_top(),
+        roi.left_bottom(),
+        roi.right_bottom(),
+    ]
+    .map(|corner| pixel_from_scene * (GlamVec2::new(corner.x, corner.y) - top_left));
+    let min = corners
+        .iter()
+        .fold(GlamVec2::INFINITY, |min, c| min.min(*c));
+    let max = corners
+        .iter()
+        .fold(GlamVec2::NEG_INFINITY, |max, c| max.max(*c));
+
+    let resolution = GlamVec2::new(width as f32, height as f32);
+    let min = min.floor().clamp(GlamVec2::ZERO, resolution).as_uvec2();
+    let max = max.ceil().clamp(GlamVec2::ZERO, resolution).as_uvec2();
+    (min.x < max.x && min.y < max.y).then_some([min.to_array(), max.to_array()])
+}
+
+/// Statistics per channel of the pixels of an image within a region.
+pub fn region_statistics(image: &ImageInfo, [min, max]: PixelRegion) -> Vec<ChannelStatistics> {
+    re_tracing::profile_function!();
+
+    let names = channel_names(image);
+    let num_pixels = (max[0] - min[0]) as u64 * (max[1] - min[1]) as u64;
+    let step = ((num_pixels as f64 / MAX_SAMPLED_PIXELS as f64)
+        .sqrt()
+        .ceil() as usize)
+        .max(1);
+
+    let mut values = vec![Vec::new(); names.len()];
+    for y in (min[1]..max[1]).step_by(step) {
+        for x in (min[0]..max[0]).step_by(step) {
+            for (channel, channel_values) in values.iter_mut().enumerate() {
+                if let Some(value) = image.get_xyc(x, y, channel as u32) {
+                    channel_values.push(value.as_f64());
+                }
+            }
+        }
+    }
+
+    names
+        .iter()
+        .zip(values)
+        .filter_map(|(name, values)| ChannelStatistics::new(name, &values))
+        .collect()
+}
+
+/// Statistics of the pixels of one image within the region of interest.
+#[derive(Clone, Debug)]
+pub struct ImageStatistics {
+    pub entity_path: EntityPath,
+    pub region: PixelRegion,
+    pub channels: Vec<ChannelStatistics>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RoiDrag {
+    /// Dragging out a new region from this corner, in scene coordinates.
+    Drawing { start: Pos2 },
+
+    /// Moving the region, grabbed at this offset from its min corner.
+    Moving { grab_offset: Vec2 },
+}
+
+#[derive(Clone, Default)]
+pub struct PixelProbeState {
+    enabled: bool,
+
+    /// The region of interest, in scene coordinates.
+    roi: Option<Rect>,
+
+    drag: Option<RoiDrag>,
+
+    /// Statistics of the region, and a hash of the images and pixels they were computed for.
+    statistics: Option<(Hash64, Vec<ImageStatistics>)>,
+}
+
+impl PixelProbeState {
+    /// Whether dragging with the primary button moves the region of interest instead of panning.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        if active != self.enabled {
+            self.enabled = active;
+            self.clear();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.roi = None;
+        self.drag = None;
+        self.statistics = None;
+    }
+
+    /// Drags out a new region of interest, or moves the existing one when grabbed inside of it.
+    pub fn handle_drag(&mut self, response: &egui::Response, scene_from_ui: &RectTransform) {
+        if !self.enabled {
+            return;
+        }
+        let Some(pointer_in_scene) = response
+            .interact_pointer_pos()
+            .map(|pos| scene_from_ui.transform_pos(pos))
+        else {
+            return;
+        };
+
+        if response.drag_started_by(DRAG_PAN2D_BUTTON) {
+            self.drag = Some(match self.roi {
+                Some(roi) if roi.contains(pointer_in_scene) => RoiDrag::Moving {
+                    grab_offset: pointer_in_scene - roi.min,
+                },
+                _ => RoiDrag::Drawing {
+                    start: pointer_in_scene,
+                },
+            });
+        }
+
+        if response.dragged_by(DRAG_PAN2D_BUTTON) {
+            match self.drag {
+                Some(RoiDrag::Drawing { start }) => {
+                    self.roi = Some(Rect::from_two_pos(start, pointer_in_scene));
+                }
+                Some(RoiDrag::Moving { grab_offset }) => {
+                    self.roi = self
+                        .roi
+                        .map(|roi| Rect::from_min_size(pointer_in_scene - grab_offset, roi.size()));
+                }
+                None => {}
+            }
+        }
+
+        if response.drag_stopped() {
+            self.drag = None;
+        }
+    }
+
+    /// Computes the statistics of the images under the region of interest, unless they didn't change.
+    pub fn update_statistics<'a>(&mut self, rects: impl Iterator<Item = &'a PickableTexturedRect>) {
+        let Some(roi) = self.roi.filter(|_| self.enabled) else {
+            self.statistics = None;
+            return;
+        };
+
+        let regions: Vec<(&EntityPath, &ImageInfo, PixelRegion)> = rects
+            .filter_map(|rect| {
+                let PickableRectSourceData::Image { image, .. } = &rect.source_data else {
+                    return None;
+                };
+                let textured_rect = &rect.textured_rect;
+                let region = pixel_region(
+                    textured_rect.top_left_corner_position.truncate(),
+                    textured_rect.extent_u.truncate(),
+                    textured_rect.extent_v.truncate(),
+                    rect.resolution(),
+                    roi,
+                )?;
+                Some((&rect.ent_path, image, region))
+            })
+            .collect();
+
+        // Images change as the time cursor moves, so their content is part of the key.
+        let key = Hash64::hash(
+            regions
+                .iter()
+                .map(|(entity_path, image, region)| {
+                    (entity_path.hash(), image.buffer_content_hash.0, *region)
+                })
+                .collect::<Vec<_>>(),
+        );
+        if self
+            .statistics
+            .as_ref()
+            .is_some_and(|(cached_key, _)| *cached_key == key)
+        {
+            return;
+        }
+
+        let statistics = regions
+            .into_iter()
+            .map(|(entity_path, image, region)| ImageStatistics {
+                entity_path: entity_path.clone(),
+                region,
+                channels: region_statistics(image, region),
+            })
+            .collect();
+        self.statistics = Some((key, statistics));
+    }
+
+    /// Outlines the region of interest.
+    pub fn paint(
+        &self,
+        painter: &egui::Painter,
+        ui_from_scene: &RectTransform,
+        stroke: egui::Stroke,
+    ) {
+        if let Some(roi) = self.roi.filter(|_| self.enabled) {
+            painter.rect_stroke(
+                ui_from_scene.transform_rect(roi),
+                0.0,
+                stroke,
+                egui::StrokeKind::Middle,
+            );
+        }
+    }
+
+    /// Instructions and the statistics of the region of interest, shown on top of the view.
+    pub fn overlay_ui(&mut self, ui: &mut egui::Ui) {
+        if !self.enabled {
+            return;
+        }
+
+        egui::Frame::popup(ui.style()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong("Pixel probe");
+                if self.roi.is_none() {
+                    ui.weak("Drag over an image to select a region");
+                } else if ui
+                    .small_icon_button(&re_ui::icons::CLOSE, "Clear region")
+                    .clicked()
+                {
+                    self.clear();
+                }
+            });
+
+            let Some((_, statistics)) = &self.statistics else {
+                return;
+            };
+            if statistics.is_empty() {
+                ui.weak("No image pixels in the region");
+            }
+
+            for image in statistics {
+                image_statistics_ui(ui, image);
+            }
+        });
+    }
+}
+
+fn image_statistics_ui(ui: &mut egui::Ui, image: &ImageStatistics) {
+    let [min, max] = image.region;
+    ui.separator();
+    ui.label(image.entity_path.to_string());
+    ui.weak(format!(
+        "{}, {} to {}, {} ({} × {} px)",
+        min[0],
+        min[1],
+        max[0],
+        max[1],
+        max[0] - min[0],
+        max[1] - min[1]
+    ));
+
+    egui::Grid::new(("pixel_probe_statistics", image.entity_path.hash()))
+        .num_columns(6)
+        .show(ui, |ui| {
+            for header in ["", "Min", "Max", "Mean", "Std dev", "Histogram"] {
+                ui.strong(header);
+            }
+            ui.end_row();
+
+            for channel in &image.channels {
+                ui.label(channel.name);
+                for value in [channel.min, channel.max, channel.mean, channel.std_dev] {
+                    ui.monospace(re_format::format_f64(value));
+                }
+                histogram_ui(ui, &channel.histogram);
+                ui.end_row();
+            }
+        });
+}
+
+fn histogram_ui(ui: &mut egui::Ui, histogram: &[u64]) {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(2.0 * NUM_BINS as f32, 16.0),
+        egui::Sense::hover(),
+    );
+    let Some(&max_count) = histogram.iter().max() else {
+        return;
+    };
+    if max_count == 0 {
+        return;
+    }
+
+    let painter = ui.painter();
+    let bin_width = rect.width() / histogram.len() as f32;
+    for (bin, &count) in histogram.iter().enumerate() {
+        let height = rect.height() * count as f32 / max_count as f32;
+        let left = rect.left() + bin as f32 * bin_width;
+        painter.rect_filled(
+            Rect::from_min_max(
+                egui::pos2(left, rect.bottom() - height),
+                egui::pos2(left + bin_width, rect.bottom()),
+            ),
+            0.0,
+            ui.visuals().text_color(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_statistics() {
+        let stats = ChannelStatistics::new("L", &[1.0, 3.0, f64::NAN, 3.0, 5.0]).unwrap();
+        assert_eq!((stats.min, stats.max, stats.mean), (1.0, 5.0, 3.0));
+        assert_eq!(stats.std_dev, 2.0_f64.sqrt());
+        assert_eq!(stats.histogram.iter().sum::<u64>(), 4);
+        assert_eq!(stats.histogram[0], 1);
+        assert_eq!(stats.histogram[NUM_BINS / 2], 2);
+        assert_eq!(stats.histogram[NUM_BINS - 1], 1);
+
+        assert_eq!(ChannelStatistics::new("L", &[f64::NAN]), None);
+    }
+
+    #[test]
+    fn region_of_scaled_image() {
+        // A 10x10 image drawn at twice its size, with its top left corner at (100, 100).
+        let region = |roi| {
+            pixel_region(
+                GlamVec2::new(100.0, 100.0),
+                GlamVec2::new(20.0, 0.0),
+                GlamVec2::new(0.0, 20.0),
+                [10, 10],
+                roi,
+            )
+        };
+
+        assert_eq!(
+            region(Rect::from_min_max(
+                egui::pos2(103.0, 90.0),
+                egui::pos2(110.0, 107.0)
+            )),
+            Some([[1, 0], [5, 4]])
+        );
+        assert_eq!(
+            region(Rect::from_min_max(
+                egui::pos2(0.0, 0.0),
+                egui::pos2(50.0, 50.0)
+            )),
+            None
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Whether point radii are in scene units or ui points, and how large points may get on screen.
+//!
+//! Set per view, next to its other properties, and overridden per entity.
+//! LiDAR clouds usually want points of constant size on screen, while markers want metric sizes.
+
+use std::ops::RangeInclusive;
+
+use re_log_types::EntityPath;
+use re_query::LatestAtResults;
+use re_renderer::Size;
+use re_types::{
+    Archetype as _, ComponentDescriptor,
+    blueprint::{archetypes, components::Enabled},
+    components::Radius,
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{DataResult, ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+/// How the radii of the points of an entity are interpreted and limited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointSizing {
+    /// Whether radii are in ui points rather than in scene units, like meters.
+    pub screen_space: bool,
+
+    /// Points are drawn with at least this radius, in ui points.
+    pub min_radius: f32,
+
+    /// Points are drawn with at most this radius, in ui points.
+    pub max_radius: f32,
+}
+
+impl Default for PointSizing {
+    fn default() -> Self {
+        Self {
+            screen_space: false,
+            min_radius: 0.0,
+            max_radius: f32::INFINITY,
+        }
+    }
+}
+
+impl PointSizing {
+    fn is_limited(&self) -> bool {
+        self.min_radius > 0.0 || self.max_radius.is_finite()
+    }
+
+    /// Reinterprets radii in scene units as ui points, if radii are in screen space.
+    pub fn apply_to_radii(&self, radii: &mut [Size]) {
+        if !self.screen_space {
+            return;
+        }
+        for radius in radii {
+            if let Some(scene_units) = radius.scene_units().filter(|r| r.is_finite()) {
+                *radius = Size::new_ui_points(scene_units);
+            }
+        }
+    }
+
+    pub fn radius_range_in_ui_points(&self) -> RangeInclusive<f32> {
+        self.min_radius..=self.max_radius
+    }
+}
+
+/// The sizing stored at one place in the blueprint, where every field may be missing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointSizingSettings {
+    screen_space: Option<bool>,
+    min_radius: Option<f32>,
+    max_radius: Option<f32>,
+}
+
+impl PointSizingSettings {
+    fn view_property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+        ViewProperty::from_archetype::<archetypes::PointSizing>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        )
+    }
+
+    fn from_results(results: &LatestAtResults) -> Self {
+        let radius = |descriptor: &ComponentDescriptor| {
+            results
+                .component_mono::<Radius>(descriptor)
+                .and_then(|radius| radius.ui_points())
+        };
+        Self {
+            screen_space: results
+                .component_mono::<Enabled>(&archetypes::PointSizing::descriptor_screen_space())
+                .map(bool::from),
+            min_radius: radius(&archetypes::PointSizing::descriptor_min_radius()),
+            max_radius: radius(&archetypes::PointSizing::descriptor_max_radius()),
+        }
+    }
+
+    /// The overrides of an entity, stored at its override path.
+    fn load_overrides(ctx: &ViewerContext<'_>, override_path: &EntityPath) -> Self {
+        Self::from_results(&ctx.blueprint_db().latest_at(
+            ctx.blueprint_query,
+            override_path,
+            archetypes::PointSizing::all_components().iter(),
+        ))
+    }
+
+    /// The settings of a view, which apply to all of its entities without overrides.
+    pub fn load_for_view(ctx: &ViewerContext<'_>, view_id: ViewId) -> Self {
+        Self::from_results(&Self::view_property(ctx, view_id).query_results)
+    }
+
+    /// The sizing of an entity: its overrides, or else the settings of the view.
+    pub fn resolve_for(&self, ctx: &ViewerContext<'_>, data_result: &DataResult) -> PointSizing {
+        Self::load_overrides(ctx, data_result.override_path())
+            .or(*self)
+            .resolve()
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn or(self, fallback: Self) -> Self {
+        Self {
+            screen_space: self.screen_space.or(fallback.screen_space),
+            min_radius: self.min_radius.or(fallback.min_radius),
+            max_radius: self.max_radius.or(fallback.max_radius),
+        }
+    }
+
+    fn resolve(self) -> PointSizing {
+        let default = PointSizing::default();
+        PointSizing {
+            screen_space: self.screen_space.unwrap_or(default.screen_space),
+            min_radius: self.min_radius.unwrap_or(default.min_radius),
+            max_radius: self.max_radius.unwrap_or(default.max_radius),
+        }
+    }
+}
+
+fn save(ctx: &ViewerContext<'_>, entity_path: EntityPath, sizing: &PointSizing) {
+    ctx.save_blueprint_component(
+        entity_path.clone(),
+        &archetypes::PointSizing::descriptor_screen_space(),
+        &Enabled::from(sizing.screen_space),
+    );
+    if sizing.is_limited() {
+        ctx.save_blueprint_component(
+            entity_path.clone(),
+            &archetypes::PointSizing::descriptor_min_radius(),
+            &Radius::new_ui_points(sizing.min_radius),
+        );
+        ctx.save_blueprint_component(
+            entity_path,
+            &archetypes::PointSizing::descriptor_max_radius(),
+            &Radius::new_ui_points(sizing.max_radius),
+        );
+    } else {
+        ctx.clear_blueprint_component(
+            entity_path.clone(),
+            archetypes::PointSizing::descriptor_min_radius(),
+        );
+        ctx.clear_blueprint_component(
+            entity_path,
+            archetypes::PointSizing::descriptor_max_radius(),
+        );
+    }
+}
+
+fn clear(ctx: &ViewerContext<'_>, entity_path: EntityPath) {
+    for descriptor in archetypes::PointSizing::all_components().iter() {
+        ctx.clear_blueprint_component(entity_path.clone(), descriptor.clone());
+    }
+}
+
+fn point_sizing_editor(ui: &mut egui::Ui, sizing: &mut PointSizing) {
+    egui::ComboBox::from_id_salt("point_radius_units")
+        .selected_text(if sizing.screen_space {
+            "UI points"
+        } else {
+            "Scene units"
+        })
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut sizing.screen_space, false, "Scene units");
+            ui.selectable_value(&mut sizing.screen_space, true, "UI points");
+        });
+
+    let mut limited = sizing.is_limited();
+    if ui
+        .re_checkbox(&mut limited, "Limit size on screen")
+        .on_hover_text(
+            "Keep the radius of points within a range of UI points, however far away they are",
+        )
+        .changed()
+    {
+        let default = PointSizing::default();
+        (sizing.min_radius, sizing.max_radius) = if limited {
+            (1.0, 10.0)
+        } else {
+            (default.min_radius, default.max_radius)
+        };
+    }
+
+    if limited {
+        ui.horizontal(|ui| {
+            ui.label("Min");
+            ui.add(
+                egui::DragValue::new(&mut sizing.min_radius)
+                    .range(0.0..=sizing.max_radius)
+                    .speed(0.1),
+            );
+            ui.label("Max");
+            ui.add(
+                egui::DragValue::new(&mut sizing.max_radius)
+                    .range(sizing.min_radius..=1000.0)
+                    .speed(0.1),
+            );
+        });
+    }
+}
+
+/// Edits the sizing of the points of a view, as a row of a grid.
+pub fn view_point_sizing_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, view_id: ViewId) {
+    ui.grid_left_hand_label("Point radii").on_hover_text(
+        "Whether the radii of points are in scene units, like meters, or in UI points",
+    );
+
+    let sizing = PointSizingSettings::load_for_view(ctx, view_id).resolve();
+    let mut edited = sizing;
+    ui.vertical(|ui| point_sizing_editor(ui, &mut edited));
+    if edited != sizing {
+        save(
+            ctx,
+            PointSizingSettings::view_property(ctx, view_id).blueprint_store_path,
+            &edited,
+        );
+    }
+    ui.end_row();
+}
+
+/// Overrides the sizing of the points of a view for one entity.
+pub fn entity_point_sizing_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    data_result: &DataResult,
+) {
+    if !crate::visualizers::draws_points(&data_result.visualizers) {
+        return;
+    }
+
+    let override_path = data_result.override_path();
+    let overrides = PointSizingSettings::load_overrides(ctx, override_path);
+    let view_settings = PointSizingSettings::load_for_view(ctx, view_id);
+
+    ui.section_collapsing_header("Point radii").show(ui, |ui| {
+        let mut is_overridden = !overrides.is_empty();
+        if ui
+            .re_checkbox(&mut is_overridden, "Override the view")
+            .on_hover_text(
+                "Size the points of this entity differently than the other points in the view",
+            )
+            .changed()
+        {
+            if is_overridden {
+                save(ctx, override_path.clone(), &view_settings.resolve());
+            } else {
+                clear(ctx, override_path.clone());
+            }
+        }
+
+        let sizing = overrides.or(view_settings).resolve();
+        let mut edited = sizing;
+        ui.add_enabled_ui(is_overridden, |ui| point_sizing_editor(ui, &mut edited));
+        if is_overridden && edited != sizing {
+            save(ctx, override_path.clone(), &edited);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_overrides_fall_back_to_the_view() {
+        let view = PointSizingSettings {
+            screen_space: Some(true),
+            min_radius: Some(2.0),
+            max_radius: Some(8.0),
+        };
+        let entity = PointSizingSettings {
+            screen_space: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            entity.or(view).resolve(),
+            PointSizing {
+                screen_space: false,
+                min_radius: 2.0,
+                max_radius: 8.0,
+            }
+        );
+        assert_eq!(
+            PointSizingSettings::default().resolve(),
+            PointSizing::default()
+        );
+    }
+
+    #[test]
+    fn screen_space_turns_scene_units_into_ui_points() {
+        let mut radii = [
+            Size::new_scene_units(0.5),
+            Size::new_ui_points(3.0),
+            Size(f32::INFINITY),
+        ];
+
+        PointSizing::default().apply_to_radii(&mut radii);
+        assert_eq!(radii[0], Size::new_scene_units(0.5));
+
+        PointSizing {
+            screen_space: true,
+            ..Default::default()
+        }
+        .apply_to_radii(&mut radii);
+        assert_eq!(
+            radii,
+            [
+                Size::new_ui_points(0.5),
+                Size::new_ui_points(3.0),
+                Size(f32::INFINITY)
+            ]
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! A plane cutting away part of a 3D view, to look inside of meshes and dense point clouds.
+//!
+//! The plane is stored in the blueprint, next to the other properties of the view.
+
+use glam::Vec3;
+use macaw::BoundingBox;
+
+use re_renderer::{LineDrawableBuilder, Rgba, Size, view_builder::ClipPlane};
+use re_types::{
+    blueprint::{archetypes::SectionPlane3D, components::Enabled},
+    components::{Position3D, Vector3D},
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::eye::Eye;
+
+/// Radius of the drag handle, in ui points.
+const HANDLE_RADIUS: f32 = 6.0;
+
+/// Cuts away everything in front of a plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SectionPlane {
+    /// A point on the plane, in the space of the view.
+    pub position: Vec3,
+
+    /// Everything on the side the normal points to is kept.
+    pub normal: Vec3,
+
+    /// Color meshes where they are cut.
+    pub show_contour: bool,
+}
+
+impl SectionPlane {
+    fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+        ViewProperty::from_archetype::<SectionPlane3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        )
+    }
+
+    /// The plane of the view, if it has one.
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Option<Self> {
+        let property = Self::property(ctx, view_id);
+
+        let position = property
+            .component_or_empty::<Position3D>(&SectionPlane3D::descriptor_position())
+            .ok()??;
+        let normal = property
+            .component_or_empty::<Vector3D>(&SectionPlane3D::descriptor_normal())
+            .ok()??;
+        Some(Self {
+            position: position.into(),
+            normal: normal.into(),
+            show_contour: property
+                .component_or_empty::<Enabled>(&SectionPlane3D::descriptor_show_contour())
+                .ok()
+                .flatten()
+                .is_some_and(bool::from),
+        })
+    }
+
+    pub fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = Self::property(ctx, view_id);
+        property.save_blueprint_component(
+            ctx,
+            &SectionPlane3D::descriptor_position(),
+            &Position3D::from(self.position),
+        );
+        property.save_blueprint_component(
+            ctx,
+            &SectionPlane3D::descriptor_normal(),
+            &Vector3D::from(self.normal),
+        );
+        property.save_blueprint_component(
+            ctx,
+            &SectionPlane3D::descriptor_show_contour(),
+            &Enabled::from(self.show_contour),
+        );
+    }
+
+    /// Removes the plane from the view.
+    pub fn clear(ctx: &ViewerContext<'_>, view_id: ViewId) {
+        Self::property(ctx, view_id).reset_all_components_to_empty(ctx);
+    }
+
+    pub fn to_clip_plane(&self, contour_color: egui::Color32) -> ClipPlane {
+        ClipPlane {
+            point: self.position,
+            normal: self.normal,
+            contour_color: self.show_contour.then(|| Rgba::from(contour_color)),
+        }
+    }
+
+    /// Length of the normal handle and half size of the drawn plane, following the size of the scene.
+    pub fn gizmo_size(scene_bbox: &BoundingBox) -> f32 {
+        let size = 0.25 * scene_bbox.size().length();
+        if size.is_finite() && size > 0.0 {
+            size
+        } else {
+            1.0
+        }
+    }
+
+    fn handle_position(&self, gizmo_size: f32) -> Vec3 {
+        self.position + self.normal.normalize_or_zero() * gizmo_size
+    }
+
+    /// Moves the plane along its normal while the user drags its handle.
+    ///
+    /// Must be called before the eye handles input, so that dragging the handle doesn't also move the eye.
+    /// Returns whether the handle is hovered or dragged.
+    pub fn handle_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &egui::Ui,
+        view_id: ViewId,
+        eye: &Eye,
+        rect: egui::Rect,
+        gizmo_size: f32,
+    ) -> bool {
+        let ui_from_world = eye.ui_from_world(rect);
+        let (Some(base), Some(handle)) = (
+            project(&ui_from_world, self.position),
+            project(&ui_from_world, self.handle_position(gizmo_size)),
+        ) else {
+            return false;
+        };
+
+        let response = ui.interact(
+            egui::Rect::from_center_size(handle, egui::Vec2::splat(3.0 * HANDLE_RADIUS)),
+            ui.id().with(("section_plane_handle", view_id)),
+            egui::Sense::drag(),
+        );
+
+        let handle_in_ui = handle - base;
+        if response.dragged() && handle_in_ui.length_sq() > 1.0 {
+            // Dragging by the on-screen length of the handle moves the plane by its length in the scene.
+            let delta = response.drag_delta().dot(handle_in_ui) / handle_in_ui.length_sq();
+            let mut moved = *self;
+            moved.position += self.normal.normalize_or_zero() * delta * gizmo_size;
+            moved.save(ctx, view_id);
+        }
+
+        let active = response.hovered() || response.dragged();
+        if active {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeColumn);
+        }
+        active
+    }
+
+    /// Adds the outline of the plane and its normal.
+    pub fn add_lines(
+        &self,
+        line_builder: &mut LineDrawableBuilder<'_>,
+        gizmo_size: f32,
+        color: egui::Color32,
+    ) {
+        let Some(normal) = self.normal.try_normalize() else {
+            return;
+        };
+        let (u, v) = normal.any_orthonormal_pair();
+        let (u, v) = (u * gizmo_size, v * gizmo_size);
+        let corners = [
+            self.position - u - v,
+            self.position + u - v,
+            self.position + u + v,
+            self.position - u + v,
+            self.position - u - v,
+        ];
+
+        let mut batch = line_builder.batch("section plane");
+        batch
+            .add_strip(corners.into_iter())
+            .radius(Size::new_ui_points(1.0))
+            .color(color);
+        batch
+            .add_segment(self.position, self.handle_position(gizmo_size))
+            .radius(Size::new_ui_points(1.5))
+            .color(color);
+    }
+
+    /// Paints the drag handle on top of the scene.
+    pub fn paint_handle(
+        &self,
+        painter: &egui::Painter,
+        ui_from_world: &glam::Mat4,
+        gizmo_size: f32,
+        color: egui::Color32,
+        active: bool,
+    ) {
+        if let Some(handle) = project(ui_from_world, self.handle_position(gizmo_size)) {
+            let radius = if active {
+                1.5 * HANDLE_RADIUS
+            } else {
+                HANDLE_RADIUS
+            };
+            painter.circle_filled(handle, radius, color);
+        }
+    }
+}
+
+fn project(ui_from_world: &glam::Mat4, position: Vec3) -> Option<egui::Pos2> {
+    let position_in_ui = *ui_from_world * position.extend(1.0);
+    (position_in_ui.w > 0.0).then(|| {
+        egui::pos2(
+            position_in_ui.x / position_in_ui.w,
+            position_in_ui.y / position_in_ui.w,
+        )
+    })
+}
+
+/// Adding, moving and removing the section plane of a view.
+pub fn section_plane_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    scene_bbox: &BoundingBox,
+    eye: Option<Eye>,
+) {
+    let plane = SectionPlane::load(ctx, view_id);
+
+    let mut enabled = plane.is_some();
+    if ui
+        .re_checkbox(&mut enabled, "Cut the scene")
+        .on_hover_text("Drag the handle in the view to move the plane")
+        .changed()
+    {
+        if enabled {
+            let center = scene_bbox.center();
+            SectionPlane {
+                position: if center.is_finite() {
+                    center
+                } else {
+                    Vec3::ZERO
+                },
+                // Cut away the half of the scene facing the eye.
+                normal: eye.map_or(Vec3::X, |eye| eye.forward_in_world()),
+                show_contour: true,
+            }
+            .save(ctx, view_id);
+        } else {
+            SectionPlane::clear(ctx, view_id);
+        }
+    }
+
+    let Some(plane) = plane.filter(|_| enabled) else {
+        return;
+    };
+    let mut edited = plane;
+
+    ui.horizontal(|ui| {
+        ui.label("Position");
+        for value in edited.position.as_mut() {
+            ui.add(egui::DragValue::new(value).speed(0.01));
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Normal");
+        for (label, axis) in [("X", Vec3::X), ("Y", Vec3::Y), ("Z", Vec3::Z)] {
+            if ui.button(label).clicked() {
+                edited.normal = axis;
+            }
+        }
+        if let Some(eye) = eye
+            && ui
+                .button("View")
+                .on_hover_text("Face the plane towards the eye")
+                .clicked()
+        {
+            edited.normal = eye.forward_in_world();
+        }
+        if ui
+            .button("Flip")
+            .on_hover_text("Keep the other side")
+            .clicked()
+        {
+            edited.normal = -edited.normal;
+        }
+    });
+
+    ui.re_checkbox(&mut edited.show_contour, "Show cut contour")
+        .on_hover_text("Color meshes where they are cut");
+
+    if edited != plane {
+        edited.save(ctx, view_id);
+    }
+}
@@ -12,6 +12,7 @@ use re_chunk_store::{
 };
 use re_entity_db::EntityDb;
 use re_log_types::{EntityPath, EntityPathHash, StoreId, TimeInt, TimelineName};
+use re_query::LatestAtResults;
 use re_types::{
     Archetype as _, ArchetypeName, Component as _, ComponentDescriptor, ComponentType,
     archetypes::{self, InstancePoses3D},
@@ -76,6 +77,8 @@ impl Default for TransformCacheStoreSubscriber {
                 invalidated_transforms: Default::default(),
                 per_entity: Default::default(),
                 recursive_clears: Default::default(), // Unused for static timeline.
+                entities_per_time: Default::default(), // Unused for static timeline.
+                volatile_entities: Default::default(),
             },
         }
     }
@@ -122,6 +125,17 @@ pub struct CachedTransformsForTimeline {
     // We need to keep track of all recursive clears that ever happened and when.
     // Otherwise, new incoming entities may not correctly change their transform at the time of clear.
     recursive_clears: IntMap<EntityPathHash, Vec<TimeInt>>,
+
+    /// The entities whose transforms change at each point in time, see [`Self::entities_changed_between`].
+    ///
+    /// A recursive clear is listed under the cleared entity, since it changes the transforms of the whole subtree.
+    /// Entries aren't removed along with their data, so this may list a few entities too many.
+    entities_per_time: BTreeMap<TimeInt, IntSet<EntityPath>>,
+
+    /// Entities whose transforms may change at any time, not just at those in [`Self::entities_per_time`].
+    ///
+    /// These have interpolated tree transforms, or pinholes, whose image plane distance isn't cached here.
+    volatile_entities: IntSet<EntityPath>,
 }
 
 impl CachedTransformsForTimeline {
@@ -144,6 +158,8 @@ impl CachedTransformsForTimeline {
                 })
                 .collect(),
             recursive_clears: IntMap::default(),
+            entities_per_time: BTreeMap::default(),
+            volatile_entities: static_transforms.volatile_entities.clone(),
         }
     }
 
@@ -156,6 +172,13 @@ impl CachedTransformsForTimeline {
             }
         }
 
+        for time in &times {
+            self.entities_per_time
+                .entry(*time)
+                .or_default()
+                .insert(entity_path.clone());
+        }
+
         // Store for future reference.
         self.recursive_clears
             .entry(entity_path.hash())
@@ -200,6 +223,26 @@ impl PoseTransformArchetypeMap {
 
 type PoseTransformTimeMap = BTreeMap<TimeInt, PoseTransformArchetypeMap>;
 
+/// A resolved tree transform at a single point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TreeTransformSample {
+    transform: Affine3A,
+
+    /// Whether this sample may be interpolated with its neighbors.
+    ///
+    /// See [`components::TransformInterpolation`].
+    /// Interpolation only happens between two consecutive samples that both have this set.
+    interpolate: bool,
+}
+
+impl TreeTransformSample {
+    /// Used for clears and for entities that don't have a transform (anymore).
+    const IDENTITY: Self = Self {
+        transform: Affine3A::IDENTITY,
+        interpolate: false,
+    };
+}
+
 /// Maps from time to pinhole projection.
 ///
 /// Unlike with tree & pose transforms, there's no identity value that we can insert upon clears.
@@ -217,7 +260,7 @@ pub struct TransformsForEntity {
     #[cfg(debug_assertions)]
     timeline: Option<TimelineName>,
 
-    tree_transforms: BTreeMap<TimeInt, Affine3A>,
+    tree_transforms: BTreeMap<TimeInt, TreeTransformSample>,
 
     // Pose transforms and pinhole projections are typically more rare, which is why we store them as optional boxes.
     pose_transforms: Option<Box<PoseTransformTimeMap>>,
@@ -241,6 +284,39 @@ impl CachedTransformsForTimeline {
     pub fn entity_transforms(&self, entity_path: &EntityPath) -> Option<&TransformsForEntity> {
         self.per_entity.get(entity_path)
     }
+
+    /// Entities whose transforms may differ between the two points in time.
+    ///
+    /// Changes to the transforms of an entity also change those of its whole subtree,
+    /// but only the entity itself is listed. The same entity may be listed several times.
+    pub fn entities_changed_between(
+        &self,
+        from: TimeInt,
+        to: TimeInt,
+    ) -> impl Iterator<Item = &EntityPath> {
+        let (min, max) = if from <= to { (from, to) } else { (to, from) };
+
+        // Latest-at queries at both times only differ if something changed after the earlier one.
+        let entities_changed_in_between = (min < max)
+            .then(|| self.entities_per_time.range(min.inc()..=max))
+            .into_iter()
+            .flatten()
+            .flat_map(|(_time, entity_paths)| entity_paths);
+
+        let volatile_entities = (min < max)
+            .then_some(&self.volatile_entities)
+            .into_iter()
+            .flatten();
+
+        entities_changed_in_between.chain(volatile_entities)
+    }
+
+    /// Entities with pinholes or interpolated tree transforms, whose transforms may change at any time.
+    ///
+    /// The image plane distance of a pinhole may also be overridden in the blueprint.
+    pub fn volatile_entities(&self) -> impl Iterator<Item = &EntityPath> {
+        self.volatile_entities.iter()
+    }
 }
 
 impl TransformsForEntity {
@@ -307,8 +383,11 @@ impl TransformsForEntity {
             return;
         }
 
-        self.tree_transforms
-            .extend(times.iter().map(|time| (*time, Affine3A::IDENTITY)));
+        self.tree_transforms.extend(
+            times
+                .iter()
+                .map(|time| (*time, TreeTransformSample::IDENTITY)),
+        );
         self.pose_transforms
             .get_or_insert(Default::default())
             .extend(
@@ -321,16 +400,32 @@ impl TransformsForEntity {
             .extend(times.iter().map(|time| (*time, None)));
     }
 
+    /// The tree transform at the queried time.
+    ///
+    /// Despite the name, this interpolates between the samples before and after the queried time
+    /// if both of them were logged with [`components::TransformInterpolation::Linear`].
     #[inline]
     pub fn latest_at_tree_transform(&self, query: &LatestAtQuery) -> Affine3A {
         #[cfg(debug_assertions)] // `self.timeline` is only present with `debug_assertions` enabled.
         debug_assert!(Some(query.timeline()) == self.timeline || self.timeline.is_none());
 
-        self.tree_transforms
-            .range(..query.at().inc())
-            .next_back()
-            .map(|(_time, transform)| *transform)
-            .unwrap_or(Affine3A::IDENTITY)
+        let Some((time_before, before)) =
+            self.tree_transforms.range(..query.at().inc()).next_back()
+        else {
+            return Affine3A::IDENTITY;
+        };
+
+        if before.interpolate
+            && *time_before != query.at()
+            && let Some((time_after, after)) = self.tree_transforms.range(query.at().inc()..).next()
+            && after.interpolate
+        {
+            let t = (query.at().as_f64() - time_before.as_f64())
+                / (time_after.as_f64() - time_before.as_f64());
+            return interpolate_tree_transforms(&before.transform, &after.transform, t as f32);
+        }
+
+        before.transform
     }
 
     #[cfg(test)]
@@ -443,12 +538,17 @@ impl TransformCacheStoreSubscriber {
             );
 
             if aspects.contains(TransformAspect::Tree)
-                && let Some(transform) =
+                && let Some(sample) =
                     query_and_resolve_tree_transform_at_entity(&entity_path, entity_db, &query)
             {
-                static_transforms
-                    .tree_transforms
-                    .insert(TimeInt::STATIC, transform);
+                // There's nothing to interpolate with on the static timeline.
+                static_transforms.tree_transforms.insert(
+                    TimeInt::STATIC,
+                    TreeTransformSample {
+                        interpolate: false,
+                        ..sample
+                    },
+                );
             }
             if aspects.contains(TransformAspect::Pose) {
                 let poses =
@@ -466,6 +566,9 @@ impl TransformCacheStoreSubscriber {
                         TimeInt::STATIC,
                         Some(pinhole_projection),
                     )])));
+                    self.static_timeline
+                        .volatile_entities
+                        .insert(entity_path.clone());
                 }
             }
         }
@@ -492,16 +595,25 @@ impl TransformCacheStoreSubscriber {
                     });
 
                 for time in times {
+                    per_timeline
+                        .entities_per_time
+                        .entry(time)
+                        .or_default()
+                        .insert(entity_path.clone());
+
                     let query = LatestAtQuery::new(*timeline, time);
                     if aspects.intersects(TransformAspect::Tree | TransformAspect::Clear) {
-                        let transform = query_and_resolve_tree_transform_at_entity(
+                        let sample = query_and_resolve_tree_transform_at_entity(
                             &entity_path,
                             entity_db,
                             &query,
                         )
-                        .unwrap_or(Affine3A::IDENTITY);
+                        .unwrap_or(TreeTransformSample::IDENTITY);
+                        if sample.interpolate {
+                            per_timeline.volatile_entities.insert(entity_path.clone());
+                        }
                         // If there's *no* transform, we have to put identity in, otherwise we'd miss clears!
-                        entity_entry.tree_transforms.insert(time, transform);
+                        entity_entry.tree_transforms.insert(time, sample);
                     }
                     if aspects.intersects(TransformAspect::Pose | TransformAspect::Clear) {
                         let poses = query_and_resolve_instance_poses_at_entity(
@@ -523,6 +635,9 @@ impl TransformCacheStoreSubscriber {
                             entity_db,
                             &query,
                         );
+                        if pinhole_projection.is_some() {
+                            per_timeline.volatile_entities.insert(entity_path.clone());
+                        }
                         // `None` values need to be inserted as well to clear out previous state.
                         // See also doc string on `PinholeProjectionMap`.
                         entity_entry
@@ -825,7 +940,7 @@ fn query_and_resolve_tree_transform_at_entity(
     entity_path: &EntityPath,
     entity_db: &EntityDb,
     query: &LatestAtQuery,
-) -> Option<Affine3A> {
+) -> Option<TreeTransformSample> {
     // TODO(andreas): Filter out styling components.
     let results = entity_db.latest_at(
         query,
@@ -836,6 +951,26 @@ fn query_and_resolve_tree_transform_at_entity(
         return None;
     }
 
+    let transform = resolve_tree_transform(entity_path, &results);
+
+    // Arbitrary matrices can't be decomposed into translation, rotation & scale, so we don't interpolate them.
+    let interpolate = results.component_mono_quiet::<components::TransformInterpolation>(
+        &archetypes::Transform3D::descriptor_interpolation(),
+    ) == Some(components::TransformInterpolation::Linear)
+        && results
+            .component_mono_quiet::<components::TransformMat3x3>(
+                &archetypes::Transform3D::descriptor_mat3x3(),
+            )
+            .is_none()
+        && transform != Affine3A::ZERO;
+
+    Some(TreeTransformSample {
+        transform,
+        interpolate,
+    })
+}
+
+fn resolve_tree_transform(entity_path: &EntityPath, results: &LatestAtResults) -> Affine3A {
     let mut transform = Affine3A::IDENTITY;
 
     // It's an error if there's more than one component. Warn in that case.
@@ -857,7 +992,7 @@ fn query_and_resolve_tree_transform_at_entity(
         if let Ok(axis_angle) = Affine3A::try_from(axis_angle) {
             transform *= axis_angle;
         } else {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
     }
     if let Some(quaternion) = results.component_mono_with_log_level::<components::RotationQuat>(
@@ -867,7 +1002,7 @@ fn query_and_resolve_tree_transform_at_entity(
         if let Ok(quaternion) = Affine3A::try_from(quaternion) {
             transform *= quaternion;
         } else {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
     }
     if let Some(scale) = results.component_mono_with_log_level::<components::Scale3D>(
@@ -875,7 +1010,7 @@ fn query_and_resolve_tree_transform_at_entity(
         mono_log_level,
     ) {
         if scale.x() == 0.0 && scale.y() == 0.0 && scale.z() == 0.0 {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
         transform *= Affine3A::from(scale);
     }
@@ -885,7 +1020,7 @@ fn query_and_resolve_tree_transform_at_entity(
     ) {
         let affine_transform = Affine3A::from(mat3x3);
         if affine_transform.matrix3.determinant() == 0.0 {
-            return Some(Affine3A::ZERO);
+            return Affine3A::ZERO;
         }
         transform *= affine_transform;
     }
@@ -908,7 +1043,19 @@ fn query_and_resolve_tree_transform_at_entity(
         }
     }
 
-    Some(transform)
+    transform
+}
+
+/// Interpolates translation & scale linearly and rotation spherically between two tree transforms.
+fn interpolate_tree_transforms(from: &Affine3A, to: &Affine3A, t: f32) -> Affine3A {
+    let (scale_from, rotation_from, translation_from) = from.to_scale_rotation_translation();
+    let (scale_to, rotation_to, translation_to) = to.to_scale_rotation_translation();
+
+    Affine3A::from_scale_rotation_translation(
+        scale_from.lerp(scale_to, t),
+        rotation_from.slerp(rotation_to, t),
+        translation_from.lerp(translation_to, t),
+    )
 }
 
 /// Lists all archetypes except [`archetypes::InstancePoses3D`] that have their own instance poses.
@@ -1785,6 +1932,117 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_tree_transforms_interpolation() {
+        let mut entity_db = new_entity_db_with_subscriber_registered();
+
+        let timeline = Timeline::new_sequence("t");
+        let linear = components::TransformInterpolation::Linear;
+        let chunk = Chunk::builder(EntityPath::from("my_entity"))
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 0)],
+                &archetypes::Transform3D::from_translation([0.0, 0.0, 0.0])
+                    .with_interpolation(linear),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 10)],
+                &archetypes::Transform3D::from_translation_rotation(
+                    [10.0, 20.0, 30.0],
+                    glam::Quat::from_rotation_z(1.0),
+                )
+                .with_interpolation(linear),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 20)],
+                // No interpolation towards samples that don't ask for it.
+                &archetypes::Transform3D::from_translation([100.0, 100.0, 100.0]),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 30)],
+                &archetypes::Transform3D::from_translation([10.0, 0.0, 0.0])
+                    .with_interpolation(linear),
+            )
+            .with_archetype(
+                RowId::new(),
+                [(timeline, 40)],
+                // Arbitrary matrices are never interpolated.
+                &archetypes::Transform3D::from_translation_mat3x3(
+                    [50.0, 0.0, 0.0],
+                    glam::Mat3::from_diagonal(glam::Vec3::splat(2.0)),
+                )
+                .with_interpolation(linear),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+
+        TransformCacheStoreSubscriber::access_mut(entity_db.store_id(), |cache| {
+            let timeline_name = *timeline.name();
+            cache.apply_all_updates(&entity_db);
+            let transforms_per_timeline = cache.transforms_for_timeline(timeline_name);
+            let transforms = transforms_per_timeline
+                .entity_transforms(&EntityPath::from("my_entity"))
+                .unwrap();
+
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 0)),
+                glam::Affine3A::IDENTITY
+            );
+            assert!(
+                transforms
+                    .latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 2))
+                    .abs_diff_eq(
+                        glam::Affine3A::from_rotation_translation(
+                            glam::Quat::from_rotation_z(0.2),
+                            glam::Vec3::new(2.0, 4.0, 6.0),
+                        ),
+                        1e-5
+                    )
+            );
+            assert!(
+                transforms
+                    .latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 5))
+                    .abs_diff_eq(
+                        glam::Affine3A::from_rotation_translation(
+                            glam::Quat::from_rotation_z(0.5),
+                            glam::Vec3::new(5.0, 10.0, 15.0),
+                        ),
+                        1e-5
+                    )
+            );
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 10)),
+                glam::Affine3A::from_rotation_translation(
+                    glam::Quat::from_rotation_z(1.0),
+                    glam::Vec3::new(10.0, 20.0, 30.0),
+                )
+            );
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 15)),
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 10))
+            );
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 25)),
+                glam::Affine3A::from_translation(glam::Vec3::new(100.0, 100.0, 100.0))
+            );
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 35)),
+                glam::Affine3A::from_translation(glam::Vec3::new(10.0, 0.0, 0.0))
+            );
+            assert_eq!(
+                transforms.latest_at_tree_transform(&LatestAtQuery::new(timeline_name, 40)),
+                glam::Affine3A::from_mat3_translation(
+                    glam::Mat3::from_diagonal(glam::Vec3::splat(2.0)),
+                    glam::Vec3::new(50.0, 0.0, 0.0),
+                )
+            );
+        });
+    }
+
     #[test]
     fn test_pose_transforms_instance_poses_only() {
         let mut entity_db = new_entity_db_with_subscriber_registered();
@@ -10,11 +10,13 @@ use re_viewer_context::{HoverHighlight, ImageInfo, SelectionHighlight, ViewHighl
 
 use crate::{
     Pinhole,
+    box_selection::BoxSelectionState,
     pickable_textured_rect::PickableRectSourceData,
     picking::{PickableUiRect, PickingResult},
+    pixel_probe::PixelProbeState,
     scene_bounding_boxes::SceneBoundingBoxes,
     view_kind::SpatialViewKind,
-    visualizers::{SpatialViewVisualizerData, UiLabel, UiLabelStyle, UiLabelTarget},
+    visualizers::{SpatialViewVisualizerData, UiLabel, UiLabelSize, UiLabelStyle, UiLabelTarget},
 };
 
 use super::{eye::Eye, ui_3d::View3DState};
@@ -42,6 +44,7 @@ pub struct ImageCounts {
     pub segmentation: usize,
     pub color: usize,
     pub depth: usize,
+    pub heatmap: usize,
 }
 
 /// TODO(andreas): Should turn this "inside out" - [`SpatialViewState`] should be used by `View3DState`, not the other way round.
@@ -61,6 +64,12 @@ pub struct SpatialViewState {
     pub pinhole_at_origin: Option<Pinhole>,
 
     pub visual_bounds_2d: Option<VisualBounds2D>,
+
+    /// Statistics of the pixels in a region of interest of the 2D view.
+    pub pixel_probe: PixelProbeState,
+
+    /// Selecting all instances within a dragged out rectangle.
+    pub box_selection: BoxSelectionState,
 }
 
 impl ViewState for SpatialViewState {
@@ -71,6 +80,40 @@ impl ViewState for SpatialViewState {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn save(&self) -> Option<serde_json::Value> {
+        let eye = self.state_3d.eye_moved_by_user()?;
+        serde_json::to_value(eye).ok()
+    }
+
+    fn restore(&mut self, saved: &serde_json::Value) {
+        match serde_json::from_value(saved.clone()) {
+            Ok(eye) => self.state_3d.restore_eye(eye),
+            Err(err) => re_log::debug!("Failed to restore the eye of a view: {err}"),
+        }
+    }
+
+    fn execution_fingerprint(&self) -> Option<u64> {
+        // What the fallbacks of the spatial views read, see `view_2d_properties` and `view_3d_properties`.
+        let bounding_box_bits = |bbox: &BoundingBox| {
+            bbox.min
+                .to_array()
+                .into_iter()
+                .chain(bbox.max.to_array())
+                .map(f32::to_bits)
+                .collect::<Vec<_>>()
+        };
+
+        Some(egui::util::hash((
+            bounding_box_bits(&self.bounding_boxes.current),
+            bounding_box_bits(&self.bounding_boxes.smoothed),
+            self.pinhole_at_origin
+                .map(|pinhole| pinhole.resolution.to_array().map(f32::to_bits)),
+            self.state_3d
+                .scene_view_coordinates
+                .map(|view_coordinates| view_coordinates.0.0),
+        )))
+    }
 }
 
 impl SpatialViewState {
@@ -101,6 +144,7 @@ impl SpatialViewState {
                         ImageKind::Segmentation => self.image_counts_last_frame.segmentation += 1,
                         ImageKind::Color => self.image_counts_last_frame.color += 1,
                         ImageKind::Depth => self.image_counts_last_frame.depth += 1,
+                        ImageKind::Heatmap => self.image_counts_last_frame.heatmap += 1,
                     },
                     PickableRectSourceData::Video => {
                         self.image_counts_last_frame.color += 1;
@@ -164,12 +208,13 @@ impl SpatialViewState {
         //
         // By default, the draw order is (front to back):
         // * segmentation image
+        // * heatmap
         // * color image
         // * depth image
         let counts = self.image_counts_last_frame;
         match kind {
             ImageKind::Segmentation => {
-                if counts.color + counts.depth > 0 {
+                if counts.color + counts.depth + counts.heatmap > 0 {
                     // Segmentation images should always be transparent if there was more than one image in the view,
                     // excluding other segmentation images.
                     0.5
@@ -184,12 +229,38 @@ impl SpatialViewState {
                     1.0
                 }
             }
+            ImageKind::Heatmap => {
+                if counts.color + counts.depth > 0 {
+                    0.5
+                } else {
+                    1.0
+                }
+            }
             // NOTE: Depth images do not support opacity
             ImageKind::Depth => 1.0,
         }
     }
 }
 
+/// Labels with a smaller font size (in ui points) are not shown.
+const MIN_LABEL_FONT_SIZE: f32 = 1.0;
+
+/// Labels in scene units can get arbitrarily large when the camera gets close to them.
+const MAX_LABEL_FONT_SIZE: f32 = 256.0;
+
+/// How many ui points a vertical line of one scene unit at `pos_in_world` spans on screen.
+fn ui_points_per_scene_unit(
+    ui_from_world: &glam::Mat4,
+    eye: &Eye,
+    pos_in_world: glam::Vec3,
+) -> f32 {
+    let pos_in_ui = ui_from_world.project_point3(pos_in_world).truncate();
+    let up_in_ui = ui_from_world
+        .project_point3(pos_in_world + eye.up_in_world())
+        .truncate();
+    pos_in_ui.distance(up_in_ui)
+}
+
 pub fn create_labels(
     mut labels: Vec<UiLabel>,
     ui_from_scene: egui::emath::RectTransform,
@@ -215,7 +286,7 @@ pub fn create_labels(
     let mut ui_rects = Vec::with_capacity(labels.len());
 
     for label in labels {
-        let (wrap_width, text_anchor_pos) = match label.target {
+        let (wrap_width, target_pos) = match label.target {
             UiLabelTarget::Rect(rect) => {
                 // TODO(#1640): 2D labels are not visible in 3D for now.
                 if spatial_kind == SpatialViewKind::ThreeD {
@@ -223,9 +294,8 @@ pub fn create_labels(
                 }
                 let rect_in_ui = ui_from_scene.transform_rect(rect);
                 (
-                    // Place the text centered below the rect
                     (rect_in_ui.width() - 4.0).at_least(60.0),
-                    rect_in_ui.center_bottom() + egui::vec2(0.0, 3.0),
+                    rect_in_ui.center_bottom(),
                 )
             }
             UiLabelTarget::Point2D(pos) => {
@@ -233,8 +303,7 @@ pub fn create_labels(
                 if spatial_kind == SpatialViewKind::ThreeD {
                     continue;
                 }
-                let pos_in_ui = ui_from_scene.transform_pos(pos);
-                (f32::INFINITY, pos_in_ui + egui::vec2(0.0, 3.0))
+                (f32::INFINITY, ui_from_scene.transform_pos(pos))
             }
             UiLabelTarget::Position3D(pos) => {
                 // TODO(#1640): 3D labels are not visible in 2D for now.
@@ -250,7 +319,33 @@ pub fn create_labels(
             }
         };
 
-        let font_id = egui::TextStyle::Body.resolve(parent_ui.style());
+        let (anchor, text_anchor_pos) = match label.anchor {
+            Some(anchor) => (anchor, target_pos),
+            // Place the text centered below the target
+            None => (egui::Align2::CENTER_TOP, target_pos + egui::vec2(0.0, 3.0)),
+        };
+
+        let mut font_id = egui::TextStyle::Body.resolve(parent_ui.style());
+        match label.size {
+            UiLabelSize::Default => {}
+            UiLabelSize::UiPoints(size) => font_id.size = size,
+            UiLabelSize::SceneUnits(size) => {
+                font_id.size = size
+                    * match label.target {
+                        UiLabelTarget::Position3D(pos) => {
+                            ui_points_per_scene_unit(&ui_from_world_3d, eye3d, pos)
+                        }
+                        UiLabelTarget::Rect(_) | UiLabelTarget::Point2D(_) => {
+                            ui_from_scene.scale().y
+                        }
+                    };
+            }
+        }
+        if font_id.size < MIN_LABEL_FONT_SIZE {
+            continue; // too small to be readable
+        }
+        font_id.size = font_id.size.at_most(MAX_LABEL_FONT_SIZE);
+
         let is_error = matches!(label.style, UiLabelStyle::Error);
         let text_color = match label.style {
             UiLabelStyle::Default => parent_ui.visuals().strong_text_color(),
@@ -279,8 +374,8 @@ pub fn create_labels(
             })
         });
 
-        let text_rect = egui::Align2::CENTER_TOP
-            .anchor_rect(egui::Rect::from_min_size(text_anchor_pos, galley.size()));
+        let text_rect =
+            anchor.anchor_rect(egui::Rect::from_min_size(text_anchor_pos, galley.size()));
         let bg_rect = text_rect.expand2(egui::vec2(4.0, 2.0));
 
         let highlight = highlights
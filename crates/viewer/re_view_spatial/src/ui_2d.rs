@@ -18,8 +18,8 @@ use re_viewport_blueprint::ViewProperty;
 
 use super::{eye::Eye, ui::create_labels};
 use crate::{
-    Pinhole, SpatialView2D, ui::SpatialViewState, view_kind::SpatialViewKind,
-    visualizers::collect_ui_labels,
+    Pinhole, SpatialView2D, grid_overlay_2d::GridOverlay2D, ui::SpatialViewState,
+    view_kind::SpatialViewKind, visualizers::collect_ui_labels,
 };
 
 // ---
@@ -67,7 +67,17 @@ fn ui_from_scene(
 
     // --------------------------------------------------------------------------
 
-    let mut pan_delta_in_ui = response.drag_delta();
+    // Two fingers always pan, while one finger drags the pointer along with them.
+    let touch_gesture = re_ui::touch::multi_finger_gesture(response);
+
+    // While probing pixels, dragging moves the region of interest instead.
+    let mut pan_delta_in_ui = if let Some(gesture) = &touch_gesture {
+        gesture.translation_delta
+    } else if view_state.pixel_probe.is_active() && response.dragged_by(DRAG_PAN2D_BUTTON) {
+        Vec2::ZERO
+    } else {
+        response.drag_delta()
+    };
     if response.hovered() {
         pan_delta_in_ui += response.ctx.input(|i| i.smooth_scroll_delta);
     }
@@ -79,8 +89,9 @@ fn ui_from_scene(
         let zoom_delta = response.ctx.input(|i| i.zoom_delta_2d());
 
         if zoom_delta != Vec2::splat(1.0) {
-            let zoom_center_in_ui = response
-                .hover_pos()
+            let zoom_center_in_ui = touch_gesture
+                .map(|gesture| gesture.center_pos)
+                .or_else(|| response.hover_pos())
                 .unwrap_or_else(|| response.rect.center());
             let zoom_center_in_scene = ui_from_scene
                 .inverse()
@@ -129,7 +140,15 @@ pub fn help(os: egui::os::OperatingSystem) -> Help {
             "Zoom",
             re_ui::IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
         )
+        .control(
+            "Pan and zoom with touch",
+            ("drag two fingers", "/", "pinch"),
+        )
         .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+        .control(
+            "Select region when probing pixels",
+            (MouseButtonText(DRAG_PAN2D_BUTTON), "+", "drag"),
+        )
 }
 
 /// Create the outer 2D view, which consists of a scrollable region
@@ -188,6 +207,11 @@ impl SpatialView2D {
             ui_from_scene
         };
         let scene_from_ui = ui_from_scene.inverse();
+        let view_rect = response.rect;
+        let hover_pos = response.hover_pos();
+
+        state.pixel_probe.handle_drag(&response, &scene_from_ui);
+        state.box_selection.handle_drag(ui, view_rect);
 
         let view_ctx = self.view_context(ctx, query.view_id, state);
         let near_clip_plane: blueprint_components::NearClipPlane = clip_property
@@ -232,7 +256,17 @@ impl SpatialView2D {
 
         let mut view_builder = ViewBuilder::new(ctx.render_ctx(), target_config);
 
-        if let Some(pointer_pos_ui) = response.hover_pos() {
+        state
+            .box_selection
+            .apply_readback(ctx, query.view_id, &system_output.view_systems);
+        let box_selection_scheduled =
+            state
+                .box_selection
+                .schedule_readback(ctx, &mut view_builder, query.view_id, view_rect);
+
+        if let Some(pointer_pos_ui) = response.hover_pos()
+            && !box_selection_scheduled
+        {
             let picking_context = crate::picking::PickingContext::new(
                 pointer_pos_ui,
                 scene_from_ui,
@@ -307,6 +341,28 @@ impl SpatialView2D {
         // Add egui-rendered labels on top of everything else:
         painter.extend(label_shapes);
 
+        GridOverlay2D::load(ctx, query.view_id).paint(&painter, &ui_from_scene, hover_pos);
+
+        if state.pixel_probe.is_active() {
+            state
+                .pixel_probe
+                .update_statistics(crate::picking_ui::iter_pickable_rects(
+                    &system_output.view_systems,
+                ));
+            state
+                .pixel_probe
+                .paint(&painter, &ui_from_scene, ui.ctx().selection_stroke());
+
+            let mut overlay_ui = ui.new_child(
+                egui::UiBuilder::new()
+                    .max_rect(view_rect.shrink(8.0))
+                    .layout(egui::Layout::top_down(egui::Align::Min)),
+            );
+            state.pixel_probe.overlay_ui(&mut overlay_ui);
+        }
+
+        state.box_selection.paint(&painter);
+
         Ok(())
     }
 }
@@ -411,6 +467,8 @@ fn setup_target_config(
             pixels_per_point,
             outline_config: any_outlines.then(|| re_view::outline_config(egui_painter.ctx())),
             blend_with_background: false,
+            clip_plane: None,
+            lighting: Default::default(),
         }
     })
 }
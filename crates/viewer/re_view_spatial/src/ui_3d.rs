@@ -22,14 +22,19 @@ use re_view::controls::{
     SPEED_UP_3D_MODIFIER, TRACKED_OBJECT_RESTORE_KEY,
 };
 use re_viewer_context::{
-    Item, ItemContext, ViewClassExt as _, ViewContext, ViewQuery, ViewSystemExecutionError,
-    ViewerContext, gpu_bridge,
+    HighResScreenshotRequest, Item, ItemContext, ViewClass as _, ViewClassExt as _, ViewContext,
+    ViewQuery, ViewSystemExecutionError, ViewerContext, gpu_bridge,
 };
-use re_viewport_blueprint::ViewProperty;
+use re_viewport_blueprint::{FollowLatest, ViewProperty};
 
 use crate::{
     SpatialView3D,
+    camera_controller::{CameraController, picked_camera_controller},
+    camera_path::{CameraPath, CameraPathPlayback},
+    lighting::load_lighting,
+    measurement::{MeasuredPoint, MeasurementState},
     scene_bounding_boxes::SceneBoundingBoxes,
+    section_plane::SectionPlane,
     space_camera_3d::SpaceCamera3D,
     transform_cache::query_view_coordinates_at_closest_ancestor,
     ui::{SpatialViewState, create_labels},
@@ -57,8 +62,16 @@ pub struct View3DState {
     /// Eye pose just before we started following an entity [`Self::tracked_entity`].
     camera_before_tracked_entity: Option<Eye>,
 
+    /// The entity last tracked because the view follows the latest data.
+    ///
+    /// It's only tracked again once it changes, so moving the eye isn't fought.
+    followed_entity: Option<EntityPath>,
+
     eye_interpolation: Option<EyeInterpolation>,
 
+    /// Eye to move to as soon as the view eye exists, see [`Self::restore_eye`].
+    restored_eye: Option<Eye>,
+
     /// Last known view coordinates.
     /// Used to detect changes in view coordinates, in which case we reset the camera eye.
     pub scene_view_coordinates: Option<ViewCoordinates>,
@@ -69,6 +82,12 @@ pub struct View3DState {
     pub show_bbox: bool,
     pub show_smoothed_bbox: bool,
 
+    /// Points picked for measuring distances and angles.
+    pub measurement: MeasurementState,
+
+    /// Set while the eye follows the [`CameraPath`] of the view.
+    pub camera_path_playback: Option<CameraPathPlayback>,
+
     eye_interact_fade_in: bool,
     eye_interact_fade_change_time: f64,
 }
@@ -80,12 +99,16 @@ impl Default for View3DState {
             last_eye_interaction: None,
             tracked_entity: None,
             camera_before_tracked_entity: None,
+            followed_entity: None,
             eye_interpolation: Default::default(),
+            restored_eye: None,
             scene_view_coordinates: None,
             spin: false,
             show_axes: false,
             show_bbox: false,
             show_smoothed_bbox: false,
+            measurement: Default::default(),
+            camera_path_playback: None,
             eye_interact_fade_in: false,
             eye_interact_fade_change_time: f64::NEG_INFINITY,
         }
@@ -97,6 +120,17 @@ fn ease_out(t: f32) -> f32 {
 }
 
 impl View3DState {
+    /// The eye, if the user moved it away from the default.
+    pub fn eye_moved_by_user(&self) -> Option<Eye> {
+        self.last_eye_interaction?;
+        self.view_eye.map(|view_eye| view_eye.to_eye())
+    }
+
+    /// Moves the eye to where it was when the recording was last open, see [`Self::eye_moved_by_user`].
+    pub fn restore_eye(&mut self, eye: Eye) {
+        self.restored_eye = Some(eye);
+    }
+
     pub fn reset_camera(
         &mut self,
         scene_bbox: &SceneBoundingBoxes,
@@ -108,6 +142,66 @@ impl View3DState {
         self.camera_before_tracked_entity = None;
     }
 
+    /// Flies to the given eye, e.g. a keyframe of the camera path.
+    pub fn go_to_eye(&mut self, eye: Eye) {
+        self.camera_path_playback = None;
+        self.last_eye_interaction = Some(Instant::now());
+        self.tracked_entity = None;
+        self.camera_before_tracked_entity = None;
+        self.interpolate_to_eye(eye);
+    }
+
+    /// Moves the eye along the camera path while it's playing.
+    ///
+    /// Dragging the view hands the eye back to the user.
+    fn follow_camera_path(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        camera_path: &CameraPath,
+        response: &egui::Response,
+    ) {
+        let Some(playback) = self.camera_path_playback else {
+            return;
+        };
+        if response.dragged() {
+            self.camera_path_playback = None;
+            return;
+        }
+
+        let time = match playback {
+            CameraPathPlayback::WallClock(start) => {
+                let start_time = camera_path.keyframes.first().map_or(0.0, |k| k.time);
+                let time = start_time + start.elapsed().as_secs_f64();
+                if camera_path
+                    .end_time()
+                    .is_none_or(|end_time| end_time < time)
+                {
+                    self.camera_path_playback = None;
+                } else {
+                    response.ctx.request_repaint();
+                }
+                Some(time)
+            }
+            CameraPathPlayback::RecordingTime => {
+                let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+                if camera_path.timeline.as_ref() == Some(time_ctrl.timeline().name()) {
+                    time_ctrl.time().map(|time| time.as_f64())
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some(eye) = time.and_then(|time| camera_path.eye_at(time))
+            && let Some(view_eye) = &mut self.view_eye
+        {
+            view_eye.copy_from_eye(&eye);
+            self.eye_interpolation = None;
+            self.tracked_entity = None;
+            self.last_eye_interaction = Some(Instant::now());
+        }
+    }
+
     fn update_eye(
         &mut self,
         response: &egui::Response,
@@ -116,6 +210,7 @@ impl View3DState {
         scene_view_coordinates: Option<ViewCoordinates>,
         view_ctx: &ViewContext<'_>,
         eye_property: &ViewProperty,
+        camera_controller: Option<&dyn CameraController>,
     ) -> ViewEye {
         // If the user has not interacted with the eye-camera yet, continue to
         // interpolate to the new default eye. This gives much better robustness
@@ -136,6 +231,15 @@ impl View3DState {
         }
         self.scene_view_coordinates = scene_view_coordinates;
 
+        if let Some(eye) = self.restored_eye.take()
+            && let Some(view_eye) = &mut self.view_eye
+        {
+            view_eye.copy_from_eye(&eye);
+            self.eye_interpolation = None;
+            self.tracked_entity = None;
+            self.last_eye_interaction = Some(Instant::now());
+        }
+
         // Follow tracked object.
         if let Some(tracked_entity) = self.tracked_entity.clone() {
             if let Some(target_eye) = find_camera(space_cameras, &tracked_entity) {
@@ -198,7 +302,17 @@ impl View3DState {
             0.0
         };
 
-        if view_eye.update(response, view_eye_drag_threshold, view_ctx, eye_property) {
+        let scene_up = scene_view_coordinates
+            .and_then(|coordinates| coordinates.up())
+            .map(Vec3::from);
+        if view_eye.update(
+            response,
+            view_eye_drag_threshold,
+            view_ctx,
+            eye_property,
+            camera_controller,
+            scene_up,
+        ) {
             self.last_eye_interaction = Some(Instant::now());
             self.eye_interpolation = None;
             self.tracked_entity = None;
@@ -327,6 +441,23 @@ impl View3DState {
         self.interpolate_eye_to_entity(entity_path, bounding_boxes, space_cameras);
     }
 
+    /// Tracks the camera entity of a view following the latest data, whenever it changes.
+    fn follow_entity(
+        &mut self,
+        entity_path: Option<&EntityPath>,
+        bounding_boxes: &SceneBoundingBoxes,
+        space_cameras: &[SpaceCamera3D],
+    ) {
+        if self.followed_entity.as_ref() == entity_path {
+            return;
+        }
+        self.followed_entity = entity_path.cloned();
+
+        if let Some(entity_path) = entity_path {
+            self.track_entity(entity_path, bounding_boxes, space_cameras);
+        }
+    }
+
     pub fn spin(&self) -> bool {
         self.spin
     }
@@ -398,6 +529,11 @@ pub fn help(os: egui::os::OperatingSystem) -> Help {
             "Roll",
             IconText::from_modifiers_and(os, ROLL_MOUSE_MODIFIER, MouseButtonText(ROLL_MOUSE_ALT)),
         )
+        .control("Rotate with touch", "drag")
+        .control(
+            "Pan, roll and zoom with touch",
+            ("drag", "/", "twist", "/", "pinch two fingers"),
+        )
         .control("Navigate", ("WASD", "/", "QE"))
         .control(
             "Slow down / speed up",
@@ -449,6 +585,31 @@ impl SpatialView3D {
             query.view_id,
         );
 
+        if state.state_3d.camera_path_playback.is_some() {
+            let camera_path = CameraPath::load(ctx, query.view_id);
+            state
+                .state_3d
+                .follow_camera_path(ctx, &camera_path, &response);
+        }
+
+        // The handle of the section plane takes precedence over moving the eye.
+        let section_plane = SectionPlane::load(ctx, query.view_id);
+        let gizmo_size = SectionPlane::gizmo_size(&state.bounding_boxes.current);
+        let section_plane_handle_active = match (&section_plane, state.state_3d.view_eye) {
+            (Some(section_plane), Some(view_eye)) => section_plane.handle_ui(
+                ctx,
+                ui,
+                query.view_id,
+                &view_eye.to_eye(),
+                ui_rect,
+                gizmo_size,
+            ),
+            _ => false,
+        };
+
+        // While box selecting, dragging draws the rectangle instead of moving the eye.
+        state.box_selection.handle_drag(ui, ui_rect);
+
         let view_eye = state.state_3d.update_eye(
             &response,
             &state.bounding_boxes,
@@ -456,6 +617,7 @@ impl SpatialView3D {
             scene_view_coordinates,
             &self.view_context(ctx, query.view_id, &state.clone()),
             &eye_property,
+            picked_camera_controller(ctx, Self::identifier(), query.view_id).as_deref(),
         );
         let eye = view_eye.to_eye();
 
@@ -486,6 +648,9 @@ impl SpatialView3D {
                 .any_outlines()
                 .then(|| re_view::outline_config(ui.ctx())),
             blend_with_background: false,
+            clip_plane: section_plane
+                .map(|section_plane| section_plane.to_clip_plane(ui.visuals().warn_fg_color)),
+            lighting: load_lighting(ctx, query.view_id),
         };
 
         // Various ui interactions draw additional lines.
@@ -515,7 +680,8 @@ impl SpatialView3D {
             state.bounding_boxes.current.extend(glam::Vec3::ZERO);
         }
 
-        let mut view_builder = ViewBuilder::new(ctx.render_ctx(), target_config);
+        let high_res_screenshot = HighResScreenshotRequest::take(ui.ctx(), query.view_id);
+        let mut view_builder = ViewBuilder::new(ctx.render_ctx(), target_config.clone());
 
         // Create labels now since their shapes participate are added to scene.ui for picking.
         let (label_shapes, ui_rects) = create_labels(
@@ -527,7 +693,17 @@ impl SpatialView3D {
             SpatialViewKind::ThreeD,
         );
 
-        if let Some(pointer_pos_ui) = response.hover_pos() {
+        state
+            .box_selection
+            .apply_readback(ctx, query.view_id, &system_output.view_systems);
+        let box_selection_scheduled =
+            state
+                .box_selection
+                .schedule_readback(ctx, &mut view_builder, query.view_id, ui_rect);
+
+        if let Some(pointer_pos_ui) = response.hover_pos()
+            && !box_selection_scheduled
+        {
             // There's no panning & zooming, so this is an identity transform.
             let ui_pan_and_zoom_from_ui = RectTransform::from_to(ui_rect, ui_rect);
 
@@ -553,6 +729,39 @@ impl SpatialView3D {
             state.previous_picking_result = None;
         }
 
+        if state.state_3d.measurement.is_active() {
+            let picked_point = state.previous_picking_result.as_ref().and_then(|result| {
+                Some(MeasuredPoint {
+                    position: result.space_position()?,
+                    normal: result.space_normal(),
+                })
+            });
+            if response.clicked()
+                && let Some(picked_point) = picked_point
+            {
+                state.state_3d.measurement.add_point(picked_point);
+            }
+            state.state_3d.measurement.add_lines(
+                &mut line_builder,
+                picked_point.map(|point| point.position),
+                ui.ctx().selection_stroke().color,
+            );
+        }
+
+        if let Some(section_plane) = &section_plane {
+            section_plane.add_lines(&mut line_builder, gizmo_size, ui.visuals().warn_fg_color);
+        }
+
+        let follow_latest = FollowLatest::load(ctx, query.view_id);
+        state.state_3d.follow_entity(
+            follow_latest
+                .camera_entity
+                .as_ref()
+                .filter(|_| follow_latest.enabled),
+            &state.bounding_boxes,
+            space_cameras,
+        );
+
         // Track focused entity if any.
         if let Some(focused_item) = ctx.focused_item {
             let focused_entity = match focused_item {
@@ -669,9 +878,8 @@ impl SpatialView3D {
             scene_view_coordinates,
         );
 
-        for draw_data in system_output.draw_data {
-            view_builder.queue_draw(draw_data);
-        }
+        // Kept around for drawing them again into a screenshot.
+        let mut draws = system_output.draw_data;
 
         let view_ctx = self.view_context(ctx, query.view_id, state);
 
@@ -682,11 +890,11 @@ impl SpatialView3D {
             query.view_id,
         );
         if let Some(draw_data) = self.setup_grid_3d(&view_ctx, &grid_config)? {
-            view_builder.queue_draw(draw_data);
+            draws.push(draw_data.into());
         }
 
         // Commit ui induced lines.
-        view_builder.queue_draw(line_builder.into_draw_data()?);
+        draws.push(line_builder.into_draw_data()?.into());
 
         let background = ViewProperty::from_archetype::<Background>(
             ctx.blueprint_db(),
@@ -696,7 +904,23 @@ impl SpatialView3D {
         let (background_drawable, clear_color) =
             crate::configure_background(&view_ctx, &background, self)?;
         if let Some(background_drawable) = background_drawable {
-            view_builder.queue_draw(background_drawable);
+            draws.push(background_drawable);
+        }
+
+        if let Some(request) = high_res_screenshot {
+            render_high_res_screenshot(
+                ctx,
+                ui,
+                ui_rect,
+                &target_config,
+                &draws,
+                clear_color,
+                request,
+            );
+        }
+
+        for draw_data in draws {
+            view_builder.queue_draw(draw_data);
         }
 
         ui.painter().add(gpu_bridge::new_renderer_callback(
@@ -717,6 +941,33 @@ impl SpatialView3D {
         let painter = ui.painter().with_clip_rect(ui.max_rect());
         painter.extend(label_shapes);
 
+        if let Some(section_plane) = &section_plane {
+            section_plane.paint_handle(
+                &painter,
+                &eye.ui_from_world(ui_rect),
+                gizmo_size,
+                ui.visuals().warn_fg_color,
+                section_plane_handle_active,
+            );
+        }
+
+        if state.state_3d.measurement.is_active() {
+            state.state_3d.measurement.paint(
+                &painter,
+                &eye.ui_from_world(ui_rect),
+                ui.ctx().selection_stroke().color,
+            );
+
+            let mut overlay_ui = ui.new_child(
+                egui::UiBuilder::new()
+                    .max_rect(ui_rect.shrink(8.0))
+                    .layout(egui::Layout::top_down(egui::Align::Min)),
+            );
+            state.state_3d.measurement.overlay_ui(&mut overlay_ui);
+        }
+
+        state.box_selection.paint(&painter);
+
         Ok(())
     }
 
@@ -767,6 +1018,56 @@ impl SpatialView3D {
     }
 }
 
+/// Renders the view again at the resolution of the screenshot, and reads it back.
+///
+/// Only the scene rendered by `re_renderer` ends up in the screenshot,
+/// labels and other overlays painted by egui are not included.
+fn render_high_res_screenshot(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    ui_rect: egui::Rect,
+    target_config: &TargetConfiguration,
+    draws: &[re_renderer::QueueableDrawData],
+    clear_color: re_renderer::Rgba,
+    request: HighResScreenshotRequest,
+) {
+    let render_ctx = ctx.render_ctx();
+    let resolution_in_pixel =
+        request.render_resolution(render_ctx.device.limits().max_texture_dimension_2d);
+
+    // Lines and points sized in ui points keep their size relative to the image.
+    let scale = resolution_in_pixel[1] as f32 / target_config.resolution_in_pixel[1] as f32;
+    let mut config = target_config.clone();
+    config.name = format!("{} (screenshot)", target_config.name).into();
+    config.resolution_in_pixel = resolution_in_pixel;
+    config.pixels_per_point *= scale;
+    if let Projection::Perspective { aspect_ratio, .. } = &mut config.projection_from_view {
+        *aspect_ratio = resolution_in_pixel[0] as f32 / resolution_in_pixel[1] as f32;
+    }
+    // Selection outlines are not part of the scene.
+    config.outline_config = None;
+
+    let mut view_builder = ViewBuilder::new(render_ctx, config);
+    for draw_data in draws {
+        view_builder.queue_draw(draw_data.clone());
+    }
+    if let Err(err) = view_builder.schedule_screenshot(
+        render_ctx,
+        HighResScreenshotRequest::READBACK_IDENTIFIER,
+        request,
+    ) {
+        re_log::error!("Failed to schedule screenshot: {err}");
+        return;
+    }
+
+    ui.painter()
+        .add(gpu_bridge::new_offscreen_renderer_callback(
+            view_builder,
+            ui_rect,
+            clear_color,
+        ));
+}
+
 /// Show center of orbit camera when interacting with camera (it's quite helpful).
 fn show_orbit_eye_center(
     egui_ctx: &egui::Context,
@@ -9,15 +9,17 @@ use re_types::{
 use re_ui::{Help, UiExt as _};
 use re_view::view_property_ui;
 use re_viewer_context::{
-    RecommendedView, ViewClass, ViewClassExt as _, ViewClassRegistryError, ViewId, ViewQuery,
-    ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError, ViewerContext,
-    VisualizableFilterContext,
+    DataResult, RecommendedView, ViewClass, ViewClassExt as _, ViewClassRegistryError, ViewId,
+    ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, VisualizableFilterContext,
 };
 
 use crate::{
     contexts::register_spatial_contexts,
+    grid_overlay_2d::grid_overlay_ui,
     heuristics::default_visualized_entities_for_visualizer_kind,
     max_image_dimension_subscriber::{ImageTypes, MaxDimensions},
+    point_sizing::{entity_point_sizing_ui, view_point_sizing_ui},
     spatial_topology::{SpatialTopology, SubSpaceConnectionFlags},
     ui::SpatialViewState,
     view_kind::SpatialViewKind,
@@ -251,6 +253,20 @@ impl ViewClass for SpatialView2D {
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             state.bounding_box_ui(ui, SpatialViewKind::TwoD);
+
+            ui.grid_left_hand_label("Pixel probe").on_hover_text(
+                "Drag over images to show statistics and histograms of the pixels in a region",
+            );
+            let mut active = state.pixel_probe.is_active();
+            ui.re_checkbox(&mut active, "Select region");
+            state.pixel_probe.set_active(active);
+            ui.end_row();
+
+            state.box_selection.selection_ui(ui);
+
+            grid_overlay_ui(ctx, ui, view_id);
+
+            view_point_sizing_ui(ctx, ui, view_id);
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view2d_selection_ui", |ui| {
@@ -263,6 +279,18 @@ impl ViewClass for SpatialView2D {
         Ok(())
     }
 
+    fn data_result_selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _state: &mut dyn ViewState,
+        view_id: ViewId,
+        data_result: &DataResult,
+    ) -> Result<(), ViewSystemExecutionError> {
+        entity_point_sizing_ui(ctx, ui, view_id, data_result);
+        Ok(())
+    }
+
     fn ui(
         &self,
         ctx: &ViewerContext<'_>,
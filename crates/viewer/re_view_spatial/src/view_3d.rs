@@ -10,19 +10,28 @@ use re_types::{Component as _, View as _, ViewClassIdentifier, blueprint::archet
 use re_ui::{Help, UiExt as _, list_item};
 use re_view::view_property_ui;
 use re_viewer_context::{
-    IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer,
-    RecommendedView, SmallVisualizerSet, ViewClass, ViewClassExt as _, ViewClassRegistryError,
-    ViewContext, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _,
-    ViewSystemExecutionError, ViewSystemIdentifier, ViewerContext, VisualizableEntities,
-    VisualizableFilterContext,
+    DataResult, IdentifiedViewSystem as _, IndicatedEntities, MaybeVisualizableEntities,
+    PerVisualizer, RecommendedView, SmallVisualizerSet, ViewClass, ViewClassExt as _,
+    ViewClassRegistryError, ViewContext, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState,
+    ViewStateExt as _, ViewSystemExecutionError, ViewSystemIdentifier, ViewerContext,
+    VisualizableEntities, VisualizableFilterContext,
 };
 use re_viewport_blueprint::ViewProperty;
 
 use crate::transform_cache::query_view_coordinates;
 use crate::visualizers::{AxisLengthDetector, CamerasVisualizer, Transform3DArrowsVisualizer};
 use crate::{
+    camera_controller::{
+        camera_controllers, pick_camera_controller, picked_camera_controller_identifier,
+        register_builtin_camera_controllers,
+    },
+    camera_path::camera_path_ui,
     contexts::register_spatial_contexts,
     heuristics::default_visualized_entities_for_visualizer_kind,
+    lighting::lighting_ui,
+    measurement::MeasurementMode,
+    point_sizing::{entity_point_sizing_ui, view_point_sizing_ui},
+    section_plane::section_plane_ui,
     spatial_topology::{HeuristicHints, SpatialTopology, SubSpaceConnectionFlags},
     ui::{SpatialViewState, format_vector},
     view_kind::SpatialViewKind,
@@ -78,6 +87,7 @@ impl ViewClass for SpatialView3D {
 
         register_spatial_contexts(system_registry)?;
         register_3d_spatial_visualizers(system_registry)?;
+        register_builtin_camera_controllers(Self::identifier());
 
         Ok(())
     }
@@ -90,6 +100,10 @@ impl ViewClass for SpatialView3D {
         true
     }
 
+    fn supports_high_res_screenshot(&self) -> bool {
+        true
+    }
+
     fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
         re_viewer_context::ViewClassLayoutPriority::High
     }
@@ -420,6 +434,84 @@ impl ViewClass for SpatialView3D {
             ui.end_row();
 
             state.bounding_box_ui(ui, SpatialViewKind::ThreeD);
+
+            ui.grid_left_hand_label("Measure")
+                .on_hover_text("Click points in the view to measure distances and angles");
+            let mut mode = state.state_3d.measurement.mode();
+            egui::ComboBox::from_id_salt("measurement_mode")
+                .selected_text(mode.label())
+                .show_ui(ui, |ui| {
+                    for option in MeasurementMode::ALL {
+                        ui.selectable_value(&mut mode, option, option.label());
+                    }
+                });
+            state.state_3d.measurement.set_mode(mode);
+            ui.end_row();
+
+            state.box_selection.selection_ui(ui);
+
+            view_point_sizing_ui(ctx, ui, view_id);
+
+            ui.grid_left_hand_label("Controller").on_hover_text(
+                "How the camera reacts to mouse and keyboard. Follows the eye controls if not set",
+            );
+            let picked = picked_camera_controller_identifier(ctx, view_id);
+            let controllers = camera_controllers(Self::identifier());
+            let selected_text = picked.as_deref().map_or("Eye controls", |identifier| {
+                controllers
+                    .iter()
+                    .find(|c| c.identifier() == identifier)
+                    .map_or(identifier, |c| c.display_name())
+            });
+            let mut new_pick = picked.clone();
+            egui::ComboBox::from_id_salt("camera_controller")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut new_pick, None, "Eye controls");
+                    for controller in &controllers {
+                        ui.selectable_value(
+                            &mut new_pick,
+                            Some(controller.identifier().to_owned()),
+                            controller.display_name(),
+                        );
+                    }
+                });
+            if new_pick != picked {
+                pick_camera_controller(ctx, view_id, new_pick.as_deref());
+            }
+            ui.end_row();
+
+            ui.grid_left_hand_label("Section plane").on_hover_text(
+                "Cut away part of the scene to look inside of meshes and point clouds",
+            );
+            ui.vertical(|ui| {
+                section_plane_ui(
+                    ctx,
+                    ui,
+                    view_id,
+                    &state.bounding_boxes.current,
+                    state.state_3d.view_eye.map(|view_eye| view_eye.to_eye()),
+                );
+            });
+            ui.end_row();
+
+            ui.grid_left_hand_label("Lighting")
+                .on_hover_text("How meshes are shaded, e.g. to brighten dark scans");
+            ui.vertical(|ui| {
+                lighting_ui(
+                    ctx,
+                    ui,
+                    view_id,
+                    state.state_3d.view_eye.map(|view_eye| view_eye.to_eye()),
+                );
+            });
+            ui.end_row();
+
+            ui.grid_left_hand_label("Camera path").on_hover_text(
+                "Keyframes of the camera, to fly through the scene the same way every time",
+            );
+            ui.vertical(|ui| camera_path_ui(ctx, ui, view_id, &mut state.state_3d));
+            ui.end_row();
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view3d_selection_ui", |ui| {
@@ -432,6 +524,18 @@ impl ViewClass for SpatialView3D {
         Ok(())
     }
 
+    fn data_result_selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        _state: &mut dyn ViewState,
+        view_id: ViewId,
+        data_result: &DataResult,
+    ) -> Result<(), ViewSystemExecutionError> {
+        entity_point_sizing_ui(ctx, ui, view_id, data_result);
+        Ok(())
+    }
+
     fn ui(
         &self,
         ctx: &ViewerContext<'_>,
@@ -191,6 +191,10 @@ impl VisualizerSystem for Asset3DVisualizer {
         }
     }
 
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
     fn data(&self) -> Option<&dyn std::any::Any> {
         Some(self.0.as_any())
     }
@@ -212,6 +212,10 @@ impl VisualizerSystem for Boxes3DVisualizer {
         builder.into_draw_data()
     }
 
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
     fn data(&self) -> Option<&dyn std::any::Any> {
         Some(self.0.as_any())
     }
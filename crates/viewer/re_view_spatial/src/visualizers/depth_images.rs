@@ -25,7 +25,7 @@ use crate::{
     visualizers::filter_visualizable_2d_entities,
 };
 
-use super::{SpatialViewVisualizerData, textured_rect_from_image};
+use super::{SpatialViewVisualizerData, query_custom_colormap, textured_rect_from_image};
 
 pub struct DepthImageVisualizer {
     pub data: SpatialViewVisualizerData,
@@ -89,6 +89,7 @@ impl DepthImageVisualizer {
             let colormap_with_range = ColormapWithRange {
                 colormap,
                 value_range,
+                custom_colormap: query_custom_colormap(ctx, &DepthImage::descriptor_colormap()),
             };
 
             // First try to create a textured rect for this image.
@@ -216,6 +217,10 @@ impl DepthImageVisualizer {
                 re_renderer::renderer::ColorMapper::Function(colormap) => colormap,
                 _ => re_renderer::Colormap::Grayscale,
             },
+            colormap_texture: match &depth_texture.color_mapper {
+                re_renderer::renderer::ColorMapper::Texture(texture) => Some(texture.clone()),
+                _ => None,
+            },
             outline_mask_id: ent_context.highlight.overall,
             picking_object_id: re_renderer::PickingLayerObjectId(ent_path.hash64()),
         })
@@ -0,0 +1,274 @@
+use re_types::{
+    Archetype as _,
+    archetypes::Heatmap,
+    components::{Colormap, DrawOrder, ImageBuffer, ImageFormat, Opacity, ValueRange},
+    image::ImageKind,
+};
+use re_viewer_context::{
+    ColormapWithRange, IdentifiedViewSystem, ImageInfo, ImageStatsCache, MaybeVisualizableEntities,
+    QueryContext, TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+
+use crate::{
+    PickableRectSourceData, PickableTexturedRect,
+    ui::SpatialViewState,
+    view_kind::SpatialViewKind,
+    visualizers::{filter_visualizable_2d_or_3d_entities, textured_rect_from_image},
+};
+
+use super::{SpatialViewVisualizerData, query_custom_colormap};
+
+pub struct HeatmapVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for HeatmapVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::TwoD)),
+        }
+    }
+}
+
+struct HeatmapComponentData {
+    image: ImageInfo,
+    colormap: Option<Colormap>,
+    value_range: Option<[f64; 2]>,
+    opacity: Option<Opacity>,
+}
+
+impl IdentifiedViewSystem for HeatmapVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Heatmap".into()
+    }
+}
+
+impl VisualizerSystem for HeatmapVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Heatmap>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_or_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        use super::entity_iterator::{iter_component, iter_slices, process_archetype};
+        process_archetype::<Self, Heatmap, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let entity_path = ctx.target_entity_path;
+
+                let Some(all_buffer_chunks) =
+                    results.get_required_chunks(Heatmap::descriptor_buffer())
+                else {
+                    return Ok(());
+                };
+                let Some(all_format_chunks) =
+                    results.get_required_chunks(Heatmap::descriptor_format())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_buffers_indexed = iter_slices::<&[u8]>(&all_buffer_chunks, timeline);
+                let all_formats_indexed =
+                    iter_component::<ImageFormat>(&all_format_chunks, timeline);
+                let all_colormaps = results.iter_as(timeline, Heatmap::descriptor_colormap());
+                let all_value_ranges = results.iter_as(timeline, Heatmap::descriptor_value_range());
+                let all_opacities = results.iter_as(timeline, Heatmap::descriptor_opacity());
+
+                let data = re_query::range_zip_1x4(
+                    all_buffers_indexed,
+                    all_formats_indexed,
+                    all_colormaps.slice::<u8>(),
+                    all_value_ranges.slice::<[f64; 2]>(),
+                    all_opacities.slice::<f32>(),
+                )
+                .filter_map(
+                    |((_time, row_id), buffers, formats, colormap, value_range, opacity)| {
+                        let buffer = buffers.first()?;
+                        Some(HeatmapComponentData {
+                            image: ImageInfo::from_stored_blob(
+                                row_id,
+                                &Heatmap::descriptor_buffer(),
+                                buffer.clone().into(),
+                                first_copied(formats.as_deref())?.0,
+                                ImageKind::Heatmap,
+                            ),
+                            colormap: first_copied(colormap).and_then(Colormap::from_u8),
+                            value_range: first_copied(value_range),
+                            opacity: first_copied(opacity).map(Into::into),
+                        })
+                    },
+                );
+
+                for data in data {
+                    let HeatmapComponentData {
+                        image,
+                        colormap,
+                        value_range,
+                        opacity,
+                    } = data;
+
+                    let colormap = colormap.unwrap_or_else(|| self.fallback_for(ctx));
+                    let value_range = value_range
+                        .map(|r| [r[0] as f32, r[1] as f32])
+                        .unwrap_or_else(|| {
+                            // Don't use fallback provider since it has to query information we already have.
+                            let image_stats = ctx
+                                .store_ctx()
+                                .caches
+                                .entry(|c: &mut ImageStatsCache| c.entry(&image));
+                            ColormapWithRange::default_range_for_heatmaps(&image_stats)
+                        });
+                    let colormap_with_range = ColormapWithRange {
+                        colormap,
+                        value_range,
+                        custom_colormap: query_custom_colormap(
+                            ctx,
+                            &Heatmap::descriptor_colormap(),
+                        ),
+                    };
+
+                    let opacity = opacity.unwrap_or_else(|| self.fallback_for(ctx));
+                    #[expect(clippy::disallowed_methods)] // This is not a hard-coded color.
+                    let multiplicative_tint =
+                        re_renderer::Rgba::from_white_alpha(opacity.0.clamp(0.0, 1.0));
+
+                    if let Some(textured_rect) = textured_rect_from_image(
+                        ctx.viewer_ctx(),
+                        entity_path,
+                        spatial_ctx,
+                        &image,
+                        Some(&colormap_with_range),
+                        multiplicative_tint,
+                        Heatmap::name(),
+                    ) {
+                        self.data.add_pickable_rect(
+                            PickableTexturedRect {
+                                ent_path: entity_path.clone(),
+                                textured_rect,
+                                source_data: PickableRectSourceData::Image {
+                                    image,
+                                    depth_meter: None,
+                                },
+                            },
+                            spatial_ctx.view_class_identifier,
+                        );
+                    }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        // TODO(#702): draw order is translated to depth offset, which works fine for opaque images,
+        // but for everything with transparency, actual drawing order is still important.
+        // We mitigate this a bit by at least sorting the heatmaps within each other.
+        self.data.pickable_rects.sort_by_key(|image| {
+            (
+                image.textured_rect.options.depth_offset,
+                egui::emath::OrderedFloat(image.textured_rect.options.multiplicative_tint.a()),
+            )
+        });
+
+        Ok(vec![PickableTexturedRect::to_draw_data(
+            ctx.viewer_ctx.render_ctx(),
+            &self.data.pickable_rects,
+        )?])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for HeatmapVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        ColormapWithRange::DEFAULT_HEATMAP_COLORMAP
+    }
+}
+
+impl TypedComponentFallbackProvider<ValueRange> for HeatmapVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> ValueRange {
+        if let Some(((_time, buffer_row_id), image_buffer)) =
+            ctx.recording().latest_at_component::<ImageBuffer>(
+                ctx.target_entity_path,
+                ctx.query,
+                &Heatmap::descriptor_buffer(),
+            )
+        {
+            // TODO(andreas): What about overrides on the image format?
+            if let Some((_, format)) = ctx.recording().latest_at_component::<ImageFormat>(
+                ctx.target_entity_path,
+                ctx.query,
+                &Heatmap::descriptor_format(),
+            ) {
+                let image = ImageInfo::from_stored_blob(
+                    buffer_row_id,
+                    &Heatmap::descriptor_buffer(),
+                    image_buffer.0,
+                    format.0,
+                    ImageKind::Heatmap,
+                );
+                let cache = ctx.store_ctx().caches;
+                let image_stats = cache.entry(|c: &mut ImageStatsCache| c.entry(&image));
+                let default_range = ColormapWithRange::default_range_for_heatmaps(&image_stats);
+                return [default_range[0] as f64, default_range[1] as f64].into();
+            }
+        }
+
+        [0.0, 1.0].into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Opacity> for HeatmapVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Opacity {
+        // Heatmaps are typically overlaid on images, so they should be transparent whenever
+        // there are other images in the view.
+        let Some(view_state) = ctx.view_state().as_any().downcast_ref::<SpatialViewState>() else {
+            return 1.0.into();
+        };
+
+        view_state
+            .fallback_opacity_for_image_kind(ImageKind::Heatmap)
+            .into()
+    }
+}
+
+impl TypedComponentFallbackProvider<DrawOrder> for HeatmapVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> DrawOrder {
+        DrawOrder::DEFAULT_HEATMAP
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HeatmapVisualizer => [Colormap, ValueRange, Opacity, DrawOrder]);
+
+fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
+    slice.and_then(|element| element.first()).copied()
+}
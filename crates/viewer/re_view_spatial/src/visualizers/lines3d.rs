@@ -260,6 +260,10 @@ impl VisualizerSystem for Lines3DVisualizer {
         Ok(vec![(line_builder.into_draw_data()?.into())])
     }
 
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
     fn data(&self) -> Option<&dyn std::any::Any> {
         Some(self.data.as_any())
     }
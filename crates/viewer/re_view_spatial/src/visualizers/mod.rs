@@ -11,6 +11,7 @@ mod cylinders3d;
 mod depth_images;
 mod ellipsoids;
 mod encoded_image;
+mod heatmaps;
 mod images;
 mod lines2d;
 mod lines3d;
@@ -18,6 +19,7 @@ mod meshes;
 mod points2d;
 mod points3d;
 mod segmentation_images;
+mod text3d;
 mod transform3d_arrows;
 mod utilities;
 mod video;
@@ -27,8 +29,8 @@ pub use depth_images::DepthImageVisualizer;
 use re_types::{ComponentDescriptor, archetypes};
 pub use transform3d_arrows::{AxisLengthDetector, Transform3DArrowsVisualizer, add_axis_arrows};
 pub use utilities::{
-    SpatialViewVisualizerData, UiLabel, UiLabelStyle, UiLabelTarget, entity_iterator,
-    process_labels_3d, textured_rect_from_image,
+    SpatialViewVisualizerData, UiLabel, UiLabelSize, UiLabelStyle, UiLabelTarget, entity_iterator,
+    process_labels_3d, query_custom_colormap, textured_rect_from_image,
 };
 
 /// Shows a loading animation in a spatial view.
@@ -78,6 +80,7 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
     system_registry.register_visualizer::<ellipsoids::Ellipsoids3DVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
+    system_registry.register_visualizer::<heatmaps::HeatmapVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
@@ -85,6 +88,7 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
+    system_registry.register_visualizer::<text3d::Text3DVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<video::VideoFrameReferenceVisualizer>()?;
@@ -106,6 +110,7 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
     system_registry.register_visualizer::<ellipsoids::Ellipsoids3DVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
+    system_registry.register_visualizer::<heatmaps::HeatmapVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
@@ -113,6 +118,7 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
+    system_registry.register_visualizer::<text3d::Text3DVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<video::VideoFrameReferenceVisualizer>()?;
@@ -141,6 +147,10 @@ pub fn visualizers_processing_draw_order()
             encoded_image::EncodedImageVisualizer::identifier(),
             archetypes::EncodedImage::descriptor_draw_order(),
         ),
+        (
+            heatmaps::HeatmapVisualizer::identifier(),
+            archetypes::Heatmap::descriptor_draw_order(),
+        ),
         (
             images::ImageVisualizer::identifier(),
             archetypes::Image::descriptor_draw_order(),
@@ -169,6 +179,14 @@ pub fn visualizers_processing_draw_order()
     .into_iter()
 }
 
+/// Whether any of the visualizers draws points, which are sized following [`crate::point_sizing::PointSizing`].
+pub fn draws_points(visualizers: &[ViewSystemIdentifier]) -> bool {
+    visualizers.iter().any(|visualizer| {
+        *visualizer == points2d::Points2DVisualizer::identifier()
+            || *visualizer == points3d::Points3DVisualizer::identifier()
+    })
+}
+
 pub fn collect_ui_labels(visualizers: &VisualizerCollection) -> Vec<UiLabel> {
     visualizers
         .iter_visualizer_data::<SpatialViewVisualizerData>()
@@ -364,3 +382,28 @@ fn filter_visualizable_3d_entities(
         VisualizableEntities(entities.0)
     }
 }
+
+/// Like [`filter_visualizable_2d_entities`], but in 3D views entities are also visualizable
+/// if they are part of the main 3D space rather than under a pinhole.
+fn filter_visualizable_2d_or_3d_entities(
+    entities: MaybeVisualizableEntities,
+    context: &dyn VisualizableFilterContext,
+) -> VisualizableEntities {
+    if let Some(context) = context
+        .as_any()
+        .downcast_ref::<VisualizableFilterContext3D>()
+    {
+        VisualizableEntities(
+            entities
+                .0
+                .into_iter()
+                .filter(|entity| {
+                    context.entities_in_main_3d_space.contains(entity)
+                        || context.entities_under_pinholes.contains(entity)
+                })
+                .collect(),
+        )
+    } else {
+        filter_visualizable_2d_entities(entities, context)
+    }
+}
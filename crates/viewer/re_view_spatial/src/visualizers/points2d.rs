@@ -76,8 +76,9 @@ impl Points2DVisualizer {
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
-            let radii =
+            let mut radii =
                 process_radius_slice(entity_path, num_instances, data.radii, Radius::default());
+            ent_context.point_sizing.apply_to_radii(&mut radii);
             let colors =
                 process_color_slice(ctx, self, num_instances, &annotation_infos, data.colors);
 
@@ -87,6 +88,7 @@ impl Points2DVisualizer {
             {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
+                    .radius_range_in_ui_points(ent_context.point_sizing.radius_range_in_ui_points())
                     .depth_offset(ent_context.depth_offset)
                     .flags(
                         re_renderer::renderer::PointCloudBatchFlags::FLAG_DRAW_AS_CIRCLES
@@ -294,6 +296,10 @@ impl VisualizerSystem for Points2DVisualizer {
         ])
     }
 
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
     fn data(&self) -> Option<&dyn std::any::Any> {
         Some(self.data.as_any())
     }
@@ -90,8 +90,9 @@ impl Points3DVisualizer {
 
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
-            let radii =
+            let mut radii =
                 process_radius_slice(entity_path, num_instances, data.radii, Radius::default());
+            ent_context.point_sizing.apply_to_radii(&mut radii);
             let colors =
                 process_color_slice(ctx, self, num_instances, &annotation_infos, data.colors);
 
@@ -102,6 +103,7 @@ impl Points3DVisualizer {
             {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
+                    .radius_range_in_ui_points(ent_context.point_sizing.radius_range_in_ui_points())
                     .world_from_obj(world_from_obj)
                     .outline_mask_ids(ent_context.highlight.overall)
                     .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
@@ -285,6 +287,10 @@ impl VisualizerSystem for Points3DVisualizer {
         ])
     }
 
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
     fn data(&self) -> Option<&dyn std::any::Any> {
         Some(self.data.as_any())
     }
@@ -0,0 +1,223 @@
+use itertools::izip;
+
+use re_entity_db::InstancePathHash;
+use re_log_types::Instance;
+use re_types::{
+    Archetype as _, ArrowString,
+    archetypes::Text3D,
+    components::{Color, FontSize, Position3D, TextAnchor},
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{contexts::SpatialSceneEntityContext, view_kind::SpatialViewKind};
+
+use super::{
+    SpatialViewVisualizerData, UiLabel, UiLabelSize, UiLabelStyle, UiLabelTarget,
+    entity_iterator::clamped_or, filter_visualizable_3d_entities,
+};
+
+// ---
+
+/// Shows [`Text3D`] labels, which are drawn as part of the ui labels of the view.
+pub struct Text3DVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for Text3DVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::ThreeD)),
+        }
+    }
+}
+
+struct Text3DComponentData<'a> {
+    // Point of views
+    positions: &'a [Position3D],
+
+    // Clamped to edge
+    texts: Vec<ArrowString>,
+    font_sizes: &'a [FontSize],
+    colors: &'a [Color],
+    anchors: &'a [u8],
+}
+
+// NOTE: Do not put profile scopes in these methods. They are called for all entities and all
+// timestamps within a time range -- it's _a lot_.
+impl Text3DVisualizer {
+    fn process_data<'a>(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        data: impl Iterator<Item = Text3DComponentData<'a>>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        for data in data {
+            let num_instances = data.positions.len();
+            if num_instances == 0 || data.texts.is_empty() {
+                continue;
+            }
+
+            let world_from_obj = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, Text3D::name());
+
+            let positions: &[glam::Vec3] = bytemuck::cast_slice(data.positions);
+            self.data.add_bounding_box_from_points(
+                entity_path.hash(),
+                positions.iter().copied(),
+                world_from_obj,
+            );
+
+            let font_sizes = data.font_sizes.iter().map(|size| {
+                if let Some(size) = size.scene_units() {
+                    UiLabelSize::SceneUnits(size)
+                } else if let Some(size) = size.ui_points() {
+                    UiLabelSize::UiPoints(size)
+                } else {
+                    UiLabelSize::Default
+                }
+            });
+            let styles = data
+                .colors
+                .iter()
+                .map(|color| UiLabelStyle::Color(egui::Color32::from(*color)));
+            let anchors = data.anchors.iter().map(|anchor| {
+                TextAnchor::from_u8(*anchor).map_or(egui::Align2::CENTER_CENTER, align_from_anchor)
+            });
+
+            let instances = izip!(
+                positions,
+                clamped_or(&data.texts, &ArrowString::default()),
+                clamped_or(&font_sizes.collect::<Vec<_>>(), &UiLabelSize::Default),
+                clamped_or(&styles.collect::<Vec<_>>(), &UiLabelStyle::Default),
+                clamped_or(&anchors.collect::<Vec<_>>(), &egui::Align2::CENTER_CENTER),
+            );
+
+            self.data.ui_labels.extend(instances.enumerate().map(
+                |(i, (position, text, size, style, anchor))| UiLabel {
+                    text: text.to_string(),
+                    style: style.clone(),
+                    target: UiLabelTarget::Position3D(world_from_obj.transform_point3(*position)),
+                    size: *size,
+                    anchor: Some(*anchor),
+                    labeled_instance: InstancePathHash::instance(
+                        entity_path,
+                        Instance::from(i as u64),
+                    ),
+                },
+            ));
+        }
+    }
+}
+
+fn align_from_anchor(anchor: TextAnchor) -> egui::Align2 {
+    match anchor {
+        TextAnchor::Center => egui::Align2::CENTER_CENTER,
+        TextAnchor::Top => egui::Align2::CENTER_TOP,
+        TextAnchor::Bottom => egui::Align2::CENTER_BOTTOM,
+        TextAnchor::Left => egui::Align2::LEFT_CENTER,
+        TextAnchor::Right => egui::Align2::RIGHT_CENTER,
+        TextAnchor::TopLeft => egui::Align2::LEFT_TOP,
+        TextAnchor::TopRight => egui::Align2::RIGHT_TOP,
+        TextAnchor::BottomLeft => egui::Align2::LEFT_BOTTOM,
+        TextAnchor::BottomRight => egui::Align2::RIGHT_BOTTOM,
+    }
+}
+
+impl IdentifiedViewSystem for Text3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Text3D".into()
+    }
+}
+
+impl VisualizerSystem for Text3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Text3D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, Text3D, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_position_chunks) =
+                    results.get_required_chunks(Text3D::descriptor_positions())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_positions_indexed = iter_slices::<[f32; 3]>(&all_position_chunks, timeline);
+                let all_texts = results.iter_as(timeline, Text3D::descriptor_texts());
+                let all_font_sizes = results.iter_as(timeline, Text3D::descriptor_font_sizes());
+                let all_colors = results.iter_as(timeline, Text3D::descriptor_colors());
+                let all_anchors = results.iter_as(timeline, Text3D::descriptor_anchors());
+
+                let data = re_query::range_zip_1x4(
+                    all_positions_indexed,
+                    all_texts.slice::<String>(),
+                    all_font_sizes.slice::<f32>(),
+                    all_colors.slice::<u32>(),
+                    all_anchors.slice::<u8>(),
+                )
+                .map(|(_index, positions, texts, font_sizes, colors, anchors)| {
+                    Text3DComponentData {
+                        positions: bytemuck::cast_slice(positions),
+                        texts: texts.unwrap_or_default(),
+                        font_sizes: font_sizes.map_or(&[], |sizes| bytemuck::cast_slice(sizes)),
+                        colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
+                        anchors: anchors.unwrap_or_default(),
+                    }
+                });
+
+                self.process_data(ctx, spatial_ctx, data);
+
+                Ok(())
+            },
+        )?;
+
+        Ok(Vec::new())
+    }
+
+    fn is_output_reusable(&self) -> bool {
+        true
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(Text3DVisualizer => []);
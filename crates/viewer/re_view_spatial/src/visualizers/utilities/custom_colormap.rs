@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use re_types::{ComponentDescriptor, components::Name};
+use re_view::DataResultQuery as _;
+use re_viewer_context::{
+    QueryContext,
+    gpu_bridge::{CustomColormap, custom_colormap, custom_colormap_descriptor},
+};
+
+/// The registered colormap picked for the entity instead of the [`re_types::components::Colormap`]
+/// of the given descriptor, if any.
+///
+/// See [`custom_colormap_descriptor`].
+pub fn query_custom_colormap(
+    ctx: &QueryContext<'_>,
+    colormap_descr: &ComponentDescriptor,
+) -> Option<Arc<CustomColormap>> {
+    let descr = custom_colormap_descriptor(colormap_descr);
+    let data_result = ctx
+        .view_ctx
+        .query_result
+        .tree
+        .lookup_result_by_path(ctx.target_entity_path)?;
+    let name = data_result
+        .latest_at_with_blueprint_resolved_data_for_component(ctx.view_ctx, ctx.query, &descr)
+        .get_mono::<Name>(&descr)?;
+    custom_colormap(name.as_str())
+}
@@ -6,7 +6,10 @@ use re_viewer_context::{
     ViewSystemExecutionError,
 };
 
-use crate::contexts::{EntityDepthOffsets, SpatialSceneEntityContext, TransformTreeContext};
+use crate::{
+    contexts::{EntityDepthOffsets, SpatialSceneEntityContext, TransformTreeContext},
+    point_sizing::PointSizingSettings,
+};
 
 // ---
 
@@ -107,6 +110,7 @@ where
     let latest_at = query.latest_at_query();
 
     let system_identifier = System::identifier();
+    let view_point_sizing = PointSizingSettings::load_for_view(ctx.viewer_ctx, query.view_id);
 
     for data_result in query.iter_visible_data_results(system_identifier) {
         let Some(transform_info) =
@@ -128,6 +132,7 @@ where
                 .highlights
                 .entity_outline_mask(data_result.entity_path.hash()),
             view_class_identifier: context_systems.view_class_identifier(),
+            point_sizing: view_point_sizing.resolve_for(ctx.viewer_ctx, data_result),
         };
 
         let results = data_result.query_archetype_with_history::<A>(ctx, query);
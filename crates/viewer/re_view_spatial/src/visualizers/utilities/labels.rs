@@ -44,6 +44,20 @@ impl From<egui::Color32> for UiLabelStyle {
     }
 }
 
+/// How big the text of a label is.
+#[derive(Clone, Copy, Default)]
+pub enum UiLabelSize {
+    /// The size of regular body text.
+    #[default]
+    Default,
+
+    /// A fixed size in ui points.
+    UiPoints(f32),
+
+    /// A size in scene units, i.e. the label gets smaller the further away it is from the camera.
+    SceneUnits(f32),
+}
+
 #[derive(Clone)]
 pub struct UiLabel {
     pub text: String,
@@ -53,6 +67,13 @@ pub struct UiLabel {
     /// The shape/position being labeled.
     pub target: UiLabelTarget,
 
+    pub size: UiLabelSize,
+
+    /// Which point of the label is placed at the target.
+    ///
+    /// If `None`, the label is placed centered below the target.
+    pub anchor: Option<egui::Align2>,
+
     /// What is hovered if this label is hovered.
     pub labeled_instance: InstancePathHash,
 }
@@ -215,6 +236,8 @@ pub fn process_labels<'a, P: 'a>(
                         UiLabelStyle::Color(*color)
                     },
                     target: target_from_position(position),
+                    size: UiLabelSize::Default,
+                    anchor: None,
                     labeled_instance: InstancePathHash::instance(
                         entity_path,
                         Instance::from(i as u64),
@@ -1,12 +1,14 @@
+mod custom_colormap;
 pub mod entity_iterator;
 mod labels;
 mod proc_mesh_vis;
 mod spatial_view_visualizer;
 mod textured_rect;
 
+pub use custom_colormap::query_custom_colormap;
 pub use labels::{
-    LabeledBatch, UiLabel, UiLabelStyle, UiLabelTarget, process_labels, process_labels_2d,
-    process_labels_3d, show_labels_fallback,
+    LabeledBatch, UiLabel, UiLabelSize, UiLabelStyle, UiLabelTarget, process_labels,
+    process_labels_2d, process_labels_3d, show_labels_fallback,
 };
 pub use proc_mesh_vis::{ProcMeshBatch, ProcMeshDrawableBuilder};
 pub use spatial_view_visualizer::SpatialViewVisualizerData;
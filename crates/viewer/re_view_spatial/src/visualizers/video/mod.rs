@@ -11,7 +11,9 @@ use re_viewer_context::{ViewClass as _, ViewContext, ViewId, ViewSystemIdentifie
 
 use crate::{PickableRectSourceData, PickableTexturedRect, SpatialView2D};
 
-use super::{LoadingSpinner, SpatialViewVisualizerData, UiLabel, UiLabelStyle, UiLabelTarget};
+use super::{
+    LoadingSpinner, SpatialViewVisualizerData, UiLabel, UiLabelSize, UiLabelStyle, UiLabelTarget,
+};
 
 /// Identify a video stream for a given video.
 fn video_stream_id(
@@ -223,6 +225,8 @@ fn show_video_playback_issue(
         text: error_string,
         style,
         target: UiLabelTarget::Rect(label_target_rect),
+        size: UiLabelSize::Default,
+        anchor: None,
         labeled_instance: re_entity_db::InstancePathHash::entity_all(entity_path),
     });
 
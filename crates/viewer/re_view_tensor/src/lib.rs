@@ -3,6 +3,7 @@
 //! A view dedicated to visualizing tensors with arbitrary dimensionality.
 
 mod dimension_mapping;
+mod slice_values;
 mod tensor_dimension_mapper;
 mod tensor_slice_to_gpu;
 mod view_class;
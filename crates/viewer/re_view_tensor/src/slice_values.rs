@@ -0,0 +1,288 @@
+//! The values of the shown 2D slice of a tensor, to fit the colormap to them and to export them.
+
+use ndarray::{Array2, ArrayViewD, Ix2};
+
+use re_types::{datatypes::TensorData, tensor_data::TensorDataType};
+use re_viewer_context::{ColormapWithRange, gpu_bridge::colormap_to_re_renderer};
+
+use crate::{
+    dimension_mapping::TensorSliceSelection, tensor_slice_to_gpu::TensorUploadError,
+    view_class::selected_tensor_slice,
+};
+
+fn slice_2d<'a, T: Copy>(
+    tensor: &'a ArrayViewD<'_, T>,
+    slice_selection: &TensorSliceSelection,
+) -> Result<ndarray::ArrayView2<'a, T>, TensorUploadError> {
+    selected_tensor_slice(slice_selection, tensor)
+        .into_dimensionality::<Ix2>()
+        .map_err(|_err| TensorUploadError::Not2D)
+}
+
+/// The shown slice, converted to `f64`.
+pub fn slice_values(
+    tensor: &TensorData,
+    slice_selection: &TensorSliceSelection,
+) -> anyhow::Result<Array2<f64>> {
+    re_tracing::profile_function!();
+
+    fn to_f64<T: Copy>(
+        tensor: &ArrayViewD<'_, T>,
+        slice_selection: &TensorSliceSelection,
+        caster: impl Fn(T) -> f64,
+    ) -> anyhow::Result<Array2<f64>> {
+        Ok(slice_2d(tensor, slice_selection)?.mapv(caster))
+    }
+
+    match tensor.dtype() {
+        TensorDataType::U8 => to_f64(
+            &ArrayViewD::<u8>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::U16 => to_f64(
+            &ArrayViewD::<u16>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::U32 => to_f64(
+            &ArrayViewD::<u32>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::U64 => to_f64(
+            &ArrayViewD::<u64>::try_from(tensor)?,
+            slice_selection,
+            |x: u64| x as f64,
+        ),
+        TensorDataType::I8 => to_f64(
+            &ArrayViewD::<i8>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::I16 => to_f64(
+            &ArrayViewD::<i16>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::I32 => to_f64(
+            &ArrayViewD::<i32>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::I64 => to_f64(
+            &ArrayViewD::<i64>::try_from(tensor)?,
+            slice_selection,
+            |x: i64| x as f64,
+        ),
+        TensorDataType::F16 => to_f64(
+            &ArrayViewD::<half::f16>::try_from(tensor)?,
+            slice_selection,
+            half::f16::to_f64,
+        ),
+        TensorDataType::F32 => to_f64(
+            &ArrayViewD::<f32>::try_from(tensor)?,
+            slice_selection,
+            f64::from,
+        ),
+        TensorDataType::F64 => to_f64(
+            &ArrayViewD::<f64>::try_from(tensor)?,
+            slice_selection,
+            |x| x,
+        ),
+    }
+}
+
+/// The range of the finite values of a slice, to fit the colormap to.
+///
+/// `None` if there are no finite values.
+pub fn slice_value_range(values: &Array2<f64>) -> Option<[f64; 2]> {
+    re_tracing::profile_function!();
+
+    let (min, max) = values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+
+    if min > max {
+        None
+    } else if min == max {
+        // A uniform slice would explode the colormapping, so map it to the middle of the colormap:
+        Some([min - 1.0, max + 1.0])
+    } else {
+        Some([min, max])
+    }
+}
+
+/// The shown slice as a PNG, colormapped the same way as in the view.
+///
+/// Non-finite values are transparent.
+pub fn slice_to_png(
+    values: &Array2<f64>,
+    colormap: &ColormapWithRange,
+    gamma: f32,
+) -> anyhow::Result<Vec<u8>> {
+    re_tracing::profile_function!();
+
+    let [min, max] = colormap.value_range;
+    let renderer_colormap = colormap_to_re_renderer(colormap.colormap);
+
+    let rgba = values
+        .iter()
+        .flat_map(|&value| {
+            if !value.is_finite() {
+                return [0; 4];
+            }
+            let normalized = ((value as f32 - min) / (max - min)).clamp(0.0, 1.0);
+            match &colormap.custom_colormap {
+                Some(custom_colormap) => custom_colormap.color(normalized.powf(gamma)).to_array(),
+                None => re_renderer::colormap_srgb(renderer_colormap, normalized.powf(gamma)),
+            }
+        })
+        .collect();
+
+    let (height, width) = values.dim();
+    let Some(image) = image::RgbaImage::from_raw(width as u32, height as u32, rgba) else {
+        anyhow::bail!("Slice of {width}x{height} doesn't fit an image");
+    };
+
+    let mut png_bytes = Vec::new();
+    image.write_to(
+        &mut std::io::Cursor::new(&mut png_bytes),
+        image::ImageFormat::Png,
+    )?;
+    Ok(png_bytes)
+}
+
+/// The shown slice as a `.npy` file, keeping the data type of the tensor.
+pub fn slice_to_npy(
+    tensor: &TensorData,
+    slice_selection: &TensorSliceSelection,
+) -> anyhow::Result<Vec<u8>> {
+    re_tracing::profile_function!();
+
+    fn npy<T: bytemuck::Pod>(
+        tensor: &ArrayViewD<'_, T>,
+        slice_selection: &TensorSliceSelection,
+        descr: &str,
+    ) -> anyhow::Result<Vec<u8>> {
+        let slice = slice_2d(tensor, slice_selection)?;
+
+        // Iterating in logical order gives row-major values, no matter how the axes were permuted.
+        let values: Vec<T> = slice.iter().copied().collect();
+        Ok(encode_npy(
+            descr,
+            slice.dim(),
+            bytemuck::cast_slice(&values),
+        ))
+    }
+
+    match tensor.dtype() {
+        TensorDataType::U8 => npy(&ArrayViewD::<u8>::try_from(tensor)?, slice_selection, "|u1"),
+        TensorDataType::U16 => npy(
+            &ArrayViewD::<u16>::try_from(tensor)?,
+            slice_selection,
+            "<u2",
+        ),
+        TensorDataType::U32 => npy(
+            &ArrayViewD::<u32>::try_from(tensor)?,
+            slice_selection,
+            "<u4",
+        ),
+        TensorDataType::U64 => npy(
+            &ArrayViewD::<u64>::try_from(tensor)?,
+            slice_selection,
+            "<u8",
+        ),
+        TensorDataType::I8 => npy(&ArrayViewD::<i8>::try_from(tensor)?, slice_selection, "|i1"),
+        TensorDataType::I16 => npy(
+            &ArrayViewD::<i16>::try_from(tensor)?,
+            slice_selection,
+            "<i2",
+        ),
+        TensorDataType::I32 => npy(
+            &ArrayViewD::<i32>::try_from(tensor)?,
+            slice_selection,
+            "<i4",
+        ),
+        TensorDataType::I64 => npy(
+            &ArrayViewD::<i64>::try_from(tensor)?,
+            slice_selection,
+            "<i8",
+        ),
+        TensorDataType::F16 => npy(
+            &ArrayViewD::<half::f16>::try_from(tensor)?,
+            slice_selection,
+            "<f2",
+        ),
+        TensorDataType::F32 => npy(
+            &ArrayViewD::<f32>::try_from(tensor)?,
+            slice_selection,
+            "<f4",
+        ),
+        TensorDataType::F64 => npy(
+            &ArrayViewD::<f64>::try_from(tensor)?,
+            slice_selection,
+            "<f8",
+        ),
+    }
+}
+
+/// Encodes row-major values of a 2D array in the `.npy` format, version 1.0.
+///
+/// The values are expected to be little-endian, like on all platforms we run on.
+fn encode_npy(descr: &str, (height, width): (usize, usize), data: &[u8]) -> Vec<u8> {
+    /// Magic string, version and header length.
+    const PREAMBLE_LEN: usize = 10;
+
+    let mut header =
+        format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': ({height}, {width}), }}");
+
+    // The header is padded with spaces and a final newline, so that the data is 64-byte aligned.
+    let data_offset = (PREAMBLE_LEN + header.len() + 1).next_multiple_of(64);
+    header.extend(std::iter::repeat_n(
+        ' ',
+        data_offset - PREAMBLE_LEN - header.len() - 1,
+    ));
+    header.push('\n');
+
+    let mut npy = Vec::with_capacity(data_offset + data.len());
+    npy.extend_from_slice(b"\x93NUMPY\x01\x00");
+    npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    npy.extend_from_slice(header.as_bytes());
+    npy.extend_from_slice(data);
+    npy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn npy_header_is_aligned() {
+        let npy = encode_npy("<f4", (2, 3), &[0; 24]);
+
+        assert_eq!(&npy[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        assert_eq!(npy.len(), 10 + header_len + 24);
+
+        let header = std::str::from_utf8(&npy[10..10 + header_len]).unwrap();
+        assert!(header.starts_with("{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }"));
+        assert!(header.ends_with(" \n"));
+    }
+
+    #[test]
+    fn value_range_of_slice() {
+        let values = ndarray::array![[1.0, f64::NAN], [-2.0, 5.0]];
+        assert_eq!(slice_value_range(&values), Some([-2.0, 5.0]));
+
+        let uniform = ndarray::array![[3.0, 3.0]];
+        assert_eq!(slice_value_range(&uniform), Some([2.0, 4.0]));
+
+        let empty = ndarray::array![[f64::NAN]];
+        assert_eq!(slice_value_range(&empty), None);
+    }
+}
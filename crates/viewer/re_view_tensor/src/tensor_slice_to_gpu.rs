@@ -1,6 +1,6 @@
 use re_chunk_store::RowId;
 use re_renderer::{
-    renderer::ColormappedTexture,
+    renderer::{ColorMapper, ColormappedTexture},
     resource_managers::{GpuTexture2D, ImageDataDesc, TextureManager2DError},
 };
 use re_types::{
@@ -31,11 +31,15 @@ pub fn colormapped_texture(
     slice_selection: &TensorSliceSelection,
     colormap: &ColormapWithRange,
     gamma: GammaCorrection,
-) -> Result<ColormappedTexture, TextureManager2DError<TensorUploadError>> {
+) -> anyhow::Result<ColormappedTexture> {
     re_tracing::profile_function!();
 
     let texture =
         upload_texture_slice_to_gpu(render_ctx, tensor_data_row_id, tensor, slice_selection)?;
+    let color_mapper = match &colormap.custom_colormap {
+        Some(custom_colormap) => ColorMapper::Texture(custom_colormap.texture(render_ctx)?),
+        None => ColorMapper::Function(colormap_to_re_renderer(colormap.colormap)),
+    };
 
     Ok(ColormappedTexture {
         texture,
@@ -43,9 +47,7 @@ pub fn colormapped_texture(
         decode_srgb: false,
         multiply_rgb_with_alpha: false,
         gamma: *gamma.0,
-        color_mapper: re_renderer::renderer::ColorMapper::Function(colormap_to_re_renderer(
-            colormap.colormap,
-        )),
+        color_mapper,
         shader_decoding: None,
     })
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use egui::{Align2, NumExt as _, Vec2, epaint::TextShape};
 use ndarray::Axis;
 
@@ -7,10 +9,12 @@ use re_log_types::hash::Hash64;
 use re_types::{
     View as _, ViewClassIdentifier,
     blueprint::{
-        archetypes::{self, TensorScalarMapping, TensorViewFit},
-        components::ViewFit,
+        archetypes::{self, TensorScalarMapping, TensorSliceValueRange, TensorViewFit},
+        components::{Enabled, ViewFit},
+    },
+    components::{
+        Colormap, GammaCorrection, MagnificationFilter, Name, TensorDimensionIndexSelection,
     },
-    components::{Colormap, GammaCorrection, MagnificationFilter, TensorDimensionIndexSelection},
     datatypes::TensorData,
 };
 use re_ui::{Help, UiExt as _, list_item};
@@ -27,6 +31,7 @@ use re_viewport_blueprint::ViewProperty;
 use crate::{
     TensorDimension,
     dimension_mapping::TensorSliceSelection,
+    slice_values::{slice_to_npy, slice_to_png, slice_value_range, slice_values},
     tensor_dimension_mapper::dimension_mapping_ui,
     visualizer_system::{TensorSystem, TensorVisualization},
 };
@@ -41,6 +46,9 @@ pub struct ViewTensorState {
     /// Last viewed tensor, copied each frame.
     /// Used for the selection view.
     tensor: Option<TensorVisualization>,
+
+    /// The value range of the last shown slice, identified by the hash of its tensor and slice selection.
+    slice_range: Option<(u64, Option<[f64; 2]>)>,
 }
 
 impl ViewState for ViewTensorState {
@@ -72,7 +80,7 @@ impl ViewClass for TensorView {
             .markdown(
                 "An N-dimensional tensor displayed as a 2D slice with a custom colormap.
 
-Set the displayed dimensions in a selection panel.",
+Set the displayed dimensions, fit the colormap to the shown slice and export the slice in a selection panel.",
             )
     }
 
@@ -189,6 +197,53 @@ Set the displayed dimensions in a selection panel.",
             {
                 slice_property.reset_all_components_to_empty(ctx);
             }
+
+            ui.separator();
+            ui.strong("Value range");
+            let mut fit_range_to_slice = load_fit_range_to_slice(ctx, view_id);
+            if ui
+                .re_checkbox(&mut fit_range_to_slice, "Fit to shown slice")
+                .on_hover_text(
+                    "Map the colormap to the values of the shown slice instead of the whole tensor",
+                )
+                .changed()
+            {
+                save_fit_range_to_slice(ctx, view_id, fit_range_to_slice);
+            }
+        }
+
+        if let Some(tensor_view) = state.tensor.clone() {
+            let slice_property = ViewProperty::from_archetype::<
+                re_types::blueprint::archetypes::TensorSliceSelection,
+            >(ctx.blueprint_db(), ctx.blueprint_query, view_id);
+            let slice_selection = TensorSliceSelection::load_and_make_valid(
+                &slice_property,
+                &TensorDimension::from_tensor_data(&tensor_view.tensor),
+            )?;
+            let slice_range = fitted_slice_range(ctx, state, view_id, &slice_selection);
+            let view_ctx = self.view_context(ctx, view_id, state);
+
+            ui.separator();
+            ui.strong("Export slice");
+            ui.horizontal(|ui| {
+                if ui
+                    .button("Save as PNG…")
+                    .on_hover_text("The slice as shown, with the colormap applied")
+                    .clicked()
+                {
+                    let png =
+                        self.slice_png(&view_ctx, &tensor_view, &slice_selection, slice_range);
+                    save_slice(ctx, ui, "tensor_slice.png", png);
+                }
+                if ui
+                    .button("Save as NPY…")
+                    .on_hover_text("The values of the slice, keeping the data type of the tensor")
+                    .clicked()
+                {
+                    let npy = slice_to_npy(&tensor_view.tensor, &slice_selection);
+                    save_slice(ctx, ui, "tensor_slice.npy", npy);
+                }
+            });
         }
 
         Ok(())
@@ -264,7 +319,7 @@ impl TensorView {
         &self,
         ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
-        state: &ViewTensorState,
+        state: &mut ViewTensorState,
         view_id: ViewId,
         tensor: &TensorData,
     ) -> Result<(), ViewSystemExecutionError> {
@@ -317,11 +372,18 @@ impl TensorView {
             }),
         ];
 
+        let slice_range = fitted_slice_range(ctx, state, view_id, &slice_selection);
+
         egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
             let ctx = self.view_context(ctx, view_id, state);
-            if let Err(err) =
-                self.tensor_slice_ui(&ctx, ui, state, dimension_labels, &slice_selection)
-            {
+            if let Err(err) = self.tensor_slice_ui(
+                &ctx,
+                ui,
+                state,
+                dimension_labels,
+                &slice_selection,
+                slice_range,
+            ) {
                 ui.error_label(err.to_string());
             }
         });
@@ -336,8 +398,10 @@ impl TensorView {
         state: &ViewTensorState,
         dimension_labels: [Option<(String, bool)>; 2],
         slice_selection: &TensorSliceSelection,
+        slice_range: Option<[f64; 2]>,
     ) -> anyhow::Result<()> {
-        let (response, image_rect) = self.paint_tensor_slice(ctx, ui, state, slice_selection)?;
+        let (response, image_rect) =
+            self.paint_tensor_slice(ctx, ui, state, slice_selection, slice_range)?;
 
         if !response.hovered() {
             let font_id = egui::TextStyle::Body.resolve(ui.style());
@@ -353,6 +417,7 @@ impl TensorView {
         ui: &mut egui::Ui,
         state: &ViewTensorState,
         slice_selection: &TensorSliceSelection,
+        slice_range: Option<[f64; 2]>,
     ) -> anyhow::Result<(egui::Response, egui::Rect)> {
         re_tracing::profile_function!();
 
@@ -386,9 +451,11 @@ impl TensorView {
             &TensorScalarMapping::descriptor_mag_filter(),
         )?;
 
+        let [min, max] = slice_range.unwrap_or([data_range.start(), data_range.end()]);
         let colormap = ColormapWithRange {
             colormap,
-            value_range: [data_range.start() as f32, data_range.end() as f32],
+            value_range: [min as f32, max as f32],
+            custom_colormap: picked_custom_colormap(ctx.viewer_ctx, &scalar_mapping),
         };
         let colormapped_texture = super::tensor_slice_to_gpu::colormapped_texture(
             ctx.render_ctx(),
@@ -442,6 +509,131 @@ impl TensorView {
 
         Ok((response, image_rect))
     }
+
+    /// The shown slice as a PNG, colormapped like in the view.
+    fn slice_png(
+        &self,
+        ctx: &ViewContext<'_>,
+        tensor_view: &TensorVisualization,
+        slice_selection: &TensorSliceSelection,
+        slice_range: Option<[f64; 2]>,
+    ) -> anyhow::Result<Vec<u8>> {
+        let scalar_mapping = ViewProperty::from_archetype::<TensorScalarMapping>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query(),
+            ctx.view_id,
+        );
+        let colormap: Colormap = scalar_mapping.component_or_fallback(
+            ctx,
+            self,
+            &TensorScalarMapping::descriptor_colormap(),
+        )?;
+        let gamma: GammaCorrection = scalar_mapping.component_or_fallback(
+            ctx,
+            self,
+            &TensorScalarMapping::descriptor_gamma(),
+        )?;
+
+        let data_range = &tensor_view.data_range;
+        let [min, max] = slice_range.unwrap_or([data_range.start(), data_range.end()]);
+        let colormap = ColormapWithRange {
+            colormap,
+            value_range: [min as f32, max as f32],
+            custom_colormap: picked_custom_colormap(ctx.viewer_ctx, &scalar_mapping),
+        };
+
+        let values = slice_values(&tensor_view.tensor, slice_selection)?;
+        slice_to_png(&values, &colormap, *gamma.0)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn slice_value_range_property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<TensorSliceValueRange>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+fn load_fit_range_to_slice(ctx: &ViewerContext<'_>, view_id: ViewId) -> bool {
+    slice_value_range_property(ctx, view_id)
+        .component_or_empty::<Enabled>(&TensorSliceValueRange::descriptor_fit_to_slice())
+        .ok()
+        .flatten()
+        .is_some_and(bool::from)
+}
+
+fn save_fit_range_to_slice(ctx: &ViewerContext<'_>, view_id: ViewId, fit_to_slice: bool) {
+    slice_value_range_property(ctx, view_id).save_blueprint_component(
+        ctx,
+        &TensorSliceValueRange::descriptor_fit_to_slice(),
+        &Enabled::from(fit_to_slice),
+    );
+}
+
+/// The registered colormap picked instead of the colormap of the scalar mapping, if any.
+fn picked_custom_colormap(
+    ctx: &ViewerContext<'_>,
+    scalar_mapping: &ViewProperty,
+) -> Option<Arc<gpu_bridge::CustomColormap>> {
+    let descr = gpu_bridge::custom_colormap_descriptor(&TensorScalarMapping::descriptor_colormap());
+    let (_, name) = ctx.blueprint_db().latest_at_component::<Name>(
+        &scalar_mapping.blueprint_store_path,
+        ctx.blueprint_query,
+        &descr,
+    )?;
+    gpu_bridge::custom_colormap(name.as_str())
+}
+
+/// The range of the values in the shown slice, if the view is set to fit the colormap to it.
+///
+/// Cached in the state, since it is needed every frame and only changes with the slice.
+fn fitted_slice_range(
+    ctx: &ViewerContext<'_>,
+    state: &mut ViewTensorState,
+    view_id: ViewId,
+    slice_selection: &TensorSliceSelection,
+) -> Option<[f64; 2]> {
+    if !load_fit_range_to_slice(ctx, view_id) {
+        return None;
+    }
+    let tensor_view = state.tensor.as_ref()?;
+
+    let key = egui::util::hash((tensor_view.tensor_row_id, slice_selection));
+    if let Some((cached_key, range)) = state.slice_range
+        && cached_key == key
+    {
+        return range;
+    }
+
+    let range = slice_values(&tensor_view.tensor, slice_selection)
+        .ok()
+        .and_then(|values| slice_value_range(&values));
+    state.slice_range = Some((key, range));
+    range
+}
+
+fn save_slice(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    file_name: &str,
+    bytes: anyhow::Result<Vec<u8>>,
+) {
+    match bytes {
+        Ok(bytes) => {
+            ctx.command_sender().save_file_dialog(
+                re_capabilities::MainThreadToken::from_egui_ui(ui),
+                file_name,
+                "Save tensor slice".to_owned(),
+                bytes,
+            );
+        }
+        Err(err) => {
+            re_log::error!("Failed to export tensor slice: {err}");
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,157 @@
+//! Filtering text log entries by their body, entity path and severity.
+
+use std::ops::RangeInclusive;
+
+use re_log_types::EntityPath;
+use re_types::components::TextLogLevel;
+
+/// The known log levels, from least to most severe.
+pub const SEVERITIES: [&str; 6] = [
+    TextLogLevel::TRACE,
+    TextLogLevel::DEBUG,
+    TextLogLevel::INFO,
+    TextLogLevel::WARN,
+    TextLogLevel::ERROR,
+    TextLogLevel::CRITICAL,
+];
+
+/// The severity of a log level, `None` for levels we don't know about.
+pub fn severity(level: &str) -> Option<usize> {
+    SEVERITIES
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(level))
+}
+
+/// Which entries to show, based on their content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextFilter {
+    /// Searched for in the body and the entity path. Everything matches an empty pattern.
+    pub pattern: String,
+
+    /// Whether the pattern is a regular expression rather than a case-insensitive substring.
+    pub is_regex: bool,
+
+    /// The least severe level shown.
+    pub min_level: TextLogLevel,
+
+    /// The most severe level shown.
+    pub max_level: TextLogLevel,
+}
+
+impl Default for TextFilter {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            is_regex: false,
+            min_level: TextLogLevel::TRACE.into(),
+            max_level: TextLogLevel::CRITICAL.into(),
+        }
+    }
+}
+
+impl TextFilter {
+    /// Whether this filter hides anything at all.
+    pub fn is_active(&self) -> bool {
+        !self.pattern.is_empty() || self.severities() != (0..=SEVERITIES.len() - 1)
+    }
+
+    fn severities(&self) -> RangeInclusive<usize> {
+        let min = severity(self.min_level.as_str()).unwrap_or(0);
+        let max = severity(self.max_level.as_str()).unwrap_or(SEVERITIES.len() - 1);
+        min..=max
+    }
+
+    fn regex(&self) -> Result<Option<regex_lite::Regex>, regex_lite::Error> {
+        if self.pattern.is_empty() {
+            Ok(None)
+        } else if self.is_regex {
+            regex_lite::Regex::new(&self.pattern).map(Some)
+        } else {
+            regex_lite::RegexBuilder::new(&regex_lite::escape(&self.pattern))
+                .case_insensitive(true)
+                .build()
+                .map(Some)
+        }
+    }
+
+    /// Prepares the filter for matching many entries.
+    ///
+    /// An invalid regular expression is ignored while it is being typed, and its error returned for display.
+    pub fn compile(&self) -> (CompiledTextFilter, Option<regex_lite::Error>) {
+        let (regex, error) = match self.regex() {
+            Ok(regex) => (regex, None),
+            Err(err) => (None, Some(err)),
+        };
+
+        let compiled = CompiledTextFilter {
+            regex,
+            severities: self.severities(),
+        };
+        (compiled, error)
+    }
+}
+
+/// A [`TextFilter`] ready to be matched against entries.
+#[derive(Clone, Debug)]
+pub struct CompiledTextFilter {
+    regex: Option<regex_lite::Regex>,
+    severities: RangeInclusive<usize>,
+}
+
+impl CompiledTextFilter {
+    /// Whether an entry passes the filter.
+    ///
+    /// Entries without a level, or with a level we don't know the severity of, are never filtered by severity.
+    pub fn matches(&self, body: &str, entity_path: &EntityPath, level: Option<&str>) -> bool {
+        let severity_matches = level
+            .and_then(severity)
+            .is_none_or(|severity| self.severities.contains(&severity));
+
+        severity_matches
+            && self.regex.as_ref().is_none_or(|regex| {
+                regex.is_match(body) || regex.is_match(&entity_path.to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_regex_and_severity() {
+        let net = EntityPath::from("/net");
+        let mut filter = TextFilter::default();
+        assert!(!filter.is_active());
+        assert!(filter.compile().0.matches("anything", &net, None));
+
+        filter.pattern = "timeout".to_owned();
+        assert!(filter.is_active());
+        let compiled = filter.compile().0;
+        assert!(compiled.matches("Connection TIMEOUT after 3s", &net, Some("WARN")));
+        assert!(compiled.matches("lost", &"/net/timeouts".into(), Some("INFO")));
+        assert!(!compiled.matches("connected", &net, Some("INFO")));
+
+        // Substrings aren't regular expressions.
+        filter.pattern = "a.c".to_owned();
+        assert!(!filter.compile().0.matches("abc", &net, None));
+
+        filter.is_regex = true;
+        assert!(filter.compile().0.matches("abc", &net, None));
+
+        filter.pattern = "(unclosed".to_owned();
+        assert!(filter.compile().1.is_some());
+        assert!(filter.compile().0.matches("(", &net, None));
+
+        filter.pattern = String::new();
+        filter.min_level = TextLogLevel::WARN.into();
+        filter.max_level = TextLogLevel::ERROR.into();
+        let compiled = filter.compile().0;
+        assert!(compiled.matches("", &net, Some("warn")));
+        assert!(compiled.matches("", &net, Some("ERROR")));
+        assert!(!compiled.matches("", &net, Some("INFO")));
+        assert!(!compiled.matches("", &net, Some("CRITICAL")));
+        assert!(compiled.matches("", &net, Some("CUSTOM")));
+        assert!(compiled.matches("", &net, None));
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! A View that shows `TextLog` entries in a table and scrolls with the active time.
 
+mod filter;
+mod saved_filters;
 mod view_class;
 mod visualizer_system;
 
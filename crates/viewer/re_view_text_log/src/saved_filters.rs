@@ -0,0 +1,102 @@
+//! Named filters, stored in the blueprint next to the other properties of the view.
+//!
+//! Every field is stored as one batch, with one instance per saved filter.
+
+use re_types::{
+    blueprint::{archetypes::TextLogSavedFilters, components::Enabled},
+    components::{Text, TextLogLevel},
+};
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::filter::TextFilter;
+
+/// A filter the user gave a name, to apply it again later.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedFilter {
+    pub name: String,
+    pub filter: TextFilter,
+}
+
+fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<TextLogSavedFilters>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// The saved filters of the view, in the order they were saved.
+pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Vec<SavedFilter> {
+    let property = property(ctx, view_id);
+
+    let names = property
+        .component_array_or_empty::<Text>(&TextLogSavedFilters::descriptor_names())
+        .unwrap_or_default();
+    let patterns = property
+        .component_array_or_empty::<Text>(&TextLogSavedFilters::descriptor_patterns())
+        .unwrap_or_default();
+    let is_regex = property
+        .component_array_or_empty::<Enabled>(&TextLogSavedFilters::descriptor_is_regex())
+        .unwrap_or_default();
+    let min_levels = property
+        .component_array_or_empty::<TextLogLevel>(&TextLogSavedFilters::descriptor_min_levels())
+        .unwrap_or_default();
+    let max_levels = property
+        .component_array_or_empty::<TextLogLevel>(&TextLogSavedFilters::descriptor_max_levels())
+        .unwrap_or_default();
+
+    itertools::izip!(names, patterns, is_regex, min_levels, max_levels)
+        .map(
+            |(name, pattern, is_regex, min_level, max_level)| SavedFilter {
+                name: name.as_str().to_owned(),
+                filter: TextFilter {
+                    pattern: pattern.as_str().to_owned(),
+                    is_regex: is_regex.into(),
+                    min_level,
+                    max_level,
+                },
+            },
+        )
+        .collect()
+}
+
+/// Replaces all saved filters of the view.
+pub fn save(ctx: &ViewerContext<'_>, view_id: ViewId, saved_filters: &[SavedFilter]) {
+    let property = property(ctx, view_id);
+
+    let names: Vec<Text> = saved_filters
+        .iter()
+        .map(|saved| Text::from(saved.name.as_str()))
+        .collect();
+    let patterns: Vec<Text> = saved_filters
+        .iter()
+        .map(|saved| Text::from(saved.filter.pattern.as_str()))
+        .collect();
+    let is_regex: Vec<Enabled> = saved_filters
+        .iter()
+        .map(|saved| Enabled::from(saved.filter.is_regex))
+        .collect();
+    let min_levels: Vec<TextLogLevel> = saved_filters
+        .iter()
+        .map(|saved| saved.filter.min_level.clone())
+        .collect();
+    let max_levels: Vec<TextLogLevel> = saved_filters
+        .iter()
+        .map(|saved| saved.filter.max_level.clone())
+        .collect();
+
+    property.save_blueprint_component(ctx, &TextLogSavedFilters::descriptor_names(), &names);
+    property.save_blueprint_component(ctx, &TextLogSavedFilters::descriptor_patterns(), &patterns);
+    property.save_blueprint_component(ctx, &TextLogSavedFilters::descriptor_is_regex(), &is_regex);
+    property.save_blueprint_component(
+        ctx,
+        &TextLogSavedFilters::descriptor_min_levels(),
+        &min_levels,
+    );
+    property.save_blueprint_component(
+        ctx,
+        &TextLogSavedFilters::descriptor_max_levels(),
+        &max_levels,
+    );
+}
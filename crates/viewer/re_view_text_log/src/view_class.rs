@@ -12,9 +12,13 @@ use re_viewer_context::{
 };
 
 use super::visualizer_system::{Entry, TextLogSystem};
+use crate::{
+    filter::{CompiledTextFilter, SEVERITIES, TextFilter},
+    saved_filters::{self, SavedFilter},
+};
 
 // TODO(andreas): This should be a blueprint component.
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct TextViewState {
     /// Keeps track of the latest time selection made by the user.
     ///
@@ -25,6 +29,25 @@ pub struct TextViewState {
     pub filters: ViewTextFilters,
 
     monospace: bool,
+
+    /// Don't follow the time cursor while a text or severity filter is active,
+    /// so that high-volume logs don't keep scrolling away from the filtered entries.
+    pause_auto_scroll_while_filtered: bool,
+
+    /// Name the current filter is saved under.
+    new_filter_name: String,
+}
+
+impl Default for TextViewState {
+    fn default() -> Self {
+        Self {
+            latest_time: 0,
+            filters: Default::default(),
+            monospace: false,
+            pause_auto_scroll_while_filtered: true,
+            new_filter_name: String::new(),
+        }
+    }
 }
 
 impl ViewState for TextViewState {
@@ -61,7 +84,11 @@ impl ViewClass for TextView {
             .markdown(
                 "TextLog entries over time.
 
-Filter message types and toggle column visibility in a selection panel.",
+Filter entries by their body and entity path at the top of the view, either by substring or regular expression.
+
+Filter message types and severity ranges, toggle column visibility and save named filters in a selection panel.
+
+While a filter is active, the view doesn't follow the time cursor unless configured otherwise.",
             )
     }
 
@@ -106,11 +133,11 @@ Filter message types and toggle column visibility in a selection panel.",
 
     fn selection_ui(
         &self,
-        _ctx: &ViewerContext<'_>,
+        ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
         _space_origin: &EntityPath,
-        _view_id: ViewId,
+        view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<TextViewState>()?;
 
@@ -119,6 +146,7 @@ Filter message types and toggle column visibility in a selection panel.",
             col_entity_path,
             col_log_level,
             row_log_levels,
+            row_text,
         } = &mut state.filters;
 
         ui.selection_grid("log_config").show(ui, |ui| {
@@ -140,6 +168,28 @@ Filter message types and toggle column visibility in a selection panel.",
             });
             ui.end_row();
 
+            ui.grid_left_hand_label("Severity");
+            ui.horizontal(|ui| {
+                severity_ui(ui, "min_severity", &mut row_text.min_level);
+                ui.label("to");
+                severity_ui(ui, "max_severity", &mut row_text.max_level);
+            });
+            ui.end_row();
+
+            ui.grid_left_hand_label("Saved filters");
+            ui.vertical(|ui| {
+                saved_filters_ui(ctx, ui, view_id, row_text, &mut state.new_filter_name);
+            });
+            ui.end_row();
+
+            ui.grid_left_hand_label("Auto-scroll");
+            ui.re_checkbox(
+                &mut state.pause_auto_scroll_while_filtered,
+                "Pause while filtered",
+            )
+            .on_hover_text("Don't follow the time cursor while a filter is active");
+            ui.end_row();
+
             ui.grid_left_hand_label("Text style");
             ui.vertical(|ui| {
                 ui.re_radio_value(&mut state.monospace, false, "Proportional");
@@ -166,23 +216,48 @@ Filter message types and toggle column visibility in a selection panel.",
         let state = state.downcast_mut::<TextViewState>()?;
         let text = system_output.view_systems.get::<TextLogSystem>()?;
 
-        // TODO(andreas): Should filter text entries in the part-system instead.
-        // this likely requires a way to pass state into a context.
-        let entries = text
-            .entries
-            .iter()
-            .filter(|te| {
-                te.level
-                    .as_ref()
-                    .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
-            })
-            .collect::<Vec<_>>();
-
         egui::Frame {
             inner_margin: tokens.view_padding().into(),
             ..egui::Frame::default()
         }
         .show(ui, |ui| {
+            let (text_filter, text_filter_error) = filter_bar_ui(ui, &mut state.filters.row_text);
+
+            // TODO(andreas): Should filter text entries in the part-system instead.
+            // this likely requires a way to pass state into a context.
+            let entries = {
+                re_tracing::profile_scope!("filter entries");
+                text.entries
+                    .iter()
+                    .filter(|te| {
+                        te.level
+                            .as_ref()
+                            .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
+                            && text_filter.matches(
+                                te.body.as_str(),
+                                &te.entity_path,
+                                te.level.as_ref().map(|lvl| lvl.as_str()),
+                            )
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let is_filtered = state.filters.row_text.is_active();
+            let auto_scroll_paused = is_filtered && state.pause_auto_scroll_while_filtered;
+            if let Some(err) = text_filter_error {
+                ui.error_label(format!("Invalid regular expression: {err}"));
+            } else if is_filtered {
+                let mut status = format!(
+                    "Showing {} of {} entries",
+                    re_format::format_uint(entries.len()),
+                    re_format::format_uint(text.entries.len())
+                );
+                if auto_scroll_paused {
+                    status.push_str(", auto-scroll paused");
+                }
+                ui.weak(status);
+            }
+
             // Update filters if necessary.
             state.filters.update(ctx, &entries);
 
@@ -196,8 +271,9 @@ Filter message types and toggle column visibility in a selection panel.",
             // Did the time cursor move since last time?
             // - If it did, autoscroll to the text log to reveal the current time.
             // - Otherwise, let the user scroll around freely!
+            // - Unless a filter is active and the user asked us not to.
             let time_cursor_moved = state.latest_time != time;
-            let scroll_to_row = time_cursor_moved.then(|| {
+            let scroll_to_row = (time_cursor_moved && !auto_scroll_paused).then(|| {
                 re_tracing::profile_scope!("search scroll time");
                 entries.partition_point(|te| te.time.as_i64() < time)
             });
@@ -218,7 +294,6 @@ Filter message types and toggle column visibility in a selection panel.",
 
 // --- Filters ---
 
-// TODO(cmc): implement "body contains <value>" filter.
 // TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ViewTextFilters {
@@ -230,6 +305,7 @@ pub struct ViewTextFilters {
 
     // Row filters: which rows should be visible?
     pub row_log_levels: BTreeMap<TextLogLevel, bool>,
+    pub row_text: TextFilter,
 }
 
 impl Default for ViewTextFilters {
@@ -239,6 +315,7 @@ impl Default for ViewTextFilters {
             col_log_level: true,
             col_timelines: Default::default(),
             row_log_levels: Default::default(),
+            row_text: Default::default(),
         }
     }
 }
@@ -258,6 +335,7 @@ impl ViewTextFilters {
             col_entity_path: _,
             col_log_level: _,
             row_log_levels,
+            row_text: _,
         } = self;
 
         for &timeline in ctx.recording().timelines().keys() {
@@ -270,6 +348,118 @@ impl ViewTextFilters {
     }
 }
 
+/// The text filter above the table.
+///
+/// Returns the filter compiled for matching, and the error of an invalid regular expression.
+fn filter_bar_ui(
+    ui: &mut egui::Ui,
+    filter: &mut TextFilter,
+) -> (CompiledTextFilter, Option<regex_lite::Error>) {
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut filter.pattern)
+                .hint_text("Filter body and entity path…")
+                .desired_width((ui.available_width() - 160.0).max(100.0)),
+        );
+        ui.re_checkbox(&mut filter.is_regex, "Regex")
+            .on_hover_text("Match a regular expression instead of a case-insensitive substring");
+        if !filter.pattern.is_empty()
+            && ui
+                .small_icon_button(&re_ui::icons::CLOSE, "Clear filter")
+                .clicked()
+        {
+            filter.pattern.clear();
+        }
+    });
+
+    filter.compile()
+}
+
+fn severity_ui(ui: &mut egui::Ui, id_salt: &str, level: &mut TextLogLevel) {
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(level_to_rich_text(ui, level.as_str()))
+        .show_ui(ui, |ui| {
+            for severity in SEVERITIES {
+                let text = level_to_rich_text(ui, severity);
+                ui.selectable_value(level, TextLogLevel::from(severity), text);
+            }
+        });
+}
+
+/// Lists the filters saved in the blueprint, to apply or remove them, and saves the current one.
+fn saved_filters_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    current: &mut TextFilter,
+    new_filter_name: &mut String,
+) {
+    let mut saved = saved_filters::load(ctx, view_id);
+
+    let mut removed = None;
+    for (index, saved_filter) in saved.iter().enumerate() {
+        ui.horizontal(|ui| {
+            let is_applied = saved_filter.filter == *current;
+            if ui
+                .selectable_label(is_applied, &saved_filter.name)
+                .on_hover_text(format!(
+                    "{:?} ({}), {} to {}",
+                    saved_filter.filter.pattern,
+                    if saved_filter.filter.is_regex {
+                        "regex"
+                    } else {
+                        "substring"
+                    },
+                    saved_filter.filter.min_level.as_str(),
+                    saved_filter.filter.max_level.as_str(),
+                ))
+                .clicked()
+            {
+                *current = saved_filter.filter.clone();
+            }
+            if ui
+                .small_icon_button(&re_ui::icons::REMOVE, "Remove saved filter")
+                .clicked()
+            {
+                removed = Some(index);
+            }
+        });
+    }
+    if let Some(index) = removed {
+        saved.remove(index);
+        saved_filters::save(ctx, view_id, &saved);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(new_filter_name)
+                .hint_text("Name")
+                .desired_width(120.0),
+        );
+
+        let name = new_filter_name.trim();
+        let can_save = !name.is_empty() && current.is_active();
+        if ui
+            .add_enabled(can_save, egui::Button::new("Save current"))
+            .on_disabled_hover_text("Name an active filter to save it")
+            .clicked()
+        {
+            // Saving under an existing name replaces that filter.
+            let saved_filter = SavedFilter {
+                name: name.to_owned(),
+                filter: current.clone(),
+            };
+            if let Some(existing) = saved.iter_mut().find(|saved| saved.name == name) {
+                *existing = saved_filter;
+            } else {
+                saved.push(saved_filter);
+            }
+            saved_filters::save(ctx, view_id, &saved);
+            new_filter_name.clear();
+        }
+    });
+}
+
 // ---
 
 /// `scroll_to_row` indicates how far down we want to scroll in terms of logical rows,
@@ -0,0 +1,298 @@
+//! Keeping the number of rendered points bounded, no matter how many were logged.
+//!
+//! Series are loaded for a zoom bucket rather than the exact zoom level and range of the plot,
+//! so that the result can be cached while panning and zooming by small amounts.
+
+use egui::ahash::HashMap;
+
+use re_log_types::{AbsoluteTimeRange, EntityPath, hash::Hash64};
+use re_viewer_context::{Cache, CacheMemoryReport, ViewContext, ViewSystemIdentifier};
+
+use crate::{PlotPoint, PlotSeries, PlotSeriesKind};
+
+/// How many zoom buckets there are per doubling of the zoom level.
+const BUCKETS_PER_OCTAVE: f64 = 4.0;
+
+/// The query range is aligned to multiples of this many pixels.
+const RANGE_ALIGNMENT_PIXELS: f64 = 512.0;
+
+/// Points per pixel of plot width we allow before downsampling.
+const POINTS_PER_PIXEL: f32 = 2.0;
+
+/// The fewest points we downsample to, e.g. for tiny plots.
+const MIN_POINTS: usize = 500;
+
+/// Cached series that haven't been used for this many frames are dropped.
+const MAX_UNUSED_FRAMES: u64 = 60;
+
+/// The zoom level a series is loaded for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomBucket {
+    /// Time units per physical pixel, rounded to the bucket.
+    pub time_per_pixel: f64,
+
+    /// Series with more points than this are downsampled.
+    pub max_points: usize,
+}
+
+impl ZoomBucket {
+    pub fn new(
+        ctx: &ViewContext<'_>,
+        plot_mem: Option<&egui_plot::PlotMemory>,
+        time_per_pixel: f64,
+    ) -> Self {
+        let bucket = (time_per_pixel.max(f64::MIN_POSITIVE).log2() * BUCKETS_PER_OCTAVE).round();
+
+        let plot_width_pixels = plot_mem.map_or(1000.0, |mem| {
+            mem.transform().frame().width() * ctx.viewer_ctx.egui_ctx().pixels_per_point()
+        });
+
+        Self {
+            time_per_pixel: (bucket / BUCKETS_PER_OCTAVE).exp2(),
+            max_points: ((POINTS_PER_PIXEL * plot_width_pixels) as usize).max(MIN_POINTS),
+        }
+    }
+
+    /// The visible plot range is widened to multiples of this, so that it changes less often while panning.
+    pub fn range_alignment(&self) -> i64 {
+        (self.time_per_pixel * RANGE_ALIGNMENT_PIXELS).max(1.0) as i64
+    }
+
+    /// Identifies the series of an entity loaded by a visualizer for this bucket, as of the current data.
+    pub fn cache_key(
+        &self,
+        ctx: &ViewContext<'_>,
+        visualizer: ViewSystemIdentifier,
+        entity_path: &EntityPath,
+        time_range: AbsoluteTimeRange,
+    ) -> Hash64 {
+        Hash64::hash((
+            visualizer,
+            entity_path,
+            self.time_per_pixel.to_bits(),
+            time_range,
+            self.max_points,
+            ctx.recording().generation(),
+            // Overrides, visibility and names live in the blueprint.
+            ctx.blueprint_db().generation(),
+        ))
+    }
+}
+
+/// Picks at most `max_points` points that keep the visual shape of the series,
+/// using Largest-Triangle-Three-Buckets.
+///
+/// Discontinuities are always kept.
+pub fn downsample(points: Vec<PlotPoint>, max_points: usize) -> Vec<PlotPoint> {
+    if points.len() <= max_points {
+        return points;
+    }
+    re_tracing::profile_function!();
+
+    let num_points = points.len();
+    let mut downsampled = Vec::with_capacity(max_points);
+    let mut run_start = 0;
+    for (i, point) in points.iter().enumerate() {
+        if point.attrs.kind == PlotSeriesKind::Clear {
+            downsample_run(
+                &points[run_start..i],
+                max_points,
+                num_points,
+                &mut downsampled,
+            );
+            downsampled.push(point.clone());
+            run_start = i + 1;
+        }
+    }
+    downsample_run(
+        &points[run_start..],
+        max_points,
+        num_points,
+        &mut downsampled,
+    );
+
+    downsampled
+}
+
+/// Gives each run between discontinuities its share of the points.
+fn downsample_run(
+    run: &[PlotPoint],
+    max_points: usize,
+    num_points: usize,
+    downsampled: &mut Vec<PlotPoint>,
+) {
+    let share = (run.len() * max_points).div_ceil(num_points).max(3);
+    downsampled.extend(lttb(run, share));
+}
+
+/// Largest-Triangle-Three-Buckets: keeps the first and last point, and for each bucket in between
+/// the point forming the largest triangle with the previously kept point and the average of the next bucket.
+fn lttb(points: &[PlotPoint], threshold: usize) -> Vec<PlotPoint> {
+    if threshold < 3 || points.len() <= threshold {
+        return points.to_vec();
+    }
+
+    // Relative to the first point, so that large timestamps keep their precision.
+    let origin = points[0].time;
+    let x = |point: &PlotPoint| (point.time - origin) as f64;
+
+    let bucket_size = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let bucket_start = |bucket: usize| (bucket as f64 * bucket_size) as usize + 1;
+
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(points[0].clone());
+
+    let mut previous = 0;
+    for bucket in 0..threshold - 2 {
+        let next_bucket =
+            &points[bucket_start(bucket + 1)..bucket_start(bucket + 2).min(points.len())];
+        let (next_x, next_y) = if next_bucket.is_empty() {
+            let last = &points[points.len() - 1];
+            (x(last), last.value)
+        } else {
+            let n = next_bucket.len() as f64;
+            (
+                next_bucket.iter().map(x).sum::<f64>() / n,
+                next_bucket.iter().map(|p| p.value).sum::<f64>() / n,
+            )
+        };
+
+        let (previous_x, previous_y) = (x(&points[previous]), points[previous].value);
+        let mut largest_area = -1.0;
+        let mut picked = bucket_start(bucket);
+        for (i, point) in points
+            .iter()
+            .enumerate()
+            .take(bucket_start(bucket + 1))
+            .skip(bucket_start(bucket))
+        {
+            let area = ((previous_x - next_x) * (point.value - previous_y)
+                - (previous_x - x(point)) * (next_y - previous_y))
+                .abs();
+            if area > largest_area {
+                largest_area = area;
+                picked = i;
+            }
+        }
+
+        sampled.push(points[picked].clone());
+        previous = picked;
+    }
+
+    sampled.push(points[points.len() - 1].clone());
+    sampled
+}
+
+/// Loaded series per visualizer, entity and zoom bucket.
+#[derive(Default)]
+pub struct PlotSeriesCache {
+    frame: u64,
+
+    /// The frame each entry was last used in.
+    series: HashMap<Hash64, (u64, Vec<PlotSeries>)>,
+}
+
+impl PlotSeriesCache {
+    pub fn get(&mut self, key: Hash64) -> Option<Vec<PlotSeries>> {
+        let (last_used, series) = self.series.get_mut(&key)?;
+        *last_used = self.frame;
+        Some(series.clone())
+    }
+
+    pub fn insert(&mut self, key: Hash64, series: Vec<PlotSeries>) {
+        self.series.insert(key, (self.frame, series));
+    }
+}
+
+impl Cache for PlotSeriesCache {
+    fn begin_frame(&mut self) {
+        self.frame += 1;
+        let frame = self.frame;
+        self.series
+            .retain(|_, (last_used, _)| frame - *last_used <= MAX_UNUSED_FRAMES);
+    }
+
+    fn purge_memory(&mut self) {
+        self.series.clear();
+    }
+
+    fn name(&self) -> &'static str {
+        "Plot Series"
+    }
+
+    fn memory_report(&self) -> CacheMemoryReport {
+        CacheMemoryReport {
+            bytes_cpu: self
+                .series
+                .values()
+                .flat_map(|(_, series)| series)
+                .map(|series| (series.points.len() * std::mem::size_of::<(i64, f64)>()) as u64)
+                .sum(),
+            bytes_gpu: None,
+            per_cache_item_info: Vec::new(),
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlotPointAttrs;
+
+    fn point(time: i64, value: f64, kind: PlotSeriesKind) -> PlotPoint {
+        PlotPoint {
+            time,
+            value,
+            attrs: PlotPointAttrs {
+                color: egui::Color32::TRANSPARENT,
+                radius_ui: 1.0,
+                kind,
+            },
+        }
+    }
+
+    #[test]
+    fn lttb_keeps_peaks_and_ends() {
+        let points: Vec<PlotPoint> = (0..1000)
+            .map(|i| {
+                let value = if i == 500 { 100.0 } else { 0.0 };
+                point(i, value, PlotSeriesKind::Continuous)
+            })
+            .collect();
+
+        let downsampled = downsample(points, 50);
+        assert!(downsampled.len() <= 50);
+        assert_eq!(downsampled.first().map(|p| p.time), Some(0));
+        assert_eq!(downsampled.last().map(|p| p.time), Some(999));
+        assert!(downsampled.iter().any(|p| p.value == 100.0));
+        assert!(downsampled.windows(2).all(|w| w[0].time < w[1].time));
+    }
+
+    #[test]
+    fn downsampling_keeps_discontinuities() {
+        let points: Vec<PlotPoint> = (0..1000)
+            .map(|i| {
+                let kind = if i == 300 {
+                    PlotSeriesKind::Clear
+                } else {
+                    PlotSeriesKind::Continuous
+                };
+                point(i, (i as f64).sin(), kind)
+            })
+            .collect();
+
+        let downsampled = downsample(points, 100);
+        assert!(downsampled.len() <= 105);
+        assert_eq!(
+            downsampled
+                .iter()
+                .filter(|p| p.attrs.kind == PlotSeriesKind::Clear)
+                .count(),
+            1
+        );
+    }
+}
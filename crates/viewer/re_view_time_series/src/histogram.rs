@@ -0,0 +1,88 @@
+//! Binning values into histograms.
+
+/// Equally wide bins between `min` and `max`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HistogramBins {
+    pub min: f64,
+    pub max: f64,
+    pub num_bins: usize,
+}
+
+impl HistogramBins {
+    /// Bins covering all finite values, or `None` if there are none.
+    ///
+    /// If all values are the same, the bins are centered on it.
+    pub fn covering(values: impl Iterator<Item = f64>, num_bins: usize) -> Option<Self> {
+        let (min, max) = values.filter(|value| value.is_finite()).fold(
+            None,
+            |range: Option<(f64, f64)>, value| {
+                Some(range.map_or((value, value), |(min, max)| {
+                    (min.min(value), max.max(value))
+                }))
+            },
+        )?;
+
+        let (min, max) = if min < max {
+            (min, max)
+        } else {
+            (min - 0.5, max + 0.5)
+        };
+
+        Some(Self {
+            min,
+            max,
+            num_bins: num_bins.max(1),
+        })
+    }
+
+    #[inline]
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / self.num_bins as f64
+    }
+
+    /// The value in the middle of the bin.
+    #[inline]
+    pub fn bin_center(&self, bin: usize) -> f64 {
+        self.min + (bin as f64 + 0.5) * self.bin_width()
+    }
+
+    /// The bin the value falls into. The maximum belongs to the last bin.
+    pub fn bin_of(&self, value: f64) -> Option<usize> {
+        if !(self.min..=self.max).contains(&value) {
+            return None;
+        }
+        let bin = ((value - self.min) / self.bin_width()).floor() as usize;
+        Some(bin.min(self.num_bins - 1))
+    }
+
+    /// The number of values falling into each bin.
+    pub fn count(&self, values: impl Iterator<Item = f64>) -> Vec<u64> {
+        let mut counts = vec![0; self.num_bins];
+        for bin in values.filter_map(|value| self.bin_of(value)) {
+            counts[bin] += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_values() {
+        let values = [0.0, 1.0, 2.5, 4.0, f64::NAN];
+        let bins = HistogramBins::covering(values.into_iter(), 4).unwrap();
+        assert_eq!((bins.min, bins.max, bins.bin_width()), (0.0, 4.0, 1.0));
+        assert_eq!(bins.count(values.into_iter()), [1, 1, 1, 1]);
+        assert_eq!(bins.bin_center(2), 2.5);
+        assert_eq!(bins.bin_of(-1.0), None);
+    }
+
+    #[test]
+    fn single_value() {
+        let bins = HistogramBins::covering([3.0, 3.0].into_iter(), 10).unwrap();
+        assert_eq!(bins.count([3.0, 3.0].into_iter()).iter().sum::<u64>(), 2);
+        assert!(HistogramBins::covering(std::iter::empty(), 10).is_none());
+    }
+}
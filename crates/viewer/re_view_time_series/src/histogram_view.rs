@@ -0,0 +1,334 @@
+//! A view of the distribution of scalars over the selected time range, as histograms.
+//!
+//! The time range is the loop selection of the time panel, or the range it shows if nothing is selected.
+
+use egui::ahash::HashMap;
+use egui_plot::{Bar, BarChart, ColorConflictHandling, Legend, Plot};
+
+use re_chunk_store::RangeQuery;
+use re_log_types::EntityPath;
+use re_types::{Archetype as _, ViewClassIdentifier, archetypes, components::Color};
+use re_ui::{Help, IconText, UiExt as _, icons};
+use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
+use re_viewer_context::{
+    IdentifiedViewSystem, IndicatedEntities, Item, MaybeVisualizableEntities, PerVisualizer,
+    QueryContext, TypedComponentFallbackProvider, ViewClass, ViewClassRegistryError, ViewContext,
+    ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, VisualizableEntities, VisualizerQueryInfo, VisualizerSystem,
+    auto_color_for_entity_path,
+};
+
+use crate::histogram::HistogramBins;
+use crate::util::selected_time_range;
+
+/// All scalar values of one entity within the selected time range.
+pub struct HistogramSeries {
+    pub entity_path: EntityPath,
+    pub color: egui::Color32,
+    pub values: Vec<f64>,
+}
+
+/// Collects the values shown by the [`HistogramView`].
+#[derive(Default)]
+pub struct HistogramSystem {
+    pub series: Vec<HistogramSeries>,
+}
+
+impl IdentifiedViewSystem for HistogramSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Histogram".into()
+    }
+}
+
+impl VisualizerSystem for HistogramSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<archetypes::Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context: &re_viewer_context::ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let Some(time_range) = selected_time_range(ctx.viewer_ctx) else {
+            return Ok(Vec::new());
+        };
+        let range_query = RangeQuery::new(query.timeline, time_range);
+        let current_query = ctx.current_query();
+
+        for data_result in query.iter_visible_data_results(Self::identifier()) {
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                archetypes::Scalars::all_components().iter(),
+            );
+            let Some(chunks) =
+                results.get_required_chunks(archetypes::Scalars::descriptor_scalars())
+            else {
+                continue;
+            };
+
+            // Every instance counts, so multi-dimensional scalars end up in the same histogram.
+            let values = chunks
+                .iter()
+                .flat_map(|chunk| chunk.iter_slices::<f64>())
+                .flat_map(|values| values.iter().copied())
+                .collect();
+
+            let query_ctx = ctx.query_context(data_result, &current_query);
+            let color: Color = self.fallback_for(&query_ctx);
+
+            self.series.push(HistogramSeries {
+                entity_path: data_result.entity_path.clone(),
+                color: color.into(),
+                values,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for HistogramSystem {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramSystem => [Color]);
+
+pub struct HistogramViewState {
+    num_bins: usize,
+
+    /// Show the counts on a logarithmic scale, so that rare values are still visible.
+    log_scale: bool,
+}
+
+impl Default for HistogramViewState {
+    fn default() -> Self {
+        Self {
+            num_bins: 50,
+            log_scale: false,
+        }
+    }
+}
+
+impl ViewState for HistogramViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct HistogramView;
+
+impl ViewClass for HistogramView {
+    fn identifier() -> ViewClassIdentifier {
+        "Histogram".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Histogram"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_HISTOGRAM
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<HistogramViewState>::default()
+    }
+
+    fn help(&self, os: egui::os::OperatingSystem) -> Help {
+        let egui::InputOptions { zoom_modifier, .. } = egui::InputOptions::default(); // This is OK, since we don't allow the user to change these modifiers.
+
+        Help::new("Histogram view")
+            .markdown(
+                "Shows how the values of scalars are distributed over the time range selected in the time panel, \
+                or the range it shows if nothing is selected.",
+            )
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control(
+                "Zoom",
+                IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
+            )
+            .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<HistogramSystem>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn choose_default_visualizers(
+        &self,
+        entity_path: &EntityPath,
+        _maybe_visualizable_entities_per_visualizer: &PerVisualizer<MaybeVisualizableEntities>,
+        visualizable_entities_per_visualizer: &PerVisualizer<VisualizableEntities>,
+        _indicated_entities_per_visualizer: &PerVisualizer<IndicatedEntities>,
+    ) -> re_viewer_context::SmallVisualizerSet {
+        // Scalars aren't indicated as histograms, so the default implementation would never pick it.
+        if visualizable_entities_per_visualizer
+            .get(&HistogramSystem::identifier())
+            .is_some_and(|entities| entities.contains(entity_path))
+        {
+            std::iter::once(HistogramSystem::identifier()).collect()
+        } else {
+            Default::default()
+        }
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Scalars are shown in time series views by default, histograms are added by hand.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<HistogramViewState>()?;
+
+        ui.selection_grid("histogram_settings_ui").show(ui, |ui| {
+            ui.grid_left_hand_label("Bins")
+                .on_hover_text("Number of equally wide bins between the lowest and highest value");
+            ui.add(egui::DragValue::new(&mut state.num_bins).range(1..=1000));
+            ui.end_row();
+
+            ui.grid_left_hand_label("Counts");
+            ui.re_checkbox(&mut state.log_scale, "Logarithmic")
+                .on_hover_text(
+                    "Show the counts on a logarithmic scale, so that rare values are still visible",
+                );
+            ui.end_row();
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<HistogramViewState>()?;
+        let all_series = &system_output.view_systems.get::<HistogramSystem>()?.series;
+
+        // All series share the same bins, so that they can be compared.
+        let bins = HistogramBins::covering(
+            all_series
+                .iter()
+                .flat_map(|series| series.values.iter().copied()),
+            state.num_bins,
+        );
+
+        let log_scale = state.log_scale;
+        let mut plot = Plot::new(("histogram_plot", query.view_id))
+            .legend(Legend::default().color_conflict_handling(ColorConflictHandling::PickFirst))
+            .y_axis_label(if log_scale { "Count (log)" } else { "Count" });
+        if log_scale {
+            plot = plot.y_axis_formatter(|mark, _| {
+                re_format::format_uint((10.0_f64.powf(mark.value) - 1.0).round().max(0.0) as u64)
+            });
+        }
+
+        let mut plot_item_id_to_entity_path = HashMap::default();
+        let egui_plot::PlotResponse {
+            response,
+            hovered_plot_item,
+            ..
+        } = plot.show(ui, |plot_ui| {
+            let Some(bins) = bins else {
+                return;
+            };
+
+            for series in all_series {
+                let fill = series.color.gamma_multiply(0.5);
+                let bars = bins
+                    .count(series.values.iter().copied())
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, count)| *count > 0)
+                    .map(|(bin, count)| {
+                        let height = if log_scale {
+                            // Plus one, so that a single value still gets a visible bar.
+                            (count as f64 + 1.0).log10()
+                        } else {
+                            count as f64
+                        };
+                        Bar::new(bins.bin_center(bin), height)
+                            .width(bins.bin_width())
+                            .name(format!(
+                                "{}\n{} values in {}…{}",
+                                series.entity_path,
+                                re_format::format_uint(count),
+                                re_format::format_f64(bins.min + bin as f64 * bins.bin_width()),
+                                re_format::format_f64(
+                                    bins.min + (bin + 1) as f64 * bins.bin_width()
+                                ),
+                            ))
+                            .fill(fill)
+                            .stroke((1.0, series.color))
+                    })
+                    .collect();
+
+                let id = egui::Id::new(series.entity_path.hash());
+                plot_item_id_to_entity_path.insert(id, series.entity_path.clone());
+                plot_ui.bar_chart(
+                    BarChart::new(series.entity_path.to_string(), bars)
+                        .color(series.color)
+                        .id(id),
+                );
+            }
+        });
+
+        let hovered = hovered_plot_item
+            .and_then(|id| plot_item_id_to_entity_path.get(&id))
+            .map_or(Item::View(query.view_id), |entity_path| {
+                Item::DataResult(query.view_id, entity_path.clone().into())
+            });
+        ctx.handle_select_hover_drag_interactions(&response, hovered, false);
+
+        Ok(())
+    }
+}
@@ -5,16 +5,28 @@
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod aggregation;
+mod downsample;
+mod histogram;
+mod histogram_view;
 mod line_visualizer_system;
+mod parallel_coordinates;
+mod parallel_coordinates_view;
 mod point_visualizer_system;
 mod series_query;
+mod stacking;
 mod util;
 mod view_class;
+mod waveform;
+mod waveform_view;
+mod y_axis;
 
 use re_types::components::{AggregationPolicy, MarkerShape};
 use re_viewer_context::external::re_entity_db::InstancePath;
 
+pub use histogram_view::HistogramView;
+pub use parallel_coordinates_view::ParallelCoordinatesView;
 pub use view_class::TimeSeriesView;
+pub use waveform_view::WaveformView;
 
 /// Computes a deterministic, globally unique ID for the plot based on the ID of the view
 /// itself.
@@ -84,6 +96,9 @@ pub struct PlotSeries {
     /// If this is false, [`PlotSeries::points`] is allowed to be empty.
     pub visible: bool,
 
+    /// Whether the series is plotted against the secondary Y axis, on the right-hand side.
+    pub secondary_axis: bool,
+
     /// Label of the series.
     pub label: String,
 
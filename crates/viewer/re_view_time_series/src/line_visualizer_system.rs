@@ -5,7 +5,7 @@ use re_log_types::{EntityPath, TimeInt};
 use re_types::{
     Archetype as _,
     archetypes::{self},
-    components::{AggregationPolicy, Color, Name, SeriesVisible, StrokeWidth},
+    components::{AggregationPolicy, Color, Name, SeriesSecondaryAxis, SeriesVisible, StrokeWidth},
 };
 use re_view::{
     RangeResultsExt as _, latest_at_with_blueprint_resolved_data,
@@ -18,9 +18,10 @@ use re_viewer_context::{
     auto_color_for_entity_path,
 };
 
+use crate::downsample::{PlotSeriesCache, ZoomBucket};
 use crate::series_query::{
     allocate_plot_points, collect_colors, collect_radius_ui, collect_scalars, collect_series_name,
-    collect_series_visibility, determine_num_series,
+    collect_series_secondary_axis, collect_series_visibility, determine_num_series,
 };
 use crate::util::{determine_time_per_pixel, determine_time_range, points_to_series};
 use crate::view_class::TimeSeriesViewState;
@@ -112,7 +113,13 @@ impl TypedComponentFallbackProvider<SeriesVisible> for SeriesLinesSystem {
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(SeriesLinesSystem => [Color, StrokeWidth, Name, SeriesVisible]);
+impl TypedComponentFallbackProvider<SeriesSecondaryAxis> for SeriesLinesSystem {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> SeriesSecondaryAxis {
+        false.into()
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(SeriesLinesSystem => [Color, StrokeWidth, Name, SeriesVisible, SeriesSecondaryAxis]);
 
 impl SeriesLinesSystem {
     fn load_scalars(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
@@ -182,8 +189,32 @@ impl SeriesLinesSystem {
             .view_state
             .downcast_ref::<TimeSeriesViewState>()
             .map_or(0, |state| state.time_offset);
-        let time_range =
-            determine_time_range(view_query.latest_at, time_offset, data_result, plot_mem);
+        // Load for the zoom bucket instead of the exact zoom, so that the result can be reused while panning and zooming.
+        let zoom_bucket = ZoomBucket::new(ctx, plot_mem, time_per_pixel);
+        let time_per_pixel = zoom_bucket.time_per_pixel;
+        let time_range = determine_time_range(
+            view_query.latest_at,
+            time_offset,
+            data_result,
+            plot_mem,
+            zoom_bucket.range_alignment(),
+        );
+        let cache_key = zoom_bucket.cache_key(
+            ctx,
+            Self::identifier(),
+            &data_result.entity_path,
+            time_range,
+        );
+        let cached_series = ctx
+            .viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut PlotSeriesCache| c.get(cache_key));
+        if let Some(cached_series) = cached_series {
+            all_series.extend(cached_series);
+            return;
+        }
+        let first_new_series = all_series.len();
         {
             use re_view::RangeResultsExt as _;
 
@@ -345,6 +376,13 @@ impl SeriesLinesSystem {
                 num_series,
                 archetypes::SeriesLines::descriptor_visible_series(),
             );
+            let series_secondary_axis = collect_series_secondary_axis(
+                &query,
+                &bootstrapped_results,
+                &results,
+                num_series,
+                archetypes::SeriesLines::descriptor_secondary_axis(),
+            );
             let series_names = collect_series_name(
                 self,
                 &query_ctx,
@@ -355,10 +393,11 @@ impl SeriesLinesSystem {
             );
 
             debug_assert_eq!(points_per_series.len(), series_names.len());
-            for (instance, (points, label, visible)) in itertools::izip!(
+            for (instance, (points, label, visible, secondary_axis)) in itertools::izip!(
                 points_per_series.into_iter(),
                 series_names.into_iter(),
-                series_visibility.into_iter()
+                series_visibility.into_iter(),
+                series_secondary_axis.into_iter()
             )
             .enumerate()
             {
@@ -374,7 +413,9 @@ impl SeriesLinesSystem {
                 points_to_series(
                     instance_path,
                     time_per_pixel,
+                    zoom_bucket.max_points,
                     visible,
+                    secondary_axis,
                     points,
                     ctx.recording_engine().store(),
                     view_query,
@@ -384,6 +425,12 @@ impl SeriesLinesSystem {
                 );
             }
         }
+
+        let new_series = all_series[first_new_series..].to_vec();
+        ctx.viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut PlotSeriesCache| c.insert(cache_key, new_series));
     }
 }
 
@@ -0,0 +1,113 @@
+//! Joining scalars into rows of a parallel coordinates plot, and brushing them.
+
+use std::collections::BTreeMap;
+
+/// One vertical axis of the plot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Axis {
+    pub name: String,
+
+    /// Lowest finite value on this axis.
+    pub min: f64,
+
+    /// Highest finite value on this axis.
+    pub max: f64,
+}
+
+impl Axis {
+    /// Where the value is on the axis, from `0.0` at the minimum to `1.0` at the maximum.
+    pub fn normalize(&self, value: f64) -> f64 {
+        if self.max > self.min {
+            (value - self.min) / (self.max - self.min)
+        } else {
+            0.5
+        }
+    }
+
+    /// The value at a normalized position on the axis.
+    pub fn denormalize(&self, t: f64) -> f64 {
+        self.min + t * (self.max - self.min)
+    }
+}
+
+/// The values of all axes at one time point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Row {
+    pub time: i64,
+
+    /// One value per axis, `NaN` if the axis has no value at this time.
+    pub values: Vec<f64>,
+}
+
+/// Joins the `(time, value)` samples of each axis into one row per time point.
+pub fn join_rows(columns: Vec<(String, Vec<(i64, f64)>)>) -> (Vec<Axis>, Vec<Row>) {
+    re_tracing::profile_function!();
+
+    let num_axes = columns.len();
+    let mut rows: BTreeMap<i64, Vec<f64>> = BTreeMap::new();
+    let mut axes = Vec::with_capacity(num_axes);
+
+    for (axis_index, (name, samples)) in columns.into_iter().enumerate() {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for (time, value) in samples {
+            if value.is_finite() {
+                min = min.min(value);
+                max = max.max(value);
+            }
+            rows.entry(time).or_insert_with(|| vec![f64::NAN; num_axes])[axis_index] = value;
+        }
+        if min > max {
+            (min, max) = (0.0, 0.0);
+        }
+        axes.push(Axis { name, min, max });
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|(time, values)| Row { time, values })
+        .collect();
+    (axes, rows)
+}
+
+/// Whether the row lies within all brushes, keyed by axis name.
+///
+/// A row without a value on a brushed axis is outside of the brush.
+pub fn passes_brushes(axes: &[Axis], row: &Row, brushes: &BTreeMap<String, (f64, f64)>) -> bool {
+    axes.iter().zip(&row.values).all(|(axis, value)| {
+        brushes
+            .get(&axis.name)
+            .is_none_or(|(min, max)| (*min..=*max).contains(value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_and_brush() {
+        let (axes, rows) = join_rows(vec![
+            ("a".to_owned(), vec![(1, 1.0), (2, 2.0), (3, 3.0)]),
+            ("b".to_owned(), vec![(2, 20.0), (3, 10.0)]),
+        ]);
+
+        assert_eq!(axes[0].min, 1.0);
+        assert_eq!(axes[1].max, 20.0);
+        assert_eq!(axes[1].normalize(15.0), 0.5);
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].values[1].is_nan());
+        assert_eq!(rows[2].values, [3.0, 10.0]);
+
+        let mut brushes = BTreeMap::new();
+        assert!(rows.iter().all(|row| passes_brushes(&axes, row, &brushes)));
+
+        brushes.insert("b".to_owned(), (15.0, 25.0));
+        let passing: Vec<i64> = rows
+            .iter()
+            .filter(|row| passes_brushes(&axes, row, &brushes))
+            .map(|row| row.time)
+            .collect();
+        assert_eq!(passing, [2]);
+    }
+}
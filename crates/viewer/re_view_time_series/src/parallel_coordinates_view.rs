@@ -0,0 +1,492 @@
+//! A view of multi-dimensional scalars as parallel coordinates.
+//!
+//! Every scalar instance of every entity gets its own axis, and every time point is a line across all axes.
+//! Dragging along an axis brushes it, which highlights the lines passing through the brushed range.
+
+use std::collections::BTreeMap;
+
+use re_chunk_store::RangeQuery;
+use re_log_types::{AbsoluteTimeRangeF, EntityPath, TimeInt};
+use re_types::{Archetype as _, ViewClassIdentifier, archetypes};
+use re_ui::{Help, UiExt as _, icons};
+use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
+use re_viewer_context::{
+    IdentifiedViewSystem, IndicatedEntities, Item, MaybeVisualizableEntities, PerVisualizer,
+    ViewClass, ViewClassRegistryError, ViewContext, ViewQuery, ViewSpawnHeuristics, ViewState,
+    ViewStateExt as _, ViewSystemExecutionError, ViewerContext, VisualizableEntities,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::parallel_coordinates::{Axis, Row, join_rows, passes_brushes};
+use crate::util::{time_view_range, visible_time_view};
+
+/// Space for the axis labels above and below the axes, in ui points.
+const LABEL_MARGIN: f32 = 20.0;
+
+/// How close the pointer has to be to a line to hover it, in ui points.
+const HOVER_DISTANCE: f32 = 6.0;
+
+/// Joins the scalars of all entities of the view into rows, one per time point.
+#[derive(Default)]
+pub struct ParallelCoordinatesSystem {
+    pub axes: Vec<Axis>,
+    pub rows: Vec<Row>,
+}
+
+impl IdentifiedViewSystem for ParallelCoordinatesSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "ParallelCoordinates".into()
+    }
+}
+
+impl VisualizerSystem for ParallelCoordinatesSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<archetypes::Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context: &re_viewer_context::ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let Some(time_view) = visible_time_view(ctx.viewer_ctx) else {
+            return Ok(Vec::new());
+        };
+        let range_query = RangeQuery::new(query.timeline, time_view_range(&time_view));
+
+        let mut columns: Vec<(String, Vec<(i64, f64)>)> = Vec::new();
+        for data_result in query.iter_visible_data_results(Self::identifier()) {
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                archetypes::Scalars::all_components().iter(),
+            );
+            let Some(chunks) =
+                results.get_required_chunks(archetypes::Scalars::descriptor_scalars())
+            else {
+                continue;
+            };
+
+            let mut entity_columns: Vec<Vec<(i64, f64)>> = Vec::new();
+            for ((time, _), values) in chunks.iter().flat_map(|chunk| {
+                chunk
+                    .iter_component_indices(range_query.timeline())
+                    .zip(chunk.iter_slices::<f64>())
+            }) {
+                if entity_columns.len() < values.len() {
+                    entity_columns.resize_with(values.len(), Vec::new);
+                }
+                for (column, value) in entity_columns.iter_mut().zip(values) {
+                    column.push((time.as_i64(), *value));
+                }
+            }
+
+            let entity_path = &data_result.entity_path;
+            let num_instances = entity_columns.len();
+            columns.extend(
+                entity_columns
+                    .into_iter()
+                    .enumerate()
+                    .map(|(instance, samples)| {
+                        let name = if num_instances == 1 {
+                            entity_path.to_string()
+                        } else {
+                            format!("{entity_path}[{instance}]")
+                        };
+                        (name, samples)
+                    }),
+            );
+        }
+
+        (self.axes, self.rows) = join_rows(columns);
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(ParallelCoordinatesSystem => []);
+
+#[derive(Default)]
+pub struct ParallelCoordinatesViewState {
+    /// Brushed value range per axis name.
+    brushes: BTreeMap<String, (f64, f64)>,
+
+    /// The axis being brushed, and the normalized position the drag started at.
+    brush_drag_start: Option<(usize, f64)>,
+}
+
+impl ViewState for ParallelCoordinatesViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct ParallelCoordinatesView;
+
+impl ViewClass for ParallelCoordinatesView {
+    fn identifier() -> ViewClassIdentifier {
+        "ParallelCoordinates".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Parallel coordinates"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_TIMESERIES
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<ParallelCoordinatesViewState>::default()
+    }
+
+    fn help(&self, _os: egui::os::OperatingSystem) -> Help {
+        Help::new("Parallel coordinates view")
+            .markdown(
+                "Shows every scalar of the view on its own axis, and every time point as a line across all axes. \
+                Only the time range shown by the time panel is included.",
+            )
+            .control("Brush axis", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control("Clear brush", icons::LEFT_MOUSE_CLICK)
+            .control("Set time to line", icons::LEFT_MOUSE_CLICK)
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<ParallelCoordinatesSystem>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn choose_default_visualizers(
+        &self,
+        entity_path: &EntityPath,
+        _maybe_visualizable_entities_per_visualizer: &PerVisualizer<MaybeVisualizableEntities>,
+        visualizable_entities_per_visualizer: &PerVisualizer<VisualizableEntities>,
+        _indicated_entities_per_visualizer: &PerVisualizer<IndicatedEntities>,
+    ) -> re_viewer_context::SmallVisualizerSet {
+        // Scalars aren't indicated as parallel coordinates, so the default implementation would never pick it.
+        if visualizable_entities_per_visualizer
+            .get(&ParallelCoordinatesSystem::identifier())
+            .is_some_and(|entities| entities.contains(entity_path))
+        {
+            std::iter::once(ParallelCoordinatesSystem::identifier()).collect()
+        } else {
+            Default::default()
+        }
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Scalars are shown in time series views by default, parallel coordinates are added by hand.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<ParallelCoordinatesViewState>()?;
+        let ParallelCoordinatesSystem { axes, rows } = system_output
+            .view_systems
+            .get::<ParallelCoordinatesSystem>()?;
+
+        let response = ui.allocate_response(ui.available_size(), egui::Sense::click());
+        let rect = response
+            .rect
+            .shrink2(egui::vec2(2.0 * LABEL_MARGIN, LABEL_MARGIN));
+        if axes.is_empty() || rect.width() <= 0.0 || rect.height() <= 0.0 {
+            ctx.handle_select_hover_drag_interactions(&response, Item::View(query.view_id), false);
+            return Ok(());
+        }
+
+        // Brushes of axes that are gone would hide every line.
+        state
+            .brushes
+            .retain(|name, _| axes.iter().any(|axis| &axis.name == name));
+
+        let axis_x = |axis_index: usize| {
+            if axes.len() == 1 {
+                rect.center().x
+            } else {
+                rect.left() + axis_index as f32 / (axes.len() - 1) as f32 * rect.width()
+            }
+        };
+        let y_from_normalized = |t: f64| rect.bottom() - t as f32 * rect.height();
+        let normalized_from_y =
+            |y: f32| ((rect.bottom() - y) / rect.height()).clamp(0.0, 1.0) as f64;
+
+        // Brushing
+        for (axis_index, axis) in axes.iter().enumerate() {
+            let x = axis_x(axis_index);
+            let axis_rect = egui::Rect::from_x_y_ranges(
+                x - HOVER_DISTANCE..=x + HOVER_DISTANCE,
+                rect.y_range(),
+            );
+            let axis_response = ui
+                .interact(
+                    axis_rect,
+                    ui.id().with(("parallel_coordinates_axis", axis_index)),
+                    egui::Sense::click_and_drag(),
+                )
+                .on_hover_cursor(egui::CursorIcon::ResizeVertical);
+
+            if axis_response.drag_started()
+                && let Some(pos) = axis_response.interact_pointer_pos()
+            {
+                state.brush_drag_start = Some((axis_index, normalized_from_y(pos.y)));
+            }
+            if axis_response.dragged()
+                && let (Some((drag_axis, start)), Some(pos)) =
+                    (state.brush_drag_start, axis_response.interact_pointer_pos())
+                && drag_axis == axis_index
+            {
+                let end = normalized_from_y(pos.y);
+                let (low, high) = (start.min(end), start.max(end));
+                state.brushes.insert(
+                    axis.name.clone(),
+                    (axis.denormalize(low), axis.denormalize(high)),
+                );
+            }
+            if axis_response.drag_stopped() {
+                state.brush_drag_start = None;
+            }
+            if axis_response.clicked() {
+                state.brushes.remove(&axis.name);
+            }
+        }
+
+        let painter = ui.painter_at(response.rect);
+        let visuals = ui.visuals();
+        let current_time = ctx.rec_cfg.time_ctrl.read().time_i64();
+
+        let line_points = |row: &Row| -> Vec<Option<egui::Pos2>> {
+            axes.iter()
+                .zip(&row.values)
+                .enumerate()
+                .map(|(axis_index, (axis, value))| {
+                    value.is_finite().then(|| {
+                        egui::pos2(
+                            axis_x(axis_index),
+                            y_from_normalized(axis.normalize(*value)),
+                        )
+                    })
+                })
+                .collect()
+        };
+        let paint_line = |points: &[Option<egui::Pos2>], stroke: egui::Stroke| {
+            for segment in points.windows(2) {
+                if let [Some(a), Some(b)] = segment {
+                    painter.line_segment([*a, *b], stroke);
+                }
+            }
+        };
+
+        // Lines outside of the brushes first, so that the brushed ones are on top.
+        let mut brushed_rows = Vec::new();
+        let mut hovered_row: Option<(&Row, f32)> = None;
+        let dimmed_stroke = egui::Stroke::new(1.0, visuals.weak_text_color().gamma_multiply(0.3));
+        for row in rows {
+            let points = line_points(row);
+            if passes_brushes(axes, row, &state.brushes) {
+                brushed_rows.push((row, points));
+                continue;
+            }
+            paint_line(&points, dimmed_stroke);
+        }
+
+        let brushed_stroke = egui::Stroke::new(1.0, visuals.selection.bg_fill);
+        for (row, points) in &brushed_rows {
+            paint_line(points, brushed_stroke);
+
+            if let Some(pointer_pos) = response.hover_pos() {
+                let distance = points
+                    .windows(2)
+                    .filter_map(|segment| match segment {
+                        [Some(a), Some(b)] => Some(distance_to_segment(pointer_pos, *a, *b)),
+                        _ => None,
+                    })
+                    .fold(f32::INFINITY, f32::min);
+                if distance < HOVER_DISTANCE
+                    && hovered_row.is_none_or(|(_, closest)| distance < closest)
+                {
+                    hovered_row = Some((*row, distance));
+                }
+            }
+        }
+
+        // The current time and the hovered line stand out.
+        let highlight_stroke = egui::Stroke::new(2.0, visuals.strong_text_color());
+        if let Some((row, points)) = brushed_rows
+            .iter()
+            .find(|(row, _)| Some(row.time) == current_time)
+        {
+            paint_line(points, highlight_stroke);
+            painter.text(
+                egui::pos2(rect.right(), rect.top() - LABEL_MARGIN),
+                egui::Align2::RIGHT_TOP,
+                format_time(ctx, row.time),
+                egui::TextStyle::Small.resolve(ui.style()),
+                visuals.strong_text_color(),
+            );
+        }
+        if let Some((row, _)) = hovered_row {
+            paint_line(&line_points(row), highlight_stroke);
+        }
+
+        // Axes, brushes and labels.
+        let font_id = egui::TextStyle::Small.resolve(ui.style());
+        for (axis_index, axis) in axes.iter().enumerate() {
+            let x = axis_x(axis_index);
+            painter.vline(x, rect.y_range(), visuals.widgets.noninteractive.fg_stroke);
+
+            if let Some((min, max)) = state.brushes.get(&axis.name) {
+                let brush_rect = egui::Rect::from_x_y_ranges(
+                    x - 0.5 * HOVER_DISTANCE..=x + 0.5 * HOVER_DISTANCE,
+                    y_from_normalized(axis.normalize(*max))
+                        ..=y_from_normalized(axis.normalize(*min)),
+                );
+                painter.rect_filled(
+                    brush_rect,
+                    2.0,
+                    visuals.selection.bg_fill.gamma_multiply(0.5),
+                );
+            }
+
+            painter.text(
+                egui::pos2(x, rect.top() - 2.0),
+                egui::Align2::CENTER_BOTTOM,
+                &axis.name,
+                font_id.clone(),
+                visuals.text_color(),
+            );
+            painter.text(
+                egui::pos2(x, rect.top() + 2.0),
+                egui::Align2::LEFT_TOP,
+                re_format::format_f64(axis.max),
+                font_id.clone(),
+                visuals.weak_text_color(),
+            );
+            painter.text(
+                egui::pos2(x, rect.bottom() + 2.0),
+                egui::Align2::CENTER_TOP,
+                re_format::format_f64(axis.min),
+                font_id.clone(),
+                visuals.weak_text_color(),
+            );
+        }
+
+        // Linking lines to time points.
+        if let Some((row, _)) = hovered_row {
+            if response.clicked() {
+                let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+                time_ctrl.set_time(row.time);
+                time_ctrl.pause();
+            }
+            response.clone().on_hover_ui_at_pointer(|ui| {
+                ui.strong(format_time(ctx, row.time));
+                for (axis, value) in axes.iter().zip(&row.values) {
+                    ui.label(format!("{}: {}", axis.name, re_format::format_f64(*value)));
+                }
+            });
+        }
+
+        if !state.brushes.is_empty() {
+            egui::Area::new(ui.id().with("parallel_coordinates_brushes"))
+                .fixed_pos(response.rect.left_top() + egui::vec2(4.0, 4.0))
+                .show(ui.ctx(), |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "{} of {} time points brushed",
+                                re_format::format_uint(brushed_rows.len()),
+                                re_format::format_uint(rows.len())
+                            ));
+
+                            let brushed_time_range = brushed_rows
+                                .first()
+                                .zip(brushed_rows.last())
+                                .map(|((first, _), (last, _))| {
+                                    AbsoluteTimeRangeF::new(
+                                        TimeInt::new_temporal(first.time),
+                                        TimeInt::new_temporal(last.time),
+                                    )
+                                });
+                            if let Some(time_range) = brushed_time_range
+                                && ui
+                                    .button("Select in time panel")
+                                    .on_hover_text("Select the time range of the brushed lines")
+                                    .clicked()
+                            {
+                                ctx.rec_cfg.time_ctrl.write().set_loop_selection(time_range);
+                            }
+
+                            if ui
+                                .small_icon_button(&re_ui::icons::CLOSE, "Clear brushes")
+                                .clicked()
+                            {
+                                state.brushes.clear();
+                            }
+                        });
+                    });
+                });
+        }
+
+        ctx.handle_select_hover_drag_interactions(&response, Item::View(query.view_id), false);
+
+        Ok(())
+    }
+}
+
+fn format_time(ctx: &ViewerContext<'_>, time: i64) -> String {
+    let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+    time_type.format(
+        TimeInt::new_temporal(time),
+        ctx.app_options().timestamp_format,
+    )
+}
+
+fn distance_to_segment(pos: egui::Pos2, a: egui::Pos2, b: egui::Pos2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_sq() > 0.0 {
+        ((pos - a).dot(ab) / ab.length_sq()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    pos.distance(a + t * ab)
+}
@@ -3,7 +3,7 @@ use itertools::Itertools as _;
 use re_chunk_store::LatestAtQuery;
 use re_types::{
     Archetype as _, archetypes,
-    components::{Color, MarkerShape, MarkerSize, Name, SeriesVisible},
+    components::{Color, MarkerShape, MarkerSize, Name, SeriesSecondaryAxis, SeriesVisible},
 };
 use re_view::{
     clamped_or_nothing, latest_at_with_blueprint_resolved_data, range_with_blueprint_resolved_data,
@@ -16,9 +16,11 @@ use re_viewer_context::{
 
 use crate::{
     PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind, ScatterAttrs,
+    downsample::{PlotSeriesCache, ZoomBucket},
     series_query::{
         all_scalars_indices, allocate_plot_points, collect_colors, collect_radius_ui,
-        collect_scalars, collect_series_name, collect_series_visibility, determine_num_series,
+        collect_scalars, collect_series_name, collect_series_secondary_axis,
+        collect_series_visibility, determine_num_series,
     },
     util::{determine_time_per_pixel, determine_time_range, points_to_series},
     view_class::TimeSeriesViewState,
@@ -113,7 +115,13 @@ impl TypedComponentFallbackProvider<SeriesVisible> for SeriesPointsSystem {
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(SeriesPointsSystem => [Color, MarkerSize, Name, SeriesVisible]);
+impl TypedComponentFallbackProvider<SeriesSecondaryAxis> for SeriesPointsSystem {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> SeriesSecondaryAxis {
+        false.into()
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(SeriesPointsSystem => [Color, MarkerSize, Name, SeriesVisible, SeriesSecondaryAxis]);
 
 impl SeriesPointsSystem {
     fn load_scalars(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
@@ -185,8 +193,32 @@ impl SeriesPointsSystem {
             .view_state
             .downcast_ref::<TimeSeriesViewState>()
             .map_or(0, |state| state.time_offset);
-        let time_range =
-            determine_time_range(view_query.latest_at, time_offset, data_result, plot_mem);
+        // Load for the zoom bucket instead of the exact zoom, so that the result can be reused while panning and zooming.
+        let zoom_bucket = ZoomBucket::new(ctx, plot_mem, time_per_pixel);
+        let time_per_pixel = zoom_bucket.time_per_pixel;
+        let time_range = determine_time_range(
+            view_query.latest_at,
+            time_offset,
+            data_result,
+            plot_mem,
+            zoom_bucket.range_alignment(),
+        );
+        let cache_key = zoom_bucket.cache_key(
+            ctx,
+            Self::identifier(),
+            &data_result.entity_path,
+            time_range,
+        );
+        let cached_series = ctx
+            .viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut PlotSeriesCache| c.get(cache_key));
+        if let Some(cached_series) = cached_series {
+            all_series.extend(cached_series);
+            return;
+        }
+        let first_new_series = all_series.len();
 
         {
             use re_view::RangeResultsExt as _;
@@ -382,6 +414,13 @@ impl SeriesPointsSystem {
                 num_series,
                 archetypes::SeriesPoints::descriptor_visible_series(),
             );
+            let series_secondary_axis = collect_series_secondary_axis(
+                &query,
+                &bootstrapped_results,
+                &results,
+                num_series,
+                archetypes::SeriesPoints::descriptor_secondary_axis(),
+            );
             let series_names = collect_series_name(
                 self,
                 &query_ctx,
@@ -392,10 +431,11 @@ impl SeriesPointsSystem {
             );
 
             debug_assert_eq!(points_per_series.len(), series_names.len());
-            for (instance, (points, label, visible)) in itertools::izip!(
+            for (instance, (points, label, visible, secondary_axis)) in itertools::izip!(
                 points_per_series.into_iter(),
                 series_names.into_iter(),
-                series_visibility.into_iter()
+                series_visibility.into_iter(),
+                series_secondary_axis.into_iter()
             )
             .enumerate()
             {
@@ -411,7 +451,9 @@ impl SeriesPointsSystem {
                 points_to_series(
                     instance_path,
                     time_per_pixel,
+                    zoom_bucket.max_points,
                     visible,
+                    secondary_axis,
                     points,
                     ctx.recording_engine().store(),
                     view_query,
@@ -422,5 +464,11 @@ impl SeriesPointsSystem {
                 );
             }
         }
+
+        let new_series = all_series[first_new_series..].to_vec();
+        ctx.viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut PlotSeriesCache| c.insert(cache_key, new_series));
     }
 }
@@ -35,24 +35,62 @@ pub fn collect_series_visibility(
     results: &HybridRangeResults<'_>,
     num_series: usize,
     visibility_descriptor: ComponentDescriptor,
+) -> Vec<bool> {
+    // By default all series are visible.
+    collect_series_flags(
+        query,
+        bootstrapped_results,
+        results,
+        num_series,
+        visibility_descriptor,
+        true,
+    )
+}
+
+/// Collects for each series whether it is plotted against the secondary Y axis.
+pub fn collect_series_secondary_axis(
+    query: &RangeQuery,
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &HybridRangeResults<'_>,
+    num_series: usize,
+    secondary_axis_descriptor: ComponentDescriptor,
+) -> Vec<bool> {
+    // By default all series are on the primary axis.
+    collect_series_flags(
+        query,
+        bootstrapped_results,
+        results,
+        num_series,
+        secondary_axis_descriptor,
+        false,
+    )
+}
+
+fn collect_series_flags(
+    query: &RangeQuery,
+    bootstrapped_results: &re_view::HybridLatestAtResults<'_>,
+    results: &HybridRangeResults<'_>,
+    num_series: usize,
+    descriptor: ComponentDescriptor,
+    default: bool,
 ) -> Vec<bool> {
     bootstrapped_results
-        .iter_as(*query.timeline(), visibility_descriptor.clone())
+        .iter_as(*query.timeline(), descriptor.clone())
         .slice::<bool>()
         .chain(
             results
-                .iter_as(*query.timeline(), visibility_descriptor)
+                .iter_as(*query.timeline(), descriptor)
                 .slice::<bool>(),
         )
         .next()
         .map_or_else(
-            || vec![true; num_series], // By default all series are visible.
-            |(_, visible)| {
-                let mut flags = visible.iter().collect_vec();
+            || vec![default; num_series],
+            |(_, values)| {
+                let mut flags = values.iter().collect_vec();
                 if flags.len() < num_series {
-                    // If there are less flags than series, repeat the last flag (or true if there are no flags).
+                    // If there are less flags than series, repeat the last flag (or the default if there are no flags).
                     flags.extend(std::iter::repeat_n(
-                        *flags.last().unwrap_or(&true),
+                        *flags.last().unwrap_or(&default),
                         num_series - flags.len(),
                     ));
                 }
@@ -0,0 +1,134 @@
+//! Stacking series on top of each other, to show their composition over time.
+//!
+//! Series rarely share their time points, so their values are averaged per time bucket first.
+
+/// Buckets are roughly this many physical pixels wide.
+const BUCKET_WIDTH_PIXELS: f64 = 16.0;
+
+/// The width of the time buckets, in time units.
+///
+/// Rounded to a power of two, so that the buckets don't jump around while zooming by small amounts.
+pub fn bucket_width(time_per_pixel: f64) -> i64 {
+    ((time_per_pixel * BUCKET_WIDTH_PIXELS).max(1.0) as u64).next_power_of_two() as i64
+}
+
+/// The values of several series, averaged per time bucket.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackedBuckets {
+    /// Width of every bucket, in time units.
+    pub bucket_width: i64,
+
+    /// Start time of every bucket that any of the series has a value in, in ascending order.
+    pub bucket_starts: Vec<i64>,
+
+    /// Per series, the average of its values in each bucket, `None` if it has none.
+    pub averages: Vec<Vec<Option<f64>>>,
+}
+
+impl StackedBuckets {
+    /// Averages the `(time, value)` points of each series per bucket.
+    ///
+    /// Buckets are aligned to multiples of their width, and non-finite values are ignored.
+    pub fn new<'a>(
+        all_points: impl Iterator<Item = &'a [(i64, f64)]> + Clone,
+        bucket_width: i64,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let bucket_width = bucket_width.max(1);
+        let bucket_start = |time: i64| time.div_euclid(bucket_width) * bucket_width;
+
+        let mut bucket_starts: Vec<i64> = all_points
+            .clone()
+            .flatten()
+            .filter(|(_, value)| value.is_finite())
+            .map(|(time, _)| bucket_start(*time))
+            .collect();
+        bucket_starts.sort_unstable();
+        bucket_starts.dedup();
+
+        let averages = all_points
+            .map(|points| {
+                let mut sums = vec![(0.0, 0_u32); bucket_starts.len()];
+                for (time, value) in points.iter().filter(|(_, value)| value.is_finite()) {
+                    if let Ok(bucket) = bucket_starts.binary_search(&bucket_start(*time)) {
+                        sums[bucket].0 += value;
+                        sums[bucket].1 += 1;
+                    }
+                }
+                sums.into_iter()
+                    .map(|(sum, count)| (count > 0).then(|| sum / count as f64))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            bucket_width,
+            bucket_starts,
+            averages,
+        }
+    }
+
+    /// The time in the middle of a bucket.
+    pub fn bucket_center(&self, bucket: usize) -> f64 {
+        self.bucket_starts[bucket] as f64 + 0.5 * self.bucket_width as f64
+    }
+
+    /// Per series, the `(bottom, top)` of its layer in each bucket.
+    ///
+    /// The series are stacked in order, starting at zero.
+    /// A series without a value in a bucket has a layer of zero thickness there.
+    pub fn stacked(&self) -> Vec<Vec<(f64, f64)>> {
+        let mut bottoms = vec![0.0; self.bucket_starts.len()];
+        self.averages
+            .iter()
+            .map(|averages| {
+                averages
+                    .iter()
+                    .zip(&mut bottoms)
+                    .map(|(average, bottom)| {
+                        let layer = (*bottom, *bottom + average.unwrap_or(0.0));
+                        *bottom = layer.1;
+                        layer
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_and_stack() {
+        let cpu = vec![(0, 1.0), (5, 3.0), (10, 4.0), (25, f64::NAN)];
+        let gpu = vec![(-3, 2.0), (12, 1.0), (14, 3.0)];
+        let buckets = StackedBuckets::new([cpu.as_slice(), gpu.as_slice()].into_iter(), 10);
+
+        assert_eq!(buckets.bucket_starts, [-10, 0, 10]);
+        assert_eq!(buckets.bucket_center(1), 5.0);
+        assert_eq!(
+            buckets.averages,
+            [
+                vec![None, Some(2.0), Some(4.0)],
+                vec![Some(2.0), None, Some(2.0)]
+            ]
+        );
+        assert_eq!(
+            buckets.stacked(),
+            [
+                vec![(0.0, 0.0), (0.0, 2.0), (0.0, 4.0)],
+                vec![(0.0, 2.0), (2.0, 2.0), (4.0, 6.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn bucket_width_is_a_power_of_two() {
+        assert_eq!(bucket_width(0.01), 1);
+        assert_eq!(bucket_width(1.0), 16);
+        assert_eq!(bucket_width(1.1), 32);
+    }
+}
@@ -1,15 +1,42 @@
-use re_log_types::AbsoluteTimeRange;
+use re_log_types::{AbsoluteTimeRange, TimeReal};
 use re_types::{
     components::AggregationPolicy,
     datatypes::{TimeRange, TimeRangeBoundary},
 };
-use re_viewer_context::{ViewQuery, ViewerContext, external::re_entity_db::InstancePath};
+use re_viewer_context::{TimeView, ViewQuery, ViewerContext, external::re_entity_db::InstancePath};
 
 use crate::{
     PlotPoint, PlotSeries, PlotSeriesKind, ScatterAttrs,
     aggregation::{AverageAggregator, MinMaxAggregator},
+    downsample::downsample,
 };
 
+/// The time range shown by the time panel, or the whole timeline if it isn't zoomed.
+pub fn visible_time_view(ctx: &ViewerContext<'_>) -> Option<TimeView> {
+    let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+    time_ctrl.time_view().or_else(|| {
+        ctx.recording()
+            .time_range_for(time_ctrl.timeline().name())
+            .map(TimeView::from)
+    })
+}
+
+/// The smallest integer time range covering the time view.
+pub fn time_view_range(time_view: &TimeView) -> AbsoluteTimeRange {
+    AbsoluteTimeRange::new(
+        time_view.min.floor(),
+        (time_view.min + TimeReal::from(time_view.time_spanned)).ceil(),
+    )
+}
+
+/// The time range selected in the time panel, or the one it shows if nothing is selected.
+pub fn selected_time_range(ctx: &ViewerContext<'_>) -> Option<AbsoluteTimeRange> {
+    let loop_selection = ctx.rec_cfg.time_ctrl.read().loop_selection();
+    loop_selection
+        .map(|selection| AbsoluteTimeRange::new(selection.min.floor(), selection.max.ceil()))
+        .or_else(|| visible_time_view(ctx).map(|time_view| time_view_range(&time_view)))
+}
+
 /// Find the number of time units per physical pixel.
 pub fn determine_time_per_pixel(
     ctx: &ViewerContext<'_>,
@@ -32,6 +59,7 @@ pub fn determine_time_range(
     time_offset: i64,
     data_result: &re_viewer_context::DataResult,
     plot_mem: Option<&egui_plot::PlotMemory>,
+    alignment: i64,
 ) -> AbsoluteTimeRange {
     let query_range = data_result.query_range();
 
@@ -56,8 +84,12 @@ pub fn determine_time_range(
     let is_auto_bounds = plot_mem.is_some_and(|mem| mem.auto_bounds.x || mem.auto_bounds.y);
     let plot_bounds = plot_mem.map(|mem| {
         let bounds = mem.bounds().range_x();
-        let x_min = bounds.start().floor() as i64;
-        let x_max = bounds.end().ceil() as i64;
+        // Widened to the alignment, so that the range stays the same while panning by a few pixels.
+        let x_min = (bounds.start().floor() as i64)
+            .div_euclid(alignment)
+            .saturating_mul(alignment);
+        let x_max =
+            ((bounds.end().ceil() as i64).div_euclid(alignment) + 1).saturating_mul(alignment);
         // We offset the time values of the plot so that unix timestamps don't run out of precision.
         (
             x_min.saturating_add(time_offset),
@@ -82,7 +114,9 @@ pub fn determine_time_range(
 pub fn points_to_series(
     instance_path: InstancePath,
     time_per_pixel: f64,
+    max_points: usize,
     visible: bool,
+    secondary_axis: bool,
     points: Vec<PlotPoint>,
     store: &re_chunk_store::ChunkStore,
     query: &ViewQuery<'_>,
@@ -96,6 +130,12 @@ pub fn points_to_series(
     }
 
     let (aggregation_factor, points) = apply_aggregation(aggregator, time_per_pixel, points, query);
+
+    // Aggregation bounds the number of points per pixel, but not in total, and is optional.
+    let num_aggregated_points = points.len();
+    let points = downsample(points, max_points);
+    let aggregation_factor =
+        aggregation_factor * num_aggregated_points as f64 / points.len() as f64;
     let min_time = store
         .entity_min_time(&query.timeline, &instance_path.entity_path)
         .map_or(points.first().map_or(0, |p| p.time), |time| time.as_i64());
@@ -109,6 +149,7 @@ pub fn points_to_series(
 
         all_series.push(PlotSeries {
             visible,
+            secondary_axis,
             id: egui::Id::new(&instance_path),
             label: series_label,
             color: points[0].attrs.color,
@@ -123,6 +164,7 @@ pub fn points_to_series(
     } else {
         add_series_runs(
             visible,
+            secondary_axis,
             series_label,
             points,
             instance_path,
@@ -205,6 +247,7 @@ pub fn apply_aggregation(
 #[inline(never)] // Better callstacks on crashes
 fn add_series_runs(
     visible: bool,
+    secondary_axis: bool,
     series_label: String,
     points: Vec<PlotPoint>,
     instance_path: InstancePath,
@@ -221,6 +264,7 @@ fn add_series_runs(
     let mut attrs = points[0].attrs.clone();
     let mut series: PlotSeries = PlotSeries {
         visible,
+        secondary_axis,
         id,
         label: series_label.clone(),
         color: attrs.color,
@@ -247,6 +291,7 @@ fn add_series_runs(
                 &mut series,
                 PlotSeries {
                     visible,
+                    secondary_axis,
                     id,
                     label: series_label.clone(),
                     color: attrs.color,
@@ -1,5 +1,7 @@
 use egui::ahash::{HashMap, HashSet};
-use egui_plot::{ColorConflictHandling, Legend, Line, Plot, PlotPoint, Points};
+use egui_plot::{
+    Bar, BarChart, ColorConflictHandling, Legend, Line, Plot, PlotPoint, Points, Polygon,
+};
 use nohash_hasher::IntSet;
 use smallvec::SmallVec;
 
@@ -10,8 +12,8 @@ use re_types::{
     ComponentBatch as _, View as _, ViewClassIdentifier,
     archetypes::{SeriesLines, SeriesPoints},
     blueprint::{
-        archetypes::{PlotLegend, ScalarAxis, TimeAxis},
-        components::{Corner2D, LinkAxis, LockRangeDuringZoom},
+        archetypes::{PlotLegend, PlotStyle, ScalarAxis, TimeAxis},
+        components::{Corner2D, Enabled, LinkAxis, LockRangeDuringZoom, PlotMode},
     },
     components::{AggregationPolicy, Range1D, SeriesVisible, Visible},
     datatypes::TimeRange,
@@ -32,8 +34,12 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewProperty;
 
 use crate::{
-    PlotSeriesKind, line_visualizer_system::SeriesLinesSystem,
+    PlotSeriesKind,
+    line_visualizer_system::SeriesLinesSystem,
     point_visualizer_system::SeriesPointsSystem,
+    stacking::{StackedBuckets, bucket_width},
+    util::determine_time_per_pixel,
+    y_axis::{YAxisTransform, value_range},
 };
 
 // ---
@@ -210,6 +216,7 @@ impl ViewClass for TimeSeriesView {
         list_item::list_item_scope(ui, "time_series_selection_ui", |ui| {
             let ctx = self.view_context(ctx, view_id, state);
             view_property_ui::<PlotLegend>(&ctx, ui, self);
+            view_property_ui::<PlotStyle>(&ctx, ui, self);
             view_property_ui::<TimeAxis>(&ctx, ui, self);
             view_property_ui::<ScalarAxis>(&ctx, ui, self);
         });
@@ -369,6 +376,16 @@ impl ViewClass for TimeSeriesView {
             &PlotLegend::descriptor_corner(),
         )?;
 
+        let plot_style =
+            ViewProperty::from_archetype::<PlotStyle>(blueprint_db, ctx.blueprint_query, view_id);
+        let plot_mode = plot_style.component_or_fallback::<PlotMode>(
+            &view_ctx,
+            self,
+            &PlotStyle::descriptor_mode(),
+        )?;
+        // Stacks are drawn on a single linear axis, since their layers add up.
+        let is_stacked = plot_mode != PlotMode::Lines;
+
         let time_axis =
             ViewProperty::from_archetype::<TimeAxis>(blueprint_db, ctx.blueprint_query, view_id);
         let link_x_axis = time_axis.component_or_fallback::<LinkAxis>(
@@ -393,6 +410,17 @@ impl ViewClass for TimeSeriesView {
         )?;
         let y_zoom_lock = y_zoom_lock.0.0;
 
+        let y_log_scale: bool = scalar_axis
+            .component_or_fallback::<Enabled>(&view_ctx, self, &ScalarAxis::descriptor_log_scale())?
+            .into();
+        let secondary_log_scale: bool = scalar_axis
+            .component_or_fallback::<Enabled>(
+                &view_ctx,
+                self,
+                &ScalarAxis::descriptor_secondary_log_scale(),
+            )?
+            .into();
+
         let (current_time, time_type, timeline) = {
             // Avoid holding the lock for long
             let time_ctrl = ctx.rec_cfg.time_ctrl.read();
@@ -419,6 +447,26 @@ impl ViewClass for TimeSeriesView {
             .map(|series| (series.id, series.instance_path.clone()))
             .collect();
 
+        // Series on the secondary axis are stretched over the range of the primary axis,
+        // which makes the axes independent of each other without `egui_plot` knowing about it.
+        let primary_axis = YAxisTransform::new(y_log_scale && !is_stacked);
+        let secondary_axis = YAxisTransform::fitted(
+            secondary_log_scale,
+            visible_values(&all_plot_series, true),
+            value_range(
+                visible_values(&all_plot_series, false).filter_map(|v| primary_axis.to_plot(v)),
+            ),
+        );
+        let has_secondary_axis =
+            !is_stacked && all_plot_series.iter().any(|series| series.secondary_axis);
+        let secondary_axis_labels: HashSet<String> = all_plot_series
+            .iter()
+            .filter(|series| has_secondary_axis && series.secondary_axis)
+            .map(|series| series.label.clone())
+            .collect();
+
+        let plot_y_range = make_range_sane(primary_axis.range_to_plot(y_range));
+
         // Get the minimum time/X value for the entire plot…
         let min_time = all_plot_series
             .iter()
@@ -466,8 +514,27 @@ impl ViewClass for TimeSeriesView {
 
         set_plot_visibility_from_store(ui.ctx(), &all_plot_series, plot_id);
 
+        let bucket_width = bucket_width(determine_time_per_pixel(
+            ctx,
+            egui_plot::PlotMemory::load(ui.ctx(), plot_id).as_ref(),
+        ));
+
         let min_axis_thickness = ui.tokens().small_icon_size.y;
 
+        let mut y_axes = vec![
+            egui_plot::AxisHints::new_y()
+                .min_thickness(min_axis_thickness)
+                .formatter(move |mark, _| primary_axis.format_grid_mark(mark)),
+        ];
+        if has_secondary_axis {
+            y_axes.push(
+                egui_plot::AxisHints::new_y()
+                    .placement(egui_plot::HPlacement::Right)
+                    .min_thickness(min_axis_thickness)
+                    .formatter(move |mark, _| secondary_axis.format_grid_mark(mark)),
+            );
+        }
+
         let mut plot = Plot::new(plot_id_src)
             .id(plot_id)
             .auto_bounds(state.saved_auto_bounds) // Note that this only sets the initial default.
@@ -483,11 +550,7 @@ impl ViewClass for TimeSeriesView {
                         .format_compact(timestamp_format)
                     }),
             ])
-            .custom_y_axes(vec![
-                egui_plot::AxisHints::new_y()
-                    .min_thickness(min_axis_thickness)
-                    .formatter(move |mark, _| format_y_axis(mark)),
-            ])
+            .custom_y_axes(y_axes)
             .label_formatter(move |name, value| {
                 let name = if name.is_empty() { "y" } else { name };
                 let label = time_type.format(
@@ -495,7 +558,12 @@ impl ViewClass for TimeSeriesView {
                     timestamp_format,
                 );
 
-                let y_value = re_format::format_f64(value.y);
+                let y_axis = if secondary_axis_labels.contains(name) {
+                    secondary_axis
+                } else {
+                    primary_axis
+                };
+                let y_value = re_format::format_f64(y_axis.from_plot(value.y));
 
                 if aggregator == AggregationPolicy::Off || aggregation_factor <= 1.0 {
                     format!("{timeline_name}: {label}\n{name}: {y_value}")
@@ -556,7 +624,7 @@ impl ViewClass for TimeSeriesView {
             plot_double_clicked = plot_ui.response().double_clicked();
 
             // Let the user pick y_range from the blueprint:
-            plot_ui.set_plot_bounds_y(y_range);
+            plot_ui.set_plot_bounds_y(plot_y_range);
 
             // Needed by for the visualizers' fallback provider.
             state.default_names_for_entities = EntityPath::short_names_with_disambiguation(
@@ -588,13 +656,26 @@ impl ViewClass for TimeSeriesView {
             state.reset_bounds_next_frame = false;
             state.was_dragging_time_cursor = state.is_dragging_time_cursor;
 
-            add_series_to_plot(
-                plot_ui,
-                &query.highlights,
-                &all_plot_series,
-                time_offset,
-                &mut state.scalar_range,
-            );
+            if is_stacked {
+                add_stacked_series_to_plot(
+                    plot_ui,
+                    &query.highlights,
+                    &all_plot_series,
+                    time_offset,
+                    plot_mode,
+                    bucket_width,
+                    &mut state.scalar_range,
+                );
+            } else {
+                add_series_to_plot(
+                    plot_ui,
+                    &query.highlights,
+                    &all_plot_series,
+                    time_offset,
+                    (primary_axis, secondary_axis),
+                    &mut state.scalar_range,
+                );
+            }
         });
 
         // Interact with the plot items (lines, scatters, etc.)
@@ -617,16 +698,18 @@ impl ViewClass for TimeSeriesView {
         let is_resetting = plot_double_clicked && hovered_data_result.is_none();
 
         // Write new y_range if it has changed.
-        let new_y_range = Range1D::new(transform.bounds().min()[1], transform.bounds().max()[1]);
+        let new_plot_y_range =
+            Range1D::new(transform.bounds().min()[1], transform.bounds().max()[1]);
         if is_resetting {
             scalar_axis.reset_blueprint_component(ctx, ScalarAxis::descriptor_range());
             state.reset_bounds_next_frame = true;
             ui.ctx().request_repaint(); // Make sure we get another frame with the reset actually applied.
-        } else if new_y_range != y_range {
+        } else if new_plot_y_range != plot_y_range {
+            // Compared in plot coordinates, since converting from a logarithmic scale and back isn't exact.
             scalar_axis.save_blueprint_component(
                 ctx,
                 &ScalarAxis::descriptor_range(),
-                &new_y_range,
+                &primary_axis.range_from_plot(new_plot_y_range),
             );
             ui.ctx().request_repaint(); // Make sure we get another frame with this new range applied.
         }
@@ -784,27 +867,37 @@ fn add_series_to_plot(
     highlights: &ViewHighlights,
     all_plot_series: &[&crate::PlotSeries],
     time_offset: i64,
+    (primary_axis, secondary_axis): (YAxisTransform, YAxisTransform),
     scalar_range: &mut Range1D,
 ) {
     re_tracing::profile_function!();
 
+    // In plot coordinates, converted to values of the primary axis at the end.
     *scalar_range.start_mut() = f64::INFINITY;
     *scalar_range.end_mut() = f64::NEG_INFINITY;
 
     for series in all_plot_series {
+        let y_axis = if series.secondary_axis {
+            secondary_axis
+        } else {
+            primary_axis
+        };
+
         let points = if series.visible {
             series
                 .points
                 .iter()
-                .map(|p| {
-                    if p.1 < scalar_range.start() {
-                        *scalar_range.start_mut() = p.1;
+                .filter_map(|p| {
+                    // Values that can't be shown on a logarithmic scale are left out.
+                    let y = y_axis.to_plot(p.1)?;
+                    if y < scalar_range.start() {
+                        *scalar_range.start_mut() = y;
                     }
-                    if p.1 > scalar_range.end() {
-                        *scalar_range.end_mut() = p.1;
+                    if y > scalar_range.end() {
+                        *scalar_range.end_mut() = y;
                     }
 
-                    [(p.0 - time_offset) as _, p.1]
+                    Some([(p.0 - time_offset) as _, y])
                 })
                 .collect::<Vec<_>>()
         } else {
@@ -814,7 +907,12 @@ fn add_series_to_plot(
             series
                 .points
                 .first()
-                .map(|p| vec![[(p.0 - time_offset) as _, p.1]])
+                .map(|p| {
+                    vec![[
+                        (p.0 - time_offset) as _,
+                        y_axis.to_plot(p.1).unwrap_or_default(),
+                    ]]
+                })
                 .unwrap_or_default()
         };
 
@@ -845,15 +943,177 @@ fn add_series_to_plot(
             PlotSeriesKind::Clear => {}
         }
     }
+
+    if scalar_range.start() <= scalar_range.end() {
+        *scalar_range = primary_axis.range_from_plot(*scalar_range);
+    }
 }
 
-fn format_y_axis(mark: egui_plot::GridMark) -> String {
-    // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
-    let num_decimals = -mark.step_size.log10().round() as usize;
+/// Draws the visible series as stacked areas or as bars, averaged per time bucket.
+///
+/// Series on the secondary axis are stacked like all others.
+fn add_stacked_series_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    highlights: &ViewHighlights,
+    all_plot_series: &[&crate::PlotSeries],
+    time_offset: i64,
+    plot_mode: PlotMode,
+    bucket_width: i64,
+    scalar_range: &mut Range1D,
+) {
+    re_tracing::profile_function!();
+
+    // The runs between clears are separate plot series, but belong to the same layer.
+    let mut layers: Vec<(&crate::PlotSeries, Vec<(i64, f64)>)> = Vec::new();
+    for series in all_plot_series {
+        if !series.visible {
+            // Hidden series still need an item, so that they show up in the legend.
+            if let Some((time, _)) = series.points.first() {
+                plot_ui.line(
+                    Line::new(&series.label, vec![[(time - time_offset) as f64, 0.0]])
+                        .color(series.color)
+                        .id(series.id),
+                );
+            }
+        } else if let Some((_, points)) = layers.iter_mut().find(|(first, _)| first.id == series.id)
+        {
+            points.extend_from_slice(&series.points);
+        } else {
+            layers.push((*series, series.points.clone()));
+        }
+    }
 
-    re_format::FloatFormatOptions::DEFAULT_f64
-        .with_decimals(num_decimals)
-        .format(mark.value)
+    let buckets = StackedBuckets::new(
+        layers.iter().map(|(_, points)| points.as_slice()),
+        bucket_width,
+    );
+    let stacked = buckets.stacked();
+    let x = |bucket: usize| buckets.bucket_center(bucket) - time_offset as f64;
+
+    // Stacks and bars always start at zero.
+    *scalar_range = Range1D::new(0.0, 0.0);
+    let mut include_in_range = |value: f64| {
+        *scalar_range.start_mut() = scalar_range.start().min(value);
+        *scalar_range.end_mut() = scalar_range.end().max(value);
+    };
+    if plot_mode == PlotMode::GroupedBars {
+        buckets
+            .averages
+            .iter()
+            .flatten()
+            .flatten()
+            .for_each(|average| include_in_range(*average));
+    } else {
+        for (bottom, top) in stacked.iter().flatten() {
+            include_in_range(*bottom);
+            include_in_range(*top);
+        }
+    }
+
+    let num_layers = layers.len();
+    for (layer_index, ((series, _), (layer, averages))) in layers
+        .iter()
+        .zip(stacked.iter().zip(&buckets.averages))
+        .enumerate()
+    {
+        let highlight = highlights
+            .entity_highlight(series.instance_path.entity_path.hash())
+            .index_highlight(series.instance_path.instance)
+            .any();
+        let fill = series.color.gamma_multiply(0.5);
+
+        match plot_mode {
+            // Drawn by `add_series_to_plot` instead.
+            PlotMode::Lines => {}
+
+            PlotMode::StackedArea => {
+                // Each segment is its own polygon, since egui can only fill convex shapes.
+                for (bucket, pair) in layer.windows(2).enumerate() {
+                    let [(bottom_start, top_start), (bottom_end, top_end)] = [pair[0], pair[1]];
+                    plot_ui.polygon(
+                        Polygon::new(
+                            "",
+                            vec![
+                                [x(bucket), bottom_start],
+                                [x(bucket + 1), bottom_end],
+                                [x(bucket + 1), top_end],
+                                [x(bucket), top_start],
+                            ],
+                        )
+                        .fill_color(fill)
+                        .stroke(egui::Stroke::NONE)
+                        .allow_hover(false),
+                    );
+                }
+                plot_ui.line(
+                    Line::new(
+                        &series.label,
+                        layer
+                            .iter()
+                            .enumerate()
+                            .map(|(bucket, (_, top))| [x(bucket), *top])
+                            .collect::<Vec<_>>(),
+                    )
+                    .color(series.color)
+                    .width(2.0 * series.radius_ui)
+                    .highlight(highlight)
+                    .id(series.id),
+                );
+            }
+
+            PlotMode::GroupedBars | PlotMode::StackedBars => {
+                // Leave some space between the buckets.
+                let group_width = 0.8 * buckets.bucket_width as f64;
+                let (bar_width, bar_offset) = if plot_mode == PlotMode::GroupedBars {
+                    let bar_width = group_width / num_layers as f64;
+                    (
+                        bar_width,
+                        (layer_index as f64 + 0.5) * bar_width - 0.5 * group_width,
+                    )
+                } else {
+                    (group_width, 0.0)
+                };
+
+                let bars = layer
+                    .iter()
+                    .zip(averages)
+                    .enumerate()
+                    .filter_map(|(bucket, ((bottom, _), average))| {
+                        let average = (*average)?;
+                        let base = if plot_mode == PlotMode::StackedBars {
+                            *bottom
+                        } else {
+                            0.0
+                        };
+                        Some(
+                            Bar::new(x(bucket) + bar_offset, average)
+                                .width(bar_width)
+                                .base_offset(base)
+                                .fill(fill)
+                                .stroke((1.0, series.color)),
+                        )
+                    })
+                    .collect();
+                plot_ui.bar_chart(
+                    BarChart::new(&series.label, bars)
+                        .color(series.color)
+                        .highlight(highlight)
+                        .id(series.id),
+                );
+            }
+        }
+    }
+}
+
+/// The values of all visible series on either the primary or the secondary axis.
+fn visible_values<'a>(
+    all_plot_series: &'a [&crate::PlotSeries],
+    secondary_axis: bool,
+) -> impl Iterator<Item = f64> + 'a {
+    all_plot_series
+        .iter()
+        .filter(move |series| series.visible && series.secondary_axis == secondary_axis)
+        .flat_map(|series| series.points.iter().map(|(_, value)| *value))
 }
 
 fn nanos_grid_spacer(
@@ -0,0 +1,197 @@
+//! Envelopes and spectrograms of densely sampled signals, e.g. audio.
+
+/// The lowest and highest value of the samples falling into each column of a waveform.
+///
+/// `times` and `values` are the samples, `time_range` is the time covered by all `num_columns`.
+/// Columns without any samples are `None`.
+pub fn envelope(
+    times: &[i64],
+    values: &[f32],
+    time_range: (f64, f64),
+    num_columns: usize,
+) -> Vec<Option<(f32, f32)>> {
+    re_tracing::profile_function!();
+
+    let mut columns = vec![None; num_columns];
+    let (min_time, max_time) = time_range;
+    if num_columns == 0 || max_time <= min_time {
+        return columns;
+    }
+
+    let columns_per_time = num_columns as f64 / (max_time - min_time);
+    for (&time, &value) in times.iter().zip(values) {
+        if !value.is_finite() {
+            continue;
+        }
+        let column = ((time as f64 - min_time) * columns_per_time).floor();
+        if column < 0.0 || column >= num_columns as f64 {
+            continue;
+        }
+        let (min, max) = columns[column as usize].get_or_insert((value, value));
+        *min = min.min(value);
+        *max = max.max(value);
+    }
+
+    columns
+}
+
+/// Magnitudes of the frequencies of a signal over time.
+#[derive(Clone, Debug, Default)]
+pub struct Spectrogram {
+    /// Number of frequency bins per frame, from zero to the Nyquist frequency.
+    pub num_bins: usize,
+
+    /// Index of the first sample of each frame.
+    pub frame_starts: Vec<usize>,
+
+    /// `num_bins` values per frame, from `0.0` (quiet) to `1.0` (the loudest bin of the spectrogram).
+    pub magnitudes: Vec<f32>,
+}
+
+/// Quieter bins than this, relative to the loudest one, are all shown as silence.
+const DYNAMIC_RANGE_DB: f32 = 80.0;
+
+/// Short-time Fourier transform of `samples`, with Hann windows of `window_size` samples.
+///
+/// `window_size` is rounded up to a power of two. Frames overlap by half a window,
+/// unless that would result in more than `max_frames`, in which case they are spread out.
+pub fn spectrogram(samples: &[f32], window_size: usize, max_frames: usize) -> Spectrogram {
+    re_tracing::profile_function!();
+
+    let window_size = window_size.max(2).next_power_of_two();
+    if samples.len() < window_size || max_frames == 0 {
+        return Spectrogram::default();
+    }
+
+    let num_bins = window_size / 2;
+    let max_start = samples.len() - window_size;
+    let hop = (window_size / 2).max(max_start.div_ceil(max_frames));
+    let frame_starts: Vec<usize> = (0..=max_start).step_by(hop).collect();
+
+    let window: Vec<f32> = (0..window_size)
+        .map(|i| {
+            let phase = std::f32::consts::TAU * i as f32 / window_size as f32;
+            0.5 - 0.5 * phase.cos()
+        })
+        .collect();
+
+    let mut re = vec![0.0; window_size];
+    let mut im = vec![0.0; window_size];
+    let mut decibels = Vec::with_capacity(frame_starts.len() * num_bins);
+    for &start in &frame_starts {
+        for (i, (re, im)) in re.iter_mut().zip(&mut im).enumerate() {
+            let sample = samples[start + i];
+            // Missing samples are silent.
+            *re = if sample.is_finite() {
+                sample * window[i]
+            } else {
+                0.0
+            };
+            *im = 0.0;
+        }
+        fft(&mut re, &mut im);
+        decibels.extend(
+            re.iter()
+                .zip(&im)
+                .take(num_bins)
+                .map(|(re, im)| 10.0 * (re * re + im * im).max(1e-20).log10()),
+        );
+    }
+
+    let loudest = decibels.iter().copied().fold(f32::MIN, f32::max);
+    let magnitudes = decibels
+        .into_iter()
+        .map(|db| (1.0 - (loudest - db) / DYNAMIC_RANGE_DB).clamp(0.0, 1.0))
+        .collect();
+
+    Spectrogram {
+        num_bins,
+        frame_starts,
+        magnitudes,
+    }
+}
+
+/// In-place radix-2 fast Fourier transform. The length must be a power of two.
+fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two() && im.len() == n);
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -std::f32::consts::TAU / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_per_column() {
+        let times = [0, 1, 2, 3, 7];
+        let values = [1.0, -2.0, 3.0, 0.5, 4.0];
+        let columns = envelope(&times, &values, (0.0, 8.0), 4);
+        assert_eq!(
+            columns,
+            [Some((-2.0, 1.0)), Some((0.5, 3.0)), None, Some((4.0, 4.0))]
+        );
+    }
+
+    #[test]
+    fn spectrogram_finds_the_frequency() {
+        let window_size = 64;
+        let bin = 8;
+        let samples: Vec<f32> = (0..4 * window_size)
+            .map(|i| (std::f32::consts::TAU * bin as f32 * i as f32 / window_size as f32).sin())
+            .collect();
+
+        let spectrogram = spectrogram(&samples, window_size, 100);
+        assert_eq!(spectrogram.num_bins, window_size / 2);
+        assert_eq!(spectrogram.frame_starts, [0, 32, 64, 96, 128, 160, 192]);
+
+        for frame in spectrogram.magnitudes.chunks(spectrogram.num_bins) {
+            let loudest = frame
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(i, _)| i);
+            assert_eq!(loudest, Some(bin));
+        }
+    }
+
+    #[test]
+    fn spectrogram_limits_frames() {
+        let samples = vec![0.0; 10_000];
+        let spectrogram = spectrogram(&samples, 100, 10);
+        assert_eq!(spectrogram.num_bins, 64);
+        assert!(spectrogram.frame_starts.len() <= 11);
+    }
+}
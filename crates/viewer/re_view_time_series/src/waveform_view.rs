@@ -0,0 +1,562 @@
+//! A view of densely sampled signals, e.g. audio, as waveforms and spectrograms.
+//!
+//! Samples are [`archetypes::Scalars`] logged on the timeline, one row per sample.
+//! The view shows the same time range as the time panel, and zooming it zooms the time panel.
+
+use std::collections::HashMap;
+
+use re_chunk_store::{ChunkStoreGeneration, RangeQuery};
+use re_log_types::{EntityPath, TimeReal, TimeType};
+use re_types::{Archetype as _, ViewClassIdentifier, archetypes, components::Color};
+use re_ui::{Help, IconText, UiExt as _, icons};
+use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
+use re_viewer_context::{
+    IdentifiedViewSystem, IndicatedEntities, Item, MaybeVisualizableEntities, PerVisualizer,
+    QueryContext, TimeView, TypedComponentFallbackProvider, ViewClass, ViewClassRegistryError,
+    ViewContext, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _,
+    ViewSystemExecutionError, ViewerContext, VisualizableEntities, VisualizerQueryInfo,
+    VisualizerSystem, auto_color_for_entity_path,
+};
+
+use crate::util::{time_view_range, visible_time_view};
+use crate::waveform::{Spectrogram, envelope, spectrogram};
+
+/// FFT window sizes to choose from, in samples.
+const WINDOW_SIZES: [usize; 5] = [256, 512, 1024, 2048, 4096];
+
+/// The samples of one entity within the visible time range.
+pub struct WaveformSeries {
+    pub entity_path: EntityPath,
+    pub color: egui::Color32,
+
+    /// Sorted.
+    pub times: Vec<i64>,
+    pub values: Vec<f32>,
+}
+
+/// Collects the samples shown by the [`WaveformView`].
+#[derive(Default)]
+pub struct WaveformSystem {
+    pub series: Vec<WaveformSeries>,
+}
+
+impl IdentifiedViewSystem for WaveformSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Waveform".into()
+    }
+}
+
+impl VisualizerSystem for WaveformSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<archetypes::Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        _context: &re_viewer_context::ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let Some(time_view) = visible_time_view(ctx.viewer_ctx) else {
+            return Ok(Vec::new());
+        };
+        let range_query = RangeQuery::new(query.timeline, time_view_range(&time_view));
+        let current_query = ctx.current_query();
+
+        for data_result in query.iter_visible_data_results(Self::identifier()) {
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                archetypes::Scalars::all_components().iter(),
+            );
+            let Some(chunks) =
+                results.get_required_chunks(archetypes::Scalars::descriptor_scalars())
+            else {
+                continue;
+            };
+
+            let mut samples: Vec<(i64, f32)> = chunks
+                .iter()
+                .flat_map(|chunk| {
+                    chunk
+                        .iter_component_indices(range_query.timeline())
+                        .zip(chunk.iter_slices::<f64>())
+                })
+                .filter_map(|((time, _), values)| Some((time.as_i64(), *values.first()? as f32)))
+                .collect();
+            // Chunks may overlap in time.
+            samples.sort_by_key(|(time, _)| *time);
+
+            let query_ctx = ctx.query_context(data_result, &current_query);
+            let color: Color = self.fallback_for(&query_ctx);
+
+            let (times, values) = samples.into_iter().unzip();
+            self.series.push(WaveformSeries {
+                entity_path: data_result.entity_path.clone(),
+                color: color.into(),
+                times,
+                values,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for WaveformSystem {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(WaveformSystem => [Color]);
+
+/// What a spectrogram texture was computed from.
+#[derive(PartialEq)]
+struct SpectrogramKey {
+    time_range: (i64, i64),
+    num_samples: usize,
+    window_size: usize,
+    max_frames: usize,
+    generation: ChunkStoreGeneration,
+}
+
+pub struct WaveformViewState {
+    show_spectrogram: bool,
+
+    /// FFT window size of the spectrogram, in samples.
+    window_size: usize,
+
+    spectrograms: HashMap<EntityPath, (SpectrogramKey, Option<SpectrogramTexture>)>,
+}
+
+struct SpectrogramTexture {
+    texture: egui::TextureHandle,
+
+    /// Index of the first sample covered by the texture.
+    first_sample: usize,
+
+    /// Index of the last sample covered by the texture.
+    last_sample: usize,
+}
+
+impl Default for WaveformViewState {
+    fn default() -> Self {
+        Self {
+            show_spectrogram: false,
+            window_size: 1024,
+            spectrograms: Default::default(),
+        }
+    }
+}
+
+impl ViewState for WaveformViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl WaveformViewState {
+    /// The spectrogram of the series as a texture, recomputed only when the samples or settings changed.
+    fn spectrogram_texture(
+        &mut self,
+        egui_ctx: &egui::Context,
+        generation: ChunkStoreGeneration,
+        series: &WaveformSeries,
+        max_frames: usize,
+    ) -> Option<&SpectrogramTexture> {
+        let key = SpectrogramKey {
+            time_range: (
+                series.times.first().copied().unwrap_or_default(),
+                series.times.last().copied().unwrap_or_default(),
+            ),
+            num_samples: series.times.len(),
+            window_size: self.window_size,
+            max_frames,
+            generation,
+        };
+
+        let needs_update = self
+            .spectrograms
+            .get(&series.entity_path)
+            .is_none_or(|(old_key, _)| old_key != &key);
+        if needs_update {
+            let spectrogram = spectrogram(&series.values, self.window_size, max_frames);
+            let texture = match (
+                spectrogram.frame_starts.first(),
+                spectrogram.frame_starts.last(),
+            ) {
+                (Some(&first_start), Some(&last_start)) => Some(SpectrogramTexture {
+                    texture: egui_ctx.load_texture(
+                        format!("spectrogram {}", series.entity_path),
+                        spectrogram_image(&spectrogram),
+                        egui::TextureOptions::LINEAR,
+                    ),
+                    first_sample: first_start,
+                    last_sample: (last_start + self.window_size.next_power_of_two() - 1)
+                        .min(series.values.len() - 1),
+                }),
+                _ => None,
+            };
+            self.spectrograms
+                .insert(series.entity_path.clone(), (key, texture));
+        }
+
+        self.spectrograms.get(&series.entity_path)?.1.as_ref()
+    }
+}
+
+/// One column per frame, low frequencies at the bottom.
+fn spectrogram_image(spectrogram: &Spectrogram) -> egui::ColorImage {
+    let width = spectrogram.frame_starts.len();
+    let height = spectrogram.num_bins;
+    let mut rgba = vec![0; width * height * 4];
+    for (x, frame) in spectrogram.magnitudes.chunks(height).enumerate() {
+        for (bin, &magnitude) in frame.iter().enumerate() {
+            let y = height - 1 - bin;
+            let offset = (y * width + x) * 4;
+            rgba[offset..offset + 4]
+                .copy_from_slice(&re_renderer::colormap_viridis_srgb(magnitude));
+        }
+    }
+    egui::ColorImage::from_rgba_unmultiplied([width, height], &rgba)
+}
+
+#[derive(Default)]
+pub struct WaveformView;
+
+impl ViewClass for WaveformView {
+    fn identifier() -> ViewClassIdentifier {
+        "Waveform".into()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Waveform"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_TIMESERIES
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<WaveformViewState>::default()
+    }
+
+    fn help(&self, os: egui::os::OperatingSystem) -> Help {
+        let egui::InputOptions { zoom_modifier, .. } = egui::InputOptions::default(); // This is OK, since we don't allow the user to change these modifiers.
+
+        Help::new("Waveform view")
+            .markdown(
+                "Shows densely sampled scalars, e.g. audio, as a waveform and an optional spectrogram. \
+                It follows the time range of the time panel.",
+            )
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control(
+                "Zoom",
+                IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
+            )
+            .control("Set time", icons::LEFT_MOUSE_CLICK)
+            .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<WaveformSystem>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn choose_default_visualizers(
+        &self,
+        entity_path: &EntityPath,
+        _maybe_visualizable_entities_per_visualizer: &PerVisualizer<MaybeVisualizableEntities>,
+        visualizable_entities_per_visualizer: &PerVisualizer<VisualizableEntities>,
+        _indicated_entities_per_visualizer: &PerVisualizer<IndicatedEntities>,
+    ) -> re_viewer_context::SmallVisualizerSet {
+        // Scalars aren't indicated as waveforms, so the default implementation would never pick it.
+        if visualizable_entities_per_visualizer
+            .get(&WaveformSystem::identifier())
+            .is_some_and(|entities| entities.contains(entity_path))
+        {
+            std::iter::once(WaveformSystem::identifier()).collect()
+        } else {
+            Default::default()
+        }
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Scalars are shown in time series views by default, waveforms are added by hand.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<WaveformViewState>()?;
+
+        ui.selection_grid("waveform_settings_ui").show(ui, |ui| {
+            ui.grid_left_hand_label("Spectrogram")
+                .on_hover_text("Show the frequencies of the signal over time");
+            ui.re_checkbox(&mut state.show_spectrogram, "Show spectrogram");
+            ui.end_row();
+
+            ui.grid_left_hand_label("FFT window")
+                .on_hover_text("Larger windows resolve frequencies better, but times worse");
+            ui.add_enabled_ui(state.show_spectrogram, |ui| {
+                egui::ComboBox::from_id_salt("fft_window_size")
+                    .selected_text(format!("{} samples", state.window_size))
+                    .show_ui(ui, |ui| {
+                        for window_size in WINDOW_SIZES {
+                            ui.selectable_value(
+                                &mut state.window_size,
+                                window_size,
+                                format!("{window_size} samples"),
+                            );
+                        }
+                    });
+            });
+            ui.end_row();
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<WaveformViewState>()?;
+        let all_series = &system_output.view_systems.get::<WaveformSystem>()?.series;
+
+        let response = ui.allocate_response(ui.available_size(), egui::Sense::click_and_drag());
+        let rect = response.rect;
+
+        let Some(time_view) = visible_time_view(ctx).filter(|view| view.time_spanned > 0.0) else {
+            return Ok(());
+        };
+        let min_time = time_view.min.as_f64();
+        let max_time = min_time + time_view.time_spanned;
+        let x_from_time = |time: f64| {
+            rect.left() + ((time - min_time) / time_view.time_spanned) as f32 * rect.width()
+        };
+        let time_from_x = |x: f32| {
+            min_time + (x - rect.left()) as f64 / rect.width() as f64 * time_view.time_spanned
+        };
+
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        let lane_height = rect.height() / all_series.len().max(1) as f32;
+        let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+        let mut hovered_entity = None;
+
+        for (i, series) in all_series.iter().enumerate() {
+            let lane = egui::Rect::from_min_size(
+                rect.left_top() + egui::vec2(0.0, i as f32 * lane_height),
+                egui::vec2(rect.width(), lane_height),
+            );
+            if response
+                .hover_pos()
+                .is_some_and(|pointer_pos| lane.contains(pointer_pos))
+            {
+                hovered_entity = Some(series.entity_path.clone());
+            }
+
+            let (waveform_rect, spectrogram_rect) = if state.show_spectrogram {
+                let (top, bottom) = lane.split_top_bottom_at_fraction(0.5);
+                (top, Some(bottom))
+            } else {
+                (lane, None)
+            };
+
+            // Waveform
+            {
+                let center_y = waveform_rect.center().y;
+                let amplitude = series
+                    .values
+                    .iter()
+                    .filter(|value| value.is_finite())
+                    .fold(0.0_f32, |max, value| max.max(value.abs()));
+                let scale = if amplitude > 0.0 {
+                    0.45 * waveform_rect.height() / amplitude
+                } else {
+                    0.0
+                };
+
+                painter.hline(
+                    waveform_rect.x_range(),
+                    center_y,
+                    visuals.widgets.noninteractive.bg_stroke,
+                );
+
+                let num_columns = rect.width().ceil() as usize;
+                let columns = envelope(
+                    &series.times,
+                    &series.values,
+                    (min_time, max_time),
+                    num_columns,
+                );
+                for (column, min_max) in columns.into_iter().enumerate() {
+                    let Some((min, max)) = min_max else {
+                        continue;
+                    };
+                    let x = rect.left() + column as f32 + 0.5;
+                    let top = center_y - max * scale;
+                    // At least one pixel high, so silence is still visible.
+                    let bottom = (center_y - min * scale).max(top + 1.0);
+                    painter.vline(x, top..=bottom, (1.0, series.color));
+                }
+
+                painter.text(
+                    waveform_rect.left_top() + egui::vec2(4.0, 4.0),
+                    egui::Align2::LEFT_TOP,
+                    series.entity_path.to_string(),
+                    egui::TextStyle::Small.resolve(ui.style()),
+                    visuals.text_color(),
+                );
+            }
+
+            if let Some(spectrogram_rect) = spectrogram_rect {
+                let max_frames = (rect.width().ceil() as usize).min(4096);
+                if let Some(spectrogram) = state.spectrogram_texture(
+                    ui.ctx(),
+                    ctx.recording().generation(),
+                    series,
+                    max_frames,
+                ) {
+                    let image_rect = egui::Rect::from_x_y_ranges(
+                        x_from_time(series.times[spectrogram.first_sample] as f64)
+                            ..=x_from_time(series.times[spectrogram.last_sample] as f64),
+                        spectrogram_rect.y_range(),
+                    );
+                    painter.image(
+                        spectrogram.texture.id(),
+                        image_rect,
+                        egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0)),
+                        egui::Color32::WHITE,
+                    );
+
+                    if let Some(nyquist) = nyquist_frequency(time_type, &series.times) {
+                        let font_id = egui::TextStyle::Small.resolve(ui.style());
+                        painter.text(
+                            spectrogram_rect.left_top() + egui::vec2(4.0, 2.0),
+                            egui::Align2::LEFT_TOP,
+                            format!("{} Hz", re_format::format_f64(nyquist.round())),
+                            font_id.clone(),
+                            visuals.text_color(),
+                        );
+                        painter.text(
+                            spectrogram_rect.left_bottom() + egui::vec2(4.0, -2.0),
+                            egui::Align2::LEFT_BOTTOM,
+                            "0 Hz",
+                            font_id,
+                            visuals.text_color(),
+                        );
+                    }
+                } else {
+                    painter.text(
+                        spectrogram_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        "Not enough samples for a spectrogram",
+                        egui::TextStyle::Small.resolve(ui.style()),
+                        visuals.weak_text_color(),
+                    );
+                }
+            }
+        }
+
+        // Zooming and panning moves the time panel along, and vice versa.
+        if response.hovered() {
+            let zoom = ui.input(|i| i.zoom_delta());
+            let pan = response.drag_delta().x + ui.input(|i| i.smooth_scroll_delta.x);
+            if zoom != 1.0 || pan != 0.0 {
+                let pivot = response
+                    .hover_pos()
+                    .map_or(min_time + 0.5 * time_view.time_spanned, |pos| {
+                        time_from_x(pos.x)
+                    });
+                let time_spanned = time_view.time_spanned / zoom as f64;
+                let min = pivot
+                    - (pivot - min_time) / zoom as f64
+                    - pan as f64 / rect.width() as f64 * time_spanned;
+                ctx.rec_cfg.time_ctrl.write().set_time_view(TimeView {
+                    min: TimeReal::from(min),
+                    time_spanned,
+                });
+            }
+        }
+
+        if response.double_clicked() {
+            ctx.rec_cfg.time_ctrl.write().reset_time_view();
+        } else if response.clicked()
+            && let Some(pointer_pos) = response.interact_pointer_pos()
+        {
+            let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+            time_ctrl.set_time(TimeReal::from(time_from_x(pointer_pos.x)));
+            time_ctrl.pause();
+        }
+
+        let current_time = ctx.rec_cfg.time_ctrl.read().time();
+        if let Some(current_time) = current_time {
+            let time_x = x_from_time(current_time.as_f64());
+            if rect.x_range().contains(time_x) {
+                ui.paint_time_cursor(&painter, &response, time_x, rect.y_range());
+            }
+        }
+
+        let hovered = hovered_entity.map_or(Item::View(query.view_id), |entity_path| {
+            Item::DataResult(query.view_id, entity_path.into())
+        });
+        ctx.handle_select_hover_drag_interactions(&response, hovered, false);
+
+        Ok(())
+    }
+}
+
+/// Half the sample rate of the series, if its times are in nanoseconds.
+fn nyquist_frequency(time_type: TimeType, times: &[i64]) -> Option<f64> {
+    if time_type == TimeType::Sequence {
+        return None;
+    }
+    let (first, last) = (*times.first()?, *times.last()?);
+    let duration_secs = (last - first) as f64 * 1e-9;
+    (duration_secs > 0.0).then(|| 0.5 * (times.len() - 1) as f64 / duration_secs)
+}
@@ -0,0 +1,174 @@
+//! Mapping scalar values onto the Y coordinates of the plot.
+//!
+//! `egui_plot` only knows a single linear Y axis, so logarithmic axes and the secondary axis
+//! are implemented by transforming the values before they are handed to the plot.
+
+use re_types::components::Range1D;
+
+/// Maps scalar values of one Y axis to the Y coordinates of the plot, and back.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct YAxisTransform {
+    pub log_scale: bool,
+
+    /// Applied after the logarithm, if any.
+    pub scale: f64,
+
+    /// Applied after the scale.
+    pub offset: f64,
+}
+
+impl YAxisTransform {
+    /// The transform of the primary axis, whose values are plotted as they are (or their logarithm).
+    pub fn new(log_scale: bool) -> Self {
+        Self {
+            log_scale,
+            scale: 1.0,
+            offset: 0.0,
+        }
+    }
+
+    /// The transform of the secondary axis.
+    ///
+    /// The range of `values` is stretched onto the `target` range of plot coordinates,
+    /// i.e. the range of the values on the primary axis.
+    /// If there are no values on the primary axis, the secondary values are plotted as they are.
+    pub fn fitted(
+        log_scale: bool,
+        values: impl Iterator<Item = f64>,
+        target: Option<(f64, f64)>,
+    ) -> Self {
+        let unfitted = Self::new(log_scale);
+        let Some((min, max)) = value_range(values.filter_map(|value| unfitted.to_plot(value)))
+        else {
+            return unfitted;
+        };
+        let (target_min, target_max) = target.unwrap_or((min, max));
+
+        let scale = if min < max && target_min < target_max {
+            (target_max - target_min) / (max - min)
+        } else {
+            1.0
+        };
+        let offset = f64::midpoint(target_min, target_max) - f64::midpoint(min, max) * scale;
+
+        Self {
+            log_scale,
+            scale,
+            offset,
+        }
+    }
+
+    /// The plot coordinate of a value, `None` if it can't be shown on a logarithmic scale.
+    #[inline]
+    pub fn to_plot(&self, value: f64) -> Option<f64> {
+        let value = if self.log_scale {
+            if value <= 0.0 {
+                return None;
+            }
+            value.log10()
+        } else {
+            value
+        };
+        Some(value * self.scale + self.offset)
+    }
+
+    /// The value at a plot coordinate.
+    #[inline]
+    pub fn from_plot(&self, y: f64) -> f64 {
+        let value = (y - self.offset) / self.scale;
+        if self.log_scale {
+            10.0_f64.powf(value)
+        } else {
+            value
+        }
+    }
+
+    /// The plot coordinates of a range of values.
+    ///
+    /// On a logarithmic scale, a range that isn't positive is moved to a positive one.
+    pub fn range_to_plot(&self, range: Range1D) -> Range1D {
+        let (mut start, end) = (range.start(), range.end());
+        if self.log_scale {
+            let end = if end > 0.0 { end } else { 1.0 };
+            if start <= 0.0 || end <= start {
+                start = end / 10.0;
+            }
+            Range1D::new(
+                self.to_plot(start).unwrap_or_default(),
+                self.to_plot(end).unwrap_or_default(),
+            )
+        } else {
+            Range1D::new(
+                start * self.scale + self.offset,
+                end * self.scale + self.offset,
+            )
+        }
+    }
+
+    /// The range of values shown in a range of plot coordinates.
+    pub fn range_from_plot(&self, range: Range1D) -> Range1D {
+        Range1D::new(self.from_plot(range.start()), self.from_plot(range.end()))
+    }
+
+    /// Formats a grid mark of the Y axis as a value of this axis.
+    pub fn format_grid_mark(&self, mark: egui_plot::GridMark) -> String {
+        let value = self.from_plot(mark.value);
+        if self.log_scale {
+            return re_format::format_f64(value);
+        }
+
+        // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
+        let step_size = mark.step_size / self.scale.abs();
+        let num_decimals = (-step_size.log10().round()).max(0.0) as usize;
+
+        re_format::FloatFormatOptions::DEFAULT_f64
+            .with_decimals(num_decimals)
+            .format(value)
+    }
+}
+
+/// The lowest and highest finite value, if there are any.
+pub fn value_range(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    values
+        .filter(|value| value.is_finite())
+        .fold(None, |range, value| {
+            Some(range.map_or((value, value), |(min, max): (f64, f64)| {
+                (min.min(value), max.max(value))
+            }))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_scale() {
+        let transform = YAxisTransform::new(true);
+        assert_eq!(transform.to_plot(100.0), Some(2.0));
+        assert_eq!(transform.to_plot(0.0), None);
+        assert_eq!(transform.to_plot(-1.0), None);
+        assert!((transform.from_plot(-3.0) - 0.001).abs() < 1e-12);
+
+        let range = transform.range_to_plot(Range1D::new(-5.0, 1000.0));
+        assert_eq!((range.start(), range.end()), (2.0, 3.0));
+    }
+
+    #[test]
+    fn secondary_axis_fits_primary_range() {
+        // Learning rates on a log scale next to a loss between 0 and 2.
+        let transform =
+            YAxisTransform::fitted(true, [1e-4, 1e-3, 1e-2, 0.0].into_iter(), Some((0.0, 2.0)));
+        assert_eq!(transform.to_plot(1e-4), Some(0.0));
+        assert_eq!(transform.to_plot(1e-2), Some(2.0));
+        assert!((transform.from_plot(1.0) - 1e-3).abs() < 1e-12);
+
+        // Without anything on the primary axis, values are plotted as they are.
+        let transform = YAxisTransform::fitted(false, [3.0, 5.0].into_iter(), None);
+        assert_eq!(transform.to_plot(4.0), Some(4.0));
+
+        // A single value is centered on the primary range.
+        let transform = YAxisTransform::fitted(false, [7.0].into_iter(), Some((0.0, 2.0)));
+        assert_eq!(transform.to_plot(7.0), Some(1.0));
+    }
+}
@@ -3,6 +3,7 @@ use std::{str::FromStr as _, sync::Arc};
 use itertools::Itertools as _;
 
 use re_build_info::CrateVersion;
+use re_byte_size::SizeBytes as _;
 use re_capabilities::MainThreadToken;
 use re_chunk::TimelineName;
 use re_data_source::{FileContents, LogDataSource};
@@ -16,7 +17,7 @@ use re_viewer_context::{
     AppOptions, AsyncRuntimeHandle, BlueprintUndoState, CommandReceiver, CommandSender,
     ComponentUiRegistry, DisplayMode, Item, PlayState, RecordingConfig, RecordingOrTable,
     StorageContext, StoreContext, SystemCommand, SystemCommandSender as _, TableStore, ViewClass,
-    ViewClassRegistry, ViewClassRegistryError, command_channel, santitize_file_name,
+    ViewClassRegistry, ViewClassRegistryError, ViewerContext, command_channel, santitize_file_name,
     store_hub::{BlueprintPersistence, StoreHub, StoreHubStats},
 };
 
@@ -50,11 +51,23 @@ const RERUN_VERSION_KEY: &str = "rerun.version";
 
 const REDAP_TOKEN_KEY: &str = "rerun.redap_token";
 
+/// Transactions that haven't completed after this long are applied anyway,
+/// so that a stalled data source can't hold back its data forever.
+const PENDING_TRANSACTION_TIMEOUT: web_time::Duration = web_time::Duration::from_secs(10);
+
 #[cfg(not(target_arch = "wasm32"))]
 const MIN_ZOOM_FACTOR: f32 = 0.2;
 #[cfg(not(target_arch = "wasm32"))]
 const MAX_ZOOM_FACTOR: f32 = 5.0;
 
+/// The messages of a transaction that hasn't fully arrived yet.
+struct PendingTransaction {
+    /// When the first message of the transaction arrived.
+    started: web_time::Instant,
+
+    msgs: Vec<(Arc<SmartChannelSource>, LogMsg)>,
+}
+
 #[cfg(target_arch = "wasm32")]
 struct PendingFilePromise {
     recommended_store_id: Option<StoreId>,
@@ -93,6 +106,12 @@ pub struct App {
     rx_log: ReceiveSet<LogMsg>,
     rx_table: ReceiveSetTable,
 
+    /// Messages of transactions that haven't fully arrived yet, per store.
+    pending_transactions: parking_lot::Mutex<ahash::HashMap<StoreId, PendingTransaction>>,
+
+    /// How fast the data of each receiver comes in.
+    ingestion: parking_lot::Mutex<crate::ingestion::Ingestion>,
+
     #[cfg(target_arch = "wasm32")]
     open_files_promise: Option<PendingFilePromise>,
 
@@ -111,8 +130,36 @@ pub struct App {
     memory_panel: crate::memory_panel::MemoryPanel,
     memory_panel_open: bool,
 
+    /// The view currently being exported as a video, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    video_export: Option<crate::video_export::VideoExport>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    video_export_modal: crate::ui::VideoExportModal,
+
+    high_res_screenshot_modal: crate::ui::HighResScreenshotModal,
+
+    #[cfg(feature = "scripting")]
+    script_console: crate::scripting::ScriptConsole,
+
+    #[cfg(feature = "scripting")]
+    script_console_open: bool,
+
     egui_debug_panel_open: bool,
 
+    /// Panels added by the host application with [`Self::add_panel`].
+    custom_panels: Vec<crate::custom_panel::CustomPanel>,
+
+    /// Handlers of the commands added by the host application with [`Self::add_command`].
+    custom_command_handlers: ahash::HashMap<String, Box<dyn FnMut(&CommandSender)>>,
+
+    /// Shared with the host application, see [`Self::time_control_handle`].
+    time_control_handle: crate::TimeControlHandle,
+
+    #[cfg(feature = "gamepad")]
+    #[cfg(not(target_arch = "wasm32"))]
+    gamepads: crate::gamepad::Gamepads,
+
     /// Last time the latency was deemed interesting.
     ///
     /// Note that initializing with an "old" `Instant` won't work reliably cross platform
@@ -138,6 +185,9 @@ pub struct App {
     /// External interactions with the Viewer host (JS, custom egui app, notebook, etc.).
     pub event_dispatcher: Option<ViewerEventDispatcher>,
 
+    /// The active blueprint as of the last frame, to tell when it changed.
+    last_blueprint_generation: Option<(StoreId, re_chunk_store::ChunkStoreGeneration)>,
+
     connection_registry: ConnectionRegistryHandle,
 
     /// The async runtime that should be used for all asynchronous operations.
@@ -216,6 +266,10 @@ impl App {
             AppState::default()
         };
 
+        if let Some(locale) = &state.app_options.locale {
+            re_ui::i18n::set_locale(Some(locale));
+        }
+
         if startup_options.persist_state {
             // Check if the user has recently upgraded Rerun.
             if let Some(storage) = creation_context.storage {
@@ -244,6 +298,10 @@ impl App {
             }
         }
 
+        if let Some(custom_theme) = &startup_options.custom_theme {
+            re_ui::set_custom_theme(&creation_context.egui_ctx, custom_theme.clone());
+        }
+
         if let Some(video_decoder_hw_acceleration) = startup_options.video_decoder_hw_acceleration {
             state.app_options.video_decoder_hw_acceleration = video_decoder_hw_acceleration;
         }
@@ -253,6 +311,15 @@ impl App {
             state.app_options.show_metrics = false;
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if startup_options.persist_state && !app_env.is_test() {
+            re_ui::Keymap::update_ctx(&creation_context.egui_ctx, |keymap| {
+                if let Err(err) = keymap.load_user_bindings() {
+                    re_log::warn!("Failed to load keyboard shortcuts: {err}");
+                }
+            });
+        }
+
         let view_class_registry = crate::default_views::create_view_class_registry()
             .unwrap_or_else(|err| {
                 re_log::error!("Failed to create view class registry: {err}");
@@ -328,6 +395,14 @@ impl App {
             command_sender.send_ui(UICommand::ExpandBlueprintPanel);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = &startup_options.blueprint_path {
+            command_sender.send_system(SystemCommand::LoadDataSource(LogDataSource::FilePath(
+                FileSource::Cli,
+                path.clone(),
+            )));
+        }
+
         Self {
             main_thread_token,
             build_info,
@@ -348,6 +423,8 @@ impl App {
             component_ui_registry,
             rx_log: Default::default(),
             rx_table: Default::default(),
+            pending_transactions: Default::default(),
+            ingestion: Default::default(),
             #[cfg(target_arch = "wasm32")]
             open_files_promise: Default::default(),
             state,
@@ -361,8 +438,33 @@ impl App {
             memory_panel: Default::default(),
             memory_panel_open: false,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            video_export: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            video_export_modal: Default::default(),
+
+            high_res_screenshot_modal: Default::default(),
+
+            #[cfg(feature = "scripting")]
+            script_console: Default::default(),
+
+            #[cfg(feature = "scripting")]
+            script_console_open: false,
+
             egui_debug_panel_open: false,
 
+            custom_panels: Vec::new(),
+            custom_command_handlers: Default::default(),
+            time_control_handle: crate::TimeControlHandle::new(creation_context.egui_ctx.clone()),
+
+            #[cfg(feature = "gamepad")]
+            #[cfg(not(target_arch = "wasm32"))]
+            gamepads: crate::gamepad::Gamepads::start(
+                creation_context.egui_ctx.clone(),
+                command_sender.clone(),
+            ),
+
             latest_latency_interest: None,
 
             frame_time_history: egui::util::History::new(1..100, 0.5),
@@ -379,6 +481,7 @@ impl App {
             reflection,
 
             event_dispatcher,
+            last_blueprint_generation: None,
 
             connection_registry,
             async_runtime: tokio_runtime,
@@ -470,6 +573,14 @@ impl App {
         &self.rx_log
     }
 
+    /// How fast the data of each receiver added with [`Self::add_log_receiver`] comes in,
+    /// and how far the viewer is behind it.
+    ///
+    /// Updated once per frame.
+    pub fn ingestion_stats(&self) -> Vec<crate::ReceiverIngestion> {
+        self.ingestion.lock().receivers().cloned().collect()
+    }
+
     /// Adds a new view class to the viewer.
     #[deprecated(
         since = "0.24.0",
@@ -489,9 +600,180 @@ impl App {
         &mut self.view_class_registry
     }
 
-    fn check_keyboard_shortcuts(&self, egui_ctx: &egui::Context) {
-        if let Some(cmd) = UICommand::listen_for_kb_shortcut(egui_ctx) {
-            self.command_sender.send_ui(cmd);
+    /// Calls `callback` with every [`crate::ViewerEvent`], e.g. when the time cursor moves or a recording is opened.
+    ///
+    /// This lets an app embedding the viewer follow what happens in it.
+    /// Unlike [`StartupOptions::on_event`], subscriptions can be made and removed at any time.
+    pub fn subscribe_to_events(
+        &mut self,
+        callback: impl Fn(&crate::ViewerEvent) + 'static,
+    ) -> crate::SubscriptionId {
+        self.event_dispatcher
+            .get_or_insert_with(Default::default)
+            .subscribe_to_events(std::rc::Rc::new(callback))
+    }
+
+    /// Calls `callback` with the new selection whenever the user changes it, e.g. by clicking a point in a view.
+    ///
+    /// This lets an app embedding the viewer react to the selection, for instance by highlighting
+    /// the corresponding row in its own UI.
+    /// Also fires [`crate::ViewerEventKind::SelectionChange`] events, if [`StartupOptions::on_event`] is set.
+    pub fn subscribe_to_selection_changes(
+        &mut self,
+        callback: impl Fn(&[crate::SelectionChangeItem]) + 'static,
+    ) -> crate::SubscriptionId {
+        self.event_dispatcher
+            .get_or_insert_with(Default::default)
+            .subscribe_to_selection_changes(std::rc::Rc::new(callback))
+    }
+
+    /// Stops calling a callback registered with [`Self::subscribe_to_events`]
+    /// or [`Self::subscribe_to_selection_changes`].
+    ///
+    /// Returns `false` if there was no such subscription.
+    pub fn unsubscribe(&mut self, id: crate::SubscriptionId) -> bool {
+        self.event_dispatcher
+            .as_mut()
+            .is_some_and(|dispatcher| dispatcher.unsubscribe(id))
+    }
+
+    /// Writes the active blueprint to a `.rbl` file, e.g. to check a curated layout into version control.
+    ///
+    /// Fails if there is no active blueprint yet.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_blueprint_to_file(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        let Some(blueprint) = self
+            .store_hub
+            .as_ref()
+            .and_then(|store_hub| store_hub.active_blueprint())
+        else {
+            anyhow::bail!("No blueprint to save");
+        };
+        save_blueprint_file(blueprint, path)
+    }
+
+    /// Loads a `.rbl` file, which becomes the active blueprint of the application it was saved for.
+    ///
+    /// The file is loaded in the background, like any other file.
+    /// Use [`StartupOptions::blueprint_path`] to load one at startup.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_blueprint_file(&self, path: impl Into<std::path::PathBuf>) {
+        self.command_sender
+            .send_system(SystemCommand::LoadDataSource(LogDataSource::FilePath(
+                FileSource::Sdk,
+                path.into(),
+            )));
+    }
+
+    /// Opens the recordings of an application in the given layout, instead of the heuristic default.
+    ///
+    /// The blueprint is typically built with `rerun::blueprint::Blueprint`, which describes
+    /// containers and views of any registered class, with their origins, entity filters and properties:
+    /// ```ignore
+    /// let (blueprint_id, blueprint_msgs) = Blueprint::new(Container::horizontal([
+    ///     View::new::<Spatial3DView>("/world").into(),
+    ///     View::from_class_identifier("ColorCoordinates", "/world").into(),
+    /// ]))
+    /// .to_log_msgs("my_app")?;
+    /// app.set_default_blueprint(blueprint_id, blueprint_msgs);
+    /// ```
+    ///
+    /// The blueprint becomes the default of its application, i.e. what "Reset blueprint" goes back to.
+    /// It is also used right away, unless the user already has a blueprint for the application.
+    pub fn set_default_blueprint(&mut self, blueprint_id: StoreId, blueprint_msgs: Vec<LogMsg>) {
+        let (tx, rx) = re_smart_channel::smart_channel(
+            re_smart_channel::SmartMessageSource::Sdk,
+            SmartChannelSource::Sdk,
+        );
+        for msg in blueprint_msgs {
+            tx.send(msg).ok();
+        }
+        tx.send(LogMsg::BlueprintActivationCommand(
+            re_log_types::BlueprintActivationCommand::make_default(blueprint_id),
+        ))
+        .ok();
+        self.add_log_receiver(rx);
+    }
+
+    /// Changes the colors, fonts and theme of the UI, e.g. to match the branding of the app embedding the viewer.
+    ///
+    /// Can be called at any time. Use [`StartupOptions::custom_theme`] to already have it on the first frame.
+    pub fn set_custom_theme(&self, custom_theme: re_ui::CustomTheme) {
+        re_ui::set_custom_theme(&self.egui_ctx, custom_theme);
+    }
+
+    /// Adds a panel with custom UI, e.g. for controlling a robot the viewer is connected to.
+    ///
+    /// The panel is shown on the given side of the viewport, with `title` as its heading.
+    /// Titles must be unique, since they are used to identify the panels.
+    /// `ui` is called every frame, and can use the [`ViewerContext`] to e.g. read
+    /// the active recording, or change the selection.
+    pub fn add_panel(
+        &mut self,
+        title: impl Into<String>,
+        side: crate::PanelSide,
+        ui: impl FnMut(&ViewerContext<'_>, &mut egui::Ui) + 'static,
+    ) {
+        self.custom_panels.push(crate::custom_panel::CustomPanel {
+            title: title.into(),
+            side,
+            ui: Box::new(ui),
+        });
+    }
+
+    /// Adds a command with a keyboard shortcut, e.g. for stopping a robot the viewer is connected to.
+    ///
+    /// The shortcut can be changed by the user in the settings, like those of the built-in commands.
+    /// `handler` is called whenever the shortcut is pressed.
+    pub fn add_command(
+        &mut self,
+        command: re_ui::CustomCommand,
+        handler: impl FnMut(&CommandSender) + 'static,
+    ) {
+        self.custom_command_handlers
+            .insert(command.id.clone(), Box::new(handler));
+        re_ui::Keymap::update_ctx(&self.egui_ctx, |keymap| keymap.register_command(command));
+    }
+
+    /// Shows custom content in the hover cards of some entities, instead of their components.
+    ///
+    /// For instance, the decoded fields of a CAN frame rather than its raw bytes.
+    /// `ui` is given the hovered instance and the latest values of all components of its entity.
+    /// See [`ComponentUiRegistry::add_hover_card`] for which hover card is shown if several apply.
+    pub fn add_hover_card(
+        &mut self,
+        target: crate::HoverCardTarget,
+        ui: impl Fn(
+            &ViewerContext<'_>,
+            &mut egui::Ui,
+            &re_entity_db::InstancePath,
+            &re_query::LatestAtResults,
+        ) + Send
+        + Sync
+        + 'static,
+    ) {
+        self.component_ui_registry
+            .add_hover_card(target, Box::new(ui));
+    }
+
+    /// A handle for reading and changing the time control of the active recording from host-application code.
+    ///
+    /// For instance, to keep the time cursor in sync with an external simulation clock.
+    pub fn time_control_handle(&self) -> crate::TimeControlHandle {
+        self.time_control_handle.clone()
+    }
+
+    fn check_keyboard_shortcuts(&mut self, egui_ctx: &egui::Context) {
+        match re_ui::Keymap::listen_for_kb_shortcut(egui_ctx) {
+            Some(re_ui::KeymapCommand::Ui(cmd)) => {
+                self.command_sender.send_ui(cmd);
+            }
+            Some(re_ui::KeymapCommand::Custom(id)) => {
+                if let Some(handler) = self.custom_command_handlers.get_mut(&id) {
+                    handler(&self.command_sender);
+                }
+            }
+            None => {}
         }
     }
 
@@ -603,6 +885,13 @@ impl App {
                     }
                 }
 
+                if let RecordingOrTable::Recording { store_id } = &entry
+                    && let Some(event_dispatcher) = self.event_dispatcher.as_ref()
+                    && let Some(entity_db) = store_hub.store_bundle().get(store_id)
+                {
+                    event_dispatcher.on_recording_close(entity_db);
+                }
+
                 store_hub.remove(&entry);
 
                 update_web_address_bar(
@@ -613,6 +902,12 @@ impl App {
             }
 
             SystemCommand::CloseAllEntries => {
+                if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                    for entity_db in store_hub.store_bundle().recordings() {
+                        event_dispatcher.on_recording_close(entity_db);
+                    }
+                }
+
                 store_hub.clear_entries();
 
                 // Stop receiving into the old recordings.
@@ -751,6 +1046,11 @@ impl App {
                 blueprint_db.drop_entity_path_recursive(&entity_path);
             }
 
+            SystemCommand::CompactRecording(store_id) => {
+                store_hub.compact_recording(&store_id, None);
+                egui_ctx.request_repaint();
+            }
+
             #[cfg(debug_assertions)]
             SystemCommand::EnableInspectBlueprintTimeline(show) => {
                 self.app_options_mut().inspect_blueprint_timeline = show;
@@ -844,6 +1144,19 @@ impl App {
                 self.state.focused_item = Some(item);
             }
 
+            SystemCommand::ShowNotification { level, text } => {
+                self.notifications.add(level, text);
+            }
+
+            SystemCommand::ShowHighResScreenshotDialog(view_id) => {
+                self.show_high_res_screenshot_dialog(store_hub, view_id);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            SystemCommand::ShowVideoExportDialog(view_id) => {
+                self.show_video_export_dialog(store_hub, view_id);
+            }
+
             #[cfg(not(target_arch = "wasm32"))]
             SystemCommand::FileSaver(file_saver) => {
                 if let Err(err) = self.background_tasks.spawn_file_saver(file_saver) {
@@ -1142,6 +1455,24 @@ impl App {
                 }
             }
 
+            UICommand::SaveViewScreenshot => {
+                let selected_view = self
+                    .state
+                    .selection_state
+                    .selected_items()
+                    .iter_items()
+                    .find_map(|item| match item {
+                        Item::View(view_id) | Item::DataResult(view_id, _) => Some(*view_id),
+                        _ => None,
+                    });
+                if let Some(view_id) = selected_view {
+                    self.command_sender
+                        .send_system(SystemCommand::ShowHighResScreenshotDialog(view_id));
+                } else {
+                    re_log::warn!("Select a view to save a screenshot of it");
+                }
+            }
+
             #[cfg(not(target_arch = "wasm32"))]
             UICommand::Open => {
                 for file_path in open_file_dialog_native(self.main_thread_token) {
@@ -1271,6 +1602,10 @@ impl App {
             UICommand::ToggleMemoryPanel => {
                 self.memory_panel_open ^= true;
             }
+            #[cfg(feature = "scripting")]
+            UICommand::ToggleScriptConsole => {
+                self.script_console_open ^= true;
+            }
             UICommand::TogglePanelStateOverrides => {
                 self.panel_state_overrides_active ^= true;
             }
@@ -1309,6 +1644,22 @@ impl App {
                 }
             },
 
+            UICommand::ToggleRecordingDiff => {
+                self.state.recording_diff.open ^= true;
+            }
+
+            UICommand::ToggleComparison => {
+                let comparison = &mut self.state.comparison;
+                if comparison.is_active() {
+                    comparison.recordings.clear();
+                } else {
+                    comparison.compare_with_other_recordings(storage_context.hub);
+                    if !comparison.is_active() {
+                        re_log::warn!("No other recordings of this application to compare with");
+                    }
+                }
+            }
+
             #[cfg(debug_assertions)]
             UICommand::ToggleBlueprintInspectionPanel => {
                 self.app_options_mut().inspect_blueprint_timeline ^= true;
@@ -1696,7 +2047,7 @@ impl App {
     }
 
     fn memory_panel_ui(
-        &self,
+        &mut self,
         ui: &mut egui::Ui,
         gpu_resource_stats: &WgpuResourcePoolStatistics,
         store_stats: Option<&StoreHubStats>,
@@ -1714,12 +2065,33 @@ impl App {
                 self.memory_panel.ui(
                     ui,
                     &self.startup_options.memory_limit,
+                    &mut self.startup_options.recording_memory_budget,
+                    &mut self.startup_options.spill_evicted_chunks,
                     gpu_resource_stats,
                     store_stats,
+                    &self.ingestion.lock(),
+                    &self.command_sender,
+                    self.main_thread_token,
                 );
             });
     }
 
+    #[cfg(feature = "scripting")]
+    fn script_console_ui(&mut self, ui: &mut egui::Ui) {
+        let frame = egui::Frame {
+            fill: ui.visuals().panel_fill,
+            ..ui.tokens().bottom_panel_frame()
+        };
+
+        egui::TopBottomPanel::bottom("script_console")
+            .default_height(250.0)
+            .resizable(true)
+            .frame(frame)
+            .show_animated_inside(ui, self.script_console_open, |ui| {
+                self.script_console.ui(ui);
+            });
+    }
+
     fn egui_debug_panel_ui(&self, ui: &mut egui::Ui) {
         let egui_ctx = ui.ctx().clone();
 
@@ -1766,6 +2138,8 @@ impl App {
         storage_context: &StorageContext<'_>,
         store_stats: Option<&StoreHubStats>,
     ) {
+        let _region = re_memory::MemoryRegion::Ui.enter();
+
         let mut main_panel_frame = egui::Frame::default();
         if re_ui::CUSTOM_WINDOW_DECORATIONS {
             // Add some margin so that we can later paint an outline around it all.
@@ -1790,6 +2164,9 @@ impl App {
 
                 self.memory_panel_ui(ui, gpu_resource_stats, store_stats);
 
+                #[cfg(feature = "scripting")]
+                self.script_console_ui(ui);
+
                 self.egui_debug_panel_ui(ui);
 
                 let egui_renderer = &mut frame
@@ -1805,6 +2182,8 @@ impl App {
                     if let Some(store_context) = store_context {
                         render_ctx.begin_frame(); // This may actually be called multiple times per egui frame, if we have a multi-pass layout frame.
 
+                        self.receive_high_res_screenshots(render_ctx);
+
                         // In some (rare) circumstances we run two egui passes in a single frame.
                         // This happens on call to `egui::Context::request_discard`.
                         let is_start_of_new_frame = egui_ctx.current_pass_index() == 0;
@@ -1836,6 +2215,7 @@ impl App {
                             self.event_dispatcher.as_ref(),
                             &self.connection_registry,
                             &self.async_runtime,
+                            &mut self.custom_panels,
                         );
                         render_ctx.before_submit();
                     }
@@ -1858,6 +2238,7 @@ impl App {
 
     fn receive_messages(&self, store_hub: &mut StoreHub, egui_ctx: &egui::Context) {
         re_tracing::profile_function!();
+        let _region = re_memory::MemoryRegion::Store.enter();
 
         // TODO(grtlr): Should we bring back analytics for this too?
         self.rx_table.lock().retain(|rx| match rx.try_recv() {
@@ -1893,13 +2274,22 @@ impl App {
             Err(_) => false,
         });
 
+        self.flush_pending_transactions(store_hub, egui_ctx, "it timed out", |transaction| {
+            PENDING_TRANSACTION_TIMEOUT <= transaction.started.elapsed()
+        });
+
         let start = web_time::Instant::now();
 
         while let Some((channel_source, msg)) = self.rx_log.try_recv() {
             re_log::trace!("Received a message from {channel_source:?}"); // Used by `test_ui_wakeup` test app!
 
             let msg = match msg.payload {
-                re_smart_channel::SmartMessagePayload::Msg(msg) => msg,
+                re_smart_channel::SmartMessagePayload::Msg(msg) => {
+                    self.ingestion
+                        .lock()
+                        .on_ingested(&channel_source, msg.total_size_bytes());
+                    msg
+                }
 
                 re_smart_channel::SmartMessagePayload::Flush { on_flush_done } => {
                     on_flush_done();
@@ -1907,153 +2297,251 @@ impl App {
                 }
 
                 re_smart_channel::SmartMessagePayload::Quit(err) => {
-                    if let Some(err) = err {
+                    if let Some(err) = &err {
                         re_log::warn!("Data source {} has left unexpectedly: {err}", msg.source);
                     } else {
                         re_log::debug!("Data source {} has finished", msg.source);
                     }
+
+                    if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                        for entity_db in store_hub.store_bundle().recordings() {
+                            if entity_db.data_source.as_ref() == Some(channel_source.as_ref()) {
+                                event_dispatcher.on_ingestion_finish(
+                                    entity_db,
+                                    &channel_source,
+                                    err.as_ref().map(|err| err.to_string()),
+                                );
+                            }
+                        }
+                    }
+
+                    // The rest of these transactions will never arrive.
+                    self.flush_pending_transactions(
+                        store_hub,
+                        egui_ctx,
+                        "its data source disconnected",
+                        |transaction| {
+                            transaction
+                                .msgs
+                                .iter()
+                                .any(|(source, _)| source == &channel_source)
+                        },
+                    );
                     continue;
                 }
             };
 
-            let store_id = msg.store_id();
-
-            if store_hub.is_active_blueprint(store_id) {
-                // TODO(#5514): handle loading of active blueprints.
-                re_log::warn_once!(
-                    "Loading a blueprint {store_id:?} that is active. See https://github.com/rerun-io/rerun/issues/5514 for details."
-                );
+            // Hold back the messages of a transaction until it is complete,
+            // so that it never shows up partially.
+            if msg.continues_transaction() {
+                self.pending_transactions
+                    .lock()
+                    .entry(msg.store_id().clone())
+                    .or_insert_with(|| PendingTransaction {
+                        started: web_time::Instant::now(),
+                        msgs: Vec::new(),
+                    })
+                    .msgs
+                    .push((channel_source, msg));
+                continue;
             }
 
-            // TODO(cmc): we have to keep grabbing and releasing entity_db because everything references
-            // everything and some of it is mutable and some not… it's really not pretty, but it
-            // does the job for now.
+            let transaction = self.pending_transactions.lock().remove(msg.store_id());
+            for (channel_source, msg) in transaction.into_iter().flat_map(|t| t.msgs) {
+                self.receive_message(store_hub, egui_ctx, channel_source, msg);
+            }
 
-            let msg_will_add_new_store = matches!(&msg, LogMsg::SetStoreInfo(..))
-                && !store_hub.store_bundle().contains(store_id);
+            self.receive_message(store_hub, egui_ctx, channel_source, msg);
 
-            let was_empty = {
-                let entity_db = store_hub.entity_db_mut(store_id);
-                if entity_db.data_source.is_none() {
-                    entity_db.data_source = Some((*channel_source).clone());
-                }
-                entity_db.is_empty()
-            };
+            if start.elapsed() > web_time::Duration::from_millis(10) {
+                egui_ctx.request_repaint(); // make sure we keep receiving messages asap
+                break; // don't block the main thread for too long
+            }
+        }
 
-            match store_hub.entity_db_mut(store_id).add(&msg) {
-                Ok(store_events) => {
-                    if let Some(caches) = store_hub.active_caches() {
-                        caches.on_store_events(&store_events);
-                    }
+        // Make sure we wake up in time to apply transactions that never complete.
+        let next_timeout = self
+            .pending_transactions
+            .lock()
+            .values()
+            .map(|transaction| {
+                PENDING_TRANSACTION_TIMEOUT.saturating_sub(transaction.started.elapsed())
+            })
+            .min();
+        if let Some(next_timeout) = next_timeout {
+            egui_ctx.request_repaint_after(next_timeout);
+        }
+    }
 
-                    self.validate_loaded_events(&store_events);
-                }
+    /// Applies the pending transactions selected by `should_flush`, even though they are incomplete.
+    fn flush_pending_transactions(
+        &self,
+        store_hub: &mut StoreHub,
+        egui_ctx: &egui::Context,
+        reason: &str,
+        mut should_flush: impl FnMut(&PendingTransaction) -> bool,
+    ) {
+        let flushed: Vec<(StoreId, PendingTransaction)> = self
+            .pending_transactions
+            .lock()
+            .extract_if(|_, transaction| should_flush(transaction))
+            .collect();
 
-                Err(err) => {
-                    re_log::error_once!("Failed to add incoming msg: {err}");
-                }
+        for (store_id, transaction) in flushed {
+            re_log::warn!(
+                "Applying an incomplete transaction of {store_id:?}, because {reason}. The recording may be missing data."
+            );
+            for (channel_source, msg) in transaction.msgs {
+                self.receive_message(store_hub, egui_ctx, channel_source, msg);
             }
+        }
+    }
+
+    fn receive_message(
+        &self,
+        store_hub: &mut StoreHub,
+        egui_ctx: &egui::Context,
+        channel_source: Arc<SmartChannelSource>,
+        msg: LogMsg,
+    ) {
+        let store_id = msg.store_id();
+
+        if store_hub.is_active_blueprint(store_id) {
+            // TODO(#5514): handle loading of active blueprints.
+            re_log::warn_once!(
+                "Loading a blueprint {store_id:?} that is active. See https://github.com/rerun-io/rerun/issues/5514 for details."
+            );
+        }
+
+        // TODO(cmc): we have to keep grabbing and releasing entity_db because everything references
+        // everything and some of it is mutable and some not… it's really not pretty, but it
+        // does the job for now.
 
+        let msg_will_add_new_store = matches!(&msg, LogMsg::SetStoreInfo(..))
+            && !store_hub.store_bundle().contains(store_id);
+
+        let was_empty = {
             let entity_db = store_hub.entity_db_mut(store_id);
+            if entity_db.data_source.is_none() {
+                entity_db.data_source = Some((*channel_source).clone());
+            }
+            entity_db.is_empty()
+        };
 
-            if was_empty && !entity_db.is_empty() {
-                // Hack: we cannot go to a specific timeline or entity until we know about it.
-                // Now we _hopefully_ do.
-                if let SmartChannelSource::RedapGrpcStream { uri, .. } = channel_source.as_ref() {
-                    self.go_to_dataset_data(uri);
+        match store_hub.entity_db_mut(store_id).add(&msg) {
+            Ok(store_events) => {
+                if let Some(caches) = store_hub.active_caches() {
+                    caches.on_store_events(&store_events);
                 }
+
+                self.validate_loaded_events(&store_events);
             }
 
-            match &msg {
-                LogMsg::SetStoreInfo(_) => {
-                    if channel_source.select_when_loaded() {
-                        // Set the recording-id after potentially creating the store in the hub.
-                        // This ordering is important because the `StoreHub` internally
-                        // updates the app-id when changing the recording.
-                        match store_id.kind() {
-                            StoreKind::Recording => {
-                                re_log::trace!("Opening a new recording: '{store_id:?}'");
-                                self.make_store_active_and_highlight(store_hub, egui_ctx, store_id);
-                            }
-                            StoreKind::Blueprint => {
-                                // We wait with activating blueprints until they are fully loaded,
-                                // so that we don't run heuristics on half-loaded blueprints.
-                                // Otherwise on a mixed connection (SDK sending both blueprint and recording)
-                                // the blueprint won't be activated until the whole _recording_ has finished loading.
-                            }
+            Err(err) => {
+                re_log::error_once!("Failed to add incoming msg: {err}");
+            }
+        }
+
+        let entity_db = store_hub.entity_db_mut(store_id);
+
+        if was_empty && !entity_db.is_empty() {
+            if entity_db.store_kind() == StoreKind::Recording
+                && let Some(event_dispatcher) = self.event_dispatcher.as_ref()
+            {
+                event_dispatcher.on_ingestion_start(entity_db, &channel_source);
+            }
+
+            // Hack: we cannot go to a specific timeline or entity until we know about it.
+            // Now we _hopefully_ do.
+            if let SmartChannelSource::RedapGrpcStream { uri, .. } = channel_source.as_ref() {
+                self.go_to_dataset_data(uri);
+            }
+        }
+
+        match &msg {
+            LogMsg::SetStoreInfo(_) => {
+                if channel_source.select_when_loaded() {
+                    // Set the recording-id after potentially creating the store in the hub.
+                    // This ordering is important because the `StoreHub` internally
+                    // updates the app-id when changing the recording.
+                    match store_id.kind() {
+                        StoreKind::Recording => {
+                            re_log::trace!("Opening a new recording: '{store_id:?}'");
+                            self.make_store_active_and_highlight(store_hub, egui_ctx, store_id);
+                        }
+                        StoreKind::Blueprint => {
+                            // We wait with activating blueprints until they are fully loaded,
+                            // so that we don't run heuristics on half-loaded blueprints.
+                            // Otherwise on a mixed connection (SDK sending both blueprint and recording)
+                            // the blueprint won't be activated until the whole _recording_ has finished loading.
                         }
                     }
                 }
+            }
 
-                LogMsg::ArrowMsg(_, _) => {
-                    // Handled by `EntityDb::add`
-                }
+            LogMsg::ArrowMsg(_, _) | LogMsg::DropTimeRange(_) => {
+                // Handled by `EntityDb::add`
+            }
 
-                LogMsg::BlueprintActivationCommand(cmd) => match store_id.kind() {
-                    StoreKind::Recording => {
-                        re_log::debug!(
-                            "Unexpected `BlueprintActivationCommand` message for {store_id:?}"
+            LogMsg::BlueprintActivationCommand(cmd) => match store_id.kind() {
+                StoreKind::Recording => {
+                    re_log::debug!(
+                        "Unexpected `BlueprintActivationCommand` message for {store_id:?}"
+                    );
+                }
+                StoreKind::Blueprint => {
+                    if let Some(info) = entity_db.store_info() {
+                        re_log::trace!(
+                            "Activating blueprint that was loaded from {channel_source}"
                         );
-                    }
-                    StoreKind::Blueprint => {
-                        if let Some(info) = entity_db.store_info() {
-                            re_log::trace!(
-                                "Activating blueprint that was loaded from {channel_source}"
-                            );
-                            let app_id = info.application_id().clone();
-                            if cmd.make_default {
-                                store_hub
-                                    .set_default_blueprint_for_app(store_id)
-                                    .unwrap_or_else(|err| {
-                                        re_log::warn!("Failed to make blueprint default: {err}");
-                                    });
-                            }
-                            if cmd.make_active {
-                                store_hub
-                                    .set_cloned_blueprint_active_for_app(store_id)
-                                    .unwrap_or_else(|err| {
-                                        re_log::warn!("Failed to make blueprint active: {err}");
-                                    });
-
-                                // Switch to this app, e.g. on drag-and-drop of a blueprint file
-                                store_hub.set_active_app(app_id);
-
-                                // If the viewer is in the background, tell the user that it has received something new.
-                                egui_ctx.send_viewport_cmd(
-                                    egui::ViewportCommand::RequestUserAttention(
-                                        egui::UserAttentionType::Informational,
-                                    ),
-                                );
-                            }
-                        } else {
-                            re_log::warn!(
-                                "Got ActivateStore message without first receiving a SetStoreInfo"
+                        let app_id = info.application_id().clone();
+                        if cmd.make_default {
+                            store_hub
+                                .set_default_blueprint_for_app(store_id)
+                                .unwrap_or_else(|err| {
+                                    re_log::warn!("Failed to make blueprint default: {err}");
+                                });
+                        }
+                        if cmd.make_active {
+                            store_hub
+                                .set_cloned_blueprint_active_for_app(store_id)
+                                .unwrap_or_else(|err| {
+                                    re_log::warn!("Failed to make blueprint active: {err}");
+                                });
+
+                            // Switch to this app, e.g. on drag-and-drop of a blueprint file
+                            store_hub.set_active_app(app_id);
+
+                            // If the viewer is in the background, tell the user that it has received something new.
+                            egui_ctx.send_viewport_cmd(
+                                egui::ViewportCommand::RequestUserAttention(
+                                    egui::UserAttentionType::Informational,
+                                ),
                             );
                         }
+                    } else {
+                        re_log::warn!(
+                            "Got ActivateStore message without first receiving a SetStoreInfo"
+                        );
                     }
-                },
-            }
-
-            // Do analytics/events after ingesting the new message,
-            // because `entity_db.store_info` needs to be set.
-            let entity_db = store_hub.entity_db_mut(store_id);
-            if msg_will_add_new_store && entity_db.store_kind() == StoreKind::Recording {
-                #[cfg(feature = "analytics")]
-                if let Some(analytics) = re_analytics::Analytics::global_or_init()
-                    && let Some(event) =
-                        crate::viewer_analytics::event::open_recording(&self.app_env, entity_db)
-                {
-                    analytics.record(event);
                 }
+            },
+        }
 
-                if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
-                    event_dispatcher.on_recording_open(entity_db);
-                }
+        // Do analytics/events after ingesting the new message,
+        // because `entity_db.store_info` needs to be set.
+        let entity_db = store_hub.entity_db_mut(store_id);
+        if msg_will_add_new_store && entity_db.store_kind() == StoreKind::Recording {
+            #[cfg(feature = "analytics")]
+            if let Some(analytics) = re_analytics::Analytics::global_or_init()
+                && let Some(event) =
+                    crate::viewer_analytics::event::open_recording(&self.app_env, entity_db)
+            {
+                analytics.record(event);
             }
 
-            if start.elapsed() > web_time::Duration::from_millis(10) {
-                egui_ctx.request_repaint(); // make sure we keep receiving messages asap
-                break; // don't block the main thread for too long
+            if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                event_dispatcher.on_recording_open(entity_db);
             }
         }
     }
@@ -2126,6 +2614,408 @@ impl App {
         }
     }
 
+    /// Load back the spilled data of the active recording that the user is looking at,
+    /// see [`StoreHub::reload_spilled_chunks`].
+    fn reload_spilled_chunks(&mut self, store_hub: &mut StoreHub) {
+        store_hub.set_spill_evicted_chunks(self.startup_options.spill_evicted_chunks);
+        if !self.startup_options.spill_evicted_chunks {
+            return;
+        }
+
+        let Some(entity_db) = store_hub.active_recording() else {
+            return;
+        };
+        let store_id = entity_db.store_id().clone();
+
+        let (timeline, time_range) = {
+            let time_ctrl = self.state.recording_config_mut(entity_db).time_ctrl.read();
+            let Some(time) = time_ctrl.time_int() else {
+                return;
+            };
+
+            // Everything that is visible in the time panel, as well as the time cursor.
+            let mut time_range = re_log_types::AbsoluteTimeRange::point(time);
+            if let Some(time_view) = time_ctrl.time_view() {
+                let max = time_view.min + re_log_types::TimeReal::from(time_view.time_spanned);
+                time_range = time_range.union(re_log_types::AbsoluteTimeRange::new(
+                    time_view.min.floor(),
+                    max.ceil(),
+                ));
+            }
+
+            (*time_ctrl.timeline().name(), time_range)
+        };
+
+        store_hub.reload_spilled_chunks(&store_id, timeline, time_range);
+    }
+
+    /// Plays back a view over a time range, and saves it as an MP4.
+    ///
+    /// The view needs to stay visible until the export is done.
+    /// Encoding uses `ffmpeg`, the same one as for video decoding.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_view_video(&mut self, settings: crate::VideoExportSettings) {
+        let name = self
+            .egui_ctx
+            .memory_mut(|mem| {
+                mem.caches
+                    .cache::<re_viewer_context::ViewRectPublisher>()
+                    .get(&settings.view_id)
+                    .map(|info| info.name.clone())
+            })
+            .unwrap_or_else(|| "video".to_owned());
+        let ffmpeg_path = self.app_options().video_decoder_settings().ffmpeg_path;
+
+        re_log::debug!("Exporting {} frames of {name:?}", settings.num_frames());
+        self.video_export = Some(crate::video_export::VideoExport::new(
+            settings,
+            name,
+            ffmpeg_path,
+        ));
+        self.egui_ctx.request_repaint();
+    }
+
+    /// Fires [`crate::ViewerEventKind::BlueprintChange`] if the active blueprint changed since the last frame.
+    fn dispatch_blueprint_change(&mut self, store_hub: &StoreHub) {
+        if self.event_dispatcher.is_none() {
+            return;
+        }
+        let (Some(recording), Some(blueprint)) =
+            (store_hub.active_recording(), store_hub.active_blueprint())
+        else {
+            self.last_blueprint_generation = None;
+            return;
+        };
+
+        let generation = (blueprint.store_id().clone(), blueprint.generation());
+        if self.last_blueprint_generation.as_ref() == Some(&generation) {
+            return;
+        }
+
+        // Only changes to the same blueprint count, not switching to another one.
+        let changed = self
+            .last_blueprint_generation
+            .as_ref()
+            .is_some_and(|(store_id, _)| store_id == &generation.0);
+        if changed && let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+            event_dispatcher.on_blueprint_change(recording, blueprint);
+        }
+        self.last_blueprint_generation = Some(generation);
+    }
+
+    /// Opens the dialog for saving a high-resolution screenshot of a view.
+    fn show_high_res_screenshot_dialog(
+        &mut self,
+        store_hub: &StoreHub,
+        view_id: re_viewer_context::ViewId,
+    ) {
+        let Some(blueprint) = store_hub.active_blueprint() else {
+            return;
+        };
+        let viewport = re_viewport_blueprint::ViewportBlueprint::from_db(
+            blueprint,
+            &re_chunk_store::LatestAtQuery::latest(re_viewer_context::blueprint_timeline()),
+        );
+        let Some(view) = viewport.view(&view_id) else {
+            return;
+        };
+        let class = view.class(&self.view_class_registry);
+        if !class.supports_high_res_screenshot() {
+            re_log::warn!(
+                "{} views don't support high-resolution screenshots",
+                class.display_name()
+            );
+            return;
+        }
+
+        let Some(view_info) = self.egui_ctx.memory_mut(|mem| {
+            mem.caches
+                .cache::<re_viewer_context::ViewRectPublisher>()
+                .get(&view_id)
+                .cloned()
+        }) else {
+            re_log::warn!("Show the view to save a screenshot of it");
+            return;
+        };
+
+        // Default to twice the size the view has on screen.
+        let size_in_pixels = view_info.rect.size() * self.egui_ctx.pixels_per_point() * 2.0;
+        self.high_res_screenshot_modal
+            .open(re_viewer_context::HighResScreenshotRequest {
+                view_id,
+                resolution: [size_in_pixels.x, size_in_pixels.y]
+                    .map(|size| (size.round() as u32).max(1)),
+                supersampling: 2,
+                name: view_info.name,
+            });
+    }
+
+    /// Opens the video export dialog for a view of the active recording.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn show_video_export_dialog(
+        &mut self,
+        store_hub: &StoreHub,
+        view_id: re_viewer_context::ViewId,
+    ) {
+        let Some(entity_db) = store_hub.active_recording() else {
+            return;
+        };
+
+        let (timeline, loop_selection) = {
+            let time_ctrl = self.state.recording_config_mut(entity_db).time_ctrl.read();
+            let loop_selection = time_ctrl.loop_selection().map(|range| {
+                re_log_types::AbsoluteTimeRange::new(range.min.floor(), range.max.ceil())
+            });
+            (*time_ctrl.timeline(), loop_selection)
+        };
+
+        let Some(full_range) = entity_db.time_range_for(timeline.name()) else {
+            re_log::warn!(
+                "There is no data on the {:?} timeline to export",
+                timeline.name()
+            );
+            return;
+        };
+
+        let view_name = self
+            .egui_ctx
+            .memory_mut(|mem| {
+                mem.caches
+                    .cache::<re_viewer_context::ViewRectPublisher>()
+                    .get(&view_id)
+                    .map(|info| info.name.clone())
+            })
+            .unwrap_or_else(|| view_id.to_string());
+
+        let settings = crate::VideoExportSettings {
+            view_id,
+            store_id: entity_db.store_id().clone(),
+            timeline,
+            time_range: loop_selection.unwrap_or(full_range),
+            fps: 30.0,
+            include_scrubber: true,
+        };
+        self.video_export_modal
+            .open(settings, view_name, full_range, loop_selection);
+    }
+
+    /// Moves the time cursor to the next frame of the ongoing video export,
+    /// and takes a screenshot of it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_video_export(&mut self, store_hub: &StoreHub, egui_ctx: &egui::Context) {
+        use crate::video_export::{VideoExportFrame, VideoExportStep};
+
+        let Some(export) = &mut self.video_export else {
+            return;
+        };
+
+        if egui_ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            re_log::info!("Video export cancelled");
+            self.video_export = None;
+            return;
+        }
+
+        egui_ctx.request_repaint();
+
+        match export.begin_frame() {
+            VideoExportStep::Wait => {}
+
+            VideoExportStep::Render(time) => {
+                let settings = export.settings.clone();
+
+                let Some(view_rect) = egui_ctx.memory_mut(|mem| {
+                    mem.caches
+                        .cache::<re_viewer_context::ViewRectPublisher>()
+                        .get(&settings.view_id)
+                        .map(|info| info.rect)
+                }) else {
+                    re_log::error!("Video export failed: the view is no longer visible");
+                    self.video_export = None;
+                    return;
+                };
+
+                if let Some(rec_cfg) = self.recording_config_mut(store_hub, &settings.store_id) {
+                    let mut time_ctrl = rec_cfg.time_ctrl.write();
+                    time_ctrl.set_timeline(settings.timeline);
+                    time_ctrl.set_time(time);
+                    time_ctrl.pause();
+                }
+
+                egui_ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(
+                    VideoExportFrame {
+                        ui_rect: view_rect.shrink(2.5), // Same as view screenshots: skip the border of the view.
+                        pixels_per_point: egui_ctx.pixels_per_point(),
+                    },
+                )));
+            }
+
+            VideoExportStep::Done => {
+                let Some(export) = self.video_export.take() else {
+                    return;
+                };
+                let file_name = format!("{}.mp4", santitize_file_name(&export.name));
+                match export.finish() {
+                    Ok(mp4) => {
+                        self.command_sender.save_file_dialog(
+                            self.main_thread_token,
+                            &file_name,
+                            "Save video".to_owned(),
+                            mp4,
+                        );
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to export video: {err}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_blueprint_on_exit(&self, egui_ctx: &egui::Context, store_hub: &StoreHub) {
+        let Some(path) = &self.startup_options.save_blueprint_on_exit else {
+            return;
+        };
+        if !egui_ctx.input(|i| i.viewport().close_requested()) {
+            return;
+        }
+        let Some(blueprint) = store_hub.active_blueprint() else {
+            re_log::warn!("No blueprint to save to {path:?}");
+            return;
+        };
+        match save_blueprint_file(blueprint, path) {
+            Ok(()) => re_log::info!("Saved blueprint to {path:?}"),
+            Err(err) => re_log::error!("Failed to save blueprint to {path:?}: {err}"),
+        }
+    }
+
+    /// Runs the startup scripts once there is a recording, and any scripts submitted in the console.
+    #[cfg(feature = "scripting")]
+    fn run_scripts(&mut self, store_hub: &StoreHub) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if store_hub.active_recording().is_some() {
+            for path in std::mem::take(&mut self.startup_options.startup_scripts) {
+                let context = self.script_context(store_hub);
+                let actions = self.script_console.run_file(context, &path);
+                self.apply_script_actions(store_hub, actions);
+            }
+        }
+
+        for source in self.script_console.take_pending() {
+            let context = self.script_context(store_hub);
+            let actions = self.script_console.run(context, &source);
+            self.apply_script_actions(store_hub, actions);
+        }
+    }
+
+    /// Snapshot of the viewer state for a script to run against.
+    #[cfg(feature = "scripting")]
+    fn script_context(&self, store_hub: &StoreHub) -> crate::scripting::ScriptContext {
+        use crate::scripting::{BlueprintContext, RecordingContext, ScriptContext, ViewSummary};
+
+        let recording = store_hub.active_recording().map(|entity_db| {
+            let (timeline, time) = self.state.recording_config(entity_db.store_id()).map_or(
+                (re_log_types::Timeline::log_tick(), None),
+                |rec_cfg| {
+                    let time_ctrl = rec_cfg.time_ctrl.read();
+                    (*time_ctrl.timeline(), time_ctrl.time_int())
+                },
+            );
+
+            RecordingContext {
+                timelines: entity_db.timelines(),
+                storage: entity_db.storage_engine_arc(),
+                timeline,
+                time,
+            }
+        });
+
+        let blueprint = store_hub.active_blueprint().map(|blueprint| {
+            let viewport = re_viewport_blueprint::ViewportBlueprint::from_db(
+                blueprint,
+                &re_chunk_store::LatestAtQuery::latest(re_viewer_context::blueprint_timeline()),
+            );
+
+            BlueprintContext {
+                store_id: blueprint.store_id().clone(),
+                timepoint: re_viewer_context::blueprint_timepoint_for_writes(blueprint),
+                views: viewport
+                    .views
+                    .values()
+                    .map(|view| ViewSummary {
+                        id: view.id,
+                        name: view.display_name_or_default().to_string(),
+                        class: view.class_identifier().to_string(),
+                        visible: view.visible,
+                    })
+                    .collect(),
+            }
+        });
+
+        ScriptContext {
+            recording,
+            blueprint,
+            selection: self.state.selection_state.selected_items().clone(),
+            timestamp_format: self.app_options().timestamp_format,
+        }
+    }
+
+    #[cfg(feature = "scripting")]
+    fn apply_script_actions(
+        &mut self,
+        store_hub: &StoreHub,
+        actions: Vec<crate::scripting::ScriptAction>,
+    ) {
+        use crate::scripting::ScriptAction;
+
+        for action in actions {
+            match action {
+                ScriptAction::SetTimeline(_)
+                | ScriptAction::SetTime(_)
+                | ScriptAction::Play
+                | ScriptAction::Pause => {
+                    let Some(entity_db) = store_hub.active_recording() else {
+                        continue;
+                    };
+                    let times_per_timeline = entity_db.times_per_timeline();
+                    let time_ctrl = self
+                        .state
+                        .recording_config_mut(entity_db)
+                        .time_ctrl
+                        .get_mut();
+
+                    match action {
+                        ScriptAction::SetTimeline(timeline) => {
+                            time_ctrl.set_timeline(timeline);
+                        }
+                        ScriptAction::SetTime(time) => {
+                            time_ctrl.set_time(time);
+                            time_ctrl.pause();
+                        }
+                        ScriptAction::Play => {
+                            time_ctrl.set_play_state(times_per_timeline, PlayState::Playing);
+                        }
+                        ScriptAction::Pause => {
+                            time_ctrl.pause();
+                        }
+                        ScriptAction::SetSelection(_) | ScriptAction::AppendToBlueprint(..) => {}
+                    }
+                }
+
+                ScriptAction::SetSelection(selection) => {
+                    self.state.selection_state.set_selection(selection);
+                }
+
+                ScriptAction::AppendToBlueprint(store_id, chunk) => {
+                    self.command_sender
+                        .send_system(SystemCommand::AppendToStore(store_id, vec![chunk]));
+                }
+            }
+        }
+
+        self.egui_ctx.request_repaint();
+    }
+
     fn purge_memory_if_needed(&mut self, store_hub: &mut StoreHub) {
         re_tracing::profile_function!();
 
@@ -2141,6 +3031,30 @@ impl App {
         use re_memory::MemoryUse;
 
         let limit = self.startup_options.memory_limit;
+
+        store_hub.set_retention_policy(self.startup_options.retention_policy.clone());
+        for purge_report in store_hub.drop_expired_data() {
+            if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                event_dispatcher.on_data_pruned(&purge_report);
+            }
+        }
+
+        if let Some(max_bytes_per_recording) = self
+            .startup_options
+            .recording_memory_budget
+            .max_bytes(&limit)
+        {
+            for purge_report in store_hub.purge_recordings_over_budget(max_bytes_per_recording) {
+                re_log::info_once!(
+                    "A recording reached its memory budget of {}, dropping its oldest data.",
+                    format_bytes(max_bytes_per_recording as _)
+                );
+                if let Some(event_dispatcher) = self.event_dispatcher.as_ref() {
+                    event_dispatcher.on_data_pruned(&purge_report);
+                }
+            }
+        }
+
         let mem_use_before = MemoryUse::capture();
 
         if let Some(minimum_fraction_to_purge) = limit.is_exceeded_by(&mem_use_before) {
@@ -2167,7 +3081,12 @@ impl App {
                     format_bytes(counted as f64 * fraction_to_purge as f64)
                 );
             }
-            store_hub.purge_fraction_of_ram(fraction_to_purge);
+            let purge_report = store_hub.purge_fraction_of_ram(fraction_to_purge);
+            if let Some(purge_report) = &purge_report
+                && let Some(event_dispatcher) = self.event_dispatcher.as_ref()
+            {
+                event_dispatcher.on_data_pruned(purge_report);
+            }
 
             let mem_use_after = MemoryUse::capture();
 
@@ -2400,6 +3319,59 @@ impl App {
     }
 
     #[allow(clippy::needless_pass_by_ref_mut)] // False positive on wasm
+    /// Saves the views that were rendered offscreen for a [`re_viewer_context::HighResScreenshotRequest`].
+    fn receive_high_res_screenshots(&self, render_ctx: &re_renderer::RenderContext) {
+        use re_viewer_context::HighResScreenshotRequest;
+
+        while re_renderer::ScreenshotProcessor::next_readback_result(
+            render_ctx,
+            HighResScreenshotRequest::READBACK_IDENTIFIER,
+            |data: &[u8], extent, request: HighResScreenshotRequest| {
+                re_tracing::profile_scope!("high_res_screenshot");
+
+                let Some(rendered) = image::RgbaImage::from_raw(extent.x, extent.y, data.to_vec())
+                else {
+                    re_log::error!("Screenshot has unexpected size");
+                    return;
+                };
+
+                // Scale the supersampled image down to the requested resolution.
+                let [width, height] = request.resolution;
+                let rgba = if rendered.dimensions() == (width, height) {
+                    rendered
+                } else {
+                    image::imageops::resize(
+                        &rendered,
+                        width,
+                        height,
+                        image::imageops::FilterType::Triangle,
+                    )
+                };
+
+                use image::ImageEncoder as _;
+                let mut png_bytes: Vec<u8> = Vec::new();
+                if let Err(err) = image::codecs::png::PngEncoder::new(&mut png_bytes).write_image(
+                    rgba.as_raw(),
+                    rgba.width(),
+                    rgba.height(),
+                    image::ExtendedColorType::Rgba8,
+                ) {
+                    re_log::error!("Failed to encode screenshot as PNG: {err}");
+                    return;
+                }
+
+                self.command_sender.save_file_dialog(
+                    self.main_thread_token,
+                    &format!("{}.png", request.name),
+                    "Save screenshot".to_owned(),
+                    png_bytes,
+                );
+            },
+        )
+        .is_some()
+        {}
+    }
+
     fn process_screenshot_result(
         &mut self,
         image: &Arc<egui::ColorImage>,
@@ -2407,6 +3379,21 @@ impl App {
     ) {
         use re_viewer_context::ScreenshotInfo;
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(frame) = user_data
+            .data
+            .as_ref()
+            .and_then(|data| data.downcast_ref::<crate::video_export::VideoExportFrame>())
+        {
+            if let Some(export) = &mut self.video_export {
+                if let Err(err) = export.add_frame(image, frame) {
+                    re_log::error!("Failed to export video: {err}");
+                    self.video_export = None;
+                }
+            }
+            return;
+        }
+
         if let Some(info) = user_data
             .data
             .as_ref()
@@ -2546,6 +3533,7 @@ impl eframe::App for App {
         storage.set_string(RERUN_VERSION_KEY, self.build_info.version.to_string());
 
         // Save the app state
+        self.state.remember_open_recordings();
         eframe::set_value(storage, eframe::APP_KEY, &self.state);
         eframe::set_value(
             storage,
@@ -2665,10 +3653,42 @@ impl eframe::App for App {
         // do early, before doing too many allocations
         self.memory_panel
             .update(&gpu_resource_stats, store_stats.as_ref());
+        self.ingestion.lock().update(self.rx_log.stats());
 
         self.check_keyboard_shortcuts(egui_ctx);
 
+        self.reload_spilled_chunks(&mut store_hub);
         self.purge_memory_if_needed(&mut store_hub);
+        if store_hub.continue_compactions(self.startup_options.compaction_time_budget) {
+            egui_ctx.request_repaint();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.update_video_export(&store_hub, egui_ctx);
+
+        self.dispatch_blueprint_change(&store_hub);
+
+        #[cfg(feature = "scripting")]
+        self.run_scripts(&store_hub);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.save_blueprint_on_exit(egui_ctx, &store_hub);
+
+        {
+            let entity_db = store_hub.active_recording();
+            let mut time_ctrl = entity_db.map(|entity_db| {
+                self.state
+                    .recording_config_mut(entity_db)
+                    .time_ctrl
+                    .get_mut()
+            });
+            self.time_control_handle
+                .apply_pending(entity_db, time_ctrl.as_deref_mut());
+
+            #[cfg(feature = "gamepad")]
+            #[cfg(not(target_arch = "wasm32"))]
+            self.gamepads.update(egui_ctx, entity_db, time_ctrl);
+        }
 
         // In some (rare) circumstances we run two egui passes in a single frame.
         // This happens on call to `egui::Context::request_discard`.
@@ -2687,6 +3707,7 @@ impl eframe::App for App {
 
         store_hub.purge_empty();
         self.state.cleanup(&store_hub);
+        self.state.comparison.on_frame_start(&mut store_hub);
 
         file_saver_progress_ui(egui_ctx, &mut self.background_tasks); // toasts for background file saver
 
@@ -2751,6 +3772,18 @@ impl eframe::App for App {
                 store_stats.as_ref(),
             );
 
+            if let Some(request) = self.high_res_screenshot_modal.ui(egui_ctx) {
+                request.schedule(egui_ctx);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let timestamp_format = self.app_options().timestamp_format;
+                if let Some(settings) = self.video_export_modal.ui(egui_ctx, timestamp_format) {
+                    self.export_view_video(settings);
+                }
+            }
+
             if re_ui::CUSTOM_WINDOW_DECORATIONS {
                 // Paint the main window frame on top of everything else
                 paint_native_window_frame(egui_ctx);
@@ -2803,6 +3836,18 @@ impl eframe::App for App {
         }
         self.run_pending_system_commands(&mut store_hub, egui_ctx);
 
+        {
+            let entity_db = store_hub.active_recording();
+            let time_ctrl = entity_db.map(|entity_db| {
+                &*self
+                    .state
+                    .recording_config_mut(entity_db)
+                    .time_ctrl
+                    .get_mut()
+            });
+            self.time_control_handle.update_state(entity_db, time_ctrl);
+        }
+
         // Return the `StoreHub` to the Viewer so we have it on the next frame
         self.store_hub = Some(store_hub);
 
@@ -3049,8 +4094,25 @@ fn save_blueprint(app: &mut App, store_context: Option<&StoreContext<'_>>) -> an
 
     re_tracing::profile_function!();
 
-    let rrd_version = store_context
-        .blueprint
+    let (rrd_version, messages) = blueprint_file_messages(store_context.blueprint);
+
+    let file_name = format!(
+        "{}.rbl",
+        crate::saving::sanitize_app_id(store_context.application_id())
+    );
+    let title = "Save blueprint";
+
+    save_entity_db(app, rrd_version, file_name, title.to_owned(), messages)
+}
+
+/// The messages of a blueprint file, and the version to encode them with.
+fn blueprint_file_messages(
+    blueprint: &EntityDb,
+) -> (
+    re_build_info::CrateVersion,
+    impl Iterator<Item = re_chunk::ChunkResult<LogMsg>> + '_,
+) {
+    let rrd_version = blueprint
         .store_info()
         .and_then(|info| info.store_version)
         .unwrap_or(re_build_info::CrateVersion::LOCAL);
@@ -3060,25 +4122,26 @@ fn save_blueprint(app: &mut App, store_context: Option<&StoreContext<'_>>) -> an
     // in a situation where the store_id we're loading is the same as the currently active one,
     // which mean they will merge in a strange way.
     // This is also related to https://github.com/rerun-io/rerun/issues/5295
-    let new_store_id = store_context
-        .blueprint
+    let new_store_id = blueprint
         .store_id()
         .clone()
         .with_recording_id(RecordingId::random());
-    let messages = store_context.blueprint.to_messages(None).map(|mut msg| {
+    let messages = blueprint.to_messages(None).map(move |mut msg| {
         if let Ok(msg) = &mut msg {
             msg.set_store_id(new_store_id.clone());
         }
         msg
     });
 
-    let file_name = format!(
-        "{}.rbl",
-        crate::saving::sanitize_app_id(store_context.application_id())
-    );
-    let title = "Save blueprint";
+    (rrd_version, messages)
+}
 
-    save_entity_db(app, rrd_version, file_name, title.to_owned(), messages)
+#[cfg(not(target_arch = "wasm32"))]
+fn save_blueprint_file(blueprint: &EntityDb, path: &std::path::Path) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let (rrd_version, messages) = blueprint_file_messages(blueprint);
+    crate::saving::encode_to_file(rrd_version, path, messages)
 }
 
 // TODO(emilk): unify this with `ViewerContext::save_file_dialog`
@@ -168,6 +168,35 @@ impl<'a> AppBlueprint<'a> {
         );
     }
 
+    /// The panel states stored in the blueprint, or the defaults if there are none.
+    ///
+    /// These ignore the overrides.
+    pub fn panel_states(&self) -> PanelStates {
+        self.panel_states
+    }
+
+    /// Stores panel states in the blueprint, e.g. the ones of [`Self::panel_states`] when a recording was closed.
+    pub fn restore_panel_states(&self, panel_states: PanelStates, command_sender: &CommandSender) {
+        for (panel_name, current, restored) in [
+            (TOP_PANEL_PATH, self.panel_states.top, panel_states.top),
+            (
+                BLUEPRINT_PANEL_PATH,
+                self.panel_states.blueprint,
+                panel_states.blueprint,
+            ),
+            (
+                SELECTION_PANEL_PATH,
+                self.panel_states.selection,
+                panel_states.selection,
+            ),
+            (TIME_PANEL_PATH, self.panel_states.time, panel_states.time),
+        ] {
+            if current != restored {
+                self.send_panel_state(panel_name, restored, command_sender);
+            }
+        }
+    }
+
     pub fn blueprint_panel_overridden(&self) -> bool {
         self.overrides.is_some_and(|s| s.blueprint.is_some())
     }
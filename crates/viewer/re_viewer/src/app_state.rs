@@ -1,6 +1,6 @@
 use std::str::FromStr as _;
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use egui::{NumExt as _, Ui, text_edit::TextEditState, text_selection::LabelSelectionState};
 
 use re_chunk::TimelineName;
@@ -24,7 +24,11 @@ use re_viewport_blueprint::ViewportBlueprint;
 use re_viewport_blueprint::ui::add_view_or_container_modal_ui;
 
 use crate::{
-    app_blueprint::AppBlueprint, event::ViewerEventDispatcher, navigation::Navigation, open_url,
+    app_blueprint::AppBlueprint,
+    event::ViewerEventDispatcher,
+    navigation::Navigation,
+    open_url,
+    resume_state::{RecordingResumeState, ResumeStates, SavedPanelStates},
     ui::settings_screen_ui,
 };
 
@@ -40,6 +44,17 @@ pub struct AppState {
     pub recording_configs: HashMap<StoreId, RecordingConfig>,
     pub blueprint_cfg: RecordingConfig,
 
+    /// UI state of closed recordings, restored when they are opened again.
+    resume_states: ResumeStates,
+
+    /// The recording shown last frame, and which panels were shown with it.
+    #[serde(skip)]
+    shown_recording: Option<(StoreId, SavedPanelStates)>,
+
+    /// Recordings that were shown since they were opened, so their state is not restored again.
+    #[serde(skip)]
+    resumed_recordings: HashSet<StoreId>,
+
     /// Maps blueprint id to the current undo state for it.
     #[serde(skip)]
     pub blueprint_undo_state: HashMap<StoreId, BlueprintUndoState>,
@@ -89,6 +104,13 @@ pub struct AppState {
     /// that last several frames.
     #[serde(skip)]
     pub(crate) focused_item: Option<Item>,
+
+    /// Other recordings shown next to the active one.
+    #[serde(skip)]
+    pub(crate) comparison: crate::comparison::Comparison,
+
+    #[serde(skip)]
+    pub(crate) recording_diff: crate::recording_diff::RecordingDiffPanel,
 }
 
 impl Default for AppState {
@@ -96,6 +118,9 @@ impl Default for AppState {
         Self {
             app_options: Default::default(),
             recording_configs: Default::default(),
+            resume_states: Default::default(),
+            shown_recording: None,
+            resumed_recordings: Default::default(),
             blueprint_undo_state: Default::default(),
             blueprint_cfg: Default::default(),
             selection_panel: Default::default(),
@@ -110,6 +135,8 @@ impl Default for AppState {
             view_states: Default::default(),
             selection_state: Default::default(),
             focused_item: Default::default(),
+            comparison: Default::default(),
+            recording_diff: Default::default(),
         }
     }
 }
@@ -169,6 +196,7 @@ impl AppState {
         event_dispatcher: Option<&crate::event::ViewerEventDispatcher>,
         connection_registry: &ConnectionRegistryHandle,
         runtime: &AsyncRuntimeHandle,
+        custom_panels: &mut [crate::custom_panel::CustomPanel],
     ) {
         re_tracing::profile_function!();
 
@@ -196,6 +224,8 @@ impl AppState {
             // TODO(grtlr,ab): This needs to be further cleaned up and split into separately handled
             // display modes. See https://www.notion.so/rerunio/Major-refactor-of-re_viewer-1d8b24554b198085a02dfe441db330b4
             _ => {
+                self.resume_recording(store_context.recording, app_blueprint, command_sender);
+
                 let blueprint_query = self.blueprint_query_for_viewer(store_context.blueprint);
 
                 let Self {
@@ -212,6 +242,8 @@ impl AppState {
                     view_states,
                     selection_state,
                     focused_item,
+                    comparison,
+                    recording_diff,
                     ..
                 } = self;
 
@@ -238,6 +270,8 @@ impl AppState {
                     return;
                 }
 
+                selection_state.set_highlighted_differences(recording_diff.highlighted_entities());
+
                 let selection_change = selection_state.on_frame_start(
                     |item| {
                         if let Item::StoreId(store_id) = item
@@ -272,6 +306,12 @@ impl AppState {
                 let indicated_entities_per_visualizer =
                     view_class_registry.indicated_entities_per_visualizer(recording.store_id());
 
+                // Component predicates of entity path filters are evaluated at the current time.
+                let recording_query = recording_config_entry(recording_configs, recording)
+                    .time_ctrl
+                    .read()
+                    .current_query();
+
                 // Execute the queries for every `View`
                 let mut query_results = {
                     re_tracing::profile_scope!("query_results");
@@ -299,6 +339,7 @@ impl AppState {
                                     store_context,
                                     view_class_registry,
                                     &blueprint_query,
+                                    &recording_query,
                                     &visualizable_entities,
                                 ),
                             )
@@ -306,7 +347,16 @@ impl AppState {
                         .collect::<_>()
                 };
 
-                let rec_cfg = recording_config_entry(recording_configs, recording);
+                recording_config_entry(recording_configs, recording);
+                for compared in &comparison.recordings {
+                    if let Some(entity_db) = storage_context.bundle.get(&compared.store_id) {
+                        recording_config_entry(recording_configs, entity_db);
+                    }
+                }
+                let recording_configs = &*recording_configs;
+                let rec_cfg = recording_configs
+                    .get(recording.store_id())
+                    .expect("the config was just created");
                 let egui_ctx = ui.ctx().clone();
                 let display_mode = self.navigation.peek();
                 let ctx = ViewerContext {
@@ -351,6 +401,12 @@ impl AppState {
                 // so that we always show the latest data when we're in "follow" mode.
                 move_time(&ctx, recording, rx_log, event_dispatcher);
 
+                comparison.sync_time(
+                    &rec_cfg.time_ctrl.read(),
+                    storage_context.bundle,
+                    recording_configs,
+                );
+
                 // Update the viewport. May spawn new views and handle queued requests (like screenshots).
                 viewport_ui.on_frame_start(&ctx);
 
@@ -591,6 +647,16 @@ impl AppState {
                     },
                 );
 
+                //
+                // Panels added by the host application
+                //
+
+                for panel in custom_panels.iter_mut() {
+                    panel.show(&ctx, ui);
+                }
+
+                recording_diff.show(&ctx, ui);
+
                 //
                 // Viewport
                 //
@@ -627,7 +693,17 @@ impl AppState {
                                         )),
                                     );
                                 }
-                                viewport_ui.viewport_ui(ui, &ctx, view_states);
+                                if comparison.is_active() {
+                                    comparison.viewport_ui(
+                                        &ctx,
+                                        ui,
+                                        &viewport_ui,
+                                        view_states,
+                                        recording_configs,
+                                    );
+                                } else {
+                                    viewport_ui.viewport_ui(ui, &ctx, view_states);
+                                }
                             }
 
                             DisplayMode::RedapEntry(entry) => {
@@ -702,13 +778,99 @@ impl AppState {
     pub fn cleanup(&mut self, store_hub: &StoreHub) {
         re_tracing::profile_function!();
 
-        self.recording_configs
-            .retain(|store_id, _| store_hub.store_bundle().contains(store_id));
+        let closed_recordings: Vec<StoreId> = self
+            .recording_configs
+            .keys()
+            .filter(|store_id| !store_hub.store_bundle().contains(store_id))
+            .cloned()
+            .collect();
+        for store_id in closed_recordings {
+            let state = self.resume_state(&store_id);
+            self.resume_states.remember(store_id.clone(), state);
+            self.resumed_recordings.remove(&store_id);
+            self.recording_configs.remove(&store_id);
+        }
+        if self
+            .shown_recording
+            .as_ref()
+            .is_some_and(|(store_id, _)| !store_hub.store_bundle().contains(store_id))
+        {
+            self.shown_recording = None;
+        }
 
         self.blueprint_undo_state
             .retain(|store_id, _| store_hub.store_bundle().contains(store_id));
     }
 
+    /// Remembers the UI state of all open recordings, so that it is restored if they are opened
+    /// after a restart of the viewer.
+    pub fn remember_open_recordings(&mut self) {
+        let store_ids: Vec<StoreId> = self.recording_configs.keys().cloned().collect();
+        for store_id in store_ids {
+            let state = self.resume_state(&store_id);
+            self.resume_states.remember(store_id, state);
+        }
+    }
+
+    /// The UI state of a recording, for resuming where the user left off when it's opened again.
+    ///
+    /// Only the recording shown last frame has a selection, panel states and view states.
+    fn resume_state(&self, store_id: &StoreId) -> RecordingResumeState {
+        let mut state = RecordingResumeState {
+            time_ctrl: self
+                .recording_configs
+                .get(store_id)
+                .map(|rec_cfg| rec_cfg.time_ctrl.read().clone()),
+            ..Default::default()
+        };
+
+        if let Some((shown_store_id, panel_states)) = &self.shown_recording
+            && shown_store_id == store_id
+        {
+            state.selection = self.selection_state.selected_items().clone();
+            state.panel_states = Some(*panel_states);
+            state.save_view_states(self.view_states.save());
+        }
+
+        state
+    }
+
+    /// Restores the UI state of a recording when it's shown for the first time since it was opened.
+    fn resume_recording(
+        &mut self,
+        recording: &EntityDb,
+        app_blueprint: &AppBlueprint<'_>,
+        command_sender: &CommandSender,
+    ) {
+        let store_id = recording.store_id();
+        if recording.store_kind() != re_log_types::StoreKind::Recording
+            || recording.application_id() == &StoreHub::welcome_screen_app_id()
+        {
+            return;
+        }
+
+        self.shown_recording = Some((store_id.clone(), app_blueprint.panel_states().into()));
+        if !self.resumed_recordings.insert(store_id.clone()) {
+            return;
+        }
+        let Some(state) = self.resume_states.take(store_id) else {
+            return;
+        };
+
+        if let Some(time_ctrl) = state.time_ctrl.clone() {
+            *recording_config_entry(&mut self.recording_configs, recording)
+                .time_ctrl
+                .get_mut() = time_ctrl;
+        }
+        if !state.selection.is_empty() {
+            self.selection_state.set_selection(state.selection.clone());
+        }
+        if let Some(panel_states) = state.panel_states {
+            app_blueprint.restore_panel_states(panel_states.into(), command_sender);
+        }
+        self.view_states.restore(state.restored_view_states());
+    }
+
     /// Returns the blueprint query that should be used for generating the current
     /// layout of the viewer.
     ///
@@ -28,6 +28,7 @@ pub use re_types::blueprint::components::LockRangeDuringZoom;
 pub use re_types::blueprint::components::MapProvider;
 pub use re_types::blueprint::components::NearClipPlane;
 pub use re_types::blueprint::components::PanelState;
+pub use re_types::blueprint::components::PlotMode;
 pub use re_types::blueprint::components::QueryExpression;
 pub use re_types::blueprint::components::RootContainer;
 pub use re_types::blueprint::components::RowShare;
@@ -72,6 +73,7 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<MapProvider>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
         && validate_component::<PanelState>(blueprint)
+        && validate_component::<PlotMode>(blueprint)
         && validate_component::<QueryExpression>(blueprint)
         && validate_component::<RootContainer>(blueprint)
         && validate_component::<RowShare>(blueprint)
@@ -0,0 +1,297 @@
+//! Comparing recordings of the same application side by side, e.g. A/B runs of an algorithm.
+
+use ahash::HashMap;
+
+use re_entity_db::EntityDb;
+use re_log_types::{StoreId, TimeReal, TimeType};
+use re_ui::UiLayout;
+use re_viewer_context::{
+    DataQueryResult, GlobalContext, RecordingConfig, StoreContext, StoreHub, TimeControl, ViewId,
+    ViewStates, ViewerContext,
+};
+use re_viewport::ViewportUi;
+
+/// A recording shown next to the active one.
+pub struct ComparedRecording {
+    pub store_id: StoreId,
+
+    /// Added to the time cursor of the active recording, to align runs that didn't start at the same time.
+    pub time_offset: TimeReal,
+}
+
+/// Recordings shown side by side with the active one.
+///
+/// They all use the blueprint of the active recording, and follow its time cursor.
+/// They also share the state of its views, so e.g. moving the camera of a 3D view moves it everywhere.
+#[derive(Default)]
+pub struct Comparison {
+    pub recordings: Vec<ComparedRecording>,
+}
+
+impl Comparison {
+    pub fn is_active(&self) -> bool {
+        !self.recordings.is_empty()
+    }
+
+    /// Compares the active recording with all other recordings of the same application.
+    pub fn compare_with_other_recordings(&mut self, store_hub: &StoreHub) {
+        let Some(active) = store_hub.active_recording() else {
+            return;
+        };
+
+        self.recordings = store_hub
+            .store_bundle()
+            .recordings()
+            .filter(|db| {
+                db.application_id() == active.application_id() && db.store_id() != active.store_id()
+            })
+            .map(|db| ComparedRecording {
+                store_id: db.store_id().clone(),
+                time_offset: TimeReal::from(0),
+            })
+            .collect();
+    }
+
+    /// Called every frame, before the UI.
+    ///
+    /// Stops comparing recordings that were closed, or aren't of the active application anymore.
+    pub fn on_frame_start(&mut self, store_hub: &mut StoreHub) {
+        let Some(active) = store_hub.active_recording() else {
+            self.recordings.clear();
+            return;
+        };
+        let active_store_id = active.store_id().clone();
+        let active_app_id = active.application_id().clone();
+
+        self.recordings.retain(|compared| {
+            compared.store_id != active_store_id
+                && store_hub
+                    .store_bundle()
+                    .get(&compared.store_id)
+                    .is_some_and(|db| db.application_id() == &active_app_id)
+        });
+
+        for compared in &self.recordings {
+            store_hub.ensure_caches(&compared.store_id);
+        }
+    }
+
+    /// Moves the time cursors of the compared recordings to follow the active one.
+    pub fn sync_time(
+        &self,
+        active: &TimeControl,
+        storage_bundle: &re_entity_db::StoreBundle,
+        recording_configs: &HashMap<StoreId, RecordingConfig>,
+    ) {
+        for compared in &self.recordings {
+            let (Some(db), Some(rec_cfg)) = (
+                storage_bundle.get(&compared.store_id),
+                recording_configs.get(&compared.store_id),
+            ) else {
+                continue;
+            };
+            sync_time(
+                active,
+                &mut rec_cfg.time_ctrl.write(),
+                db,
+                compared.time_offset,
+            );
+        }
+    }
+
+    /// Shows the viewport of the active recording, with the compared ones next to it.
+    pub fn viewport_ui(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        viewport_ui: &ViewportUi,
+        view_states: &mut ViewStates,
+        recording_configs: &HashMap<StoreId, RecordingConfig>,
+    ) {
+        let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+
+        ui.columns(self.recordings.len() + 1, |columns| {
+            let (active_column, compared_columns) = columns
+                .split_first_mut()
+                .expect("there is always at least one column");
+
+            re_data_ui::item_ui::entity_db_button_ui(
+                ctx,
+                active_column,
+                ctx.recording(),
+                UiLayout::List,
+                false,
+            );
+            viewport_ui.viewport_ui(active_column, ctx, view_states);
+
+            for (compared, ui) in self.recordings.iter_mut().zip(compared_columns) {
+                let (Some(recording), Some(caches), Some(rec_cfg)) = (
+                    ctx.storage_context.bundle.get(&compared.store_id),
+                    ctx.storage_context.hub.caches(&compared.store_id),
+                    recording_configs.get(&compared.store_id),
+                ) else {
+                    continue;
+                };
+
+                ui.horizontal(|ui| {
+                    time_offset_ui(ui, time_type, &mut compared.time_offset);
+                    re_data_ui::item_ui::entity_db_button_ui(
+                        ctx,
+                        ui,
+                        recording,
+                        UiLayout::List,
+                        false,
+                    );
+                });
+
+                let store_context = StoreContext {
+                    blueprint: ctx.store_context.blueprint,
+                    default_blueprint: ctx.store_context.default_blueprint,
+                    recording,
+                    caches,
+                    should_enable_heuristics: false,
+                };
+
+                compared_viewport_ui(ctx, ui, viewport_ui, view_states, &store_context, rec_cfg);
+            }
+        });
+    }
+}
+
+/// Shows the viewport of the active recording for a compared one.
+fn compared_viewport_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    viewport_ui: &ViewportUi,
+    view_states: &mut ViewStates,
+    store_context: &StoreContext<'_>,
+    rec_cfg: &RecordingConfig,
+) {
+    re_tracing::profile_function!();
+
+    let recording = store_context.recording;
+    let view_class_registry = ctx.view_class_registry;
+
+    let maybe_visualizable_entities_per_visualizer = view_class_registry
+        .maybe_visualizable_entities_for_visualizer_systems(recording.store_id());
+    let indicated_entities_per_visualizer =
+        view_class_registry.indicated_entities_per_visualizer(recording.store_id());
+
+    let mut query_results: HashMap<ViewId, DataQueryResult> = HashMap::default();
+    for view in viewport_ui.blueprint.views.values() {
+        let visualizable_entities = view
+            .class(view_class_registry)
+            .determine_visualizable_entities(
+                &maybe_visualizable_entities_per_visualizer,
+                recording,
+                &view_class_registry.new_visualizer_collection(view.class_identifier()),
+                &view.space_origin,
+            );
+
+        let mut query_result = view.contents.execute_query(
+            store_context,
+            view_class_registry,
+            ctx.blueprint_query,
+            &rec_cfg.time_ctrl.read().current_query(),
+            &visualizable_entities,
+        );
+
+        let resolver = re_viewport_blueprint::DataQueryPropertyResolver::new(
+            view,
+            view_class_registry,
+            &maybe_visualizable_entities_per_visualizer,
+            &visualizable_entities,
+            &indicated_entities_per_visualizer,
+        );
+        resolver.update_overrides(
+            store_context.blueprint,
+            ctx.blueprint_query,
+            rec_cfg.time_ctrl.read().timeline(),
+            view_class_registry,
+            &mut query_result,
+            view_states,
+        );
+
+        query_results.insert(view.id, query_result);
+    }
+
+    let global = &ctx.global_context;
+    let compared_ctx = ViewerContext {
+        global_context: GlobalContext {
+            is_test: global.is_test,
+            app_options: global.app_options,
+            reflection: global.reflection,
+            egui_ctx: global.egui_ctx,
+            render_ctx: global.render_ctx,
+            command_sender: global.command_sender,
+            connection_registry: global.connection_registry,
+            display_mode: global.display_mode,
+        },
+        component_ui_registry: ctx.component_ui_registry,
+        view_class_registry,
+        connected_receivers: ctx.connected_receivers,
+        store_context,
+        storage_context: ctx.storage_context,
+        maybe_visualizable_entities_per_visualizer: &maybe_visualizable_entities_per_visualizer,
+        indicated_entities_per_visualizer: &indicated_entities_per_visualizer,
+        query_results: &query_results,
+        rec_cfg,
+        blueprint_cfg: ctx.blueprint_cfg,
+        selection_state: ctx.selection_state,
+        blueprint_query: ctx.blueprint_query,
+        focused_item: ctx.focused_item,
+        drag_and_drop_manager: ctx.drag_and_drop_manager,
+    };
+
+    viewport_ui.comparison_viewport_ui(
+        ui,
+        &compared_ctx,
+        view_states,
+        egui::Id::new("comparison_viewport_tree").with(recording.store_id()),
+    );
+}
+
+fn time_offset_ui(ui: &mut egui::Ui, time_type: TimeType, time_offset: &mut TimeReal) {
+    ui.label("Offset:");
+
+    let response = match time_type {
+        TimeType::Sequence => {
+            let mut steps = time_offset.round().as_i64();
+            let response = ui.add(egui::DragValue::new(&mut steps));
+            if response.changed() {
+                *time_offset = TimeReal::from(steps);
+            }
+            response
+        }
+        TimeType::DurationNs | TimeType::TimestampNs => {
+            let mut secs = time_offset.as_secs_f64();
+            let response = ui.add(egui::DragValue::new(&mut secs).speed(0.01).suffix(" s"));
+            if response.changed() {
+                *time_offset = TimeReal::from_secs(secs);
+            }
+            response
+        }
+    };
+    response.on_hover_text("Added to the time of the active recording");
+}
+
+fn sync_time(
+    active: &TimeControl,
+    compared: &mut TimeControl,
+    compared_db: &EntityDb,
+    time_offset: TimeReal,
+) {
+    let timeline = *active.timeline();
+    if !compared_db.timelines().contains_key(timeline.name()) {
+        return;
+    }
+
+    // The compared recordings never play on their own.
+    compared.pause();
+
+    if let Some(time) = active.time() {
+        compared.set_timeline_and_time(timeline, time + time_offset);
+    } else {
+        compared.set_timeline(timeline);
+    }
+}
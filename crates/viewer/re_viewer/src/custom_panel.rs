@@ -0,0 +1,68 @@
+//! Panels added by applications that embed the viewer, see [`crate::App::add_panel`].
+
+use re_viewer_context::ViewerContext;
+
+/// Where a panel added with [`crate::App::add_panel`] is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanelSide {
+    /// Next to the blueprint panel.
+    Left,
+
+    /// Next to the selection panel.
+    Right,
+
+    /// Above the time panel.
+    Bottom,
+}
+
+pub(crate) type PanelUi = Box<dyn FnMut(&ViewerContext<'_>, &mut egui::Ui)>;
+
+pub(crate) struct CustomPanel {
+    pub title: String,
+    pub side: PanelSide,
+    pub ui: PanelUi,
+}
+
+impl CustomPanel {
+    /// Must be called before the viewport is shown, since it takes up the remaining space.
+    pub fn show(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        let id = egui::Id::new("custom_panel").with(&self.title);
+        let frame = egui::Frame {
+            fill: ui.visuals().panel_fill,
+            inner_margin: egui::Margin::same(8),
+            ..Default::default()
+        };
+
+        let contents = |ui: &mut egui::Ui| {
+            ui.strong(&self.title);
+            ui.separator();
+            egui::ScrollArea::both()
+                .auto_shrink(false)
+                .show(ui, |ui| (self.ui)(ctx, ui));
+        };
+
+        match self.side {
+            PanelSide::Left => {
+                egui::SidePanel::left(id)
+                    .resizable(true)
+                    .default_width(250.0)
+                    .frame(frame)
+                    .show_inside(ui, contents);
+            }
+            PanelSide::Right => {
+                egui::SidePanel::right(id)
+                    .resizable(true)
+                    .default_width(250.0)
+                    .frame(frame)
+                    .show_inside(ui, contents);
+            }
+            PanelSide::Bottom => {
+                egui::TopBottomPanel::bottom(id)
+                    .resizable(true)
+                    .default_height(200.0)
+                    .frame(frame)
+                    .show_inside(ui, contents);
+            }
+        }
+    }
+}
@@ -22,7 +22,10 @@ fn populate_view_class_registry_with_builtin(
     view_class_registry.add_class::<re_view_tensor::TensorView>()?;
     view_class_registry.add_class::<re_view_text_document::TextDocumentView>()?;
     view_class_registry.add_class::<re_view_text_log::TextView>()?;
+    view_class_registry.add_class::<re_view_time_series::HistogramView>()?;
+    view_class_registry.add_class::<re_view_time_series::ParallelCoordinatesView>()?;
     view_class_registry.add_class::<re_view_time_series::TimeSeriesView>()?;
+    view_class_registry.add_class::<re_view_time_series::WaveformView>()?;
 
     Ok(())
 }
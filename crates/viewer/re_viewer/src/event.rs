@@ -2,6 +2,9 @@
 //!
 //! A callback may be registered to the Viewer via [`crate::StartupOptions::on_event`]
 //! which will receive instances of [`ViewerEvent`].
+//!
+//! Apps embedding the Viewer can also subscribe to them at any time with [`crate::App::subscribe_to_events`],
+//! or to only the selection with [`crate::App::subscribe_to_selection_changes`].
 
 // NOTE: Any changes to the type definitions in this file must be replicated in:
 // - rerun_js/web-viewer/index.ts (ViewerEvent)
@@ -13,7 +16,7 @@ use std::rc::Rc;
 use re_entity_db::EntityDb;
 use re_log_types::{ApplicationId, RecordingId, TimeReal, Timeline, TimelineName};
 use re_smart_channel::SmartChannelSource;
-use re_viewer_context::{ContainerId, Item, ItemCollection, ItemContext, ViewId};
+use re_viewer_context::{ContainerId, Item, ItemCollection, ItemContext, PurgeReport, ViewId};
 use re_viewport_blueprint::ViewportBlueprint;
 
 /// An event produced in the Viewer.
@@ -35,7 +38,21 @@ pub struct ViewerEvent {
 impl ViewerEvent {
     #[inline]
     fn from_db_and_kind(db: &EntityDb, kind: ViewerEventKind) -> Self {
-        let partition_id = db.data_source.as_ref().and_then(|ds| {
+        Self::from_parts(
+            db.application_id().clone(),
+            db.recording_id().clone(),
+            db.data_source.as_ref(),
+            kind,
+        )
+    }
+
+    fn from_parts(
+        application_id: ApplicationId,
+        recording_id: RecordingId,
+        data_source: Option<&SmartChannelSource>,
+        kind: ViewerEventKind,
+    ) -> Self {
+        let partition_id = data_source.and_then(|ds| {
             if let SmartChannelSource::RedapGrpcStream {
                 uri: re_uri::DatasetPartitionUri { partition_id, .. },
                 ..
@@ -48,8 +65,8 @@ impl ViewerEvent {
         });
 
         Self {
-            application_id: db.application_id().clone(),
-            recording_id: db.recording_id().clone(),
+            application_id,
+            recording_id,
             partition_id,
             kind,
         }
@@ -59,6 +76,7 @@ impl ViewerEvent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ViewerEventKind {
     /// Fired when the timeline starts playing.
     Play,
@@ -108,6 +126,66 @@ pub enum ViewerEventKind {
         /// Uses semver format.
         version: Option<String>,
     },
+
+    /// Fired when a recording is closed, e.g. by the user.
+    ///
+    /// Recordings closed to stay within the memory limit fire [`Self::DataPruned`] instead.
+    RecordingClose,
+
+    /// Fired when the blueprint of the active recording changes, e.g. because a view was added.
+    ///
+    /// Fires at most once per frame, and not for activating a different blueprint.
+    BlueprintChange {
+        /// Id of the changed blueprint.
+        blueprint_id: String,
+    },
+
+    /// Fired when the first data of a recording arrives.
+    IngestionStart {
+        /// Where the data comes from, e.g. a file path or URL.
+        ///
+        /// The value should be considered unstable.
+        source: String,
+    },
+
+    /// Fired when the source of a recording stops sending data, e.g. because a file was fully loaded.
+    IngestionFinish {
+        /// Where the data came from, e.g. a file path or URL.
+        ///
+        /// The value should be considered unstable.
+        source: String,
+
+        /// Set if the source stopped because of an error.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    /// Fired when data is dropped from a recording to stay within the memory limit.
+    DataPruned {
+        /// For each timeline, the time range covered by the dropped data.
+        ///
+        /// Data for some entities may remain within these ranges.
+        time_ranges: Vec<PrunedTimeRange>,
+
+        /// How much memory was freed by dropping the data, in bytes.
+        bytes_freed: u64,
+
+        /// Whether the whole recording was closed.
+        recording_closed: bool,
+    },
+}
+
+/// The time range covered by the data dropped from a timeline, see [`ViewerEventKind::DataPruned`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PrunedTimeRange {
+    #[serde(rename = "timeline")]
+    pub timeline_name: TimelineName,
+
+    /// Inclusive.
+    pub min: i64,
+
+    /// Inclusive.
+    pub max: i64,
 }
 
 /// A single item in a selection.
@@ -118,7 +196,7 @@ pub enum SelectionChangeItem {
     /// Selected an entity, or an instance of an entity.
     ///
     /// If the entity was selected within a view, then this also
-    /// includes the view's name.
+    /// includes the view's id and name.
     ///
     /// If the entity was selected within a 2D or 3D space view,
     /// then this also includes the position.
@@ -130,6 +208,10 @@ pub enum SelectionChangeItem {
         #[serde(skip_serializing_if = "instance_is_all")]
         instance_id: re_log_types::Instance,
 
+        #[serde(with = "serde::optional_blueprint_id")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view_id: Option<ViewId>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         view_name: Option<String>,
 
@@ -137,6 +219,15 @@ pub enum SelectionChangeItem {
         position: Option<glam::Vec3>,
     },
 
+    /// Selected a component of an entity, e.g. in the selection panel.
+    Component {
+        #[serde(with = "serde::entity_path")]
+        entity_path: re_log_types::EntityPath,
+
+        /// e.g. `Points3D:colors`
+        component: String,
+    },
+
     /// Selected a view.
     View {
         #[serde(with = "serde::blueprint_id")]
@@ -181,7 +272,6 @@ impl SelectionChangeItem {
         match item {
             Item::StoreId(_)
             | Item::AppId(_)
-            | Item::ComponentPath(_)
             | Item::DataSource(_)
             | Item::RedapEntry(_)
             | Item::RedapServer(_)
@@ -205,15 +295,22 @@ impl SelectionChangeItem {
                 },
             }),
 
+            Item::ComponentPath(component_path) => Some(Self::Component {
+                entity_path: component_path.entity_path.clone(),
+                component: component_path.component_descriptor.component.to_string(),
+            }),
+
             Item::DataResult(view_id, instance_path) => Some(Self::Entity {
                 entity_path: instance_path.entity_path.clone(),
                 instance_id: instance_path.instance,
+                view_id: Some(*view_id),
                 view_name: get_view_name(blueprint, view_id),
                 position: get_position(context),
             }),
             Item::InstancePath(instance_path) => Some(Self::Entity {
                 entity_path: instance_path.entity_path.clone(),
                 instance_id: instance_path.instance,
+                view_id: None,
                 view_name: None,
                 position: get_position(context),
             }),
@@ -223,15 +320,62 @@ impl SelectionChangeItem {
 
 pub type ViewerEventCallback = Rc<dyn Fn(ViewerEvent)>;
 
-#[derive(Clone)]
+/// Called with every event, see [`crate::App::subscribe_to_events`].
+pub type ViewerEventSubscriber = Rc<dyn Fn(&ViewerEvent)>;
+
+/// Called with the items of the new selection, see [`crate::App::subscribe_to_selection_changes`].
+pub type SelectionChangeCallback = Rc<dyn Fn(&[SelectionChangeItem])>;
+
+/// Identifies a subscription, for [`crate::App::unsubscribe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+#[derive(Clone, Default)]
 pub struct ViewerEventDispatcher {
-    f: ViewerEventCallback,
+    f: Option<ViewerEventCallback>,
+    event_subscribers: Vec<(SubscriptionId, ViewerEventSubscriber)>,
+    selection_subscribers: Vec<(SubscriptionId, SelectionChangeCallback)>,
+    next_subscription_id: u64,
 }
 
 impl ViewerEventDispatcher {
     #[inline]
     pub fn new(f: ViewerEventCallback) -> Self {
-        Self { f }
+        Self {
+            f: Some(f),
+            ..Default::default()
+        }
+    }
+
+    fn next_subscription_id(&mut self) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription_id);
+        self.next_subscription_id += 1;
+        id
+    }
+
+    pub fn subscribe_to_events(&mut self, subscriber: ViewerEventSubscriber) -> SubscriptionId {
+        let id = self.next_subscription_id();
+        self.event_subscribers.push((id, subscriber));
+        id
+    }
+
+    pub fn subscribe_to_selection_changes(
+        &mut self,
+        callback: SelectionChangeCallback,
+    ) -> SubscriptionId {
+        let id = self.next_subscription_id();
+        self.selection_subscribers.push((id, callback));
+        id
+    }
+
+    /// Returns `false` if there was no such subscription.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let num_subscribers = self.event_subscribers.len() + self.selection_subscribers.len();
+        self.event_subscribers
+            .retain(|(subscription_id, _)| *subscription_id != id);
+        self.selection_subscribers
+            .retain(|(subscription_id, _)| *subscription_id != id);
+        self.event_subscribers.len() + self.selection_subscribers.len() != num_subscribers
     }
 
     #[inline]
@@ -272,16 +416,18 @@ impl ViewerEventDispatcher {
         items: &ItemCollection,
         viewport_blueprint: &ViewportBlueprint,
     ) {
+        let items: Vec<_> = items
+            .iter()
+            .filter_map(|(item, ctx)| SelectionChangeItem::new(item, ctx, viewport_blueprint))
+            .collect();
+
+        for (_, callback) in &self.selection_subscribers {
+            callback(&items);
+        }
+
         self.dispatch(ViewerEvent::from_db_and_kind(
             db,
-            ViewerEventKind::SelectionChange {
-                items: items
-                    .iter()
-                    .filter_map(|(item, ctx)| {
-                        SelectionChangeItem::new(item, ctx, viewport_blueprint)
-                    })
-                    .collect(),
-            },
+            ViewerEventKind::SelectionChange { items },
         ));
     }
 
@@ -316,9 +462,81 @@ impl ViewerEventDispatcher {
         ));
     }
 
+    #[inline]
+    pub fn on_recording_close(&self, db: &EntityDb) {
+        self.dispatch(ViewerEvent::from_db_and_kind(
+            db,
+            ViewerEventKind::RecordingClose,
+        ));
+    }
+
+    /// The `db` is the active recording.
+    #[inline]
+    pub fn on_blueprint_change(&self, db: &EntityDb, blueprint: &EntityDb) {
+        self.dispatch(ViewerEvent::from_db_and_kind(
+            db,
+            ViewerEventKind::BlueprintChange {
+                blueprint_id: blueprint.store_id().recording_id().as_str().to_owned(),
+            },
+        ));
+    }
+
+    #[inline]
+    pub fn on_ingestion_start(&self, db: &EntityDb, source: &SmartChannelSource) {
+        self.dispatch(ViewerEvent::from_db_and_kind(
+            db,
+            ViewerEventKind::IngestionStart {
+                source: source.to_string(),
+            },
+        ));
+    }
+
+    #[inline]
+    pub fn on_ingestion_finish(
+        &self,
+        db: &EntityDb,
+        source: &SmartChannelSource,
+        error: Option<String>,
+    ) {
+        self.dispatch(ViewerEvent::from_db_and_kind(
+            db,
+            ViewerEventKind::IngestionFinish {
+                source: source.to_string(),
+                error,
+            },
+        ));
+    }
+
+    #[inline]
+    pub fn on_data_pruned(&self, report: &PurgeReport) {
+        self.dispatch(ViewerEvent::from_parts(
+            report.store_id.application_id().clone(),
+            report.store_id.recording_id().clone(),
+            report.data_source.as_ref(),
+            ViewerEventKind::DataPruned {
+                time_ranges: report
+                    .time_ranges
+                    .iter()
+                    .map(|(timeline_name, time_range)| PrunedTimeRange {
+                        timeline_name: *timeline_name,
+                        min: time_range.min().as_i64(),
+                        max: time_range.max().as_i64(),
+                    })
+                    .collect(),
+                bytes_freed: report.num_bytes_freed,
+                recording_closed: report.recording_closed,
+            },
+        ));
+    }
+
     #[inline]
     fn dispatch(&self, event: ViewerEvent) {
-        (self.f)(event);
+        for (_, subscriber) in &self.event_subscribers {
+            subscriber(&event);
+        }
+        if let Some(f) = &self.f {
+            f(event);
+        }
     }
 }
 
@@ -403,6 +621,40 @@ mod serde {
         }
     }
 
+    pub mod optional_blueprint_id {
+        use super::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S, T>(
+            v: &Option<re_viewer_context::BlueprintId<T>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: re_viewer_context::BlueprintIdRegistry,
+        {
+            match v {
+                Some(v) => super::blueprint_id::serialize(v, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D, T>(
+            deserializer: D,
+        ) -> Result<Option<re_viewer_context::BlueprintId<T>>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: re_viewer_context::BlueprintIdRegistry,
+        {
+            let s: Option<String> = Deserialize::deserialize(deserializer)?;
+            s.map(|s| {
+                re_types::external::uuid::Uuid::try_parse(&s)
+                    .map_err(serde::de::Error::custom)
+                    .map(re_viewer_context::BlueprintId::from)
+            })
+            .transpose()
+        }
+    }
+
     pub mod recording_id {
         use super::{Deserialize, Deserializer, Serializer};
 
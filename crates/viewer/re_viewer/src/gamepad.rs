@@ -0,0 +1,151 @@
+//! Gamepad input, for reviewing recordings from a couch or a test bench.
+//!
+//! | Input                 | Action                                  |
+//! |-----------------------|-----------------------------------------|
+//! | Left stick            | Fly forwards, backwards and sideways    |
+//! | Right stick           | Look around                             |
+//! | Left / right trigger  | Fly down / up                           |
+//! | Left / right bumper   | Scrub time backwards / forwards (held)  |
+//! | D-pad left / right    | Step time backwards / forwards          |
+//! | South (A / ✕)         | Toggle play/pause                       |
+//! | Start                 | Follow                                  |
+//! | Select                | Restart                                 |
+//!
+//! The sticks and triggers fly the eye of the 3D view that was hovered last, see [`GamepadAxes`].
+
+use std::sync::Arc;
+
+use egui::NumExt as _;
+use gilrs::{Axis, Button, EventType, GamepadId, Gilrs};
+use parking_lot::Mutex;
+
+use re_entity_db::EntityDb;
+use re_log_types::TimeReal;
+use re_ui::{UICommand, UICommandSender as _};
+use re_viewer_context::{CommandSender, GamepadAxes, TimeControl};
+
+/// How long a held bumper takes to scrub through the visible time range, in seconds.
+const SCRUB_SECONDS: f64 = 4.0;
+
+#[derive(Clone, Copy, Default)]
+struct GamepadState {
+    axes: GamepadAxes,
+
+    /// Scrubs forwards if positive and backwards if negative, in `-1.0..=1.0`.
+    scrub: f32,
+}
+
+/// Listens to gamepads on a background thread.
+///
+/// Buttons are sent as [`UICommand`]s, while sticks and triggers are applied every frame in [`Self::update`].
+pub struct Gamepads {
+    state: Arc<Mutex<GamepadState>>,
+}
+
+impl Gamepads {
+    pub fn start(egui_ctx: egui::Context, command_sender: CommandSender) -> Self {
+        let state = Arc::new(Mutex::new(GamepadState::default()));
+
+        let thread_state = Arc::clone(&state);
+        let result = std::thread::Builder::new()
+            .name("gamepads".to_owned())
+            .spawn(move || {
+                // Gilrs isn't `Send` on all platforms, so it's created on the thread that uses it.
+                let mut gilrs = match Gilrs::new() {
+                    Ok(gilrs) => gilrs,
+                    Err(err) => {
+                        re_log::warn!("Gamepads are not supported: {err}");
+                        return;
+                    }
+                };
+
+                while let Some(event) = gilrs.next_event_blocking(None) {
+                    if let Some(command) = command_for_event(event.event) {
+                        command_sender.send_ui(command);
+                    }
+                    *thread_state.lock() = if event.event == EventType::Disconnected {
+                        GamepadState::default()
+                    } else {
+                        gamepad_state(&gilrs, event.id)
+                    };
+                    egui_ctx.request_repaint();
+                }
+            });
+        if let Err(err) = result {
+            re_log::warn!("Failed to listen to gamepads: {err}");
+        }
+
+        Self { state }
+    }
+
+    /// Publishes the sticks and triggers to the views, and scrubs the time while a bumper is held.
+    pub fn update(
+        &self,
+        egui_ctx: &egui::Context,
+        entity_db: Option<&EntityDb>,
+        time_ctrl: Option<&mut TimeControl>,
+    ) {
+        let GamepadState { axes, scrub } = *self.state.lock();
+        axes.store(egui_ctx);
+
+        let (Some(entity_db), Some(time_ctrl)) = (entity_db, time_ctrl) else {
+            return;
+        };
+        if scrub == 0.0 {
+            return;
+        }
+        let Some(time) = time_ctrl.time() else {
+            return;
+        };
+        let Some(full_range) = time_ctrl.full_range(entity_db.times_per_timeline()) else {
+            return;
+        };
+
+        let visible_span = time_ctrl
+            .time_view()
+            .map_or(full_range.abs_length() as f64, |view| view.time_spanned);
+        let dt = egui_ctx.input(|input| input.stable_dt.at_most(0.1)) as f64;
+        let delta = TimeReal::from(scrub as f64 * visible_span * dt / SCRUB_SECONDS);
+
+        time_ctrl.pause();
+        time_ctrl.set_time((time + delta).clamp(full_range.min().into(), full_range.max().into()));
+        egui_ctx.request_repaint();
+    }
+}
+
+fn command_for_event(event: EventType) -> Option<UICommand> {
+    match event {
+        EventType::ButtonPressed(button, _) => match button {
+            Button::South => Some(UICommand::PlaybackTogglePlayPause),
+            Button::Start => Some(UICommand::PlaybackFollow),
+            Button::Select => Some(UICommand::PlaybackRestart),
+            Button::DPadLeft => Some(UICommand::PlaybackStepBack),
+            Button::DPadRight => Some(UICommand::PlaybackStepForward),
+            _ => None,
+        },
+        EventType::ButtonRepeated(Button::DPadLeft, _) => Some(UICommand::PlaybackStepBack),
+        EventType::ButtonRepeated(Button::DPadRight, _) => Some(UICommand::PlaybackStepForward),
+        _ => None,
+    }
+}
+
+fn gamepad_state(gilrs: &Gilrs, id: GamepadId) -> GamepadState {
+    let gamepad = gilrs.gamepad(id);
+    let trigger = |button| gamepad.button_data(button).map_or(0.0, |data| data.value());
+    let pressed = |button| gamepad.is_pressed(button) as i32 as f32;
+
+    GamepadState {
+        axes: GamepadAxes {
+            move_stick: egui::vec2(
+                gamepad.value(Axis::LeftStickX),
+                gamepad.value(Axis::LeftStickY),
+            ),
+            look_stick: egui::vec2(
+                gamepad.value(Axis::RightStickX),
+                gamepad.value(Axis::RightStickY),
+            ),
+            vertical: trigger(Button::RightTrigger2) - trigger(Button::LeftTrigger2),
+        },
+        scrub: pressed(Button::RightTrigger) - pressed(Button::LeftTrigger),
+    }
+}
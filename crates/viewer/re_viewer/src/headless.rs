@@ -0,0 +1,309 @@
+//! Render views of a recording to images, without opening a window.
+//!
+//! The viewer runs as usual, but its frames are rendered to offscreen textures.
+//! This is what powers `rerun screenshot`, e.g. for golden-image tests in CI.
+
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use egui_kittest::Harness;
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::LatestAtQuery;
+use re_data_source::LogDataSource;
+use re_log_types::{FileSource, Timeline, TimelineName, TimestampFormat};
+use re_types::blueprint::{archetypes as blueprint_archetypes, components::ViewMaximized};
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, ViewId, blueprint_timeline};
+use re_viewport_blueprint::{VIEWPORT_PATH, ViewportBlueprint};
+
+use crate::{
+    App, AppEnvironment, AsyncRuntimeHandle, MainThreadToken, StartupOptions,
+    app_blueprint::PanelStateOverrides, customize_eframe_and_setup_renderer,
+};
+
+/// How long we wait for the recording to load, and the viewer to settle.
+const LOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Number of frames we run before each screenshot, to let the layout and any
+/// lazily loaded data (textures, videos, …) catch up.
+const SETTLE_FRAMES: usize = 10;
+
+/// What to render with [`render_views`].
+#[derive(Clone, Debug)]
+pub struct HeadlessOptions {
+    /// The recording to render.
+    ///
+    /// Anything the viewer can open works, e.g. `.rrd` or `.mcap` files.
+    pub recording: PathBuf,
+
+    /// A `.rbl` blueprint to use, instead of the one in the recording or the default one.
+    pub blueprint: Option<PathBuf>,
+
+    /// Size of the images, in logical points.
+    pub size: egui::Vec2,
+
+    /// Number of pixels per logical point.
+    pub pixels_per_point: f32,
+
+    /// The timeline to use, if not the default one.
+    pub timeline: Option<String>,
+
+    /// The time to render at, on [`Self::timeline`].
+    ///
+    /// Parsed the same way as the time in the time panel, e.g. `42`, `1.5s` or an ISO 8601 timestamp.
+    /// If not set, we render the end of the timeline.
+    pub time: Option<String>,
+
+    /// Names or ids of the views to render, each maximized into its own image.
+    ///
+    /// If empty, the whole viewport is rendered into a single image.
+    pub views: Vec<String>,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            recording: PathBuf::new(),
+            blueprint: None,
+            size: egui::vec2(1024.0, 768.0),
+            pixels_per_point: 1.0,
+            timeline: None,
+            time: None,
+            views: Vec::new(),
+        }
+    }
+}
+
+/// An image produced by [`render_views`].
+pub struct HeadlessRender {
+    /// The display name of the rendered view, or `None` for the whole viewport.
+    pub view_name: Option<String>,
+
+    pub image: image::RgbaImage,
+}
+
+/// Loads a recording, and renders the requested views to images.
+///
+/// Must be called from within a tokio runtime.
+pub fn render_views(
+    main_thread_token: MainThreadToken,
+    app_env: AppEnvironment,
+    options: &HeadlessOptions,
+) -> anyhow::Result<Vec<HeadlessRender>> {
+    re_tracing::profile_function!();
+
+    let async_runtime = AsyncRuntimeHandle::from_current_tokio_runtime_or_wasmbindgen()?;
+
+    let startup_options = StartupOptions {
+        persist_state: false,
+        hide_welcome_screen: true,
+        panel_state_overrides: PanelStateOverrides {
+            top: Some(re_types::blueprint::components::PanelState::Hidden),
+            blueprint: Some(re_types::blueprint::components::PanelState::Hidden),
+            selection: Some(re_types::blueprint::components::PanelState::Hidden),
+            time: Some(re_types::blueprint::components::PanelState::Hidden),
+        },
+        ..Default::default()
+    };
+
+    let mut harness = Harness::builder()
+        .wgpu()
+        .with_size(options.size)
+        .with_pixels_per_point(options.pixels_per_point)
+        .build_eframe(|cc| {
+            if let Err(err) = customize_eframe_and_setup_renderer(cc) {
+                re_log::error!("Failed to set up the renderer: {err}");
+            }
+            App::new(
+                main_thread_token,
+                crate::build_info(),
+                app_env,
+                startup_options,
+                cc,
+                None,
+                async_runtime,
+            )
+        });
+
+    load_file(harness.state(), &options.recording);
+    if let Some(blueprint) = &options.blueprint {
+        load_file(harness.state(), blueprint);
+    }
+    wait_until_loaded(&mut harness)?;
+
+    set_time(harness.state(), options)?;
+
+    if options.views.is_empty() {
+        settle(&mut harness);
+        return Ok(vec![HeadlessRender {
+            view_name: None,
+            image: render(&mut harness)?,
+        }]);
+    }
+
+    let views = find_views(harness.state(), &options.views)?;
+    views
+        .into_iter()
+        .map(|(view_id, view_name)| {
+            maximize_view(harness.state(), view_id)?;
+            settle(&mut harness);
+            Ok(HeadlessRender {
+                view_name: Some(view_name),
+                image: render(&mut harness)?,
+            })
+        })
+        .collect()
+}
+
+fn load_file(app: &App, path: &std::path::Path) {
+    app.command_sender
+        .send_system(SystemCommand::LoadDataSource(LogDataSource::FilePath(
+            FileSource::Cli,
+            path.to_owned(),
+        )));
+}
+
+/// Steps the viewer until everything is loaded, and there is a blueprint to render.
+fn wait_until_loaded(harness: &mut Harness<'_, App>) -> anyhow::Result<()> {
+    let start_time = std::time::Instant::now();
+
+    // Let the data loaders start.
+    harness.step();
+
+    loop {
+        harness.step();
+
+        let app = harness.state();
+        let is_loaded = app.msg_receive_set().is_empty()
+            && app.store_hub.as_ref().is_some_and(|store_hub| {
+                store_hub.active_recording().is_some() && store_hub.active_blueprint().is_some()
+            });
+        if is_loaded {
+            return Ok(());
+        }
+
+        anyhow::ensure!(
+            start_time.elapsed() < LOAD_TIMEOUT,
+            "Timed out waiting for the recording to load"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+/// Pauses the time cursor at the requested time, or at the end of the timeline.
+fn set_time(app: &App, options: &HeadlessOptions) -> anyhow::Result<()> {
+    let recording = app
+        .store_hub
+        .as_ref()
+        .and_then(|store_hub| store_hub.active_recording())
+        .context("No recording loaded")?;
+
+    let timeline = if let Some(name) = &options.timeline {
+        *recording
+            .timelines()
+            .get(&TimelineName::new(name))
+            .with_context(|| format!("Unknown timeline {name:?}"))?
+    } else {
+        // Whatever timeline the viewer picked by default.
+        app.state
+            .recording_config(recording.store_id())
+            .map(|rec_cfg| *rec_cfg.time_ctrl.read().timeline())
+            .unwrap_or_else(Timeline::log_tick)
+    };
+
+    let time = if let Some(time) = &options.time {
+        Some(
+            timeline
+                .typ()
+                .parse_time(time, TimestampFormat::Utc)
+                .with_context(|| format!("Failed to parse {time:?} as a time on {timeline:?}"))?,
+        )
+    } else {
+        recording
+            .time_range_for(timeline.name())
+            .map(|time_range| time_range.max)
+    };
+
+    app.command_sender
+        .send_system(SystemCommand::SetActiveTime {
+            store_id: recording.store_id().clone(),
+            timeline,
+            time: time.map(Into::into),
+        });
+
+    Ok(())
+}
+
+/// Finds the views with the given names or ids, in the order they were requested.
+fn find_views(app: &App, names: &[String]) -> anyhow::Result<Vec<(ViewId, String)>> {
+    let blueprint = app
+        .store_hub
+        .as_ref()
+        .and_then(|store_hub| store_hub.active_blueprint())
+        .context("No blueprint loaded")?;
+    let viewport =
+        ViewportBlueprint::from_db(blueprint, &LatestAtQuery::latest(blueprint_timeline()));
+
+    names
+        .iter()
+        .map(|name| {
+            viewport
+                .views
+                .iter()
+                .find(|(view_id, view)| {
+                    view.display_name_or_default().as_ref() == name
+                        || view_id.uuid().to_string() == *name
+                })
+                .map(|(view_id, view)| (*view_id, view.display_name_or_default().to_string()))
+                .with_context(|| {
+                    let available = viewport
+                        .views
+                        .values()
+                        .map(|view| format!("{:?}", view.display_name_or_default().as_ref()))
+                        .collect::<Vec<_>>();
+                    format!(
+                        "Unknown view {name:?}. Available views: {}",
+                        available.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+fn maximize_view(app: &App, view_id: ViewId) -> anyhow::Result<()> {
+    let blueprint = app
+        .store_hub
+        .as_ref()
+        .and_then(|store_hub| store_hub.active_blueprint())
+        .context("No blueprint loaded")?;
+
+    let chunk = Chunk::builder(VIEWPORT_PATH.into())
+        .with_component_batch(
+            RowId::new(),
+            re_viewer_context::blueprint_timepoint_for_writes(blueprint),
+            (
+                blueprint_archetypes::ViewportBlueprint::descriptor_maximized(),
+                &ViewMaximized(view_id.into()),
+            ),
+        )
+        .build()?;
+
+    app.command_sender.send_system(SystemCommand::AppendToStore(
+        blueprint.store_id().clone(),
+        vec![chunk],
+    ));
+
+    Ok(())
+}
+
+fn settle(harness: &mut Harness<'_, App>) {
+    for _ in 0..SETTLE_FRAMES {
+        harness.step();
+    }
+}
+
+fn render(harness: &mut Harness<'_, App>) -> anyhow::Result<image::RgbaImage> {
+    harness
+        .render()
+        .map_err(|err| anyhow::anyhow!("Failed to render: {err}"))
+}
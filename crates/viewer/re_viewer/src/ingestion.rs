@@ -0,0 +1,197 @@
+//! How fast the data of each source added with [`crate::App::add_log_receiver`] is coming in,
+//! and how far the viewer is behind it.
+
+use std::sync::Arc;
+
+use re_smart_channel::{ChannelRates, ChannelStats, SmartChannelSource};
+
+/// How often we recompute the rates of the sources.
+const RATE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// The ingestion of one of the sources added with [`crate::App::add_log_receiver`],
+/// see [`crate::App::ingestion_stats`].
+///
+/// Tells apart a source that isn't sending anything from one the viewer can't keep up with.
+#[derive(Clone, Debug)]
+pub struct ReceiverIngestion {
+    pub source: Arc<SmartChannelSource>,
+
+    /// Number of messages received but not yet ingested by the viewer.
+    pub queue_len: usize,
+
+    /// Maximum number of queued messages, if the source is bounded.
+    pub capacity: Option<usize>,
+
+    /// `None` until the source was watched for a second.
+    pub rates: Option<ReceiverRates>,
+
+    /// How long the most recently ingested message was queued, in seconds.
+    pub latency_sec: f32,
+}
+
+/// See [`ReceiverIngestion::rates`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReceiverRates {
+    /// Messages sent by the source per second.
+    pub received_per_sec: f64,
+
+    /// Messages ingested by the viewer per second.
+    ///
+    /// If this stays below [`Self::received_per_sec`], the viewer can't keep up.
+    pub ingested_per_sec: f64,
+
+    /// Bytes ingested by the viewer per second.
+    pub ingested_bytes_per_sec: f64,
+}
+
+impl ReceiverIngestion {
+    /// Estimate of how far the viewer is behind the source, in seconds.
+    ///
+    /// This is the time until the queued messages are ingested at the current rate,
+    /// plus the time the most recent message was queued.
+    /// `None` if messages are queued but none are being ingested.
+    pub fn estimated_lag_sec(&self) -> Option<f32> {
+        if self.queue_len == 0 {
+            return Some(self.latency_sec);
+        }
+        let ingested_per_sec = self.rates?.ingested_per_sec;
+        (0.0 < ingested_per_sec)
+            .then(|| self.latency_sec + (self.queue_len as f64 / ingested_per_sec) as f32)
+    }
+
+    /// Is the viewer more than `max_lag_sec` behind the source?
+    pub fn is_behind(&self, max_lag_sec: f32) -> bool {
+        self.queue_len > 0
+            && self
+                .estimated_lag_sec()
+                .is_none_or(|lag_sec| max_lag_sec < lag_sec)
+    }
+}
+
+/// Per-source state of [`Ingestion`].
+struct Tracked {
+    ingestion: ReceiverIngestion,
+
+    /// Bytes ingested so far.
+    total_bytes: u64,
+
+    /// The stats and bytes we last computed the rates from.
+    sampled: (ChannelStats, u64),
+}
+
+/// Tracks the ingestion of every source of the viewer, see [`ReceiverIngestion`].
+#[derive(Default)]
+pub struct Ingestion {
+    tracked: Vec<Tracked>,
+}
+
+impl Ingestion {
+    /// Call for every message ingested from `source`.
+    pub fn on_ingested(&mut self, source: &SmartChannelSource, num_bytes: u64) {
+        if let Some(tracked) = self
+            .tracked
+            .iter_mut()
+            .find(|tracked| *tracked.ingestion.source == *source)
+        {
+            tracked.total_bytes += num_bytes;
+        }
+    }
+
+    /// Call once per frame with the stats of all connected sources.
+    pub fn update(&mut self, rx_stats: Vec<(Arc<SmartChannelSource>, ChannelStats)>) {
+        let mut untracked = std::mem::take(&mut self.tracked);
+
+        self.tracked = rx_stats
+            .into_iter()
+            .map(|(source, latest)| {
+                let previous = untracked
+                    .iter()
+                    .position(|tracked| tracked.ingestion.source == source)
+                    .map(|index| untracked.swap_remove(index));
+
+                let (total_bytes, sampled, rates) = match previous {
+                    Some(previous)
+                        if latest.time.duration_since(previous.sampled.0.time) < RATE_INTERVAL =>
+                    {
+                        (
+                            previous.total_bytes,
+                            previous.sampled,
+                            previous.ingestion.rates,
+                        )
+                    }
+                    Some(previous) => {
+                        let (sampled_stats, sampled_bytes) = previous.sampled;
+                        let rates = latest.rates_since(&sampled_stats).map(
+                            |ChannelRates {
+                                 enqueued_per_sec,
+                                 dequeued_per_sec,
+                             }| {
+                                let dt = latest.time.duration_since(sampled_stats.time);
+                                ReceiverRates {
+                                    received_per_sec: enqueued_per_sec,
+                                    ingested_per_sec: dequeued_per_sec,
+                                    ingested_bytes_per_sec: previous
+                                        .total_bytes
+                                        .saturating_sub(sampled_bytes)
+                                        as f64
+                                        / dt.as_secs_f64(),
+                                }
+                            },
+                        );
+                        (previous.total_bytes, (latest, previous.total_bytes), rates)
+                    }
+                    None => (0, (latest, 0), None),
+                };
+
+                Tracked {
+                    ingestion: ReceiverIngestion {
+                        source,
+                        queue_len: latest.queue_len,
+                        capacity: latest.capacity,
+                        rates,
+                        latency_sec: latest.latency_sec,
+                    },
+                    total_bytes,
+                    sampled,
+                }
+            })
+            .collect();
+    }
+
+    /// The ingestion of every connected source.
+    pub fn receivers(&self) -> impl Iterator<Item = &ReceiverIngestion> {
+        self.tracked.iter().map(|tracked| &tracked.ingestion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingestion(queue_len: usize, ingested_per_sec: Option<f64>) -> ReceiverIngestion {
+        ReceiverIngestion {
+            source: Arc::new(SmartChannelSource::Sdk),
+            queue_len,
+            capacity: None,
+            rates: ingested_per_sec.map(|ingested_per_sec| ReceiverRates {
+                received_per_sec: 0.0,
+                ingested_per_sec,
+                ingested_bytes_per_sec: 0.0,
+            }),
+            latency_sec: 0.5,
+        }
+    }
+
+    #[test]
+    fn lag_includes_the_time_to_ingest_the_queue() {
+        assert_eq!(ingestion(0, None).estimated_lag_sec(), Some(0.5));
+        assert_eq!(ingestion(100, Some(50.0)).estimated_lag_sec(), Some(2.5));
+        assert_eq!(ingestion(100, Some(0.0)).estimated_lag_sec(), None);
+        assert_eq!(ingestion(100, None).estimated_lag_sec(), None);
+
+        assert!(!ingestion(0, None).is_behind(0.1));
+        assert!(ingestion(100, Some(50.0)).is_behind(1.0));
+        assert!(!ingestion(100, Some(50.0)).is_behind(3.0));
+        assert!(ingestion(100, None).is_behind(3.0));
+    }
+}
@@ -9,20 +9,40 @@ mod app;
 mod app_blueprint;
 mod app_state;
 mod background_tasks;
+mod comparison;
+mod custom_panel;
 mod default_views;
 mod docker_detection;
 pub mod env_vars;
 pub mod event;
+mod ingestion;
 mod navigation;
 mod open_url;
+mod recording_diff;
+mod resume_state;
 mod saving;
 mod screenshotter;
 mod startup_options;
+mod time_control_handle;
 mod ui;
 
+#[cfg(feature = "scripting")]
+mod scripting;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod video_export;
+
 #[cfg(feature = "analytics")]
 mod viewer_analytics;
 
+#[cfg(feature = "gamepad")]
+#[cfg(not(target_arch = "wasm32"))]
+mod gamepad;
+
+#[cfg(feature = "headless")]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod headless;
+
 #[cfg(feature = "testing")]
 #[cfg(not(target_arch = "wasm32"))]
 pub mod viewer_test_utils;
@@ -39,16 +59,26 @@ pub mod blueprint;
 
 pub(crate) use {app_state::AppState, ui::memory_panel};
 
-pub use event::{SelectionChangeItem, ViewerEvent, ViewerEventKind};
+pub use event::{
+    PrunedTimeRange, SelectionChangeItem, SubscriptionId, ViewerEvent, ViewerEventKind,
+};
 
 pub use app::App;
+pub use custom_panel::PanelSide;
+pub use ingestion::{ReceiverIngestion, ReceiverRates};
 pub use startup_options::StartupOptions;
+pub use time_control_handle::{TimeControlHandle, TimeControlState};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use video_export::VideoExportSettings;
 
 pub use re_capabilities::MainThreadToken;
 
+pub use re_chunk_store::RetentionPolicy;
+
 pub use re_viewer_context::{
-    AsyncRuntimeHandle, CommandReceiver, CommandSender, SystemCommand, SystemCommandSender,
-    command_channel,
+    AsyncRuntimeHandle, CommandReceiver, CommandSender, HoverCardTarget, PlayState,
+    RecordingMemoryBudget, SystemCommand, SystemCommandSender, command_channel,
 };
 
 pub mod external {
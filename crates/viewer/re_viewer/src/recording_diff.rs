@@ -0,0 +1,318 @@
+//! Lists the differences between the active recording and a baseline, see [`re_entity_db::RecordingDiff`].
+
+use re_chunk_store::{ChunkStoreGeneration, LatestAtQuery};
+use re_data_ui::item_ui;
+use re_entity_db::{DiffStatus, DiffValue, EntityDb, EntityDiff, RecordingDiff};
+use re_log_types::{EntityPath, StoreId, TimeInt};
+use re_types::{archetypes::RecordingInfo, components::Name};
+use re_types_core::ComponentDescriptor;
+use re_ui::{UiExt as _, UiLayout};
+use re_viewer_context::ViewerContext;
+
+/// What the diff was computed from, to only recompute it when something changed.
+#[derive(PartialEq)]
+struct DiffKey {
+    baseline_generation: ChunkStoreGeneration,
+    baseline_query: LatestAtQuery,
+    active_generation: ChunkStoreGeneration,
+    active_query: LatestAtQuery,
+}
+
+/// Compares the active recording with a baseline, entity by entity and component by component.
+///
+/// The baseline is either another recording of the same application, or the active recording itself at a pinned time.
+pub struct RecordingDiffPanel {
+    pub open: bool,
+
+    /// The active recording if `None`.
+    baseline: Option<StoreId>,
+
+    /// The time of the baseline on the current timeline. Follows the active recording if `None`.
+    pinned_time: Option<TimeInt>,
+
+    /// Highlight the entities that differ in all views.
+    highlight_in_views: bool,
+
+    diff: Option<(DiffKey, RecordingDiff)>,
+}
+
+impl Default for RecordingDiffPanel {
+    fn default() -> Self {
+        Self {
+            open: false,
+            baseline: None,
+            pinned_time: None,
+            highlight_in_views: true,
+            diff: None,
+        }
+    }
+}
+
+impl RecordingDiffPanel {
+    /// The entities to highlight in the views, as of the last frame.
+    pub fn highlighted_entities(&self) -> Vec<EntityPath> {
+        match &self.diff {
+            Some((_, diff)) if self.open && self.highlight_in_views => diff
+                .entities
+                .iter()
+                .map(|entity| entity.entity_path.clone())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Must be called before the viewport is shown, since it takes up the remaining space.
+    pub fn show(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        if !self.open {
+            self.diff = None;
+            return;
+        }
+
+        let frame = egui::Frame {
+            fill: ui.visuals().panel_fill,
+            inner_margin: egui::Margin::same(8),
+            ..Default::default()
+        };
+
+        egui::SidePanel::right("recording_diff_panel")
+            .resizable(true)
+            .default_width(350.0)
+            .frame(frame)
+            .show_inside(ui, |ui| self.ui(ctx, ui));
+    }
+
+    fn ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.strong("Recording diff");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_icon_button(&re_ui::icons::CLOSE, "Close")
+                    .clicked()
+                {
+                    self.open = false;
+                }
+            });
+        });
+        ui.separator();
+
+        let active = ctx.recording();
+        if self
+            .baseline
+            .as_ref()
+            .is_some_and(|store_id| !ctx.storage_context.bundle.contains(store_id))
+        {
+            self.baseline = None;
+        }
+
+        self.baseline_ui(ctx, ui, active);
+
+        let baseline = self
+            .baseline
+            .as_ref()
+            .and_then(|store_id| ctx.storage_context.bundle.get(store_id))
+            .unwrap_or(active);
+
+        ui.checkbox(
+            &mut self.highlight_in_views,
+            "Highlight differences in views",
+        );
+        ui.separator();
+
+        let active_query = ctx.current_query();
+        let baseline_query = LatestAtQuery::new(
+            active_query.timeline(),
+            self.pinned_time.unwrap_or(active_query.at()),
+        );
+        let key = DiffKey {
+            baseline_generation: baseline.generation(),
+            baseline_query,
+            active_generation: active.generation(),
+            active_query,
+        };
+
+        if self
+            .diff
+            .as_ref()
+            .is_none_or(|(old_key, _)| old_key != &key)
+        {
+            let diff =
+                RecordingDiff::latest_at(baseline, &key.baseline_query, active, &key.active_query);
+            self.diff = Some((key, diff));
+        }
+        let Some((key, diff)) = &self.diff else {
+            return;
+        };
+
+        if diff.is_empty() {
+            ui.weak("No differences");
+            return;
+        }
+
+        ui.label(format!(
+            "{} added, {} removed, {} changed",
+            diff.num_entities(DiffStatus::Added),
+            diff.num_entities(DiffStatus::Removed),
+            diff.num_entities(DiffStatus::Changed),
+        ));
+
+        egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
+            for entity in &diff.entities {
+                entity_diff_ui(ctx, ui, key, baseline, entity);
+            }
+        });
+    }
+
+    fn baseline_ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui, active: &EntityDb) {
+        let selected_label = self
+            .baseline
+            .as_ref()
+            .and_then(|store_id| ctx.storage_context.bundle.get(store_id))
+            .map_or_else(|| "This recording".to_owned(), recording_label);
+
+        egui::Grid::new("recording_diff_baseline")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Baseline:");
+                egui::ComboBox::from_id_salt("recording_diff_baseline_recording")
+                    .selected_text(selected_label)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.baseline, None, "This recording");
+                        for db in ctx.storage_context.bundle.recordings() {
+                            if db.application_id() == active.application_id()
+                                && db.store_id() != active.store_id()
+                            {
+                                ui.selectable_value(
+                                    &mut self.baseline,
+                                    Some(db.store_id().clone()),
+                                    recording_label(db),
+                                );
+                            }
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Baseline time:");
+                ui.horizontal(|ui| {
+                    if let Some(pinned_time) = self.pinned_time {
+                        let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+                        ui.label(time_type.format(pinned_time, ctx.app_options().timestamp_format));
+                        if ui.button("Unpin").clicked() {
+                            self.pinned_time = None;
+                        }
+                    } else {
+                        ui.label("Current time");
+                    }
+                    if ui
+                        .button("Pin current time")
+                        .on_hover_text("Compare with the baseline as of the current time, even when the time cursor moves")
+                        .clicked()
+                    {
+                        self.pinned_time = Some(ctx.current_query().at());
+                    }
+                });
+                ui.end_row();
+            });
+
+        if self.baseline.is_none() && self.pinned_time.is_none() {
+            ui.weak("Pin a time, or pick another recording, to see what changed");
+        }
+    }
+}
+
+fn entity_diff_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    key: &DiffKey,
+    baseline: &EntityDb,
+    entity: &EntityDiff,
+) {
+    let id = ui.make_persistent_id(("recording_diff", &entity.entity_path));
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+        .show_header(ui, |ui| {
+            status_ui(ui, entity.status);
+            item_ui::entity_path_button(
+                ctx,
+                &key.active_query,
+                ctx.recording(),
+                ui,
+                None,
+                &entity.entity_path,
+            );
+        })
+        .body(|ui| {
+            for component in &entity.components {
+                ui.horizontal(|ui| {
+                    status_ui(ui, component.status);
+                    ui.label(component.component.display_name());
+                });
+
+                egui::Grid::new(("recording_diff_component", &component.component))
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        if let Some(before) = &component.before {
+                            ui.label("Before:");
+                            value_ui(
+                                ctx,
+                                ui,
+                                &key.baseline_query,
+                                baseline,
+                                &entity.entity_path,
+                                &component.component,
+                                before,
+                            );
+                            ui.end_row();
+                        }
+                        if let Some(after) = &component.after {
+                            ui.label("After:");
+                            value_ui(
+                                ctx,
+                                ui,
+                                &key.active_query,
+                                ctx.recording(),
+                                &entity.entity_path,
+                                &component.component,
+                                after,
+                            );
+                            ui.end_row();
+                        }
+                    });
+            }
+        });
+}
+
+fn value_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    query: &LatestAtQuery,
+    db: &EntityDb,
+    entity_path: &EntityPath,
+    component: &ComponentDescriptor,
+    value: &DiffValue,
+) {
+    ctx.component_ui_registry().component_ui_raw(
+        ctx,
+        ui,
+        UiLayout::List,
+        query,
+        db,
+        entity_path,
+        component,
+        value.row_id,
+        value.array.as_ref(),
+    );
+}
+
+fn status_ui(ui: &mut egui::Ui, status: DiffStatus) {
+    let (text, color) = match status {
+        DiffStatus::Added => ("+", ui.tokens().success_text_color),
+        DiffStatus::Removed => ("−", ui.visuals().error_fg_color),
+        DiffStatus::Changed => ("~", ui.visuals().warn_fg_color),
+    };
+    ui.label(egui::RichText::new(text).monospace().strong().color(color))
+        .on_hover_text(format!("{status:?}"));
+}
+
+fn recording_label(db: &EntityDb) -> String {
+    db.recording_info_property::<Name>(&RecordingInfo::descriptor_name())
+        .map_or_else(|| db.recording_id().to_string(), |name| name.to_string())
+}
@@ -0,0 +1,155 @@
+//! UI state of recordings that is kept after they are closed,
+//! so that opening a recording again resumes where the user left off.
+
+use ahash::HashMap;
+
+use re_log_types::StoreId;
+use re_types::blueprint::components::PanelState;
+use re_viewer_context::{ItemCollection, TimeControl, ViewId};
+
+use crate::app_blueprint::PanelStates;
+
+/// How many closed recordings are remembered, the least recently closed ones are forgotten first.
+const MAX_REMEMBERED_RECORDINGS: usize = 64;
+
+/// A serializable [`PanelState`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+enum SavedPanelState {
+    Hidden,
+    Collapsed,
+    Expanded,
+}
+
+impl From<PanelState> for SavedPanelState {
+    fn from(state: PanelState) -> Self {
+        match state {
+            PanelState::Hidden => Self::Hidden,
+            PanelState::Collapsed => Self::Collapsed,
+            PanelState::Expanded => Self::Expanded,
+        }
+    }
+}
+
+impl From<SavedPanelState> for PanelState {
+    fn from(state: SavedPanelState) -> Self {
+        match state {
+            SavedPanelState::Hidden => Self::Hidden,
+            SavedPanelState::Collapsed => Self::Collapsed,
+            SavedPanelState::Expanded => Self::Expanded,
+        }
+    }
+}
+
+/// A serializable [`PanelStates`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SavedPanelStates {
+    top: SavedPanelState,
+    blueprint: SavedPanelState,
+    selection: SavedPanelState,
+    time: SavedPanelState,
+}
+
+impl From<PanelStates> for SavedPanelStates {
+    fn from(states: PanelStates) -> Self {
+        Self {
+            top: states.top.into(),
+            blueprint: states.blueprint.into(),
+            selection: states.selection.into(),
+            time: states.time.into(),
+        }
+    }
+}
+
+impl From<SavedPanelStates> for PanelStates {
+    fn from(states: SavedPanelStates) -> Self {
+        Self {
+            top: states.top.into(),
+            blueprint: states.blueprint.into(),
+            selection: states.selection.into(),
+            time: states.time.into(),
+        }
+    }
+}
+
+/// UI state of one recording.
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RecordingResumeState {
+    /// The active timeline, the time cursor and how the time is played.
+    pub time_ctrl: Option<TimeControl>,
+
+    /// What was selected, if the recording was shown.
+    pub selection: ItemCollection,
+
+    /// Which panels were shown, if the recording was shown.
+    pub panel_states: Option<SavedPanelStates>,
+
+    /// State of views worth keeping as JSON, like the poses of their cameras.
+    ///
+    /// See [`re_viewer_context::ViewState::save`].
+    pub view_states: HashMap<ViewId, String>,
+}
+
+impl RecordingResumeState {
+    pub fn save_view_states(&mut self, view_states: HashMap<ViewId, serde_json::Value>) {
+        self.view_states = view_states
+            .into_iter()
+            .map(|(view_id, state)| (view_id, state.to_string()))
+            .collect();
+    }
+
+    pub fn restored_view_states(&self) -> HashMap<ViewId, serde_json::Value> {
+        self.view_states
+            .iter()
+            .filter_map(|(view_id, state)| Some((*view_id, serde_json::from_str(state).ok()?)))
+            .collect()
+    }
+}
+
+/// The [`RecordingResumeState`] of recently closed recordings, keyed by application and recording id.
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ResumeStates {
+    /// The least recently remembered first.
+    states: Vec<(StoreId, RecordingResumeState)>,
+}
+
+impl ResumeStates {
+    /// Remembers the state of a recording, replacing what was remembered of it before.
+    pub fn remember(&mut self, store_id: StoreId, state: RecordingResumeState) {
+        self.states.retain(|(id, _)| id != &store_id);
+        self.states.push((store_id, state));
+
+        let forgotten = self.states.len().saturating_sub(MAX_REMEMBERED_RECORDINGS);
+        self.states.drain(..forgotten);
+    }
+
+    /// Forgets the state of a recording, returning it.
+    pub fn take(&mut self, store_id: &StoreId) -> Option<RecordingResumeState> {
+        let index = self.states.iter().position(|(id, _)| id == store_id)?;
+        Some(self.states.remove(index).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::StoreKind;
+
+    use super::*;
+
+    #[test]
+    fn forgets_least_recently_remembered() {
+        let store_id = |i: usize| StoreId::new(StoreKind::Recording, "app", format!("rec{i}"));
+
+        let mut states = ResumeStates::default();
+        for i in 0..=MAX_REMEMBERED_RECORDINGS {
+            states.remember(store_id(i), RecordingResumeState::default());
+        }
+        states.remember(store_id(1), RecordingResumeState::default());
+
+        assert!(states.take(&store_id(0)).is_none());
+        assert!(states.take(&store_id(1)).is_some());
+        assert!(states.take(&store_id(1)).is_none());
+        assert!(states.take(&store_id(MAX_REMEMBERED_RECORDINGS)).is_some());
+    }
+}
@@ -64,11 +64,17 @@ pub fn encode_to_file(
     re_tracing::profile_function!();
     use anyhow::Context as _;
 
-    let mut file = std::fs::File::create(path)
+    let file = std::fs::File::create(path)
         .with_context(|| format!("Failed to create file at {path:?}"))?;
 
+    // The index lets the viewer show the file progressively when it is opened again.
     let encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
-    re_log_encoding::encoder::encode(version, encoding_options, messages, &mut file)
-        .map(|_| ())
-        .context("Message encode")
+    let mut encoder =
+        re_log_encoding::encoder::DroppableEncoder::new(version, encoding_options, file)
+            .context("Message encode")?
+            .with_index_footer();
+    for message in messages {
+        encoder.append(&message?).context("Message encode")?;
+    }
+    encoder.finish().context("Message encode")
 }
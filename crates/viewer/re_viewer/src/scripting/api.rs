@@ -0,0 +1,580 @@
+//! The functions exposed to scripts.
+
+use std::{cell::RefCell, rc::Rc};
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_entity_db::InstancePath;
+use re_log_types::{AbsoluteTimeRange, EntityPath, TimeInt, TimelineName};
+use re_types::{
+    ComponentBatch, ComponentDescriptor,
+    blueprint::{
+        archetypes as blueprint_archetypes,
+        components::{ViewMaximized, Visible},
+    },
+};
+use re_viewer_context::{Item, ItemCollection};
+use re_viewport_blueprint::VIEWPORT_PATH;
+
+use super::{
+    BlueprintContext, RecordingContext, ScriptAction, ScriptState, convert::array_to_rhai,
+    item_name,
+};
+
+type State = Rc<RefCell<ScriptState>>;
+
+type RhaiResult<T> = Result<T, Box<rhai::EvalAltResult>>;
+
+/// Printed by `help()`.
+pub const HELP: &str = "\
+Recording:
+  entities()                          all entity paths
+  components(entity)                  all components of an entity, e.g. \"Points3D:positions\"
+  timelines()                         all timeline names
+  latest_at(entity, component)        the latest value at the time cursor, as an array
+  latest_at(entity, component, time)  the latest value at the given time
+  times(entity, component)            all times with data on the current timeline
+  range(entity, component)            all values, as an array of #{time, value}
+  range(entity, component, min, max)  all values in the given time range
+
+Time:
+  timeline(), set_timeline(name)
+  time(), set_time(time)              time is a number, or a string like \"1.5s\"
+  play(), pause()
+
+Selection:
+  selection()                         the selected entities and views
+  select(entity_or_view), select([…]), clear_selection()
+
+Blueprint:
+  views()                             all views, as an array of #{id, name, class, visible}
+  set_view_visible(view, visible)
+  maximize_view(view), restore_views()
+
+Example: jump to the first frame where the scalar at /temperature exceeds 30:
+  let hits = range(\"/temperature\", \"Scalars:scalars\").filter(|r| r.value[0] > 30.0);
+  set_time(hits[0].time);";
+
+pub fn register(engine: &mut rhai::Engine, state: &State) {
+    engine.register_fn("help", || HELP.to_owned());
+
+    // Recording:
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("entities", move || entities(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("components", move |entity: &str| components(&state, entity));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("timelines", move || timelines(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("latest_at", move |entity: &str, component: &str| {
+            latest_at(&state, entity, component, None)
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn(
+            "latest_at",
+            move |entity: &str, component: &str, time: rhai::INT| {
+                latest_at(&state, entity, component, Some(TimeInt::new_temporal(time)))
+            },
+        );
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("times", move |entity: &str, component: &str| {
+            times(&state, entity, component)
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("range", move |entity: &str, component: &str| {
+            range(&state, entity, component, AbsoluteTimeRange::EVERYTHING)
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn(
+            "range",
+            move |entity: &str, component: &str, min: rhai::INT, max: rhai::INT| {
+                range(&state, entity, component, AbsoluteTimeRange::new(min, max))
+            },
+        );
+    }
+
+    // Time:
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("timeline", move || timeline(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("set_timeline", move |name: &str| set_timeline(&state, name));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("time", move || time(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("set_time", move |time: rhai::INT| {
+            set_time(&state, TimeInt::new_temporal(time))
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("set_time", move |time: &str| {
+            let time = parse_time(&state, time)?;
+            set_time(&state, time)
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("play", move || {
+            state.borrow_mut().actions.push(ScriptAction::Play);
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("pause", move || {
+            state.borrow_mut().actions.push(ScriptAction::Pause);
+        });
+    }
+
+    // Selection:
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("selection", move || selection(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("select", move |name: &str| select(&state, &[name.into()]));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("select", move |names: rhai::Array| select(&state, &names));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("clear_selection", move || select(&state, &[]));
+    }
+
+    // Blueprint:
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("views", move || views(&state));
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("set_view_visible", move |view: &str, visible: bool| {
+            set_view_visible(&state, view, visible)
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("maximize_view", move |view: &str| {
+            set_maximized_view(&state, Some(view))
+        });
+    }
+    {
+        let state = Rc::clone(state);
+        engine.register_fn("restore_views", move || set_maximized_view(&state, None));
+    }
+}
+
+// --- Recording ---
+
+fn with_recording<R>(
+    state: &State,
+    f: impl FnOnce(&RecordingContext) -> RhaiResult<R>,
+) -> RhaiResult<R> {
+    let state = state.borrow();
+    let recording = state
+        .context
+        .as_ref()
+        .and_then(|context| context.recording.as_ref())
+        .ok_or("No recording is loaded")?;
+    f(recording)
+}
+
+/// Finds a component of an entity, by identifier (`Points3D:positions`) or name (`positions`).
+fn find_component(
+    recording: &RecordingContext,
+    entity: &str,
+    component: &str,
+) -> RhaiResult<(EntityPath, ComponentDescriptor)> {
+    let entity_path = EntityPath::parse_forgiving(entity);
+    let components = recording
+        .storage
+        .store()
+        .all_components_for_entity_sorted(&entity_path)
+        .ok_or_else(|| format!("Unknown entity {entity:?}"))?;
+
+    let descr = components
+        .iter()
+        .find(|descr| descr.component.as_str() == component)
+        .or_else(|| {
+            components.iter().find(|descr| {
+                descr
+                    .component
+                    .as_str()
+                    .split_once(':')
+                    .is_some_and(|(_archetype, field)| field == component)
+            })
+        })
+        .ok_or_else(|| {
+            let available = components
+                .iter()
+                .map(|descr| format!("{:?}", descr.component.as_str()))
+                .collect::<Vec<_>>();
+            format!(
+                "Unknown component {component:?} on {entity:?}. Available components: {}",
+                available.join(", ")
+            )
+        })?;
+
+    Ok((entity_path, descr.clone()))
+}
+
+fn entities(state: &State) -> RhaiResult<rhai::Array> {
+    with_recording(state, |recording| {
+        Ok(recording
+            .storage
+            .store()
+            .all_entities_sorted()
+            .into_iter()
+            .map(|entity_path| entity_path.to_string().into())
+            .collect())
+    })
+}
+
+fn components(state: &State, entity: &str) -> RhaiResult<rhai::Array> {
+    with_recording(state, |recording| {
+        let components = recording
+            .storage
+            .store()
+            .all_components_for_entity_sorted(&EntityPath::parse_forgiving(entity))
+            .ok_or_else(|| format!("Unknown entity {entity:?}"))?;
+        Ok(components
+            .iter()
+            .map(|descr| descr.component.as_str().into())
+            .collect())
+    })
+}
+
+fn timelines(state: &State) -> RhaiResult<rhai::Array> {
+    with_recording(state, |recording| {
+        Ok(recording
+            .timelines
+            .keys()
+            .map(|name| name.as_str().into())
+            .collect())
+    })
+}
+
+fn latest_at(
+    state: &State,
+    entity: &str,
+    component: &str,
+    time: Option<TimeInt>,
+) -> RhaiResult<rhai::Dynamic> {
+    with_recording(state, |recording| {
+        let (entity_path, descr) = find_component(recording, entity, component)?;
+        let query = LatestAtQuery::new(
+            *recording.timeline.name(),
+            time.or(recording.time).unwrap_or(TimeInt::MAX),
+        );
+
+        let results = recording
+            .storage
+            .cache()
+            .latest_at(&query, &entity_path, [&descr]);
+        Ok(results
+            .component_batch_raw(&descr)
+            .map_or(rhai::Dynamic::UNIT, |array| array_to_rhai(&array).into()))
+    })
+}
+
+fn times(state: &State, entity: &str, component: &str) -> RhaiResult<rhai::Array> {
+    with_recording(state, |recording| {
+        let (entity_path, descr) = find_component(recording, entity, component)?;
+        let timeline = *recording.timeline.name();
+        let query = RangeQuery::new(timeline, AbsoluteTimeRange::EVERYTHING);
+
+        let results = recording
+            .storage
+            .cache()
+            .range(&query, &entity_path, [&descr]);
+        Ok(results
+            .get(&descr)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|chunk| chunk.iter_component_indices(&timeline, &descr))
+            .filter(|(time, _row_id)| !time.is_static())
+            .map(|(time, _row_id)| time.as_i64().into())
+            .collect())
+    })
+}
+
+fn range(
+    state: &State,
+    entity: &str,
+    component: &str,
+    time_range: AbsoluteTimeRange,
+) -> RhaiResult<rhai::Array> {
+    with_recording(state, |recording| {
+        let (entity_path, descr) = find_component(recording, entity, component)?;
+        let timeline = *recording.timeline.name();
+        let query = RangeQuery::new(timeline, time_range);
+
+        let results = recording
+            .storage
+            .cache()
+            .range(&query, &entity_path, [&descr]);
+
+        let mut values = rhai::Array::new();
+        for chunk in results.get(&descr).unwrap_or_default() {
+            for (row_index, (time, _row_id)) in chunk.iter_indices(&timeline).enumerate() {
+                let Some(Ok(array)) = chunk.component_batch_raw(&descr, row_index) else {
+                    continue;
+                };
+                let mut value = rhai::Map::new();
+                value.insert("time".into(), time.as_i64().into());
+                value.insert("value".into(), array_to_rhai(&array).into());
+                values.push(value.into());
+            }
+        }
+        Ok(values)
+    })
+}
+
+// --- Time ---
+
+fn timeline(state: &State) -> RhaiResult<String> {
+    with_recording(state, |recording| {
+        Ok(recording.timeline.name().as_str().to_owned())
+    })
+}
+
+fn set_timeline(state: &State, name: &str) -> RhaiResult<()> {
+    let timeline = with_recording(state, |recording| {
+        recording
+            .timelines
+            .get(&TimelineName::new(name))
+            .copied()
+            .ok_or_else(|| format!("Unknown timeline {name:?}").into())
+    })?;
+
+    let mut state = state.borrow_mut();
+    if let Some(recording) = state
+        .context
+        .as_mut()
+        .and_then(|context| context.recording.as_mut())
+    {
+        recording.timeline = timeline;
+    }
+    state.actions.push(ScriptAction::SetTimeline(timeline));
+    Ok(())
+}
+
+fn time(state: &State) -> RhaiResult<rhai::Dynamic> {
+    with_recording(state, |recording| {
+        Ok(recording
+            .time
+            .map_or(rhai::Dynamic::UNIT, |time| time.as_i64().into()))
+    })
+}
+
+fn parse_time(state: &State, time: &str) -> RhaiResult<TimeInt> {
+    let state = state.borrow();
+    let context = state.context.as_ref().ok_or("No recording is loaded")?;
+    let recording = context.recording.as_ref().ok_or("No recording is loaded")?;
+    recording
+        .timeline
+        .typ()
+        .parse_time(time, context.timestamp_format)
+        .ok_or_else(|| {
+            format!(
+                "Failed to parse {time:?} as a time on {:?}",
+                recording.timeline.name()
+            )
+            .into()
+        })
+}
+
+fn set_time(state: &State, time: TimeInt) -> RhaiResult<()> {
+    let mut state = state.borrow_mut();
+    let recording = state
+        .context
+        .as_mut()
+        .and_then(|context| context.recording.as_mut())
+        .ok_or("No recording is loaded")?;
+    recording.time = Some(time);
+    state.actions.push(ScriptAction::SetTime(time));
+    Ok(())
+}
+
+// --- Selection ---
+
+fn selection(state: &State) -> rhai::Array {
+    let state = state.borrow();
+    let Some(context) = &state.context else {
+        return rhai::Array::new();
+    };
+    context
+        .selection
+        .iter_items()
+        .map(|item| item_name(item, context.blueprint.as_ref()).into())
+        .collect()
+}
+
+/// Selects views (by name or id) and entities (by path).
+fn select(state: &State, names: &[rhai::Dynamic]) -> RhaiResult<()> {
+    let mut state = state.borrow_mut();
+    let context = state.context.as_mut().ok_or("No recording is loaded")?;
+
+    let items = names
+        .iter()
+        .map(|name| {
+            let name = name
+                .clone()
+                .into_string()
+                .map_err(|typ| format!("Expected a view name or entity path, got a {typ}"))?;
+
+            if let Some(view) = context
+                .blueprint
+                .as_ref()
+                .and_then(|blueprint| blueprint.find_view(&name))
+            {
+                return Ok(Item::View(view.id));
+            }
+
+            let entity_path = EntityPath::parse_forgiving(&name);
+            let is_known_entity = context.recording.as_ref().is_some_and(|recording| {
+                recording
+                    .storage
+                    .store()
+                    .all_components_for_entity(&entity_path)
+                    .is_some()
+            });
+            if is_known_entity {
+                Ok(Item::InstancePath(InstancePath::entity_all(entity_path)))
+            } else {
+                Err(format!("Unknown view or entity {name:?}").into())
+            }
+        })
+        .collect::<RhaiResult<Vec<_>>>()?;
+
+    let selection =
+        ItemCollection::from_items_and_context(items.into_iter().map(|item| (item, None)));
+    context.selection = selection.clone();
+    state.actions.push(ScriptAction::SetSelection(selection));
+    Ok(())
+}
+
+// --- Blueprint ---
+
+fn with_blueprint<R>(
+    state: &State,
+    f: impl FnOnce(&BlueprintContext) -> RhaiResult<R>,
+) -> RhaiResult<R> {
+    let state = state.borrow();
+    let blueprint = state
+        .context
+        .as_ref()
+        .and_then(|context| context.blueprint.as_ref())
+        .ok_or("No blueprint is loaded")?;
+    f(blueprint)
+}
+
+fn views(state: &State) -> RhaiResult<rhai::Array> {
+    with_blueprint(state, |blueprint| {
+        Ok(blueprint
+            .views
+            .iter()
+            .map(|view| {
+                let mut map = rhai::Map::new();
+                map.insert("id".into(), view.id.uuid().to_string().into());
+                map.insert("name".into(), view.name.clone().into());
+                map.insert("class".into(), view.class.clone().into());
+                map.insert("visible".into(), view.visible.into());
+                map.into()
+            })
+            .collect())
+    })
+}
+
+fn write_blueprint(
+    state: &State,
+    entity_path: EntityPath,
+    descr: ComponentDescriptor,
+    component: &dyn ComponentBatch,
+) -> RhaiResult<()> {
+    let (store_id, timepoint) = with_blueprint(state, |blueprint| {
+        Ok((blueprint.store_id.clone(), blueprint.timepoint.clone()))
+    })?;
+
+    let chunk = Chunk::builder(entity_path)
+        .with_component_batch(RowId::new(), timepoint, (descr, component))
+        .build()
+        .map_err(|err| err.to_string())?;
+
+    state
+        .borrow_mut()
+        .actions
+        .push(ScriptAction::AppendToBlueprint(store_id, chunk));
+    Ok(())
+}
+
+fn set_view_visible(state: &State, view: &str, visible: bool) -> RhaiResult<()> {
+    let view_id = with_blueprint(state, |blueprint| {
+        blueprint
+            .find_view(view)
+            .map(|view| view.id)
+            .ok_or_else(|| format!("Unknown view {view:?}").into())
+    })?;
+
+    write_blueprint(
+        state,
+        view_id.as_entity_path(),
+        blueprint_archetypes::ViewBlueprint::descriptor_visible(),
+        &Visible::from(visible),
+    )?;
+
+    if let Some(view) = state
+        .borrow_mut()
+        .context
+        .as_mut()
+        .and_then(|context| context.blueprint.as_mut())
+        .and_then(|blueprint| blueprint.views.iter_mut().find(|view| view.id == view_id))
+    {
+        view.visible = visible;
+    }
+    Ok(())
+}
+
+fn set_maximized_view(state: &State, view: Option<&str>) -> RhaiResult<()> {
+    let view_maximized = view
+        .map(|view| {
+            with_blueprint(state, |blueprint| {
+                blueprint
+                    .find_view(view)
+                    .map(|view| ViewMaximized(view.id.into()))
+                    .ok_or_else(|| format!("Unknown view {view:?}").into())
+            })
+        })
+        .transpose()?;
+
+    write_blueprint(
+        state,
+        VIEWPORT_PATH.into(),
+        blueprint_archetypes::ViewportBlueprint::descriptor_maximized(),
+        &view_maximized,
+    )
+}
@@ -0,0 +1,163 @@
+use super::{ScriptAction, ScriptContext, ScriptEngine, ScriptOutput};
+
+enum ConsoleEntry {
+    Input(String),
+    Output(String),
+    Error(String),
+}
+
+/// An interactive console for running scripts, shown at the bottom of the viewer.
+#[derive(Default)]
+pub struct ScriptConsole {
+    engine: ScriptEngine,
+    history: Vec<ConsoleEntry>,
+    input: String,
+
+    /// Scripts submitted by the user, waiting to be run by the app.
+    pending: Vec<String>,
+}
+
+impl ScriptConsole {
+    /// Takes the scripts the user has submitted since the last call.
+    pub fn take_pending(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Runs a script typed into the console, and shows its output.
+    pub fn run(&mut self, context: ScriptContext, source: &str) -> Vec<ScriptAction> {
+        self.history.push(ConsoleEntry::Input(source.to_owned()));
+
+        let ScriptOutput {
+            printed,
+            result,
+            actions,
+        } = self.engine.run(context, source);
+
+        self.history
+            .extend(printed.into_iter().map(ConsoleEntry::Output));
+        match result {
+            Ok(Some(value)) => self.history.push(ConsoleEntry::Output(value)),
+            Ok(None) => {}
+            Err(err) => self.history.push(ConsoleEntry::Error(err)),
+        }
+
+        actions
+    }
+
+    /// Runs a script file, e.g. one passed with `rerun --script`.
+    ///
+    /// The output also goes to the log, since the console may not be open.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_file(
+        &mut self,
+        context: ScriptContext,
+        path: &std::path::Path,
+    ) -> Vec<ScriptAction> {
+        re_tracing::profile_function!();
+
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(err) => {
+                re_log::error!("Failed to read script {}: {err}", path.display());
+                return Vec::new();
+            }
+        };
+
+        self.history
+            .push(ConsoleEntry::Input(format!("// {}", path.display())));
+
+        let ScriptOutput {
+            printed,
+            result,
+            actions,
+        } = self.engine.run(context, &source);
+
+        for text in printed {
+            re_log::info!("{text}");
+            self.history.push(ConsoleEntry::Output(text));
+        }
+        if let Err(err) = result {
+            re_log::error!("Script {} failed: {err}", path.display());
+            self.history.push(ConsoleEntry::Error(err));
+        }
+
+        actions
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.strong("Script console");
+            ui.weak("Rhai. Type help() for the available functions.");
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("Clear").clicked() {
+                    self.history.clear();
+                }
+            });
+        });
+
+        egui::TopBottomPanel::bottom("script_console_input")
+            .frame(egui::Frame::new().inner_margin(egui::Margin::symmetric(0, 4)))
+            .show_inside(ui, |ui| {
+                self.input_ui(ui);
+            });
+
+        egui::ScrollArea::vertical()
+            .auto_shrink(false)
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                self.history_ui(ui);
+            });
+    }
+
+    fn input_ui(&mut self, ui: &mut egui::Ui) {
+        let run = ui
+            .with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                let run_clicked = ui
+                    .button("Run")
+                    .on_hover_text("Run the script (Ctrl+Enter)")
+                    .clicked();
+
+                let response = ui.add(
+                    egui::TextEdit::multiline(&mut self.input)
+                        .code_editor()
+                        .desired_rows(2)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("e.g. entities()"),
+                );
+                let run_pressed = response.has_focus()
+                    && ui.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Enter));
+
+                run_clicked || run_pressed
+            })
+            .inner;
+
+        if run && !self.input.trim().is_empty() {
+            self.pending.push(std::mem::take(&mut self.input));
+        }
+    }
+
+    fn history_ui(&self, ui: &mut egui::Ui) {
+        for entry in &self.history {
+            match entry {
+                ConsoleEntry::Input(source) => {
+                    ui.label(
+                        egui::RichText::new(format!("> {source}"))
+                            .monospace()
+                            .weak(),
+                    );
+                }
+                ConsoleEntry::Output(text) => {
+                    ui.label(egui::RichText::new(text).monospace());
+                }
+                ConsoleEntry::Error(err) => {
+                    ui.label(
+                        egui::RichText::new(err)
+                            .monospace()
+                            .color(ui.visuals().error_fg_color),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Converting Arrow data to Rhai values.
+
+use arrow::{
+    array::{Array, AsArray as _},
+    datatypes::DataType,
+};
+use rhai::Dynamic;
+
+/// Converts every element of an Arrow array to a Rhai value.
+pub fn array_to_rhai(array: &dyn Array) -> rhai::Array {
+    (0..array.len())
+        .map(|index| value_to_rhai(array, index))
+        .collect()
+}
+
+/// Converts a single element of an Arrow array to a Rhai value.
+///
+/// Numbers, booleans and strings map to their Rhai counterparts, lists to arrays,
+/// and structs to object maps. Anything else is formatted as a string.
+pub fn value_to_rhai(array: &dyn Array, index: usize) -> Dynamic {
+    if array.is_null(index) {
+        return Dynamic::UNIT;
+    }
+
+    match array.data_type() {
+        DataType::Boolean => array.as_boolean().value(index).into(),
+
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => {
+            match arrow::compute::cast(&array.slice(index, 1), &DataType::Int64) {
+                Ok(ints) => ints
+                    .as_primitive::<arrow::datatypes::Int64Type>()
+                    .value(0)
+                    .into(),
+                Err(_) => format_value(array, index),
+            }
+        }
+
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            match arrow::compute::cast(&array.slice(index, 1), &DataType::Float64) {
+                Ok(floats) => floats
+                    .as_primitive::<arrow::datatypes::Float64Type>()
+                    .value(0)
+                    .into(),
+                Err(_) => format_value(array, index),
+            }
+        }
+
+        DataType::Utf8 => array.as_string::<i32>().value(index).into(),
+        DataType::LargeUtf8 => array.as_string::<i64>().value(index).into(),
+        DataType::Utf8View => array.as_string_view().value(index).into(),
+
+        DataType::List(_) => array_to_rhai(&array.as_list::<i32>().value(index)).into(),
+        DataType::LargeList(_) => array_to_rhai(&array.as_list::<i64>().value(index)).into(),
+        DataType::FixedSizeList(_, _) => {
+            array_to_rhai(&array.as_fixed_size_list().value(index)).into()
+        }
+
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            fields
+                .iter()
+                .zip(array.columns())
+                .map(|(field, column)| (field.name().as_str().into(), value_to_rhai(column, index)))
+                .collect::<rhai::Map>()
+                .into()
+        }
+
+        _ => format_value(array, index),
+    }
+}
+
+fn format_value(array: &dyn Array, index: usize) -> Dynamic {
+    let options = arrow::util::display::FormatOptions::default();
+    match arrow::util::display::ArrayFormatter::try_new(array, &options) {
+        Ok(formatter) => formatter.value(index).to_string().into(),
+        Err(_) => Dynamic::UNIT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{FixedSizeListArray, Float32Array, StringArray, UInt32Array};
+    use arrow::datatypes::Field;
+
+    use super::*;
+
+    #[test]
+    fn test_array_to_rhai() {
+        let floats = array_to_rhai(&Float32Array::from(vec![Some(1.5), None]));
+        assert_eq!(floats[0].as_float(), Ok(1.5));
+        assert!(floats[1].is_unit());
+
+        let ints = array_to_rhai(&UInt32Array::from(vec![42]));
+        assert_eq!(ints[0].as_int(), Ok(42));
+
+        let strings = array_to_rhai(&StringArray::from(vec!["hello"]));
+        assert_eq!(strings[0].clone().into_string().unwrap(), "hello");
+
+        let positions = FixedSizeListArray::new(
+            Arc::new(Field::new("item", DataType::Float32, false)),
+            2,
+            Arc::new(Float32Array::from(vec![1.0, 2.0, 3.0, 4.0])),
+            None,
+        );
+        let positions = array_to_rhai(&positions);
+        assert_eq!(positions.len(), 2);
+        let second = positions[1].clone().into_array().unwrap();
+        assert_eq!(second[0].as_float(), Ok(3.0));
+        assert_eq!(second[1].as_float(), Ok(4.0));
+    }
+}
@@ -0,0 +1,223 @@
+//! Embedded [Rhai](https://rhai.rs) scripting, for automating repetitive inspection workflows.
+//!
+//! Scripts can query the active recording, and change the selection, time cursor and blueprint,
+//! e.g. to jump to every frame where some scalar crosses a threshold.
+//! They are run from the [`ScriptConsole`], or at startup with `rerun --script`.
+//!
+//! Each script runs against a [`ScriptContext`], a snapshot of the viewer state taken right before.
+//! Any changes it requests are returned as [`ScriptAction`]s, and applied by the app afterwards.
+
+mod api;
+mod console;
+mod convert;
+
+pub use console::ScriptConsole;
+
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use re_chunk::Chunk;
+use re_log_types::{StoreId, TimeInt, TimePoint, Timeline, TimelineName, TimestampFormat};
+use re_query::StorageEngineArcReadGuard;
+use re_viewer_context::{Item, ItemCollection, ViewId};
+
+/// Scripts are interrupted after this many operations, so that a runaway loop can't freeze the viewer.
+///
+/// Scripts run on the UI thread while holding a read lock on the store, which also holds up
+/// ingestion, so this is kept low enough to finish within a few frames.
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// The state of the viewer that a script runs against.
+pub struct ScriptContext {
+    /// The active recording, if any.
+    pub recording: Option<RecordingContext>,
+
+    /// The blueprint of the active recording, if any.
+    pub blueprint: Option<BlueprintContext>,
+
+    /// Currently selected items.
+    pub selection: ItemCollection,
+
+    /// Used when parsing timestamps.
+    pub timestamp_format: TimestampFormat,
+}
+
+pub struct RecordingContext {
+    /// Keeps the store locked while the script runs.
+    pub storage: StorageEngineArcReadGuard,
+
+    pub timelines: BTreeMap<TimelineName, Timeline>,
+
+    /// The timeline of the time cursor.
+    pub timeline: Timeline,
+
+    /// The time of the time cursor, if any.
+    pub time: Option<TimeInt>,
+}
+
+pub struct BlueprintContext {
+    pub store_id: StoreId,
+
+    /// Used for all blueprint writes of a script.
+    pub timepoint: TimePoint,
+
+    pub views: Vec<ViewSummary>,
+}
+
+pub struct ViewSummary {
+    pub id: ViewId,
+    pub name: String,
+    pub class: String,
+    pub visible: bool,
+}
+
+impl BlueprintContext {
+    /// Finds a view by display name or id.
+    fn find_view(&self, name: &str) -> Option<&ViewSummary> {
+        self.views
+            .iter()
+            .find(|view| view.name == name || view.id.uuid().to_string() == name)
+    }
+}
+
+/// A change to the viewer state requested by a script.
+pub enum ScriptAction {
+    SetTimeline(Timeline),
+
+    /// Moves the time cursor, and pauses playback.
+    SetTime(TimeInt),
+
+    Play,
+    Pause,
+    SetSelection(ItemCollection),
+
+    /// Write to the blueprint of the active recording.
+    AppendToBlueprint(StoreId, Chunk),
+}
+
+/// The result of running a script with [`ScriptEngine::run`].
+pub struct ScriptOutput {
+    /// Everything the script printed.
+    pub printed: Vec<String>,
+
+    /// The value the script evaluated to, unless it is `()`, or the error it failed with.
+    pub result: Result<Option<String>, String>,
+
+    /// To be applied by the caller, in order.
+    pub actions: Vec<ScriptAction>,
+}
+
+/// Shared between the [`ScriptEngine`] and the functions it exposes to scripts.
+#[derive(Default)]
+struct ScriptState {
+    /// Only set while a script is running.
+    context: Option<ScriptContext>,
+    actions: Vec<ScriptAction>,
+    printed: Vec<String>,
+}
+
+/// Runs Rhai scripts against the viewer.
+///
+/// Variables and functions defined by one script are available to the ones run after it.
+pub struct ScriptEngine {
+    engine: rhai::Engine,
+    scope: rhai::Scope<'static>,
+    state: Rc<RefCell<ScriptState>>,
+}
+
+impl Default for ScriptEngine {
+    fn default() -> Self {
+        let state = Rc::new(RefCell::new(ScriptState::default()));
+
+        let mut engine = rhai::Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+
+        {
+            let state = Rc::clone(&state);
+            engine.on_print(move |text| state.borrow_mut().printed.push(text.to_owned()));
+        }
+        {
+            let state = Rc::clone(&state);
+            engine.on_debug(move |text, _source, _pos| {
+                state.borrow_mut().printed.push(text.to_owned());
+            });
+        }
+
+        api::register(&mut engine, &state);
+
+        Self {
+            engine,
+            scope: rhai::Scope::new(),
+            state,
+        }
+    }
+}
+
+impl ScriptEngine {
+    pub fn run(&mut self, context: ScriptContext, source: &str) -> ScriptOutput {
+        re_tracing::profile_function!();
+
+        self.state.borrow_mut().context = Some(context);
+
+        let result = self
+            .engine
+            .eval_with_scope::<rhai::Dynamic>(&mut self.scope, source);
+
+        // This also drops the context, which unlocks the store.
+        let ScriptState {
+            context: _,
+            actions,
+            printed,
+        } = std::mem::take(&mut *self.state.borrow_mut());
+
+        ScriptOutput {
+            printed,
+            result: result
+                .map(|value| (!value.is_unit()).then(|| value.to_string()))
+                .map_err(|err| err.to_string()),
+            actions,
+        }
+    }
+}
+
+/// A short description of an [`Item`], for scripts.
+fn item_name(item: &Item, blueprint: Option<&BlueprintContext>) -> String {
+    match item {
+        Item::InstancePath(instance_path) => instance_path.to_string(),
+        Item::ComponentPath(component_path) => component_path.to_string(),
+        Item::DataResult(_, instance_path) => instance_path.to_string(),
+        Item::View(view_id) => blueprint
+            .and_then(|blueprint| blueprint.views.iter().find(|view| view.id == *view_id))
+            .map_or_else(|| view_id.uuid().to_string(), |view| view.name.clone()),
+        _ => format!("{item:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_context() -> ScriptContext {
+        ScriptContext {
+            recording: None,
+            blueprint: None,
+            selection: Default::default(),
+            timestamp_format: TimestampFormat::Utc,
+        }
+    }
+
+    #[test]
+    fn test_scope_is_kept_between_runs() {
+        let mut engine = ScriptEngine::default();
+
+        let output = engine.run(empty_context(), "let x = 40; print(x + 1);");
+        assert_eq!(output.printed, vec!["41".to_owned()]);
+        assert_eq!(output.result, Ok(None));
+
+        let output = engine.run(empty_context(), "x + 2");
+        assert_eq!(output.result, Ok(Some("42".to_owned())));
+
+        let output = engine.run(empty_context(), "entities()");
+        assert!(output.result.is_err());
+        assert!(output.actions.is_empty());
+    }
+}
@@ -7,6 +7,27 @@ pub struct StartupOptions {
     /// When the total process RAM reaches this limit, we GC old data.
     pub memory_limit: re_memory::MemoryLimit,
 
+    /// When a single recording grows beyond this, we GC its oldest data.
+    ///
+    /// This keeps one busy recording from evicting all others once [`Self::memory_limit`] is reached.
+    /// Can be changed at runtime from the memory panel.
+    pub recording_memory_budget: re_viewer_context::RecordingMemoryBudget,
+
+    /// Write the data that is dropped to stay within the memory limits to a temporary file,
+    /// and load it back in when the time cursor returns to it.
+    ///
+    /// Can be changed at runtime from the memory panel. Not supported on the web.
+    pub spill_evicted_chunks: bool,
+
+    /// How long the data of each entity subtree is kept, regardless of the memory limits.
+    ///
+    /// Recordings and blueprints can add their own rules, see [`re_viewer_context::RETENTION_HINTS_PATH`].
+    pub retention_policy: re_chunk_store::RetentionPolicy,
+
+    /// How much time per frame the compaction of recordings may take,
+    /// see [`re_viewer_context::StoreHub::compact_recording`].
+    pub compaction_time_budget: std::time::Duration,
+
     pub persist_state: bool,
 
     /// Whether or not the app is running in the context of a Jupyter Notebook.
@@ -32,6 +53,25 @@ pub struct StartupOptions {
     #[cfg(not(target_arch = "wasm32"))]
     pub resolution_in_points: Option<[f32; 2]>,
 
+    /// Rhai scripts to run once the first recording is loaded.
+    ///
+    /// See the script console for the functions available to them.
+    #[cfg(feature = "scripting")]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub startup_scripts: Vec<std::path::PathBuf>,
+
+    /// A blueprint (`.rbl`) file to load at startup.
+    ///
+    /// It becomes the active blueprint of the application it was saved for.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub blueprint_path: Option<std::path::PathBuf>,
+
+    /// Save the active blueprint to this file when the viewer is closed.
+    ///
+    /// See also [`crate::App::save_blueprint_to_file`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub save_blueprint_on_exit: Option<std::path::PathBuf>,
+
     /// This is a hint that we expect a recording to stream in very soon.
     ///
     /// This is set by the `spawn()` method in our logging SDK.
@@ -63,6 +103,11 @@ pub struct StartupOptions {
     /// Default overrides for state of top/side/bottom panels.
     pub panel_state_overrides: PanelStateOverrides,
 
+    /// Changes to the colors, fonts and theme of the UI, for matching the branding of an app embedding the viewer.
+    ///
+    /// See also [`crate::App::set_custom_theme`].
+    pub custom_theme: Option<re_ui::CustomTheme>,
+
     /// Whether or not to enable usage of the `History` API on web.
     ///
     /// It is disabled by default.
@@ -81,6 +126,10 @@ pub struct StartupOptions {
 }
 
 impl StartupOptions {
+    /// Leaves most of a 60 Hz frame to the rest of the viewer.
+    pub const DEFAULT_COMPACTION_TIME_BUDGET: std::time::Duration =
+        std::time::Duration::from_millis(4);
+
     /// Returns `StartupOptions::enable_history` on web, and `false` on native.
     #[allow(clippy::unused_self)] // Only used on web.
     pub fn web_history_enabled(&self) -> bool {
@@ -100,6 +149,10 @@ impl Default for StartupOptions {
     fn default() -> Self {
         Self {
             memory_limit: re_memory::MemoryLimit::from_fraction_of_total(0.75),
+            recording_memory_budget: Default::default(),
+            spill_evicted_chunks: false,
+            retention_policy: Default::default(),
+            compaction_time_budget: Self::DEFAULT_COMPACTION_TIME_BUDGET,
             persist_state: true,
             is_in_notebook: false,
 
@@ -117,6 +170,16 @@ impl Default for StartupOptions {
             #[cfg(not(target_arch = "wasm32"))]
             resolution_in_points: None,
 
+            #[cfg(feature = "scripting")]
+            #[cfg(not(target_arch = "wasm32"))]
+            startup_scripts: Vec::new(),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            blueprint_path: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            save_blueprint_on_exit: None,
+
             expect_data_soon: None,
             force_wgpu_backend: None,
             video_decoder_hw_acceleration: None,
@@ -128,6 +191,8 @@ impl Default for StartupOptions {
 
             panel_state_overrides: Default::default(),
 
+            custom_theme: None,
+
             #[cfg(target_arch = "wasm32")]
             enable_history: false,
         }
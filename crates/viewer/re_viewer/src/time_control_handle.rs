@@ -0,0 +1,156 @@
+//! Controlling the time of the viewer from the host application, see [`crate::App::time_control_handle`].
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use re_entity_db::EntityDb;
+use re_log_types::{StoreId, TimeReal, Timeline, TimelineName};
+use re_viewer_context::{PlayState, TimeControl};
+
+/// The time control of the active recording, as of the last frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeControlState {
+    /// The active recording.
+    pub store_id: StoreId,
+
+    pub timeline: Timeline,
+
+    /// The time cursor, if the timeline has any data.
+    pub time: Option<TimeReal>,
+
+    pub play_state: PlayState,
+
+    /// Playback speed, where 1.0 is real-time.
+    pub speed: f32,
+}
+
+enum TimeControlCommand {
+    SetTimeline(TimelineName),
+    SetTime(TimeReal),
+    SetPlayState(PlayState),
+    SetSpeed(f32),
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Option<TimeControlState>,
+    pending: Vec<TimeControlCommand>,
+}
+
+/// Reads and changes the time control of the active recording, e.g. to follow an external simulation clock.
+///
+/// Can be cloned and sent to other threads.
+/// Changes are applied in order at the start of the next frame, and a repaint is requested.
+#[derive(Clone)]
+pub struct TimeControlHandle {
+    shared: Arc<Mutex<Shared>>,
+    egui_ctx: egui::Context,
+}
+
+impl TimeControlHandle {
+    pub(crate) fn new(egui_ctx: egui::Context) -> Self {
+        Self {
+            shared: Default::default(),
+            egui_ctx,
+        }
+    }
+
+    /// The time control of the active recording as of the last frame, if there is one.
+    ///
+    /// Changes made through this handle are only visible after the next frame.
+    pub fn state(&self) -> Option<TimeControlState> {
+        self.shared.lock().state.clone()
+    }
+
+    /// Switches to the timeline with the given name.
+    ///
+    /// Ignored if the active recording has no such timeline.
+    pub fn set_timeline(&self, timeline: impl Into<TimelineName>) {
+        self.send(TimeControlCommand::SetTimeline(timeline.into()));
+    }
+
+    /// Moves the time cursor on the current timeline, and pauses playback.
+    pub fn set_time(&self, time: impl Into<TimeReal>) {
+        self.send(TimeControlCommand::SetTime(time.into()));
+    }
+
+    pub fn set_play_state(&self, play_state: PlayState) {
+        self.send(TimeControlCommand::SetPlayState(play_state));
+    }
+
+    #[inline]
+    pub fn play(&self) {
+        self.set_play_state(PlayState::Playing);
+    }
+
+    #[inline]
+    pub fn pause(&self) {
+        self.set_play_state(PlayState::Paused);
+    }
+
+    /// Sets the playback speed, where 1.0 is real-time.
+    pub fn set_speed(&self, speed: f32) {
+        self.send(TimeControlCommand::SetSpeed(speed));
+    }
+
+    fn send(&self, command: TimeControlCommand) {
+        self.shared.lock().pending.push(command);
+        self.egui_ctx.request_repaint();
+    }
+
+    /// Applies the changes requested since the last frame.
+    pub(crate) fn apply_pending(
+        &self,
+        entity_db: Option<&EntityDb>,
+        time_ctrl: Option<&mut TimeControl>,
+    ) {
+        let pending = std::mem::take(&mut self.shared.lock().pending);
+        let (Some(entity_db), Some(time_ctrl)) = (entity_db, time_ctrl) else {
+            if !pending.is_empty() {
+                re_log::debug!("Ignoring time control changes: no active recording");
+            }
+            return;
+        };
+
+        for command in pending {
+            match command {
+                TimeControlCommand::SetTimeline(name) => {
+                    if let Some(timeline) = entity_db.timelines().get(&name) {
+                        time_ctrl.set_timeline(*timeline);
+                    } else {
+                        re_log::warn_once!("Unknown timeline {name:?}");
+                    }
+                }
+                TimeControlCommand::SetTime(time) => {
+                    time_ctrl.set_time(time);
+                    time_ctrl.pause();
+                }
+                TimeControlCommand::SetPlayState(play_state) => {
+                    time_ctrl.set_play_state(entity_db.times_per_timeline(), play_state);
+                }
+                TimeControlCommand::SetSpeed(speed) => {
+                    time_ctrl.set_speed(speed);
+                }
+            }
+        }
+    }
+
+    /// Publishes the state at the end of a frame.
+    pub(crate) fn update_state(
+        &self,
+        entity_db: Option<&EntityDb>,
+        time_ctrl: Option<&TimeControl>,
+    ) {
+        self.shared.lock().state =
+            entity_db
+                .zip(time_ctrl)
+                .map(|(entity_db, time_ctrl)| TimeControlState {
+                    store_id: entity_db.store_id().clone(),
+                    timeline: *time_ctrl.timeline(),
+                    time: time_ctrl.time(),
+                    play_state: time_ctrl.play_state(),
+                    speed: time_ctrl.speed(),
+                });
+    }
+}
@@ -0,0 +1,141 @@
+use re_ui::UiExt as _;
+use re_ui::modal::{ModalHandler, ModalWrapper};
+use re_viewer_context::HighResScreenshotRequest;
+
+/// Lets the user pick the resolution of a screenshot of a single view.
+#[derive(Default)]
+pub struct HighResScreenshotModal {
+    modal: ModalHandler,
+
+    /// The request being edited.
+    request: Option<HighResScreenshotRequest>,
+
+    /// Width divided by height, kept while editing if `keep_aspect_ratio` is set.
+    aspect_ratio: f32,
+    keep_aspect_ratio: bool,
+}
+
+impl HighResScreenshotModal {
+    const MAX_RESOLUTION: u32 = 16_384;
+
+    pub fn open(&mut self, request: HighResScreenshotRequest) {
+        let [width, height] = request.resolution;
+        self.aspect_ratio = width as f32 / height.max(1) as f32;
+        self.keep_aspect_ratio = true;
+        self.request = Some(request);
+        self.modal.open();
+    }
+
+    /// Returns the screenshot to take, once the user clicks "Save".
+    pub fn ui(&mut self, egui_ctx: &egui::Context) -> Option<HighResScreenshotRequest> {
+        let Self {
+            modal,
+            request,
+            aspect_ratio,
+            keep_aspect_ratio,
+        } = self;
+
+        let mut save = false;
+
+        modal.ui(
+            egui_ctx,
+            || ModalWrapper::new("Save screenshot").max_width(400.0),
+            |ui| {
+                let Some(request) = request else {
+                    ui.close();
+                    return;
+                };
+
+                ui.label(format!(
+                    "Renders {:?} again at the chosen resolution. \
+                    Labels and other overlays are not included.",
+                    request.name
+                ));
+
+                ui.add_space(8.0);
+
+                let [width, height] = &mut request.resolution;
+                egui::Grid::new("high_res_screenshot_settings")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Width:");
+                        if ui
+                            .add(
+                                egui::DragValue::new(width)
+                                    .range(1..=Self::MAX_RESOLUTION)
+                                    .suffix(" px"),
+                            )
+                            .changed()
+                            && *keep_aspect_ratio
+                        {
+                            *height = ((*width as f32 / *aspect_ratio).round() as u32)
+                                .clamp(1, Self::MAX_RESOLUTION);
+                        }
+                        ui.end_row();
+
+                        ui.label("Height:");
+                        if ui
+                            .add(
+                                egui::DragValue::new(height)
+                                    .range(1..=Self::MAX_RESOLUTION)
+                                    .suffix(" px"),
+                            )
+                            .changed()
+                            && *keep_aspect_ratio
+                        {
+                            *width = ((*height as f32 * *aspect_ratio).round() as u32)
+                                .clamp(1, Self::MAX_RESOLUTION);
+                        }
+                        ui.end_row();
+
+                        ui.label("Supersampling:");
+                        ui.horizontal(|ui| {
+                            for factor in [1, 2, 4] {
+                                ui.selectable_value(
+                                    &mut request.supersampling,
+                                    factor,
+                                    format!("{factor}×"),
+                                );
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                if ui
+                    .re_checkbox(keep_aspect_ratio, "Keep aspect ratio")
+                    .changed()
+                {
+                    *aspect_ratio = request.resolution[0] as f32 / request.resolution[1] as f32;
+                }
+
+                if request.supersampling > 1 {
+                    ui.weak(
+                        "The view is rendered at a higher resolution and scaled down, \
+                        for smoother edges. The GPU may lower the factor for very large images.",
+                    );
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let button_width = ui.tokens().modal_button_width;
+
+                    if ui
+                        .add(egui::Button::new("Save").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        save = true;
+                        ui.close();
+                    }
+
+                    if ui
+                        .add(egui::Button::new("Cancel").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        ui.close();
+                    }
+                });
+            },
+        );
+
+        if save { self.request.take() } else { None }
+    }
+}
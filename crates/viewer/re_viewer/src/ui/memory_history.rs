@@ -108,6 +108,8 @@ impl MemoryHistory {
                     query_cache_stats,
                     viewer_cache_size,
                     cache_memory_reports: _,
+                    num_spilled_chunks: _,
+                    spilled_size_bytes: _,
                 } = stats;
 
                 match store_id.kind() {
@@ -1,12 +1,18 @@
+use re_capabilities::MainThreadToken;
 use re_chunk_store::{ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreStats};
 use re_format::{format_bytes, format_uint};
 use re_memory::{MemoryLimit, MemoryUse, util::sec_since_start};
 use re_query::{QueryCacheStats, QueryCachesStats};
 use re_renderer::WgpuResourcePoolStatistics;
 use re_ui::UiExt as _;
-use re_viewer_context::{CacheMemoryReport, store_hub::StoreHubStats};
+use re_viewer_context::{
+    CacheMemoryReport, CommandSender, RecordingMemoryBudget, store_hub::StoreHubStats,
+};
 
-use crate::env_vars::RERUN_TRACK_ALLOCATIONS;
+use crate::{
+    env_vars::RERUN_TRACK_ALLOCATIONS,
+    ingestion::{Ingestion, ReceiverIngestion},
+};
 
 use super::memory_history::MemoryHistory;
 
@@ -40,8 +46,13 @@ impl MemoryPanel {
         &self,
         ui: &mut egui::Ui,
         limit: &MemoryLimit,
+        recording_budget: &mut RecordingMemoryBudget,
+        spill_evicted_chunks: &mut bool,
         gpu_resource_stats: &WgpuResourcePoolStatistics,
         store_stats: Option<&StoreHubStats>,
+        ingestion: &Ingestion,
+        command_sender: &CommandSender,
+        main_thread_token: MainThreadToken,
     ) {
         re_tracing::profile_function!();
 
@@ -53,7 +64,17 @@ impl MemoryPanel {
             .min_width(250.0)
             .default_width(300.0)
             .show_inside(ui, |ui| {
-                Self::left_side(ui, limit, gpu_resource_stats, store_stats);
+                Self::left_side(
+                    ui,
+                    limit,
+                    recording_budget,
+                    spill_evicted_chunks,
+                    gpu_resource_stats,
+                    store_stats,
+                    ingestion,
+                    command_sender,
+                    main_thread_token,
+                );
             });
 
         egui::CentralPanel::default().show_inside(ui, |ui| {
@@ -62,17 +83,35 @@ impl MemoryPanel {
         });
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn left_side(
         ui: &mut egui::Ui,
         limit: &MemoryLimit,
+        recording_budget: &mut RecordingMemoryBudget,
+        spill_evicted_chunks: &mut bool,
         gpu_resource_stats: &WgpuResourcePoolStatistics,
         store_stats: Option<&StoreHubStats>,
+        ingestion: &Ingestion,
+        command_sender: &CommandSender,
+        main_thread_token: MainThreadToken,
     ) {
         ui.strong("Rerun Viewer resource usage");
 
         ui.separator();
         ui.collapsing("CPU Resources", |ui| {
-            Self::cpu_stats(ui, limit);
+            Self::cpu_stats(
+                ui,
+                limit,
+                recording_budget,
+                spill_evicted_chunks,
+                command_sender,
+                main_thread_token,
+            );
+        });
+
+        ui.separator();
+        ui.collapsing("Memory per Subsystem", |ui| {
+            Self::region_stats(ui);
         });
 
         ui.separator();
@@ -80,6 +119,11 @@ impl MemoryPanel {
             Self::gpu_stats(ui, gpu_resource_stats);
         });
 
+        ui.separator();
+        ui.collapsing("Ingestion", |ui| {
+            Self::ingestion_stats(ui, ingestion);
+        });
+
         if let Some(store_stats) = store_stats {
             ui.separator();
             ui.collapsing("Store Stats", |ui| {
@@ -92,6 +136,13 @@ impl MemoryPanel {
                                 &store_stats.store_config,
                                 &store_stats.store_stats,
                             );
+                            if store_stats.num_spilled_chunks > 0 {
+                                ui.label(format!(
+                                    "Spilled to disk: {} chunks ({})",
+                                    format_uint(store_stats.num_spilled_chunks),
+                                    format_bytes(store_stats.spilled_size_bytes as _)
+                                ));
+                            }
                         });
 
                         ui.separator();
@@ -136,6 +187,72 @@ impl MemoryPanel {
         }
     }
 
+    fn ingestion_stats(ui: &mut egui::Ui, ingestion: &Ingestion) {
+        if ingestion.receivers().next().is_none() {
+            ui.label("Not receiving any data.");
+            return;
+        }
+
+        egui::Grid::new("ingestion grid")
+            .num_columns(7)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Source").underline());
+                ui.label(egui::RichText::new("Queued").underline())
+                    .on_hover_text("Messages received but not yet ingested by the viewer");
+                ui.label(egui::RichText::new("In (msg/s)").underline());
+                ui.label(egui::RichText::new("Out (msg/s)").underline())
+                    .on_hover_text(
+                        "If this stays below the incoming rate, the viewer can't keep up with the data",
+                    );
+                ui.label(egui::RichText::new("Out (bytes/s)").underline());
+                ui.label(egui::RichText::new("Latency").underline())
+                    .on_hover_text("Time from the data arriving to the viewer ingesting it");
+                ui.label(egui::RichText::new("Lag").underline())
+                    .on_hover_text(
+                        "Estimate of how far the viewer is behind the source, \
+                        including the time to ingest the queued messages",
+                    );
+                ui.end_row();
+
+                for receiver in ingestion.receivers() {
+                    let ReceiverIngestion {
+                        source,
+                        queue_len,
+                        capacity,
+                        rates,
+                        latency_sec,
+                    } = receiver;
+
+                    ui.label(source.to_string());
+                    if let Some(capacity) = capacity {
+                        ui.label(format!(
+                            "{} / {}",
+                            format_uint(*queue_len),
+                            format_uint(*capacity)
+                        ));
+                    } else {
+                        ui.label(format_uint(*queue_len));
+                    }
+                    if let Some(rates) = rates {
+                        ui.label(format!("{:.1}", rates.received_per_sec));
+                        ui.label(format!("{:.1}", rates.ingested_per_sec));
+                        ui.label(format_bytes(rates.ingested_bytes_per_sec));
+                    } else {
+                        ui.label("-");
+                        ui.label("-");
+                        ui.label("-");
+                    }
+                    ui.label(format!("{:.0} ms", 1e3 * latency_sec));
+                    if let Some(lag_sec) = receiver.estimated_lag_sec() {
+                        ui.label(format!("{lag_sec:.1} s"));
+                    } else {
+                        ui.label("∞");
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
     fn cache_memory_report(ui: &mut egui::Ui, name: &str, report: &CacheMemoryReport) {
         ui.horizontal(|ui| {
             ui.label(format!(
@@ -173,7 +290,14 @@ impl MemoryPanel {
         }
     }
 
-    fn cpu_stats(ui: &mut egui::Ui, limit: &MemoryLimit) {
+    fn cpu_stats(
+        ui: &mut egui::Ui,
+        limit: &MemoryLimit,
+        recording_budget: &mut RecordingMemoryBudget,
+        spill_evicted_chunks: &mut bool,
+        command_sender: &CommandSender,
+        main_thread_token: MainThreadToken,
+    ) {
         if let Some(max_bytes) = limit.max_bytes {
             ui.label(format!("Memory limit: {}", format_bytes(max_bytes as _)));
         } else {
@@ -185,6 +309,16 @@ impl MemoryPanel {
             ui.separator();
         }
 
+        Self::recording_budget_ui(ui, limit, recording_budget);
+
+        if !cfg!(target_arch = "wasm32") {
+            ui.re_checkbox(spill_evicted_chunks, "Spill dropped data to disk")
+                .on_hover_text(
+                    "Instead of discarding data to stay within the memory limits, write it to a \
+                    temporary file, and load it back in when you scrub back to it",
+                );
+        }
+
         let mem_use = MemoryUse::capture();
 
         if mem_use.resident.is_some() || mem_use.counted.is_some() {
@@ -207,6 +341,7 @@ impl MemoryPanel {
         re_memory::accounting_allocator::set_tracking_callstacks(is_tracking_callstacks);
 
         if let Some(tracking_stats) = re_memory::accounting_allocator::tracking_stats() {
+            Self::heap_profile_export(ui, command_sender, main_thread_token);
             ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
             Self::tracking_stats(ui, tracking_stats);
         } else if !cfg!(target_arch = "wasm32") {
@@ -216,6 +351,45 @@ impl MemoryPanel {
         }
     }
 
+    fn region_stats(ui: &mut egui::Ui) {
+        let mut is_tracking_regions = re_memory::accounting_allocator::is_tracking_regions();
+        if ui
+            .re_checkbox(&mut is_tracking_regions, "Track memory per subsystem")
+            .on_hover_text(
+                "Attribute allocations to the subsystem that made them. \
+                This will slow down the program a bit, and only counts allocations made while turned on.",
+            )
+            .changed()
+        {
+            re_memory::accounting_allocator::set_tracking_regions(is_tracking_regions);
+        }
+
+        let Some(region_stats) = re_memory::accounting_allocator::region_stats() else {
+            return;
+        };
+
+        if re_memory::accounting_allocator::global_allocs().is_none() {
+            ui.label("Memory-tracking allocator not installed.");
+            return;
+        }
+
+        egui::Grid::new("memory region grid")
+            .num_columns(3)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Subsystem").underline());
+                ui.label(egui::RichText::new("Bytes").underline());
+                ui.label(egui::RichText::new("Allocations").underline());
+                ui.end_row();
+
+                for (region, count_and_size) in region_stats {
+                    ui.label(region.name());
+                    ui.label(format_bytes(count_and_size.size as _));
+                    ui.label(format_uint(count_and_size.count));
+                    ui.end_row();
+                }
+            });
+    }
+
     fn gpu_stats(ui: &mut egui::Ui, gpu_resource_stats: &WgpuResourcePoolStatistics) {
         egui::Grid::new("gpu resource grid")
             .num_columns(2)
@@ -280,6 +454,8 @@ impl MemoryPanel {
                 let ChunkStoreStats {
                     static_chunks,
                     temporal_chunks,
+                    static_bytes_deduplicated,
+                    bytes_memory_mapped,
                 } = *store_stats;
 
                 ui.label(egui::RichText::new("Stats").italics());
@@ -315,6 +491,34 @@ impl MemoryPanel {
                 ui.label("Total:");
                 label_chunk_stats(ui, static_chunks + temporal_chunks);
                 ui.end_row();
+
+                if static_bytes_deduplicated > 0 {
+                    ui.label("Deduplicated:").on_hover_text(
+                        "Static data that was logged more than once, but is only kept in memory once",
+                    );
+                    ui.label("");
+                    ui.label("");
+                    ui.label("");
+                    ui.label(format!(
+                        "-{}",
+                        re_format::format_bytes(static_bytes_deduplicated as _)
+                    ));
+                    ui.end_row();
+                }
+
+                if bytes_memory_mapped > 0 {
+                    ui.label("Memory-mapped:").on_hover_text(
+                        "Data kept in temporary files, which is only read into RAM when it is needed",
+                    );
+                    ui.label("");
+                    ui.label("");
+                    ui.label("");
+                    ui.label(format!(
+                        "-{}",
+                        re_format::format_bytes(bytes_memory_mapped as _)
+                    ));
+                    ui.end_row();
+                }
             });
     }
 
@@ -398,6 +602,121 @@ impl MemoryPanel {
         }
     }
 
+    fn recording_budget_ui(
+        ui: &mut egui::Ui,
+        limit: &MemoryLimit,
+        recording_budget: &mut RecordingMemoryBudget,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label("Per-recording budget:");
+
+            let selected_text = match recording_budget {
+                RecordingMemoryBudget::Unlimited => "Unlimited",
+                RecordingMemoryBudget::Bytes(_) => "Bytes",
+                RecordingMemoryBudget::FractionOfLimit(_) => "Fraction of limit",
+            };
+            egui::ComboBox::from_id_salt("recording_memory_budget")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(
+                        recording_budget,
+                        RecordingMemoryBudget::Unlimited,
+                        "Unlimited",
+                    );
+                    if ui
+                        .selectable_label(
+                            matches!(recording_budget, RecordingMemoryBudget::Bytes(_)),
+                            "Bytes",
+                        )
+                        .clicked()
+                        && !matches!(recording_budget, RecordingMemoryBudget::Bytes(_))
+                    {
+                        *recording_budget = RecordingMemoryBudget::Bytes(1_000_000_000);
+                    }
+                    if ui
+                        .selectable_label(
+                            matches!(recording_budget, RecordingMemoryBudget::FractionOfLimit(_)),
+                            "Fraction of limit",
+                        )
+                        .clicked()
+                        && !matches!(recording_budget, RecordingMemoryBudget::FractionOfLimit(_))
+                    {
+                        *recording_budget = RecordingMemoryBudget::FractionOfLimit(0.5);
+                    }
+                });
+
+            match recording_budget {
+                RecordingMemoryBudget::Unlimited => {}
+                RecordingMemoryBudget::Bytes(bytes) => {
+                    ui.add(
+                        egui::DragValue::new(bytes)
+                            .speed(1_000_000.0)
+                            .range(1_000_000..=u64::MAX)
+                            .custom_formatter(|bytes, _| format_bytes(bytes))
+                            .custom_parser(|text| re_format::parse_bytes(text).map(|b| b as f64)),
+                    );
+                }
+                RecordingMemoryBudget::FractionOfLimit(fraction) => {
+                    ui.add_enabled(
+                        limit.is_limited(),
+                        egui::DragValue::new(fraction)
+                            .speed(0.01)
+                            .range(0.01..=1.0)
+                            .custom_formatter(|fraction, _| format!("{:.0}%", 100.0 * fraction))
+                            .custom_parser(|text| {
+                                text.trim_end_matches('%')
+                                    .parse::<f64>()
+                                    .ok()
+                                    .map(|percentage| percentage / 100.0)
+                            }),
+                    )
+                    .on_disabled_hover_text("There is no memory limit to take a fraction of");
+                }
+            }
+        })
+        .response
+        .on_hover_text("When a recording grows beyond this, its oldest data is dropped");
+    }
+
+    /// Lets the user save all tracked callstacks, for inspection with external profiling tools.
+    fn heap_profile_export(
+        ui: &mut egui::Ui,
+        command_sender: &CommandSender,
+        main_thread_token: MainThreadToken,
+    ) {
+        ui.horizontal(|ui| {
+            let save_pprof = ui
+                .button("Save heap profile")
+                .on_hover_text("pprof format, e.g. for `go tool pprof -http=: heap.pb`");
+            let save_folded = ui
+                .button("Save flamegraph")
+                .on_hover_text("Folded stacks, e.g. for `inferno-flamegraph` or speedscope");
+
+            if !save_pprof.clicked() && !save_folded.clicked() {
+                return;
+            }
+            let Some(profile) = re_memory::accounting_allocator::heap_profile() else {
+                return;
+            };
+
+            if save_pprof.clicked() {
+                command_sender.save_file_dialog(
+                    main_thread_token,
+                    "heap.pb",
+                    "Save heap profile".to_owned(),
+                    profile.to_pprof(),
+                );
+            } else {
+                command_sender.save_file_dialog(
+                    main_thread_token,
+                    "heap.folded",
+                    "Save flamegraph".to_owned(),
+                    profile.to_folded().into_bytes(),
+                );
+            }
+        });
+    }
+
     fn tracking_stats(
         ui: &mut egui::Ui,
         tracking_stats: re_memory::accounting_allocator::TrackingStatistics,
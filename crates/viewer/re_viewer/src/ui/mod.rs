@@ -1,3 +1,4 @@
+mod high_res_screenshot_modal;
 mod memory_history;
 mod mobile_warning_ui;
 mod open_url_modal;
@@ -5,13 +6,20 @@ mod rerun_menu;
 mod top_panel;
 mod welcome_screen;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod video_export_modal;
+
 pub(crate) mod memory_panel;
 mod settings_screen;
 
 // ----
 
 pub(crate) use {
+    self::high_res_screenshot_modal::HighResScreenshotModal,
     self::mobile_warning_ui::mobile_warning_ui, self::top_panel::top_panel,
     self::welcome_screen::WelcomeScreen, open_url_modal::OpenUrlModal,
     settings_screen::settings_screen_ui,
 };
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use self::video_export_modal::VideoExportModal;
@@ -56,7 +56,9 @@ impl App {
         ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
         // no wrapping: make as wide as needed
 
-        ui.menu_button("About", |ui| self.about_rerun_ui(ui, render_state));
+        ui.menu_button(re_ui::tr("About"), |ui| {
+            self.about_rerun_ui(ui, render_state)
+        });
 
         ui.add_space(SPACING);
 
@@ -75,6 +77,8 @@ impl App {
 
         UICommand::SaveBlueprint.menu_button_ui(ui, &self.command_sender);
 
+        UICommand::SaveViewScreenshot.menu_button_ui(ui, &self.command_sender);
+
         UICommand::CloseCurrentRecording.menu_button_ui(ui, &self.command_sender);
 
         ui.add_space(SPACING);
@@ -107,7 +111,12 @@ impl App {
             UICommand::OpenProfiler.menu_button_ui(ui, &self.command_sender);
 
             UICommand::ToggleMemoryPanel.menu_button_ui(ui, &self.command_sender);
+
+            #[cfg(feature = "scripting")]
+            UICommand::ToggleScriptConsole.menu_button_ui(ui, &self.command_sender);
             UICommand::ToggleChunkStoreBrowser.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleComparison.menu_button_ui(ui, &self.command_sender);
+            UICommand::ToggleRecordingDiff.menu_button_ui(ui, &self.command_sender);
 
             #[cfg(debug_assertions)]
             UICommand::ToggleEguiDebugPanel.menu_button_ui(ui, &self.command_sender);
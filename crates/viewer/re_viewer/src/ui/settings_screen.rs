@@ -1,7 +1,7 @@
 use egui::{NumExt as _, Ui};
 
 use re_log_types::TimestampFormat;
-use re_ui::{DesignTokens, UiExt as _};
+use re_ui::{DesignTokens, Keymap, KeymapCommand, UICommand, UiExt as _};
 use re_viewer_context::AppOptions;
 
 pub fn settings_screen_ui(ui: &mut egui::Ui, app_options: &mut AppOptions, keep_open: &mut bool) {
@@ -39,7 +39,7 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
 
     ui.horizontal(|ui| {
         ui.add(egui::Label::new(
-            egui::RichText::new("Settings")
+            egui::RichText::new(re_ui::tr("Settings"))
                 .strong()
                 .line_height(Some(32.0))
                 .text_style(DesignTokens::welcome_screen_h2()),
@@ -50,7 +50,7 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
             egui::Layout::right_to_left(egui::Align::Center),
             |ui| {
                 if ui
-                    .small_icon_button(&re_ui::icons::CLOSE, "Close")
+                    .small_icon_button(&re_ui::icons::CLOSE, re_ui::tr("Close"))
                     .clicked()
                 {
                     *keep_open = false;
@@ -65,20 +65,27 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
 
     separator_with_some_space(ui);
 
-    ui.strong("General");
+    ui.strong(re_ui::tr("General"));
 
-    ui.horizontal(|ui| {
-        ui.label("Theme:");
-        egui::global_theme_preference_buttons(ui);
-    });
+    language_ui(ui, app_options);
+
+    if !re_ui::is_theme_forced() {
+        ui.horizontal(|ui| {
+            ui.label(re_ui::tr("Theme:"));
+            egui::global_theme_preference_buttons(ui);
+        });
+    }
 
     ui.re_checkbox(
         &mut app_options.include_rerun_examples_button_in_recordings_panel,
         "Show 'Rerun examples' button",
     );
 
-    ui.re_checkbox(&mut app_options.show_metrics, "Show performance metrics")
-        .on_hover_text("Show metrics for milliseconds/frame and RAM usage in the top bar");
+    ui.re_checkbox(
+        &mut app_options.show_metrics,
+        re_ui::tr("Show performance metrics"),
+    )
+    .on_hover_text("Show metrics for milliseconds/frame and RAM usage in the top bar");
 
     //
     // Timezone
@@ -86,7 +93,7 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
 
     separator_with_some_space(ui);
 
-    ui.strong("Timezone");
+    ui.strong(re_ui::tr("Timezone"));
     ui.re_radio_value(
         &mut app_options.timestamp_format,
         TimestampFormat::Utc,
@@ -96,7 +103,7 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
     ui.re_radio_value(
         &mut app_options.timestamp_format,
         TimestampFormat::LocalTimezone,
-        "Local",
+        re_ui::tr("Local"),
     )
     .on_hover_text("Display timestamps in the local timezone");
     ui.re_radio_value(
@@ -112,7 +119,7 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
 
     separator_with_some_space(ui);
 
-    ui.strong("Map view");
+    ui.strong(re_ui::tr("Map view"));
 
     ui.horizontal(|ui| {
         // TODO(ab): needed for alignment, we should use egui flex instead
@@ -138,6 +145,14 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
     ui.strong("Video");
     video_section_ui(ui, app_options);
 
+    //
+    // Keyboard shortcuts
+    //
+
+    separator_with_some_space(ui);
+    ui.strong(re_ui::tr("Keyboard shortcuts"));
+    keyboard_shortcuts_ui(ui);
+
     //
     // Experimental features
     //
@@ -148,10 +163,43 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
     // Currently there are no experimental features
     if false {
         separator_with_some_space(ui);
-        ui.strong("Experimental features");
+        ui.strong(re_ui::tr("Experimental features"));
     }
 }
 
+fn language_ui(ui: &mut Ui, app_options: &mut AppOptions) {
+    const ENGLISH: &str = "English";
+
+    let locales = re_ui::i18n::available_locales();
+    let selected_name = app_options
+        .locale
+        .as_ref()
+        .map_or(ENGLISH, |selected| {
+            locales
+                .iter()
+                .find(|(locale, _)| locale.eq_ignore_ascii_case(selected))
+                .map_or(selected.as_str(), |(_, name)| name.as_str())
+        })
+        .to_owned();
+
+    ui.horizontal(|ui| {
+        ui.label(re_ui::tr("Language:"));
+
+        let previous_locale = app_options.locale.clone();
+        egui::ComboBox::from_id_salt("ui_language")
+            .selected_text(selected_name)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut app_options.locale, None, ENGLISH);
+                for (locale, name) in locales {
+                    ui.selectable_value(&mut app_options.locale, Some(locale), name);
+                }
+            });
+        if app_options.locale != previous_locale {
+            re_ui::i18n::set_locale(app_options.locale.as_deref());
+        }
+    });
+}
+
 fn video_section_ui(ui: &mut Ui, app_options: &mut AppOptions) {
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -255,6 +303,172 @@ fn ffmpeg_path_status_ui(ui: &mut Ui, app_options: &AppOptions) {
     }
 }
 
+/// Lists the shortcuts of all commands, and lets the user rebind them.
+fn keyboard_shortcuts_ui(ui: &mut Ui) {
+    use itertools::Itertools as _;
+    use strum::IntoEnumIterator as _;
+
+    let egui_ctx = ui.ctx().clone();
+    let os = egui_ctx.os();
+    let keymap = Keymap::from_ctx(&egui_ctx);
+
+    // The id of the command waiting for a new shortcut, if any.
+    let recording_id = egui::Id::new("keyboard_shortcut_recording");
+    let mut recording: Option<String> = ui.data(|data| data.get_temp(recording_id));
+
+    let rows = UICommand::iter()
+        .map(|command| {
+            (
+                command.id().to_owned(),
+                command.text().to_owned(),
+                keymap.kb_shortcuts(command, os).to_vec(),
+            )
+        })
+        .chain(keymap.custom_commands().iter().map(|command| {
+            (
+                command.id.clone(),
+                command.text.clone(),
+                keymap.custom_kb_shortcuts(command),
+            )
+        }))
+        .collect_vec();
+
+    let command_text = |command: &KeymapCommand| match command {
+        KeymapCommand::Ui(command) => command.text().to_owned(),
+        KeymapCommand::Custom(id) => keymap
+            .custom_commands()
+            .iter()
+            .find(|custom| &custom.id == id)
+            .map_or_else(|| id.clone(), |custom| custom.text.clone()),
+    };
+
+    let mut edit: Option<Box<dyn FnOnce(&mut Keymap)>> = None;
+
+    ui.label("Click a shortcut to change it.");
+
+    egui::Grid::new("keyboard_shortcuts")
+        .num_columns(3)
+        .striped(true)
+        .show(ui, |ui| {
+            for (id, text, shortcuts) in rows {
+                ui.label(&text);
+
+                if recording.as_deref() == Some(id.as_str()) {
+                    let response = ui.button("Press a shortcut…");
+                    // Keeps the app from running the command of the pressed shortcut.
+                    response.request_focus();
+
+                    if ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape)) {
+                        recording = None;
+                    } else if let Some(shortcut) = ui.input(|i| pressed_shortcut(os, &i.events)) {
+                        recording = None;
+                        let id = id.clone();
+                        edit = Some(Box::new(move |keymap: &mut Keymap| {
+                            keymap.rebind(id, vec![shortcut]);
+                        }));
+                    }
+                } else {
+                    let label = if shortcuts.is_empty() {
+                        "–".to_owned()
+                    } else {
+                        shortcuts
+                            .iter()
+                            .map(|shortcut| egui_ctx.format_shortcut(shortcut))
+                            .join(", ")
+                    };
+                    if ui.button(label).clicked() {
+                        recording = Some(id.clone());
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    if !shortcuts.is_empty()
+                        && ui
+                            .small_icon_button(&re_ui::icons::REMOVE, "Remove shortcut")
+                            .clicked()
+                    {
+                        let id = id.clone();
+                        edit = Some(Box::new(move |keymap: &mut Keymap| {
+                            keymap.rebind(id, Vec::new());
+                        }));
+                    }
+
+                    if keymap.is_rebound(&id) {
+                        if ui
+                            .small_icon_button(&re_ui::icons::RESET, "Reset to default")
+                            .clicked()
+                        {
+                            let id = id.clone();
+                            edit = Some(Box::new(move |keymap: &mut Keymap| {
+                                keymap.reset(&id);
+                            }));
+                        }
+
+                        let conflicts = shortcuts
+                            .iter()
+                            .flat_map(|shortcut| keymap.conflicts(&id, *shortcut, os))
+                            .map(|command| command_text(&command))
+                            .join(", ");
+                        if !conflicts.is_empty() {
+                            ui.label(
+                                egui::RichText::new(format!("Also used by {conflicts}"))
+                                    .color(ui.visuals().warn_fg_color),
+                            );
+                        }
+                    }
+                });
+
+                ui.end_row();
+            }
+        });
+
+    if ui.button("Reset all shortcuts").clicked() {
+        edit = Some(Box::new(Keymap::reset_all));
+    }
+
+    ui.data_mut(|data| match &recording {
+        Some(id) => data.insert_temp(recording_id, id.clone()),
+        None => data.remove::<String>(recording_id),
+    });
+
+    if let Some(edit) = edit {
+        Keymap::update_ctx(&egui_ctx, |keymap| {
+            edit(keymap);
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Err(err) = keymap.save_user_bindings() {
+                re_log::error!("Failed to save keyboard shortcuts: {err}");
+            }
+        });
+    }
+}
+
+/// The first key pressed with its modifiers, as a shortcut that works the same on all platforms.
+fn pressed_shortcut(
+    os: egui::os::OperatingSystem,
+    events: &[egui::Event],
+) -> Option<egui::KeyboardShortcut> {
+    events.iter().find_map(|event| match event {
+        egui::Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } => {
+            let mut modifiers = *modifiers;
+            if modifiers.command {
+                // `command` is `ctrl` on Windows and Linux, and `mac_cmd` on Mac.
+                modifiers.mac_cmd = false;
+                if os != egui::os::OperatingSystem::Mac {
+                    modifiers.ctrl = false;
+                }
+            }
+            Some(egui::KeyboardShortcut::new(modifiers, *key))
+        }
+        _ => None,
+    })
+}
+
 fn separator_with_some_space(ui: &mut egui::Ui) {
     ui.add_space(10.0);
     ui.separator();
@@ -127,6 +127,8 @@ fn top_bar_ui(
             }
         }
 
+        ingestion_backlog_ui(ui, app);
+
         if cfg!(debug_assertions) && !app.app_env().is_test() {
             multi_pass_warning_dot_ui(ui);
         }
@@ -307,6 +309,28 @@ fn connection_status_ui(ui: &mut egui::Ui, rx: &ReceiveSet<re_log_types::LogMsg>
         }
     }
 
+    // Lets the user inspect a moment of a live stream, without new data racing past.
+    // The data keeps buffering up in the meantime.
+    let is_paused = sources.iter().any(|source| rx.is_paused(source));
+    let (icon, alt_text) = if is_paused {
+        (&re_ui::icons::PLAY, "Resume ingestion")
+    } else {
+        (&re_ui::icons::PAUSE, "Pause ingestion")
+    };
+    if ui
+        .small_icon_button(icon, alt_text)
+        .on_hover_text(alt_text)
+        .clicked()
+    {
+        for source in &sources {
+            if is_paused {
+                rx.resume(source);
+            } else {
+                rx.pause(source);
+            }
+        }
+    }
+
     fn source_label(ui: &mut egui::Ui, source: &SmartChannelSource) -> egui::Response {
         let response = ui.label(source.status_string());
 
@@ -517,6 +541,55 @@ fn memory_use_label_ui(ui: &mut egui::Ui, gpu_resource_stats: &WgpuResourcePoolS
     }
 }
 
+/// Shows how far behind the viewer is with the data of any receiver that it can't keep up with,
+/// so that a backlog isn't mistaken for a lack of data.
+fn ingestion_backlog_ui(ui: &mut egui::Ui, app: &App) {
+    let max_lag_sec = app.app_options().warn_e2e_latency;
+    let behind = app
+        .ingestion_stats()
+        .into_iter()
+        .filter(|receiver| receiver.is_behind(max_lag_sec))
+        .collect::<Vec<_>>();
+    if behind.is_empty() {
+        return;
+    }
+
+    let queue_len: usize = behind.iter().map(|receiver| receiver.queue_len).sum();
+    let lag_sec = behind
+        .iter()
+        .map(|receiver| receiver.estimated_lag_sec())
+        .try_fold(0.0_f32, |max, lag_sec| Some(max.max(lag_sec?)));
+
+    ui.separator();
+    let text = match lag_sec {
+        Some(lag_sec) => format!(
+            "Catching up: {} queued, {lag_sec:.1} s behind",
+            format_uint(queue_len)
+        ),
+        None => format!("Stalled: {} queued", format_uint(queue_len)),
+    };
+    ui.weak(text).on_hover_ui(|ui| {
+        ui.ctx().request_repaint();
+        ui.label("The viewer is behind the data it receives:");
+        for receiver in &behind {
+            let rate = receiver.rates.map_or_else(
+                || "-".to_owned(),
+                |rates| {
+                    format!(
+                        "{}/s",
+                        re_format::format_bytes(rates.ingested_bytes_per_sec)
+                    )
+                },
+            );
+            ui.label(format!(
+                "{}: {} queued, ingesting {rate}",
+                receiver.source,
+                format_uint(receiver.queue_len)
+            ));
+        }
+    });
+}
+
 /// Shows the e2e latency.
 fn latency_snapshot_button_ui(
     ui: &mut egui::Ui,
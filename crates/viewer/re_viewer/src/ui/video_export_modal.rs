@@ -0,0 +1,123 @@
+use re_log_types::{AbsoluteTimeRange, TimestampFormat};
+use re_ui::UiExt as _;
+use re_ui::modal::{ModalHandler, ModalWrapper};
+
+use crate::video_export::VideoExportSettings;
+
+/// Lets the user pick the time range and options before exporting a view as a video.
+#[derive(Default)]
+pub struct VideoExportModal {
+    modal: ModalHandler,
+
+    /// The settings being edited.
+    settings: Option<VideoExportSettings>,
+    view_name: String,
+
+    /// Everything that is on the timeline.
+    full_range: AbsoluteTimeRange,
+
+    /// The loop selection in the time panel, if any.
+    loop_selection: Option<AbsoluteTimeRange>,
+}
+
+impl VideoExportModal {
+    pub fn open(
+        &mut self,
+        settings: VideoExportSettings,
+        view_name: String,
+        full_range: AbsoluteTimeRange,
+        loop_selection: Option<AbsoluteTimeRange>,
+    ) {
+        self.settings = Some(settings);
+        self.view_name = view_name;
+        self.full_range = full_range;
+        self.loop_selection = loop_selection;
+        self.modal.open();
+    }
+
+    /// Returns the settings to export with, once the user clicks "Export".
+    pub fn ui(
+        &mut self,
+        egui_ctx: &egui::Context,
+        timestamp_format: TimestampFormat,
+    ) -> Option<VideoExportSettings> {
+        let Self {
+            modal,
+            settings,
+            view_name,
+            full_range,
+            loop_selection,
+        } = self;
+
+        let mut export = false;
+
+        modal.ui(
+            egui_ctx,
+            || ModalWrapper::new("Export video").max_width(400.0),
+            |ui| {
+                let Some(settings) = settings else {
+                    ui.close();
+                    return;
+                };
+                let typ = settings.timeline.typ();
+
+                ui.label(format!(
+                    "Plays back {view_name:?} on the {:?} timeline, and saves it as an MP4. \
+                    Leave the view visible until the export is done, or press Escape to cancel.",
+                    settings.timeline.name().as_str()
+                ));
+
+                ui.add_space(8.0);
+
+                ui.radio_value(
+                    &mut settings.time_range,
+                    *full_range,
+                    format!(
+                        "Whole timeline ({})",
+                        typ.format_range(*full_range, timestamp_format)
+                    ),
+                );
+                if let Some(loop_selection) = *loop_selection {
+                    ui.radio_value(
+                        &mut settings.time_range,
+                        loop_selection,
+                        format!(
+                            "Loop selection ({})",
+                            typ.format_range(loop_selection, timestamp_format)
+                        ),
+                    );
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Frames per second:");
+                    ui.add(egui::DragValue::new(&mut settings.fps).range(1.0..=120.0));
+                });
+
+                ui.re_checkbox(&mut settings.include_scrubber, "Include time scrubber");
+
+                ui.weak(format!("{} frames", settings.num_frames()));
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let button_width = ui.tokens().modal_button_width;
+
+                    if ui
+                        .add(egui::Button::new("Export").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        export = true;
+                        ui.close();
+                    }
+
+                    if ui
+                        .add(egui::Button::new("Cancel").min_size(egui::vec2(button_width, 0.0)))
+                        .clicked()
+                    {
+                        ui.close();
+                    }
+                });
+            },
+        );
+
+        if export { self.settings.take() } else { None }
+    }
+}
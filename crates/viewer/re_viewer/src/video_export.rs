@@ -0,0 +1,277 @@
+//! Export a view as a video, see [`VideoExportSettings`].
+//!
+//! We step the time cursor through the time range one frame at a time, take a screenshot of the
+//! view for each, and stream the screenshots to `ffmpeg` for encoding.
+
+use re_log_types::{AbsoluteTimeRange, StoreId, TimeInt, TimeType, Timeline};
+use re_video::{EncodeError, Mp4Encoder};
+use re_viewer_context::ViewId;
+
+/// Height of the burned-in time scrubber, in ui points.
+const SCRUBBER_HEIGHT: f32 = 12.0;
+
+/// What to export with [`crate::App::export_view_video`].
+#[derive(Clone, Debug)]
+pub struct VideoExportSettings {
+    /// The view to export.
+    ///
+    /// It needs to be visible on screen while the export is running.
+    pub view_id: ViewId,
+
+    /// The recording to play back.
+    pub store_id: StoreId,
+
+    /// The timeline to play back on.
+    pub timeline: Timeline,
+
+    /// The time range to export, inclusive.
+    pub time_range: AbsoluteTimeRange,
+
+    /// Frames per second of the video.
+    ///
+    /// Temporal timelines are played back in real time,
+    /// sequence timelines at one step per frame.
+    pub fps: f32,
+
+    /// Draw a time scrubber below the view, showing the progress through the time range.
+    pub include_scrubber: bool,
+}
+
+impl VideoExportSettings {
+    /// Total number of frames in the video.
+    pub fn num_frames(&self) -> u64 {
+        let span = self.time_range.abs_length();
+        match self.timeline.typ() {
+            TimeType::Sequence => span + 1,
+            TimeType::DurationNs | TimeType::TimestampNs => {
+                (span as f64 / self.nanos_per_frame()) as u64 + 1
+            }
+        }
+    }
+
+    /// The time we show in the given frame.
+    pub fn frame_time(&self, frame_index: u64) -> TimeInt {
+        let offset = match self.timeline.typ() {
+            TimeType::Sequence => frame_index as i64,
+            TimeType::DurationNs | TimeType::TimestampNs => {
+                (frame_index as f64 * self.nanos_per_frame()).round() as i64
+            }
+        };
+        TimeInt::new_temporal(self.time_range.min().as_i64().saturating_add(offset))
+    }
+
+    fn nanos_per_frame(&self) -> f64 {
+        1e9 / self.fps.max(1.0) as f64
+    }
+
+    /// How far through the time range the given time is, in `0..=1`.
+    fn progress(&self, time: TimeInt) -> f32 {
+        let span = self.time_range.abs_length();
+        if span == 0 {
+            1.0
+        } else {
+            (time.as_i64() - self.time_range.min().as_i64()) as f32 / span as f32
+        }
+    }
+}
+
+/// Attached to the screenshots we take for a [`VideoExport`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VideoExportFrame {
+    /// What portion of the UI to use, in ui points.
+    pub ui_rect: egui::Rect,
+    pub pixels_per_point: f32,
+}
+
+/// What a [`VideoExport`] wants to do this frame.
+pub enum VideoExportStep {
+    /// Waiting for the screenshot of the previous frame.
+    Wait,
+
+    /// Show this time, and take a screenshot.
+    Render(TimeInt),
+
+    /// All frames are encoded.
+    Done,
+}
+
+/// An ongoing export of a view as a video.
+pub struct VideoExport {
+    pub settings: VideoExportSettings,
+
+    /// Used as the file name.
+    pub name: String,
+
+    ffmpeg_path: Option<std::path::PathBuf>,
+
+    /// Created when we know the size of the frames.
+    encoder: Option<Mp4Encoder>,
+
+    /// Height of the burned-in scrubber at the bottom of each frame, in pixels.
+    scrubber_height: usize,
+
+    next_frame: u64,
+    awaiting_screenshot: bool,
+}
+
+impl VideoExport {
+    pub fn new(
+        settings: VideoExportSettings,
+        name: String,
+        ffmpeg_path: Option<std::path::PathBuf>,
+    ) -> Self {
+        Self {
+            settings,
+            name,
+            ffmpeg_path,
+            encoder: None,
+            scrubber_height: 0,
+            next_frame: 0,
+            awaiting_screenshot: false,
+        }
+    }
+
+    /// Call once at the start of each frame, before the UI is shown.
+    pub fn begin_frame(&mut self) -> VideoExportStep {
+        if self.awaiting_screenshot {
+            VideoExportStep::Wait
+        } else if self.next_frame < self.settings.num_frames() {
+            self.awaiting_screenshot = true;
+            VideoExportStep::Render(self.settings.frame_time(self.next_frame))
+        } else {
+            VideoExportStep::Done
+        }
+    }
+
+    /// Encodes the screenshot taken for the current frame.
+    pub fn add_frame(
+        &mut self,
+        screenshot: &egui::ColorImage,
+        frame: &VideoExportFrame,
+    ) -> Result<(), EncodeError> {
+        re_tracing::profile_function!();
+
+        let view = screenshot.region(&frame.ui_rect, Some(frame.pixels_per_point));
+
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => {
+                // H.264 wants even sizes.
+                let width = view.width() & !1;
+                if self.settings.include_scrubber {
+                    self.scrubber_height = (SCRUBBER_HEIGHT * frame.pixels_per_point) as usize & !1;
+                }
+                let height = (view.height() & !1) + self.scrubber_height;
+                self.encoder.insert(Mp4Encoder::new(
+                    self.ffmpeg_path.as_deref(),
+                    width as u32,
+                    height as u32,
+                    self.settings.fps,
+                )?)
+            }
+        };
+
+        let [width, height] = encoder.size();
+        let (width, height) = (width as usize, height as usize);
+
+        let view_height = height - self.scrubber_height;
+
+        // The view may have been resized since the first frame, so we crop or pad to the video size.
+        let mut rgba = vec![0_u8; width * height * 4];
+        for y in 0..view_height.min(view.height()) {
+            for x in 0..width.min(view.width()) {
+                let color = view.pixels[y * view.width() + x];
+                rgba[(y * width + x) * 4..][..4].copy_from_slice(&color.to_array());
+            }
+        }
+
+        if 0 < self.scrubber_height {
+            let time = self.settings.frame_time(self.next_frame);
+            paint_scrubber(
+                &mut rgba[view_height * width * 4..],
+                width,
+                self.scrubber_height,
+                self.settings.progress(time),
+            );
+        }
+
+        encoder.push_frame(&rgba)?;
+
+        self.next_frame += 1;
+        self.awaiting_screenshot = false;
+
+        Ok(())
+    }
+
+    /// Finishes encoding, and returns the MP4 file.
+    pub fn finish(self) -> Result<Vec<u8>, EncodeError> {
+        match self.encoder {
+            Some(encoder) => encoder.finish(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Paints a simple time scrubber into a strip of RGBA pixels: a track,
+/// filled up to the current time, and a playhead.
+fn paint_scrubber(rgba: &mut [u8], width: usize, height: usize, progress: f32) {
+    const BACKGROUND: [u8; 4] = [17, 17, 17, 255];
+    const TRACK: [u8; 4] = [64, 64, 64, 255];
+    const FILL: [u8; 4] = [58, 117, 222, 255];
+    const PLAYHEAD: [u8; 4] = [255, 255, 255, 255];
+
+    let margin = height;
+    let track_start = margin.min(width);
+    let track_end = width.saturating_sub(margin).max(track_start);
+    let playhead =
+        track_start + ((track_end - track_start) as f32 * progress.clamp(0.0, 1.0)) as usize;
+
+    let track_half_height = (height / 6).max(1);
+    let track_rows = (height / 2).saturating_sub(track_half_height)..height / 2 + track_half_height;
+    let playhead_half_width = (height / 8).max(1);
+
+    for y in 0..height {
+        for x in 0..width {
+            let color = if x + playhead_half_width > playhead && x < playhead + playhead_half_width
+            {
+                PLAYHEAD
+            } else if track_rows.contains(&y) && (track_start..track_end).contains(&x) {
+                if x < playhead { FILL } else { TRACK }
+            } else {
+                BACKGROUND
+            };
+            rgba[(y * width + x) * 4..][..4].copy_from_slice(&color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_times() {
+        let settings = VideoExportSettings {
+            view_id: ViewId::random(),
+            store_id: StoreId::random(re_log_types::StoreKind::Recording, "test"),
+            timeline: Timeline::new_duration("time"),
+            time_range: AbsoluteTimeRange::new(1_000_000_000, 2_000_000_000),
+            fps: 10.0,
+            include_scrubber: true,
+        };
+        assert_eq!(settings.num_frames(), 11);
+        assert_eq!(settings.frame_time(0), TimeInt::new_temporal(1_000_000_000));
+        assert_eq!(
+            settings.frame_time(10),
+            TimeInt::new_temporal(2_000_000_000)
+        );
+
+        let settings = VideoExportSettings {
+            timeline: Timeline::new_sequence("frame"),
+            time_range: AbsoluteTimeRange::new(5, 7),
+            ..settings
+        };
+        assert_eq!(settings.num_frames(), 3);
+        assert_eq!(settings.frame_time(2), TimeInt::new_temporal(7));
+    }
+}
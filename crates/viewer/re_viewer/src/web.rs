@@ -759,6 +759,10 @@ fn create_app(
             // On wasm32 we only have 4GB of memory to play around with.
             max_bytes: Some(2_500_000_000),
         },
+        recording_memory_budget: Default::default(),
+        spill_evicted_chunks: false,
+        retention_policy: Default::default(),
+        compaction_time_budget: crate::StartupOptions::DEFAULT_COMPACTION_TIME_BUDGET,
         location: Some(cc.integration_info.web_info.location.clone()),
         persist_state: persist.unwrap_or(true),
         is_in_notebook: notebook.unwrap_or(false),
@@ -780,6 +784,7 @@ fn create_app(
 
         fullscreen_options: fullscreen.clone(),
         panel_state_overrides: panel_state_overrides.unwrap_or_default().into(),
+        custom_theme: None,
 
         enable_history,
     };
@@ -0,0 +1,71 @@
+#![cfg(feature = "headless")]
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{
+    ApplicationId, LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreKind, StoreSource, TimePoint,
+};
+use re_types::archetypes::Points3D;
+use re_viewer::{AppEnvironment, MainThreadToken, headless};
+
+/// Writes a recording with a handful of points to an `.rrd` file.
+fn write_points_rrd(path: &std::path::Path) {
+    let store_id = StoreId::new(
+        StoreKind::Recording,
+        ApplicationId::from("headless_test"),
+        "headless_test",
+    );
+
+    let chunk = Chunk::builder("points")
+        .with_archetype(
+            RowId::new(),
+            TimePoint::default(),
+            &Points3D::new([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)])
+                .with_colors([0xFF0000FF, 0x00FF00FF, 0x0000FFFF])
+                .with_radii([0.1]),
+        )
+        .build()
+        .unwrap();
+
+    let messages = [
+        Ok(LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Unknown,
+                store_version: Some(re_build_info::CrateVersion::LOCAL),
+            },
+        })),
+        chunk
+            .to_arrow_msg()
+            .map(|arrow_msg| LogMsg::ArrowMsg(store_id.clone(), arrow_msg)),
+    ];
+
+    let bytes = re_log_encoding::encoder::encode_as_bytes_local(messages.into_iter()).unwrap();
+    std::fs::write(path, bytes).unwrap();
+}
+
+/// Renders a small recording the same way `rerun screenshot` does, and snapshots the result.
+#[tokio::test]
+async fn render_points_recording() {
+    let recording =
+        std::env::temp_dir().join(format!("rerun_headless_test_{}.rrd", std::process::id()));
+    write_points_rrd(&recording);
+
+    let renders = headless::render_views(
+        MainThreadToken::i_promise_i_am_only_using_this_for_a_test(),
+        AppEnvironment::Test,
+        &headless::HeadlessOptions {
+            recording: recording.clone(),
+            size: egui::vec2(400.0, 300.0),
+            ..Default::default()
+        },
+    );
+    std::fs::remove_file(&recording).ok();
+
+    let renders = renders.unwrap();
+    assert_eq!(renders.len(), 1);
+    assert_eq!(renders[0].view_name, None);
+
+    egui_kittest::image_snapshot(&renders[0].image, "headless_points");
+}
@@ -4,10 +4,11 @@ use ahash::HashMap;
 
 use re_chunk::{RowId, TimePoint, UnitChunkShared};
 use re_chunk_store::LatestAtQuery;
-use re_entity_db::{EntityDb, EntityPath};
+use re_entity_db::{EntityDb, EntityPath, InstancePath};
 use re_log::ResultExt as _;
 use re_log_types::{Instance, StoreId};
-use re_types::{ComponentDescriptor, ComponentType};
+use re_query::LatestAtResults;
+use re_types::{ArchetypeName, ComponentDescriptor, ComponentType};
 use re_ui::{UiExt as _, UiLayout};
 
 use crate::{ComponentFallbackProvider, MaybeMutRef, QueryContext, ViewerContext};
@@ -49,9 +50,9 @@ type LegacyDisplayComponentUiCallback = Box<
         + Sync,
 >;
 
-pub enum EditOrView {
-    /// Allow the user to view and mutate the value
-    Edit,
+pub enum EditOrView<'a> {
+    /// Allow the user to view and mutate the value, which is written to the given target.
+    Edit(&'a EditTarget),
 
     /// No mutation allowed
     View,
@@ -96,12 +97,38 @@ pub type UntypedComponentEditOrViewCallback = Box<
             &ComponentDescriptor,
             Option<RowId>,
             &dyn arrow::array::Array,
-            EditOrView,
+            EditOrView<'_>,
         ) -> Option<arrow::array::ArrayRef>
         + Send
         + Sync,
 >;
 
+/// Which entities a hover card registered with [`ComponentUiRegistry::add_hover_card`] is shown for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HoverCardTarget {
+    /// Entities with components of this archetype.
+    Archetype(ArchetypeName),
+
+    /// This entity and all of its descendants.
+    Subtree(EntityPath),
+}
+
+impl HoverCardTarget {
+    fn applies_to(&self, entity_path: &EntityPath, components: &[ComponentDescriptor]) -> bool {
+        match self {
+            Self::Archetype(archetype) => components
+                .iter()
+                .any(|component| component.archetype == Some(*archetype)),
+            Self::Subtree(subtree) => entity_path.starts_with(subtree),
+        }
+    }
+}
+
+/// Callback showing the content of the hover card of an entity instance,
+/// given the latest values of all components of the entity.
+pub type HoverCardCallback =
+    Box<dyn Fn(&ViewerContext<'_>, &mut egui::Ui, &InstancePath, &LatestAtResults) + Send + Sync>;
+
 /// How to display components in a Ui.
 pub struct ComponentUiRegistry {
     /// Older component uis - TODO(#6661): we're in the process of removing these.
@@ -129,6 +156,9 @@ pub struct ComponentUiRegistry {
     /// Implements viewing and probably editing
     component_multiline_edit_or_view:
         HashMap<ComponentUiIdentifier, UntypedComponentEditOrViewCallback>,
+
+    /// Custom hover card content, in order of registration.
+    hover_cards: Vec<(HoverCardTarget, HoverCardCallback)>,
 }
 
 impl Default for ComponentUiRegistry {
@@ -143,6 +173,7 @@ impl ComponentUiRegistry {
             legacy_display_component_uis: Default::default(),
             component_singleline_edit_or_view: Default::default(),
             component_multiline_edit_or_view: Default::default(),
+            hover_cards: Default::default(),
         }
     }
 
@@ -182,6 +213,29 @@ impl ComponentUiRegistry {
         + Send
         + Sync
         + 'static,
+    ) {
+        let multiline = false;
+        self.add_editor_ui(multiline, move |ctx, ui, _target, _descr, value| {
+            callback(ctx, ui, value)
+        });
+    }
+
+    /// Like [`Self::add_singleline_edit_or_view`], for editors that write more components next to the edited one.
+    ///
+    /// The callback also gets the descriptor of the edited component,
+    /// and when editing, the [`EditTarget`] the edited value is written to.
+    pub fn add_singleline_edit_or_view_with_target<C: re_types::Component>(
+        &mut self,
+        callback: impl Fn(
+            &ViewerContext<'_>,
+            &mut egui::Ui,
+            Option<&EditTarget>,
+            &ComponentDescriptor,
+            &mut MaybeMutRef<'_, C>,
+        ) -> egui::Response
+        + Send
+        + Sync
+        + 'static,
     ) {
         let multiline = false;
         self.add_editor_ui(multiline, callback);
@@ -212,31 +266,50 @@ impl ComponentUiRegistry {
         + 'static,
     ) {
         let multiline = true;
-        self.add_editor_ui(multiline, callback);
+        self.add_editor_ui(multiline, move |ctx, ui, _target, _descr, value| {
+            callback(ctx, ui, value)
+        });
     }
 
     fn add_editor_ui<C: re_types::Component>(
         &mut self,
         multiline: bool,
-        callback: impl Fn(&ViewerContext<'_>, &mut egui::Ui, &mut MaybeMutRef<'_, C>) -> egui::Response
+        callback: impl Fn(
+            &ViewerContext<'_>,
+            &mut egui::Ui,
+            Option<&EditTarget>,
+            &ComponentDescriptor,
+            &mut MaybeMutRef<'_, C>,
+        ) -> egui::Response
         + Send
         + Sync
         + 'static,
     ) {
         let untyped_callback: UntypedComponentEditOrViewCallback = Box::new(
-            move |ctx, ui, _component_descriptor, _row_id, value, edit_or_view| {
+            move |ctx, ui, component_descriptor, _row_id, value, edit_or_view| {
                 // if we end up being called with a mismatching component, its likely a bug.
-                debug_assert_eq!(_component_descriptor.component_type, Some(C::name()));
+                debug_assert_eq!(component_descriptor.component_type, Some(C::name()));
 
                 let mut deserialized_value = try_deserialize(value)?;
                 match edit_or_view {
                     EditOrView::View => {
-                        callback(ctx, ui, &mut MaybeMutRef::Ref(&deserialized_value));
+                        callback(
+                            ctx,
+                            ui,
+                            None,
+                            component_descriptor,
+                            &mut MaybeMutRef::Ref(&deserialized_value),
+                        );
                         None
                     }
-                    EditOrView::Edit => {
-                        let response =
-                            callback(ctx, ui, &mut MaybeMutRef::MutRef(&mut deserialized_value));
+                    EditOrView::Edit(target) => {
+                        let response = callback(
+                            ctx,
+                            ui,
+                            Some(target),
+                            component_descriptor,
+                            &mut MaybeMutRef::MutRef(&mut deserialized_value),
+                        );
 
                         if response.changed() {
                             use re_types::ComponentBatch as _;
@@ -277,7 +350,7 @@ impl ComponentUiRegistry {
             move |ctx, ui, component_descriptor, row_id, value, edit_or_view| {
                 match edit_or_view {
                     EditOrView::View => {}
-                    EditOrView::Edit => {
+                    EditOrView::Edit(_) => {
                         re_log::error_once!("Editing variant UIs is not supported.");
                         return None;
                     }
@@ -301,6 +374,41 @@ impl ComponentUiRegistry {
             .insert(variant_name.into(), untyped_callback);
     }
 
+    /// Registers custom content for the hover cards of entities, shown instead of their components.
+    ///
+    /// This lets apps show domain-specific summaries on hover, e.g. the decoded fields of a CAN frame
+    /// rather than its raw bytes.
+    /// If there's already a hover card for the same target, the new callback replaces the old one.
+    pub fn add_hover_card(&mut self, target: HoverCardTarget, callback: HoverCardCallback) {
+        self.hover_cards.retain(|(t, _)| *t != target);
+        self.hover_cards.push((target, callback));
+    }
+
+    /// The custom hover card for an entity with the given components, if any.
+    ///
+    /// Hover cards for subtrees take precedence over those for archetypes, and the deepest subtree wins.
+    /// Among archetypes, the first registered one the entity has components of wins.
+    pub fn hover_card(
+        &self,
+        entity_path: &EntityPath,
+        components: &[ComponentDescriptor],
+    ) -> Option<&HoverCardCallback> {
+        let applicable = || {
+            self.hover_cards
+                .iter()
+                .filter(|(target, _)| target.applies_to(entity_path, components))
+        };
+
+        applicable()
+            .filter_map(|(target, callback)| match target {
+                HoverCardTarget::Subtree(subtree) => Some((subtree.len(), callback)),
+                HoverCardTarget::Archetype(_) => None,
+            })
+            .max_by_key(|(depth, _)| *depth)
+            .map(|(_, callback)| callback)
+            .or_else(|| applicable().next().map(|(_, callback)| callback))
+    }
+
     /// Queries which UI types are registered for a component.
     ///
     /// Note that there's always a fallback display UI.
@@ -655,11 +763,6 @@ impl ComponentUiRegistry {
         allow_multiline: bool,
     ) -> bool {
         re_tracing::profile_function!(component_descr.display_name());
-        let EditTarget {
-            store_id,
-            timepoint,
-            entity_path,
-        } = target;
 
         // We use the component type to identify which UI to show.
         // (but for saving back edit results, we need the full descriptor)
@@ -690,8 +793,13 @@ impl ComponentUiRegistry {
                 &component_descr,
                 None,
                 raw_current_value,
-                EditOrView::Edit,
+                EditOrView::Edit(&target),
             ) {
+                let EditTarget {
+                    store_id,
+                    timepoint,
+                    entity_path,
+                } = target;
                 ctx.append_array_to_store(
                     store_id,
                     timepoint,
@@ -739,3 +847,103 @@ fn try_deserialize<C: re_types::Component>(value: &dyn arrow::array::Array) -> O
 fn fallback_ui(ui: &mut egui::Ui, ui_layout: UiLayout, component: &dyn arrow::array::Array) {
     re_arrow_ui::arrow_ui(ui, ui_layout, component);
 }
+
+#[cfg(test)]
+mod tests {
+    use re_types::{Archetype as _, archetypes};
+
+    use super::*;
+
+    fn registry_with_hover_cards(targets: &[HoverCardTarget]) -> ComponentUiRegistry {
+        let mut registry = ComponentUiRegistry::new();
+        for target in targets {
+            registry.add_hover_card(target.clone(), Box::new(|_, _, _, _| {}));
+        }
+        registry
+    }
+
+    /// The target of the hover card that is shown for the given entity, if any.
+    fn hover_card_target(
+        registry: &ComponentUiRegistry,
+        entity_path: &str,
+        components: &[ComponentDescriptor],
+    ) -> Option<HoverCardTarget> {
+        let callback = registry.hover_card(&EntityPath::from(entity_path), components)?;
+        registry
+            .hover_cards
+            .iter()
+            .find(|(_, registered)| std::ptr::eq(registered, callback))
+            .map(|(target, _)| target.clone())
+    }
+
+    #[test]
+    fn test_hover_card_subtree_takes_precedence_over_archetype() {
+        let points = HoverCardTarget::Archetype(archetypes::Points3D::name());
+        let subtree = HoverCardTarget::Subtree("world".into());
+        let registry = registry_with_hover_cards(&[points.clone(), subtree.clone()]);
+        let components = [archetypes::Points3D::descriptor_positions()];
+
+        assert_eq!(
+            hover_card_target(&registry, "world/points", &components),
+            Some(subtree)
+        );
+        assert_eq!(
+            hover_card_target(&registry, "other/points", &components),
+            Some(points)
+        );
+        assert_eq!(hover_card_target(&registry, "other/points", &[]), None);
+    }
+
+    #[test]
+    fn test_hover_card_deepest_subtree_wins() {
+        let world = HoverCardTarget::Subtree("world".into());
+        let robot = HoverCardTarget::Subtree("world/robot".into());
+
+        // The order of registration doesn't matter.
+        for registry in [
+            registry_with_hover_cards(&[world.clone(), robot.clone()]),
+            registry_with_hover_cards(&[robot.clone(), world.clone()]),
+        ] {
+            assert_eq!(
+                hover_card_target(&registry, "world/robot/arm", &[]),
+                Some(robot.clone())
+            );
+            assert_eq!(
+                hover_card_target(&registry, "world/robot", &[]),
+                Some(robot.clone())
+            );
+            assert_eq!(
+                hover_card_target(&registry, "world/robots", &[]),
+                Some(world.clone())
+            );
+            assert_eq!(hover_card_target(&registry, "other", &[]), None);
+        }
+    }
+
+    #[test]
+    fn test_hover_card_first_registered_archetype_wins() {
+        let points = HoverCardTarget::Archetype(archetypes::Points3D::name());
+        let boxes = HoverCardTarget::Archetype(archetypes::Boxes3D::name());
+        let registry = registry_with_hover_cards(&[points.clone(), boxes.clone()]);
+
+        assert_eq!(
+            hover_card_target(
+                &registry,
+                "entity",
+                &[
+                    archetypes::Boxes3D::descriptor_half_sizes(),
+                    archetypes::Points3D::descriptor_positions(),
+                ]
+            ),
+            Some(points)
+        );
+        assert_eq!(
+            hover_card_target(
+                &registry,
+                "entity",
+                &[archetypes::Boxes3D::descriptor_half_sizes()]
+            ),
+            Some(boxes)
+        );
+    }
+}
@@ -0,0 +1,38 @@
+/// The sticks and triggers of a gamepad, used to fly the eye of 3D views.
+///
+/// The viewer reads these from the gamepad at the start of every frame and stores them in the egui context,
+/// where views pick them up with [`Self::load`].
+/// Gamepad buttons are mapped to [`re_ui::UICommand`]s instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadAxes {
+    /// Moves sideways and forwards, with x to the right and y forwards, each in `-1.0..=1.0`.
+    pub move_stick: egui::Vec2,
+
+    /// Turns the eye, with x to the right and y up, each in `-1.0..=1.0`.
+    pub look_stick: egui::Vec2,
+
+    /// Moves up if positive and down if negative, in `-1.0..=1.0`.
+    pub vertical: f32,
+}
+
+impl GamepadAxes {
+    fn ctx_id() -> egui::Id {
+        egui::Id::new("gamepad_axes")
+    }
+
+    /// The axes stored for this frame, all zero if no gamepad is connected.
+    pub fn load(egui_ctx: &egui::Context) -> Self {
+        egui_ctx
+            .data(|data| data.get_temp::<Self>(Self::ctx_id()))
+            .unwrap_or_default()
+    }
+
+    pub fn store(self, egui_ctx: &egui::Context) {
+        egui_ctx.data_mut(|data| data.insert_temp(Self::ctx_id(), self));
+    }
+
+    /// Whether all sticks and triggers are at rest.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -1,9 +1,19 @@
-use re_types::reflection::Enum as _;
+use std::sync::Arc;
+
+use re_renderer::renderer::ColorMapper;
+use re_types::{
+    ComponentDescriptor, Loggable as _,
+    components::{Colormap, Name},
+    reflection::Enum as _,
+};
 use re_ui::list_item;
 
 use crate::{
-    MaybeMutRef,
-    gpu_bridge::{get_or_create_texture, render_image},
+    EditTarget, MaybeMutRef,
+    gpu_bridge::{
+        CustomColormap, custom_colormap, custom_colormap_descriptor, custom_colormaps,
+        get_or_create_texture, render_image,
+    },
 };
 
 const MIN_WIDTH: f32 = 150.0;
@@ -12,7 +22,8 @@ const MIN_WIDTH: f32 = 150.0;
 fn colormap_preview_ui(
     render_ctx: &re_renderer::RenderContext,
     ui: &mut egui::Ui,
-    colormap: re_types::components::Colormap,
+    debug_name: &str,
+    color_mapper: impl FnOnce() -> anyhow::Result<ColorMapper>,
 ) -> anyhow::Result<egui::Response> {
     re_tracing::profile_function!();
 
@@ -50,12 +61,10 @@ fn colormap_preview_ui(
         multiply_rgb_with_alpha: false,
         gamma: 1.0,
         shader_decoding: None,
-        color_mapper: re_renderer::renderer::ColorMapper::Function(colormap_to_re_renderer(
-            colormap,
-        )),
+        color_mapper: color_mapper()?,
     };
 
-    let debug_name = format!("colormap_{colormap}");
+    let debug_name = format!("colormap_{debug_name}");
     render_image(
         render_ctx,
         ui.painter(),
@@ -68,58 +77,161 @@ fn colormap_preview_ui(
     Ok(response)
 }
 
+/// Either a built-in colormap, or one registered with [`crate::gpu_bridge::register_colormap`].
+#[derive(Clone)]
+enum AnyColormap {
+    BuiltIn(Colormap),
+    Custom(Arc<CustomColormap>),
+}
+
+impl AnyColormap {
+    fn name(&self) -> String {
+        match self {
+            Self::BuiltIn(colormap) => colormap.to_string(),
+            Self::Custom(colormap) => colormap.name().to_owned(),
+        }
+    }
+
+    fn is(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::BuiltIn(a), Self::BuiltIn(b)) => a == b,
+            (Self::Custom(a), Self::Custom(b)) => a.name() == b.name(),
+            _ => false,
+        }
+    }
+
+    fn preview_ui(
+        &self,
+        render_ctx: &re_renderer::RenderContext,
+        ui: &mut egui::Ui,
+    ) -> anyhow::Result<egui::Response> {
+        match self {
+            Self::BuiltIn(colormap) => {
+                colormap_preview_ui(render_ctx, ui, &colormap.to_string(), || {
+                    Ok(ColorMapper::Function(colormap_to_re_renderer(*colormap)))
+                })
+            }
+            Self::Custom(colormap) => colormap_preview_ui(render_ctx, ui, colormap.name(), || {
+                Ok(ColorMapper::Texture(colormap.texture(render_ctx)?))
+            }),
+        }
+    }
+}
+
 fn colormap_variant_ui(
     render_ctx: &re_renderer::RenderContext,
     ui: &mut egui::Ui,
-    option: &re_types::components::Colormap,
-    map: &mut re_types::components::Colormap,
+    option: &AnyColormap,
+    map: &mut AnyColormap,
 ) -> egui::Response {
-    let list_item = list_item::ListItem::new().selected(option == map);
+    let list_item = list_item::ListItem::new().selected(option.is(map));
 
     let mut response = list_item.show_flat(
         ui,
-        list_item::PropertyContent::new(option.to_string())
+        list_item::PropertyContent::new(option.name())
             .min_desired_width(MIN_WIDTH)
             .value_fn(|ui, _| {
-                if let Err(err) = colormap_preview_ui(render_ctx, ui, *option) {
+                if let Err(err) = option.preview_ui(render_ctx, ui) {
                     re_log::error_once!("Failed to paint colormap preview: {err}");
                 }
             }),
     );
 
     if response.clicked() {
-        *map = *option;
+        *map = option.clone();
         response.mark_changed();
     }
 
     response
 }
 
+/// The registered colormap picked at the edit target instead of the built-in one, if any.
+fn picked_custom_colormap(
+    ctx: &crate::ViewerContext<'_>,
+    target: &EditTarget,
+    component_descr: &ComponentDescriptor,
+) -> Option<Arc<CustomColormap>> {
+    let (db, query) = if &target.store_id == ctx.blueprint_db().store_id() {
+        (ctx.blueprint_db(), ctx.blueprint_query.clone())
+    } else {
+        (ctx.recording(), ctx.current_query())
+    };
+    let (_, name) = db.latest_at_component::<Name>(
+        &target.entity_path,
+        &query,
+        &custom_colormap_descriptor(component_descr),
+    )?;
+    custom_colormap(name.as_str())
+}
+
+/// Writes the name of the picked registered colormap next to the built-in one, or clears it.
+fn save_custom_colormap(
+    ctx: &crate::ViewerContext<'_>,
+    target: &EditTarget,
+    component_descr: &ComponentDescriptor,
+    custom_colormap: Option<&CustomColormap>,
+) {
+    let names: Vec<Name> = custom_colormap
+        .map(|colormap| Name::from(colormap.name()))
+        .into_iter()
+        .collect();
+    let Ok(array) = Name::to_arrow(names) else {
+        re_log::error_once!("Failed to serialize the name of a colormap");
+        return;
+    };
+    ctx.append_array_to_store(
+        target.store_id.clone(),
+        target.timepoint.clone(),
+        target.entity_path.clone(),
+        custom_colormap_descriptor(component_descr),
+        array,
+    );
+}
+
+/// Picks one of the built-in colormaps, or one registered with [`crate::gpu_bridge::register_colormap`].
+///
+/// The pick of a registered colormap is written to the [`custom_colormap_descriptor`] next to the
+/// edited [`Colormap`], so it needs an edit target.
 pub fn colormap_edit_or_view_ui(
     ctx: &crate::ViewerContext<'_>,
     ui: &mut egui::Ui,
-    map: &mut MaybeMutRef<'_, re_types::components::Colormap>,
+    target: Option<&EditTarget>,
+    component_descr: &ComponentDescriptor,
+    map: &mut MaybeMutRef<'_, Colormap>,
 ) -> egui::Response {
+    let custom = target.and_then(|target| picked_custom_colormap(ctx, target, component_descr));
+
     if let Some(map) = map.as_mut() {
-        let selected_text = map.to_string();
+        let mut picked = custom
+            .clone()
+            .map_or(AnyColormap::BuiltIn(*map), AnyColormap::Custom);
+        let mut options: Vec<AnyColormap> = Colormap::variants()
+            .iter()
+            .copied()
+            .map(AnyColormap::BuiltIn)
+            .collect();
+        if target.is_some() {
+            options.extend(custom_colormaps().into_iter().map(AnyColormap::Custom));
+        }
+
         let content_ui = |ui: &mut egui::Ui| {
-            let mut iter = re_types::components::Colormap::variants().iter();
+            let mut iter = options.iter();
 
             let Some(first) = iter.next() else {
                 return ui.label("<no variants>");
             };
 
-            let mut response = colormap_variant_ui(ctx.render_ctx(), ui, first, map);
+            let mut response = colormap_variant_ui(ctx.render_ctx(), ui, first, &mut picked);
 
             for option in iter {
-                response |= colormap_variant_ui(ctx.render_ctx(), ui, option, map);
+                response |= colormap_variant_ui(ctx.render_ctx(), ui, option, &mut picked);
             }
 
             response
         };
 
         let mut inner_response = egui::ComboBox::from_id_salt("color map select")
-            .selected_text(selected_text)
+            .selected_text(picked.name())
             .show_ui(ui, |ui| {
                 list_item::list_item_scope(ui, "inner_scope", content_ui)
             });
@@ -127,19 +239,35 @@ pub fn colormap_edit_or_view_ui(
             && inner.changed()
         {
             inner_response.response.mark_changed();
+
+            match &picked {
+                AnyColormap::BuiltIn(colormap) => {
+                    *map = *colormap;
+                    if let Some(target) = target
+                        && custom.is_some()
+                    {
+                        save_custom_colormap(ctx, target, component_descr, None);
+                    }
+                }
+                AnyColormap::Custom(colormap) => {
+                    if let Some(target) = target {
+                        save_custom_colormap(ctx, target, component_descr, Some(colormap));
+                    }
+                }
+            }
         }
         inner_response.response
     } else {
-        let map: re_types::components::Colormap = **map;
+        let map = custom.map_or(AnyColormap::BuiltIn(**map), AnyColormap::Custom);
         let colormap_response = {
-            let result = colormap_preview_ui(ctx.render_ctx(), ui, map);
+            let result = map.preview_ui(ctx.render_ctx(), ui);
             if let Err(err) = &result {
                 re_log::error_once!("Failed to paint colormap preview: {err}");
             }
             result.ok()
         };
 
-        let label_response = ui.add(egui::Label::new(map.to_string()).truncate());
+        let label_response = ui.add(egui::Label::new(map.name()).truncate());
 
         match colormap_response {
             Some(colormap_response) => colormap_response | label_response,
@@ -0,0 +1,201 @@
+//! Colormaps registered by host applications and plugins, selectable next to the built-in [`Colormap`]s.
+//!
+//! [`Colormap`] is a closed set, so the pick of a registered colormap is stored by name in a component next to it,
+//! see [`custom_colormap_descriptor`].
+//!
+//! [`Colormap`]: re_types::components::Colormap
+
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::RwLock;
+
+use re_renderer::{
+    RenderContext,
+    resource_managers::{GpuTexture2D, ImageDataDesc, SourceImageDataFormat},
+};
+use re_types::{ComponentDescriptor, Loggable as _, components::Name};
+
+use super::get_or_create_texture;
+
+/// How many colors a [`CustomColormap`] is resampled to.
+const LUT_SIZE: usize = 256;
+
+/// A named colormap, as a lookup table of [`LUT_SIZE`] colors.
+pub struct CustomColormap {
+    name: String,
+    lut: Vec<egui::Color32>,
+    texture_key: u64,
+}
+
+impl CustomColormap {
+    /// Interpolates between colors at positions in `0.0..=1.0`, in gamma space.
+    ///
+    /// Values before the first and after the last position get the color of that position.
+    pub fn from_control_points(
+        name: impl Into<String>,
+        control_points: &[(f32, egui::Color32)],
+    ) -> Self {
+        let mut control_points = control_points.to_vec();
+        control_points.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let lut = (0..LUT_SIZE)
+            .map(|i| {
+                let t = i as f32 / (LUT_SIZE - 1) as f32;
+                let next = control_points.partition_point(|(position, _)| *position < t);
+                match (
+                    control_points.get(next.wrapping_sub(1)),
+                    control_points.get(next),
+                ) {
+                    (Some(&(t0, c0)), Some(&(t1, c1))) => {
+                        c0.lerp_to_gamma(c1, (t - t0) / (t1 - t0).max(f32::EPSILON))
+                    }
+                    (Some(&(_, color)), None) | (None, Some(&(_, color))) => color,
+                    (None, None) => egui::Color32::BLACK,
+                }
+            })
+            .collect();
+
+        Self::from_lut(name, lut)
+    }
+
+    /// Uses colors for evenly spaced values from 0.0 to 1.0, e.g. the pixels of a LUT texture.
+    ///
+    /// The colors are resampled to nearest, so any number of colors works.
+    pub fn from_lut(name: impl Into<String>, colors: Vec<egui::Color32>) -> Self {
+        let lut = if colors.len() == LUT_SIZE || colors.is_empty() {
+            colors
+        } else {
+            (0..LUT_SIZE)
+                .map(|i| colors[i * (colors.len() - 1) / (LUT_SIZE - 1)])
+                .collect()
+        };
+        let name = name.into();
+        let texture_key = egui::util::hash(("custom_colormap", &name, &lut));
+
+        Self {
+            name,
+            lut,
+            texture_key,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The color of a normalized value, which is clamped to `0.0..=1.0`.
+    pub fn color(&self, t: f32) -> egui::Color32 {
+        let index = (t.clamp(0.0, 1.0) * (self.lut.len() as f32 - 1.0)).round() as usize;
+        self.lut.get(index).copied().unwrap_or(egui::Color32::BLACK)
+    }
+
+    /// The lookup table as a texture for [`re_renderer::renderer::ColorMapper::Texture`].
+    pub fn texture(&self, render_ctx: &RenderContext) -> anyhow::Result<GpuTexture2D> {
+        get_or_create_texture(render_ctx, self.texture_key, || ImageDataDesc {
+            label: format!("colormap_{}", self.name).into(),
+            data: self
+                .lut
+                .iter()
+                .flat_map(|color| color.to_array())
+                .collect::<Vec<u8>>()
+                .into(),
+            format: SourceImageDataFormat::WgpuCompatible(wgpu::TextureFormat::Rgba8UnormSrgb),
+            width_height: [self.lut.len() as u32, 1],
+        })
+        .map_err(|err| {
+            anyhow::anyhow!(
+                "Failed to create texture of colormap {:?}: {err}",
+                self.name
+            )
+        })
+    }
+}
+
+impl std::fmt::Debug for CustomColormap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomColormap").field(&self.name).finish()
+    }
+}
+
+static CUSTOM_COLORMAPS: LazyLock<RwLock<Vec<Arc<CustomColormap>>>> = LazyLock::new(|| {
+    // Perceptually uniform, and readable with red-green color blindness.
+    let cividis = CustomColormap::from_control_points(
+        "Cividis",
+        &[
+            (0.0, egui::Color32::from_rgb(0x00, 0x20, 0x4D)),
+            (0.25, egui::Color32::from_rgb(0x41, 0x4D, 0x6B)),
+            (0.5, egui::Color32::from_rgb(0x7C, 0x7B, 0x78)),
+            (0.75, egui::Color32::from_rgb(0xBC, 0xAF, 0x6F)),
+            (1.0, egui::Color32::from_rgb(0xFF, 0xEA, 0x46)),
+        ],
+    );
+    RwLock::new(vec![Arc::new(cividis)])
+});
+
+/// Makes a colormap selectable wherever colormaps are, e.g. for depth images, heatmaps and tensors.
+///
+/// Replaces any colormap registered before with the same name.
+pub fn register_colormap(colormap: CustomColormap) {
+    let mut colormaps = CUSTOM_COLORMAPS.write();
+    colormaps.retain(|c| c.name != colormap.name);
+    colormaps.push(Arc::new(colormap));
+}
+
+/// The registered colormaps, in order of registration.
+pub fn custom_colormaps() -> Vec<Arc<CustomColormap>> {
+    CUSTOM_COLORMAPS.read().clone()
+}
+
+/// The registered colormap of the given name, if any.
+pub fn custom_colormap(name: &str) -> Option<Arc<CustomColormap>> {
+    CUSTOM_COLORMAPS
+        .read()
+        .iter()
+        .find(|colormap| colormap.name == name)
+        .cloned()
+}
+
+/// The [`Name`] of the registered colormap picked instead of the [`re_types::components::Colormap`] of the given descriptor.
+pub fn custom_colormap_descriptor(colormap: &ComponentDescriptor) -> ComponentDescriptor {
+    ComponentDescriptor {
+        archetype: colormap.archetype,
+        component: format!("{}_name", colormap.component).as_str().into(),
+        component_type: Some(Name::name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_points_are_interpolated_and_clamped() {
+        let colormap = CustomColormap::from_control_points(
+            "test",
+            &[(1.0, egui::Color32::WHITE), (0.5, egui::Color32::BLACK)],
+        );
+
+        assert_eq!(colormap.color(-1.0), egui::Color32::BLACK);
+        assert_eq!(colormap.color(0.25), egui::Color32::BLACK);
+        assert_eq!(colormap.color(1.0), egui::Color32::WHITE);
+        assert_eq!(colormap.color(2.0), egui::Color32::WHITE);
+        let middle = colormap.color(0.75);
+        assert!((126..=129).contains(&middle.r()), "{middle:?}");
+    }
+
+    #[test]
+    fn lut_is_resampled() {
+        let colormap = CustomColormap::from_lut(
+            "test",
+            vec![
+                egui::Color32::RED,
+                egui::Color32::GREEN,
+                egui::Color32::BLUE,
+            ],
+        );
+
+        assert_eq!(colormap.color(0.0), egui::Color32::RED);
+        assert_eq!(colormap.color(0.5), egui::Color32::GREEN);
+        assert_eq!(colormap.color(1.0), egui::Color32::BLUE);
+    }
+}
@@ -44,7 +44,7 @@ fn generate_texture_key(image: &ImageInfo) -> u64 {
     hash((buffer_content_hash, format, kind))
 }
 
-/// `colormap` is currently only used for depth images.
+/// `colormap` is currently only used for depth images and heatmaps.
 pub fn image_to_gpu(
     render_ctx: &RenderContext,
     debug_name: &str,
@@ -61,7 +61,7 @@ pub fn image_to_gpu(
         ImageKind::Color => {
             color_image_to_gpu(render_ctx, debug_name, texture_key, image, image_stats)
         }
-        ImageKind::Depth => depth_image_to_gpu(
+        ImageKind::Depth | ImageKind::Heatmap => depth_image_to_gpu(
             render_ctx,
             debug_name,
             texture_key,
@@ -420,22 +420,32 @@ fn depth_image_to_gpu(
     let ColormapWithRange {
         value_range,
         colormap,
-    } = colormap_with_range
-        .cloned()
-        .unwrap_or_else(|| ColormapWithRange::default_for_depth_images(image_stats));
+        custom_colormap,
+    } = colormap_with_range.cloned().unwrap_or_else(|| {
+        if image.kind == ImageKind::Heatmap {
+            ColormapWithRange::default_for_heatmaps(image_stats)
+        } else {
+            ColormapWithRange::default_for_depth_images(image_stats)
+        }
+    });
 
     let texture = get_or_create_texture(render_ctx, texture_key, || {
         general_texture_creation_desc_from_image(debug_name, image, ColorModel::L, datatype)
     })
     .map_err(|err| anyhow::anyhow!("Failed to create depth texture: {err}"))?;
 
+    let color_mapper = match custom_colormap {
+        Some(custom_colormap) => ColorMapper::Texture(custom_colormap.texture(render_ctx)?),
+        None => ColorMapper::Function(colormap_to_re_renderer(colormap)),
+    };
+
     Ok(ColormappedTexture {
         texture,
         range: value_range,
         decode_srgb: false,
         multiply_rgb_with_alpha: false,
         gamma: 1.0,
-        color_mapper: ColorMapper::Function(colormap_to_re_renderer(colormap)),
+        color_mapper,
         shader_decoding: None,
     })
 }
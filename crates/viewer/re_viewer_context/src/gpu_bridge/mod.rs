@@ -1,15 +1,20 @@
 //! Bridge to `re_renderer`
 
 mod colormap;
+mod custom_colormap;
 mod image_to_gpu;
 mod re_renderer_callback;
 
 pub use colormap::{colormap_edit_or_view_ui, colormap_to_re_renderer};
+pub use custom_colormap::{
+    CustomColormap, custom_colormap, custom_colormap_descriptor, custom_colormaps,
+    register_colormap,
+};
 pub use image_to_gpu::{
     image_data_range_heuristic, image_to_gpu, required_shader_decode,
     texture_creation_desc_from_color_image,
 };
-pub use re_renderer_callback::new_renderer_callback;
+pub use re_renderer_callback::{new_offscreen_renderer_callback, new_renderer_callback};
 
 use crate::TensorStats;
 
@@ -151,6 +156,8 @@ pub fn render_image(
         pixels_per_point,
         outline_config: None,
         blend_with_background: false,
+        clip_plane: None,
+        lighting: Default::default(),
     };
 
     let mut view_builder = ViewBuilder::new(render_ctx, target_config);
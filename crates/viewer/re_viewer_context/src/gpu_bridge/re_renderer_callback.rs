@@ -10,6 +10,25 @@ pub fn new_renderer_callback(
         ReRendererCallback {
             view_builder,
             clear_color,
+            composite: true,
+        },
+    )
+}
+
+/// Like [`new_renderer_callback`], but only renders the view without showing it.
+///
+/// Useful for views that are only rendered to be read back, e.g. for screenshots.
+pub fn new_offscreen_renderer_callback(
+    view_builder: re_renderer::ViewBuilder,
+    viewport: egui::Rect,
+    clear_color: re_renderer::Rgba,
+) -> egui::PaintCallback {
+    egui_wgpu::Callback::new_paint_callback(
+        viewport,
+        ReRendererCallback {
+            view_builder,
+            clear_color,
+            composite: false,
         },
     )
 }
@@ -17,6 +36,9 @@ pub fn new_renderer_callback(
 struct ReRendererCallback {
     view_builder: re_renderer::ViewBuilder,
     clear_color: re_renderer::Rgba,
+
+    /// Whether to draw the result into the egui frame.
+    composite: bool,
 }
 
 impl egui_wgpu::CallbackTrait for ReRendererCallback {
@@ -54,6 +76,10 @@ impl egui_wgpu::CallbackTrait for ReRendererCallback {
         render_pass: &mut wgpu::RenderPass<'static>,
         paint_callback_resources: &egui_wgpu::CallbackResources,
     ) {
+        if !self.composite {
+            return;
+        }
+
         let Some(ctx) = paint_callback_resources.get::<re_renderer::RenderContext>() else {
             // TODO(#4433): Shouldn't show up like this.
             re_log::error_once!(
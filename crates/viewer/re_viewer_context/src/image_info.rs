@@ -1,4 +1,4 @@
-use std::{borrow::Cow, ops::RangeInclusive};
+use std::{borrow::Cow, ops::RangeInclusive, sync::Arc};
 
 use re_chunk::RowId;
 use re_log_types::hash::Hash64;
@@ -10,6 +10,8 @@ use re_types::{
     tensor_data::TensorElement,
 };
 
+use crate::gpu_bridge::CustomColormap;
+
 /// Colormap together with the range of image values that is mapped to the colormap's range.
 ///
 /// The range is used to linearly re-map the image values to a normalized range (of 0-1)
@@ -18,10 +20,14 @@ use re_types::{
 pub struct ColormapWithRange {
     pub colormap: Colormap,
     pub value_range: [f32; 2],
+
+    /// A registered colormap picked instead of [`Self::colormap`].
+    pub custom_colormap: Option<Arc<CustomColormap>>,
 }
 
 impl ColormapWithRange {
     pub const DEFAULT_DEPTH_COLORMAP: Colormap = Colormap::Turbo;
+    pub const DEFAULT_HEATMAP_COLORMAP: Colormap = Colormap::Viridis;
 
     pub fn default_range_for_depth_images(image_stats: &crate::ImageStats) -> [f32; 2] {
         // Use 0.0 as default minimum depth value, even if it doesn't show up in the data.
@@ -33,6 +39,20 @@ impl ColormapWithRange {
         Self {
             colormap: Self::DEFAULT_DEPTH_COLORMAP,
             value_range: Self::default_range_for_depth_images(image_stats),
+            custom_colormap: None,
+        }
+    }
+
+    pub fn default_range_for_heatmaps(image_stats: &crate::ImageStats) -> [f32; 2] {
+        let (min, max) = image_stats.finite_range;
+        [min as _, max as _]
+    }
+
+    pub fn default_for_heatmaps(image_stats: &crate::ImageStats) -> Self {
+        Self {
+            colormap: Self::DEFAULT_HEATMAP_COLORMAP,
+            value_range: Self::default_range_for_heatmaps(image_stats),
+            custom_colormap: None,
         }
     }
 }
@@ -61,7 +81,7 @@ impl StoredBlobCacheKey {
     }
 }
 
-/// Represents the contents of an `Image`, `SegmentationImage` or `DepthImage`.
+/// Represents the contents of an `Image`, `SegmentationImage`, `DepthImage` or `Heatmap`.
 #[derive(Clone)]
 pub struct ImageInfo {
     /// Hash for the contents of the blob.
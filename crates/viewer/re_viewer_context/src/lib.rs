@@ -12,6 +12,7 @@ mod collapsed_id;
 mod component_fallbacks;
 mod component_ui_registry;
 mod drag_and_drop;
+mod gamepad;
 mod heuristics;
 mod image_info;
 mod maybe_mut_ref;
@@ -52,8 +53,12 @@ pub use self::{
         ComponentFallbackError, ComponentFallbackProvider, ComponentFallbackProviderResult,
         TypedComponentFallbackProvider,
     },
-    component_ui_registry::{ComponentUiRegistry, ComponentUiTypes, EditTarget, VariantName},
+    component_ui_registry::{
+        ComponentUiRegistry, ComponentUiTypes, EditTarget, HoverCardCallback, HoverCardTarget,
+        VariantName,
+    },
     drag_and_drop::{DragAndDropFeedback, DragAndDropManager, DragAndDropPayload},
+    gamepad::GamepadAxes,
     heuristics::suggest_view_for_each_entity,
     image_info::{ColormapWithRange, ImageInfo, StoredBlobCacheKey},
     maybe_mut_ref::MaybeMutRef,
@@ -67,7 +72,10 @@ pub use self::{
     },
     storage_context::StorageContext,
     store_context::StoreContext,
-    store_hub::StoreHub,
+    store_hub::{
+        PurgeReport, RETENTION_BLUEPRINT_PATH, RETENTION_HINTS_PATH, RecordingMemoryBudget,
+        StoreHub, retention_rules_descriptor,
+    },
     tables::{TableStore, TableStores},
     tensor::{ImageStats, TensorStats},
     time_control::{Looping, PlayState, TimeControl, TimeControlResponse, TimeView},
@@ -158,6 +166,53 @@ pub enum ScreenshotTarget {
     SaveToDisk,
 }
 
+/// Renders a view again, offscreen and at a resolution independent of its size on screen.
+///
+/// Scheduled with [`Self::schedule`] and picked up the next time the view is drawn,
+/// if its class [supports it](ViewClass::supports_high_res_screenshot).
+/// The image is read back with [`Self::READBACK_IDENTIFIER`] and this request as user data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighResScreenshotRequest {
+    pub view_id: ViewId,
+
+    /// Size of the saved image, in pixels.
+    pub resolution: [u32; 2],
+
+    /// The view is rendered at this many times the resolution in each direction, and then scaled down.
+    pub supersampling: u32,
+
+    /// Name of the screenshot (e.g. view name), excluding file extension.
+    pub name: String,
+}
+
+impl HighResScreenshotRequest {
+    pub const READBACK_IDENTIFIER: re_renderer::GpuReadbackIdentifier = 0x5C8EE7;
+
+    fn egui_id(view_id: ViewId) -> egui::Id {
+        egui::Id::new(("high_res_screenshot", view_id))
+    }
+
+    pub fn schedule(self, egui_ctx: &egui::Context) {
+        egui_ctx.data_mut(|data| data.insert_temp(Self::egui_id(self.view_id), self));
+        egui_ctx.request_repaint();
+    }
+
+    /// Takes the pending request for the view, if any. Called by the view while drawing.
+    pub fn take(egui_ctx: &egui::Context, view_id: ViewId) -> Option<Self> {
+        egui_ctx.data_mut(|data| data.remove_temp::<Self>(Self::egui_id(view_id)))
+    }
+
+    /// The resolution to render at, including supersampling, within the texture size limit of the GPU.
+    ///
+    /// Lowers the supersampling first, so that the image keeps its aspect ratio.
+    pub fn render_resolution(&self, max_texture_dimension_2d: u32) -> [u32; 2] {
+        let [width, height] = self.resolution.map(|size| size.max(1));
+        let max_supersampling = (max_texture_dimension_2d / width.max(height)).max(1);
+        let supersampling = self.supersampling.clamp(1, max_supersampling);
+        [width, height].map(|size| (size * supersampling).min(max_texture_dimension_2d))
+    }
+}
+
 // ----------------------------------------------------------------------------------------
 
 /// Used to publish info aboutr each view.
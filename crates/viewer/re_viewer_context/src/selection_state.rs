@@ -373,6 +373,10 @@ pub struct ApplicationSelectionState {
     /// What objects are hovered? Write to this.
     #[serde(skip)]
     hovered_this_frame: Mutex<ItemCollection>,
+
+    /// Entities that differ from the baseline of the recording diff, highlighted in all views.
+    #[serde(skip)]
+    highlighted_differences: Vec<EntityPath>,
 }
 
 pub enum SelectionChange<'a> {
@@ -444,6 +448,16 @@ impl ApplicationSelectionState {
         *self.hovered_this_frame.lock() = hovered.into();
     }
 
+    /// Entities that differ from the baseline of the recording diff.
+    pub fn highlighted_differences(&self) -> &[EntityPath] {
+        &self.highlighted_differences
+    }
+
+    /// Sets the entities to highlight as differing from the baseline of the recording diff.
+    pub fn set_highlighted_differences(&mut self, entity_paths: Vec<EntityPath>) {
+        self.highlighted_differences = entity_paths;
+    }
+
     /// Select passed objects unless already selected in which case they get unselected.
     /// If however an object is already selected but now gets passed a *different* item context, it stays selected after all
     /// but with an updated context!
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, sync::LazyLock};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, LazyLock},
+};
 
 use ahash::{HashMap, HashMapExt as _, HashSet};
 use anyhow::Context as _;
@@ -6,14 +9,20 @@ use itertools::Itertools as _;
 use nohash_hasher::IntMap;
 
 use re_chunk_store::{
-    ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreStats, GarbageCollectionOptions,
-    GarbageCollectionTarget,
+    ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreGeneration, ChunkStoreStats,
+    CompactionCursor, GarbageCollectionOptions, GarbageCollectionTarget, LatestAtQuery,
+    RetentionPolicy,
 };
-use re_entity_db::{EntityDb, StoreBundle};
+use re_entity_db::{ChunkSpill, EntityDb, StoreBundle};
 use re_global_context::RecordingOrTable;
-use re_log_types::{AbsoluteTimeRange, ApplicationId, StoreId, StoreKind, TableId};
+use re_log_types::{
+    AbsoluteTimeRange, ApplicationId, EntityPath, StoreId, StoreKind, TableId, TimelineName,
+};
 use re_query::QueryCachesStats;
-use re_types::{archetypes, components::Timestamp};
+use re_types::{
+    Component as _, ComponentDescriptor, archetypes,
+    components::{Text, Timestamp},
+};
 
 use crate::{
     BlueprintUndoState, CacheMemoryReport, Caches, StorageContext, StoreContext, TableStore,
@@ -66,6 +75,48 @@ pub struct StoreHub {
 
     /// The [`ChunkStoreGeneration`] from when the [`EntityDb`] was last garbage collected
     blueprint_last_gc: HashMap<StoreId, ChunkStoreGeneration>,
+
+    /// See [`Self::set_spill_evicted_chunks`].
+    spill_evicted_chunks: bool,
+
+    /// The data of each recording that was written to disk instead of being dropped.
+    chunk_spills: HashMap<StoreId, RecordingSpill>,
+
+    /// See [`Self::set_retention_policy`].
+    retention_policy: RetentionPolicy,
+
+    /// The recordings being compacted, see [`Self::compact_recording`].
+    compactions: HashMap<StoreId, CompactionCursor>,
+}
+
+/// The data of a recording that was written to disk, see [`StoreHub::set_spill_evicted_chunks`].
+#[derive(Default)]
+struct RecordingSpill {
+    chunks: ChunkSpill,
+
+    /// The time range last passed to [`StoreHub::reload_spilled_chunks`].
+    ///
+    /// It is protected from being dropped again, so we don't keep reloading the same data.
+    viewed: Option<(TimelineName, AbsoluteTimeRange)>,
+}
+
+impl RecordingSpill {
+    fn protected_time_ranges(&self) -> IntMap<TimelineName, AbsoluteTimeRange> {
+        self.viewed.into_iter().collect()
+    }
+
+    /// Write the chunks that were dropped by the GC to disk.
+    fn spill(&mut self, store_id: &StoreId, store_events: &[ChunkStoreEvent]) {
+        for event in store_events {
+            if event.kind != ChunkStoreDiffKind::Deletion {
+                continue;
+            }
+            if let Err(err) = self.chunks.spill(store_id, &event.chunk) {
+                re_log::warn_once!("Failed to write dropped data to disk: {err}");
+                return;
+            }
+        }
+    }
 }
 
 /// Load a blueprint from persisted storage, e.g. disk.
@@ -102,6 +153,13 @@ pub struct StoreStats {
 
     /// CPU memory of the viewer caches, e.g. image decode caches etc.
     pub viewer_cache_size: u64,
+
+    /// Number of chunks written to disk instead of being dropped,
+    /// see [`StoreHub::set_spill_evicted_chunks`].
+    pub num_spilled_chunks: usize,
+
+    /// Size on disk of those chunks.
+    pub spilled_size_bytes: u64,
 }
 
 /// Convenient information used for `MemoryPanel`
@@ -178,6 +236,12 @@ impl StoreHub {
             blueprint_last_gc: Default::default(),
 
             table_stores: TableStores::default(),
+
+            spill_evicted_chunks: false,
+            chunk_spills: Default::default(),
+
+            retention_policy: Default::default(),
+            compactions: Default::default(),
         }
     }
 
@@ -309,6 +373,7 @@ impl StoreHub {
 
     fn remove_store(&mut self, store_id: &StoreId) {
         _ = self.caches_per_recording.remove(store_id);
+        _ = self.chunk_spills.remove(store_id);
         let removed_store = self.store_bundle.remove(store_id);
 
         let Some(removed_store) = removed_store else {
@@ -556,6 +621,21 @@ impl StoreHub {
             .or_insert_with(|| Caches::new(recording_id));
     }
 
+    /// Makes sure a recording has associated caches, e.g. so it can be shown next to the active one.
+    pub fn ensure_caches(&mut self, store_id: &StoreId) {
+        if self.store_bundle.contains(store_id) {
+            _ = self
+                .caches_per_recording
+                .entry(store_id.clone())
+                .or_insert_with(|| Caches::new(store_id.clone()));
+        }
+    }
+
+    /// The [`Caches`] of any recording, see [`Self::ensure_caches`].
+    pub fn caches(&self, store_id: &StoreId) -> Option<&Caches> {
+        self.caches_per_recording.get(store_id)
+    }
+
     /// Activate a recording by its [`StoreId`].
     pub fn set_active_recording(&mut self, store_id: StoreId) {
         match store_id.kind() {
@@ -715,10 +795,12 @@ impl StoreHub {
     }
 
     /// Call [`EntityDb::purge_fraction_of_ram`] on every recording
+    ///
+    /// Returns what was dropped, if anything.
     //
     // NOTE: If you touch any of this, make sure to play around with our GC stress test scripts
     // available under `$WORKSPACE_ROOT/tests/python/gc_stress`.
-    pub fn purge_fraction_of_ram(&mut self, fraction_to_purge: f32) {
+    pub fn purge_fraction_of_ram(&mut self, fraction_to_purge: f32) -> Option<PurgeReport> {
         re_tracing::profile_function!();
 
         #[expect(clippy::iter_over_hash_type)]
@@ -726,9 +808,21 @@ impl StoreHub {
             cache.purge_memory();
         }
 
-        let Some(store_id) = self.store_bundle.find_oldest_modified_recording() else {
-            return;
-        };
+        let store_id = self.store_bundle.find_oldest_modified_recording()?;
+
+        let retention = self
+            .store_bundle
+            .get(&store_id)
+            .map(|entity_db| self.retention_policy_for(entity_db))
+            .unwrap_or_default();
+
+        let mut spill = self
+            .spill_evicted_chunks
+            .then(|| self.chunk_spills.entry(store_id.clone()).or_default());
+        let protected_time_ranges = spill
+            .as_ref()
+            .map(|spill| spill.protected_time_ranges())
+            .unwrap_or_default();
 
         let store_bundle = &mut self.store_bundle;
 
@@ -736,7 +830,7 @@ impl StoreHub {
             if cfg!(debug_assertions) {
                 unreachable!();
             }
-            return; // unreachable
+            return None; // unreachable
         };
 
         let store_size_before = entity_db
@@ -745,7 +839,8 @@ impl StoreHub {
             .stats()
             .total()
             .total_size_bytes;
-        let store_events = entity_db.purge_fraction_of_ram(fraction_to_purge);
+        let store_events =
+            entity_db.purge_fraction_of_ram(fraction_to_purge, protected_time_ranges, retention);
         let store_size_after = entity_db
             .storage_engine()
             .store()
@@ -753,6 +848,18 @@ impl StoreHub {
             .total()
             .total_size_bytes;
 
+        if let Some(spill) = &mut spill {
+            spill.spill(&store_id, &store_events);
+        }
+
+        let mut report = PurgeReport {
+            store_id: store_id.clone(),
+            data_source: entity_db.data_source.clone(),
+            time_ranges: PurgeReport::dropped_time_ranges(&store_events),
+            num_bytes_freed: store_size_before.saturating_sub(store_size_after),
+            recording_closed: false,
+        };
+
         if let Some(caches) = self.caches_per_recording.get_mut(&store_id) {
             caches.on_store_events(&store_events);
         }
@@ -760,7 +867,8 @@ impl StoreHub {
         // No point keeping an empty recording around.
         if entity_db.is_empty() {
             self.remove_store(&store_id);
-            return;
+            report.recording_closed = true;
+            return Some(report);
         }
 
         // Running the GC didn't do anything.
@@ -774,6 +882,8 @@ impl StoreHub {
         let num_recordings = store_bundle.recordings().count();
         if store_size_before == store_size_after && num_recordings > 1 {
             self.remove_store(&store_id);
+            report.num_bytes_freed = store_size_before;
+            report.recording_closed = true;
         }
 
         // Either we've reached our target goal or we couldn't fetch memory stats, in which case
@@ -783,6 +893,318 @@ impl StoreHub {
         // our actual target goal.
         // We cannot do that though: there are other subsystems that need to release memory before
         // we can get an accurate reading of the current memory used and decide if we should go on.
+
+        (report.recording_closed || !report.time_ranges.is_empty()).then_some(report)
+    }
+
+    /// Drop the oldest data of every recording that is larger than `max_bytes_per_recording`,
+    /// see [`RecordingMemoryBudget`].
+    ///
+    /// Unlike [`Self::purge_fraction_of_ram`], this never closes a recording.
+    ///
+    /// Returns what was dropped from each recording.
+    pub fn purge_recordings_over_budget(
+        &mut self,
+        max_bytes_per_recording: u64,
+    ) -> Vec<PurgeReport> {
+        re_tracing::profile_function!();
+
+        let mut reports = Vec::new();
+        let mut retention_policies = self.retention_policies();
+
+        for entity_db in self.store_bundle.entity_dbs_mut() {
+            if entity_db.store_kind() != StoreKind::Recording {
+                continue;
+            }
+
+            let store_size_before = entity_db
+                .storage_engine()
+                .store()
+                .stats()
+                .total()
+                .total_size_bytes;
+            if store_size_before <= max_bytes_per_recording {
+                continue;
+            }
+            let retention = retention_policies
+                .remove(entity_db.store_id())
+                .unwrap_or_default();
+
+            // Drop a bit more than strictly needed, so we don't have to GC again on the next frame.
+            let fraction_over_budget =
+                (store_size_before - max_bytes_per_recording) as f32 / store_size_before as f32;
+            let fraction_to_purge = (fraction_over_budget + 0.1).clamp(0.0, 1.0);
+
+            let mut spill = self.spill_evicted_chunks.then(|| {
+                self.chunk_spills
+                    .entry(entity_db.store_id().clone())
+                    .or_default()
+            });
+            let protected_time_ranges = spill
+                .as_ref()
+                .map(|spill| spill.protected_time_ranges())
+                .unwrap_or_default();
+
+            let store_events = entity_db.purge_fraction_of_ram(
+                fraction_to_purge,
+                protected_time_ranges,
+                retention,
+            );
+            if let Some(spill) = &mut spill {
+                spill.spill(entity_db.store_id(), &store_events);
+            }
+
+            let store_size_after = entity_db
+                .storage_engine()
+                .store()
+                .stats()
+                .total()
+                .total_size_bytes;
+
+            if let Some(caches) = self.caches_per_recording.get_mut(entity_db.store_id()) {
+                caches.on_store_events(&store_events);
+            }
+
+            let time_ranges = PurgeReport::dropped_time_ranges(&store_events);
+            if !time_ranges.is_empty() {
+                reports.push(PurgeReport {
+                    store_id: entity_db.store_id().clone(),
+                    data_source: entity_db.data_source.clone(),
+                    time_ranges,
+                    num_bytes_freed: store_size_before.saturating_sub(store_size_after),
+                    recording_closed: false,
+                });
+            }
+        }
+
+        reports
+    }
+
+    /// Keep the data of some entities longer or shorter than the memory limits alone would,
+    /// see [`RetentionPolicy`].
+    ///
+    /// These rules apply to all recordings. A recording can add its own with [`RETENTION_HINTS_PATH`],
+    /// and a blueprint with [`RETENTION_BLUEPRINT_PATH`], which take precedence in that order.
+    pub fn set_retention_policy(&mut self, retention_policy: RetentionPolicy) {
+        self.retention_policy = retention_policy;
+    }
+
+    /// The retention rules that apply to a recording, see [`Self::set_retention_policy`].
+    pub fn retention_policy_for(&self, entity_db: &EntityDb) -> RetentionPolicy {
+        let mut policy = self.retention_policy.clone();
+
+        let recording_hints = read_retention_rules(
+            entity_db,
+            &EntityPath::from(RETENTION_HINTS_PATH),
+            &LatestAtQuery::latest(TimelineName::log_tick()),
+        );
+        let blueprint_rules = self
+            .active_blueprint_for_app(entity_db.application_id())
+            .and_then(|blueprint| {
+                read_retention_rules(
+                    blueprint,
+                    &EntityPath::from(RETENTION_BLUEPRINT_PATH),
+                    &LatestAtQuery::latest(crate::blueprint_timeline()),
+                )
+            });
+
+        for rules in [recording_hints, blueprint_rules].into_iter().flatten() {
+            policy.extend(&rules);
+        }
+        policy
+    }
+
+    fn retention_policies(&self) -> HashMap<StoreId, RetentionPolicy> {
+        self.store_bundle
+            .recordings()
+            .map(|entity_db| {
+                (
+                    entity_db.store_id().clone(),
+                    self.retention_policy_for(entity_db),
+                )
+            })
+            .filter(|(_, policy)| !policy.is_empty())
+            .collect()
+    }
+
+    /// Drop the data of every recording that was kept longer than its retention allows,
+    /// see [`Self::set_retention_policy`].
+    ///
+    /// This is independent of the memory use, and never closes a recording.
+    /// The dropped data is not written to disk, even with [`Self::set_spill_evicted_chunks`].
+    ///
+    /// Returns what was dropped from each recording.
+    pub fn drop_expired_data(&mut self) -> Vec<PurgeReport> {
+        re_tracing::profile_function!();
+
+        let mut reports = Vec::new();
+        let mut retention_policies = self.retention_policies();
+        if retention_policies.is_empty() {
+            return reports;
+        }
+
+        for entity_db in self.store_bundle.entity_dbs_mut() {
+            let Some(retention) = retention_policies.remove(entity_db.store_id()) else {
+                continue;
+            };
+
+            let store_size_before = entity_db
+                .storage_engine()
+                .store()
+                .stats()
+                .total()
+                .total_size_bytes;
+            let store_events = entity_db.drop_expired(retention);
+            if store_events.is_empty() {
+                continue;
+            }
+            let store_size_after = entity_db
+                .storage_engine()
+                .store()
+                .stats()
+                .total()
+                .total_size_bytes;
+
+            if let Some(caches) = self.caches_per_recording.get_mut(entity_db.store_id()) {
+                caches.on_store_events(&store_events);
+            }
+
+            reports.push(PurgeReport {
+                store_id: entity_db.store_id().clone(),
+                data_source: entity_db.data_source.clone(),
+                time_ranges: PurgeReport::dropped_time_ranges(&store_events),
+                num_bytes_freed: store_size_before.saturating_sub(store_size_after),
+                recording_closed: false,
+            });
+        }
+
+        reports
+    }
+
+    /// Merge the small chunks of a recording with their neighbors, e.g. because it was logged
+    /// with many tiny log calls and queries it slowly as a result.
+    ///
+    /// Restarts the compaction if it is already running.
+    /// If `config` is set, it replaces the compaction thresholds of the recording first.
+    ///
+    /// The compaction runs over the next frames, see [`Self::continue_compactions`].
+    pub fn compact_recording(&mut self, store_id: &StoreId, config: Option<ChunkStoreConfig>) {
+        let Some(entity_db) = self.store_bundle.get_mut(store_id) else {
+            return;
+        };
+        if let Some(config) = config {
+            entity_db.set_store_config(config);
+        }
+        self.compactions
+            .insert(store_id.clone(), CompactionCursor::default());
+    }
+
+    /// Whether this recording is being compacted, see [`Self::compact_recording`].
+    pub fn is_compacting(&self, store_id: &StoreId) -> bool {
+        self.compactions.contains_key(store_id)
+    }
+
+    /// Make progress on the compactions started with [`Self::compact_recording`],
+    /// for at most `time_budget` in total, shared evenly between the recordings.
+    ///
+    /// Returns whether any compaction is still running.
+    pub fn continue_compactions(&mut self, time_budget: std::time::Duration) -> bool {
+        if self.compactions.is_empty() {
+            return false;
+        }
+
+        re_tracing::profile_function!();
+
+        let time_budget = time_budget / self.compactions.len() as u32;
+        self.compactions.retain(|store_id, cursor| {
+            let Some(entity_db) = self.store_bundle.get_mut(store_id) else {
+                return false; // closed meanwhile
+            };
+
+            match entity_db.compact(cursor, time_budget) {
+                Ok(store_events) => {
+                    if let Some(caches) = self.caches_per_recording.get_mut(store_id) {
+                        caches.on_store_events(&store_events);
+                    }
+                }
+                Err(err) => {
+                    re_log::error!("Failed to compact {store_id:?}: {err}");
+                    return false;
+                }
+            }
+
+            if cursor.is_done() {
+                re_log::info!(
+                    "Compacted {store_id:?}: merged {} chunks in {} passes, {} chunks left",
+                    re_format::format_uint(cursor.num_merges()),
+                    cursor.num_passes(),
+                    re_format::format_uint(entity_db.storage_engine().store().num_chunks()),
+                );
+            }
+            !cursor.is_done()
+        });
+
+        !self.compactions.is_empty()
+    }
+
+    /// Write the data dropped to stay within the memory limits to disk, instead of discarding it.
+    ///
+    /// Use [`Self::reload_spilled_chunks`] to load it back in when it is needed again.
+    /// Turning this off discards everything that was written to disk so far.
+    ///
+    /// Not supported on the web.
+    pub fn set_spill_evicted_chunks(&mut self, spill: bool) {
+        self.spill_evicted_chunks = spill;
+        if !spill {
+            self.chunk_spills.clear();
+        }
+    }
+
+    /// Load back the data of a recording that was written to disk and falls within `time_range`,
+    /// see [`Self::set_spill_evicted_chunks`].
+    ///
+    /// Until the next call, that time range is also kept from being dropped again.
+    pub fn reload_spilled_chunks(
+        &mut self,
+        store_id: &StoreId,
+        timeline: TimelineName,
+        time_range: AbsoluteTimeRange,
+    ) {
+        if !self.spill_evicted_chunks {
+            return;
+        }
+
+        let spill = self.chunk_spills.entry(store_id.clone()).or_default();
+        spill.viewed = Some((timeline, time_range));
+
+        let chunks = match spill.chunks.reload(&timeline, time_range) {
+            Ok(chunks) => chunks,
+            Err(err) => {
+                re_log::warn_once!("Failed to load data back from disk: {err}");
+                return;
+            }
+        };
+        if chunks.is_empty() {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        let Some(entity_db) = self.store_bundle.get_mut(store_id) else {
+            return;
+        };
+        for chunk in chunks {
+            match entity_db.add_chunk(&Arc::new(chunk)) {
+                Ok(store_events) => {
+                    if let Some(caches) = self.caches_per_recording.get_mut(store_id) {
+                        caches.on_store_events(&store_events);
+                    }
+                }
+                Err(err) => {
+                    re_log::warn_once!("Failed to load data back from disk: {err}");
+                }
+            }
+        }
     }
 
     /// Remove any recordings with a network source pointing at this `uri`.
@@ -837,6 +1259,7 @@ impl StoreHub {
                     protect_latest: 1, // keep the latest instance of everything, or we will forget things that haven't changed in a while
                     time_budget: re_entity_db::DEFAULT_GC_TIME_BUDGET,
                     protected_time_ranges,
+                    retention: Default::default(),
                 });
                 if !store_events.is_empty() {
                     re_log::debug!("Garbage-collected blueprint store");
@@ -956,6 +1379,8 @@ impl StoreHub {
             caches_per_recording,
             blueprint_last_save: _,
             blueprint_last_gc: _,
+            spill_evicted_chunks: _,
+            chunk_spills,
         } = self;
 
         let mut store_stats = BTreeMap::new();
@@ -967,6 +1392,7 @@ impl StoreHub {
                 .get(store_id)
                 .map(|caches| caches.memory_reports())
                 .unwrap_or_default();
+            let spilled_chunks = chunk_spills.get(store_id).map(|spill| &spill.chunks);
             store_stats.insert(
                 store_id.clone(),
                 StoreStats {
@@ -978,6 +1404,8 @@ impl StoreHub {
                         .map(|report| report.bytes_cpu)
                         .sum(),
                     cache_memory_reports,
+                    num_spilled_chunks: spilled_chunks.map_or(0, |chunks| chunks.num_chunks()),
+                    spilled_size_bytes: spilled_chunks.map_or(0, |chunks| chunks.num_bytes()),
                 },
             );
         }
@@ -995,3 +1423,152 @@ impl StoreHub {
         }
     }
 }
+
+// ---
+
+/// Where a recording can set its own retention rules, as a recording property,
+/// see [`StoreHub::set_retention_policy`].
+///
+/// The rules are a [`Text`] in the format of [`RetentionPolicy`]'s `FromStr`,
+/// logged with [`retention_rules_descriptor`].
+pub const RETENTION_HINTS_PATH: &str = "/__properties/retention";
+
+/// Where a blueprint can set retention rules, see [`RETENTION_HINTS_PATH`].
+pub const RETENTION_BLUEPRINT_PATH: &str = "/retention";
+
+/// The descriptor of the retention rules at [`RETENTION_HINTS_PATH`] and [`RETENTION_BLUEPRINT_PATH`].
+pub fn retention_rules_descriptor() -> ComponentDescriptor {
+    ComponentDescriptor {
+        archetype: None,
+        component: "retention:rules".into(),
+        component_type: Some(Text::name()),
+    }
+}
+
+fn read_retention_rules(
+    entity_db: &EntityDb,
+    entity_path: &EntityPath,
+    query: &LatestAtQuery,
+) -> Option<RetentionPolicy> {
+    let (_, rules) =
+        entity_db.latest_at_component::<Text>(entity_path, query, &retention_rules_descriptor())?;
+    match rules.as_str().parse() {
+        Ok(policy) => Some(policy),
+        Err(err) => {
+            re_log::warn_once!("Ignoring the retention rules at {entity_path}: {err}");
+            None
+        }
+    }
+}
+
+/// How much memory a single recording may use, on top of the global memory limit.
+///
+/// Without a budget, a single recording that receives lots of data can cause the data of all
+/// other recordings to be dropped once the global memory limit is reached.
+///
+/// Enforced with [`StoreHub::purge_recordings_over_budget`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RecordingMemoryBudget {
+    /// Recordings are only limited by the global memory limit.
+    #[default]
+    Unlimited,
+
+    /// At most this many bytes per recording.
+    Bytes(u64),
+
+    /// At most this fraction (0-1) of the global memory limit per recording.
+    ///
+    /// Has no effect if there is no global memory limit.
+    FractionOfLimit(f32),
+}
+
+impl RecordingMemoryBudget {
+    /// The budget can either be absolute (e.g. "2GB") or relative to the global memory limit (e.g. "25%").
+    pub fn parse(budget: &str) -> Result<Self, String> {
+        if let Some(percentage) = budget.strip_suffix('%') {
+            let percentage = percentage
+                .parse::<f32>()
+                .map_err(|_err| format!("expected e.g. '25%', got {budget:?}"))?;
+            if !(0.0..=100.0).contains(&percentage) {
+                return Err(format!(
+                    "expected a percentage between 0% and 100%, got {budget:?}"
+                ));
+            }
+            Ok(Self::FractionOfLimit(percentage / 100.0))
+        } else {
+            re_format::parse_bytes(budget)
+                .and_then(|bytes| u64::try_from(bytes).ok())
+                .map(Self::Bytes)
+                .ok_or_else(|| format!("expected e.g. '2GB', got {budget:?}"))
+        }
+    }
+
+    /// The budget in bytes, if any.
+    pub fn max_bytes(&self, memory_limit: &re_memory::MemoryLimit) -> Option<u64> {
+        match *self {
+            Self::Unlimited => None,
+            Self::Bytes(bytes) => Some(bytes),
+            Self::FractionOfLimit(fraction) => {
+                let limit = memory_limit.max_bytes?;
+                Some((fraction as f64 * limit.max(0) as f64).round() as u64)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for RecordingMemoryBudget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// What [`StoreHub::purge_fraction_of_ram`] or [`StoreHub::purge_recordings_over_budget`]
+/// dropped to stay within the memory limits.
+#[derive(Clone, Debug)]
+pub struct PurgeReport {
+    /// The recording data was dropped from.
+    pub store_id: StoreId,
+
+    /// Where that recording came from, if known.
+    pub data_source: Option<re_smart_channel::SmartChannelSource>,
+
+    /// For each timeline, the time range covered by the dropped data.
+    ///
+    /// Data for some entities may remain within these ranges.
+    /// Static data is never dropped.
+    pub time_ranges: BTreeMap<TimelineName, AbsoluteTimeRange>,
+
+    /// How much smaller the store got, in bytes.
+    pub num_bytes_freed: u64,
+
+    /// Whether the whole recording was closed.
+    ///
+    /// This happens when nothing is left in it, or nothing else could be dropped from it.
+    pub recording_closed: bool,
+}
+
+impl PurgeReport {
+    fn dropped_time_ranges(
+        store_events: &[ChunkStoreEvent],
+    ) -> BTreeMap<TimelineName, AbsoluteTimeRange> {
+        let mut time_ranges: BTreeMap<TimelineName, AbsoluteTimeRange> = BTreeMap::new();
+
+        for event in store_events {
+            if event.kind != ChunkStoreDiffKind::Deletion {
+                continue;
+            }
+
+            for (timeline, time_column) in event.chunk.timelines() {
+                let time_range = time_column.time_range();
+                time_ranges
+                    .entry(*timeline)
+                    .and_modify(|range| *range = range.union(time_range))
+                    .or_insert(time_range);
+            }
+        }
+
+        time_ranges
+    }
+}
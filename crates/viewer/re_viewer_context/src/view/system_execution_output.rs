@@ -1,12 +1,17 @@
+use std::sync::Arc;
+
 use crate::{ViewContextCollection, VisualizerCollection};
 
 /// Output of view system execution.
+///
+/// Cloning is cheap, which allows reusing the output across frames, see [`crate::VisualizerSystem::is_output_reusable`].
+#[derive(Clone)]
 pub struct SystemExecutionOutput {
     /// Executed view systems, may hold state that the ui method needs.
-    pub view_systems: VisualizerCollection,
+    pub view_systems: Arc<VisualizerCollection>,
 
     /// Executed context systems, may hold state that the ui method needs.
-    pub context_systems: ViewContextCollection,
+    pub context_systems: Arc<ViewContextCollection>,
 
     /// Draw data gathered during execution of the view part systems.
     ///
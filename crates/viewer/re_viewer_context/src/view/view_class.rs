@@ -5,9 +5,10 @@ use re_log_types::EntityPath;
 use re_types::{ComponentType, ViewClassIdentifier};
 
 use crate::{
-    IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, QueryRange, SmallVisualizerSet,
-    SystemExecutionOutput, ViewClassRegistryError, ViewId, ViewQuery, ViewSpawnHeuristics,
-    ViewSystemExecutionError, ViewSystemRegistrator, ViewerContext, VisualizableEntities,
+    DataResult, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, QueryRange,
+    SmallVisualizerSet, SystemExecutionOutput, ViewClassRegistryError, ViewId, ViewQuery,
+    ViewSpawnHeuristics, ViewSystemExecutionError, ViewSystemRegistrator, ViewerContext,
+    VisualizableEntities,
 };
 
 use super::ViewContext;
@@ -99,6 +100,11 @@ pub trait ViewClass: Send + Sync {
         false
     }
 
+    /// Whether the view renders [`crate::HighResScreenshotRequest`]s for itself.
+    fn supports_high_res_screenshot(&self) -> bool {
+        false
+    }
+
     /// Default query range for this view.
     //TODO(#6918): also provide ViewerContext and ViewId, to enable reading view properties.
     fn default_query_range(&self, _state: &dyn ViewState) -> QueryRange {
@@ -197,6 +203,18 @@ pub trait ViewClass: Send + Sync {
         Ok(())
     }
 
+    /// Ui shown when the user selects an entity in a view of this class, below its visualizers.
+    fn data_result_selection_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _ui: &mut egui::Ui,
+        _state: &mut dyn ViewState,
+        _view_id: ViewId,
+        _data_result: &DataResult,
+    ) -> Result<(), ViewSystemExecutionError> {
+        Ok(())
+    }
+
     /// Additional UI displayed in the tab title bar, between the "maximize" and "help" buttons.
     ///
     /// Note: this is a right-to-left layout.
@@ -303,6 +321,24 @@ pub trait ViewState: std::any::Any + Sync + Send {
 
     /// Converts itself to a reference of [`std::any::Any`], which enables downcasting to concrete types.
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+
+    /// State worth keeping after the recording is closed, like the pose of a camera.
+    ///
+    /// It is given back to [`Self::restore`] when the recording is opened again.
+    fn save(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Restores state returned by [`Self::save`].
+    fn restore(&mut self, _saved: &serde_json::Value) {}
+
+    /// Hash of everything in this state that visualizers and their fallbacks may read,
+    /// see [`crate::VisualizerSystem::is_output_reusable`].
+    ///
+    /// If this returns `None`, the output of the view's visualizers is never reused.
+    fn execution_fingerprint(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// Implementation of an empty view state.
@@ -314,6 +350,10 @@ impl ViewState for () {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn execution_fingerprint(&self) -> Option<u64> {
+        Some(0)
+    }
 }
 
 pub trait ViewStateExt: ViewState {
@@ -1,7 +1,12 @@
+use std::sync::Arc;
+
 use ahash::{HashMap, HashSet};
 use itertools::Itertools as _;
 
-use re_chunk_store::{ChunkStore, ChunkStoreSubscriberHandle};
+use re_chunk_store::{
+    ChunkStore, ChunkStoreDiff, ChunkStoreEvent, ChunkStoreSubscriber as _,
+    ChunkStoreSubscriberHandle,
+};
 use re_types::ViewClassIdentifier;
 
 use crate::{
@@ -29,6 +34,12 @@ pub enum ViewClassRegistryError {
 
     #[error("View with class identifier {0:?} was not registered.")]
     UnknownClassIdentifier(ViewClassIdentifier),
+
+    #[error("A visualizer system with identifier {0:?} was not registered.")]
+    UnknownVisualizer(ViewSystemIdentifier),
+
+    #[error("View with class identifier {1:?} does not use the visualizer system {0:?}.")]
+    VisualizerNotUsedByClass(ViewSystemIdentifier, ViewClassIdentifier),
 }
 
 /// Utility for registering view systems, passed on to [`crate::ViewClass::on_register`].
@@ -217,6 +228,172 @@ impl ViewClassRegistry {
         Ok(())
     }
 
+    /// Registers a new [`ViewContextSystem`] type for an already registered view class.
+    ///
+    /// This lets downstream crates provide shared per-view context, e.g. a custom coordinate-frame resolver,
+    /// to the visualizers they add to built-in view classes with [`Self::register_visualizer`].
+    ///
+    /// See [`ViewSystemRegistrator::register_context_system`].
+    pub fn register_context_system<
+        T: ViewContextSystem + IdentifiedViewSystem + Default + 'static,
+    >(
+        &mut self,
+        view_class: ViewClassIdentifier,
+    ) -> Result<(), ViewClassRegistryError> {
+        self.with_registrator(view_class, |registrator| {
+            registrator.register_context_system::<T>()
+        })
+    }
+
+    /// Registers a new [`VisualizerSystem`] type for an already registered view class.
+    ///
+    /// See [`ViewSystemRegistrator::register_visualizer`].
+    pub fn register_visualizer<T: VisualizerSystem + IdentifiedViewSystem + Default + 'static>(
+        &mut self,
+        view_class: ViewClassIdentifier,
+    ) -> Result<(), ViewClassRegistryError> {
+        self.with_registrator(view_class, |registrator| {
+            registrator.register_visualizer::<T>()
+        })
+    }
+
+    /// Replaces a registered visualizer with another type, in all view classes that use it.
+    ///
+    /// The replacement keeps the identifier of the visualizer it replaces, so blueprints and
+    /// visualizer overrides that refer to it keep working, e.g. substitute the built-in
+    /// `Points3D` visualizer with one that draws instanced impostors.
+    ///
+    /// Note that code looking up the replaced visualizer by its concrete type,
+    /// e.g. with [`VisualizerCollection::get`], will no longer find it.
+    ///
+    /// Pass all stores that already contain data in `existing_stores`, e.g. those of the `StoreHub`:
+    /// the replacement only learns about new data on its own, and would otherwise not know
+    /// which of their entities it can visualize.
+    pub fn replace_visualizer<'a, T: VisualizerSystem + Default + 'static>(
+        &mut self,
+        identifier: ViewSystemIdentifier,
+        existing_stores: impl IntoIterator<Item = &'a ChunkStore>,
+    ) -> Result<(), ViewClassRegistryError> {
+        let entry = self
+            .visualizers
+            .get_mut(&identifier)
+            .ok_or(ViewClassRegistryError::UnknownVisualizer(identifier))?;
+
+        let mut entity_subscriber =
+            VisualizerEntitySubscriber::with_identifier(identifier, &T::default());
+        for store in existing_stores {
+            let events = store
+                .iter_chunks()
+                .map(|chunk| ChunkStoreEvent {
+                    store_id: store.id(),
+                    store_generation: store.generation(),
+                    event_id: 0,
+                    diff: ChunkStoreDiff::addition(Arc::clone(chunk), None),
+                })
+                .collect_vec();
+            entity_subscriber.on_events(&events);
+        }
+
+        // Swap out the state of the existing subscriber rather than registering a new one,
+        // since subscribers can't be unregistered.
+        let swapped = ChunkStore::with_subscriber_mut(
+            entry.entity_subscriber_handle,
+            |subscriber: &mut VisualizerEntitySubscriber| {
+                std::mem::swap(subscriber, &mut entity_subscriber);
+            },
+        );
+        if swapped.is_none() {
+            entry.entity_subscriber_handle =
+                ChunkStore::register_subscriber(Box::new(entity_subscriber));
+        }
+        entry.factory_method = Box::new(|| Box::<T>::default());
+
+        Ok(())
+    }
+
+    /// Removes a registered visualizer from all view classes that use it.
+    pub fn disable_visualizer(
+        &mut self,
+        identifier: ViewSystemIdentifier,
+    ) -> Result<(), ViewClassRegistryError> {
+        let used_by = self
+            .visualizers
+            .get(&identifier)
+            .ok_or(ViewClassRegistryError::UnknownVisualizer(identifier))?
+            .used_by
+            .clone();
+
+        for view_class in used_by {
+            self.disable_visualizer_for_class(view_class, identifier)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes a registered visualizer from a single view class.
+    ///
+    /// Other view classes that use the same visualizer keep it.
+    pub fn disable_visualizer_for_class(
+        &mut self,
+        view_class: ViewClassIdentifier,
+        identifier: ViewSystemIdentifier,
+    ) -> Result<(), ViewClassRegistryError> {
+        let entry = self
+            .visualizers
+            .get_mut(&identifier)
+            .ok_or(ViewClassRegistryError::UnknownVisualizer(identifier))?;
+
+        if !entry.used_by.remove(&view_class) {
+            return Err(ViewClassRegistryError::VisualizerNotUsedByClass(
+                identifier, view_class,
+            ));
+        }
+        if entry.used_by.is_empty() {
+            self.visualizers.remove(&identifier);
+        }
+
+        if let Some(class_entry) = self.view_classes.get_mut(&view_class) {
+            class_entry.visualizer_system_ids.remove(&identifier);
+        }
+
+        Ok(())
+    }
+
+    /// Registers more systems for an already registered view class.
+    fn with_registrator(
+        &mut self,
+        view_class: ViewClassIdentifier,
+        register: impl FnOnce(&mut ViewSystemRegistrator<'_>) -> Result<(), ViewClassRegistryError>,
+    ) -> Result<(), ViewClassRegistryError> {
+        let entry = self
+            .view_classes
+            .get_mut(&view_class)
+            .ok_or(ViewClassRegistryError::UnknownClassIdentifier(view_class))?;
+
+        let mut registrator = ViewSystemRegistrator {
+            context_systems: std::mem::take(&mut entry.context_system_ids),
+            visualizers: std::mem::take(&mut entry.visualizer_system_ids),
+            registry: self,
+            identifier: view_class,
+        };
+
+        let result = register(&mut registrator);
+
+        let ViewSystemRegistrator {
+            registry: _,
+            identifier: _,
+            context_systems,
+            visualizers,
+        } = registrator;
+
+        if let Some(entry) = self.view_classes.get_mut(&view_class) {
+            entry.context_system_ids = context_systems;
+            entry.visualizer_system_ids = visualizers;
+        }
+
+        result
+    }
+
     /// Removes a view class from the registry.
     pub fn remove_class<T: ViewClass + Sized>(&mut self) -> Result<(), ViewClassRegistryError> {
         let identifier = T::identifier();
@@ -385,3 +562,201 @@ impl ViewClassRegistry {
             .map(|entry| (entry.factory_method)())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{Chunk, RowId};
+    use re_chunk_store::ChunkStoreConfig;
+    use re_log_types::{EntityPath, StoreId, StoreKind, TimePoint};
+    use re_types::archetypes::{Boxes3D, Points3D};
+
+    use super::*;
+    use crate::{ViewContext, ViewQuery, ViewSystemExecutionError};
+
+    #[derive(Default)]
+    struct FrameResolver;
+
+    impl IdentifiedViewSystem for FrameResolver {
+        fn identifier() -> ViewSystemIdentifier {
+            "FrameResolver".into()
+        }
+    }
+
+    impl ViewContextSystem for FrameResolver {
+        fn execute(&mut self, _ctx: &ViewContext<'_>, _query: &ViewQuery<'_>) {}
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Stands in for a built-in visualizer.
+    #[derive(Default)]
+    struct PointsVisualizer;
+
+    /// Replaces [`PointsVisualizer`], but visualizes boxes, so we can tell them apart.
+    #[derive(Default)]
+    struct BoxesVisualizer;
+
+    macro_rules! impl_test_visualizer {
+        ($type:ty, $archetype:ty, $identifier:literal) => {
+            impl IdentifiedViewSystem for $type {
+                fn identifier() -> ViewSystemIdentifier {
+                    $identifier.into()
+                }
+            }
+
+            impl VisualizerSystem for $type {
+                fn visualizer_query_info(&self) -> crate::VisualizerQueryInfo {
+                    crate::VisualizerQueryInfo::from_archetype::<$archetype>()
+                }
+
+                fn execute(
+                    &mut self,
+                    _ctx: &ViewContext<'_>,
+                    _query: &ViewQuery<'_>,
+                    _context_systems: &ViewContextCollection,
+                ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+                    Ok(Vec::new())
+                }
+
+                fn as_any(&self) -> &dyn std::any::Any {
+                    self
+                }
+
+                fn fallback_provider(&self) -> &dyn crate::ComponentFallbackProvider {
+                    self
+                }
+            }
+
+            crate::impl_component_fallback_provider!($type => []);
+        };
+    }
+
+    impl_test_visualizer!(PointsVisualizer, Points3D, "TestPoints");
+    impl_test_visualizer!(BoxesVisualizer, Boxes3D, "TestBoxes");
+
+    fn registry_with_points_visualizer() -> ViewClassRegistry {
+        let mut registry = ViewClassRegistry::default();
+        registry.add_class::<ViewClassPlaceholder>().unwrap();
+        registry
+            .register_visualizer::<PointsVisualizer>(ViewClassPlaceholder::identifier())
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_register_context_system_for_existing_class() {
+        let mut registry = ViewClassRegistry::default();
+        let class = ViewClassPlaceholder::identifier();
+
+        assert!(matches!(
+            registry.register_context_system::<FrameResolver>(class),
+            Err(ViewClassRegistryError::UnknownClassIdentifier(_))
+        ));
+
+        registry.add_class::<ViewClassPlaceholder>().unwrap();
+        registry
+            .register_context_system::<FrameResolver>(class)
+            .unwrap();
+        assert!(
+            registry
+                .new_context_collection(class)
+                .get::<FrameResolver>()
+                .is_ok()
+        );
+
+        // Registering the same system twice for one class is an error.
+        assert!(
+            registry
+                .register_context_system::<FrameResolver>(class)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_replace_visualizer() {
+        let mut registry = registry_with_points_visualizer();
+        let class = ViewClassPlaceholder::identifier();
+        let identifier = PointsVisualizer::identifier();
+
+        // Data that is already there before the visualizer gets replaced.
+        let mut store = ChunkStore::new(
+            StoreId::random(StoreKind::Recording, "test_app"),
+            ChunkStoreConfig::default(),
+        );
+        let chunk = Chunk::builder("boxes")
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &Boxes3D::from_half_sizes([(1.0, 1.0, 1.0)]),
+            )
+            .build()
+            .unwrap();
+        store.insert_chunk(&Arc::new(chunk)).unwrap();
+
+        assert!(matches!(
+            registry.replace_visualizer::<BoxesVisualizer>("Unknown".into(), [&store]),
+            Err(ViewClassRegistryError::UnknownVisualizer(_))
+        ));
+        let handle = registry.visualizers[&identifier].entity_subscriber_handle;
+        registry
+            .replace_visualizer::<BoxesVisualizer>(identifier, [&store])
+            .unwrap();
+
+        // The replacement keeps the identifier of the original.
+        let visualizers = registry.new_visualizer_collection(class);
+        let visualizer = visualizers.get_by_identifier(identifier).unwrap();
+        assert!(visualizer.as_any().is::<BoxesVisualizer>());
+        assert!(visualizers.get::<PointsVisualizer>().is_err());
+
+        // The replacement knows about the data that was already there.
+        let maybe_visualizable =
+            registry.maybe_visualizable_entities_for_visualizer_systems(&store.id());
+        assert!(maybe_visualizable[&identifier].contains(&EntityPath::from("boxes")));
+
+        // The replacement reuses the store subscriber of the original instead of registering another one.
+        assert_eq!(
+            ChunkStore::with_subscriber(handle, |subscriber: &VisualizerEntitySubscriber| {
+                subscriber
+                    .maybe_visualizable_entities(&store.id())
+                    .is_some_and(|entities| entities.contains(&EntityPath::from("boxes")))
+            }),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_disable_visualizer() {
+        let mut registry = registry_with_points_visualizer();
+        let class = ViewClassPlaceholder::identifier();
+        let identifier = PointsVisualizer::identifier();
+
+        assert!(matches!(
+            registry.disable_visualizer_for_class("Unknown".into(), identifier),
+            Err(ViewClassRegistryError::VisualizerNotUsedByClass(_, _))
+        ));
+
+        registry.disable_visualizer(identifier).unwrap();
+        assert!(
+            registry
+                .new_visualizer_collection(class)
+                .get_by_identifier(identifier)
+                .is_err()
+        );
+        assert!(registry.instantiate_visualizer(identifier).is_none());
+        assert!(matches!(
+            registry.disable_visualizer(identifier),
+            Err(ViewClassRegistryError::UnknownVisualizer(_))
+        ));
+
+        // Nothing is left over that would keep the visualizer around when it's registered again.
+        registry
+            .register_visualizer::<PointsVisualizer>(class)
+            .unwrap();
+        registry
+            .disable_visualizer_for_class(class, identifier)
+            .unwrap();
+        assert!(!registry.visualizers.contains_key(&identifier));
+    }
+}
@@ -9,6 +9,7 @@ use super::view_context::ViewContext;
 /// View context that can be used by view parts and ui methods to retrieve information about the scene as a whole.
 ///
 /// Is always populated before view part systems.
+/// The context systems of a view are executed in parallel with each other, like visualizers.
 pub trait ViewContextSystem: Send + Sync {
     /// Queries the chunk store and performs data conversions to make it ready for consumption by scene elements.
     fn execute(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>);
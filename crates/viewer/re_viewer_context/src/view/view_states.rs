@@ -8,10 +8,13 @@ use ahash::HashMap;
 use crate::{ViewClass, ViewId, ViewState};
 
 /// State for the `View`s that persists across frames but otherwise
-/// is not saved.
+/// is not saved, except for what views opt into with [`ViewState::save`].
 #[derive(Default)]
 pub struct ViewStates {
     states: HashMap<ViewId, Box<dyn ViewState>>,
+
+    /// Saved states of views that don't have a state yet, restored once they do.
+    pending_restores: HashMap<ViewId, serde_json::Value>,
 }
 
 impl ViewStates {
@@ -26,13 +29,46 @@ impl ViewStates {
     ) -> &mut dyn ViewState {
         self.states
             .entry(view_id)
-            .or_insert_with(|| view_class.new_state())
+            .or_insert_with(|| new_state(&mut self.pending_restores, view_id, view_class))
             .as_mut()
     }
 
     pub fn ensure_state_exists(&mut self, view_id: ViewId, view_class: &dyn ViewClass) {
         self.states
             .entry(view_id)
-            .or_insert_with(|| view_class.new_state());
+            .or_insert_with(|| new_state(&mut self.pending_restores, view_id, view_class));
+    }
+
+    /// The state of every view that is worth keeping after the recording is closed, see [`ViewState::save`].
+    pub fn save(&self) -> HashMap<ViewId, serde_json::Value> {
+        self.states
+            .iter()
+            .filter_map(|(view_id, state)| Some((*view_id, state.save()?)))
+            .collect()
+    }
+
+    /// Restores states returned by [`Self::save`].
+    ///
+    /// Views without a state yet are restored when their state is created.
+    pub fn restore(&mut self, saved: HashMap<ViewId, serde_json::Value>) {
+        for (view_id, saved) in saved {
+            if let Some(state) = self.states.get_mut(&view_id) {
+                state.restore(&saved);
+            } else {
+                self.pending_restores.insert(view_id, saved);
+            }
+        }
+    }
+}
+
+fn new_state(
+    pending_restores: &mut HashMap<ViewId, serde_json::Value>,
+    view_id: ViewId,
+    view_class: &dyn ViewClass,
+) -> Box<dyn ViewState> {
+    let mut state = view_class.new_state();
+    if let Some(saved) = pending_restores.remove(&view_id) {
+        state.restore(&saved);
     }
+    state
 }
@@ -95,10 +95,19 @@ struct VisualizerEntityMapping {
 
 impl VisualizerEntitySubscriber {
     pub fn new<T: IdentifiedViewSystem + VisualizerSystem>(visualizer: &T) -> Self {
+        Self::with_identifier(T::identifier(), visualizer)
+    }
+
+    /// Like [`Self::new`], but for a visualizer registered under a different identifier than its own,
+    /// e.g. one that replaces a built-in visualizer.
+    pub fn with_identifier(
+        identifier: ViewSystemIdentifier,
+        visualizer: &dyn VisualizerSystem,
+    ) -> Self {
         let visualizer_query_info = visualizer.visualizer_query_info();
 
         Self {
-            visualizer: T::identifier(),
+            visualizer: identifier,
             relevant_archetypes: visualizer_query_info.relevant_archetypes,
             required_components_indices: visualizer_query_info
                 .required
@@ -11,6 +11,12 @@ use crate::{
     VisualizableFilterContext,
 };
 
+// Shared between the visualizers of all views while they execute, see [`VisualizerSystem`].
+static_assertions::assert_impl_all!(crate::ViewerContext<'static>: Sync);
+static_assertions::assert_impl_all!(ViewContext<'static>: Sync);
+static_assertions::assert_impl_all!(ViewQuery<'static>: Sync);
+static_assertions::assert_impl_all!(ViewContextCollection: Sync);
+
 #[derive(Debug, Clone, Default)]
 pub struct SortedComponentDescriptorSet(linked_hash_map::LinkedHashMap<ComponentDescriptor, ()>);
 
@@ -81,6 +87,21 @@ impl VisualizerQueryInfo {
 ///
 /// All visualizers are expected to be able to provide a fallback value for any component they're using
 /// via the [`ComponentFallbackProvider`] trait.
+///
+/// ## Thread safety
+///
+/// The visualizers of a view are executed in parallel with each other, and with those of other views,
+/// on the rayon thread pool. During [`Self::execute`]:
+/// - A visualizer only has mutable access to itself.
+/// - The [`ViewContext`] (and with it the [`crate::ViewerContext`] and the [`crate::ViewState`]),
+///   the [`ViewQuery`] and the [`ViewContextCollection`] are shared between all threads, read-only.
+/// - The [`crate::ViewContextSystem`]s of the view have all finished executing.
+/// - Stores are read through [`crate::ViewerContext::recording_engine`], which holds a read lock,
+///   and caches through [`crate::Caches::entry`], which holds a lock on all caches,
+///   so keep the closures passed to it short.
+///
+/// State shared with other visualizers must therefore be synchronized,
+/// and [`Self::execute`] must not wait on anything that only the UI thread makes progress on.
 pub trait VisualizerSystem: Send + Sync + 'static {
     // TODO(andreas): This should be able to list out the ContextSystems it needs.
 
@@ -119,6 +140,18 @@ pub trait VisualizerSystem: Send + Sync + 'static {
         context_systems: &ViewContextCollection,
     ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError>;
 
+    /// Whether the result of [`Self::execute`] only depends on the recording, the blueprint,
+    /// the query and the selection & hover state.
+    ///
+    /// If all visualizers of a view with data opt in, the execution of the view is reused
+    /// across frames until one of these changes.
+    /// This requires the context systems of the view to only depend on them as well.
+    ///
+    /// Visualizers that e.g. read the view state, or show data that is loaded in the background, must not opt in.
+    fn is_output_reusable(&self) -> bool {
+        false
+    }
+
     /// Optionally retrieves a chunk store reference from the scene element.
     ///
     /// This is useful for retrieving data that is common to several visualizers of a [`crate::ViewClass`].
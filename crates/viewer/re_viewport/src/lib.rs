@@ -5,6 +5,7 @@
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod system_execution;
+mod view_execution_cache;
 mod view_highlights;
 mod viewport_ui;
 
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use ahash::HashMap;
 use rayon::prelude::*;
@@ -8,8 +9,9 @@ use re_viewer_context::{
     ViewState, ViewStates, ViewerContext, VisualizerCollection,
 };
 
+use crate::view_execution_cache::ViewExecutionCache;
 use crate::view_highlights::highlights_for_view;
-use re_viewport_blueprint::ViewBlueprint;
+use re_viewport_blueprint::{FollowLatest, TimeOffset, ViewBlueprint};
 
 fn run_view_systems(
     ctx: &ViewerContext<'_>,
@@ -78,7 +80,12 @@ pub fn execute_systems_for_view<'a>(
         });
     }
 
-    let current_query = ctx.rec_cfg.time_ctrl.read().current_query();
+    // Views following the latest data ignore the time cursor.
+    let current_query = FollowLatest::load(ctx, view.id)
+        .latest_at_query(ctx)
+        .unwrap_or_else(|| ctx.rec_cfg.time_ctrl.read().current_query());
+    let time_type = ctx.rec_cfg.time_ctrl.read().time_type();
+    let current_query = TimeOffset::load(ctx, view.id).apply(time_type, current_query);
     let query = re_viewer_context::ViewQuery {
         view_id: view.id,
         space_origin: &view.space_origin,
@@ -88,6 +95,19 @@ pub fn execute_systems_for_view<'a>(
         highlights,
     };
 
+    // Without a fingerprint of the view state, we can't tell whether the output is still valid.
+    let fingerprint = view_state
+        .execution_fingerprint()
+        .map(|view_state_fingerprint| execution_fingerprint(ctx, &query, view_state_fingerprint));
+    if let Some(fingerprint) = fingerprint
+        && let Some(output) = ctx
+            .store_context
+            .caches
+            .entry(|c: &mut ViewExecutionCache| c.get(view.id, fingerprint))
+    {
+        return (query, output);
+    }
+
     let mut context_systems = ctx
         .view_class_registry()
         .new_context_collection(view.class_identifier());
@@ -104,14 +124,45 @@ pub fn execute_systems_for_view<'a>(
         &mut view_systems,
     );
 
-    (
-        query,
-        SystemExecutionOutput {
-            view_systems,
-            context_systems,
-            draw_data,
-        },
-    )
+    let is_output_reusable = view_systems.systems.iter().all(|(name, system)| {
+        !query.per_visualizer_data_results.contains_key(name) || system.is_output_reusable()
+    });
+
+    let output = SystemExecutionOutput {
+        view_systems: Arc::new(view_systems),
+        context_systems: Arc::new(context_systems),
+        draw_data,
+    };
+    if let Some(fingerprint) = fingerprint
+        && is_output_reusable
+    {
+        ctx.store_context
+            .caches
+            .entry(|c: &mut ViewExecutionCache| c.insert(view.id, fingerprint, output.clone()));
+    }
+
+    (query, output)
+}
+
+/// Everything the output of visualizers that opted in to reusing it depends on,
+/// see [`re_viewer_context::VisualizerSystem::is_output_reusable`].
+fn execution_fingerprint(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    view_state_fingerprint: u64,
+) -> u64 {
+    re_tracing::profile_function!();
+
+    egui::util::hash((
+        query.view_id,
+        view_state_fingerprint,
+        query.timeline,
+        query.latest_at,
+        ctx.recording().generation(),
+        ctx.blueprint_db().generation(),
+        ctx.selection().iter_items().collect::<Vec<_>>(),
+        ctx.hovered().iter_items().collect::<Vec<_>>(),
+    ))
 }
 
 pub fn execute_systems_for_all_views<'a>(
@@ -127,6 +178,10 @@ pub fn execute_systems_for_all_views<'a>(
         view_states.ensure_state_exists(*view_id, view.class(ctx.view_class_registry()));
     }
 
+    ctx.store_context
+        .caches
+        .entry(|c: &mut ViewExecutionCache| c.retain_views(|view_id| views.contains_key(view_id)));
+
     tree.active_tiles()
         .into_par_iter()
         .filter_map(|tile_id| {
@@ -0,0 +1,99 @@
+use ahash::HashMap;
+
+use re_viewer_context::{
+    Cache, CacheMemoryReport, CacheMemoryReportItem, SystemExecutionOutput, ViewId,
+};
+
+struct CachedExecution {
+    fingerprint: u64,
+    output: SystemExecutionOutput,
+    used_this_frame: bool,
+}
+
+/// The [`SystemExecutionOutput`] of views whose visualizers all opted in to reusing it,
+/// see [`re_viewer_context::VisualizerSystem::is_output_reusable`].
+///
+/// Saves rebuilding the draw data of static scenes every frame.
+#[derive(Default)]
+pub struct ViewExecutionCache(HashMap<ViewId, CachedExecution>);
+
+impl ViewExecutionCache {
+    /// The output of the last execution of the view, if it had the same fingerprint.
+    ///
+    /// An outdated output is dropped right away,
+    /// which lets context systems update the state they share with it in place.
+    pub fn get(&mut self, view_id: ViewId, fingerprint: u64) -> Option<SystemExecutionOutput> {
+        let cached = self.0.get_mut(&view_id)?;
+        if cached.fingerprint != fingerprint {
+            self.0.remove(&view_id);
+            return None;
+        }
+        cached.used_this_frame = true;
+        Some(cached.output.clone())
+    }
+
+    pub fn insert(&mut self, view_id: ViewId, fingerprint: u64, output: SystemExecutionOutput) {
+        self.0.insert(
+            view_id,
+            CachedExecution {
+                fingerprint,
+                output,
+                used_this_frame: true,
+            },
+        );
+    }
+
+    /// Drops the output of all views for which `keep` returns `false`, e.g. views that were removed.
+    pub fn retain_views(&mut self, mut keep: impl FnMut(&ViewId) -> bool) {
+        self.0.retain(|view_id, _| keep(view_id));
+    }
+}
+
+impl CachedExecution {
+    /// Estimated cpu memory of the cached output.
+    ///
+    /// The buffers the draw data refers to are on the gpu, and accounted for by the renderer.
+    fn bytes_cpu(&self) -> u64 {
+        (std::mem::size_of::<Self>()
+            + self.output.draw_data.capacity()
+                * std::mem::size_of::<re_renderer::QueueableDrawData>()) as u64
+    }
+}
+
+impl Cache for ViewExecutionCache {
+    fn begin_frame(&mut self) {
+        // Drop the output of views that are no longer shown, which keeps their gpu data alive.
+        self.0
+            .retain(|_, cached| std::mem::take(&mut cached.used_this_frame));
+    }
+
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn name(&self) -> &'static str {
+        "View Executions"
+    }
+
+    fn memory_report(&self) -> CacheMemoryReport {
+        let mut items: Vec<_> = self
+            .0
+            .iter()
+            .map(|(view_id, cached)| CacheMemoryReportItem {
+                item_name: view_id.to_string(),
+                bytes_cpu: cached.bytes_cpu(),
+                bytes_gpu: None,
+            })
+            .collect();
+        items.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+        CacheMemoryReport {
+            bytes_cpu: items.iter().map(|item| item.bytes_cpu).sum(),
+            bytes_gpu: None,
+            per_cache_item_info: items,
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -77,6 +77,14 @@ pub fn highlights_for_view(
         }
     }
 
+    for entity_path in ctx.selection_state().highlighted_differences() {
+        add_highlight_and_mask(
+            entity_path.hash(),
+            entity_path.clone().into(),
+            SelectionHighlight::SiblingSelection,
+        );
+    }
+
     let mut hover_mask_index: u8 = 0;
     let mut next_hover_mask = || {
         // We don't expect to overflow u8, but if we do, don't use the "background mask".
@@ -15,7 +15,8 @@ use re_viewer_context::{
     SystemExecutionOutput, ViewId, ViewQuery, ViewStates, ViewerContext, icon_for_container_kind,
 };
 use re_viewport_blueprint::{
-    ViewBlueprint, ViewportBlueprint, ViewportCommand, create_entity_add_info,
+    FollowLatest, TimeOffset, ViewBlueprint, ViewportBlueprint, ViewportCommand,
+    create_entity_add_info,
 };
 
 use crate::system_execution::{execute_systems_for_all_views, execute_systems_for_view};
@@ -40,6 +41,30 @@ impl ViewportUi {
         ui: &mut egui::Ui,
         ctx: &ViewerContext<'_>,
         view_states: &mut ViewStates,
+    ) {
+        self.viewport_ui_impl(ui, ctx, view_states, None);
+    }
+
+    /// Shows the same layout for another recording, next to the one of [`Self::viewport_ui`].
+    ///
+    /// `tree_id` must be unique for each copy.
+    /// Changes to the layout made in the copy are discarded, the views can still be interacted with.
+    pub fn comparison_viewport_ui(
+        &self,
+        ui: &mut egui::Ui,
+        ctx: &ViewerContext<'_>,
+        view_states: &mut ViewStates,
+        tree_id: egui::Id,
+    ) {
+        self.viewport_ui_impl(ui, ctx, view_states, Some(tree_id));
+    }
+
+    fn viewport_ui_impl(
+        &self,
+        ui: &mut egui::Ui,
+        ctx: &ViewerContext<'_>,
+        view_states: &mut ViewStates,
+        comparison_tree_id: Option<egui::Id>,
     ) {
         let tokens = ui.tokens();
 
@@ -79,6 +104,14 @@ impl ViewportUi {
             blueprint.tree.clone()
         };
 
+        if let Some(tree_id) = comparison_tree_id {
+            // egui_tiles derives all its ids from the id of the tree.
+            let mut comparison_tree = egui_tiles::Tree::empty(tree_id);
+            comparison_tree.root = tree.root;
+            comparison_tree.tiles = tree.tiles;
+            tree = comparison_tree;
+        }
+
         let executed_systems_per_view =
             execute_systems_for_all_views(ctx, &tree, &blueprint.views, view_states);
 
@@ -191,7 +224,7 @@ impl ViewportUi {
                 }
             }
 
-            if blueprint.maximized.is_none() {
+            if blueprint.maximized.is_none() && comparison_tree_id.is_none() {
                 // Detect if the user has moved a tab or similar.
                 // If so we can no longer automatically change the layout without discarding user edits.
                 let is_dragging_a_tile = tree.dragged_id(ui.ctx()).is_some();
@@ -370,24 +403,6 @@ impl<'a> egui_tiles::Behavior<ViewId> for TilesDelegate<'a, '_> {
             let view = view_blueprint;
             re_tracing::profile_scope!("late-system-execute", view.class_identifier().as_str());
 
-            let query_result = ctx.lookup_query_result(view.id);
-
-            let mut per_visualizer_data_results = re_viewer_context::PerSystemDataResults::default();
-
-            {
-                re_tracing::profile_scope!("per_system_data_results");
-
-                query_result.tree.visit(&mut |node| {
-                    for system in &node.data_result.visualizers {
-                        per_visualizer_data_results
-                            .entry(*system)
-                            .or_default()
-                            .push(&node.data_result);
-                    }
-                    true
-                });
-            }
-
             let class = view_blueprint.class(self.ctx.view_class_registry());
             execute_systems_for_view(ctx, view, self.view_states.get_mut_or_create(*view_id, class))
         });
@@ -602,6 +617,31 @@ impl<'a> egui_tiles::Behavior<ViewId> for TilesDelegate<'a, '_> {
             }
         }
 
+        // Show button to follow the latest data:
+        let mut follow_latest = FollowLatest::load(self.ctx, view_id);
+        if ui
+            .add(
+                ui.small_icon_button_widget(&re_ui::icons::FOLLOW, "Follow latest data")
+                    .selected(follow_latest.enabled),
+            )
+            .on_hover_text(if follow_latest.enabled {
+                "Stop following the latest data, and show the data at the time cursor again"
+            } else {
+                "Follow the latest data, wherever the time cursor is"
+            })
+            .clicked()
+        {
+            follow_latest.enabled = !follow_latest.enabled;
+            follow_latest.save(self.ctx, view_id);
+        }
+
+        let time_offset = TimeOffset::load(self.ctx, view_id);
+        if !time_offset.is_zero() {
+            let time_type = self.ctx.rec_cfg.time_ctrl.read().time_type();
+            ui.weak(time_offset.format(time_type))
+                .on_hover_text("This view is shown at an offset from the time cursor");
+        }
+
         let view_class = view_blueprint.class(self.ctx.view_class_registry());
 
         // give the view a chance to display some extra UI in the top bar.
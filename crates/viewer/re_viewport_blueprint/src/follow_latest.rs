@@ -0,0 +1,88 @@
+//! Live-tail mode of a view, for monitoring dashboards.
+//!
+//! A view that follows the latest data always shows the newest data on the selected timeline,
+//! wherever the time cursor is. Other views can still be scrubbed back in time meanwhile.
+//!
+//! The mode is stored in the blueprint, next to the other properties of the view.
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::EntityPath;
+use re_types::{
+    blueprint::{archetypes, components::Enabled},
+    components,
+};
+use re_viewer_context::{ViewId, ViewerContext};
+
+use crate::ViewProperty;
+
+fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<archetypes::FollowLatest>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// Whether a view follows the latest data, see the [module docs](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FollowLatest {
+    pub enabled: bool,
+
+    /// The entity the eye of a 3D view tracks while following the latest data.
+    pub camera_entity: Option<EntityPath>,
+}
+
+impl FollowLatest {
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Self {
+        let property = property(ctx, view_id);
+
+        Self {
+            enabled: property
+                .component_or_empty::<Enabled>(&archetypes::FollowLatest::descriptor_enabled())
+                .ok()
+                .flatten()
+                .is_some_and(bool::from),
+            camera_entity: property
+                .component_or_empty::<components::EntityPath>(
+                    &archetypes::FollowLatest::descriptor_camera_entity(),
+                )
+                .ok()
+                .flatten()
+                .map(|entity_path| EntityPath::parse_forgiving(&entity_path)),
+        }
+    }
+
+    pub fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = property(ctx, view_id);
+        property.save_blueprint_component(
+            ctx,
+            &archetypes::FollowLatest::descriptor_enabled(),
+            &Enabled::from(self.enabled),
+        );
+        if let Some(camera_entity) = &self.camera_entity {
+            property.save_blueprint_component(
+                ctx,
+                &archetypes::FollowLatest::descriptor_camera_entity(),
+                &components::EntityPath::from(camera_entity.to_string()),
+            );
+        } else {
+            property.clear_blueprint_component(
+                ctx,
+                archetypes::FollowLatest::descriptor_camera_entity(),
+            );
+        }
+    }
+
+    /// The query a view following the latest data is shown at:
+    /// the newest time with data on the selected timeline.
+    ///
+    /// `None` if not enabled, or if there is no data on the timeline yet.
+    pub fn latest_at_query(&self, ctx: &ViewerContext<'_>) -> Option<LatestAtQuery> {
+        if !self.enabled {
+            return None;
+        }
+        let timeline = *ctx.rec_cfg.time_ctrl.read().timeline().name();
+        let time_range = ctx.recording().time_range_for(&timeline)?;
+        Some(LatestAtQuery::new(timeline, time_range.max()))
+    }
+}
@@ -5,6 +5,8 @@
 mod auto_layout;
 mod container;
 mod entity_add_info;
+mod follow_latest;
+mod time_offset;
 pub mod ui;
 mod view;
 mod view_contents;
@@ -14,8 +16,10 @@ mod viewport_command;
 
 pub use container::ContainerBlueprint;
 pub use entity_add_info::{CanAddToView, EntityAddInfo, create_entity_add_info};
+pub use follow_latest::FollowLatest;
 use re_chunk::EntityPath;
 use re_viewer_context::ViewerContext;
+pub use time_offset::TimeOffset;
 pub use view::ViewBlueprint;
 pub use view_contents::{DataQueryPropertyResolver, ViewContents};
 pub use view_properties::{ViewProperty, ViewPropertyQueryError, entity_path_for_view_property};
@@ -0,0 +1,125 @@
+//! Offset of the time a view is shown at, relative to the time cursor.
+//!
+//! With it, e.g. one 3D view can show the time cursor and another one the data of five seconds before,
+//! to see cause and effect across a delay side by side.
+//!
+//! The offset is stored in the blueprint, next to the other properties of the view.
+
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{TimeInt, TimeType};
+use re_types::{blueprint::archetypes, components::Scalar};
+use re_viewer_context::{ViewId, ViewerContext};
+
+use crate::ViewProperty;
+
+fn property(ctx: &ViewerContext<'_>, view_id: ViewId) -> ViewProperty {
+    ViewProperty::from_archetype::<archetypes::TimeOffset>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    )
+}
+
+/// See the [module docs](self).
+///
+/// The same offset applies to whichever timeline is selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimeOffset {
+    /// In seconds on temporal timelines, and in steps on sequence timelines.
+    ///
+    /// Negative to show the past.
+    pub offset: f64,
+}
+
+impl TimeOffset {
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Self {
+        let offset = property(ctx, view_id)
+            .component_or_empty::<Scalar>(&archetypes::TimeOffset::descriptor_offset())
+            .ok()
+            .flatten()
+            .map_or(0.0, |offset| offset.0.0);
+        Self { offset }
+    }
+
+    pub fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = property(ctx, view_id);
+        if self.is_zero() {
+            property.clear_blueprint_component(ctx, archetypes::TimeOffset::descriptor_offset());
+        } else {
+            property.save_blueprint_component(
+                ctx,
+                &archetypes::TimeOffset::descriptor_offset(),
+                &Scalar::from(self.offset),
+            );
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.offset == 0.0
+    }
+
+    /// The offset in the units of [`TimeInt`] on a timeline of the given type.
+    pub fn offset_int(&self, time_type: TimeType) -> i64 {
+        match time_type {
+            TimeType::Sequence => self.offset.round() as i64,
+            TimeType::DurationNs | TimeType::TimestampNs => (self.offset * 1e9).round() as i64,
+        }
+    }
+
+    /// Moves the query by the offset.
+    ///
+    /// A query at [`TimeInt::MAX`], i.e. without a time cursor, is left as is.
+    pub fn apply(&self, time_type: TimeType, query: LatestAtQuery) -> LatestAtQuery {
+        if self.is_zero() || query.at() == TimeInt::MAX {
+            return query;
+        }
+        LatestAtQuery::new(
+            query.timeline(),
+            TimeInt::saturated_temporal_i64(
+                query
+                    .at()
+                    .as_i64()
+                    .saturating_add(self.offset_int(time_type)),
+            ),
+        )
+    }
+
+    /// E.g. "−5 s" or "+3 steps".
+    pub fn format(&self, time_type: TimeType) -> String {
+        let sign = if self.offset < 0.0 { '−' } else { '+' };
+        let unit = match time_type {
+            TimeType::Sequence => "steps",
+            TimeType::DurationNs | TimeType::TimestampNs => "s",
+        };
+        format!("{sign}{} {unit}", self.offset.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimelineName;
+
+    use super::*;
+
+    #[test]
+    fn offset_moves_query() {
+        let timeline = TimelineName::new("t");
+        let query = LatestAtQuery::new(timeline, TimeInt::new_temporal(10_000_000_000));
+        let offset = TimeOffset { offset: -5.0 };
+
+        assert_eq!(
+            offset.apply(TimeType::TimestampNs, query.clone()).at(),
+            TimeInt::new_temporal(5_000_000_000)
+        );
+        assert_eq!(
+            offset.apply(TimeType::Sequence, query).at(),
+            TimeInt::new_temporal(9_999_999_995)
+        );
+
+        let unbounded = LatestAtQuery::new(timeline, TimeInt::MAX);
+        assert_eq!(
+            offset.apply(TimeType::Sequence, unbounded).at(),
+            TimeInt::MAX
+        );
+    }
+}
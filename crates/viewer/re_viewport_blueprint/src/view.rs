@@ -761,6 +761,7 @@ mod tests {
                 ctx.store_context,
                 &test_ctx.view_class_registry,
                 &test_ctx.blueprint_query,
+                &ctx.current_query(),
                 visualizable_entities,
             );
             let mut view_states = ViewStates::default();
@@ -8,8 +8,8 @@ use smallvec::SmallVec;
 
 use re_entity_db::{EntityDb, EntityTree, external::re_chunk_store::LatestAtQuery};
 use re_log_types::{
-    EntityPath, EntityPathFilter, EntityPathSubs, ResolvedEntityPathFilter, ResolvedEntityPathRule,
-    Timeline, path::RuleEffect,
+    ComponentPredicate, EntityPath, EntityPathFilter, EntityPathSubs, ResolvedEntityPathFilter,
+    ResolvedEntityPathRule, Timeline, path::RuleEffect,
 };
 use re_types::Loggable as _;
 use re_types::{
@@ -271,11 +271,14 @@ impl ViewContents {
     /// Note that this result will not have any resolved [`PropertyOverrides`]. Those can
     /// be added by separately calling `DataQueryPropertyResolver::update_overrides` on
     /// the result.
+    ///
+    /// Component predicates of the entity path filter are evaluated at `recording_query`.
     pub fn execute_query(
         &self,
         ctx: &re_viewer_context::StoreContext<'_>,
         view_class_registry: &re_viewer_context::ViewClassRegistry,
         blueprint_query: &LatestAtQuery,
+        recording_query: &LatestAtQuery,
         visualizable_entities_for_visualizer_systems: &PerVisualizer<VisualizableEntities>,
     ) -> DataQueryResult {
         re_tracing::profile_function!();
@@ -286,6 +289,8 @@ impl ViewContents {
             visualizable_entities_for_visualizer_systems,
             entity_path_filter: self.entity_path_filter.clone(),
             override_base_path: Self::override_path_for_entity(self.view_id, &EntityPath::root()),
+            recording: ctx.recording,
+            recording_query,
         };
 
         let mut num_matching_entities = 0;
@@ -345,9 +350,34 @@ struct QueryExpressionEvaluator<'a> {
     visualizable_entities_for_visualizer_systems: &'a PerVisualizer<VisualizableEntities>,
     entity_path_filter: ResolvedEntityPathFilter,
     override_base_path: EntityPath,
+    recording: &'a EntityDb,
+    recording_query: &'a LatestAtQuery,
 }
 
 impl QueryExpressionEvaluator<'_> {
+    /// Whether the components of an entity pass a predicate of the filter.
+    fn predicate_passes(&self, entity_path: &EntityPath, predicate: &ComponentPredicate) -> bool {
+        let Some(components) = self
+            .recording
+            .storage_engine()
+            .store()
+            .all_components_for_entity_sorted(entity_path)
+        else {
+            return false;
+        };
+
+        predicate.matches(|comparison| {
+            components
+                .iter()
+                .filter(|component_descr| comparison.refers_to(component_descr))
+                .find_map(|component_descr| {
+                    self.recording
+                        .latest_at(self.recording_query, entity_path, [component_descr])
+                        .component_batch_raw(component_descr)
+                })
+        })
+    }
+
     fn add_entity_tree_to_data_results_recursive(
         &self,
         tree: &EntityTree,
@@ -367,7 +397,11 @@ impl QueryExpressionEvaluator<'_> {
 
         let entity_path = &tree.path;
 
-        let matches_filter = self.entity_path_filter.matches(entity_path);
+        let matches_filter = self
+            .entity_path_filter
+            .matches_with(entity_path, |predicate| {
+                self.predicate_passes(entity_path, predicate)
+            });
         *num_matching_entities += matches_filter as usize;
 
         // This list will be updated below during `update_overrides_recursive` by calling `choose_default_visualizers`
@@ -794,6 +828,7 @@ mod tests {
                 &ctx,
                 &view_class_registry,
                 &LatestAtQuery::latest(blueprint_timeline()),
+                &LatestAtQuery::latest(*timeline_frame.name()),
                 &visualizable_entities_for_visualizer_systems,
             );
 
@@ -0,0 +1,22 @@
+// Attach a configuration file to a recording.
+
+#include <rerun.hpp>
+#include <string>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_attachment");
+    rec.spawn().exit_on_failure();
+
+    const std::string config = R"({
+  "model": "yolov8n",
+  "confidence_threshold": 0.5,
+  "classes": ["person", "car", "bicycle"]
+})";
+
+    rec.log_static(
+        "config",
+        rerun::Attachment(rerun::components::Blob(config))
+            .with_media_type(rerun::components::MediaType::json())
+            .with_filename("config.json")
+    );
+}
@@ -0,0 +1,19 @@
+"""Attach a configuration file to a recording."""
+
+import json
+
+import rerun as rr
+
+rr.init("rerun_example_attachment", spawn=True)
+
+config = {"model": "yolov8n", "confidence_threshold": 0.5, "classes": ["person", "car", "bicycle"]}
+
+rr.log(
+    "config",
+    rr.Attachment(
+        contents=json.dumps(config, indent=2).encode(),
+        media_type=rr.MediaType.JSON,
+        filename="config.json",
+    ),
+    static=True,
+)
@@ -0,0 +1,22 @@
+//! Attach a configuration file to a recording.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_attachment").spawn()?;
+
+    let config = r#"{
+  "model": "yolov8n",
+  "confidence_threshold": 0.5,
+  "classes": ["person", "car", "bicycle"]
+}"#;
+
+    rec.log_static(
+        "config",
+        &rerun::Attachment::from_file_contents(
+            config.as_bytes().to_vec(),
+            Some(rerun::MediaType::json()),
+        )
+        .with_filename("config.json"),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// Create and log a heatmap.
+
+#include <rerun.hpp>
+
+#include <array>
+#include <vector>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_heatmap");
+    rec.spawn().exit_on_failure();
+
+    // The squared distance of each cell to the center, e.g. a simple cost map.
+    const uint32_t HEIGHT = 100;
+    const uint32_t WIDTH = 150;
+    std::vector<float> values(WIDTH * HEIGHT);
+    for (uint32_t y = 0; y < HEIGHT; ++y) {
+        for (uint32_t x = 0; x < WIDTH; ++x) {
+            const float dx = static_cast<float>(x) - 75.0f;
+            const float dy = static_cast<float>(y) - 50.0f;
+            values[y * WIDTH + x] = dx * dx + dy * dy;
+        }
+    }
+
+    rec.log(
+        "heatmap",
+        rerun::Heatmap(values, {WIDTH, HEIGHT})
+            .with_colormap(rerun::components::Colormap::Inferno)
+            .with_value_range(rerun::components::ValueRange(std::array<double, 2>{0.0, 5000.0}))
+    );
+}
@@ -0,0 +1,12 @@
+"""Create and log a heatmap."""
+
+import numpy as np
+import rerun as rr
+
+# The squared distance of each cell to the center, e.g. a simple cost map.
+y, x = np.mgrid[0:100, 0:150]
+values = (x - 75) ** 2 + (y - 50) ** 2
+
+rr.init("rerun_example_heatmap", spawn=True)
+
+rr.log("heatmap", rr.Heatmap(values, colormap="Inferno", value_range=[0.0, 5000.0]))
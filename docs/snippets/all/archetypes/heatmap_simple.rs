@@ -0,0 +1,21 @@
+//! Create and log a heatmap.
+
+use ndarray::Array;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_heatmap").spawn()?;
+
+    // The squared distance of each cell to the center, e.g. a simple cost map.
+    let values = Array::from_shape_fn((100, 150), |(y, x)| {
+        let (dx, dy) = (x as f32 - 75.0, y as f32 - 50.0);
+        dx * dx + dy * dy
+    });
+
+    let heatmap = rerun::Heatmap::try_from(values)?
+        .with_colormap(rerun::components::Colormap::Inferno)
+        .with_value_range(rerun::components::ValueRange::new(0.0, 5000.0));
+
+    rec.log("heatmap", &heatmap)?;
+
+    Ok(())
+}
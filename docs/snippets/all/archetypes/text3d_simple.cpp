@@ -0,0 +1,28 @@
+// Log a few text labels in 3D space.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_text3d");
+    rec.spawn().exit_on_failure();
+
+    rec.log(
+        "labels",
+        rerun::Text3D(
+            {{0.0f, 0.0f, 0.0f}, {1.0f, 0.0f, 0.0f}, {0.0f, 1.0f, 0.0f}},
+            {"origin", "x", "y"}
+        )
+            // Positive values are scene units, negative values UI points.
+            .with_font_sizes({0.2f, -16.0f, -16.0f})
+            .with_colors({
+                rerun::Rgba32(255, 255, 255),
+                rerun::Rgba32(255, 0, 0),
+                rerun::Rgba32(0, 255, 0),
+            })
+            .with_anchors({
+                rerun::TextAnchor::Bottom,
+                rerun::TextAnchor::Left,
+                rerun::TextAnchor::Left,
+            })
+    );
+}
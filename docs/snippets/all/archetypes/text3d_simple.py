@@ -0,0 +1,16 @@
+"""Log a few text labels in 3D space."""
+
+import rerun as rr
+
+rr.init("rerun_example_text3d", spawn=True)
+
+rr.log(
+    "labels",
+    rr.Text3D(
+        positions=[[0, 0, 0], [1, 0, 0], [0, 1, 0]],
+        texts=["origin", "x", "y"],
+        font_sizes=[0.2, -16.0, -16.0],  # Positive values are scene units, negative values UI points.
+        colors=[[255, 255, 255], [255, 0, 0], [0, 255, 0]],
+        anchors=["Bottom", "Left", "Left"],
+    ),
+)
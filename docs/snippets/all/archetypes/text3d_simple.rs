@@ -0,0 +1,28 @@
+//! Log a few text labels in 3D space.
+
+use rerun::components::{FontSize, TextAnchor};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_text3d").spawn()?;
+
+    rec.log(
+        "labels",
+        &rerun::Text3D::new(
+            [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+            ["origin", "x", "y"],
+        )
+        .with_font_sizes([
+            FontSize::new_scene_units(0.2),
+            FontSize::new_ui_points(16.0),
+            FontSize::new_ui_points(16.0),
+        ])
+        .with_colors([
+            rerun::Color::from_rgb(255, 255, 255),
+            rerun::Color::from_rgb(255, 0, 0),
+            rerun::Color::from_rgb(0, 255, 0),
+        ])
+        .with_anchors([TextAnchor::Bottom, TextAnchor::Left, TextAnchor::Left]),
+    )?;
+
+    Ok(())
+}
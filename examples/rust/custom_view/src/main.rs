@@ -1,6 +1,17 @@
 //! This example shows how to add custom Views to the Rerun Viewer.
+//!
+//! Usage:
+//! ```
+//!  cargo run -p custom_view -- --help
+//! ```
 
-use rerun::external::{re_crash_handler, re_grpc_server, re_log, re_memory, re_viewer, tokio};
+use std::path::PathBuf;
+
+use rerun::blueprint::{Blueprint, Container, View, views::Spatial3DView};
+use rerun::external::{
+    re_crash_handler, re_grpc_server, re_log, re_memory, re_viewer,
+    re_viewer_context::ViewClass as _, tokio,
+};
 
 mod points3d_color_view;
 mod points3d_color_visualizer;
@@ -12,6 +23,20 @@ mod points3d_color_visualizer;
 static GLOBAL: re_memory::AccountingAllocator<mimalloc::MiMalloc> =
     re_memory::AccountingAllocator::new(mimalloc::MiMalloc);
 
+#[derive(Debug, clap::Parser)]
+#[clap(author, version, about)]
+struct Args {
+    /// PEM-encoded certificate chain to accept TLS connections with, e.g. from SDKs on other machines.
+    ///
+    /// Plaintext connections are rejected then. Requires `--tls-key`.
+    #[clap(long, requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+
+    /// PEM-encoded private key of `--tls-cert`.
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let main_thread_token = rerun::MainThreadToken::i_promise_i_am_on_the_main_thread();
@@ -23,16 +48,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // them to Rerun analytics (if the `analytics` feature is on in `Cargo.toml`).
     re_crash_handler::install_crash_handlers(re_viewer::build_info());
 
+    use clap::Parser as _;
+    let args = Args::parse();
+
+    let mut server_options =
+        re_grpc_server::ServerOptions::from("75%".parse::<re_memory::MemoryLimit>()?);
+    if let (Some(cert_path), Some(key_path)) = (&args.tls_cert, &args.tls_key) {
+        let tls = re_grpc_server::TlsConfig::from_pem_files(cert_path, key_path)?;
+        server_options =
+            server_options.with_security(re_grpc_server::ServerSecurity::default().with_tls(tls));
+    }
+
     // Listen for gRPC connections from Rerun's logging SDKs.
     // There are other ways of "feeding" the viewer though - all you need is a `re_smart_channel::Receiver`.
     let (rx, _) = re_grpc_server::spawn_with_recv(
         "0.0.0.0:9876".parse()?,
-        "75%".parse()?,
+        server_options,
         re_grpc_server::shutdown::never(),
     );
 
     let startup_options = re_viewer::StartupOptions::default();
 
+    // Show the data of `minimal_options` side by side in a 3D view and our custom view,
+    // instead of the layout the viewer would come up with.
+    let (blueprint_id, blueprint_msgs) = Blueprint::new(Container::horizontal([
+        View::new::<Spatial3DView>("/").with_name("3D").into(),
+        View::from_class_identifier(points3d_color_view::ColorCoordinatesView::identifier(), "/")
+            .with_name("Colors")
+            .into(),
+    ]))
+    .to_log_msgs("rerun_example_minimal_options")?;
+
     // This is used for analytics, if the `analytics` feature is on in `Cargo.toml`
     let app_env = re_viewer::AppEnvironment::Custom("My extended Rerun Viewer".to_owned());
 
@@ -58,12 +104,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ),
             );
             app.add_log_receiver(rx);
+            app.set_default_blueprint(blueprint_id, blueprint_msgs);
 
             // Register the custom view
             app.view_class_registry()
                 .add_class::<points3d_color_view::ColorCoordinatesView>()
                 .unwrap();
 
+            // Add a custom panel next to the selection panel
+            app.add_panel("Recording info", re_viewer::PanelSide::Right, |ctx, ui| {
+                let recording = ctx.recording();
+                ui.label(format!("Application: {}", recording.application_id()));
+                ui.label(format!("Entities: {}", recording.entity_paths().len()));
+                ui.label(format!("Selected items: {}", ctx.selection().len()));
+            });
+
             Box::new(app)
         }),
         None,
@@ -32,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // There are other ways of "feeding" the viewer though - all you need is a `re_smart_channel::Receiver`.
     let (rx, _) = re_grpc_server::spawn_with_recv(
         "0.0.0.0:9876".parse()?,
-        "75%".parse()?,
+        "75%".parse::<re_memory::MemoryLimit>()?,
         re_grpc_server::shutdown::never(),
     );
 
@@ -63,7 +63,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     ViewerEventKind::SelectionChange { items } => {
                         shared_state.current_selection = items;
                     }
-                    ViewerEventKind::RecordingOpen { .. } => {}
+                    ViewerEventKind::RecordingOpen { .. }
+                    | ViewerEventKind::RecordingClose
+                    | ViewerEventKind::BlueprintChange { .. }
+                    | ViewerEventKind::IngestionStart { .. }
+                    | ViewerEventKind::IngestionFinish { .. }
+                    | ViewerEventKind::DataPruned { .. } => {}
+
+                    // New kinds of events may be added in the future.
+                    _ => {}
                 }
             })
         }),
@@ -45,7 +45,9 @@ namespace rerun {
     using components::Position3D;
     using components::Radius;
     using components::Text;
+    using components::TextAnchor;
     using components::TextLogLevel;
+    using components::TransformInterpolation;
     using components::TransformRelation;
     using components::TriangleIndices;
     using components::Vector2D;
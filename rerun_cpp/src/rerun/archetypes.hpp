@@ -7,6 +7,7 @@
 #include "archetypes/arrows3d.hpp"
 #include "archetypes/asset3d.hpp"
 #include "archetypes/asset_video.hpp"
+#include "archetypes/attachment.hpp"
 #include "archetypes/bar_chart.hpp"
 #include "archetypes/boxes2d.hpp"
 #include "archetypes/boxes3d.hpp"
@@ -20,6 +21,7 @@
 #include "archetypes/geo_points.hpp"
 #include "archetypes/graph_edges.hpp"
 #include "archetypes/graph_nodes.hpp"
+#include "archetypes/heatmap.hpp"
 #include "archetypes/image.hpp"
 #include "archetypes/instance_poses3d.hpp"
 #include "archetypes/line_strips2d.hpp"
@@ -38,6 +40,7 @@
 #include "archetypes/series_lines.hpp"
 #include "archetypes/series_points.hpp"
 #include "archetypes/tensor.hpp"
+#include "archetypes/text3d.hpp"
 #include "archetypes/text_document.hpp"
 #include "archetypes/text_log.hpp"
 #include "archetypes/transform3d.hpp"
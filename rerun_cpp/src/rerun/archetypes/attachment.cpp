@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/attachment.fbs".
+
+#include "attachment.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Attachment Attachment::clear_fields() {
+        auto archetype = Attachment();
+        archetype.blob =
+            ComponentBatch::empty<rerun::components::Blob>(Descriptor_blob).value_or_throw();
+        archetype.media_type = ComponentBatch::empty<rerun::components::MediaType>(Descriptor_media_type)
+                                   .value_or_throw();
+        archetype.filename =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_filename).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Attachment::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (blob.has_value()) {
+            columns.push_back(blob.value().partitioned(lengths_).value_or_throw());
+        }
+        if (media_type.has_value()) {
+            columns.push_back(media_type.value().partitioned(lengths_).value_or_throw());
+        }
+        if (filename.has_value()) {
+            columns.push_back(filename.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Attachment::columns() {
+        if (blob.has_value()) {
+            return columns(std::vector<uint32_t>(blob.value().length(), 1));
+        }
+        if (media_type.has_value()) {
+            return columns(std::vector<uint32_t>(media_type.value().length(), 1));
+        }
+        if (filename.has_value()) {
+            return columns(std::vector<uint32_t>(filename.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Attachment>::as_batches(
+        const archetypes::Attachment& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.blob.has_value()) {
+            cells.push_back(archetype.blob.value());
+        }
+        if (archetype.media_type.has_value()) {
+            cells.push_back(archetype.media_type.value());
+        }
+        if (archetype.filename.has_value()) {
+            cells.push_back(archetype.filename.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
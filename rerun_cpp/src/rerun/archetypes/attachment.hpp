@@ -0,0 +1,165 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/attachment.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/blob.hpp"
+#include "../components/media_type.hpp"
+#include "../components/name.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: An arbitrary file attached to a recording, e.g. a configuration file or a report.
+    ///
+    /// The Rerun Viewer shows a preview of the attachment in the selection panel:
+    /// images and videos are shown as such, JSON documents as a collapsible tree,
+    /// other text as plain text, and anything else as a hexdump.
+    /// There is no dedicated preview for PDF documents yet: save them to disk to view them.
+    ///
+    /// See also `archetypes::Asset3D` and `archetypes::AssetVideo` for assets that are shown in the spatial views.
+    struct Attachment {
+        /// The contents of the attachment.
+        std::optional<ComponentBatch> blob;
+
+        /// The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        std::optional<ComponentBatch> media_type;
+
+        /// The name of the file the attachment was read from, e.g. `config.yaml`.
+        ///
+        /// Used as the default file name when saving the attachment from the viewer.
+        std::optional<ComponentBatch> filename;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Attachment";
+
+        /// `ComponentDescriptor` for the `blob` field.
+        static constexpr auto Descriptor_blob = ComponentDescriptor(
+            ArchetypeName, "Attachment:blob", Loggable<rerun::components::Blob>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `media_type` field.
+        static constexpr auto Descriptor_media_type = ComponentDescriptor(
+            ArchetypeName, "Attachment:media_type",
+            Loggable<rerun::components::MediaType>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `filename` field.
+        static constexpr auto Descriptor_filename = ComponentDescriptor(
+            ArchetypeName, "Attachment:filename", Loggable<rerun::components::Name>::ComponentType
+        );
+
+      public:
+        Attachment() = default;
+        Attachment(Attachment&& other) = default;
+        Attachment(const Attachment& other) = default;
+        Attachment& operator=(const Attachment& other) = default;
+        Attachment& operator=(Attachment&& other) = default;
+
+        explicit Attachment(rerun::components::Blob _blob)
+            : blob(ComponentBatch::from_loggable(std::move(_blob), Descriptor_blob).value_or_throw()
+              ) {}
+
+        /// Update only some specific fields of a `Attachment`.
+        static Attachment update_fields() {
+            return Attachment();
+        }
+
+        /// Clear all the fields of a `Attachment`.
+        static Attachment clear_fields();
+
+        /// The contents of the attachment.
+        Attachment with_blob(const rerun::components::Blob& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `blob` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_blob` should
+        /// be used when logging a single row's worth of data.
+        Attachment with_many_blob(const Collection<rerun::components::Blob>& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        Attachment with_media_type(const rerun::components::MediaType& _media_type) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `media_type` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_media_type` should
+        /// be used when logging a single row's worth of data.
+        Attachment with_many_media_type(const Collection<rerun::components::MediaType>& _media_type
+        ) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The name of the file the attachment was read from, e.g. `config.yaml`.
+        ///
+        /// Used as the default file name when saving the attachment from the viewer.
+        Attachment with_filename(const rerun::components::Name& _filename) && {
+            filename =
+                ComponentBatch::from_loggable(_filename, Descriptor_filename).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `filename` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_filename` should
+        /// be used when logging a single row's worth of data.
+        Attachment with_many_filename(const Collection<rerun::components::Name>& _filename) && {
+            filename =
+                ComponentBatch::from_loggable(_filename, Descriptor_filename).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Attachment> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::Attachment& archetype
+        );
+    };
+} // namespace rerun
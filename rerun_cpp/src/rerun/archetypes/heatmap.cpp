@@ -0,0 +1,105 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/heatmap.fbs".
+
+#include "heatmap.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Heatmap Heatmap::clear_fields() {
+        auto archetype = Heatmap();
+        archetype.buffer = ComponentBatch::empty<rerun::components::ImageBuffer>(Descriptor_buffer)
+                               .value_or_throw();
+        archetype.format = ComponentBatch::empty<rerun::components::ImageFormat>(Descriptor_format)
+                               .value_or_throw();
+        archetype.colormap = ComponentBatch::empty<rerun::components::Colormap>(Descriptor_colormap)
+                                 .value_or_throw();
+        archetype.value_range =
+            ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_value_range)
+                .value_or_throw();
+        archetype.opacity =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacity).value_or_throw();
+        archetype.draw_order =
+            ComponentBatch::empty<rerun::components::DrawOrder>(Descriptor_draw_order)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Heatmap::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(6);
+        if (buffer.has_value()) {
+            columns.push_back(buffer.value().partitioned(lengths_).value_or_throw());
+        }
+        if (format.has_value()) {
+            columns.push_back(format.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colormap.has_value()) {
+            columns.push_back(colormap.value().partitioned(lengths_).value_or_throw());
+        }
+        if (value_range.has_value()) {
+            columns.push_back(value_range.value().partitioned(lengths_).value_or_throw());
+        }
+        if (opacity.has_value()) {
+            columns.push_back(opacity.value().partitioned(lengths_).value_or_throw());
+        }
+        if (draw_order.has_value()) {
+            columns.push_back(draw_order.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Heatmap::columns() {
+        if (buffer.has_value()) {
+            return columns(std::vector<uint32_t>(buffer.value().length(), 1));
+        }
+        if (format.has_value()) {
+            return columns(std::vector<uint32_t>(format.value().length(), 1));
+        }
+        if (colormap.has_value()) {
+            return columns(std::vector<uint32_t>(colormap.value().length(), 1));
+        }
+        if (value_range.has_value()) {
+            return columns(std::vector<uint32_t>(value_range.value().length(), 1));
+        }
+        if (opacity.has_value()) {
+            return columns(std::vector<uint32_t>(opacity.value().length(), 1));
+        }
+        if (draw_order.has_value()) {
+            return columns(std::vector<uint32_t>(draw_order.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Heatmap>::as_batches(
+        const archetypes::Heatmap& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(6);
+
+        if (archetype.buffer.has_value()) {
+            cells.push_back(archetype.buffer.value());
+        }
+        if (archetype.format.has_value()) {
+            cells.push_back(archetype.format.value());
+        }
+        if (archetype.colormap.has_value()) {
+            cells.push_back(archetype.colormap.value());
+        }
+        if (archetype.value_range.has_value()) {
+            cells.push_back(archetype.value_range.value());
+        }
+        if (archetype.opacity.has_value()) {
+            cells.push_back(archetype.opacity.value());
+        }
+        if (archetype.draw_order.has_value()) {
+            cells.push_back(archetype.draw_order.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
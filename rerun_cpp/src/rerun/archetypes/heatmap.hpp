@@ -0,0 +1,282 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/heatmap.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/colormap.hpp"
+#include "../components/draw_order.hpp"
+#include "../components/image_buffer.hpp"
+#include "../components/image_format.hpp"
+#include "../components/opacity.hpp"
+#include "../components/value_range.hpp"
+#include "../image_utils.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A 2D grid of scalar values, shown as a colormapped image.
+    ///
+    /// Useful for cost maps, attention maps, correlation matrices and other data that
+    /// would otherwise have to be colormapped manually before logging it as an `archetypes::Image`.
+    ///
+    /// In 2D views the heatmap is drawn like an image, with one pixel per value.
+    /// In 3D views it is drawn as a textured quad on the XY plane of its entity.
+    struct Heatmap {
+        /// The values of the heatmap, in row-major order.
+        std::optional<ComponentBatch> buffer;
+
+        /// The format of the heatmap, i.e. its width, height and datatype.
+        std::optional<ComponentBatch> format;
+
+        /// Colormap used to map the values to colors.
+        ///
+        /// If not set, the heatmap will be rendered using the Viridis colormap.
+        std::optional<ComponentBatch> colormap;
+
+        /// The range of values that is mapped onto the colormap.
+        ///
+        /// Values outside of the range are clamped to the range for the purpose of colormapping.
+        ///
+        /// If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+        std::optional<ComponentBatch> value_range;
+
+        /// Opacity of the heatmap, useful for layering it on top of an image.
+        ///
+        /// Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+        std::optional<ComponentBatch> opacity;
+
+        /// An optional floating point value that specifies the 2D drawing order.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        /// Defaults to `-5.0`.
+        std::optional<ComponentBatch> draw_order;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Heatmap";
+
+        /// `ComponentDescriptor` for the `buffer` field.
+        static constexpr auto Descriptor_buffer = ComponentDescriptor(
+            ArchetypeName, "Heatmap:buffer", Loggable<rerun::components::ImageBuffer>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `format` field.
+        static constexpr auto Descriptor_format = ComponentDescriptor(
+            ArchetypeName, "Heatmap:format", Loggable<rerun::components::ImageFormat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "Heatmap:colormap", Loggable<rerun::components::Colormap>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `value_range` field.
+        static constexpr auto Descriptor_value_range = ComponentDescriptor(
+            ArchetypeName, "Heatmap:value_range",
+            Loggable<rerun::components::ValueRange>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "Heatmap:opacity", Loggable<rerun::components::Opacity>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `draw_order` field.
+        static constexpr auto Descriptor_draw_order = ComponentDescriptor(
+            ArchetypeName, "Heatmap:draw_order",
+            Loggable<rerun::components::DrawOrder>::ComponentType
+        );
+
+      public: // START of extensions from heatmap_ext.cpp:
+        /// Constructs a heatmap from values + resolution.
+        ///
+        /// @param values The values of the heatmap in row-major order.
+        /// If the data does not outlive the heatmap, use `std::move` or create the `rerun::Collection`
+        /// explicitly ahead of time with `rerun::Collection::take_ownership`.
+        /// The length of the data should be `W * H`.
+        /// @param resolution The resolution of the heatmap as {width, height}.
+        Heatmap(Collection<float> values, WidthHeight resolution)
+            : Heatmap{values.to_uint8(), resolution, datatypes::ChannelDatatype::F32} {}
+
+        /// Constructs a heatmap from raw bytes + resolution + datatype.
+        ///
+        /// @param bytes The raw values as bytes, in row-major order.
+        /// If the data does not outlive the heatmap, use `std::move` or create the `rerun::Collection`
+        /// explicitly ahead of time with `rerun::Collection::take_ownership`.
+        /// The length of the data should be `W * H * datatype.size`.
+        /// @param resolution The resolution of the heatmap as {width, height}.
+        /// @param datatype How the data should be interpreted.
+        Heatmap(
+            Collection<uint8_t> bytes, WidthHeight resolution, datatypes::ChannelDatatype datatype
+        ) {
+            auto image_format = datatypes::ImageFormat{resolution, datatype};
+            if (bytes.size() != image_format.num_bytes()) {
+                Error(
+                    ErrorCode::InvalidTensorDimension,
+                    "Heatmap buffer has the wrong size. Got " + std::to_string(bytes.size()) +
+                        " bytes, expected " + std::to_string(image_format.num_bytes())
+                )
+                    .handle();
+            }
+            *this = std::move(*this).with_buffer(bytes).with_format(image_format);
+        }
+
+        // END of extensions from heatmap_ext.cpp, start of generated code:
+
+      public:
+        Heatmap() = default;
+        Heatmap(Heatmap&& other) = default;
+        Heatmap(const Heatmap& other) = default;
+        Heatmap& operator=(const Heatmap& other) = default;
+        Heatmap& operator=(Heatmap&& other) = default;
+
+        /// Update only some specific fields of a `Heatmap`.
+        static Heatmap update_fields() {
+            return Heatmap();
+        }
+
+        /// Clear all the fields of a `Heatmap`.
+        static Heatmap clear_fields();
+
+        /// The values of the heatmap, in row-major order.
+        Heatmap with_buffer(const rerun::components::ImageBuffer& _buffer) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `buffer` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_buffer` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_buffer(const Collection<rerun::components::ImageBuffer>& _buffer) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The format of the heatmap, i.e. its width, height and datatype.
+        Heatmap with_format(const rerun::components::ImageFormat& _format) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `format` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_format` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_format(const Collection<rerun::components::ImageFormat>& _format) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Colormap used to map the values to colors.
+        ///
+        /// If not set, the heatmap will be rendered using the Viridis colormap.
+        Heatmap with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `colormap` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_colormap` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_colormap(const Collection<rerun::components::Colormap>& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The range of values that is mapped onto the colormap.
+        ///
+        /// Values outside of the range are clamped to the range for the purpose of colormapping.
+        ///
+        /// If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+        Heatmap with_value_range(const rerun::components::ValueRange& _value_range) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `value_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_value_range` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_value_range(const Collection<rerun::components::ValueRange>& _value_range
+        ) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity of the heatmap, useful for layering it on top of an image.
+        ///
+        /// Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+        Heatmap with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `opacity` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_opacity` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_opacity(const Collection<rerun::components::Opacity>& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An optional floating point value that specifies the 2D drawing order.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        /// Defaults to `-5.0`.
+        Heatmap with_draw_order(const rerun::components::DrawOrder& _draw_order) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `draw_order` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_draw_order` should
+        /// be used when logging a single row's worth of data.
+        Heatmap with_many_draw_order(const Collection<rerun::components::DrawOrder>& _draw_order
+        ) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Heatmap> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(const archetypes::Heatmap& archetype);
+    };
+} // namespace rerun
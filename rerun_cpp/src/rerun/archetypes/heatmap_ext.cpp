@@ -0,0 +1,51 @@
+#if 0
+
+#include "heatmap.hpp"
+
+// <CODEGEN_COPY_TO_HEADER>
+#include "../image_utils.hpp"
+
+// </CODEGEN_COPY_TO_HEADER>
+
+namespace rerun::archetypes {
+
+    // <CODEGEN_COPY_TO_HEADER>
+
+    /// Constructs a heatmap from values + resolution.
+    ///
+    /// @param values The values of the heatmap in row-major order.
+    /// If the data does not outlive the heatmap, use `std::move` or create the `rerun::Collection`
+    /// explicitly ahead of time with `rerun::Collection::take_ownership`.
+    /// The length of the data should be `W * H`.
+    /// @param resolution The resolution of the heatmap as {width, height}.
+    Heatmap(Collection<float> values, WidthHeight resolution)
+        : Heatmap{values.to_uint8(), resolution, datatypes::ChannelDatatype::F32} {}
+
+    /// Constructs a heatmap from raw bytes + resolution + datatype.
+    ///
+    /// @param bytes The raw values as bytes, in row-major order.
+    /// If the data does not outlive the heatmap, use `std::move` or create the `rerun::Collection`
+    /// explicitly ahead of time with `rerun::Collection::take_ownership`.
+    /// The length of the data should be `W * H * datatype.size`.
+    /// @param resolution The resolution of the heatmap as {width, height}.
+    /// @param datatype How the data should be interpreted.
+    Heatmap(
+        Collection<uint8_t> bytes, WidthHeight resolution, datatypes::ChannelDatatype datatype
+    ) {
+        auto image_format = datatypes::ImageFormat{resolution, datatype};
+        if (bytes.size() != image_format.num_bytes()) {
+            Error(
+                ErrorCode::InvalidTensorDimension,
+                "Heatmap buffer has the wrong size. Got " + std::to_string(bytes.size()) +
+                    " bytes, expected " + std::to_string(image_format.num_bytes())
+            )
+                .handle();
+        }
+        *this = std::move(*this).with_buffer(bytes).with_format(image_format);
+    }
+
+    // </CODEGEN_COPY_TO_HEADER>
+
+} // namespace rerun::archetypes
+
+#endif
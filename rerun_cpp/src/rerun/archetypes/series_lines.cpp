@@ -17,6 +17,9 @@ namespace rerun::archetypes {
         archetype.visible_series =
             ComponentBatch::empty<rerun::components::SeriesVisible>(Descriptor_visible_series)
                 .value_or_throw();
+        archetype.secondary_axis =
+            ComponentBatch::empty<rerun::components::SeriesSecondaryAxis>(Descriptor_secondary_axis)
+                .value_or_throw();
         archetype.aggregation_policy = ComponentBatch::empty<rerun::components::AggregationPolicy>(
                                            Descriptor_aggregation_policy
         )
@@ -26,7 +29,7 @@ namespace rerun::archetypes {
 
     Collection<ComponentColumn> SeriesLines::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(5);
+        columns.reserve(6);
         if (colors.has_value()) {
             columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
         }
@@ -39,6 +42,9 @@ namespace rerun::archetypes {
         if (visible_series.has_value()) {
             columns.push_back(visible_series.value().partitioned(lengths_).value_or_throw());
         }
+        if (secondary_axis.has_value()) {
+            columns.push_back(secondary_axis.value().partitioned(lengths_).value_or_throw());
+        }
         if (aggregation_policy.has_value()) {
             columns.push_back(aggregation_policy.value().partitioned(lengths_).value_or_throw());
         }
@@ -58,6 +64,9 @@ namespace rerun::archetypes {
         if (visible_series.has_value()) {
             return columns(std::vector<uint32_t>(visible_series.value().length(), 1));
         }
+        if (secondary_axis.has_value()) {
+            return columns(std::vector<uint32_t>(secondary_axis.value().length(), 1));
+        }
         if (aggregation_policy.has_value()) {
             return columns(std::vector<uint32_t>(aggregation_policy.value().length(), 1));
         }
@@ -72,7 +81,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(5);
+        cells.reserve(6);
 
         if (archetype.colors.has_value()) {
             cells.push_back(archetype.colors.value());
@@ -86,6 +95,9 @@ namespace rerun {
         if (archetype.visible_series.has_value()) {
             cells.push_back(archetype.visible_series.value());
         }
+        if (archetype.secondary_axis.has_value()) {
+            cells.push_back(archetype.secondary_axis.value());
+        }
         if (archetype.aggregation_policy.has_value()) {
             cells.push_back(archetype.aggregation_policy.value());
         }
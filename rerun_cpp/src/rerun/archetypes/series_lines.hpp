@@ -9,6 +9,7 @@
 #include "../components/aggregation_policy.hpp"
 #include "../components/color.hpp"
 #include "../components/name.hpp"
+#include "../components/series_secondary_axis.hpp"
 #include "../components/series_visible.hpp"
 #include "../components/stroke_width.hpp"
 #include "../result.hpp"
@@ -96,6 +97,14 @@ namespace rerun::archetypes {
         /// May change over time, but can cause discontinuities in the line.
         std::optional<ComponentBatch> visible_series;
 
+        /// Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+        ///
+        /// If not set, all lines on this entity use the primary Y axis.
+        /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+        ///
+        /// Expected to be unchanging over time.
+        std::optional<ComponentBatch> secondary_axis;
+
         /// Configures the zoom-dependent scalar aggregation.
         ///
         /// This is done only if steps on the X axis go below a single pixel,
@@ -127,6 +136,11 @@ namespace rerun::archetypes {
             ArchetypeName, "SeriesLines:visible_series",
             Loggable<rerun::components::SeriesVisible>::ComponentType
         );
+        /// `ComponentDescriptor` for the `secondary_axis` field.
+        static constexpr auto Descriptor_secondary_axis = ComponentDescriptor(
+            ArchetypeName, "SeriesLines:secondary_axis",
+            Loggable<rerun::components::SeriesSecondaryAxis>::ComponentType
+        );
         /// `ComponentDescriptor` for the `aggregation_policy` field.
         static constexpr auto Descriptor_aggregation_policy = ComponentDescriptor(
             ArchetypeName, "SeriesLines:aggregation_policy",
@@ -201,6 +215,21 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+        ///
+        /// If not set, all lines on this entity use the primary Y axis.
+        /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+        ///
+        /// Expected to be unchanging over time.
+        SeriesLines with_secondary_axis(
+            const Collection<rerun::components::SeriesSecondaryAxis>& _secondary_axis
+        ) && {
+            secondary_axis =
+                ComponentBatch::from_loggable(_secondary_axis, Descriptor_secondary_axis)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Configures the zoom-dependent scalar aggregation.
         ///
         /// This is done only if steps on the X axis go below a single pixel,
@@ -10,6 +10,7 @@
 #include "../components/marker_shape.hpp"
 #include "../components/marker_size.hpp"
 #include "../components/name.hpp"
+#include "../components/series_secondary_axis.hpp"
 #include "../components/series_visible.hpp"
 #include "../result.hpp"
 
@@ -98,6 +99,14 @@ namespace rerun::archetypes {
         /// May change over time.
         std::optional<ComponentBatch> visible_series;
 
+        /// Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+        ///
+        /// If not set, all points on this entity use the primary Y axis.
+        /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+        ///
+        /// Expected to be unchanging over time.
+        std::optional<ComponentBatch> secondary_axis;
+
         /// Sizes of the markers.
         ///
         /// May change over time.
@@ -125,6 +134,11 @@ namespace rerun::archetypes {
             ArchetypeName, "SeriesPoints:visible_series",
             Loggable<rerun::components::SeriesVisible>::ComponentType
         );
+        /// `ComponentDescriptor` for the `secondary_axis` field.
+        static constexpr auto Descriptor_secondary_axis = ComponentDescriptor(
+            ArchetypeName, "SeriesPoints:secondary_axis",
+            Loggable<rerun::components::SeriesSecondaryAxis>::ComponentType
+        );
         /// `ComponentDescriptor` for the `marker_sizes` field.
         static constexpr auto Descriptor_marker_sizes = ComponentDescriptor(
             ArchetypeName, "SeriesPoints:marker_sizes",
@@ -199,6 +213,21 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+        ///
+        /// If not set, all points on this entity use the primary Y axis.
+        /// The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+        ///
+        /// Expected to be unchanging over time.
+        SeriesPoints with_secondary_axis(
+            const Collection<rerun::components::SeriesSecondaryAxis>& _secondary_axis
+        ) && {
+            secondary_axis =
+                ComponentBatch::from_loggable(_secondary_axis, Descriptor_secondary_axis)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Sizes of the markers.
         ///
         /// May change over time.
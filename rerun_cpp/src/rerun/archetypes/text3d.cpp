@@ -0,0 +1,94 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/text3d.fbs".
+
+#include "text3d.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    Text3D Text3D::clear_fields() {
+        auto archetype = Text3D();
+        archetype.positions =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_positions)
+                .value_or_throw();
+        archetype.texts =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_texts).value_or_throw();
+        archetype.font_sizes =
+            ComponentBatch::empty<rerun::components::FontSize>(Descriptor_font_sizes)
+                .value_or_throw();
+        archetype.colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_colors).value_or_throw();
+        archetype.anchors = ComponentBatch::empty<rerun::components::TextAnchor>(Descriptor_anchors)
+                                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Text3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (positions.has_value()) {
+            columns.push_back(positions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (texts.has_value()) {
+            columns.push_back(texts.value().partitioned(lengths_).value_or_throw());
+        }
+        if (font_sizes.has_value()) {
+            columns.push_back(font_sizes.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colors.has_value()) {
+            columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
+        }
+        if (anchors.has_value()) {
+            columns.push_back(anchors.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Text3D::columns() {
+        if (positions.has_value()) {
+            return columns(std::vector<uint32_t>(positions.value().length(), 1));
+        }
+        if (texts.has_value()) {
+            return columns(std::vector<uint32_t>(texts.value().length(), 1));
+        }
+        if (font_sizes.has_value()) {
+            return columns(std::vector<uint32_t>(font_sizes.value().length(), 1));
+        }
+        if (colors.has_value()) {
+            return columns(std::vector<uint32_t>(colors.value().length(), 1));
+        }
+        if (anchors.has_value()) {
+            return columns(std::vector<uint32_t>(anchors.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::Text3D>::as_batches(
+        const archetypes::Text3D& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.positions.has_value()) {
+            cells.push_back(archetype.positions.value());
+        }
+        if (archetype.texts.has_value()) {
+            cells.push_back(archetype.texts.value());
+        }
+        if (archetype.font_sizes.has_value()) {
+            cells.push_back(archetype.font_sizes.value());
+        }
+        if (archetype.colors.has_value()) {
+            cells.push_back(archetype.colors.value());
+        }
+        if (archetype.anchors.has_value()) {
+            cells.push_back(archetype.anchors.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,165 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/text3d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/font_size.hpp"
+#include "../components/position3d.hpp"
+#include "../components/text.hpp"
+#include "../components/text_anchor.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Text labels placed at positions in 3D space.
+    ///
+    /// The labels always face the camera. Their size is either given in UI points, in which case
+    /// they keep the same size on screen, or in scene units, in which case they get smaller as
+    /// the camera moves away from them.
+    ///
+    /// Unlike the `labels` of other archetypes, these labels are not attached to any other geometry.
+    struct Text3D {
+        /// The 3D positions of the labels.
+        std::optional<ComponentBatch> positions;
+
+        /// The text of each label.
+        std::optional<ComponentBatch> texts;
+
+        /// Optional font sizes for the labels.
+        ///
+        /// Positive values are in scene units, negative values in UI points.
+        std::optional<ComponentBatch> font_sizes;
+
+        /// Optional colors for the labels.
+        std::optional<ComponentBatch> colors;
+
+        /// Which point of each label is placed at its position.
+        ///
+        /// Defaults to the center of the label.
+        std::optional<ComponentBatch> anchors;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.Text3D";
+
+        /// `ComponentDescriptor` for the `positions` field.
+        static constexpr auto Descriptor_positions = ComponentDescriptor(
+            ArchetypeName, "Text3D:positions",
+            Loggable<rerun::components::Position3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `texts` field.
+        static constexpr auto Descriptor_texts = ComponentDescriptor(
+            ArchetypeName, "Text3D:texts", Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `font_sizes` field.
+        static constexpr auto Descriptor_font_sizes = ComponentDescriptor(
+            ArchetypeName, "Text3D:font_sizes", Loggable<rerun::components::FontSize>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "Text3D:colors", Loggable<rerun::components::Color>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `anchors` field.
+        static constexpr auto Descriptor_anchors = ComponentDescriptor(
+            ArchetypeName, "Text3D:anchors", Loggable<rerun::components::TextAnchor>::ComponentType
+        );
+
+      public:
+        Text3D() = default;
+        Text3D(Text3D&& other) = default;
+        Text3D(const Text3D& other) = default;
+        Text3D& operator=(const Text3D& other) = default;
+        Text3D& operator=(Text3D&& other) = default;
+
+        explicit Text3D(
+            Collection<rerun::components::Position3D> _positions,
+            Collection<rerun::components::Text> _texts
+        )
+            : positions(ComponentBatch::from_loggable(std::move(_positions), Descriptor_positions)
+                            .value_or_throw()),
+              texts(ComponentBatch::from_loggable(std::move(_texts), Descriptor_texts)
+                        .value_or_throw()) {}
+
+        /// Update only some specific fields of a `Text3D`.
+        static Text3D update_fields() {
+            return Text3D();
+        }
+
+        /// Clear all the fields of a `Text3D`.
+        static Text3D clear_fields();
+
+        /// The 3D positions of the labels.
+        Text3D with_positions(const Collection<rerun::components::Position3D>& _positions) && {
+            positions =
+                ComponentBatch::from_loggable(_positions, Descriptor_positions).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The text of each label.
+        Text3D with_texts(const Collection<rerun::components::Text>& _texts) && {
+            texts = ComponentBatch::from_loggable(_texts, Descriptor_texts).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional font sizes for the labels.
+        ///
+        /// Positive values are in scene units, negative values in UI points.
+        Text3D with_font_sizes(const Collection<rerun::components::FontSize>& _font_sizes) && {
+            font_sizes =
+                ComponentBatch::from_loggable(_font_sizes, Descriptor_font_sizes).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional colors for the labels.
+        Text3D with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Which point of each label is placed at its position.
+        ///
+        /// Defaults to the center of the label.
+        Text3D with_anchors(const Collection<rerun::components::TextAnchor>& _anchors) && {
+            anchors = ComponentBatch::from_loggable(_anchors, Descriptor_anchors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::Text3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(const archetypes::Text3D& archetype);
+    };
+} // namespace rerun
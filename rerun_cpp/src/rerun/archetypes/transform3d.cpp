@@ -26,6 +26,10 @@ namespace rerun::archetypes {
         archetype.relation =
             ComponentBatch::empty<rerun::components::TransformRelation>(Descriptor_relation)
                 .value_or_throw();
+        archetype.interpolation = ComponentBatch::empty<rerun::components::TransformInterpolation>(
+                                      Descriptor_interpolation
+        )
+                                      .value_or_throw();
         archetype.axis_length =
             ComponentBatch::empty<rerun::components::AxisLength>(Descriptor_axis_length)
                 .value_or_throw();
@@ -34,7 +38,7 @@ namespace rerun::archetypes {
 
     Collection<ComponentColumn> Transform3D::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(7);
+        columns.reserve(8);
         if (translation.has_value()) {
             columns.push_back(translation.value().partitioned(lengths_).value_or_throw());
         }
@@ -53,6 +57,9 @@ namespace rerun::archetypes {
         if (relation.has_value()) {
             columns.push_back(relation.value().partitioned(lengths_).value_or_throw());
         }
+        if (interpolation.has_value()) {
+            columns.push_back(interpolation.value().partitioned(lengths_).value_or_throw());
+        }
         if (axis_length.has_value()) {
             columns.push_back(axis_length.value().partitioned(lengths_).value_or_throw());
         }
@@ -78,6 +85,9 @@ namespace rerun::archetypes {
         if (relation.has_value()) {
             return columns(std::vector<uint32_t>(relation.value().length(), 1));
         }
+        if (interpolation.has_value()) {
+            return columns(std::vector<uint32_t>(interpolation.value().length(), 1));
+        }
         if (axis_length.has_value()) {
             return columns(std::vector<uint32_t>(axis_length.value().length(), 1));
         }
@@ -92,7 +102,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(7);
+        cells.reserve(8);
 
         if (archetype.translation.has_value()) {
             cells.push_back(archetype.translation.value());
@@ -112,6 +122,9 @@ namespace rerun {
         if (archetype.relation.has_value()) {
             cells.push_back(archetype.relation.value());
         }
+        if (archetype.interpolation.has_value()) {
+            cells.push_back(archetype.interpolation.value());
+        }
         if (archetype.axis_length.has_value()) {
             cells.push_back(archetype.axis_length.value());
         }
@@ -11,6 +11,7 @@
 #include "../components/rotation_axis_angle.hpp"
 #include "../components/rotation_quat.hpp"
 #include "../components/scale3d.hpp"
+#include "../components/transform_interpolation.hpp"
 #include "../components/transform_mat3x3.hpp"
 #include "../components/transform_relation.hpp"
 #include "../components/translation3d.hpp"
@@ -318,6 +319,12 @@ namespace rerun::archetypes {
         /// Specifies the relation this transform establishes between this entity and its parent.
         std::optional<ComponentBatch> relation;
 
+        /// How the transform is interpolated between logged samples.
+        ///
+        /// By default, the latest transform logged before the queried time is used.
+        /// With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
+        std::optional<ComponentBatch> interpolation;
+
         /// Visual length of the 3 axes.
         ///
         /// The length is interpreted in the local coordinate system of the transform.
@@ -357,6 +364,11 @@ namespace rerun::archetypes {
             ArchetypeName, "Transform3D:relation",
             Loggable<rerun::components::TransformRelation>::ComponentType
         );
+        /// `ComponentDescriptor` for the `interpolation` field.
+        static constexpr auto Descriptor_interpolation = ComponentDescriptor(
+            ArchetypeName, "Transform3D:interpolation",
+            Loggable<rerun::components::TransformInterpolation>::ComponentType
+        );
         /// `ComponentDescriptor` for the `axis_length` field.
         static constexpr auto Descriptor_axis_length = ComponentDescriptor(
             ArchetypeName, "Transform3D:axis_length",
@@ -862,6 +874,30 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// How the transform is interpolated between logged samples.
+        ///
+        /// By default, the latest transform logged before the queried time is used.
+        /// With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
+        Transform3D with_interpolation(
+            const rerun::components::TransformInterpolation& _interpolation
+        ) && {
+            interpolation = ComponentBatch::from_loggable(_interpolation, Descriptor_interpolation)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `interpolation` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_interpolation` should
+        /// be used when logging a single row's worth of data.
+        Transform3D with_many_interpolation(
+            const Collection<rerun::components::TransformInterpolation>& _interpolation
+        ) && {
+            interpolation = ComponentBatch::from_loggable(_interpolation, Descriptor_interpolation)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Visual length of the 3 axes.
         ///
         /// The length is interpreted in the local coordinate system of the transform.
@@ -3,26 +3,38 @@
 #pragma once
 
 #include "blueprint/archetypes/background.hpp"
+#include "blueprint/archetypes/camera_controller3d.hpp"
+#include "blueprint/archetypes/camera_path3d.hpp"
 #include "blueprint/archetypes/container_blueprint.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
 #include "blueprint/archetypes/entity_behavior.hpp"
 #include "blueprint/archetypes/eye_controls3d.hpp"
+#include "blueprint/archetypes/follow_latest.hpp"
 #include "blueprint/archetypes/force_center.hpp"
 #include "blueprint/archetypes/force_collision_radius.hpp"
 #include "blueprint/archetypes/force_link.hpp"
 #include "blueprint/archetypes/force_many_body.hpp"
 #include "blueprint/archetypes/force_position.hpp"
+#include "blueprint/archetypes/graph_layout_algorithm.hpp"
+#include "blueprint/archetypes/grid_overlay2d.hpp"
+#include "blueprint/archetypes/lighting3d.hpp"
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
 #include "blueprint/archetypes/plot_legend.hpp"
+#include "blueprint/archetypes/plot_style.hpp"
+#include "blueprint/archetypes/point_sizing.hpp"
 #include "blueprint/archetypes/scalar_axis.hpp"
+#include "blueprint/archetypes/section_plane3d.hpp"
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
 #include "blueprint/archetypes/tensor_slice_selection.hpp"
+#include "blueprint/archetypes/tensor_slice_value_range.hpp"
 #include "blueprint/archetypes/tensor_view_fit.hpp"
+#include "blueprint/archetypes/text_log_saved_filters.hpp"
 #include "blueprint/archetypes/time_axis.hpp"
+#include "blueprint/archetypes/time_offset.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
 #include "blueprint/archetypes/viewport_blueprint.hpp"
@@ -0,0 +1,49 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_controller3d.fbs".
+
+#include "camera_controller3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    CameraController3D CameraController3D::clear_fields() {
+        auto archetype = CameraController3D();
+        archetype.controller =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_controller).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CameraController3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (controller.has_value()) {
+            columns.push_back(controller.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> CameraController3D::columns() {
+        if (controller.has_value()) {
+            return columns(std::vector<uint32_t>(controller.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::CameraController3D>::as_batches(
+            const blueprint::archetypes::CameraController3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.controller.has_value()) {
+            cells.push_back(archetype.controller.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
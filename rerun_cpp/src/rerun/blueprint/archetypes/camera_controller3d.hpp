@@ -0,0 +1,94 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_controller3d.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: The camera controller that moves the eye of a 3D view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CameraController3D {
+        /// Identifier of the controller, e.g. `orbit` or `fly`.
+        ///
+        /// If not set, the eye controls of the view are used.
+        std::optional<ComponentBatch> controller;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.CameraController3D";
+
+        /// `ComponentDescriptor` for the `controller` field.
+        static constexpr auto Descriptor_controller = ComponentDescriptor(
+            ArchetypeName, "CameraController3D:controller",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+
+      public:
+        CameraController3D() = default;
+        CameraController3D(CameraController3D&& other) = default;
+        CameraController3D(const CameraController3D& other) = default;
+        CameraController3D& operator=(const CameraController3D& other) = default;
+        CameraController3D& operator=(CameraController3D&& other) = default;
+
+        /// Update only some specific fields of a `CameraController3D`.
+        static CameraController3D update_fields() {
+            return CameraController3D();
+        }
+
+        /// Clear all the fields of a `CameraController3D`.
+        static CameraController3D clear_fields();
+
+        /// Identifier of the controller, e.g. `orbit` or `fly`.
+        ///
+        /// If not set, the eye controls of the view are used.
+        CameraController3D with_controller(const rerun::components::Name& _controller) && {
+            controller =
+                ComponentBatch::from_loggable(_controller, Descriptor_controller).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::CameraController3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::CameraController3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,97 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path3d.fbs".
+
+#include "camera_path3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    CameraPath3D CameraPath3D::clear_fields() {
+        auto archetype = CameraPath3D();
+        archetype.times =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_times).value_or_throw();
+        archetype.positions =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_positions)
+                .value_or_throw();
+        archetype.rotations =
+            ComponentBatch::empty<rerun::components::RotationQuat>(Descriptor_rotations)
+                .value_or_throw();
+        archetype.fields_of_view =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_fields_of_view)
+                .value_or_throw();
+        archetype.timeline =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timeline)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CameraPath3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (times.has_value()) {
+            columns.push_back(times.value().partitioned(lengths_).value_or_throw());
+        }
+        if (positions.has_value()) {
+            columns.push_back(positions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (rotations.has_value()) {
+            columns.push_back(rotations.value().partitioned(lengths_).value_or_throw());
+        }
+        if (fields_of_view.has_value()) {
+            columns.push_back(fields_of_view.value().partitioned(lengths_).value_or_throw());
+        }
+        if (timeline.has_value()) {
+            columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> CameraPath3D::columns() {
+        if (times.has_value()) {
+            return columns(std::vector<uint32_t>(times.value().length(), 1));
+        }
+        if (positions.has_value()) {
+            return columns(std::vector<uint32_t>(positions.value().length(), 1));
+        }
+        if (rotations.has_value()) {
+            return columns(std::vector<uint32_t>(rotations.value().length(), 1));
+        }
+        if (fields_of_view.has_value()) {
+            return columns(std::vector<uint32_t>(fields_of_view.value().length(), 1));
+        }
+        if (timeline.has_value()) {
+            return columns(std::vector<uint32_t>(timeline.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::CameraPath3D>::as_batches(
+            const blueprint::archetypes::CameraPath3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.times.has_value()) {
+            cells.push_back(archetype.times.value());
+        }
+        if (archetype.positions.has_value()) {
+            cells.push_back(archetype.positions.value());
+        }
+        if (archetype.rotations.has_value()) {
+            cells.push_back(archetype.rotations.value());
+        }
+        if (archetype.fields_of_view.has_value()) {
+            cells.push_back(archetype.fields_of_view.value());
+        }
+        if (archetype.timeline.has_value()) {
+            cells.push_back(archetype.timeline.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
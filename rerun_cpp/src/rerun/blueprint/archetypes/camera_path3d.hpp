@@ -0,0 +1,170 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/position3d.hpp"
+#include "../../components/rotation_quat.hpp"
+#include "../../components/scalar.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Keyframes of the camera of a 3D view, to fly through the scene along them.
+    ///
+    /// All fields have one instance per keyframe, sorted by time.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CameraPath3D {
+        /// Time of each keyframe.
+        ///
+        /// On the timeline if one is set, otherwise in seconds since the start of the playback.
+        std::optional<ComponentBatch> times;
+
+        /// Position of the camera at each keyframe, in the space of the view.
+        std::optional<ComponentBatch> positions;
+
+        /// Rotation of the camera at each keyframe, in the space of the view.
+        std::optional<ComponentBatch> rotations;
+
+        /// Vertical field of view of the camera at each keyframe, in radians.
+        ///
+        /// NaN for an orthographic camera.
+        std::optional<ComponentBatch> fields_of_view;
+
+        /// The timeline the keyframe times are on.
+        ///
+        /// If not set, the keyframe times are wall-clock seconds since the start of the playback.
+        std::optional<ComponentBatch> timeline;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.CameraPath3D";
+
+        /// `ComponentDescriptor` for the `times` field.
+        static constexpr auto Descriptor_times = ComponentDescriptor(
+            ArchetypeName, "CameraPath3D:times", Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `positions` field.
+        static constexpr auto Descriptor_positions = ComponentDescriptor(
+            ArchetypeName, "CameraPath3D:positions",
+            Loggable<rerun::components::Position3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `rotations` field.
+        static constexpr auto Descriptor_rotations = ComponentDescriptor(
+            ArchetypeName, "CameraPath3D:rotations",
+            Loggable<rerun::components::RotationQuat>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `fields_of_view` field.
+        static constexpr auto Descriptor_fields_of_view = ComponentDescriptor(
+            ArchetypeName, "CameraPath3D:fields_of_view",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `timeline` field.
+        static constexpr auto Descriptor_timeline = ComponentDescriptor(
+            ArchetypeName, "CameraPath3D:timeline",
+            Loggable<rerun::blueprint::components::TimelineName>::ComponentType
+        );
+
+      public:
+        CameraPath3D() = default;
+        CameraPath3D(CameraPath3D&& other) = default;
+        CameraPath3D(const CameraPath3D& other) = default;
+        CameraPath3D& operator=(const CameraPath3D& other) = default;
+        CameraPath3D& operator=(CameraPath3D&& other) = default;
+
+        /// Update only some specific fields of a `CameraPath3D`.
+        static CameraPath3D update_fields() {
+            return CameraPath3D();
+        }
+
+        /// Clear all the fields of a `CameraPath3D`.
+        static CameraPath3D clear_fields();
+
+        /// Time of each keyframe.
+        ///
+        /// On the timeline if one is set, otherwise in seconds since the start of the playback.
+        CameraPath3D with_times(const Collection<rerun::components::Scalar>& _times) && {
+            times = ComponentBatch::from_loggable(_times, Descriptor_times).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Position of the camera at each keyframe, in the space of the view.
+        CameraPath3D with_positions(const Collection<rerun::components::Position3D>& _positions
+        ) && {
+            positions =
+                ComponentBatch::from_loggable(_positions, Descriptor_positions).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Rotation of the camera at each keyframe, in the space of the view.
+        CameraPath3D with_rotations(const Collection<rerun::components::RotationQuat>& _rotations
+        ) && {
+            rotations =
+                ComponentBatch::from_loggable(_rotations, Descriptor_rotations).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Vertical field of view of the camera at each keyframe, in radians.
+        ///
+        /// NaN for an orthographic camera.
+        CameraPath3D with_fields_of_view(
+            const Collection<rerun::components::Scalar>& _fields_of_view
+        ) && {
+            fields_of_view =
+                ComponentBatch::from_loggable(_fields_of_view, Descriptor_fields_of_view)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The timeline the keyframe times are on.
+        ///
+        /// If not set, the keyframe times are wall-clock seconds since the start of the playback.
+        CameraPath3D with_timeline(const rerun::blueprint::components::TimelineName& _timeline) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::CameraPath3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::CameraPath3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/follow_latest.fbs".
+
+#include "follow_latest.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    FollowLatest FollowLatest::clear_fields() {
+        auto archetype = FollowLatest();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.camera_entity =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_camera_entity)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> FollowLatest::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (enabled.has_value()) {
+            columns.push_back(enabled.value().partitioned(lengths_).value_or_throw());
+        }
+        if (camera_entity.has_value()) {
+            columns.push_back(camera_entity.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> FollowLatest::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (camera_entity.has_value()) {
+            return columns(std::vector<uint32_t>(camera_entity.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::FollowLatest>::as_batches(
+            const blueprint::archetypes::FollowLatest& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.camera_entity.has_value()) {
+            cells.push_back(archetype.camera_entity.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,109 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/follow_latest.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Whether a view always shows the newest data on the selected timeline, wherever the time cursor is.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct FollowLatest {
+        /// Whether the view follows the latest data.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> enabled;
+
+        /// The entity the eye of a 3D view tracks while following the latest data.
+        std::optional<ComponentBatch> camera_entity;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.FollowLatest";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "FollowLatest:enabled",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `camera_entity` field.
+        static constexpr auto Descriptor_camera_entity = ComponentDescriptor(
+            ArchetypeName, "FollowLatest:camera_entity",
+            Loggable<rerun::components::EntityPath>::ComponentType
+        );
+
+      public:
+        FollowLatest() = default;
+        FollowLatest(FollowLatest&& other) = default;
+        FollowLatest(const FollowLatest& other) = default;
+        FollowLatest& operator=(const FollowLatest& other) = default;
+        FollowLatest& operator=(FollowLatest&& other) = default;
+
+        /// Update only some specific fields of a `FollowLatest`.
+        static FollowLatest update_fields() {
+            return FollowLatest();
+        }
+
+        /// Clear all the fields of a `FollowLatest`.
+        static FollowLatest clear_fields();
+
+        /// Whether the view follows the latest data.
+        ///
+        /// Defaults to false.
+        FollowLatest with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The entity the eye of a 3D view tracks while following the latest data.
+        FollowLatest with_camera_entity(const rerun::components::EntityPath& _camera_entity) && {
+            camera_entity = ComponentBatch::from_loggable(_camera_entity, Descriptor_camera_entity)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::FollowLatest> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::FollowLatest& archetype
+        );
+    };
+} // namespace rerun
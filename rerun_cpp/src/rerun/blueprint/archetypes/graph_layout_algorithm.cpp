@@ -0,0 +1,49 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout_algorithm.fbs".
+
+#include "graph_layout_algorithm.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    GraphLayoutAlgorithm GraphLayoutAlgorithm::clear_fields() {
+        auto archetype = GraphLayoutAlgorithm();
+        archetype.algorithm =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_algorithm).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> GraphLayoutAlgorithm::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (algorithm.has_value()) {
+            columns.push_back(algorithm.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> GraphLayoutAlgorithm::columns() {
+        if (algorithm.has_value()) {
+            return columns(std::vector<uint32_t>(algorithm.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::GraphLayoutAlgorithm>::as_batches(
+            const blueprint::archetypes::GraphLayoutAlgorithm& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.algorithm.has_value()) {
+            cells.push_back(archetype.algorithm.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,94 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout_algorithm.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: The algorithm that lays out the nodes of a graph view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct GraphLayoutAlgorithm {
+        /// Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+        ///
+        /// If not set, the force-based layout is used.
+        std::optional<ComponentBatch> algorithm;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.GraphLayoutAlgorithm";
+
+        /// `ComponentDescriptor` for the `algorithm` field.
+        static constexpr auto Descriptor_algorithm = ComponentDescriptor(
+            ArchetypeName, "GraphLayoutAlgorithm:algorithm",
+            Loggable<rerun::components::Name>::ComponentType
+        );
+
+      public:
+        GraphLayoutAlgorithm() = default;
+        GraphLayoutAlgorithm(GraphLayoutAlgorithm&& other) = default;
+        GraphLayoutAlgorithm(const GraphLayoutAlgorithm& other) = default;
+        GraphLayoutAlgorithm& operator=(const GraphLayoutAlgorithm& other) = default;
+        GraphLayoutAlgorithm& operator=(GraphLayoutAlgorithm&& other) = default;
+
+        /// Update only some specific fields of a `GraphLayoutAlgorithm`.
+        static GraphLayoutAlgorithm update_fields() {
+            return GraphLayoutAlgorithm();
+        }
+
+        /// Clear all the fields of a `GraphLayoutAlgorithm`.
+        static GraphLayoutAlgorithm clear_fields();
+
+        /// Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+        ///
+        /// If not set, the force-based layout is used.
+        GraphLayoutAlgorithm with_algorithm(const rerun::components::Name& _algorithm) && {
+            algorithm =
+                ComponentBatch::from_loggable(_algorithm, Descriptor_algorithm).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::GraphLayoutAlgorithm> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::GraphLayoutAlgorithm& archetype
+        );
+    };
+} // namespace rerun
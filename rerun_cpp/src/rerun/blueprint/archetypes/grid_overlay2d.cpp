@@ -0,0 +1,98 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/grid_overlay2d.fbs".
+
+#include "grid_overlay2d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    GridOverlay2D GridOverlay2D::clear_fields() {
+        auto archetype = GridOverlay2D();
+        archetype.show_grid =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_show_grid)
+                .value_or_throw();
+        archetype.show_rulers =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_show_rulers)
+                .value_or_throw();
+        archetype.spacing =
+            ComponentBatch::empty<rerun::blueprint::components::GridSpacing>(Descriptor_spacing)
+                .value_or_throw();
+        archetype.subdivisions =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_subdivisions)
+                .value_or_throw();
+        archetype.snap_to_grid =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_snap_to_grid)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> GridOverlay2D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (show_grid.has_value()) {
+            columns.push_back(show_grid.value().partitioned(lengths_).value_or_throw());
+        }
+        if (show_rulers.has_value()) {
+            columns.push_back(show_rulers.value().partitioned(lengths_).value_or_throw());
+        }
+        if (spacing.has_value()) {
+            columns.push_back(spacing.value().partitioned(lengths_).value_or_throw());
+        }
+        if (subdivisions.has_value()) {
+            columns.push_back(subdivisions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (snap_to_grid.has_value()) {
+            columns.push_back(snap_to_grid.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> GridOverlay2D::columns() {
+        if (show_grid.has_value()) {
+            return columns(std::vector<uint32_t>(show_grid.value().length(), 1));
+        }
+        if (show_rulers.has_value()) {
+            return columns(std::vector<uint32_t>(show_rulers.value().length(), 1));
+        }
+        if (spacing.has_value()) {
+            return columns(std::vector<uint32_t>(spacing.value().length(), 1));
+        }
+        if (subdivisions.has_value()) {
+            return columns(std::vector<uint32_t>(subdivisions.value().length(), 1));
+        }
+        if (snap_to_grid.has_value()) {
+            return columns(std::vector<uint32_t>(snap_to_grid.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::GridOverlay2D>::as_batches(
+            const blueprint::archetypes::GridOverlay2D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.show_grid.has_value()) {
+            cells.push_back(archetype.show_grid.value());
+        }
+        if (archetype.show_rulers.has_value()) {
+            cells.push_back(archetype.show_rulers.value());
+        }
+        if (archetype.spacing.has_value()) {
+            cells.push_back(archetype.spacing.value());
+        }
+        if (archetype.subdivisions.has_value()) {
+            cells.push_back(archetype.subdivisions.value());
+        }
+        if (archetype.snap_to_grid.has_value()) {
+            cells.push_back(archetype.snap_to_grid.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,173 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/grid_overlay2d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../blueprint/components/grid_spacing.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/scalar.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Rulers along the edges and a grid over the contents of a 2D view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct GridOverlay2D {
+        /// Whether the grid is drawn.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> show_grid;
+
+        /// Whether the rulers are drawn.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> show_rulers;
+
+        /// Distance between major lines in scene units.
+        ///
+        /// If not set, it is picked for the zoom level.
+        std::optional<ComponentBatch> spacing;
+
+        /// Number of minor cells each major cell is divided into.
+        ///
+        /// Defaults to 5.
+        std::optional<ComponentBatch> subdivisions;
+
+        /// Whether the cursor snaps to the nearest intersection of minor lines.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> snap_to_grid;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.GridOverlay2D";
+
+        /// `ComponentDescriptor` for the `show_grid` field.
+        static constexpr auto Descriptor_show_grid = ComponentDescriptor(
+            ArchetypeName, "GridOverlay2D:show_grid",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `show_rulers` field.
+        static constexpr auto Descriptor_show_rulers = ComponentDescriptor(
+            ArchetypeName, "GridOverlay2D:show_rulers",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `spacing` field.
+        static constexpr auto Descriptor_spacing = ComponentDescriptor(
+            ArchetypeName, "GridOverlay2D:spacing",
+            Loggable<rerun::blueprint::components::GridSpacing>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `subdivisions` field.
+        static constexpr auto Descriptor_subdivisions = ComponentDescriptor(
+            ArchetypeName, "GridOverlay2D:subdivisions",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `snap_to_grid` field.
+        static constexpr auto Descriptor_snap_to_grid = ComponentDescriptor(
+            ArchetypeName, "GridOverlay2D:snap_to_grid",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        GridOverlay2D() = default;
+        GridOverlay2D(GridOverlay2D&& other) = default;
+        GridOverlay2D(const GridOverlay2D& other) = default;
+        GridOverlay2D& operator=(const GridOverlay2D& other) = default;
+        GridOverlay2D& operator=(GridOverlay2D&& other) = default;
+
+        /// Update only some specific fields of a `GridOverlay2D`.
+        static GridOverlay2D update_fields() {
+            return GridOverlay2D();
+        }
+
+        /// Clear all the fields of a `GridOverlay2D`.
+        static GridOverlay2D clear_fields();
+
+        /// Whether the grid is drawn.
+        ///
+        /// Defaults to false.
+        GridOverlay2D with_show_grid(const rerun::blueprint::components::Enabled& _show_grid) && {
+            show_grid =
+                ComponentBatch::from_loggable(_show_grid, Descriptor_show_grid).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the rulers are drawn.
+        ///
+        /// Defaults to false.
+        GridOverlay2D with_show_rulers(const rerun::blueprint::components::Enabled& _show_rulers
+        ) && {
+            show_rulers = ComponentBatch::from_loggable(_show_rulers, Descriptor_show_rulers)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Distance between major lines in scene units.
+        ///
+        /// If not set, it is picked for the zoom level.
+        GridOverlay2D with_spacing(const rerun::blueprint::components::GridSpacing& _spacing) && {
+            spacing = ComponentBatch::from_loggable(_spacing, Descriptor_spacing).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Number of minor cells each major cell is divided into.
+        ///
+        /// Defaults to 5.
+        GridOverlay2D with_subdivisions(const rerun::components::Scalar& _subdivisions) && {
+            subdivisions = ComponentBatch::from_loggable(_subdivisions, Descriptor_subdivisions)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the cursor snaps to the nearest intersection of minor lines.
+        ///
+        /// Defaults to false.
+        GridOverlay2D with_snap_to_grid(const rerun::blueprint::components::Enabled& _snap_to_grid
+        ) && {
+            snap_to_grid = ComponentBatch::from_loggable(_snap_to_grid, Descriptor_snap_to_grid)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::GridOverlay2D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::GridOverlay2D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,85 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/lighting3d.fbs".
+
+#include "lighting3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    Lighting3D Lighting3D::clear_fields() {
+        auto archetype = Lighting3D();
+        archetype.key_light_direction =
+            ComponentBatch::empty<rerun::components::Vector3D>(Descriptor_key_light_direction)
+                .value_or_throw();
+        archetype.key_light_intensity =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_key_light_intensity)
+                .value_or_throw();
+        archetype.ambient_light =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_ambient_light)
+                .value_or_throw();
+        archetype.unlit =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_unlit)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Lighting3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (key_light_direction.has_value()) {
+            columns.push_back(key_light_direction.value().partitioned(lengths_).value_or_throw());
+        }
+        if (key_light_intensity.has_value()) {
+            columns.push_back(key_light_intensity.value().partitioned(lengths_).value_or_throw());
+        }
+        if (ambient_light.has_value()) {
+            columns.push_back(ambient_light.value().partitioned(lengths_).value_or_throw());
+        }
+        if (unlit.has_value()) {
+            columns.push_back(unlit.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> Lighting3D::columns() {
+        if (key_light_direction.has_value()) {
+            return columns(std::vector<uint32_t>(key_light_direction.value().length(), 1));
+        }
+        if (key_light_intensity.has_value()) {
+            return columns(std::vector<uint32_t>(key_light_intensity.value().length(), 1));
+        }
+        if (ambient_light.has_value()) {
+            return columns(std::vector<uint32_t>(ambient_light.value().length(), 1));
+        }
+        if (unlit.has_value()) {
+            return columns(std::vector<uint32_t>(unlit.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::Lighting3D>::as_batches(
+        const blueprint::archetypes::Lighting3D& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.key_light_direction.has_value()) {
+            cells.push_back(archetype.key_light_direction.value());
+        }
+        if (archetype.key_light_intensity.has_value()) {
+            cells.push_back(archetype.key_light_intensity.value());
+        }
+        if (archetype.ambient_light.has_value()) {
+            cells.push_back(archetype.ambient_light.value());
+        }
+        if (archetype.unlit.has_value()) {
+            cells.push_back(archetype.unlit.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
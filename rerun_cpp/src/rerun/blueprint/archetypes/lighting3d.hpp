@@ -0,0 +1,150 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/lighting3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/scalar.hpp"
+#include "../../components/vector3d.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: The lights meshes are shaded with in a 3D view.
+    ///
+    /// Anything not set uses the default lighting of the viewer.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct Lighting3D {
+        /// Direction towards the key light, in the space of the view.
+        ///
+        /// Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+        std::optional<ComponentBatch> key_light_direction;
+
+        /// Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+        std::optional<ComponentBatch> key_light_intensity;
+
+        /// Brightness of the light reaching meshes from all sides.
+        std::optional<ComponentBatch> ambient_light;
+
+        /// Draws meshes in their albedo color, ignoring all lights.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> unlit;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.Lighting3D";
+
+        /// `ComponentDescriptor` for the `key_light_direction` field.
+        static constexpr auto Descriptor_key_light_direction = ComponentDescriptor(
+            ArchetypeName, "Lighting3D:key_light_direction",
+            Loggable<rerun::components::Vector3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `key_light_intensity` field.
+        static constexpr auto Descriptor_key_light_intensity = ComponentDescriptor(
+            ArchetypeName, "Lighting3D:key_light_intensity",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `ambient_light` field.
+        static constexpr auto Descriptor_ambient_light = ComponentDescriptor(
+            ArchetypeName, "Lighting3D:ambient_light",
+            Loggable<rerun::components::Scalar>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `unlit` field.
+        static constexpr auto Descriptor_unlit = ComponentDescriptor(
+            ArchetypeName, "Lighting3D:unlit",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        Lighting3D() = default;
+        Lighting3D(Lighting3D&& other) = default;
+        Lighting3D(const Lighting3D& other) = default;
+        Lighting3D& operator=(const Lighting3D& other) = default;
+        Lighting3D& operator=(Lighting3D&& other) = default;
+
+        /// Update only some specific fields of a `Lighting3D`.
+        static Lighting3D update_fields() {
+            return Lighting3D();
+        }
+
+        /// Clear all the fields of a `Lighting3D`.
+        static Lighting3D clear_fields();
+
+        /// Direction towards the key light, in the space of the view.
+        ///
+        /// Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+        Lighting3D with_key_light_direction(const rerun::components::Vector3D& _key_light_direction
+        ) && {
+            key_light_direction =
+                ComponentBatch::from_loggable(_key_light_direction, Descriptor_key_light_direction)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+        Lighting3D with_key_light_intensity(const rerun::components::Scalar& _key_light_intensity
+        ) && {
+            key_light_intensity =
+                ComponentBatch::from_loggable(_key_light_intensity, Descriptor_key_light_intensity)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Brightness of the light reaching meshes from all sides.
+        Lighting3D with_ambient_light(const rerun::components::Scalar& _ambient_light) && {
+            ambient_light = ComponentBatch::from_loggable(_ambient_light, Descriptor_ambient_light)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Draws meshes in their albedo color, ignoring all lights.
+        ///
+        /// Defaults to false.
+        Lighting3D with_unlit(const rerun::blueprint::components::Enabled& _unlit) && {
+            unlit = ComponentBatch::from_loggable(_unlit, Descriptor_unlit).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::Lighting3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::Lighting3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,49 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/plot_style.fbs".
+
+#include "plot_style.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    PlotStyle PlotStyle::clear_fields() {
+        auto archetype = PlotStyle();
+        archetype.mode =
+            ComponentBatch::empty<rerun::blueprint::components::PlotMode>(Descriptor_mode)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> PlotStyle::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (mode.has_value()) {
+            columns.push_back(mode.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> PlotStyle::columns() {
+        if (mode.has_value()) {
+            return columns(std::vector<uint32_t>(mode.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::PlotStyle>::as_batches(
+        const blueprint::archetypes::PlotStyle& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.mode.has_value()) {
+            cells.push_back(archetype.mode.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
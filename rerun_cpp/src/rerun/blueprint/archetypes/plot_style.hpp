@@ -0,0 +1,93 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/plot_style.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/plot_mode.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for how the series of a plot are drawn.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct PlotStyle {
+        /// Whether the series are drawn on their own, or stacked to show their composition over time.
+        ///
+        /// Defaults to lines.
+        std::optional<ComponentBatch> mode;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.PlotStyle";
+
+        /// `ComponentDescriptor` for the `mode` field.
+        static constexpr auto Descriptor_mode = ComponentDescriptor(
+            ArchetypeName, "PlotStyle:mode",
+            Loggable<rerun::blueprint::components::PlotMode>::ComponentType
+        );
+
+      public:
+        PlotStyle() = default;
+        PlotStyle(PlotStyle&& other) = default;
+        PlotStyle(const PlotStyle& other) = default;
+        PlotStyle& operator=(const PlotStyle& other) = default;
+        PlotStyle& operator=(PlotStyle&& other) = default;
+
+        /// Update only some specific fields of a `PlotStyle`.
+        static PlotStyle update_fields() {
+            return PlotStyle();
+        }
+
+        /// Clear all the fields of a `PlotStyle`.
+        static PlotStyle clear_fields();
+
+        /// Whether the series are drawn on their own, or stacked to show their composition over time.
+        ///
+        /// Defaults to lines.
+        PlotStyle with_mode(const rerun::blueprint::components::PlotMode& _mode) && {
+            mode = ComponentBatch::from_loggable(_mode, Descriptor_mode).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::PlotStyle> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::PlotStyle& archetype
+        );
+    };
+} // namespace rerun
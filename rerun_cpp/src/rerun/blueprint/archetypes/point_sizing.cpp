@@ -0,0 +1,73 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_sizing.fbs".
+
+#include "point_sizing.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    PointSizing PointSizing::clear_fields() {
+        auto archetype = PointSizing();
+        archetype.screen_space =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_screen_space)
+                .value_or_throw();
+        archetype.min_radius =
+            ComponentBatch::empty<rerun::components::Radius>(Descriptor_min_radius)
+                .value_or_throw();
+        archetype.max_radius =
+            ComponentBatch::empty<rerun::components::Radius>(Descriptor_max_radius)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> PointSizing::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (screen_space.has_value()) {
+            columns.push_back(screen_space.value().partitioned(lengths_).value_or_throw());
+        }
+        if (min_radius.has_value()) {
+            columns.push_back(min_radius.value().partitioned(lengths_).value_or_throw());
+        }
+        if (max_radius.has_value()) {
+            columns.push_back(max_radius.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> PointSizing::columns() {
+        if (screen_space.has_value()) {
+            return columns(std::vector<uint32_t>(screen_space.value().length(), 1));
+        }
+        if (min_radius.has_value()) {
+            return columns(std::vector<uint32_t>(min_radius.value().length(), 1));
+        }
+        if (max_radius.has_value()) {
+            return columns(std::vector<uint32_t>(max_radius.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::PointSizing>::as_batches(
+        const blueprint::archetypes::PointSizing& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.screen_space.has_value()) {
+            cells.push_back(archetype.screen_space.value());
+        }
+        if (archetype.min_radius.has_value()) {
+            cells.push_back(archetype.min_radius.value());
+        }
+        if (archetype.max_radius.has_value()) {
+            cells.push_back(archetype.max_radius.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
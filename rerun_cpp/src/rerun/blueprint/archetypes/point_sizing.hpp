@@ -0,0 +1,128 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_sizing.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/radius.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: How the radii of points are interpreted and limited.
+    ///
+    /// Set per view, and overridden per entity.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct PointSizing {
+        /// Whether radii are in ui points rather than in scene units, like meters.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> screen_space;
+
+        /// Points are drawn with at least this radius, in ui points.
+        std::optional<ComponentBatch> min_radius;
+
+        /// Points are drawn with at most this radius, in ui points.
+        std::optional<ComponentBatch> max_radius;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.PointSizing";
+
+        /// `ComponentDescriptor` for the `screen_space` field.
+        static constexpr auto Descriptor_screen_space = ComponentDescriptor(
+            ArchetypeName, "PointSizing:screen_space",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `min_radius` field.
+        static constexpr auto Descriptor_min_radius = ComponentDescriptor(
+            ArchetypeName, "PointSizing:min_radius",
+            Loggable<rerun::components::Radius>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `max_radius` field.
+        static constexpr auto Descriptor_max_radius = ComponentDescriptor(
+            ArchetypeName, "PointSizing:max_radius",
+            Loggable<rerun::components::Radius>::ComponentType
+        );
+
+      public:
+        PointSizing() = default;
+        PointSizing(PointSizing&& other) = default;
+        PointSizing(const PointSizing& other) = default;
+        PointSizing& operator=(const PointSizing& other) = default;
+        PointSizing& operator=(PointSizing&& other) = default;
+
+        /// Update only some specific fields of a `PointSizing`.
+        static PointSizing update_fields() {
+            return PointSizing();
+        }
+
+        /// Clear all the fields of a `PointSizing`.
+        static PointSizing clear_fields();
+
+        /// Whether radii are in ui points rather than in scene units, like meters.
+        ///
+        /// Defaults to false.
+        PointSizing with_screen_space(const rerun::blueprint::components::Enabled& _screen_space
+        ) && {
+            screen_space = ComponentBatch::from_loggable(_screen_space, Descriptor_screen_space)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Points are drawn with at least this radius, in ui points.
+        PointSizing with_min_radius(const rerun::components::Radius& _min_radius) && {
+            min_radius =
+                ComponentBatch::from_loggable(_min_radius, Descriptor_min_radius).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Points are drawn with at most this radius, in ui points.
+        PointSizing with_max_radius(const rerun::components::Radius& _max_radius) && {
+            max_radius =
+                ComponentBatch::from_loggable(_max_radius, Descriptor_max_radius).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::PointSizing> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::PointSizing& archetype
+        );
+    };
+} // namespace rerun
@@ -15,18 +15,32 @@ namespace rerun::blueprint::archetypes {
                 Descriptor_zoom_lock
             )
                 .value_or_throw();
+        archetype.log_scale =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_log_scale)
+                .value_or_throw();
+        archetype.secondary_log_scale =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(
+                Descriptor_secondary_log_scale
+            )
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> ScalarAxis::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(2);
+        columns.reserve(4);
         if (range.has_value()) {
             columns.push_back(range.value().partitioned(lengths_).value_or_throw());
         }
         if (zoom_lock.has_value()) {
             columns.push_back(zoom_lock.value().partitioned(lengths_).value_or_throw());
         }
+        if (log_scale.has_value()) {
+            columns.push_back(log_scale.value().partitioned(lengths_).value_or_throw());
+        }
+        if (secondary_log_scale.has_value()) {
+            columns.push_back(secondary_log_scale.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -37,6 +51,12 @@ namespace rerun::blueprint::archetypes {
         if (zoom_lock.has_value()) {
             return columns(std::vector<uint32_t>(zoom_lock.value().length(), 1));
         }
+        if (log_scale.has_value()) {
+            return columns(std::vector<uint32_t>(log_scale.value().length(), 1));
+        }
+        if (secondary_log_scale.has_value()) {
+            return columns(std::vector<uint32_t>(secondary_log_scale.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::blueprint::archetypes
@@ -48,7 +68,7 @@ namespace rerun {
     ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(2);
+        cells.reserve(4);
 
         if (archetype.range.has_value()) {
             cells.push_back(archetype.range.value());
@@ -56,6 +76,12 @@ namespace rerun {
         if (archetype.zoom_lock.has_value()) {
             cells.push_back(archetype.zoom_lock.value());
         }
+        if (archetype.log_scale.has_value()) {
+            cells.push_back(archetype.log_scale.value());
+        }
+        if (archetype.secondary_log_scale.has_value()) {
+            cells.push_back(archetype.secondary_log_scale.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
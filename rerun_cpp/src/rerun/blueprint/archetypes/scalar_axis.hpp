@@ -3,6 +3,7 @@
 
 #pragma once
 
+#include "../../blueprint/components/enabled.hpp"
 #include "../../blueprint/components/lock_range_during_zoom.hpp"
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
@@ -29,6 +30,16 @@ namespace rerun::blueprint::archetypes {
         /// If enabled, the Y axis range will remain locked to the specified range when zooming.
         std::optional<ComponentBatch> zoom_lock;
 
+        /// If enabled, the Y axis uses a logarithmic scale.
+        ///
+        /// Values that aren't positive can't be shown on a logarithmic scale and are left out.
+        std::optional<ComponentBatch> log_scale;
+
+        /// If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+        ///
+        /// Only has an effect if some series are plotted against the secondary axis.
+        std::optional<ComponentBatch> secondary_log_scale;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.ScalarAxis";
@@ -42,6 +53,16 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "ScalarAxis:zoom_lock",
             Loggable<rerun::blueprint::components::LockRangeDuringZoom>::ComponentType
         );
+        /// `ComponentDescriptor` for the `log_scale` field.
+        static constexpr auto Descriptor_log_scale = ComponentDescriptor(
+            ArchetypeName, "ScalarAxis:log_scale",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `secondary_log_scale` field.
+        static constexpr auto Descriptor_secondary_log_scale = ComponentDescriptor(
+            ArchetypeName, "ScalarAxis:secondary_log_scale",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
 
       public:
         ScalarAxis() = default;
@@ -75,6 +96,27 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// If enabled, the Y axis uses a logarithmic scale.
+        ///
+        /// Values that aren't positive can't be shown on a logarithmic scale and are left out.
+        ScalarAxis with_log_scale(const rerun::blueprint::components::Enabled& _log_scale) && {
+            log_scale =
+                ComponentBatch::from_loggable(_log_scale, Descriptor_log_scale).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+        ///
+        /// Only has an effect if some series are plotted against the secondary axis.
+        ScalarAxis with_secondary_log_scale(
+            const rerun::blueprint::components::Enabled& _secondary_log_scale
+        ) && {
+            secondary_log_scale =
+                ComponentBatch::from_loggable(_secondary_log_scale, Descriptor_secondary_log_scale)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
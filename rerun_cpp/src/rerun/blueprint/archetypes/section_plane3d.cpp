@@ -0,0 +1,73 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_plane3d.fbs".
+
+#include "section_plane3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    SectionPlane3D SectionPlane3D::clear_fields() {
+        auto archetype = SectionPlane3D();
+        archetype.position =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_position)
+                .value_or_throw();
+        archetype.normal =
+            ComponentBatch::empty<rerun::components::Vector3D>(Descriptor_normal).value_or_throw();
+        archetype.show_contour =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_show_contour)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> SectionPlane3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (position.has_value()) {
+            columns.push_back(position.value().partitioned(lengths_).value_or_throw());
+        }
+        if (normal.has_value()) {
+            columns.push_back(normal.value().partitioned(lengths_).value_or_throw());
+        }
+        if (show_contour.has_value()) {
+            columns.push_back(show_contour.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> SectionPlane3D::columns() {
+        if (position.has_value()) {
+            return columns(std::vector<uint32_t>(position.value().length(), 1));
+        }
+        if (normal.has_value()) {
+            return columns(std::vector<uint32_t>(normal.value().length(), 1));
+        }
+        if (show_contour.has_value()) {
+            return columns(std::vector<uint32_t>(show_contour.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::SectionPlane3D>::as_batches(
+            const blueprint::archetypes::SectionPlane3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.position.has_value()) {
+            cells.push_back(archetype.position.value());
+        }
+        if (archetype.normal.has_value()) {
+            cells.push_back(archetype.normal.value());
+        }
+        if (archetype.show_contour.has_value()) {
+            cells.push_back(archetype.show_contour.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
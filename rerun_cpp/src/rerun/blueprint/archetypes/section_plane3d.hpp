@@ -0,0 +1,130 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_plane3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/position3d.hpp"
+#include "../../components/vector3d.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: A plane cutting away part of a 3D view, to look inside of meshes and dense point clouds.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct SectionPlane3D {
+        /// A point on the plane, in the space of the view.
+        std::optional<ComponentBatch> position;
+
+        /// Normal of the plane.
+        ///
+        /// Everything on the side the normal points to is kept.
+        std::optional<ComponentBatch> normal;
+
+        /// Whether meshes are colored where they are cut.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> show_contour;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.SectionPlane3D";
+
+        /// `ComponentDescriptor` for the `position` field.
+        static constexpr auto Descriptor_position = ComponentDescriptor(
+            ArchetypeName, "SectionPlane3D:position",
+            Loggable<rerun::components::Position3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `normal` field.
+        static constexpr auto Descriptor_normal = ComponentDescriptor(
+            ArchetypeName, "SectionPlane3D:normal",
+            Loggable<rerun::components::Vector3D>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `show_contour` field.
+        static constexpr auto Descriptor_show_contour = ComponentDescriptor(
+            ArchetypeName, "SectionPlane3D:show_contour",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        SectionPlane3D() = default;
+        SectionPlane3D(SectionPlane3D&& other) = default;
+        SectionPlane3D(const SectionPlane3D& other) = default;
+        SectionPlane3D& operator=(const SectionPlane3D& other) = default;
+        SectionPlane3D& operator=(SectionPlane3D&& other) = default;
+
+        /// Update only some specific fields of a `SectionPlane3D`.
+        static SectionPlane3D update_fields() {
+            return SectionPlane3D();
+        }
+
+        /// Clear all the fields of a `SectionPlane3D`.
+        static SectionPlane3D clear_fields();
+
+        /// A point on the plane, in the space of the view.
+        SectionPlane3D with_position(const rerun::components::Position3D& _position) && {
+            position =
+                ComponentBatch::from_loggable(_position, Descriptor_position).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Normal of the plane.
+        ///
+        /// Everything on the side the normal points to is kept.
+        SectionPlane3D with_normal(const rerun::components::Vector3D& _normal) && {
+            normal = ComponentBatch::from_loggable(_normal, Descriptor_normal).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether meshes are colored where they are cut.
+        ///
+        /// Defaults to false.
+        SectionPlane3D with_show_contour(const rerun::blueprint::components::Enabled& _show_contour
+        ) && {
+            show_contour = ComponentBatch::from_loggable(_show_contour, Descriptor_show_contour)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::SectionPlane3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::SectionPlane3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,50 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_slice_value_range.fbs".
+
+#include "tensor_slice_value_range.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TensorSliceValueRange TensorSliceValueRange::clear_fields() {
+        auto archetype = TensorSliceValueRange();
+        archetype.fit_to_slice =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_fit_to_slice)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TensorSliceValueRange::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (fit_to_slice.has_value()) {
+            columns.push_back(fit_to_slice.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TensorSliceValueRange::columns() {
+        if (fit_to_slice.has_value()) {
+            return columns(std::vector<uint32_t>(fit_to_slice.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TensorSliceValueRange>::as_batches(
+            const blueprint::archetypes::TensorSliceValueRange& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.fit_to_slice.has_value()) {
+            cells.push_back(archetype.fit_to_slice.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
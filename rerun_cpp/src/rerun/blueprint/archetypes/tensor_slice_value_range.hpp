@@ -0,0 +1,96 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_slice_value_range.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Which values of a tensor the colormap of a tensor view spans.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TensorSliceValueRange {
+        /// Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> fit_to_slice;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TensorSliceValueRange";
+
+        /// `ComponentDescriptor` for the `fit_to_slice` field.
+        static constexpr auto Descriptor_fit_to_slice = ComponentDescriptor(
+            ArchetypeName, "TensorSliceValueRange:fit_to_slice",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+
+      public:
+        TensorSliceValueRange() = default;
+        TensorSliceValueRange(TensorSliceValueRange&& other) = default;
+        TensorSliceValueRange(const TensorSliceValueRange& other) = default;
+        TensorSliceValueRange& operator=(const TensorSliceValueRange& other) = default;
+        TensorSliceValueRange& operator=(TensorSliceValueRange&& other) = default;
+
+        /// Update only some specific fields of a `TensorSliceValueRange`.
+        static TensorSliceValueRange update_fields() {
+            return TensorSliceValueRange();
+        }
+
+        /// Clear all the fields of a `TensorSliceValueRange`.
+        static TensorSliceValueRange clear_fields();
+
+        /// Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+        ///
+        /// Defaults to false.
+        TensorSliceValueRange with_fit_to_slice(
+            const rerun::blueprint::components::Enabled& _fit_to_slice
+        ) && {
+            fit_to_slice = ComponentBatch::from_loggable(_fit_to_slice, Descriptor_fit_to_slice)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TensorSliceValueRange> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TensorSliceValueRange& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,96 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_saved_filters.fbs".
+
+#include "text_log_saved_filters.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TextLogSavedFilters TextLogSavedFilters::clear_fields() {
+        auto archetype = TextLogSavedFilters();
+        archetype.names =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_names).value_or_throw();
+        archetype.patterns =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_patterns).value_or_throw();
+        archetype.is_regex =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_is_regex)
+                .value_or_throw();
+        archetype.min_levels =
+            ComponentBatch::empty<rerun::components::TextLogLevel>(Descriptor_min_levels)
+                .value_or_throw();
+        archetype.max_levels =
+            ComponentBatch::empty<rerun::components::TextLogLevel>(Descriptor_max_levels)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TextLogSavedFilters::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (names.has_value()) {
+            columns.push_back(names.value().partitioned(lengths_).value_or_throw());
+        }
+        if (patterns.has_value()) {
+            columns.push_back(patterns.value().partitioned(lengths_).value_or_throw());
+        }
+        if (is_regex.has_value()) {
+            columns.push_back(is_regex.value().partitioned(lengths_).value_or_throw());
+        }
+        if (min_levels.has_value()) {
+            columns.push_back(min_levels.value().partitioned(lengths_).value_or_throw());
+        }
+        if (max_levels.has_value()) {
+            columns.push_back(max_levels.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TextLogSavedFilters::columns() {
+        if (names.has_value()) {
+            return columns(std::vector<uint32_t>(names.value().length(), 1));
+        }
+        if (patterns.has_value()) {
+            return columns(std::vector<uint32_t>(patterns.value().length(), 1));
+        }
+        if (is_regex.has_value()) {
+            return columns(std::vector<uint32_t>(is_regex.value().length(), 1));
+        }
+        if (min_levels.has_value()) {
+            return columns(std::vector<uint32_t>(min_levels.value().length(), 1));
+        }
+        if (max_levels.has_value()) {
+            return columns(std::vector<uint32_t>(max_levels.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TextLogSavedFilters>::as_batches(
+            const blueprint::archetypes::TextLogSavedFilters& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.names.has_value()) {
+            cells.push_back(archetype.names.value());
+        }
+        if (archetype.patterns.has_value()) {
+            cells.push_back(archetype.patterns.value());
+        }
+        if (archetype.is_regex.has_value()) {
+            cells.push_back(archetype.is_regex.value());
+        }
+        if (archetype.min_levels.has_value()) {
+            cells.push_back(archetype.min_levels.value());
+        }
+        if (archetype.max_levels.has_value()) {
+            cells.push_back(archetype.max_levels.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,159 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_saved_filters.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/text.hpp"
+#include "../../components/text_log_level.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Named filters of a text log view, to apply them again later.
+    ///
+    /// All fields have one instance per saved filter, in the order they were saved.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogSavedFilters {
+        /// The name the user gave each filter.
+        std::optional<ComponentBatch> names;
+
+        /// The text each filter matches log messages against.
+        std::optional<ComponentBatch> patterns;
+
+        /// Whether each pattern is a regular expression rather than plain text.
+        std::optional<ComponentBatch> is_regex;
+
+        /// The least severe level each filter shows.
+        std::optional<ComponentBatch> min_levels;
+
+        /// The most severe level each filter shows.
+        std::optional<ComponentBatch> max_levels;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TextLogSavedFilters";
+
+        /// `ComponentDescriptor` for the `names` field.
+        static constexpr auto Descriptor_names = ComponentDescriptor(
+            ArchetypeName, "TextLogSavedFilters:names",
+            Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `patterns` field.
+        static constexpr auto Descriptor_patterns = ComponentDescriptor(
+            ArchetypeName, "TextLogSavedFilters:patterns",
+            Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `is_regex` field.
+        static constexpr auto Descriptor_is_regex = ComponentDescriptor(
+            ArchetypeName, "TextLogSavedFilters:is_regex",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `min_levels` field.
+        static constexpr auto Descriptor_min_levels = ComponentDescriptor(
+            ArchetypeName, "TextLogSavedFilters:min_levels",
+            Loggable<rerun::components::TextLogLevel>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `max_levels` field.
+        static constexpr auto Descriptor_max_levels = ComponentDescriptor(
+            ArchetypeName, "TextLogSavedFilters:max_levels",
+            Loggable<rerun::components::TextLogLevel>::ComponentType
+        );
+
+      public:
+        TextLogSavedFilters() = default;
+        TextLogSavedFilters(TextLogSavedFilters&& other) = default;
+        TextLogSavedFilters(const TextLogSavedFilters& other) = default;
+        TextLogSavedFilters& operator=(const TextLogSavedFilters& other) = default;
+        TextLogSavedFilters& operator=(TextLogSavedFilters&& other) = default;
+
+        /// Update only some specific fields of a `TextLogSavedFilters`.
+        static TextLogSavedFilters update_fields() {
+            return TextLogSavedFilters();
+        }
+
+        /// Clear all the fields of a `TextLogSavedFilters`.
+        static TextLogSavedFilters clear_fields();
+
+        /// The name the user gave each filter.
+        TextLogSavedFilters with_names(const Collection<rerun::components::Text>& _names) && {
+            names = ComponentBatch::from_loggable(_names, Descriptor_names).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The text each filter matches log messages against.
+        TextLogSavedFilters with_patterns(const Collection<rerun::components::Text>& _patterns) && {
+            patterns =
+                ComponentBatch::from_loggable(_patterns, Descriptor_patterns).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether each pattern is a regular expression rather than plain text.
+        TextLogSavedFilters with_is_regex(
+            const Collection<rerun::blueprint::components::Enabled>& _is_regex
+        ) && {
+            is_regex =
+                ComponentBatch::from_loggable(_is_regex, Descriptor_is_regex).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The least severe level each filter shows.
+        TextLogSavedFilters with_min_levels(
+            const Collection<rerun::components::TextLogLevel>& _min_levels
+        ) && {
+            min_levels =
+                ComponentBatch::from_loggable(_min_levels, Descriptor_min_levels).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The most severe level each filter shows.
+        TextLogSavedFilters with_max_levels(
+            const Collection<rerun::components::TextLogLevel>& _max_levels
+        ) && {
+            max_levels =
+                ComponentBatch::from_loggable(_max_levels, Descriptor_max_levels).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TextLogSavedFilters> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TextLogSavedFilters& archetype
+        );
+    };
+} // namespace rerun
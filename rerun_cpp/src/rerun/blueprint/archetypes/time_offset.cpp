@@ -0,0 +1,48 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/time_offset.fbs".
+
+#include "time_offset.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TimeOffset TimeOffset::clear_fields() {
+        auto archetype = TimeOffset();
+        archetype.offset =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_offset).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TimeOffset::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (offset.has_value()) {
+            columns.push_back(offset.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TimeOffset::columns() {
+        if (offset.has_value()) {
+            return columns(std::vector<uint32_t>(offset.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::TimeOffset>::as_batches(
+        const blueprint::archetypes::TimeOffset& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.offset.has_value()) {
+            cells.push_back(archetype.offset.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,92 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/time_offset.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/scalar.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Offset of the time a view is shown at, relative to the time cursor.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TimeOffset {
+        /// In seconds on temporal timelines, and in steps on sequence timelines.
+        ///
+        /// Negative to show the past. Defaults to zero.
+        std::optional<ComponentBatch> offset;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TimeOffset";
+
+        /// `ComponentDescriptor` for the `offset` field.
+        static constexpr auto Descriptor_offset = ComponentDescriptor(
+            ArchetypeName, "TimeOffset:offset", Loggable<rerun::components::Scalar>::ComponentType
+        );
+
+      public:
+        TimeOffset() = default;
+        TimeOffset(TimeOffset&& other) = default;
+        TimeOffset(const TimeOffset& other) = default;
+        TimeOffset& operator=(const TimeOffset& other) = default;
+        TimeOffset& operator=(TimeOffset&& other) = default;
+
+        /// Update only some specific fields of a `TimeOffset`.
+        static TimeOffset update_fields() {
+            return TimeOffset();
+        }
+
+        /// Clear all the fields of a `TimeOffset`.
+        static TimeOffset clear_fields();
+
+        /// In seconds on temporal timelines, and in steps on sequence timelines.
+        ///
+        /// Negative to show the past. Defaults to zero.
+        TimeOffset with_offset(const rerun::components::Scalar& _offset) && {
+            offset = ComponentBatch::from_loggable(_offset, Descriptor_offset).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TimeOffset> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TimeOffset& archetype
+        );
+    };
+} // namespace rerun
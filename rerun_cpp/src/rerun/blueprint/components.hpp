@@ -26,6 +26,7 @@
 #include "blueprint/components/map_provider.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
 #include "blueprint/components/panel_state.hpp"
+#include "blueprint/components/plot_mode.hpp"
 #include "blueprint/components/query_expression.hpp"
 #include "blueprint/components/root_container.hpp"
 #include "blueprint/components/row_share.hpp"
@@ -0,0 +1,69 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/plot_mode.fbs".
+
+#pragma once
+
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: How the series of a time series view are drawn.
+    enum class PlotMode : uint8_t {
+
+        /// Every series is drawn on its own, as lines or points.
+        Lines = 1,
+
+        /// The series are stacked on top of each other as filled areas.
+        ///
+        /// Values are averaged per time bucket.
+        StackedArea = 2,
+
+        /// The series are drawn as bars next to each other, one group per time bucket.
+        ///
+        /// Values are averaged per time bucket.
+        GroupedBars = 3,
+
+        /// The series are drawn as bars stacked on top of each other, one stack per time bucket.
+        ///
+        /// Values are averaged per time bucket.
+        StackedBars = 4,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::PlotMode> {
+        static constexpr std::string_view ComponentType = "rerun.blueprint.components.PlotMode";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::PlotMode` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::PlotMode* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::components::PlotMode* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
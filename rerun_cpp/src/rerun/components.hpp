@@ -19,6 +19,7 @@
 #include "components/entity_path.hpp"
 #include "components/fill_mode.hpp"
 #include "components/fill_ratio.hpp"
+#include "components/font_size.hpp"
 #include "components/gamma_correction.hpp"
 #include "components/geo_line_string.hpp"
 #include "components/graph_edge.hpp"
@@ -60,6 +61,7 @@
 #include "components/scalar.hpp"
 #include "components/scale3d.hpp"
 #include "components/schema_id.hpp"
+#include "components/series_secondary_axis.hpp"
 #include "components/series_visible.hpp"
 #include "components/show_labels.hpp"
 #include "components/stroke_width.hpp"
@@ -69,8 +71,10 @@
 #include "components/tensor_width_dimension.hpp"
 #include "components/texcoord2d.hpp"
 #include "components/text.hpp"
+#include "components/text_anchor.hpp"
 #include "components/text_log_level.hpp"
 #include "components/timestamp.hpp"
+#include "components/transform_interpolation.hpp"
 #include "components/transform_mat3x3.hpp"
 #include "components/transform_relation.hpp"
 #include "components/translation3d.hpp"
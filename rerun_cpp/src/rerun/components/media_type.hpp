@@ -39,6 +39,23 @@ namespace rerun::components {
             return "text/markdown";
         }
 
+        /// [JSON](https://en.wikipedia.org/wiki/JSON): `application/json`.
+        ///
+        /// <https://www.iana.org/assignments/media-types/application/json>
+        static MediaType json() {
+            return "application/json";
+        }
+
+        // ------------------------------------------------
+        // Documents:
+
+        /// [PDF document](https://en.wikipedia.org/wiki/PDF): `application/pdf`.
+        ///
+        /// <https://www.iana.org/assignments/media-types/application/pdf>
+        static MediaType pdf() {
+            return "application/pdf";
+        }
+
         // ------------------------------------------------
         // Images:
 
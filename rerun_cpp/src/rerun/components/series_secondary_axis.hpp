@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/series_secondary_axis.fbs".
+
+#pragma once
+
+#include "../datatypes/bool.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: Whether a time series is plotted against the secondary Y axis, on the right-hand side of the plot.
+    ///
+    /// Useful for series whose values differ by orders of magnitude from the others in the same plot.
+    struct SeriesSecondaryAxis {
+        rerun::datatypes::Bool secondary_axis;
+
+      public:
+        SeriesSecondaryAxis() = default;
+
+        SeriesSecondaryAxis(rerun::datatypes::Bool secondary_axis_)
+            : secondary_axis(secondary_axis_) {}
+
+        SeriesSecondaryAxis& operator=(rerun::datatypes::Bool secondary_axis_) {
+            secondary_axis = secondary_axis_;
+            return *this;
+        }
+
+        SeriesSecondaryAxis(bool value_) : secondary_axis(value_) {}
+
+        SeriesSecondaryAxis& operator=(bool value_) {
+            secondary_axis = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Bool datatype
+        operator rerun::datatypes::Bool() const {
+            return secondary_axis;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Bool) == sizeof(components::SeriesSecondaryAxis));
+
+    /// \private
+    template <>
+    struct Loggable<components::SeriesSecondaryAxis> {
+        static constexpr std::string_view ComponentType = "rerun.components.SeriesSecondaryAxis";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Bool>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::SeriesSecondaryAxis` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::SeriesSecondaryAxis* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(
+                    &instances->secondary_axis,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,80 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/text_anchor.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: Which point of a text label is placed at its position.
+    ///
+    /// E.g. with `components::TextAnchor::Bottom`, the text is centered horizontally above its position.
+    enum class TextAnchor : uint8_t {
+
+        /// The center of the text is placed at the position.
+        Center = 1,
+
+        /// The middle of the top edge of the text is placed at the position.
+        Top = 2,
+
+        /// The middle of the bottom edge of the text is placed at the position.
+        Bottom = 3,
+
+        /// The middle of the left edge of the text is placed at the position.
+        Left = 4,
+
+        /// The middle of the right edge of the text is placed at the position.
+        Right = 5,
+
+        /// The top left corner of the text is placed at the position.
+        TopLeft = 6,
+
+        /// The top right corner of the text is placed at the position.
+        TopRight = 7,
+
+        /// The bottom left corner of the text is placed at the position.
+        BottomLeft = 8,
+
+        /// The bottom right corner of the text is placed at the position.
+        BottomRight = 9,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::TextAnchor> {
+        static constexpr std::string_view ComponentType = "rerun.components.TextAnchor";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::TextAnchor` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TextAnchor* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::TextAnchor* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/transform_interpolation.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: Specifies how the viewer resolves a transform at times that fall between two logged samples.
+    enum class TransformInterpolation : uint8_t {
+
+        /// The transform jumps to the next sample once it is reached, holding the previous sample until then.
+        ///
+        /// This is what latest-at queries do for all other components.
+        Step = 1,
+
+        /// The transform is interpolated between the samples before and after the queried time.
+        ///
+        /// Translations and scales are interpolated linearly, rotations with spherical linear interpolation (slerp).
+        /// Transforms that are given as a 3x3 matrix can't be interpolated and behave as if `components::TransformInterpolation` was `Step`.
+        Linear = 2,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::TransformInterpolation> {
+        static constexpr std::string_view ComponentType = "rerun.components.TransformInterpolation";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::TransformInterpolation` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TransformInterpolation* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::TransformInterpolation* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
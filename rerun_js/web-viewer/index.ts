@@ -135,7 +135,12 @@ export type ViewerEvent =
   | TimeUpdateEvent
   | TimelineChangeEvent
   | SelectionChangeEvent
-  | RecordingOpenEvent;
+  | RecordingOpenEvent
+  | RecordingCloseEvent
+  | BlueprintChangeEvent
+  | IngestionStartEvent
+  | IngestionFinishEvent
+  | DataPrunedEvent;
 
 /**
  * Properties available on all {@link ViewerEvent} types.
@@ -217,6 +222,87 @@ export type RecordingOpenEvent = ViewerEventBase & {
   version?: string;
 }
 
+/**
+ * Fired when a recording is closed, e.g. by the user.
+ *
+ * Recordings closed to stay within the memory limit fire {@link DataPrunedEvent} instead.
+ */
+export type RecordingCloseEvent = ViewerEventBase & { type: "recording_close" };
+
+/**
+ * Fired when the blueprint of the active recording changes, e.g. because a view was added.
+ *
+ * Fires at most once per frame, and not for activating a different blueprint.
+ */
+export type BlueprintChangeEvent = ViewerEventBase & {
+  type: "blueprint_change";
+
+  /** Id of the changed blueprint. */
+  blueprint_id: string;
+};
+
+/**
+ * Fired when the first data of a recording arrives.
+ */
+export type IngestionStartEvent = ViewerEventBase & {
+  type: "ingestion_start";
+
+  /**
+   * Where the data comes from, e.g. a file path or URL.
+   *
+   * The value should be considered unstable.
+   */
+  source: string;
+};
+
+/**
+ * Fired when the source of a recording stops sending data, e.g. because a file was fully loaded.
+ */
+export type IngestionFinishEvent = ViewerEventBase & {
+  type: "ingestion_finish";
+
+  /**
+   * Where the data came from, e.g. a file path or URL.
+   *
+   * The value should be considered unstable.
+   */
+  source: string;
+
+  /** Set if the source stopped because of an error. */
+  error?: string;
+};
+
+/**
+ * Fired when data is dropped from a recording to stay within the memory limit.
+ */
+export type DataPrunedEvent = ViewerEventBase & {
+  type: "data_pruned";
+
+  /**
+   * For each timeline, the time range covered by the dropped data.
+   *
+   * Data for some entities may remain within these ranges.
+   */
+  time_ranges: PrunedTimeRange[];
+
+  /** How much memory was freed by dropping the data, in bytes. */
+  bytes_freed: number;
+
+  /** Whether the whole recording was closed. */
+  recording_closed: boolean;
+}
+
+/**
+ * The time range covered by the data dropped from a timeline.
+ *
+ * Both ends are inclusive.
+ */
+export type PrunedTimeRange = {
+  timeline: string;
+  min: number;
+  max: number;
+}
+
 // A bit of TypeScript metaprogramming to automatically produce a
 // mapping of event names to event payloads given the above type
 // definitions.
@@ -238,7 +324,7 @@ type ViewerEventMap = {
  * Selected an entity, or an instance of an entity.
  *
  * If the entity was selected within a view, then this also
- * includes the view's name.
+ * includes the view's id and name.
  *
  * If the entity was selected within a 2D or 3D space view,
  * then this also includes the position.
@@ -248,10 +334,18 @@ export type EntityItem = {
 
   entity_path: string;
   instance_id?: number;
+  view_id?: string;
   view_name?: string;
   position?: [number, number, number];
 };
 
+/** Selected a component of an entity. */
+export type ComponentItem = {
+  type: "component";
+  entity_path: string;
+  component: string;
+};
+
 /** Selected a view. */
 export type ViewItem = { type: "view"; view_id: string; view_name: string };
 
@@ -263,7 +357,11 @@ export type ContainerItem = {
 };
 
 /** A single item in a selection. */
-export type SelectionChangeItem = EntityItem | ViewItem | ContainerItem;
+export type SelectionChangeItem =
+  | EntityItem
+  | ComponentItem
+  | ViewItem
+  | ContainerItem;
 
 interface FullscreenOptions {
   get_state: () => boolean;
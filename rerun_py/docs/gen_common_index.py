@@ -161,6 +161,7 @@ SECTION_TABLE: Final[list[Section]] = [
     Section(
         title="General",
         class_list=[
+            "archetypes.Attachment",
             "archetypes.Clear",
             "blueprint.archetypes.EntityBehavior",
             "archetypes.RecordingInfo",
@@ -183,6 +184,7 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Image",
             "archetypes.EncodedImage",
             "archetypes.SegmentationImage",
+            "archetypes.Heatmap",
         ],
         gen_page=False,
     ),
@@ -221,6 +223,7 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Mesh3D",
             "archetypes.Points2D",
             "archetypes.Points3D",
+            "archetypes.Text3D",
         ],
         gen_page=False,
     ),
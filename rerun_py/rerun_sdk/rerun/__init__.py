@@ -77,6 +77,7 @@ from .archetypes import (
     Arrows3D as Arrows3D,
     Asset3D as Asset3D,
     AssetVideo as AssetVideo,
+    Attachment as Attachment,
     BarChart as BarChart,
     Boxes2D as Boxes2D,
     Boxes3D as Boxes3D,
@@ -90,6 +91,7 @@ from .archetypes import (
     GeoPoints as GeoPoints,
     GraphEdges as GraphEdges,
     GraphNodes as GraphNodes,
+    Heatmap as Heatmap,
     Image as Image,
     InstancePoses3D as InstancePoses3D,
     LineStrips2D as LineStrips2D,
@@ -107,6 +109,7 @@ from .archetypes import (
     SeriesLines as SeriesLines,
     SeriesPoints as SeriesPoints,
     Tensor as Tensor,
+    Text3D as Text3D,
     TextDocument as TextDocument,
     TextLog as TextLog,
     Transform3D as Transform3D,
@@ -125,7 +128,9 @@ from .components import (
     Radius as Radius,
     Scale3D as Scale3D,
     TensorDimensionIndexSelection as TensorDimensionIndexSelection,
+    TextAnchor as TextAnchor,
     TextLogLevel as TextLogLevel,
+    TransformInterpolation as TransformInterpolation,
     TransformRelation as TransformRelation,
     VideoCodec as VideoCodec,
 )
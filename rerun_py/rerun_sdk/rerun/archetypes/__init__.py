@@ -7,6 +7,7 @@ from .arrows2d import Arrows2D
 from .arrows3d import Arrows3D
 from .asset3d import Asset3D
 from .asset_video import AssetVideo
+from .attachment import Attachment
 from .bar_chart import BarChart
 from .boxes2d import Boxes2D
 from .boxes3d import Boxes3D
@@ -20,6 +21,7 @@ from .geo_line_strings import GeoLineStrings
 from .geo_points import GeoPoints
 from .graph_edges import GraphEdges
 from .graph_nodes import GraphNodes
+from .heatmap import Heatmap
 from .image import Image
 from .instance_poses3d import InstancePoses3D
 from .line_strips2d import LineStrips2D
@@ -38,6 +40,7 @@ from .segmentation_image import SegmentationImage
 from .series_lines import SeriesLines
 from .series_points import SeriesPoints
 from .tensor import Tensor
+from .text3d import Text3D
 from .text_document import TextDocument
 from .text_log import TextLog
 from .transform3d import Transform3D
@@ -51,6 +54,7 @@ __all__ = [
     "Arrows3D",
     "Asset3D",
     "AssetVideo",
+    "Attachment",
     "BarChart",
     "Boxes2D",
     "Boxes3D",
@@ -64,6 +68,7 @@ __all__ = [
     "GeoPoints",
     "GraphEdges",
     "GraphNodes",
+    "Heatmap",
     "Image",
     "InstancePoses3D",
     "LineStrips2D",
@@ -82,6 +87,7 @@ __all__ = [
     "SeriesLines",
     "SeriesPoints",
     "Tensor",
+    "Text3D",
     "TextDocument",
     "TextLog",
     "Transform3D",
@@ -0,0 +1,209 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/attachment.fbs".
+
+# You can extend this class by creating a "AttachmentExt" class in "attachment_ext.py".
+
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+from .attachment_ext import AttachmentExt
+
+__all__ = ["Attachment"]
+
+
+@define(str=False, repr=False, init=False)
+class Attachment(AttachmentExt, Archetype):
+    """
+    **Archetype**: An arbitrary file attached to a recording, e.g. a configuration file or a report.
+
+    The Rerun Viewer shows a preview of the attachment in the selection panel:
+    images and videos are shown as such, JSON documents as a collapsible tree,
+    other text as plain text, and anything else as a hexdump.
+    There is no dedicated preview for PDF documents yet: save them to disk to view them.
+
+    See also [`archetypes.Asset3D`][rerun.archetypes.Asset3D] and [`archetypes.AssetVideo`][rerun.archetypes.AssetVideo] for assets that are shown in the spatial views.
+    """
+
+    # __init__ can be found in attachment_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            blob=None,
+            media_type=None,
+            filename=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Attachment:
+        """Produce an empty Attachment, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        blob: datatypes.BlobLike | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+        filename: datatypes.Utf8Like | None = None,
+    ) -> Attachment:
+        """
+        Update only some specific fields of a `Attachment`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        blob:
+            The contents of the attachment.
+        media_type:
+            The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+
+            If omitted, the viewer will try to guess from the data blob.
+        filename:
+            The name of the file the attachment was read from, e.g. `config.yaml`.
+
+            Used as the default file name when saving the attachment from the viewer.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "blob": blob,
+                "media_type": media_type,
+                "filename": filename,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Attachment:
+        """Clear all the fields of a `Attachment`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        blob: datatypes.BlobArrayLike | None = None,
+        media_type: datatypes.Utf8ArrayLike | None = None,
+        filename: datatypes.Utf8ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        blob:
+            The contents of the attachment.
+        media_type:
+            The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+
+            If omitted, the viewer will try to guess from the data blob.
+        filename:
+            The name of the file the attachment was read from, e.g. `config.yaml`.
+
+            Used as the default file name when saving the attachment from the viewer.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                blob=blob,
+                media_type=media_type,
+                filename=filename,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"Attachment:blob": blob, "Attachment:media_type": media_type, "Attachment:filename": filename}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    blob: components.BlobBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.BlobBatch._converter,  # type: ignore[misc]
+    )
+    # The contents of the attachment.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    media_type: components.MediaTypeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.MediaTypeBatch._converter,  # type: ignore[misc]
+    )
+    # The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+    #
+    # If omitted, the viewer will try to guess from the data blob.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    filename: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The name of the file the attachment was read from, e.g. `config.yaml`.
+    #
+    # Used as the default file name when saving the attachment from the viewer.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+import pathlib
+from typing import TYPE_CHECKING, Any
+
+from ..error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from .. import datatypes
+
+
+class AttachmentExt:
+    """Extension for [Attachment][rerun.archetypes.Attachment]."""
+
+    def __init__(
+        self: Any,
+        *,
+        path: str | pathlib.Path | None = None,
+        contents: datatypes.BlobLike | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+        filename: datatypes.Utf8Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Attachment archetype.
+
+        Parameters
+        ----------
+        path:
+            A path to an file stored on the local filesystem. Mutually
+            exclusive with `contents`.
+
+        contents:
+            The contents of the file. Can be a BufferedReader, BytesIO, or
+            bytes. Mutually exclusive with `path`.
+
+        media_type:
+            The Media Type of the attachment, e.g. `application/json` or `application/pdf`.
+
+            If omitted, it will be guessed from the `path` (if any),
+            or the viewer will try to guess from the contents (magic header).
+
+        filename:
+            The name of the file the attachment was read from.
+
+            If omitted, it defaults to the file name of `path` (if any).
+
+        """
+
+        from ..components import MediaType
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            if (path is None) == (contents is None):
+                raise ValueError("Must provide exactly one of 'path' or 'contents'")
+
+            if path is None:
+                blob = contents
+            else:
+                blob = pathlib.Path(path).read_bytes()
+                if media_type is None:
+                    media_type = MediaType.guess_from_path(path)
+                if filename is None:
+                    filename = pathlib.Path(path).name
+
+            self.__attrs_init__(blob=blob, media_type=media_type, filename=filename)
+            return
+
+        self.__attrs_clear__()
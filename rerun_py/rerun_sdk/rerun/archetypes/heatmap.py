@@ -0,0 +1,290 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/heatmap.fbs".
+
+# You can extend this class by creating a "HeatmapExt" class in "heatmap_ext.py".
+
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+from .heatmap_ext import HeatmapExt
+
+__all__ = ["Heatmap"]
+
+
+@define(str=False, repr=False, init=False)
+class Heatmap(HeatmapExt, Archetype):
+    """
+    **Archetype**: A 2D grid of scalar values, shown as a colormapped image.
+
+    Useful for cost maps, attention maps, correlation matrices and other data that
+    would otherwise have to be colormapped manually before logging it as an [`archetypes.Image`][rerun.archetypes.Image].
+
+    In 2D views the heatmap is drawn like an image, with one pixel per value.
+    In 3D views it is drawn as a textured quad on the XY plane of its entity.
+    """
+
+    # __init__ can be found in heatmap_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            buffer=None,
+            format=None,
+            colormap=None,
+            value_range=None,
+            opacity=None,
+            draw_order=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Heatmap:
+        """Produce an empty Heatmap, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        buffer: datatypes.BlobLike | None = None,
+        format: datatypes.ImageFormatLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        draw_order: datatypes.Float32Like | None = None,
+    ) -> Heatmap:
+        """
+        Update only some specific fields of a `Heatmap`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        buffer:
+            The values of the heatmap, in row-major order.
+        format:
+            The format of the heatmap, i.e. its width, height and datatype.
+        colormap:
+            Colormap used to map the values to colors.
+
+            If not set, the heatmap will be rendered using the Viridis colormap.
+        value_range:
+            The range of values that is mapped onto the colormap.
+
+            Values outside of the range are clamped to the range for the purpose of colormapping.
+
+            If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+        opacity:
+            Opacity of the heatmap, useful for layering it on top of an image.
+
+            Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+            Defaults to `-5.0`.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "buffer": buffer,
+                "format": format,
+                "colormap": colormap,
+                "value_range": value_range,
+                "opacity": opacity,
+                "draw_order": draw_order,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Heatmap:
+        """Clear all the fields of a `Heatmap`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        buffer: datatypes.BlobArrayLike | None = None,
+        format: datatypes.ImageFormatArrayLike | None = None,
+        colormap: components.ColormapArrayLike | None = None,
+        value_range: datatypes.Range1DArrayLike | None = None,
+        opacity: datatypes.Float32ArrayLike | None = None,
+        draw_order: datatypes.Float32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        buffer:
+            The values of the heatmap, in row-major order.
+        format:
+            The format of the heatmap, i.e. its width, height and datatype.
+        colormap:
+            Colormap used to map the values to colors.
+
+            If not set, the heatmap will be rendered using the Viridis colormap.
+        value_range:
+            The range of values that is mapped onto the colormap.
+
+            Values outside of the range are clamped to the range for the purpose of colormapping.
+
+            If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+        opacity:
+            Opacity of the heatmap, useful for layering it on top of an image.
+
+            Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+            Defaults to `-5.0`.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                buffer=buffer,
+                format=format,
+                colormap=colormap,
+                value_range=value_range,
+                opacity=opacity,
+                draw_order=draw_order,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "Heatmap:buffer": buffer,
+            "Heatmap:format": format,
+            "Heatmap:colormap": colormap,
+            "Heatmap:value_range": value_range,
+            "Heatmap:opacity": opacity,
+            "Heatmap:draw_order": draw_order,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    buffer: components.ImageBufferBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageBufferBatch._converter,  # type: ignore[misc]
+    )
+    # The values of the heatmap, in row-major order.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    format: components.ImageFormatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageFormatBatch._converter,  # type: ignore[misc]
+    )
+    # The format of the heatmap, i.e. its width, height and datatype.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # Colormap used to map the values to colors.
+    #
+    # If not set, the heatmap will be rendered using the Viridis colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    value_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The range of values that is mapped onto the colormap.
+    #
+    # Values outside of the range are clamped to the range for the purpose of colormapping.
+    #
+    # If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity of the heatmap, useful for layering it on top of an image.
+    #
+    # Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    draw_order: components.DrawOrderBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DrawOrderBatch._converter,  # type: ignore[misc]
+    )
+    # An optional floating point value that specifies the 2D drawing order.
+    #
+    # Objects with higher values are drawn on top of those with lower values.
+    # Defaults to `-5.0`.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+import numpy as np
+import numpy.typing as npt
+
+from ..components import ColormapLike, ImageFormat
+from ..datatypes import ChannelDatatype, Float32Like
+
+if TYPE_CHECKING:
+    from rerun.datatypes.range1d import Range1DLike
+
+
+class HeatmapExt:
+    """Extension for [Heatmap][rerun.archetypes.Heatmap]."""
+
+    def __init__(
+        self: Any,
+        values: npt.ArrayLike,
+        *,
+        colormap: ColormapLike | None = None,
+        value_range: Range1DLike | None = None,
+        opacity: Float32Like | None = None,
+        draw_order: Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Heatmap archetype.
+
+        Parameters
+        ----------
+        values:
+            A 2D array of values with shape `(height, width)`, converted to 32-bit floats.
+            Leading and trailing unit-dimensions are ignored, so that
+            `1x480x640x1` is treated as a `480x640`.
+        colormap:
+            Colormap used to map the values to colors.
+
+            If not set, the heatmap will be rendered using the Viridis colormap.
+        value_range:
+            The range of values that is mapped onto the colormap.
+
+            Values outside of the range are clamped to the range for the purpose of colormapping.
+
+            If not specified, the range is the minimum and maximum of the finite values in the heatmap.
+        opacity:
+            Opacity of the heatmap, useful for layering it on top of an image.
+
+            Defaults to 0.5 if there are any images in the view, otherwise 1.0.
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+            Defaults to `-5.0`.
+
+        """
+        if not isinstance(values, np.ndarray) and hasattr(values, "numpy"):
+            # Make available to the cpu
+            values = values.numpy(force=True)
+        values = np.asarray(values, dtype=np.float32)
+
+        shape = values.shape
+
+        # Ignore leading and trailing dimensions of size 1:
+        while 2 < len(shape) and shape[0] == 1:
+            shape = shape[1:]
+        while 2 < len(shape) and shape[-1] == 1:
+            shape = shape[:-1]
+
+        if len(shape) != 2:
+            raise ValueError(f"Heatmap must be 2D, got shape {values.shape}")
+        height, width = shape
+
+        self.__attrs_init__(
+            buffer=values.tobytes(),
+            format=ImageFormat(
+                width=width,
+                height=height,
+                channel_datatype=ChannelDatatype.F32,
+            ),
+            colormap=colormap,
+            value_range=value_range,
+            opacity=opacity,
+            draw_order=draw_order,
+        )
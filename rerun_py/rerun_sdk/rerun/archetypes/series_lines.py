@@ -75,6 +75,7 @@ class SeriesLines(Archetype):
         widths: datatypes.Float32ArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyLike | None = None,
     ) -> None:
         """
@@ -102,6 +103,13 @@ class SeriesLines(Archetype):
             via this property will still be visible in the legend.
 
             May change over time, but can cause discontinuities in the line.
+        secondary_axis:
+            Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all lines on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         aggregation_policy:
             Configures the zoom-dependent scalar aggregation.
 
@@ -120,6 +128,7 @@ class SeriesLines(Archetype):
                 widths=widths,
                 names=names,
                 visible_series=visible_series,
+                secondary_axis=secondary_axis,
                 aggregation_policy=aggregation_policy,
             )
             return
@@ -132,6 +141,7 @@ class SeriesLines(Archetype):
             widths=None,
             names=None,
             visible_series=None,
+            secondary_axis=None,
             aggregation_policy=None,
         )
 
@@ -151,6 +161,7 @@ class SeriesLines(Archetype):
         widths: datatypes.Float32ArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyLike | None = None,
     ) -> SeriesLines:
         """
@@ -180,6 +191,13 @@ class SeriesLines(Archetype):
             via this property will still be visible in the legend.
 
             May change over time, but can cause discontinuities in the line.
+        secondary_axis:
+            Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all lines on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         aggregation_policy:
             Configures the zoom-dependent scalar aggregation.
 
@@ -198,6 +216,7 @@ class SeriesLines(Archetype):
                 "widths": widths,
                 "names": names,
                 "visible_series": visible_series,
+                "secondary_axis": secondary_axis,
                 "aggregation_policy": aggregation_policy,
             }
 
@@ -223,6 +242,7 @@ class SeriesLines(Archetype):
         widths: datatypes.Float32ArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         aggregation_policy: components.AggregationPolicyArrayLike | None = None,
     ) -> ComponentColumnList:
         """
@@ -255,6 +275,13 @@ class SeriesLines(Archetype):
             via this property will still be visible in the legend.
 
             May change over time, but can cause discontinuities in the line.
+        secondary_axis:
+            Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all lines on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         aggregation_policy:
             Configures the zoom-dependent scalar aggregation.
 
@@ -273,6 +300,7 @@ class SeriesLines(Archetype):
                 widths=widths,
                 names=names,
                 visible_series=visible_series,
+                secondary_axis=secondary_axis,
                 aggregation_policy=aggregation_policy,
             )
 
@@ -285,6 +313,7 @@ class SeriesLines(Archetype):
             "SeriesLines:widths": widths,
             "SeriesLines:names": names,
             "SeriesLines:visible_series": visible_series,
+            "SeriesLines:secondary_axis": secondary_axis,
             "SeriesLines:aggregation_policy": aggregation_policy,
         }
         columns = []
@@ -364,6 +393,20 @@ class SeriesLines(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    secondary_axis: components.SeriesSecondaryAxisBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SeriesSecondaryAxisBatch._converter,  # type: ignore[misc]
+    )
+    # Which lines are plotted against the secondary Y axis, on the right-hand side of the plot.
+    #
+    # If not set, all lines on this entity use the primary Y axis.
+    # The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    #
+    # Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     aggregation_policy: components.AggregationPolicyBatch | None = field(
         metadata={"component": True},
         default=None,
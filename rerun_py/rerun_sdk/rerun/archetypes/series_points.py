@@ -95,6 +95,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             markers=None,
             names=None,
             visible_series=None,
+            secondary_axis=None,
             marker_sizes=None,
         )
 
@@ -114,6 +115,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
         markers: components.MarkerShapeArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
     ) -> SeriesPoints:
         """
@@ -143,6 +145,13 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             via this property will still be visible in the legend.
 
             May change over time.
+        secondary_axis:
+            Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all points on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         marker_sizes:
             Sizes of the markers.
 
@@ -157,6 +166,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
                 "markers": markers,
                 "names": names,
                 "visible_series": visible_series,
+                "secondary_axis": secondary_axis,
                 "marker_sizes": marker_sizes,
             }
 
@@ -182,6 +192,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
         markers: components.MarkerShapeArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
@@ -214,6 +225,13 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             via this property will still be visible in the legend.
 
             May change over time.
+        secondary_axis:
+            Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all points on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         marker_sizes:
             Sizes of the markers.
 
@@ -228,6 +246,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
                 markers=markers,
                 names=names,
                 visible_series=visible_series,
+                secondary_axis=secondary_axis,
                 marker_sizes=marker_sizes,
             )
 
@@ -240,6 +259,7 @@ class SeriesPoints(SeriesPointsExt, Archetype):
             "SeriesPoints:markers": markers,
             "SeriesPoints:names": names,
             "SeriesPoints:visible_series": visible_series,
+            "SeriesPoints:secondary_axis": secondary_axis,
             "SeriesPoints:marker_sizes": marker_sizes,
         }
         columns = []
@@ -319,6 +339,20 @@ class SeriesPoints(SeriesPointsExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    secondary_axis: components.SeriesSecondaryAxisBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SeriesSecondaryAxisBatch._converter,  # type: ignore[misc]
+    )
+    # Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+    #
+    # If not set, all points on this entity use the primary Y axis.
+    # The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+    #
+    # Expected to be unchanging over time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     marker_sizes: components.MarkerSizeBatch | None = field(
         metadata={"component": True},
         default=None,
@@ -16,6 +16,7 @@ class SeriesPointsExt:
         markers: components.MarkerShapeArrayLike | None = None,
         names: datatypes.Utf8ArrayLike | None = None,
         visible_series: datatypes.BoolArrayLike | None = None,
+        secondary_axis: datatypes.BoolArrayLike | None = None,
         marker_sizes: datatypes.Float32ArrayLike | None = None,
     ) -> None:
         """
@@ -43,6 +44,13 @@ class SeriesPointsExt:
             via this property will still be visible in the legend.
 
             May change over time.
+        secondary_axis:
+            Which points are plotted against the secondary Y axis, on the right-hand side of the plot.
+
+            If not set, all points on this entity use the primary Y axis.
+            The secondary axis has its own scale, so that series of very different magnitudes can share a plot.
+
+            Expected to be unchanging over time.
         marker_sizes:
             Sizes of the markers.
 
@@ -54,7 +62,7 @@ class SeriesPointsExt:
 
         # You can define your own __init__ function as a member of SeriesPointsExt in series_points_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            if all(arg is None for arg in [colors, markers, names, visible_series, marker_sizes]):
+            if all(arg is None for arg in [colors, markers, names, visible_series, secondary_axis, marker_sizes]):
                 # TODO(#10512): Back when we had indcators, we did'nt need to specify any additional components
                 # when logging a `SeriesPoints`. Now that we don't have indicators anymore, we need to have at
                 # least one component set in `SeriesPoints`, otherwise nothing would get logged and visualizers
@@ -62,7 +70,12 @@ class SeriesPointsExt:
                 markers = components.MarkerShape.Circle
 
             self.__attrs_init__(
-                colors=colors, markers=markers, names=names, visible_series=visible_series, marker_sizes=marker_sizes
+                colors=colors,
+                markers=markers,
+                names=names,
+                visible_series=visible_series,
+                secondary_axis=secondary_axis,
+                marker_sizes=marker_sizes,
             )
             return
         self.__attrs_clear__()
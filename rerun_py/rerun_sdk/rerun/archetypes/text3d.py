@@ -0,0 +1,286 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/text3d.fbs".
+
+# You can extend this class by creating a "Text3DExt" class in "text3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["Text3D"]
+
+
+@define(str=False, repr=False, init=False)
+class Text3D(Archetype):
+    """
+    **Archetype**: Text labels placed at positions in 3D space.
+
+    The labels always face the camera. Their size is either given in UI points, in which case
+    they keep the same size on screen, or in scene units, in which case they get smaller as
+    the camera moves away from them.
+
+    Unlike the `labels` of other archetypes, these labels are not attached to any other geometry.
+    """
+
+    def __init__(
+        self: Any,
+        positions: datatypes.Vec3DArrayLike,
+        texts: datatypes.Utf8ArrayLike,
+        *,
+        font_sizes: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        anchors: components.TextAnchorArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Text3D archetype.
+
+        Parameters
+        ----------
+        positions:
+            The 3D positions of the labels.
+        texts:
+            The text of each label.
+        font_sizes:
+            Optional font sizes for the labels.
+
+            Positive values are in scene units, negative values in UI points.
+        colors:
+            Optional colors for the labels.
+        anchors:
+            Which point of each label is placed at its position.
+
+            Defaults to the center of the label.
+
+        """
+
+        # You can define your own __init__ function as a member of Text3DExt in text3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(positions=positions, texts=texts, font_sizes=font_sizes, colors=colors, anchors=anchors)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            positions=None,
+            texts=None,
+            font_sizes=None,
+            colors=None,
+            anchors=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Text3D:
+        """Produce an empty Text3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        texts: datatypes.Utf8ArrayLike | None = None,
+        font_sizes: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        anchors: components.TextAnchorArrayLike | None = None,
+    ) -> Text3D:
+        """
+        Update only some specific fields of a `Text3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        positions:
+            The 3D positions of the labels.
+        texts:
+            The text of each label.
+        font_sizes:
+            Optional font sizes for the labels.
+
+            Positive values are in scene units, negative values in UI points.
+        colors:
+            Optional colors for the labels.
+        anchors:
+            Which point of each label is placed at its position.
+
+            Defaults to the center of the label.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "positions": positions,
+                "texts": texts,
+                "font_sizes": font_sizes,
+                "colors": colors,
+                "anchors": anchors,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Text3D:
+        """Clear all the fields of a `Text3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        texts: datatypes.Utf8ArrayLike | None = None,
+        font_sizes: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        anchors: components.TextAnchorArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        positions:
+            The 3D positions of the labels.
+        texts:
+            The text of each label.
+        font_sizes:
+            Optional font sizes for the labels.
+
+            Positive values are in scene units, negative values in UI points.
+        colors:
+            Optional colors for the labels.
+        anchors:
+            Which point of each label is placed at its position.
+
+            Defaults to the center of the label.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                positions=positions,
+                texts=texts,
+                font_sizes=font_sizes,
+                colors=colors,
+                anchors=anchors,
+            )
+
+        batches = inst.as_component_batches()
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "Text3D:positions": positions,
+            "Text3D:texts": texts,
+            "Text3D:font_sizes": font_sizes,
+            "Text3D:colors": colors,
+            "Text3D:anchors": anchors,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().component]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        return ComponentColumnList(columns)
+
+    positions: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The 3D positions of the labels.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    texts: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # The text of each label.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    font_sizes: components.FontSizeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.FontSizeBatch._converter,  # type: ignore[misc]
+    )
+    # Optional font sizes for the labels.
+    #
+    # Positive values are in scene units, negative values in UI points.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional colors for the labels.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    anchors: components.TextAnchorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextAnchorBatch._converter,  # type: ignore[misc]
+    )
+    # Which point of each label is placed at its position.
+    #
+    # Defaults to the center of the label.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
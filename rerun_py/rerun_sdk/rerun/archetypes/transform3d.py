@@ -297,6 +297,7 @@ class Transform3D(Transform3DExt, Archetype):
             scale=None,
             mat3x3=None,
             relation=None,
+            interpolation=None,
             axis_length=None,
         )
 
@@ -318,6 +319,7 @@ class Transform3D(Transform3DExt, Archetype):
         scale: datatypes.Vec3DLike | None = None,
         mat3x3: datatypes.Mat3x3Like | None = None,
         relation: components.TransformRelationLike | None = None,
+        interpolation: components.TransformInterpolationLike | None = None,
         axis_length: datatypes.Float32Like | None = None,
     ) -> Transform3D:
         """
@@ -339,6 +341,11 @@ class Transform3D(Transform3DExt, Archetype):
             3x3 transformation matrix.
         relation:
             Specifies the relation this transform establishes between this entity and its parent.
+        interpolation:
+            How the transform is interpolated between logged samples.
+
+            By default, the latest transform logged before the queried time is used.
+            With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
         axis_length:
             Visual length of the 3 axes.
 
@@ -356,6 +363,7 @@ class Transform3D(Transform3DExt, Archetype):
                 "scale": scale,
                 "mat3x3": mat3x3,
                 "relation": relation,
+                "interpolation": interpolation,
                 "axis_length": axis_length,
             }
 
@@ -383,6 +391,7 @@ class Transform3D(Transform3DExt, Archetype):
         scale: datatypes.Vec3DArrayLike | None = None,
         mat3x3: datatypes.Mat3x3ArrayLike | None = None,
         relation: components.TransformRelationArrayLike | None = None,
+        interpolation: components.TransformInterpolationArrayLike | None = None,
         axis_length: datatypes.Float32ArrayLike | None = None,
     ) -> ComponentColumnList:
         """
@@ -407,6 +416,11 @@ class Transform3D(Transform3DExt, Archetype):
             3x3 transformation matrix.
         relation:
             Specifies the relation this transform establishes between this entity and its parent.
+        interpolation:
+            How the transform is interpolated between logged samples.
+
+            By default, the latest transform logged before the queried time is used.
+            With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
         axis_length:
             Visual length of the 3 axes.
 
@@ -424,6 +438,7 @@ class Transform3D(Transform3DExt, Archetype):
                 scale=scale,
                 mat3x3=mat3x3,
                 relation=relation,
+                interpolation=interpolation,
                 axis_length=axis_length,
             )
 
@@ -438,6 +453,7 @@ class Transform3D(Transform3DExt, Archetype):
             "Transform3D:scale": scale,
             "Transform3D:mat3x3": mat3x3,
             "Transform3D:relation": relation,
+            "Transform3D:interpolation": interpolation,
             "Transform3D:axis_length": axis_length,
         }
         columns = []
@@ -523,6 +539,18 @@ class Transform3D(Transform3DExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    interpolation: components.TransformInterpolationBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TransformInterpolationBatch._converter,  # type: ignore[misc]
+    )
+    # How the transform is interpolated between logged samples.
+    #
+    # By default, the latest transform logged before the queried time is used.
+    # With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly instead of jumping from sample to sample.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     axis_length: components.AxisLengthBatch | None = field(
         metadata={"component": True},
         default=None,
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 from typing import Any
 
-from rerun.components import Scale3D, TransformRelation, TransformRelationLike
+from rerun.components import Scale3D, TransformInterpolationLike, TransformRelation, TransformRelationLike
 from rerun.datatypes import (
     Float32Like,
     Mat3x3Like,
@@ -31,6 +31,7 @@ class Transform3DExt:
         mat3x3: Mat3x3Like | None = None,
         from_parent: bool | None = None,
         relation: TransformRelationLike | None = None,
+        interpolation: TransformInterpolationLike | None = None,
         axis_length: Float32Like | None = None,
     ) -> None:
         """
@@ -70,6 +71,12 @@ class Transform3DExt:
             Otherwise, the transform maps from the space to its parent.
 
             Mutually exclusive with `from_parent`.
+        interpolation:
+            How the transform is interpolated between logged samples.
+
+            By default, the latest transform logged before the queried time is used.
+            With `Linear` interpolation, sparsely logged poses (e.g. at 10 Hz) move smoothly
+            instead of jumping from sample to sample.
         axis_length:
             Visual length of the 3 axes.
 
@@ -136,6 +143,7 @@ class Transform3DExt:
                     scale=scale if scale is not None else [],
                     mat3x3=mat3x3 if mat3x3 is not None else [],
                     relation=relation if relation is not None else [],
+                    interpolation=interpolation if interpolation is not None else [],
                     axis_length=axis_length if axis_length is not None else [],
                 )
             else:
@@ -146,6 +154,7 @@ class Transform3DExt:
                     scale=scale,
                     mat3x3=mat3x3,
                     relation=relation,
+                    interpolation=interpolation,
                     axis_length=axis_length,
                 )
             return
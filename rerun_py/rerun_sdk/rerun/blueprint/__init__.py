@@ -34,6 +34,7 @@ from .archetypes import (
     EyeControls3D as EyeControls3D,
     LineGrid3D as LineGrid3D,
     PlotLegend as PlotLegend,
+    PlotStyle as PlotStyle,
     ScalarAxis as ScalarAxis,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
@@ -47,6 +48,7 @@ from .components import (
     Eye3DKind as Eye3DKind,
     LockRangeDuringZoom as LockRangeDuringZoom,
     MapProvider as MapProvider,
+    PlotMode as PlotMode,
 )
 from .containers import (
     Grid as Grid,
@@ -3,26 +3,38 @@
 from __future__ import annotations
 
 from .background import Background
+from .camera_controller3d import CameraController3D
+from .camera_path3d import CameraPath3D
 from .container_blueprint import ContainerBlueprint
 from .dataframe_query import DataframeQuery
 from .entity_behavior import EntityBehavior
 from .eye_controls3d import EyeControls3D
+from .follow_latest import FollowLatest
 from .force_center import ForceCenter
 from .force_collision_radius import ForceCollisionRadius
 from .force_link import ForceLink
 from .force_many_body import ForceManyBody
 from .force_position import ForcePosition
+from .graph_layout_algorithm import GraphLayoutAlgorithm
+from .grid_overlay2d import GridOverlay2D
+from .lighting3d import Lighting3D
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
 from .map_zoom import MapZoom
 from .near_clip_plane import NearClipPlane
 from .panel_blueprint import PanelBlueprint
 from .plot_legend import PlotLegend
+from .plot_style import PlotStyle
+from .point_sizing import PointSizing
 from .scalar_axis import ScalarAxis
+from .section_plane3d import SectionPlane3D
 from .tensor_scalar_mapping import TensorScalarMapping
 from .tensor_slice_selection import TensorSliceSelection
+from .tensor_slice_value_range import TensorSliceValueRange
 from .tensor_view_fit import TensorViewFit
+from .text_log_saved_filters import TextLogSavedFilters
 from .time_axis import TimeAxis
+from .time_offset import TimeOffset
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
 from .viewport_blueprint import ViewportBlueprint
@@ -32,26 +44,38 @@ from .visualizer_overrides import VisualizerOverrides
 
 __all__ = [
     "Background",
+    "CameraController3D",
+    "CameraPath3D",
     "ContainerBlueprint",
     "DataframeQuery",
     "EntityBehavior",
     "EyeControls3D",
+    "FollowLatest",
     "ForceCenter",
     "ForceCollisionRadius",
     "ForceLink",
     "ForceManyBody",
     "ForcePosition",
+    "GraphLayoutAlgorithm",
+    "GridOverlay2D",
+    "Lighting3D",
     "LineGrid3D",
     "MapBackground",
     "MapZoom",
     "NearClipPlane",
     "PanelBlueprint",
     "PlotLegend",
+    "PlotStyle",
+    "PointSizing",
     "ScalarAxis",
+    "SectionPlane3D",
     "TensorScalarMapping",
     "TensorSliceSelection",
+    "TensorSliceValueRange",
     "TensorViewFit",
+    "TextLogSavedFilters",
     "TimeAxis",
+    "TimeOffset",
     "ViewBlueprint",
     "ViewContents",
     "ViewportBlueprint",
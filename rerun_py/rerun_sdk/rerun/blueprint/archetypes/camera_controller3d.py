@@ -0,0 +1,114 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_controller3d.fbs".
+
+# You can extend this class by creating a "CameraController3DExt" class in "camera_controller3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["CameraController3D"]
+
+
+@define(str=False, repr=False, init=False)
+class CameraController3D(Archetype):
+    """
+    **Archetype**: The camera controller that moves the eye of a 3D view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, controller: datatypes.Utf8Like | None = None) -> None:
+        """
+        Create a new instance of the CameraController3D archetype.
+
+        Parameters
+        ----------
+        controller:
+            Identifier of the controller, e.g. `orbit` or `fly`.
+
+            If not set, the eye controls of the view are used.
+
+        """
+
+        # You can define your own __init__ function as a member of CameraController3DExt in camera_controller3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(controller=controller)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            controller=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CameraController3D:
+        """Produce an empty CameraController3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        controller: datatypes.Utf8Like | None = None,
+    ) -> CameraController3D:
+        """
+        Update only some specific fields of a `CameraController3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        controller:
+            Identifier of the controller, e.g. `orbit` or `fly`.
+
+            If not set, the eye controls of the view are used.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "controller": controller,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CameraController3D:
+        """Clear all the fields of a `CameraController3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    controller: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # Identifier of the controller, e.g. `orbit` or `fly`.
+    #
+    # If not set, the eye controls of the view are used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
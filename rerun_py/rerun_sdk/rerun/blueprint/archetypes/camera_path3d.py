@@ -0,0 +1,203 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path3d.fbs".
+
+# You can extend this class by creating a "CameraPath3DExt" class in "camera_path3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["CameraPath3D"]
+
+
+@define(str=False, repr=False, init=False)
+class CameraPath3D(Archetype):
+    """
+    **Archetype**: Keyframes of the camera of a 3D view, to fly through the scene along them.
+
+    All fields have one instance per keyframe, sorted by time.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        times: datatypes.Float64ArrayLike | None = None,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        rotations: datatypes.QuaternionArrayLike | None = None,
+        fields_of_view: datatypes.Float64ArrayLike | None = None,
+        timeline: datatypes.Utf8Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the CameraPath3D archetype.
+
+        Parameters
+        ----------
+        times:
+            Time of each keyframe.
+
+            On the timeline if one is set, otherwise in seconds since the start of the playback.
+        positions:
+            Position of the camera at each keyframe, in the space of the view.
+        rotations:
+            Rotation of the camera at each keyframe, in the space of the view.
+        fields_of_view:
+            Vertical field of view of the camera at each keyframe, in radians.
+
+            NaN for an orthographic camera.
+        timeline:
+            The timeline the keyframe times are on.
+
+            If not set, the keyframe times are wall-clock seconds since the start of the playback.
+
+        """
+
+        # You can define your own __init__ function as a member of CameraPath3DExt in camera_path3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                times=times, positions=positions, rotations=rotations, fields_of_view=fields_of_view, timeline=timeline
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            times=None,
+            positions=None,
+            rotations=None,
+            fields_of_view=None,
+            timeline=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CameraPath3D:
+        """Produce an empty CameraPath3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        times: datatypes.Float64ArrayLike | None = None,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        rotations: datatypes.QuaternionArrayLike | None = None,
+        fields_of_view: datatypes.Float64ArrayLike | None = None,
+        timeline: datatypes.Utf8Like | None = None,
+    ) -> CameraPath3D:
+        """
+        Update only some specific fields of a `CameraPath3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        times:
+            Time of each keyframe.
+
+            On the timeline if one is set, otherwise in seconds since the start of the playback.
+        positions:
+            Position of the camera at each keyframe, in the space of the view.
+        rotations:
+            Rotation of the camera at each keyframe, in the space of the view.
+        fields_of_view:
+            Vertical field of view of the camera at each keyframe, in radians.
+
+            NaN for an orthographic camera.
+        timeline:
+            The timeline the keyframe times are on.
+
+            If not set, the keyframe times are wall-clock seconds since the start of the playback.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "times": times,
+                "positions": positions,
+                "rotations": rotations,
+                "fields_of_view": fields_of_view,
+                "timeline": timeline,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CameraPath3D:
+        """Clear all the fields of a `CameraPath3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    times: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Time of each keyframe.
+    #
+    # On the timeline if one is set, otherwise in seconds since the start of the playback.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    positions: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # Position of the camera at each keyframe, in the space of the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    rotations: components.RotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # Rotation of the camera at each keyframe, in the space of the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    fields_of_view: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Vertical field of view of the camera at each keyframe, in radians.
+    #
+    # NaN for an orthographic camera.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    timeline: blueprint_components.TimelineNameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimelineNameBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline the keyframe times are on.
+    #
+    # If not set, the keyframe times are wall-clock seconds since the start of the playback.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,133 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/follow_latest.fbs".
+
+# You can extend this class by creating a "FollowLatestExt" class in "follow_latest_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["FollowLatest"]
+
+
+@define(str=False, repr=False, init=False)
+class FollowLatest(Archetype):
+    """
+    **Archetype**: Whether a view always shows the newest data on the selected timeline, wherever the time cursor is.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any, *, enabled: datatypes.BoolLike | None = None, camera_entity: datatypes.EntityPathLike | None = None
+    ) -> None:
+        """
+        Create a new instance of the FollowLatest archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether the view follows the latest data.
+
+            Defaults to false.
+        camera_entity:
+            The entity the eye of a 3D view tracks while following the latest data.
+
+        """
+
+        # You can define your own __init__ function as a member of FollowLatestExt in follow_latest_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(enabled=enabled, camera_entity=camera_entity)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            camera_entity=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> FollowLatest:
+        """Produce an empty FollowLatest, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        camera_entity: datatypes.EntityPathLike | None = None,
+    ) -> FollowLatest:
+        """
+        Update only some specific fields of a `FollowLatest`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether the view follows the latest data.
+
+            Defaults to false.
+        camera_entity:
+            The entity the eye of a 3D view tracks while following the latest data.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "camera_entity": camera_entity,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> FollowLatest:
+        """Clear all the fields of a `FollowLatest`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the view follows the latest data.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    camera_entity: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The entity the eye of a 3D view tracks while following the latest data.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
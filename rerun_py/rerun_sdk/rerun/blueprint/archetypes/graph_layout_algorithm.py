@@ -0,0 +1,114 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/graph_layout_algorithm.fbs".
+
+# You can extend this class by creating a "GraphLayoutAlgorithmExt" class in "graph_layout_algorithm_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["GraphLayoutAlgorithm"]
+
+
+@define(str=False, repr=False, init=False)
+class GraphLayoutAlgorithm(Archetype):
+    """
+    **Archetype**: The algorithm that lays out the nodes of a graph view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, algorithm: datatypes.Utf8Like | None = None) -> None:
+        """
+        Create a new instance of the GraphLayoutAlgorithm archetype.
+
+        Parameters
+        ----------
+        algorithm:
+            Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+
+            If not set, the force-based layout is used.
+
+        """
+
+        # You can define your own __init__ function as a member of GraphLayoutAlgorithmExt in graph_layout_algorithm_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(algorithm=algorithm)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            algorithm=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GraphLayoutAlgorithm:
+        """Produce an empty GraphLayoutAlgorithm, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        algorithm: datatypes.Utf8Like | None = None,
+    ) -> GraphLayoutAlgorithm:
+        """
+        Update only some specific fields of a `GraphLayoutAlgorithm`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        algorithm:
+            Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+
+            If not set, the force-based layout is used.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "algorithm": algorithm,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GraphLayoutAlgorithm:
+        """Clear all the fields of a `GraphLayoutAlgorithm`."""
+        return cls.from_fields(clear_unset=True)
+
+    algorithm: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # Identifier of the algorithm, e.g. `layered`, `circular` or `fixed`.
+    #
+    # If not set, the force-based layout is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,217 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/grid_overlay2d.fbs".
+
+# You can extend this class by creating a "GridOverlay2DExt" class in "grid_overlay2d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["GridOverlay2D"]
+
+
+@define(str=False, repr=False, init=False)
+class GridOverlay2D(Archetype):
+    """
+    **Archetype**: Rulers along the edges and a grid over the contents of a 2D view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        show_grid: datatypes.BoolLike | None = None,
+        show_rulers: datatypes.BoolLike | None = None,
+        spacing: datatypes.Float32Like | None = None,
+        subdivisions: datatypes.Float64Like | None = None,
+        snap_to_grid: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the GridOverlay2D archetype.
+
+        Parameters
+        ----------
+        show_grid:
+            Whether the grid is drawn.
+
+            Defaults to false.
+        show_rulers:
+            Whether the rulers are drawn.
+
+            Defaults to false.
+        spacing:
+            Distance between major lines in scene units.
+
+            If not set, it is picked for the zoom level.
+        subdivisions:
+            Number of minor cells each major cell is divided into.
+
+            Defaults to 5.
+        snap_to_grid:
+            Whether the cursor snaps to the nearest intersection of minor lines.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of GridOverlay2DExt in grid_overlay2d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                show_grid=show_grid,
+                show_rulers=show_rulers,
+                spacing=spacing,
+                subdivisions=subdivisions,
+                snap_to_grid=snap_to_grid,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            show_grid=None,
+            show_rulers=None,
+            spacing=None,
+            subdivisions=None,
+            snap_to_grid=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GridOverlay2D:
+        """Produce an empty GridOverlay2D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        show_grid: datatypes.BoolLike | None = None,
+        show_rulers: datatypes.BoolLike | None = None,
+        spacing: datatypes.Float32Like | None = None,
+        subdivisions: datatypes.Float64Like | None = None,
+        snap_to_grid: datatypes.BoolLike | None = None,
+    ) -> GridOverlay2D:
+        """
+        Update only some specific fields of a `GridOverlay2D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        show_grid:
+            Whether the grid is drawn.
+
+            Defaults to false.
+        show_rulers:
+            Whether the rulers are drawn.
+
+            Defaults to false.
+        spacing:
+            Distance between major lines in scene units.
+
+            If not set, it is picked for the zoom level.
+        subdivisions:
+            Number of minor cells each major cell is divided into.
+
+            Defaults to 5.
+        snap_to_grid:
+            Whether the cursor snaps to the nearest intersection of minor lines.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "show_grid": show_grid,
+                "show_rulers": show_rulers,
+                "spacing": spacing,
+                "subdivisions": subdivisions,
+                "snap_to_grid": snap_to_grid,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GridOverlay2D:
+        """Clear all the fields of a `GridOverlay2D`."""
+        return cls.from_fields(clear_unset=True)
+
+    show_grid: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the grid is drawn.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    show_rulers: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the rulers are drawn.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    spacing: blueprint_components.GridSpacingBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.GridSpacingBatch._converter,  # type: ignore[misc]
+    )
+    # Distance between major lines in scene units.
+    #
+    # If not set, it is picked for the zoom level.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    subdivisions: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Number of minor cells each major cell is divided into.
+    #
+    # Defaults to 5.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    snap_to_grid: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the cursor snaps to the nearest intersection of minor lines.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
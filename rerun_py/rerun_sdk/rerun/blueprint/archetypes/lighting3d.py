@@ -0,0 +1,183 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/lighting3d.fbs".
+
+# You can extend this class by creating a "Lighting3DExt" class in "lighting3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["Lighting3D"]
+
+
+@define(str=False, repr=False, init=False)
+class Lighting3D(Archetype):
+    """
+    **Archetype**: The lights meshes are shaded with in a 3D view.
+
+    Anything not set uses the default lighting of the viewer.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        key_light_direction: datatypes.Vec3DLike | None = None,
+        key_light_intensity: datatypes.Float64Like | None = None,
+        ambient_light: datatypes.Float64Like | None = None,
+        unlit: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the Lighting3D archetype.
+
+        Parameters
+        ----------
+        key_light_direction:
+            Direction towards the key light, in the space of the view.
+
+            Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+        key_light_intensity:
+            Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+        ambient_light:
+            Brightness of the light reaching meshes from all sides.
+        unlit:
+            Draws meshes in their albedo color, ignoring all lights.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of Lighting3DExt in lighting3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                key_light_direction=key_light_direction,
+                key_light_intensity=key_light_intensity,
+                ambient_light=ambient_light,
+                unlit=unlit,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            key_light_direction=None,
+            key_light_intensity=None,
+            ambient_light=None,
+            unlit=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Lighting3D:
+        """Produce an empty Lighting3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        key_light_direction: datatypes.Vec3DLike | None = None,
+        key_light_intensity: datatypes.Float64Like | None = None,
+        ambient_light: datatypes.Float64Like | None = None,
+        unlit: datatypes.BoolLike | None = None,
+    ) -> Lighting3D:
+        """
+        Update only some specific fields of a `Lighting3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        key_light_direction:
+            Direction towards the key light, in the space of the view.
+
+            Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+        key_light_intensity:
+            Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+        ambient_light:
+            Brightness of the light reaching meshes from all sides.
+        unlit:
+            Draws meshes in their albedo color, ignoring all lights.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "key_light_direction": key_light_direction,
+                "key_light_intensity": key_light_intensity,
+                "ambient_light": ambient_light,
+                "unlit": unlit,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Lighting3D:
+        """Clear all the fields of a `Lighting3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    key_light_direction: components.Vector3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Vector3DBatch._converter,  # type: ignore[misc]
+    )
+    # Direction towards the key light, in the space of the view.
+    #
+    # Meshes are also lit from the opposite direction, at half the intensity, so that they are shaded on all sides.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    key_light_intensity: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Brightness of the key light, where 1 lights surfaces facing it in their full albedo color.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    ambient_light: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Brightness of the light reaching meshes from all sides.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    unlit: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Draws meshes in their albedo color, ignoring all lights.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
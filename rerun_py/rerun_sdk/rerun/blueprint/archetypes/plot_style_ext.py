@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from ...error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from .. import components as blueprint_components
+
+
+class PlotStyleExt:
+    """Extension for [PlotStyle][rerun.blueprint.archetypes.PlotStyle]."""
+
+    def __init__(self: Any, mode: blueprint_components.PlotModeLike | None = None) -> None:
+        """
+        Create a new instance of the PlotStyle archetype.
+
+        Parameters
+        ----------
+        mode:
+            Whether the series are drawn on their own, or stacked to show their composition over time.
+
+            Defaults to lines.
+
+        """
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(mode=mode)
+            return
+        self.__attrs_clear__()
@@ -0,0 +1,155 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/point_sizing.fbs".
+
+# You can extend this class by creating a "PointSizingExt" class in "point_sizing_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["PointSizing"]
+
+
+@define(str=False, repr=False, init=False)
+class PointSizing(Archetype):
+    """
+    **Archetype**: How the radii of points are interpreted and limited.
+
+    Set per view, and overridden per entity.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        screen_space: datatypes.BoolLike | None = None,
+        min_radius: datatypes.Float32Like | None = None,
+        max_radius: datatypes.Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the PointSizing archetype.
+
+        Parameters
+        ----------
+        screen_space:
+            Whether radii are in ui points rather than in scene units, like meters.
+
+            Defaults to false.
+        min_radius:
+            Points are drawn with at least this radius, in ui points.
+        max_radius:
+            Points are drawn with at most this radius, in ui points.
+
+        """
+
+        # You can define your own __init__ function as a member of PointSizingExt in point_sizing_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(screen_space=screen_space, min_radius=min_radius, max_radius=max_radius)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            screen_space=None,
+            min_radius=None,
+            max_radius=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> PointSizing:
+        """Produce an empty PointSizing, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        screen_space: datatypes.BoolLike | None = None,
+        min_radius: datatypes.Float32Like | None = None,
+        max_radius: datatypes.Float32Like | None = None,
+    ) -> PointSizing:
+        """
+        Update only some specific fields of a `PointSizing`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        screen_space:
+            Whether radii are in ui points rather than in scene units, like meters.
+
+            Defaults to false.
+        min_radius:
+            Points are drawn with at least this radius, in ui points.
+        max_radius:
+            Points are drawn with at most this radius, in ui points.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "screen_space": screen_space,
+                "min_radius": min_radius,
+                "max_radius": max_radius,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> PointSizing:
+        """Clear all the fields of a `PointSizing`."""
+        return cls.from_fields(clear_unset=True)
+
+    screen_space: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether radii are in ui points rather than in scene units, like meters.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    min_radius: components.RadiusBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RadiusBatch._converter,  # type: ignore[misc]
+    )
+    # Points are drawn with at least this radius, in ui points.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    max_radius: components.RadiusBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RadiusBatch._converter,  # type: ignore[misc]
+    )
+    # Points are drawn with at most this radius, in ui points.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -28,7 +28,12 @@ class ScalarAxis(Archetype):
     """
 
     def __init__(
-        self: Any, *, range: datatypes.Range1DLike | None = None, zoom_lock: datatypes.BoolLike | None = None
+        self: Any,
+        *,
+        range: datatypes.Range1DLike | None = None,
+        zoom_lock: datatypes.BoolLike | None = None,
+        log_scale: datatypes.BoolLike | None = None,
+        secondary_log_scale: datatypes.BoolLike | None = None,
     ) -> None:
         """
         Create a new instance of the ScalarAxis archetype.
@@ -41,12 +46,22 @@ class ScalarAxis(Archetype):
             If unset, the range well be automatically determined based on the queried data.
         zoom_lock:
             If enabled, the Y axis range will remain locked to the specified range when zooming.
+        log_scale:
+            If enabled, the Y axis uses a logarithmic scale.
+
+            Values that aren't positive can't be shown on a logarithmic scale and are left out.
+        secondary_log_scale:
+            If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+
+            Only has an effect if some series are plotted against the secondary axis.
 
         """
 
         # You can define your own __init__ function as a member of ScalarAxisExt in scalar_axis_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(range=range, zoom_lock=zoom_lock)
+            self.__attrs_init__(
+                range=range, zoom_lock=zoom_lock, log_scale=log_scale, secondary_log_scale=secondary_log_scale
+            )
             return
         self.__attrs_clear__()
 
@@ -55,6 +70,8 @@ class ScalarAxis(Archetype):
         self.__attrs_init__(
             range=None,
             zoom_lock=None,
+            log_scale=None,
+            secondary_log_scale=None,
         )
 
     @classmethod
@@ -71,6 +88,8 @@ class ScalarAxis(Archetype):
         clear_unset: bool = False,
         range: datatypes.Range1DLike | None = None,
         zoom_lock: datatypes.BoolLike | None = None,
+        log_scale: datatypes.BoolLike | None = None,
+        secondary_log_scale: datatypes.BoolLike | None = None,
     ) -> ScalarAxis:
         """
         Update only some specific fields of a `ScalarAxis`.
@@ -85,6 +104,14 @@ class ScalarAxis(Archetype):
             If unset, the range well be automatically determined based on the queried data.
         zoom_lock:
             If enabled, the Y axis range will remain locked to the specified range when zooming.
+        log_scale:
+            If enabled, the Y axis uses a logarithmic scale.
+
+            Values that aren't positive can't be shown on a logarithmic scale and are left out.
+        secondary_log_scale:
+            If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+
+            Only has an effect if some series are plotted against the secondary axis.
 
         """
 
@@ -93,6 +120,8 @@ class ScalarAxis(Archetype):
             kwargs = {
                 "range": range,
                 "zoom_lock": zoom_lock,
+                "log_scale": log_scale,
+                "secondary_log_scale": secondary_log_scale,
             }
 
             if clear_unset:
@@ -129,5 +158,27 @@ class ScalarAxis(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    log_scale: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # If enabled, the Y axis uses a logarithmic scale.
+    #
+    # Values that aren't positive can't be shown on a logarithmic scale and are left out.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    secondary_log_scale: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # If enabled, the secondary Y axis on the right-hand side uses a logarithmic scale.
+    #
+    # Only has an effect if some series are plotted against the secondary axis.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,159 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_plane3d.fbs".
+
+# You can extend this class by creating a "SectionPlane3DExt" class in "section_plane3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["SectionPlane3D"]
+
+
+@define(str=False, repr=False, init=False)
+class SectionPlane3D(Archetype):
+    """
+    **Archetype**: A plane cutting away part of a 3D view, to look inside of meshes and dense point clouds.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        position: datatypes.Vec3DLike | None = None,
+        normal: datatypes.Vec3DLike | None = None,
+        show_contour: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the SectionPlane3D archetype.
+
+        Parameters
+        ----------
+        position:
+            A point on the plane, in the space of the view.
+        normal:
+            Normal of the plane.
+
+            Everything on the side the normal points to is kept.
+        show_contour:
+            Whether meshes are colored where they are cut.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of SectionPlane3DExt in section_plane3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(position=position, normal=normal, show_contour=show_contour)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            position=None,
+            normal=None,
+            show_contour=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> SectionPlane3D:
+        """Produce an empty SectionPlane3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        position: datatypes.Vec3DLike | None = None,
+        normal: datatypes.Vec3DLike | None = None,
+        show_contour: datatypes.BoolLike | None = None,
+    ) -> SectionPlane3D:
+        """
+        Update only some specific fields of a `SectionPlane3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        position:
+            A point on the plane, in the space of the view.
+        normal:
+            Normal of the plane.
+
+            Everything on the side the normal points to is kept.
+        show_contour:
+            Whether meshes are colored where they are cut.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "position": position,
+                "normal": normal,
+                "show_contour": show_contour,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> SectionPlane3D:
+        """Clear all the fields of a `SectionPlane3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    position: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # A point on the plane, in the space of the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    normal: components.Vector3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Vector3DBatch._converter,  # type: ignore[misc]
+    )
+    # Normal of the plane.
+    #
+    # Everything on the side the normal points to is kept.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    show_contour: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether meshes are colored where they are cut.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,117 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_slice_value_range.fbs".
+
+# You can extend this class by creating a "TensorSliceValueRangeExt" class in "tensor_slice_value_range_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from ... import datatypes
+
+__all__ = ["TensorSliceValueRange"]
+
+
+@define(str=False, repr=False, init=False)
+class TensorSliceValueRange(Archetype):
+    """
+    **Archetype**: Which values of a tensor the colormap of a tensor view spans.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, fit_to_slice: datatypes.BoolLike | None = None) -> None:
+        """
+        Create a new instance of the TensorSliceValueRange archetype.
+
+        Parameters
+        ----------
+        fit_to_slice:
+            Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of TensorSliceValueRangeExt in tensor_slice_value_range_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(fit_to_slice=fit_to_slice)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            fit_to_slice=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TensorSliceValueRange:
+        """Produce an empty TensorSliceValueRange, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        fit_to_slice: datatypes.BoolLike | None = None,
+    ) -> TensorSliceValueRange:
+        """
+        Update only some specific fields of a `TensorSliceValueRange`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        fit_to_slice:
+            Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "fit_to_slice": fit_to_slice,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TensorSliceValueRange:
+        """Clear all the fields of a `TensorSliceValueRange`."""
+        return cls.from_fields(clear_unset=True)
+
+    fit_to_slice: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the colormap spans the values of the shown slice rather than of the whole tensor.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,185 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_saved_filters.fbs".
+
+# You can extend this class by creating a "TextLogSavedFiltersExt" class in "text_log_saved_filters_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogSavedFilters"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogSavedFilters(Archetype):
+    """
+    **Archetype**: Named filters of a text log view, to apply them again later.
+
+    All fields have one instance per saved filter, in the order they were saved.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        names: datatypes.Utf8ArrayLike | None = None,
+        patterns: datatypes.Utf8ArrayLike | None = None,
+        is_regex: datatypes.BoolArrayLike | None = None,
+        min_levels: datatypes.Utf8ArrayLike | None = None,
+        max_levels: datatypes.Utf8ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TextLogSavedFilters archetype.
+
+        Parameters
+        ----------
+        names:
+            The name the user gave each filter.
+        patterns:
+            The text each filter matches log messages against.
+        is_regex:
+            Whether each pattern is a regular expression rather than plain text.
+        min_levels:
+            The least severe level each filter shows.
+        max_levels:
+            The most severe level each filter shows.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogSavedFiltersExt in text_log_saved_filters_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                names=names, patterns=patterns, is_regex=is_regex, min_levels=min_levels, max_levels=max_levels
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            names=None,
+            patterns=None,
+            is_regex=None,
+            min_levels=None,
+            max_levels=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogSavedFilters:
+        """Produce an empty TextLogSavedFilters, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        names: datatypes.Utf8ArrayLike | None = None,
+        patterns: datatypes.Utf8ArrayLike | None = None,
+        is_regex: datatypes.BoolArrayLike | None = None,
+        min_levels: datatypes.Utf8ArrayLike | None = None,
+        max_levels: datatypes.Utf8ArrayLike | None = None,
+    ) -> TextLogSavedFilters:
+        """
+        Update only some specific fields of a `TextLogSavedFilters`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        names:
+            The name the user gave each filter.
+        patterns:
+            The text each filter matches log messages against.
+        is_regex:
+            Whether each pattern is a regular expression rather than plain text.
+        min_levels:
+            The least severe level each filter shows.
+        max_levels:
+            The most severe level each filter shows.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "names": names,
+                "patterns": patterns,
+                "is_regex": is_regex,
+                "min_levels": min_levels,
+                "max_levels": max_levels,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogSavedFilters:
+        """Clear all the fields of a `TextLogSavedFilters`."""
+        return cls.from_fields(clear_unset=True)
+
+    names: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # The name the user gave each filter.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    patterns: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # The text each filter matches log messages against.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    is_regex: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether each pattern is a regular expression rather than plain text.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    min_levels: components.TextLogLevelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogLevelBatch._converter,  # type: ignore[misc]
+    )
+    # The least severe level each filter shows.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    max_levels: components.TextLogLevelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogLevelBatch._converter,  # type: ignore[misc]
+    )
+    # The most severe level each filter shows.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,114 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/time_offset.fbs".
+
+# You can extend this class by creating a "TimeOffsetExt" class in "time_offset_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TimeOffset"]
+
+
+@define(str=False, repr=False, init=False)
+class TimeOffset(Archetype):
+    """
+    **Archetype**: Offset of the time a view is shown at, relative to the time cursor.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, offset: datatypes.Float64Like | None = None) -> None:
+        """
+        Create a new instance of the TimeOffset archetype.
+
+        Parameters
+        ----------
+        offset:
+            In seconds on temporal timelines, and in steps on sequence timelines.
+
+            Negative to show the past. Defaults to zero.
+
+        """
+
+        # You can define your own __init__ function as a member of TimeOffsetExt in time_offset_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(offset=offset)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            offset=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TimeOffset:
+        """Produce an empty TimeOffset, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        offset: datatypes.Float64Like | None = None,
+    ) -> TimeOffset:
+        """
+        Update only some specific fields of a `TimeOffset`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        offset:
+            In seconds on temporal timelines, and in steps on sequence timelines.
+
+            Negative to show the past. Defaults to zero.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "offset": offset,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TimeOffset:
+        """Clear all the fields of a `TimeOffset`."""
+        return cls.from_fields(clear_unset=True)
+
+    offset: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # In seconds on temporal timelines, and in steps on sequence timelines.
+    #
+    # Negative to show the past. Defaults to zero.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -26,6 +26,7 @@ from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatc
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
+from .plot_mode import PlotMode, PlotModeArrayLike, PlotModeBatch, PlotModeLike
 from .query_expression import QueryExpression, QueryExpressionBatch
 from .root_container import RootContainer, RootContainerBatch
 from .row_share import RowShare, RowShareBatch
@@ -105,6 +106,10 @@ __all__ = [
     "PanelStateArrayLike",
     "PanelStateBatch",
     "PanelStateLike",
+    "PlotMode",
+    "PlotModeArrayLike",
+    "PlotModeBatch",
+    "PlotModeLike",
     "QueryExpression",
     "QueryExpressionBatch",
     "RootContainer",
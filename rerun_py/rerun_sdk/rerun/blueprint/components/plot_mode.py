@@ -0,0 +1,100 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/plot_mode.fbs".
+
+# You can extend this class by creating a "PlotModeExt" class in "plot_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["PlotMode", "PlotModeArrayLike", "PlotModeBatch", "PlotModeLike"]
+
+
+from enum import Enum
+
+
+class PlotMode(Enum):
+    """**Component**: How the series of a time series view are drawn."""
+
+    Lines = 1
+    """Every series is drawn on its own, as lines or points."""
+
+    StackedArea = 2
+    """
+    The series are stacked on top of each other as filled areas.
+
+    Values are averaged per time bucket.
+    """
+
+    GroupedBars = 3
+    """
+    The series are drawn as bars next to each other, one group per time bucket.
+
+    Values are averaged per time bucket.
+    """
+
+    StackedBars = 4
+    """
+    The series are drawn as bars stacked on top of each other, one stack per time bucket.
+
+    Values are averaged per time bucket.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | PlotMode) -> PlotMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, PlotMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+PlotModeLike = Union[
+    PlotMode,
+    Literal[
+        "GroupedBars",
+        "Lines",
+        "StackedArea",
+        "StackedBars",
+        "groupedbars",
+        "lines",
+        "stackedarea",
+        "stackedbars",
+    ],
+    int,
+]
+PlotModeArrayLike = Union[PlotModeLike, Sequence[PlotModeLike]]
+
+
+class PlotModeBatch(BaseBatch[PlotModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.PlotMode"
+
+    @staticmethod
+    def _native_to_pa_array(data: PlotModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (PlotMode, int, str)):
+            data = [data]
+
+        pa_data = [PlotMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -107,6 +107,7 @@ class TimeSeriesView(View):
         axis_x: blueprint_archetypes.TimeAxis | None = None,
         axis_y: blueprint_archetypes.ScalarAxis | None = None,
         plot_legend: blueprint_archetypes.PlotLegend | blueprint_components.Corner2D | None = None,
+        plot_style: blueprint_archetypes.PlotStyle | blueprint_components.PlotModeLike | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -153,6 +154,8 @@ class TimeSeriesView(View):
             Configures the vertical axis of the plot.
         plot_legend:
             Configures the legend of the plot.
+        plot_style:
+            Configures how the series are drawn, e.g. stacked to show their composition over time.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -177,6 +180,11 @@ class TimeSeriesView(View):
                 plot_legend = blueprint_archetypes.PlotLegend(plot_legend)
             properties["PlotLegend"] = plot_legend
 
+        if plot_style is not None:
+            if not isinstance(plot_style, blueprint_archetypes.PlotStyle):
+                plot_style = blueprint_archetypes.PlotStyle(plot_style)
+            properties["PlotStyle"] = plot_style
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
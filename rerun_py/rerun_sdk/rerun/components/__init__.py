@@ -34,6 +34,7 @@ from .draw_order import DrawOrder, DrawOrderBatch
 from .entity_path import EntityPath, EntityPathBatch
 from .fill_mode import FillMode, FillModeArrayLike, FillModeBatch, FillModeLike
 from .fill_ratio import FillRatio, FillRatioBatch
+from .font_size import FontSize, FontSizeBatch
 from .gamma_correction import GammaCorrection, GammaCorrectionBatch
 from .geo_line_string import GeoLineString, GeoLineStringArrayLike, GeoLineStringBatch, GeoLineStringLike
 from .graph_edge import GraphEdge, GraphEdgeBatch
@@ -80,6 +81,7 @@ from .rotation_quat import RotationQuat, RotationQuatBatch
 from .scalar import Scalar, ScalarBatch
 from .scale3d import Scale3D, Scale3DBatch
 from .schema_id import SchemaId, SchemaIdBatch
+from .series_secondary_axis import SeriesSecondaryAxis, SeriesSecondaryAxisBatch
 from .series_visible import SeriesVisible, SeriesVisibleBatch
 from .show_labels import ShowLabels, ShowLabelsBatch
 from .stroke_width import StrokeWidth, StrokeWidthBatch
@@ -89,8 +91,15 @@ from .tensor_height_dimension import TensorHeightDimension, TensorHeightDimensio
 from .tensor_width_dimension import TensorWidthDimension, TensorWidthDimensionBatch
 from .texcoord2d import Texcoord2D, Texcoord2DBatch
 from .text import Text, TextBatch
+from .text_anchor import TextAnchor, TextAnchorArrayLike, TextAnchorBatch, TextAnchorLike
 from .text_log_level import TextLogLevel, TextLogLevelBatch
 from .timestamp import Timestamp, TimestampBatch
+from .transform_interpolation import (
+    TransformInterpolation,
+    TransformInterpolationArrayLike,
+    TransformInterpolationBatch,
+    TransformInterpolationLike,
+)
 from .transform_mat3x3 import TransformMat3x3, TransformMat3x3Batch
 from .transform_relation import (
     TransformRelation,
@@ -154,6 +163,8 @@ __all__ = [
     "FillModeLike",
     "FillRatio",
     "FillRatioBatch",
+    "FontSize",
+    "FontSizeBatch",
     "GammaCorrection",
     "GammaCorrectionBatch",
     "GeoLineString",
@@ -250,6 +261,8 @@ __all__ = [
     "Scale3DBatch",
     "SchemaId",
     "SchemaIdBatch",
+    "SeriesSecondaryAxis",
+    "SeriesSecondaryAxisBatch",
     "SeriesVisible",
     "SeriesVisibleBatch",
     "ShowLabels",
@@ -267,11 +280,19 @@ __all__ = [
     "Texcoord2D",
     "Texcoord2DBatch",
     "Text",
+    "TextAnchor",
+    "TextAnchorArrayLike",
+    "TextAnchorBatch",
+    "TextAnchorLike",
     "TextBatch",
     "TextLogLevel",
     "TextLogLevelBatch",
     "Timestamp",
     "TimestampBatch",
+    "TransformInterpolation",
+    "TransformInterpolationArrayLike",
+    "TransformInterpolationBatch",
+    "TransformInterpolationLike",
     "TransformMat3x3",
     "TransformMat3x3Batch",
     "TransformRelation",
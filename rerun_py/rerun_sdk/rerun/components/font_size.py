@@ -0,0 +1,40 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/font_size.fbs".
+
+# You can extend this class by creating a "FontSizeExt" class in "font_size_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["FontSize", "FontSizeBatch"]
+
+
+class FontSize(datatypes.Float32, ComponentMixin):
+    """
+    **Component**: The size of text, measured as the height of a line of text.
+
+    Internally, positive values indicate scene units, whereas negative values
+    are interpreted as UI points.
+
+    UI points are independent of zooming in Views, but are sensitive to the application UI scaling.
+    at 100% UI scaling, UI points are equal to pixels
+    The Viewer's UI scaling defaults to the OS scaling which typically is 100% for full HD screens and 200% for 4k screens.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of FontSizeExt in font_size_ext.py
+
+    # Note: there are no fields here because FontSize delegates to datatypes.Float32
+
+
+class FontSizeBatch(datatypes.Float32Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.FontSize"
+
+
+# This is patched in late to avoid circular dependencies.
+FontSize._BATCH_TYPE = FontSizeBatch  # type: ignore[assignment]
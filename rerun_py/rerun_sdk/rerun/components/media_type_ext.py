@@ -21,6 +21,23 @@ class MediaTypeExt:
     <https://www.iana.org/assignments/media-types/text/markdown>
     """
 
+    JSON: MediaType = None  # type: ignore[assignment]
+    """
+    [JSON](https://en.wikipedia.org/wiki/JSON): `application/json`.
+
+    <https://www.iana.org/assignments/media-types/application/json>
+    """
+
+    # --------------------------
+    # Documents:
+
+    PDF: MediaType = None  # type: ignore[assignment]
+    """
+    [PDF document](https://en.wikipedia.org/wiki/PDF): `application/pdf`.
+
+    <https://www.iana.org/assignments/media-types/application/pdf>
+    """
+
     # --------------------------
     # Images:
 
@@ -82,6 +99,9 @@ class MediaTypeExt:
     def deferred_patch_class(cls: Any) -> None:
         cls.TEXT = cls("text/plain")
         cls.MARKDOWN = cls("text/markdown")
+        cls.JSON = cls("application/json")
+
+        cls.PDF = cls("application/pdf")
 
         cls.JPEG = cls("image/jpeg")
         cls.PNG = cls("image/png")
@@ -99,6 +119,16 @@ class MediaTypeExt:
 
         ext = Path(path).suffix.lower()
 
+        # Text & documents
+        if ext == ".txt":
+            return MediaType.TEXT
+        elif ext == ".md":
+            return MediaType.MARKDOWN
+        elif ext == ".json":
+            return MediaType.JSON
+        elif ext == ".pdf":
+            return MediaType.PDF
+
         # Images
         if ext == ".jpg" or ext == ".jpeg":
             return MediaType.JPEG
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/series_secondary_axis.fbs".
+
+# You can extend this class by creating a "SeriesSecondaryAxisExt" class in "series_secondary_axis_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["SeriesSecondaryAxis", "SeriesSecondaryAxisBatch"]
+
+
+class SeriesSecondaryAxis(datatypes.Bool, ComponentMixin):
+    """
+    **Component**: Whether a time series is plotted against the secondary Y axis, on the right-hand side of the plot.
+
+    Useful for series whose values differ by orders of magnitude from the others in the same plot.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of SeriesSecondaryAxisExt in series_secondary_axis_ext.py
+
+    # Note: there are no fields here because SeriesSecondaryAxis delegates to datatypes.Bool
+
+
+class SeriesSecondaryAxisBatch(datatypes.BoolBatch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.components.SeriesSecondaryAxis"
+
+
+# This is patched in late to avoid circular dependencies.
+SeriesSecondaryAxis._BATCH_TYPE = SeriesSecondaryAxisBatch  # type: ignore[assignment]
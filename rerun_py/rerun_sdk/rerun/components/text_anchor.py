@@ -0,0 +1,181 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/text_anchor.fbs".
+
+# You can extend this class by creating a "TextAnchorExt" class in "text_anchor_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["TextAnchor", "TextAnchorArrayLike", "TextAnchorBatch", "TextAnchorLike"]
+
+
+from enum import Enum
+
+
+class TextAnchor(Enum):
+    """
+    **Component**: Which point of a text label is placed at its position.
+
+    E.g. with [`components.TextAnchor.Bottom`][rerun.components.TextAnchor.Bottom], the text is centered horizontally above its position.
+    """
+
+    Center = 1
+    """The center of the text is placed at the position."""
+
+    Top = 2
+    """The middle of the top edge of the text is placed at the position."""
+
+    Bottom = 3
+    """The middle of the bottom edge of the text is placed at the position."""
+
+    Left = 4
+    """The middle of the left edge of the text is placed at the position."""
+
+    Right = 5
+    """The middle of the right edge of the text is placed at the position."""
+
+    TopLeft = 6
+    """The top left corner of the text is placed at the position."""
+
+    TopRight = 7
+    """The top right corner of the text is placed at the position."""
+
+    BottomLeft = 8
+    """The bottom left corner of the text is placed at the position."""
+
+    BottomRight = 9
+    """The bottom right corner of the text is placed at the position."""
+
+    @classmethod
+    def auto(cls, val: str | int | TextAnchor) -> TextAnchor:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TextAnchor):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TextAnchorLike = Union[
+    TextAnchor,
+    Literal[
+        "Bottom",
+        "BottomLeft",
+        "BottomRight",
+        "Center",
+        "Left",
+        "Right",
+        "Top",
+        "TopLeft",
+        "TopRight",
+        "bottom",
+        "bottomleft",
+        "bottomright",
+        "center",
+        "left",
+        "right",
+        "top",
+        "topleft",
+        "topright",
+    ],
+    int,
+]
+TextAnchorArrayLike", "TextAnchorBatch", "TextAnchorLike"]
+
+
+from enum import Enum
+
+
+class TextAnchor(Enum):
+    """
+    **Component**: Which point of a text label is placed at its position.
+
+    E.g. with [`components.TextAnchor.Bottom`][rerun.components.TextAnchor.Bottom], the text is centered horizontally above its position.
+    """
+
+    Center = 1
+    """The center of the text is placed at the position."""
+
+    Top = 2
+    """The middle of the top edge of the text is placed at the position."""
+
+    Bottom = 3
+    """The middle of the bottom edge of the text is placed at the position."""
+
+    Left = 4
+    """The middle of the left edge of the text is placed at the position."""
+
+    Right = 5
+    """The middle of the right edge of the text is placed at the position."""
+
+    TopLeft = 6
+    """The top left corner of the text is placed at the position."""
+
+    TopRight = 7
+    """The top right corner of the text is placed at the position."""
+
+    BottomLeft = 8
+    """The bottom left corner of the text is placed at the position."""
+
+    BottomRight = 9
+    """The bottom right corner of the text is placed at the position."""
+
+    @classmethod
+    def auto(cls, val: str | int | TextAnchor) -> TextAnchor:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TextAnchor):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TextAnchorLike = Union[
+    TextAnchor, Literal["DenseWireframe", "MajorWireframe", "Solid", "densewireframe", "majorwireframe", "solid"], int
+]
+TextAnchorArrayLike = Union[TextAnchorLike, Sequence[TextAnchorLike]]
+
+
+class TextAnchorBatch(BaseBatch[TextAnchorArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.components.TextAnchor"
+
+    @staticmethod
+    def _native_to_pa_array(data: TextAnchorArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TextAnchor, int, str)):
+            data = [data]
+
+        pa_data = [TextAnchor.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,83 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/transform_interpolation.fbs".
+
+# You can extend this class by creating a "TransformInterpolationExt" class in "transform_interpolation_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = [
+    "TransformInterpolation",
+    "TransformInterpolationArrayLike",
+    "TransformInterpolationBatch",
+    "TransformInterpolationLike",
+]
+
+
+from enum import Enum
+
+
+class TransformInterpolation(Enum):
+    """**Component**: Specifies how the viewer resolves a transform at times that fall between two logged samples."""
+
+    Step = 1
+    """
+    The transform jumps to the next sample once it is reached, holding the previous sample until then.
+
+    This is what latest-at queries do for all other components.
+    """
+
+    Linear = 2
+    """
+    The transform is interpolated between the samples before and after the queried time.
+
+    Translations and scales are interpolated linearly, rotations with spherical linear interpolation (slerp).
+    Transforms that are given as a 3x3 matrix can't be interpolated and behave as if [`components.TransformInterpolation`][rerun.components.TransformInterpolation] was `Step`.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | TransformInterpolation) -> TransformInterpolation:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TransformInterpolation):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TransformInterpolationLike = Union[TransformInterpolation, Literal["Linear", "Step", "linear", "step"], int]
+TransformInterpolationArrayLike = Union[TransformInterpolationLike, Sequence[TransformInterpolationLike]]
+
+
+class TransformInterpolationBatch(BaseBatch[TransformInterpolationArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.components.TransformInterpolation"
+
+    @staticmethod
+    def _native_to_pa_array(data: TransformInterpolationArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TransformInterpolation, int, str)):
+            data = [data]
+
+        pa_data = [TransformInterpolation.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
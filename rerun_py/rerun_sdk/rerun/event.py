@@ -26,10 +26,21 @@ class EntitySelectionItem:
 
     entity_path: str
     instance_id: int | None = None
+    view_id: str | None = None
     view_name: str | None = None
     position: list[float] | None = None
 
 
+@dataclass
+class ComponentSelectionItem:
+    @property
+    def type(self) -> Literal["component"]:
+        return "component"
+
+    entity_path: str
+    component: str
+
+
 @dataclass
 class ViewSelectionItem:
     @property
@@ -50,7 +61,7 @@ class ContainerSelectionItem:
     container_name: str
 
 
-SelectionItem = Union[EntitySelectionItem, ViewSelectionItem, ContainerSelectionItem]
+SelectionItem = Union[EntitySelectionItem, ComponentSelectionItem, ViewSelectionItem, ContainerSelectionItem]
 
 
 # Concrete event classes
@@ -106,6 +117,61 @@ class RecordingOpenEvent(ViewerEventBase):
     version: str | None
 
 
+@dataclass
+class RecordingCloseEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["recording_close"]:
+        return "recording_close"
+
+
+@dataclass
+class BlueprintChangeEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["blueprint_change"]:
+        return "blueprint_change"
+
+    blueprint_id: str
+
+
+@dataclass
+class IngestionStartEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["ingestion_start"]:
+        return "ingestion_start"
+
+    source: str
+
+
+@dataclass
+class IngestionFinishEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["ingestion_finish"]:
+        return "ingestion_finish"
+
+    source: str
+    error: str | None = None
+
+
+@dataclass
+class PrunedTimeRange:
+    """The time range covered by the data dropped from a timeline. Both ends are inclusive."""
+
+    timeline: str
+    min: int
+    max: int
+
+
+@dataclass
+class DataPrunedEvent(ViewerEventBase):
+    @property
+    def type(self) -> Literal["data_pruned"]:
+        return "data_pruned"
+
+    time_ranges: list[PrunedTimeRange]
+    bytes_freed: int
+    recording_closed: bool
+
+
 # Union type for all possible event types
 ViewerEvent = Union[
     PlayEvent,
@@ -114,6 +180,11 @@ ViewerEvent = Union[
     TimelineChangeEvent,
     SelectionChangeEvent,
     RecordingOpenEvent,
+    RecordingCloseEvent,
+    BlueprintChangeEvent,
+    IngestionStartEvent,
+    IngestionFinishEvent,
+    DataPrunedEvent,
 ]
 
 
@@ -156,10 +227,18 @@ def _viewer_event_from_json_str(json_str: str) -> ViewerEvent:
                     EntitySelectionItem(
                         entity_path=item["entity_path"],
                         instance_id=item.get("instance_id", None),
+                        view_id=item.get("view_id", None),
                         view_name=item.get("view_name", None),
                         position=item.get("position", None),
                     )
                 )
+            elif item["type"] == "component":
+                items.append(
+                    ComponentSelectionItem(
+                        entity_path=item["entity_path"],
+                        component=item["component"],
+                    )
+                )
             elif item["type"] == "view":
                 items.append(
                     ViewSelectionItem(
@@ -191,5 +270,50 @@ def _viewer_event_from_json_str(json_str: str) -> ViewerEvent:
             version=data.get("version", None),
         )
 
+    elif event_type == "recording_close":
+        return RecordingCloseEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+        )
+
+    elif event_type == "blueprint_change":
+        return BlueprintChangeEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+            blueprint_id=data["blueprint_id"],
+        )
+
+    elif event_type == "ingestion_start":
+        return IngestionStartEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+            source=data["source"],
+        )
+
+    elif event_type == "ingestion_finish":
+        return IngestionFinishEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+            source=data["source"],
+            error=data.get("error", None),
+        )
+
+    elif event_type == "data_pruned":
+        return DataPrunedEvent(
+            application_id=app_id,
+            recording_id=recording_id,
+            partition_id=partition_id,
+            time_ranges=[
+                PrunedTimeRange(timeline=r["timeline"], min=r["min"], max=r["max"])
+                for r in data["time_ranges"]
+            ],
+            bytes_freed=data["bytes_freed"],
+            recording_closed=data["recording_closed"],
+        )
+
     else:
         raise ValueError(f"Unknown event type: '{event_type}'")
@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pytest
+import rerun as rr
+import torch
+from rerun.components import ImageFormat
+from rerun.datatypes import ChannelDatatype
+
+rng = np.random.default_rng(12345)
+RANDOM_VALUES = rng.uniform(0.0, 1.0, (10, 20))
+
+
+def test_heatmap() -> None:
+    ranges = [None, [0.0, 1.0], (-1.0, 1.0)]
+
+    for value_range in ranges:
+        arch = rr.Heatmap(RANDOM_VALUES, colormap="Inferno", value_range=value_range)
+
+        assert arch.buffer == rr.components.ImageBufferBatch._converter(RANDOM_VALUES.astype(np.float32).tobytes())
+        assert arch.format == rr.components.ImageFormatBatch._converter(
+            ImageFormat(
+                width=20,
+                height=10,
+                channel_datatype=ChannelDatatype.F32,
+            ),
+        )
+        assert arch.colormap == rr.components.ColormapBatch._converter("Inferno")
+        assert arch.value_range == rr.components.ValueRangeBatch._converter(value_range)
+
+
+GOOD_VALUE_INPUTS: list[Any] = [
+    rng.uniform(0.0, 1.0, (10, 20)),
+    rng.integers(0, 255, (10, 20)),
+    [[0.0, 1.0], [2.0, 3.0]],
+    rng.uniform(0.0, 1.0, (1, 10, 20)),
+    rng.uniform(0.0, 1.0, (10, 20, 1)),
+    torch.rand(10, 20, 1),
+]
+
+BAD_VALUE_INPUTS: list[Any] = [
+    rng.uniform(0.0, 1.0, (10,)),
+    rng.uniform(0.0, 1.0, (10, 20, 3)),
+    rng.uniform(0.0, 1.0, (1, 10, 20, 3)),
+]
+
+
+def test_heatmap_shapes() -> None:
+    for values in GOOD_VALUE_INPUTS:
+        rr.Heatmap(values)
+
+    for values in BAD_VALUE_INPUTS:
+        with pytest.raises(ValueError):
+            rr.Heatmap(values)
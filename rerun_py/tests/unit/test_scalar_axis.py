@@ -25,27 +25,39 @@ def test_scalar_axis() -> None:
         True,
         False,
     ]
+    log_scales = [
+        False,
+        True,
+    ]
 
     all_arrays = itertools.zip_longest(
         ranges,
         zoom_locks,
+        log_scales,
     )
 
-    for range, zoom_lock in all_arrays:
+    for range, zoom_lock, log_scale in all_arrays:
         range = cast("Optional[rr.datatypes.Range1DLike]", range)
         zoom_lock = cast("Optional[rr.datatypes.Bool]", zoom_lock)
+        log_scale = cast("Optional[rr.datatypes.Bool]", log_scale)
 
         print(
             f"rr.ScalarAxis(\n"
             f"    range={range!r}\n"  #
             f"    zoom_lock={zoom_lock!r}\n"
+            f"    log_scale={log_scale!r}\n"
+            f"    secondary_log_scale={log_scale!r}\n"
             f")",
         )
         arch = rrb.ScalarAxis(
             range=range,
             zoom_lock=zoom_lock,
+            log_scale=log_scale,
+            secondary_log_scale=log_scale,
         )
         print(f"{arch}\n")
 
         assert arch.range == rr.components.Range1DBatch._converter(none_empty_or_value(range, [42.1337, 1337.42]))
         assert arch.zoom_lock == rrb.components.LockRangeDuringZoomBatch._converter(zoom_lock)
+        assert arch.log_scale == rrb.components.EnabledBatch._converter(log_scale)
+        assert arch.secondary_log_scale == rrb.components.EnabledBatch._converter(log_scale)
@@ -0,0 +1,24 @@
+from __future__ import annotations
+
+import rerun.blueprint as rrb
+
+from .blueprint_utils import assert_blueprint_contents_are_equal
+
+
+def test_time_series_view_blueprint() -> None:
+    """Various ways to set the plot style of a `TimeSeriesView` blueprint."""
+
+    bp1 = rrb.TimeSeriesView(origin="plot", name="TimeSeriesView", plot_style="stackedarea")
+    bp2 = rrb.TimeSeriesView(origin="plot", name="TimeSeriesView", plot_style=rrb.PlotMode.StackedArea)
+    bp3 = rrb.TimeSeriesView(
+        origin="plot",
+        name="TimeSeriesView",
+        plot_style=rrb.PlotStyle(rrb.PlotMode.StackedArea),
+    )
+    bp4 = rrb.TimeSeriesView(
+        origin="plot",
+        name="TimeSeriesView",
+        plot_style=rrb.PlotStyle(mode="StackedArea"),
+    )
+
+    assert_blueprint_contents_are_equal(bp1, bp2, bp3, bp4)
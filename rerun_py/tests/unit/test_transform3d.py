@@ -77,6 +77,12 @@ def test_transform3d() -> None:
         "parentfromchild",
         "childfromparent",
     ]
+    interpolations = [
+        None,
+        rr.TransformInterpolation.Step,
+        rr.TransformInterpolation.Linear,
+        "linear",
+    ]
 
     all_arrays = itertools.zip_longest(
         VEC_3D_INPUT + [None],
@@ -85,6 +91,7 @@ def test_transform3d() -> None:
         scale_arrays,
         MAT_3X3_INPUT + [None],
         relations,
+        interpolations,
         axis_lengths,
     )
 
@@ -95,6 +102,7 @@ def test_transform3d() -> None:
         scale,
         mat3x3,
         relation,
+        interpolation,
         axis_length,
     ) in all_arrays:
         translation = cast("Optional[rr.datatypes.Vec3DLike]", translation)
@@ -102,6 +110,7 @@ def test_transform3d() -> None:
         scale = cast("Optional[rr.datatypes.Vec3DLike]", scale)
         mat3x3 = cast("Optional[rr.datatypes.Mat3x3Like]", mat3x3)
         relation = cast("Optional[rr.components.TransformRelationLike]", relations)
+        interpolation = cast("Optional[rr.components.TransformInterpolationLike]", interpolation)
         axis_length = cast("Optional[rr.datatypes.Float32Like]", axis_length)
 
         print(
@@ -112,6 +121,7 @@ def test_transform3d() -> None:
             f"    scale={scale!r}\n"  #
             f"    mat3x3={mat3x3!r}\n"  #
             f"    relation={relation!r}\n"  #
+            f"    interpolation={interpolation!r}\n"  #
             f"    axis_length={axis_length!r}\n"  #
             f")",
         )
@@ -122,6 +132,7 @@ def test_transform3d() -> None:
             scale=scale,
             mat3x3=mat3x3,
             relation=relation,
+            interpolation=interpolation,
             axis_length=axis_length,
         )
         print(f"{arch}\n")
@@ -143,6 +154,9 @@ def test_transform3d() -> None:
             none_empty_or_value(axis_length, rr.components.AxisLength(1.0)),
         )
         assert arch.relation == rr.components.TransformRelationBatch(relation)
+        assert arch.interpolation == rr.components.TransformInterpolationBatch(
+            none_empty_or_value(interpolation, interpolation),
+        )
 
 
 def test_transform_mat3x3_snippets() -> None: